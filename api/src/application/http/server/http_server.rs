@@ -10,16 +10,24 @@ use ferriskey_api_authentication::router::authentication_routes;
 use ferriskey_api_broker::router::broker_routes;
 use ferriskey_api_client::router::client_routes;
 use ferriskey_api_compass::router::compass_routes;
+use ferriskey_api_email_outbox::router::email_outbox_routes;
 use ferriskey_api_email_template::router::email_template_routes;
+use ferriskey_api_localization::router::localization_routes;
 use ferriskey_api_maintenance::router::maintenance_routes;
 use ferriskey_api_organization::router::organization_routes;
 use ferriskey_api_portal_layouts::router::portal_layouts_routes;
 use ferriskey_api_portal_theme::router::portal_theme_routes;
+use ferriskey_api_rate_limit::router::rate_limit_routes;
 use ferriskey_api_realm::router::realm_routes;
+use ferriskey_api_realm_group::router::realm_group_routes;
+use ferriskey_api_risk::router::login_risk_routes;
 use ferriskey_api_role::router::role_routes;
 use ferriskey_api_seawatch::router::seawatch_router;
+use ferriskey_api_security_notification::router::security_notification_routes;
 use ferriskey_api_trident::router::trident_routes;
 use ferriskey_api_user::router::user_routes;
+use ferriskey_api_user_profile::router::user_profile_routes;
+use ferriskey_api_webauthn_policy::router::webauthn_policy_routes;
 use ferriskey_api_webhook::router::webhook_routes;
 
 use super::config::get_config;
@@ -146,6 +154,8 @@ pub fn router(state: AppState) -> Result<Router, anyhow::Error> {
         .merge(webhook_routes(state.clone()))
         .merge(maintenance_routes(state.clone()))
         .merge(email_template_routes(state.clone()))
+        .merge(email_outbox_routes(state.clone()))
+        .merge(security_notification_routes(state.clone()))
         .merge(portal_theme_routes(state.clone()))
         .merge(portal_layouts_routes(state.clone()))
         .merge(trident_routes(state.clone()))
@@ -155,6 +165,12 @@ pub fn router(state: AppState) -> Result<Router, anyhow::Error> {
         .merge(aegis_routes(state.clone()))
         .merge(broker_routes(state.clone(), &root_path))
         .merge(organization_routes(state.clone()))
+        .merge(realm_group_routes(state.clone()))
+        .merge(rate_limit_routes(state.clone()))
+        .merge(login_risk_routes(state.clone()))
+        .merge(user_profile_routes(state.clone()))
+        .merge(localization_routes(state.clone()))
+        .merge(webauthn_policy_routes(state.clone()))
         .merge(health_routes(&root_path))
         .route(
             &format!("{}/metrics", root_path),
@@ -4,18 +4,50 @@ use ferriskey_api_authentication::router::AuthenticationApiDoc;
 use ferriskey_api_broker::BrokerApiDoc;
 use ferriskey_api_client::router::ClientApiDoc;
 use ferriskey_api_compass::router::CompassApiDoc;
+use ferriskey_api_email_outbox::router::EmailOutboxApiDoc;
 use ferriskey_api_email_template::router::{EmailTemplateApiDoc, EmailTemplateVariablesApiDoc};
-use ferriskey_api_maintenance::router::MaintenanceApiDoc;
+use ferriskey_api_localization::router::LocalizationApiDoc;
+use ferriskey_api_maintenance::router::{MaintenanceApiDoc, MaintenanceWindowApiDoc};
 use ferriskey_api_organization::router::OrganizationApiDoc;
 use ferriskey_api_portal_layouts::router::{PortalLayoutsApiDoc, PortalLayoutsPublicApiDoc};
 use ferriskey_api_portal_theme::router::{PortalThemeApiDoc, PortalThemePublicApiDoc};
+use ferriskey_api_rate_limit::router::RateLimitApiDoc;
 use ferriskey_api_realm::router::RealmApiDoc;
+use ferriskey_api_realm_group::router::RealmGroupApiDoc;
+use ferriskey_api_risk::router::LoginRiskApiDoc;
 use ferriskey_api_role::router::RoleApiDoc;
 use ferriskey_api_seawatch::router::SeawatchApiDoc;
+use ferriskey_api_security_notification::router::SecurityNotificationApiDoc;
 use ferriskey_api_trident::router::TridentApiDoc;
 use ferriskey_api_user::router::UserApiDoc;
+use ferriskey_api_user_profile::router::UserProfileApiDoc;
+use ferriskey_api_webauthn_policy::router::WebAuthnPolicyApiDoc;
 use ferriskey_api_webhook::router::WebhookApiDoc;
-use utoipa::OpenApi;
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+/// Every route accepts the access token as a bearer token; routes open to anonymous callers
+/// ignore it. Declared so generated clients send the token they are configured with.
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "Authorization",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+        openapi.security = Some(vec![SecurityRequirement::new(
+            "Authorization",
+            Vec::<String>::new(),
+        )]);
+    }
+}
 
 #[derive(OpenApi)]
 #[openapi(
@@ -23,6 +55,7 @@ use utoipa::OpenApi;
         title = "FerrisKey API",
         license(name = "Apache-2.0", identifier = "Apache-2.0")
     ),
+    modifiers(&BearerAuth),
     nest(
         (path = "/realms", api = RealmApiDoc),
         (path = "/realms/{realm_name}/clients", api = ClientApiDoc),
@@ -37,13 +70,22 @@ use utoipa::OpenApi;
         (path = "/realms/{realm_name}", api = AegisApiDoc),
         (path = "/realms/{realm_name}", api = CompassApiDoc),
         (path = "/realms/{realm_name}/email-templates", api = EmailTemplateApiDoc),
+        (path = "/realms/{realm_name}/emails", api = EmailOutboxApiDoc),
+        (path = "/realms/{realm_name}/security-notifications", api = SecurityNotificationApiDoc),
         (path = "/realms/{realm_name}", api = PortalThemeApiDoc),
         (path = "/realms/{realm_name}/portal", api = PortalThemePublicApiDoc),
         (path = "/realms/{realm_name}/portal-layouts", api = PortalLayoutsApiDoc),
         (path = "/realms/{realm_name}/portal-layouts/public", api = PortalLayoutsPublicApiDoc),
         (path = "/email-templates/variables", api = EmailTemplateVariablesApiDoc),
         (path = "/realms/{realm_name}/organizations", api = OrganizationApiDoc),
-        (path = "/realms/{realm_name}/clients", api = MaintenanceApiDoc)
+        (path = "/realms/{realm_name}", api = RealmGroupApiDoc),
+        (path = "/realms/{realm_name}", api = RateLimitApiDoc),
+        (path = "/realms/{realm_name}", api = LoginRiskApiDoc),
+        (path = "/realms/{realm_name}", api = UserProfileApiDoc),
+        (path = "/realms/{realm_name}", api = LocalizationApiDoc),
+        (path = "/realms/{realm_name}", api = WebAuthnPolicyApiDoc),
+        (path = "/realms/{realm_name}/clients", api = MaintenanceApiDoc),
+        (path = "/realms/{realm_name}/maintenance", api = MaintenanceWindowApiDoc)
     )
 )]
 pub struct ApiDoc;
@@ -97,6 +97,12 @@ mod tests {
             .expect("run migrations");
 
         let service = create_service(FerriskeyConfig {
+            geoip_database: None,
+            fido_metadata_blob: None,
+            fido_metadata_root_certificate: None,
+            mail_maildir: None,
+            mail_outbox_retention_hours: 72,
+            dns_over_https_endpoint: "https://cloudflare-dns.com/dns-query".to_string(),
             webapp_url: "http://localhost:5555".to_string(),
             database: DatabaseConfig {
                 host: db_host,
@@ -0,0 +1,130 @@
+use clap::{Args, Parser, Subcommand};
+use clap_complete::Shell;
+
+use crate::commands::clients::ClientCommand;
+use crate::commands::events::EventCommand;
+use crate::commands::identity_providers::IdentityProviderCommand;
+use crate::commands::organizations::OrganizationCommand;
+use crate::commands::realms::RealmCommand;
+use crate::commands::roles::RoleCommand;
+use crate::commands::users::UserCommand;
+use crate::commands::webhooks::WebhookCommand;
+use crate::output::OutputFormat;
+
+/// Administer a FerrisKey server from scripts and terminals.
+#[derive(Debug, Parser)]
+#[command(name = "ferriskey", version)]
+pub struct Cli {
+    /// Profile to use instead of the default one
+    #[arg(long, short, global = true, env = "FERRISKEY_PROFILE")]
+    pub profile: Option<String>,
+
+    /// Realm to act on instead of the profile's
+    #[arg(long, short, global = true, env = "FERRISKEY_REALM")]
+    pub realm: Option<String>,
+
+    #[arg(long, short, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
+    /// Print the requests that would change state instead of sending them
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Check credentials against a server and save them as a profile
+    Login(LoginArgs),
+    /// Forget a saved profile
+    Logout,
+    /// List saved profiles
+    Profiles,
+    /// Print a shell completion script
+    Completion { shell: Shell },
+
+    /// Realms
+    #[command(subcommand)]
+    Realms(RealmCommand),
+    /// OAuth clients and their secrets
+    #[command(subcommand)]
+    Clients(ClientCommand),
+    /// Users, passwords and role assignments
+    #[command(subcommand)]
+    Users(UserCommand),
+    /// Roles
+    #[command(subcommand)]
+    Roles(RoleCommand),
+    /// Identity providers
+    #[command(subcommand, name = "idp")]
+    IdentityProviders(IdentityProviderCommand),
+    /// Webhooks
+    #[command(subcommand)]
+    Webhooks(WebhookCommand),
+    /// Organizations and their members
+    #[command(subcommand, name = "orgs")]
+    Organizations(OrganizationCommand),
+    /// Security events
+    #[command(subcommand)]
+    Events(EventCommand),
+}
+
+#[derive(Debug, Args)]
+pub struct LoginArgs {
+    /// Base URL of the API, e.g. `https://auth.example.com`
+    #[arg(long)]
+    pub server: String,
+
+    /// Realm the credentials belong to
+    #[arg(long, default_value = "master")]
+    pub auth_realm: String,
+
+    #[arg(long)]
+    pub client_id: String,
+
+    #[arg(long, env = "FERRISKEY_CLIENT_SECRET", hide_env_values = true)]
+    pub client_secret: Option<String>,
+
+    /// Log in with the password grant; the password is asked on every run, or read from
+    /// `FERRISKEY_PASSWORD`
+    #[arg(long)]
+    pub username: Option<String>,
+
+    /// Make this the default profile
+    #[arg(long)]
+    pub default: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn command_definition_is_consistent() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn global_flags_follow_subcommands() {
+        let cli = Cli::try_parse_from([
+            "ferriskey",
+            "clients",
+            "list",
+            "--realm",
+            "acme",
+            "-o",
+            "json",
+            "--dry-run",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.realm.as_deref(), Some("acme"));
+        assert_eq!(cli.output, OutputFormat::Json);
+        assert!(cli.dry_run);
+        assert!(matches!(cli.command, Command::Clients(ClientCommand::List)));
+    }
+}
@@ -0,0 +1,124 @@
+use clap::{Args, Subcommand};
+use ferriskey_sdk::generated::apis::client_api;
+use ferriskey_sdk::generated::models::{ClientType, CreateClientValidator};
+use uuid::Uuid;
+
+use crate::context::Context;
+
+#[derive(Debug, Subcommand)]
+pub enum ClientCommand {
+    List,
+    Get {
+        id: Uuid,
+    },
+    Create(CreateClientArgs),
+    Delete {
+        id: Uuid,
+    },
+    /// Reveal or rotate the secret of a confidential client
+    #[command(subcommand)]
+    Secret(SecretCommand),
+}
+
+#[derive(Debug, Args)]
+pub struct CreateClientArgs {
+    /// The `client_id` applications authenticate with
+    #[arg(long)]
+    client_id: String,
+    #[arg(long)]
+    name: String,
+    /// A public client (SPA, native app) without a secret
+    #[arg(long)]
+    public: bool,
+    #[arg(long)]
+    service_account: bool,
+    #[arg(long)]
+    direct_access_grants: bool,
+    #[arg(long)]
+    disabled: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SecretCommand {
+    Reveal {
+        id: Uuid,
+    },
+    /// Replace the secret; the previous one stops working immediately
+    Rotate {
+        id: Uuid,
+    },
+}
+
+const COLUMNS: &[&str] = &["id", "client_id", "name", "client_type", "enabled"];
+
+pub async fn run(ctx: &Context, command: ClientCommand) -> anyhow::Result<()> {
+    match command {
+        ClientCommand::List => {
+            let clients = ctx
+                .call(|config, realm| async move { client_api::get_clients(&config, &realm).await })
+                .await?;
+            ctx.show(&clients, COLUMNS)
+        }
+        ClientCommand::Get { id } => {
+            let client = ctx
+                .call(|config, realm| async move {
+                    client_api::get_client(&config, &realm, &id.to_string()).await
+                })
+                .await?;
+            ctx.show(&client, &[])
+        }
+        ClientCommand::Create(args) => {
+            let body = CreateClientValidator {
+                client_id: Some(args.client_id),
+                client_type: if args.public {
+                    ClientType::Public
+                } else {
+                    ClientType::Confidential
+                },
+                direct_access_grants_enabled: Some(args.direct_access_grants),
+                enabled: Some(!args.disabled),
+                name: Some(args.name),
+                protocol: Some("openid-connect".to_string()),
+                public_client: Some(args.public),
+                service_account_enabled: Some(args.service_account),
+                ..Default::default()
+            };
+            if ctx.skip_mutation("POST", &ctx.path("/clients"), Some(&body))? {
+                return Ok(());
+            }
+
+            let client = ctx
+                .call(|config, realm| {
+                    let body = body.clone();
+                    async move { client_api::create_client(&config, &realm, body).await }
+                })
+                .await?;
+            ctx.show(&client, &[])
+        }
+        ClientCommand::Delete { id } => {
+            if ctx.skip_mutation::<()>("DELETE", &ctx.path(&format!("/clients/{id}")), None)? {
+                return Ok(());
+            }
+
+            ctx.call(|config, realm| async move {
+                client_api::delete_client(&config, &realm, &id.to_string()).await
+            })
+            .await?;
+            eprintln!("client {id} deleted");
+            Ok(())
+        }
+        ClientCommand::Secret(SecretCommand::Reveal { id }) => {
+            let secret = ctx.admin.clients().reveal_secret(id).await?;
+            ctx.show(&serde_json::json!({ "client_secret": secret }), &[])
+        }
+        ClientCommand::Secret(SecretCommand::Rotate { id }) => {
+            let path = ctx.path(&format!("/clients/{id}/client-secret"));
+            if ctx.skip_mutation::<()>("POST", &path, None)? {
+                return Ok(());
+            }
+
+            let secret = ctx.admin.clients().rotate_secret(id).await?;
+            ctx.show(&serde_json::json!({ "client_secret": secret }), &[])
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use clap::{Args, Subcommand};
+use ferriskey_sdk::generated::apis::seawatch_api;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::context::Context;
+
+#[derive(Debug, Subcommand)]
+pub enum EventCommand {
+    /// Security events of the realm, filtered here since the route takes no filters
+    List(EventFilter),
+}
+
+#[derive(Debug, Default, Args)]
+pub struct EventFilter {
+    /// e.g. `login_failure`, `client_secret_rotated`
+    #[arg(long = "type")]
+    event_type: Option<String>,
+    /// `success` or `failure`
+    #[arg(long)]
+    status: Option<String>,
+    #[arg(long)]
+    actor: Option<Uuid>,
+    #[arg(long)]
+    target: Option<Uuid>,
+    #[arg(long)]
+    limit: Option<usize>,
+}
+
+impl EventFilter {
+    fn matches(&self, event: &Value) -> bool {
+        let field_is = |field: &str, expected: Option<String>| {
+            expected
+                .is_none_or(|expected| event.get(field).and_then(Value::as_str) == Some(&expected))
+        };
+
+        field_is("event_type", self.event_type.clone())
+            && field_is("status", self.status.clone())
+            && field_is("actor_id", self.actor.map(|id| id.to_string()))
+            && field_is("target_id", self.target.map(|id| id.to_string()))
+    }
+
+    fn apply(&self, events: Vec<Value>) -> Vec<Value> {
+        events
+            .into_iter()
+            .filter(|event| self.matches(event))
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+const COLUMNS: &[&str] = &[
+    "timestamp",
+    "event_type",
+    "status",
+    "actor_id",
+    "target_id",
+    "ip_address",
+];
+
+pub async fn run(ctx: &Context, command: EventCommand) -> anyhow::Result<()> {
+    match command {
+        EventCommand::List(filter) => {
+            let events = ctx
+                .call(|config, realm| async move {
+                    seawatch_api::get_security_events(&config, &realm).await
+                })
+                .await?;
+
+            let events = events
+                .data
+                .iter()
+                .map(serde_json::to_value)
+                .collect::<Result<Vec<_>, _>>()?;
+            ctx.show(&filter.apply(events), COLUMNS)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn filters_on_every_given_field_then_limits() {
+        let actor = Uuid::new_v4();
+        let events = vec![
+            json!({ "event_type": "login_failure", "status": "failure", "actor_id": actor }),
+            json!({ "event_type": "login_success", "status": "success", "actor_id": actor }),
+            json!({ "event_type": "login_failure", "status": "failure", "actor_id": null }),
+            json!({ "event_type": "login_failure", "status": "failure", "actor_id": actor }),
+        ];
+
+        let filter = EventFilter {
+            event_type: Some("login_failure".to_string()),
+            actor: Some(actor),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.apply(events.clone()),
+            vec![events[0].clone(), events[3].clone()]
+        );
+
+        let limited = EventFilter {
+            limit: Some(1),
+            ..filter
+        };
+        assert_eq!(limited.apply(events.clone()), vec![events[0].clone()]);
+        assert_eq!(EventFilter::default().apply(events.clone()), events);
+    }
+}
@@ -0,0 +1,53 @@
+use clap::Subcommand;
+use ferriskey_sdk::generated::apis::identity_provider_api;
+
+use crate::context::Context;
+
+#[derive(Debug, Subcommand)]
+pub enum IdentityProviderCommand {
+    List,
+    Get { alias: String },
+    Delete { alias: String },
+}
+
+const COLUMNS: &[&str] = &["alias", "provider_id", "display_name", "enabled"];
+
+pub async fn run(ctx: &Context, command: IdentityProviderCommand) -> anyhow::Result<()> {
+    match command {
+        IdentityProviderCommand::List => {
+            let providers = ctx
+                .call(|config, realm| async move {
+                    identity_provider_api::list_identity_providers(&config, &realm, None).await
+                })
+                .await?;
+            ctx.show(&providers, COLUMNS)
+        }
+        IdentityProviderCommand::Get { alias } => {
+            let provider = ctx
+                .call(|config, realm| {
+                    let alias = alias.clone();
+                    async move {
+                        identity_provider_api::get_identity_provider(&config, &realm, &alias).await
+                    }
+                })
+                .await?;
+            ctx.show(&provider, &[])
+        }
+        IdentityProviderCommand::Delete { alias } => {
+            let path = ctx.path(&format!("/identity-providers/{alias}"));
+            if ctx.skip_mutation::<()>("DELETE", &path, None)? {
+                return Ok(());
+            }
+
+            ctx.call(|config, realm| {
+                let alias = alias.clone();
+                async move {
+                    identity_provider_api::delete_identity_provider(&config, &realm, &alias).await
+                }
+            })
+            .await?;
+            eprintln!("identity provider {alias} deleted");
+            Ok(())
+        }
+    }
+}
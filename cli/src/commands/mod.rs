@@ -0,0 +1,128 @@
+pub mod clients;
+pub mod events;
+pub mod identity_providers;
+pub mod organizations;
+pub mod realms;
+pub mod roles;
+pub mod users;
+pub mod webhooks;
+
+use anyhow::bail;
+use clap::CommandFactory;
+use ferriskey_sdk::FerrisKeyAdmin;
+
+use crate::cli::{Cli, Command, LoginArgs};
+use crate::context::Context;
+use crate::output::render;
+use crate::profile::{Profile, ProfileStore};
+
+pub async fn run(cli: Cli) -> anyhow::Result<()> {
+    let path = ProfileStore::default_path()?;
+    let mut store = ProfileStore::load(&path)?;
+
+    let command = match cli.command {
+        Command::Login(args) => {
+            let name = cli.profile.unwrap_or_else(|| "default".to_string());
+            return login(&mut store, &path, name, cli.realm, args).await;
+        }
+        Command::Logout => {
+            let name = store.resolve(cli.profile.as_deref())?.0.to_string();
+            store.profiles.remove(&name);
+            if store.default.as_deref() == Some(name.as_str()) {
+                store.default = None;
+            }
+            store.save(&path)?;
+            eprintln!("profile {name} removed");
+            return Ok(());
+        }
+        Command::Profiles => {
+            let rows: Vec<_> = store
+                .profiles
+                .iter()
+                .map(|(name, profile)| {
+                    serde_json::json!({
+                        "name": name,
+                        "server": profile.server,
+                        "realm": profile.realm,
+                        "client_id": profile.client_id,
+                        "default": store.default.as_deref() == Some(name.as_str()),
+                    })
+                })
+                .collect();
+            println!(
+                "{}",
+                render(
+                    cli.output,
+                    &rows,
+                    &["name", "server", "realm", "client_id", "default"]
+                )?
+            );
+            return Ok(());
+        }
+        Command::Completion { shell } => {
+            clap_complete::generate(
+                shell,
+                &mut Cli::command(),
+                "ferriskey",
+                &mut std::io::stdout(),
+            );
+            return Ok(());
+        }
+        command => command,
+    };
+
+    let (_, profile) = store.resolve(cli.profile.as_deref())?;
+    let realm = cli.realm.unwrap_or_else(|| profile.realm.clone());
+    let ctx = Context {
+        admin: FerrisKeyAdmin::new(&profile.server, &profile.auth_realm, profile.auth()?)
+            .in_realm(&realm),
+        auth_realm: profile.auth_realm.clone(),
+        output: cli.output,
+        dry_run: cli.dry_run,
+    };
+
+    match command {
+        Command::Realms(command) => realms::run(&ctx, command).await,
+        Command::Clients(command) => clients::run(&ctx, command).await,
+        Command::Users(command) => users::run(&ctx, command).await,
+        Command::Roles(command) => roles::run(&ctx, command).await,
+        Command::IdentityProviders(command) => identity_providers::run(&ctx, command).await,
+        Command::Webhooks(command) => webhooks::run(&ctx, command).await,
+        Command::Organizations(command) => organizations::run(&ctx, command).await,
+        Command::Events(command) => events::run(&ctx, command).await,
+        Command::Login(_) | Command::Logout | Command::Profiles | Command::Completion { .. } => {
+            unreachable!("handled without a profile")
+        }
+    }
+}
+
+async fn login(
+    store: &mut ProfileStore,
+    path: &std::path::Path,
+    name: String,
+    realm: Option<String>,
+    args: LoginArgs,
+) -> anyhow::Result<()> {
+    if args.client_secret.is_none() && args.username.is_none() {
+        bail!("pass --client-secret for a service account or --username for a password login");
+    }
+
+    let profile = Profile {
+        server: args.server.trim_end_matches('/').to_string(),
+        realm: realm.unwrap_or_else(|| args.auth_realm.clone()),
+        auth_realm: args.auth_realm,
+        client_id: args.client_id,
+        client_secret: args.client_secret,
+        username: args.username,
+    };
+    profile.auth()?.access_token().await?;
+
+    if args.default || store.profiles.is_empty() {
+        store.default = Some(name.clone());
+    }
+    store.profiles.insert(name.clone(), profile);
+    store.save(path)?;
+
+    eprintln!("logged in, saved as profile {name}");
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use clap::{Args, Subcommand};
+use ferriskey_sdk::generated::models::CreateOrganizationValidator;
+use uuid::Uuid;
+
+use crate::context::Context;
+
+#[derive(Debug, Subcommand)]
+pub enum OrganizationCommand {
+    List,
+    Get { id: Uuid },
+    Create(CreateOrganizationArgs),
+    Delete { id: Uuid },
+    Members { id: Uuid },
+    AddMember { id: Uuid, user_id: Uuid },
+    RemoveMember { id: Uuid, user_id: Uuid },
+}
+
+#[derive(Debug, Args)]
+pub struct CreateOrganizationArgs {
+    #[arg(long)]
+    name: String,
+    /// URL-safe identifier, unique in the realm
+    #[arg(long)]
+    alias: String,
+    #[arg(long)]
+    domain: Option<String>,
+    #[arg(long)]
+    description: Option<String>,
+}
+
+const COLUMNS: &[&str] = &["id", "name", "alias", "domain", "enabled"];
+
+pub async fn run(ctx: &Context, command: OrganizationCommand) -> anyhow::Result<()> {
+    let organizations = ctx.admin.organizations();
+
+    match command {
+        OrganizationCommand::List => ctx.show(&organizations.list().await?, COLUMNS),
+        OrganizationCommand::Get { id } => ctx.show(&organizations.get(id).await?, &[]),
+        OrganizationCommand::Create(args) => {
+            let body = CreateOrganizationValidator {
+                domain: args.domain.map(Some),
+                description: args.description.map(Some),
+                ..CreateOrganizationValidator::new(args.alias, args.name)
+            };
+            if ctx.skip_mutation("POST", &ctx.path("/organizations"), Some(&body))? {
+                return Ok(());
+            }
+
+            ctx.show(&organizations.create(&body).await?, &[])
+        }
+        OrganizationCommand::Delete { id } => {
+            let path = ctx.path(&format!("/organizations/{id}"));
+            if ctx.skip_mutation::<()>("DELETE", &path, None)? {
+                return Ok(());
+            }
+
+            organizations.delete(id).await?;
+            eprintln!("organization {id} deleted");
+            Ok(())
+        }
+        OrganizationCommand::Members { id } => ctx.show(
+            &organizations.members(id).await?,
+            &["user_id", "created_at"],
+        ),
+        OrganizationCommand::AddMember { id, user_id } => {
+            let path = ctx.path(&format!("/organizations/{id}/members"));
+            let body = serde_json::json!({ "user_id": user_id });
+            if ctx.skip_mutation("POST", &path, Some(&body))? {
+                return Ok(());
+            }
+
+            ctx.show(&organizations.add_member(id, user_id).await?, &[])
+        }
+        OrganizationCommand::RemoveMember { id, user_id } => {
+            let path = ctx.path(&format!("/organizations/{id}/members/{user_id}"));
+            if ctx.skip_mutation::<()>("DELETE", &path, None)? {
+                return Ok(());
+            }
+
+            organizations.remove_member(id, user_id).await?;
+            eprintln!("user {user_id} removed from organization {id}");
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use clap::Subcommand;
+use ferriskey_sdk::generated::apis::realm_api;
+use ferriskey_sdk::generated::models::CreateRealmValidator;
+
+use crate::context::Context;
+
+#[derive(Debug, Subcommand)]
+pub enum RealmCommand {
+    /// Realms the logged-in user can administer
+    List,
+    Get {
+        name: String,
+    },
+    Create {
+        name: String,
+    },
+    Delete {
+        name: String,
+    },
+}
+
+const COLUMNS: &[&str] = &["id", "name", "created_at"];
+
+pub async fn run(ctx: &Context, command: RealmCommand) -> anyhow::Result<()> {
+    match command {
+        RealmCommand::List => {
+            let auth_realm = ctx.auth_realm.clone();
+            let realms = ctx
+                .admin
+                .call(|config| {
+                    let auth_realm = auth_realm.clone();
+                    async move { realm_api::get_user_realms(&config, &auth_realm).await }
+                })
+                .await?;
+            ctx.show(&realms, COLUMNS)
+        }
+        RealmCommand::Get { name } => {
+            let realm = ctx
+                .admin
+                .call(|config| {
+                    let name = name.clone();
+                    async move { realm_api::get_realm(&config, &name).await }
+                })
+                .await?;
+            ctx.show(&realm, &[])
+        }
+        RealmCommand::Create { name } => {
+            let body = CreateRealmValidator {
+                name: Some(name),
+                ..Default::default()
+            };
+            if ctx.skip_mutation("POST", "/realms", Some(&body))? {
+                return Ok(());
+            }
+
+            let realm = ctx
+                .admin
+                .call(|config| {
+                    let body = body.clone();
+                    async move { realm_api::create_realm(&config, body).await }
+                })
+                .await?;
+            ctx.show(&realm, &[])
+        }
+        RealmCommand::Delete { name } => {
+            if ctx.skip_mutation::<()>("DELETE", &format!("/realms/{name}"), None)? {
+                return Ok(());
+            }
+
+            ctx.admin
+                .call(|config| {
+                    let name = name.clone();
+                    async move { realm_api::delete_realm(&config, &name).await }
+                })
+                .await?;
+            eprintln!("realm {name} deleted");
+            Ok(())
+        }
+    }
+}
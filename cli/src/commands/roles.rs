@@ -0,0 +1,45 @@
+use clap::Subcommand;
+use ferriskey_sdk::generated::apis::role_api;
+use uuid::Uuid;
+
+use crate::context::Context;
+
+#[derive(Debug, Subcommand)]
+pub enum RoleCommand {
+    List,
+    Get { id: Uuid },
+    Delete { id: Uuid },
+}
+
+pub const COLUMNS: &[&str] = &["id", "name", "description", "client_id"];
+
+pub async fn run(ctx: &Context, command: RoleCommand) -> anyhow::Result<()> {
+    match command {
+        RoleCommand::List => {
+            let roles = ctx
+                .call(|config, realm| async move { role_api::get_roles(&config, &realm).await })
+                .await?;
+            ctx.show(&roles, COLUMNS)
+        }
+        RoleCommand::Get { id } => {
+            let role = ctx
+                .call(|config, realm| async move {
+                    role_api::get_role(&config, &realm, &id.to_string()).await
+                })
+                .await?;
+            ctx.show(&role, &[])
+        }
+        RoleCommand::Delete { id } => {
+            if ctx.skip_mutation::<()>("DELETE", &ctx.path(&format!("/roles/{id}")), None)? {
+                return Ok(());
+            }
+
+            ctx.call(|config, realm| async move {
+                role_api::delete_role(&config, &realm, &id.to_string()).await
+            })
+            .await?;
+            eprintln!("role {id} deleted");
+            Ok(())
+        }
+    }
+}
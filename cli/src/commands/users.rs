@@ -0,0 +1,172 @@
+use clap::{Args, Subcommand};
+use ferriskey_sdk::generated::apis::user_api;
+use ferriskey_sdk::generated::models::{CreateUserValidator, ResetPasswordValidator};
+use uuid::Uuid;
+
+use crate::context::Context;
+use crate::profile::read_secret;
+
+#[derive(Debug, Subcommand)]
+pub enum UserCommand {
+    List,
+    Get {
+        id: Uuid,
+    },
+    Create(CreateUserArgs),
+    Delete {
+        id: Uuid,
+    },
+    /// Set a password, read from `FERRISKEY_NEW_PASSWORD` or stdin
+    ResetPassword {
+        id: Uuid,
+        /// Require the user to change it at next login
+        #[arg(long)]
+        temporary: bool,
+    },
+    Roles {
+        id: Uuid,
+    },
+    AssignRole {
+        id: Uuid,
+        role_id: Uuid,
+    },
+    UnassignRole {
+        id: Uuid,
+        role_id: Uuid,
+    },
+    /// Lift a brute-force lockout
+    Unlock {
+        id: Uuid,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct CreateUserArgs {
+    #[arg(long)]
+    username: String,
+    #[arg(long)]
+    email: Option<String>,
+    #[arg(long)]
+    firstname: Option<String>,
+    #[arg(long)]
+    lastname: Option<String>,
+    #[arg(long)]
+    email_verified: bool,
+}
+
+const COLUMNS: &[&str] = &["id", "username", "email", "enabled"];
+
+pub async fn run(ctx: &Context, command: UserCommand) -> anyhow::Result<()> {
+    match command {
+        UserCommand::List => {
+            let users = ctx
+                .call(|config, realm| async move { user_api::get_users(&config, &realm).await })
+                .await?;
+            ctx.show(&users, COLUMNS)
+        }
+        UserCommand::Get { id } => {
+            let user = ctx
+                .call(|config, realm| async move {
+                    user_api::get_user(&config, &realm, &id.to_string()).await
+                })
+                .await?;
+            ctx.show(&user, &[])
+        }
+        UserCommand::Create(args) => {
+            let body = CreateUserValidator {
+                email: args.email.map(Some),
+                email_verified: Some(Some(args.email_verified)),
+                firstname: args.firstname.map(Some),
+                lastname: args.lastname.map(Some),
+                username: Some(args.username),
+            };
+            if ctx.skip_mutation("POST", &ctx.path("/users"), Some(&body))? {
+                return Ok(());
+            }
+
+            let user = ctx
+                .call(|config, realm| {
+                    let body = body.clone();
+                    async move { user_api::create_user(&config, &realm, body).await }
+                })
+                .await?;
+            ctx.show(&user, &[])
+        }
+        UserCommand::Delete { id } => {
+            if ctx.skip_mutation::<()>("DELETE", &ctx.path(&format!("/users/{id}")), None)? {
+                return Ok(());
+            }
+
+            ctx.call(|config, realm| async move {
+                user_api::delete_user(&config, &realm, &id.to_string()).await
+            })
+            .await?;
+            eprintln!("user {id} deleted");
+            Ok(())
+        }
+        UserCommand::ResetPassword { id, temporary } => {
+            let path = ctx.path(&format!("/users/{id}/reset-password"));
+            if ctx.skip_mutation::<()>("PUT", &path, None)? {
+                return Ok(());
+            }
+
+            let body = ResetPasswordValidator {
+                credential_type: Some("password".to_string()),
+                temporary: Some(temporary),
+                value: Some(read_secret("FERRISKEY_NEW_PASSWORD", "New password: ")?),
+            };
+            ctx.call(|config, realm| {
+                let body = body.clone();
+                async move { user_api::reset_password(&config, &realm, &id.to_string(), body).await }
+            })
+            .await?;
+            eprintln!("password of user {id} reset");
+            Ok(())
+        }
+        UserCommand::Roles { id } => {
+            let roles = ctx
+                .call(|config, realm| async move {
+                    user_api::get_user_roles(&config, &realm, &id.to_string()).await
+                })
+                .await?;
+            ctx.show(&roles, super::roles::COLUMNS)
+        }
+        UserCommand::AssignRole { id, role_id } => {
+            let path = ctx.path(&format!("/users/{id}/roles/{role_id}"));
+            if ctx.skip_mutation::<()>("POST", &path, None)? {
+                return Ok(());
+            }
+
+            ctx.call(|config, realm| async move {
+                user_api::assign_role(&config, &realm, &id.to_string(), &role_id.to_string()).await
+            })
+            .await?;
+            eprintln!("role {role_id} assigned to user {id}");
+            Ok(())
+        }
+        UserCommand::UnassignRole { id, role_id } => {
+            let path = ctx.path(&format!("/users/{id}/roles/{role_id}"));
+            if ctx.skip_mutation::<()>("DELETE", &path, None)? {
+                return Ok(());
+            }
+
+            ctx.call(|config, realm| async move {
+                user_api::unassign_role(&config, &realm, &id.to_string(), &role_id.to_string())
+                    .await
+            })
+            .await?;
+            eprintln!("role {role_id} unassigned from user {id}");
+            Ok(())
+        }
+        UserCommand::Unlock { id } => {
+            let path = ctx.path(&format!("/users/{id}/unlock"));
+            if ctx.skip_mutation::<()>("POST", &path, None)? {
+                return Ok(());
+            }
+
+            ctx.admin.users().unlock(id).await?;
+            eprintln!("user {id} unlocked");
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use clap::Subcommand;
+use ferriskey_sdk::generated::apis::webhook_api;
+use uuid::Uuid;
+
+use crate::context::Context;
+
+#[derive(Debug, Subcommand)]
+pub enum WebhookCommand {
+    List,
+    Get { id: Uuid },
+    Delete { id: Uuid },
+}
+
+const COLUMNS: &[&str] = &["id", "name", "endpoint", "triggered_at"];
+
+pub async fn run(ctx: &Context, command: WebhookCommand) -> anyhow::Result<()> {
+    match command {
+        WebhookCommand::List => {
+            let webhooks =
+                ctx.call(|config, realm| async move {
+                    webhook_api::fetch_webhooks(&config, &realm).await
+                })
+                .await?;
+            ctx.show(&webhooks, COLUMNS)
+        }
+        WebhookCommand::Get { id } => {
+            let webhook = ctx
+                .call(|config, realm| async move {
+                    webhook_api::get_webhook(&config, &realm, &id.to_string()).await
+                })
+                .await?;
+            ctx.show(&webhook, &[])
+        }
+        WebhookCommand::Delete { id } => {
+            if ctx.skip_mutation::<()>("DELETE", &ctx.path(&format!("/webhooks/{id}")), None)? {
+                return Ok(());
+            }
+
+            ctx.call(|config, realm| async move {
+                webhook_api::delete_webhook(&config, &realm, &id.to_string()).await
+            })
+            .await?;
+            eprintln!("webhook {id} deleted");
+            Ok(())
+        }
+    }
+}
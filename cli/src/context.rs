@@ -0,0 +1,61 @@
+use std::future::Future;
+
+use ferriskey_sdk::FerrisKeyAdmin;
+use ferriskey_sdk::generated::apis::configuration::Configuration;
+use serde::Serialize;
+
+use crate::output::{OutputFormat, render};
+
+/// What every realm-scoped command runs with.
+pub struct Context {
+    pub admin: FerrisKeyAdmin,
+    /// The realm the credentials belong to.
+    pub auth_realm: String,
+    pub output: OutputFormat,
+    pub dry_run: bool,
+}
+
+impl Context {
+    pub fn realm(&self) -> &str {
+        self.admin.realm()
+    }
+
+    /// `/realms/{realm}{suffix}`, as shown by `--dry-run`.
+    pub fn path(&self, suffix: &str) -> String {
+        format!("/realms/{}{suffix}", self.realm())
+    }
+
+    pub fn show<T: Serialize>(&self, value: &T, columns: &[&str]) -> anyhow::Result<()> {
+        println!("{}", render(self.output, value, columns)?);
+        Ok(())
+    }
+
+    /// With `--dry-run`, prints the request a mutation would send and returns `true`; the
+    /// caller then stops before sending it.
+    pub fn skip_mutation<B: Serialize>(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&B>,
+    ) -> anyhow::Result<bool> {
+        if !self.dry_run {
+            return Ok(false);
+        }
+
+        eprintln!("dry run: {method} {path}");
+        if let Some(body) = body {
+            println!("{}", serde_json::to_string_pretty(body)?);
+        }
+        Ok(true)
+    }
+
+    /// Runs a generated `ferriskey_client::apis` function for the current realm.
+    pub async fn call<T, E, F, Fut>(&self, f: F) -> anyhow::Result<T>
+    where
+        F: Fn(Configuration, String) -> Fut,
+        Fut: Future<Output = Result<T, ferriskey_sdk::generated::apis::Error<E>>>,
+    {
+        let realm = self.realm().to_string();
+        Ok(self.admin.call(|config| f(config, realm.clone())).await?)
+    }
+}
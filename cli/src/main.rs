@@ -0,0 +1,21 @@
+mod cli;
+mod commands;
+mod context;
+mod output;
+mod profile;
+
+use clap::Parser;
+
+fn main() {
+    let cli = cli::Cli::parse();
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("failed to start the tokio runtime");
+
+    if let Err(e) = runtime.block_on(commands::run(cli)) {
+        eprintln!("error: {e:#}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,151 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+/// Renders `value` as pretty JSON, or as a table of `columns`. Lists become one row per
+/// item (all fields of the first item when `columns` is empty), single objects a
+/// field/value table. A `{ "data": ... }` envelope is unwrapped first.
+pub fn render<T: Serialize>(
+    format: OutputFormat,
+    value: &T,
+    columns: &[&str],
+) -> anyhow::Result<String> {
+    let value = unwrap_data(serde_json::to_value(value)?);
+
+    Ok(match format {
+        OutputFormat::Json => serde_json::to_string_pretty(&value)?,
+        OutputFormat::Table => match &value {
+            Value::Array(items) => list_table(items, columns),
+            Value::Object(fields) => table(
+                &["FIELD", "VALUE"],
+                fields
+                    .iter()
+                    .map(|(name, value)| vec![name.clone(), cell(value)])
+                    .collect(),
+            ),
+            scalar => cell(scalar),
+        },
+    })
+}
+
+fn unwrap_data(value: Value) -> Value {
+    match value {
+        Value::Object(mut fields) if fields.len() == 1 && fields.contains_key("data") => {
+            fields.remove("data").unwrap_or_default()
+        }
+        value => value,
+    }
+}
+
+fn list_table(items: &[Value], columns: &[&str]) -> String {
+    let columns: Vec<String> = if columns.is_empty() {
+        match items.first() {
+            Some(Value::Object(fields)) => fields.keys().cloned().collect(),
+            _ => vec!["value".to_string()],
+        }
+    } else {
+        columns.iter().map(|column| column.to_string()).collect()
+    };
+
+    let rows = items
+        .iter()
+        .map(|item| {
+            columns
+                .iter()
+                .map(|column| match item {
+                    Value::Object(fields) => fields.get(column).map(cell).unwrap_or_default(),
+                    scalar => cell(scalar),
+                })
+                .collect()
+        })
+        .collect();
+
+    let header: Vec<String> = columns.iter().map(|c| c.to_uppercase()).collect();
+    table(&header.iter().map(String::as_str).collect::<Vec<_>>(), rows)
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn table(header: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    std::iter::once(line(header.to_vec()))
+        .chain(
+            rows.iter()
+                .map(|row| line(row.iter().map(String::as_str).collect())),
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn renders_lists_as_aligned_columns() {
+        let users = json!({ "data": [
+            { "id": 1, "username": "alice", "email": "alice@example.com", "enabled": true },
+            { "id": 2, "username": "bob", "email": null, "enabled": false },
+        ]});
+
+        let rendered = render(
+            OutputFormat::Table,
+            &users,
+            &["username", "email", "enabled"],
+        )
+        .unwrap();
+        assert_eq!(
+            rendered,
+            "USERNAME  EMAIL              ENABLED\n\
+             alice     alice@example.com  true\n\
+             bob                          false"
+        );
+    }
+
+    #[test]
+    fn renders_objects_as_fields_and_json_unchanged() {
+        let client = json!({ "client_id": "web", "redirect_uris": ["https://app"] });
+
+        assert_eq!(
+            render(OutputFormat::Table, &client, &[]).unwrap(),
+            "FIELD          VALUE\n\
+             client_id      web\n\
+             redirect_uris  [\"https://app\"]"
+        );
+        assert_eq!(
+            render(OutputFormat::Json, &json!({ "data": client.clone() }), &[]).unwrap(),
+            serde_json::to_string_pretty(&client).unwrap()
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::BTreeMap;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, anyhow, bail};
+use ferriskey_sdk::FerrisKeyAuth;
+use serde::{Deserialize, Serialize};
+
+/// The credentials and default realm of one FerrisKey server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub server: String,
+    /// The realm the credentials belong to, usually `master`.
+    pub auth_realm: String,
+    /// The realm commands target when `--realm` is not given.
+    pub realm: String,
+    pub client_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    /// Set for password logins. The password itself is never stored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+impl Profile {
+    /// Client credentials when no username is set, the password grant otherwise, with the
+    /// password read from `FERRISKEY_PASSWORD` or prompted on stdin.
+    pub fn auth(&self) -> anyhow::Result<FerrisKeyAuth> {
+        let auth = match &self.username {
+            None => FerrisKeyAuth::client_credentials(
+                &self.server,
+                &self.auth_realm,
+                &self.client_id,
+                self.client_secret
+                    .clone()
+                    .ok_or_else(|| anyhow!("profile has neither a client secret nor a username"))?,
+            ),
+            Some(username) => FerrisKeyAuth::new(
+                &self.server,
+                &self.auth_realm,
+                ferriskey_sdk::Grant::Password {
+                    client_id: self.client_id.clone(),
+                    client_secret: self.client_secret.clone(),
+                    username: username.clone(),
+                    password: read_secret(
+                        "FERRISKEY_PASSWORD",
+                        &format!("Password for {username}: "),
+                    )?,
+                },
+            ),
+        };
+
+        Ok(auth)
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ProfileStore {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl ProfileStore {
+    /// `FERRISKEY_CONFIG`, else `profiles.json` under `$XDG_CONFIG_HOME/ferriskey` or
+    /// `~/.config/ferriskey`.
+    pub fn default_path() -> anyhow::Result<PathBuf> {
+        if let Some(path) = std::env::var_os("FERRISKEY_CONFIG") {
+            return Ok(PathBuf::from(path));
+        }
+
+        let config_dir = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) => PathBuf::from(dir),
+            None => {
+                PathBuf::from(std::env::var_os("HOME").ok_or_else(|| anyhow!("HOME is not set"))?)
+                    .join(".config")
+            }
+        };
+
+        Ok(config_dir.join("ferriskey").join("profiles.json"))
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)
+                .with_context(|| format!("invalid profile file {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("cannot read {}", path.display())),
+        }
+    }
+
+    /// Writes the store readable by its owner only: it holds client secrets.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options
+            .open(path)
+            .with_context(|| format!("cannot write {}", path.display()))?;
+        file.write_all(serde_json::to_string_pretty(self)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// The named profile, the default one, or the only one.
+    pub fn resolve(&self, name: Option<&str>) -> anyhow::Result<(&str, &Profile)> {
+        let name = match name.or(self.default.as_deref()) {
+            Some(name) => name,
+            None if self.profiles.len() == 1 => self.profiles.keys().next().unwrap(),
+            None if self.profiles.is_empty() => bail!("no profile, run `ferriskey login` first"),
+            None => bail!("several profiles and no default, pass --profile"),
+        };
+
+        self.profiles
+            .get_key_value(name)
+            .map(|(name, profile)| (name.as_str(), profile))
+            .ok_or_else(|| anyhow!("unknown profile `{name}`"))
+    }
+}
+
+/// Reads a secret from `env`, or prompts for it on stdin.
+pub fn read_secret(env: &str, prompt: &str) -> anyhow::Result<String> {
+    if let Ok(secret) = std::env::var(env) {
+        return Ok(secret);
+    }
+
+    eprint!("{prompt}");
+    std::io::stderr().flush()?;
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+
+    let secret = line.trim_end_matches(['\r', '\n']).to_string();
+    if secret.is_empty() {
+        bail!("no value given (set {env} to run non-interactively)");
+    }
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(realm: &str) -> Profile {
+        Profile {
+            server: "https://auth.example.com".to_string(),
+            auth_realm: "master".to_string(),
+            realm: realm.to_string(),
+            client_id: "cli".to_string(),
+            client_secret: Some("secret".to_string()),
+            username: None,
+        }
+    }
+
+    #[test]
+    fn resolves_the_named_default_or_only_profile() {
+        let mut store = ProfileStore::default();
+        assert!(store.resolve(None).is_err());
+
+        store.profiles.insert("prod".to_string(), profile("acme"));
+        assert_eq!(store.resolve(None).unwrap().0, "prod");
+
+        store
+            .profiles
+            .insert("staging".to_string(), profile("acme-staging"));
+        assert!(store.resolve(None).is_err());
+        assert_eq!(
+            store.resolve(Some("staging")).unwrap().1.realm,
+            "acme-staging"
+        );
+
+        store.default = Some("prod".to_string());
+        assert_eq!(store.resolve(None).unwrap().0, "prod");
+        assert!(store.resolve(Some("dev")).is_err());
+    }
+
+    #[test]
+    fn round_trips_through_the_profile_file() {
+        let path = std::env::temp_dir()
+            .join(format!("ferriskey-cli-{}", uuid::Uuid::new_v4()))
+            .join("profiles.json");
+
+        assert!(ProfileStore::load(&path).unwrap().profiles.is_empty());
+
+        let mut store = ProfileStore::default();
+        store.profiles.insert("prod".to_string(), profile("acme"));
+        store.save(&path).unwrap();
+
+        let loaded = ProfileStore::load(&path).unwrap();
+        assert_eq!(loaded.profiles["prod"], profile("acme"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
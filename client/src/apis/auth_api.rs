@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -29,6 +29,7 @@ pub enum AuthHandlerError {
 pub enum AuthenticateError {
     Status400(models::ApiError),
     Status401(models::ApiError),
+    Status429(models::ApiError),
     Status500(models::ApiError),
     UnknownValue(serde_json::Value),
 }
@@ -55,12 +56,62 @@ pub enum ChallengeOtpError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`device_authorization`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DeviceAuthorizationError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    Status404(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`device_preview`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DevicePreviewError {
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status429(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`device_verification_page`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DeviceVerificationPageError {
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`device_verify`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DeviceVerifyError {
+    Status400(models::ApiErrorResponse),
+    Status401(),
+    Status403(models::ApiErrorResponse),
+    Status429(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`exchange_token`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ExchangeTokenError {
     Status401(models::ApiErrorResponse),
     Status404(models::ApiErrorResponse),
+    Status429(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`forgot_password`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ForgotPasswordError {
+    Status400(models::ApiErrorResponse),
+    Status429(models::ApiErrorResponse),
     Status500(models::ApiErrorResponse),
     UnknownValue(serde_json::Value),
 }
@@ -102,6 +153,15 @@ pub enum GetOpenidConfigurationError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`get_saml_descriptor`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetSamlDescriptorError {
+    Status404(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`get_userinfo`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -112,6 +172,14 @@ pub enum GetUserinfoError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`idp_initiated_saml_login`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IdpInitiatedSamlLoginError {
+    Status404(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`introspect_token`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -135,6 +203,26 @@ pub enum LogoutPostError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`passkey_authenticate`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PasskeyAuthenticateError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`passkey_request_options`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PasskeyRequestOptionsError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`registration_handler`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -142,10 +230,38 @@ pub enum RegistrationHandlerError {
     Status400(models::ApiErrorResponse),
     Status401(models::ApiErrorResponse),
     Status403(models::ApiErrorResponse),
+    Status422(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`resend_verification_email_handler`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ResendVerificationEmailHandlerError {
+    Status401(models::ApiErrorResponse),
+    Status404(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`reset_password_with_token`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ResetPasswordWithTokenError {
+    Status400(models::ApiErrorResponse),
     Status500(models::ApiErrorResponse),
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`resume_saml_login`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ResumeSamlLoginError {
+    Status400(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`revoke_token`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -153,11 +269,30 @@ pub enum RevokeTokenError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`saml_post_binding`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SamlPostBindingError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`saml_redirect_binding`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SamlRedirectBindingError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`send_magic_link`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum SendMagicLinkError {
     Status400(),
+    Status429(),
     Status500(),
     UnknownValue(serde_json::Value),
 }
@@ -180,6 +315,15 @@ pub enum UpdatePasswordError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`verify_email_handler`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VerifyEmailHandlerError {
+    Status400(models::ApiErrorResponse),
+    Status422(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`verify_magic_link`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -196,10 +340,21 @@ pub enum VerifyMagicLinkError {
 #[serde(untagged)]
 pub enum VerifyOtpError {
     Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
     Status500(models::ApiErrorResponse),
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`verify_reset_token`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VerifyResetTokenError {
+    Status401(models::ApiErrorResponse),
+    Status404(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`webauthn_public_key_authenticate`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -251,6 +406,10 @@ pub async fn auth_handler(
     redirect_uri: Option<&str>,
     scope: Option<&str>,
     state: Option<&str>,
+    nonce: Option<&str>,
+    code_challenge: Option<&str>,
+    code_challenge_method: Option<models::CodeChallengeMethod>,
+    ui_locales: Option<&str>,
 ) -> Result<(), Error<AuthHandlerError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
@@ -259,6 +418,10 @@ pub async fn auth_handler(
     let p_query_redirect_uri = redirect_uri;
     let p_query_scope = scope;
     let p_query_state = state;
+    let p_query_nonce = nonce;
+    let p_query_code_challenge = code_challenge;
+    let p_query_code_challenge_method = code_challenge_method;
+    let p_query_ui_locales = ui_locales;
 
     let uri_str = format!(
         "{}/realms/{realm_name}/protocol/openid-connect/auth",
@@ -282,9 +445,24 @@ pub async fn auth_handler(
     if let Some(ref param_value) = p_query_state {
         req_builder = req_builder.query(&[("state", &param_value.to_string())]);
     }
+    if let Some(ref param_value) = p_query_nonce {
+        req_builder = req_builder.query(&[("nonce", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_code_challenge {
+        req_builder = req_builder.query(&[("code_challenge", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_code_challenge_method {
+        req_builder = req_builder.query(&[("code_challenge_method", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_ui_locales {
+        req_builder = req_builder.query(&[("ui_locales", &param_value.to_string())]);
+    }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -325,6 +503,9 @@ pub async fn authenticate(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_authenticate_request);
 
     let req = req_builder.build()?;
@@ -342,8 +523,8 @@ pub async fn authenticate(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AuthenticateResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AuthenticateResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AuthenticateResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AuthenticateResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -378,6 +559,9 @@ pub async fn burn_recovery_code(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_burn_recovery_code_request);
 
     let req = req_builder.build()?;
@@ -395,8 +579,8 @@ pub async fn burn_recovery_code(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::BurnRecoveryCodeResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::BurnRecoveryCodeResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::BurnRecoveryCodeResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::BurnRecoveryCodeResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -431,6 +615,9 @@ pub async fn challenge_otp(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_challenge_otp_request);
 
     let req = req_builder.build()?;
@@ -448,8 +635,8 @@ pub async fn challenge_otp(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ChallengeOtpResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ChallengeOtpResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ChallengeOtpResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ChallengeOtpResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -462,18 +649,22 @@ pub async fn challenge_otp(
     }
 }
 
-/// Exchanges a token for a JWT token. This endpoint allows clients to exchange various types of tokens (like authorization codes, refresh tokens, etc.) for a JWT token.
-pub async fn exchange_token(
+/// Initiates the OAuth 2.0 Device Authorization Grant (RFC 8628 §3.1). Public clients pass `client_id` in the form body; confidential clients authenticate with HTTP Basic. Returns a device code, an end-user code, and the verification URIs the device should display.
+pub async fn device_authorization(
     configuration: &configuration::Configuration,
     realm_name: &str,
-    token_request_validator: models::TokenRequestValidator,
-) -> Result<models::JwtToken, Error<ExchangeTokenError>> {
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    scope: Option<String>,
+) -> Result<models::InitiateDeviceFlowOutput, Error<DeviceAuthorizationError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
-    let p_body_token_request_validator = token_request_validator;
+    let p_form_client_id = client_id;
+    let p_form_client_secret = client_secret;
+    let p_form_scope = scope;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/protocol/openid-connect/token",
+        "{}/realms/{realm_name}/protocol/openid-connect/auth/device",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name)
     );
@@ -484,7 +675,20 @@ pub async fn exchange_token(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    req_builder = req_builder.json(&p_body_token_request_validator);
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    let mut multipart_form_params = std::collections::HashMap::new();
+    if let Some(param_value) = p_form_client_id {
+        multipart_form_params.insert("client_id", param_value.to_string());
+    }
+    if let Some(param_value) = p_form_client_secret {
+        multipart_form_params.insert("client_secret", param_value.to_string());
+    }
+    if let Some(param_value) = p_form_scope {
+        multipart_form_params.insert("scope", param_value.to_string());
+    }
+    req_builder = req_builder.form(&multipart_form_params);
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -501,12 +705,12 @@ pub async fn exchange_token(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::JwtToken`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::JwtToken`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::InitiateDeviceFlowOutput`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::InitiateDeviceFlowOutput`")))),
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<ExchangeTokenError> = serde_json::from_str(&content).ok();
+        let entity: Option<DeviceAuthorizationError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
@@ -515,29 +719,30 @@ pub async fn exchange_token(
     }
 }
 
-/// Generates recovery codes that allows the user to bypass a MFA challenge
-pub async fn generate_recovery_codes(
+/// Returns the client and the scopes a pending device session is asking for, so the verification page can show what is being approved (RFC 8628 §5.3). Requires the identity cookie and refuses codes belonging to another realm.
+pub async fn device_preview(
     configuration: &configuration::Configuration,
     realm_name: &str,
-    generate_recovery_codes_request: models::GenerateRecoveryCodesRequest,
-) -> Result<models::GenerateRecoveryCodesResponse, Error<GenerateRecoveryCodesError>> {
+    user_code: &str,
+) -> Result<models::DeviceVerificationPreview, Error<DevicePreviewError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
-    let p_body_generate_recovery_codes_request = generate_recovery_codes_request;
+    let p_query_user_code = user_code;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/login-actions/generate-recovery-codes",
+        "{}/realms/{realm_name}/device/preview",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name)
     );
-    let mut req_builder = configuration
-        .client
-        .request(reqwest::Method::POST, &uri_str);
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
+    req_builder = req_builder.query(&[("user_code", &p_query_user_code.to_string())]);
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    req_builder = req_builder.json(&p_body_generate_recovery_codes_request);
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -554,12 +759,12 @@ pub async fn generate_recovery_codes(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GenerateRecoveryCodesResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GenerateRecoveryCodesResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::DeviceVerificationPreview`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::DeviceVerificationPreview`")))),
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<GenerateRecoveryCodesError> = serde_json::from_str(&content).ok();
+        let entity: Option<DevicePreviewError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
@@ -568,46 +773,43 @@ pub async fn generate_recovery_codes(
     }
 }
 
-/// Retrieves the JSON Web Key (JWK) keys for a specific realm, used for verifying JWT tokens.
-pub async fn get_certs(
+/// Entry point the user visits (the `verification_uri`) to approve a device. Redirects to the FerrisKey web app, pre-filling the user code when supplied via `?user_code=`.
+pub async fn device_verification_page(
     configuration: &configuration::Configuration,
     realm_name: &str,
-) -> Result<models::GetCertsResponse, Error<GetCertsError>> {
+    user_code: Option<&str>,
+) -> Result<(), Error<DeviceVerificationPageError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
+    let p_query_user_code = user_code;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/protocol/openid-connect/certs",
+        "{}/realms/{realm_name}/device",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name)
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
+    if let Some(ref param_value) = p_query_user_code {
+        req_builder = req_builder.query(&[("user_code", &param_value.to_string())]);
+    }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
 
     let status = resp.status();
-    let content_type = resp
-        .headers()
-        .get("content-type")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("application/octet-stream");
-    let content_type = super::ContentType::from(content_type);
 
     if !status.is_client_error() && !status.is_server_error() {
-        let content = resp.text().await?;
-        match content_type {
-            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetCertsResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetCertsResponse`")))),
-        }
+        Ok(())
     } else {
         let content = resp.text().await?;
-        let entity: Option<GetCertsError> = serde_json::from_str(&content).ok();
+        let entity: Option<DeviceVerificationPageError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
@@ -616,24 +818,32 @@ pub async fn get_certs(
     }
 }
 
-/// Retrieves the JSON Web Key Set (JWKS) for a specific realm, used by resource servers to validate JWT signatures.
-pub async fn get_jwks_json(
+/// Called from the verification page once the user is authenticated. Requires the `FERRISKEY_IDENTITY` cookie; when absent, responds 401 with a `redirect_uri` hint pointing back to the verification page so the front can route to login first.
+pub async fn device_verify(
     configuration: &configuration::Configuration,
     realm_name: &str,
-) -> Result<models::GetCertsResponse, Error<GetJwksJsonError>> {
+    device_verify_request: models::DeviceVerifyRequest,
+) -> Result<models::DeviceVerifyResponse, Error<DeviceVerifyError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
+    let p_body_device_verify_request = device_verify_request;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/protocol/openid-connect/jwks.json",
+        "{}/realms/{realm_name}/device/verify",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name)
     );
-    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
 
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_device_verify_request);
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -650,12 +860,12 @@ pub async fn get_jwks_json(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetCertsResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetCertsResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::DeviceVerifyResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::DeviceVerifyResponse`")))),
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<GetJwksJsonError> = serde_json::from_str(&content).ok();
+        let entity: Option<DeviceVerifyError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
@@ -664,24 +874,32 @@ pub async fn get_jwks_json(
     }
 }
 
-/// Retrieves the OpenID Connect configuration for a specific realm. This endpoint provides metadata about the OpenID Connect provider, including endpoints for authorization, token issuance, introspection, user information, and JWKs.
-pub async fn get_openid_configuration(
+/// Exchanges a token for a JWT token. This endpoint allows clients to exchange various types of tokens (like authorization codes, refresh tokens, etc.) for a JWT token.
+pub async fn exchange_token(
     configuration: &configuration::Configuration,
     realm_name: &str,
-) -> Result<models::GetOpenIdConfigurationResponse, Error<GetOpenidConfigurationError>> {
+    token_request_validator: models::TokenRequestValidator,
+) -> Result<models::JwtToken, Error<ExchangeTokenError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
+    let p_body_token_request_validator = token_request_validator;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/.well-known/openid-configuration",
+        "{}/realms/{realm_name}/protocol/openid-connect/token",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name)
     );
-    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
 
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_token_request_validator);
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -698,12 +916,12 @@ pub async fn get_openid_configuration(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetOpenIdConfigurationResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetOpenIdConfigurationResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::JwtToken`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::JwtToken`")))),
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<GetOpenidConfigurationError> = serde_json::from_str(&content).ok();
+        let entity: Option<ExchangeTokenError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
@@ -712,24 +930,32 @@ pub async fn get_openid_configuration(
     }
 }
 
-/// Retrieves the user information for the authenticated user in a specific realm.
-pub async fn get_userinfo(
+/// Sends a password reset email to the user if the email exists in the realm. Always returns 204 to prevent email enumeration.
+pub async fn forgot_password(
     configuration: &configuration::Configuration,
     realm_name: &str,
-) -> Result<models::UserInfoResponse, Error<GetUserinfoError>> {
+    forgot_password_request: models::ForgotPasswordRequest,
+) -> Result<serde_json::Value, Error<ForgotPasswordError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
+    let p_body_forgot_password_request = forgot_password_request;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/protocol/openid-connect/userinfo",
+        "{}/realms/{realm_name}/login-actions/forgot-password",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name)
     );
-    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
 
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_forgot_password_request);
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -746,12 +972,12 @@ pub async fn get_userinfo(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UserInfoResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UserInfoResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `serde_json::Value`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<GetUserinfoError> = serde_json::from_str(&content).ok();
+        let entity: Option<ForgotPasswordError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
@@ -760,18 +986,18 @@ pub async fn get_userinfo(
     }
 }
 
-/// OAuth2/OIDC Token Introspection (RFC 7662). Only confidential clients may call this endpoint using client_secret_basic or client_secret_post. Authorization requires the caller's service account to have the role `introspect` (treated as the `introspect` scope).
-pub async fn introspect_token(
+/// Generates recovery codes that allows the user to bypass a MFA challenge
+pub async fn generate_recovery_codes(
     configuration: &configuration::Configuration,
     realm_name: &str,
-    introspect_request_validator: models::IntrospectRequestValidator,
-) -> Result<models::TokenIntrospectionResponse, Error<IntrospectTokenError>> {
+    generate_recovery_codes_request: models::GenerateRecoveryCodesRequest,
+) -> Result<models::GenerateRecoveryCodesResponse, Error<GenerateRecoveryCodesError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
-    let p_body_introspect_request_validator = introspect_request_validator;
+    let p_body_generate_recovery_codes_request = generate_recovery_codes_request;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/protocol/openid-connect/token/introspect",
+        "{}/realms/{realm_name}/login-actions/generate-recovery-codes",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name)
     );
@@ -782,7 +1008,10 @@ pub async fn introspect_token(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    req_builder = req_builder.json(&p_body_introspect_request_validator);
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_generate_recovery_codes_request);
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -799,12 +1028,12 @@ pub async fn introspect_token(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::TokenIntrospectionResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::TokenIntrospectionResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GenerateRecoveryCodesResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GenerateRecoveryCodesResponse`")))),
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<IntrospectTokenError> = serde_json::from_str(&content).ok();
+        let entity: Option<GenerateRecoveryCodesError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
@@ -813,55 +1042,49 @@ pub async fn introspect_token(
     }
 }
 
-/// Ends the user's OP session. Supports id_token_hint, post_logout_redirect_uri, state, and client_id.
-pub async fn logout_get(
+/// Retrieves the JSON Web Key (JWK) keys for a specific realm, used for verifying JWT tokens.
+pub async fn get_certs(
     configuration: &configuration::Configuration,
     realm_name: &str,
-    id_token_hint: Option<&str>,
-    post_logout_redirect_uri: Option<&str>,
-    state: Option<&str>,
-    client_id: Option<&str>,
-) -> Result<(), Error<LogoutGetError>> {
+) -> Result<models::GetCertsResponse, Error<GetCertsError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
-    let p_query_id_token_hint = id_token_hint;
-    let p_query_post_logout_redirect_uri = post_logout_redirect_uri;
-    let p_query_state = state;
-    let p_query_client_id = client_id;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/protocol/openid-connect/logout",
+        "{}/realms/{realm_name}/protocol/openid-connect/certs",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name)
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
-    if let Some(ref param_value) = p_query_id_token_hint {
-        req_builder = req_builder.query(&[("id_token_hint", &param_value.to_string())]);
-    }
-    if let Some(ref param_value) = p_query_post_logout_redirect_uri {
-        req_builder = req_builder.query(&[("post_logout_redirect_uri", &param_value.to_string())]);
-    }
-    if let Some(ref param_value) = p_query_state {
-        req_builder = req_builder.query(&[("state", &param_value.to_string())]);
-    }
-    if let Some(ref param_value) = p_query_client_id {
-        req_builder = req_builder.query(&[("client_id", &param_value.to_string())]);
-    }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
 
     let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
 
     if !status.is_client_error() && !status.is_server_error() {
-        Ok(())
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetCertsResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetCertsResponse`")))),
+        }
     } else {
         let content = resp.text().await?;
-        let entity: Option<LogoutGetError> = serde_json::from_str(&content).ok();
+        let entity: Option<GetCertsError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
@@ -870,18 +1093,592 @@ pub async fn logout_get(
     }
 }
 
-/// Ends the user's OP session. Supports id_token_hint, post_logout_redirect_uri, state, and client_id.
-pub async fn logout_post(
+/// Retrieves the JSON Web Key Set (JWKS) for a specific realm, used by resource servers to validate JWT signatures.
+pub async fn get_jwks_json(
     configuration: &configuration::Configuration,
     realm_name: &str,
-    logout_request_validator: models::LogoutRequestValidator,
+) -> Result<models::GetCertsResponse, Error<GetJwksJsonError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/protocol/openid-connect/jwks.json",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetCertsResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetCertsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetJwksJsonError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Retrieves the OpenID Connect configuration for a specific realm. This endpoint provides metadata about the OpenID Connect provider, including endpoints for authorization, token issuance, introspection, user information, and JWKs.
+pub async fn get_openid_configuration(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+) -> Result<models::GetOpenIdConfigurationResponse, Error<GetOpenidConfigurationError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/.well-known/openid-configuration",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetOpenIdConfigurationResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetOpenIdConfigurationResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetOpenidConfigurationError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Returns the realm's SAML 2.0 IdP EntityDescriptor: entity ID, signing certificate, supported NameID formats and the single sign-on and logout endpoints.
+pub async fn get_saml_descriptor(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+) -> Result<String, Error<GetSamlDescriptorError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/protocol/saml/descriptor",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `String`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `String`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetSamlDescriptorError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Retrieves the user information for the authenticated user in a specific realm.
+pub async fn get_userinfo(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+) -> Result<models::UserInfoResponse, Error<GetUserinfoError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/protocol/openid-connect/userinfo",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UserInfoResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UserInfoResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetUserinfoError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Starts an unsolicited login for the SAML client whose IdP-initiated SSO URL name is `url_name`; the response is posted to its default assertion consumer service.
+pub async fn idp_initiated_saml_login(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    url_name: &str,
+) -> Result<(), Error<IdpInitiatedSamlLoginError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_url_name = url_name;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/protocol/saml/clients/{url_name}",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        url_name = crate::apis::urlencode(p_path_url_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<IdpInitiatedSamlLoginError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// OAuth2/OIDC Token Introspection (RFC 7662). Only confidential clients may call this endpoint using client_secret_basic or client_secret_post. Authorization requires the caller's service account to have the role `introspect` (treated as the `introspect` scope).
+pub async fn introspect_token(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    introspect_request_validator: models::IntrospectRequestValidator,
+) -> Result<models::TokenIntrospectionResponse, Error<IntrospectTokenError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_body_introspect_request_validator = introspect_request_validator;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/protocol/openid-connect/token/introspect",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_introspect_request_validator);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::TokenIntrospectionResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::TokenIntrospectionResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<IntrospectTokenError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Ends the user's OP session. Supports id_token_hint, post_logout_redirect_uri, state, and client_id.
+pub async fn logout_get(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    id_token_hint: Option<&str>,
+    post_logout_redirect_uri: Option<&str>,
+    state: Option<&str>,
+    client_id: Option<&str>,
+) -> Result<(), Error<LogoutGetError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_query_id_token_hint = id_token_hint;
+    let p_query_post_logout_redirect_uri = post_logout_redirect_uri;
+    let p_query_state = state;
+    let p_query_client_id = client_id;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/protocol/openid-connect/logout",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_id_token_hint {
+        req_builder = req_builder.query(&[("id_token_hint", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_post_logout_redirect_uri {
+        req_builder = req_builder.query(&[("post_logout_redirect_uri", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_state {
+        req_builder = req_builder.query(&[("state", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_client_id {
+        req_builder = req_builder.query(&[("client_id", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<LogoutGetError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Ends the user's OP session. Supports id_token_hint, post_logout_redirect_uri, state, and client_id.
+pub async fn logout_post(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    logout_request_validator: models::LogoutRequestValidator,
 ) -> Result<(), Error<LogoutPostError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
-    let p_body_logout_request_validator = logout_request_validator;
+    let p_body_logout_request_validator = logout_request_validator;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/protocol/openid-connect/logout",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_logout_request_validator);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<LogoutPostError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Complete passkey authentication by submitting the browser's assertion response. On success, returns a login URL with an authorization code.
+pub async fn passkey_authenticate(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    body: serde_json::Value,
+) -> Result<models::PasskeyAuthenticateResponse, Error<PasskeyAuthenticateError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_body_body = body;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/login-actions/passkey-authenticate",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_body);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::PasskeyAuthenticateResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::PasskeyAuthenticateResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PasskeyAuthenticateError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Initiates a passkey authentication flow. If username is provided, returns a challenge scoped to that user's passkeys. If omitted, returns a discoverable challenge allowing the browser to propose available passkeys.
+pub async fn passkey_request_options(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    passkey_request_options_request: models::PasskeyRequestOptionsRequest,
+) -> Result<serde_json::Value, Error<PasskeyRequestOptionsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_body_passkey_request_options_request = passkey_request_options_request;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/login-actions/passkey-request-options",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_passkey_request_options_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `serde_json::Value`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<PasskeyRequestOptionsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Register a new user in the specified realm. Returns JWT tokens if email verification is disabled, or a pending verification message if enabled.
+pub async fn registration_handler(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    registration_request: models::RegistrationRequest,
+) -> Result<models::RegistrationResponse, Error<RegistrationHandlerError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_body_registration_request = registration_request;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/protocol/openid-connect/registrations",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_registration_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::RegistrationResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RegistrationResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<RegistrationHandlerError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Resend the email verification link to the user's email address. Requires a valid Bearer token in the Authorization header.
+pub async fn resend_verification_email_handler(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+) -> Result<models::ResendVerificationEmailResponse, Error<ResendVerificationEmailHandlerError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/protocol/openid-connect/logout",
+        "{}/realms/{realm_name}/login-actions/resend-verification-email",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name)
     );
@@ -892,18 +1689,32 @@ pub async fn logout_post(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    req_builder = req_builder.json(&p_body_logout_request_validator);
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
 
     let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
 
     if !status.is_client_error() && !status.is_server_error() {
-        Ok(())
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ResendVerificationEmailResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ResendVerificationEmailResponse`")))),
+        }
     } else {
         let content = resp.text().await?;
-        let entity: Option<LogoutPostError> = serde_json::from_str(&content).ok();
+        let entity: Option<ResendVerificationEmailHandlerError> =
+            serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
@@ -912,18 +1723,18 @@ pub async fn logout_post(
     }
 }
 
-/// Register a new user in the specified realm
-pub async fn registration_handler(
+/// Completes the password reset flow by verifying the token and setting a new password. Returns authentication tokens to log the user in directly.
+pub async fn reset_password_with_token(
     configuration: &configuration::Configuration,
     realm_name: &str,
-    registration_request: models::RegistrationRequest,
-) -> Result<models::JwtToken, Error<RegistrationHandlerError>> {
+    reset_password_request: models::ResetPasswordRequest,
+) -> Result<models::CompletePasswordResetResponse, Error<ResetPasswordWithTokenError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
-    let p_body_registration_request = registration_request;
+    let p_body_reset_password_request = reset_password_request;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/protocol/openid-connect/registrations",
+        "{}/realms/{realm_name}/login-actions/reset-password",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name)
     );
@@ -934,7 +1745,10 @@ pub async fn registration_handler(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    req_builder = req_builder.json(&p_body_registration_request);
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_reset_password_request);
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -951,12 +1765,55 @@ pub async fn registration_handler(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::JwtToken`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::JwtToken`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::CompletePasswordResetResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::CompletePasswordResetResponse`")))),
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<RegistrationHandlerError> = serde_json::from_str(&content).ok();
+        let entity: Option<ResetPasswordWithTokenError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Target of a completed SAML login: redeems the authorization code and posts the signed SAML Response to the service provider's assertion consumer service.
+pub async fn resume_saml_login(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    code: &str,
+) -> Result<(), Error<ResumeSamlLoginError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_query_code = code;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/protocol/saml/resume",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    req_builder = req_builder.query(&[("code", &p_query_code.to_string())]);
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ResumeSamlLoginError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
@@ -987,6 +1844,9 @@ pub async fn revoke_token(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_revoke_token_request_validator);
 
     let req = req_builder.build()?;
@@ -1007,6 +1867,140 @@ pub async fn revoke_token(
     }
 }
 
+/// Receives an AuthnRequest, which starts a login, or a LogoutRequest, which ends the SSO session it names and is answered to the service provider.
+pub async fn saml_post_binding(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    relay_state: Option<String>,
+    saml_request: Option<String>,
+    saml_response: Option<String>,
+    sig_alg: Option<String>,
+    signature: Option<String>,
+) -> Result<(), Error<SamlPostBindingError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_form_relay_state = relay_state;
+    let p_form_saml_request = saml_request;
+    let p_form_saml_response = saml_response;
+    let p_form_sig_alg = sig_alg;
+    let p_form_signature = signature;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/protocol/saml",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    let mut multipart_form_params = std::collections::HashMap::new();
+    if let Some(param_value) = p_form_relay_state {
+        multipart_form_params.insert("RelayState", param_value.to_string());
+    }
+    if let Some(param_value) = p_form_saml_request {
+        multipart_form_params.insert("SAMLRequest", param_value.to_string());
+    }
+    if let Some(param_value) = p_form_saml_response {
+        multipart_form_params.insert("SAMLResponse", param_value.to_string());
+    }
+    if let Some(param_value) = p_form_sig_alg {
+        multipart_form_params.insert("SigAlg", param_value.to_string());
+    }
+    if let Some(param_value) = p_form_signature {
+        multipart_form_params.insert("Signature", param_value.to_string());
+    }
+    req_builder = req_builder.form(&multipart_form_params);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<SamlPostBindingError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Receives an AuthnRequest, which starts a login, or a LogoutRequest, which ends the SSO session it names and is answered to the service provider.
+pub async fn saml_redirect_binding(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    saml_request: Option<&str>,
+    saml_response: Option<&str>,
+    relay_state: Option<&str>,
+    sig_alg: Option<&str>,
+    signature: Option<&str>,
+) -> Result<(), Error<SamlRedirectBindingError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_query_saml_request = saml_request;
+    let p_query_saml_response = saml_response;
+    let p_query_relay_state = relay_state;
+    let p_query_sig_alg = sig_alg;
+    let p_query_signature = signature;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/protocol/saml",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_saml_request {
+        req_builder = req_builder.query(&[("SAMLRequest", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_saml_response {
+        req_builder = req_builder.query(&[("SAMLResponse", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_relay_state {
+        req_builder = req_builder.query(&[("RelayState", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_sig_alg {
+        req_builder = req_builder.query(&[("SigAlg", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_signature {
+        req_builder = req_builder.query(&[("Signature", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<SamlRedirectBindingError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
 /// Sends a magic link to the user's email for passwordless authentication. The link contains a unique token that can be used to verify the user's identity.
 pub async fn send_magic_link(
     configuration: &configuration::Configuration,
@@ -1029,6 +2023,9 @@ pub async fn send_magic_link(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_send_magic_link_request);
 
     let req = req_builder.build()?;
@@ -1046,8 +2043,8 @@ pub async fn send_magic_link(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::SendMagicLinkResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::SendMagicLinkResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::SendMagicLinkResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::SendMagicLinkResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -1078,6 +2075,9 @@ pub async fn setup_otp(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -1094,8 +2094,8 @@ pub async fn setup_otp(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::SetupOtpResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::SetupOtpResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::SetupOtpResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::SetupOtpResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -1129,6 +2129,9 @@ pub async fn update_password(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_update_password_request);
 
     let req = req_builder.build()?;
@@ -1146,8 +2149,8 @@ pub async fn update_password(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UpdatePasswordResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UpdatePasswordResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UpdatePasswordResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UpdatePasswordResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -1160,6 +2163,62 @@ pub async fn update_password(
     }
 }
 
+/// Verify a user's email address using the token from the verification email.
+pub async fn verify_email_handler(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    verify_email_request: models::VerifyEmailRequest,
+) -> Result<models::VerifyEmailResult, Error<VerifyEmailHandlerError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_body_verify_email_request = verify_email_request;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/login-actions/verify-email",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_verify_email_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::VerifyEmailResult`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::VerifyEmailResult`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<VerifyEmailHandlerError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
 /// Verifies the magic link token and completes the authentication flow. Returns authentication status and redirect URL with authorization code.
 pub async fn verify_magic_link(
     configuration: &configuration::Configuration,
@@ -1184,6 +2243,9 @@ pub async fn verify_magic_link(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -1200,8 +2262,8 @@ pub async fn verify_magic_link(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AuthenticateResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AuthenticateResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AuthenticateResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AuthenticateResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -1236,6 +2298,9 @@ pub async fn verify_otp(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_otp_verify_request);
 
     let req = req_builder.build()?;
@@ -1253,8 +2318,8 @@ pub async fn verify_otp(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::VerifyOtpResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::VerifyOtpResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::VerifyOtpResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::VerifyOtpResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -1267,6 +2332,62 @@ pub async fn verify_otp(
     }
 }
 
+/// Checks if a password reset token exists and has not expired, without consuming it.
+pub async fn verify_reset_token(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    verify_reset_token_request: models::VerifyResetTokenRequest,
+) -> Result<models::VerifyResetTokenResponse, Error<VerifyResetTokenError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_body_verify_reset_token_request = verify_reset_token_request;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/login-actions/verify-reset-token",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_verify_reset_token_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::VerifyResetTokenResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::VerifyResetTokenResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<VerifyResetTokenError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
 /// Attempt authentication using a WebAuthnAssertionResponse payload for webauthn authentication. See https://w3c.github.io/webauthn/#dictdef-authenticationresponsejson and https://w3c.github.io/webauthn/#authenticatorassertionresponse
 pub async fn webauthn_public_key_authenticate(
     configuration: &configuration::Configuration,
@@ -1289,6 +2410,9 @@ pub async fn webauthn_public_key_authenticate(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_body);
 
     let req = req_builder.build()?;
@@ -1306,8 +2430,8 @@ pub async fn webauthn_public_key_authenticate(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AuthenticationAttemptResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AuthenticationAttemptResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AuthenticationAttemptResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AuthenticationAttemptResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -1343,6 +2467,9 @@ pub async fn webauthn_public_key_create(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_body);
 
     let req = req_builder.build()?;
@@ -1360,8 +2487,8 @@ pub async fn webauthn_public_key_create(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `serde_json::Value`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `serde_json::Value`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -1394,6 +2521,9 @@ pub async fn webauthn_public_key_create_options(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -1410,8 +2540,8 @@ pub async fn webauthn_public_key_create_options(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `serde_json::Value`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `serde_json::Value`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -1445,6 +2575,9 @@ pub async fn webauthn_public_key_request_options(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -1461,8 +2594,8 @@ pub async fn webauthn_public_key_request_options(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `serde_json::Value`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `serde_json::Value`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
         }
     } else {
         let content = resp.text().await?;
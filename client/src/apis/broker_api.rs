@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -33,6 +33,69 @@ pub enum BrokerLoginError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`broker_saml_metadata`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BrokerSamlMetadataError {
+    Status400(models::ApiErrorResponse),
+    Status404(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`broker_saml_response`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BrokerSamlResponseError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`confirm_broker_link`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConfirmBrokerLinkError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_pending_broker_login`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetPendingBrokerLoginError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`review_brokered_profile`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ReviewBrokeredProfileError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`send_link_confirmation_code`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SendLinkConfirmationCodeError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`verify_broker_login_otp`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VerifyBrokerLoginOtpError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// Processes the IdP callback, exchanges code for tokens, and redirects to client
 pub async fn broker_callback(
     configuration: &configuration::Configuration,
@@ -57,14 +120,12 @@ pub async fn broker_callback(
         realm_name = crate::apis::urlencode(p_path_realm_name),
         alias = crate::apis::urlencode(p_path_alias)
     );
-    let mut req_builder = configuration
-        .client
-        .request(reqwest::Method::POST, &uri_str);
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
+    req_builder = req_builder.query(&[("state", &p_query_state.to_string())]);
     if let Some(ref param_value) = p_query_code {
         req_builder = req_builder.query(&[("code", &param_value.to_string())]);
     }
-    req_builder = req_builder.query(&[("state", &p_query_state.to_string())]);
     if let Some(ref param_value) = p_query_error {
         req_builder = req_builder.query(&[("error", &param_value.to_string())]);
     }
@@ -74,6 +135,9 @@ pub async fn broker_callback(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -105,6 +169,8 @@ pub async fn broker_login(
     state: Option<&str>,
     nonce: Option<&str>,
     session_id: Option<&str>,
+    code_challenge: Option<&str>,
+    code_challenge_method: Option<&str>,
 ) -> Result<(), Error<BrokerLoginError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
@@ -116,6 +182,8 @@ pub async fn broker_login(
     let p_query_state = state;
     let p_query_nonce = nonce;
     let p_query_session_id = session_id;
+    let p_query_code_challenge = code_challenge;
+    let p_query_code_challenge_method = code_challenge_method;
 
     let uri_str = format!(
         "{}/realms/{realm_name}/broker/{alias}/login",
@@ -146,9 +214,18 @@ pub async fn broker_login(
     if let Some(ref param_value) = p_query_session_id {
         req_builder = req_builder.query(&[("session_id", &param_value.to_string())]);
     }
+    if let Some(ref param_value) = p_query_code_challenge {
+        req_builder = req_builder.query(&[("code_challenge", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_code_challenge_method {
+        req_builder = req_builder.query(&[("code_challenge_method", &param_value.to_string())]);
+    }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -167,3 +244,371 @@ pub async fn broker_login(
         }))
     }
 }
+
+/// Returns the SP EntityDescriptor: entity ID, assertion consumer service and the realm's signing certificate.
+pub async fn broker_saml_metadata(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    alias: &str,
+) -> Result<String, Error<BrokerSamlMetadataError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_alias = alias;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/broker/{alias}/endpoint/descriptor",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        alias = crate::apis::urlencode(p_path_alias)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `String`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `String`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<BrokerSamlMetadataError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Validates the SAML Response posted by the IdP, finds or creates the user, and redirects to the client
+pub async fn broker_saml_response(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    alias: &str,
+    saml_response: String,
+    relay_state: Option<String>,
+) -> Result<(), Error<BrokerSamlResponseError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_alias = alias;
+    let p_form_saml_response = saml_response;
+    let p_form_relay_state = relay_state;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/broker/{alias}/endpoint",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        alias = crate::apis::urlencode(p_path_alias)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    let mut multipart_form_params = std::collections::HashMap::new();
+    multipart_form_params.insert("SAMLResponse", p_form_saml_response.to_string());
+    if let Some(param_value) = p_form_relay_state {
+        multipart_form_params.insert("RelayState", param_value.to_string());
+    }
+    req_builder = req_builder.form(&multipart_form_params);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<BrokerSamlResponseError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Links the brokered identity to the existing account once its password or the emailed code is confirmed.
+pub async fn confirm_broker_link(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    confirm_broker_link_request: models::ConfirmBrokerLinkRequest,
+) -> Result<models::BrokerLoginStepResponse, Error<ConfirmBrokerLinkError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_body_confirm_broker_link_request = confirm_broker_link_request;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/broker/login-actions/first-login/confirm",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_confirm_broker_link_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::BrokerLoginStepResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::BrokerLoginStepResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ConfirmBrokerLinkError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Describes what a brokered login waits on: a profile review, the confirmation of an existing account, or a one-time password.
+pub async fn get_pending_broker_login(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+) -> Result<models::PendingBrokerLoginView, Error<GetPendingBrokerLoginError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/broker/login-actions/first-login",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::PendingBrokerLoginView`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::PendingBrokerLoginView`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetPendingBrokerLoginError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+pub async fn review_brokered_profile(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    review_brokered_profile_request: models::ReviewBrokeredProfileRequest,
+) -> Result<models::BrokerLoginStepResponse, Error<ReviewBrokeredProfileError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_body_review_brokered_profile_request = review_brokered_profile_request;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/broker/login-actions/first-login/profile",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_review_brokered_profile_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::BrokerLoginStepResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::BrokerLoginStepResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ReviewBrokeredProfileError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Sends a one-time code to the email of the account a brokered login would be linked to.
+pub async fn send_link_confirmation_code(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+) -> Result<(), Error<SendLinkConfirmationCodeError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/broker/login-actions/first-login/send-code",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<SendLinkConfirmationCodeError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+pub async fn verify_broker_login_otp(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    verify_broker_login_otp_request: models::VerifyBrokerLoginOtpRequest,
+) -> Result<models::BrokerLoginStepResponse, Error<VerifyBrokerLoginOtpError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_body_verify_broker_login_otp_request = verify_broker_login_otp_request;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/broker/login-actions/first-login/otp",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_verify_broker_login_otp_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::BrokerLoginStepResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::BrokerLoginStepResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<VerifyBrokerLoginOtpError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -23,6 +23,16 @@ pub enum CreateClientError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`create_client_role`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CreateClientRoleError {
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`create_post_logout_redirect_uri`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -37,10 +47,11 @@ pub enum CreateRedirectUriError {
     UnknownValue(serde_json::Value),
 }
 
-/// struct for typed errors of method [`create_role`]
+/// struct for typed errors of method [`create_web_origin`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
-pub enum CreateRoleError {
+pub enum CreateWebOriginError {
+    Status400(models::ApiErrorResponse),
     Status401(models::ApiErrorResponse),
     Status403(models::ApiErrorResponse),
     Status500(models::ApiErrorResponse),
@@ -75,6 +86,27 @@ pub enum DeleteRedirectUriError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`delete_web_origin`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DeleteWebOriginError {
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status404(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`evaluate_client_scopes`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EvaluateClientScopesError {
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`get_client`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -97,6 +129,15 @@ pub enum GetClientRolesError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`get_client_secret`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetClientSecretError {
+    Status403(),
+    Status404(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`get_clients`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -126,6 +167,50 @@ pub enum GetRedirectUrisError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`get_saml_settings`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetSamlSettingsError {
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status404(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_web_origins`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetWebOriginsError {
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status404(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`import_saml_metadata`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImportSamlMetadataError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status404(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`rotate_client_secret`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RotateClientSecretError {
+    Status400(),
+    Status403(),
+    Status404(),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`update_client`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -155,6 +240,18 @@ pub enum UpdateRedirectUriError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`update_saml_settings`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum UpdateSamlSettingsError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status404(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
 /// Creates a new client within the specified realm. This endpoint allows you to register a new client application that can interact with the realm's resources.
 pub async fn create_client(
     configuration: &configuration::Configuration,
@@ -177,6 +274,9 @@ pub async fn create_client(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_create_client_validator);
 
     let req = req_builder.build()?;
@@ -194,8 +294,8 @@ pub async fn create_client(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Client`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Client`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Client`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Client`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -208,6 +308,65 @@ pub async fn create_client(
     }
 }
 
+/// Creates a new role for a specific client within a realm. This endpoint allows you to define roles that can be assigned to users or groups in the context of a client application.
+pub async fn create_client_role(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    client_id: &str,
+    create_role_validator: models::CreateRoleValidator,
+) -> Result<models::Role, Error<CreateClientRoleError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_client_id = client_id;
+    let p_body_create_role_validator = create_role_validator;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/clients/{client_id}/roles",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        client_id = crate::apis::urlencode(p_path_client_id)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_create_role_validator);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Role`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Role`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<CreateClientRoleError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
 /// Creates a new post-logout redirect URI for the specified client.
 pub async fn create_post_logout_redirect_uri(
     configuration: &configuration::Configuration,
@@ -233,6 +392,9 @@ pub async fn create_post_logout_redirect_uri(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_create_redirect_uri_validator);
 
     let req = req_builder.build()?;
@@ -250,8 +412,8 @@ pub async fn create_post_logout_redirect_uri(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::RedirectUri`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RedirectUri`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::RedirectUri`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RedirectUri`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -289,6 +451,9 @@ pub async fn create_redirect_uri(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_create_redirect_uri_validator);
 
     let req = req_builder.build()?;
@@ -306,8 +471,8 @@ pub async fn create_redirect_uri(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::RedirectUri`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RedirectUri`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::RedirectUri`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RedirectUri`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -320,20 +485,20 @@ pub async fn create_redirect_uri(
     }
 }
 
-/// Creates a new role for a specific client within a realm. This endpoint allows you to define roles that can be assigned to users or groups in the context of a client application.
-pub async fn create_role(
+/// Registers an origin the client is allowed to call FerrisKey from in a browser. The value must be a serialized origin (scheme://host[:port], no path), or the `+` sentinel meaning \"derive the origins from this client's literal redirect URIs\". Enforcement is per realm: the allowlist a browser is answered with is the union of the origins of every enabled client of the realm.
+pub async fn create_web_origin(
     configuration: &configuration::Configuration,
     realm_name: &str,
     client_id: &str,
-    create_role_validator: models::CreateRoleValidator,
-) -> Result<models::Role, Error<CreateRoleError>> {
+    create_web_origin_validator: models::CreateWebOriginValidator,
+) -> Result<models::WebOrigin, Error<CreateWebOriginError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_realm_name = realm_name;
     let p_path_client_id = client_id;
-    let p_body_create_role_validator = create_role_validator;
+    let p_body_create_web_origin_validator = create_web_origin_validator;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/clients/{client_id}/roles",
+        "{}/realms/{realm_name}/clients/{client_id}/web-origins",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name),
         client_id = crate::apis::urlencode(p_path_client_id)
@@ -345,7 +510,10 @@ pub async fn create_role(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    req_builder = req_builder.json(&p_body_create_role_validator);
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_create_web_origin_validator);
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -362,12 +530,12 @@ pub async fn create_role(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Role`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Role`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::WebOrigin`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::WebOrigin`")))),
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<CreateRoleError> = serde_json::from_str(&content).ok();
+        let entity: Option<CreateWebOriginError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
@@ -399,6 +567,9 @@ pub async fn delete_client(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -415,8 +586,8 @@ pub async fn delete_client(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::DeleteClientResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::DeleteClientResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::DeleteClientResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::DeleteClientResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -455,6 +626,9 @@ pub async fn delete_post_logout_redirect_uri(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -500,6 +674,9 @@ pub async fn delete_redirect_uri(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -519,6 +696,113 @@ pub async fn delete_redirect_uri(
     }
 }
 
+/// Removes a registered origin. Browsers may keep honouring a cached preflight until its max-age lapses, and other API replicas may keep serving the origin for up to one cache TTL.
+pub async fn delete_web_origin(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    client_id: &str,
+    web_origin_id: &str,
+) -> Result<(), Error<DeleteWebOriginError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_client_id = client_id;
+    let p_path_web_origin_id = web_origin_id;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/clients/{client_id}/web-origins/{web_origin_id}",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        client_id = crate::apis::urlencode(p_path_client_id),
+        web_origin_id = crate::apis::urlencode(p_path_web_origin_id)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::DELETE, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<DeleteWebOriginError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Previews the effective protocol mappers, roles and token claims (access token, ID token, userinfo) a user would receive from this client for a given scope set — without issuing (signing or persisting) a real token.
+pub async fn evaluate_client_scopes(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    client_id: &str,
+    evaluate_scopes_validator: models::EvaluateScopesValidator,
+) -> Result<models::EvaluateClientScopesResult, Error<EvaluateClientScopesError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_client_id = client_id;
+    let p_body_evaluate_scopes_validator = evaluate_scopes_validator;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/clients/{client_id}/evaluate-scopes",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        client_id = crate::apis::urlencode(p_path_client_id)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_evaluate_scopes_validator);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::EvaluateClientScopesResult`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::EvaluateClientScopesResult`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<EvaluateClientScopesError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
 /// Retrieves a client from the specified realm by its ID.
 pub async fn get_client(
     configuration: &configuration::Configuration,
@@ -540,6 +824,9 @@ pub async fn get_client(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -556,8 +843,8 @@ pub async fn get_client(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetClientResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetClientResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetClientResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetClientResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -591,6 +878,9 @@ pub async fn get_client_roles(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -607,8 +897,8 @@ pub async fn get_client_roles(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetClientRolesResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetClientRolesResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetClientRolesResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetClientRolesResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -621,6 +911,60 @@ pub async fn get_client_roles(
     }
 }
 
+/// Returns the plaintext secret of a confidential client. Requires ManageClients (or ManageRealm) — strictly more than the ViewClients needed to read the client itself — and records a `client_secret_viewed` security event.
+pub async fn get_client_secret(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    client_id: &str,
+) -> Result<models::ClientSecretResponse, Error<GetClientSecretError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_client_id = client_id;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/clients/{client_id}/client-secret",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        client_id = crate::apis::urlencode(p_path_client_id)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientSecretResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientSecretResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetClientSecretError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
 /// Retrieves all clients associated with a specific realm.
 pub async fn get_clients(
     configuration: &configuration::Configuration,
@@ -639,6 +983,9 @@ pub async fn get_clients(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -655,8 +1002,8 @@ pub async fn get_clients(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientsResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientsResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientsResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientsResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -690,6 +1037,9 @@ pub async fn get_post_logout_redirect_uris(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -706,8 +1056,8 @@ pub async fn get_post_logout_redirect_uris(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::RedirectUri&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::RedirectUri&gt;`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec<models::RedirectUri>`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec<models::RedirectUri>`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -741,6 +1091,9 @@ pub async fn get_redirect_uris(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -757,8 +1110,8 @@ pub async fn get_redirect_uris(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::RedirectUri&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::RedirectUri&gt;`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec<models::RedirectUri>`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec<models::RedirectUri>`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -771,6 +1124,229 @@ pub async fn get_redirect_uris(
     }
 }
 
+/// Returns the service provider settings of a `saml` client: assertion consumer and logout endpoints, NameID format, signing and encryption options, certificates and IdP-initiated SSO.
+pub async fn get_saml_settings(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    client_id: &str,
+) -> Result<models::SamlClientSettings, Error<GetSamlSettingsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_client_id = client_id;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/clients/{client_id}/saml",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        client_id = crate::apis::urlencode(p_path_client_id)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::SamlClientSettings`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::SamlClientSettings`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetSamlSettingsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Returns the origins this client is allowed to call FerrisKey from in a browser. A `+` entry expands to the origins of the client's literal redirect URIs; anchored regex redirect URIs are skipped, since an origin cannot be derived from a pattern.
+pub async fn get_web_origins(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    client_id: &str,
+) -> Result<Vec<models::WebOrigin>, Error<GetWebOriginsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_client_id = client_id;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/clients/{client_id}/web-origins",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        client_id = crate::apis::urlencode(p_path_client_id)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec<models::WebOrigin>`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec<models::WebOrigin>`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetWebOriginsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Reads the SPSSODescriptor of the client's entity ID (its `client_id`) and overwrites the assertion consumer services, logout endpoint, certificates and request signing requirement. Options the metadata does not describe are kept.
+pub async fn import_saml_metadata(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    client_id: &str,
+    import_saml_metadata_validator: models::ImportSamlMetadataValidator,
+) -> Result<models::SamlClientSettings, Error<ImportSamlMetadataError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_client_id = client_id;
+    let p_body_import_saml_metadata_validator = import_saml_metadata_validator;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/clients/{client_id}/saml/metadata",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        client_id = crate::apis::urlencode(p_path_client_id)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_import_saml_metadata_validator);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::SamlClientSettings`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::SamlClientSettings`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ImportSamlMetadataError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Replaces the secret of a confidential client with a new random one and returns it. The previous secret stops working immediately. Requires ManageClients (or ManageRealm) and records a `client_secret_rotated` security event.
+pub async fn rotate_client_secret(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    client_id: &str,
+) -> Result<models::ClientSecretResponse, Error<RotateClientSecretError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_client_id = client_id;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/clients/{client_id}/client-secret",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        client_id = crate::apis::urlencode(p_path_client_id)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientSecretResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientSecretResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<RotateClientSecretError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
 /// Updates an existing client in the specified realm. This endpoint allows you to modify client details such as name, client ID, and enabled status.
 pub async fn update_client(
     configuration: &configuration::Configuration,
@@ -796,6 +1372,9 @@ pub async fn update_client(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_update_client_validator);
 
     let req = req_builder.build()?;
@@ -813,8 +1392,8 @@ pub async fn update_client(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UpdateClientResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UpdateClientResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UpdateClientResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UpdateClientResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -853,6 +1432,9 @@ pub async fn update_post_logout_redirect_uri(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_update_redirect_uri_validator);
 
     let req = req_builder.build()?;
@@ -870,8 +1452,8 @@ pub async fn update_post_logout_redirect_uri(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UpdatePostLogoutRedirectUriResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UpdatePostLogoutRedirectUriResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UpdatePostLogoutRedirectUriResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UpdatePostLogoutRedirectUriResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -910,6 +1492,9 @@ pub async fn update_redirect_uri(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_update_redirect_uri_validator);
 
     let req = req_builder.build()?;
@@ -927,8 +1512,8 @@ pub async fn update_redirect_uri(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UpdateRedirectUriResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UpdateRedirectUriResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UpdateRedirectUriResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UpdateRedirectUriResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -940,3 +1525,60 @@ pub async fn update_redirect_uri(
         }))
     }
 }
+
+/// Replaces the service provider settings of a `saml` client. At least one assertion consumer service is required, responses or assertions must be signed, encryption needs an encryption certificate and signed requests need a signing certificate. IdP-initiated SSO URL names are unique within the realm.
+pub async fn update_saml_settings(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    client_id: &str,
+    update_saml_client_settings_request: models::UpdateSamlClientSettingsRequest,
+) -> Result<models::SamlClientSettings, Error<UpdateSamlSettingsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_client_id = client_id;
+    let p_body_update_saml_client_settings_request = update_saml_client_settings_request;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/clients/{client_id}/saml",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        client_id = crate::apis::urlencode(p_path_client_id)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_update_saml_client_settings_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::SamlClientSettings`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::SamlClientSettings`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<UpdateSamlSettingsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
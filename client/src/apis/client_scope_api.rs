@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -126,7 +126,7 @@ pub async fn assign_default_scope(
     let p_path_scope_id = scope_id;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/client-scopes/clients/{client_id}/default-client-scopes/{scope_id}",
+        "{}/realms/{realm_name}/clients/{client_id}/default-client-scopes/{scope_id}",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name),
         client_id = crate::apis::urlencode(p_path_client_id),
@@ -137,6 +137,9 @@ pub async fn assign_default_scope(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -153,8 +156,8 @@ pub async fn assign_default_scope(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScopeMapping`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScopeMapping`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScopeMapping`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScopeMapping`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -179,12 +182,21 @@ pub async fn assign_optional_scope(
     let p_path_client_id = client_id;
     let p_path_scope_id = scope_id;
 
-    let uri_str = format!("{}/realms/{realm_name}/client-scopes/clients/{client_id}/optional-client-scopes/{scope_id}", configuration.base_path, realm_name=crate::apis::urlencode(p_path_realm_name), client_id=crate::apis::urlencode(p_path_client_id), scope_id=crate::apis::urlencode(p_path_scope_id));
+    let uri_str = format!(
+        "{}/realms/{realm_name}/clients/{client_id}/optional-client-scopes/{scope_id}",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        client_id = crate::apis::urlencode(p_path_client_id),
+        scope_id = crate::apis::urlencode(p_path_scope_id)
+    );
     let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -201,8 +213,8 @@ pub async fn assign_optional_scope(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScopeMapping`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScopeMapping`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScopeMapping`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScopeMapping`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -237,6 +249,9 @@ pub async fn create_client_scope(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_create_client_scope_validator);
 
     let req = req_builder.build()?;
@@ -254,8 +269,8 @@ pub async fn create_client_scope(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScope`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScope`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScope`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScope`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -293,6 +308,9 @@ pub async fn create_protocol_mapper(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_create_protocol_mapper_validator);
 
     let req = req_builder.build()?;
@@ -310,8 +328,8 @@ pub async fn create_protocol_mapper(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ProtocolMapper`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ProtocolMapper`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ProtocolMapper`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ProtocolMapper`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -347,6 +365,9 @@ pub async fn delete_client_scope(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -363,8 +384,8 @@ pub async fn delete_client_scope(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::DeleteClientScopeResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::DeleteClientScopeResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::DeleteClientScopeResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::DeleteClientScopeResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -403,6 +424,9 @@ pub async fn delete_protocol_mapper(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -419,8 +443,8 @@ pub async fn delete_protocol_mapper(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::DeleteProtocolMapperResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::DeleteProtocolMapperResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::DeleteProtocolMapperResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::DeleteProtocolMapperResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -444,7 +468,7 @@ pub async fn get_client_client_scopes(
     let p_path_client_id = client_id;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/client-scopes/clients/{client_id}/client-scopes",
+        "{}/realms/{realm_name}/clients/{client_id}/client-scopes",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name),
         client_id = crate::apis::urlencode(p_path_client_id)
@@ -454,6 +478,9 @@ pub async fn get_client_client_scopes(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -470,8 +497,8 @@ pub async fn get_client_client_scopes(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ClientScope&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ClientScope&gt;`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec<models::ClientScope>`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec<models::ClientScope>`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -505,6 +532,9 @@ pub async fn get_client_scope(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -521,8 +551,8 @@ pub async fn get_client_scope(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScope`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScope`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScope`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScope`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -553,6 +583,9 @@ pub async fn get_client_scopes(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -569,8 +602,8 @@ pub async fn get_client_scopes(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScopesResponse`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScopesResponse`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScopesResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScopesResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -596,7 +629,7 @@ pub async fn unassign_default_scope(
     let p_path_scope_id = scope_id;
 
     let uri_str = format!(
-        "{}/realms/{realm_name}/client-scopes/clients/{client_id}/default-client-scopes/{scope_id}",
+        "{}/realms/{realm_name}/clients/{client_id}/default-client-scopes/{scope_id}",
         configuration.base_path,
         realm_name = crate::apis::urlencode(p_path_realm_name),
         client_id = crate::apis::urlencode(p_path_client_id),
@@ -609,6 +642,9 @@ pub async fn unassign_default_scope(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -640,7 +676,13 @@ pub async fn unassign_optional_scope(
     let p_path_client_id = client_id;
     let p_path_scope_id = scope_id;
 
-    let uri_str = format!("{}/realms/{realm_name}/client-scopes/clients/{client_id}/optional-client-scopes/{scope_id}", configuration.base_path, realm_name=crate::apis::urlencode(p_path_realm_name), client_id=crate::apis::urlencode(p_path_client_id), scope_id=crate::apis::urlencode(p_path_scope_id));
+    let uri_str = format!(
+        "{}/realms/{realm_name}/clients/{client_id}/optional-client-scopes/{scope_id}",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        client_id = crate::apis::urlencode(p_path_client_id),
+        scope_id = crate::apis::urlencode(p_path_scope_id)
+    );
     let mut req_builder = configuration
         .client
         .request(reqwest::Method::DELETE, &uri_str);
@@ -648,6 +690,9 @@ pub async fn unassign_optional_scope(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -692,6 +737,9 @@ pub async fn update_client_scope(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_update_client_scope_validator);
 
     let req = req_builder.build()?;
@@ -709,8 +757,8 @@ pub async fn update_client_scope(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScope`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScope`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ClientScope`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ClientScope`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -751,6 +799,9 @@ pub async fn update_protocol_mapper(
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
     req_builder = req_builder.json(&p_body_update_protocol_mapper_validator);
 
     let req = req_builder.build()?;
@@ -768,8 +819,8 @@ pub async fn update_protocol_mapper(
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ProtocolMapper`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ProtocolMapper`")))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ProtocolMapper`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ProtocolMapper`")))),
         }
     } else {
         let content = resp.text().await?;
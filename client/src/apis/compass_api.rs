@@ -0,0 +1,570 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use super::{configuration, ContentType, Error};
+use crate::{apis::ResponseContent, models};
+use reqwest;
+use serde::{de::Error as _, Deserialize, Serialize};
+
+/// struct for typed errors of method [`compare_flow_analytics`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CompareFlowAnalyticsError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`export_flow_metrics`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ExportFlowMetricsError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_daily_activity_stats`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetDailyActivityStatsError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_flow`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetFlowError {
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status404(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_flow_analytics`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetFlowAnalyticsError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_flows`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetFlowsError {
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_stats`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetStatsError {
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status500(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// Computes the flow analytics of a baseline and a current range, with the completion rate and latency percentile changes between them.
+pub async fn compare_flow_analytics(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+    baseline_from: Option<&str>,
+    baseline_to: Option<&str>,
+    client_id: Option<&str>,
+    grant_type: Option<&str>,
+) -> Result<models::CompareFlowAnalyticsResponse, Error<CompareFlowAnalyticsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_query_from = from;
+    let p_query_to = to;
+    let p_query_baseline_from = baseline_from;
+    let p_query_baseline_to = baseline_to;
+    let p_query_client_id = client_id;
+    let p_query_grant_type = grant_type;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/compass/v1/analytics/compare",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_from {
+        req_builder = req_builder.query(&[("from", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_to {
+        req_builder = req_builder.query(&[("to", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_baseline_from {
+        req_builder = req_builder.query(&[("baseline_from", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_baseline_to {
+        req_builder = req_builder.query(&[("baseline_to", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_client_id {
+        req_builder = req_builder.query(&[("client_id", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_grant_type {
+        req_builder = req_builder.query(&[("grant_type", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::CompareFlowAnalyticsResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::CompareFlowAnalyticsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<CompareFlowAnalyticsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Funnel, latency percentile and failure aggregates in the Prometheus text exposition format, for scraping.
+pub async fn export_flow_metrics(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+    client_id: Option<&str>,
+    grant_type: Option<&str>,
+) -> Result<String, Error<ExportFlowMetricsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_query_from = from;
+    let p_query_to = to;
+    let p_query_client_id = client_id;
+    let p_query_grant_type = grant_type;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/compass/v1/analytics/metrics",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_from {
+        req_builder = req_builder.query(&[("from", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_to {
+        req_builder = req_builder.query(&[("to", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_client_id {
+        req_builder = req_builder.query(&[("client_id", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_grant_type {
+        req_builder = req_builder.query(&[("grant_type", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `String`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `String`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ExportFlowMetricsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+pub async fn get_daily_activity_stats(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+    client_id: Option<&str>,
+    user_id: Option<&str>,
+    grant_type: Option<&str>,
+) -> Result<models::GetDailyActivityStatsResponse, Error<GetDailyActivityStatsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_query_from = from;
+    let p_query_to = to;
+    let p_query_client_id = client_id;
+    let p_query_user_id = user_id;
+    let p_query_grant_type = grant_type;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/compass/v1/activity/daily",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_from {
+        req_builder = req_builder.query(&[("from", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_to {
+        req_builder = req_builder.query(&[("to", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_client_id {
+        req_builder = req_builder.query(&[("client_id", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_user_id {
+        req_builder = req_builder.query(&[("user_id", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_grant_type {
+        req_builder = req_builder.query(&[("grant_type", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetDailyActivityStatsResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetDailyActivityStatsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetDailyActivityStatsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+pub async fn get_flow(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    flow_id: &str,
+) -> Result<models::GetFlowResponse, Error<GetFlowError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_flow_id = flow_id;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/compass/v1/flows/{flow_id}",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        flow_id = crate::apis::urlencode(p_path_flow_id)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetFlowResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetFlowResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetFlowError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Funnel conversion per client and grant type, step latency percentiles, failure reasons and hourly buckets for a time range.
+pub async fn get_flow_analytics(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+    client_id: Option<&str>,
+    grant_type: Option<&str>,
+) -> Result<models::GetFlowAnalyticsResponse, Error<GetFlowAnalyticsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_query_from = from;
+    let p_query_to = to;
+    let p_query_client_id = client_id;
+    let p_query_grant_type = grant_type;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/compass/v1/analytics",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_from {
+        req_builder = req_builder.query(&[("from", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_to {
+        req_builder = req_builder.query(&[("to", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_client_id {
+        req_builder = req_builder.query(&[("client_id", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_grant_type {
+        req_builder = req_builder.query(&[("grant_type", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetFlowAnalyticsResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetFlowAnalyticsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetFlowAnalyticsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+pub async fn get_flows(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    client_id: Option<&str>,
+    user_id: Option<&str>,
+    grant_type: Option<&str>,
+    status: Option<&str>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+) -> Result<models::GetFlowsResponse, Error<GetFlowsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_query_client_id = client_id;
+    let p_query_user_id = user_id;
+    let p_query_grant_type = grant_type;
+    let p_query_status = status;
+    let p_query_limit = limit;
+    let p_query_offset = offset;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/compass/v1/flows",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_client_id {
+        req_builder = req_builder.query(&[("client_id", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_user_id {
+        req_builder = req_builder.query(&[("user_id", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_grant_type {
+        req_builder = req_builder.query(&[("grant_type", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_status {
+        req_builder = req_builder.query(&[("status", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_limit {
+        req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_offset {
+        req_builder = req_builder.query(&[("offset", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetFlowsResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetFlowsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetFlowsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+pub async fn get_stats(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+) -> Result<models::GetStatsResponse, Error<GetStatsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/compass/v1/stats",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetStatsResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetStatsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetStatsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
@@ -0,0 +1,151 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use super::{configuration, ContentType, Error};
+use crate::{apis::ResponseContent, models};
+use reqwest;
+use serde::{de::Error as _, Deserialize, Serialize};
+
+/// struct for typed errors of method [`get_outbox_emails`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetOutboxEmailsError {
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`resend_outbox_email`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ResendOutboxEmailError {
+    Status400(models::ApiErrorResponse),
+    Status401(models::ApiErrorResponse),
+    Status403(models::ApiErrorResponse),
+    Status404(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// Returns the most recent emails of the realm with their delivery status, attempts and last error, newest first. Bodies are not returned: they carry sign-in and password reset links.
+pub async fn get_outbox_emails(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    status: Option<models::OutboxEmailStatus>,
+    limit: Option<i64>,
+) -> Result<models::GetOutboxEmailsResponse, Error<GetOutboxEmailsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_query_status = status;
+    let p_query_limit = limit;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/emails",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_status {
+        req_builder = req_builder.query(&[("status", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_limit {
+        req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetOutboxEmailsResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetOutboxEmailsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetOutboxEmailsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
+
+/// Queues a sent or failed email again with a fresh attempt budget. Emails still pending or being sent cannot be resent.
+pub async fn resend_outbox_email(
+    configuration: &configuration::Configuration,
+    realm_name: &str,
+    email_id: &str,
+) -> Result<models::OutboxEmail, Error<ResendOutboxEmailError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_realm_name = realm_name;
+    let p_path_email_id = email_id;
+
+    let uri_str = format!(
+        "{}/realms/{realm_name}/emails/{email_id}/resend",
+        configuration.base_path,
+        realm_name = crate::apis::urlencode(p_path_realm_name),
+        email_id = crate::apis::urlencode(p_path_email_id)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::OutboxEmail`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::OutboxEmail`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ResendOutboxEmailError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
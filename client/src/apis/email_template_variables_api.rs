@@ -0,0 +1,73 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use super::{configuration, ContentType, Error};
+use crate::{apis::ResponseContent, models};
+use reqwest;
+use serde::{de::Error as _, Deserialize, Serialize};
+
+/// struct for typed errors of method [`get_variables`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetVariablesError {
+    Status400(models::ApiErrorResponse),
+    UnknownValue(serde_json::Value),
+}
+
+/// Returns the available variables for a given email type.
+pub async fn get_variables(
+    configuration: &configuration::Configuration,
+    email_type: &str,
+) -> Result<models::GetTemplateVariablesResponse, Error<GetVariablesError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_email_type = email_type;
+
+    let uri_str = format!(
+        "{}/email-templates/variables/{email_type}",
+        configuration.base_path,
+        email_type = crate::apis::urlencode(p_path_email_type)
+    );
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetTemplateVariablesResponse`"))),
+            ContentType::Unsupported(unknown_type) => Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetTemplateVariablesResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetVariablesError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            entity,
+        }))
+    }
+}
@@ -104,11 +104,11 @@ enum ContentType {
 impl From<&str> for ContentType {
     fn from(content_type: &str) -> Self {
         if content_type.starts_with("application") && content_type.contains("json") {
-            return Self::Json;
+            Self::Json
         } else if content_type.starts_with("text/plain") {
-            return Self::Text;
+            Self::Text
         } else {
-            return Self::Unsupported(content_type.to_string());
+            Self::Unsupported(content_type.to_string())
         }
     }
 }
@@ -117,12 +117,28 @@ pub mod auth_api;
 pub mod broker_api;
 pub mod client_api;
 pub mod client_scope_api;
+pub mod compass_api;
+pub mod email_outbox_api;
+pub mod email_template_api;
+pub mod email_template_variables_api;
 pub mod federation_api;
+pub mod group_api;
 pub mod identity_provider_api;
+pub mod localization_api;
+pub mod login_risk_api;
+pub mod maintenance_api;
+pub mod organization_api;
+pub mod portal_layouts_api;
+pub mod portal_theme_api;
+pub mod portal_theme_public_api;
+pub mod rate_limit_api;
 pub mod realm_api;
 pub mod role_api;
 pub mod seawatch_api;
+pub mod security_notification_api;
 pub mod user_api;
+pub mod user_profile_api;
+pub mod webauthn_policy_api;
 pub mod webhook_api;
 
 pub mod configuration;
@@ -0,0 +1,60 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AcceptInvitationValidator {
+    #[serde(
+        rename = "first_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub first_name: Option<Option<String>>,
+    #[serde(
+        rename = "last_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_name: Option<Option<String>>,
+    /// Required when no account exists for the invited email.
+    #[serde(
+        rename = "password",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub password: Option<Option<String>>,
+    #[serde(rename = "token")]
+    pub token: String,
+    /// Only used when no account exists for the invited email; defaults to the email.
+    #[serde(
+        rename = "username",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub username: Option<Option<String>>,
+}
+
+impl AcceptInvitationValidator {
+    pub fn new(token: String) -> AcceptInvitationValidator {
+        AcceptInvitationValidator {
+            first_name: None,
+            last_name: None,
+            password: None,
+            token,
+            username: None,
+        }
+    }
+}
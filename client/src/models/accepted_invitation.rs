@@ -0,0 +1,37 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AcceptedInvitation {
+    /// `true` when accepting created the account.
+    #[serde(rename = "account_created")]
+    pub account_created: bool,
+    #[serde(rename = "organization_id")]
+    pub organization_id: uuid::Uuid,
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+}
+
+impl AcceptedInvitation {
+    pub fn new(
+        account_created: bool,
+        organization_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    ) -> AcceptedInvitation {
+        AcceptedInvitation {
+            account_created,
+            organization_id,
+            user_id,
+        }
+    }
+}
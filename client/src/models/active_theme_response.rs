@@ -0,0 +1,48 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActiveThemeResponse {
+    #[serde(rename = "design_tokens")]
+    pub design_tokens: Box<models::PortalThemeConfig>,
+    #[serde(
+        rename = "layout_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub layout_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "page_tree", deserialize_with = "Option::deserialize")]
+    pub page_tree: Option<serde_json::Value>,
+    #[serde(
+        rename = "theme_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub theme_id: Option<Option<uuid::Uuid>>,
+}
+
+impl ActiveThemeResponse {
+    pub fn new(
+        design_tokens: models::PortalThemeConfig,
+        page_tree: Option<serde_json::Value>,
+    ) -> ActiveThemeResponse {
+        ActiveThemeResponse {
+            design_tokens: Box::new(design_tokens),
+            layout_id: None,
+            page_tree,
+            theme_id: None,
+        }
+    }
+}
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -11,10 +11,12 @@
 use crate::models;
 use serde::{Deserialize, Serialize};
 
-///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
 pub enum ActorType {
     #[serde(rename = "user")]
+    #[default]
     User,
     #[serde(rename = "service_account")]
     ServiceAccount,
@@ -34,9 +36,3 @@ impl std::fmt::Display for ActorType {
         }
     }
 }
-
-impl Default for ActorType {
-    fn default() -> ActorType {
-        Self::User
-    }
-}
@@ -0,0 +1,24 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AddMemberValidator {
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+}
+
+impl AddMemberValidator {
+    pub fn new(user_id: uuid::Uuid) -> AddMemberValidator {
+        AddMemberValidator { user_id }
+    }
+}
@@ -0,0 +1,39 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AddWhitelistEntryValidator {
+    #[serde(
+        rename = "role_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub role_id: Option<Option<uuid::Uuid>>,
+    #[serde(
+        rename = "user_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub user_id: Option<Option<uuid::Uuid>>,
+}
+
+impl AddWhitelistEntryValidator {
+    pub fn new() -> AddWhitelistEntryValidator {
+        AddWhitelistEntryValidator {
+            role_id: None,
+            user_id: None,
+        }
+    }
+}
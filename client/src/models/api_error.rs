@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -21,6 +21,10 @@ pub enum ApiError {
     ApiErrorOneOf4(Box<models::ApiErrorOneOf4>),
     ApiErrorOneOf5(Box<models::ApiErrorOneOf5>),
     ApiErrorOneOf6(Box<models::ApiErrorOneOf6>),
+    /// Answered with `429` and a `Retry-After` header.
+    ApiErrorOneOf7(Box<models::ApiErrorOneOf7>),
+    /// RFC 6749 §5.2 OAuth2 error response
+    ApiErrorOneOf8(Box<models::ApiErrorOneOf8>),
 }
 
 impl Default for ApiError {
@@ -0,0 +1,29 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ApiErrorOneOf7 : Answered with `429` and a `Retry-After` header.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiErrorOneOf7 {
+    /// Answered with `429` and a `Retry-After` header.
+    #[serde(rename = "TooManyRequests")]
+    pub too_many_requests: Box<models::ApiErrorOneOf7TooManyRequests>,
+}
+
+impl ApiErrorOneOf7 {
+    /// Answered with `429` and a `Retry-After` header.
+    pub fn new(too_many_requests: models::ApiErrorOneOf7TooManyRequests) -> ApiErrorOneOf7 {
+        ApiErrorOneOf7 {
+            too_many_requests: Box::new(too_many_requests),
+        }
+    }
+}
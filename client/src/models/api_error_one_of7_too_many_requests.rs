@@ -0,0 +1,31 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ApiErrorOneOf7TooManyRequests : Answered with `429` and a `Retry-After` header.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiErrorOneOf7TooManyRequests {
+    #[serde(rename = "message")]
+    pub message: String,
+    #[serde(rename = "retry_after_seconds")]
+    pub retry_after_seconds: i64,
+}
+
+impl ApiErrorOneOf7TooManyRequests {
+    /// Answered with `429` and a `Retry-After` header.
+    pub fn new(message: String, retry_after_seconds: i64) -> ApiErrorOneOf7TooManyRequests {
+        ApiErrorOneOf7TooManyRequests {
+            message,
+            retry_after_seconds,
+        }
+    }
+}
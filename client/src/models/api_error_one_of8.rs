@@ -0,0 +1,29 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ApiErrorOneOf8 : RFC 6749 §5.2 OAuth2 error response
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiErrorOneOf8 {
+    /// RFC 6749 §5.2 OAuth2 error response
+    #[serde(rename = "OAuthError")]
+    pub o_auth_error: Box<models::ApiErrorOneOf8OAuthError>,
+}
+
+impl ApiErrorOneOf8 {
+    /// RFC 6749 §5.2 OAuth2 error response
+    pub fn new(o_auth_error: models::ApiErrorOneOf8OAuthError) -> ApiErrorOneOf8 {
+        ApiErrorOneOf8 {
+            o_auth_error: Box::new(o_auth_error),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ApiErrorOneOf8OAuthError : RFC 6749 §5.2 OAuth2 error response
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiErrorOneOf8OAuthError {
+    #[serde(rename = "error")]
+    pub error: String,
+    #[serde(rename = "error_description")]
+    pub error_description: String,
+}
+
+impl ApiErrorOneOf8OAuthError {
+    /// RFC 6749 §5.2 OAuth2 error response
+    pub fn new(error: String, error_description: String) -> ApiErrorOneOf8OAuthError {
+        ApiErrorOneOf8OAuthError {
+            error,
+            error_description,
+        }
+    }
+}
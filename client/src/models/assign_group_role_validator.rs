@@ -0,0 +1,24 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AssignGroupRoleValidator {
+    #[serde(rename = "role_id")]
+    pub role_id: uuid::Uuid,
+}
+
+impl AssignGroupRoleValidator {
+    pub fn new(role_id: uuid::Uuid) -> AssignGroupRoleValidator {
+        AssignGroupRoleValidator { role_id }
+    }
+}
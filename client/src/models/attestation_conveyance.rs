@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Whether authenticators are asked to prove their make and model at registration.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum AttestationConveyance {
+    #[serde(rename = "none")]
+    #[default]
+    None,
+    #[serde(rename = "indirect")]
+    Indirect,
+    #[serde(rename = "direct")]
+    Direct,
+}
+
+impl std::fmt::Display for AttestationConveyance {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Indirect => write!(f, "indirect"),
+            Self::Direct => write!(f, "direct"),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// AttributeGroup : A heading under which forms display related attributes.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AttributeGroup {
+    #[serde(
+        rename = "description",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Option<String>>,
+    #[serde(
+        rename = "display_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub display_name: Option<Option<String>>,
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+impl AttributeGroup {
+    /// A heading under which forms display related attributes.
+    pub fn new(name: String) -> AttributeGroup {
+        AttributeGroup {
+            description: None,
+            display_name: None,
+            name,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// AttributePermissions : Audiences allowed to read and to write an attribute. Write access implies read access.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AttributePermissions {
+    #[serde(rename = "edit", skip_serializing_if = "Option::is_none")]
+    pub edit: Option<Vec<models::Audience>>,
+    #[serde(rename = "view", skip_serializing_if = "Option::is_none")]
+    pub view: Option<Vec<models::Audience>>,
+}
+
+impl AttributePermissions {
+    /// Audiences allowed to read and to write an attribute. Write access implies read access.
+    pub fn new() -> AttributePermissions {
+        AttributePermissions {
+            edit: None,
+            view: None,
+        }
+    }
+}
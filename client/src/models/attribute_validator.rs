@@ -0,0 +1,31 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AttributeValidator {
+    /// The whole value must match the regular expression.
+    AttributeValidatorOneOf(Box<models::AttributeValidatorOneOf>),
+    /// Bounds on the number of characters.
+    AttributeValidatorOneOf1(Box<models::AttributeValidatorOneOf1>),
+    /// The value must be one of `values`.
+    AttributeValidatorOneOf2(Box<models::AttributeValidatorOneOf2>),
+    /// Bounds on a `date` attribute.
+    AttributeValidatorOneOf3(Box<models::AttributeValidatorOneOf3>),
+}
+
+impl Default for AttributeValidator {
+    fn default() -> Self {
+        Self::AttributeValidatorOneOf(Default::default())
+    }
+}
@@ -0,0 +1,48 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// AttributeValidatorOneOf : The whole value must match the regular expression.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AttributeValidatorOneOf {
+    /// Shown instead of the default message when the value does not match.
+    #[serde(
+        rename = "message",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub message: Option<Option<String>>,
+    #[serde(rename = "pattern")]
+    pub pattern: String,
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl AttributeValidatorOneOf {
+    /// The whole value must match the regular expression.
+    pub fn new(pattern: String, r#type: Type) -> AttributeValidatorOneOf {
+        AttributeValidatorOneOf {
+            message: None,
+            pattern,
+            r#type,
+        }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "pattern")]
+    #[default]
+    Pattern,
+}
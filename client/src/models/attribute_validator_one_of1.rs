@@ -0,0 +1,52 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// AttributeValidatorOneOf1 : Bounds on the number of characters.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AttributeValidatorOneOf1 {
+    #[serde(
+        rename = "max",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max: Option<Option<i32>>,
+    #[serde(
+        rename = "min",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub min: Option<Option<i32>>,
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl AttributeValidatorOneOf1 {
+    /// Bounds on the number of characters.
+    pub fn new(r#type: Type) -> AttributeValidatorOneOf1 {
+        AttributeValidatorOneOf1 {
+            max: None,
+            min: None,
+            r#type,
+        }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "length")]
+    #[default]
+    Length,
+}
@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// AttributeValidatorOneOf2 : The value must be one of `values`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AttributeValidatorOneOf2 {
+    #[serde(rename = "type")]
+    pub r#type: Type,
+    #[serde(rename = "values")]
+    pub values: Vec<String>,
+}
+
+impl AttributeValidatorOneOf2 {
+    /// The value must be one of `values`.
+    pub fn new(r#type: Type, values: Vec<String>) -> AttributeValidatorOneOf2 {
+        AttributeValidatorOneOf2 { r#type, values }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "options")]
+    #[default]
+    Options,
+}
@@ -0,0 +1,55 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// AttributeValidatorOneOf3 : Bounds on a `date` attribute.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AttributeValidatorOneOf3 {
+    #[serde(
+        rename = "max",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max: Option<Option<String>>,
+    #[serde(
+        rename = "min",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub min: Option<Option<String>>,
+    #[serde(rename = "not_in_future", skip_serializing_if = "Option::is_none")]
+    pub not_in_future: Option<bool>,
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl AttributeValidatorOneOf3 {
+    /// Bounds on a `date` attribute.
+    pub fn new(r#type: Type) -> AttributeValidatorOneOf3 {
+        AttributeValidatorOneOf3 {
+            max: None,
+            min: None,
+            not_in_future: None,
+            r#type,
+        }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "date")]
+    #[default]
+    Date,
+}
@@ -0,0 +1,33 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Who reads or writes an attribute: the user themselves or a realm administrator.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Audience {
+    #[serde(rename = "user")]
+    #[default]
+    User,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl std::fmt::Display for Audience {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::User => write!(f, "user"),
+            Self::Admin => write!(f, "admin"),
+        }
+    }
+}
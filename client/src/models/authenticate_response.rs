@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,6 +13,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AuthenticateResponse {
+    #[serde(
+        rename = "email",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email: Option<Option<String>>,
     #[serde(
         rename = "message",
         default,
@@ -29,13 +36,6 @@ pub struct AuthenticateResponse {
     pub required_actions: Option<Option<Vec<models::RequiredAction>>>,
     #[serde(rename = "status")]
     pub status: models::AuthenticationStatus,
-    #[serde(
-        rename = "token",
-        default,
-        with = "::serde_with::rust::double_option",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub token: Option<Option<String>>,
     #[serde(
         rename = "url",
         default,
@@ -48,10 +48,10 @@ pub struct AuthenticateResponse {
 impl AuthenticateResponse {
     pub fn new(status: models::AuthenticationStatus) -> AuthenticateResponse {
         AuthenticateResponse {
+            email: None,
             message: None,
             required_actions: None,
             status,
-            token: None,
             url: None,
         }
     }
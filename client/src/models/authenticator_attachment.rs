@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum AuthenticatorAttachment {
+    #[serde(rename = "platform")]
+    #[default]
+    Platform,
+    #[serde(rename = "cross_platform")]
+    CrossPlatform,
+}
+
+impl std::fmt::Display for AuthenticatorAttachment {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Platform => write!(f, "platform"),
+            Self::CrossPlatform => write!(f, "cross_platform"),
+        }
+    }
+}
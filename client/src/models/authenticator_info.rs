@@ -0,0 +1,60 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// AuthenticatorInfo : What is known about the authenticator behind a WebAuthn credential.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuthenticatorInfo {
+    /// The model of the authenticator, known when it was registered with attestation.
+    #[serde(
+        rename = "aaguid",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aaguid: Option<Option<uuid::Uuid>>,
+    #[serde(
+        rename = "last_used_at",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_used_at: Option<Option<String>>,
+    /// The model name, from the FIDO metadata.
+    #[serde(
+        rename = "name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub name: Option<Option<String>>,
+    /// The last signature counter the authenticator reported. Authenticators that keep no counter always report 0.
+    #[serde(
+        rename = "sign_count",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sign_count: Option<Option<i32>>,
+}
+
+impl AuthenticatorInfo {
+    /// What is known about the authenticator behind a WebAuthn credential.
+    pub fn new() -> AuthenticatorInfo {
+        AuthenticatorInfo {
+            aaguid: None,
+            last_used_at: None,
+            name: None,
+            sign_count: None,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BrokerLoginStepResponse {
+    /// The next step of a login still waiting on the user
+    #[serde(
+        rename = "login",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub login: Option<Option<Box<models::PendingBrokerLoginView>>>,
+    /// Where to send the browser once the login completed
+    #[serde(
+        rename = "redirect_url",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub redirect_url: Option<Option<String>>,
+    #[serde(rename = "status")]
+    pub status: models::BrokerLoginStepStatus,
+}
+
+impl BrokerLoginStepResponse {
+    pub fn new(status: models::BrokerLoginStepStatus) -> BrokerLoginStepResponse {
+        BrokerLoginStepResponse {
+            login: None,
+            redirect_url: None,
+            status,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// BrokerSamlResponseForm : Form posted by a SAML identity provider to the broker's assertion consumer service
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BrokerSamlResponseForm {
+    /// Opaque value echoed by the identity provider; the login is found via InResponseTo
+    #[serde(
+        rename = "RelayState",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub relay_state: Option<Option<String>>,
+    /// Base64-encoded SAML Response
+    #[serde(rename = "SAMLResponse")]
+    pub saml_response: String,
+}
+
+impl BrokerSamlResponseForm {
+    /// Form posted by a SAML identity provider to the broker's assertion consumer service
+    pub fn new(saml_response: String) -> BrokerSamlResponseForm {
+        BrokerSamlResponseForm {
+            relay_state: None,
+            saml_response,
+        }
+    }
+}
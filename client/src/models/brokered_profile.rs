@@ -0,0 +1,57 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// BrokeredProfile : Profile values entered on the review page; absent values keep what the provider sent.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BrokeredProfile {
+    #[serde(
+        rename = "email",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email: Option<Option<String>>,
+    #[serde(
+        rename = "first_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub first_name: Option<Option<String>>,
+    #[serde(
+        rename = "last_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_name: Option<Option<String>>,
+    #[serde(
+        rename = "username",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub username: Option<Option<String>>,
+}
+
+impl BrokeredProfile {
+    /// Profile values entered on the review page; absent values keep what the provider sent.
+    pub fn new() -> BrokeredProfile {
+        BrokeredProfile {
+            email: None,
+            first_name: None,
+            last_name: None,
+            username: None,
+        }
+    }
+}
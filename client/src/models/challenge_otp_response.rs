@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,12 +13,22 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChallengeOtpResponse {
-    #[serde(rename = "url")]
-    pub url: String,
+    #[serde(rename = "required_actions", skip_serializing_if = "Option::is_none")]
+    pub required_actions: Option<Vec<models::RequiredAction>>,
+    #[serde(
+        rename = "url",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub url: Option<Option<String>>,
 }
 
 impl ChallengeOtpResponse {
-    pub fn new(url: String) -> ChallengeOtpResponse {
-        ChallengeOtpResponse { url }
+    pub fn new() -> ChallengeOtpResponse {
+        ChallengeOtpResponse {
+            required_actions: None,
+            url: None,
+        }
     }
 }
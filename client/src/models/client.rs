@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,10 +13,17 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Client {
+    #[serde(
+        rename = "access_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub access_token_lifetime: Option<Option<i64>>,
     #[serde(rename = "client_id")]
     pub client_id: String,
     #[serde(rename = "client_type")]
-    pub client_type: String,
+    pub client_type: models::ClientType,
     #[serde(rename = "created_at")]
     pub created_at: String,
     #[serde(rename = "direct_access_grants_enabled")]
@@ -25,8 +32,29 @@ pub struct Client {
     pub enabled: bool,
     #[serde(rename = "id")]
     pub id: uuid::Uuid,
+    #[serde(
+        rename = "id_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id_token_lifetime: Option<Option<i64>>,
+    #[serde(rename = "maintenance_enabled")]
+    pub maintenance_enabled: bool,
+    #[serde(
+        rename = "maintenance_reason",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub maintenance_reason: Option<Option<String>>,
+    #[serde(rename = "maintenance_session_strategy")]
+    pub maintenance_session_strategy: models::MaintenanceSessionStrategy,
     #[serde(rename = "name")]
     pub name: String,
+    /// Whether this client is allowed to use the OAuth 2.0 Device Authorization Grant (RFC 8628). Opt-in: most clients should keep this disabled; only browserless devices (CLI, IoT, TVs) need it.
+    #[serde(rename = "oauth_device_code_grant_enabled")]
+    pub oauth_device_code_grant_enabled: bool,
     #[serde(rename = "protocol")]
     pub protocol: String,
     #[serde(rename = "public_client")]
@@ -40,6 +68,15 @@ pub struct Client {
         skip_serializing_if = "Option::is_none"
     )]
     pub redirect_uris: Option<Option<Vec<models::RedirectUri>>>,
+    #[serde(
+        rename = "refresh_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub refresh_token_lifetime: Option<Option<i64>>,
+    #[serde(rename = "require_pkce")]
+    pub require_pkce: bool,
     #[serde(
         rename = "secret",
         default,
@@ -49,6 +86,13 @@ pub struct Client {
     pub secret: Option<Option<String>>,
     #[serde(rename = "service_account_enabled")]
     pub service_account_enabled: bool,
+    #[serde(
+        rename = "temporary_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub temporary_token_lifetime: Option<Option<i64>>,
     #[serde(rename = "updated_at")]
     pub updated_at: String,
 }
@@ -56,32 +100,45 @@ pub struct Client {
 impl Client {
     pub fn new(
         client_id: String,
-        client_type: String,
+        client_type: models::ClientType,
         created_at: String,
         direct_access_grants_enabled: bool,
         enabled: bool,
         id: uuid::Uuid,
+        maintenance_enabled: bool,
+        maintenance_session_strategy: models::MaintenanceSessionStrategy,
         name: String,
+        oauth_device_code_grant_enabled: bool,
         protocol: String,
         public_client: bool,
         realm_id: uuid::Uuid,
+        require_pkce: bool,
         service_account_enabled: bool,
         updated_at: String,
     ) -> Client {
         Client {
+            access_token_lifetime: None,
             client_id,
             client_type,
             created_at,
             direct_access_grants_enabled,
             enabled,
             id,
+            id_token_lifetime: None,
+            maintenance_enabled,
+            maintenance_reason: None,
+            maintenance_session_strategy,
             name,
+            oauth_device_code_grant_enabled,
             protocol,
             public_client,
             realm_id,
             redirect_uris: None,
+            refresh_token_lifetime: None,
+            require_pkce,
             secret: None,
             service_account_enabled,
+            temporary_token_lifetime: None,
             updated_at,
         }
     }
@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Client authentication toward the token endpoint of the upstream identity provider.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum ClientAuthMethod {
+    #[serde(rename = "client_secret_post")]
+    #[default]
+    ClientSecretPost,
+    #[serde(rename = "client_secret_basic")]
+    ClientSecretBasic,
+    #[serde(rename = "private_key_jwt")]
+    PrivateKeyJwt,
+}
+
+impl std::fmt::Display for ClientAuthMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ClientSecretPost => write!(f, "client_secret_post"),
+            Self::ClientSecretBasic => write!(f, "client_secret_basic"),
+            Self::PrivateKeyJwt => write!(f, "private_key_jwt"),
+        }
+    }
+}
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -22,6 +22,8 @@ pub struct ClientScope {
     pub attributes: Option<Option<Vec<models::ClientScopeAttribute>>>,
     #[serde(rename = "created_at")]
     pub created_at: String,
+    #[serde(rename = "default_scope_type")]
+    pub default_scope_type: models::ScopeType,
     #[serde(
         rename = "description",
         default,
@@ -31,8 +33,6 @@ pub struct ClientScope {
     pub description: Option<Option<String>>,
     #[serde(rename = "id")]
     pub id: uuid::Uuid,
-    #[serde(rename = "is_default")]
-    pub is_default: bool,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "protocol")]
@@ -53,8 +53,8 @@ pub struct ClientScope {
 impl ClientScope {
     pub fn new(
         created_at: String,
+        default_scope_type: models::ScopeType,
         id: uuid::Uuid,
-        is_default: bool,
         name: String,
         protocol: String,
         realm_id: uuid::Uuid,
@@ -63,9 +63,9 @@ impl ClientScope {
         ClientScope {
             attributes: None,
             created_at,
+            default_scope_type,
             description: None,
             id,
-            is_default,
             name,
             protocol,
             protocol_mappers: None,
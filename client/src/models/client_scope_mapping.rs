@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -15,10 +15,8 @@ use serde::{Deserialize, Serialize};
 pub struct ClientScopeMapping {
     #[serde(rename = "client_id")]
     pub client_id: uuid::Uuid,
-    #[serde(rename = "is_default")]
-    pub is_default: bool,
-    #[serde(rename = "is_optional")]
-    pub is_optional: bool,
+    #[serde(rename = "default_scope_type")]
+    pub default_scope_type: models::ScopeType,
     #[serde(rename = "scope_id")]
     pub scope_id: uuid::Uuid,
 }
@@ -26,14 +24,12 @@ pub struct ClientScopeMapping {
 impl ClientScopeMapping {
     pub fn new(
         client_id: uuid::Uuid,
-        is_default: bool,
-        is_optional: bool,
+        default_scope_type: models::ScopeType,
         scope_id: uuid::Uuid,
     ) -> ClientScopeMapping {
         ClientScopeMapping {
             client_id,
-            is_default,
-            is_optional,
+            default_scope_type,
             scope_id,
         }
     }
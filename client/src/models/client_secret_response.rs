@@ -0,0 +1,31 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientSecretResponse {
+    #[serde(
+        rename = "client_secret",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_secret: Option<Option<String>>,
+}
+
+impl ClientSecretResponse {
+    pub fn new() -> ClientSecretResponse {
+        ClientSecretResponse {
+            client_secret: None,
+        }
+    }
+}
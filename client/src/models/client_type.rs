@@ -0,0 +1,35 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum ClientType {
+    #[serde(rename = "confidential")]
+    #[default]
+    Confidential,
+    #[serde(rename = "public")]
+    Public,
+    #[serde(rename = "system")]
+    System,
+}
+
+impl std::fmt::Display for ClientType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Confidential => write!(f, "confidential"),
+            Self::Public => write!(f, "public"),
+            Self::System => write!(f, "system"),
+        }
+    }
+}
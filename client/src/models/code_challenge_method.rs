@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum CodeChallengeMethod {
+    #[serde(rename = "S256")]
+    #[default]
+    S256,
+    #[serde(rename = "PLAIN")]
+    Plain,
+}
+
+impl std::fmt::Display for CodeChallengeMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::S256 => write!(f, "S256"),
+            Self::Plain => write!(f, "PLAIN"),
+        }
+    }
+}
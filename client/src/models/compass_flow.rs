@@ -0,0 +1,96 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompassFlow {
+    #[serde(
+        rename = "client_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_id: Option<Option<String>>,
+    #[serde(
+        rename = "completed_at",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub completed_at: Option<Option<String>>,
+    #[serde(
+        rename = "duration_ms",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub duration_ms: Option<Option<i64>>,
+    #[serde(rename = "grant_type")]
+    pub grant_type: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(
+        rename = "ip_address",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ip_address: Option<Option<String>>,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "started_at")]
+    pub started_at: String,
+    #[serde(rename = "status")]
+    pub status: models::FlowStatus,
+    #[serde(rename = "steps")]
+    pub steps: Vec<models::CompassFlowStep>,
+    #[serde(
+        rename = "user_agent",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub user_agent: Option<Option<String>>,
+    #[serde(
+        rename = "user_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub user_id: Option<Option<uuid::Uuid>>,
+}
+
+impl CompassFlow {
+    pub fn new(
+        grant_type: String,
+        id: uuid::Uuid,
+        realm_id: uuid::Uuid,
+        started_at: String,
+        status: models::FlowStatus,
+        steps: Vec<models::CompassFlowStep>,
+    ) -> CompassFlow {
+        CompassFlow {
+            client_id: None,
+            completed_at: None,
+            duration_ms: None,
+            grant_type,
+            id,
+            ip_address: None,
+            realm_id,
+            started_at,
+            status,
+            steps,
+            user_agent: None,
+            user_id: None,
+        }
+    }
+}
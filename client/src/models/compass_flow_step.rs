@@ -0,0 +1,68 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompassFlowStep {
+    #[serde(
+        rename = "duration_ms",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub duration_ms: Option<Option<i64>>,
+    #[serde(
+        rename = "error_code",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub error_code: Option<Option<String>>,
+    #[serde(
+        rename = "error_message",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub error_message: Option<Option<String>>,
+    #[serde(rename = "flow_id")]
+    pub flow_id: uuid::Uuid,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "started_at")]
+    pub started_at: String,
+    #[serde(rename = "status")]
+    pub status: models::StepStatus,
+    #[serde(rename = "step_name")]
+    pub step_name: models::FlowStepName,
+}
+
+impl CompassFlowStep {
+    pub fn new(
+        flow_id: uuid::Uuid,
+        id: uuid::Uuid,
+        started_at: String,
+        status: models::StepStatus,
+        step_name: models::FlowStepName,
+    ) -> CompassFlowStep {
+        CompassFlowStep {
+            duration_ms: None,
+            error_code: None,
+            error_message: None,
+            flow_id,
+            id,
+            started_at,
+            status,
+            step_name,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompletePasswordResetResponse {
+    #[serde(rename = "access_token")]
+    pub access_token: String,
+    #[serde(rename = "expires_in")]
+    pub expires_in: i32,
+    #[serde(
+        rename = "id_token",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id_token: Option<Option<String>>,
+    #[serde(rename = "refresh_expires_in")]
+    pub refresh_expires_in: i32,
+    #[serde(rename = "refresh_token")]
+    pub refresh_token: String,
+    #[serde(
+        rename = "session_state",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub session_state: Option<Option<String>>,
+    #[serde(rename = "token_type")]
+    pub token_type: String,
+    /// Present when the password reset happened inside an OAuth flow. The frontend should perform a full-page redirect to this URL to complete the original authorization flow and reach the client's `redirect_uri`.
+    #[serde(
+        rename = "login_url",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub login_url: Option<Option<String>>,
+}
+
+impl CompletePasswordResetResponse {
+    pub fn new(
+        access_token: String,
+        expires_in: i32,
+        refresh_expires_in: i32,
+        refresh_token: String,
+        token_type: String,
+    ) -> CompletePasswordResetResponse {
+        CompletePasswordResetResponse {
+            access_token,
+            expires_in,
+            id_token: None,
+            refresh_expires_in,
+            refresh_token,
+            session_state: None,
+            token_type,
+            login_url: None,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ConfigurationCheck : Outcome of one check run against an identity provider's configuration.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConfigurationCheck {
+    #[serde(rename = "message")]
+    pub message: String,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "passed")]
+    pub passed: bool,
+}
+
+impl ConfigurationCheck {
+    /// Outcome of one check run against an identity provider's configuration.
+    pub fn new(message: String, name: String, passed: bool) -> ConfigurationCheck {
+        ConfigurationCheck {
+            message,
+            name,
+            passed,
+        }
+    }
+}
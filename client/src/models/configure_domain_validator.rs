@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConfigureDomainValidator {
+    /// Alias of the realm identity provider the domain's users are sent to; omit to only verify the domain.
+    #[serde(
+        rename = "identity_provider_alias",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub identity_provider_alias: Option<Option<String>>,
+}
+
+impl ConfigureDomainValidator {
+    pub fn new() -> ConfigureDomainValidator {
+        ConfigureDomainValidator {
+            identity_provider_alias: None,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ConfirmBrokerLinkRequest : Proof that the existing account belongs to the user: its password or the emailed code
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConfirmBrokerLinkRequest {
+    #[serde(
+        rename = "code",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub code: Option<Option<String>>,
+    #[serde(
+        rename = "password",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub password: Option<Option<String>>,
+}
+
+impl ConfirmBrokerLinkRequest {
+    /// Proof that the existing account belongs to the user: its password or the emailed code
+    pub fn new() -> ConfirmBrokerLinkRequest {
+        ConfirmBrokerLinkRequest {
+            code: None,
+            password: None,
+        }
+    }
+}
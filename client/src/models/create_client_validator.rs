@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -15,8 +15,8 @@ use serde::{Deserialize, Serialize};
 pub struct CreateClientValidator {
     #[serde(rename = "client_id", skip_serializing_if = "Option::is_none")]
     pub client_id: Option<String>,
-    #[serde(rename = "client_type", skip_serializing_if = "Option::is_none")]
-    pub client_type: Option<String>,
+    #[serde(rename = "client_type")]
+    pub client_type: models::ClientType,
     #[serde(
         rename = "direct_access_grants_enabled",
         skip_serializing_if = "Option::is_none"
@@ -26,6 +26,11 @@ pub struct CreateClientValidator {
     pub enabled: Option<bool>,
     #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(
+        rename = "oauth_device_code_grant_enabled",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub oauth_device_code_grant_enabled: Option<bool>,
     #[serde(rename = "protocol", skip_serializing_if = "Option::is_none")]
     pub protocol: Option<String>,
     #[serde(rename = "public_client", skip_serializing_if = "Option::is_none")]
@@ -38,13 +43,14 @@ pub struct CreateClientValidator {
 }
 
 impl CreateClientValidator {
-    pub fn new() -> CreateClientValidator {
+    pub fn new(client_type: models::ClientType) -> CreateClientValidator {
         CreateClientValidator {
             client_id: None,
-            client_type: None,
+            client_type,
             direct_access_grants_enabled: None,
             enabled: None,
             name: None,
+            oauth_device_code_grant_enabled: None,
             protocol: None,
             public_client: None,
             service_account_enabled: None,
@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateEmailTemplateValidator {
+    #[serde(rename = "email_type")]
+    pub email_type: String,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "structure", deserialize_with = "Option::deserialize")]
+    pub structure: Option<serde_json::Value>,
+}
+
+impl CreateEmailTemplateValidator {
+    pub fn new(
+        email_type: String,
+        name: String,
+        structure: Option<serde_json::Value>,
+    ) -> CreateEmailTemplateValidator {
+        CreateEmailTemplateValidator {
+            email_type,
+            name,
+            structure,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateGroupValidator {
+    #[serde(
+        rename = "description",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Option<String>>,
+    #[serde(rename = "name")]
+    pub name: String,
+    /// Optional parent group; omit for a top-level group.
+    #[serde(
+        rename = "parent_group_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub parent_group_id: Option<Option<uuid::Uuid>>,
+}
+
+impl CreateGroupValidator {
+    pub fn new(name: String) -> CreateGroupValidator {
+        CreateGroupValidator {
+            description: None,
+            name,
+            parent_group_id: None,
+        }
+    }
+}
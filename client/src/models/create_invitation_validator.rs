@@ -0,0 +1,53 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateInvitationValidator {
+    #[serde(rename = "email")]
+    pub email: String,
+    /// Validity of the link, seven days when omitted.
+    #[serde(
+        rename = "expires_in_hours",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expires_in_hours: Option<Option<i64>>,
+    /// Group of the organization the member joins on acceptance.
+    #[serde(
+        rename = "group_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub group_id: Option<Option<uuid::Uuid>>,
+    /// Role granted to the member on acceptance.
+    #[serde(
+        rename = "role_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub role_id: Option<Option<uuid::Uuid>>,
+}
+
+impl CreateInvitationValidator {
+    pub fn new(email: String) -> CreateInvitationValidator {
+        CreateInvitationValidator {
+            email,
+            expires_in_hours: None,
+            group_id: None,
+            role_id: None,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateOrganizationValidator {
+    #[serde(rename = "alias")]
+    pub alias: String,
+    #[serde(
+        rename = "description",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Option<String>>,
+    #[serde(
+        rename = "domain",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub domain: Option<Option<String>>,
+    #[serde(rename = "enabled", skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(
+        rename = "redirect_url",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub redirect_url: Option<Option<String>>,
+}
+
+impl CreateOrganizationValidator {
+    pub fn new(alias: String, name: String) -> CreateOrganizationValidator {
+        CreateOrganizationValidator {
+            alias,
+            description: None,
+            domain: None,
+            enabled: None,
+            name,
+            redirect_url: None,
+        }
+    }
+}
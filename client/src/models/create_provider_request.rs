@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,10 +13,26 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreateProviderRequest {
+    /// Period of the changed-since sync (`modifyTimestamp` / `uSNChanged`).
+    #[serde(
+        rename = "changed_sync_interval_minutes",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub changed_sync_interval_minutes: Option<Option<i32>>,
     #[serde(rename = "config", deserialize_with = "Option::deserialize")]
     pub config: Option<serde_json::Value>,
     #[serde(rename = "enabled")]
     pub enabled: bool,
+    /// Five-field cron expression (UTC) for full syncs; takes precedence over the interval.
+    #[serde(
+        rename = "full_sync_cron",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub full_sync_cron: Option<Option<String>>,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "priority")]
@@ -47,8 +63,10 @@ impl CreateProviderRequest {
         sync_mode: String,
     ) -> CreateProviderRequest {
         CreateProviderRequest {
+            changed_sync_interval_minutes: None,
             config,
             enabled,
+            full_sync_cron: None,
             name,
             priority,
             provider_type,
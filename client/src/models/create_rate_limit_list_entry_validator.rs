@@ -0,0 +1,38 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateRateLimitListEntryValidator {
+    /// An IP address or a CIDR range, e.g. `203.0.113.0/24`.
+    #[serde(rename = "cidr")]
+    pub cidr: String,
+    #[serde(
+        rename = "description",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Option<String>>,
+    #[serde(rename = "kind")]
+    pub kind: models::RateLimitListKind,
+}
+
+impl CreateRateLimitListEntryValidator {
+    pub fn new(cidr: String, kind: models::RateLimitListKind) -> CreateRateLimitListEntryValidator {
+        CreateRateLimitListEntryValidator {
+            cidr,
+            description: None,
+            kind,
+        }
+    }
+}
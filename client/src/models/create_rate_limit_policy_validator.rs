@@ -0,0 +1,52 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateRateLimitPolicyValidator {
+    /// How long a key stays blocked once the limit is reached; 0 only waits for the refill.
+    #[serde(rename = "block_seconds", skip_serializing_if = "Option::is_none")]
+    pub block_seconds: Option<i32>,
+    #[serde(rename = "enabled", skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(rename = "endpoint")]
+    pub endpoint: models::RateLimitEndpoint,
+    #[serde(rename = "key_kind")]
+    pub key_kind: models::RateLimitKeyKind,
+    /// Requests allowed per window.
+    #[serde(rename = "limit")]
+    pub limit: i32,
+    /// Share the counter across replicas through the database.
+    #[serde(rename = "shared", skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+    #[serde(rename = "window_seconds")]
+    pub window_seconds: i32,
+}
+
+impl CreateRateLimitPolicyValidator {
+    pub fn new(
+        endpoint: models::RateLimitEndpoint,
+        key_kind: models::RateLimitKeyKind,
+        limit: i32,
+        window_seconds: i32,
+    ) -> CreateRateLimitPolicyValidator {
+        CreateRateLimitPolicyValidator {
+            block_seconds: None,
+            enabled: None,
+            endpoint,
+            key_kind,
+            limit,
+            shared: None,
+            window_seconds,
+        }
+    }
+}
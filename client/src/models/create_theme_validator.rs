@@ -0,0 +1,37 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateThemeValidator {
+    #[serde(rename = "config", skip_serializing_if = "Option::is_none")]
+    pub config: Option<Box<models::PortalThemeConfig>>,
+    #[serde(
+        rename = "layout_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub layout_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+impl CreateThemeValidator {
+    pub fn new(name: String) -> CreateThemeValidator {
+        CreateThemeValidator {
+            config: None,
+            layout_id: None,
+            name,
+        }
+    }
+}
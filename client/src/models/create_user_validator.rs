@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,8 +13,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreateUserValidator {
-    #[serde(rename = "email", skip_serializing_if = "Option::is_none")]
-    pub email: Option<String>,
+    #[serde(
+        rename = "email",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email: Option<Option<String>>,
     #[serde(
         rename = "email_verified",
         default,
@@ -22,10 +27,20 @@ pub struct CreateUserValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub email_verified: Option<Option<bool>>,
-    #[serde(rename = "firstname", skip_serializing_if = "Option::is_none")]
-    pub firstname: Option<String>,
-    #[serde(rename = "lastname", skip_serializing_if = "Option::is_none")]
-    pub lastname: Option<String>,
+    #[serde(
+        rename = "firstname",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub firstname: Option<Option<String>>,
+    #[serde(
+        rename = "lastname",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lastname: Option<Option<String>>,
     #[serde(rename = "username", skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
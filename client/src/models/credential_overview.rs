@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,6 +13,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CredentialOverview {
+    #[serde(
+        rename = "authenticator",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub authenticator: Option<Option<Box<models::AuthenticatorInfo>>>,
     #[serde(rename = "created_at")]
     pub created_at: String,
     #[serde(rename = "credential_data")]
@@ -44,6 +51,7 @@ impl CredentialOverview {
         user_id: uuid::Uuid,
     ) -> CredentialOverview {
         CredentialOverview {
+            authenticator: None,
             created_at,
             credential_data: Box::new(credential_data),
             credential_type,
@@ -0,0 +1,64 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DailyActivityStats {
+    #[serde(
+        rename = "avg_login_duration_ms",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub avg_login_duration_ms: Option<Option<f64>>,
+    #[serde(rename = "date")]
+    pub date: String,
+    #[serde(rename = "expired_logins")]
+    pub expired_logins: i64,
+    #[serde(rename = "login_failures")]
+    pub login_failures: i64,
+    #[serde(rename = "logins")]
+    pub logins: i64,
+    #[serde(rename = "pending_logins")]
+    pub pending_logins: i64,
+    #[serde(rename = "signups")]
+    pub signups: i64,
+    #[serde(rename = "total_flows")]
+    pub total_flows: i64,
+    #[serde(rename = "unique_login_users")]
+    pub unique_login_users: i64,
+}
+
+impl DailyActivityStats {
+    pub fn new(
+        date: String,
+        expired_logins: i64,
+        login_failures: i64,
+        logins: i64,
+        pending_logins: i64,
+        signups: i64,
+        total_flows: i64,
+        unique_login_users: i64,
+    ) -> DailyActivityStats {
+        DailyActivityStats {
+            avg_login_duration_ms: None,
+            date,
+            expired_logins,
+            login_failures,
+            logins,
+            pending_logins,
+            signups,
+            total_flows,
+            unique_login_users,
+        }
+    }
+}
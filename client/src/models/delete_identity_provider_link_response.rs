@@ -0,0 +1,24 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeleteIdentityProviderLinkResponse {
+    #[serde(rename = "count")]
+    pub count: i32,
+}
+
+impl DeleteIdentityProviderLinkResponse {
+    pub fn new(count: i32) -> DeleteIdentityProviderLinkResponse {
+        DeleteIdentityProviderLinkResponse { count }
+    }
+}
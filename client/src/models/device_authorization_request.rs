@@ -0,0 +1,51 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// DeviceAuthorizationRequest : Form body for the device authorization request (RFC 8628 §3.1).
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeviceAuthorizationRequest {
+    /// OAuth 2.0 client identifier. Optional in the body when the client authenticates with HTTP Basic (confidential clients).
+    #[serde(
+        rename = "client_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_id: Option<Option<String>>,
+    #[serde(
+        rename = "client_secret",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_secret: Option<Option<String>>,
+    /// Space-delimited list of requested scopes.
+    #[serde(
+        rename = "scope",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub scope: Option<Option<String>>,
+}
+
+impl DeviceAuthorizationRequest {
+    /// Form body for the device authorization request (RFC 8628 §3.1).
+    pub fn new() -> DeviceAuthorizationRequest {
+        DeviceAuthorizationRequest {
+            client_id: None,
+            client_secret: None,
+            scope: None,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeviceVerificationPreview {
+    #[serde(rename = "client_id")]
+    pub client_id: String,
+    #[serde(rename = "client_name")]
+    pub client_name: String,
+    #[serde(rename = "scopes")]
+    pub scopes: Vec<String>,
+}
+
+impl DeviceVerificationPreview {
+    pub fn new(
+        client_id: String,
+        client_name: String,
+        scopes: Vec<String>,
+    ) -> DeviceVerificationPreview {
+        DeviceVerificationPreview {
+            client_id,
+            client_name,
+            scopes,
+        }
+    }
+}
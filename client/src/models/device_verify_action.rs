@@ -0,0 +1,33 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Approve or deny choice submitted from the verification page.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum DeviceVerifyAction {
+    #[serde(rename = "approve")]
+    #[default]
+    Approve,
+    #[serde(rename = "deny")]
+    Deny,
+}
+
+impl std::fmt::Display for DeviceVerifyAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Approve => write!(f, "approve"),
+            Self::Deny => write!(f, "deny"),
+        }
+    }
+}
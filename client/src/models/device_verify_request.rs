@@ -0,0 +1,27 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeviceVerifyRequest {
+    #[serde(rename = "action")]
+    pub action: models::DeviceVerifyAction,
+    /// The end-user code shown on the device (e.g. `WDJB-MJHT`).
+    #[serde(rename = "user_code")]
+    pub user_code: String,
+}
+
+impl DeviceVerifyRequest {
+    pub fn new(action: models::DeviceVerifyAction, user_code: String) -> DeviceVerifyRequest {
+        DeviceVerifyRequest { action, user_code }
+    }
+}
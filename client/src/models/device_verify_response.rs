@@ -0,0 +1,25 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeviceVerifyResponse {
+    /// Resulting session status: `approved` or `denied`.
+    #[serde(rename = "status")]
+    pub status: String,
+}
+
+impl DeviceVerifyResponse {
+    pub fn new(status: String) -> DeviceVerifyResponse {
+        DeviceVerifyResponse { status }
+    }
+}
@@ -0,0 +1,27 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DiscoverIdentityProviderValidator {
+    /// The discovery document URL, or the issuer it is published under
+    #[serde(rename = "discovery_url", skip_serializing_if = "Option::is_none")]
+    pub discovery_url: Option<String>,
+}
+
+impl DiscoverIdentityProviderValidator {
+    pub fn new() -> DiscoverIdentityProviderValidator {
+        DiscoverIdentityProviderValidator {
+            discovery_url: None,
+        }
+    }
+}
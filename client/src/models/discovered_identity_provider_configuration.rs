@@ -0,0 +1,62 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// DiscoveredIdentityProviderConfiguration : Endpoints and capabilities read from a discovery document, before any provider exists.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DiscoveredIdentityProviderConfiguration {
+    #[serde(rename = "authorization_url")]
+    pub authorization_url: String,
+    #[serde(rename = "client_auth_methods_supported")]
+    pub client_auth_methods_supported: Vec<models::ClientAuthMethod>,
+    #[serde(rename = "discovery_url")]
+    pub discovery_url: String,
+    #[serde(rename = "issuer")]
+    pub issuer: String,
+    #[serde(rename = "jwks_url")]
+    pub jwks_url: String,
+    #[serde(rename = "scopes_supported")]
+    pub scopes_supported: Vec<String>,
+    #[serde(rename = "token_url")]
+    pub token_url: String,
+    #[serde(
+        rename = "userinfo_url",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub userinfo_url: Option<Option<String>>,
+}
+
+impl DiscoveredIdentityProviderConfiguration {
+    /// Endpoints and capabilities read from a discovery document, before any provider exists.
+    pub fn new(
+        authorization_url: String,
+        client_auth_methods_supported: Vec<models::ClientAuthMethod>,
+        discovery_url: String,
+        issuer: String,
+        jwks_url: String,
+        scopes_supported: Vec<String>,
+        token_url: String,
+    ) -> DiscoveredIdentityProviderConfiguration {
+        DiscoveredIdentityProviderConfiguration {
+            authorization_url,
+            client_auth_methods_supported,
+            discovery_url,
+            issuer,
+            jwks_url,
+            scopes_supported,
+            token_url,
+            userinfo_url: None,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// EffectiveRealmGroup : A group the user effectively belongs to, with its full path from the root.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EffectiveRealmGroup {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(
+        rename = "description",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Option<String>>,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(
+        rename = "parent_group_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub parent_group_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+    /// `true` for a direct membership, `false` when present only as an ancestor of one.
+    #[serde(rename = "direct")]
+    pub direct: bool,
+    /// Full path from the root, e.g. `/engineering/backend`.
+    #[serde(rename = "path")]
+    pub path: String,
+}
+
+impl EffectiveRealmGroup {
+    /// A group the user effectively belongs to, with its full path from the root.
+    pub fn new(
+        created_at: String,
+        id: uuid::Uuid,
+        name: String,
+        realm_id: uuid::Uuid,
+        updated_at: String,
+        direct: bool,
+        path: String,
+    ) -> EffectiveRealmGroup {
+        EffectiveRealmGroup {
+            created_at,
+            description: None,
+            id,
+            name,
+            parent_group_id: None,
+            realm_id,
+            updated_at,
+            direct,
+            path,
+        }
+    }
+}
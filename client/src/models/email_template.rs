@@ -0,0 +1,56 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EmailTemplate {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "email_type")]
+    pub email_type: models::EmailType,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "mjml")]
+    pub mjml: String,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "structure", deserialize_with = "Option::deserialize")]
+    pub structure: Option<serde_json::Value>,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl EmailTemplate {
+    pub fn new(
+        created_at: String,
+        email_type: models::EmailType,
+        id: uuid::Uuid,
+        mjml: String,
+        name: String,
+        realm_id: uuid::Uuid,
+        structure: Option<serde_json::Value>,
+        updated_at: String,
+    ) -> EmailTemplate {
+        EmailTemplate {
+            created_at,
+            email_type,
+            id,
+            mjml,
+            name,
+            realm_id,
+            structure,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// EvaluateClientScopesResult : Result of a client-scope evaluation: the effective scopes/mappers/roles plus the decoded (unsigned, non-persisted) token claims a real token would carry.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EvaluateClientScopesResult {
+    #[serde(rename = "access_token", deserialize_with = "Option::deserialize")]
+    pub access_token: Option<serde_json::Value>,
+    #[serde(rename = "effective_mappers")]
+    pub effective_mappers: Vec<models::EvaluatedMapper>,
+    #[serde(rename = "effective_roles")]
+    pub effective_roles: Box<models::EvaluatedRoles>,
+    #[serde(rename = "effective_scopes")]
+    pub effective_scopes: Vec<models::EvaluatedScope>,
+    #[serde(
+        rename = "id_token",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id_token: Option<Option<serde_json::Value>>,
+    #[serde(rename = "userinfo", deserialize_with = "Option::deserialize")]
+    pub userinfo: Option<serde_json::Value>,
+}
+
+impl EvaluateClientScopesResult {
+    /// Result of a client-scope evaluation: the effective scopes/mappers/roles plus the decoded (unsigned, non-persisted) token claims a real token would carry.
+    pub fn new(
+        access_token: Option<serde_json::Value>,
+        effective_mappers: Vec<models::EvaluatedMapper>,
+        effective_roles: models::EvaluatedRoles,
+        effective_scopes: Vec<models::EvaluatedScope>,
+        userinfo: Option<serde_json::Value>,
+    ) -> EvaluateClientScopesResult {
+        EvaluateClientScopesResult {
+            access_token,
+            effective_mappers,
+            effective_roles: Box::new(effective_roles),
+            effective_scopes,
+            id_token: None,
+            userinfo,
+        }
+    }
+}
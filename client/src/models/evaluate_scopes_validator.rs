@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EvaluateScopesValidator {
+    /// Requested scope string (space-separated). Default scopes always apply; optional scopes apply only when named here.
+    #[serde(
+        rename = "scope",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub scope: Option<Option<String>>,
+    /// User whose token would be previewed.
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+}
+
+impl EvaluateScopesValidator {
+    pub fn new(user_id: uuid::Uuid) -> EvaluateScopesValidator {
+        EvaluateScopesValidator {
+            scope: None,
+            user_id,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EvaluatedMapper {
+    #[serde(rename = "config", deserialize_with = "Option::deserialize")]
+    pub config: Option<serde_json::Value>,
+    #[serde(rename = "mapper_type")]
+    pub mapper_type: String,
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+impl EvaluatedMapper {
+    pub fn new(
+        config: Option<serde_json::Value>,
+        mapper_type: String,
+        name: String,
+    ) -> EvaluatedMapper {
+        EvaluatedMapper {
+            config,
+            mapper_type,
+            name,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EvaluatedRoles {
+    #[serde(rename = "client_roles")]
+    pub client_roles: std::collections::HashMap<String, Vec<String>>,
+    #[serde(rename = "realm_roles")]
+    pub realm_roles: Vec<String>,
+}
+
+impl EvaluatedRoles {
+    pub fn new(
+        client_roles: std::collections::HashMap<String, Vec<String>>,
+        realm_roles: Vec<String>,
+    ) -> EvaluatedRoles {
+        EvaluatedRoles {
+            client_roles,
+            realm_roles,
+        }
+    }
+}
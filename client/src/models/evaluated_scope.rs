@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EvaluatedScope {
+    #[serde(rename = "default_scope_type")]
+    pub default_scope_type: String,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "protocol")]
+    pub protocol: String,
+}
+
+impl EvaluatedScope {
+    pub fn new(default_scope_type: String, name: String, protocol: String) -> EvaluatedScope {
+        EvaluatedScope {
+            default_scope_type,
+            name,
+            protocol,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ExistingAccountHint : The existing account a login would be linked to, with its email partly hidden.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExistingAccountHint {
+    #[serde(
+        rename = "email",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email: Option<Option<String>>,
+    #[serde(rename = "username")]
+    pub username: String,
+}
+
+impl ExistingAccountHint {
+    /// The existing account a login would be linked to, with its email partly hidden.
+    pub fn new(username: String) -> ExistingAccountHint {
+        ExistingAccountHint {
+            email: None,
+            username,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FailureReason {
+    /// The recorded error code, or `unknown` when the step failed without one.
+    #[serde(rename = "error_code")]
+    pub error_code: String,
+    #[serde(rename = "occurrences")]
+    pub occurrences: i64,
+    #[serde(rename = "step_name")]
+    pub step_name: models::FlowStepName,
+}
+
+impl FailureReason {
+    pub fn new(
+        error_code: String,
+        occurrences: i64,
+        step_name: models::FlowStepName,
+    ) -> FailureReason {
+        FailureReason {
+            error_code,
+            occurrences,
+            step_name,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FlowAnalytics {
+    #[serde(rename = "failure_reasons")]
+    pub failure_reasons: Vec<models::FailureReason>,
+    #[serde(rename = "from")]
+    pub from: String,
+    #[serde(rename = "funnels")]
+    pub funnels: Vec<models::LoginFunnel>,
+    #[serde(rename = "hourly")]
+    pub hourly: Vec<models::HourlyFlowBucket>,
+    #[serde(rename = "step_latencies")]
+    pub step_latencies: Vec<models::StepLatency>,
+    #[serde(rename = "to")]
+    pub to: String,
+}
+
+impl FlowAnalytics {
+    pub fn new(
+        failure_reasons: Vec<models::FailureReason>,
+        from: String,
+        funnels: Vec<models::LoginFunnel>,
+        hourly: Vec<models::HourlyFlowBucket>,
+        step_latencies: Vec<models::StepLatency>,
+        to: String,
+    ) -> FlowAnalytics {
+        FlowAnalytics {
+            failure_reasons,
+            from,
+            funnels,
+            hourly,
+            step_latencies,
+            to,
+        }
+    }
+}
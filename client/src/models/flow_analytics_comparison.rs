@@ -0,0 +1,45 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FlowAnalyticsComparison {
+    #[serde(rename = "baseline")]
+    pub baseline: Box<models::FlowAnalytics>,
+    /// Change of the overall completion rate, `None` when either range has no flows.
+    #[serde(
+        rename = "completion_rate_delta",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub completion_rate_delta: Option<Option<f64>>,
+    #[serde(rename = "current")]
+    pub current: Box<models::FlowAnalytics>,
+    #[serde(rename = "step_latency_deltas")]
+    pub step_latency_deltas: Vec<models::StepLatencyDelta>,
+}
+
+impl FlowAnalyticsComparison {
+    pub fn new(
+        baseline: models::FlowAnalytics,
+        current: models::FlowAnalytics,
+        step_latency_deltas: Vec<models::StepLatencyDelta>,
+    ) -> FlowAnalyticsComparison {
+        FlowAnalyticsComparison {
+            baseline: Box::new(baseline),
+            completion_rate_delta: None,
+            current: Box::new(current),
+            step_latency_deltas,
+        }
+    }
+}
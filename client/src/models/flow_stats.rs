@@ -0,0 +1,48 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FlowStats {
+    #[serde(
+        rename = "avg_duration_ms",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub avg_duration_ms: Option<Option<f64>>,
+    #[serde(rename = "failure_count")]
+    pub failure_count: i64,
+    #[serde(rename = "pending_count")]
+    pub pending_count: i64,
+    #[serde(rename = "success_count")]
+    pub success_count: i64,
+    #[serde(rename = "total")]
+    pub total: i64,
+}
+
+impl FlowStats {
+    pub fn new(
+        failure_count: i64,
+        pending_count: i64,
+        success_count: i64,
+        total: i64,
+    ) -> FlowStats {
+        FlowStats {
+            avg_duration_ms: None,
+            failure_count,
+            pending_count,
+            success_count,
+            total,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum FlowStepName {
+    #[serde(rename = "authorize")]
+    #[default]
+    Authorize,
+    #[serde(rename = "credential_validation")]
+    CredentialValidation,
+    #[serde(rename = "mfa_challenge")]
+    MfaChallenge,
+    #[serde(rename = "token_exchange")]
+    TokenExchange,
+    #[serde(rename = "idp_redirect")]
+    IdpRedirect,
+    #[serde(rename = "idp_callback")]
+    IdpCallback,
+    #[serde(rename = "finalize")]
+    Finalize,
+}
+
+impl std::fmt::Display for FlowStepName {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Authorize => write!(f, "authorize"),
+            Self::CredentialValidation => write!(f, "credential_validation"),
+            Self::MfaChallenge => write!(f, "mfa_challenge"),
+            Self::TokenExchange => write!(f, "token_exchange"),
+            Self::IdpRedirect => write!(f, "idp_redirect"),
+            Self::IdpCallback => write!(f, "idp_callback"),
+            Self::Finalize => write!(f, "finalize"),
+        }
+    }
+}
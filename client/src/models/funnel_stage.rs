@@ -0,0 +1,54 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FunnelStage {
+    /// Share of the started flows that reached this stage.
+    #[serde(rename = "conversion_rate")]
+    pub conversion_rate: f64,
+    /// Share of the flows that reached the previous stage and never reached this one.
+    #[serde(rename = "drop_off_rate")]
+    pub drop_off_rate: f64,
+    #[serde(rename = "failed")]
+    pub failed: i64,
+    #[serde(rename = "reached")]
+    pub reached: i64,
+    #[serde(rename = "skipped")]
+    pub skipped: i64,
+    #[serde(rename = "step_name")]
+    pub step_name: models::FlowStepName,
+    #[serde(rename = "succeeded")]
+    pub succeeded: i64,
+}
+
+impl FunnelStage {
+    pub fn new(
+        conversion_rate: f64,
+        drop_off_rate: f64,
+        failed: i64,
+        reached: i64,
+        skipped: i64,
+        step_name: models::FlowStepName,
+        succeeded: i64,
+    ) -> FunnelStage {
+        FunnelStage {
+            conversion_rate,
+            drop_off_rate,
+            failed,
+            reached,
+            skipped,
+            step_name,
+            succeeded,
+        }
+    }
+}
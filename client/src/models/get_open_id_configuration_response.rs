@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -19,16 +19,26 @@ pub struct GetOpenIdConfigurationResponse {
     pub end_session_endpoint: String,
     #[serde(rename = "grant_types_supported")]
     pub grant_types_supported: Vec<String>,
+    #[serde(rename = "id_token_signing_alg_values_supported")]
+    pub id_token_signing_alg_values_supported: Vec<String>,
     #[serde(rename = "introspection_endpoint")]
     pub introspection_endpoint: String,
     #[serde(rename = "issuer")]
     pub issuer: String,
     #[serde(rename = "jwks_uri")]
     pub jwks_uri: String,
+    #[serde(rename = "response_types_supported")]
+    pub response_types_supported: Vec<String>,
     #[serde(rename = "revocation_endpoint")]
     pub revocation_endpoint: String,
+    #[serde(rename = "subject_types_supported")]
+    pub subject_types_supported: Vec<String>,
     #[serde(rename = "token_endpoint")]
     pub token_endpoint: String,
+    #[serde(rename = "token_endpoint_auth_methods_supported")]
+    pub token_endpoint_auth_methods_supported: Vec<String>,
+    #[serde(rename = "ui_locales_supported")]
+    pub ui_locales_supported: Vec<String>,
     #[serde(rename = "userinfo_endpoint")]
     pub userinfo_endpoint: String,
 }
@@ -38,22 +48,32 @@ impl GetOpenIdConfigurationResponse {
         authorization_endpoint: String,
         end_session_endpoint: String,
         grant_types_supported: Vec<String>,
+        id_token_signing_alg_values_supported: Vec<String>,
         introspection_endpoint: String,
         issuer: String,
         jwks_uri: String,
+        response_types_supported: Vec<String>,
         revocation_endpoint: String,
+        subject_types_supported: Vec<String>,
         token_endpoint: String,
+        token_endpoint_auth_methods_supported: Vec<String>,
+        ui_locales_supported: Vec<String>,
         userinfo_endpoint: String,
     ) -> GetOpenIdConfigurationResponse {
         GetOpenIdConfigurationResponse {
             authorization_endpoint,
             end_session_endpoint,
             grant_types_supported,
+            id_token_signing_alg_values_supported,
             introspection_endpoint,
             issuer,
             jwks_uri,
+            response_types_supported,
             revocation_endpoint,
+            subject_types_supported,
             token_endpoint,
+            token_endpoint_auth_methods_supported,
+            ui_locales_supported,
             userinfo_endpoint,
         }
     }
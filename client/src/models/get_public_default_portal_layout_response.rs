@@ -0,0 +1,29 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetPublicDefaultPortalLayoutResponse {
+    #[serde(
+        rename = "data",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub data: Option<Option<Box<models::PortalLayout>>>,
+}
+
+impl GetPublicDefaultPortalLayoutResponse {
+    pub fn new() -> GetPublicDefaultPortalLayoutResponse {
+        GetPublicDefaultPortalLayoutResponse { data: None }
+    }
+}
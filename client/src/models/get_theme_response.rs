@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GetThemeResponse {
+    #[serde(rename = "data")]
+    pub data: Box<models::PortalThemeConfig>,
+}
+
+impl GetThemeResponse {
+    pub fn new(data: models::PortalThemeConfig) -> GetThemeResponse {
+        GetThemeResponse {
+            data: Box::new(data),
+        }
+    }
+}
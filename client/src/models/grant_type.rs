@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -11,7 +11,6 @@
 use crate::models;
 use serde::{Deserialize, Serialize};
 
-///
 #[derive(
     Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
 )]
@@ -25,6 +24,10 @@ pub enum GrantType {
     ClientCredentials,
     #[serde(rename = "refresh_token")]
     RefreshToken,
+    #[serde(rename = "urn:ietf:params:oauth:grant-type:device_code")]
+    UrnIetfParamsOauthGrantTypeDeviceCode,
+    #[serde(rename = "urn:ietf:params:oauth:grant-type:token-exchange")]
+    UrnIetfParamsOauthGrantTypeTokenExchange,
 }
 
 impl std::fmt::Display for GrantType {
@@ -34,6 +37,12 @@ impl std::fmt::Display for GrantType {
             Self::Password => write!(f, "password"),
             Self::ClientCredentials => write!(f, "client_credentials"),
             Self::RefreshToken => write!(f, "refresh_token"),
+            Self::UrnIetfParamsOauthGrantTypeDeviceCode => {
+                write!(f, "urn:ietf:params:oauth:grant-type:device_code")
+            }
+            Self::UrnIetfParamsOauthGrantTypeTokenExchange => {
+                write!(f, "urn:ietf:params:oauth:grant-type:token-exchange")
+            }
         }
     }
 }
@@ -0,0 +1,62 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Group : A group within an organization. Groups form a tree via `parent_group_id` (`None` = top-level group). Membership is recursive: a member of a group is effectively a member of all its ancestors.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Group {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(
+        rename = "description",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Option<String>>,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "organization_id")]
+    pub organization_id: uuid::Uuid,
+    #[serde(
+        rename = "parent_group_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub parent_group_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl Group {
+    /// A group within an organization. Groups form a tree via `parent_group_id` (`None` = top-level group). Membership is recursive: a member of a group is effectively a member of all its ancestors.
+    pub fn new(
+        created_at: String,
+        id: uuid::Uuid,
+        name: String,
+        organization_id: uuid::Uuid,
+        updated_at: String,
+    ) -> Group {
+        Group {
+            created_at,
+            description: None,
+            id,
+            name,
+            organization_id,
+            parent_group_id: None,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// GroupMember : A user's membership in a group.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GroupMember {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "group_id")]
+    pub group_id: uuid::Uuid,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+}
+
+impl GroupMember {
+    /// A user's membership in a group.
+    pub fn new(
+        created_at: String,
+        group_id: uuid::Uuid,
+        id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    ) -> GroupMember {
+        GroupMember {
+            created_at,
+            group_id,
+            id,
+            user_id,
+        }
+    }
+}
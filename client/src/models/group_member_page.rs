@@ -0,0 +1,42 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// GroupMemberPage : A page of group members plus the total count (for server-side pagination).
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GroupMemberPage {
+    #[serde(rename = "data")]
+    pub data: Vec<models::GroupMemberDetail>,
+    #[serde(rename = "limit")]
+    pub limit: i32,
+    #[serde(rename = "offset")]
+    pub offset: i32,
+    #[serde(rename = "total")]
+    pub total: i64,
+}
+
+impl GroupMemberPage {
+    /// A page of group members plus the total count (for server-side pagination).
+    pub fn new(
+        data: Vec<models::GroupMemberDetail>,
+        limit: i32,
+        offset: i32,
+        total: i64,
+    ) -> GroupMemberPage {
+        GroupMemberPage {
+            data,
+            limit,
+            offset,
+            total,
+        }
+    }
+}
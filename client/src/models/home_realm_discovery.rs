@@ -0,0 +1,31 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// HomeRealmDiscovery : Where the login page sends a user whose email domain belongs to an organization.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HomeRealmDiscovery {
+    #[serde(rename = "identity_provider_alias")]
+    pub identity_provider_alias: String,
+    #[serde(rename = "organization_alias")]
+    pub organization_alias: String,
+}
+
+impl HomeRealmDiscovery {
+    /// Where the login page sends a user whose email domain belongs to an organization.
+    pub fn new(identity_provider_alias: String, organization_alias: String) -> HomeRealmDiscovery {
+        HomeRealmDiscovery {
+            identity_provider_alias,
+            organization_alias,
+        }
+    }
+}
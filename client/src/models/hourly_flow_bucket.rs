@@ -0,0 +1,59 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HourlyFlowBucket {
+    #[serde(rename = "failed")]
+    pub failed: i64,
+    #[serde(rename = "hour")]
+    pub hour: String,
+    #[serde(
+        rename = "p50_duration_ms",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub p50_duration_ms: Option<Option<f64>>,
+    #[serde(
+        rename = "p90_duration_ms",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub p90_duration_ms: Option<Option<f64>>,
+    #[serde(
+        rename = "p99_duration_ms",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub p99_duration_ms: Option<Option<f64>>,
+    #[serde(rename = "succeeded")]
+    pub succeeded: i64,
+    #[serde(rename = "total_flows")]
+    pub total_flows: i64,
+}
+
+impl HourlyFlowBucket {
+    pub fn new(failed: i64, hour: String, succeeded: i64, total_flows: i64) -> HourlyFlowBucket {
+        HourlyFlowBucket {
+            failed,
+            hour,
+            p50_duration_ms: None,
+            p90_duration_ms: None,
+            p99_duration_ms: None,
+            succeeded,
+            total_flows,
+        }
+    }
+}
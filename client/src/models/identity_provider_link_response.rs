@@ -0,0 +1,48 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProviderLinkResponse {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "identity_provider_alias")]
+    pub identity_provider_alias: String,
+    #[serde(rename = "identity_provider_id")]
+    pub identity_provider_id: uuid::Uuid,
+    #[serde(rename = "identity_provider_user_id")]
+    pub identity_provider_user_id: String,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl IdentityProviderLinkResponse {
+    pub fn new(
+        created_at: String,
+        id: uuid::Uuid,
+        identity_provider_alias: String,
+        identity_provider_id: uuid::Uuid,
+        identity_provider_user_id: String,
+        updated_at: String,
+    ) -> IdentityProviderLinkResponse {
+        IdentityProviderLinkResponse {
+            created_at,
+            id,
+            identity_provider_alias,
+            identity_provider_id,
+            identity_provider_user_id,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// IdentityProviderMapper : A mapper attached to an identity provider.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProviderMapper {
+    #[serde(rename = "config")]
+    pub config: Box<models::IdentityProviderMapperConfig>,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "identity_provider_id")]
+    pub identity_provider_id: uuid::Uuid,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "sync_mode")]
+    pub sync_mode: models::MapperSyncMode,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl IdentityProviderMapper {
+    /// A mapper attached to an identity provider.
+    pub fn new(
+        config: models::IdentityProviderMapperConfig,
+        created_at: String,
+        id: uuid::Uuid,
+        identity_provider_id: uuid::Uuid,
+        name: String,
+        realm_id: uuid::Uuid,
+        sync_mode: models::MapperSyncMode,
+        updated_at: String,
+    ) -> IdentityProviderMapper {
+        IdentityProviderMapper {
+            config: Box::new(config),
+            created_at,
+            id,
+            identity_provider_id,
+            name,
+            realm_id,
+            sync_mode,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// IdentityProviderMapperConfig : What a mapper does with the claims of the upstream identity provider.  Claims are addressed by name; nested claims use a dotted path (`realm_access.roles`), with `\\.` for a dot inside a name. A claim whose full name contains dots is matched as is first.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IdentityProviderMapperConfig {
+    /// Copy a claim into `firstname`, `lastname`, `email` or a user attribute.
+    IdentityProviderMapperConfigOneOf(Box<models::IdentityProviderMapperConfigOneOf>),
+    /// Grant a realm or client role while `claim` holds `claim_value`.
+    IdentityProviderMapperConfigOneOf1(Box<models::IdentityProviderMapperConfigOneOf1>),
+    /// Make the user a member of an organization, and of one of its groups when `group_id` is set, while `claim` holds `claim_value`.
+    IdentityProviderMapperConfigOneOf2(Box<models::IdentityProviderMapperConfigOneOf2>),
+    /// Grant a role to every user of the provider.
+    IdentityProviderMapperConfigOneOf3(Box<models::IdentityProviderMapperConfigOneOf3>),
+    /// Username of the accounts the provider creates, e.g. `${ALIAS}.${CLAIM.preferred_username | lowercase}`. Supported transforms are `lowercase`, `uppercase` and `localpart`.
+    IdentityProviderMapperConfigOneOf4(Box<models::IdentityProviderMapperConfigOneOf4>),
+}
+
+impl Default for IdentityProviderMapperConfig {
+    fn default() -> Self {
+        Self::IdentityProviderMapperConfigOneOf(Default::default())
+    }
+}
@@ -0,0 +1,46 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// IdentityProviderMapperConfigOneOf : Copy a claim into `firstname`, `lastname`, `email` or a user attribute.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProviderMapperConfigOneOf {
+    #[serde(rename = "attribute")]
+    pub attribute: String,
+    #[serde(rename = "claim")]
+    pub claim: String,
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl IdentityProviderMapperConfigOneOf {
+    /// Copy a claim into `firstname`, `lastname`, `email` or a user attribute.
+    pub fn new(
+        attribute: String,
+        claim: String,
+        r#type: Type,
+    ) -> IdentityProviderMapperConfigOneOf {
+        IdentityProviderMapperConfigOneOf {
+            attribute,
+            claim,
+            r#type,
+        }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "claim_to_attribute")]
+    #[default]
+    ClaimToAttribute,
+}
@@ -0,0 +1,50 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// IdentityProviderMapperConfigOneOf1 : Grant a realm or client role while `claim` holds `claim_value`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProviderMapperConfigOneOf1 {
+    #[serde(rename = "claim")]
+    pub claim: String,
+    #[serde(rename = "claim_value")]
+    pub claim_value: String,
+    #[serde(rename = "role_id")]
+    pub role_id: uuid::Uuid,
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl IdentityProviderMapperConfigOneOf1 {
+    /// Grant a realm or client role while `claim` holds `claim_value`.
+    pub fn new(
+        claim: String,
+        claim_value: String,
+        role_id: uuid::Uuid,
+        r#type: Type,
+    ) -> IdentityProviderMapperConfigOneOf1 {
+        IdentityProviderMapperConfigOneOf1 {
+            claim,
+            claim_value,
+            role_id,
+            r#type,
+        }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "claim_to_role")]
+    #[default]
+    ClaimToRole,
+}
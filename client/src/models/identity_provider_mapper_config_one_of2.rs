@@ -0,0 +1,58 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// IdentityProviderMapperConfigOneOf2 : Make the user a member of an organization, and of one of its groups when `group_id` is set, while `claim` holds `claim_value`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProviderMapperConfigOneOf2 {
+    #[serde(rename = "claim")]
+    pub claim: String,
+    #[serde(rename = "claim_value")]
+    pub claim_value: String,
+    #[serde(
+        rename = "group_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub group_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "organization_id")]
+    pub organization_id: uuid::Uuid,
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl IdentityProviderMapperConfigOneOf2 {
+    /// Make the user a member of an organization, and of one of its groups when `group_id` is set, while `claim` holds `claim_value`.
+    pub fn new(
+        claim: String,
+        claim_value: String,
+        organization_id: uuid::Uuid,
+        r#type: Type,
+    ) -> IdentityProviderMapperConfigOneOf2 {
+        IdentityProviderMapperConfigOneOf2 {
+            claim,
+            claim_value,
+            group_id: None,
+            organization_id,
+            r#type,
+        }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "claim_to_organization")]
+    #[default]
+    ClaimToOrganization,
+}
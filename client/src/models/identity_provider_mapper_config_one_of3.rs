@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// IdentityProviderMapperConfigOneOf3 : Grant a role to every user of the provider.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProviderMapperConfigOneOf3 {
+    #[serde(rename = "role_id")]
+    pub role_id: uuid::Uuid,
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl IdentityProviderMapperConfigOneOf3 {
+    /// Grant a role to every user of the provider.
+    pub fn new(role_id: uuid::Uuid, r#type: Type) -> IdentityProviderMapperConfigOneOf3 {
+        IdentityProviderMapperConfigOneOf3 { role_id, r#type }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "hardcoded_role")]
+    #[default]
+    HardcodedRole,
+}
@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// IdentityProviderMapperConfigOneOf4 : Username of the accounts the provider creates, e.g. `${ALIAS}.${CLAIM.preferred_username | lowercase}`. Supported transforms are `lowercase`, `uppercase` and `localpart`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProviderMapperConfigOneOf4 {
+    #[serde(rename = "template")]
+    pub template: String,
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl IdentityProviderMapperConfigOneOf4 {
+    /// Username of the accounts the provider creates, e.g. `${ALIAS}.${CLAIM.preferred_username | lowercase}`. Supported transforms are `lowercase`, `uppercase` and `localpart`.
+    pub fn new(template: String, r#type: Type) -> IdentityProviderMapperConfigOneOf4 {
+        IdentityProviderMapperConfigOneOf4 { template, r#type }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "username_template")]
+    #[default]
+    UsernameTemplate,
+}
@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProviderMapperResponse {
+    #[serde(rename = "data")]
+    pub data: Box<models::IdentityProviderMapper>,
+}
+
+impl IdentityProviderMapperResponse {
+    pub fn new(data: models::IdentityProviderMapper) -> IdentityProviderMapperResponse {
+        IdentityProviderMapperResponse {
+            data: Box::new(data),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProviderMapperValidator {
+    #[serde(rename = "config")]
+    pub config: Box<models::IdentityProviderMapperConfig>,
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "sync_mode", skip_serializing_if = "Option::is_none")]
+    pub sync_mode: Option<models::MapperSyncMode>,
+}
+
+impl IdentityProviderMapperValidator {
+    pub fn new(config: models::IdentityProviderMapperConfig) -> IdentityProviderMapperValidator {
+        IdentityProviderMapperValidator {
+            config: Box::new(config),
+            name: None,
+            sync_mode: None,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProviderValidationReport {
+    #[serde(rename = "checks")]
+    pub checks: Vec<models::ConfigurationCheck>,
+    #[serde(rename = "valid")]
+    pub valid: bool,
+}
+
+impl IdentityProviderValidationReport {
+    pub fn new(
+        checks: Vec<models::ConfigurationCheck>,
+        valid: bool,
+    ) -> IdentityProviderValidationReport {
+        IdentityProviderValidationReport { checks, valid }
+    }
+}
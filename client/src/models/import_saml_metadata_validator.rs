@@ -0,0 +1,25 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImportSamlMetadataValidator {
+    /// `EntityDescriptor` (or an `EntitiesDescriptor` aggregate) describing the client's entity ID.
+    #[serde(rename = "metadata", skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+}
+
+impl ImportSamlMetadataValidator {
+    pub fn new() -> ImportSamlMetadataValidator {
+        ImportSamlMetadataValidator { metadata: None }
+    }
+}
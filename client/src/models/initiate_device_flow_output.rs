@@ -0,0 +1,52 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// InitiateDeviceFlowOutput : Output of the device authorization endpoint (RFC 8628 §3.2).
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InitiateDeviceFlowOutput {
+    #[serde(rename = "device_code")]
+    pub device_code: String,
+    /// Session lifetime, in seconds.
+    #[serde(rename = "expires_in")]
+    pub expires_in: i64,
+    /// Minimum polling interval, in seconds.
+    #[serde(rename = "interval")]
+    pub interval: i64,
+    #[serde(rename = "user_code")]
+    pub user_code: String,
+    #[serde(rename = "verification_uri")]
+    pub verification_uri: String,
+    #[serde(rename = "verification_uri_complete")]
+    pub verification_uri_complete: String,
+}
+
+impl InitiateDeviceFlowOutput {
+    /// Output of the device authorization endpoint (RFC 8628 §3.2).
+    pub fn new(
+        device_code: String,
+        expires_in: i64,
+        interval: i64,
+        user_code: String,
+        verification_uri: String,
+        verification_uri_complete: String,
+    ) -> InitiateDeviceFlowOutput {
+        InitiateDeviceFlowOutput {
+            device_code,
+            expires_in,
+            interval,
+            user_code,
+            verification_uri,
+            verification_uri_complete,
+        }
+    }
+}
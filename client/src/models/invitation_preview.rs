@@ -0,0 +1,47 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// InvitationPreview : What the invitee sees before accepting.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InvitationPreview {
+    /// Whether accepting links an existing account instead of creating one.
+    #[serde(rename = "account_exists")]
+    pub account_exists: bool,
+    #[serde(rename = "email")]
+    pub email: String,
+    #[serde(rename = "expires_at")]
+    pub expires_at: String,
+    #[serde(rename = "organization_alias")]
+    pub organization_alias: String,
+    #[serde(rename = "organization_name")]
+    pub organization_name: String,
+}
+
+impl InvitationPreview {
+    /// What the invitee sees before accepting.
+    pub fn new(
+        account_exists: bool,
+        email: String,
+        expires_at: String,
+        organization_alias: String,
+        organization_name: String,
+    ) -> InvitationPreview {
+        InvitationPreview {
+            account_exists,
+            email,
+            expires_at,
+            organization_alias,
+            organization_name,
+        }
+    }
+}
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -25,10 +25,6 @@ pub struct JwkKey {
     pub n: String,
     #[serde(rename = "use")]
     pub r#use: String,
-    #[serde(rename = "x5c")]
-    pub x5c: Vec<String>,
-    #[serde(rename = "x5t")]
-    pub x5t: String,
 }
 
 impl JwkKey {
@@ -39,8 +35,6 @@ impl JwkKey {
         kty: String,
         n: String,
         r#use: String,
-        x5c: Vec<String>,
-        x5t: String,
     ) -> JwkKey {
         JwkKey {
             alg,
@@ -49,8 +43,6 @@ impl JwkKey {
             kty,
             n,
             r#use,
-            x5c,
-            x5t,
         }
     }
 }
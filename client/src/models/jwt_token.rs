@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -24,8 +24,17 @@ pub struct JwtToken {
         skip_serializing_if = "Option::is_none"
     )]
     pub id_token: Option<Option<String>>,
+    #[serde(rename = "refresh_expires_in")]
+    pub refresh_expires_in: i32,
     #[serde(rename = "refresh_token")]
     pub refresh_token: String,
+    #[serde(
+        rename = "session_state",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub session_state: Option<Option<String>>,
     #[serde(rename = "token_type")]
     pub token_type: String,
 }
@@ -34,6 +43,7 @@ impl JwtToken {
     pub fn new(
         access_token: String,
         expires_in: i32,
+        refresh_expires_in: i32,
         refresh_token: String,
         token_type: String,
     ) -> JwtToken {
@@ -41,7 +51,9 @@ impl JwtToken {
             access_token,
             expires_in,
             id_token: None,
+            refresh_expires_in,
             refresh_token,
+            session_state: None,
             token_type,
         }
     }
@@ -0,0 +1,33 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// How the owner of an existing account confirms it may be linked.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum LinkConfirmationMethod {
+    #[serde(rename = "password")]
+    #[default]
+    Password,
+    #[serde(rename = "email_code")]
+    EmailCode,
+}
+
+impl std::fmt::Display for LinkConfirmationMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Password => write!(f, "password"),
+            Self::EmailCode => write!(f, "email_code"),
+        }
+    }
+}
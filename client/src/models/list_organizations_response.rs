@@ -0,0 +1,24 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListOrganizationsResponse {
+    #[serde(rename = "data")]
+    pub data: Vec<models::Organization>,
+}
+
+impl ListOrganizationsResponse {
+    pub fn new(data: Vec<models::Organization>) -> ListOrganizationsResponse {
+        ListOrganizationsResponse { data }
+    }
+}
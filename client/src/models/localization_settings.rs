@@ -0,0 +1,47 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// LocalizationSettings : The languages a realm serves its login portal and emails in.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LocalizationSettings {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    /// Used when nothing about the visitor or the user matches a supported locale.
+    #[serde(rename = "default_locale")]
+    pub default_locale: String,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "supported_locales")]
+    pub supported_locales: Vec<String>,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl LocalizationSettings {
+    /// The languages a realm serves its login portal and emails in.
+    pub fn new(
+        created_at: String,
+        default_locale: String,
+        realm_id: uuid::Uuid,
+        supported_locales: Vec<String>,
+        updated_at: String,
+    ) -> LocalizationSettings {
+        LocalizationSettings {
+            created_at,
+            default_locale,
+            realm_id,
+            supported_locales,
+            updated_at,
+        }
+    }
+}
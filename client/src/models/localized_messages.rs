@@ -0,0 +1,31 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// LocalizedMessages : The texts of one locale, with the built-in and default-locale texts filled in.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LocalizedMessages {
+    #[serde(rename = "locale")]
+    pub locale: String,
+    #[serde(rename = "messages")]
+    pub messages: std::collections::HashMap<String, String>,
+}
+
+impl LocalizedMessages {
+    /// The texts of one locale, with the built-in and default-locale texts filled in.
+    pub fn new(
+        locale: String,
+        messages: std::collections::HashMap<String, String>,
+    ) -> LocalizedMessages {
+        LocalizedMessages { locale, messages }
+    }
+}
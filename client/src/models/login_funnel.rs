@@ -0,0 +1,52 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LoginFunnel {
+    #[serde(
+        rename = "client_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_id: Option<Option<String>>,
+    #[serde(rename = "completion_rate")]
+    pub completion_rate: f64,
+    #[serde(rename = "grant_type")]
+    pub grant_type: String,
+    #[serde(rename = "stages")]
+    pub stages: Vec<models::FunnelStage>,
+    #[serde(rename = "started")]
+    pub started: i64,
+    #[serde(rename = "succeeded")]
+    pub succeeded: i64,
+}
+
+impl LoginFunnel {
+    pub fn new(
+        completion_rate: f64,
+        grant_type: String,
+        stages: Vec<models::FunnelStage>,
+        started: i64,
+        succeeded: i64,
+    ) -> LoginFunnel {
+        LoginFunnel {
+            client_id: None,
+            completion_rate,
+            grant_type,
+            stages,
+            started,
+            succeeded,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// LoginRecord : One past login of a user.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LoginRecord {
+    #[serde(
+        rename = "country",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub country: Option<Option<String>>,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(
+        rename = "device_fingerprint",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub device_fingerprint: Option<Option<String>>,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(
+        rename = "ip_address",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ip_address: Option<Option<String>>,
+    #[serde(
+        rename = "latitude",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub latitude: Option<Option<f64>>,
+    #[serde(
+        rename = "longitude",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub longitude: Option<Option<f64>>,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "risk_score")]
+    pub risk_score: i32,
+    #[serde(rename = "signals")]
+    pub signals: Vec<models::RiskSignal>,
+    #[serde(
+        rename = "user_agent",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub user_agent: Option<Option<String>>,
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+}
+
+impl LoginRecord {
+    /// One past login of a user.
+    pub fn new(
+        created_at: String,
+        id: uuid::Uuid,
+        realm_id: uuid::Uuid,
+        risk_score: i32,
+        signals: Vec<models::RiskSignal>,
+        user_id: uuid::Uuid,
+    ) -> LoginRecord {
+        LoginRecord {
+            country: None,
+            created_at,
+            device_fingerprint: None,
+            id,
+            ip_address: None,
+            latitude: None,
+            longitude: None,
+            realm_id,
+            risk_score,
+            signals,
+            user_agent: None,
+            user_id,
+        }
+    }
+}
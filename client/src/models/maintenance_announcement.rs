@@ -0,0 +1,51 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// MaintenanceAnnouncement : What the login portal shows about a pending window.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MaintenanceAnnouncement {
+    /// The OAuth `client_id` under maintenance, `None` when the whole realm is.
+    #[serde(
+        rename = "client_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_id: Option<Option<String>>,
+    #[serde(rename = "ends_at")]
+    pub ends_at: String,
+    #[serde(rename = "in_progress")]
+    pub in_progress: bool,
+    #[serde(rename = "reason")]
+    pub reason: String,
+    #[serde(rename = "starts_at")]
+    pub starts_at: String,
+}
+
+impl MaintenanceAnnouncement {
+    /// What the login portal shows about a pending window.
+    pub fn new(
+        ends_at: String,
+        in_progress: bool,
+        reason: String,
+        starts_at: String,
+    ) -> MaintenanceAnnouncement {
+        MaintenanceAnnouncement {
+            client_id: None,
+            ends_at,
+            in_progress,
+            reason,
+            starts_at,
+        }
+    }
+}
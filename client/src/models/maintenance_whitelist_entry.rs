@@ -0,0 +1,52 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MaintenanceWhitelistEntry {
+    #[serde(rename = "client_id")]
+    pub client_id: uuid::Uuid,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(
+        rename = "role_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub role_id: Option<Option<uuid::Uuid>>,
+    #[serde(
+        rename = "user_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub user_id: Option<Option<uuid::Uuid>>,
+}
+
+impl MaintenanceWhitelistEntry {
+    pub fn new(
+        client_id: uuid::Uuid,
+        created_at: String,
+        id: uuid::Uuid,
+    ) -> MaintenanceWhitelistEntry {
+        MaintenanceWhitelistEntry {
+            client_id,
+            created_at,
+            id,
+            role_id: None,
+            user_id: None,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// MaintenanceWindow : A planned maintenance of one client, or of every client of the realm. The scheduler switches client maintenance on at `starts_at` and off at `ends_at`; from `announce_at` the window is listed on the public announcements route.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MaintenanceWindow {
+    /// The clients this window put under maintenance when it started. Only those are released at the end, so a client already under manual maintenance stays so.
+    #[serde(rename = "affected_client_ids")]
+    pub affected_client_ids: Vec<uuid::Uuid>,
+    #[serde(rename = "announce_at")]
+    pub announce_at: String,
+    /// `None` for a realm-wide window.
+    #[serde(
+        rename = "client_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "created_by")]
+    pub created_by: uuid::Uuid,
+    #[serde(rename = "ends_at")]
+    pub ends_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "reason")]
+    pub reason: String,
+    #[serde(rename = "session_strategy")]
+    pub session_strategy: models::MaintenanceSessionStrategy,
+    #[serde(rename = "starts_at")]
+    pub starts_at: String,
+    #[serde(rename = "status")]
+    pub status: models::MaintenanceWindowStatus,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl MaintenanceWindow {
+    /// A planned maintenance of one client, or of every client of the realm. The scheduler switches client maintenance on at `starts_at` and off at `ends_at`; from `announce_at` the window is listed on the public announcements route.
+    pub fn new(
+        affected_client_ids: Vec<uuid::Uuid>,
+        announce_at: String,
+        created_at: String,
+        created_by: uuid::Uuid,
+        ends_at: String,
+        id: uuid::Uuid,
+        realm_id: uuid::Uuid,
+        reason: String,
+        session_strategy: models::MaintenanceSessionStrategy,
+        starts_at: String,
+        status: models::MaintenanceWindowStatus,
+        updated_at: String,
+    ) -> MaintenanceWindow {
+        MaintenanceWindow {
+            affected_client_ids,
+            announce_at,
+            client_id: None,
+            created_at,
+            created_by,
+            ends_at,
+            id,
+            realm_id,
+            reason,
+            session_strategy,
+            starts_at,
+            status,
+            updated_at,
+        }
+    }
+}
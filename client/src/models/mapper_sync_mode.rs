@@ -0,0 +1,33 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// When a mapper runs during a brokered login.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum MapperSyncMode {
+    #[serde(rename = "import")]
+    #[default]
+    Import,
+    #[serde(rename = "force")]
+    Force,
+}
+
+impl std::fmt::Display for MapperSyncMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Import => write!(f, "import"),
+            Self::Force => write!(f, "force"),
+        }
+    }
+}
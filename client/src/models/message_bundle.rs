@@ -0,0 +1,46 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// MessageBundle : A realm's texts for one locale, keyed like `login.title` or `email.magic_link.subject`. Keys the bundle does not define fall back to the built-in texts.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageBundle {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "locale")]
+    pub locale: String,
+    #[serde(rename = "messages")]
+    pub messages: std::collections::HashMap<String, String>,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl MessageBundle {
+    /// A realm's texts for one locale, keyed like `login.title` or `email.magic_link.subject`. Keys the bundle does not define fall back to the built-in texts.
+    pub fn new(
+        created_at: String,
+        locale: String,
+        messages: std::collections::HashMap<String, String>,
+        realm_id: uuid::Uuid,
+        updated_at: String,
+    ) -> MessageBundle {
+        MessageBundle {
+            created_at,
+            locale,
+            messages,
+            realm_id,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// MetadataSummary : What FerrisKey knows about the FIDO metadata it was started with.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MetadataSummary {
+    #[serde(rename = "authenticator_count")]
+    pub authenticator_count: i32,
+    #[serde(rename = "loaded")]
+    pub loaded: bool,
+    /// Date by which FIDO publishes a newer BLOB.
+    #[serde(
+        rename = "next_update",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub next_update: Option<Option<String>>,
+    /// Serial number of the BLOB.
+    #[serde(
+        rename = "serial",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub serial: Option<Option<i64>>,
+    /// Whether the BLOB signature was checked against the configured root certificate.
+    #[serde(rename = "signature_verified")]
+    pub signature_verified: bool,
+}
+
+impl MetadataSummary {
+    /// What FerrisKey knows about the FIDO metadata it was started with.
+    pub fn new(
+        authenticator_count: i32,
+        loaded: bool,
+        signature_verified: bool,
+    ) -> MetadataSummary {
+        MetadataSummary {
+            authenticator_count,
+            loaded,
+            next_update: None,
+            serial: None,
+            signature_verified,
+        }
+    }
+}
@@ -1,25 +1,79 @@
+pub mod accept_invitation_validator;
+pub use self::accept_invitation_validator::AcceptInvitationValidator;
+pub mod accepted_invitation;
+pub use self::accepted_invitation::AcceptedInvitation;
+pub mod activate_theme_response;
+pub use self::activate_theme_response::ActivateThemeResponse;
+pub mod active_theme_response;
+pub use self::active_theme_response::ActiveThemeResponse;
 pub mod actor_type;
 pub use self::actor_type::ActorType;
+pub mod add_client_whitelist_entry_response;
+pub use self::add_client_whitelist_entry_response::AddClientWhitelistEntryResponse;
+pub mod add_group_member_validator;
+pub use self::add_group_member_validator::AddGroupMemberValidator;
+pub mod add_member_validator;
+pub use self::add_member_validator::AddMemberValidator;
+pub mod add_realm_group_member_validator;
+pub use self::add_realm_group_member_validator::AddRealmGroupMemberValidator;
+pub mod add_realm_whitelist_entry_response;
+pub use self::add_realm_whitelist_entry_response::AddRealmWhitelistEntryResponse;
+pub mod add_whitelist_entry_validator;
+pub use self::add_whitelist_entry_validator::AddWhitelistEntryValidator;
 pub mod api_error;
 pub use self::api_error::ApiError;
 pub mod api_error_one_of;
 pub use self::api_error_one_of::ApiErrorOneOf;
-pub mod api_error_one_of_1;
-pub use self::api_error_one_of_1::ApiErrorOneOf1;
-pub mod api_error_one_of_2;
-pub use self::api_error_one_of_2::ApiErrorOneOf2;
-pub mod api_error_one_of_3;
-pub use self::api_error_one_of_3::ApiErrorOneOf3;
-pub mod api_error_one_of_4;
-pub use self::api_error_one_of_4::ApiErrorOneOf4;
-pub mod api_error_one_of_5;
-pub use self::api_error_one_of_5::ApiErrorOneOf5;
-pub mod api_error_one_of_6;
-pub use self::api_error_one_of_6::ApiErrorOneOf6;
+pub mod api_error_one_of1;
+pub use self::api_error_one_of1::ApiErrorOneOf1;
+pub mod api_error_one_of2;
+pub use self::api_error_one_of2::ApiErrorOneOf2;
+pub mod api_error_one_of3;
+pub use self::api_error_one_of3::ApiErrorOneOf3;
+pub mod api_error_one_of4;
+pub use self::api_error_one_of4::ApiErrorOneOf4;
+pub mod api_error_one_of5;
+pub use self::api_error_one_of5::ApiErrorOneOf5;
+pub mod api_error_one_of6;
+pub use self::api_error_one_of6::ApiErrorOneOf6;
+pub mod api_error_one_of7;
+pub use self::api_error_one_of7::ApiErrorOneOf7;
+pub mod api_error_one_of7_too_many_requests;
+pub use self::api_error_one_of7_too_many_requests::ApiErrorOneOf7TooManyRequests;
+pub mod api_error_one_of8;
+pub use self::api_error_one_of8::ApiErrorOneOf8;
+pub mod api_error_one_of8_o_auth_error;
+pub use self::api_error_one_of8_o_auth_error::ApiErrorOneOf8OAuthError;
 pub mod api_error_response;
 pub use self::api_error_response::ApiErrorResponse;
+pub mod assign_group_role_validator;
+pub use self::assign_group_role_validator::AssignGroupRoleValidator;
+pub mod assign_member_role_validator;
+pub use self::assign_member_role_validator::AssignMemberRoleValidator;
+pub mod assign_realm_group_role_validator;
+pub use self::assign_realm_group_role_validator::AssignRealmGroupRoleValidator;
 pub mod assign_role_response;
 pub use self::assign_role_response::AssignRoleResponse;
+pub mod attestation_conveyance;
+pub use self::attestation_conveyance::AttestationConveyance;
+pub mod attribute_group;
+pub use self::attribute_group::AttributeGroup;
+pub mod attribute_permissions;
+pub use self::attribute_permissions::AttributePermissions;
+pub mod attribute_type;
+pub use self::attribute_type::AttributeType;
+pub mod attribute_validator;
+pub use self::attribute_validator::AttributeValidator;
+pub mod attribute_validator_one_of;
+pub use self::attribute_validator_one_of::AttributeValidatorOneOf;
+pub mod attribute_validator_one_of1;
+pub use self::attribute_validator_one_of1::AttributeValidatorOneOf1;
+pub mod attribute_validator_one_of2;
+pub use self::attribute_validator_one_of2::AttributeValidatorOneOf2;
+pub mod attribute_validator_one_of3;
+pub use self::attribute_validator_one_of3::AttributeValidatorOneOf3;
+pub mod audience;
+pub use self::audience::Audience;
 pub mod auth_response;
 pub use self::auth_response::AuthResponse;
 pub mod authenticate_request;
@@ -30,6 +84,18 @@ pub mod authentication_attempt_response;
 pub use self::authentication_attempt_response::AuthenticationAttemptResponse;
 pub mod authentication_status;
 pub use self::authentication_status::AuthenticationStatus;
+pub mod authenticator_attachment;
+pub use self::authenticator_attachment::AuthenticatorAttachment;
+pub mod authenticator_info;
+pub use self::authenticator_info::AuthenticatorInfo;
+pub mod broker_login_step_response;
+pub use self::broker_login_step_response::BrokerLoginStepResponse;
+pub mod broker_login_step_status;
+pub use self::broker_login_step_status::BrokerLoginStepStatus;
+pub mod broker_saml_response_form;
+pub use self::broker_saml_response_form::BrokerSamlResponseForm;
+pub mod brokered_profile;
+pub use self::brokered_profile::BrokeredProfile;
 pub mod bulk_delete_user_response;
 pub use self::bulk_delete_user_response::BulkDeleteUserResponse;
 pub mod bulk_delete_user_validator;
@@ -44,6 +110,8 @@ pub mod challenge_otp_response;
 pub use self::challenge_otp_response::ChallengeOtpResponse;
 pub mod client;
 pub use self::client::Client;
+pub mod client_auth_method;
+pub use self::client_auth_method::ClientAuthMethod;
 pub mod client_scope;
 pub use self::client_scope::ClientScope;
 pub mod client_scope_attribute;
@@ -52,28 +120,76 @@ pub mod client_scope_mapping;
 pub use self::client_scope_mapping::ClientScopeMapping;
 pub mod client_scopes_response;
 pub use self::client_scopes_response::ClientScopesResponse;
+pub mod client_secret_response;
+pub use self::client_secret_response::ClientSecretResponse;
+pub mod client_type;
+pub use self::client_type::ClientType;
 pub mod clients_response;
 pub use self::clients_response::ClientsResponse;
+pub mod code_challenge_method;
+pub use self::code_challenge_method::CodeChallengeMethod;
+pub mod compare_flow_analytics_response;
+pub use self::compare_flow_analytics_response::CompareFlowAnalyticsResponse;
+pub mod compass_flow;
+pub use self::compass_flow::CompassFlow;
+pub mod compass_flow_step;
+pub use self::compass_flow_step::CompassFlowStep;
+pub mod complete_password_reset_response;
+pub use self::complete_password_reset_response::CompletePasswordResetResponse;
+pub mod configuration_check;
+pub use self::configuration_check::ConfigurationCheck;
+pub mod configure_domain_validator;
+pub use self::configure_domain_validator::ConfigureDomainValidator;
+pub mod confirm_broker_link_request;
+pub use self::confirm_broker_link_request::ConfirmBrokerLinkRequest;
 pub mod create_client_scope_validator;
 pub use self::create_client_scope_validator::CreateClientScopeValidator;
 pub mod create_client_validator;
 pub use self::create_client_validator::CreateClientValidator;
+pub mod create_email_template_response;
+pub use self::create_email_template_response::CreateEmailTemplateResponse;
+pub mod create_email_template_validator;
+pub use self::create_email_template_validator::CreateEmailTemplateValidator;
+pub mod create_group_validator;
+pub use self::create_group_validator::CreateGroupValidator;
 pub mod create_identity_provider_validator;
 pub use self::create_identity_provider_validator::CreateIdentityProviderValidator;
+pub mod create_invitation_validator;
+pub use self::create_invitation_validator::CreateInvitationValidator;
+pub mod create_organization_validator;
+pub use self::create_organization_validator::CreateOrganizationValidator;
+pub mod create_portal_layout_response;
+pub use self::create_portal_layout_response::CreatePortalLayoutResponse;
+pub mod create_portal_layout_validator;
+pub use self::create_portal_layout_validator::CreatePortalLayoutValidator;
 pub mod create_protocol_mapper_validator;
 pub use self::create_protocol_mapper_validator::CreateProtocolMapperValidator;
 pub mod create_provider_request;
 pub use self::create_provider_request::CreateProviderRequest;
+pub mod create_rate_limit_list_entry_validator;
+pub use self::create_rate_limit_list_entry_validator::CreateRateLimitListEntryValidator;
+pub mod create_rate_limit_policy_validator;
+pub use self::create_rate_limit_policy_validator::CreateRateLimitPolicyValidator;
+pub mod create_realm_group_validator;
+pub use self::create_realm_group_validator::CreateRealmGroupValidator;
 pub mod create_realm_validator;
 pub use self::create_realm_validator::CreateRealmValidator;
 pub mod create_redirect_uri_validator;
 pub use self::create_redirect_uri_validator::CreateRedirectUriValidator;
+pub mod create_role_response;
+pub use self::create_role_response::CreateRoleResponse;
 pub mod create_role_validator;
 pub use self::create_role_validator::CreateRoleValidator;
+pub mod create_theme_response;
+pub use self::create_theme_response::CreateThemeResponse;
+pub mod create_theme_validator;
+pub use self::create_theme_validator::CreateThemeValidator;
 pub mod create_user_response;
 pub use self::create_user_response::CreateUserResponse;
 pub mod create_user_validator;
 pub use self::create_user_validator::CreateUserValidator;
+pub mod create_web_origin_validator;
+pub use self::create_web_origin_validator::CreateWebOriginValidator;
 pub mod create_webhook_response;
 pub use self::create_webhook_response::CreateWebhookResponse;
 pub mod create_webhook_validator;
@@ -82,34 +198,94 @@ pub mod credential_data_overview;
 pub use self::credential_data_overview::CredentialDataOverview;
 pub mod credential_data_overview_one_of;
 pub use self::credential_data_overview_one_of::CredentialDataOverviewOneOf;
-pub mod credential_data_overview_one_of_1;
-pub use self::credential_data_overview_one_of_1::CredentialDataOverviewOneOf1;
-pub mod credential_data_overview_one_of_1_federated;
-pub use self::credential_data_overview_one_of_1_federated::CredentialDataOverviewOneOf1Federated;
+pub mod credential_data_overview_one_of1;
+pub use self::credential_data_overview_one_of1::CredentialDataOverviewOneOf1;
+pub mod credential_data_overview_one_of1_federated;
+pub use self::credential_data_overview_one_of1_federated::CredentialDataOverviewOneOf1Federated;
 pub mod credential_data_overview_one_of_hash;
 pub use self::credential_data_overview_one_of_hash::CredentialDataOverviewOneOfHash;
 pub mod credential_overview;
 pub use self::credential_overview::CredentialOverview;
+pub mod daily_activity_stats;
+pub use self::daily_activity_stats::DailyActivityStats;
 pub mod delete_client_response;
 pub use self::delete_client_response::DeleteClientResponse;
 pub mod delete_client_scope_response;
 pub use self::delete_client_scope_response::DeleteClientScopeResponse;
+pub mod delete_email_template_response;
+pub use self::delete_email_template_response::DeleteEmailTemplateResponse;
+pub mod delete_identity_provider_link_response;
+pub use self::delete_identity_provider_link_response::DeleteIdentityProviderLinkResponse;
+pub mod delete_identity_provider_mapper_response;
+pub use self::delete_identity_provider_mapper_response::DeleteIdentityProviderMapperResponse;
 pub mod delete_identity_provider_response;
 pub use self::delete_identity_provider_response::DeleteIdentityProviderResponse;
+pub mod delete_portal_layout_response;
+pub use self::delete_portal_layout_response::DeletePortalLayoutResponse;
 pub mod delete_protocol_mapper_response;
 pub use self::delete_protocol_mapper_response::DeleteProtocolMapperResponse;
 pub mod delete_provider_response;
 pub use self::delete_provider_response::DeleteProviderResponse;
 pub mod delete_role_response;
 pub use self::delete_role_response::DeleteRoleResponse;
+pub mod delete_theme_response;
+pub use self::delete_theme_response::DeleteThemeResponse;
 pub mod delete_user_credential_response;
 pub use self::delete_user_credential_response::DeleteUserCredentialResponse;
 pub mod delete_user_response;
 pub use self::delete_user_response::DeleteUserResponse;
 pub mod delete_webhook_response;
 pub use self::delete_webhook_response::DeleteWebhookResponse;
+pub mod device_authorization_request;
+pub use self::device_authorization_request::DeviceAuthorizationRequest;
+pub mod device_verification_preview;
+pub use self::device_verification_preview::DeviceVerificationPreview;
+pub mod device_verify_action;
+pub use self::device_verify_action::DeviceVerifyAction;
+pub mod device_verify_request;
+pub use self::device_verify_request::DeviceVerifyRequest;
+pub mod device_verify_response;
+pub use self::device_verify_response::DeviceVerifyResponse;
+pub mod discover_identity_provider_validator;
+pub use self::discover_identity_provider_validator::DiscoverIdentityProviderValidator;
+pub mod discovered_identity_provider_configuration;
+pub use self::discovered_identity_provider_configuration::DiscoveredIdentityProviderConfiguration;
+pub mod effective_realm_group;
+pub use self::effective_realm_group::EffectiveRealmGroup;
+pub mod email_template;
+pub use self::email_template::EmailTemplate;
+pub mod email_type;
+pub use self::email_type::EmailType;
+pub mod evaluate_client_scopes_result;
+pub use self::evaluate_client_scopes_result::EvaluateClientScopesResult;
+pub mod evaluate_scopes_validator;
+pub use self::evaluate_scopes_validator::EvaluateScopesValidator;
+pub mod evaluated_mapper;
+pub use self::evaluated_mapper::EvaluatedMapper;
+pub mod evaluated_roles;
+pub use self::evaluated_roles::EvaluatedRoles;
+pub mod evaluated_scope;
+pub use self::evaluated_scope::EvaluatedScope;
 pub mod event_status;
 pub use self::event_status::EventStatus;
+pub mod existing_account_hint;
+pub use self::existing_account_hint::ExistingAccountHint;
+pub mod failure_reason;
+pub use self::failure_reason::FailureReason;
+pub mod flow_analytics;
+pub use self::flow_analytics::FlowAnalytics;
+pub mod flow_analytics_comparison;
+pub use self::flow_analytics_comparison::FlowAnalyticsComparison;
+pub mod flow_stats;
+pub use self::flow_stats::FlowStats;
+pub mod flow_status;
+pub use self::flow_status::FlowStatus;
+pub mod flow_step_name;
+pub use self::flow_step_name::FlowStepName;
+pub mod forgot_password_request;
+pub use self::forgot_password_request::ForgotPasswordRequest;
+pub mod funnel_stage;
+pub use self::funnel_stage::FunnelStage;
 pub mod generate_recovery_codes_request;
 pub use self::generate_recovery_codes_request::GenerateRecoveryCodesRequest;
 pub mod generate_recovery_codes_response;
@@ -120,14 +296,52 @@ pub mod get_client_response;
 pub use self::get_client_response::GetClientResponse;
 pub mod get_client_roles_response;
 pub use self::get_client_roles_response::GetClientRolesResponse;
+pub mod get_client_whitelist_response;
+pub use self::get_client_whitelist_response::GetClientWhitelistResponse;
+pub mod get_daily_activity_stats_response;
+pub use self::get_daily_activity_stats_response::GetDailyActivityStatsResponse;
+pub mod get_email_template_response;
+pub use self::get_email_template_response::GetEmailTemplateResponse;
+pub mod get_email_templates_response;
+pub use self::get_email_templates_response::GetEmailTemplatesResponse;
+pub mod get_flow_analytics_response;
+pub use self::get_flow_analytics_response::GetFlowAnalyticsResponse;
+pub mod get_flow_response;
+pub use self::get_flow_response::GetFlowResponse;
+pub mod get_flows_response;
+pub use self::get_flows_response::GetFlowsResponse;
+pub mod get_maintenance_announcements_response;
+pub use self::get_maintenance_announcements_response::GetMaintenanceAnnouncementsResponse;
+pub mod get_maintenance_windows_response;
+pub use self::get_maintenance_windows_response::GetMaintenanceWindowsResponse;
 pub mod get_open_id_configuration_response;
 pub use self::get_open_id_configuration_response::GetOpenIdConfigurationResponse;
+pub mod get_outbox_emails_response;
+pub use self::get_outbox_emails_response::GetOutboxEmailsResponse;
+pub mod get_portal_layout_response;
+pub use self::get_portal_layout_response::GetPortalLayoutResponse;
+pub mod get_public_default_portal_layout_response;
+pub use self::get_public_default_portal_layout_response::GetPublicDefaultPortalLayoutResponse;
+pub mod get_public_portal_layout_response;
+pub use self::get_public_portal_layout_response::GetPublicPortalLayoutResponse;
+pub mod get_realm_whitelist_response;
+pub use self::get_realm_whitelist_response::GetRealmWhitelistResponse;
 pub mod get_role_response;
 pub use self::get_role_response::GetRoleResponse;
 pub mod get_roles_response;
 pub use self::get_roles_response::GetRolesResponse;
 pub mod get_security_events_response;
 pub use self::get_security_events_response::GetSecurityEventsResponse;
+pub mod get_security_notifications_response;
+pub use self::get_security_notifications_response::GetSecurityNotificationsResponse;
+pub mod get_stats_response;
+pub use self::get_stats_response::GetStatsResponse;
+pub mod get_template_variables_response;
+pub use self::get_template_variables_response::GetTemplateVariablesResponse;
+pub mod get_theme_by_id_response;
+pub use self::get_theme_by_id_response::GetThemeByIdResponse;
+pub mod get_theme_response;
+pub use self::get_theme_response::GetThemeResponse;
 pub mod get_user_credentials_response;
 pub use self::get_user_credentials_response::GetUserCredentialsResponse;
 pub mod get_user_roles_response;
@@ -136,84 +350,394 @@ pub mod get_webhooks_response;
 pub use self::get_webhooks_response::GetWebhooksResponse;
 pub mod grant_type;
 pub use self::grant_type::GrantType;
+pub mod group;
+pub use self::group::Group;
+pub mod group_attribute;
+pub use self::group_attribute::GroupAttribute;
+pub mod group_member;
+pub use self::group_member::GroupMember;
+pub mod group_member_detail;
+pub use self::group_member_detail::GroupMemberDetail;
+pub mod group_member_page;
+pub use self::group_member_page::GroupMemberPage;
+pub mod group_node;
+pub use self::group_node::GroupNode;
+pub mod home_realm_discovery;
+pub use self::home_realm_discovery::HomeRealmDiscovery;
+pub mod hourly_flow_bucket;
+pub use self::hourly_flow_bucket::HourlyFlowBucket;
+pub mod identity_provider_link_response;
+pub use self::identity_provider_link_response::IdentityProviderLinkResponse;
+pub mod identity_provider_links_response;
+pub use self::identity_provider_links_response::IdentityProviderLinksResponse;
+pub mod identity_provider_mapper;
+pub use self::identity_provider_mapper::IdentityProviderMapper;
+pub mod identity_provider_mapper_config;
+pub use self::identity_provider_mapper_config::IdentityProviderMapperConfig;
+pub mod identity_provider_mapper_config_one_of;
+pub use self::identity_provider_mapper_config_one_of::IdentityProviderMapperConfigOneOf;
+pub mod identity_provider_mapper_config_one_of1;
+pub use self::identity_provider_mapper_config_one_of1::IdentityProviderMapperConfigOneOf1;
+pub mod identity_provider_mapper_config_one_of2;
+pub use self::identity_provider_mapper_config_one_of2::IdentityProviderMapperConfigOneOf2;
+pub mod identity_provider_mapper_config_one_of3;
+pub use self::identity_provider_mapper_config_one_of3::IdentityProviderMapperConfigOneOf3;
+pub mod identity_provider_mapper_config_one_of4;
+pub use self::identity_provider_mapper_config_one_of4::IdentityProviderMapperConfigOneOf4;
+pub mod identity_provider_mapper_response;
+pub use self::identity_provider_mapper_response::IdentityProviderMapperResponse;
+pub mod identity_provider_mapper_validator;
+pub use self::identity_provider_mapper_validator::IdentityProviderMapperValidator;
+pub mod identity_provider_mappers_response;
+pub use self::identity_provider_mappers_response::IdentityProviderMappersResponse;
 pub mod identity_provider_presentation;
 pub use self::identity_provider_presentation::IdentityProviderPresentation;
 pub mod identity_provider_response;
 pub use self::identity_provider_response::IdentityProviderResponse;
+pub mod identity_provider_validation_report;
+pub use self::identity_provider_validation_report::IdentityProviderValidationReport;
 pub mod identity_providers_response;
 pub use self::identity_providers_response::IdentityProvidersResponse;
+pub mod import_saml_metadata_validator;
+pub use self::import_saml_metadata_validator::ImportSamlMetadataValidator;
+pub mod initiate_device_flow_output;
+pub use self::initiate_device_flow_output::InitiateDeviceFlowOutput;
 pub mod introspect_request_validator;
 pub use self::introspect_request_validator::IntrospectRequestValidator;
+pub mod invitation_preview;
+pub use self::invitation_preview::InvitationPreview;
+pub mod invitation_status;
+pub use self::invitation_status::InvitationStatus;
 pub mod jwk_key;
 pub use self::jwk_key::JwkKey;
 pub mod jwt_token;
 pub use self::jwt_token::JwtToken;
+pub mod link_confirmation_method;
+pub use self::link_confirmation_method::LinkConfirmationMethod;
+pub mod list_organization_attributes_response;
+pub use self::list_organization_attributes_response::ListOrganizationAttributesResponse;
+pub mod list_organizations_response;
+pub use self::list_organizations_response::ListOrganizationsResponse;
+pub mod list_portal_layouts_response;
+pub use self::list_portal_layouts_response::ListPortalLayoutsResponse;
 pub mod list_providers_response;
 pub use self::list_providers_response::ListProvidersResponse;
+pub mod list_sync_runs_response;
+pub use self::list_sync_runs_response::ListSyncRunsResponse;
+pub mod list_themes_response;
+pub use self::list_themes_response::ListThemesResponse;
+pub mod list_user_sessions_response;
+pub use self::list_user_sessions_response::ListUserSessionsResponse;
+pub mod localization_settings;
+pub use self::localization_settings::LocalizationSettings;
+pub mod localized_messages;
+pub use self::localized_messages::LocalizedMessages;
+pub mod login_alias;
+pub use self::login_alias::LoginAlias;
+pub mod login_funnel;
+pub use self::login_funnel::LoginFunnel;
+pub mod login_record;
+pub use self::login_record::LoginRecord;
+pub mod login_risk_policy;
+pub use self::login_risk_policy::LoginRiskPolicy;
+pub mod login_risk_settings;
+pub use self::login_risk_settings::LoginRiskSettings;
 pub mod logout_request_validator;
 pub use self::logout_request_validator::LogoutRequestValidator;
+pub mod maintenance_announcement;
+pub use self::maintenance_announcement::MaintenanceAnnouncement;
+pub mod maintenance_session_strategy;
+pub use self::maintenance_session_strategy::MaintenanceSessionStrategy;
+pub mod maintenance_whitelist_entry;
+pub use self::maintenance_whitelist_entry::MaintenanceWhitelistEntry;
+pub mod maintenance_window;
+pub use self::maintenance_window::MaintenanceWindow;
+pub mod maintenance_window_status;
+pub use self::maintenance_window_status::MaintenanceWindowStatus;
+pub mod mapper_sync_mode;
+pub use self::mapper_sync_mode::MapperSyncMode;
+pub mod message_bundle;
+pub use self::message_bundle::MessageBundle;
+pub mod metadata_summary;
+pub use self::metadata_summary::MetadataSummary;
+pub mod name_id_format;
+pub use self::name_id_format::NameIdFormat;
+pub mod organization;
+pub use self::organization::Organization;
+pub mod organization_attribute;
+pub use self::organization_attribute::OrganizationAttribute;
+pub mod organization_domain_status;
+pub use self::organization_domain_status::OrganizationDomainStatus;
+pub mod organization_invitation;
+pub use self::organization_invitation::OrganizationInvitation;
+pub mod organization_member;
+pub use self::organization_member::OrganizationMember;
 pub mod otp_verify_request;
 pub use self::otp_verify_request::OtpVerifyRequest;
+pub mod outbox_email;
+pub use self::outbox_email::OutboxEmail;
+pub mod outbox_email_status;
+pub use self::outbox_email_status::OutboxEmailStatus;
+pub mod page_requirement;
+pub use self::page_requirement::PageRequirement;
+pub mod page_requirements_response;
+pub use self::page_requirements_response::PageRequirementsResponse;
+pub mod passkey_authenticate_response;
+pub use self::passkey_authenticate_response::PasskeyAuthenticateResponse;
+pub mod passkey_request_options_request;
+pub use self::passkey_request_options_request::PasskeyRequestOptionsRequest;
+pub mod password_policy;
+pub use self::password_policy::PasswordPolicy;
+pub mod pending_action_response;
+pub use self::pending_action_response::PendingActionResponse;
+pub mod pending_broker_login_stage;
+pub use self::pending_broker_login_stage::PendingBrokerLoginStage;
+pub mod pending_broker_login_view;
+pub use self::pending_broker_login_view::PendingBrokerLoginView;
 pub mod permissions;
 pub use self::permissions::Permissions;
+pub mod portal_layout;
+pub use self::portal_layout::PortalLayout;
+pub mod portal_messages;
+pub use self::portal_messages::PortalMessages;
+pub mod portal_page_type;
+pub use self::portal_page_type::PortalPageType;
+pub mod portal_theme;
+pub use self::portal_theme::PortalTheme;
+pub mod portal_theme_config;
+pub use self::portal_theme_config::PortalThemeConfig;
+pub mod portal_theme_config_borders;
+pub use self::portal_theme_config_borders::PortalThemeConfigBorders;
+pub mod portal_theme_config_colors;
+pub use self::portal_theme_config_colors::PortalThemeConfigColors;
+pub mod portal_theme_config_fonts;
+pub use self::portal_theme_config_fonts::PortalThemeConfigFonts;
+pub mod portal_theme_config_spacing;
+pub use self::portal_theme_config_spacing::PortalThemeConfigSpacing;
+pub mod portal_theme_pages;
+pub use self::portal_theme_pages::PortalThemePages;
+pub mod profile_context;
+pub use self::profile_context::ProfileContext;
+pub mod profile_field;
+pub use self::profile_field::ProfileField;
 pub mod protocol_mapper;
 pub use self::protocol_mapper::ProtocolMapper;
 pub mod provider_response;
 pub use self::provider_response::ProviderResponse;
+pub mod public_password_policy;
+pub use self::public_password_policy::PublicPasswordPolicy;
+pub mod put_message_bundle_validator;
+pub use self::put_message_bundle_validator::PutMessageBundleValidator;
+pub mod rate_limit_endpoint;
+pub use self::rate_limit_endpoint::RateLimitEndpoint;
+pub mod rate_limit_key_kind;
+pub use self::rate_limit_key_kind::RateLimitKeyKind;
+pub mod rate_limit_list_entry;
+pub use self::rate_limit_list_entry::RateLimitListEntry;
+pub mod rate_limit_list_kind;
+pub use self::rate_limit_list_kind::RateLimitListKind;
+pub mod rate_limit_overview;
+pub use self::rate_limit_overview::RateLimitOverview;
+pub mod rate_limit_policy;
+pub use self::rate_limit_policy::RateLimitPolicy;
+pub mod rate_limit_rule;
+pub use self::rate_limit_rule::RateLimitRule;
 pub mod realm;
 pub use self::realm::Realm;
+pub mod realm_group;
+pub use self::realm_group::RealmGroup;
+pub mod realm_group_attribute;
+pub use self::realm_group_attribute::RealmGroupAttribute;
+pub mod realm_group_member;
+pub use self::realm_group_member::RealmGroupMember;
+pub mod realm_group_member_detail;
+pub use self::realm_group_member_detail::RealmGroupMemberDetail;
+pub mod realm_group_member_page;
+pub use self::realm_group_member_page::RealmGroupMemberPage;
+pub mod realm_group_membership;
+pub use self::realm_group_membership::RealmGroupMembership;
+pub mod realm_group_node;
+pub use self::realm_group_node::RealmGroupNode;
 pub mod realm_login_setting;
 pub use self::realm_login_setting::RealmLoginSetting;
+pub mod realm_maintenance_whitelist_entry;
+pub use self::realm_maintenance_whitelist_entry::RealmMaintenanceWhitelistEntry;
 pub mod realm_setting;
 pub use self::realm_setting::RealmSetting;
+pub mod redirect_registration_response;
+pub use self::redirect_registration_response::RedirectRegistrationResponse;
 pub mod redirect_uri;
 pub use self::redirect_uri::RedirectUri;
 pub mod registration_request;
 pub use self::registration_request::RegistrationRequest;
+pub mod registration_response;
+pub use self::registration_response::RegistrationResponse;
+pub mod registration_response_one_of;
+pub use self::registration_response_one_of::RegistrationResponseOneOf;
+pub mod registration_response_one_of1;
+pub use self::registration_response_one_of1::RegistrationResponseOneOf1;
+pub mod registration_response_one_of2;
+pub use self::registration_response_one_of2::RegistrationResponseOneOf2;
+pub mod remove_client_whitelist_entry_response;
+pub use self::remove_client_whitelist_entry_response::RemoveClientWhitelistEntryResponse;
+pub mod remove_realm_whitelist_entry_response;
+pub use self::remove_realm_whitelist_entry_response::RemoveRealmWhitelistEntryResponse;
 pub mod required_action;
 pub use self::required_action::RequiredAction;
+pub mod resend_verification_email_response;
+pub use self::resend_verification_email_response::ResendVerificationEmailResponse;
+pub mod reset_password_request;
+pub use self::reset_password_request::ResetPasswordRequest;
 pub mod reset_password_response;
 pub use self::reset_password_response::ResetPasswordResponse;
 pub mod reset_password_validator;
 pub use self::reset_password_validator::ResetPasswordValidator;
+pub mod review_brokered_profile_request;
+pub use self::review_brokered_profile_request::ReviewBrokeredProfileRequest;
 pub mod revoke_token_request_validator;
 pub use self::revoke_token_request_validator::RevokeTokenRequestValidator;
+pub mod risk_signal;
+pub use self::risk_signal::RiskSignal;
+pub mod risk_signal_one_of;
+pub use self::risk_signal_one_of::RiskSignalOneOf;
+pub mod risk_signal_one_of1;
+pub use self::risk_signal_one_of1::RiskSignalOneOf1;
+pub mod risk_signal_one_of2;
+pub use self::risk_signal_one_of2::RiskSignalOneOf2;
 pub mod role;
 pub use self::role::Role;
+pub mod saml_binding;
+pub use self::saml_binding::SamlBinding;
+pub mod saml_client_settings;
+pub use self::saml_client_settings::SamlClientSettings;
+pub mod saml_endpoint;
+pub use self::saml_endpoint::SamlEndpoint;
+pub mod saml_message_params;
+pub use self::saml_message_params::SamlMessageParams;
+pub mod schedule_maintenance_window_validator;
+pub use self::schedule_maintenance_window_validator::ScheduleMaintenanceWindowValidator;
+pub mod scope_type;
+pub use self::scope_type::ScopeType;
 pub mod security_event;
 pub use self::security_event::SecurityEvent;
 pub mod security_event_type;
 pub use self::security_event_type::SecurityEventType;
+pub mod security_notification_kind;
+pub use self::security_notification_kind::SecurityNotificationKind;
+pub mod security_notification_setting;
+pub use self::security_notification_setting::SecurityNotificationSetting;
 pub mod send_magic_link_request;
 pub use self::send_magic_link_request::SendMagicLinkRequest;
 pub mod send_magic_link_response;
 pub use self::send_magic_link_response::SendMagicLinkResponse;
+pub mod set_default_portal_layout_response;
+pub use self::set_default_portal_layout_response::SetDefaultPortalLayoutResponse;
+pub mod set_user_attributes_response;
+pub use self::set_user_attributes_response::SetUserAttributesResponse;
+pub mod set_user_attributes_validator;
+pub use self::set_user_attributes_validator::SetUserAttributesValidator;
 pub mod setup_otp_response;
 pub use self::setup_otp_response::SetupOtpResponse;
+pub mod smtp_config;
+pub use self::smtp_config::SmtpConfig;
+pub mod smtp_encryption;
+pub use self::smtp_encryption::SmtpEncryption;
+pub mod step_latency;
+pub use self::step_latency::StepLatency;
+pub mod step_latency_delta;
+pub use self::step_latency_delta::StepLatencyDelta;
+pub mod step_status;
+pub use self::step_status::StepStatus;
+pub mod sync_kind;
+pub use self::sync_kind::SyncKind;
+pub mod sync_run_error_response;
+pub use self::sync_run_error_response::SyncRunErrorResponse;
+pub mod sync_run_response;
+pub use self::sync_run_response::SyncRunResponse;
+pub mod sync_status;
+pub use self::sync_status::SyncStatus;
+pub mod sync_trigger;
+pub use self::sync_trigger::SyncTrigger;
 pub mod sync_users_response;
 pub use self::sync_users_response::SyncUsersResponse;
+pub mod template_variable;
+pub use self::template_variable::TemplateVariable;
 pub mod test_connection_response;
 pub use self::test_connection_response::TestConnectionResponse;
+pub mod theme_borders;
+pub use self::theme_borders::ThemeBorders;
+pub mod theme_borders_widget_shadow;
+pub use self::theme_borders_widget_shadow::ThemeBordersWidgetShadow;
+pub mod theme_colors;
+pub use self::theme_colors::ThemeColors;
+pub mod theme_font_link_style;
+pub use self::theme_font_link_style::ThemeFontLinkStyle;
+pub mod theme_font_style;
+pub use self::theme_font_style::ThemeFontStyle;
+pub mod theme_fonts;
+pub use self::theme_fonts::ThemeFonts;
+pub mod theme_fonts_body;
+pub use self::theme_fonts_body::ThemeFontsBody;
+pub mod theme_fonts_buttons;
+pub use self::theme_fonts_buttons::ThemeFontsButtons;
+pub mod theme_fonts_input_labels;
+pub use self::theme_fonts_input_labels::ThemeFontsInputLabels;
+pub mod theme_fonts_links;
+pub use self::theme_fonts_links::ThemeFontsLinks;
+pub mod theme_fonts_subtitle;
+pub use self::theme_fonts_subtitle::ThemeFontsSubtitle;
+pub mod theme_fonts_title;
+pub use self::theme_fonts_title::ThemeFontsTitle;
+pub mod theme_link_style;
+pub use self::theme_link_style::ThemeLinkStyle;
+pub mod theme_shadow;
+pub use self::theme_shadow::ThemeShadow;
+pub mod theme_spacing;
+pub use self::theme_spacing::ThemeSpacing;
+pub mod toggle_maintenance_response;
+pub use self::toggle_maintenance_response::ToggleMaintenanceResponse;
+pub mod toggle_maintenance_validator;
+pub use self::toggle_maintenance_validator::ToggleMaintenanceValidator;
 pub mod token_introspection_response;
 pub use self::token_introspection_response::TokenIntrospectionResponse;
 pub mod token_request_validator;
 pub use self::token_request_validator::TokenRequestValidator;
 pub mod unassign_role_response;
 pub use self::unassign_role_response::UnassignRoleResponse;
+pub mod unmanaged_attribute_policy;
+pub use self::unmanaged_attribute_policy::UnmanagedAttributePolicy;
 pub mod update_client_response;
 pub use self::update_client_response::UpdateClientResponse;
 pub mod update_client_scope_validator;
 pub use self::update_client_scope_validator::UpdateClientScopeValidator;
 pub mod update_client_validator;
 pub use self::update_client_validator::UpdateClientValidator;
+pub mod update_email_template_response;
+pub use self::update_email_template_response::UpdateEmailTemplateResponse;
+pub mod update_email_template_validator;
+pub use self::update_email_template_validator::UpdateEmailTemplateValidator;
+pub mod update_group_validator;
+pub use self::update_group_validator::UpdateGroupValidator;
 pub mod update_identity_provider_response;
 pub use self::update_identity_provider_response::UpdateIdentityProviderResponse;
 pub mod update_identity_provider_validator;
 pub use self::update_identity_provider_validator::UpdateIdentityProviderValidator;
+pub mod update_localization_settings_validator;
+pub use self::update_localization_settings_validator::UpdateLocalizationSettingsValidator;
+pub mod update_login_risk_policy_validator;
+pub use self::update_login_risk_policy_validator::UpdateLoginRiskPolicyValidator;
+pub mod update_organization_validator;
+pub use self::update_organization_validator::UpdateOrganizationValidator;
+pub mod update_password_policy_validator;
+pub use self::update_password_policy_validator::UpdatePasswordPolicyValidator;
 pub mod update_password_request;
 pub use self::update_password_request::UpdatePasswordRequest;
 pub mod update_password_response;
 pub use self::update_password_response::UpdatePasswordResponse;
+pub mod update_portal_layout_response;
+pub use self::update_portal_layout_response::UpdatePortalLayoutResponse;
+pub mod update_portal_layout_validator;
+pub use self::update_portal_layout_validator::UpdatePortalLayoutValidator;
 pub mod update_post_logout_redirect_uri_response;
 pub use self::update_post_logout_redirect_uri_response::UpdatePostLogoutRedirectUriResponse;
 pub mod update_protocol_mapper_validator;
@@ -222,6 +746,10 @@ pub mod update_provider_request;
 pub use self::update_provider_request::UpdateProviderRequest;
 pub mod update_provider_response;
 pub use self::update_provider_response::UpdateProviderResponse;
+pub mod update_rate_limit_policy_validator;
+pub use self::update_rate_limit_policy_validator::UpdateRateLimitPolicyValidator;
+pub mod update_realm_group_validator;
+pub use self::update_realm_group_validator::UpdateRealmGroupValidator;
 pub mod update_realm_response;
 pub use self::update_realm_response::UpdateRealmResponse;
 pub mod update_realm_setting_response;
@@ -242,28 +770,86 @@ pub mod update_role_response;
 pub use self::update_role_response::UpdateRoleResponse;
 pub mod update_role_validator;
 pub use self::update_role_validator::UpdateRoleValidator;
+pub mod update_saml_client_settings_request;
+pub use self::update_saml_client_settings_request::UpdateSamlClientSettingsRequest;
+pub mod update_security_notification_validator;
+pub use self::update_security_notification_validator::UpdateSecurityNotificationValidator;
+pub mod update_theme_metadata_response;
+pub use self::update_theme_metadata_response::UpdateThemeMetadataResponse;
+pub mod update_theme_metadata_validator;
+pub use self::update_theme_metadata_validator::UpdateThemeMetadataValidator;
+pub mod update_theme_page_response;
+pub use self::update_theme_page_response::UpdateThemePageResponse;
+pub mod update_theme_page_validator;
+pub use self::update_theme_page_validator::UpdateThemePageValidator;
+pub mod update_theme_response;
+pub use self::update_theme_response::UpdateThemeResponse;
+pub mod update_theme_validator;
+pub use self::update_theme_validator::UpdateThemeValidator;
+pub mod update_user_profile_schema_validator;
+pub use self::update_user_profile_schema_validator::UpdateUserProfileSchemaValidator;
 pub mod update_user_response;
 pub use self::update_user_response::UpdateUserResponse;
 pub mod update_user_validator;
 pub use self::update_user_validator::UpdateUserValidator;
+pub mod update_web_authn_policy_validator;
+pub use self::update_web_authn_policy_validator::UpdateWebAuthnPolicyValidator;
 pub mod update_webhook_response;
 pub use self::update_webhook_response::UpdateWebhookResponse;
+pub mod update_webhook_validator;
+pub use self::update_webhook_validator::UpdateWebhookValidator;
+pub mod upsert_attribute_validator;
+pub use self::upsert_attribute_validator::UpsertAttributeValidator;
+pub mod upsert_realm_group_attribute_validator;
+pub use self::upsert_realm_group_attribute_validator::UpsertRealmGroupAttributeValidator;
+pub mod upsert_smtp_config_validator;
+pub use self::upsert_smtp_config_validator::UpsertSmtpConfigValidator;
 pub mod user;
 pub use self::user::User;
+pub mod user_attribute;
+pub use self::user_attribute::UserAttribute;
+pub mod user_attributes_response;
+pub use self::user_attributes_response::UserAttributesResponse;
 pub mod user_info_response;
 pub use self::user_info_response::UserInfoResponse;
 pub mod user_permissions_response;
 pub use self::user_permissions_response::UserPermissionsResponse;
+pub mod user_profile_attribute;
+pub use self::user_profile_attribute::UserProfileAttribute;
+pub mod user_profile_config;
+pub use self::user_profile_config::UserProfileConfig;
+pub mod user_profile_schema;
+pub use self::user_profile_schema::UserProfileSchema;
 pub mod user_realms_response;
 pub use self::user_realms_response::UserRealmsResponse;
 pub mod user_response;
 pub use self::user_response::UserResponse;
+pub mod user_session_dto;
+pub use self::user_session_dto::UserSessionDto;
+pub mod user_verification_requirement;
+pub use self::user_verification_requirement::UserVerificationRequirement;
 pub mod users_response;
 pub use self::users_response::UsersResponse;
 pub mod validation_error;
 pub use self::validation_error::ValidationError;
+pub mod verify_broker_login_otp_request;
+pub use self::verify_broker_login_otp_request::VerifyBrokerLoginOtpRequest;
+pub mod verify_email_request;
+pub use self::verify_email_request::VerifyEmailRequest;
+pub mod verify_email_result;
+pub use self::verify_email_result::VerifyEmailResult;
 pub mod verify_otp_response;
 pub use self::verify_otp_response::VerifyOtpResponse;
+pub mod verify_reset_token_request;
+pub use self::verify_reset_token_request::VerifyResetTokenRequest;
+pub mod verify_reset_token_response;
+pub use self::verify_reset_token_response::VerifyResetTokenResponse;
+pub mod web_authn_policy;
+pub use self::web_authn_policy::WebAuthnPolicy;
+pub mod web_authn_policy_settings;
+pub use self::web_authn_policy_settings::WebAuthnPolicySettings;
+pub mod web_origin;
+pub use self::web_origin::WebOrigin;
 pub mod webhook;
 pub use self::webhook::Webhook;
 pub mod webhook_subscriber;
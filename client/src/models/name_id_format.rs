@@ -0,0 +1,38 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum NameIdFormat {
+    #[serde(rename = "unspecified")]
+    #[default]
+    Unspecified,
+    #[serde(rename = "email_address")]
+    EmailAddress,
+    #[serde(rename = "persistent")]
+    Persistent,
+    #[serde(rename = "transient")]
+    Transient,
+}
+
+impl std::fmt::Display for NameIdFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Unspecified => write!(f, "unspecified"),
+            Self::EmailAddress => write!(f, "email_address"),
+            Self::Persistent => write!(f, "persistent"),
+            Self::Transient => write!(f, "transient"),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Organization : Organization domain entity  `name` is the primary human-readable label shown in admin or end-user surfaces. `alias` is the stable URL-safe identifier used for lookups and routing within a realm. The first organizations release does not persist a separate `display_name`, so callers should rely on `name` as the display label.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Organization {
+    #[serde(rename = "alias")]
+    pub alias: String,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(
+        rename = "description",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Option<String>>,
+    #[serde(
+        rename = "domain",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub domain: Option<Option<String>>,
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(
+        rename = "redirect_url",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub redirect_url: Option<Option<String>>,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl Organization {
+    /// Organization domain entity  `name` is the primary human-readable label shown in admin or end-user surfaces. `alias` is the stable URL-safe identifier used for lookups and routing within a realm. The first organizations release does not persist a separate `display_name`, so callers should rely on `name` as the display label.
+    pub fn new(
+        alias: String,
+        created_at: String,
+        enabled: bool,
+        id: uuid::Uuid,
+        name: String,
+        realm_id: uuid::Uuid,
+        updated_at: String,
+    ) -> Organization {
+        Organization {
+            alias,
+            created_at,
+            description: None,
+            domain: None,
+            enabled,
+            id,
+            name,
+            realm_id,
+            redirect_url: None,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrganizationAttribute {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "key")]
+    pub key: String,
+    #[serde(rename = "organization_id")]
+    pub organization_id: uuid::Uuid,
+    #[serde(rename = "value")]
+    pub value: String,
+}
+
+impl OrganizationAttribute {
+    pub fn new(
+        created_at: String,
+        id: uuid::Uuid,
+        key: String,
+        organization_id: uuid::Uuid,
+        value: String,
+    ) -> OrganizationAttribute {
+        OrganizationAttribute {
+            created_at,
+            id,
+            key,
+            organization_id,
+            value,
+        }
+    }
+}
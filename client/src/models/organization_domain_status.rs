@@ -0,0 +1,58 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// OrganizationDomainStatus : Admin view of the organization domain: the record to publish and the linked provider.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrganizationDomainStatus {
+    #[serde(rename = "domain")]
+    pub domain: String,
+    #[serde(
+        rename = "identity_provider_alias",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub identity_provider_alias: Option<Option<String>>,
+    #[serde(rename = "verification_record_name")]
+    pub verification_record_name: String,
+    #[serde(rename = "verification_record_value")]
+    pub verification_record_value: String,
+    #[serde(rename = "verified")]
+    pub verified: bool,
+    #[serde(
+        rename = "verified_at",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub verified_at: Option<Option<String>>,
+}
+
+impl OrganizationDomainStatus {
+    /// Admin view of the organization domain: the record to publish and the linked provider.
+    pub fn new(
+        domain: String,
+        verification_record_name: String,
+        verification_record_value: String,
+        verified: bool,
+    ) -> OrganizationDomainStatus {
+        OrganizationDomainStatus {
+            domain,
+            identity_provider_alias: None,
+            verification_record_name,
+            verification_record_value,
+            verified,
+            verified_at: None,
+        }
+    }
+}
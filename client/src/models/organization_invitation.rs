@@ -0,0 +1,98 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// OrganizationInvitation : An emailed invitation to join an organization, optionally with a member role and a group granted on acceptance. Only the hash of the invitation token is stored.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrganizationInvitation {
+    #[serde(
+        rename = "accepted_at",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub accepted_at: Option<Option<String>>,
+    #[serde(
+        rename = "accepted_by",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub accepted_by: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "email")]
+    pub email: String,
+    #[serde(rename = "expires_at")]
+    pub expires_at: String,
+    #[serde(
+        rename = "group_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub group_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(
+        rename = "invited_by",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub invited_by: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "organization_id")]
+    pub organization_id: uuid::Uuid,
+    #[serde(
+        rename = "revoked_at",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub revoked_at: Option<Option<String>>,
+    #[serde(
+        rename = "role_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub role_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "status")]
+    pub status: models::InvitationStatus,
+}
+
+impl OrganizationInvitation {
+    /// An emailed invitation to join an organization, optionally with a member role and a group granted on acceptance. Only the hash of the invitation token is stored.
+    pub fn new(
+        created_at: String,
+        email: String,
+        expires_at: String,
+        id: uuid::Uuid,
+        organization_id: uuid::Uuid,
+        status: models::InvitationStatus,
+    ) -> OrganizationInvitation {
+        OrganizationInvitation {
+            accepted_at: None,
+            accepted_by: None,
+            created_at,
+            email,
+            expires_at,
+            group_id: None,
+            id,
+            invited_by: None,
+            organization_id,
+            revoked_at: None,
+            role_id: None,
+            status,
+        }
+    }
+}
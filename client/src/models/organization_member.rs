@@ -0,0 +1,42 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// OrganizationMember : A user's membership in an organization
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrganizationMember {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "organization_id")]
+    pub organization_id: uuid::Uuid,
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+}
+
+impl OrganizationMember {
+    /// A user's membership in an organization
+    pub fn new(
+        created_at: String,
+        id: uuid::Uuid,
+        organization_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    ) -> OrganizationMember {
+        OrganizationMember {
+            created_at,
+            id,
+            organization_id,
+            user_id,
+        }
+    }
+}
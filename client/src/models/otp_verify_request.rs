@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -11,22 +11,18 @@
 use crate::models;
 use serde::{Deserialize, Serialize};
 
+/// OtpVerifyRequest : The TOTP secret is deliberately absent: it is read from the enrollment the server recorded during `setup-otp`. Accepting it here let a caller enroll a secret of their own choosing and verify a code against it (FK-003).
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OtpVerifyRequest {
     #[serde(rename = "code")]
     pub code: String,
     #[serde(rename = "label")]
     pub label: String,
-    #[serde(rename = "secret")]
-    pub secret: String,
 }
 
 impl OtpVerifyRequest {
-    pub fn new(code: String, label: String, secret: String) -> OtpVerifyRequest {
-        OtpVerifyRequest {
-            code,
-            label,
-            secret,
-        }
+    /// The TOTP secret is deliberately absent: it is read from the enrollment the server recorded during `setup-otp`. Accepting it here let a caller enroll a secret of their own choosing and verify a code against it (FK-003).
+    pub fn new(code: String, label: String) -> OtpVerifyRequest {
+        OtpVerifyRequest { code, label }
     }
 }
@@ -0,0 +1,79 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// OutboxEmail : An email persisted before delivery. The sender address comes from the realm SMTP configuration at delivery time, so a fixed configuration applies to queued emails too.  Bodies are never serialized: they carry password reset and magic link tokens, which the admin listing must not hand out.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEmail {
+    #[serde(rename = "attempts")]
+    pub attempts: i32,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    /// Error of the last failed attempt.
+    #[serde(
+        rename = "last_error",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_error: Option<Option<String>>,
+    #[serde(rename = "next_attempt_at")]
+    pub next_attempt_at: String,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "recipient")]
+    pub recipient: String,
+    #[serde(
+        rename = "sent_at",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sent_at: Option<Option<String>>,
+    #[serde(rename = "status")]
+    pub status: models::OutboxEmailStatus,
+    #[serde(rename = "subject")]
+    pub subject: String,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl OutboxEmail {
+    /// An email persisted before delivery. The sender address comes from the realm SMTP configuration at delivery time, so a fixed configuration applies to queued emails too.  Bodies are never serialized: they carry password reset and magic link tokens, which the admin listing must not hand out.
+    pub fn new(
+        attempts: i32,
+        created_at: String,
+        id: uuid::Uuid,
+        next_attempt_at: String,
+        realm_id: uuid::Uuid,
+        recipient: String,
+        status: models::OutboxEmailStatus,
+        subject: String,
+        updated_at: String,
+    ) -> OutboxEmail {
+        OutboxEmail {
+            attempts,
+            created_at,
+            id,
+            last_error: None,
+            next_attempt_at,
+            realm_id,
+            recipient,
+            sent_at: None,
+            status,
+            subject,
+            updated_at,
+        }
+    }
+}
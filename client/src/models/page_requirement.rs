@@ -0,0 +1,29 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PageRequirement {
+    #[serde(rename = "page_type")]
+    pub page_type: models::PortalPageType,
+    #[serde(rename = "required_blocks")]
+    pub required_blocks: Vec<String>,
+}
+
+impl PageRequirement {
+    pub fn new(page_type: models::PortalPageType, required_blocks: Vec<String>) -> PageRequirement {
+        PageRequirement {
+            page_type,
+            required_blocks,
+        }
+    }
+}
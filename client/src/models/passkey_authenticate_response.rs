@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PasskeyAuthenticateResponse {
+    #[serde(rename = "login_url")]
+    pub login_url: String,
+    #[serde(rename = "status")]
+    pub status: String,
+}
+
+impl PasskeyAuthenticateResponse {
+    pub fn new(login_url: String, status: String) -> PasskeyAuthenticateResponse {
+        PasskeyAuthenticateResponse { login_url, status }
+    }
+}
@@ -0,0 +1,83 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PasswordPolicy {
+    /// Interface flag — reject passwords reported as breached (requires external check; no network call is made when the provider is not wired up).
+    #[serde(rename = "check_breached")]
+    pub check_breached: bool,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    /// Reject passwords found in the embedded common-password list or matching username/email.
+    #[serde(rename = "forbid_common")]
+    pub forbid_common: bool,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(
+        rename = "max_age_days",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_age_days: Option<Option<i32>>,
+    /// Minimum Shannon entropy in bits (charset-pool model). CNIL 2022-100 default: 80.
+    #[serde(rename = "min_entropy_bits")]
+    pub min_entropy_bits: i32,
+    #[serde(rename = "min_length")]
+    pub min_length: i32,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "require_lowercase")]
+    pub require_lowercase: bool,
+    #[serde(rename = "require_number")]
+    pub require_number: bool,
+    #[serde(rename = "require_special")]
+    pub require_special: bool,
+    #[serde(rename = "require_uppercase")]
+    pub require_uppercase: bool,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl PasswordPolicy {
+    pub fn new(
+        check_breached: bool,
+        created_at: String,
+        forbid_common: bool,
+        id: uuid::Uuid,
+        min_entropy_bits: i32,
+        min_length: i32,
+        realm_id: uuid::Uuid,
+        require_lowercase: bool,
+        require_number: bool,
+        require_special: bool,
+        require_uppercase: bool,
+        updated_at: String,
+    ) -> PasswordPolicy {
+        PasswordPolicy {
+            check_breached,
+            created_at,
+            forbid_common,
+            id,
+            max_age_days: None,
+            min_entropy_bits,
+            min_length,
+            realm_id,
+            require_lowercase,
+            require_number,
+            require_special,
+            require_uppercase,
+            updated_at,
+        }
+    }
+}
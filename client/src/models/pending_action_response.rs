@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PendingActionResponse {
+    #[serde(rename = "message")]
+    pub message: String,
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+}
+
+impl PendingActionResponse {
+    pub fn new(message: String, user_id: uuid::Uuid) -> PendingActionResponse {
+        PendingActionResponse { message, user_id }
+    }
+}
@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// The step a pending brokered login waits on.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum PendingBrokerLoginStage {
+    #[serde(rename = "review_profile")]
+    #[default]
+    ReviewProfile,
+    #[serde(rename = "confirm_link")]
+    ConfirmLink,
+    #[serde(rename = "otp")]
+    Otp,
+}
+
+impl std::fmt::Display for PendingBrokerLoginStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ReviewProfile => write!(f, "review_profile"),
+            Self::ConfirmLink => write!(f, "confirm_link"),
+            Self::Otp => write!(f, "otp"),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// PendingBrokerLoginView : What the login page shows for a pending brokered login.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PendingBrokerLoginView {
+    #[serde(rename = "confirmation_methods")]
+    pub confirmation_methods: Vec<models::LinkConfirmationMethod>,
+    #[serde(
+        rename = "existing_account",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub existing_account: Option<Option<Box<models::ExistingAccountHint>>>,
+    #[serde(rename = "expires_at")]
+    pub expires_at: String,
+    #[serde(rename = "identity_provider_alias")]
+    pub identity_provider_alias: String,
+    #[serde(
+        rename = "identity_provider_display_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub identity_provider_display_name: Option<Option<String>>,
+    #[serde(rename = "missing_fields")]
+    pub missing_fields: Vec<models::ProfileField>,
+    #[serde(rename = "profile")]
+    pub profile: Box<models::BrokeredProfile>,
+    #[serde(rename = "required_fields")]
+    pub required_fields: Vec<models::ProfileField>,
+    #[serde(rename = "stage")]
+    pub stage: models::PendingBrokerLoginStage,
+    #[serde(rename = "username_taken")]
+    pub username_taken: bool,
+}
+
+impl PendingBrokerLoginView {
+    /// What the login page shows for a pending brokered login.
+    pub fn new(
+        confirmation_methods: Vec<models::LinkConfirmationMethod>,
+        expires_at: String,
+        identity_provider_alias: String,
+        missing_fields: Vec<models::ProfileField>,
+        profile: models::BrokeredProfile,
+        required_fields: Vec<models::ProfileField>,
+        stage: models::PendingBrokerLoginStage,
+        username_taken: bool,
+    ) -> PendingBrokerLoginView {
+        PendingBrokerLoginView {
+            confirmation_methods,
+            existing_account: None,
+            expires_at,
+            identity_provider_alias,
+            identity_provider_display_name: None,
+            missing_fields,
+            profile: Box::new(profile),
+            required_fields,
+            stage,
+            username_taken,
+        }
+    }
+}
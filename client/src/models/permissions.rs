@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -11,7 +11,6 @@
 use crate::models;
 use serde::{Deserialize, Serialize};
 
-///
 #[derive(
     Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
 )]
@@ -67,6 +66,10 @@ pub enum Permissions {
     QueryClientScopes,
     #[serde(rename = "view_client_scopes")]
     ViewClientScopes,
+    #[serde(rename = "manage_email_templates")]
+    ManageEmailTemplates,
+    #[serde(rename = "view_email_templates")]
+    ViewEmailTemplates,
 }
 
 impl std::fmt::Display for Permissions {
@@ -97,6 +100,8 @@ impl std::fmt::Display for Permissions {
             Self::ManageClientScopes => write!(f, "manage_client_scopes"),
             Self::QueryClientScopes => write!(f, "query_client_scopes"),
             Self::ViewClientScopes => write!(f, "view_client_scopes"),
+            Self::ManageEmailTemplates => write!(f, "manage_email_templates"),
+            Self::ViewEmailTemplates => write!(f, "view_email_templates"),
         }
     }
 }
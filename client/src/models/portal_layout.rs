@@ -0,0 +1,52 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PortalLayout {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "is_default")]
+    pub is_default: bool,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "tree")]
+    pub tree: serde_json::Value,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl PortalLayout {
+    pub fn new(
+        created_at: String,
+        id: uuid::Uuid,
+        is_default: bool,
+        name: String,
+        realm_id: uuid::Uuid,
+        tree: serde_json::Value,
+        updated_at: String,
+    ) -> PortalLayout {
+        PortalLayout {
+            created_at,
+            id,
+            is_default,
+            name,
+            realm_id,
+            tree,
+            updated_at,
+        }
+    }
+}
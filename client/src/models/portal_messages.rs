@@ -0,0 +1,38 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// PortalMessages : The texts the login portal renders, and the languages it can offer to switch to.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PortalMessages {
+    #[serde(rename = "locale")]
+    pub locale: String,
+    #[serde(rename = "messages")]
+    pub messages: std::collections::HashMap<String, String>,
+    #[serde(rename = "supported_locales")]
+    pub supported_locales: Vec<String>,
+}
+
+impl PortalMessages {
+    /// The texts the login portal renders, and the languages it can offer to switch to.
+    pub fn new(
+        locale: String,
+        messages: std::collections::HashMap<String, String>,
+        supported_locales: Vec<String>,
+    ) -> PortalMessages {
+        PortalMessages {
+            locale,
+            messages,
+            supported_locales,
+        }
+    }
+}
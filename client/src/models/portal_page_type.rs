@@ -0,0 +1,62 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum PortalPageType {
+    #[serde(rename = "login")]
+    #[default]
+    Login,
+    #[serde(rename = "register")]
+    Register,
+    #[serde(rename = "totp")]
+    Totp,
+    #[serde(rename = "forgot_password")]
+    ForgotPassword,
+    #[serde(rename = "reset_password")]
+    ResetPassword,
+    #[serde(rename = "magic_link_verify")]
+    MagicLinkVerify,
+    #[serde(rename = "magic_link_request")]
+    MagicLinkRequest,
+    #[serde(rename = "verify_email")]
+    VerifyEmail,
+    #[serde(rename = "email_verified")]
+    EmailVerified,
+    #[serde(rename = "totp_setup")]
+    TotpSetup,
+    #[serde(rename = "device_verify")]
+    DeviceVerify,
+    #[serde(rename = "device_verified")]
+    DeviceVerified,
+}
+
+impl std::fmt::Display for PortalPageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Login => write!(f, "login"),
+            Self::Register => write!(f, "register"),
+            Self::Totp => write!(f, "totp"),
+            Self::ForgotPassword => write!(f, "forgot_password"),
+            Self::ResetPassword => write!(f, "reset_password"),
+            Self::MagicLinkVerify => write!(f, "magic_link_verify"),
+            Self::MagicLinkRequest => write!(f, "magic_link_request"),
+            Self::VerifyEmail => write!(f, "verify_email"),
+            Self::EmailVerified => write!(f, "email_verified"),
+            Self::TotpSetup => write!(f, "totp_setup"),
+            Self::DeviceVerify => write!(f, "device_verify"),
+            Self::DeviceVerified => write!(f, "device_verified"),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PortalTheme {
+    #[serde(rename = "config")]
+    pub config: Box<models::PortalThemeConfig>,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(
+        rename = "layout_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub layout_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "pages")]
+    pub pages: Box<models::PortalThemePages>,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl PortalTheme {
+    pub fn new(
+        config: models::PortalThemeConfig,
+        created_at: String,
+        id: uuid::Uuid,
+        name: String,
+        pages: models::PortalThemePages,
+        realm_id: uuid::Uuid,
+        updated_at: String,
+    ) -> PortalTheme {
+        PortalTheme {
+            config: Box::new(config),
+            created_at,
+            id,
+            layout_id: None,
+            name,
+            pages: Box::new(pages),
+            realm_id,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PortalThemeConfig {
+    #[serde(rename = "borders", skip_serializing_if = "Option::is_none")]
+    pub borders: Option<Box<models::PortalThemeConfigBorders>>,
+    #[serde(rename = "colors", skip_serializing_if = "Option::is_none")]
+    pub colors: Option<Box<models::PortalThemeConfigColors>>,
+    #[serde(rename = "fonts", skip_serializing_if = "Option::is_none")]
+    pub fonts: Option<Box<models::PortalThemeConfigFonts>>,
+    #[serde(rename = "spacing", skip_serializing_if = "Option::is_none")]
+    pub spacing: Option<Box<models::PortalThemeConfigSpacing>>,
+}
+
+impl PortalThemeConfig {
+    pub fn new() -> PortalThemeConfig {
+        PortalThemeConfig {
+            borders: None,
+            colors: None,
+            fonts: None,
+            spacing: None,
+        }
+    }
+}
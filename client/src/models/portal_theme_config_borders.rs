@@ -0,0 +1,24 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PortalThemeConfigBorders {
+    ThemeBorders(Box<models::ThemeBorders>),
+}
+
+impl Default for PortalThemeConfigBorders {
+    fn default() -> Self {
+        Self::ThemeBorders(Default::default())
+    }
+}
@@ -0,0 +1,119 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PortalThemePages {
+    #[serde(
+        rename = "deviceVerified",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub device_verified: Option<Option<serde_json::Value>>,
+    #[serde(
+        rename = "deviceVerify",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub device_verify: Option<Option<serde_json::Value>>,
+    #[serde(
+        rename = "emailVerified",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email_verified: Option<Option<serde_json::Value>>,
+    #[serde(
+        rename = "forgotPassword",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub forgot_password: Option<Option<serde_json::Value>>,
+    #[serde(
+        rename = "login",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub login: Option<Option<serde_json::Value>>,
+    #[serde(
+        rename = "magicLinkRequest",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub magic_link_request: Option<Option<serde_json::Value>>,
+    #[serde(
+        rename = "magicLinkVerify",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub magic_link_verify: Option<Option<serde_json::Value>>,
+    #[serde(
+        rename = "register",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub register: Option<Option<serde_json::Value>>,
+    #[serde(
+        rename = "resetPassword",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reset_password: Option<Option<serde_json::Value>>,
+    #[serde(
+        rename = "totp",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub totp: Option<Option<serde_json::Value>>,
+    #[serde(
+        rename = "totpSetup",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub totp_setup: Option<Option<serde_json::Value>>,
+    #[serde(
+        rename = "verifyEmail",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub verify_email: Option<Option<serde_json::Value>>,
+}
+
+impl PortalThemePages {
+    pub fn new() -> PortalThemePages {
+        PortalThemePages {
+            device_verified: None,
+            device_verify: None,
+            email_verified: None,
+            forgot_password: None,
+            login: None,
+            magic_link_request: None,
+            magic_link_verify: None,
+            register: None,
+            reset_password: None,
+            totp: None,
+            totp_setup: None,
+            verify_email: None,
+        }
+    }
+}
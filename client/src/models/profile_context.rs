@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Where a profile is being written from.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum ProfileContext {
+    #[serde(rename = "registration")]
+    #[default]
+    Registration,
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "self_service")]
+    SelfService,
+}
+
+impl std::fmt::Display for ProfileContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Registration => write!(f, "registration"),
+            Self::Admin => write!(f, "admin"),
+            Self::SelfService => write!(f, "self_service"),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum ProfileField {
+    #[serde(rename = "username")]
+    #[default]
+    Username,
+    #[serde(rename = "email")]
+    Email,
+    #[serde(rename = "first_name")]
+    FirstName,
+    #[serde(rename = "last_name")]
+    LastName,
+}
+
+impl std::fmt::Display for ProfileField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Username => write!(f, "username"),
+            Self::Email => write!(f, "email"),
+            Self::FirstName => write!(f, "first_name"),
+            Self::LastName => write!(f, "last_name"),
+        }
+    }
+}
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,12 +13,26 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProviderResponse {
+    #[serde(
+        rename = "changed_sync_interval_minutes",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub changed_sync_interval_minutes: Option<Option<i32>>,
     #[serde(rename = "config", deserialize_with = "Option::deserialize")]
     pub config: Option<serde_json::Value>,
     #[serde(rename = "created_at")]
     pub created_at: String,
     #[serde(rename = "enabled")]
     pub enabled: bool,
+    #[serde(
+        rename = "full_sync_cron",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub full_sync_cron: Option<Option<String>>,
     #[serde(rename = "id")]
     pub id: String,
     #[serde(
@@ -73,9 +87,11 @@ impl ProviderResponse {
         updated_at: String,
     ) -> ProviderResponse {
         ProviderResponse {
+            changed_sync_interval_minutes: None,
             config,
             created_at,
             enabled,
+            full_sync_cron: None,
             id,
             last_sync_at: None,
             last_sync_status: None,
@@ -0,0 +1,46 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// PublicPasswordPolicy : A trimmed, anonymous-safe view of the realm password policy.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PublicPasswordPolicy {
+    #[serde(rename = "min_length")]
+    pub min_length: i32,
+    #[serde(rename = "require_lowercase")]
+    pub require_lowercase: bool,
+    #[serde(rename = "require_number")]
+    pub require_number: bool,
+    #[serde(rename = "require_special")]
+    pub require_special: bool,
+    #[serde(rename = "require_uppercase")]
+    pub require_uppercase: bool,
+}
+
+impl PublicPasswordPolicy {
+    /// A trimmed, anonymous-safe view of the realm password policy.
+    pub fn new(
+        min_length: i32,
+        require_lowercase: bool,
+        require_number: bool,
+        require_special: bool,
+        require_uppercase: bool,
+    ) -> PublicPasswordPolicy {
+        PublicPasswordPolicy {
+            min_length,
+            require_lowercase,
+            require_number,
+            require_special,
+            require_uppercase,
+        }
+    }
+}
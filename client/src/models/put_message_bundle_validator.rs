@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// PutMessageBundleValidator : Keys the bundle leaves out fall back to the built-in texts.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PutMessageBundleValidator {
+    #[serde(rename = "messages", skip_serializing_if = "Option::is_none")]
+    pub messages: Option<std::collections::HashMap<String, String>>,
+}
+
+impl PutMessageBundleValidator {
+    /// Keys the bundle leaves out fall back to the built-in texts.
+    pub fn new() -> PutMessageBundleValidator {
+        PutMessageBundleValidator { messages: None }
+    }
+}
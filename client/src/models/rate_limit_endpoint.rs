@@ -0,0 +1,42 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// The unauthenticated endpoints a realm can throttle.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum RateLimitEndpoint {
+    #[serde(rename = "token")]
+    #[default]
+    Token,
+    #[serde(rename = "authenticate")]
+    Authenticate,
+    #[serde(rename = "forgot_password")]
+    ForgotPassword,
+    #[serde(rename = "magic_link")]
+    MagicLink,
+    #[serde(rename = "device_verify")]
+    DeviceVerify,
+}
+
+impl std::fmt::Display for RateLimitEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Token => write!(f, "token"),
+            Self::Authenticate => write!(f, "authenticate"),
+            Self::ForgotPassword => write!(f, "forgot_password"),
+            Self::MagicLink => write!(f, "magic_link"),
+            Self::DeviceVerify => write!(f, "device_verify"),
+        }
+    }
+}
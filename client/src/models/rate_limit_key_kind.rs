@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// What a rate limit bucket is keyed by, on top of the endpoint.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum RateLimitKeyKind {
+    #[serde(rename = "ip")]
+    #[default]
+    Ip,
+    #[serde(rename = "username")]
+    Username,
+    #[serde(rename = "client_id")]
+    ClientId,
+}
+
+impl std::fmt::Display for RateLimitKeyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Ip => write!(f, "ip"),
+            Self::Username => write!(f, "username"),
+            Self::ClientId => write!(f, "client_id"),
+        }
+    }
+}
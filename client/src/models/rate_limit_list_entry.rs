@@ -0,0 +1,55 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RateLimitListEntry : An allow or deny list entry: a single address or a CIDR range.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitListEntry {
+    /// Normalized CIDR, e.g. `203.0.113.0/24` or `2001:db8::1/128`.
+    #[serde(rename = "cidr")]
+    pub cidr: String,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(
+        rename = "description",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Option<String>>,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "kind")]
+    pub kind: models::RateLimitListKind,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+}
+
+impl RateLimitListEntry {
+    /// An allow or deny list entry: a single address or a CIDR range.
+    pub fn new(
+        cidr: String,
+        created_at: String,
+        id: uuid::Uuid,
+        kind: models::RateLimitListKind,
+        realm_id: uuid::Uuid,
+    ) -> RateLimitListEntry {
+        RateLimitListEntry {
+            cidr,
+            created_at,
+            description: None,
+            id,
+            kind,
+            realm_id,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RateLimitOverview : The configured policies of a realm and the rules actually enforced.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitOverview {
+    #[serde(rename = "effective")]
+    pub effective: Vec<models::RateLimitRule>,
+    #[serde(rename = "policies")]
+    pub policies: Vec<models::RateLimitPolicy>,
+}
+
+impl RateLimitOverview {
+    /// The configured policies of a realm and the rules actually enforced.
+    pub fn new(
+        effective: Vec<models::RateLimitRule>,
+        policies: Vec<models::RateLimitPolicy>,
+    ) -> RateLimitOverview {
+        RateLimitOverview {
+            effective,
+            policies,
+        }
+    }
+}
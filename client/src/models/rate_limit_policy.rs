@@ -0,0 +1,70 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RateLimitPolicy : A rate limit rule configured by a realm administrator.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitPolicy {
+    #[serde(rename = "block_seconds")]
+    pub block_seconds: i32,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+    #[serde(rename = "endpoint")]
+    pub endpoint: models::RateLimitEndpoint,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "key_kind")]
+    pub key_kind: models::RateLimitKeyKind,
+    #[serde(rename = "limit")]
+    pub limit: i32,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "shared")]
+    pub shared: bool,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+    #[serde(rename = "window_seconds")]
+    pub window_seconds: i32,
+}
+
+impl RateLimitPolicy {
+    /// A rate limit rule configured by a realm administrator.
+    pub fn new(
+        block_seconds: i32,
+        created_at: String,
+        enabled: bool,
+        endpoint: models::RateLimitEndpoint,
+        id: uuid::Uuid,
+        key_kind: models::RateLimitKeyKind,
+        limit: i32,
+        realm_id: uuid::Uuid,
+        shared: bool,
+        updated_at: String,
+        window_seconds: i32,
+    ) -> RateLimitPolicy {
+        RateLimitPolicy {
+            block_seconds,
+            created_at,
+            enabled,
+            endpoint,
+            id,
+            key_kind,
+            limit,
+            realm_id,
+            shared,
+            updated_at,
+            window_seconds,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RateLimitRule : The budget of one endpoint for one key kind: `limit` requests per `window_seconds`, refilled continuously. Once exhausted the key is blocked for `block_seconds` (when non-zero) before the budget refills.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitRule {
+    #[serde(rename = "block_seconds")]
+    pub block_seconds: i32,
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+    #[serde(rename = "endpoint")]
+    pub endpoint: models::RateLimitEndpoint,
+    #[serde(rename = "key_kind")]
+    pub key_kind: models::RateLimitKeyKind,
+    #[serde(rename = "limit")]
+    pub limit: i32,
+    /// Count hits in Postgres so every replica shares the budget, instead of in memory.
+    #[serde(rename = "shared")]
+    pub shared: bool,
+    #[serde(rename = "window_seconds")]
+    pub window_seconds: i32,
+}
+
+impl RateLimitRule {
+    /// The budget of one endpoint for one key kind: `limit` requests per `window_seconds`, refilled continuously. Once exhausted the key is blocked for `block_seconds` (when non-zero) before the budget refills.
+    pub fn new(
+        block_seconds: i32,
+        enabled: bool,
+        endpoint: models::RateLimitEndpoint,
+        key_kind: models::RateLimitKeyKind,
+        limit: i32,
+        shared: bool,
+        window_seconds: i32,
+    ) -> RateLimitRule {
+        RateLimitRule {
+            block_seconds,
+            enabled,
+            endpoint,
+            key_kind,
+            limit,
+            shared,
+            window_seconds,
+        }
+    }
+}
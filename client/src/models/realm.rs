@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -15,6 +15,14 @@ use serde::{Deserialize, Serialize};
 pub struct Realm {
     #[serde(rename = "created_at")]
     pub created_at: String,
+    /// Human-readable label shown in the login and admin UI.  Unlike `name` (which is the URL slug and must stay URL-safe), this can contain whitespace and be changed freely without breaking realm URLs. Falls back to `name` when unset.
+    #[serde(
+        rename = "display_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub display_name: Option<Option<String>>,
     #[serde(rename = "id")]
     pub id: uuid::Uuid,
     #[serde(rename = "name")]
@@ -34,6 +42,7 @@ impl Realm {
     pub fn new(created_at: String, id: uuid::Uuid, name: String, updated_at: String) -> Realm {
         Realm {
             created_at,
+            display_name: None,
             id,
             name,
             settings: None,
@@ -0,0 +1,46 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RealmGroupAttribute : A key/value attribute on a group, inherited by members as a user attribute.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RealmGroupAttribute {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "group_id")]
+    pub group_id: uuid::Uuid,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "key")]
+    pub key: String,
+    #[serde(rename = "value")]
+    pub value: String,
+}
+
+impl RealmGroupAttribute {
+    /// A key/value attribute on a group, inherited by members as a user attribute.
+    pub fn new(
+        created_at: String,
+        group_id: uuid::Uuid,
+        id: uuid::Uuid,
+        key: String,
+        value: String,
+    ) -> RealmGroupAttribute {
+        RealmGroupAttribute {
+            created_at,
+            group_id,
+            id,
+            key,
+            value,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RealmGroupMember : A user's direct membership in a realm group.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RealmGroupMember {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "group_id")]
+    pub group_id: uuid::Uuid,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+}
+
+impl RealmGroupMember {
+    /// A user's direct membership in a realm group.
+    pub fn new(
+        created_at: String,
+        group_id: uuid::Uuid,
+        id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    ) -> RealmGroupMember {
+        RealmGroupMember {
+            created_at,
+            group_id,
+            id,
+            user_id,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RealmGroupMemberDetail : A group member enriched with the user's identity fields, for the admin members list.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RealmGroupMemberDetail {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(
+        rename = "email",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email: Option<Option<String>>,
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+    #[serde(
+        rename = "firstname",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub firstname: Option<Option<String>>,
+    #[serde(rename = "group_id")]
+    pub group_id: uuid::Uuid,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(
+        rename = "lastname",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lastname: Option<Option<String>>,
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+    #[serde(rename = "username")]
+    pub username: String,
+}
+
+impl RealmGroupMemberDetail {
+    /// A group member enriched with the user's identity fields, for the admin members list.
+    pub fn new(
+        created_at: String,
+        enabled: bool,
+        group_id: uuid::Uuid,
+        id: uuid::Uuid,
+        user_id: uuid::Uuid,
+        username: String,
+    ) -> RealmGroupMemberDetail {
+        RealmGroupMemberDetail {
+            created_at,
+            email: None,
+            enabled,
+            firstname: None,
+            group_id,
+            id,
+            lastname: None,
+            user_id,
+            username,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RealmGroupMemberPage : A page of group members plus the total count (for server-side pagination).
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RealmGroupMemberPage {
+    #[serde(rename = "data")]
+    pub data: Vec<models::RealmGroupMemberDetail>,
+    #[serde(rename = "limit")]
+    pub limit: i32,
+    #[serde(rename = "offset")]
+    pub offset: i32,
+    #[serde(rename = "total")]
+    pub total: i64,
+}
+
+impl RealmGroupMemberPage {
+    /// A page of group members plus the total count (for server-side pagination).
+    pub fn new(
+        data: Vec<models::RealmGroupMemberDetail>,
+        limit: i32,
+        offset: i32,
+        total: i64,
+    ) -> RealmGroupMemberPage {
+        RealmGroupMemberPage {
+            data,
+            limit,
+            offset,
+            total,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RealmGroupMembership : What a user gets from realm groups: every group in their effective membership, the roles inherited through them and the attributes those groups contribute.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RealmGroupMembership {
+    #[serde(rename = "attributes")]
+    pub attributes: std::collections::HashMap<String, String>,
+    #[serde(rename = "groups")]
+    pub groups: Vec<models::EffectiveRealmGroup>,
+    #[serde(rename = "inherited_roles")]
+    pub inherited_roles: Vec<models::Role>,
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+}
+
+impl RealmGroupMembership {
+    /// What a user gets from realm groups: every group in their effective membership, the roles inherited through them and the attributes those groups contribute.
+    pub fn new(
+        attributes: std::collections::HashMap<String, String>,
+        groups: Vec<models::EffectiveRealmGroup>,
+        inherited_roles: Vec<models::Role>,
+        user_id: uuid::Uuid,
+    ) -> RealmGroupMembership {
+        RealmGroupMembership {
+            attributes,
+            groups,
+            inherited_roles,
+            user_id,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RealmGroupNode : A node in the group tree, used for hierarchical responses.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RealmGroupNode {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(
+        rename = "description",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Option<String>>,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(
+        rename = "parent_group_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub parent_group_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+    /// Breaks the self-referential schema so utoipa's OpenAPI generation doesn't recurse forever.
+    #[serde(rename = "children")]
+    pub children: Vec<models::RealmGroupNode>,
+}
+
+impl RealmGroupNode {
+    /// A node in the group tree, used for hierarchical responses.
+    pub fn new(
+        created_at: String,
+        id: uuid::Uuid,
+        name: String,
+        realm_id: uuid::Uuid,
+        updated_at: String,
+        children: Vec<models::RealmGroupNode>,
+    ) -> RealmGroupNode {
+        RealmGroupNode {
+            created_at,
+            description: None,
+            id,
+            name,
+            parent_group_id: None,
+            realm_id,
+            updated_at,
+            children,
+        }
+    }
+}
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,39 +13,63 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RealmLoginSetting {
+    #[serde(
+        rename = "display_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub display_name: Option<Option<String>>,
+    #[serde(rename = "email_verification_enabled")]
+    pub email_verification_enabled: bool,
     #[serde(rename = "forgot_password_enabled")]
     pub forgot_password_enabled: bool,
     #[serde(rename = "identity_providers")]
     pub identity_providers: Vec<models::IdentityProviderPresentation>,
+    #[serde(rename = "login_aliases")]
+    pub login_aliases: Vec<models::LoginAlias>,
     #[serde(rename = "magic_link_enabled")]
     pub magic_link_enabled: bool,
     #[serde(rename = "magic_link_ttl")]
     pub magic_link_ttl: i32,
+    #[serde(rename = "name")]
+    pub name: String,
     #[serde(rename = "passkey_enabled")]
     pub passkey_enabled: bool,
     #[serde(rename = "remember_me_enabled")]
     pub remember_me_enabled: bool,
+    #[serde(rename = "theme")]
+    pub theme: Box<models::PortalThemeConfig>,
     #[serde(rename = "user_registration_enabled")]
     pub user_registration_enabled: bool,
 }
 
 impl RealmLoginSetting {
     pub fn new(
+        email_verification_enabled: bool,
         forgot_password_enabled: bool,
         identity_providers: Vec<models::IdentityProviderPresentation>,
+        login_aliases: Vec<models::LoginAlias>,
         magic_link_enabled: bool,
         magic_link_ttl: i32,
+        name: String,
         passkey_enabled: bool,
         remember_me_enabled: bool,
+        theme: models::PortalThemeConfig,
         user_registration_enabled: bool,
     ) -> RealmLoginSetting {
         RealmLoginSetting {
+            display_name: None,
+            email_verification_enabled,
             forgot_password_enabled,
             identity_providers,
+            login_aliases,
             magic_link_enabled,
             magic_link_ttl,
+            name,
             passkey_enabled,
             remember_me_enabled,
+            theme: Box::new(theme),
             user_registration_enabled,
         }
     }
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,6 +13,10 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RealmSetting {
+    #[serde(rename = "access_token_lifetime")]
+    pub access_token_lifetime: i64,
+    #[serde(rename = "compass_enabled")]
+    pub compass_enabled: bool,
     #[serde(
         rename = "default_signing_algorithm",
         default,
@@ -20,18 +24,68 @@ pub struct RealmSetting {
         skip_serializing_if = "Option::is_none"
     )]
     pub default_signing_algorithm: Option<Option<String>>,
+    #[serde(rename = "email_verification_enabled")]
+    pub email_verification_enabled: bool,
+    #[serde(
+        rename = "email_verification_template_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email_verification_template_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "email_verification_ttl_hours")]
+    pub email_verification_ttl_hours: i64,
     #[serde(rename = "forgot_password_enabled")]
     pub forgot_password_enabled: bool,
     #[serde(rename = "id")]
     pub id: uuid::Uuid,
+    #[serde(rename = "id_token_lifetime")]
+    pub id_token_lifetime: i64,
+    #[serde(rename = "lockout_duration_seconds")]
+    pub lockout_duration_seconds: i32,
+    #[serde(rename = "lockout_threshold")]
+    pub lockout_threshold: i32,
+    #[serde(rename = "login_aliases")]
+    pub login_aliases: Vec<models::LoginAlias>,
     #[serde(rename = "magic_link_enabled")]
     pub magic_link_enabled: bool,
+    #[serde(
+        rename = "magic_link_template_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub magic_link_template_id: Option<Option<uuid::Uuid>>,
     #[serde(rename = "magic_link_ttl")]
     pub magic_link_ttl: i32,
+    #[serde(
+        rename = "organization_invitation_template_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub organization_invitation_template_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "passkey_enabled")]
+    pub passkey_enabled: bool,
     #[serde(rename = "realm_id")]
     pub realm_id: uuid::Uuid,
+    #[serde(rename = "refresh_token_lifetime")]
+    pub refresh_token_lifetime: i64,
     #[serde(rename = "remember_me_enabled")]
     pub remember_me_enabled: bool,
+    #[serde(rename = "require_mfa")]
+    pub require_mfa: bool,
+    #[serde(
+        rename = "reset_password_template_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reset_password_template_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "seawatch_pii_mode")]
+    pub seawatch_pii_mode: String,
+    #[serde(rename = "temporary_token_lifetime")]
+    pub temporary_token_lifetime: i64,
     #[serde(rename = "updated_at")]
     pub updated_at: String,
     #[serde(rename = "user_registration_enabled")]
@@ -40,23 +94,53 @@ pub struct RealmSetting {
 
 impl RealmSetting {
     pub fn new(
+        access_token_lifetime: i64,
+        compass_enabled: bool,
+        email_verification_enabled: bool,
+        email_verification_ttl_hours: i64,
         forgot_password_enabled: bool,
         id: uuid::Uuid,
+        id_token_lifetime: i64,
+        lockout_duration_seconds: i32,
+        lockout_threshold: i32,
+        login_aliases: Vec<models::LoginAlias>,
         magic_link_enabled: bool,
         magic_link_ttl: i32,
+        passkey_enabled: bool,
         realm_id: uuid::Uuid,
+        refresh_token_lifetime: i64,
         remember_me_enabled: bool,
+        require_mfa: bool,
+        seawatch_pii_mode: String,
+        temporary_token_lifetime: i64,
         updated_at: String,
         user_registration_enabled: bool,
     ) -> RealmSetting {
         RealmSetting {
+            access_token_lifetime,
+            compass_enabled,
             default_signing_algorithm: None,
+            email_verification_enabled,
+            email_verification_template_id: None,
+            email_verification_ttl_hours,
             forgot_password_enabled,
             id,
+            id_token_lifetime,
+            lockout_duration_seconds,
+            lockout_threshold,
+            login_aliases,
             magic_link_enabled,
+            magic_link_template_id: None,
             magic_link_ttl,
+            organization_invitation_template_id: None,
+            passkey_enabled,
             realm_id,
+            refresh_token_lifetime,
             remember_me_enabled,
+            require_mfa,
+            reset_password_template_id: None,
+            seawatch_pii_mode,
+            temporary_token_lifetime,
             updated_at,
             user_registration_enabled,
         }
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,6 +13,9 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RegistrationRequest {
+    /// Values for the attributes of the realm's user profile, keyed by attribute name.
+    #[serde(rename = "attributes", skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "email", skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     #[serde(
@@ -38,6 +41,7 @@ pub struct RegistrationRequest {
 impl RegistrationRequest {
     pub fn new() -> RegistrationRequest {
         RegistrationRequest {
+            attributes: None,
             email: None,
             first_name: None,
             last_name: None,
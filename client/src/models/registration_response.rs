@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RegistrationResponse {
+    RegistrationResponseOneOf(Box<models::RegistrationResponseOneOf>),
+    RegistrationResponseOneOf1(Box<models::RegistrationResponseOneOf1>),
+    RegistrationResponseOneOf2(Box<models::RegistrationResponseOneOf2>),
+}
+
+impl Default for RegistrationResponse {
+    fn default() -> Self {
+        Self::RegistrationResponseOneOf(Default::default())
+    }
+}
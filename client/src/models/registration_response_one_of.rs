@@ -0,0 +1,37 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RegistrationResponseOneOf {
+    #[serde(rename = "data")]
+    pub data: Box<models::JwtToken>,
+    #[serde(rename = "status")]
+    pub status: Status,
+}
+
+impl RegistrationResponseOneOf {
+    pub fn new(data: models::JwtToken, status: Status) -> RegistrationResponseOneOf {
+        RegistrationResponseOneOf {
+            data: Box::new(data),
+            status,
+        }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Status {
+    #[serde(rename = "authenticated")]
+    #[default]
+    Authenticated,
+}
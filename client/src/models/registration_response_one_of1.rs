@@ -0,0 +1,40 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RegistrationResponseOneOf1 {
+    #[serde(rename = "data")]
+    pub data: Box<models::RedirectRegistrationResponse>,
+    #[serde(rename = "status")]
+    pub status: Status,
+}
+
+impl RegistrationResponseOneOf1 {
+    pub fn new(
+        data: models::RedirectRegistrationResponse,
+        status: Status,
+    ) -> RegistrationResponseOneOf1 {
+        RegistrationResponseOneOf1 {
+            data: Box::new(data),
+            status,
+        }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Status {
+    #[serde(rename = "redirect")]
+    #[default]
+    Redirect,
+}
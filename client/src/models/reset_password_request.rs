@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResetPasswordRequest {
+    #[serde(rename = "new_password")]
+    pub new_password: String,
+    #[serde(rename = "token")]
+    pub token: String,
+    #[serde(rename = "token_id")]
+    pub token_id: uuid::Uuid,
+}
+
+impl ResetPasswordRequest {
+    pub fn new(new_password: String, token: String, token_id: uuid::Uuid) -> ResetPasswordRequest {
+        ResetPasswordRequest {
+            new_password,
+            token,
+            token_id,
+        }
+    }
+}
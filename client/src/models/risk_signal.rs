@@ -0,0 +1,30 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RiskSignal : Something unusual about a login, compared with the user's history.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RiskSignal {
+    /// The device fingerprint was never seen for this user.
+    RiskSignalOneOf(Box<models::RiskSignalOneOf>),
+    /// The login comes from a country never seen for this user.
+    RiskSignalOneOf1(Box<models::RiskSignalOneOf1>),
+    /// The user would have travelled from the previous login's location faster than the realm's maximum travel speed.
+    RiskSignalOneOf2(Box<models::RiskSignalOneOf2>),
+}
+
+impl Default for RiskSignal {
+    fn default() -> Self {
+        Self::RiskSignalOneOf(Default::default())
+    }
+}
@@ -0,0 +1,34 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RiskSignalOneOf : The device fingerprint was never seen for this user.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RiskSignalOneOf {
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl RiskSignalOneOf {
+    /// The device fingerprint was never seen for this user.
+    pub fn new(r#type: Type) -> RiskSignalOneOf {
+        RiskSignalOneOf { r#type }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "new_device")]
+    #[default]
+    NewDevice,
+}
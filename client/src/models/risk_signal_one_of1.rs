@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RiskSignalOneOf1 : The login comes from a country never seen for this user.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RiskSignalOneOf1 {
+    #[serde(rename = "country")]
+    pub country: String,
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl RiskSignalOneOf1 {
+    /// The login comes from a country never seen for this user.
+    pub fn new(country: String, r#type: Type) -> RiskSignalOneOf1 {
+        RiskSignalOneOf1 { country, r#type }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "new_country")]
+    #[default]
+    NewCountry,
+}
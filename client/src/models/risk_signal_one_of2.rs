@@ -0,0 +1,50 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RiskSignalOneOf2 : The user would have travelled from the previous login's location faster than the realm's maximum travel speed.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RiskSignalOneOf2 {
+    #[serde(rename = "distance_km")]
+    pub distance_km: i32,
+    #[serde(
+        rename = "from_country",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub from_country: Option<Option<String>>,
+    #[serde(rename = "speed_kmh")]
+    pub speed_kmh: i32,
+    #[serde(rename = "type")]
+    pub r#type: Type,
+}
+
+impl RiskSignalOneOf2 {
+    /// The user would have travelled from the previous login's location faster than the realm's maximum travel speed.
+    pub fn new(distance_km: i32, speed_kmh: i32, r#type: Type) -> RiskSignalOneOf2 {
+        RiskSignalOneOf2 {
+            distance_km,
+            from_country: None,
+            speed_kmh,
+            r#type,
+        }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum Type {
+    #[serde(rename = "impossible_travel")]
+    #[default]
+    ImpossibleTravel,
+}
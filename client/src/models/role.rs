@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -44,6 +44,8 @@ pub struct Role {
     pub permissions: Vec<String>,
     #[serde(rename = "realm_id")]
     pub realm_id: uuid::Uuid,
+    #[serde(rename = "require_mfa")]
+    pub require_mfa: bool,
     #[serde(rename = "updated_at")]
     pub updated_at: String,
 }
@@ -55,6 +57,7 @@ impl Role {
         name: String,
         permissions: Vec<String>,
         realm_id: uuid::Uuid,
+        require_mfa: bool,
         updated_at: String,
     ) -> Role {
         Role {
@@ -66,6 +69,7 @@ impl Role {
             name,
             permissions,
             realm_id,
+            require_mfa,
             updated_at,
         }
     }
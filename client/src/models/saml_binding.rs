@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum SamlBinding {
+    #[serde(rename = "http_redirect")]
+    #[default]
+    HttpRedirect,
+    #[serde(rename = "http_post")]
+    HttpPost,
+}
+
+impl std::fmt::Display for SamlBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::HttpRedirect => write!(f, "http_redirect"),
+            Self::HttpPost => write!(f, "http_post"),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// SamlClientSettings : SAML settings of a client whose `protocol` is `saml`. The client's `client_id` is the service provider's entity ID.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SamlClientSettings {
+    /// Assertion consumer services; the first one is used when a request names none.
+    #[serde(rename = "assertion_consumer_services")]
+    pub assertion_consumer_services: Vec<models::SamlEndpoint>,
+    #[serde(rename = "assertion_lifetime_seconds")]
+    pub assertion_lifetime_seconds: i64,
+    #[serde(rename = "client_id")]
+    pub client_id: uuid::Uuid,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "encrypt_assertions")]
+    pub encrypt_assertions: bool,
+    /// Certificate assertions are encrypted for (base64 DER).
+    #[serde(
+        rename = "encryption_certificate",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub encryption_certificate: Option<Option<String>>,
+    #[serde(
+        rename = "idp_initiated_relay_state",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub idp_initiated_relay_state: Option<Option<String>>,
+    /// Enables IdP-initiated login at `/protocol/saml/clients/{idp_initiated_sso_url_name}`.
+    #[serde(
+        rename = "idp_initiated_sso_url_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub idp_initiated_sso_url_name: Option<Option<String>>,
+    #[serde(rename = "name_id_format")]
+    pub name_id_format: models::NameIdFormat,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    /// Reject authentication and logout requests that are not signed with `signing_certificate`.
+    #[serde(rename = "require_signed_requests")]
+    pub require_signed_requests: bool,
+    #[serde(rename = "sign_assertions")]
+    pub sign_assertions: bool,
+    #[serde(rename = "sign_response")]
+    pub sign_response: bool,
+    /// Certificate the service provider signs its requests with (base64 DER).
+    #[serde(
+        rename = "signing_certificate",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub signing_certificate: Option<Option<String>>,
+    #[serde(
+        rename = "single_logout_service",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub single_logout_service: Option<Option<Box<models::SamlEndpoint>>>,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl SamlClientSettings {
+    /// SAML settings of a client whose `protocol` is `saml`. The client's `client_id` is the service provider's entity ID.
+    pub fn new(
+        assertion_consumer_services: Vec<models::SamlEndpoint>,
+        assertion_lifetime_seconds: i64,
+        client_id: uuid::Uuid,
+        created_at: String,
+        encrypt_assertions: bool,
+        name_id_format: models::NameIdFormat,
+        realm_id: uuid::Uuid,
+        require_signed_requests: bool,
+        sign_assertions: bool,
+        sign_response: bool,
+        updated_at: String,
+    ) -> SamlClientSettings {
+        SamlClientSettings {
+            assertion_consumer_services,
+            assertion_lifetime_seconds,
+            client_id,
+            created_at,
+            encrypt_assertions,
+            encryption_certificate: None,
+            idp_initiated_relay_state: None,
+            idp_initiated_sso_url_name: None,
+            name_id_format,
+            realm_id,
+            require_signed_requests,
+            sign_assertions,
+            sign_response,
+            signing_certificate: None,
+            single_logout_service: None,
+            updated_at,
+        }
+    }
+}
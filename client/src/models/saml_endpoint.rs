@@ -0,0 +1,28 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// SamlEndpoint : A service provider endpoint, as listed in its metadata.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SamlEndpoint {
+    #[serde(rename = "binding")]
+    pub binding: models::SamlBinding,
+    #[serde(rename = "location")]
+    pub location: String,
+}
+
+impl SamlEndpoint {
+    /// A service provider endpoint, as listed in its metadata.
+    pub fn new(binding: models::SamlBinding, location: String) -> SamlEndpoint {
+        SamlEndpoint { binding, location }
+    }
+}
@@ -0,0 +1,66 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// SamlMessageParams : A SAML protocol message, as query parameters (HTTP-Redirect) or form fields (HTTP-POST).
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SamlMessageParams {
+    #[serde(
+        rename = "RelayState",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub relay_state: Option<Option<String>>,
+    #[serde(
+        rename = "SAMLRequest",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub saml_request: Option<Option<String>>,
+    #[serde(
+        rename = "SAMLResponse",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub saml_response: Option<Option<String>>,
+    /// HTTP-Redirect binding only; verified against the raw query string.
+    #[serde(
+        rename = "SigAlg",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sig_alg: Option<Option<String>>,
+    #[serde(
+        rename = "Signature",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub signature: Option<Option<String>>,
+}
+
+impl SamlMessageParams {
+    /// A SAML protocol message, as query parameters (HTTP-Redirect) or form fields (HTTP-POST).
+    pub fn new() -> SamlMessageParams {
+        SamlMessageParams {
+            relay_state: None,
+            saml_request: None,
+            saml_response: None,
+            sig_alg: None,
+            signature: None,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleMaintenanceWindowValidator {
+    /// When the window starts being announced on the login page. Defaults to now.
+    #[serde(
+        rename = "announce_at",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub announce_at: Option<Option<String>>,
+    /// The client to put under maintenance; omit it for every client of the realm.
+    #[serde(
+        rename = "client_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "ends_at")]
+    pub ends_at: String,
+    #[serde(rename = "reason")]
+    pub reason: String,
+    #[serde(
+        rename = "session_strategy",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub session_strategy: Option<Option<models::MaintenanceSessionStrategy>>,
+    #[serde(rename = "starts_at")]
+    pub starts_at: String,
+}
+
+impl ScheduleMaintenanceWindowValidator {
+    pub fn new(
+        ends_at: String,
+        reason: String,
+        starts_at: String,
+    ) -> ScheduleMaintenanceWindowValidator {
+        ScheduleMaintenanceWindowValidator {
+            announce_at: None,
+            client_id: None,
+            ends_at,
+            reason,
+            session_strategy: None,
+            starts_at,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum ScopeType {
+    #[serde(rename = "NONE")]
+    #[default]
+    None,
+    #[serde(rename = "OPTIONAL")]
+    Optional,
+    #[serde(rename = "DEFAULT")]
+    Default,
+}
+
+impl std::fmt::Display for ScopeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "NONE"),
+            Self::Optional => write!(f, "OPTIONAL"),
+            Self::Default => write!(f, "DEFAULT"),
+        }
+    }
+}
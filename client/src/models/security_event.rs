@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -34,6 +34,14 @@ pub struct SecurityEvent {
         skip_serializing_if = "Option::is_none"
     )]
     pub details: Option<Option<serde_json::Value>>,
+    /// SHA-256 of the canonical preimage (hex). `None` for legacy rows without hashes.
+    #[serde(
+        rename = "event_hash",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub event_hash: Option<Option<Vec<i32>>>,
     #[serde(rename = "event_type")]
     pub event_type: models::SecurityEventType,
     #[serde(rename = "id")]
@@ -45,6 +53,14 @@ pub struct SecurityEvent {
         skip_serializing_if = "Option::is_none"
     )]
     pub ip_address: Option<Option<String>>,
+    /// Previous event's `event_hash` in the realm chain (hex-decoded). Genesis = `[0u8; 32]`.
+    #[serde(
+        rename = "prev_hash",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub prev_hash: Option<Option<Vec<i32>>>,
     #[serde(rename = "realm_id")]
     pub realm_id: uuid::Uuid,
     #[serde(
@@ -100,9 +116,11 @@ impl SecurityEvent {
             actor_id: None,
             actor_type: None,
             details: None,
+            event_hash: None,
             event_type,
             id,
             ip_address: None,
+            prev_hash: None,
             realm_id,
             resource: None,
             status,
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -11,7 +11,6 @@
 use crate::models;
 use serde::{Deserialize, Serialize};
 
-///
 #[derive(
     Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
 )]
@@ -23,8 +22,14 @@ pub enum SecurityEventType {
     LoginFailure,
     #[serde(rename = "password_reset")]
     PasswordReset,
+    #[serde(rename = "password_reset_requested")]
+    PasswordResetRequested,
+    #[serde(rename = "password_reset_completed")]
+    PasswordResetCompleted,
     #[serde(rename = "user_created")]
     UserCreated,
+    #[serde(rename = "user_email_verified")]
+    UserEmailVerified,
     #[serde(rename = "user_deleted")]
     UserDeleted,
     #[serde(rename = "role_assigned")]
@@ -41,8 +46,32 @@ pub enum SecurityEventType {
     ClientDeleted,
     #[serde(rename = "client_secret_rotated")]
     ClientSecretRotated,
+    #[serde(rename = "client_secret_viewed")]
+    ClientSecretViewed,
     #[serde(rename = "realm_config_changed")]
     RealmConfigChanged,
+    #[serde(rename = "email_not_sent")]
+    EmailNotSent,
+    #[serde(rename = "email_sent")]
+    EmailSent,
+    #[serde(rename = "client_maintenance_enabled")]
+    ClientMaintenanceEnabled,
+    #[serde(rename = "client_maintenance_disabled")]
+    ClientMaintenanceDisabled,
+    #[serde(rename = "session_created")]
+    SessionCreated,
+    #[serde(rename = "session_revoked")]
+    SessionRevoked,
+    #[serde(rename = "identity_provider_link_removed")]
+    IdentityProviderLinkRemoved,
+    #[serde(rename = "rate_limit_exceeded")]
+    RateLimitExceeded,
+    #[serde(rename = "deny_list_blocked")]
+    DenyListBlocked,
+    #[serde(rename = "login_risk_evaluated")]
+    LoginRiskEvaluated,
+    #[serde(rename = "login_risk_blocked")]
+    LoginRiskBlocked,
 }
 
 impl std::fmt::Display for SecurityEventType {
@@ -51,7 +80,10 @@ impl std::fmt::Display for SecurityEventType {
             Self::LoginSuccess => write!(f, "login_success"),
             Self::LoginFailure => write!(f, "login_failure"),
             Self::PasswordReset => write!(f, "password_reset"),
+            Self::PasswordResetRequested => write!(f, "password_reset_requested"),
+            Self::PasswordResetCompleted => write!(f, "password_reset_completed"),
             Self::UserCreated => write!(f, "user_created"),
+            Self::UserEmailVerified => write!(f, "user_email_verified"),
             Self::UserDeleted => write!(f, "user_deleted"),
             Self::RoleAssigned => write!(f, "role_assigned"),
             Self::RoleUnassigned => write!(f, "role_unassigned"),
@@ -60,7 +92,19 @@ impl std::fmt::Display for SecurityEventType {
             Self::ClientCreated => write!(f, "client_created"),
             Self::ClientDeleted => write!(f, "client_deleted"),
             Self::ClientSecretRotated => write!(f, "client_secret_rotated"),
+            Self::ClientSecretViewed => write!(f, "client_secret_viewed"),
             Self::RealmConfigChanged => write!(f, "realm_config_changed"),
+            Self::EmailNotSent => write!(f, "email_not_sent"),
+            Self::EmailSent => write!(f, "email_sent"),
+            Self::ClientMaintenanceEnabled => write!(f, "client_maintenance_enabled"),
+            Self::ClientMaintenanceDisabled => write!(f, "client_maintenance_disabled"),
+            Self::SessionCreated => write!(f, "session_created"),
+            Self::SessionRevoked => write!(f, "session_revoked"),
+            Self::IdentityProviderLinkRemoved => write!(f, "identity_provider_link_removed"),
+            Self::RateLimitExceeded => write!(f, "rate_limit_exceeded"),
+            Self::DenyListBlocked => write!(f, "deny_list_blocked"),
+            Self::LoginRiskEvaluated => write!(f, "login_risk_evaluated"),
+            Self::LoginRiskBlocked => write!(f, "login_risk_blocked"),
         }
     }
 }
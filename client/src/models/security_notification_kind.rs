@@ -0,0 +1,48 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Emails telling a user that something security-sensitive happened to their account.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum SecurityNotificationKind {
+    #[serde(rename = "password_changed")]
+    #[default]
+    PasswordChanged,
+    #[serde(rename = "new_sign_in")]
+    NewSignIn,
+    #[serde(rename = "account_locked")]
+    AccountLocked,
+    #[serde(rename = "mfa_factor_added")]
+    MfaFactorAdded,
+    #[serde(rename = "mfa_factor_removed")]
+    MfaFactorRemoved,
+    #[serde(rename = "email_changed")]
+    EmailChanged,
+    #[serde(rename = "identity_provider_linked")]
+    IdentityProviderLinked,
+}
+
+impl std::fmt::Display for SecurityNotificationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::PasswordChanged => write!(f, "password_changed"),
+            Self::NewSignIn => write!(f, "new_sign_in"),
+            Self::AccountLocked => write!(f, "account_locked"),
+            Self::MfaFactorAdded => write!(f, "mfa_factor_added"),
+            Self::MfaFactorRemoved => write!(f, "mfa_factor_removed"),
+            Self::EmailChanged => write!(f, "email_changed"),
+            Self::IdentityProviderLinked => write!(f, "identity_provider_linked"),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// SecurityNotificationSetting : How a realm sends one kind of security notification.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SecurityNotificationSetting {
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+    #[serde(rename = "kind")]
+    pub kind: models::SecurityNotificationKind,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    /// Email template of the matching type; the built-in MJML is used without one.
+    #[serde(
+        rename = "template_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub template_id: Option<Option<uuid::Uuid>>,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl SecurityNotificationSetting {
+    /// How a realm sends one kind of security notification.
+    pub fn new(
+        enabled: bool,
+        kind: models::SecurityNotificationKind,
+        realm_id: uuid::Uuid,
+        updated_at: String,
+    ) -> SecurityNotificationSetting {
+        SecurityNotificationSetting {
+            enabled,
+            kind,
+            realm_id,
+            template_id: None,
+            updated_at,
+        }
+    }
+}
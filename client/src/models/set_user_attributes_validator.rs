@@ -0,0 +1,27 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetUserAttributesValidator {
+    /// Key-value pairs to upsert. Existing attributes with the same key are updated.
+    #[serde(rename = "attributes")]
+    pub attributes: std::collections::HashMap<String, String>,
+}
+
+impl SetUserAttributesValidator {
+    pub fn new(
+        attributes: std::collections::HashMap<String, String>,
+    ) -> SetUserAttributesValidator {
+        SetUserAttributesValidator { attributes }
+    }
+}
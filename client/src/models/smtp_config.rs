@@ -0,0 +1,73 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SmtpConfig {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "encryption")]
+    pub encryption: models::SmtpEncryption,
+    #[serde(rename = "from_email")]
+    pub from_email: String,
+    #[serde(rename = "from_name")]
+    pub from_name: String,
+    #[serde(rename = "host")]
+    pub host: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    /// Emails the outbox delivers per minute for the realm, unlimited when unset.
+    #[serde(
+        rename = "max_emails_per_minute",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_emails_per_minute: Option<Option<i32>>,
+    #[serde(rename = "port")]
+    pub port: i32,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+    #[serde(rename = "username")]
+    pub username: String,
+}
+
+impl SmtpConfig {
+    pub fn new(
+        created_at: String,
+        encryption: models::SmtpEncryption,
+        from_email: String,
+        from_name: String,
+        host: String,
+        id: uuid::Uuid,
+        port: i32,
+        realm_id: uuid::Uuid,
+        updated_at: String,
+        username: String,
+    ) -> SmtpConfig {
+        SmtpConfig {
+            created_at,
+            encryption,
+            from_email,
+            from_name,
+            host,
+            id,
+            max_emails_per_minute: None,
+            port,
+            realm_id,
+            updated_at,
+            username,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum SmtpEncryption {
+    #[serde(rename = "tls")]
+    #[default]
+    Tls,
+    #[serde(rename = "starttls")]
+    Starttls,
+    #[serde(rename = "none")]
+    None,
+}
+
+impl std::fmt::Display for SmtpEncryption {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Tls => write!(f, "tls"),
+            Self::Starttls => write!(f, "starttls"),
+            Self::None => write!(f, "none"),
+        }
+    }
+}
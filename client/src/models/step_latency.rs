@@ -0,0 +1,48 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StepLatency {
+    #[serde(rename = "max_ms")]
+    pub max_ms: i64,
+    #[serde(rename = "p50_ms")]
+    pub p50_ms: f64,
+    #[serde(rename = "p90_ms")]
+    pub p90_ms: f64,
+    #[serde(rename = "p99_ms")]
+    pub p99_ms: f64,
+    #[serde(rename = "samples")]
+    pub samples: i64,
+    #[serde(rename = "step_name")]
+    pub step_name: models::FlowStepName,
+}
+
+impl StepLatency {
+    pub fn new(
+        max_ms: i64,
+        p50_ms: f64,
+        p90_ms: f64,
+        p99_ms: f64,
+        samples: i64,
+        step_name: models::FlowStepName,
+    ) -> StepLatency {
+        StepLatency {
+            max_ms,
+            p50_ms,
+            p90_ms,
+            p99_ms,
+            samples,
+            step_name,
+        }
+    }
+}
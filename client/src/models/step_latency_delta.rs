@@ -0,0 +1,52 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// StepLatencyDelta : Change of a step's latency percentiles from the baseline range to the current one. A percentile is `None` when the step has no samples in either range.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StepLatencyDelta {
+    #[serde(
+        rename = "p50_ms",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub p50_ms: Option<Option<f64>>,
+    #[serde(
+        rename = "p90_ms",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub p90_ms: Option<Option<f64>>,
+    #[serde(
+        rename = "p99_ms",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub p99_ms: Option<Option<f64>>,
+    #[serde(rename = "step_name")]
+    pub step_name: models::FlowStepName,
+}
+
+impl StepLatencyDelta {
+    /// Change of a step's latency percentiles from the baseline range to the current one. A percentile is `None` when the step has no samples in either range.
+    pub fn new(step_name: models::FlowStepName) -> StepLatencyDelta {
+        StepLatencyDelta {
+            p50_ms: None,
+            p90_ms: None,
+            p99_ms: None,
+            step_name,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Full syncs read the whole directory; incremental syncs only read entries changed since the previous run's change marker and never disable users.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum SyncKind {
+    #[serde(rename = "Full")]
+    #[default]
+    Full,
+    #[serde(rename = "Incremental")]
+    Incremental,
+}
+
+impl std::fmt::Display for SyncKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Full => write!(f, "Full"),
+            Self::Incremental => write!(f, "Incremental"),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SyncRunErrorResponse {
+    #[serde(rename = "error")]
+    pub error: String,
+    #[serde(rename = "external_id")]
+    pub external_id: String,
+    #[serde(
+        rename = "username",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub username: Option<Option<String>>,
+}
+
+impl SyncRunErrorResponse {
+    pub fn new(error: String, external_id: String) -> SyncRunErrorResponse {
+        SyncRunErrorResponse {
+            error,
+            external_id,
+            username: None,
+        }
+    }
+}
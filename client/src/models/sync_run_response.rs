@@ -0,0 +1,88 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SyncRunResponse {
+    #[serde(
+        rename = "completed_at",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub completed_at: Option<Option<String>>,
+    #[serde(rename = "created")]
+    pub created: i32,
+    #[serde(rename = "disabled")]
+    pub disabled: i32,
+    #[serde(rename = "errors")]
+    pub errors: Vec<models::SyncRunErrorResponse>,
+    #[serde(rename = "failed")]
+    pub failed: i32,
+    #[serde(rename = "groups_added")]
+    pub groups_added: i32,
+    #[serde(rename = "groups_removed")]
+    pub groups_removed: i32,
+    #[serde(rename = "id")]
+    pub id: String,
+    #[serde(rename = "kind")]
+    pub kind: models::SyncKind,
+    #[serde(rename = "mode")]
+    pub mode: String,
+    #[serde(rename = "started_at")]
+    pub started_at: String,
+    #[serde(rename = "status")]
+    pub status: models::SyncStatus,
+    #[serde(rename = "total_processed")]
+    pub total_processed: i32,
+    #[serde(rename = "trigger")]
+    pub trigger: models::SyncTrigger,
+    #[serde(rename = "updated")]
+    pub updated: i32,
+}
+
+impl SyncRunResponse {
+    pub fn new(
+        created: i32,
+        disabled: i32,
+        errors: Vec<models::SyncRunErrorResponse>,
+        failed: i32,
+        groups_added: i32,
+        groups_removed: i32,
+        id: String,
+        kind: models::SyncKind,
+        mode: String,
+        started_at: String,
+        status: models::SyncStatus,
+        total_processed: i32,
+        trigger: models::SyncTrigger,
+        updated: i32,
+    ) -> SyncRunResponse {
+        SyncRunResponse {
+            completed_at: None,
+            created,
+            disabled,
+            errors,
+            failed,
+            groups_added,
+            groups_removed,
+            id,
+            kind,
+            mode,
+            started_at,
+            status,
+            total_processed,
+            trigger,
+            updated,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum SyncStatus {
+    #[serde(rename = "Success")]
+    #[default]
+    Success,
+    #[serde(rename = "Failure")]
+    Failure,
+    #[serde(rename = "PartialSuccess")]
+    PartialSuccess,
+    #[serde(rename = "InProgress")]
+    InProgress,
+}
+
+impl std::fmt::Display for SyncStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Success => write!(f, "Success"),
+            Self::Failure => write!(f, "Failure"),
+            Self::PartialSuccess => write!(f, "PartialSuccess"),
+            Self::InProgress => write!(f, "InProgress"),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum SyncTrigger {
+    #[serde(rename = "Manual")]
+    #[default]
+    Manual,
+    #[serde(rename = "Scheduled")]
+    Scheduled,
+}
+
+impl std::fmt::Display for SyncTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Manual => write!(f, "Manual"),
+            Self::Scheduled => write!(f, "Scheduled"),
+        }
+    }
+}
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -26,6 +26,10 @@ pub struct SyncUsersResponse {
     pub disabled: i32,
     #[serde(rename = "failed")]
     pub failed: i32,
+    #[serde(rename = "groups_added")]
+    pub groups_added: i32,
+    #[serde(rename = "groups_removed")]
+    pub groups_removed: i32,
     #[serde(
         rename = "started_at",
         default,
@@ -44,6 +48,8 @@ impl SyncUsersResponse {
         created: i32,
         disabled: i32,
         failed: i32,
+        groups_added: i32,
+        groups_removed: i32,
         total_processed: i32,
         updated: i32,
     ) -> SyncUsersResponse {
@@ -52,6 +58,8 @@ impl SyncUsersResponse {
             created,
             disabled,
             failed,
+            groups_added,
+            groups_removed,
             started_at: None,
             total_processed,
             updated,
@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TemplateVariable {
+    #[serde(rename = "description")]
+    pub description: String,
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+impl TemplateVariable {
+    pub fn new(description: String, name: String) -> TemplateVariable {
+        TemplateVariable { description, name }
+    }
+}
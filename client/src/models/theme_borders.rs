@@ -0,0 +1,65 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThemeBorders {
+    #[serde(rename = "buttonBorderWeight", skip_serializing_if = "Option::is_none")]
+    pub button_border_weight: Option<i32>,
+    #[serde(rename = "buttonRadius", skip_serializing_if = "Option::is_none")]
+    pub button_radius: Option<i32>,
+    #[serde(rename = "inputBorderWeight", skip_serializing_if = "Option::is_none")]
+    pub input_border_weight: Option<i32>,
+    #[serde(rename = "inputRadius", skip_serializing_if = "Option::is_none")]
+    pub input_radius: Option<i32>,
+    /// Border thickness for the Magic link button. Same rationale as `social_button_border_weight` — alternative auth buttons often want a different visual weight than primary/secondary CTAs.
+    #[serde(
+        rename = "magicLinkButtonBorderWeight",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub magic_link_button_border_weight: Option<i32>,
+    /// Border thickness for the Passkey button.
+    #[serde(
+        rename = "passkeyButtonBorderWeight",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub passkey_button_border_weight: Option<i32>,
+    /// Border thickness applied specifically to social auth buttons. Pulled out from `button_border_weight` so an admin can give provider pills a thicker outline (the usual visual treatment for OAuth buttons on a white-on-white surface) without affecting primary/secondary CTAs.
+    #[serde(
+        rename = "socialButtonBorderWeight",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub social_button_border_weight: Option<i32>,
+    #[serde(rename = "widgetBorderWeight", skip_serializing_if = "Option::is_none")]
+    pub widget_border_weight: Option<i32>,
+    #[serde(rename = "widgetRadius", skip_serializing_if = "Option::is_none")]
+    pub widget_radius: Option<i32>,
+    #[serde(rename = "widgetShadow", skip_serializing_if = "Option::is_none")]
+    pub widget_shadow: Option<Box<models::ThemeBordersWidgetShadow>>,
+}
+
+impl ThemeBorders {
+    pub fn new() -> ThemeBorders {
+        ThemeBorders {
+            button_border_weight: None,
+            button_radius: None,
+            input_border_weight: None,
+            input_radius: None,
+            magic_link_button_border_weight: None,
+            passkey_button_border_weight: None,
+            social_button_border_weight: None,
+            widget_border_weight: None,
+            widget_radius: None,
+            widget_shadow: None,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ThemeBordersWidgetShadow {
+    ThemeShadow(models::ThemeShadow),
+}
+
+impl Default for ThemeBordersWidgetShadow {
+    fn default() -> Self {
+        Self::ThemeShadow(Default::default())
+    }
+}
@@ -0,0 +1,102 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThemeColors {
+    #[serde(rename = "bodyText", skip_serializing_if = "Option::is_none")]
+    pub body_text: Option<String>,
+    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(rename = "links", skip_serializing_if = "Option::is_none")]
+    pub links: Option<String>,
+    /// Magic-link button (alternative auth path). Distinct from the generic secondary button so admins can keep \"Sign in with a magic link\" neutral while branding the primary CTAs separately.
+    #[serde(
+        rename = "magicLinkButtonBackground",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub magic_link_button_background: Option<String>,
+    #[serde(
+        rename = "magicLinkButtonBorder",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub magic_link_button_border: Option<String>,
+    #[serde(
+        rename = "magicLinkButtonLabel",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub magic_link_button_label: Option<String>,
+    #[serde(rename = "pageBackground", skip_serializing_if = "Option::is_none")]
+    pub page_background: Option<String>,
+    /// Passkey button (alternative auth path). Same rationale as magic-link.
+    #[serde(
+        rename = "passkeyButtonBackground",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub passkey_button_background: Option<String>,
+    #[serde(
+        rename = "passkeyButtonBorder",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub passkey_button_border: Option<String>,
+    #[serde(rename = "passkeyButtonLabel", skip_serializing_if = "Option::is_none")]
+    pub passkey_button_label: Option<String>,
+    #[serde(rename = "primaryButton", skip_serializing_if = "Option::is_none")]
+    pub primary_button: Option<String>,
+    #[serde(rename = "primaryButtonLabel", skip_serializing_if = "Option::is_none")]
+    pub primary_button_label: Option<String>,
+    #[serde(rename = "secondaryButton", skip_serializing_if = "Option::is_none")]
+    pub secondary_button: Option<String>,
+    #[serde(
+        rename = "secondaryButtonLabel",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub secondary_button_label: Option<String>,
+    /// Background of social auth buttons (Google, GitHub, …). Defaults to white because most provider icons assume a light surface. Distinct from `secondary_button` so admins can keep a neutral \"Sign in with\" look while branding their primary/secondary CTAs.
+    #[serde(
+        rename = "socialButtonBackground",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub social_button_background: Option<String>,
+    /// Outline color of social buttons. Typically a faint grey — provider pills tend to read better with a visible border than a flat background, even when the background matches the page surface.
+    #[serde(rename = "socialButtonBorder", skip_serializing_if = "Option::is_none")]
+    pub social_button_border: Option<String>,
+    #[serde(rename = "socialButtonLabel", skip_serializing_if = "Option::is_none")]
+    pub social_button_label: Option<String>,
+    #[serde(rename = "widgetBackground", skip_serializing_if = "Option::is_none")]
+    pub widget_background: Option<String>,
+}
+
+impl ThemeColors {
+    pub fn new() -> ThemeColors {
+        ThemeColors {
+            body_text: None,
+            error: None,
+            links: None,
+            magic_link_button_background: None,
+            magic_link_button_border: None,
+            magic_link_button_label: None,
+            page_background: None,
+            passkey_button_background: None,
+            passkey_button_border: None,
+            passkey_button_label: None,
+            primary_button: None,
+            primary_button_label: None,
+            secondary_button: None,
+            secondary_button_label: None,
+            social_button_background: None,
+            social_button_border: None,
+            social_button_label: None,
+            widget_background: None,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThemeFontLinkStyle {
+    #[serde(rename = "sizePct")]
+    pub size_pct: f32,
+    #[serde(rename = "style")]
+    pub style: models::ThemeLinkStyle,
+    #[serde(rename = "weight")]
+    pub weight: i32,
+}
+
+impl ThemeFontLinkStyle {
+    pub fn new(size_pct: f32, style: models::ThemeLinkStyle, weight: i32) -> ThemeFontLinkStyle {
+        ThemeFontLinkStyle {
+            size_pct,
+            style,
+            weight,
+        }
+    }
+}
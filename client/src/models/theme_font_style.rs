@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThemeFontStyle {
+    #[serde(rename = "sizePct")]
+    pub size_pct: f32,
+    #[serde(rename = "weight")]
+    pub weight: i32,
+}
+
+impl ThemeFontStyle {
+    pub fn new(size_pct: f32, weight: i32) -> ThemeFontStyle {
+        ThemeFontStyle { size_pct, weight }
+    }
+}
@@ -0,0 +1,52 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThemeFonts {
+    #[serde(rename = "baseSize", skip_serializing_if = "Option::is_none")]
+    pub base_size: Option<i32>,
+    #[serde(rename = "body", skip_serializing_if = "Option::is_none")]
+    pub body: Option<Box<models::ThemeFontsBody>>,
+    #[serde(rename = "buttons", skip_serializing_if = "Option::is_none")]
+    pub buttons: Option<Box<models::ThemeFontsButtons>>,
+    #[serde(rename = "inputLabels", skip_serializing_if = "Option::is_none")]
+    pub input_labels: Option<Box<models::ThemeFontsInputLabels>>,
+    #[serde(rename = "links", skip_serializing_if = "Option::is_none")]
+    pub links: Option<Box<models::ThemeFontsLinks>>,
+    #[serde(rename = "subtitle", skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<Box<models::ThemeFontsSubtitle>>,
+    #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<Box<models::ThemeFontsTitle>>,
+    #[serde(
+        rename = "url",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub url: Option<Option<String>>,
+}
+
+impl ThemeFonts {
+    pub fn new() -> ThemeFonts {
+        ThemeFonts {
+            base_size: None,
+            body: None,
+            buttons: None,
+            input_labels: None,
+            links: None,
+            subtitle: None,
+            title: None,
+            url: None,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ThemeFontsSubtitle {
+    ThemeFontStyle(Box<models::ThemeFontStyle>),
+}
+
+impl Default for ThemeFontsSubtitle {
+    fn default() -> Self {
+        Self::ThemeFontStyle(Default::default())
+    }
+}
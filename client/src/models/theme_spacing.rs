@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThemeSpacing {
+    #[serde(rename = "fieldGap", skip_serializing_if = "Option::is_none")]
+    pub field_gap: Option<i32>,
+    #[serde(rename = "sectionGap", skip_serializing_if = "Option::is_none")]
+    pub section_gap: Option<i32>,
+    #[serde(rename = "widgetPadding", skip_serializing_if = "Option::is_none")]
+    pub widget_padding: Option<i32>,
+}
+
+impl ThemeSpacing {
+    pub fn new() -> ThemeSpacing {
+        ThemeSpacing {
+            field_gap: None,
+            section_gap: None,
+            widget_padding: None,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ToggleMaintenanceValidator {
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+    #[serde(
+        rename = "reason",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reason: Option<Option<String>>,
+    #[serde(
+        rename = "session_strategy",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub session_strategy: Option<Option<models::MaintenanceSessionStrategy>>,
+}
+
+impl ToggleMaintenanceValidator {
+    pub fn new(enabled: bool) -> ToggleMaintenanceValidator {
+        ToggleMaintenanceValidator {
+            enabled,
+            reason: None,
+            session_strategy: None,
+        }
+    }
+}
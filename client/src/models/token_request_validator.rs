@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,8 +13,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TokenRequestValidator {
-    #[serde(rename = "client_id", skip_serializing_if = "Option::is_none")]
-    pub client_id: Option<String>,
+    #[serde(
+        rename = "client_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub client_id: Option<Option<String>>,
     #[serde(
         rename = "client_secret",
         default,
@@ -29,6 +34,20 @@ pub struct TokenRequestValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub code: Option<Option<String>>,
+    #[serde(
+        rename = "code_verifier",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub code_verifier: Option<Option<String>>,
+    #[serde(
+        rename = "device_code",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub device_code: Option<Option<String>>,
     #[serde(rename = "grant_type", skip_serializing_if = "Option::is_none")]
     pub grant_type: Option<models::GrantType>,
     #[serde(
@@ -38,6 +57,13 @@ pub struct TokenRequestValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub password: Option<Option<String>>,
+    #[serde(
+        rename = "redirect_uri",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub redirect_uri: Option<Option<String>>,
     #[serde(
         rename = "refresh_token",
         default,
@@ -67,8 +93,11 @@ impl TokenRequestValidator {
             client_id: None,
             client_secret: None,
             code: None,
+            code_verifier: None,
+            device_code: None,
             grant_type: None,
             password: None,
+            redirect_uri: None,
             refresh_token: None,
             scope: None,
             username: None,
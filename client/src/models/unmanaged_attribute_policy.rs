@@ -0,0 +1,36 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// What happens to attributes the schema does not declare.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum UnmanagedAttributePolicy {
+    #[serde(rename = "enabled")]
+    #[default]
+    Enabled,
+    #[serde(rename = "admin_edit")]
+    AdminEdit,
+    #[serde(rename = "disabled")]
+    Disabled,
+}
+
+impl std::fmt::Display for UnmanagedAttributePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Enabled => write!(f, "enabled"),
+            Self::AdminEdit => write!(f, "admin_edit"),
+            Self::Disabled => write!(f, "disabled"),
+        }
+    }
+}
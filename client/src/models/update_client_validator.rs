@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,6 +13,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UpdateClientValidator {
+    #[serde(
+        rename = "access_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub access_token_lifetime: Option<Option<i64>>,
     #[serde(
         rename = "client_id",
         default,
@@ -34,6 +41,13 @@ pub struct UpdateClientValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub enabled: Option<Option<bool>>,
+    #[serde(
+        rename = "id_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id_token_lifetime: Option<Option<i64>>,
     #[serde(
         rename = "name",
         default,
@@ -41,15 +55,49 @@ pub struct UpdateClientValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub name: Option<Option<String>>,
+    #[serde(
+        rename = "oauth_device_code_grant_enabled",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub oauth_device_code_grant_enabled: Option<Option<bool>>,
+    #[serde(
+        rename = "refresh_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub refresh_token_lifetime: Option<Option<i64>>,
+    #[serde(
+        rename = "require_pkce",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub require_pkce: Option<Option<bool>>,
+    #[serde(
+        rename = "temporary_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub temporary_token_lifetime: Option<Option<i64>>,
 }
 
 impl UpdateClientValidator {
     pub fn new() -> UpdateClientValidator {
         UpdateClientValidator {
+            access_token_lifetime: None,
             client_id: None,
             direct_access_grants_enabled: None,
             enabled: None,
+            id_token_lifetime: None,
             name: None,
+            oauth_device_code_grant_enabled: None,
+            refresh_token_lifetime: None,
+            require_pkce: None,
+            temporary_token_lifetime: None,
         }
     }
 }
@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateEmailTemplateValidator {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "structure", deserialize_with = "Option::deserialize")]
+    pub structure: Option<serde_json::Value>,
+}
+
+impl UpdateEmailTemplateValidator {
+    pub fn new(name: String, structure: Option<serde_json::Value>) -> UpdateEmailTemplateValidator {
+        UpdateEmailTemplateValidator { name, structure }
+    }
+}
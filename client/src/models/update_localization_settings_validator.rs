@@ -0,0 +1,33 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateLocalizationSettingsValidator {
+    /// Must be one of `supported_locales`.
+    #[serde(rename = "default_locale")]
+    pub default_locale: String,
+    #[serde(rename = "supported_locales")]
+    pub supported_locales: Vec<String>,
+}
+
+impl UpdateLocalizationSettingsValidator {
+    pub fn new(
+        default_locale: String,
+        supported_locales: Vec<String>,
+    ) -> UpdateLocalizationSettingsValidator {
+        UpdateLocalizationSettingsValidator {
+            default_locale,
+            supported_locales,
+        }
+    }
+}
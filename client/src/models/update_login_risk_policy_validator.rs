@@ -0,0 +1,72 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateLoginRiskPolicyValidator {
+    /// Score from which the login is refused; `null` never blocks.
+    #[serde(
+        rename = "block_threshold",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub block_threshold: Option<Option<i32>>,
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+    #[serde(rename = "impossible_travel_score")]
+    pub impossible_travel_score: i32,
+    /// Travel between two consecutive logins faster than this is impossible.
+    #[serde(rename = "max_travel_speed_kmh")]
+    pub max_travel_speed_kmh: i32,
+    #[serde(rename = "new_country_score")]
+    pub new_country_score: i32,
+    #[serde(rename = "new_device_score")]
+    pub new_device_score: i32,
+    /// Score from which the user is emailed about the login; `null` disables notifications.
+    #[serde(
+        rename = "notify_threshold",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub notify_threshold: Option<Option<i32>>,
+    /// Score from which the user must pass MFA; `null` disables the step-up.
+    #[serde(
+        rename = "step_up_threshold",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub step_up_threshold: Option<Option<i32>>,
+}
+
+impl UpdateLoginRiskPolicyValidator {
+    pub fn new(
+        enabled: bool,
+        impossible_travel_score: i32,
+        max_travel_speed_kmh: i32,
+        new_country_score: i32,
+        new_device_score: i32,
+    ) -> UpdateLoginRiskPolicyValidator {
+        UpdateLoginRiskPolicyValidator {
+            block_threshold: None,
+            enabled,
+            impossible_travel_score,
+            max_travel_speed_kmh,
+            new_country_score,
+            new_device_score,
+            notify_threshold: None,
+            step_up_threshold: None,
+        }
+    }
+}
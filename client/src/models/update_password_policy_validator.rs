@@ -0,0 +1,95 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdatePasswordPolicyValidator {
+    #[serde(
+        rename = "check_breached",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub check_breached: Option<Option<bool>>,
+    #[serde(
+        rename = "forbid_common",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub forbid_common: Option<Option<bool>>,
+    #[serde(
+        rename = "max_age_days",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_age_days: Option<Option<i32>>,
+    #[serde(
+        rename = "min_entropy_bits",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub min_entropy_bits: Option<Option<i32>>,
+    #[serde(
+        rename = "min_length",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub min_length: Option<Option<i32>>,
+    #[serde(
+        rename = "require_lowercase",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub require_lowercase: Option<Option<bool>>,
+    #[serde(
+        rename = "require_number",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub require_number: Option<Option<bool>>,
+    #[serde(
+        rename = "require_special",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub require_special: Option<Option<bool>>,
+    #[serde(
+        rename = "require_uppercase",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub require_uppercase: Option<Option<bool>>,
+}
+
+impl UpdatePasswordPolicyValidator {
+    pub fn new() -> UpdatePasswordPolicyValidator {
+        UpdatePasswordPolicyValidator {
+            check_breached: None,
+            forbid_common: None,
+            max_age_days: None,
+            min_entropy_bits: None,
+            min_length: None,
+            require_lowercase: None,
+            require_number: None,
+            require_special: None,
+            require_uppercase: None,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdatePortalLayoutValidator {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "tree", deserialize_with = "Option::deserialize")]
+    pub tree: Option<serde_json::Value>,
+}
+
+impl UpdatePortalLayoutValidator {
+    pub fn new(name: String, tree: Option<serde_json::Value>) -> UpdatePortalLayoutValidator {
+        UpdatePortalLayoutValidator { name, tree }
+    }
+}
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,6 +13,14 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UpdateProviderRequest {
+    /// Period of the changed-since sync (`modifyTimestamp` / `uSNChanged`).
+    #[serde(
+        rename = "changed_sync_interval_minutes",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub changed_sync_interval_minutes: Option<Option<i32>>,
     #[serde(
         rename = "config",
         default,
@@ -27,6 +35,14 @@ pub struct UpdateProviderRequest {
         skip_serializing_if = "Option::is_none"
     )]
     pub enabled: Option<Option<bool>>,
+    /// Five-field cron expression (UTC) for full syncs; takes precedence over the interval.
+    #[serde(
+        rename = "full_sync_cron",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub full_sync_cron: Option<Option<String>>,
     #[serde(
         rename = "name",
         default,
@@ -74,8 +90,10 @@ pub struct UpdateProviderRequest {
 impl UpdateProviderRequest {
     pub fn new() -> UpdateProviderRequest {
         UpdateProviderRequest {
+            changed_sync_interval_minutes: None,
             config: None,
             enabled: None,
+            full_sync_cron: None,
             name: None,
             priority: None,
             provider_type: None,
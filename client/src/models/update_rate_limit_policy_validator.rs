@@ -0,0 +1,63 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateRateLimitPolicyValidator {
+    #[serde(
+        rename = "block_seconds",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub block_seconds: Option<Option<i32>>,
+    #[serde(
+        rename = "enabled",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub enabled: Option<Option<bool>>,
+    #[serde(
+        rename = "limit",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub limit: Option<Option<i32>>,
+    #[serde(
+        rename = "shared",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub shared: Option<Option<bool>>,
+    #[serde(
+        rename = "window_seconds",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub window_seconds: Option<Option<i32>>,
+}
+
+impl UpdateRateLimitPolicyValidator {
+    pub fn new() -> UpdateRateLimitPolicyValidator {
+        UpdateRateLimitPolicyValidator {
+            block_seconds: None,
+            enabled: None,
+            limit: None,
+            shared: None,
+            window_seconds: None,
+        }
+    }
+}
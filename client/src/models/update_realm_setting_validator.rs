@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,6 +13,20 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UpdateRealmSettingValidator {
+    #[serde(
+        rename = "access_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub access_token_lifetime: Option<Option<i64>>,
+    #[serde(
+        rename = "compass_enabled",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub compass_enabled: Option<Option<bool>>,
     #[serde(
         rename = "default_signing_algorithm",
         default,
@@ -20,6 +34,27 @@ pub struct UpdateRealmSettingValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub default_signing_algorithm: Option<Option<String>>,
+    #[serde(
+        rename = "email_verification_enabled",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email_verification_enabled: Option<Option<bool>>,
+    #[serde(
+        rename = "email_verification_template_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email_verification_template_id: Option<Option<uuid::Uuid>>,
+    #[serde(
+        rename = "email_verification_ttl_hours",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email_verification_ttl_hours: Option<Option<i64>>,
     #[serde(
         rename = "forgot_password_enabled",
         default,
@@ -27,6 +62,34 @@ pub struct UpdateRealmSettingValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub forgot_password_enabled: Option<Option<bool>>,
+    #[serde(
+        rename = "id_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id_token_lifetime: Option<Option<i64>>,
+    #[serde(
+        rename = "lockout_duration_seconds",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lockout_duration_seconds: Option<Option<i32>>,
+    #[serde(
+        rename = "lockout_threshold",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lockout_threshold: Option<Option<i32>>,
+    #[serde(
+        rename = "login_aliases",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub login_aliases: Option<Option<Vec<String>>>,
     #[serde(
         rename = "magic_link_enabled",
         default,
@@ -34,6 +97,13 @@ pub struct UpdateRealmSettingValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub magic_link_enabled: Option<Option<bool>>,
+    #[serde(
+        rename = "magic_link_template_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub magic_link_template_id: Option<Option<uuid::Uuid>>,
     #[serde(
         rename = "magic_link_ttl",
         default,
@@ -41,6 +111,27 @@ pub struct UpdateRealmSettingValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub magic_link_ttl: Option<Option<i32>>,
+    #[serde(
+        rename = "organization_invitation_template_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub organization_invitation_template_id: Option<Option<uuid::Uuid>>,
+    #[serde(
+        rename = "passkey_enabled",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub passkey_enabled: Option<Option<bool>>,
+    #[serde(
+        rename = "refresh_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub refresh_token_lifetime: Option<Option<i64>>,
     #[serde(
         rename = "remember_me_enabled",
         default,
@@ -48,6 +139,41 @@ pub struct UpdateRealmSettingValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub remember_me_enabled: Option<Option<bool>>,
+    #[serde(
+        rename = "require_mfa",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub require_mfa: Option<Option<bool>>,
+    #[serde(
+        rename = "reset_password_template_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reset_password_template_id: Option<Option<uuid::Uuid>>,
+    #[serde(
+        rename = "seawatch_pii_mode",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub seawatch_pii_mode: Option<Option<String>>,
+    #[serde(
+        rename = "seawatch_pseudo_key",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub seawatch_pseudo_key: Option<Option<String>>,
+    #[serde(
+        rename = "temporary_token_lifetime",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub temporary_token_lifetime: Option<Option<i64>>,
     #[serde(
         rename = "user_registration_enabled",
         default,
@@ -60,11 +186,29 @@ pub struct UpdateRealmSettingValidator {
 impl UpdateRealmSettingValidator {
     pub fn new() -> UpdateRealmSettingValidator {
         UpdateRealmSettingValidator {
+            access_token_lifetime: None,
+            compass_enabled: None,
             default_signing_algorithm: None,
+            email_verification_enabled: None,
+            email_verification_template_id: None,
+            email_verification_ttl_hours: None,
             forgot_password_enabled: None,
+            id_token_lifetime: None,
+            lockout_duration_seconds: None,
+            lockout_threshold: None,
+            login_aliases: None,
             magic_link_enabled: None,
+            magic_link_template_id: None,
             magic_link_ttl: None,
+            organization_invitation_template_id: None,
+            passkey_enabled: None,
+            refresh_token_lifetime: None,
             remember_me_enabled: None,
+            require_mfa: None,
+            reset_password_template_id: None,
+            seawatch_pii_mode: None,
+            seawatch_pseudo_key: None,
+            temporary_token_lifetime: None,
             user_registration_enabled: None,
         }
     }
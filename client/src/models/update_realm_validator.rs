@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,12 +13,22 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UpdateRealmValidator {
+    #[serde(
+        rename = "display_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub display_name: Option<Option<String>>,
     #[serde(rename = "name")]
     pub name: String,
 }
 
 impl UpdateRealmValidator {
     pub fn new(name: String) -> UpdateRealmValidator {
-        UpdateRealmValidator { name }
+        UpdateRealmValidator {
+            display_name: None,
+            name,
+        }
     }
 }
@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -27,6 +27,13 @@ pub struct UpdateRoleValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub name: Option<Option<String>>,
+    #[serde(
+        rename = "require_mfa",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub require_mfa: Option<Option<bool>>,
 }
 
 impl UpdateRoleValidator {
@@ -34,6 +41,7 @@ impl UpdateRoleValidator {
         UpdateRoleValidator {
             description: None,
             name: None,
+            require_mfa: None,
         }
     }
 }
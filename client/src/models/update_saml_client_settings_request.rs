@@ -0,0 +1,92 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateSamlClientSettingsRequest {
+    #[serde(rename = "assertion_consumer_services")]
+    pub assertion_consumer_services: Vec<models::SamlEndpoint>,
+    #[serde(rename = "assertion_lifetime_seconds")]
+    pub assertion_lifetime_seconds: i64,
+    #[serde(rename = "encrypt_assertions")]
+    pub encrypt_assertions: bool,
+    #[serde(
+        rename = "encryption_certificate",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub encryption_certificate: Option<Option<String>>,
+    #[serde(
+        rename = "idp_initiated_relay_state",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub idp_initiated_relay_state: Option<Option<String>>,
+    #[serde(
+        rename = "idp_initiated_sso_url_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub idp_initiated_sso_url_name: Option<Option<String>>,
+    #[serde(rename = "name_id_format")]
+    pub name_id_format: models::NameIdFormat,
+    #[serde(rename = "require_signed_requests")]
+    pub require_signed_requests: bool,
+    #[serde(rename = "sign_assertions")]
+    pub sign_assertions: bool,
+    #[serde(rename = "sign_response")]
+    pub sign_response: bool,
+    #[serde(
+        rename = "signing_certificate",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub signing_certificate: Option<Option<String>>,
+    #[serde(
+        rename = "single_logout_service",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub single_logout_service: Option<Option<Box<models::SamlEndpoint>>>,
+}
+
+impl UpdateSamlClientSettingsRequest {
+    pub fn new(
+        assertion_consumer_services: Vec<models::SamlEndpoint>,
+        assertion_lifetime_seconds: i64,
+        encrypt_assertions: bool,
+        name_id_format: models::NameIdFormat,
+        require_signed_requests: bool,
+        sign_assertions: bool,
+        sign_response: bool,
+    ) -> UpdateSamlClientSettingsRequest {
+        UpdateSamlClientSettingsRequest {
+            assertion_consumer_services,
+            assertion_lifetime_seconds,
+            encrypt_assertions,
+            encryption_certificate: None,
+            idp_initiated_relay_state: None,
+            idp_initiated_sso_url_name: None,
+            name_id_format,
+            require_signed_requests,
+            sign_assertions,
+            sign_response,
+            signing_certificate: None,
+            single_logout_service: None,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateSecurityNotificationValidator {
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+    /// Email template of the same type as the notification; omit it for the built-in one.
+    #[serde(
+        rename = "template_id",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub template_id: Option<Option<uuid::Uuid>>,
+}
+
+impl UpdateSecurityNotificationValidator {
+    pub fn new(enabled: bool) -> UpdateSecurityNotificationValidator {
+        UpdateSecurityNotificationValidator {
+            enabled,
+            template_id: None,
+        }
+    }
+}
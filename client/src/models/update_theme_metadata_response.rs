@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateThemeMetadataResponse {
+    #[serde(rename = "data")]
+    pub data: Box<models::PortalTheme>,
+}
+
+impl UpdateThemeMetadataResponse {
+    pub fn new(data: models::PortalTheme) -> UpdateThemeMetadataResponse {
+        UpdateThemeMetadataResponse {
+            data: Box::new(data),
+        }
+    }
+}
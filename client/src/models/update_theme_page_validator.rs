@@ -0,0 +1,24 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateThemePageValidator {
+    #[serde(rename = "tree", deserialize_with = "Option::deserialize")]
+    pub tree: Option<serde_json::Value>,
+}
+
+impl UpdateThemePageValidator {
+    pub fn new(tree: Option<serde_json::Value>) -> UpdateThemePageValidator {
+        UpdateThemePageValidator { tree }
+    }
+}
@@ -0,0 +1,38 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// UpdateUserProfileSchemaValidator : The schema is checked as a whole by the service, which reports every problem at once.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateUserProfileSchemaValidator {
+    #[serde(rename = "attributes", skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<models::UserProfileAttribute>>,
+    #[serde(rename = "groups", skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<models::AttributeGroup>>,
+    /// What happens to attributes the schema does not declare.
+    #[serde(
+        rename = "unmanaged_attributes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub unmanaged_attributes: Option<models::UnmanagedAttributePolicy>,
+}
+
+impl UpdateUserProfileSchemaValidator {
+    /// The schema is checked as a whole by the service, which reports every problem at once.
+    pub fn new() -> UpdateUserProfileSchemaValidator {
+        UpdateUserProfileSchemaValidator {
+            attributes: None,
+            groups: None,
+            unmanaged_attributes: None,
+        }
+    }
+}
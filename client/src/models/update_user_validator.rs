@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -13,8 +13,21 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UpdateUserValidator {
-    #[serde(rename = "email", skip_serializing_if = "Option::is_none")]
-    pub email: Option<String>,
+    /// Attribute changes checked against the realm's user profile; an empty value clears the attribute.
+    #[serde(
+        rename = "attributes",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub attributes: Option<Option<std::collections::HashMap<String, String>>>,
+    #[serde(
+        rename = "email",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email: Option<Option<String>>,
     #[serde(
         rename = "email_verified",
         default,
@@ -29,10 +42,20 @@ pub struct UpdateUserValidator {
         skip_serializing_if = "Option::is_none"
     )]
     pub enabled: Option<Option<bool>>,
-    #[serde(rename = "firstname", skip_serializing_if = "Option::is_none")]
-    pub firstname: Option<String>,
-    #[serde(rename = "lastname", skip_serializing_if = "Option::is_none")]
-    pub lastname: Option<String>,
+    #[serde(
+        rename = "firstname",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub firstname: Option<Option<String>>,
+    #[serde(
+        rename = "lastname",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lastname: Option<Option<String>>,
     #[serde(
         rename = "required_actions",
         default,
@@ -45,6 +68,7 @@ pub struct UpdateUserValidator {
 impl UpdateUserValidator {
     pub fn new() -> UpdateUserValidator {
         UpdateUserValidator {
+            attributes: None,
             email: None,
             email_verified: None,
             enabled: None,
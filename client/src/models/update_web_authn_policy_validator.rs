@@ -0,0 +1,62 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateWebAuthnPolicyValidator {
+    /// When not empty, only these authenticator models are accepted.
+    #[serde(rename = "allowed_aaguids", skip_serializing_if = "Option::is_none")]
+    pub allowed_aaguids: Option<Vec<uuid::Uuid>>,
+    /// Origins accepted besides the one of the login portal, e.g. `https://login.acme.com`.
+    #[serde(rename = "allowed_origins", skip_serializing_if = "Option::is_none")]
+    pub allowed_origins: Option<Vec<String>>,
+    #[serde(rename = "attestation")]
+    pub attestation: models::AttestationConveyance,
+    /// `null` lets the user choose.
+    #[serde(
+        rename = "authenticator_attachment",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub authenticator_attachment: Option<Option<models::AuthenticatorAttachment>>,
+    #[serde(rename = "blocked_aaguids", skip_serializing_if = "Option::is_none")]
+    pub blocked_aaguids: Option<Vec<uuid::Uuid>>,
+    #[serde(
+        rename = "fips_certified_only",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub fips_certified_only: Option<bool>,
+    #[serde(rename = "require_resident_key")]
+    pub require_resident_key: bool,
+    #[serde(rename = "user_verification")]
+    pub user_verification: models::UserVerificationRequirement,
+}
+
+impl UpdateWebAuthnPolicyValidator {
+    pub fn new(
+        attestation: models::AttestationConveyance,
+        require_resident_key: bool,
+        user_verification: models::UserVerificationRequirement,
+    ) -> UpdateWebAuthnPolicyValidator {
+        UpdateWebAuthnPolicyValidator {
+            allowed_aaguids: None,
+            allowed_origins: None,
+            attestation,
+            authenticator_attachment: None,
+            blocked_aaguids: None,
+            fips_certified_only: None,
+            require_resident_key,
+            user_verification,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpsertSmtpConfigValidator {
+    #[serde(rename = "encryption")]
+    pub encryption: String,
+    #[serde(rename = "from_email")]
+    pub from_email: String,
+    #[serde(rename = "from_name")]
+    pub from_name: String,
+    #[serde(rename = "host")]
+    pub host: String,
+    /// Emails delivered per minute for the realm; unlimited when omitted.
+    #[serde(
+        rename = "max_emails_per_minute",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_emails_per_minute: Option<Option<i32>>,
+    #[serde(rename = "password")]
+    pub password: String,
+    #[serde(rename = "port")]
+    pub port: i32,
+    #[serde(rename = "username")]
+    pub username: String,
+}
+
+impl UpsertSmtpConfigValidator {
+    pub fn new(
+        encryption: String,
+        from_email: String,
+        from_name: String,
+        host: String,
+        password: String,
+        port: i32,
+        username: String,
+    ) -> UpsertSmtpConfigValidator {
+        UpsertSmtpConfigValidator {
+            encryption,
+            from_email,
+            from_name,
+            host,
+            max_emails_per_minute: None,
+            password,
+            port,
+            username,
+        }
+    }
+}
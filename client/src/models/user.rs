@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -22,18 +22,42 @@ pub struct User {
     pub client_id: Option<Option<uuid::Uuid>>,
     #[serde(rename = "created_at")]
     pub created_at: String,
-    #[serde(rename = "email")]
-    pub email: String,
+    #[serde(
+        rename = "email",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email: Option<Option<String>>,
     #[serde(rename = "email_verified")]
     pub email_verified: bool,
     #[serde(rename = "enabled")]
     pub enabled: bool,
-    #[serde(rename = "firstname")]
-    pub firstname: String,
+    #[serde(rename = "failed_login_attempts")]
+    pub failed_login_attempts: i32,
+    #[serde(
+        rename = "firstname",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub firstname: Option<Option<String>>,
     #[serde(rename = "id")]
     pub id: uuid::Uuid,
-    #[serde(rename = "lastname")]
-    pub lastname: String,
+    #[serde(
+        rename = "lastname",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lastname: Option<Option<String>>,
+    #[serde(
+        rename = "locked_until",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub locked_until: Option<Option<String>>,
     #[serde(
         rename = "realm",
         default,
@@ -61,12 +85,10 @@ pub struct User {
 impl User {
     pub fn new(
         created_at: String,
-        email: String,
         email_verified: bool,
         enabled: bool,
-        firstname: String,
+        failed_login_attempts: i32,
         id: uuid::Uuid,
-        lastname: String,
         realm_id: uuid::Uuid,
         required_actions: Vec<models::RequiredAction>,
         updated_at: String,
@@ -75,12 +97,14 @@ impl User {
         User {
             client_id: None,
             created_at,
-            email,
+            email: None,
             email_verified,
             enabled,
-            firstname,
+            failed_login_attempts,
+            firstname: None,
             id,
-            lastname,
+            lastname: None,
+            locked_until: None,
             realm: None,
             realm_id,
             required_actions,
@@ -0,0 +1,52 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserAttribute {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "key")]
+    pub key: String,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+    #[serde(rename = "value")]
+    pub value: String,
+}
+
+impl UserAttribute {
+    pub fn new(
+        created_at: String,
+        id: uuid::Uuid,
+        key: String,
+        realm_id: uuid::Uuid,
+        updated_at: String,
+        user_id: uuid::Uuid,
+        value: String,
+    ) -> UserAttribute {
+        UserAttribute {
+            created_at,
+            id,
+            key,
+            realm_id,
+            updated_at,
+            user_id,
+            value,
+        }
+    }
+}
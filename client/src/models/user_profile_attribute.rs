@@ -0,0 +1,56 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserProfileAttribute {
+    #[serde(
+        rename = "display_name",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub display_name: Option<Option<String>>,
+    /// Name of the [`AttributeGroup`] the attribute is displayed in.
+    #[serde(
+        rename = "group",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub group: Option<Option<String>>,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "permissions", skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Box<models::AttributePermissions>>,
+    /// Contexts in which the attribute must have a value.
+    #[serde(rename = "required_for", skip_serializing_if = "Option::is_none")]
+    pub required_for: Option<Vec<models::ProfileContext>>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<models::AttributeType>,
+    #[serde(rename = "validators", skip_serializing_if = "Option::is_none")]
+    pub validators: Option<Vec<models::AttributeValidator>>,
+}
+
+impl UserProfileAttribute {
+    pub fn new(name: String) -> UserProfileAttribute {
+        UserProfileAttribute {
+            display_name: None,
+            group: None,
+            name,
+            permissions: None,
+            required_for: None,
+            r#type: None,
+            validators: None,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// UserProfileConfig : The editable part of a realm's user profile schema.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserProfileConfig {
+    #[serde(rename = "attributes", skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<models::UserProfileAttribute>>,
+    #[serde(rename = "groups", skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<models::AttributeGroup>>,
+    #[serde(
+        rename = "unmanaged_attributes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub unmanaged_attributes: Option<models::UnmanagedAttributePolicy>,
+}
+
+impl UserProfileConfig {
+    /// The editable part of a realm's user profile schema.
+    pub fn new() -> UserProfileConfig {
+        UserProfileConfig {
+            attributes: None,
+            groups: None,
+            unmanaged_attributes: None,
+        }
+    }
+}
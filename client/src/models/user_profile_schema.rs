@@ -0,0 +1,46 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// UserProfileSchema : The attributes users of a realm have beyond the fixed user columns, and the rules their values follow. Realms without one behave as if it declared nothing.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserProfileSchema {
+    #[serde(rename = "attributes", skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<models::UserProfileAttribute>>,
+    #[serde(rename = "groups", skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<models::AttributeGroup>>,
+    #[serde(
+        rename = "unmanaged_attributes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub unmanaged_attributes: Option<models::UnmanagedAttributePolicy>,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+}
+
+impl UserProfileSchema {
+    /// The attributes users of a realm have beyond the fixed user columns, and the rules their values follow. Realms without one behave as if it declared nothing.
+    pub fn new(created_at: String, realm_id: uuid::Uuid, updated_at: String) -> UserProfileSchema {
+        UserProfileSchema {
+            attributes: None,
+            groups: None,
+            unmanaged_attributes: None,
+            created_at,
+            realm_id,
+            updated_at,
+        }
+    }
+}
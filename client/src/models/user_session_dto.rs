@@ -0,0 +1,68 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserSessionDto {
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "expires_at")]
+    pub expires_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(
+        rename = "ip_address",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ip_address: Option<Option<String>>,
+    #[serde(
+        rename = "last_seen_at",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_seen_at: Option<Option<String>>,
+    #[serde(rename = "realm_id")]
+    pub realm_id: uuid::Uuid,
+    #[serde(
+        rename = "user_agent",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub user_agent: Option<Option<String>>,
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+}
+
+impl UserSessionDto {
+    pub fn new(
+        created_at: String,
+        expires_at: String,
+        id: uuid::Uuid,
+        realm_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    ) -> UserSessionDto {
+        UserSessionDto {
+            created_at,
+            expires_at,
+            id,
+            ip_address: None,
+            last_seen_at: None,
+            realm_id,
+            user_agent: None,
+            user_id,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
+)]
+pub enum UserVerificationRequirement {
+    #[serde(rename = "required")]
+    #[default]
+    Required,
+    #[serde(rename = "preferred")]
+    Preferred,
+}
+
+impl std::fmt::Display for UserVerificationRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Required => write!(f, "required"),
+            Self::Preferred => write!(f, "preferred"),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VerifyEmailRequest {
+    /// Email verification token
+    #[serde(rename = "token")]
+    pub token: String,
+}
+
+impl VerifyEmailRequest {
+    pub fn new(token: String) -> VerifyEmailRequest {
+        VerifyEmailRequest { token }
+    }
+}
@@ -0,0 +1,26 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VerifyEmailResult {
+    #[serde(rename = "user_id")]
+    pub user_id: uuid::Uuid,
+    #[serde(rename = "verified")]
+    pub verified: bool,
+}
+
+impl VerifyEmailResult {
+    pub fn new(user_id: uuid::Uuid, verified: bool) -> VerifyEmailResult {
+        VerifyEmailResult { user_id, verified }
+    }
+}
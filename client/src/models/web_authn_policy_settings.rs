@@ -0,0 +1,67 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// WebAuthnPolicySettings : How a realm registers and accepts WebAuthn credentials. The defaults are those of passkeys: no attestation, user verification required, any authenticator.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WebAuthnPolicySettings {
+    /// When not empty, only these authenticator models are accepted.
+    #[serde(rename = "allowed_aaguids")]
+    pub allowed_aaguids: Vec<uuid::Uuid>,
+    /// Origins accepted besides the one of the login portal, e.g. a custom login domain.
+    #[serde(rename = "allowed_origins")]
+    pub allowed_origins: Vec<String>,
+    #[serde(rename = "attestation")]
+    pub attestation: models::AttestationConveyance,
+    /// `null` lets the user choose.
+    #[serde(
+        rename = "authenticator_attachment",
+        default,
+        with = "::serde_with::rust::double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub authenticator_attachment: Option<Option<models::AuthenticatorAttachment>>,
+    #[serde(rename = "blocked_aaguids")]
+    pub blocked_aaguids: Vec<uuid::Uuid>,
+    /// Only accept authenticators whose FIPS 140 validation the FIDO metadata records.
+    #[serde(rename = "fips_certified_only")]
+    pub fips_certified_only: bool,
+    /// Whether the credential must be discoverable, so users can sign in without a username.
+    #[serde(rename = "require_resident_key")]
+    pub require_resident_key: bool,
+    #[serde(rename = "user_verification")]
+    pub user_verification: models::UserVerificationRequirement,
+}
+
+impl WebAuthnPolicySettings {
+    /// How a realm registers and accepts WebAuthn credentials. The defaults are those of passkeys: no attestation, user verification required, any authenticator.
+    pub fn new(
+        allowed_aaguids: Vec<uuid::Uuid>,
+        allowed_origins: Vec<String>,
+        attestation: models::AttestationConveyance,
+        blocked_aaguids: Vec<uuid::Uuid>,
+        fips_certified_only: bool,
+        require_resident_key: bool,
+        user_verification: models::UserVerificationRequirement,
+    ) -> WebAuthnPolicySettings {
+        WebAuthnPolicySettings {
+            allowed_aaguids,
+            allowed_origins,
+            attestation,
+            authenticator_attachment: None,
+            blocked_aaguids,
+            fips_certified_only,
+            require_resident_key,
+            user_verification,
+        }
+    }
+}
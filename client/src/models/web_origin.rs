@@ -0,0 +1,44 @@
+/*
+ * FerrisKey API
+ *
+ * FerrisKey API
+ *
+ * The version of the OpenAPI document: 0.7.0
+ * Contact: pro.nathaelbonnal@gmail.com
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WebOrigin {
+    #[serde(rename = "client_id")]
+    pub client_id: uuid::Uuid,
+    #[serde(rename = "created_at")]
+    pub created_at: String,
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+    #[serde(rename = "updated_at")]
+    pub updated_at: String,
+    #[serde(rename = "value")]
+    pub value: String,
+}
+
+impl WebOrigin {
+    pub fn new(
+        client_id: uuid::Uuid,
+        created_at: String,
+        id: uuid::Uuid,
+        updated_at: String,
+        value: String,
+    ) -> WebOrigin {
+        WebOrigin {
+            client_id,
+            created_at,
+            id,
+            updated_at,
+            value,
+        }
+    }
+}
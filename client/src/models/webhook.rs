@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -11,6 +11,7 @@
 use crate::models;
 use serde::{Deserialize, Serialize};
 
+/// Webhook : A configured webhook subscription.  `headers` and `secret` are `skip_serializing`: no serialized representation of a `Webhook` — an HTTP read response, or a `webhook.*` notification naming this row as its payload `data` — can disclose this webhook's stored credentials or another webhook's. Both fields stay plain, unrestricted struct fields otherwise: the code that composes an outbound delivery for this webhook reads them directly, which is the only path meant to see them again after creation.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Webhook {
     #[serde(rename = "created_at")]
@@ -24,8 +25,6 @@ pub struct Webhook {
     pub description: Option<Option<String>>,
     #[serde(rename = "endpoint")]
     pub endpoint: String,
-    #[serde(rename = "headers")]
-    pub headers: std::collections::HashMap<String, String>,
     #[serde(rename = "id")]
     pub id: uuid::Uuid,
     #[serde(
@@ -49,10 +48,10 @@ pub struct Webhook {
 }
 
 impl Webhook {
+    /// A configured webhook subscription.  `headers` and `secret` are `skip_serializing`: no serialized representation of a `Webhook` — an HTTP read response, or a `webhook.*` notification naming this row as its payload `data` — can disclose this webhook's stored credentials or another webhook's. Both fields stay plain, unrestricted struct fields otherwise: the code that composes an outbound delivery for this webhook reads them directly, which is the only path meant to see them again after creation.
     pub fn new(
         created_at: String,
         endpoint: String,
-        headers: std::collections::HashMap<String, String>,
         id: uuid::Uuid,
         subscribers: Vec<models::WebhookSubscriber>,
         updated_at: String,
@@ -61,7 +60,6 @@ impl Webhook {
             created_at,
             description: None,
             endpoint,
-            headers,
             id,
             name: None,
             subscribers,
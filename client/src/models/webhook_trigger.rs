@@ -3,7 +3,7 @@
  *
  * FerrisKey API
  *
- * The version of the OpenAPI document: 0.1.0
+ * The version of the OpenAPI document: 0.7.0
  * Contact: pro.nathaelbonnal@gmail.com
  * Generated by: https://openapi-generator.tech
  */
@@ -11,7 +11,6 @@
 use crate::models;
 use serde::{Deserialize, Serialize};
 
-///
 #[derive(
     Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
 )]
@@ -19,6 +18,8 @@ pub enum WebhookTrigger {
     #[serde(rename = "user.created")]
     #[default]
     UserCreated,
+    #[serde(rename = "user.email_verified")]
+    UserEmailVerified,
     #[serde(rename = "user.updated")]
     UserUpdated,
     #[serde(rename = "user.deleted")]
@@ -33,6 +34,12 @@ pub enum WebhookTrigger {
     UserCredentialsDeleted,
     #[serde(rename = "auth.reset_password")]
     AuthResetPassword,
+    #[serde(rename = "auth.device_flow.initiated")]
+    AuthDeviceFlowInitiated,
+    #[serde(rename = "auth.device_flow.denied")]
+    AuthDeviceFlowDenied,
+    #[serde(rename = "auth.device_flow.expired")]
+    AuthDeviceFlowExpired,
     #[serde(rename = "client.created")]
     ClientCreated,
     #[serde(rename = "client.updated")]
@@ -49,6 +56,10 @@ pub enum WebhookTrigger {
     RedirectUriUpdated,
     #[serde(rename = "redirect_uri.deleted")]
     RedirectUriDeleted,
+    #[serde(rename = "web_origin.created")]
+    WebOriginCreated,
+    #[serde(rename = "web_origin.deleted")]
+    WebOriginDeleted,
     #[serde(rename = "role.created")]
     RoleCreated,
     #[serde(rename = "role.updated")]
@@ -71,12 +82,21 @@ pub enum WebhookTrigger {
     WebhookUpdated,
     #[serde(rename = "webhook.deleted")]
     WebhookDeleted,
+    #[serde(rename = "client.maintenance.enabled")]
+    ClientMaintenanceEnabled,
+    #[serde(rename = "client.maintenance.disabled")]
+    ClientMaintenanceDisabled,
+    #[serde(rename = "maintenance.window.started")]
+    MaintenanceWindowStarted,
+    #[serde(rename = "maintenance.window.ended")]
+    MaintenanceWindowEnded,
 }
 
 impl std::fmt::Display for WebhookTrigger {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::UserCreated => write!(f, "user.created"),
+            Self::UserEmailVerified => write!(f, "user.email_verified"),
             Self::UserUpdated => write!(f, "user.updated"),
             Self::UserDeleted => write!(f, "user.deleted"),
             Self::UserRoleAssigned => write!(f, "user.role.assigned"),
@@ -84,6 +104,9 @@ impl std::fmt::Display for WebhookTrigger {
             Self::UserBulkDeleted => write!(f, "user.bulk_deleted"),
             Self::UserCredentialsDeleted => write!(f, "user.credentials.deleted"),
             Self::AuthResetPassword => write!(f, "auth.reset_password"),
+            Self::AuthDeviceFlowInitiated => write!(f, "auth.device_flow.initiated"),
+            Self::AuthDeviceFlowDenied => write!(f, "auth.device_flow.denied"),
+            Self::AuthDeviceFlowExpired => write!(f, "auth.device_flow.expired"),
             Self::ClientCreated => write!(f, "client.created"),
             Self::ClientUpdated => write!(f, "client.updated"),
             Self::ClientDeleted => write!(f, "client.deleted"),
@@ -92,6 +115,8 @@ impl std::fmt::Display for WebhookTrigger {
             Self::RedirectUriCreated => write!(f, "redirect_uri.created"),
             Self::RedirectUriUpdated => write!(f, "redirect_uri.updated"),
             Self::RedirectUriDeleted => write!(f, "redirect_uri.deleted"),
+            Self::WebOriginCreated => write!(f, "web_origin.created"),
+            Self::WebOriginDeleted => write!(f, "web_origin.deleted"),
             Self::RoleCreated => write!(f, "role.created"),
             Self::RoleUpdated => write!(f, "role.updated"),
             Self::RoleDeleted => write!(f, "role.deleted"),
@@ -103,6 +128,10 @@ impl std::fmt::Display for WebhookTrigger {
             Self::WebhookCreated => write!(f, "webhook.created"),
             Self::WebhookUpdated => write!(f, "webhook.updated"),
             Self::WebhookDeleted => write!(f, "webhook.deleted"),
+            Self::ClientMaintenanceEnabled => write!(f, "client.maintenance.enabled"),
+            Self::ClientMaintenanceDisabled => write!(f, "client.maintenance.disabled"),
+            Self::MaintenanceWindowStarted => write!(f, "maintenance.window.started"),
+            Self::MaintenanceWindowEnded => write!(f, "maintenance.window.ended"),
         }
     }
 }
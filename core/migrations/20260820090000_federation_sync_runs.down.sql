@@ -0,0 +1,6 @@
+DROP TABLE IF EXISTS user_federation_sync_locks;
+DROP TABLE IF EXISTS user_federation_sync_runs;
+
+ALTER TABLE user_federation_providers
+    DROP COLUMN IF EXISTS changed_sync_interval_minutes,
+    DROP COLUMN IF EXISTS full_sync_cron;
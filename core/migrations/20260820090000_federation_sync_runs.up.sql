@@ -0,0 +1,33 @@
+ALTER TABLE user_federation_providers
+    ADD COLUMN full_sync_cron VARCHAR(120),
+    ADD COLUMN changed_sync_interval_minutes INTEGER;
+
+CREATE TABLE user_federation_sync_runs (
+    id UUID PRIMARY KEY,
+    provider_id UUID NOT NULL REFERENCES user_federation_providers(id) ON DELETE CASCADE,
+    kind VARCHAR(20) NOT NULL,
+    triggered_by VARCHAR(20) NOT NULL,
+    mode VARCHAR(20) NOT NULL,
+    status VARCHAR(50) NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL,
+    completed_at TIMESTAMPTZ,
+    total_processed INTEGER NOT NULL DEFAULT 0,
+    created INTEGER NOT NULL DEFAULT 0,
+    updated INTEGER NOT NULL DEFAULT 0,
+    disabled INTEGER NOT NULL DEFAULT 0,
+    failed INTEGER NOT NULL DEFAULT 0,
+    groups_added INTEGER NOT NULL DEFAULT 0,
+    groups_removed INTEGER NOT NULL DEFAULT 0,
+    errors JSONB NOT NULL DEFAULT '[]',
+    change_marker VARCHAR(64)
+);
+
+CREATE INDEX idx_federation_sync_runs_provider_started
+ON user_federation_sync_runs(provider_id, started_at DESC);
+
+-- One row per provider while a replica is syncing it; expired leases can be taken over.
+CREATE TABLE user_federation_sync_locks (
+    provider_id UUID PRIMARY KEY REFERENCES user_federation_providers(id) ON DELETE CASCADE,
+    holder VARCHAR(64) NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL
+);
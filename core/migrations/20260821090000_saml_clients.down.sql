@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS saml_signing_certificates;
+DROP TABLE IF EXISTS saml_auth_requests;
+DROP TABLE IF EXISTS saml_client_settings;
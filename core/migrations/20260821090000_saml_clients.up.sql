@@ -0,0 +1,40 @@
+CREATE TABLE saml_client_settings (
+    client_id UUID PRIMARY KEY REFERENCES clients(id) ON DELETE CASCADE,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    assertion_consumer_services JSONB NOT NULL DEFAULT '[]',
+    single_logout_service JSONB,
+    name_id_format VARCHAR(32) NOT NULL DEFAULT 'unspecified',
+    sign_response BOOLEAN NOT NULL DEFAULT TRUE,
+    sign_assertions BOOLEAN NOT NULL DEFAULT TRUE,
+    encrypt_assertions BOOLEAN NOT NULL DEFAULT FALSE,
+    require_signed_requests BOOLEAN NOT NULL DEFAULT FALSE,
+    signing_certificate TEXT,
+    encryption_certificate TEXT,
+    idp_initiated_sso_url_name VARCHAR(255),
+    idp_initiated_relay_state TEXT,
+    assertion_lifetime_seconds INTEGER NOT NULL DEFAULT 300,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_saml_client_settings_idp_initiated
+ON saml_client_settings(realm_id, idp_initiated_sso_url_name)
+WHERE idp_initiated_sso_url_name IS NOT NULL;
+
+-- The AuthnRequest an auth session answers, until its login completes.
+CREATE TABLE saml_auth_requests (
+    auth_session_id UUID PRIMARY KEY REFERENCES auth_sessions(id) ON DELETE CASCADE,
+    request_id VARCHAR(255),
+    acs_url TEXT NOT NULL,
+    relay_state TEXT,
+    name_id_format VARCHAR(32),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Self-signed certificate publishing a realm's signing key in SAML metadata.
+CREATE TABLE saml_signing_certificates (
+    realm_id UUID PRIMARY KEY REFERENCES realms(id) ON DELETE CASCADE,
+    key_id UUID NOT NULL REFERENCES jwt_keys(id) ON DELETE CASCADE,
+    certificate TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,11 @@
+CREATE TABLE identity_provider_mappers (
+    id UUID PRIMARY KEY,
+    identity_provider_id UUID NOT NULL REFERENCES identity_providers(id) ON DELETE CASCADE,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    sync_mode VARCHAR(16) NOT NULL DEFAULT 'import',
+    config JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (identity_provider_id, name)
+);
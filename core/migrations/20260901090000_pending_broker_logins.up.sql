@@ -0,0 +1,20 @@
+CREATE TABLE pending_broker_logins (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    identity_provider_id UUID NOT NULL REFERENCES identity_providers(id) ON DELETE CASCADE,
+    broker_session_id UUID NOT NULL REFERENCES broker_auth_sessions(id) ON DELETE CASCADE,
+    token VARCHAR(255) NOT NULL UNIQUE,
+    stage VARCHAR(32) NOT NULL,
+    brokered_user JSONB NOT NULL,
+    idp_access_token TEXT,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
+    is_new_user BOOLEAN NOT NULL DEFAULT FALSE,
+    confirmation_code_hash VARCHAR(255),
+    confirmation_code_expires_at TIMESTAMPTZ,
+    failed_attempts INT NOT NULL DEFAULT 0,
+    compass_flow_id UUID,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_pending_broker_logins_expires_at ON pending_broker_logins(expires_at);
@@ -0,0 +1,4 @@
+ALTER TABLE realm_settings DROP COLUMN IF EXISTS organization_invitation_template_id;
+
+DROP TABLE IF EXISTS organization_domains;
+DROP TABLE IF EXISTS organization_invitations;
@@ -0,0 +1,42 @@
+-- Emailed invitations to join an organization. Only the SHA-256 of the invitation token
+-- is stored; the role and group are granted when the invitee accepts.
+
+CREATE TABLE organization_invitations (
+    id               UUID         PRIMARY KEY,
+    organization_id  UUID         NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    email            VARCHAR(255) NOT NULL,
+    role_id          UUID         REFERENCES roles(id) ON DELETE SET NULL,
+    group_id         UUID         REFERENCES organization_groups(id) ON DELETE SET NULL,
+    invited_by       UUID         REFERENCES users(id) ON DELETE SET NULL,
+    token_hash       VARCHAR(64)  NOT NULL UNIQUE,
+    expires_at       TIMESTAMPTZ  NOT NULL,
+    accepted_at      TIMESTAMPTZ,
+    accepted_by      UUID         REFERENCES users(id) ON DELETE SET NULL,
+    revoked_at       TIMESTAMPTZ,
+    created_at       TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_organization_invitations_organization_id ON organization_invitations(organization_id);
+CREATE INDEX idx_organization_invitations_email ON organization_invitations(organization_id, email);
+
+-- The organization's email domain, its DNS TXT proof and the identity provider its users
+-- are routed to at login. A domain can be verified by only one organization per realm.
+
+CREATE TABLE organization_domains (
+    organization_id       UUID         PRIMARY KEY REFERENCES organizations(id) ON DELETE CASCADE,
+    realm_id              UUID         NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    domain                VARCHAR(255) NOT NULL,
+    verification_token    VARCHAR(64)  NOT NULL,
+    verified_at           TIMESTAMPTZ,
+    identity_provider_id  UUID         REFERENCES identity_providers(id) ON DELETE SET NULL,
+    created_at            TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    updated_at            TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX uq_organization_domains_verified
+    ON organization_domains(realm_id, domain)
+    WHERE verified_at IS NOT NULL;
+
+ALTER TABLE realm_settings
+  ADD COLUMN organization_invitation_template_id UUID NULL
+    CONSTRAINT fk_organization_invitation_template REFERENCES email_templates (id) ON DELETE SET NULL;
@@ -0,0 +1,4 @@
+DELETE FROM roles
+WHERE id IN (SELECT role_id FROM organization_admin_roles);
+
+DROP TABLE IF EXISTS organization_admin_roles;
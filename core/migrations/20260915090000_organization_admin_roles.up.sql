@@ -0,0 +1,28 @@
+-- Realm roles seeded as organization admin roles. Only roles recorded here are honoured as
+-- such: a realm role a customer created under one of the names grants nothing and is left
+-- alone by the down migration.
+CREATE TABLE organization_admin_roles (
+    role_id      UUID        PRIMARY KEY REFERENCES roles(id) ON DELETE CASCADE,
+    realm_id     UUID        NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    admin_role   VARCHAR(64) NOT NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_organization_admin_role UNIQUE (realm_id, admin_role)
+);
+
+-- Seed the organization-scoped admin roles in every existing realm. They carry no realm
+-- permissions: authority comes from assigning them to an organization membership.
+WITH seeded AS (
+    INSERT INTO roles (id, name, description, permissions, realm_id, client_id, created_at, updated_at)
+    SELECT gen_random_uuid(), seed.name, seed.description, 0, realms.id, NULL, NOW(), NOW()
+    FROM realms
+    CROSS JOIN (
+        VALUES
+            ('org-owner', 'Full control over an organization, including its administrators'),
+            ('org-admin', 'Manage an organization, its members, groups and invitations'),
+            ('org-member-manager', 'Manage the members and invitations of an organization')
+    ) AS seed(name, description)
+    ON CONFLICT (name, realm_id) DO NOTHING
+    RETURNING id, realm_id, name
+)
+INSERT INTO organization_admin_roles (role_id, realm_id, admin_role)
+SELECT id, realm_id, name FROM seeded;
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS realm_group_attributes;
+DROP TABLE IF EXISTS realm_group_roles;
+DROP TABLE IF EXISTS realm_group_members;
+DROP TABLE IF EXISTS realm_groups;
@@ -0,0 +1,10 @@
+DROP TRIGGER IF EXISTS access_tokens_cache_notify ON access_tokens;
+DROP TRIGGER IF EXISTS client_scope_protocol_mappers_cache_notify ON client_scope_protocol_mappers;
+DROP TRIGGER IF EXISTS client_scope_mappings_cache_notify ON client_scope_mappings;
+DROP TRIGGER IF EXISTS client_scopes_cache_notify ON client_scopes;
+DROP TRIGGER IF EXISTS jwt_keys_cache_notify ON jwt_keys;
+DROP TRIGGER IF EXISTS redirect_uris_cache_notify ON redirect_uris;
+DROP TRIGGER IF EXISTS clients_cache_notify ON clients;
+DROP TRIGGER IF EXISTS realm_settings_cache_notify ON realm_settings;
+DROP TRIGGER IF EXISTS realms_cache_notify ON realms;
+DROP FUNCTION IF EXISTS ferriskey_cache_notify();
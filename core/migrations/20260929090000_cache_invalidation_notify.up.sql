@@ -0,0 +1,45 @@
+-- Publishes the name of every changed table on the `ferriskey_cache` channel so each
+-- replica can drop its in-process copies of the affected rows.
+CREATE OR REPLACE FUNCTION ferriskey_cache_notify() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify('ferriskey_cache', TG_TABLE_NAME);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER realms_cache_notify
+    AFTER INSERT OR UPDATE OR DELETE ON realms
+    FOR EACH STATEMENT EXECUTE FUNCTION ferriskey_cache_notify();
+
+CREATE TRIGGER realm_settings_cache_notify
+    AFTER INSERT OR UPDATE OR DELETE ON realm_settings
+    FOR EACH STATEMENT EXECUTE FUNCTION ferriskey_cache_notify();
+
+CREATE TRIGGER clients_cache_notify
+    AFTER INSERT OR UPDATE OR DELETE ON clients
+    FOR EACH STATEMENT EXECUTE FUNCTION ferriskey_cache_notify();
+
+CREATE TRIGGER redirect_uris_cache_notify
+    AFTER INSERT OR UPDATE OR DELETE ON redirect_uris
+    FOR EACH STATEMENT EXECUTE FUNCTION ferriskey_cache_notify();
+
+CREATE TRIGGER jwt_keys_cache_notify
+    AFTER INSERT OR UPDATE OR DELETE ON jwt_keys
+    FOR EACH STATEMENT EXECUTE FUNCTION ferriskey_cache_notify();
+
+CREATE TRIGGER client_scopes_cache_notify
+    AFTER INSERT OR UPDATE OR DELETE ON client_scopes
+    FOR EACH STATEMENT EXECUTE FUNCTION ferriskey_cache_notify();
+
+CREATE TRIGGER client_scope_mappings_cache_notify
+    AFTER INSERT OR UPDATE OR DELETE ON client_scope_mappings
+    FOR EACH STATEMENT EXECUTE FUNCTION ferriskey_cache_notify();
+
+CREATE TRIGGER client_scope_protocol_mappers_cache_notify
+    AFTER INSERT OR UPDATE OR DELETE ON client_scope_protocol_mappers
+    FOR EACH STATEMENT EXECUTE FUNCTION ferriskey_cache_notify();
+
+-- New tokens cannot be cached yet, only revocations and deletions matter.
+CREATE TRIGGER access_tokens_cache_notify
+    AFTER UPDATE OR DELETE ON access_tokens
+    FOR EACH STATEMENT EXECUTE FUNCTION ferriskey_cache_notify();
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS rate_limit_counters;
+DROP TABLE IF EXISTS rate_limit_list_entries;
+DROP TABLE IF EXISTS rate_limit_policies;
@@ -0,0 +1,42 @@
+-- Per-realm rate limits for the authentication endpoints. A realm without rows here gets the
+-- built-in defaults; a row overrides the default for its (endpoint, key_kind) pair.
+
+CREATE TABLE rate_limit_policies (
+    id             UUID        PRIMARY KEY,
+    realm_id       UUID        NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    endpoint       VARCHAR(64) NOT NULL,
+    key_kind       VARCHAR(32) NOT NULL,
+    "limit"        INTEGER     NOT NULL CHECK ("limit" > 0),
+    window_seconds INTEGER     NOT NULL CHECK (window_seconds > 0),
+    block_seconds  INTEGER     NOT NULL DEFAULT 0 CHECK (block_seconds >= 0),
+    shared         BOOLEAN     NOT NULL DEFAULT FALSE,
+    enabled        BOOLEAN     NOT NULL DEFAULT TRUE,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_rate_limit_policy UNIQUE (realm_id, endpoint, key_kind)
+);
+
+-- Client address ranges that bypass every limit (allow) or are rejected outright (deny).
+CREATE TABLE rate_limit_list_entries (
+    id          UUID        PRIMARY KEY,
+    realm_id    UUID        NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    kind        VARCHAR(16) NOT NULL CHECK (kind IN ('allow', 'deny')),
+    cidr        VARCHAR(64) NOT NULL,
+    description TEXT,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_rate_limit_list_entry UNIQUE (realm_id, kind, cidr)
+);
+
+CREATE INDEX idx_rate_limit_list_entries_realm_id ON rate_limit_list_entries(realm_id);
+
+-- Fixed-window hit counters shared by every replica, for policies flagged `shared`.
+-- Unlogged: losing them on a crash only resets the current windows.
+CREATE UNLOGGED TABLE rate_limit_counters (
+    key          VARCHAR(512) NOT NULL,
+    window_start TIMESTAMPTZ  NOT NULL,
+    hits         INTEGER      NOT NULL DEFAULT 0,
+    expires_at   TIMESTAMPTZ  NOT NULL,
+    PRIMARY KEY (key, window_start)
+);
+
+CREATE INDEX idx_rate_limit_counters_expires_at ON rate_limit_counters(expires_at);
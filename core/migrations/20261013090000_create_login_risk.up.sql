@@ -0,0 +1,33 @@
+-- Login risk settings, one row per realm. A realm without a row has risk evaluation disabled.
+CREATE TABLE login_risk_policies (
+    realm_id                UUID        PRIMARY KEY REFERENCES realms(id) ON DELETE CASCADE,
+    enabled                 BOOLEAN     NOT NULL DEFAULT FALSE,
+    new_device_score        SMALLINT    NOT NULL CHECK (new_device_score BETWEEN 0 AND 100),
+    new_country_score       SMALLINT    NOT NULL CHECK (new_country_score BETWEEN 0 AND 100),
+    impossible_travel_score SMALLINT    NOT NULL CHECK (impossible_travel_score BETWEEN 0 AND 100),
+    max_travel_speed_kmh    INTEGER     NOT NULL CHECK (max_travel_speed_kmh > 0),
+    step_up_threshold       SMALLINT    CHECK (step_up_threshold BETWEEN 1 AND 100),
+    notify_threshold        SMALLINT    CHECK (notify_threshold BETWEEN 1 AND 100),
+    block_threshold         SMALLINT    CHECK (block_threshold BETWEEN 1 AND 100),
+    created_at              TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at              TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- The logins new ones are compared with. Blocked attempts are not recorded here.
+CREATE TABLE login_history (
+    id                 UUID             PRIMARY KEY,
+    realm_id           UUID             NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    user_id            UUID             NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    ip_address         VARCHAR(64),
+    user_agent         TEXT,
+    device_fingerprint VARCHAR(64),
+    country            VARCHAR(2),
+    latitude           DOUBLE PRECISION,
+    longitude          DOUBLE PRECISION,
+    risk_score         SMALLINT         NOT NULL DEFAULT 0,
+    signals            JSONB            NOT NULL DEFAULT '[]'::jsonb,
+    created_at         TIMESTAMPTZ      NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_login_history_user_id_created_at ON login_history(user_id, created_at DESC);
+CREATE INDEX idx_login_history_created_at ON login_history(created_at);
@@ -0,0 +1,7 @@
+-- Declarative user profile, one row per realm. A realm without a row declares no attributes.
+CREATE TABLE user_profile_schemas (
+    realm_id   UUID        PRIMARY KEY REFERENCES realms(id) ON DELETE CASCADE,
+    schema     JSONB       NOT NULL DEFAULT '{}'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
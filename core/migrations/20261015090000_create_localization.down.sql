@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS message_bundles;
+DROP TABLE IF EXISTS realm_localization_settings;
@@ -0,0 +1,19 @@
+-- Languages each realm serves its login portal and emails in. A realm without a row
+-- supports English only.
+CREATE TABLE realm_localization_settings (
+    realm_id          UUID        PRIMARY KEY REFERENCES realms(id) ON DELETE CASCADE,
+    supported_locales JSONB       NOT NULL DEFAULT '["en"]'::jsonb,
+    default_locale    VARCHAR(35) NOT NULL DEFAULT 'en',
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Realm overrides of the built-in texts, one bundle per locale.
+CREATE TABLE message_bundles (
+    realm_id   UUID        NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    locale     VARCHAR(35) NOT NULL,
+    messages   JSONB       NOT NULL DEFAULT '{}'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (realm_id, locale)
+);
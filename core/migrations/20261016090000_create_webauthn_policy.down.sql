@@ -0,0 +1,7 @@
+ALTER TABLE credentials
+    DROP COLUMN IF EXISTS last_used_at,
+    DROP COLUMN IF EXISTS sign_count,
+    DROP COLUMN IF EXISTS authenticator_name,
+    DROP COLUMN IF EXISTS aaguid;
+
+DROP TABLE IF EXISTS webauthn_policies;
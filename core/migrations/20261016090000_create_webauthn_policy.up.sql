@@ -0,0 +1,31 @@
+-- WebAuthn relying-party settings, one row per realm. A realm without a row uses the passkey
+-- defaults: no attestation, user verification required, any authenticator.
+CREATE TABLE webauthn_policies (
+    realm_id                 UUID        PRIMARY KEY REFERENCES realms(id) ON DELETE CASCADE,
+    attestation              VARCHAR(16) NOT NULL CHECK (attestation IN ('none', 'indirect', 'direct')),
+    user_verification        VARCHAR(16) NOT NULL CHECK (user_verification IN ('required', 'preferred')),
+    require_resident_key     BOOLEAN     NOT NULL DEFAULT FALSE,
+    authenticator_attachment VARCHAR(16) CHECK (authenticator_attachment IN ('platform', 'cross_platform')),
+    allowed_origins          JSONB       NOT NULL DEFAULT '[]'::jsonb,
+    allowed_aaguids          JSONB       NOT NULL DEFAULT '[]'::jsonb,
+    blocked_aaguids          JSONB       NOT NULL DEFAULT '[]'::jsonb,
+    fips_certified_only      BOOLEAN     NOT NULL DEFAULT FALSE,
+    created_at               TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at               TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- What is known about the authenticator behind each WebAuthn credential. The sign count is the
+-- last one the authenticator reported; a count that does not increase betrays a cloned key.
+ALTER TABLE credentials
+    ADD COLUMN aaguid             UUID,
+    ADD COLUMN authenticator_name VARCHAR(255),
+    ADD COLUMN sign_count         BIGINT,
+    ADD COLUMN last_used_at       TIMESTAMP;
+
+UPDATE credentials
+SET aaguid = COALESCE(
+        credential_data #>> '{credential,attestation,metadata,Packed,aaguid}',
+        credential_data #>> '{credential,attestation,metadata,Tpm,aaguid}'
+    )::uuid,
+    sign_count = (credential_data #>> '{credential,counter}')::bigint
+WHERE credential_type = 'webauthn-public-key-credential';
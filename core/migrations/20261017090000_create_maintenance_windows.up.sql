@@ -0,0 +1,24 @@
+-- Planned maintenance of one client (client_id set) or of every client of a realm. The
+-- scheduler moves a window from scheduled to active at starts_at and to completed at ends_at.
+CREATE TABLE maintenance_windows (
+    id                  UUID        PRIMARY KEY,
+    realm_id            UUID        NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    client_id           UUID        REFERENCES clients(id) ON DELETE CASCADE,
+    reason              TEXT        NOT NULL,
+    session_strategy    VARCHAR(16) NOT NULL CHECK (session_strategy IN ('terminate', 'expire')),
+    announce_at         TIMESTAMPTZ NOT NULL,
+    starts_at           TIMESTAMPTZ NOT NULL,
+    ends_at             TIMESTAMPTZ NOT NULL,
+    status              VARCHAR(16) NOT NULL DEFAULT 'scheduled'
+                        CHECK (status IN ('scheduled', 'active', 'completed', 'cancelled')),
+    -- Clients the window put under maintenance when it started, released when it ends.
+    affected_client_ids JSONB       NOT NULL DEFAULT '[]'::jsonb,
+    created_by          UUID        NOT NULL,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT chk_maintenance_window_range CHECK (starts_at < ends_at AND announce_at <= starts_at)
+);
+
+CREATE INDEX idx_maintenance_windows_realm_id ON maintenance_windows(realm_id, starts_at);
+CREATE INDEX idx_maintenance_windows_pending ON maintenance_windows(status, starts_at, ends_at)
+    WHERE status IN ('scheduled', 'active');
@@ -0,0 +1,3 @@
+ALTER TABLE smtp_configs DROP COLUMN IF EXISTS max_emails_per_minute;
+
+DROP TABLE IF EXISTS email_outbox;
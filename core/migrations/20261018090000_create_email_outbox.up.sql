@@ -0,0 +1,29 @@
+-- Every email goes through the outbox: persisted first, then delivered by the outbox
+-- worker, which retries failed attempts with backoff until max attempts are spent.
+CREATE TABLE email_outbox (
+    id              UUID         PRIMARY KEY,
+    realm_id        UUID         NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    recipient       VARCHAR(320) NOT NULL,
+    subject         TEXT         NOT NULL,
+    body            TEXT         NOT NULL,
+    html_body       TEXT,
+    status          VARCHAR(16)  NOT NULL DEFAULT 'pending'
+                    CHECK (status IN ('pending', 'sending', 'sent', 'failed')),
+    attempts        INTEGER      NOT NULL DEFAULT 0,
+    -- Next attempt for pending emails, end of the claim for sending ones.
+    next_attempt_at TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    last_error      TEXT,
+    sent_at         TIMESTAMPTZ,
+    created_at      TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_email_outbox_realm_id ON email_outbox(realm_id, created_at DESC);
+CREATE INDEX idx_email_outbox_due ON email_outbox(next_attempt_at)
+    WHERE status IN ('pending', 'sending');
+CREATE INDEX idx_email_outbox_sent ON email_outbox(realm_id, sent_at)
+    WHERE status = 'sent';
+
+-- Per-realm send rate of the outbox worker; NULL means unlimited.
+ALTER TABLE smtp_configs
+    ADD COLUMN max_emails_per_minute INTEGER CHECK (max_emails_per_minute > 0);
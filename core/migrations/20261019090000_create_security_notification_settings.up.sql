@@ -0,0 +1,10 @@
+-- Per-realm settings of the security notifications. A kind without a row is enabled and
+-- uses the built-in template.
+CREATE TABLE security_notification_settings (
+    realm_id    UUID        NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    kind        VARCHAR(50) NOT NULL,
+    enabled     BOOLEAN     NOT NULL DEFAULT TRUE,
+    template_id UUID        REFERENCES email_templates(id) ON DELETE SET NULL,
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (realm_id, kind)
+);
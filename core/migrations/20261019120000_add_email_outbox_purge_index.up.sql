@@ -0,0 +1,4 @@
+-- Sent and failed emails are deleted once past the retention period, so their bodies and
+-- the tokens they carry do not stay in the database.
+CREATE INDEX idx_email_outbox_finished ON email_outbox(updated_at)
+    WHERE status IN ('sent', 'failed');
@@ -2,7 +2,7 @@ use crate::{
     ApplicationService,
     domain::{
         abyss::federation::{
-            entities::{FederationProvider, SyncMode},
+            entities::{FederationProvider, FederationSyncRun, SyncMode},
             ports::FederationService,
             value_objects::{
                 CreateProviderRequest, SyncResult, TestConnectionResult, UpdateProviderRequest,
@@ -92,4 +92,20 @@ impl FederationService for ApplicationService {
             .sync_federation_users(identity, realm_name, id, mode)
             .await
     }
+
+    async fn list_federation_sync_runs(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<FederationSyncRun>, CoreError> {
+        self.federation_service
+            .list_federation_sync_runs(identity, realm_name, id, limit)
+            .await
+    }
+
+    async fn run_scheduled_syncs(&self) -> Result<u32, CoreError> {
+        self.federation_service.run_scheduled_syncs().await
+    }
 }
@@ -2,8 +2,11 @@ use crate::{
     ApplicationService,
     domain::{
         abyss::identity_provider::broker::{
-            self, BrokerCallbackInput, BrokerCallbackOutput, BrokerLoginOutput, BrokerService,
-            BrokeredUserInfo, OAuthProviderConfig, OAuthTokenResponse,
+            self, BrokerCallbackInput, BrokerLoginOutcome, BrokerLoginOutput,
+            BrokerSamlMetadataInput, BrokerSamlResponseInput, BrokerService, BrokeredUserInfo,
+            ConfirmBrokerLinkInput, OAuthProviderConfig, OAuthTokenResponse,
+            PendingBrokerLoginInput, PendingBrokerLoginView, ReviewBrokeredProfileInput,
+            VerifyBrokerLoginOtpInput,
         },
         common::entities::app_errors::CoreError,
     },
@@ -24,10 +27,56 @@ impl BrokerService for ApplicationService {
     async fn handle_callback(
         &self,
         input: BrokerCallbackInput,
-    ) -> Result<BrokerCallbackOutput, CoreError> {
+    ) -> Result<BrokerLoginOutcome, CoreError> {
         self.broker_service.handle_callback(input).await
     }
 
+    async fn handle_saml_response(
+        &self,
+        input: BrokerSamlResponseInput,
+    ) -> Result<BrokerLoginOutcome, CoreError> {
+        self.broker_service.handle_saml_response(input).await
+    }
+
+    async fn get_saml_metadata(&self, input: BrokerSamlMetadataInput) -> Result<String, CoreError> {
+        self.broker_service.get_saml_metadata(input).await
+    }
+
+    async fn get_pending_login(
+        &self,
+        input: PendingBrokerLoginInput,
+    ) -> Result<PendingBrokerLoginView, CoreError> {
+        self.broker_service.get_pending_login(input).await
+    }
+
+    async fn review_brokered_profile(
+        &self,
+        input: ReviewBrokeredProfileInput,
+    ) -> Result<BrokerLoginOutcome, CoreError> {
+        self.broker_service.review_brokered_profile(input).await
+    }
+
+    async fn send_link_confirmation_code(
+        &self,
+        input: PendingBrokerLoginInput,
+    ) -> Result<(), CoreError> {
+        self.broker_service.send_link_confirmation_code(input).await
+    }
+
+    async fn confirm_link(
+        &self,
+        input: ConfirmBrokerLinkInput,
+    ) -> Result<BrokerLoginOutcome, CoreError> {
+        self.broker_service.confirm_link(input).await
+    }
+
+    async fn verify_login_otp(
+        &self,
+        input: VerifyBrokerLoginOtpInput,
+    ) -> Result<BrokerLoginOutcome, CoreError> {
+        self.broker_service.verify_login_otp(input).await
+    }
+
     async fn initiate_login(
         &self,
         input: broker::BrokerLoginInput,
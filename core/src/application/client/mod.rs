@@ -129,6 +129,16 @@ impl ClientService for ApplicationService {
             .await
     }
 
+    async fn rotate_client_secret(
+        &self,
+        identity: Identity,
+        input: GetClientInput,
+    ) -> Result<String, CoreError> {
+        self.client_service
+            .rotate_client_secret(identity, input)
+            .await
+    }
+
     async fn get_client_roles(
         &self,
         identity: Identity,
@@ -1,7 +1,10 @@
 use ferriskey_compass::{
     entities::CompassFlow,
     ports::CompassService,
-    value_objects::{DailyActivityStats, DailyActivityStatsFilter, FetchFlowsInput, FlowStats},
+    value_objects::{
+        DailyActivityStats, DailyActivityStatsFilter, FetchFlowsInput, FlowAnalytics,
+        FlowAnalyticsComparison, FlowAnalyticsFilter, FlowStats,
+    },
 };
 use uuid::Uuid;
 
@@ -48,4 +51,38 @@ impl CompassService for ApplicationService {
             .get_daily_activity_stats(identity, realm_name, filter)
             .await
     }
+
+    async fn get_flow_analytics(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        filter: FlowAnalyticsFilter,
+    ) -> Result<FlowAnalytics, CoreError> {
+        self.compass_service
+            .get_flow_analytics(identity, realm_name, filter)
+            .await
+    }
+
+    async fn compare_flow_analytics(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        baseline: FlowAnalyticsFilter,
+        current: FlowAnalyticsFilter,
+    ) -> Result<FlowAnalyticsComparison, CoreError> {
+        self.compass_service
+            .compare_flow_analytics(identity, realm_name, baseline, current)
+            .await
+    }
+
+    async fn export_flow_metrics(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        filter: FlowAnalyticsFilter,
+    ) -> Result<String, CoreError> {
+        self.compass_service
+            .export_flow_metrics(identity, realm_name, filter)
+            .await
+    }
 }
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        email_outbox::{
+            entities::OutboxEmail,
+            ports::{EmailOutboxService, GetOutboxEmailsInput, ResendOutboxEmailInput},
+        },
+    },
+};
+
+impl EmailOutboxService for ApplicationService {
+    async fn get_outbox_emails(
+        &self,
+        identity: Identity,
+        input: GetOutboxEmailsInput,
+    ) -> Result<Vec<OutboxEmail>, CoreError> {
+        self.email_outbox_service
+            .get_outbox_emails(identity, input)
+            .await
+    }
+
+    async fn resend_outbox_email(
+        &self,
+        identity: Identity,
+        input: ResendOutboxEmailInput,
+    ) -> Result<OutboxEmail, CoreError> {
+        self.email_outbox_service
+            .resend_outbox_email(identity, input)
+            .await
+    }
+
+    async fn deliver_due_emails(&self) -> Result<usize, CoreError> {
+        self.email_outbox_service.deliver_due_emails().await
+    }
+
+    async fn purge_finished_emails(&self, before: DateTime<Utc>) -> Result<u64, CoreError> {
+        self.email_outbox_service
+            .purge_finished_emails(before)
+            .await
+    }
+}
@@ -1,11 +1,18 @@
 use crate::domain::{
     abyss::identity_provider::{
+        discovery::{DiscoveredIdentityProviderConfiguration, IdentityProviderValidationReport},
         entities::{
             CreateIdentityProviderInput, DeleteIdentityProviderInput,
-            DeleteIdentityProviderLinkInput, GetIdentityProviderInput, IdentityProvider,
-            IdentityProviderLinkView, ListIdentityProviderLinksInput, ListIdentityProvidersInput,
+            DeleteIdentityProviderLinkInput, DiscoverIdentityProviderInput,
+            GetIdentityProviderInput, IdentityProvider, IdentityProviderLinkView,
+            ListIdentityProviderLinksInput, ListIdentityProvidersInput,
             UpdateIdentityProviderInput,
         },
+        mappers::{
+            CreateIdentityProviderMapperInput, DeleteIdentityProviderMapperInput,
+            IdentityProviderMapper, IdentityProviderMapperService,
+            ListIdentityProviderMappersInput, UpdateIdentityProviderMapperInput,
+        },
         ports::IdentityProviderService,
     },
     authentication::value_objects::Identity,
@@ -84,4 +91,72 @@ impl IdentityProviderService for ApplicationService {
             .delete_identity_provider_link(identity, input)
             .await
     }
+
+    async fn discover_identity_provider_configuration(
+        &self,
+        identity: Identity,
+        input: DiscoverIdentityProviderInput,
+    ) -> Result<DiscoveredIdentityProviderConfiguration, CoreError> {
+        self.identity_provider_service
+            .discover_identity_provider_configuration(identity, input)
+            .await
+    }
+
+    async fn validate_identity_provider(
+        &self,
+        identity: Identity,
+        input: GetIdentityProviderInput,
+    ) -> Result<IdentityProviderValidationReport, CoreError> {
+        self.identity_provider_service
+            .validate_identity_provider(identity, input)
+            .await
+    }
+
+    async fn refresh_discovery_metadata(&self) -> Result<u32, CoreError> {
+        self.identity_provider_service
+            .refresh_discovery_metadata()
+            .await
+    }
+}
+
+impl IdentityProviderMapperService for ApplicationService {
+    async fn list_identity_provider_mappers(
+        &self,
+        identity: Identity,
+        input: ListIdentityProviderMappersInput,
+    ) -> Result<Vec<IdentityProviderMapper>, CoreError> {
+        self.identity_provider_mapper_service
+            .list_identity_provider_mappers(identity, input)
+            .await
+    }
+
+    async fn create_identity_provider_mapper(
+        &self,
+        identity: Identity,
+        input: CreateIdentityProviderMapperInput,
+    ) -> Result<IdentityProviderMapper, CoreError> {
+        self.identity_provider_mapper_service
+            .create_identity_provider_mapper(identity, input)
+            .await
+    }
+
+    async fn update_identity_provider_mapper(
+        &self,
+        identity: Identity,
+        input: UpdateIdentityProviderMapperInput,
+    ) -> Result<IdentityProviderMapper, CoreError> {
+        self.identity_provider_mapper_service
+            .update_identity_provider_mapper(identity, input)
+            .await
+    }
+
+    async fn delete_identity_provider_mapper(
+        &self,
+        identity: Identity,
+        input: DeleteIdentityProviderMapperInput,
+    ) -> Result<(), CoreError> {
+        self.identity_provider_mapper_service
+            .delete_identity_provider_mapper(identity, input)
+            .await
+    }
 }
@@ -0,0 +1,88 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        localization::{
+            GetPortalMessagesInput, Locale, LocalizationService, LocalizationSettings,
+            MessageBundle, PortalMessages, PutMessageBundleInput, UpdateLocalizationSettingsInput,
+        },
+    },
+};
+
+impl LocalizationService for ApplicationService {
+    async fn get_localization_settings(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<LocalizationSettings, CoreError> {
+        self.localization_service
+            .get_localization_settings(identity, realm_name)
+            .await
+    }
+
+    async fn update_localization_settings(
+        &self,
+        identity: Identity,
+        input: UpdateLocalizationSettingsInput,
+    ) -> Result<LocalizationSettings, CoreError> {
+        self.localization_service
+            .update_localization_settings(identity, input)
+            .await
+    }
+
+    async fn list_message_bundles(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<MessageBundle>, CoreError> {
+        self.localization_service
+            .list_message_bundles(identity, realm_name)
+            .await
+    }
+
+    async fn get_message_bundle(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        locale: Locale,
+    ) -> Result<MessageBundle, CoreError> {
+        self.localization_service
+            .get_message_bundle(identity, realm_name, locale)
+            .await
+    }
+
+    async fn put_message_bundle(
+        &self,
+        identity: Identity,
+        input: PutMessageBundleInput,
+    ) -> Result<MessageBundle, CoreError> {
+        self.localization_service
+            .put_message_bundle(identity, input)
+            .await
+    }
+
+    async fn delete_message_bundle(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        locale: Locale,
+    ) -> Result<(), CoreError> {
+        self.localization_service
+            .delete_message_bundle(identity, realm_name, locale)
+            .await
+    }
+
+    async fn get_portal_messages(
+        &self,
+        input: GetPortalMessagesInput,
+    ) -> Result<PortalMessages, CoreError> {
+        self.localization_service.get_portal_messages(input).await
+    }
+
+    async fn get_supported_locales(&self, realm_name: String) -> Result<Vec<Locale>, CoreError> {
+        self.localization_service
+            .get_supported_locales(realm_name)
+            .await
+    }
+}
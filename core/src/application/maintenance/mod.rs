@@ -6,9 +6,12 @@ use crate::{
         authentication::value_objects::Identity,
         common::entities::app_errors::CoreError,
         maintenance::{
-            entities::{MaintenanceWhitelistEntry, RealmMaintenanceWhitelistEntry},
+            entities::{
+                MaintenanceAnnouncement, MaintenanceWhitelistEntry, MaintenanceWindow,
+                RealmMaintenanceWhitelistEntry,
+            },
             ports::MaintenanceService,
-            value_objects::ToggleMaintenanceRequest,
+            value_objects::{ScheduleMaintenanceWindowRequest, ToggleMaintenanceRequest},
         },
     },
 };
@@ -128,4 +131,50 @@ impl MaintenanceService for ApplicationService {
             .get_realm_whitelist(identity, realm_name)
             .await
     }
+
+    async fn schedule_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        request: ScheduleMaintenanceWindowRequest,
+    ) -> Result<MaintenanceWindow, CoreError> {
+        self.maintenance_service
+            .schedule_maintenance_window(identity, realm_name, request)
+            .await
+    }
+
+    async fn get_maintenance_windows(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<MaintenanceWindow>, CoreError> {
+        self.maintenance_service
+            .get_maintenance_windows(identity, realm_name)
+            .await
+    }
+
+    async fn cancel_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        window_id: Uuid,
+    ) -> Result<MaintenanceWindow, CoreError> {
+        self.maintenance_service
+            .cancel_maintenance_window(identity, realm_name, window_id)
+            .await
+    }
+
+    async fn get_maintenance_announcements(
+        &self,
+        realm_name: String,
+        client_id: Option<String>,
+    ) -> Result<Vec<MaintenanceAnnouncement>, CoreError> {
+        self.maintenance_service
+            .get_maintenance_announcements(realm_name, client_id)
+            .await
+    }
+
+    async fn run_due_maintenance_windows(&self) -> Result<usize, CoreError> {
+        self.maintenance_service.run_due_maintenance_windows().await
+    }
 }
@@ -5,8 +5,14 @@ use ferriskey_compass::recorder::FlowRecorder;
 use crate::{
     domain::{
         abyss::{
-            BrokerServiceImpl, IdentityProviderServiceImpl,
-            federation::services::FederationServiceImpl,
+            BrokerLoginVerification, BrokerServiceImpl, BrokeredUserMapping,
+            IdentityProviderMapperServiceImpl, IdentityProviderServiceImpl,
+            federation::{
+                group_sync::GroupMapperSync,
+                password_writeback::FederatedPasswordWriter,
+                services::{FederationServiceImpl, federation_sync_scheduler_task},
+            },
+            identity_provider_services::identity_provider_metadata_refresh_task,
         },
         aegis::services::{
             ClientScopeServiceImpl, ProtocolMapperServiceImpl, ScopeMappingServiceImpl,
@@ -16,6 +22,7 @@ use crate::{
                 DeviceFlowConfig, DeviceFlowServiceImpl, purge_expired_device_sessions_task,
             },
             mapper_engine::MapperEngine,
+            saml::services::SamlServiceImpl,
             services::AuthServiceImpl,
         },
         client::services::ClientServiceImpl,
@@ -25,31 +32,57 @@ use crate::{
         },
         compass::services::CompassServiceImpl,
         credential::services::CredentialServiceImpl,
+        email_outbox::services::{
+            EmailOutboxServiceImpl, email_outbox_purge_task, email_outbox_worker_task,
+        },
         email_template::services::EmailTemplateServiceImpl,
         email_verification::services::EmailVerificationServiceImpl,
         health::services::HealthServiceImpl,
-        maintenance::services::MaintenanceServiceImpl,
+        localization::services::LocalizationServiceImpl,
+        maintenance::services::{MaintenanceServiceImpl, maintenance_window_scheduler_task},
+        organization::domain_services::OrganizationDomainServiceImpl,
         organization::group_services::GroupServiceImpl,
+        organization::invitation_services::OrganizationInvitationServiceImpl,
         organization::member_role_services::OrganizationMemberRoleServiceImpl,
+        organization::policies::OrganizationAdminPolicy,
         organization::services::OrganizationServiceImpl,
         password_policy::service::PasswordPolicyService,
         portal_layouts::services::PortalLayoutsServiceImpl,
         portal_theme::services::PortalThemeServiceImpl,
+        rate_limit::services::{RateLimitServiceImpl, purge_expired_rate_limit_counters_task},
         realm::services::{MailServiceImpl, RealmServiceImpl},
+        realm_group::services::RealmGroupServiceImpl,
+        risk::services::{LoginRiskServiceImpl, purge_login_history_task},
         role::services::RoleServiceImpl,
         seawatch::services::SecurityEventServiceImpl,
+        security_notification::services::{EmailSecurityNotifier, SecurityNotificationServiceImpl},
         session::services::UserSessionManagementServiceImpl,
         trident::services::TridentServiceImpl,
         user::services::UserServiceImpl,
+        user_profile::services::UserProfileServiceImpl,
+        webauthn_policy::{MetadataBlob, services::WebAuthnPolicyServiceImpl},
         webhook::services::WebhookServiceImpl,
     },
     infrastructure::{
-        abyss::federation::repository::FederationRepositoryImpl,
+        abyss::federation::{
+            ldap::LdapClientImpl, repository::FederationRepositoryImpl,
+            sync_repository::FederationSyncRepositoryImpl,
+        },
         aegis::repositories::{
             client_scope_postgres_repository::PostgresClientScopeRepository,
             protocol_mapper_postgres_repository::PostgresProtocolMapperRepository,
             scope_mapping_postgres_repository::PostgresScopeMappingRepository,
         },
+        cache::{
+            RepositoryCaches, access_token_repository::CachedAccessTokenRepository,
+            client_repository::CachedClientRepository,
+            client_scope_repository::CachedClientScopeRepository,
+            invalidation::cache_invalidation_listener_task,
+            keystore_repository::CachedKeyStoreRepository,
+            protocol_mapper_repository::CachedProtocolMapperRepository,
+            realm_repository::CachedRealmRepository,
+            scope_mapping_repository::CachedScopeMappingRepository,
+        },
         client::repositories::{
             client_postgres_repository::PostgresClientRepository,
             post_logout_redirect_uri_postgres_repository::PostgresPostLogoutRedirectUriRepository,
@@ -61,7 +94,8 @@ use crate::{
             writer::compass_writer_task,
         },
         db::postgres::{Postgres, PostgresConfig},
-        email::SmtpEmailPort,
+        email::{ConfiguredEmailTransport, OutboxEmailPort},
+        email_outbox::repositories::email_outbox_repository::PostgresEmailOutboxRepository,
         email_template::{
             renderer::mjml_renderer::MjmlTemplateRenderer,
             repositories::email_template_repository::PostgresEmailTemplateRepository,
@@ -69,27 +103,48 @@ use crate::{
         health::repositories::PostgresHealthCheckRepository,
         identity_provider::{
             PostgresBrokerAuthSessionRepository, PostgresIdentityProviderLinkRepository,
-            PostgresIdentityProviderRepository, ReqwestOAuthClient,
+            PostgresIdentityProviderMapperRepository, PostgresIdentityProviderRepository,
+            PostgresPendingBrokerLoginRepository, ReqwestOAuthClient,
+        },
+        localization::{
+            bundle_repository::PostgresMessageBundleRepository,
+            settings_repository::PostgresLocalizationSettingsRepository,
         },
         maintenance::repositories::{
             maintenance_whitelist_repository::PostgresMaintenanceWhitelistRepository,
+            maintenance_window_repository::PostgresMaintenanceWindowRepository,
             realm_maintenance_whitelist_repository::PostgresRealmMaintenanceWhitelistRepository,
         },
         organization::{
+            dns_resolver::DohTxtResolver,
             group_attribute_repository::PostgresGroupAttributeRepository,
             group_member_repository::PostgresGroupMemberRepository,
             group_repository::PostgresGroupRepository,
             group_role_repository::PostgresGroupRoleRepository,
             group_token_repository::PostgresGroupTokenRepository,
             organization_attribute_repository::PostgresOrganizationAttributeRepository,
+            organization_domain_repository::PostgresOrganizationDomainRepository,
+            organization_invitation_repository::PostgresOrganizationInvitationRepository,
             organization_member_repository::PostgresOrganizationMemberRepository,
             organization_member_role_repository::PostgresOrganizationMemberRoleRepository,
             organization_repository::PostgresOrganizationRepository,
         },
+        rate_limit::{
+            counter_repository::PostgresRateLimitCounterRepository,
+            list_repository::PostgresRateLimitListRepository,
+            policy_repository::PostgresRateLimitPolicyRepository,
+        },
         realm::repositories::{
             realm_postgres_repository::PostgresRealmRepository,
             smtp_config_postgres_repository::PostgresSmtpConfigRepository,
         },
+        realm_group::{
+            group_attribute_repository::PostgresRealmGroupAttributeRepository,
+            group_member_repository::PostgresRealmGroupMemberRepository,
+            group_membership_repository::PostgresRealmGroupMembershipRepository,
+            group_repository::PostgresRealmGroupRepository,
+            group_role_repository::PostgresRealmGroupRoleRepository,
+        },
         repositories::{
             access_token_repository::PostgresAccessTokenRepository,
             argon2_hasher::Argon2HasherRepository,
@@ -108,8 +163,17 @@ use crate::{
             refresh_token_repository::PostgresRefreshTokenRepository,
             user_session_repository::PostgresUserSessionRepository,
         },
+        risk::{
+            geoip::MaxMindGeoIpLookup, history_repository::PostgresLoginHistoryRepository,
+            policy_repository::PostgresLoginRiskPolicyRepository,
+        },
         role::repositories::role_postgres_repository::PostgresRoleRepository,
+        saml::{
+            key_store::OpenSslSamlKeyStore,
+            repository::{SamlAuthRequestRepositoryImpl, SamlClientRepositoryImpl},
+        },
         seawatch::repositories::security_event_postgres_repository::PostgresSecurityEventRepository,
+        security_notification::repositories::security_notification_repository::PostgresSecurityNotificationRepository,
         trident::repositories::otp_enrollment_repository::PostgresOtpEnrollmentRepository,
         user::{
             repositories::{
@@ -119,6 +183,8 @@ use crate::{
             },
             repository::PostgresUserRepository,
         },
+        user_profile::schema_repository::PostgresUserProfileSchemaRepository,
+        webauthn_policy::policy_repository::PostgresWebAuthnPolicyRepository,
         webhook::repositories::webhook_repository::PostgresWebhookRepository,
     },
 };
@@ -132,26 +198,43 @@ pub mod broker;
 pub mod client;
 pub mod compass;
 pub mod credential;
+pub mod email_outbox;
 pub mod email_template;
 pub mod health;
 pub mod identity_provider;
+pub mod localization;
 pub mod mail;
 pub mod maintenance;
 pub mod migrate;
 pub mod organization;
 pub mod portal_layouts;
 pub mod portal_theme;
+pub mod rate_limit;
 pub mod realm;
+pub mod realm_group;
+pub mod risk;
 pub mod role;
+pub mod saml;
 pub mod seawatch;
+pub mod security_notification;
 pub mod token_revocation;
 pub mod trident;
 pub mod user;
+pub mod user_profile;
+pub mod webauthn_policy;
 pub mod webhook;
 
 pub use services::ApplicationService;
 
 const DEVICE_SESSION_PURGE_PERIOD: std::time::Duration = std::time::Duration::from_secs(900);
+const RATE_LIMIT_COUNTER_PURGE_PERIOD: std::time::Duration = std::time::Duration::from_secs(300);
+const LOGIN_HISTORY_PURGE_PERIOD: std::time::Duration = std::time::Duration::from_secs(3600);
+const FEDERATION_SYNC_SCHEDULER_PERIOD: std::time::Duration = std::time::Duration::from_secs(60);
+const MAINTENANCE_WINDOW_SCHEDULER_PERIOD: std::time::Duration = std::time::Duration::from_secs(30);
+const EMAIL_OUTBOX_WORKER_PERIOD: std::time::Duration = std::time::Duration::from_secs(5);
+const EMAIL_OUTBOX_PURGE_PERIOD: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+const IDENTITY_PROVIDER_METADATA_REFRESH_PERIOD: std::time::Duration =
+    std::time::Duration::from_secs(6 * 60 * 60);
 
 pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationService, CoreError> {
     let database_url = format!(
@@ -168,8 +251,20 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         .await
         .map_err(|e| CoreError::ServiceUnavailable(e.to_string()))?;
 
-    let realm = Arc::new(PostgresRealmRepository::new(postgres.get_db()));
-    let client = Arc::new(PostgresClientRepository::new(postgres.get_db()));
+    let caches = Arc::new(RepositoryCaches::new());
+    tokio::spawn(cache_invalidation_listener_task(
+        postgres.get_db(),
+        caches.clone(),
+    ));
+
+    let realm = Arc::new(CachedRealmRepository::new(
+        PostgresRealmRepository::new(postgres.get_db()),
+        caches.clone(),
+    ));
+    let client = Arc::new(CachedClientRepository::new(
+        PostgresClientRepository::new(postgres.get_db()),
+        caches.clone(),
+    ));
     let user = Arc::new(PostgresUserRepository::new(postgres.get_db()));
     let credential = Arc::new(PostgresCredentialRepository::new(postgres.get_db()));
     let hasher = Arc::new(Argon2HasherRepository::new());
@@ -182,15 +277,26 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
     ));
     let web_origin = Arc::new(PostgresWebOriginRepository::new(postgres.get_db()));
     let role = Arc::new(PostgresRoleRepository::new(postgres.get_db()));
-    let keystore = Arc::new(PostgresKeyStoreRepository::new(postgres.get_db()));
+    let keystore = Arc::new(CachedKeyStoreRepository::new(
+        PostgresKeyStoreRepository::new(postgres.get_db()),
+        caches.clone(),
+    ));
     let user_role = Arc::new(PostgresUserRoleRepository::new(postgres.get_db()));
     let user_required_action =
         Arc::new(PostgresUserRequiredActionRepository::new(postgres.get_db()));
     let user_attribute = Arc::new(PostgresUserAttributeRepository::new(postgres.get_db()));
+    let user_profile_schema = Arc::new(PostgresUserProfileSchemaRepository::new(postgres.get_db()));
+    let localization_settings = Arc::new(PostgresLocalizationSettingsRepository::new(
+        postgres.get_db(),
+    ));
+    let message_bundle = Arc::new(PostgresMessageBundleRepository::new(postgres.get_db()));
     let health_check = Arc::new(PostgresHealthCheckRepository::new(postgres.get_db()));
     let webhook = Arc::new(PostgresWebhookRepository::new(postgres.get_db()));
     let refresh_token = Arc::new(PostgresRefreshTokenRepository::new(postgres.get_db()));
-    let access_token = Arc::new(PostgresAccessTokenRepository::new(postgres.get_db()));
+    let access_token = Arc::new(CachedAccessTokenRepository::new(
+        PostgresAccessTokenRepository::new(postgres.get_db()),
+        caches.clone(),
+    ));
     let user_session = Arc::new(PostgresUserSessionRepository::new(postgres.get_db()));
     let token_revocation = Arc::new(
         crate::application::token_revocation::TokenRevocationAdapter::new(
@@ -203,19 +309,41 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
     let security_event = Arc::new(PostgresSecurityEventRepository::new(postgres.get_db()));
     let identity_provider = Arc::new(PostgresIdentityProviderRepository::new(postgres.get_db()));
     let federation = Arc::new(FederationRepositoryImpl::new(postgres.get_db()));
+    let federated_password = Arc::new(FederatedPasswordWriter::new(
+        federation.clone(),
+        Arc::new(LdapClientImpl),
+    ));
     let broker_auth_session = Arc::new(PostgresBrokerAuthSessionRepository::new(postgres.get_db()));
     let identity_provider_link = Arc::new(PostgresIdentityProviderLinkRepository::new(
         postgres.get_db(),
     ));
+    let identity_provider_mapper = Arc::new(PostgresIdentityProviderMapperRepository::new(
+        postgres.get_db(),
+    ));
+    let pending_broker_login =
+        Arc::new(PostgresPendingBrokerLoginRepository::new(postgres.get_db()));
     let oauth_client = Arc::new(ReqwestOAuthClient::new());
     let magic_link = Arc::new(PostgresMagicLinkRepository::new(postgres.get_db()));
-    let client_scope = Arc::new(PostgresClientScopeRepository::new(postgres.get_db()));
-    let protocol_mapper = Arc::new(PostgresProtocolMapperRepository::new(postgres.get_db()));
-    let scope_mapping = Arc::new(PostgresScopeMappingRepository::new(postgres.get_db()));
+    let client_scope = Arc::new(CachedClientScopeRepository::new(
+        PostgresClientScopeRepository::new(postgres.get_db()),
+        caches.clone(),
+    ));
+    let protocol_mapper = Arc::new(CachedProtocolMapperRepository::new(
+        PostgresProtocolMapperRepository::new(postgres.get_db()),
+        caches.clone(),
+    ));
+    let scope_mapping = Arc::new(CachedScopeMappingRepository::new(
+        PostgresScopeMappingRepository::new(postgres.get_db()),
+        caches.clone(),
+    ));
     let compass_flow = Arc::new(PostgresCompassFlowRepository::new(postgres.get_db()));
     let compass_flow_step = Arc::new(PostgresCompassFlowStepRepository::new(postgres.get_db()));
     let smtp_config = Arc::new(PostgresSmtpConfigRepository::new(postgres.get_db()));
-    let email_port = Arc::new(SmtpEmailPort::new());
+    let email_outbox = Arc::new(PostgresEmailOutboxRepository::new(postgres.get_db()));
+    let email_port = Arc::new(OutboxEmailPort::new(email_outbox.clone()));
+    let security_notification = Arc::new(PostgresSecurityNotificationRepository::new(
+        postgres.get_db(),
+    ));
     let password_reset_token =
         Arc::new(PostgresPasswordResetTokenRepository::new(postgres.get_db()));
 
@@ -232,11 +360,25 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
     let organization_member_role = Arc::new(PostgresOrganizationMemberRoleRepository::new(
         postgres.get_db(),
     ));
+    let organization_invitation = Arc::new(PostgresOrganizationInvitationRepository::new(
+        postgres.get_db(),
+    ));
+    let organization_domain =
+        Arc::new(PostgresOrganizationDomainRepository::new(postgres.get_db()));
     let group = Arc::new(PostgresGroupRepository::new(postgres.get_db()));
     let group_member = Arc::new(PostgresGroupMemberRepository::new(postgres.get_db()));
     let group_role = Arc::new(PostgresGroupRoleRepository::new(postgres.get_db()));
     let group_attribute = Arc::new(PostgresGroupAttributeRepository::new(postgres.get_db()));
     let group_token = Arc::new(PostgresGroupTokenRepository::new(postgres.get_db()));
+    let realm_group = Arc::new(PostgresRealmGroupRepository::new(postgres.get_db()));
+    let realm_group_member = Arc::new(PostgresRealmGroupMemberRepository::new(postgres.get_db()));
+    let realm_group_role = Arc::new(PostgresRealmGroupRoleRepository::new(postgres.get_db()));
+    let realm_group_attribute = Arc::new(PostgresRealmGroupAttributeRepository::new(
+        postgres.get_db(),
+    ));
+    let realm_group_membership = Arc::new(PostgresRealmGroupMembershipRepository::new(
+        postgres.get_db(),
+    ));
     let email_verification_token_repo = Arc::new(PostgresEmailVerificationTokenRepository::new(
         postgres.get_db(),
     ));
@@ -263,6 +405,55 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         client.clone(),
         user_role.clone(),
     ));
+    let organization_policy = Arc::new(OrganizationAdminPolicy::new(
+        policy.clone(),
+        organization_member.clone(),
+        organization_member_role.clone(),
+    ));
+
+    let localization_service = LocalizationServiceImpl::new(
+        realm.clone(),
+        localization_settings,
+        message_bundle,
+        user_attribute.clone(),
+        policy.clone(),
+    );
+    let email_localizer = Arc::new(localization_service.clone());
+    let security_notifier = Arc::new(EmailSecurityNotifier::new(
+        realm.clone(),
+        user.clone(),
+        smtp_config.clone(),
+        security_notification.clone(),
+        email_template.clone(),
+        mjml_renderer.clone(),
+        email_port.clone(),
+        security_event.clone(),
+        email_localizer.clone(),
+    ));
+
+    let login_risk_service = LoginRiskServiceImpl::new(
+        realm.clone(),
+        Arc::new(PostgresLoginRiskPolicyRepository::new(postgres.get_db())),
+        Arc::new(PostgresLoginHistoryRepository::new(postgres.get_db())),
+        Arc::new(MaxMindGeoIpLookup::new(config.geoip_database.as_deref())),
+        smtp_config.clone(),
+        email_port.clone(),
+        security_event.clone(),
+        security_notifier.clone(),
+        policy.clone(),
+    );
+    tokio::spawn(purge_login_history_task(
+        login_risk_service.clone(),
+        LOGIN_HISTORY_PURGE_PERIOD,
+    ));
+
+    let fido_metadata = load_fido_metadata(&config);
+    let webauthn_policy_service = WebAuthnPolicyServiceImpl::new(
+        realm.clone(),
+        Arc::new(PostgresWebAuthnPolicyRepository::new(postgres.get_db())),
+        fido_metadata,
+        policy.clone(),
+    );
 
     let email_verification_service = EmailVerificationServiceImpl::new(
         email_verification_token_repo,
@@ -275,6 +466,7 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         mjml_renderer.clone(),
         webhook.clone(),
         security_event.clone(),
+        email_localizer.clone(),
     );
 
     let auth_service = AuthServiceImpl::new(
@@ -306,6 +498,10 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         security_event.clone(),
         user_session.clone(),
         login_action_token.clone(),
+        realm_group_membership.clone(),
+        Arc::new(login_risk_service.clone()),
+        user_profile_schema.clone(),
+        security_notifier.clone(),
         Arc::new(MapperEngine::new()),
         flow_recorder.clone(),
     );
@@ -318,11 +514,38 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         DeviceFlowConfig::default(),
     );
 
+    let saml_key_store = Arc::new(OpenSslSamlKeyStore::new(postgres.get_db()));
+
+    // ...and as the SAML service's session issuer.
+    let saml_service = SamlServiceImpl::new(
+        realm.clone(),
+        client.clone(),
+        Arc::new(SamlClientRepositoryImpl::new(postgres.get_db())),
+        Arc::new(SamlAuthRequestRepositoryImpl::new(postgres.get_db())),
+        auth_session.clone(),
+        saml_key_store.clone(),
+        Arc::new(auth_service.clone()),
+        policy.clone(),
+    );
+
     tokio::spawn(purge_expired_device_sessions_task(
         device_flow_service.clone(),
         DEVICE_SESSION_PURGE_PERIOD,
     ));
 
+    let rate_limit_service = RateLimitServiceImpl::new(
+        realm.clone(),
+        Arc::new(PostgresRateLimitPolicyRepository::new(postgres.get_db())),
+        Arc::new(PostgresRateLimitListRepository::new(postgres.get_db())),
+        Arc::new(PostgresRateLimitCounterRepository::new(postgres.get_db())),
+        security_event.clone(),
+        policy.clone(),
+    );
+    tokio::spawn(purge_expired_rate_limit_counters_task(
+        rate_limit_service.clone(),
+        RATE_LIMIT_COUNTER_PURGE_PERIOD,
+    ));
+
     let app = ApplicationService {
         maintenance_service: MaintenanceServiceImpl::new(
             realm.clone(),
@@ -331,10 +554,12 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             security_event.clone(),
             maintenance_whitelist.clone(),
             realm_maintenance_whitelist.clone(),
+            Arc::new(PostgresMaintenanceWindowRepository::new(postgres.get_db())),
             policy.clone(),
         ),
         auth_service,
         device_flow_service,
+        saml_service,
         client_service: ClientServiceImpl::new(
             realm.clone(),
             user.clone(),
@@ -352,6 +577,7 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         credential_service: CredentialServiceImpl::new(
             realm.clone(),
             credential.clone(),
+            security_notifier.clone(),
             policy.clone(),
         ),
         health_service: HealthServiceImpl::new(health_check.clone()),
@@ -367,6 +593,7 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             protocol_mapper.clone(),
             scope_mapping.clone(),
             redirect_uri.clone(),
+            organization_member_role.clone(),
             policy.clone(),
             config.webapp_url.clone(),
         ),
@@ -404,6 +631,10 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             otp_enrollment.clone(),
             user_role.clone(),
             token_revocation.clone(),
+            federated_password,
+            email_localizer.clone(),
+            Arc::new(webauthn_policy_service.clone()),
+            security_notifier.clone(),
         ),
         user_service: UserServiceImpl::new(
             realm.clone(),
@@ -418,6 +649,8 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             security_event.clone(),
             password_policy.clone(),
             token_revocation.clone(),
+            user_profile_schema.clone(),
+            security_notifier.clone(),
             policy.clone(),
         ),
         webhook_service: WebhookServiceImpl::new(realm.clone(), webhook.clone(), policy.clone()),
@@ -427,6 +660,21 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             mjml_renderer.clone(),
             policy.clone(),
         ),
+        email_outbox_service: EmailOutboxServiceImpl::new(
+            realm.clone(),
+            smtp_config.clone(),
+            email_outbox.clone(),
+            Arc::new(ConfiguredEmailTransport::new(
+                config.mail_maildir.as_deref(),
+            )),
+            policy.clone(),
+        ),
+        security_notification_service: SecurityNotificationServiceImpl::new(
+            realm.clone(),
+            security_notification.clone(),
+            email_template.clone(),
+            policy.clone(),
+        ),
         portal_theme_service: PortalThemeServiceImpl::new(
             realm.clone(),
             portal_theme.clone(),
@@ -447,6 +695,7 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             hasher.clone(),
             credential.clone(),
             redirect_uri.clone(),
+            organization_member_role.clone(),
         ),
         identity_provider_service: IdentityProviderServiceImpl::new(
             identity_provider.clone(),
@@ -455,6 +704,16 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             user.clone(),
             identity_provider_link.clone(),
             security_event.clone(),
+            oauth_client.clone(),
+        ),
+        identity_provider_mapper_service: IdentityProviderMapperServiceImpl::new(
+            identity_provider_mapper.clone(),
+            identity_provider.clone(),
+            policy.clone(),
+            realm.clone(),
+            role.clone(),
+            organization.clone(),
+            group.clone(),
         ),
         federation_service: FederationServiceImpl::new(
             realm.clone(),
@@ -462,6 +721,16 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             user.clone(),
             credential.clone(),
             policy.clone(),
+            Arc::new(LdapClientImpl),
+            Arc::new(FederationSyncRepositoryImpl::new(postgres.get_db())),
+            GroupMapperSync::new(
+                role.clone(),
+                user_role.clone(),
+                group.clone(),
+                group_member.clone(),
+                realm_group.clone(),
+                realm_group_member.clone(),
+            ),
         ),
         broker_service: BrokerServiceImpl::new(
             realm.clone(),
@@ -474,6 +743,24 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             auth_session.clone(),
             oauth_client.clone(),
             flow_recorder.clone(),
+            BrokeredUserMapping::new(
+                identity_provider_mapper.clone(),
+                user.clone(),
+                user_role.clone(),
+                user_attribute.clone(),
+                organization_member.clone(),
+                group_member.clone(),
+            ),
+            keystore.clone(),
+            pending_broker_login.clone(),
+            BrokerLoginVerification::new(
+                credential.clone(),
+                hasher.clone(),
+                smtp_config.clone(),
+                email_port.clone(),
+            ),
+            saml_key_store,
+            security_notifier.clone(),
         ),
         client_scope_service: ClientScopeServiceImpl::new(
             realm.clone(),
@@ -510,7 +797,7 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             organization.clone(),
             organization_attribute.clone(),
             organization_member.clone(),
-            policy.clone(),
+            organization_policy.clone(),
         ),
         group_service: GroupServiceImpl::new(
             realm.clone(),
@@ -521,14 +808,62 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             group_member.clone(),
             group_role.clone(),
             group_attribute.clone(),
+            organization_policy.clone(),
+        ),
+        realm_group_service: RealmGroupServiceImpl::new(
+            realm.clone(),
+            user.clone(),
+            user_role.clone(),
+            realm_group,
+            realm_group_member,
+            realm_group_role,
+            realm_group_attribute,
+            realm_group_membership,
+            policy.clone(),
+        ),
+        rate_limit_service,
+        login_risk_service,
+        user_profile_service: UserProfileServiceImpl::new(
+            realm.clone(),
+            user_profile_schema,
             policy.clone(),
         ),
+        localization_service,
+        webauthn_policy_service,
         organization_member_role_service: OrganizationMemberRoleServiceImpl::new(
             realm.clone(),
             user_role.clone(),
             organization.clone(),
             organization_member.clone(),
             organization_member_role.clone(),
+            organization_policy.clone(),
+        ),
+        organization_invitation_service: OrganizationInvitationServiceImpl::new(
+            realm.clone(),
+            user.clone(),
+            role.clone(),
+            organization.clone(),
+            organization_member.clone(),
+            organization_member_role.clone(),
+            group.clone(),
+            group_member.clone(),
+            organization_invitation,
+            credential.clone(),
+            hasher.clone(),
+            password_policy.clone(),
+            smtp_config.clone(),
+            email_port.clone(),
+            email_template.clone(),
+            mjml_renderer.clone(),
+            email_localizer,
+            organization_policy.clone(),
+        ),
+        organization_domain_service: OrganizationDomainServiceImpl::new(
+            realm.clone(),
+            organization.clone(),
+            organization_domain,
+            identity_provider.clone(),
+            Arc::new(DohTxtResolver::new(config.dns_over_https_endpoint.clone())),
             policy.clone(),
         ),
         flow_recorder,
@@ -543,9 +878,45 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         security_event_repository: security_event.clone(),
     };
 
+    tokio::spawn(email_outbox_worker_task(
+        app.email_outbox_service.clone(),
+        EMAIL_OUTBOX_WORKER_PERIOD,
+    ));
+    tokio::spawn(email_outbox_purge_task(
+        app.email_outbox_service.clone(),
+        chrono::Duration::hours(config.mail_outbox_retention_hours.into()),
+        EMAIL_OUTBOX_PURGE_PERIOD,
+    ));
+    tokio::spawn(maintenance_window_scheduler_task(
+        app.maintenance_service.clone(),
+        MAINTENANCE_WINDOW_SCHEDULER_PERIOD,
+    ));
+    tokio::spawn(federation_sync_scheduler_task(
+        app.federation_service.clone(),
+        FEDERATION_SYNC_SCHEDULER_PERIOD,
+    ));
+    tokio::spawn(identity_provider_metadata_refresh_task(
+        app.identity_provider_service.clone(),
+        IDENTITY_PROVIDER_METADATA_REFRESH_PERIOD,
+    ));
+
     Ok(app)
 }
 
+/// Loads the FIDO metadata BLOB. A missing or broken BLOB is logged and leaves attestation
+/// unverified rather than keeping the server from starting.
+fn load_fido_metadata(config: &FerriskeyConfig) -> Option<Arc<MetadataBlob>> {
+    let path = config.fido_metadata_blob.as_deref()?;
+
+    match MetadataBlob::load(path, config.fido_metadata_root_certificate.as_deref()) {
+        Ok(blob) => Some(Arc::new(blob)),
+        Err(e) => {
+            tracing::warn!("Failed to load FIDO metadata {}: {}", path, e);
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::env;
@@ -634,6 +1005,12 @@ mod tests {
             .expect("run migrations");
 
         let app = create_service(FerriskeyConfig {
+            geoip_database: None,
+            fido_metadata_blob: None,
+            fido_metadata_root_certificate: None,
+            mail_maildir: None,
+            mail_outbox_retention_hours: 72,
+            dns_over_https_endpoint: "https://cloudflare-dns.com/dns-query".to_string(),
             database: crate::domain::common::DatabaseConfig {
                 host: db_host,
                 port: db_port,
@@ -806,6 +1183,12 @@ mod tests {
             .expect("run migrations");
 
         let app = create_service(FerriskeyConfig {
+            geoip_database: None,
+            fido_metadata_blob: None,
+            fido_metadata_root_certificate: None,
+            mail_maildir: None,
+            mail_outbox_retention_hours: 72,
+            dns_over_https_endpoint: "https://cloudflare-dns.com/dns-query".to_string(),
             database: crate::domain::common::DatabaseConfig {
                 host: db_host,
                 port: db_port,
@@ -4,18 +4,23 @@ use crate::{
         authentication::value_objects::Identity,
         common::entities::app_errors::CoreError,
         organization::ports::{
-            AddGroupMemberInput, AddOrganizationMemberInput, AssignGroupRoleInput,
-            AssignMemberRoleInput, CreateGroupInput, CreateOrganizationInput,
-            DeleteGroupAttributeInput, DeleteGroupInput, DeleteOrganizationAttributeInput,
-            DeleteOrganizationInput, GetGroupInput, GetOrganizationInput, Group, GroupAttribute,
-            GroupMember, GroupMemberPage, GroupNode, GroupService, ListGroupAttributesInput,
-            ListGroupMembersInput, ListGroupRolesInput, ListGroupsInput, ListMemberRolesInput,
+            AcceptInvitationInput, AcceptedInvitation, AddGroupMemberInput,
+            AddOrganizationMemberInput, AssignGroupRoleInput, AssignMemberRoleInput,
+            ConfigureOrganizationDomainInput, CreateGroupInput, CreateInvitationInput,
+            CreateOrganizationInput, DeleteGroupAttributeInput, DeleteGroupInput,
+            DeleteOrganizationAttributeInput, DeleteOrganizationInput, DiscoverHomeRealmInput,
+            GetGroupInput, GetInvitationInput, GetOrganizationDomainInput, GetOrganizationInput,
+            Group, GroupAttribute, GroupMember, GroupMemberPage, GroupNode, GroupService,
+            HomeRealmDiscovery, InvitationPreview, ListGroupAttributesInput, ListGroupMembersInput,
+            ListGroupRolesInput, ListGroupsInput, ListInvitationsInput, ListMemberRolesInput,
             ListOrganizationAttributesInput, ListOrganizationMembersInput, ListOrganizationsInput,
-            ListUserOrganizationsInput, Organization, OrganizationAttribute, OrganizationMember,
-            OrganizationMemberRoleService, OrganizationService, RemoveGroupMemberInput,
-            RemoveOrganizationMemberInput, RevokeGroupRoleInput, RevokeMemberRoleInput,
-            UpdateGroupInput, UpdateOrganizationInput, UpsertGroupAttributeInput,
-            UpsertOrganizationAttributeInput,
+            ListUserOrganizationsInput, Organization, OrganizationAttribute,
+            OrganizationDomainService, OrganizationDomainStatus, OrganizationInvitation,
+            OrganizationInvitationService, OrganizationMember, OrganizationMemberRoleService,
+            OrganizationService, RemoveGroupMemberInput, RemoveOrganizationMemberInput,
+            RevokeGroupRoleInput, RevokeInvitationInput, RevokeMemberRoleInput, UpdateGroupInput,
+            UpdateOrganizationInput, UpsertGroupAttributeInput, UpsertOrganizationAttributeInput,
+            VerifyOrganizationDomainInput,
         },
         role::entities::Role,
     },
@@ -286,3 +291,94 @@ impl OrganizationMemberRoleService for ApplicationService {
             .await
     }
 }
+
+impl OrganizationInvitationService for ApplicationService {
+    async fn create_invitation(
+        &self,
+        identity: Identity,
+        input: CreateInvitationInput,
+    ) -> Result<OrganizationInvitation, CoreError> {
+        self.organization_invitation_service
+            .create_invitation(identity, input)
+            .await
+    }
+
+    async fn list_invitations(
+        &self,
+        identity: Identity,
+        input: ListInvitationsInput,
+    ) -> Result<Vec<OrganizationInvitation>, CoreError> {
+        self.organization_invitation_service
+            .list_invitations(identity, input)
+            .await
+    }
+
+    async fn revoke_invitation(
+        &self,
+        identity: Identity,
+        input: RevokeInvitationInput,
+    ) -> Result<OrganizationInvitation, CoreError> {
+        self.organization_invitation_service
+            .revoke_invitation(identity, input)
+            .await
+    }
+
+    async fn get_invitation(
+        &self,
+        input: GetInvitationInput,
+    ) -> Result<InvitationPreview, CoreError> {
+        self.organization_invitation_service
+            .get_invitation(input)
+            .await
+    }
+
+    async fn accept_invitation(
+        &self,
+        input: AcceptInvitationInput,
+    ) -> Result<AcceptedInvitation, CoreError> {
+        self.organization_invitation_service
+            .accept_invitation(input)
+            .await
+    }
+}
+
+impl OrganizationDomainService for ApplicationService {
+    async fn get_organization_domain(
+        &self,
+        identity: Identity,
+        input: GetOrganizationDomainInput,
+    ) -> Result<OrganizationDomainStatus, CoreError> {
+        self.organization_domain_service
+            .get_organization_domain(identity, input)
+            .await
+    }
+
+    async fn configure_organization_domain(
+        &self,
+        identity: Identity,
+        input: ConfigureOrganizationDomainInput,
+    ) -> Result<OrganizationDomainStatus, CoreError> {
+        self.organization_domain_service
+            .configure_organization_domain(identity, input)
+            .await
+    }
+
+    async fn verify_organization_domain(
+        &self,
+        identity: Identity,
+        input: VerifyOrganizationDomainInput,
+    ) -> Result<OrganizationDomainStatus, CoreError> {
+        self.organization_domain_service
+            .verify_organization_domain(identity, input)
+            .await
+    }
+
+    async fn discover_home_realm(
+        &self,
+        input: DiscoverHomeRealmInput,
+    ) -> Result<Option<HomeRealmDiscovery>, CoreError> {
+        self.organization_domain_service
+            .discover_home_realm(input)
+            .await
+    }
+}
@@ -0,0 +1,98 @@
+use uuid::Uuid;
+
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        rate_limit::{
+            CreateRateLimitListEntryInput, CreateRateLimitPolicyInput, RateLimitCheck,
+            RateLimitListEntry, RateLimitOverview, RateLimitPolicy, RateLimitService,
+            UpdateRateLimitPolicyInput,
+        },
+    },
+};
+
+impl RateLimitService for ApplicationService {
+    async fn check_rate_limit(&self, check: RateLimitCheck) -> Result<(), CoreError> {
+        self.rate_limit_service.check_rate_limit(check).await
+    }
+
+    async fn get_rate_limits(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<RateLimitOverview, CoreError> {
+        self.rate_limit_service
+            .get_rate_limits(identity, realm_name)
+            .await
+    }
+
+    async fn create_rate_limit_policy(
+        &self,
+        identity: Identity,
+        input: CreateRateLimitPolicyInput,
+    ) -> Result<RateLimitPolicy, CoreError> {
+        self.rate_limit_service
+            .create_rate_limit_policy(identity, input)
+            .await
+    }
+
+    async fn update_rate_limit_policy(
+        &self,
+        identity: Identity,
+        input: UpdateRateLimitPolicyInput,
+    ) -> Result<RateLimitPolicy, CoreError> {
+        self.rate_limit_service
+            .update_rate_limit_policy(identity, input)
+            .await
+    }
+
+    async fn delete_rate_limit_policy(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        policy_id: Uuid,
+    ) -> Result<(), CoreError> {
+        self.rate_limit_service
+            .delete_rate_limit_policy(identity, realm_name, policy_id)
+            .await
+    }
+
+    async fn get_rate_limit_list(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<RateLimitListEntry>, CoreError> {
+        self.rate_limit_service
+            .get_rate_limit_list(identity, realm_name)
+            .await
+    }
+
+    async fn create_rate_limit_list_entry(
+        &self,
+        identity: Identity,
+        input: CreateRateLimitListEntryInput,
+    ) -> Result<RateLimitListEntry, CoreError> {
+        self.rate_limit_service
+            .create_rate_limit_list_entry(identity, input)
+            .await
+    }
+
+    async fn delete_rate_limit_list_entry(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        entry_id: Uuid,
+    ) -> Result<(), CoreError> {
+        self.rate_limit_service
+            .delete_rate_limit_list_entry(identity, realm_name, entry_id)
+            .await
+    }
+
+    async fn purge_expired_rate_limit_counters(&self) -> Result<u64, CoreError> {
+        self.rate_limit_service
+            .purge_expired_rate_limit_counters()
+            .await
+    }
+}
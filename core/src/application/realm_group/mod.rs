@@ -0,0 +1,161 @@
+use uuid::Uuid;
+
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        realm_group::{
+            CreateRealmGroupInput, DeleteRealmGroupAttributeInput, ListRealmGroupMembersInput,
+            RealmGroup, RealmGroupAttribute, RealmGroupId, RealmGroupMember, RealmGroupMemberInput,
+            RealmGroupMemberPage, RealmGroupMembership, RealmGroupNode, RealmGroupRoleInput,
+            RealmGroupService, UpdateRealmGroupInput, UpsertRealmGroupAttributeInput,
+        },
+        role::entities::Role,
+    },
+};
+
+impl RealmGroupService for ApplicationService {
+    async fn create_group(
+        &self,
+        identity: Identity,
+        input: CreateRealmGroupInput,
+    ) -> Result<RealmGroup, CoreError> {
+        self.realm_group_service.create_group(identity, input).await
+    }
+
+    async fn get_group(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> Result<RealmGroup, CoreError> {
+        self.realm_group_service
+            .get_group(identity, realm_name, group_id)
+            .await
+    }
+
+    async fn list_groups(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<RealmGroupNode>, CoreError> {
+        self.realm_group_service
+            .list_groups(identity, realm_name)
+            .await
+    }
+
+    async fn update_group(
+        &self,
+        identity: Identity,
+        input: UpdateRealmGroupInput,
+    ) -> Result<RealmGroup, CoreError> {
+        self.realm_group_service.update_group(identity, input).await
+    }
+
+    async fn delete_group(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> Result<(), CoreError> {
+        self.realm_group_service
+            .delete_group(identity, realm_name, group_id)
+            .await
+    }
+
+    async fn add_member(
+        &self,
+        identity: Identity,
+        input: RealmGroupMemberInput,
+    ) -> Result<RealmGroupMember, CoreError> {
+        self.realm_group_service.add_member(identity, input).await
+    }
+
+    async fn remove_member(
+        &self,
+        identity: Identity,
+        input: RealmGroupMemberInput,
+    ) -> Result<(), CoreError> {
+        self.realm_group_service
+            .remove_member(identity, input)
+            .await
+    }
+
+    async fn list_members(
+        &self,
+        identity: Identity,
+        input: ListRealmGroupMembersInput,
+    ) -> Result<RealmGroupMemberPage, CoreError> {
+        self.realm_group_service.list_members(identity, input).await
+    }
+
+    async fn assign_role(
+        &self,
+        identity: Identity,
+        input: RealmGroupRoleInput,
+    ) -> Result<(), CoreError> {
+        self.realm_group_service.assign_role(identity, input).await
+    }
+
+    async fn revoke_role(
+        &self,
+        identity: Identity,
+        input: RealmGroupRoleInput,
+    ) -> Result<(), CoreError> {
+        self.realm_group_service.revoke_role(identity, input).await
+    }
+
+    async fn list_roles(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> Result<Vec<Role>, CoreError> {
+        self.realm_group_service
+            .list_roles(identity, realm_name, group_id)
+            .await
+    }
+
+    async fn list_attributes(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> Result<Vec<RealmGroupAttribute>, CoreError> {
+        self.realm_group_service
+            .list_attributes(identity, realm_name, group_id)
+            .await
+    }
+
+    async fn upsert_attribute(
+        &self,
+        identity: Identity,
+        input: UpsertRealmGroupAttributeInput,
+    ) -> Result<RealmGroupAttribute, CoreError> {
+        self.realm_group_service
+            .upsert_attribute(identity, input)
+            .await
+    }
+
+    async fn delete_attribute(
+        &self,
+        identity: Identity,
+        input: DeleteRealmGroupAttributeInput,
+    ) -> Result<(), CoreError> {
+        self.realm_group_service
+            .delete_attribute(identity, input)
+            .await
+    }
+
+    async fn get_user_membership(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        user_id: Uuid,
+    ) -> Result<RealmGroupMembership, CoreError> {
+        self.realm_group_service
+            .get_user_membership(identity, realm_name, user_id)
+            .await
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        risk::{
+            EvaluateLoginRiskInput, GetLoginHistoryInput, LoginRecord, LoginRiskAssessment,
+            LoginRiskPolicy, LoginRiskService, UpdateLoginRiskPolicyInput,
+        },
+    },
+};
+
+impl LoginRiskService for ApplicationService {
+    async fn evaluate_login_risk(
+        &self,
+        input: EvaluateLoginRiskInput,
+    ) -> Result<LoginRiskAssessment, CoreError> {
+        self.login_risk_service.evaluate_login_risk(input).await
+    }
+
+    async fn get_login_risk_policy(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<LoginRiskPolicy, CoreError> {
+        self.login_risk_service
+            .get_login_risk_policy(identity, realm_name)
+            .await
+    }
+
+    async fn update_login_risk_policy(
+        &self,
+        identity: Identity,
+        input: UpdateLoginRiskPolicyInput,
+    ) -> Result<LoginRiskPolicy, CoreError> {
+        self.login_risk_service
+            .update_login_risk_policy(identity, input)
+            .await
+    }
+
+    async fn get_login_history(
+        &self,
+        identity: Identity,
+        input: GetLoginHistoryInput,
+    ) -> Result<Vec<LoginRecord>, CoreError> {
+        self.login_risk_service
+            .get_login_history(identity, input)
+            .await
+    }
+
+    async fn purge_login_history(&self) -> Result<u64, CoreError> {
+        self.login_risk_service.purge_login_history().await
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::{
+            saml::{
+                entities::{SamlClientSettings, SamlInboundMessage, SamlOutcome, SamlPostForm},
+                ports::SamlService,
+                value_objects::{
+                    GetSamlClientSettingsInput, ImportSamlMetadataInput,
+                    UpdateSamlClientSettingsInput,
+                },
+            },
+            value_objects::Identity,
+        },
+        common::entities::app_errors::CoreError,
+    },
+};
+
+impl SamlService for ApplicationService {
+    async fn get_idp_metadata(
+        &self,
+        realm_name: String,
+        base_url: String,
+    ) -> Result<String, CoreError> {
+        self.saml_service
+            .get_idp_metadata(realm_name, base_url)
+            .await
+    }
+
+    async fn handle_message(&self, input: SamlInboundMessage) -> Result<SamlOutcome, CoreError> {
+        self.saml_service.handle_message(input).await
+    }
+
+    async fn initiate_idp_sso(
+        &self,
+        realm_name: String,
+        url_name: String,
+        base_url: String,
+    ) -> Result<SamlOutcome, CoreError> {
+        self.saml_service
+            .initiate_idp_sso(realm_name, url_name, base_url)
+            .await
+    }
+
+    async fn complete_login(
+        &self,
+        realm_name: String,
+        code: String,
+        base_url: String,
+    ) -> Result<SamlPostForm, CoreError> {
+        self.saml_service
+            .complete_login(realm_name, code, base_url)
+            .await
+    }
+
+    async fn get_client_settings(
+        &self,
+        identity: Identity,
+        input: GetSamlClientSettingsInput,
+    ) -> Result<SamlClientSettings, CoreError> {
+        self.saml_service.get_client_settings(identity, input).await
+    }
+
+    async fn update_client_settings(
+        &self,
+        identity: Identity,
+        input: UpdateSamlClientSettingsInput,
+    ) -> Result<SamlClientSettings, CoreError> {
+        self.saml_service
+            .update_client_settings(identity, input)
+            .await
+    }
+
+    async fn import_client_metadata(
+        &self,
+        identity: Identity,
+        input: ImportSamlMetadataInput,
+    ) -> Result<SamlClientSettings, CoreError> {
+        self.saml_service
+            .import_client_metadata(identity, input)
+            .await
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        security_notification::{
+            entities::SecurityNotificationSetting,
+            ports::{
+                GetSecurityNotificationsInput, SecurityNotificationService,
+                UpdateSecurityNotificationInput,
+            },
+        },
+    },
+};
+
+impl SecurityNotificationService for ApplicationService {
+    async fn get_security_notifications(
+        &self,
+        identity: Identity,
+        input: GetSecurityNotificationsInput,
+    ) -> Result<Vec<SecurityNotificationSetting>, CoreError> {
+        self.security_notification_service
+            .get_security_notifications(identity, input)
+            .await
+    }
+
+    async fn update_security_notification(
+        &self,
+        identity: Identity,
+        input: UpdateSecurityNotificationInput,
+    ) -> Result<SecurityNotificationSetting, CoreError> {
+        self.security_notification_service
+            .update_security_notification(identity, input)
+            .await
+    }
+}
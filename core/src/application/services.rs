@@ -3,14 +3,19 @@ use std::sync::Arc;
 use ferriskey_compass::recorder::FlowRecorder;
 use ferriskey_migrate::{entities::MigrationReport, error::MigrationError};
 use sea_orm::DatabaseConnection;
+use uuid::Uuid;
 
 use crate::domain::authentication::services::client_secret_matches;
 use crate::domain::realm::entities::RealmId;
+use crate::domain::user::entities::User;
 
 use crate::{
     application::migrate::{build_runner, context::MigrationContext},
     domain::{
-        abyss::{BrokerServiceImpl, IdentityProviderServiceImpl},
+        abyss::{
+            BrokerServiceImpl, IdentityProviderMapperServiceImpl, IdentityProviderServiceImpl,
+            federation::password_writeback::FederatedPasswordWriter,
+        },
         aegis::services::{
             ClientScopeServiceImpl, ProtocolMapperServiceImpl, ScopeMappingServiceImpl,
         },
@@ -26,6 +31,11 @@ use crate::{
             },
             entities::{ExchangeTokenInput, JwtToken},
             ports::AuthService,
+            saml::{
+                entities::{SamlIdentity, SamlIdentityInput},
+                ports::SamlIdentityIssuer,
+                services::SamlServiceImpl,
+            },
             services::AuthServiceImpl,
             value_objects::{
                 EvaluateClientScopesInput, EvaluateClientScopesRequest, EvaluateClientScopesResult,
@@ -44,11 +54,15 @@ use crate::{
         },
         compass::services::CompassServiceImpl,
         credential::services::CredentialServiceImpl,
+        email_outbox::services::EmailOutboxServiceImpl,
         email_template::services::EmailTemplateServiceImpl,
         email_verification::services::EmailVerificationServiceImpl,
         health::services::HealthServiceImpl,
+        localization::services::LocalizationServiceImpl,
         maintenance::services::MaintenanceServiceImpl,
+        organization::domain_services::OrganizationDomainServiceImpl,
         organization::group_services::GroupServiceImpl,
+        organization::invitation_services::OrganizationInvitationServiceImpl,
         organization::member_role_services::OrganizationMemberRoleServiceImpl,
         organization::services::OrganizationServiceImpl,
         password_policy::{
@@ -57,32 +71,50 @@ use crate::{
         },
         portal_layouts::services::PortalLayoutsServiceImpl,
         portal_theme::services::PortalThemeServiceImpl,
+        rate_limit::services::RateLimitServiceImpl,
         realm::{
             ports::RealmRepository,
             services::{MailServiceImpl, RealmServiceImpl},
         },
+        realm_group::services::RealmGroupServiceImpl,
+        risk::services::LoginRiskServiceImpl,
         role::services::RoleServiceImpl,
         seawatch::{
             entities::{EventStatus, SecurityEvent, SecurityEventType},
             ports::SecurityEventRepository,
             services::SecurityEventServiceImpl,
         },
+        security_notification::services::{EmailSecurityNotifier, SecurityNotificationServiceImpl},
         session::{
             entities::UserSession, ports::UserSessionManagementService,
             services::UserSessionManagementServiceImpl,
         },
         trident::services::TridentServiceImpl,
         user::services::UserServiceImpl,
+        user_profile::services::UserProfileServiceImpl,
+        webauthn_policy::services::WebAuthnPolicyServiceImpl,
         webhook::services::WebhookServiceImpl,
     },
     infrastructure::migrate::repository::PostgresMigrationRepository,
     infrastructure::{
-        abyss::federation::repository::FederationRepositoryImpl,
+        abyss::federation::{
+            ldap::LdapClientImpl, repository::FederationRepositoryImpl,
+            sync_repository::FederationSyncRepositoryImpl,
+        },
         aegis::repositories::{
             client_scope_postgres_repository::PostgresClientScopeRepository,
             protocol_mapper_postgres_repository::PostgresProtocolMapperRepository,
             scope_mapping_postgres_repository::PostgresScopeMappingRepository,
         },
+        cache::{
+            access_token_repository::CachedAccessTokenRepository,
+            client_repository::CachedClientRepository,
+            client_scope_repository::CachedClientScopeRepository,
+            keystore_repository::CachedKeyStoreRepository,
+            protocol_mapper_repository::CachedProtocolMapperRepository,
+            realm_repository::CachedRealmRepository,
+            scope_mapping_repository::CachedScopeMappingRepository,
+        },
         client::repositories::{
             client_postgres_repository::PostgresClientRepository,
             post_logout_redirect_uri_postgres_repository::PostgresPostLogoutRedirectUriRepository,
@@ -90,7 +122,8 @@ use crate::{
             web_origin_postgres_repository::PostgresWebOriginRepository,
         },
         compass::repositories::{PostgresCompassFlowRepository, PostgresCompassFlowStepRepository},
-        email::SmtpEmailPort,
+        email::{ConfiguredEmailTransport, OutboxEmailPort},
+        email_outbox::repositories::email_outbox_repository::PostgresEmailOutboxRepository,
         email_template::{
             renderer::mjml_renderer::MjmlTemplateRenderer,
             repositories::email_template_repository::PostgresEmailTemplateRepository,
@@ -98,23 +131,43 @@ use crate::{
         health::repositories::PostgresHealthCheckRepository,
         identity_provider::{
             PostgresBrokerAuthSessionRepository, PostgresIdentityProviderLinkRepository,
-            PostgresIdentityProviderRepository, ReqwestOAuthClient,
+            PostgresIdentityProviderMapperRepository, PostgresIdentityProviderRepository,
+            PostgresPendingBrokerLoginRepository, ReqwestOAuthClient,
+        },
+        localization::{
+            bundle_repository::PostgresMessageBundleRepository,
+            settings_repository::PostgresLocalizationSettingsRepository,
         },
         organization::{
+            dns_resolver::DohTxtResolver,
             group_attribute_repository::PostgresGroupAttributeRepository,
             group_member_repository::PostgresGroupMemberRepository,
             group_repository::PostgresGroupRepository,
             group_role_repository::PostgresGroupRoleRepository,
             group_token_repository::PostgresGroupTokenRepository,
             organization_attribute_repository::PostgresOrganizationAttributeRepository,
+            organization_domain_repository::PostgresOrganizationDomainRepository,
+            organization_invitation_repository::PostgresOrganizationInvitationRepository,
             organization_member_repository::PostgresOrganizationMemberRepository,
             organization_member_role_repository::PostgresOrganizationMemberRoleRepository,
             organization_repository::PostgresOrganizationRepository,
         },
+        rate_limit::{
+            counter_repository::PostgresRateLimitCounterRepository,
+            list_repository::PostgresRateLimitListRepository,
+            policy_repository::PostgresRateLimitPolicyRepository,
+        },
         realm::repositories::{
             realm_postgres_repository::PostgresRealmRepository,
             smtp_config_postgres_repository::PostgresSmtpConfigRepository,
         },
+        realm_group::{
+            group_attribute_repository::PostgresRealmGroupAttributeRepository,
+            group_member_repository::PostgresRealmGroupMemberRepository,
+            group_membership_repository::PostgresRealmGroupMembershipRepository,
+            group_repository::PostgresRealmGroupRepository,
+            group_role_repository::PostgresRealmGroupRoleRepository,
+        },
         repositories::{
             access_token_repository::PostgresAccessTokenRepository,
             argon2_hasher::Argon2HasherRepository,
@@ -132,8 +185,17 @@ use crate::{
             refresh_token_repository::PostgresRefreshTokenRepository,
             user_session_repository::PostgresUserSessionRepository,
         },
+        risk::{
+            geoip::MaxMindGeoIpLookup, history_repository::PostgresLoginHistoryRepository,
+            policy_repository::PostgresLoginRiskPolicyRepository,
+        },
         role::repositories::role_postgres_repository::PostgresRoleRepository,
+        saml::{
+            key_store::OpenSslSamlKeyStore,
+            repository::{SamlAuthRequestRepositoryImpl, SamlClientRepositoryImpl},
+        },
         seawatch::repositories::security_event_postgres_repository::PostgresSecurityEventRepository,
+        security_notification::repositories::security_notification_repository::PostgresSecurityNotificationRepository,
         trident::repositories::otp_enrollment_repository::PostgresOtpEnrollmentRepository,
         user::{
             repositories::{
@@ -143,12 +205,14 @@ use crate::{
             },
             repository::PostgresUserRepository,
         },
+        user_profile::schema_repository::PostgresUserProfileSchemaRepository,
+        webauthn_policy::policy_repository::PostgresWebAuthnPolicyRepository,
         webhook::repositories::webhook_repository::PostgresWebhookRepository,
     },
 };
 
-type RealmRepo = PostgresRealmRepository;
-type ClientRepo = PostgresClientRepository;
+type RealmRepo = CachedRealmRepository<PostgresRealmRepository>;
+type ClientRepo = CachedClientRepository<PostgresClientRepository>;
 type UserRepo = PostgresUserRepository;
 type UserRoleRepo = PostgresUserRoleRepository;
 type SecurityEventRepo = PostgresSecurityEventRepository;
@@ -165,22 +229,28 @@ type HasherRepo = Argon2HasherRepository;
 type UserRequiredActionRepo = PostgresUserRequiredActionRepository;
 type OtpEnrollmentRepo = PostgresOtpEnrollmentRepository;
 type UserAttributeRepo = PostgresUserAttributeRepository;
-type KeystoreRepo = PostgresKeyStoreRepository;
+type KeystoreRepo = CachedKeyStoreRepository<PostgresKeyStoreRepository>;
 type RefreshTokenRepo = PostgresRefreshTokenRepository;
-type AccessTokenRepo = PostgresAccessTokenRepository;
+type AccessTokenRepo = CachedAccessTokenRepository<PostgresAccessTokenRepository>;
 type IdentityProviderRepo = PostgresIdentityProviderRepository;
 type FederationRepo = FederationRepositoryImpl;
+type FederationSyncRepo = FederationSyncRepositoryImpl;
 type BrokerAuthSessionRepo = PostgresBrokerAuthSessionRepository;
 type IdentityProviderLinkRepo = PostgresIdentityProviderLinkRepository;
+type IdentityProviderMapperRepo = PostgresIdentityProviderMapperRepository;
+type PendingBrokerLoginRepo = PostgresPendingBrokerLoginRepository;
 type OAuthClientImpl = ReqwestOAuthClient;
-type ClientScopeRepo = PostgresClientScopeRepository;
-type ProtocolMapperRepo = PostgresProtocolMapperRepository;
-type ScopeMappingRepo = PostgresScopeMappingRepository;
+type ClientScopeRepo = CachedClientScopeRepository<PostgresClientScopeRepository>;
+type ProtocolMapperRepo = CachedProtocolMapperRepository<PostgresProtocolMapperRepository>;
+type ScopeMappingRepo = CachedScopeMappingRepository<PostgresScopeMappingRepository>;
 type MagicLinkRepo = PostgresMagicLinkRepository;
 type CompassFlowRepo = PostgresCompassFlowRepository;
 type CompassFlowStepRepo = PostgresCompassFlowStepRepository;
 type SmtpConfigRepo = PostgresSmtpConfigRepository;
-type EmailPortImpl = SmtpEmailPort;
+type EmailOutboxRepo = PostgresEmailOutboxRepository;
+type EmailPortImpl = OutboxEmailPort<EmailOutboxRepo>;
+type EmailTransportImpl = ConfiguredEmailTransport;
+type SecurityNotificationRepo = PostgresSecurityNotificationRepository;
 type PasswordResetTokenRepo = PostgresPasswordResetTokenRepository;
 type PasswordPolicyRepo = crate::infrastructure::repositories::password_policy_repository::PostgresPasswordPolicyRepository;
 type EmailTemplateRepo = PostgresEmailTemplateRepository;
@@ -191,13 +261,34 @@ type OrganizationRepo = PostgresOrganizationRepository;
 type OrganizationAttributeRepo = PostgresOrganizationAttributeRepository;
 type OrganizationMemberRepo = PostgresOrganizationMemberRepository;
 type OrganizationMemberRoleRepo = PostgresOrganizationMemberRoleRepository;
+type OrganizationInvitationRepo = PostgresOrganizationInvitationRepository;
+type OrganizationDomainRepo = PostgresOrganizationDomainRepository;
+type DomainTxtResolverImpl = DohTxtResolver;
 type GroupRepo = PostgresGroupRepository;
 type GroupMemberRepo = PostgresGroupMemberRepository;
 type GroupRoleRepo = PostgresGroupRoleRepository;
 type GroupAttributeRepo = PostgresGroupAttributeRepository;
 type GroupTokenRepo = PostgresGroupTokenRepository;
+type RealmGroupRepo = PostgresRealmGroupRepository;
+type RealmGroupMemberRepo = PostgresRealmGroupMemberRepository;
+type RealmGroupRoleRepo = PostgresRealmGroupRoleRepository;
+type RealmGroupAttributeRepo = PostgresRealmGroupAttributeRepository;
+type RealmGroupMembershipRepo = PostgresRealmGroupMembershipRepository;
+type RateLimitPolicyRepo = PostgresRateLimitPolicyRepository;
+type RateLimitListRepo = PostgresRateLimitListRepository;
+type RateLimitCounterRepo = PostgresRateLimitCounterRepository;
+type LoginRiskPolicyRepo = PostgresLoginRiskPolicyRepository;
+type LoginHistoryRepo = PostgresLoginHistoryRepository;
+type GeoIpLookupImpl = MaxMindGeoIpLookup;
+type UserProfileSchemaRepo = PostgresUserProfileSchemaRepository;
+type LocalizationSettingsRepo = PostgresLocalizationSettingsRepository;
+type MessageBundleRepo = PostgresMessageBundleRepository;
+type WebAuthnPolicyRepo = PostgresWebAuthnPolicyRepository;
 type EmailVerificationTokenRepo = PostgresEmailVerificationTokenRepository;
 type UserSessionRepo = PostgresUserSessionRepository;
+type SamlClientRepo = SamlClientRepositoryImpl;
+type SamlAuthRequestRepo = SamlAuthRequestRepositoryImpl;
+type SamlKeyStoreImpl = OpenSslSamlKeyStore;
 
 pub(crate) type ApplicationTokenRevocation =
     crate::application::token_revocation::TokenRevocationAdapter<
@@ -206,6 +297,59 @@ pub(crate) type ApplicationTokenRevocation =
         UserSessionRepo,
     >;
 
+pub(crate) type ApplicationRateLimitService = RateLimitServiceImpl<
+    RealmRepo,
+    UserRepo,
+    ClientRepo,
+    UserRoleRepo,
+    RateLimitPolicyRepo,
+    RateLimitListRepo,
+    RateLimitCounterRepo,
+    SecurityEventRepo,
+>;
+
+pub(crate) type ApplicationLoginRiskService = LoginRiskServiceImpl<
+    RealmRepo,
+    UserRepo,
+    ClientRepo,
+    UserRoleRepo,
+    LoginRiskPolicyRepo,
+    LoginHistoryRepo,
+    GeoIpLookupImpl,
+    SmtpConfigRepo,
+    EmailPortImpl,
+    SecurityEventRepo,
+    SecurityNotifierImpl,
+>;
+
+pub(crate) type ApplicationUserProfileService =
+    UserProfileServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, UserProfileSchemaRepo>;
+
+pub(crate) type ApplicationLocalizationService = LocalizationServiceImpl<
+    RealmRepo,
+    UserRepo,
+    ClientRepo,
+    UserRoleRepo,
+    LocalizationSettingsRepo,
+    MessageBundleRepo,
+    UserAttributeRepo,
+>;
+
+pub(crate) type SecurityNotifierImpl = EmailSecurityNotifier<
+    RealmRepo,
+    UserRepo,
+    SmtpConfigRepo,
+    SecurityNotificationRepo,
+    EmailTemplateRepo,
+    MjmlRenderer,
+    EmailPortImpl,
+    SecurityEventRepo,
+    ApplicationLocalizationService,
+>;
+
+pub(crate) type ApplicationWebAuthnPolicyService =
+    WebAuthnPolicyServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, WebAuthnPolicyRepo>;
+
 type ApplicationUserSessionManagementService = UserSessionManagementServiceImpl<
     RealmRepo,
     UserSessionRepo,
@@ -227,6 +371,8 @@ type ApplicationUserService = UserServiceImpl<
     UserAttributeRepo,
     PasswordPolicyRepo,
     ApplicationTokenRevocation,
+    UserProfileSchemaRepo,
+    SecurityNotifierImpl,
 >;
 
 type ApplicationTridentService = TridentServiceImpl<
@@ -249,10 +395,37 @@ type ApplicationTridentService = TridentServiceImpl<
     OtpEnrollmentRepo,
     UserRoleRepo,
     ApplicationTokenRevocation,
+    FederatedPasswordWriter<FederationRepo, LdapClientImpl>,
+    ApplicationLocalizationService,
+    ApplicationWebAuthnPolicyService,
+    SecurityNotifierImpl,
 >;
 
 type MaintenanceWhitelistRepo = crate::infrastructure::maintenance::repositories::maintenance_whitelist_repository::PostgresMaintenanceWhitelistRepository;
 type RealmMaintenanceWhitelistRepo = crate::infrastructure::maintenance::repositories::realm_maintenance_whitelist_repository::PostgresRealmMaintenanceWhitelistRepository;
+type MaintenanceWindowRepo = crate::infrastructure::maintenance::repositories::maintenance_window_repository::PostgresMaintenanceWindowRepository;
+type ApplicationOrganizationInvitationService = OrganizationInvitationServiceImpl<
+    RealmRepo,
+    UserRepo,
+    ClientRepo,
+    UserRoleRepo,
+    RoleRepo,
+    OrganizationRepo,
+    OrganizationMemberRepo,
+    OrganizationMemberRoleRepo,
+    GroupRepo,
+    GroupMemberRepo,
+    OrganizationInvitationRepo,
+    CredentialRepo,
+    HasherRepo,
+    PasswordPolicyRepo,
+    SmtpConfigRepo,
+    EmailPortImpl,
+    EmailTemplateRepo,
+    MjmlRenderer,
+    ApplicationLocalizationService,
+>;
+
 type ApplicationEmailVerificationService = EmailVerificationServiceImpl<
     EmailVerificationTokenRepo,
     UserRepo,
@@ -264,6 +437,7 @@ type ApplicationEmailVerificationService = EmailVerificationServiceImpl<
     MjmlRenderer,
     WebhookRepo,
     SecurityEventRepo,
+    ApplicationLocalizationService,
 >;
 
 type ApplicationMaintenanceService = MaintenanceServiceImpl<
@@ -275,6 +449,7 @@ type ApplicationMaintenanceService = MaintenanceServiceImpl<
     SecurityEventRepo,
     MaintenanceWhitelistRepo,
     RealmMaintenanceWhitelistRepo,
+    MaintenanceWindowRepo,
 >;
 
 type ApplicationAuthService = AuthServiceImpl<
@@ -306,8 +481,29 @@ type ApplicationAuthService = AuthServiceImpl<
     SecurityEventRepo,
     UserSessionRepo,
     LoginActionTokenRepo,
+    RealmGroupMembershipRepo,
+    ApplicationLoginRiskService,
+    UserProfileSchemaRepo,
+    SecurityNotifierImpl,
 >;
 
+type ApplicationFederationService =
+    crate::domain::abyss::federation::services::FederationServiceImpl<
+        RealmRepo,
+        FederationRepo,
+        crate::domain::common::policies::FerriskeyPolicy<UserRepo, ClientRepo, UserRoleRepo>,
+        UserRepo,
+        CredentialRepo,
+        RoleRepo,
+        UserRoleRepo,
+        GroupRepo,
+        GroupMemberRepo,
+        RealmGroupRepo,
+        RealmGroupMemberRepo,
+        LdapClientImpl,
+        FederationSyncRepo,
+    >;
+
 type LoginActionTokenRepo = PostgresLoginActionTokenRepository;
 
 type DeviceAuthRepo = PostgresDeviceAuthRepository;
@@ -326,12 +522,82 @@ impl DeviceTokenIssuer for ApplicationAuthService {
 type ApplicationDeviceFlowService =
     DeviceFlowServiceImpl<DeviceAuthRepo, WebhookRepo, ApplicationAuthService>;
 
+/// SAML logins redeem their code and end their sessions through the auth service.
+impl SamlIdentityIssuer for ApplicationAuthService {
+    async fn issue_saml_identity(
+        &self,
+        input: SamlIdentityInput,
+    ) -> Result<SamlIdentity, CoreError> {
+        AuthServiceImpl::issue_saml_identity(self, input).await
+    }
+
+    async fn find_saml_session_user(
+        &self,
+        realm_id: RealmId,
+        session_id: Uuid,
+    ) -> Result<Option<User>, CoreError> {
+        AuthServiceImpl::find_saml_session_user(self, realm_id, session_id).await
+    }
+
+    async fn end_saml_session(
+        &self,
+        realm_id: RealmId,
+        session_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), CoreError> {
+        AuthServiceImpl::end_saml_session(self, realm_id, session_id, user_id).await
+    }
+}
+
+type ApplicationSamlService = SamlServiceImpl<
+    RealmRepo,
+    UserRepo,
+    ClientRepo,
+    UserRoleRepo,
+    SamlClientRepo,
+    SamlAuthRequestRepo,
+    AuthSessionRepo,
+    SamlKeyStoreImpl,
+    ApplicationAuthService,
+>;
+
+type ApplicationBrokerService = BrokerServiceImpl<
+    RealmRepo,
+    IdentityProviderRepo,
+    BrokerAuthSessionRepo,
+    IdentityProviderLinkRepo,
+    ClientRepo,
+    RedirectUriRepo,
+    UserRepo,
+    AuthSessionRepo,
+    OAuthClientImpl,
+    IdentityProviderMapperRepo,
+    UserRoleRepo,
+    UserAttributeRepo,
+    OrganizationMemberRepo,
+    GroupMemberRepo,
+    KeystoreRepo,
+    PendingBrokerLoginRepo,
+    CredentialRepo,
+    HasherRepo,
+    SmtpConfigRepo,
+    EmailPortImpl,
+    SamlKeyStoreImpl,
+    SecurityNotifierImpl,
+>;
+
 #[derive(Clone, Debug)]
 pub struct ApplicationService {
     pub(crate) security_event_service:
         SecurityEventServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, SecurityEventRepo>,
-    pub(crate) credential_service:
-        CredentialServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, CredentialRepo>,
+    pub(crate) credential_service: CredentialServiceImpl<
+        RealmRepo,
+        UserRepo,
+        ClientRepo,
+        UserRoleRepo,
+        CredentialRepo,
+        SecurityNotifierImpl,
+    >,
     pub(crate) client_service: ClientServiceImpl<
         RealmRepo,
         UserRepo,
@@ -358,6 +624,7 @@ pub struct ApplicationService {
         ProtocolMapperRepo,
         ScopeMappingRepo,
         RedirectUriRepo,
+        OrganizationMemberRoleRepo,
     >,
     pub(crate) mail_service:
         MailServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, SmtpConfigRepo>,
@@ -379,6 +646,7 @@ pub struct ApplicationService {
     pub(crate) maintenance_service: ApplicationMaintenanceService,
     pub(crate) auth_service: ApplicationAuthService,
     pub(crate) device_flow_service: ApplicationDeviceFlowService,
+    pub(crate) saml_service: ApplicationSamlService,
     pub(crate) core_service: CoreServiceImpl<
         RealmRepo,
         KeystoreRepo,
@@ -389,6 +657,7 @@ pub struct ApplicationService {
         HasherRepo,
         CredentialRepo,
         RedirectUriRepo,
+        OrganizationMemberRoleRepo,
     >,
     pub(crate) identity_provider_service: IdentityProviderServiceImpl<
         IdentityProviderRepo,
@@ -397,26 +666,19 @@ pub struct ApplicationService {
         UserRepo,
         IdentityProviderLinkRepo,
         SecurityEventRepo,
+        OAuthClientImpl,
     >,
-    pub(crate) federation_service:
-        crate::domain::abyss::federation::services::FederationServiceImpl<
-            RealmRepo,
-            FederationRepo,
-            crate::domain::common::policies::FerriskeyPolicy<UserRepo, ClientRepo, UserRoleRepo>,
-            UserRepo,
-            CredentialRepo,
-        >,
-    pub(crate) broker_service: BrokerServiceImpl<
-        RealmRepo,
+    pub(crate) identity_provider_mapper_service: IdentityProviderMapperServiceImpl<
+        IdentityProviderMapperRepo,
         IdentityProviderRepo,
-        BrokerAuthSessionRepo,
-        IdentityProviderLinkRepo,
-        ClientRepo,
-        RedirectUriRepo,
-        UserRepo,
-        AuthSessionRepo,
-        OAuthClientImpl,
+        crate::domain::common::policies::FerriskeyPolicy<UserRepo, ClientRepo, UserRoleRepo>,
+        RealmRepo,
+        RoleRepo,
+        OrganizationRepo,
+        GroupRepo,
     >,
+    pub(crate) federation_service: ApplicationFederationService,
+    pub(crate) broker_service: ApplicationBrokerService,
     pub(crate) client_scope_service: ClientScopeServiceImpl<
         RealmRepo,
         UserRepo,
@@ -457,6 +719,23 @@ pub struct ApplicationService {
         EmailTemplateRepo,
         MjmlRenderer,
     >,
+    pub(crate) email_outbox_service: EmailOutboxServiceImpl<
+        RealmRepo,
+        UserRepo,
+        ClientRepo,
+        UserRoleRepo,
+        SmtpConfigRepo,
+        EmailOutboxRepo,
+        EmailTransportImpl,
+    >,
+    pub(crate) security_notification_service: SecurityNotificationServiceImpl<
+        RealmRepo,
+        UserRepo,
+        ClientRepo,
+        UserRoleRepo,
+        SecurityNotificationRepo,
+        EmailTemplateRepo,
+    >,
     #[allow(dead_code)]
     pub(crate) portal_theme_service:
         PortalThemeServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, PortalThemeRepo>,
@@ -473,6 +752,7 @@ pub struct ApplicationService {
         OrganizationRepo,
         OrganizationAttributeRepo,
         OrganizationMemberRepo,
+        OrganizationMemberRoleRepo,
     >,
     pub(crate) group_service: GroupServiceImpl<
         RealmRepo,
@@ -484,7 +764,25 @@ pub struct ApplicationService {
         GroupMemberRepo,
         GroupRoleRepo,
         GroupAttributeRepo,
+        OrganizationMemberRepo,
+        OrganizationMemberRoleRepo,
+    >,
+    pub(crate) realm_group_service: RealmGroupServiceImpl<
+        RealmRepo,
+        UserRepo,
+        ClientRepo,
+        UserRoleRepo,
+        RealmGroupRepo,
+        RealmGroupMemberRepo,
+        RealmGroupRoleRepo,
+        RealmGroupAttributeRepo,
+        RealmGroupMembershipRepo,
     >,
+    pub(crate) rate_limit_service: ApplicationRateLimitService,
+    pub(crate) login_risk_service: ApplicationLoginRiskService,
+    pub(crate) user_profile_service: ApplicationUserProfileService,
+    pub(crate) localization_service: ApplicationLocalizationService,
+    pub(crate) webauthn_policy_service: ApplicationWebAuthnPolicyService,
     pub(crate) organization_member_role_service: OrganizationMemberRoleServiceImpl<
         RealmRepo,
         UserRepo,
@@ -494,6 +792,17 @@ pub struct ApplicationService {
         OrganizationMemberRepo,
         OrganizationMemberRoleRepo,
     >,
+    pub(crate) organization_invitation_service: ApplicationOrganizationInvitationService,
+    pub(crate) organization_domain_service: OrganizationDomainServiceImpl<
+        RealmRepo,
+        UserRepo,
+        ClientRepo,
+        UserRoleRepo,
+        OrganizationRepo,
+        OrganizationDomainRepo,
+        IdentityProviderRepo,
+        DomainTxtResolverImpl,
+    >,
     #[allow(dead_code)]
     pub(crate) flow_recorder: FlowRecorder,
     pub(crate) db: DatabaseConnection,
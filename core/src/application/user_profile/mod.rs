@@ -0,0 +1,41 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        user_profile::{
+            UpdateUserProfileSchemaInput, UserProfileConfig, UserProfileSchema, UserProfileService,
+        },
+    },
+};
+
+impl UserProfileService for ApplicationService {
+    async fn get_user_profile_schema(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<UserProfileSchema, CoreError> {
+        self.user_profile_service
+            .get_user_profile_schema(identity, realm_name)
+            .await
+    }
+
+    async fn update_user_profile_schema(
+        &self,
+        identity: Identity,
+        input: UpdateUserProfileSchemaInput,
+    ) -> Result<UserProfileSchema, CoreError> {
+        self.user_profile_service
+            .update_user_profile_schema(identity, input)
+            .await
+    }
+
+    async fn get_registration_profile(
+        &self,
+        realm_name: String,
+    ) -> Result<UserProfileConfig, CoreError> {
+        self.user_profile_service
+            .get_registration_profile(realm_name)
+            .await
+    }
+}
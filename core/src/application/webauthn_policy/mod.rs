@@ -0,0 +1,42 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        webauthn_policy::{
+            MetadataSummary, UpdateWebAuthnPolicyInput, WebAuthnPolicy, WebAuthnPolicyService,
+        },
+    },
+};
+
+impl WebAuthnPolicyService for ApplicationService {
+    async fn get_webauthn_policy(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<WebAuthnPolicy, CoreError> {
+        self.webauthn_policy_service
+            .get_webauthn_policy(identity, realm_name)
+            .await
+    }
+
+    async fn update_webauthn_policy(
+        &self,
+        identity: Identity,
+        input: UpdateWebAuthnPolicyInput,
+    ) -> Result<WebAuthnPolicy, CoreError> {
+        self.webauthn_policy_service
+            .update_webauthn_policy(identity, input)
+            .await
+    }
+
+    async fn get_fido_metadata(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<MetadataSummary, CoreError> {
+        self.webauthn_policy_service
+            .get_fido_metadata(identity, realm_name)
+            .await
+    }
+}
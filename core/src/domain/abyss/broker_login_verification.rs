@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use tracing::{instrument, warn};
+use uuid::Uuid;
+
+use crate::domain::common::email::EmailPort;
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::credential::entities::{CredentialData, CredentialType};
+use crate::domain::credential::ports::CredentialRepository;
+use crate::domain::crypto::HasherRepository;
+use crate::domain::realm::entities::RealmId;
+use crate::domain::realm::ports::SmtpConfigRepository;
+use crate::domain::trident::entities::TotpSecret;
+use crate::domain::trident::services::verify as verify_totp;
+use crate::domain::user::entities::User;
+
+/// How long an emailed link confirmation code stays valid.
+const CONFIRMATION_CODE_TTL: Duration = Duration::minutes(10);
+
+/// The checks a brokered login runs against an existing local account: its password, an
+/// emailed code and its one-time password.
+#[derive(Clone, Debug)]
+pub struct BrokerLoginVerification<CR, H, SC, EP>
+where
+    CR: CredentialRepository,
+    H: HasherRepository,
+    SC: SmtpConfigRepository,
+    EP: EmailPort,
+{
+    credential_repository: Arc<CR>,
+    hasher_repository: Arc<H>,
+    smtp_config_repository: Arc<SC>,
+    email_port: Arc<EP>,
+}
+
+impl<CR, H, SC, EP> BrokerLoginVerification<CR, H, SC, EP>
+where
+    CR: CredentialRepository,
+    H: HasherRepository,
+    SC: SmtpConfigRepository,
+    EP: EmailPort,
+{
+    pub fn new(
+        credential_repository: Arc<CR>,
+        hasher_repository: Arc<H>,
+        smtp_config_repository: Arc<SC>,
+        email_port: Arc<EP>,
+    ) -> Self {
+        Self {
+            credential_repository,
+            hasher_repository,
+            smtp_config_repository,
+            email_port,
+        }
+    }
+
+    pub async fn has_password(&self, user_id: Uuid) -> bool {
+        self.credential_repository
+            .get_password_credential(user_id)
+            .await
+            .is_ok()
+    }
+
+    #[instrument(skip_all, fields(user.id = %user_id))]
+    pub async fn verify_password(&self, user_id: Uuid, password: &str) -> Result<bool, CoreError> {
+        let Ok(credential) = self
+            .credential_repository
+            .get_password_credential(user_id)
+            .await
+        else {
+            return Ok(false);
+        };
+
+        let salt = credential.salt.ok_or(CoreError::InternalServerError)?;
+        let CredentialData::Hash {
+            hash_iterations,
+            algorithm,
+        } = credential.credential_data
+        else {
+            return Err(CoreError::InternalServerError);
+        };
+
+        self.hasher_repository
+            .verify_password(
+                password,
+                &credential.secret_data,
+                hash_iterations,
+                &algorithm,
+                &salt,
+            )
+            .await
+            .map_err(|_| CoreError::InternalServerError)
+    }
+
+    pub async fn has_otp(&self, user_id: Uuid) -> Result<bool, CoreError> {
+        Ok(self.otp_secret(user_id).await?.is_some())
+    }
+
+    #[instrument(skip_all, fields(user.id = %user_id))]
+    pub async fn verify_otp(&self, user_id: Uuid, code: &str) -> Result<bool, CoreError> {
+        match self.otp_secret(user_id).await? {
+            Some(secret) => verify_totp(&secret, code),
+            None => Ok(false),
+        }
+    }
+
+    async fn otp_secret(&self, user_id: Uuid) -> Result<Option<TotpSecret>, CoreError> {
+        let credentials = self
+            .credential_repository
+            .get_credentials_by_user_id(user_id)
+            .await
+            .map_err(|_| CoreError::GetUserCredentialsError)?;
+
+        Ok(credentials
+            .iter()
+            .find(|credential| credential.credential_type == CredentialType::Otp)
+            .map(|credential| TotpSecret::from_base32(&credential.secret_data)))
+    }
+
+    /// Whether a confirmation code can be emailed to `user`.
+    pub async fn can_email(&self, realm_id: RealmId, user: &User) -> Result<bool, CoreError> {
+        if user.email.is_none() {
+            return Ok(false);
+        }
+
+        Ok(self
+            .smtp_config_repository
+            .get_by_realm_id(realm_id)
+            .await?
+            .is_some())
+    }
+
+    /// Emails a six digit code to `user` and returns its hash and expiry, for the caller
+    /// to store.
+    #[instrument(skip_all, fields(user.id = %user.id))]
+    pub async fn send_confirmation_code(
+        &self,
+        realm_id: RealmId,
+        user: &User,
+        provider_name: &str,
+    ) -> Result<(String, DateTime<Utc>), CoreError> {
+        let email = user.email.as_deref().ok_or(CoreError::InvalidRequest)?;
+        let smtp_config = self
+            .smtp_config_repository
+            .get_by_realm_id(realm_id)
+            .await?
+            .ok_or(CoreError::InvalidRequest)?;
+
+        let code = format!("{:06}", rand::thread_rng().gen_range(0..1_000_000));
+        let body = format!(
+            "Someone is signing in with {provider_name} and asked to link it to your account.\n\nYour confirmation code is {code}. It expires in {} minutes.\n\nIf this was not you, ignore this email; your account stays unchanged.",
+            CONFIRMATION_CODE_TTL.num_minutes(),
+        );
+
+        self.email_port
+            .send_email(&smtp_config, email, "Confirm account linking", &body, None)
+            .await
+            .inspect_err(|e| warn!("Failed to send broker link confirmation code: {}", e))?;
+
+        Ok((hash_code(&code), Utc::now() + CONFIRMATION_CODE_TTL))
+    }
+}
+
+fn hash_code(code: &str) -> String {
+    hex::encode(Sha256::digest(code.trim().as_bytes()))
+}
+
+/// Whether `code` is the emailed code whose hash was stored, and has not expired.
+pub fn confirmation_code_matches(
+    code: &str,
+    stored_hash: Option<&str>,
+    expires_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> bool {
+    let (Some(stored_hash), Some(expires_at)) = (stored_hash, expires_at) else {
+        return false;
+    };
+
+    now <= expires_at && bool::from(hash_code(code).as_bytes().ct_eq(stored_hash.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confirmation_code_matches_until_expiry() {
+        let now = Utc::now();
+        let hash = hash_code("042137");
+        let expires_at = Some(now + Duration::minutes(10));
+
+        assert!(confirmation_code_matches(
+            " 042137 ",
+            Some(&hash),
+            expires_at,
+            now
+        ));
+        assert!(!confirmation_code_matches(
+            "042138",
+            Some(&hash),
+            expires_at,
+            now
+        ));
+        assert!(!confirmation_code_matches(
+            "042137",
+            Some(&hash),
+            expires_at,
+            now + Duration::minutes(11)
+        ));
+        assert!(!confirmation_code_matches("042137", None, None, now));
+    }
+}
@@ -0,0 +1,530 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+use crate::domain::abyss::identity_provider::IdentityProvider;
+use crate::domain::abyss::identity_provider::broker::BrokeredUserInfo;
+use crate::domain::abyss::identity_provider::mappers::{
+    IdentityProviderMapper, IdentityProviderMapperConfig, IdentityProviderMapperRepository,
+    MapperSyncMode, claim_has_value, claim_values, render_username_template,
+};
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::organization::ports::{
+    GroupId, GroupMemberRepository, OrganizationId, OrganizationMemberRepository,
+};
+use crate::domain::user::entities::User;
+use crate::domain::user::ports::{UserAttributeRepository, UserRepository, UserRoleRepository};
+use crate::domain::user::value_objects::UpdateUserRequest;
+
+/// Whether the applicable mappers grant a role or membership, and whether one of them manages
+/// it on every login.
+#[derive(Debug, Clone, Copy, Default)]
+struct Grant {
+    granted: bool,
+    forced: bool,
+}
+
+impl Grant {
+    fn record(&mut self, mapper: &IdentityProviderMapper, granted: bool) {
+        self.granted |= granted;
+        self.forced |= mapper.sync_mode == MapperSyncMode::Force;
+    }
+
+    /// Revoke only what a force mapper manages and no applicable mapper grants anymore.
+    fn revokes(&self) -> bool {
+        self.forced && !self.granted
+    }
+}
+
+/// Applies the mappers of an identity provider to the user of a brokered login.
+#[derive(Clone, Debug)]
+pub struct BrokeredUserMapping<MR, UR, URR, UAR, OM, GM>
+where
+    MR: IdentityProviderMapperRepository,
+    UR: UserRepository,
+    URR: UserRoleRepository,
+    UAR: UserAttributeRepository,
+    OM: OrganizationMemberRepository,
+    GM: GroupMemberRepository,
+{
+    mapper_repository: Arc<MR>,
+    user_repository: Arc<UR>,
+    user_role_repository: Arc<URR>,
+    user_attribute_repository: Arc<UAR>,
+    organization_member_repository: Arc<OM>,
+    group_member_repository: Arc<GM>,
+}
+
+impl<MR, UR, URR, UAR, OM, GM> BrokeredUserMapping<MR, UR, URR, UAR, OM, GM>
+where
+    MR: IdentityProviderMapperRepository,
+    UR: UserRepository,
+    URR: UserRoleRepository,
+    UAR: UserAttributeRepository,
+    OM: OrganizationMemberRepository,
+    GM: GroupMemberRepository,
+{
+    pub fn new(
+        mapper_repository: Arc<MR>,
+        user_repository: Arc<UR>,
+        user_role_repository: Arc<URR>,
+        user_attribute_repository: Arc<UAR>,
+        organization_member_repository: Arc<OM>,
+        group_member_repository: Arc<GM>,
+    ) -> Self {
+        Self {
+            mapper_repository,
+            user_repository,
+            user_role_repository,
+            user_attribute_repository,
+            organization_member_repository,
+            group_member_repository,
+        }
+    }
+
+    pub async fn mappers_for(
+        &self,
+        idp: &IdentityProvider,
+    ) -> Result<Vec<IdentityProviderMapper>, CoreError> {
+        self.mapper_repository
+            .list_mappers_by_provider(idp.id)
+            .await
+    }
+
+    /// Username of an account created by the login: the first username template that renders
+    /// to something, otherwise the provider's preferred username.
+    pub fn username_for(
+        idp: &IdentityProvider,
+        mappers: &[IdentityProviderMapper],
+        user_info: &BrokeredUserInfo,
+    ) -> Result<String, CoreError> {
+        for mapper in mappers {
+            if let IdentityProviderMapperConfig::UsernameTemplate { template } = &mapper.config {
+                let username = render_username_template(template, &idp.alias, &user_info.claims)?;
+                if !username.trim().is_empty() {
+                    return Ok(username);
+                }
+            }
+        }
+
+        Ok(user_info.get_username(&idp.alias))
+    }
+
+    /// Apply the mappers that run for this login to `user`.
+    #[instrument(skip_all, fields(user.id = %user.id, is_new_user))]
+    pub async fn apply(
+        &self,
+        user: &User,
+        mappers: &[IdentityProviderMapper],
+        user_info: &BrokeredUserInfo,
+        is_new_user: bool,
+    ) -> Result<(), CoreError> {
+        let applicable: Vec<&IdentityProviderMapper> = mappers
+            .iter()
+            .filter(|mapper| mapper.applies(is_new_user))
+            .collect();
+
+        if applicable.is_empty() {
+            return Ok(());
+        }
+
+        self.apply_attributes(user, &applicable, user_info).await?;
+        self.apply_roles(user, &applicable, user_info).await?;
+        self.apply_memberships(user, &applicable, user_info).await?;
+
+        Ok(())
+    }
+
+    async fn apply_attributes(
+        &self,
+        user: &User,
+        mappers: &[&IdentityProviderMapper],
+        user_info: &BrokeredUserInfo,
+    ) -> Result<(), CoreError> {
+        let mut firstname = user.firstname.clone();
+        let mut lastname = user.lastname.clone();
+        let mut email = user.email.clone();
+        let mut attributes = HashMap::new();
+        let mut cleared = Vec::new();
+
+        for mapper in mappers {
+            let IdentityProviderMapperConfig::ClaimToAttribute { claim, attribute } =
+                &mapper.config
+            else {
+                continue;
+            };
+            let value = claim_values(&user_info.claims, claim).into_iter().next();
+
+            match (attribute.as_str(), value) {
+                ("firstname", Some(value)) => firstname = Some(value),
+                ("lastname", Some(value)) => lastname = Some(value),
+                ("email", Some(value)) => email = Some(value),
+                ("firstname" | "lastname" | "email", None) => {}
+                (_, Some(value)) => {
+                    attributes.insert(attribute.clone(), value);
+                }
+                (_, None) if mapper.sync_mode == MapperSyncMode::Force => {
+                    cleared.push(attribute.clone());
+                }
+                (_, None) => {}
+            }
+        }
+
+        if firstname != user.firstname || lastname != user.lastname || email != user.email {
+            // A mapped address has not been verified by us, whatever the provider says.
+            let email_verified = user.email_verified && email == user.email;
+            self.user_repository
+                .update_user(
+                    user.id,
+                    UpdateUserRequest {
+                        firstname,
+                        lastname,
+                        email,
+                        email_verified,
+                        enabled: user.enabled,
+                        required_actions: None,
+                    },
+                )
+                .await?;
+        }
+
+        if !attributes.is_empty() {
+            self.user_attribute_repository
+                .upsert_many(user.id, user.realm_id, attributes)
+                .await?;
+        }
+
+        for key in cleared {
+            self.user_attribute_repository
+                .delete_by_key(user.id, key)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn apply_roles(
+        &self,
+        user: &User,
+        mappers: &[&IdentityProviderMapper],
+        user_info: &BrokeredUserInfo,
+    ) -> Result<(), CoreError> {
+        let mut roles: BTreeMap<Uuid, Grant> = BTreeMap::new();
+
+        for mapper in mappers {
+            let (role_id, granted) = match &mapper.config {
+                IdentityProviderMapperConfig::ClaimToRole {
+                    claim,
+                    claim_value,
+                    role_id,
+                } => (
+                    *role_id,
+                    claim_has_value(&user_info.claims, claim, claim_value),
+                ),
+                IdentityProviderMapperConfig::HardcodedRole { role_id } => (*role_id, true),
+                _ => continue,
+            };
+            roles.entry(role_id).or_default().record(mapper, granted);
+        }
+
+        for (role_id, grant) in roles {
+            let has_role = self.user_role_repository.has_role(user.id, role_id).await?;
+
+            if grant.granted && !has_role {
+                debug!(%role_id, "Granting role from identity provider claims");
+                self.user_role_repository
+                    .assign_role(user.id, role_id)
+                    .await?;
+            } else if grant.revokes() && has_role {
+                debug!(%role_id, "Revoking role no longer granted by identity provider claims");
+                self.user_role_repository
+                    .revoke_role(user.id, role_id)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn apply_memberships(
+        &self,
+        user: &User,
+        mappers: &[&IdentityProviderMapper],
+        user_info: &BrokeredUserInfo,
+    ) -> Result<(), CoreError> {
+        let mut organizations: BTreeMap<Uuid, Grant> = BTreeMap::new();
+        let mut groups: BTreeMap<(Uuid, Uuid), Grant> = BTreeMap::new();
+
+        for mapper in mappers {
+            let IdentityProviderMapperConfig::ClaimToOrganization {
+                claim,
+                claim_value,
+                organization_id,
+                group_id,
+            } = &mapper.config
+            else {
+                continue;
+            };
+            let granted = claim_has_value(&user_info.claims, claim, claim_value);
+
+            // A group mapper needs the organization membership but does not manage it.
+            let organization = organizations.entry(*organization_id).or_default();
+            match group_id {
+                Some(group_id) => {
+                    organization.granted |= granted;
+                    groups
+                        .entry((*organization_id, *group_id))
+                        .or_default()
+                        .record(mapper, granted);
+                }
+                None => organization.record(mapper, granted),
+            }
+        }
+
+        for (organization_id, grant) in organizations {
+            let organization_id = OrganizationId::new(organization_id);
+            let is_member = self
+                .organization_member_repository
+                .get_member(organization_id, user.id)
+                .await?
+                .is_some();
+
+            if grant.granted && !is_member {
+                self.organization_member_repository
+                    .add_member(organization_id, user.id)
+                    .await?;
+            } else if grant.revokes() && is_member {
+                self.organization_member_repository
+                    .remove_member(organization_id, user.id)
+                    .await?;
+            }
+        }
+
+        for ((_, group_id), grant) in groups {
+            let group_id = GroupId::new(group_id);
+            let is_member = self
+                .group_member_repository
+                .get_member(group_id, user.id)
+                .await?
+                .is_some();
+
+            if grant.granted && !is_member {
+                self.group_member_repository
+                    .add_member(group_id, user.id)
+                    .await?;
+            } else if grant.revokes() && is_member {
+                self.group_member_repository
+                    .remove_member(group_id, user.id)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::abyss::identity_provider::mappers::ports::MockIdentityProviderMapperRepository;
+    use crate::domain::abyss::identity_provider::{
+        IdentityProviderConfig, IdentityProviderCreationConfig, IdentityProviderId,
+    };
+    use crate::domain::realm::entities::RealmId;
+    use crate::domain::user::entities::UserConfig;
+    use crate::domain::user::ports::{
+        MockUserAttributeRepository, MockUserRepository, MockUserRoleRepository,
+    };
+    use ferriskey_organization::{MockGroupMemberRepository, MockOrganizationMemberRepository};
+
+    type Mapping = BrokeredUserMapping<
+        MockIdentityProviderMapperRepository,
+        MockUserRepository,
+        MockUserRoleRepository,
+        MockUserAttributeRepository,
+        MockOrganizationMemberRepository,
+        MockGroupMemberRepository,
+    >;
+
+    fn mapping(user_roles: MockUserRoleRepository) -> Mapping {
+        BrokeredUserMapping::new(
+            Arc::new(MockIdentityProviderMapperRepository::new()),
+            Arc::new(MockUserRepository::new()),
+            Arc::new(user_roles),
+            Arc::new(MockUserAttributeRepository::new()),
+            Arc::new(MockOrganizationMemberRepository::new()),
+            Arc::new(MockGroupMemberRepository::new()),
+        )
+    }
+
+    fn mapper(
+        sync_mode: MapperSyncMode,
+        config: IdentityProviderMapperConfig,
+    ) -> IdentityProviderMapper {
+        IdentityProviderMapper {
+            id: Uuid::new_v4(),
+            realm_id: RealmId::default(),
+            identity_provider_id: IdentityProviderId::new(Uuid::new_v4()),
+            name: "mapper".to_string(),
+            sync_mode,
+            config,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn user() -> User {
+        User::new(UserConfig {
+            realm_id: RealmId::default(),
+            client_id: None,
+            username: "jane".to_string(),
+            firstname: Some("Jane".to_string()),
+            lastname: None,
+            email: None,
+            email_verified: false,
+            enabled: true,
+        })
+    }
+
+    fn user_info(claims: serde_json::Value) -> BrokeredUserInfo {
+        BrokeredUserInfo {
+            subject: "1234".to_string(),
+            claims,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_groups_claim_grants_role_and_force_mode_revokes_it() {
+        let granted_role = Uuid::new_v4();
+        let stale_role = Uuid::new_v4();
+        let mappers = vec![
+            mapper(
+                MapperSyncMode::Force,
+                IdentityProviderMapperConfig::ClaimToRole {
+                    claim: "groups".to_string(),
+                    claim_value: "a1b2".to_string(),
+                    role_id: granted_role,
+                },
+            ),
+            mapper(
+                MapperSyncMode::Force,
+                IdentityProviderMapperConfig::ClaimToRole {
+                    claim: "groups".to_string(),
+                    claim_value: "c3d4".to_string(),
+                    role_id: stale_role,
+                },
+            ),
+        ];
+
+        let mut user_roles = MockUserRoleRepository::new();
+        user_roles
+            .expect_has_role()
+            .returning(move |_, role_id| Box::pin(async move { Ok(role_id == stale_role) }));
+        user_roles
+            .expect_assign_role()
+            .withf(move |_, role_id| *role_id == granted_role)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        user_roles
+            .expect_revoke_role()
+            .withf(move |_, role_id| *role_id == stale_role)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        mapping(user_roles)
+            .apply(
+                &user(),
+                &mappers,
+                &user_info(json!({ "groups": ["a1b2", "e5f6"] })),
+                false,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_import_mappers_are_skipped_for_existing_users() {
+        let mappers = vec![mapper(
+            MapperSyncMode::Import,
+            IdentityProviderMapperConfig::HardcodedRole {
+                role_id: Uuid::new_v4(),
+            },
+        )];
+
+        // Any repository call would panic on the unconfigured mocks.
+        mapping(MockUserRoleRepository::new())
+            .apply(&user(), &mappers, &user_info(json!({})), false)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_import_role_mapper_does_not_revoke() {
+        let role_id = Uuid::new_v4();
+        let mappers = vec![mapper(
+            MapperSyncMode::Import,
+            IdentityProviderMapperConfig::ClaimToRole {
+                claim: "groups".to_string(),
+                claim_value: "a1b2".to_string(),
+                role_id,
+            },
+        )];
+
+        let mut user_roles = MockUserRoleRepository::new();
+        user_roles
+            .expect_has_role()
+            .returning(|_, _| Box::pin(async { Ok(true) }));
+
+        mapping(user_roles)
+            .apply(&user(), &mappers, &user_info(json!({ "groups": [] })), true)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_username_template_falls_back_to_preferred_username() {
+        let idp = IdentityProvider::new(IdentityProviderCreationConfig {
+            realm_id: RealmId::default(),
+            alias: "azure".to_string(),
+            provider_id: "oidc".to_string(),
+            enabled: true,
+            display_name: None,
+            first_broker_login_flow_alias: None,
+            post_broker_login_flow_alias: None,
+            store_token: false,
+            add_read_token_role_on_create: false,
+            trust_email: false,
+            link_only: false,
+            config: IdentityProviderConfig {
+                client_id: None,
+                client_secret: None,
+                extra: json!({}),
+            },
+        });
+        let mappers = vec![mapper(
+            MapperSyncMode::Import,
+            IdentityProviderMapperConfig::UsernameTemplate {
+                template: "${CLAIM.upn | localpart | lowercase}".to_string(),
+            },
+        )];
+
+        let mut info = user_info(json!({ "upn": "Jane.Doe@contoso.com" }));
+        info.preferred_username = Some("jdoe".to_string());
+        assert_eq!(
+            Mapping::username_for(&idp, &mappers, &info).unwrap(),
+            "jane.doe"
+        );
+
+        info.claims = json!({});
+        assert_eq!(
+            Mapping::username_for(&idp, &mappers, &info).unwrap(),
+            "jdoe"
+        );
+    }
+}
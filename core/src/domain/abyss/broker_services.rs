@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
-use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use base64::{
+    Engine as _,
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+};
 use chrono::Utc;
 use ferriskey_compass::{
-    entities::{FlowStatus, FlowStepName, StepStatus},
+    entities::{FlowId, FlowStatus, FlowStepName, StepStatus},
     recorder::FlowRecorder,
 };
 use rand::{RngCore, thread_rng};
@@ -11,25 +14,64 @@ use sha2::{Digest, Sha256};
 use tracing::{error, instrument, warn};
 use uuid::Uuid;
 
+use ferriskey_security::jwt::ports::KeyStoreRepository;
+
+use crate::domain::abyss::broker_login_verification::{
+    BrokerLoginVerification, confirmation_code_matches,
+};
+use crate::domain::abyss::broker_mapping::BrokeredUserMapping;
+use crate::domain::abyss::identity_provider::broker::first_login::{
+    CreatePendingBrokerLoginRequest, ExistingAccountHint, MAX_CONFIRMATION_ATTEMPTS,
+    UpdatePendingBrokerLoginRequest, mask_email,
+};
 use crate::domain::abyss::identity_provider::broker::{
-    BrokerAuthSessionRepository, BrokerCallbackInput, BrokerCallbackOutput, BrokerLoginInput,
-    BrokerLoginOutput, BrokerService, BrokeredUserInfo, CreateBrokerAuthSessionRequest,
-    CreateIdentityProviderLinkRequest, IdentityProviderLink, IdentityProviderLinkRepository,
-    OAuthClient, OAuthProviderConfig, OAuthTokenResponse,
+    BrokerAuthSession, BrokerAuthSessionRepository, BrokerCallbackInput, BrokerCallbackOutput,
+    BrokerLoginInput, BrokerLoginOutcome, BrokerLoginOutput, BrokerSamlMetadataInput,
+    BrokerSamlResponseInput, BrokerService, BrokeredProfile, BrokeredUserInfo, ClientAuthMethod,
+    ConfirmBrokerLinkInput, CreateBrokerAuthSessionRequest, CreateIdentityProviderLinkRequest,
+    FirstBrokerLoginFlow, IdentityProviderLink, IdentityProviderLinkRepository,
+    LinkConfirmationMethod, OAuthClient, OAuthProviderConfig, OAuthTokenResponse,
+    PendingBrokerLogin, PendingBrokerLoginInput, PendingBrokerLoginRepository,
+    PendingBrokerLoginStage, PendingBrokerLoginView, PostBrokerLoginFlow, ProfileField,
+    ReviewBrokeredProfileInput, TokenEndpointAuth, VerifyBrokerLoginOtpInput,
+};
+use crate::domain::abyss::identity_provider::mappers::{
+    IdentityProviderMapper, IdentityProviderMapperRepository,
 };
 use crate::domain::abyss::identity_provider::{IdentityProvider, IdentityProviderRepository};
+use crate::domain::abyss::jwks_cache::{JwksCache, JwksLookup};
+use crate::domain::abyss::saml_broker::{
+    self, ExpectedResponse, SAML_PROVIDER_ID, SamlProviderConfig, signatures_to_verify,
+    user_info_from_assertion, validate_response,
+};
 use crate::domain::authentication::entities::{AuthSession, AuthSessionParams};
 use crate::domain::authentication::ports::AuthSessionRepository;
+use crate::domain::authentication::saml::metadata::{ServiceProviderDescriptor, sp_metadata};
+use crate::domain::authentication::saml::ports::SamlKeyStore;
+use crate::domain::authentication::saml::protocol::{
+    self as saml_protocol, AuthnRequestParams, ReceivedResponse,
+};
+use crate::domain::authentication::saml::signature::{self, ReceivedSignature};
+use crate::domain::authentication::saml::xml::{self, invalid};
 use crate::domain::authentication::value_objects::CodeChallengeMethod;
 use crate::domain::client::ports::{ClientRepository, RedirectUriRepository};
 use crate::domain::client::redirect_uri_matching::redirect_uri_matches_any;
+use crate::domain::common::email::EmailPort;
 use crate::domain::common::entities::app_errors::CoreError;
-use crate::domain::realm::entities::RealmId;
-use crate::domain::realm::ports::RealmRepository;
+use crate::domain::common::security_notification::{
+    SecurityNotice, SecurityNotificationKind, SecurityNotifier,
+};
+use crate::domain::credential::ports::CredentialRepository;
+use crate::domain::crypto::HasherRepository;
+use crate::domain::organization::ports::{GroupMemberRepository, OrganizationMemberRepository};
+use crate::domain::realm::entities::{Realm, RealmId};
+use crate::domain::realm::ports::{RealmRepository, SmtpConfigRepository};
 use crate::domain::user::entities::User;
-use crate::domain::user::ports::UserRepository;
+use crate::domain::user::ports::{UserAttributeRepository, UserRepository, UserRoleRepository};
 use crate::domain::user::value_objects::CreateUserRequest;
 
+const CLIENT_ASSERTION_LIFETIME_SECS: i64 = 60;
+
 const ID_TOKEN_ALGORITHMS: &[jsonwebtoken::Algorithm] = &[
     jsonwebtoken::Algorithm::RS256,
     jsonwebtoken::Algorithm::RS384,
@@ -43,8 +85,30 @@ const ID_TOKEN_ALGORITHMS: &[jsonwebtoken::Algorithm] = &[
 
 /// Implementation of the BrokerService trait
 #[derive(Clone, Debug)]
-pub struct BrokerServiceImpl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC>
-where
+pub struct BrokerServiceImpl<
+    RR,
+    IR,
+    BR,
+    LR,
+    CR,
+    RUR,
+    UR,
+    ASR,
+    OC,
+    MR,
+    URR,
+    UAR,
+    OM,
+    GM,
+    KS,
+    PR,
+    CRR,
+    H,
+    SC,
+    EP,
+    SK,
+    SN,
+> where
     RR: RealmRepository,
     IR: IdentityProviderRepository,
     BR: BrokerAuthSessionRepository,
@@ -54,6 +118,19 @@ where
     UR: UserRepository,
     ASR: AuthSessionRepository,
     OC: OAuthClient,
+    MR: IdentityProviderMapperRepository,
+    URR: UserRoleRepository,
+    UAR: UserAttributeRepository,
+    OM: OrganizationMemberRepository,
+    GM: GroupMemberRepository,
+    KS: KeyStoreRepository,
+    PR: PendingBrokerLoginRepository,
+    CRR: CredentialRepository,
+    H: HasherRepository,
+    SC: SmtpConfigRepository,
+    EP: EmailPort,
+    SK: SamlKeyStore,
+    SN: SecurityNotifier,
 {
     realm_repository: Arc<RR>,
     identity_provider_repository: Arc<IR>,
@@ -65,6 +142,47 @@ where
     auth_session_repository: Arc<ASR>,
     oauth_client: Arc<OC>,
     flow_recorder: FlowRecorder,
+    mapping: BrokeredUserMapping<MR, UR, URR, UAR, OM, GM>,
+    keystore_repository: Arc<KS>,
+    jwks_cache: Arc<JwksCache>,
+    pending_login_repository: Arc<PR>,
+    verification: BrokerLoginVerification<CRR, H, SC, EP>,
+    saml_key_store: Arc<SK>,
+    security_notifier: Arc<SN>,
+}
+
+/// A brokered login between the provider's callback and the authorization code it ends with.
+struct BrokeredLogin {
+    realm: Realm,
+    idp: IdentityProvider,
+    broker_session: BrokerAuthSession,
+    client_id: String,
+    user_info: BrokeredUserInfo,
+    access_token: Option<String>,
+    flow_id: FlowId,
+}
+
+/// How the broker talks to an upstream identity provider.
+enum UpstreamProtocol {
+    OAuth(Box<OAuthProviderConfig>),
+    Saml(Box<SamlProviderConfig>),
+}
+
+impl UpstreamProtocol {
+    fn of(idp: &IdentityProvider) -> Result<Self, CoreError> {
+        if idp.provider_id == SAML_PROVIDER_ID {
+            Ok(Self::Saml(Box::new(idp.config.clone().try_into()?)))
+        } else {
+            Ok(Self::OAuth(Box::new(idp.config.clone().try_into()?)))
+        }
+    }
+
+    fn required_profile_fields(&self) -> &[ProfileField] {
+        match self {
+            Self::OAuth(config) => &config.required_profile_fields,
+            Self::Saml(config) => &config.required_profile_fields,
+        }
+    }
 }
 
 fn evaluate_redirect_uri(allowed: &[String], redirect_uri: &str) -> Result<(), CoreError> {
@@ -79,7 +197,31 @@ fn evaluate_redirect_uri(allowed: &[String], redirect_uri: &str) -> Result<(), C
     }
 }
 
-impl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC> BrokerServiceImpl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC>
+impl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC, MR, URR, UAR, OM, GM, KS, PR, CRR, H, SC, EP, SK, SN>
+    BrokerServiceImpl<
+        RR,
+        IR,
+        BR,
+        LR,
+        CR,
+        RUR,
+        UR,
+        ASR,
+        OC,
+        MR,
+        URR,
+        UAR,
+        OM,
+        GM,
+        KS,
+        PR,
+        CRR,
+        H,
+        SC,
+        EP,
+        SK,
+        SN,
+    >
 where
     RR: RealmRepository,
     IR: IdentityProviderRepository,
@@ -90,6 +232,19 @@ where
     UR: UserRepository,
     ASR: AuthSessionRepository,
     OC: OAuthClient,
+    MR: IdentityProviderMapperRepository,
+    URR: UserRoleRepository,
+    UAR: UserAttributeRepository,
+    OM: OrganizationMemberRepository,
+    GM: GroupMemberRepository,
+    KS: KeyStoreRepository,
+    PR: PendingBrokerLoginRepository,
+    CRR: CredentialRepository,
+    H: HasherRepository,
+    SC: SmtpConfigRepository,
+    EP: EmailPort,
+    SK: SamlKeyStore,
+    SN: SecurityNotifier,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -103,6 +258,12 @@ where
         auth_session_repository: Arc<ASR>,
         oauth_client: Arc<OC>,
         flow_recorder: FlowRecorder,
+        mapping: BrokeredUserMapping<MR, UR, URR, UAR, OM, GM>,
+        keystore_repository: Arc<KS>,
+        pending_login_repository: Arc<PR>,
+        verification: BrokerLoginVerification<CRR, H, SC, EP>,
+        saml_key_store: Arc<SK>,
+        security_notifier: Arc<SN>,
     ) -> Self {
         Self {
             realm_repository,
@@ -115,6 +276,13 @@ where
             auth_session_repository,
             oauth_client,
             flow_recorder,
+            mapping,
+            keystore_repository,
+            jwks_cache: Arc::new(JwksCache::new()),
+            pending_login_repository,
+            verification,
+            saml_key_store,
+            security_notifier,
         }
     }
 
@@ -207,89 +375,568 @@ where
         url
     }
 
-    /// Finds or creates a user based on the brokered user info
-    async fn find_or_create_user(
+    /// Builds the HTTP-Redirect URL carrying an `AuthnRequest` to a SAML IdP
+    async fn build_authn_request_url(
         &self,
         realm_id: RealmId,
-        idp: &IdentityProvider,
-        user_info: &BrokeredUserInfo,
-        access_token: Option<&str>,
-    ) -> Result<(User, bool), CoreError> {
-        // 1. Check if user is already linked to this IdP
+        config: &SamlProviderConfig,
+        sp_entity_id: &str,
+        acs_url: &str,
+        request_id: &str,
+    ) -> Result<String, CoreError> {
+        let request = saml_protocol::authn_request(&AuthnRequestParams {
+            id: request_id,
+            issuer: sp_entity_id,
+            destination: &config.sso_url,
+            acs_url,
+            name_id_format: config.name_id_format,
+            force_authn: config.force_authn,
+            issue_instant: Utc::now(),
+        });
+        let message = xml::encode_redirect_message(&request)?;
+
+        let query = if config.sign_authn_requests {
+            let query = signature::redirect_query("SAMLRequest", &message, None);
+            let signature = self
+                .saml_key_store
+                .sign(realm_id, query.clone().into_bytes())
+                .await?;
+            format!(
+                "{query}&Signature={}",
+                urlencoding::encode(&STANDARD.encode(signature))
+            )
+        } else {
+            format!("SAMLRequest={}", urlencoding::encode(&message))
+        };
+
+        let separator = if config.sso_url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        Ok(format!("{}{}{}", config.sso_url, separator, query))
+    }
+
+    /// Checks a SAML response against the pending request and the provider's certificates,
+    /// then reads the user out of its assertion.
+    async fn accept_saml_response(
+        &self,
+        config: &SamlProviderConfig,
+        response: &ReceivedResponse,
+        expected: &ExpectedResponse<'_>,
+    ) -> Result<BrokeredUserInfo, CoreError> {
+        let assertion = validate_response(config, response, expected)?;
+
+        for received in signatures_to_verify(config, response, assertion)? {
+            self.verify_saml_signature(config, received).await?;
+        }
+
+        user_info_from_assertion(config, assertion)
+    }
+
+    /// Any of the provider's signing certificates may have produced the signature, so a
+    /// key rollover does not break logins.
+    async fn verify_saml_signature(
+        &self,
+        config: &SamlProviderConfig,
+        received: &ReceivedSignature,
+    ) -> Result<(), CoreError> {
+        for certificate in &config.signing_certificates {
+            let valid = self
+                .saml_key_store
+                .verify(
+                    certificate.clone(),
+                    received.algorithm,
+                    received.signed_info.clone(),
+                    received.signature.clone(),
+                )
+                .await?;
+            if valid {
+                return Ok(());
+            }
+        }
+
+        Err(invalid("response signature is invalid"))
+    }
+
+    /// Continues a login once the provider identified the user: an already linked account
+    /// signs in, anything else goes through the first-login flow.
+    async fn continue_login(&self, login: BrokeredLogin) -> Result<BrokerLoginOutcome, CoreError> {
+        let mappers = self.mapping.mappers_for(&login.idp).await?;
+
         if let Some(link) = self
             .link_repository
-            .get_by_provider_and_external_id(idp.id, &user_info.subject)
+            .get_by_provider_and_external_id(login.idp.id, &login.user_info.subject)
             .await?
         {
-            // get_by_id returns Result<User>, not Result<Option<User>>
-            // If user doesn't exist, it returns an error
             let user = self
                 .user_repository
                 .get_by_id(link.user_id)
                 .await
                 .map_err(|_| CoreError::UserNotFound)?;
 
-            // Update token if store_token is enabled
-            if idp.store_token
-                && let Some(token) = access_token
-            {
+            if let Some(token) = &login.access_token {
                 self.link_repository
-                    .update_token(link.id, Some(token.to_string()))
+                    .update_token(link.id, Some(token.clone()))
                     .await?;
             }
 
-            return Ok((user, false));
+            return self
+                .complete_login(login, None, user, false, &mappers, false)
+                .await;
         }
 
-        // 2. If link_only mode, try to find user by email
-        if idp.link_only {
-            if let Some(email) = &user_info.email
-                && user_info.email_verified.unwrap_or(false)
-                && let Some(user) = self.user_repository.get_by_email(email, realm_id).await?
-            {
-                // Link existing user
-                self.create_idp_link(&user, idp, user_info, access_token)
-                    .await?;
-                return Ok((user, false));
-            }
-            // link_only mode and no matching user found
+        let flow =
+            FirstBrokerLoginFlow::from_alias(login.idp.first_broker_login_flow_alias.as_deref())?;
+
+        // Whatever the provider says about the email, only the owner of the existing account
+        // may link it.
+        if let Some(user) = self.account_with_email(&login).await? {
+            return self
+                .suspend(
+                    login,
+                    None,
+                    PendingBrokerLoginStage::ConfirmLink,
+                    Some(user.id),
+                    false,
+                )
+                .await;
+        }
+
+        if login.idp.link_only {
             return Err(CoreError::LinkOnlyUserNotFound);
         }
 
-        // 3. Try to find by email if trust_email is enabled
-        if idp.trust_email
-            && let Some(email) = &user_info.email
-            && user_info.email_verified.unwrap_or(false)
-            && let Some(user) = self.user_repository.get_by_email(email, realm_id).await?
+        let username = BrokeredUserMapping::<MR, UR, URR, UAR, OM, GM>::username_for(
+            &login.idp,
+            &mappers,
+            &login.user_info,
+        )?;
+
+        if flow == FirstBrokerLoginFlow::ReviewProfile
+            || self.needs_review(&login, &username).await?
         {
-            // Link existing user
-            self.create_idp_link(&user, idp, user_info, access_token)
-                .await?;
-            return Ok((user, false));
+            let mut login = login;
+            // The review page proposes the username the account would get.
+            login.user_info.preferred_username = Some(username);
+            return self
+                .suspend(
+                    login,
+                    None,
+                    PendingBrokerLoginStage::ReviewProfile,
+                    None,
+                    true,
+                )
+                .await;
+        }
+
+        self.create_account(login, None, username, &mappers).await
+    }
+
+    async fn account_with_email(&self, login: &BrokeredLogin) -> Result<Option<User>, CoreError> {
+        match &login.user_info.email {
+            Some(email) => {
+                self.user_repository
+                    .get_by_email(email, login.realm.id)
+                    .await
+            }
+            None => Ok(None),
         }
+    }
 
-        // 4. Create new user
-        let username = user_info.get_username(&idp.alias);
+    fn required_fields(idp: &IdentityProvider) -> Result<Vec<ProfileField>, CoreError> {
+        Ok(UpstreamProtocol::of(idp)?
+            .required_profile_fields()
+            .to_vec())
+    }
+
+    async fn username_taken(&self, realm_id: RealmId, username: &str) -> bool {
+        self.user_repository
+            .get_by_username(username.to_string(), realm_id)
+            .await
+            .is_ok()
+    }
+
+    /// Whether a required profile field is missing or the username belongs to someone else.
+    async fn needs_review(&self, login: &BrokeredLogin, username: &str) -> Result<bool, CoreError> {
+        let required = Self::required_fields(&login.idp)?;
+        let mut profile = BrokeredProfile::from(&login.user_info);
+        profile.username = Some(username.to_string());
+
+        Ok(!profile.missing_fields(&required).is_empty()
+            || self.username_taken(login.realm.id, username).await)
+    }
+
+    async fn create_account(
+        &self,
+        login: BrokeredLogin,
+        pending: Option<&PendingBrokerLogin>,
+        username: String,
+        mappers: &[IdentityProviderMapper],
+    ) -> Result<BrokerLoginOutcome, CoreError> {
+        // A new account has no one-time password the post-login flow could ask for.
+        if PostBrokerLoginFlow::from_alias(login.idp.post_broker_login_flow_alias.as_deref())?
+            == PostBrokerLoginFlow::RequireOtp
+        {
+            return Err(CoreError::BrokerLoginOtpNotConfigured);
+        }
 
         let user = self
             .user_repository
             .create_user(CreateUserRequest {
-                realm_id,
+                realm_id: login.realm.id,
                 client_id: None,
                 username,
-                firstname: user_info.given_name.clone(),
-                lastname: user_info.family_name.clone(),
-                email: user_info.email.clone(),
-                email_verified: user_info.email_verified.unwrap_or(false) && idp.trust_email,
+                firstname: login.user_info.given_name.clone(),
+                lastname: login.user_info.family_name.clone(),
+                email: login.user_info.email.clone(),
+                email_verified: login.user_info.email_verified.unwrap_or(false)
+                    && login.idp.trust_email,
                 enabled: true,
             })
             .await?;
 
-        // 5. Create IdP link
-        self.create_idp_link(&user, idp, user_info, access_token)
+        self.create_idp_link(
+            &user,
+            &login.idp,
+            &login.user_info,
+            login.access_token.as_deref(),
+        )
+        .await?;
+
+        self.complete_login(login, pending, user, true, mappers, false)
+            .await
+    }
+
+    /// Runs the post-login flow, then issues the authorization code.
+    async fn complete_login(
+        &self,
+        login: BrokeredLogin,
+        pending: Option<&PendingBrokerLogin>,
+        user: User,
+        is_new_user: bool,
+        mappers: &[IdentityProviderMapper],
+        otp_verified: bool,
+    ) -> Result<BrokerLoginOutcome, CoreError> {
+        if !user.enabled {
+            return Err(CoreError::UserDisabled);
+        }
+
+        if !otp_verified
+            && PostBrokerLoginFlow::from_alias(login.idp.post_broker_login_flow_alias.as_deref())?
+                == PostBrokerLoginFlow::RequireOtp
+        {
+            if !self.verification.has_otp(user.id).await? {
+                return Err(CoreError::BrokerLoginOtpNotConfigured);
+            }
+
+            return self
+                .suspend(
+                    login,
+                    pending,
+                    PendingBrokerLoginStage::Otp,
+                    Some(user.id),
+                    is_new_user,
+                )
+                .await;
+        }
+
+        self.mapping
+            .apply(&user, mappers, &login.user_info, is_new_user)
             .await?;
 
-        Ok((user, true))
+        let output = self
+            .issue_authorization_code(login, &user, is_new_user)
+            .await?;
+        Ok(BrokerLoginOutcome::Completed(output))
+    }
+
+    async fn issue_authorization_code(
+        &self,
+        login: BrokeredLogin,
+        user: &User,
+        is_new_user: bool,
+    ) -> Result<BrokerCallbackOutput, CoreError> {
+        let BrokeredLogin {
+            realm,
+            broker_session,
+            client_id,
+            flow_id,
+            ..
+        } = login;
+
+        // Set compass_flow_id so authorization_code() records TokenExchange + complete_flow
+        let authorization_code = Self::generate_random_string(32);
+
+        if let Some(auth_session_id) = broker_session.auth_session_id {
+            self.auth_session_repository
+                .update_user_id(auth_session_id, user.id)
+                .await?;
+            self.auth_session_repository
+                .update_code(auth_session_id, authorization_code.clone())
+                .await?;
+            self.auth_session_repository
+                .update_compass_flow_id(auth_session_id, flow_id.0)
+                .await?;
+        } else {
+            let challenge_method = broker_session
+                .code_challenge_method
+                .as_deref()
+                .map(|method| {
+                    method.parse::<CodeChallengeMethod>().map_err(|_| {
+                        warn!("broker session carries an unusable code_challenge_method");
+                        CoreError::InvalidRequest
+                    })
+                })
+                .transpose()?;
+
+            let auth_session = AuthSession::new(AuthSessionParams {
+                realm_id: realm.id,
+                client_id: broker_session.client_id,
+                redirect_uri: broker_session.redirect_uri.clone(),
+                response_type: broker_session.response_type.clone(),
+                scope: broker_session.scope.clone(),
+                state: broker_session.state.clone(),
+                nonce: broker_session.nonce.clone(),
+                user_id: Some(user.id),
+                code: Some(authorization_code.clone()),
+                authenticated: false,
+                webauthn_challenge: None,
+                webauthn_challenge_issued_at: None,
+                compass_flow_id: Some(flow_id.0),
+                code_challenge: broker_session.code_challenge.clone(),
+                code_challenge_method: challenge_method,
+            });
+            self.auth_session_repository.create(&auth_session).await?;
+        }
+
+        // Clean up the broker session, and with it any pending login
+        self.broker_session_repository
+            .delete(broker_session.id)
+            .await?;
+
+        let mut redirect_url = broker_session.redirect_uri.clone();
+        redirect_url.push_str(&format!(
+            "?code={}",
+            urlencoding::encode(&authorization_code)
+        ));
+        if let Some(state) = &broker_session.state {
+            redirect_url.push_str(&format!("&state={}", urlencoding::encode(state)));
+        }
+
+        Ok(BrokerCallbackOutput {
+            redirect_url,
+            authorization_code,
+            user_id: user.id,
+            is_new_user,
+            client_id,
+        })
+    }
+
+    /// Parks the login until the user acts; a login that was already pending keeps its token.
+    async fn suspend(
+        &self,
+        login: BrokeredLogin,
+        pending: Option<&PendingBrokerLogin>,
+        stage: PendingBrokerLoginStage,
+        user_id: Option<Uuid>,
+        is_new_user: bool,
+    ) -> Result<BrokerLoginOutcome, CoreError> {
+        let pending = match pending {
+            Some(pending) => {
+                self.pending_login_repository
+                    .update(
+                        pending.id,
+                        UpdatePendingBrokerLoginRequest {
+                            stage,
+                            brokered_user: login.user_info,
+                            user_id,
+                            is_new_user,
+                        },
+                    )
+                    .await?
+            }
+            None => {
+                self.pending_login_repository
+                    .create(CreatePendingBrokerLoginRequest {
+                        realm_id: login.realm.id,
+                        identity_provider_id: login.idp.id,
+                        broker_session_id: login.broker_session.id,
+                        token: Self::generate_random_string(32),
+                        stage,
+                        brokered_user: login.user_info,
+                        idp_access_token: login.access_token,
+                        user_id,
+                        is_new_user,
+                        compass_flow_id: Some(login.flow_id.0),
+                    })
+                    .await?
+            }
+        };
+
+        let view = self.pending_view(&login.idp, &pending).await?;
+
+        Ok(BrokerLoginOutcome::Pending {
+            token: pending.token,
+            login: Box::new(view),
+        })
+    }
+
+    async fn pending_view(
+        &self,
+        idp: &IdentityProvider,
+        pending: &PendingBrokerLogin,
+    ) -> Result<PendingBrokerLoginView, CoreError> {
+        let required_fields = Self::required_fields(idp)?;
+        let profile = BrokeredProfile::from(&pending.brokered_user);
+
+        let (missing_fields, username_taken) = match pending.stage {
+            PendingBrokerLoginStage::ReviewProfile => {
+                let username_taken = match profile.value(ProfileField::Username) {
+                    Some(username) => self.username_taken(pending.realm_id, username).await,
+                    None => false,
+                };
+                (profile.missing_fields(&required_fields), username_taken)
+            }
+            _ => (Vec::new(), false),
+        };
+
+        let (existing_account, confirmation_methods) = match pending.stage {
+            PendingBrokerLoginStage::ConfirmLink => {
+                let user = self.pending_user(pending).await?;
+
+                let mut methods = Vec::new();
+                if self.verification.has_password(user.id).await {
+                    methods.push(LinkConfirmationMethod::Password);
+                }
+                if self.verification.can_email(pending.realm_id, &user).await? {
+                    methods.push(LinkConfirmationMethod::EmailCode);
+                }
+
+                let hint = ExistingAccountHint {
+                    username: user.username,
+                    email: user.email.as_deref().map(mask_email),
+                };
+                (Some(hint), methods)
+            }
+            _ => (None, Vec::new()),
+        };
+
+        Ok(PendingBrokerLoginView {
+            stage: pending.stage,
+            identity_provider_alias: idp.alias.clone(),
+            identity_provider_display_name: idp.display_name.clone(),
+            profile,
+            required_fields,
+            missing_fields,
+            username_taken,
+            existing_account,
+            confirmation_methods,
+            expires_at: pending.expires_at,
+        })
+    }
+
+    async fn load_pending(
+        &self,
+        realm_name: &str,
+        token: &str,
+    ) -> Result<(Realm, PendingBrokerLogin), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let pending = self
+            .pending_login_repository
+            .get_by_token(token)
+            .await?
+            .filter(|pending| pending.realm_id == realm.id)
+            .ok_or(CoreError::BrokerSessionNotFound)?;
+
+        if pending.is_expired(Utc::now()) {
+            self.broker_session_repository
+                .delete(pending.broker_session_id)
+                .await?;
+            return Err(CoreError::BrokerSessionExpired);
+        }
+
+        Ok((realm, pending))
+    }
+
+    /// Rebuilds the login a pending step belongs to.
+    async fn resume(
+        &self,
+        realm: Realm,
+        pending: &PendingBrokerLogin,
+        stage: PendingBrokerLoginStage,
+    ) -> Result<BrokeredLogin, CoreError> {
+        if pending.stage != stage {
+            return Err(CoreError::InvalidRequest);
+        }
+
+        let idp = self
+            .identity_provider_repository
+            .get_identity_provider_by_id(pending.identity_provider_id.into())
+            .await?
+            .ok_or(CoreError::ProviderNotFound)?;
+
+        if !idp.enabled {
+            return Err(CoreError::ProviderDisabled);
+        }
+
+        let broker_session = self
+            .broker_session_repository
+            .get_by_id(pending.broker_session_id)
+            .await?
+            .ok_or(CoreError::BrokerSessionNotFound)?;
+
+        let client = self
+            .client_repository
+            .get_by_id(realm.id, broker_session.client_id)
+            .await?;
+
+        Ok(BrokeredLogin {
+            realm,
+            idp,
+            broker_session,
+            client_id: client.client_id,
+            user_info: pending.brokered_user.clone(),
+            access_token: pending.idp_access_token.clone(),
+            flow_id: pending.compass_flow_id.map(FlowId).unwrap_or_default(),
+        })
+    }
+
+    async fn pending_user(&self, pending: &PendingBrokerLogin) -> Result<User, CoreError> {
+        let user_id = pending.user_id.ok_or(CoreError::InternalServerError)?;
+
+        self.user_repository
+            .get_by_id(user_id)
+            .await
+            .map_err(|_| CoreError::UserNotFound)
+    }
+
+    /// Counts a wrong answer; too many of them end the login, which then has to start over.
+    async fn reject_attempt(&self, pending: &PendingBrokerLogin, error: CoreError) -> CoreError {
+        match self
+            .pending_login_repository
+            .record_failed_attempt(pending.id)
+            .await
+        {
+            Ok(attempts) if attempts >= MAX_CONFIRMATION_ATTEMPTS => {
+                warn!(login.id = %pending.id, "too many failed attempts, dropping the pending broker login");
+                match self
+                    .broker_session_repository
+                    .delete(pending.broker_session_id)
+                    .await
+                {
+                    Ok(()) => CoreError::BrokerSessionExpired,
+                    Err(e) => e,
+                }
+            }
+            Ok(_) => error,
+            Err(e) => e,
+        }
     }
 
     /// Creates a link between a user and an identity provider
@@ -321,6 +968,61 @@ where
         self.link_repository.create(request).await
     }
 
+    /// How the broker authenticates to the provider's token endpoint.
+    ///
+    /// With `private_key_jwt` the client assertion is signed with the realm key, whose
+    /// public half the provider reads from the realm's JWKS endpoint.
+    async fn token_endpoint_auth(
+        &self,
+        realm_id: RealmId,
+        config: &OAuthProviderConfig,
+    ) -> Result<TokenEndpointAuth, CoreError> {
+        let client_id = config.client_id.clone();
+
+        match config.client_auth_method {
+            ClientAuthMethod::ClientSecretPost => Ok(TokenEndpointAuth::ClientSecretPost {
+                client_id,
+                client_secret: config.client_secret.clone(),
+            }),
+            ClientAuthMethod::ClientSecretBasic => Ok(TokenEndpointAuth::ClientSecretBasic {
+                client_id,
+                client_secret: config.client_secret.clone(),
+            }),
+            ClientAuthMethod::PrivateKeyJwt => {
+                let key_pair = self
+                    .keystore_repository
+                    .get_or_generate_key(realm_id)
+                    .await
+                    .map_err(|_| CoreError::InternalServerError)?;
+
+                let now = Utc::now().timestamp();
+                let claims = serde_json::json!({
+                    "iss": client_id,
+                    "sub": client_id,
+                    "aud": config.token_url,
+                    "jti": Uuid::new_v4().to_string(),
+                    "iat": now,
+                    "exp": now + CLIENT_ASSERTION_LIFETIME_SECS,
+                });
+
+                let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+                header.kid = Some(key_pair.id.to_string());
+                let client_assertion =
+                    jsonwebtoken::encode(&header, &claims, &key_pair.encoding_key).map_err(
+                        |e| {
+                            error!("Failed to sign client assertion: {}", e);
+                            CoreError::TokenGenerationError(e.to_string())
+                        },
+                    )?;
+
+                Ok(TokenEndpointAuth::PrivateKeyJwt {
+                    client_id,
+                    client_assertion,
+                })
+            }
+        }
+    }
+
     async fn verify_id_token(
         &self,
         id_token: &str,
@@ -333,7 +1035,31 @@ where
             .ok_or(CoreError::InvalidIdToken)?;
         let issuer = config.issuer.as_deref().ok_or(CoreError::InvalidIdToken)?;
 
-        let jwks = self.oauth_client.fetch_jwks(jwks_url).await?;
+        let kid = jsonwebtoken::decode_header(id_token)
+            .ok()
+            .and_then(|header| header.kid);
+
+        let now = Utc::now();
+        let jwks = match self.jwks_cache.lookup(jwks_url, kid.as_deref(), now) {
+            JwksLookup::Cached(jwks) => jwks,
+            JwksLookup::Unavailable => return Err(CoreError::InvalidIdToken),
+            JwksLookup::Refetch(stale) => match self.oauth_client.fetch_jwks(jwks_url).await {
+                Ok(jwks) => {
+                    self.jwks_cache.store(jwks_url, jwks.clone(), now);
+                    jwks
+                }
+                Err(error) => {
+                    self.jwks_cache.record_failure(jwks_url, now);
+                    match stale {
+                        Some(jwks) => {
+                            warn!(jwks_url, "JWKS refetch failed, using the cached keys");
+                            jwks
+                        }
+                        None => return Err(error),
+                    }
+                }
+            },
+        };
 
         verify_id_token_against_jwks(id_token, &jwks, issuer, &config.client_id, expected_nonce)
     }
@@ -422,11 +1148,36 @@ fn user_info_from_claims(claims: serde_json::Value) -> Result<BrokeredUserInfo,
         family_name: claims["family_name"].as_str().map(|s| s.to_string()),
         preferred_username: claims["preferred_username"].as_str().map(|s| s.to_string()),
         picture: claims["picture"].as_str().map(|s| s.to_string()),
+        claims,
     })
 }
 
-impl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC> BrokerService
-    for BrokerServiceImpl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC>
+impl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC, MR, URR, UAR, OM, GM, KS, PR, CRR, H, SC, EP, SK, SN>
+    BrokerService
+    for BrokerServiceImpl<
+        RR,
+        IR,
+        BR,
+        LR,
+        CR,
+        RUR,
+        UR,
+        ASR,
+        OC,
+        MR,
+        URR,
+        UAR,
+        OM,
+        GM,
+        KS,
+        PR,
+        CRR,
+        H,
+        SC,
+        EP,
+        SK,
+        SN,
+    >
 where
     RR: RealmRepository,
     IR: IdentityProviderRepository,
@@ -437,6 +1188,19 @@ where
     UR: UserRepository,
     ASR: AuthSessionRepository,
     OC: OAuthClient,
+    MR: IdentityProviderMapperRepository,
+    URR: UserRoleRepository,
+    UAR: UserAttributeRepository,
+    OM: OrganizationMemberRepository,
+    GM: GroupMemberRepository,
+    KS: KeyStoreRepository,
+    PR: PendingBrokerLoginRepository,
+    CRR: CredentialRepository,
+    H: HasherRepository,
+    SC: SmtpConfigRepository,
+    EP: EmailPort,
+    SK: SamlKeyStore,
+    SN: SecurityNotifier,
 {
     #[instrument(
         skip(self, input),
@@ -493,22 +1257,27 @@ where
             return Err(CoreError::PkceRequired);
         }
 
-        // 4. Parse OAuth config from idp.config
-        let oauth_config: OAuthProviderConfig = idp.config.clone().try_into().map_err(|e| {
+        // 4. Parse the provider configuration from idp.config
+        let protocol = UpstreamProtocol::of(&idp).map_err(|e| {
             error!("error: {e}");
             e
         })?;
 
-        // 5. Generate secure random state for CSRF protection
-        let broker_state = Self::generate_random_string(32);
+        // 5. Generate secure random state for CSRF protection; SAML providers echo it back
+        // as the ID of the AuthnRequest
+        let broker_state = match &protocol {
+            UpstreamProtocol::OAuth(_) => Self::generate_random_string(32),
+            UpstreamProtocol::Saml(_) => saml_protocol::new_id(),
+        };
 
         // 6. Generate PKCE if enabled
-        let (code_verifier, code_challenge) = if oauth_config.use_pkce.unwrap_or(false) {
-            let verifier = Self::generate_pkce_verifier();
-            let challenge = Self::generate_pkce_challenge(&verifier);
-            (Some(verifier), Some(challenge))
-        } else {
-            (None, None)
+        let (code_verifier, code_challenge) = match &protocol {
+            UpstreamProtocol::OAuth(config) if config.use_pkce.unwrap_or(false) => {
+                let verifier = Self::generate_pkce_verifier();
+                let challenge = Self::generate_pkce_challenge(&verifier);
+                (Some(verifier), Some(challenge))
+            }
+            _ => (None, None),
         };
 
         // 7. Create broker session
@@ -536,13 +1305,25 @@ where
             input.base_url, input.realm_name, input.alias
         );
 
-        let authorization_url = self.build_authorization_url(
-            &oauth_config,
-            &callback_url,
-            &broker_state,
-            code_challenge.as_deref(),
-            input.nonce.as_deref(),
-        );
+        let authorization_url = match &protocol {
+            UpstreamProtocol::OAuth(config) => self.build_authorization_url(
+                config,
+                &callback_url,
+                &broker_state,
+                code_challenge.as_deref(),
+                input.nonce.as_deref(),
+            ),
+            UpstreamProtocol::Saml(config) => {
+                self.build_authn_request_url(
+                    realm.id,
+                    config,
+                    &config.sp_entity_id(&input.base_url, &input.realm_name, &input.alias),
+                    &callback_url,
+                    &broker_state,
+                )
+                .await?
+            }
+        };
 
         Ok(BrokerLoginOutput {
             authorization_url,
@@ -560,7 +1341,7 @@ where
     async fn handle_callback(
         &self,
         input: BrokerCallbackInput,
-    ) -> Result<BrokerCallbackOutput, CoreError> {
+    ) -> Result<BrokerLoginOutcome, CoreError> {
         // 1. Handle IdP errors - redirect to client with error
         if let Some(error) = &input.error {
             let broker_session = self
@@ -675,6 +1456,8 @@ where
             parts.join("&")
         };
 
+        let token_endpoint_auth = self.token_endpoint_auth(realm.id, &oauth_config).await?;
+
         let cred_start = Utc::now();
         let token_response = match self
             .oauth_client
@@ -682,8 +1465,7 @@ where
                 &oauth_config.token_url,
                 code,
                 &callback_url,
-                &oauth_config.client_id,
-                &oauth_config.client_secret,
+                &token_endpoint_auth,
                 broker_session.code_verifier.as_deref(),
             )
             .await
@@ -726,88 +1508,382 @@ where
             )
             .await?;
 
-        // 8. Find or create user
-        let (user, is_new_user) = self
-            .find_or_create_user(
+        // 8. Link or create the account; the first-login and post-login flows may stop here
+        let access_token = idp.store_token.then(|| token_response.access_token.clone());
+
+        self.continue_login(BrokeredLogin {
+            realm,
+            idp,
+            broker_session,
+            client_id: client.client_id,
+            user_info,
+            access_token,
+            flow_id,
+        })
+        .await
+    }
+
+    #[instrument(
+        skip(self, input),
+        fields(
+            realm.name = %input.realm_name,
+            provider.alias = %input.alias,
+        )
+    )]
+    async fn handle_saml_response(
+        &self,
+        input: BrokerSamlResponseInput,
+    ) -> Result<BrokerLoginOutcome, CoreError> {
+        let message = xml::decode_message(&input.saml_response, false)?;
+        let response = {
+            let doc = xml::parse(&message)?;
+            saml_protocol::parse_response(doc.root_element())?
+        };
+
+        // 1. The response answers the AuthnRequest of a broker session, and only once
+        let request_id = response
+            .in_response_to
+            .clone()
+            .ok_or_else(|| invalid("unsolicited responses are not accepted"))?;
+        let broker_session = self
+            .broker_session_repository
+            .consume_broker_state(&request_id)
+            .await?
+            .ok_or(CoreError::BrokerSessionNotFound)?;
+
+        if broker_session.is_expired() {
+            self.broker_session_repository
+                .delete(broker_session.id)
+                .await?;
+            return Err(CoreError::BrokerSessionExpired);
+        }
+
+        // 2. Resolve realm, IdP and client
+        let realm = self
+            .realm_repository
+            .get_by_id(broker_session.realm_id)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let idp = self
+            .identity_provider_repository
+            .get_identity_provider_by_id(broker_session.identity_provider_id.into())
+            .await?
+            .ok_or(CoreError::ProviderNotFound)?;
+
+        if realm.name != input.realm_name || idp.alias != input.alias {
+            return Err(CoreError::BrokerSessionNotFound);
+        }
+
+        let client = self
+            .client_repository
+            .get_by_id(broker_session.realm_id, broker_session.client_id)
+            .await?;
+
+        let UpstreamProtocol::Saml(config) = UpstreamProtocol::of(&idp)? else {
+            return Err(CoreError::InvalidProviderConfiguration(
+                "identity provider does not use SAML".to_string(),
+            ));
+        };
+
+        let flow_id = self
+            .flow_recorder
+            .start_flow(
                 realm.id,
-                &idp,
-                &user_info,
-                Some(&token_response.access_token),
+                Some(client.client_id.clone()),
+                format!("broker_{}", idp.alias),
+                None,
+                None,
             )
+            .await;
+
+        self.flow_recorder.record_step(
+            flow_id.clone(),
+            FlowStepName::IdpRedirect,
+            StepStatus::Success,
+            None,
+            None,
+            None,
+        );
+
+        // 3. Validate the response and its signatures
+        let acs_url = saml_broker::acs_url(&input.base_url, &realm.name, &idp.alias);
+        let sp_entity_id = config.sp_entity_id(&input.base_url, &realm.name, &idp.alias);
+        let expected = ExpectedResponse {
+            idp_entity_id: &config.idp_entity_id,
+            sp_entity_id: &sp_entity_id,
+            acs_url: &acs_url,
+            request_id: &request_id,
+            now: Utc::now(),
+        };
+        let idp_params = format!("InResponseTo={request_id}");
+
+        let started = Utc::now();
+        let user_info = match self
+            .accept_saml_response(&config, &response, &expected)
+            .await
+        {
+            Ok(user_info) => {
+                let duration = (Utc::now() - started).num_milliseconds();
+                self.flow_recorder.record_step(
+                    flow_id.clone(),
+                    FlowStepName::IdpCallback,
+                    StepStatus::Success,
+                    Some(duration),
+                    None,
+                    Some(idp_params),
+                );
+                user_info
+            }
+            Err(e) => {
+                let duration = (Utc::now() - started).num_milliseconds();
+                self.flow_recorder.record_step(
+                    flow_id.clone(),
+                    FlowStepName::IdpCallback,
+                    StepStatus::Failure,
+                    Some(duration),
+                    Some(format!("{:?}", e)),
+                    Some(idp_params),
+                );
+                self.flow_recorder
+                    .complete_flow(flow_id, FlowStatus::Failure, duration, None);
+                self.broker_session_repository
+                    .delete(broker_session.id)
+                    .await?;
+                return Err(e);
+            }
+        };
+
+        // 4. Link or create the account; the first-login and post-login flows may stop here
+        self.continue_login(BrokeredLogin {
+            realm,
+            idp,
+            broker_session,
+            client_id: client.client_id,
+            user_info,
+            access_token: None,
+            flow_id,
+        })
+        .await
+    }
+
+    #[instrument(
+        skip(self, input),
+        fields(
+            realm.name = %input.realm_name,
+            provider.alias = %input.alias,
+        )
+    )]
+    async fn get_saml_metadata(&self, input: BrokerSamlMetadataInput) -> Result<String, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&input.realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let idp = self
+            .identity_provider_repository
+            .get_identity_provider_by_realm_and_alias(realm.id, &input.alias)
+            .await?
+            .ok_or(CoreError::ProviderNotFound)?;
+
+        let UpstreamProtocol::Saml(config) = UpstreamProtocol::of(&idp)? else {
+            return Err(CoreError::ProviderNotFound);
+        };
+
+        let certificate = self.saml_key_store.signing_certificate(realm.id).await?;
+
+        Ok(sp_metadata(&ServiceProviderDescriptor {
+            entity_id: &config.sp_entity_id(&input.base_url, &realm.name, &idp.alias),
+            acs_url: &saml_broker::acs_url(&input.base_url, &realm.name, &idp.alias),
+            signing_certificate: &certificate,
+            authn_requests_signed: config.sign_authn_requests,
+            want_assertions_signed: config.want_assertions_signed,
+            name_id_format: config.name_id_format,
+        }))
+    }
+
+    #[instrument(skip(self, input), fields(realm.name = %input.realm_name))]
+    async fn get_pending_login(
+        &self,
+        input: PendingBrokerLoginInput,
+    ) -> Result<PendingBrokerLoginView, CoreError> {
+        let (_, pending) = self.load_pending(&input.realm_name, &input.token).await?;
+
+        let idp = self
+            .identity_provider_repository
+            .get_identity_provider_by_id(pending.identity_provider_id.into())
+            .await?
+            .ok_or(CoreError::ProviderNotFound)?;
+
+        self.pending_view(&idp, &pending).await
+    }
+
+    #[instrument(skip(self, input), fields(realm.name = %input.realm_name))]
+    async fn review_brokered_profile(
+        &self,
+        input: ReviewBrokeredProfileInput,
+    ) -> Result<BrokerLoginOutcome, CoreError> {
+        let (realm, pending) = self.load_pending(&input.realm_name, &input.token).await?;
+        let mut login = self
+            .resume(realm, &pending, PendingBrokerLoginStage::ReviewProfile)
             .await?;
 
-        if !user.enabled {
-            return Err(CoreError::UserDisabled);
-        }
+        input.profile.validate()?;
+        login.user_info.apply_review(input.profile);
 
-        // 9. Create or update auth session with authorization code
-        // Set compass_flow_id so authorization_code() records TokenExchange + complete_flow
-        let authorization_code = Self::generate_random_string(32);
+        // The reviewed email may be the one of an existing account
+        if let Some(user) = self.account_with_email(&login).await? {
+            return self
+                .suspend(
+                    login,
+                    Some(&pending),
+                    PendingBrokerLoginStage::ConfirmLink,
+                    Some(user.id),
+                    false,
+                )
+                .await;
+        }
 
-        if let Some(auth_session_id) = broker_session.auth_session_id {
-            self.auth_session_repository
-                .update_user_id(auth_session_id, user.id)
-                .await?;
-            self.auth_session_repository
-                .update_code(auth_session_id, authorization_code.clone())
-                .await?;
-            self.auth_session_repository
-                .update_compass_flow_id(auth_session_id, flow_id.0)
-                .await?;
-        } else {
-            let challenge_method = broker_session
-                .code_challenge_method
-                .as_deref()
-                .map(|method| {
-                    method.parse::<CodeChallengeMethod>().map_err(|_| {
-                        warn!("broker session carries an unusable code_challenge_method");
-                        CoreError::InvalidRequest
-                    })
-                })
-                .transpose()?;
+        let mappers = self.mapping.mappers_for(&login.idp).await?;
+        let username = match login.user_info.preferred_username.clone() {
+            Some(username) => username,
+            None => BrokeredUserMapping::<MR, UR, URR, UAR, OM, GM>::username_for(
+                &login.idp,
+                &mappers,
+                &login.user_info,
+            )?,
+        };
 
-            let auth_session = AuthSession::new(AuthSessionParams {
-                realm_id: realm.id,
-                client_id: broker_session.client_id,
-                redirect_uri: broker_session.redirect_uri.clone(),
-                response_type: broker_session.response_type.clone(),
-                scope: broker_session.scope.clone(),
-                state: broker_session.state.clone(),
-                nonce: broker_session.nonce.clone(),
-                user_id: Some(user.id),
-                code: Some(authorization_code.clone()),
-                authenticated: false,
-                webauthn_challenge: None,
-                webauthn_challenge_issued_at: None,
-                compass_flow_id: Some(flow_id.0),
-                code_challenge: broker_session.code_challenge.clone(),
-                code_challenge_method: challenge_method,
-            });
-            self.auth_session_repository.create(&auth_session).await?;
+        if self.needs_review(&login, &username).await? {
+            return self
+                .suspend(
+                    login,
+                    Some(&pending),
+                    PendingBrokerLoginStage::ReviewProfile,
+                    None,
+                    true,
+                )
+                .await;
         }
 
-        // 10. Clean up broker session
-        self.broker_session_repository
-            .delete(broker_session.id)
+        self.create_account(login, Some(&pending), username, &mappers)
+            .await
+    }
+
+    #[instrument(skip(self, input), fields(realm.name = %input.realm_name))]
+    async fn send_link_confirmation_code(
+        &self,
+        input: PendingBrokerLoginInput,
+    ) -> Result<(), CoreError> {
+        let (realm, pending) = self.load_pending(&input.realm_name, &input.token).await?;
+        let login = self
+            .resume(realm, &pending, PendingBrokerLoginStage::ConfirmLink)
             .await?;
+        let user = self.pending_user(&pending).await?;
 
-        // 11. Build redirect URL back to client
-        let mut redirect_url = broker_session.redirect_uri.clone();
-        redirect_url.push_str(&format!(
-            "?code={}",
-            urlencoding::encode(&authorization_code)
-        ));
-        if let Some(state) = &broker_session.state {
-            redirect_url.push_str(&format!("&state={}", urlencoding::encode(state)));
+        let provider_name = login
+            .idp
+            .display_name
+            .as_deref()
+            .unwrap_or(&login.idp.alias);
+        let (code_hash, expires_at) = self
+            .verification
+            .send_confirmation_code(login.realm.id, &user, provider_name)
+            .await?;
+
+        self.pending_login_repository
+            .set_confirmation_code(pending.id, code_hash, expires_at)
+            .await
+    }
+
+    #[instrument(skip(self, input), fields(realm.name = %input.realm_name))]
+    async fn confirm_link(
+        &self,
+        input: ConfirmBrokerLinkInput,
+    ) -> Result<BrokerLoginOutcome, CoreError> {
+        let (realm, pending) = self.load_pending(&input.realm_name, &input.token).await?;
+        let login = self
+            .resume(realm, &pending, PendingBrokerLoginStage::ConfirmLink)
+            .await?;
+        let user = self.pending_user(&pending).await?;
+
+        let confirmed = match (&input.password, &input.code) {
+            (Some(password), _) => self.verification.verify_password(user.id, password).await?,
+            (None, Some(code)) => confirmation_code_matches(
+                code,
+                pending.confirmation_code_hash.as_deref(),
+                pending.confirmation_code_expires_at,
+                Utc::now(),
+            ),
+            (None, None) => return Err(CoreError::InvalidRequest),
+        };
+
+        if !confirmed {
+            return Err(self
+                .reject_attempt(&pending, CoreError::BrokerLinkConfirmationFailed)
+                .await);
         }
 
-        Ok(BrokerCallbackOutput {
-            redirect_url,
-            authorization_code,
-            user_id: user.id,
-            is_new_user,
-            client_id: client.client_id,
-        })
+        self.create_idp_link(
+            &user,
+            &login.idp,
+            &login.user_info,
+            login.access_token.as_deref(),
+        )
+        .await?;
+
+        let identity_provider = login
+            .idp
+            .display_name
+            .clone()
+            .unwrap_or_else(|| login.idp.alias.clone());
+        self.security_notifier
+            .notify(
+                SecurityNotice::new(
+                    user.realm_id,
+                    user.id,
+                    SecurityNotificationKind::IdentityProviderLinked,
+                )
+                .with_detail("identity_provider", identity_provider),
+            )
+            .await;
+
+        let mappers = self.mapping.mappers_for(&login.idp).await?;
+        self.complete_login(login, Some(&pending), user, false, &mappers, false)
+            .await
+    }
+
+    #[instrument(skip(self, input), fields(realm.name = %input.realm_name))]
+    async fn verify_login_otp(
+        &self,
+        input: VerifyBrokerLoginOtpInput,
+    ) -> Result<BrokerLoginOutcome, CoreError> {
+        let (realm, pending) = self.load_pending(&input.realm_name, &input.token).await?;
+        let login = self
+            .resume(realm, &pending, PendingBrokerLoginStage::Otp)
+            .await?;
+        let user = self.pending_user(&pending).await?;
+
+        if !self.verification.verify_otp(user.id, &input.code).await? {
+            return Err(self
+                .reject_attempt(
+                    &pending,
+                    CoreError::TotpVerificationFailed("failed to verify OTP".to_string()),
+                )
+                .await);
+        }
+
+        let mappers = self.mapping.mappers_for(&login.idp).await?;
+        self.complete_login(
+            login,
+            Some(&pending),
+            user,
+            pending.is_new_user,
+            &mappers,
+            true,
+        )
+        .await
     }
 
     async fn extract_user_info(
@@ -7,6 +7,13 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use super::value_objects::{SyncError, SyncResult};
+use crate::domain::common::entities::app_errors::CoreError;
+
+/// `secret_data` of the password credential that marks a user as authenticating through
+/// their federation provider.
+pub const FEDERATED_CREDENTIAL_MARKER: &str = "federated";
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub enum FederationType {
     Ldap,
@@ -56,7 +63,7 @@ impl FromStr for SyncMode {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub enum SyncStatus {
     Success,
     Failure,
@@ -64,6 +71,170 @@ pub enum SyncStatus {
     InProgress,
 }
 
+impl fmt::Display for SyncStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyncStatus::Success => write!(f, "Success"),
+            SyncStatus::Failure => write!(f, "Failure"),
+            SyncStatus::PartialSuccess => write!(f, "PartialSuccess"),
+            SyncStatus::InProgress => write!(f, "InProgress"),
+        }
+    }
+}
+
+impl FromStr for SyncStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Success" => Ok(SyncStatus::Success),
+            "Failure" => Ok(SyncStatus::Failure),
+            "PartialSuccess" => Ok(SyncStatus::PartialSuccess),
+            "InProgress" => Ok(SyncStatus::InProgress),
+            _ => Err(format!("Invalid SyncStatus: {}", s)),
+        }
+    }
+}
+
+/// Full syncs read the whole directory; incremental syncs only read entries changed since the
+/// previous run's change marker and never disable users.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum SyncKind {
+    Full,
+    Incremental,
+}
+
+impl fmt::Display for SyncKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyncKind::Full => write!(f, "Full"),
+            SyncKind::Incremental => write!(f, "Incremental"),
+        }
+    }
+}
+
+impl FromStr for SyncKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Full" => Ok(SyncKind::Full),
+            "Incremental" => Ok(SyncKind::Incremental),
+            _ => Err(format!("Invalid SyncKind: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum SyncTrigger {
+    Manual,
+    Scheduled,
+}
+
+impl fmt::Display for SyncTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyncTrigger::Manual => write!(f, "Manual"),
+            SyncTrigger::Scheduled => write!(f, "Scheduled"),
+        }
+    }
+}
+
+impl FromStr for SyncTrigger {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Manual" => Ok(SyncTrigger::Manual),
+            "Scheduled" => Ok(SyncTrigger::Scheduled),
+            _ => Err(format!("Invalid SyncTrigger: {}", s)),
+        }
+    }
+}
+
+/// One execution of a provider sync, kept as history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FederationSyncRun {
+    pub id: Uuid,
+    pub provider_id: Uuid,
+    pub kind: SyncKind,
+    pub trigger: SyncTrigger,
+    pub mode: SyncMode,
+    pub status: SyncStatus,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub total_processed: u32,
+    pub created: u32,
+    pub updated: u32,
+    pub disabled: u32,
+    pub failed: u32,
+    pub groups_added: u32,
+    pub groups_removed: u32,
+    pub errors: Vec<SyncError>,
+    /// Highest `modifyTimestamp` / `uSNChanged` seen, where the next incremental sync resumes.
+    pub change_marker: Option<String>,
+}
+
+impl FederationSyncRun {
+    /// Only the first errors are kept so a broken directory doesn't produce megabyte rows.
+    pub const MAX_RECORDED_ERRORS: usize = 100;
+
+    pub fn start(provider_id: Uuid, kind: SyncKind, trigger: SyncTrigger, mode: SyncMode) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            provider_id,
+            kind,
+            trigger,
+            mode,
+            status: SyncStatus::InProgress,
+            started_at: Utc::now(),
+            completed_at: None,
+            total_processed: 0,
+            created: 0,
+            updated: 0,
+            disabled: 0,
+            failed: 0,
+            groups_added: 0,
+            groups_removed: 0,
+            errors: vec![],
+            change_marker: None,
+        }
+    }
+
+    pub fn complete(&mut self, result: &SyncResult, change_marker: Option<String>) {
+        self.status = if result.failed == 0 && result.errors.is_empty() {
+            SyncStatus::Success
+        } else {
+            SyncStatus::PartialSuccess
+        };
+        self.completed_at = Some(Utc::now());
+        self.total_processed = result.total_processed;
+        self.created = result.created;
+        self.updated = result.updated;
+        self.disabled = result.disabled;
+        self.failed = result.failed;
+        self.groups_added = result.groups_added;
+        self.groups_removed = result.groups_removed;
+        self.errors = result
+            .errors
+            .iter()
+            .take(Self::MAX_RECORDED_ERRORS)
+            .cloned()
+            .collect();
+        self.change_marker = change_marker;
+    }
+
+    pub fn fail(&mut self, error: &CoreError) {
+        self.status = SyncStatus::Failure;
+        self.completed_at = Some(Utc::now());
+        self.errors = vec![SyncError {
+            username: None,
+            external_id: self.provider_id.to_string(),
+            error: error.to_string(),
+        }];
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FederationProvider {
     pub id: Uuid,
@@ -78,6 +249,31 @@ pub struct FederationProvider {
     pub updated_at: DateTime<Utc>,
 }
 
+impl FederationProvider {
+    pub fn is_directory(&self) -> bool {
+        matches!(
+            self.provider_type,
+            FederationType::Ldap | FederationType::ActiveDirectory
+        )
+    }
+
+    /// Sync mode from `sync_settings`. Providers without one keep the directory authoritative.
+    pub fn sync_mode(&self) -> SyncMode {
+        self.sync_settings
+            .get("mode")
+            .and_then(|mode| serde_json::from_value(mode.clone()).ok())
+            .unwrap_or(SyncMode::Force)
+    }
+
+    /// Whether password changes are pushed back to the directory (`config.writable`).
+    pub fn is_writable(&self) -> bool {
+        self.config
+            .get("writable")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FederationMapping {
     pub id: Uuid,
@@ -92,12 +288,29 @@ pub struct FederationMapping {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FederatedUser {
     pub external_id: String,
+    /// Distinguished name of the directory entry, used to resolve group memberships.
+    #[serde(default)]
+    pub dn: String,
     pub username: String,
     pub email: Option<String>,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<HashMap<String, Vec<String>>>,
+    /// Value of the provider's change-tracking attribute (`modifyTimestamp` / `uSNChanged`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_marker: Option<String>,
+}
+
+/// A group entry read from the directory by a group mapper.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FederatedGroup {
+    pub dn: String,
+    pub name: String,
+    /// Values of the membership attribute (member DNs or uids, depending on the mapper).
+    pub members: Vec<String>,
+    /// Groups this group is itself a member of (`memberOf` on the group entry).
+    pub member_of: Vec<String>,
 }
 
 #[cfg(test)]
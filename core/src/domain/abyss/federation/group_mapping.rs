@@ -0,0 +1,463 @@
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::abyss::federation::entities::{
+    FederatedGroup, FederatedUser, FederationProvider,
+};
+use crate::domain::common::entities::app_errors::CoreError;
+
+/// How a mapper discovers which groups a user belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupMembershipSource {
+    /// Read the `memberOf` attribute on the user entry (Active Directory, OpenLDAP memberof overlay).
+    MemberOf,
+    /// Search the group base and match the user against each group's membership attribute.
+    GroupSearch,
+}
+
+/// What the values of the group membership attribute reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MembershipAttributeType {
+    /// `member` / `uniqueMember`: full DNs of the members.
+    Dn,
+    /// `memberUid` (posixGroup): plain usernames.
+    Uid,
+}
+
+/// Where directory groups are mirrored to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GroupMappingTarget {
+    RealmRole,
+    OrganizationGroup { organization_id: Uuid },
+    RealmGroup,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupMapperPreset {
+    ActiveDirectory,
+    OpenLdap,
+}
+
+/// A group mapper as stored in the provider config under `group_mappers`.
+///
+/// Every field left unset falls back to the preset (OpenLDAP when no preset is given), so an
+/// Active Directory mapper only needs `{ "preset": "active_directory", "target": {...} }`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LdapGroupMapperConfig {
+    pub name: String,
+    #[serde(default)]
+    pub preset: Option<GroupMapperPreset>,
+    #[serde(default)]
+    pub source: Option<GroupMembershipSource>,
+    /// Base DN of the group search. Defaults to the parent of the provider's user base DN, so
+    /// that `ou=groups` is found next to `ou=people`.
+    #[serde(default)]
+    pub group_search_base: Option<String>,
+    #[serde(default)]
+    pub group_filter: Option<String>,
+    #[serde(default)]
+    pub group_name_attribute: Option<String>,
+    #[serde(default)]
+    pub membership_attribute: Option<String>,
+    #[serde(default)]
+    pub membership_attribute_type: Option<MembershipAttributeType>,
+    #[serde(default)]
+    pub member_of_attribute: Option<String>,
+    /// Follow group-in-group membership so members of a child group also get its parents.
+    #[serde(default)]
+    pub nested: Option<bool>,
+    /// Create missing realm roles / organization groups / realm groups instead of skipping them.
+    #[serde(default = "default_true")]
+    pub create_missing: bool,
+    pub target: GroupMappingTarget,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// A group mapper with its preset applied: every setting is concrete.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupMapperSettings {
+    pub name: String,
+    pub source: GroupMembershipSource,
+    pub group_search_base: String,
+    pub group_filter: String,
+    pub group_name_attribute: String,
+    pub membership_attribute: String,
+    pub membership_attribute_type: MembershipAttributeType,
+    pub member_of_attribute: String,
+    pub nested: bool,
+    pub create_missing: bool,
+    pub target: GroupMappingTarget,
+}
+
+impl GroupMapperPreset {
+    fn source(self) -> GroupMembershipSource {
+        match self {
+            GroupMapperPreset::ActiveDirectory => GroupMembershipSource::MemberOf,
+            GroupMapperPreset::OpenLdap => GroupMembershipSource::GroupSearch,
+        }
+    }
+
+    fn group_filter(self) -> &'static str {
+        match self {
+            GroupMapperPreset::ActiveDirectory => "(objectClass=group)",
+            GroupMapperPreset::OpenLdap => "(objectClass=groupOfNames)",
+        }
+    }
+
+    fn nested(self) -> bool {
+        matches!(self, GroupMapperPreset::ActiveDirectory)
+    }
+}
+
+impl LdapGroupMapperConfig {
+    pub fn resolve(&self, user_base_dn: &str) -> GroupMapperSettings {
+        let preset = self.preset.unwrap_or(GroupMapperPreset::OpenLdap);
+
+        GroupMapperSettings {
+            name: self.name.clone(),
+            source: self.source.unwrap_or_else(|| preset.source()),
+            group_search_base: self
+                .group_search_base
+                .clone()
+                .unwrap_or_else(|| parent_dn(user_base_dn).to_string()),
+            group_filter: self
+                .group_filter
+                .clone()
+                .unwrap_or_else(|| preset.group_filter().to_string()),
+            group_name_attribute: self
+                .group_name_attribute
+                .clone()
+                .unwrap_or_else(|| "cn".to_string()),
+            membership_attribute: self
+                .membership_attribute
+                .clone()
+                .unwrap_or_else(|| "member".to_string()),
+            membership_attribute_type: self
+                .membership_attribute_type
+                .unwrap_or(MembershipAttributeType::Dn),
+            member_of_attribute: self
+                .member_of_attribute
+                .clone()
+                .unwrap_or_else(|| "memberOf".to_string()),
+            nested: self.nested.unwrap_or_else(|| preset.nested()),
+            create_missing: self.create_missing,
+            target: self.target.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct GroupMappersSection {
+    #[serde(default)]
+    group_mappers: Vec<LdapGroupMapperConfig>,
+    #[serde(default)]
+    search: Option<SearchSection>,
+}
+
+#[derive(Deserialize)]
+struct SearchSection {
+    base_dn: String,
+}
+
+/// Read the group mappers configured on a provider, with presets applied.
+pub fn group_mappers_for_provider(
+    provider: &FederationProvider,
+) -> Result<Vec<GroupMapperSettings>, CoreError> {
+    let section: GroupMappersSection = serde_json::from_value(provider.config.clone())
+        .map_err(|e| CoreError::Configuration(format!("Invalid group mapper config: {}", e)))?;
+
+    let base_dn = section.search.map(|s| s.base_dn).unwrap_or_default();
+
+    Ok(section
+        .group_mappers
+        .iter()
+        .map(|mapper| mapper.resolve(&base_dn))
+        .collect())
+}
+
+/// `dn` without its first RDN, or `dn` itself when it has a single one.
+fn parent_dn(dn: &str) -> &str {
+    let mut escaped = false;
+    for (index, c) in dn.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            ',' => return dn[index + 1..].trim_start(),
+            _ => {}
+        }
+    }
+
+    dn
+}
+
+fn normalize_dn(dn: &str) -> String {
+    dn.split(',')
+        .map(|rdn| rdn.trim().to_lowercase())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Names of the groups `user` belongs to according to `settings`, including the ancestors
+/// reached through nested membership when enabled.
+///
+/// Only groups returned by the group search are considered, so a `memberOf` value pointing
+/// outside the configured base is ignored.
+pub fn resolve_user_groups(
+    user: &FederatedUser,
+    groups: &[FederatedGroup],
+    settings: &GroupMapperSettings,
+) -> BTreeSet<String> {
+    let by_dn: HashMap<String, &FederatedGroup> =
+        groups.iter().map(|g| (normalize_dn(&g.dn), g)).collect();
+
+    let direct: Vec<String> = match settings.source {
+        GroupMembershipSource::MemberOf => user
+            .attributes
+            .as_ref()
+            .and_then(|attrs| attrs.get(&settings.member_of_attribute))
+            .map(|values| values.iter().map(|dn| normalize_dn(dn)).collect())
+            .unwrap_or_default(),
+        GroupMembershipSource::GroupSearch => {
+            let needle = match settings.membership_attribute_type {
+                MembershipAttributeType::Dn => normalize_dn(&user.dn),
+                MembershipAttributeType::Uid => user.username.to_lowercase(),
+            };
+            groups
+                .iter()
+                .filter(|g| {
+                    g.members
+                        .iter()
+                        .any(|m| match settings.membership_attribute_type {
+                            MembershipAttributeType::Dn => normalize_dn(m) == needle,
+                            MembershipAttributeType::Uid => m.to_lowercase() == needle,
+                        })
+                })
+                .map(|g| normalize_dn(&g.dn))
+                .collect()
+        }
+    };
+
+    let parents = if settings.nested {
+        parent_index(groups)
+    } else {
+        HashMap::new()
+    };
+
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut queue: VecDeque<String> = direct.into_iter().collect();
+    let mut names = BTreeSet::new();
+
+    while let Some(dn) = queue.pop_front() {
+        if !visited.insert(dn.clone()) {
+            continue;
+        }
+        let Some(group) = by_dn.get(&dn) else {
+            continue;
+        };
+        names.insert(group.name.clone());
+
+        if let Some(ancestors) = parents.get(&dn) {
+            queue.extend(ancestors.iter().cloned());
+        }
+    }
+
+    names
+}
+
+/// Child DN -> parent DNs, built from both directions the directory may record nesting in:
+/// the parent's membership attribute and the child's own `memberOf`.
+fn parent_index(groups: &[FederatedGroup]) -> HashMap<String, HashSet<String>> {
+    let mut parents: HashMap<String, HashSet<String>> = HashMap::new();
+
+    for group in groups {
+        let dn = normalize_dn(&group.dn);
+        for member in &group.members {
+            parents
+                .entry(normalize_dn(member))
+                .or_default()
+                .insert(dn.clone());
+        }
+        for parent in &group.member_of {
+            parents
+                .entry(dn.clone())
+                .or_default()
+                .insert(normalize_dn(parent));
+        }
+    }
+
+    parents
+}
+
+/// Memberships to add and remove so a user's mapped groups match the directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroupMembershipDiff {
+    pub to_add: BTreeSet<String>,
+    pub to_remove: BTreeSet<String>,
+}
+
+/// Diff the groups a user should have against what they hold locally.
+///
+/// `current` may contain roles or groups that have nothing to do with the directory; only
+/// those named after a directory group (`managed`) are ever removed.
+pub fn diff_memberships(
+    desired: &BTreeSet<String>,
+    current: &BTreeSet<String>,
+    managed: &BTreeSet<String>,
+) -> GroupMembershipDiff {
+    GroupMembershipDiff {
+        to_add: desired.difference(current).cloned().collect(),
+        to_remove: current
+            .iter()
+            .filter(|name| managed.contains(*name) && !desired.contains(*name))
+            .cloned()
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(dn: &str, name: &str, members: &[&str], member_of: &[&str]) -> FederatedGroup {
+        FederatedGroup {
+            dn: dn.to_string(),
+            name: name.to_string(),
+            members: members.iter().map(|s| s.to_string()).collect(),
+            member_of: member_of.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn user(dn: &str, username: &str, member_of: &[&str]) -> FederatedUser {
+        let mut attributes = HashMap::new();
+        attributes.insert(
+            "memberOf".to_string(),
+            member_of.iter().map(|s| s.to_string()).collect(),
+        );
+        FederatedUser {
+            external_id: dn.to_string(),
+            dn: dn.to_string(),
+            username: username.to_string(),
+            email: None,
+            first_name: None,
+            last_name: None,
+            attributes: Some(attributes),
+            change_marker: None,
+        }
+    }
+
+    fn mapper(preset: GroupMapperPreset) -> GroupMapperSettings {
+        LdapGroupMapperConfig {
+            name: "groups".to_string(),
+            preset: Some(preset),
+            source: None,
+            group_search_base: None,
+            group_filter: None,
+            group_name_attribute: None,
+            membership_attribute: None,
+            membership_attribute_type: None,
+            member_of_attribute: None,
+            nested: None,
+            create_missing: true,
+            target: GroupMappingTarget::RealmRole,
+        }
+        .resolve("ou=people,dc=example,dc=org")
+    }
+
+    #[test]
+    fn presets_fill_defaults() {
+        let ad = mapper(GroupMapperPreset::ActiveDirectory);
+        assert_eq!(ad.source, GroupMembershipSource::MemberOf);
+        assert_eq!(ad.group_filter, "(objectClass=group)");
+        assert!(ad.nested);
+
+        let openldap = mapper(GroupMapperPreset::OpenLdap);
+        assert_eq!(openldap.source, GroupMembershipSource::GroupSearch);
+        assert_eq!(openldap.group_search_base, "dc=example,dc=org");
+        assert!(!openldap.nested);
+    }
+
+    #[test]
+    fn group_search_base_defaults_to_the_parent_of_the_user_base() {
+        assert_eq!(
+            parent_dn("ou=people,dc=example,dc=org"),
+            "dc=example,dc=org"
+        );
+        assert_eq!(parent_dn("cn=Doe\\, John, dc=example"), "dc=example");
+        assert_eq!(parent_dn("dc=org"), "dc=org");
+    }
+
+    #[test]
+    fn member_of_with_nesting_includes_ancestors() {
+        let groups = vec![
+            group(
+                "CN=Devs,OU=Groups,DC=corp",
+                "Devs",
+                &[],
+                &["CN=Eng,OU=Groups,DC=corp"],
+            ),
+            group(
+                "CN=Eng,OU=Groups,DC=corp",
+                "Eng",
+                &[],
+                &["CN=Staff,OU=Groups,DC=corp"],
+            ),
+            group("CN=Staff,OU=Groups,DC=corp", "Staff", &[], &[]),
+        ];
+        let alice = user(
+            "CN=Alice,OU=Users,DC=corp",
+            "alice",
+            &["cn=devs, ou=groups, dc=corp"],
+        );
+
+        let names =
+            resolve_user_groups(&alice, &groups, &mapper(GroupMapperPreset::ActiveDirectory));
+
+        assert_eq!(
+            names,
+            BTreeSet::from(["Devs".to_string(), "Eng".to_string(), "Staff".to_string()])
+        );
+    }
+
+    #[test]
+    fn group_search_follows_member_attribute_and_survives_cycles() {
+        let groups = vec![
+            group("cn=a,dc=org", "a", &["uid=bob,dc=org"], &[]),
+            group("cn=b,dc=org", "b", &["cn=a,dc=org", "cn=c,dc=org"], &[]),
+            group("cn=c,dc=org", "c", &["cn=b,dc=org"], &[]),
+        ];
+        let bob = user("uid=bob,dc=org", "bob", &[]);
+
+        let mut settings = mapper(GroupMapperPreset::OpenLdap);
+        assert_eq!(
+            resolve_user_groups(&bob, &groups, &settings),
+            BTreeSet::from(["a".to_string()])
+        );
+
+        settings.nested = true;
+        assert_eq!(
+            resolve_user_groups(&bob, &groups, &settings),
+            BTreeSet::from(["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+    }
+
+    #[test]
+    fn diff_only_removes_managed_names() {
+        let desired = BTreeSet::from(["eng".to_string()]);
+        let current = BTreeSet::from(["ops".to_string(), "local-admin".to_string()]);
+        let managed = BTreeSet::from(["eng".to_string(), "ops".to_string()]);
+
+        let diff = diff_memberships(&desired, &current, &managed);
+
+        assert_eq!(diff.to_add, BTreeSet::from(["eng".to_string()]));
+        assert_eq!(diff.to_remove, BTreeSet::from(["ops".to_string()]));
+    }
+}
@@ -0,0 +1,670 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+use crate::domain::abyss::federation::entities::FederatedGroup;
+use crate::domain::abyss::federation::group_mapping::{
+    GroupMapperSettings, GroupMappingTarget, diff_memberships,
+};
+use crate::domain::abyss::federation::value_objects::SyncError;
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::organization::ports::{
+    CreateGroupParams, GroupId, GroupMemberRepository, GroupRepository, OrganizationId,
+};
+use crate::domain::realm::entities::RealmId;
+use crate::domain::realm_group::{
+    CreateRealmGroupParams, RealmGroupId, RealmGroupMemberRepository, RealmGroupRepository,
+};
+use crate::domain::role::ports::RoleRepository;
+use crate::domain::role::value_objects::CreateRoleRequest;
+use crate::domain::user::ports::UserRoleRepository;
+
+/// The groups one federated user should hold for a given mapper.
+#[derive(Debug, Clone)]
+pub struct GroupAssignment {
+    pub user_id: Uuid,
+    pub external_id: String,
+    pub username: String,
+    pub groups: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GroupSyncStats {
+    pub added: u32,
+    pub removed: u32,
+    pub errors: Vec<SyncError>,
+}
+
+/// Mirrors directory group memberships into realm roles, organization groups or realm groups.
+///
+/// Only roles and groups named after a directory group are touched, so memberships granted
+/// locally by an administrator survive a sync. Realm roles are further limited to the ones the
+/// mapper created and that carry no permissions, so a directory group named after an existing
+/// role, such as a realm `admin`, confers nothing.
+#[derive(Clone, Debug)]
+pub struct GroupMapperSync<RR, URR, G, GM, RG, RGM>
+where
+    RR: RoleRepository,
+    URR: UserRoleRepository,
+    G: GroupRepository,
+    GM: GroupMemberRepository,
+    RG: RealmGroupRepository,
+    RGM: RealmGroupMemberRepository,
+{
+    role_repository: Arc<RR>,
+    user_role_repository: Arc<URR>,
+    group_repository: Arc<G>,
+    group_member_repository: Arc<GM>,
+    realm_group_repository: Arc<RG>,
+    realm_group_member_repository: Arc<RGM>,
+}
+
+impl<RR, URR, G, GM, RG, RGM> GroupMapperSync<RR, URR, G, GM, RG, RGM>
+where
+    RR: RoleRepository,
+    URR: UserRoleRepository,
+    G: GroupRepository,
+    GM: GroupMemberRepository,
+    RG: RealmGroupRepository,
+    RGM: RealmGroupMemberRepository,
+{
+    pub fn new(
+        role_repository: Arc<RR>,
+        user_role_repository: Arc<URR>,
+        group_repository: Arc<G>,
+        group_member_repository: Arc<GM>,
+        realm_group_repository: Arc<RG>,
+        realm_group_member_repository: Arc<RGM>,
+    ) -> Self {
+        Self {
+            role_repository,
+            user_role_repository,
+            group_repository,
+            group_member_repository,
+            realm_group_repository,
+            realm_group_member_repository,
+        }
+    }
+
+    #[instrument(skip(self, mapper, directory_groups, assignments), fields(mapper = %mapper.name))]
+    pub async fn apply(
+        &self,
+        realm_id: RealmId,
+        mapper: &GroupMapperSettings,
+        directory_groups: &[FederatedGroup],
+        assignments: &[GroupAssignment],
+    ) -> Result<GroupSyncStats, CoreError> {
+        let managed: BTreeSet<String> = directory_groups.iter().map(|g| g.name.clone()).collect();
+
+        match &mapper.target {
+            GroupMappingTarget::RealmRole => {
+                self.apply_realm_roles(realm_id, mapper, &managed, assignments)
+                    .await
+            }
+            GroupMappingTarget::OrganizationGroup { organization_id } => {
+                self.apply_organization_groups(
+                    OrganizationId::new(*organization_id),
+                    mapper,
+                    &managed,
+                    assignments,
+                )
+                .await
+            }
+            GroupMappingTarget::RealmGroup => {
+                self.apply_realm_groups(realm_id, mapper, &managed, assignments)
+                    .await
+            }
+        }
+    }
+
+    async fn apply_realm_roles(
+        &self,
+        realm_id: RealmId,
+        mapper: &GroupMapperSettings,
+        managed: &BTreeSet<String>,
+        assignments: &[GroupAssignment],
+    ) -> Result<GroupSyncStats, CoreError> {
+        let description = synchronized_description(mapper);
+        let mut roles: BTreeMap<String, Uuid> = BTreeMap::new();
+        let mut foreign: BTreeSet<String> = BTreeSet::new();
+        for role in self
+            .role_repository
+            .find_by_realm_id(realm_id)
+            .await?
+            .into_iter()
+            .filter(|r| r.client_id.is_none())
+        {
+            if role.description.as_deref() == Some(description.as_str())
+                && role.permissions.is_empty()
+            {
+                roles.insert(role.name, role.id);
+            } else if managed.contains(&role.name) {
+                warn!(
+                    "Not synchronizing realm role '{}': it was not created by mapper '{}' or carries permissions",
+                    role.name, mapper.name
+                );
+                foreign.insert(role.name);
+            }
+        }
+
+        if mapper.create_missing {
+            let missing: Vec<&String> = managed
+                .iter()
+                .filter(|n| !roles.contains_key(*n) && !foreign.contains(*n))
+                .collect();
+            for name in missing {
+                info!("Creating realm role '{}' for LDAP group", name);
+                let role = self
+                    .role_repository
+                    .create(CreateRoleRequest {
+                        name: name.clone(),
+                        description: Some(description.clone()),
+                        permissions: vec![],
+                        realm_id,
+                        client_id: None,
+                    })
+                    .await?;
+                roles.insert(role.name, role.id);
+            }
+        }
+
+        let managed: BTreeSet<String> = managed
+            .iter()
+            .filter(|n| roles.contains_key(*n))
+            .cloned()
+            .collect();
+
+        let mut stats = GroupSyncStats::default();
+
+        for assignment in assignments {
+            let result: Result<(u32, u32), CoreError> = async {
+                let current: BTreeSet<String> = self
+                    .user_role_repository
+                    .get_direct_user_roles(assignment.user_id)
+                    .await?
+                    .into_iter()
+                    .filter(|r| r.client_id.is_none())
+                    .map(|r| r.name)
+                    .collect();
+
+                let desired = known(&assignment.groups, &roles);
+                let diff = diff_memberships(&desired, &current, &managed);
+
+                for name in &diff.to_add {
+                    self.user_role_repository
+                        .assign_role(assignment.user_id, roles[name])
+                        .await?;
+                }
+                for name in &diff.to_remove {
+                    if let Some(role_id) = roles.get(name) {
+                        self.user_role_repository
+                            .revoke_role(assignment.user_id, *role_id)
+                            .await?;
+                    }
+                }
+
+                Ok((diff.to_add.len() as u32, diff.to_remove.len() as u32))
+            }
+            .await;
+
+            stats.record(assignment, result);
+        }
+
+        Ok(stats)
+    }
+
+    async fn apply_organization_groups(
+        &self,
+        organization_id: OrganizationId,
+        mapper: &GroupMapperSettings,
+        managed: &BTreeSet<String>,
+        assignments: &[GroupAssignment],
+    ) -> Result<GroupSyncStats, CoreError> {
+        let mut groups: BTreeMap<String, GroupId> = BTreeMap::new();
+        for group in self
+            .group_repository
+            .list_groups_by_organization(organization_id)
+            .await?
+        {
+            groups.entry(group.name).or_insert(group.id);
+        }
+
+        if mapper.create_missing {
+            let missing: Vec<&String> = managed
+                .iter()
+                .filter(|n| !groups.contains_key(*n))
+                .collect();
+            for name in missing {
+                info!("Creating organization group '{}' for LDAP group", name);
+                let group = self
+                    .group_repository
+                    .create_group(CreateGroupParams {
+                        organization_id,
+                        parent_group_id: None,
+                        name: name.clone(),
+                        description: Some(synchronized_description(mapper)),
+                    })
+                    .await?;
+                groups.insert(group.name, group.id);
+            }
+        }
+
+        let mut stats = GroupSyncStats::default();
+
+        for assignment in assignments {
+            let result: Result<(u32, u32), CoreError> = async {
+                let mut current = BTreeSet::new();
+                for name in managed {
+                    if let Some(group_id) = groups.get(name)
+                        && self
+                            .group_member_repository
+                            .get_member(*group_id, assignment.user_id)
+                            .await?
+                            .is_some()
+                    {
+                        current.insert(name.clone());
+                    }
+                }
+
+                let desired = known(&assignment.groups, &groups);
+                let diff = diff_memberships(&desired, &current, managed);
+
+                for name in &diff.to_add {
+                    self.group_member_repository
+                        .add_member(groups[name], assignment.user_id)
+                        .await?;
+                }
+                for name in &diff.to_remove {
+                    self.group_member_repository
+                        .remove_member(groups[name], assignment.user_id)
+                        .await?;
+                }
+
+                Ok((diff.to_add.len() as u32, diff.to_remove.len() as u32))
+            }
+            .await;
+
+            stats.record(assignment, result);
+        }
+
+        Ok(stats)
+    }
+
+    /// Directory groups map onto top-level realm groups of the same name.
+    async fn apply_realm_groups(
+        &self,
+        realm_id: RealmId,
+        mapper: &GroupMapperSettings,
+        managed: &BTreeSet<String>,
+        assignments: &[GroupAssignment],
+    ) -> Result<GroupSyncStats, CoreError> {
+        let mut groups: BTreeMap<String, RealmGroupId> = self
+            .realm_group_repository
+            .list_groups_by_realm(realm_id)
+            .await?
+            .into_iter()
+            .filter(|g| g.parent_group_id.is_none())
+            .map(|g| (g.name, g.id))
+            .collect();
+
+        if mapper.create_missing {
+            let missing: Vec<&String> = managed
+                .iter()
+                .filter(|n| !groups.contains_key(*n))
+                .collect();
+            for name in missing {
+                info!("Creating realm group '{}' for LDAP group", name);
+                let group = self
+                    .realm_group_repository
+                    .create_group(CreateRealmGroupParams {
+                        realm_id,
+                        parent_group_id: None,
+                        name: name.clone(),
+                        description: Some(synchronized_description(mapper)),
+                    })
+                    .await?;
+                groups.insert(group.name, group.id);
+            }
+        }
+
+        let mut stats = GroupSyncStats::default();
+
+        for assignment in assignments {
+            let result: Result<(u32, u32), CoreError> = async {
+                let mut current = BTreeSet::new();
+                for name in managed {
+                    if let Some(group_id) = groups.get(name)
+                        && self
+                            .realm_group_member_repository
+                            .get_member(*group_id, assignment.user_id)
+                            .await?
+                            .is_some()
+                    {
+                        current.insert(name.clone());
+                    }
+                }
+
+                let desired = known(&assignment.groups, &groups);
+                let diff = diff_memberships(&desired, &current, managed);
+
+                for name in &diff.to_add {
+                    self.realm_group_member_repository
+                        .add_member(groups[name], assignment.user_id)
+                        .await?;
+                }
+                for name in &diff.to_remove {
+                    self.realm_group_member_repository
+                        .remove_member(groups[name], assignment.user_id)
+                        .await?;
+                }
+
+                Ok((diff.to_add.len() as u32, diff.to_remove.len() as u32))
+            }
+            .await;
+
+            stats.record(assignment, result);
+        }
+
+        Ok(stats)
+    }
+}
+
+/// Description of the roles and groups a mapper creates. Realm roles are only synchronized
+/// while they keep it.
+fn synchronized_description(mapper: &GroupMapperSettings) -> String {
+    format!("Synchronized from LDAP ({})", mapper.name)
+}
+
+/// Keep only the names that exist locally; without `create_missing` unknown groups are skipped.
+fn known<V>(names: &BTreeSet<String>, local: &BTreeMap<String, V>) -> BTreeSet<String> {
+    names
+        .iter()
+        .filter(|n| local.contains_key(*n))
+        .cloned()
+        .collect()
+}
+
+impl GroupSyncStats {
+    fn record(&mut self, assignment: &GroupAssignment, result: Result<(u32, u32), CoreError>) {
+        match result {
+            Ok((added, removed)) => {
+                self.added += added;
+                self.removed += removed;
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to reconcile groups for user '{}': {}",
+                    assignment.username, e
+                );
+                self.errors.push(SyncError {
+                    username: Some(assignment.username.clone()),
+                    external_id: assignment.external_id.clone(),
+                    error: e.to_string(),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::Utc;
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::abyss::federation::entities::{FederationProvider, FederationType};
+    use crate::domain::abyss::federation::group_mapping::{
+        group_mappers_for_provider, resolve_user_groups,
+    };
+    use crate::domain::abyss::federation::ports::FederationDirectory;
+    use crate::domain::role::entities::Role;
+    use crate::domain::role::ports::MockRoleRepository;
+    use crate::domain::user::ports::MockUserRoleRepository;
+    use crate::infrastructure::abyss::federation::in_memory::InMemoryDirectory;
+    use ferriskey_organization::{MockGroupMemberRepository, MockGroupRepository};
+    use ferriskey_realm_group::{MockRealmGroupMemberRepository, MockRealmGroupRepository};
+
+    fn role(realm_id: RealmId, name: &str) -> Role {
+        Role {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            description: None,
+            permissions: vec![],
+            realm_id,
+            client_id: None,
+            client: None,
+            require_mfa: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn synchronized_role(realm_id: RealmId, name: &str) -> Role {
+        Role {
+            description: Some("Synchronized from LDAP (ad-groups)".to_string()),
+            ..role(realm_id, name)
+        }
+    }
+
+    fn active_directory() -> InMemoryDirectory {
+        InMemoryDirectory::new()
+            .with_entry(
+                "CN=Alice,OU=Users,DC=corp,DC=local",
+                &[
+                    ("objectClass", &["user"]),
+                    ("sAMAccountName", &["alice"]),
+                    ("memberOf", &["CN=Developers,OU=Groups,DC=corp,DC=local"]),
+                ],
+            )
+            .with_entry(
+                "CN=Developers,OU=Groups,DC=corp,DC=local",
+                &[
+                    ("objectClass", &["group"]),
+                    ("cn", &["Developers"]),
+                    ("memberOf", &["CN=Engineering,OU=Groups,DC=corp,DC=local"]),
+                ],
+            )
+            .with_entry(
+                "CN=Engineering,OU=Groups,DC=corp,DC=local",
+                &[("objectClass", &["group"]), ("cn", &["Engineering"])],
+            )
+            .with_entry(
+                "CN=Finance,OU=Groups,DC=corp,DC=local",
+                &[("objectClass", &["group"]), ("cn", &["Finance"])],
+            )
+    }
+
+    fn provider(realm_id: RealmId) -> FederationProvider {
+        FederationProvider {
+            id: Uuid::new_v4(),
+            realm_id: realm_id.into(),
+            name: "corp-ad".to_string(),
+            provider_type: FederationType::ActiveDirectory,
+            enabled: true,
+            priority: 0,
+            config: json!({
+                "connection": {
+                    "server_url": "ldap://in-memory",
+                    "port": 389,
+                    "use_tls": false,
+                    "use_starttls": false,
+                    "connection_timeout_seconds": 5
+                },
+                "bind": { "bind_dn": "", "bind_password_encrypted": "" },
+                "search": {
+                    "base_dn": "OU=Users,DC=corp,DC=local",
+                    "user_search_filter": "(&(objectClass=user)(sAMAccountName={0}))"
+                },
+                "attributes": {
+                    "username": "sAMAccountName",
+                    "email": "mail",
+                    "first_name": "givenName",
+                    "last_name": "sn"
+                },
+                "group_mappers": [{
+                    "name": "ad-groups",
+                    "preset": "active_directory",
+                    "group_search_base": "OU=Groups,DC=corp,DC=local",
+                    "target": { "type": "realm_role" }
+                }]
+            }),
+            sync_settings: json!({}),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn active_directory_groups_become_realm_roles_and_stale_ones_are_revoked() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let provider = provider(realm_id);
+        let directory = active_directory();
+
+        let mappers = group_mappers_for_provider(&provider).unwrap();
+        let mapper = &mappers[0];
+        let users = directory.search_users(&provider, None).await.unwrap();
+        let groups = directory.search_groups(&provider, mapper).await.unwrap();
+        assert_eq!(users.len(), 1);
+        assert_eq!(groups.len(), 3);
+
+        let user_id = Uuid::new_v4();
+        let assignment = GroupAssignment {
+            user_id,
+            external_id: users[0].external_id.clone(),
+            username: users[0].username.clone(),
+            groups: resolve_user_groups(&users[0], &groups, mapper),
+        };
+
+        // "Engineering" and "Finance" exist from a previous sync; "Developers" must be created.
+        // The user still holds "Finance" and a hand-assigned "auditor" role.
+        let engineering = synchronized_role(realm_id, "Engineering");
+        let finance = synchronized_role(realm_id, "Finance");
+        let auditor = role(realm_id, "auditor");
+        let finance_id = finance.id;
+        let existing = vec![engineering.clone(), finance.clone(), auditor.clone()];
+
+        let mut role_repo = MockRoleRepository::new();
+        role_repo
+            .expect_find_by_realm_id()
+            .times(1)
+            .return_once(move |_| Box::pin(async move { Ok(existing) }));
+        let created = role(realm_id, "Developers");
+        let developers_id = created.id;
+        role_repo
+            .expect_create()
+            .withf(|req| req.name == "Developers" && req.client_id.is_none())
+            .times(1)
+            .return_once(move |_| Box::pin(async move { Ok(created) }));
+
+        let assigned = Arc::new(Mutex::new(BTreeSet::new()));
+        let revoked = Arc::new(Mutex::new(BTreeSet::new()));
+
+        let mut user_role_repo = MockUserRoleRepository::new();
+        user_role_repo
+            .expect_get_direct_user_roles()
+            .times(1)
+            .return_once(move |_| Box::pin(async move { Ok(vec![finance, auditor]) }));
+        let sink = assigned.clone();
+        user_role_repo
+            .expect_assign_role()
+            .returning(move |_, role_id| {
+                sink.lock().unwrap().insert(role_id);
+                Box::pin(async { Ok(()) })
+            });
+        let sink = revoked.clone();
+        user_role_repo
+            .expect_revoke_role()
+            .returning(move |_, role_id| {
+                sink.lock().unwrap().insert(role_id);
+                Box::pin(async { Ok(()) })
+            });
+
+        let sync = GroupMapperSync::new(
+            Arc::new(role_repo),
+            Arc::new(user_role_repo),
+            Arc::new(MockGroupRepository::new()),
+            Arc::new(MockGroupMemberRepository::new()),
+            Arc::new(MockRealmGroupRepository::new()),
+            Arc::new(MockRealmGroupMemberRepository::new()),
+        );
+
+        let stats = sync
+            .apply(realm_id, mapper, &groups, &[assignment])
+            .await
+            .unwrap();
+
+        assert_eq!(stats.added, 2);
+        assert_eq!(stats.removed, 1);
+        assert!(stats.errors.is_empty());
+        assert_eq!(
+            *assigned.lock().unwrap(),
+            BTreeSet::from([developers_id, engineering.id])
+        );
+        assert_eq!(*revoked.lock().unwrap(), BTreeSet::from([finance_id]));
+    }
+
+    #[tokio::test]
+    async fn roles_the_mapper_did_not_create_or_that_carry_permissions_are_left_alone() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let provider = provider(realm_id);
+        let directory = active_directory();
+
+        let mappers = group_mappers_for_provider(&provider).unwrap();
+        let mapper = &mappers[0];
+        let users = directory.search_users(&provider, None).await.unwrap();
+        let groups = directory.search_groups(&provider, mapper).await.unwrap();
+
+        // The user is in "Developers" and, through nesting, "Engineering". "Developers" is a
+        // privileged role carrying the sync description; "Engineering" and "Finance" were
+        // created by hand, and the user holds "Finance" from an administrator.
+        let developers = Role {
+            permissions: vec!["manage_realm".to_string()],
+            ..synchronized_role(realm_id, "Developers")
+        };
+        let engineering = role(realm_id, "Engineering");
+        let finance = role(realm_id, "Finance");
+        let existing = vec![developers, engineering, finance.clone()];
+
+        let mut role_repo = MockRoleRepository::new();
+        role_repo
+            .expect_find_by_realm_id()
+            .times(1)
+            .return_once(move |_| Box::pin(async move { Ok(existing) }));
+        role_repo.expect_create().never();
+
+        let mut user_role_repo = MockUserRoleRepository::new();
+        user_role_repo
+            .expect_get_direct_user_roles()
+            .times(1)
+            .return_once(move |_| Box::pin(async move { Ok(vec![finance]) }));
+        user_role_repo.expect_assign_role().never();
+        user_role_repo.expect_revoke_role().never();
+
+        let sync = GroupMapperSync::new(
+            Arc::new(role_repo),
+            Arc::new(user_role_repo),
+            Arc::new(MockGroupRepository::new()),
+            Arc::new(MockGroupMemberRepository::new()),
+            Arc::new(MockRealmGroupRepository::new()),
+            Arc::new(MockRealmGroupMemberRepository::new()),
+        );
+
+        let assignment = GroupAssignment {
+            user_id: Uuid::new_v4(),
+            external_id: users[0].external_id.clone(),
+            username: users[0].username.clone(),
+            groups: resolve_user_groups(&users[0], &groups, mapper),
+        };
+        let stats = sync
+            .apply(realm_id, mapper, &groups, &[assignment])
+            .await
+            .unwrap();
+
+        assert_eq!((stats.added, stats.removed), (0, 0));
+        assert!(stats.errors.is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::abyss::federation::entities::{FederationProvider, FederationType};
+use crate::domain::common::entities::app_errors::CoreError;
+
+const DEFAULT_CLOCK_SKEW_SECONDS: i64 = 300;
+
+/// Configuration of a `Kerberos` provider (`config`), used for SPNEGO single sign-on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KerberosConfig {
+    /// Kerberos realm whose principals may sign in, e.g. `CORP.EXAMPLE.COM`.
+    pub kerberos_realm: String,
+    /// Service principal browsers request tickets for, e.g. `HTTP/sso.example.com`.
+    pub server_principal: String,
+    /// Keytab holding the service principal's keys.
+    pub keytab_path: String,
+    /// LDAP provider of the same realm that users seen for the first time are imported from.
+    /// An existing user signs in only when it is federated with this provider.
+    #[serde(default)]
+    pub ldap_provider_id: Option<Uuid>,
+    #[serde(default = "default_clock_skew")]
+    pub allowed_clock_skew_seconds: i64,
+}
+
+fn default_clock_skew() -> i64 {
+    DEFAULT_CLOCK_SKEW_SECONDS
+}
+
+impl KerberosConfig {
+    pub fn from_provider(provider: &FederationProvider) -> Result<Self, CoreError> {
+        if provider.provider_type != FederationType::Kerberos {
+            return Err(CoreError::InvalidProviderConfiguration(format!(
+                "Provider '{}' is not a Kerberos provider",
+                provider.name
+            )));
+        }
+
+        serde_json::from_value(provider.config.clone()).map_err(|e| {
+            CoreError::InvalidProviderConfiguration(format!("Invalid Kerberos config: {}", e))
+        })
+    }
+
+    /// The service principal, defaulting its realm to `kerberos_realm`.
+    pub fn service_principal(&self) -> KerberosPrincipal {
+        KerberosPrincipal::parse(&self.server_principal, &self.kerberos_realm)
+    }
+}
+
+/// A Kerberos principal name, e.g. `jdoe@CORP.EXAMPLE.COM` or `HTTP/sso.example.com@CORP`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KerberosPrincipal {
+    pub components: Vec<String>,
+    pub realm: String,
+}
+
+impl KerberosPrincipal {
+    pub fn parse(name: &str, default_realm: &str) -> Self {
+        let (name, realm) = name.rsplit_once('@').unwrap_or((name, default_realm));
+
+        Self {
+            components: name.split('/').map(str::to_string).collect(),
+            realm: realm.to_string(),
+        }
+    }
+
+    /// The username a user principal maps to. Principals with an instance
+    /// (`jdoe/admin`) are service or elevated identities and never map to a user.
+    pub fn username(&self) -> Option<&str> {
+        match self.components.as_slice() {
+            [name] if !name.is_empty() => Some(name),
+            _ => None,
+        }
+    }
+
+    pub fn same_name(&self, other: &KerberosPrincipal) -> bool {
+        self.components == other.components && self.realm.eq_ignore_ascii_case(&other.realm)
+    }
+}
+
+impl fmt::Display for KerberosPrincipal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.components.join("/"), self.realm)
+    }
+}
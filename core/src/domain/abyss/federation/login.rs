@@ -0,0 +1,653 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::domain::abyss::federation::entities::{
+    FEDERATED_CREDENTIAL_MARKER, FederatedUser, FederationMapping, FederationProvider, SyncMode,
+};
+use crate::domain::abyss::federation::kerberos::{KerberosConfig, KerberosPrincipal};
+use crate::domain::abyss::federation::ports::{
+    FederationCredentialDirectory, FederationRepository,
+};
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::credential::ports::CredentialRepository;
+use crate::domain::realm::entities::RealmId;
+use crate::domain::user::entities::User;
+use crate::domain::user::ports::UserRepository;
+use crate::domain::user::value_objects::CreateUserRequest;
+
+/// Decides how a login reaches the realm's directories: which provider checks a federated
+/// user's password, and which directory entry a login with no local account imports.
+#[derive(Clone, Debug)]
+pub struct FederatedLogin<F, U, CR, D>
+where
+    F: FederationRepository,
+    U: UserRepository,
+    CR: CredentialRepository,
+    D: FederationCredentialDirectory,
+{
+    federation_repository: Arc<F>,
+    user_repository: Arc<U>,
+    credential_repository: Arc<CR>,
+    directory: Arc<D>,
+}
+
+impl<F, U, CR, D> FederatedLogin<F, U, CR, D>
+where
+    F: FederationRepository,
+    U: UserRepository,
+    CR: CredentialRepository,
+    D: FederationCredentialDirectory,
+{
+    pub fn new(
+        federation_repository: Arc<F>,
+        user_repository: Arc<U>,
+        credential_repository: Arc<CR>,
+        directory: Arc<D>,
+    ) -> Self {
+        Self {
+            federation_repository,
+            user_repository,
+            credential_repository,
+            directory,
+        }
+    }
+
+    /// The provider a federated user's password is checked against, or `None` when the local
+    /// credential applies. `Force` providers stay authoritative; in the other modes a password
+    /// set locally since the import takes over.
+    pub async fn directory_provider(
+        &self,
+        user: &User,
+        mapping: &FederationMapping,
+    ) -> Result<Option<FederationProvider>, CoreError> {
+        let provider = self
+            .federation_repository
+            .get_by_id(mapping.provider_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::InternalServerError)?;
+
+        if !provider.enabled {
+            error!("Federation provider {} is disabled", provider.name);
+            return Err(CoreError::InvalidPassword);
+        }
+
+        if provider.sync_mode() == SyncMode::Force {
+            return Ok(Some(provider));
+        }
+
+        let has_local_password = self
+            .credential_repository
+            .get_password_credential(user.id)
+            .await
+            .is_ok_and(|credential| credential.secret_data != FEDERATED_CREDENTIAL_MARKER);
+
+        Ok((!has_local_password).then_some(provider))
+    }
+
+    /// Just-in-time import for a login that matches no local account. The realm's enabled
+    /// directory providers are tried by priority; the first one that accepts the credentials
+    /// gets the user created with its mapped attributes. `LinkOnly` providers never create
+    /// users.
+    pub async fn import_user(
+        &self,
+        realm_id: RealmId,
+        username: &str,
+        password: &str,
+    ) -> Result<Option<User>, CoreError> {
+        let mut providers = self
+            .federation_repository
+            .list_by_realm(realm_id.into())
+            .await?;
+        providers.retain(|p| p.enabled && p.is_directory() && p.sync_mode() != SyncMode::LinkOnly);
+        providers.sort_by_key(|p| p.priority);
+
+        for provider in providers {
+            let federated = match self
+                .directory
+                .authenticate(&provider, username, password)
+                .await
+            {
+                Ok(federated) => federated,
+                Err(e) => {
+                    info!(
+                        "Provider '{}' did not authenticate '{}': {}",
+                        provider.name, username, e
+                    );
+                    continue;
+                }
+            };
+
+            return self
+                .import_directory_user(realm_id, &provider, &federated)
+                .await;
+        }
+
+        Ok(None)
+    }
+
+    /// The user a Kerberos principal signs in as. A mapping on the Kerberos provider whose
+    /// external id is the full principal name links it explicitly. Otherwise the realm user
+    /// with the principal's name is accepted only when it is federated with the provider's
+    /// LDAP provider, so a directory principal never takes over a local-only account with the
+    /// same name. With no such user, the LDAP entry is imported on first sight.
+    pub async fn kerberos_user(
+        &self,
+        realm_id: RealmId,
+        kerberos_provider: &FederationProvider,
+        config: &KerberosConfig,
+        principal: &KerberosPrincipal,
+    ) -> Result<Option<User>, CoreError> {
+        if let Some(link) = self
+            .federation_repository
+            .get_mapping(kerberos_provider.id, &principal.to_string())
+            .await?
+        {
+            return Ok(Some(self.user_repository.get_by_id(link.user_id).await?));
+        }
+
+        let Some(username) = principal.username() else {
+            return Ok(None);
+        };
+
+        if let Ok(user) = self
+            .user_repository
+            .get_by_username(username.to_string(), realm_id)
+            .await
+        {
+            let federated_with_ldap = self
+                .federation_repository
+                .get_mapping_by_user_id(user.id)
+                .await?
+                .is_some_and(|m| Some(m.provider_id) == config.ldap_provider_id);
+
+            if !federated_with_ldap {
+                warn!(
+                    "Refusing Kerberos principal {} for '{}': the account is not federated with the provider's LDAP provider",
+                    principal, user.username
+                );
+                return Ok(None);
+            }
+            return Ok(Some(user));
+        }
+
+        let Some(ldap_provider_id) = config.ldap_provider_id else {
+            return Ok(None);
+        };
+        let Some(ldap_provider) = self
+            .federation_repository
+            .get_by_id(ldap_provider_id)
+            .await?
+            .filter(|p| {
+                p.enabled
+                    && p.is_directory()
+                    && p.realm_id == Uuid::from(realm_id)
+                    && p.sync_mode() != SyncMode::LinkOnly
+            })
+        else {
+            return Ok(None);
+        };
+
+        match self.directory.find_user(&ldap_provider, username).await? {
+            Some(federated) => {
+                self.import_directory_user(realm_id, &ldap_provider, &federated)
+                    .await
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Create the local user and federation mapping for a directory entry seen at login.
+    async fn import_directory_user(
+        &self,
+        realm_id: RealmId,
+        provider: &FederationProvider,
+        federated: &FederatedUser,
+    ) -> Result<Option<User>, CoreError> {
+        // An existing mapping means the local account was removed on purpose.
+        if self
+            .federation_repository
+            .get_mapping(provider.id, &federated.external_id)
+            .await?
+            .is_some()
+        {
+            warn!(
+                "Not re-importing '{}' from provider '{}': a mapping already exists",
+                federated.username, provider.name
+            );
+            return Ok(None);
+        }
+
+        let user = self
+            .user_repository
+            .create_user(CreateUserRequest {
+                realm_id,
+                username: federated.username.clone(),
+                email: federated.email.clone(),
+                firstname: federated.first_name.clone(),
+                lastname: federated.last_name.clone(),
+                enabled: true,
+                email_verified: false,
+                client_id: None,
+            })
+            .await?;
+
+        self.federation_repository
+            .create_mapping(FederationMapping {
+                id: Uuid::new_v4(),
+                provider_id: provider.id,
+                user_id: user.id,
+                external_id: federated.external_id.clone(),
+                external_username: federated.username.clone(),
+                mapping_metadata: serde_json::to_value(&federated.attributes)
+                    .unwrap_or(serde_json::Value::Null),
+                last_synced_at: Utc::now(),
+            })
+            .await?;
+
+        if self
+            .credential_repository
+            .create_custom_credential(
+                user.id,
+                "password".to_string(),
+                FEDERATED_CREDENTIAL_MARKER.to_string(),
+                Some(format!("Federated - {}", provider.name)),
+                serde_json::json!({
+                    "provider_id": provider.id.to_string(),
+                    "provider_type": provider.provider_type.to_string(),
+                }),
+            )
+            .await
+            .is_err()
+        {
+            warn!(
+                "Failed to create federated credential marker for user '{}'",
+                user.username
+            );
+        }
+
+        info!(
+            "Imported user '{}' from provider '{}'",
+            user.username, provider.name
+        );
+        Ok(Some(user))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::abyss::federation::entities::FederationType;
+    use crate::domain::abyss::federation::ports::{
+        MockFederationCredentialDirectory, MockFederationRepository,
+    };
+    use crate::domain::common::services::tests::create_test_user;
+    use crate::domain::credential::entities::{Credential, CredentialData, CredentialType};
+    use crate::domain::credential::ports::MockCredentialRepository;
+    use crate::domain::user::ports::MockUserRepository;
+
+    type TestFederatedLogin = FederatedLogin<
+        MockFederationRepository,
+        MockUserRepository,
+        MockCredentialRepository,
+        MockFederationCredentialDirectory,
+    >;
+
+    fn provider(name: &str, mode: &str, priority: i32) -> FederationProvider {
+        FederationProvider {
+            id: Uuid::new_v4(),
+            realm_id: Uuid::new_v4(),
+            name: name.to_string(),
+            provider_type: FederationType::Ldap,
+            enabled: true,
+            priority,
+            config: json!({}),
+            sync_settings: json!({ "enabled": false, "mode": mode }),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn mapping(provider: &FederationProvider, user_id: Uuid) -> FederationMapping {
+        FederationMapping {
+            id: Uuid::new_v4(),
+            provider_id: provider.id,
+            user_id,
+            external_id: "entry-1".to_string(),
+            external_username: "jdoe".to_string(),
+            mapping_metadata: serde_json::Value::Null,
+            last_synced_at: Utc::now(),
+        }
+    }
+
+    fn federated_user() -> FederatedUser {
+        FederatedUser {
+            external_id: "entry-1".to_string(),
+            dn: "uid=jdoe,ou=people,dc=example,dc=org".to_string(),
+            username: "jdoe".to_string(),
+            email: Some("jdoe@example.org".to_string()),
+            first_name: None,
+            last_name: None,
+            attributes: None,
+            change_marker: None,
+        }
+    }
+
+    fn password_credential(user_id: Uuid, secret_data: &str) -> Credential {
+        Credential {
+            id: Uuid::new_v4(),
+            salt: Some("salt".to_string()),
+            credential_type: CredentialType::Password,
+            user_id,
+            user_label: None,
+            secret_data: secret_data.to_string(),
+            credential_data: CredentialData::new_hash(1, "argon2".to_string()),
+            temporary: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            webauthn_credential_id: None,
+            authenticator: None,
+        }
+    }
+
+    fn login(
+        federation_repository: MockFederationRepository,
+        user_repository: MockUserRepository,
+        credential_repository: MockCredentialRepository,
+        directory: MockFederationCredentialDirectory,
+    ) -> TestFederatedLogin {
+        FederatedLogin::new(
+            Arc::new(federation_repository),
+            Arc::new(user_repository),
+            Arc::new(credential_repository),
+            Arc::new(directory),
+        )
+    }
+
+    fn repository_returning(provider: FederationProvider) -> MockFederationRepository {
+        let mut repository = MockFederationRepository::new();
+        repository
+            .expect_get_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(provider)) }));
+        repository
+    }
+
+    fn credentials_with_password(secret_data: &'static str) -> MockCredentialRepository {
+        let mut credentials = MockCredentialRepository::new();
+        credentials
+            .expect_get_password_credential()
+            .returning(move |user_id| {
+                Box::pin(async move { Ok(password_credential(user_id, secret_data)) })
+            });
+        credentials
+    }
+
+    #[tokio::test]
+    async fn force_provider_checks_the_password_even_when_one_is_set_locally() {
+        let user = create_test_user(RealmId::new(Uuid::new_v4()));
+        let force = provider("corp-ldap", "Force", 0);
+        let mapping = mapping(&force, user.id);
+
+        let login = login(
+            repository_returning(force.clone()),
+            MockUserRepository::new(),
+            credentials_with_password("local-hash"),
+            MockFederationCredentialDirectory::new(),
+        );
+
+        let selected = login.directory_provider(&user, &mapping).await.unwrap();
+        assert_eq!(selected.map(|p| p.id), Some(force.id));
+    }
+
+    #[tokio::test]
+    async fn local_password_takes_over_outside_force_mode() {
+        let user = create_test_user(RealmId::new(Uuid::new_v4()));
+        let import = provider("corp-ldap", "Import", 0);
+        let mapping = mapping(&import, user.id);
+
+        let login_with_local = login(
+            repository_returning(import.clone()),
+            MockUserRepository::new(),
+            credentials_with_password("local-hash"),
+            MockFederationCredentialDirectory::new(),
+        );
+        assert!(
+            login_with_local
+                .directory_provider(&user, &mapping)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let login_with_marker = login(
+            repository_returning(import.clone()),
+            MockUserRepository::new(),
+            credentials_with_password(FEDERATED_CREDENTIAL_MARKER),
+            MockFederationCredentialDirectory::new(),
+        );
+        let selected = login_with_marker
+            .directory_provider(&user, &mapping)
+            .await
+            .unwrap();
+        assert_eq!(selected.map(|p| p.id), Some(import.id));
+    }
+
+    #[tokio::test]
+    async fn providers_are_tried_by_priority_and_link_only_ones_are_skipped() {
+        let link_only = provider("link-only", "LinkOnly", 0);
+        let second = provider("secondary", "Import", 5);
+        let first = provider("primary", "Import", 1);
+        let providers = vec![link_only.clone(), second.clone(), first.clone()];
+
+        let mut repository = MockFederationRepository::new();
+        repository
+            .expect_list_by_realm()
+            .return_once(move |_| Box::pin(async move { Ok(providers) }));
+        repository
+            .expect_get_mapping()
+            .return_once(|_, _| Box::pin(async { Ok(None) }));
+        repository
+            .expect_create_mapping()
+            .withf({
+                let second_id = second.id;
+                move |m| m.provider_id == second_id && m.external_id == "entry-1"
+            })
+            .times(1)
+            .returning(|m| Box::pin(async move { Ok(m) }));
+
+        let tried = Arc::new(Mutex::new(Vec::new()));
+        let mut directory = MockFederationCredentialDirectory::new();
+        directory.expect_authenticate().returning({
+            let tried = tried.clone();
+            let first_id = first.id;
+            move |provider, _, _| {
+                tried.lock().unwrap().push(provider.id);
+                let result = if provider.id == first_id {
+                    Err(CoreError::InvalidPassword)
+                } else {
+                    Ok(federated_user())
+                };
+                Box::pin(async move { result })
+            }
+        });
+
+        let mut users = MockUserRepository::new();
+        users.expect_create_user().times(1).return_once(|request| {
+            let mut user = create_test_user(request.realm_id);
+            user.username = request.username;
+            Box::pin(async move { Ok(user) })
+        });
+
+        let mut credentials = MockCredentialRepository::new();
+        credentials
+            .expect_create_custom_credential()
+            .times(1)
+            .returning(|user_id, _, secret_data, _, _| {
+                Box::pin(async move { Ok(password_credential(user_id, &secret_data)) })
+            });
+
+        let login = login(repository, users, credentials, directory);
+        let user = login
+            .import_user(RealmId::new(Uuid::new_v4()), "jdoe", "Secret1!")
+            .await
+            .unwrap()
+            .expect("the secondary provider imports the user");
+
+        assert_eq!(user.username, "jdoe");
+        assert_eq!(*tried.lock().unwrap(), vec![first.id, second.id]);
+    }
+
+    #[tokio::test]
+    async fn existing_mapping_is_not_imported_again() {
+        let import = provider("corp-ldap", "Import", 0);
+        let existing = mapping(&import, Uuid::new_v4());
+
+        let mut repository = MockFederationRepository::new();
+        repository
+            .expect_get_mapping()
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(existing)) }));
+
+        let login = login(
+            repository,
+            MockUserRepository::new(),
+            MockCredentialRepository::new(),
+            MockFederationCredentialDirectory::new(),
+        );
+
+        let imported = login
+            .import_directory_user(RealmId::new(Uuid::new_v4()), &import, &federated_user())
+            .await
+            .unwrap();
+        assert!(imported.is_none());
+    }
+
+    fn kerberos_setup(ldap: &FederationProvider) -> (FederationProvider, KerberosConfig) {
+        let mut kerberos = provider("corp-kerberos", "Import", 0);
+        kerberos.provider_type = FederationType::Kerberos;
+        let config = KerberosConfig {
+            kerberos_realm: "CORP.EXAMPLE.COM".to_string(),
+            server_principal: "HTTP/sso.example.com".to_string(),
+            keytab_path: "/etc/ferriskey/sso.keytab".to_string(),
+            ldap_provider_id: Some(ldap.id),
+            allowed_clock_skew_seconds: 300,
+        };
+        (kerberos, config)
+    }
+
+    fn users_with(user: User) -> MockUserRepository {
+        let mut users = MockUserRepository::new();
+        users
+            .expect_get_by_username()
+            .return_once(move |_, _| Box::pin(async move { Ok(user) }));
+        users
+    }
+
+    #[tokio::test]
+    async fn kerberos_principal_does_not_take_over_a_local_only_account() {
+        let ldap = provider("corp-ldap", "Import", 0);
+        let (kerberos, config) = kerberos_setup(&ldap);
+        let mut admin = create_test_user(RealmId::new(Uuid::new_v4()));
+        admin.username = "admin".to_string();
+
+        let mut repository = MockFederationRepository::new();
+        repository
+            .expect_get_mapping()
+            .return_once(|_, _| Box::pin(async { Ok(None) }));
+        repository
+            .expect_get_mapping_by_user_id()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+
+        let login = login(
+            repository,
+            users_with(admin.clone()),
+            MockCredentialRepository::new(),
+            MockFederationCredentialDirectory::new(),
+        );
+
+        let principal = KerberosPrincipal::parse("admin@CORP.EXAMPLE.COM", "CORP.EXAMPLE.COM");
+        let user = login
+            .kerberos_user(admin.realm_id, &kerberos, &config, &principal)
+            .await
+            .unwrap();
+        assert!(user.is_none());
+    }
+
+    #[tokio::test]
+    async fn kerberos_principal_signs_in_as_the_user_federated_with_the_ldap_provider() {
+        let ldap = provider("corp-ldap", "Import", 0);
+        let (kerberos, config) = kerberos_setup(&ldap);
+        let jdoe = create_test_user(RealmId::new(Uuid::new_v4()));
+        let ldap_mapping = mapping(&ldap, jdoe.id);
+
+        let mut repository = MockFederationRepository::new();
+        repository
+            .expect_get_mapping()
+            .return_once(|_, _| Box::pin(async { Ok(None) }));
+        repository
+            .expect_get_mapping_by_user_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(ldap_mapping)) }));
+
+        let login = login(
+            repository,
+            users_with(jdoe.clone()),
+            MockCredentialRepository::new(),
+            MockFederationCredentialDirectory::new(),
+        );
+
+        let principal = KerberosPrincipal::parse("jdoe@CORP.EXAMPLE.COM", "CORP.EXAMPLE.COM");
+        let user = login
+            .kerberos_user(jdoe.realm_id, &kerberos, &config, &principal)
+            .await
+            .unwrap();
+        assert_eq!(user.map(|u| u.id), Some(jdoe.id));
+    }
+
+    #[tokio::test]
+    async fn kerberos_principal_linked_explicitly_signs_in_as_the_linked_user() {
+        let ldap = provider("corp-ldap", "Import", 0);
+        let (kerberos, config) = kerberos_setup(&ldap);
+        let linked = create_test_user(RealmId::new(Uuid::new_v4()));
+        let link = FederationMapping {
+            external_id: "svc-deploy@CORP.EXAMPLE.COM".to_string(),
+            ..mapping(&kerberos, linked.id)
+        };
+
+        let mut repository = MockFederationRepository::new();
+        repository
+            .expect_get_mapping()
+            .withf({
+                let kerberos_id = kerberos.id;
+                move |provider_id, external_id| {
+                    *provider_id == kerberos_id && external_id == "svc-deploy@CORP.EXAMPLE.COM"
+                }
+            })
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(link)) }));
+
+        let mut users = MockUserRepository::new();
+        users.expect_get_by_id().return_once({
+            let linked = linked.clone();
+            move |_| Box::pin(async move { Ok(linked) })
+        });
+
+        let login = login(
+            repository,
+            users,
+            MockCredentialRepository::new(),
+            MockFederationCredentialDirectory::new(),
+        );
+
+        let principal = KerberosPrincipal::parse("svc-deploy@CORP.EXAMPLE.COM", "CORP.EXAMPLE.COM");
+        let user = login
+            .kerberos_user(linked.realm_id, &kerberos, &config, &principal)
+            .await
+            .unwrap();
+        assert_eq!(user.map(|u| u.id), Some(linked.id));
+    }
+}
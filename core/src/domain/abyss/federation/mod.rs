@@ -1,5 +1,11 @@
 pub mod entities;
+pub mod group_mapping;
+pub mod group_sync;
+pub mod kerberos;
+pub mod login;
+pub mod password_writeback;
 pub mod policies;
 pub mod ports;
+pub mod schedule;
 pub mod services;
 pub mod value_objects;
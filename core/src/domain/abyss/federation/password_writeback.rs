@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use crate::domain::abyss::federation::entities::SyncMode;
+use crate::domain::abyss::federation::ports::{
+    FederatedPasswordPort, FederationCredentialDirectory, FederationRepository,
+};
+use crate::domain::common::entities::app_errors::CoreError;
+
+/// Pushes password changes of federated users to their directory.
+///
+/// Writable providers receive the new password before it is stored locally. Read-only
+/// providers in `Force` mode own the password, so local changes are refused; in the other
+/// modes the local credential simply takes over from the directory.
+#[derive(Clone, Debug)]
+pub struct FederatedPasswordWriter<F, D>
+where
+    F: FederationRepository,
+    D: FederationCredentialDirectory,
+{
+    federation_repository: Arc<F>,
+    directory: Arc<D>,
+}
+
+impl<F, D> FederatedPasswordWriter<F, D>
+where
+    F: FederationRepository,
+    D: FederationCredentialDirectory,
+{
+    pub fn new(federation_repository: Arc<F>, directory: Arc<D>) -> Self {
+        Self {
+            federation_repository,
+            directory,
+        }
+    }
+}
+
+impl<F, D> FederatedPasswordPort for FederatedPasswordWriter<F, D>
+where
+    F: FederationRepository,
+    D: FederationCredentialDirectory,
+{
+    #[instrument(skip(self, new_password))]
+    async fn write_back_password(
+        &self,
+        user_id: Uuid,
+        new_password: &str,
+    ) -> Result<(), CoreError> {
+        let Some(mapping) = self
+            .federation_repository
+            .get_mapping_by_user_id(user_id)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let Some(provider) = self
+            .federation_repository
+            .get_by_id(mapping.provider_id)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        if !provider.enabled || !provider.is_directory() {
+            return Ok(());
+        }
+
+        if provider.is_writable() {
+            self.directory
+                .update_password(&provider, &mapping.external_username, new_password)
+                .await?;
+            info!(
+                "Password of '{}' written back to provider '{}'",
+                mapping.external_username, provider.name
+            );
+            return Ok(());
+        }
+
+        if provider.sync_mode() == SyncMode::Force {
+            return Err(CoreError::Forbidden(format!(
+                "Password is managed by federation provider '{}'",
+                provider.name
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::abyss::federation::entities::{
+        FederationMapping, FederationProvider, FederationType,
+    };
+    use crate::domain::abyss::federation::ports::{
+        MockFederationCredentialDirectory, MockFederationRepository,
+    };
+
+    fn provider(writable: bool, mode: &str) -> FederationProvider {
+        FederationProvider {
+            id: Uuid::new_v4(),
+            realm_id: Uuid::new_v4(),
+            name: "corp-ldap".to_string(),
+            provider_type: FederationType::Ldap,
+            enabled: true,
+            priority: 0,
+            config: json!({ "writable": writable }),
+            sync_settings: json!({ "enabled": false, "mode": mode }),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn writer_for(
+        provider: FederationProvider,
+        directory: MockFederationCredentialDirectory,
+    ) -> FederatedPasswordWriter<MockFederationRepository, MockFederationCredentialDirectory> {
+        let mapping = FederationMapping {
+            id: Uuid::new_v4(),
+            provider_id: provider.id,
+            user_id: Uuid::new_v4(),
+            external_id: "entry-1".to_string(),
+            external_username: "jdoe".to_string(),
+            mapping_metadata: serde_json::Value::Null,
+            last_synced_at: Utc::now(),
+        };
+
+        let mut repository = MockFederationRepository::new();
+        repository
+            .expect_get_mapping_by_user_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(mapping)) }));
+        repository
+            .expect_get_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(provider)) }));
+
+        FederatedPasswordWriter::new(Arc::new(repository), Arc::new(directory))
+    }
+
+    #[tokio::test]
+    async fn writable_provider_receives_the_new_password() {
+        let mut directory = MockFederationCredentialDirectory::new();
+        directory
+            .expect_update_password()
+            .withf(|_, username, password| username == "jdoe" && password == "n3w-Secret!")
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let writer = writer_for(provider(true, "Force"), directory);
+
+        assert!(
+            writer
+                .write_back_password(Uuid::new_v4(), "n3w-Secret!")
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn read_only_force_provider_refuses_local_changes() {
+        let writer = writer_for(
+            provider(false, "Force"),
+            MockFederationCredentialDirectory::new(),
+        );
+
+        let result = writer
+            .write_back_password(Uuid::new_v4(), "n3w-Secret!")
+            .await;
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+
+        let writer = writer_for(
+            provider(false, "Import"),
+            MockFederationCredentialDirectory::new(),
+        );
+        assert!(
+            writer
+                .write_back_password(Uuid::new_v4(), "n3w-Secret!")
+                .await
+                .is_ok()
+        );
+    }
+}
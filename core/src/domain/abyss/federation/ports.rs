@@ -1,7 +1,13 @@
 use std::future::Future;
+
+use chrono::Duration;
 use uuid::Uuid;
 
-use super::entities::{FederationMapping, FederationProvider, SyncMode};
+use super::entities::{
+    FederatedGroup, FederatedUser, FederationMapping, FederationProvider, FederationSyncRun,
+    SyncKind, SyncMode,
+};
+use super::group_mapping::GroupMapperSettings;
 use super::value_objects::{
     CreateProviderRequest, SyncResult, TestConnectionResult, UpdateProviderRequest,
 };
@@ -9,6 +15,7 @@ use crate::domain::authentication::value_objects::Identity;
 use crate::domain::common::entities::app_errors::CoreError;
 use crate::domain::realm::entities::Realm;
 
+#[cfg_attr(test, mockall::automock)]
 pub trait FederationRepository: Send + Sync {
     // Provider CRUD
     fn create(
@@ -29,6 +36,10 @@ pub trait FederationRepository: Send + Sync {
         &self,
         realm_id: Uuid,
     ) -> impl Future<Output = Result<Vec<FederationProvider>, CoreError>> + Send;
+    /// Enabled providers with `sync_enabled`, across all realms.
+    fn list_sync_enabled(
+        &self,
+    ) -> impl Future<Output = Result<Vec<FederationProvider>, CoreError>> + Send;
 
     // Mappings
     fn create_mapping(
@@ -55,6 +66,115 @@ pub trait FederationRepository: Send + Sync {
     fn delete_mapping(&self, id: Uuid) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
 
+/// Read access to the external directory behind a provider. Implemented by the LDAP client;
+/// the in-process directory used by tests implements it as well.
+pub trait FederationDirectory: Send + Sync {
+    fn test_connection(
+        &self,
+        provider: &FederationProvider,
+    ) -> impl Future<Output = Result<TestConnectionResult, CoreError>> + Send;
+
+    /// Users matching the provider's search filter. With `changed_since`, only entries whose
+    /// change-tracking attribute is at or after that marker are returned.
+    fn search_users(
+        &self,
+        provider: &FederationProvider,
+        changed_since: Option<&str>,
+    ) -> impl Future<Output = Result<Vec<FederatedUser>, CoreError>> + Send;
+
+    fn search_groups(
+        &self,
+        provider: &FederationProvider,
+        mapper: &GroupMapperSettings,
+    ) -> impl Future<Output = Result<Vec<FederatedGroup>, CoreError>> + Send;
+}
+
+/// Credential operations against the directory behind a provider.
+#[cfg_attr(test, mockall::automock)]
+pub trait FederationCredentialDirectory: Send + Sync {
+    /// Bind as the user to check the password, returning the entry with its mapped attributes.
+    fn authenticate(
+        &self,
+        provider: &FederationProvider,
+        username: &str,
+        password: &str,
+    ) -> impl Future<Output = Result<FederatedUser, CoreError>> + Send;
+
+    /// Look up a user's entry with the provider's bind credentials, for sign-ins another
+    /// mechanism (Kerberos) has already authenticated.
+    fn find_user(
+        &self,
+        provider: &FederationProvider,
+        username: &str,
+    ) -> impl Future<Output = Result<Option<FederatedUser>, CoreError>> + Send;
+
+    /// Replace the user's password in the directory (`userPassword`, or `unicodePwd` for
+    /// Active Directory).
+    fn update_password(
+        &self,
+        provider: &FederationProvider,
+        username: &str,
+        new_password: &str,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Keeps the passwords of federated users in step with their directory.
+#[cfg_attr(test, mockall::automock)]
+pub trait FederatedPasswordPort: Send + Sync {
+    /// Called before a new password is stored locally. Writes it to the directory when the
+    /// user's provider is writable, and refuses it when the directory owns the password.
+    fn write_back_password(
+        &self,
+        user_id: Uuid,
+        new_password: &str,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Sync history and the lease that keeps a provider's sync on a single replica.
+#[cfg_attr(test, mockall::automock)]
+pub trait FederationSyncRepository: Send + Sync {
+    /// Take or renew the provider's sync lease. Returns `false` while another holder's lease
+    /// is still valid.
+    fn try_acquire_lock(
+        &self,
+        provider_id: Uuid,
+        holder: &str,
+        ttl: Duration,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn release_lock(
+        &self,
+        provider_id: Uuid,
+        holder: &str,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn create_run(
+        &self,
+        run: FederationSyncRun,
+    ) -> impl Future<Output = Result<FederationSyncRun, CoreError>> + Send;
+    /// Persist the final state of a run and mirror it onto the provider's `last_sync_*` columns.
+    fn complete_run(
+        &self,
+        run: FederationSyncRun,
+    ) -> impl Future<Output = Result<FederationSyncRun, CoreError>> + Send;
+    /// Most recent runs first.
+    fn list_runs(
+        &self,
+        provider_id: Uuid,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<FederationSyncRun>, CoreError>> + Send;
+    /// Most recent run, optionally restricted to one kind.
+    fn latest_run(
+        &self,
+        provider_id: Uuid,
+        kind: Option<SyncKind>,
+    ) -> impl Future<Output = Result<Option<FederationSyncRun>, CoreError>> + Send;
+    /// Change marker of the most recent run that completed and recorded one.
+    fn latest_change_marker(
+        &self,
+        provider_id: Uuid,
+    ) -> impl Future<Output = Result<Option<String>, CoreError>> + Send;
+}
+
 pub trait FederationPolicy: Send + Sync {
     fn can_create_federation_provider(
         &self,
@@ -126,4 +246,17 @@ pub trait FederationService: Send + Sync {
         id: Uuid,
         mode: SyncMode,
     ) -> impl Future<Output = Result<SyncResult, CoreError>> + Send;
+
+    fn list_federation_sync_runs(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        id: Uuid,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<FederationSyncRun>, CoreError>> + Send;
+
+    /// Run every scheduled sync that is due. Called by the scheduler on each replica; the
+    /// per-provider lease makes sure only one of them actually syncs. Returns the number of
+    /// syncs started by this replica.
+    fn run_scheduled_syncs(&self) -> impl Future<Output = Result<u32, CoreError>> + Send;
 }
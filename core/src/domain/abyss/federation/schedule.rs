@@ -0,0 +1,358 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, DurationRound, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::abyss::federation::entities::{SyncKind, SyncMode};
+use crate::domain::common::entities::app_errors::CoreError;
+
+/// Sync settings stored on a provider (`sync_settings`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncSettings {
+    pub enabled: bool,
+    pub mode: SyncMode,
+    /// Full sync period, used when no `full_sync_cron` is set.
+    #[serde(default)]
+    pub interval_minutes: Option<i32>,
+    /// Five-field cron expression (UTC) for full syncs, e.g. `0 3 * * *`.
+    #[serde(default)]
+    pub full_sync_cron: Option<String>,
+    /// Period of the changed-since sync that only pulls entries modified after the last run.
+    #[serde(default)]
+    pub changed_sync_interval_minutes: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FullSyncSchedule {
+    Cron(CronSchedule),
+    Every(Duration),
+}
+
+/// When a provider's scheduled syncs are due.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SyncSchedule {
+    full: Option<FullSyncSchedule>,
+    changed_every: Option<Duration>,
+}
+
+impl SyncSchedule {
+    pub fn from_settings(settings: &SyncSettings) -> Result<Self, CoreError> {
+        let full = match (&settings.full_sync_cron, settings.interval_minutes) {
+            (Some(expr), _) if !expr.trim().is_empty() => {
+                Some(FullSyncSchedule::Cron(expr.parse()?))
+            }
+            (_, Some(minutes)) => Some(FullSyncSchedule::Every(positive_minutes(
+                minutes,
+                "interval_minutes",
+            )?)),
+            _ => None,
+        };
+
+        let changed_every = settings
+            .changed_sync_interval_minutes
+            .map(|minutes| positive_minutes(minutes, "changed_sync_interval_minutes"))
+            .transpose()?;
+
+        Ok(Self {
+            full,
+            changed_every,
+        })
+    }
+
+    /// Parse and validate the raw `sync_settings` JSON of a provider.
+    pub fn from_json(value: &serde_json::Value) -> Result<Self, CoreError> {
+        let settings: SyncSettings = serde_json::from_value(value.clone()).map_err(|e| {
+            CoreError::InvalidProviderConfiguration(format!("Invalid sync settings: {}", e))
+        })?;
+        Self::from_settings(&settings)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.full.is_none() && self.changed_every.is_none()
+    }
+
+    /// Which sync should run now, given when the last full sync and the last sync of any kind
+    /// started. A due full sync wins over a changed-since sync.
+    pub fn due(
+        &self,
+        now: DateTime<Utc>,
+        last_full: Option<DateTime<Utc>>,
+        last_any: Option<DateTime<Utc>>,
+    ) -> Option<SyncKind> {
+        if let Some(full) = &self.full {
+            let due = match (full, last_full) {
+                (_, None) => true,
+                (FullSyncSchedule::Every(period), Some(last)) => last + *period <= now,
+                (FullSyncSchedule::Cron(cron), Some(last)) => {
+                    cron.next_after(last).is_some_and(|next| next <= now)
+                }
+            };
+            if due {
+                return Some(SyncKind::Full);
+            }
+        }
+
+        let changed_every = self.changed_every?;
+        match last_any {
+            Some(last) if last + changed_every > now => None,
+            _ => Some(SyncKind::Incremental),
+        }
+    }
+}
+
+/// Order two change-tracking values: numerically for `uSNChanged`, lexically for generalized
+/// time (`modifyTimestamp`), which sorts chronologically within one directory.
+pub fn compare_change_markers(a: &str, b: &str) -> Ordering {
+    match (a.parse::<u64>(), b.parse::<u64>()) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+pub fn newest_change_marker<'a>(markers: impl IntoIterator<Item = &'a String>) -> Option<String> {
+    markers
+        .into_iter()
+        .max_by(|a, b| compare_change_markers(a, b))
+        .cloned()
+}
+
+fn positive_minutes(minutes: i32, field: &str) -> Result<Duration, CoreError> {
+    if minutes <= 0 {
+        return Err(CoreError::InvalidProviderConfiguration(format!(
+            "{} must be greater than zero",
+            field
+        )));
+    }
+    Ok(Duration::minutes(minutes as i64))
+}
+
+/// A standard five-field cron expression (`minute hour day-of-month month day-of-week`),
+/// evaluated in UTC. Supports `*`, lists, ranges and steps; names are not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    days_of_week: u8,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl FromStr for CronSchedule {
+    type Err = CoreError;
+
+    fn from_str(expr: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, dom, month, dow] = fields.as_slice() else {
+            return Err(invalid_cron(expr, "expected 5 fields"));
+        };
+
+        let parse = |field: &str, min: u32, max: u32| {
+            parse_cron_field(field, min, max).ok_or_else(|| invalid_cron(expr, field))
+        };
+
+        // Day-of-week accepts 7 as an alias for Sunday.
+        let dow_bits = parse(dow, 0, 7)?;
+        let dow_bits = (dow_bits | (dow_bits >> 7)) & 0x7f;
+
+        Ok(Self {
+            minutes: parse(minute, 0, 59)?,
+            hours: parse(hour, 0, 23)? as u32,
+            days_of_month: parse(dom, 1, 31)? as u32,
+            months: parse(month, 1, 12)? as u16,
+            days_of_week: dow_bits as u8,
+            day_of_month_restricted: *dom != "*",
+            day_of_week_restricted: *dow != "*",
+        })
+    }
+}
+
+impl CronSchedule {
+    /// First matching minute strictly after `after`, searching up to five years ahead.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut t = after.duration_trunc(Duration::minutes(1)).ok()? + Duration::minutes(1);
+        let limit = after + Duration::days(5 * 366);
+
+        while t <= limit {
+            if self.months & (1 << t.month()) == 0 {
+                let (year, month) = if t.month() == 12 {
+                    (t.year() + 1, 1)
+                } else {
+                    (t.year(), t.month() + 1)
+                };
+                t = t
+                    .with_day(1)?
+                    .with_hour(0)?
+                    .with_minute(0)?
+                    .with_month(month)?
+                    .with_year(year)?;
+                continue;
+            }
+            if !self.day_matches(t) {
+                t = t.with_hour(0)?.with_minute(0)? + Duration::days(1);
+                continue;
+            }
+            if self.hours & (1 << t.hour()) == 0 {
+                t = t.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+            if self.minutes & (1 << t.minute()) == 0 {
+                t += Duration::minutes(1);
+                continue;
+            }
+            return Some(t);
+        }
+
+        None
+    }
+
+    fn day_matches(&self, t: DateTime<Utc>) -> bool {
+        let dom = self.days_of_month & (1 << t.day()) != 0;
+        let dow = self.days_of_week & (1 << t.weekday().num_days_from_sunday()) != 0;
+
+        // Classic cron: when both fields are restricted, either one matching is enough.
+        match (self.day_of_month_restricted, self.day_of_week_restricted) {
+            (true, true) => dom || dow,
+            (true, false) => dom,
+            (false, true) => dow,
+            (false, false) => true,
+        }
+    }
+}
+
+fn parse_cron_field(field: &str, min: u32, max: u32) -> Option<u64> {
+    let mut bits = 0u64;
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().ok().filter(|s| *s > 0)?),
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (a.parse().ok()?, b.parse().ok()?)
+        } else {
+            let value = range.parse().ok()?;
+            // `5/15` means "from 5 to the end, every 15".
+            if part.contains('/') {
+                (value, max)
+            } else {
+                (value, value)
+            }
+        };
+
+        if start < min || end > max || start > end {
+            return None;
+        }
+
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1 << value;
+        }
+    }
+
+    Some(bits)
+}
+
+fn invalid_cron(expr: &str, reason: &str) -> CoreError {
+    CoreError::InvalidProviderConfiguration(format!(
+        "Invalid cron expression '{}': {}",
+        expr, reason
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+    }
+
+    #[test]
+    fn cron_finds_next_matching_minute() {
+        let nightly: CronSchedule = "30 3 * * *".parse().unwrap();
+        assert_eq!(
+            nightly.next_after(at(2026, 3, 10, 3, 30)),
+            Some(at(2026, 3, 11, 3, 30))
+        );
+        assert_eq!(
+            nightly.next_after(at(2026, 3, 10, 1, 0)),
+            Some(at(2026, 3, 10, 3, 30))
+        );
+
+        let quarter_hour: CronSchedule = "*/15 * * * *".parse().unwrap();
+        assert_eq!(
+            quarter_hour.next_after(at(2026, 12, 31, 23, 50)),
+            Some(at(2027, 1, 1, 0, 0))
+        );
+
+        // Sundays (0 and 7 are both Sunday) at 02:00; 2026-03-15 is a Sunday.
+        let weekly: CronSchedule = "0 2 * * 7".parse().unwrap();
+        assert_eq!(
+            weekly.next_after(at(2026, 3, 10, 0, 0)),
+            Some(at(2026, 3, 15, 2, 0))
+        );
+
+        let impossible: CronSchedule = "0 0 31 2 *".parse().unwrap();
+        assert_eq!(impossible.next_after(at(2026, 1, 1, 0, 0)), None);
+    }
+
+    #[test]
+    fn cron_rejects_malformed_expressions() {
+        for expr in [
+            "* * * *",
+            "60 * * * *",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "a * * * *",
+        ] {
+            assert!(expr.parse::<CronSchedule>().is_err(), "{}", expr);
+        }
+    }
+
+    #[test]
+    fn usn_markers_compare_numerically() {
+        let markers = ["9800".to_string(), "10250".to_string(), "10030".to_string()];
+        assert_eq!(newest_change_marker(&markers), Some("10250".to_string()));
+
+        let stamps = ["20260310120000Z".to_string(), "20260311080000Z".to_string()];
+        assert_eq!(
+            newest_change_marker(&stamps),
+            Some("20260311080000Z".to_string())
+        );
+    }
+
+    #[test]
+    fn due_prefers_full_sync_and_falls_back_to_changed_since() {
+        let settings = SyncSettings {
+            enabled: true,
+            mode: SyncMode::Import,
+            interval_minutes: None,
+            full_sync_cron: Some("0 3 * * *".to_string()),
+            changed_sync_interval_minutes: Some(15),
+        };
+        let schedule = SyncSchedule::from_settings(&settings).unwrap();
+
+        let now = at(2026, 3, 10, 12, 0);
+        assert_eq!(schedule.due(now, None, None), Some(SyncKind::Full));
+
+        let last_full = at(2026, 3, 10, 3, 0);
+        assert_eq!(
+            schedule.due(now, Some(last_full), Some(at(2026, 3, 10, 11, 50))),
+            None
+        );
+        assert_eq!(
+            schedule.due(now, Some(last_full), Some(at(2026, 3, 10, 11, 40))),
+            Some(SyncKind::Incremental)
+        );
+        assert_eq!(
+            schedule.due(at(2026, 3, 11, 3, 5), Some(last_full), Some(now)),
+            Some(SyncKind::Full)
+        );
+    }
+}
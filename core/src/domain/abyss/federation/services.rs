@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -7,10 +7,19 @@ use tracing::{error, info, instrument, warn};
 use uuid::Uuid;
 
 use crate::domain::abyss::federation::entities::{
-    FederationMapping, FederationProvider, FederationType, SyncMode,
+    FEDERATED_CREDENTIAL_MARKER, FederatedUser, FederationMapping, FederationProvider,
+    FederationSyncRun, FederationType, SyncKind, SyncMode, SyncTrigger,
 };
+use crate::domain::abyss::federation::group_mapping::{
+    group_mappers_for_provider, resolve_user_groups,
+};
+use crate::domain::abyss::federation::group_sync::{GroupAssignment, GroupMapperSync};
 use crate::domain::abyss::federation::ports::{
-    FederationPolicy, FederationRepository, FederationService,
+    FederationDirectory, FederationPolicy, FederationRepository, FederationService,
+    FederationSyncRepository,
+};
+use crate::domain::abyss::federation::schedule::{
+    SyncSchedule, SyncSettings, newest_change_marker,
 };
 use crate::domain::abyss::federation::value_objects::{
     CreateProviderRequest, SyncError, SyncResult, TestConnectionResult, UpdateProviderRequest,
@@ -19,42 +28,75 @@ use crate::domain::authentication::value_objects::Identity;
 use crate::domain::common::entities::app_errors::CoreError;
 use crate::domain::common::policies::ensure_policy;
 use crate::domain::credential::ports::CredentialRepository;
+use crate::domain::organization::ports::{GroupMemberRepository, GroupRepository};
 use crate::domain::realm::ports::RealmRepository;
-use crate::domain::user::ports::UserRepository;
+use crate::domain::realm_group::{RealmGroupMemberRepository, RealmGroupRepository};
+use crate::domain::role::ports::RoleRepository;
+use crate::domain::user::ports::{UserRepository, UserRoleRepository};
 use crate::domain::user::value_objects::{CreateUserRequest, UpdateUserRequest};
-use crate::infrastructure::abyss::federation::ldap::LdapClientImpl;
+
+/// How long a sync lease stays valid without being renewed.
+const SYNC_LOCK_TTL: chrono::Duration = chrono::Duration::minutes(15);
+/// Renew the lease every N reconciled users.
+const SYNC_LOCK_RENEW_EVERY: u32 = 500;
+const MAX_SYNC_RUNS_PAGE: u64 = 100;
 
 #[derive(Clone, Debug)]
-pub struct FederationServiceImpl<R, F, P, U, CR>
+pub struct FederationServiceImpl<R, F, P, U, CR, RR, URR, G, GM, RG, RGM, D, S>
 where
     R: RealmRepository,
     F: FederationRepository,
     P: FederationPolicy,
     U: UserRepository,
     CR: CredentialRepository,
+    RR: RoleRepository,
+    URR: UserRoleRepository,
+    G: GroupRepository,
+    GM: GroupMemberRepository,
+    RG: RealmGroupRepository,
+    RGM: RealmGroupMemberRepository,
+    D: FederationDirectory,
+    S: FederationSyncRepository,
 {
     federation_repository: Arc<F>,
     realm_repository: Arc<R>,
     user_repository: Arc<U>,
     credential_repository: Arc<CR>,
     policy: Arc<P>,
-    ldap_client: LdapClientImpl,
+    directory: Arc<D>,
+    sync_repository: Arc<S>,
+    group_sync: GroupMapperSync<RR, URR, G, GM, RG, RGM>,
+    /// Identifies this replica as the holder of provider sync leases.
+    instance_id: String,
 }
 
-impl<R, F, P, U, CR> FederationServiceImpl<R, F, P, U, CR>
+impl<R, F, P, U, CR, RR, URR, G, GM, RG, RGM, D, S>
+    FederationServiceImpl<R, F, P, U, CR, RR, URR, G, GM, RG, RGM, D, S>
 where
     R: RealmRepository,
     F: FederationRepository,
     P: FederationPolicy,
     U: UserRepository,
     CR: CredentialRepository,
+    RR: RoleRepository,
+    URR: UserRoleRepository,
+    G: GroupRepository,
+    GM: GroupMemberRepository,
+    RG: RealmGroupRepository,
+    RGM: RealmGroupMemberRepository,
+    D: FederationDirectory,
+    S: FederationSyncRepository,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         realm_repository: Arc<R>,
         federation_repository: Arc<F>,
         user_repository: Arc<U>,
         credential_repository: Arc<CR>,
         policy: Arc<P>,
+        directory: Arc<D>,
+        sync_repository: Arc<S>,
+        group_sync: GroupMapperSync<RR, URR, G, GM, RG, RGM>,
     ) -> Self {
         Self {
             realm_repository,
@@ -62,18 +104,30 @@ where
             user_repository,
             credential_repository,
             policy,
-            ldap_client: LdapClientImpl,
+            directory,
+            sync_repository,
+            group_sync,
+            instance_id: Uuid::new_v4().to_string(),
         }
     }
 }
 
-impl<R, F, P, U, CR> FederationService for FederationServiceImpl<R, F, P, U, CR>
+impl<R, F, P, U, CR, RR, URR, G, GM, RG, RGM, D, S> FederationService
+    for FederationServiceImpl<R, F, P, U, CR, RR, URR, G, GM, RG, RGM, D, S>
 where
     R: RealmRepository,
     F: FederationRepository,
     P: FederationPolicy,
     U: UserRepository,
     CR: CredentialRepository,
+    RR: RoleRepository,
+    URR: UserRoleRepository,
+    G: GroupRepository,
+    GM: GroupMemberRepository,
+    RG: RealmGroupRepository,
+    RGM: RealmGroupMemberRepository,
+    D: FederationDirectory,
+    S: FederationSyncRepository,
 {
     #[instrument(skip(self, identity, request))]
     async fn create_federation_provider(
@@ -97,6 +151,7 @@ where
         )?;
 
         request.realm_id = realm_id.into();
+        SyncSchedule::from_json(&request.sync_settings)?;
 
         // TODO: Validate config based on provider type
         self.federation_repository.create(request).await
@@ -166,6 +221,10 @@ where
             "insufficient permissions to update provider",
         )?;
 
+        if let Some(sync_settings) = &request.sync_settings {
+            SyncSchedule::from_json(sync_settings)?;
+        }
+
         self.federation_repository.update(id, request).await
     }
 
@@ -242,7 +301,7 @@ where
 
         match provider.provider_type {
             FederationType::Ldap | FederationType::ActiveDirectory => {
-                self.ldap_client.test_connection(&provider).await
+                self.directory.test_connection(&provider).await
             }
             _ => Ok(TestConnectionResult {
                 success: false,
@@ -271,22 +330,134 @@ where
 
         match provider.provider_type {
             FederationType::Ldap | FederationType::ActiveDirectory => {
-                self.sync_ldap_users(&provider, mode).await
+                self.run_sync(&provider, mode, SyncKind::Full, SyncTrigger::Manual)
+                    .await
             }
             _ => Err(CoreError::Configuration(
                 "Provider type does not support sync".to_string(),
             )),
         }
     }
+
+    #[instrument(skip(self, identity))]
+    async fn list_federation_sync_runs(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<FederationSyncRun>, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        ensure_policy(
+            self.policy
+                .can_view_federation_provider(&identity, &realm)
+                .await,
+            "insufficient permissions to view provider",
+        )?;
+
+        let provider = self
+            .federation_repository
+            .get_by_id(id)
+            .await?
+            .ok_or(CoreError::NotFound)?;
+
+        if provider.realm_id != Into::<Uuid>::into(realm.id) {
+            return Err(CoreError::NotFound);
+        }
+
+        self.sync_repository
+            .list_runs(provider.id, limit.clamp(1, MAX_SYNC_RUNS_PAGE))
+            .await
+    }
+
+    #[instrument(skip(self))]
+    async fn run_scheduled_syncs(&self) -> Result<u32, CoreError> {
+        let providers = self.federation_repository.list_sync_enabled().await?;
+        let mut started = 0;
+
+        for provider in providers {
+            if !matches!(
+                provider.provider_type,
+                FederationType::Ldap | FederationType::ActiveDirectory
+            ) {
+                continue;
+            }
+
+            let settings: SyncSettings =
+                match serde_json::from_value(provider.sync_settings.clone()) {
+                    Ok(settings) => settings,
+                    Err(e) => {
+                        warn!(
+                            "Provider '{}' has invalid sync settings: {}",
+                            provider.name, e
+                        );
+                        continue;
+                    }
+                };
+            let schedule = match SyncSchedule::from_settings(&settings) {
+                Ok(schedule) if schedule.is_empty() => continue,
+                Ok(schedule) => schedule,
+                Err(e) => {
+                    warn!(
+                        "Provider '{}' has an invalid schedule: {}",
+                        provider.name, e
+                    );
+                    continue;
+                }
+            };
+
+            if !self
+                .sync_repository
+                .try_acquire_lock(provider.id, &self.instance_id, SYNC_LOCK_TTL)
+                .await?
+            {
+                continue;
+            }
+
+            // Decide under the lease: another replica may have just finished this sync.
+            let outcome = self
+                .run_scheduled_sync(&provider, settings.mode, &schedule)
+                .await;
+            self.release_sync_lock(&provider).await;
+
+            match outcome {
+                Ok(true) => started += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    started += 1;
+                    warn!(
+                        "Scheduled sync of provider '{}' failed: {}",
+                        provider.name, e
+                    );
+                }
+            }
+        }
+
+        Ok(started)
+    }
 }
 
-impl<R, F, P, U, CR> FederationServiceImpl<R, F, P, U, CR>
+impl<R, F, P, U, CR, RR, URR, G, GM, RG, RGM, D, S>
+    FederationServiceImpl<R, F, P, U, CR, RR, URR, G, GM, RG, RGM, D, S>
 where
     R: RealmRepository,
     F: FederationRepository,
     P: FederationPolicy,
     U: UserRepository,
     CR: CredentialRepository,
+    RR: RoleRepository,
+    URR: UserRoleRepository,
+    G: GroupRepository,
+    GM: GroupMemberRepository,
+    RG: RealmGroupRepository,
+    RGM: RealmGroupMemberRepository,
+    D: FederationDirectory,
+    S: FederationSyncRepository,
 {
     async fn resolve_provider_for_management(
         &self,
@@ -321,12 +492,146 @@ where
         Ok(provider)
     }
 
+    /// Run a sync while holding the provider's lease; fails fast if another replica holds it.
+    async fn run_sync(
+        &self,
+        provider: &FederationProvider,
+        mode: SyncMode,
+        kind: SyncKind,
+        trigger: SyncTrigger,
+    ) -> Result<SyncResult, CoreError> {
+        if !self
+            .sync_repository
+            .try_acquire_lock(provider.id, &self.instance_id, SYNC_LOCK_TTL)
+            .await?
+        {
+            return Err(CoreError::ServiceUnavailable(format!(
+                "a sync is already running for provider '{}'",
+                provider.name
+            )));
+        }
+
+        let outcome = self.run_locked_sync(provider, mode, kind, trigger).await;
+        self.release_sync_lock(provider).await;
+        outcome
+    }
+
+    async fn run_scheduled_sync(
+        &self,
+        provider: &FederationProvider,
+        mode: SyncMode,
+        schedule: &SyncSchedule,
+    ) -> Result<bool, CoreError> {
+        let last_full = self
+            .sync_repository
+            .latest_run(provider.id, Some(SyncKind::Full))
+            .await?
+            .map(|run| run.started_at);
+        let last_any = self
+            .sync_repository
+            .latest_run(provider.id, None)
+            .await?
+            .map(|run| run.started_at);
+
+        let Some(kind) = schedule.due(Utc::now(), last_full, last_any) else {
+            return Ok(false);
+        };
+
+        self.run_locked_sync(provider, mode, kind, SyncTrigger::Scheduled)
+            .await?;
+        Ok(true)
+    }
+
+    /// Record a run around `sync_ldap_users`. Incremental syncs resume from the last recorded
+    /// change marker and fall back to a full sync when there is none yet.
+    async fn run_locked_sync(
+        &self,
+        provider: &FederationProvider,
+        mode: SyncMode,
+        kind: SyncKind,
+        trigger: SyncTrigger,
+    ) -> Result<SyncResult, CoreError> {
+        let changed_since = match kind {
+            SyncKind::Incremental => {
+                self.sync_repository
+                    .latest_change_marker(provider.id)
+                    .await?
+            }
+            SyncKind::Full => None,
+        };
+        let kind = if changed_since.is_some() {
+            kind
+        } else {
+            SyncKind::Full
+        };
+
+        let mut run = self
+            .sync_repository
+            .create_run(FederationSyncRun::start(provider.id, kind, trigger, mode))
+            .await?;
+
+        let outcome = self
+            .sync_ldap_users(provider, mode, changed_since.as_deref())
+            .await;
+
+        match &outcome {
+            Ok(result) => {
+                let marker =
+                    newest_change_marker(changed_since.iter().chain(&result.change_marker));
+                run.complete(result, marker);
+            }
+            Err(e) => run.fail(e),
+        }
+
+        if let Err(e) = self.sync_repository.complete_run(run).await {
+            warn!(
+                "Failed to record sync run for provider '{}': {}",
+                provider.name, e
+            );
+        }
+
+        outcome
+    }
+
+    async fn release_sync_lock(&self, provider: &FederationProvider) {
+        if let Err(e) = self
+            .sync_repository
+            .release_lock(provider.id, &self.instance_id)
+            .await
+        {
+            // The lease expires on its own; the next sync only waits for the TTL.
+            warn!(
+                "Failed to release sync lock for provider '{}': {}",
+                provider.name, e
+            );
+        }
+    }
+
+    /// Extend the lease during long syncs so it doesn't expire under us.
+    async fn renew_sync_lock(&self, provider: &FederationProvider) -> Result<(), CoreError> {
+        if self
+            .sync_repository
+            .try_acquire_lock(provider.id, &self.instance_id, SYNC_LOCK_TTL)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(CoreError::ServiceUnavailable(format!(
+                "lost the sync lock for provider '{}'",
+                provider.name
+            )))
+        }
+    }
+
     /// Comprehensive LDAP user synchronization with reconciliation
     ///
     /// This method performs a "diff" between LDAP and the local database:
     /// - TO CREATE: Users in LDAP but not in local DB
     /// - TO UPDATE: Users in both, but with changed attributes
-    /// - TO DISABLE: Users in local DB but not in LDAP (Force mode only)
+    /// - TO DISABLE: Users in local DB but not in LDAP (Force mode only, full syncs only)
+    ///
+    /// With `changed_since`, only entries changed after that marker are read from the
+    /// directory, so missing users can't be detected and nobody is disabled.
     ///
     /// Performance optimizations:
     /// - Batch fetching of federation mappings to avoid N+1 queries
@@ -337,6 +642,7 @@ where
         &self,
         provider: &FederationProvider,
         mode: SyncMode,
+        changed_since: Option<&str>,
     ) -> Result<SyncResult, CoreError> {
         // Start timing
         let start_time = Instant::now();
@@ -353,7 +659,10 @@ where
             updated: 0,
             disabled: 0,
             failed: 0,
+            groups_added: 0,
+            groups_removed: 0,
             errors: vec![],
+            change_marker: None,
             duration_ms: None,
             started_at: Some(started_at.to_rfc3339()),
             completed_at: None,
@@ -361,7 +670,7 @@ where
 
         // Step 1: Fetch all users from LDAP
         info!("Fetching users from LDAP provider '{}'", provider.name);
-        let ldap_users = match self.ldap_client.search_users(provider, None).await {
+        let ldap_users = match self.directory.search_users(provider, changed_since).await {
             Ok(users) => {
                 info!("Found {} users in LDAP", users.len());
                 users
@@ -410,14 +719,17 @@ where
 
         // Step 4: Optimized reconciliation loop - create or update users
         // Use the pre-fetched mappings to avoid N+1 database queries
-        for ldap_user in ldap_users {
+        for ldap_user in &ldap_users {
             result.total_processed += 1;
+            if result.total_processed.is_multiple_of(SYNC_LOCK_RENEW_EVERY) {
+                self.renew_sync_lock(provider).await?;
+            }
 
             // Check if we have an existing mapping in our HashMap
             let existing_mapping = mappings_by_external_id.remove(&ldap_user.external_id);
 
             match self
-                .reconcile_user_optimized(provider, &ldap_user, existing_mapping, mode)
+                .reconcile_user_optimized(provider, ldap_user, existing_mapping, mode)
                 .await
             {
                 Ok(action) => match action {
@@ -464,7 +776,7 @@ where
 
         // Step 5: Handle missing users (users in Ferriskey but not in LDAP)
         // Only in Force mode to prevent accidental mass disables
-        if mode == SyncMode::Force {
+        if mode == SyncMode::Force && changed_since.is_none() {
             info!("Checking for users to disable (Force mode enabled)");
             match self
                 .disable_missing_users(provider, &ldap_external_ids)
@@ -481,6 +793,14 @@ where
             }
         }
 
+        // Step 6: Mirror directory groups into realm roles / organization groups
+        self.renew_sync_lock(provider).await?;
+        self.sync_ldap_groups(provider, &ldap_users, &mut result)
+            .await;
+
+        result.change_marker =
+            newest_change_marker(ldap_users.iter().filter_map(|u| u.change_marker.as_ref()));
+
         // Record completion time and duration
         let completed_at = Utc::now();
         let duration = start_time.elapsed();
@@ -512,6 +832,102 @@ where
         Ok(result)
     }
 
+    /// Run every group mapper configured on the provider against the synced users.
+    ///
+    /// Group errors never fail the user sync: they are reported in `result.errors`.
+    #[instrument(skip(self, provider, ldap_users, result))]
+    async fn sync_ldap_groups(
+        &self,
+        provider: &FederationProvider,
+        ldap_users: &[FederatedUser],
+        result: &mut SyncResult,
+    ) {
+        let mappers = match group_mappers_for_provider(provider) {
+            Ok(mappers) if mappers.is_empty() => return,
+            Ok(mappers) => mappers,
+            Err(e) => {
+                warn!("Skipping group sync: {}", e);
+                result.errors.push(SyncError {
+                    username: None,
+                    external_id: provider.id.to_string(),
+                    error: e.to_string(),
+                });
+                return;
+            }
+        };
+
+        let user_ids: HashMap<String, Uuid> = match self
+            .federation_repository
+            .list_mappings_by_provider(provider.id)
+            .await
+        {
+            Ok(mappings) => mappings
+                .into_iter()
+                .map(|m| (m.external_id, m.user_id))
+                .collect(),
+            Err(e) => {
+                warn!("Skipping group sync, failed to load mappings: {}", e);
+                return;
+            }
+        };
+
+        for mapper in &mappers {
+            let groups = match self.directory.search_groups(provider, mapper).await {
+                Ok(groups) => groups,
+                Err(e) => {
+                    warn!(
+                        "Group mapper '{}' failed to search groups: {}",
+                        mapper.name, e
+                    );
+                    result.errors.push(SyncError {
+                        username: None,
+                        external_id: mapper.group_search_base.clone(),
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            let assignments: Vec<GroupAssignment> = ldap_users
+                .iter()
+                .filter_map(|user| {
+                    user_ids
+                        .get(&user.external_id)
+                        .map(|user_id| GroupAssignment {
+                            user_id: *user_id,
+                            external_id: user.external_id.clone(),
+                            username: user.username.clone(),
+                            groups: resolve_user_groups(user, &groups, mapper),
+                        })
+                })
+                .collect();
+
+            match self
+                .group_sync
+                .apply(provider.realm_id.into(), mapper, &groups, &assignments)
+                .await
+            {
+                Ok(stats) => {
+                    info!(
+                        "Group mapper '{}': {} memberships added, {} removed",
+                        mapper.name, stats.added, stats.removed
+                    );
+                    result.groups_added += stats.added;
+                    result.groups_removed += stats.removed;
+                    result.errors.extend(stats.errors);
+                }
+                Err(e) => {
+                    warn!("Group mapper '{}' failed: {}", mapper.name, e);
+                    result.errors.push(SyncError {
+                        username: None,
+                        external_id: mapper.group_search_base.clone(),
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
     /// Reconcile a single user: create if new, update if changed
     ///
     /// NOTE: This is the non-optimized version that queries for the mapping.
@@ -521,7 +937,7 @@ where
     async fn reconcile_user(
         &self,
         provider: &FederationProvider,
-        ldap_user: &crate::domain::abyss::federation::entities::FederatedUser,
+        ldap_user: &FederatedUser,
         mode: SyncMode,
     ) -> Result<ReconcileAction, CoreError> {
         // Check if mapping exists
@@ -625,7 +1041,7 @@ where
     async fn reconcile_user_optimized(
         &self,
         provider: &FederationProvider,
-        ldap_user: &crate::domain::abyss::federation::entities::FederatedUser,
+        ldap_user: &FederatedUser,
         existing_mapping: Option<FederationMapping>,
         mode: SyncMode,
     ) -> Result<ReconcileAction, CoreError> {
@@ -762,7 +1178,8 @@ where
                     .unwrap_or_default();
 
                 let has_federated_credential = existing_credentials.iter().any(|c| {
-                    c.credential_type.to_string() == "password" && c.secret_data == "federated"
+                    c.credential_type.to_string() == "password"
+                        && c.secret_data == FEDERATED_CREDENTIAL_MARKER
                 });
 
                 if !has_federated_credential {
@@ -772,7 +1189,7 @@ where
                         .create_custom_credential(
                             user.id,
                             "password".to_string(),
-                            "federated".to_string(), // Marker that indicates LDAP authentication
+                            FEDERATED_CREDENTIAL_MARKER.to_string(), // Marker that indicates LDAP authentication
                             Some(format!("Federated - {}", provider.name)),
                             credential_data,
                         )
@@ -871,6 +1288,25 @@ where
     }
 }
 
+/// Periodically run the federation syncs that are due. Every replica runs this loop; the
+/// per-provider lease decides which one performs each sync.
+pub async fn federation_sync_scheduler_task<S>(service: S, period: std::time::Duration)
+where
+    S: FederationService,
+{
+    let mut ticker = tokio::time::interval(period);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+        match service.run_scheduled_syncs().await {
+            Ok(0) => {}
+            Ok(started) => info!(started, "Ran scheduled federation syncs"),
+            Err(error) => warn!(error = ?error, "Failed to run scheduled federation syncs"),
+        }
+    }
+}
+
 #[derive(Debug)]
 enum ReconcileAction {
     Created,
@@ -44,8 +44,19 @@ pub struct SyncResult {
     pub created: u32,
     pub updated: u32,
     pub disabled: u32,
+    /// Users that could not be reconciled. Group mapper failures are only listed in `errors`,
+    /// since their users were already counted by the user pass.
     pub failed: u32,
+    /// Group/role memberships granted by group mappers.
+    #[serde(default)]
+    pub groups_added: u32,
+    /// Group/role memberships revoked because the directory no longer lists them.
+    #[serde(default)]
+    pub groups_removed: u32,
     pub errors: Vec<SyncError>,
+    /// Newest change-tracking value seen in this sync.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_marker: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration_ms: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,288 @@
+use std::sync::Arc;
+
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::abyss::identity_provider::mappers::{
+    CreateIdentityProviderMapperInput, CreateIdentityProviderMapperRequest,
+    DeleteIdentityProviderMapperInput, IdentityProviderMapper, IdentityProviderMapperConfig,
+    IdentityProviderMapperRepository, IdentityProviderMapperService,
+    ListIdentityProviderMappersInput, MapperSyncMode, UpdateIdentityProviderMapperInput,
+    UpdateIdentityProviderMapperRequest,
+};
+use crate::domain::abyss::identity_provider::{
+    IdentityProvider, IdentityProviderPolicy, IdentityProviderRepository,
+};
+use crate::domain::authentication::value_objects::Identity;
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::common::policies::ensure_policy;
+use crate::domain::organization::ports::{
+    GroupId, GroupRepository, OrganizationId, OrganizationRepository,
+};
+use crate::domain::realm::entities::Realm;
+use crate::domain::realm::ports::RealmRepository;
+use crate::domain::role::ports::RoleRepository;
+
+/// Implementation of the IdentityProviderMapperService trait
+#[derive(Clone, Debug)]
+pub struct IdentityProviderMapperServiceImpl<M, R, P, RR, RO, O, G>
+where
+    M: IdentityProviderMapperRepository,
+    R: IdentityProviderRepository,
+    P: IdentityProviderPolicy,
+    RR: RealmRepository,
+    RO: RoleRepository,
+    O: OrganizationRepository,
+    G: GroupRepository,
+{
+    mapper_repository: Arc<M>,
+    identity_provider_repository: Arc<R>,
+    identity_provider_policy: Arc<P>,
+    realm_repository: Arc<RR>,
+    role_repository: Arc<RO>,
+    organization_repository: Arc<O>,
+    group_repository: Arc<G>,
+}
+
+impl<M, R, P, RR, RO, O, G> IdentityProviderMapperServiceImpl<M, R, P, RR, RO, O, G>
+where
+    M: IdentityProviderMapperRepository,
+    R: IdentityProviderRepository,
+    P: IdentityProviderPolicy,
+    RR: RealmRepository,
+    RO: RoleRepository,
+    O: OrganizationRepository,
+    G: GroupRepository,
+{
+    pub fn new(
+        mapper_repository: Arc<M>,
+        identity_provider_repository: Arc<R>,
+        identity_provider_policy: Arc<P>,
+        realm_repository: Arc<RR>,
+        role_repository: Arc<RO>,
+        organization_repository: Arc<O>,
+        group_repository: Arc<G>,
+    ) -> Self {
+        Self {
+            mapper_repository,
+            identity_provider_repository,
+            identity_provider_policy,
+            realm_repository,
+            role_repository,
+            organization_repository,
+            group_repository,
+        }
+    }
+
+    async fn resolve_provider(
+        &self,
+        realm_name: &str,
+        alias: &str,
+    ) -> Result<(Realm, IdentityProvider), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let provider = self
+            .identity_provider_repository
+            .get_identity_provider_by_realm_and_alias(realm.id, alias)
+            .await?
+            .ok_or(CoreError::ProviderNotFound)?;
+
+        Ok((realm, provider))
+    }
+
+    async fn ensure_can_update(&self, identity: &Identity, realm: &Realm) -> Result<(), CoreError> {
+        ensure_policy(
+            self.identity_provider_policy
+                .can_update_identity_provider(identity, realm)
+                .await,
+            "insufficient permissions to manage identity provider mappers",
+        )
+    }
+
+    async fn find_mapper(
+        &self,
+        provider: &IdentityProvider,
+        mapper_id: Uuid,
+    ) -> Result<IdentityProviderMapper, CoreError> {
+        self.mapper_repository
+            .get_mapper_by_id(mapper_id)
+            .await?
+            .filter(|mapper| mapper.identity_provider_id == provider.id)
+            .ok_or(CoreError::NotFound)
+    }
+
+    /// Validate the configuration and make sure everything it references lives in `realm`.
+    async fn validate_config(
+        &self,
+        realm: &Realm,
+        name: &str,
+        sync_mode: MapperSyncMode,
+        config: &IdentityProviderMapperConfig,
+    ) -> Result<(), CoreError> {
+        if name.trim().is_empty() {
+            return Err(CoreError::InvalidProviderConfiguration(
+                "name is required".to_string(),
+            ));
+        }
+        config.validate(sync_mode)?;
+
+        match config {
+            IdentityProviderMapperConfig::ClaimToRole { role_id, .. }
+            | IdentityProviderMapperConfig::HardcodedRole { role_id } => {
+                self.role_repository
+                    .get_by_id(*role_id)
+                    .await?
+                    .filter(|role| role.realm_id == realm.id)
+                    .ok_or_else(|| {
+                        CoreError::InvalidProviderConfiguration(format!(
+                            "role {role_id} does not exist in this realm"
+                        ))
+                    })?;
+            }
+            IdentityProviderMapperConfig::ClaimToOrganization {
+                organization_id,
+                group_id,
+                ..
+            } => {
+                self.organization_repository
+                    .get_organization_by_id(OrganizationId::new(*organization_id))
+                    .await?
+                    .filter(|organization| organization.realm_id == realm.id)
+                    .ok_or_else(|| {
+                        CoreError::InvalidProviderConfiguration(format!(
+                            "organization {organization_id} does not exist in this realm"
+                        ))
+                    })?;
+
+                if let Some(group_id) = group_id {
+                    self.group_repository
+                        .get_group_by_id(GroupId::new(*group_id))
+                        .await?
+                        .filter(|group| group.organization_id.as_uuid() == *organization_id)
+                        .ok_or_else(|| {
+                            CoreError::InvalidProviderConfiguration(format!(
+                                "group {group_id} does not belong to organization {organization_id}"
+                            ))
+                        })?;
+                }
+            }
+            IdentityProviderMapperConfig::ClaimToAttribute { .. }
+            | IdentityProviderMapperConfig::UsernameTemplate { .. } => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl<M, R, P, RR, RO, O, G> IdentityProviderMapperService
+    for IdentityProviderMapperServiceImpl<M, R, P, RR, RO, O, G>
+where
+    M: IdentityProviderMapperRepository,
+    R: IdentityProviderRepository,
+    P: IdentityProviderPolicy,
+    RR: RealmRepository,
+    RO: RoleRepository,
+    O: OrganizationRepository,
+    G: GroupRepository,
+{
+    #[instrument(
+        skip(self, identity, input),
+        fields(realm.name = %input.realm_name, idp.alias = %input.alias)
+    )]
+    async fn list_identity_provider_mappers(
+        &self,
+        identity: Identity,
+        input: ListIdentityProviderMappersInput,
+    ) -> Result<Vec<IdentityProviderMapper>, CoreError> {
+        let (realm, provider) = self
+            .resolve_provider(&input.realm_name, &input.alias)
+            .await?;
+
+        ensure_policy(
+            self.identity_provider_policy
+                .can_view_identity_provider(&identity, &realm)
+                .await,
+            "insufficient permissions to view identity provider",
+        )?;
+
+        self.mapper_repository
+            .list_mappers_by_provider(provider.id)
+            .await
+    }
+
+    #[instrument(
+        skip(self, identity, input),
+        fields(realm.name = %input.realm_name, idp.alias = %input.alias, mapper.name = %input.name)
+    )]
+    async fn create_identity_provider_mapper(
+        &self,
+        identity: Identity,
+        input: CreateIdentityProviderMapperInput,
+    ) -> Result<IdentityProviderMapper, CoreError> {
+        let (realm, provider) = self
+            .resolve_provider(&input.realm_name, &input.alias)
+            .await?;
+        self.ensure_can_update(&identity, &realm).await?;
+        self.validate_config(&realm, &input.name, input.sync_mode, &input.config)
+            .await?;
+
+        self.mapper_repository
+            .create_mapper(CreateIdentityProviderMapperRequest {
+                realm_id: realm.id,
+                identity_provider_id: provider.id,
+                name: input.name.trim().to_string(),
+                sync_mode: input.sync_mode,
+                config: input.config,
+            })
+            .await
+    }
+
+    #[instrument(
+        skip(self, identity, input),
+        fields(realm.name = %input.realm_name, idp.alias = %input.alias, mapper.id = %input.mapper_id)
+    )]
+    async fn update_identity_provider_mapper(
+        &self,
+        identity: Identity,
+        input: UpdateIdentityProviderMapperInput,
+    ) -> Result<IdentityProviderMapper, CoreError> {
+        let (realm, provider) = self
+            .resolve_provider(&input.realm_name, &input.alias)
+            .await?;
+        self.ensure_can_update(&identity, &realm).await?;
+        let mapper = self.find_mapper(&provider, input.mapper_id).await?;
+        self.validate_config(&realm, &input.name, input.sync_mode, &input.config)
+            .await?;
+
+        self.mapper_repository
+            .update_mapper(UpdateIdentityProviderMapperRequest {
+                id: mapper.id,
+                name: input.name.trim().to_string(),
+                sync_mode: input.sync_mode,
+                config: input.config,
+            })
+            .await
+    }
+
+    #[instrument(
+        skip(self, identity, input),
+        fields(realm.name = %input.realm_name, idp.alias = %input.alias, mapper.id = %input.mapper_id)
+    )]
+    async fn delete_identity_provider_mapper(
+        &self,
+        identity: Identity,
+        input: DeleteIdentityProviderMapperInput,
+    ) -> Result<(), CoreError> {
+        let (realm, provider) = self
+            .resolve_provider(&input.realm_name, &input.alias)
+            .await?;
+        self.ensure_can_update(&identity, &realm).await?;
+        let mapper = self.find_mapper(&provider, input.mapper_id).await?;
+
+        self.mapper_repository.delete_mapper(mapper.id).await
+    }
+}
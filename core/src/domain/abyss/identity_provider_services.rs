@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use tracing::{instrument, warn};
+use chrono::Utc;
+use serde_json::Value as JsonValue;
+use tracing::{info, instrument, warn};
 use uuid::Uuid;
 
 use crate::domain::authentication::value_objects::Identity;
@@ -16,26 +18,43 @@ use crate::domain::user::entities::User;
 use crate::domain::user::ports::{UserPolicy, UserRepository};
 
 use crate::domain::abyss::identity_provider::broker::{
-    IdentityProviderLink, IdentityProviderLinkRepository,
+    FirstBrokerLoginFlow, IdentityProviderLink, IdentityProviderLinkRepository, OAuthClient,
+    OAuthProviderConfig, PostBrokerLoginFlow,
+};
+use crate::domain::abyss::identity_provider::discovery::{
+    ConfigurationCheck, DiscoveredIdentityProviderConfiguration, IdentityProviderValidationReport,
+    discovery_document_url,
 };
 use crate::domain::abyss::identity_provider::value_objects::{
     CreateIdentityProviderRequest, UpdateIdentityProviderRequest,
 };
 use crate::domain::abyss::identity_provider::{
     CreateIdentityProviderInput, DeleteIdentityProviderInput, DeleteIdentityProviderLinkInput,
-    GetIdentityProviderInput, IdentityProvider, IdentityProviderLinkView,
-    ListIdentityProviderLinksInput, ListIdentityProvidersInput, UpdateIdentityProviderInput,
+    DiscoverIdentityProviderInput, GetIdentityProviderInput, IdentityProvider,
+    IdentityProviderLinkView, ListIdentityProviderLinksInput, ListIdentityProvidersInput,
+    UpdateIdentityProviderInput,
 };
 use crate::domain::abyss::identity_provider::{
     IdentityProviderPolicy, IdentityProviderRepository, IdentityProviderService,
 };
+use crate::domain::abyss::saml_broker::{SAML_PROVIDER_ID, SamlProviderConfig, apply_idp_metadata};
+
+/// Rejects flow aliases no brokered login could run.
+fn validate_login_flows(
+    first_broker_login_flow_alias: Option<&str>,
+    post_broker_login_flow_alias: Option<&str>,
+) -> Result<(), CoreError> {
+    FirstBrokerLoginFlow::from_alias(first_broker_login_flow_alias)?;
+    PostBrokerLoginFlow::from_alias(post_broker_login_flow_alias)?;
+    Ok(())
+}
 
 /// Implementation of the IdentityProviderService trait
 ///
 /// Provides business logic for managing identity providers,
 /// including authorization checks and validation.
 #[derive(Clone, Debug)]
-pub struct IdentityProviderServiceImpl<R, P, RR, U, L, SE>
+pub struct IdentityProviderServiceImpl<R, P, RR, U, L, SE, OC>
 where
     R: IdentityProviderRepository,
     P: IdentityProviderPolicy + UserPolicy,
@@ -43,6 +62,7 @@ where
     U: UserRepository,
     L: IdentityProviderLinkRepository,
     SE: SecurityEventRepository,
+    OC: OAuthClient,
 {
     identity_provider_repository: Arc<R>,
     identity_provider_policy: Arc<P>,
@@ -50,9 +70,10 @@ where
     user_repository: Arc<U>,
     identity_provider_link_repository: Arc<L>,
     security_event_repository: Arc<SE>,
+    oauth_client: Arc<OC>,
 }
 
-impl<R, P, RR, U, L, SE> IdentityProviderServiceImpl<R, P, RR, U, L, SE>
+impl<R, P, RR, U, L, SE, OC> IdentityProviderServiceImpl<R, P, RR, U, L, SE, OC>
 where
     R: IdentityProviderRepository,
     P: IdentityProviderPolicy + UserPolicy,
@@ -60,6 +81,7 @@ where
     U: UserRepository,
     L: IdentityProviderLinkRepository,
     SE: SecurityEventRepository,
+    OC: OAuthClient,
 {
     /// Creates a new IdentityProviderServiceImpl
     ///
@@ -67,6 +89,7 @@ where
     /// * `identity_provider_repository` - The identity provider repository for data access
     /// * `identity_provider_policy` - The authorization policy for access control
     /// * `realm_repository` - The realm repository to resolve realm names
+    /// * `oauth_client` - The HTTP client used to read discovery documents and key sets
     pub fn new(
         identity_provider_repository: Arc<R>,
         identity_provider_policy: Arc<P>,
@@ -74,6 +97,7 @@ where
         user_repository: Arc<U>,
         identity_provider_link_repository: Arc<L>,
         security_event_repository: Arc<SE>,
+        oauth_client: Arc<OC>,
     ) -> Self {
         Self {
             identity_provider_repository,
@@ -82,7 +106,126 @@ where
             user_repository,
             identity_provider_link_repository,
             security_event_repository,
+            oauth_client,
+        }
+    }
+
+    /// Fetches the discovery document a configuration names, if any, and overwrites its
+    /// endpoints with the published ones.
+    async fn apply_discovery(&self, mut config: JsonValue) -> Result<JsonValue, CoreError> {
+        let Some(discovery_url) = config
+            .get("discovery_url")
+            .and_then(JsonValue::as_str)
+            .map(discovery_document_url)
+        else {
+            return Ok(config);
+        };
+
+        let document = self
+            .oauth_client
+            .fetch_discovery_document(&discovery_url)
+            .await?;
+        document.validate_issuer(&discovery_url)?;
+        document.apply_to(&mut config, &discovery_url, Utc::now())?;
+
+        Ok(config)
+    }
+
+    /// Reads what a configuration points at: the discovery document of OAuth providers, the
+    /// pasted metadata of SAML providers.
+    async fn resolve_config(
+        &self,
+        provider_id: &str,
+        mut config: JsonValue,
+    ) -> Result<JsonValue, CoreError> {
+        if provider_id == SAML_PROVIDER_ID {
+            apply_idp_metadata(&mut config)?;
+            return Ok(config);
+        }
+
+        self.apply_discovery(config).await
+    }
+
+    async fn run_validation_checks(&self, provider: &IdentityProvider) -> Vec<ConfigurationCheck> {
+        let mut checks = Vec::new();
+
+        if provider.provider_id == SAML_PROVIDER_ID {
+            checks.push(ConfigurationCheck::from_result(
+                "configuration",
+                SamlProviderConfig::try_from(provider.config.clone()).map(|config| {
+                    format!(
+                        "{} signing certificates for {}",
+                        config.signing_certificates.len(),
+                        config.idp_entity_id
+                    )
+                }),
+            ));
+            return checks;
+        }
+
+        let oauth_config = OAuthProviderConfig::try_from(provider.config.clone());
+        checks.push(ConfigurationCheck::from_result(
+            "configuration",
+            oauth_config
+                .as_ref()
+                .map(|_| "configuration is complete".to_string())
+                .map_err(Clone::clone),
+        ));
+        let Ok(oauth_config) = oauth_config else {
+            return checks;
+        };
+
+        if let Some(discovery_url) = &oauth_config.discovery_url {
+            let document = self
+                .oauth_client
+                .fetch_discovery_document(discovery_url)
+                .await
+                .and_then(|document| {
+                    document.validate_issuer(discovery_url)?;
+                    Ok(document)
+                });
+
+            checks.push(ConfigurationCheck::from_result(
+                "discovery",
+                document
+                    .as_ref()
+                    .map(|document| format!("issuer {}", document.issuer))
+                    .map_err(Clone::clone),
+            ));
+
+            if let Ok(document) = &document {
+                let method = oauth_config.client_auth_method;
+                checks.push(ConfigurationCheck::from_result(
+                    "client_authentication",
+                    if document.supports_auth_method(method) {
+                        Ok(format!("{} is supported", method.as_str()))
+                    } else {
+                        Err(CoreError::InvalidProviderConfiguration(format!(
+                            "{} is not supported by the token endpoint",
+                            method.as_str()
+                        )))
+                    },
+                ));
+            }
+        }
+
+        if let Some(jwks_url) = &oauth_config.jwks_url {
+            let keys = self
+                .oauth_client
+                .fetch_jwks(jwks_url)
+                .await
+                .and_then(
+                    |jwks| match jwks["keys"].as_array().map(Vec::len).unwrap_or(0) {
+                        0 => Err(CoreError::InvalidProviderConfiguration(
+                            "the key set contains no keys".to_string(),
+                        )),
+                        count => Ok(format!("{count} signing keys published")),
+                    },
+                );
+            checks.push(ConfigurationCheck::from_result("jwks", keys));
         }
+
+        checks
     }
 
     async fn resolve_user_for_link_management(
@@ -149,7 +292,8 @@ where
     }
 }
 
-impl<R, P, RR, U, L, SE> IdentityProviderService for IdentityProviderServiceImpl<R, P, RR, U, L, SE>
+impl<R, P, RR, U, L, SE, OC> IdentityProviderService
+    for IdentityProviderServiceImpl<R, P, RR, U, L, SE, OC>
 where
     R: IdentityProviderRepository,
     P: IdentityProviderPolicy + UserPolicy,
@@ -157,6 +301,7 @@ where
     U: UserRepository,
     L: IdentityProviderLinkRepository,
     SE: SecurityEventRepository,
+    OC: OAuthClient,
 {
     #[instrument(
         skip(self, identity, input),
@@ -197,6 +342,15 @@ where
             return Err(CoreError::ProviderNameAlreadyExists);
         }
 
+        validate_login_flows(
+            input.first_broker_login_flow_alias.as_deref(),
+            input.post_broker_login_flow_alias.as_deref(),
+        )?;
+
+        let config = self
+            .resolve_config(&input.provider_id, input.config)
+            .await?;
+
         // Create the identity provider
         let request = CreateIdentityProviderRequest {
             realm_id: realm.id,
@@ -210,7 +364,7 @@ where
             add_read_token_role_on_create: input.add_read_token_role_on_create,
             trust_email: input.trust_email,
             link_only: input.link_only,
-            config: input.config,
+            config,
         };
 
         self.identity_provider_repository
@@ -335,6 +489,16 @@ where
             "insufficient permissions to update identity provider",
         )?;
 
+        validate_login_flows(
+            input.first_broker_login_flow_alias.as_deref(),
+            input.post_broker_login_flow_alias.as_deref(),
+        )?;
+
+        let config = match input.config {
+            Some(config) => Some(self.resolve_config(&provider.provider_id, config).await?),
+            None => None,
+        };
+
         // Update the identity provider
         let request = UpdateIdentityProviderRequest {
             enabled: input.enabled,
@@ -345,7 +509,7 @@ where
             add_read_token_role_on_create: input.add_read_token_role_on_create,
             trust_email: input.trust_email,
             link_only: input.link_only,
-            config: input.config,
+            config,
         };
 
         self.identity_provider_repository
@@ -475,4 +639,121 @@ where
 
         Ok(())
     }
+
+    #[instrument(
+        skip(self, identity, input),
+        fields(
+            identity.id = %identity.id(),
+            identity.kind = %identity.kind(),
+            realm.name = %input.realm_name,
+        )
+    )]
+    async fn discover_identity_provider_configuration(
+        &self,
+        identity: Identity,
+        input: DiscoverIdentityProviderInput,
+    ) -> Result<DiscoveredIdentityProviderConfiguration, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&input.realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        ensure_policy(
+            self.identity_provider_policy
+                .can_create_identity_provider(&identity, &realm)
+                .await,
+            "insufficient permissions to create identity provider",
+        )?;
+
+        let discovery_url = discovery_document_url(&input.discovery_url);
+        let document = self
+            .oauth_client
+            .fetch_discovery_document(&discovery_url)
+            .await?;
+        document.validate_issuer(&discovery_url)?;
+
+        Ok(DiscoveredIdentityProviderConfiguration::new(
+            discovery_url,
+            document,
+        ))
+    }
+
+    #[instrument(
+        skip(self, identity, input),
+        fields(
+            identity.id = %identity.id(),
+            identity.kind = %identity.kind(),
+            realm.name = %input.realm_name,
+            provider.alias = %input.alias,
+        )
+    )]
+    async fn validate_identity_provider(
+        &self,
+        identity: Identity,
+        input: GetIdentityProviderInput,
+    ) -> Result<IdentityProviderValidationReport, CoreError> {
+        let provider = self.get_identity_provider(identity, input).await?;
+
+        Ok(IdentityProviderValidationReport::new(
+            self.run_validation_checks(&provider).await,
+        ))
+    }
+
+    #[instrument(skip(self))]
+    async fn refresh_discovery_metadata(&self) -> Result<u32, CoreError> {
+        let providers = self
+            .identity_provider_repository
+            .list_identity_providers_with_discovery()
+            .await?;
+
+        let mut refreshed = 0;
+        for provider in providers {
+            let config = match self.apply_discovery(provider.config.into_stored()).await {
+                Ok(config) => config,
+                Err(error) => {
+                    // The provider keeps its previous endpoints until the next refresh.
+                    warn!(
+                        provider.id = %provider.id,
+                        provider.alias = %provider.alias,
+                        error = %error,
+                        "Failed to refresh identity provider discovery metadata"
+                    );
+                    continue;
+                }
+            };
+
+            self.identity_provider_repository
+                .update_identity_provider(
+                    provider.id.into(),
+                    UpdateIdentityProviderRequest {
+                        config: Some(config),
+                        ..Default::default()
+                    },
+                )
+                .await?;
+            refreshed += 1;
+        }
+
+        Ok(refreshed)
+    }
+}
+
+/// Periodically re-read the discovery documents of the providers configured from one, so
+/// rotated endpoints are picked up without an administrator.
+pub async fn identity_provider_metadata_refresh_task<S>(service: S, period: std::time::Duration)
+where
+    S: IdentityProviderService,
+{
+    let mut ticker = tokio::time::interval(period);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+        match service.refresh_discovery_metadata().await {
+            Ok(0) => {}
+            Ok(refreshed) => info!(refreshed, "Refreshed identity provider discovery metadata"),
+            Err(error) => warn!(error = ?error, "Failed to refresh identity provider metadata"),
+        }
+    }
 }
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value as JsonValue;
+
+/// How long a fetched key set is trusted before it is fetched again.
+const JWKS_TTL: Duration = Duration::minutes(10);
+/// Minimum spacing between two fetches of the same key set; doubled after every failure.
+const REFETCH_BACKOFF: Duration = Duration::seconds(10);
+const MAX_REFETCH_BACKOFF: Duration = Duration::minutes(5);
+
+/// What the cache can do for an id_token signed with a given key.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JwksLookup {
+    /// Verify against these keys.
+    Cached(JsonValue),
+    /// Fetch the key set; the stale keys, if any, are the fallback when the fetch fails.
+    Refetch(Option<JsonValue>),
+    /// Nothing is cached and the last fetch failed too recently to try again.
+    Unavailable,
+}
+
+#[derive(Debug, Clone)]
+struct JwksEntry {
+    keys: Option<JsonValue>,
+    fetched_at: Option<DateTime<Utc>>,
+    last_attempt: DateTime<Utc>,
+    failures: u32,
+}
+
+impl JwksEntry {
+    fn backoff(&self) -> Duration {
+        let factor = 2_i32.saturating_pow(self.failures.min(16));
+        (REFETCH_BACKOFF * factor).min(MAX_REFETCH_BACKOFF)
+    }
+
+    fn has_kid(&self, kid: &str) -> bool {
+        self.keys
+            .as_ref()
+            .and_then(|keys| keys["keys"].as_array())
+            .is_some_and(|keys| keys.iter().any(|key| key["kid"].as_str() == Some(kid)))
+    }
+}
+
+/// Upstream identity provider key sets, shared by every broker login.
+///
+/// A key set is refetched when it expires or when an id_token names a key it does not
+/// contain (the provider rotated its keys), but never more often than the backoff allows,
+/// so forged `kid`s cannot turn logins into requests against the provider. When a fetch
+/// fails the previous keys keep being served.
+#[derive(Debug, Default)]
+pub struct JwksCache {
+    entries: Mutex<HashMap<String, JwksEntry>>,
+}
+
+impl JwksCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn lookup(&self, jwks_url: &str, kid: Option<&str>, now: DateTime<Utc>) -> JwksLookup {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(entry) = entries.get(jwks_url) else {
+            return JwksLookup::Refetch(None);
+        };
+
+        let fresh = entry
+            .fetched_at
+            .is_some_and(|fetched_at| now - fetched_at < JWKS_TTL);
+        let has_key = kid.is_none_or(|kid| entry.has_kid(kid));
+
+        if let Some(keys) = &entry.keys
+            && fresh
+            && has_key
+        {
+            return JwksLookup::Cached(keys.clone());
+        }
+
+        if now - entry.last_attempt < entry.backoff() {
+            return match &entry.keys {
+                Some(keys) => JwksLookup::Cached(keys.clone()),
+                None => JwksLookup::Unavailable,
+            };
+        }
+
+        JwksLookup::Refetch(entry.keys.clone())
+    }
+
+    pub fn store(&self, jwks_url: &str, keys: JsonValue, now: DateTime<Utc>) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.insert(
+            jwks_url.to_string(),
+            JwksEntry {
+                keys: Some(keys),
+                fetched_at: Some(now),
+                last_attempt: now,
+                failures: 0,
+            },
+        );
+    }
+
+    pub fn record_failure(&self, jwks_url: &str, now: DateTime<Utc>) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = entries
+            .entry(jwks_url.to_string())
+            .or_insert_with(|| JwksEntry {
+                keys: None,
+                fetched_at: None,
+                last_attempt: now,
+                failures: 0,
+            });
+
+        entry.last_attempt = now;
+        entry.failures = entry.failures.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const URL: &str = "https://idp.example.com/keys";
+
+    fn keys(kid: &str) -> JsonValue {
+        json!({ "keys": [{ "kty": "RSA", "kid": kid }] })
+    }
+
+    #[test]
+    fn test_serves_fresh_keys_and_refetches_once_expired() {
+        let cache = JwksCache::new();
+        let now = Utc::now();
+
+        assert_eq!(cache.lookup(URL, Some("a"), now), JwksLookup::Refetch(None));
+
+        cache.store(URL, keys("a"), now);
+        assert_eq!(
+            cache.lookup(URL, Some("a"), now + Duration::minutes(5)),
+            JwksLookup::Cached(keys("a"))
+        );
+        assert_eq!(
+            cache.lookup(URL, Some("a"), now + Duration::minutes(11)),
+            JwksLookup::Refetch(Some(keys("a")))
+        );
+    }
+
+    #[test]
+    fn test_unknown_kid_refetches_only_after_backoff() {
+        let cache = JwksCache::new();
+        let now = Utc::now();
+        cache.store(URL, keys("a"), now);
+
+        assert_eq!(
+            cache.lookup(URL, Some("rotated"), now + Duration::seconds(1)),
+            JwksLookup::Cached(keys("a"))
+        );
+        assert_eq!(
+            cache.lookup(URL, Some("rotated"), now + Duration::seconds(11)),
+            JwksLookup::Refetch(Some(keys("a")))
+        );
+    }
+
+    #[test]
+    fn test_failures_keep_stale_keys_and_back_off() {
+        let cache = JwksCache::new();
+        let now = Utc::now();
+        cache.store(URL, keys("a"), now);
+
+        let expired = now + Duration::minutes(11);
+        cache.record_failure(URL, expired);
+        cache.record_failure(URL, expired);
+
+        // Two failures: 40 seconds before the next attempt.
+        assert_eq!(
+            cache.lookup(URL, Some("a"), expired + Duration::seconds(30)),
+            JwksLookup::Cached(keys("a"))
+        );
+        assert_eq!(
+            cache.lookup(URL, Some("a"), expired + Duration::seconds(41)),
+            JwksLookup::Refetch(Some(keys("a")))
+        );
+    }
+
+    #[test]
+    fn test_failure_without_keys_is_unavailable_during_backoff() {
+        let cache = JwksCache::new();
+        let now = Utc::now();
+        cache.record_failure(URL, now);
+
+        assert_eq!(
+            cache.lookup(URL, None, now + Duration::seconds(5)),
+            JwksLookup::Unavailable
+        );
+        assert_eq!(
+            cache.lookup(URL, None, now + Duration::seconds(25)),
+            JwksLookup::Refetch(None)
+        );
+    }
+}
@@ -1,8 +1,16 @@
+pub mod broker_login_verification;
+pub mod broker_mapping;
 pub mod broker_services;
 pub mod federation;
 pub mod identity_provider;
+pub mod identity_provider_mapper_services;
 pub mod identity_provider_policies;
 pub mod identity_provider_services;
+pub mod jwks_cache;
+pub mod saml_broker;
 
+pub use broker_login_verification::BrokerLoginVerification;
+pub use broker_mapping::BrokeredUserMapping;
 pub use broker_services::BrokerServiceImpl;
+pub use identity_provider_mapper_services::IdentityProviderMapperServiceImpl;
 pub use identity_provider_services::IdentityProviderServiceImpl;
@@ -0,0 +1,598 @@
+//! SAML 2.0 toward upstream identity providers: the realm acts as service provider, sends
+//! `AuthnRequest`s over HTTP-Redirect and takes the responses over HTTP-POST at the same
+//! broker endpoint OAuth providers call back.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
+
+use crate::domain::abyss::identity_provider::IdentityProviderConfig;
+use crate::domain::abyss::identity_provider::broker::{BrokeredUserInfo, ProfileField};
+use crate::domain::authentication::saml::entities::{NameIdFormat, SamlBinding};
+use crate::domain::authentication::saml::metadata::parse_idp_metadata;
+use crate::domain::authentication::saml::protocol::{
+    BEARER, ReceivedAssertion, ReceivedResponse, STATUS_SUCCESS,
+};
+use crate::domain::authentication::saml::signature::ReceivedSignature;
+use crate::domain::authentication::saml::xml::invalid;
+use crate::domain::common::entities::app_errors::CoreError;
+
+/// `provider_id` of identity providers spoken to over SAML.
+pub const SAML_PROVIDER_ID: &str = "saml";
+
+const DEFAULT_CLOCK_SKEW_SECONDS: i64 = 60;
+
+/// Attribute names tried when the mapping names none, covering LDAP-style names, their
+/// OIDs (Shibboleth) and the WS-Federation claim types ADFS sends.
+const EMAIL_ATTRIBUTES: &[&str] = &[
+    "email",
+    "mail",
+    "urn:oid:0.9.2342.19200300.100.1.3",
+    "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/emailaddress",
+];
+const USERNAME_ATTRIBUTES: &[&str] = &[
+    "username",
+    "uid",
+    "urn:oid:0.9.2342.19200300.100.1.1",
+    "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/upn",
+];
+const FIRST_NAME_ATTRIBUTES: &[&str] = &[
+    "givenName",
+    "firstName",
+    "urn:oid:2.5.4.42",
+    "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/givenname",
+];
+const LAST_NAME_ATTRIBUTES: &[&str] = &[
+    "sn",
+    "surname",
+    "lastName",
+    "urn:oid:2.5.4.4",
+    "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/surname",
+];
+const FULL_NAME_ATTRIBUTES: &[&str] = &[
+    "displayName",
+    "cn",
+    "urn:oid:2.16.840.1.113730.3.1.241",
+    "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/name",
+];
+
+/// Assertion attributes read into the brokered profile; unset ones fall back to the names
+/// the common identity providers use.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SamlAttributeMapping {
+    pub email: Option<String>,
+    pub username: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub full_name: Option<String>,
+}
+
+fn enabled() -> bool {
+    true
+}
+
+fn default_clock_skew() -> i64 {
+    DEFAULT_CLOCK_SKEW_SECONDS
+}
+
+/// SAML provider configuration extracted from the identity provider's config JSONB
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SamlProviderConfig {
+    /// Entity ID of the identity provider, the issuer of its responses
+    pub idp_entity_id: String,
+
+    /// HTTP-Redirect single sign-on endpoint
+    pub sso_url: String,
+
+    /// Certificates the identity provider signs with (base64 DER); any of them verifies
+    #[serde(default)]
+    pub signing_certificates: Vec<String>,
+
+    /// Entity ID the realm presents; defaults to the URL of its service provider metadata
+    #[serde(default)]
+    pub sp_entity_id: Option<String>,
+
+    /// NameID format asked for in the `NameIDPolicy`
+    #[serde(default)]
+    pub name_id_format: Option<NameIdFormat>,
+
+    /// Sign `AuthnRequest`s with the realm's key
+    #[serde(default = "enabled")]
+    pub sign_authn_requests: bool,
+
+    /// Reject responses whose assertion is not signed itself
+    #[serde(default = "enabled")]
+    pub want_assertions_signed: bool,
+
+    /// Ask the identity provider to authenticate the user again
+    #[serde(default)]
+    pub force_authn: bool,
+
+    /// Attribute identifying the user instead of the NameID, for providers sending
+    /// transient NameIDs
+    #[serde(default)]
+    pub principal_attribute: Option<String>,
+
+    #[serde(default)]
+    pub attribute_mapping: SamlAttributeMapping,
+
+    /// Tolerated clock difference with the identity provider when checking validity windows
+    #[serde(default = "default_clock_skew")]
+    pub allowed_clock_skew_seconds: i64,
+
+    /// Profile fields a new account needs; missing ones are asked for on first login
+    #[serde(default)]
+    pub required_profile_fields: Vec<ProfileField>,
+}
+
+impl TryFrom<IdentityProviderConfig> for SamlProviderConfig {
+    type Error = CoreError;
+
+    fn try_from(config: IdentityProviderConfig) -> Result<Self, Self::Error> {
+        let config: SamlProviderConfig = serde_json::from_value(config.extra).map_err(|e| {
+            CoreError::InvalidProviderConfiguration(format!(
+                "Failed to parse SAML provider config: {}",
+                e
+            ))
+        })?;
+
+        if config.signing_certificates.is_empty() {
+            return Err(CoreError::InvalidProviderConfiguration(
+                "Missing signing_certificates".to_string(),
+            ));
+        }
+
+        Ok(config)
+    }
+}
+
+/// The broker endpoint, which is the assertion consumer service of SAML providers.
+pub fn acs_url(base_url: &str, realm_name: &str, alias: &str) -> String {
+    format!("{base_url}/realms/{realm_name}/broker/{alias}/endpoint")
+}
+
+impl SamlProviderConfig {
+    pub fn sp_entity_id(&self, base_url: &str, realm_name: &str, alias: &str) -> String {
+        self.sp_entity_id
+            .clone()
+            .unwrap_or_else(|| format!("{}/descriptor", acs_url(base_url, realm_name, alias)))
+    }
+
+    fn clock_skew(&self) -> Duration {
+        Duration::seconds(self.allowed_clock_skew_seconds.max(0))
+    }
+}
+
+/// Replace the `metadata_xml` of a SAML provider configuration, if it has one, with the
+/// entity ID, single sign-on endpoint and signing certificates it publishes. Every other
+/// setting is kept.
+pub fn apply_idp_metadata(config: &mut JsonValue) -> Result<(), CoreError> {
+    let Some(settings) = config.as_object_mut() else {
+        return Ok(());
+    };
+    let Some(metadata) = settings.remove("metadata_xml") else {
+        return Ok(());
+    };
+    let metadata = metadata.as_str().ok_or_else(|| {
+        CoreError::InvalidProviderConfiguration("metadata_xml must be a string".to_string())
+    })?;
+
+    let entity_id = settings.get("idp_entity_id").and_then(JsonValue::as_str);
+    let metadata = parse_idp_metadata(metadata, entity_id)
+        .map_err(|e| CoreError::InvalidProviderConfiguration(e.to_string()))?;
+
+    let sso = metadata
+        .single_sign_on_services
+        .iter()
+        .find(|endpoint| endpoint.binding == SamlBinding::HttpRedirect)
+        .ok_or_else(|| {
+            CoreError::InvalidProviderConfiguration(
+                "identity provider has no HTTP-Redirect single sign-on service".to_string(),
+            )
+        })?;
+    if metadata.signing_certificates.is_empty() {
+        return Err(CoreError::InvalidProviderConfiguration(
+            "identity provider metadata lists no signing certificate".to_string(),
+        ));
+    }
+
+    settings.insert(
+        "idp_entity_id".to_string(),
+        JsonValue::String(metadata.entity_id.clone()),
+    );
+    settings.insert(
+        "sso_url".to_string(),
+        JsonValue::String(sso.location.clone()),
+    );
+    settings.insert(
+        "signing_certificates".to_string(),
+        JsonValue::from(metadata.signing_certificates.clone()),
+    );
+    if metadata.want_authn_requests_signed {
+        settings.insert("sign_authn_requests".to_string(), JsonValue::Bool(true));
+    }
+
+    Ok(())
+}
+
+/// What a response must match to be accepted.
+pub struct ExpectedResponse<'a> {
+    pub idp_entity_id: &'a str,
+    pub sp_entity_id: &'a str,
+    pub acs_url: &'a str,
+    /// ID of the `AuthnRequest` the broker session was opened with.
+    pub request_id: &'a str,
+    pub now: DateTime<Utc>,
+}
+
+/// The signatures a response must be verified with: the assertion's when the provider
+/// requires signed assertions, otherwise the response's or the assertion's. Every
+/// signature present is returned, and all of them have to verify.
+pub fn signatures_to_verify<'r>(
+    config: &SamlProviderConfig,
+    response: &'r ReceivedResponse,
+    assertion: &'r ReceivedAssertion,
+) -> Result<Vec<&'r ReceivedSignature>, CoreError> {
+    if config.want_assertions_signed && assertion.signature.is_none() {
+        return Err(invalid("assertion must be signed"));
+    }
+
+    let signatures: Vec<&ReceivedSignature> = [&response.signature, &assertion.signature]
+        .into_iter()
+        .flatten()
+        .collect();
+    if signatures.is_empty() {
+        return Err(invalid("response is not signed"));
+    }
+
+    Ok(signatures)
+}
+
+/// Check the status, issuer, destination, validity window, audience and subject
+/// confirmation of a response, and return its assertion.
+pub fn validate_response<'r>(
+    config: &SamlProviderConfig,
+    response: &'r ReceivedResponse,
+    expected: &ExpectedResponse,
+) -> Result<&'r ReceivedAssertion, CoreError> {
+    if response.status != STATUS_SUCCESS {
+        return Err(CoreError::IdpAuthenticationFailed(format!(
+            "{}: {}",
+            response.status,
+            response.status_message.as_deref().unwrap_or("")
+        )));
+    }
+    if response.in_response_to.as_deref() != Some(expected.request_id) {
+        return Err(invalid("response does not answer the pending request"));
+    }
+    if response
+        .destination
+        .as_deref()
+        .is_some_and(|destination| destination != expected.acs_url)
+    {
+        return Err(invalid("response destination is not this service provider"));
+    }
+    if response
+        .issuer
+        .as_deref()
+        .is_some_and(|issuer| issuer != expected.idp_entity_id)
+    {
+        return Err(invalid("response issuer is not the identity provider"));
+    }
+
+    let assertion = response
+        .assertion
+        .as_ref()
+        .ok_or_else(|| invalid("response carries no assertion"))?;
+    if assertion.issuer != expected.idp_entity_id {
+        return Err(invalid("assertion issuer is not the identity provider"));
+    }
+
+    let skew = config.clock_skew();
+    let now = expected.now;
+    let not_yet = |not_before: Option<DateTime<Utc>>| not_before.is_some_and(|at| now + skew < at);
+    let expired =
+        |not_on_or_after: Option<DateTime<Utc>>| not_on_or_after.is_some_and(|at| now - skew >= at);
+
+    if not_yet(assertion.not_before) || expired(assertion.not_on_or_after) {
+        return Err(invalid("assertion is outside its validity window"));
+    }
+
+    if assertion.audience_restrictions.is_empty()
+        || !assertion.audience_restrictions.iter().all(|audiences| {
+            audiences
+                .iter()
+                .any(|audience| audience == expected.sp_entity_id)
+        })
+    {
+        return Err(invalid(
+            "assertion is not intended for this service provider",
+        ));
+    }
+
+    let confirmed = assertion.confirmations.iter().any(|confirmation| {
+        confirmation.method == BEARER
+            && confirmation.recipient.as_deref() == Some(expected.acs_url)
+            && confirmation.in_response_to.as_deref() == Some(expected.request_id)
+            && confirmation.not_on_or_after.is_some()
+            && !expired(confirmation.not_on_or_after)
+            && !not_yet(confirmation.not_before)
+    });
+    if !confirmed {
+        return Err(invalid(
+            "assertion has no valid bearer subject confirmation",
+        ));
+    }
+
+    Ok(assertion)
+}
+
+/// The brokered profile an assertion describes. Every attribute is kept in the claims
+/// for identity provider mappers, multi-valued ones as arrays.
+pub fn user_info_from_assertion(
+    config: &SamlProviderConfig,
+    assertion: &ReceivedAssertion,
+) -> Result<BrokeredUserInfo, CoreError> {
+    let attribute = |configured: &Option<String>, defaults: &[&str]| -> Option<String> {
+        let first_value = |name: &str| {
+            assertion
+                .attributes
+                .iter()
+                .find(|(attribute, _)| attribute == name)
+                .and_then(|(_, values)| values.first().cloned())
+        };
+        match configured {
+            Some(name) => first_value(name),
+            None => defaults.iter().find_map(|name| first_value(name)),
+        }
+    };
+
+    let subject = match &config.principal_attribute {
+        Some(name) => attribute(&Some(name.clone()), &[])
+            .ok_or_else(|| invalid(format!("assertion has no {name} attribute")))?,
+        None => {
+            if assertion.name_id_format.as_deref() == Some(NameIdFormat::Transient.uri()) {
+                return Err(CoreError::InvalidProviderConfiguration(
+                    "transient NameIDs change on every login; configure a principal_attribute"
+                        .to_string(),
+                ));
+            }
+            assertion
+                .name_id
+                .clone()
+                .ok_or_else(|| invalid("assertion has no NameID"))?
+        }
+    };
+
+    let email = attribute(&config.attribute_mapping.email, EMAIL_ATTRIBUTES).or_else(|| {
+        (assertion.name_id_format.as_deref() == Some(NameIdFormat::EmailAddress.uri()))
+            .then(|| assertion.name_id.clone())
+            .flatten()
+    });
+
+    let mut claims = Map::new();
+    for (name, values) in &assertion.attributes {
+        let value = match values.as_slice() {
+            [value] => JsonValue::String(value.clone()),
+            values => JsonValue::from(values.to_vec()),
+        };
+        claims.insert(name.clone(), value);
+    }
+    for (name, value) in [
+        ("name_id", &assertion.name_id),
+        ("name_id_format", &assertion.name_id_format),
+        ("session_index", &assertion.session_index),
+    ] {
+        if let Some(value) = value {
+            claims.insert(name.to_string(), JsonValue::String(value.clone()));
+        }
+    }
+
+    Ok(BrokeredUserInfo {
+        subject,
+        email,
+        email_verified: None,
+        name: attribute(&config.attribute_mapping.full_name, FULL_NAME_ATTRIBUTES),
+        given_name: attribute(&config.attribute_mapping.first_name, FIRST_NAME_ATTRIBUTES),
+        family_name: attribute(&config.attribute_mapping.last_name, LAST_NAME_ATTRIBUTES),
+        preferred_username: attribute(&config.attribute_mapping.username, USERNAME_ATTRIBUTES),
+        picture: None,
+        claims: JsonValue::Object(claims),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::authentication::saml::entities::SamlSignatureAlgorithm;
+    use crate::domain::authentication::saml::protocol::SubjectConfirmation;
+
+    const ACS: &str = "https://auth.example.com/realms/demo/broker/adfs/endpoint";
+    const SP: &str = "https://auth.example.com/realms/demo/broker/adfs/endpoint/descriptor";
+    const IDP: &str = "http://adfs.example.com/adfs/services/trust";
+
+    fn config() -> SamlProviderConfig {
+        SamlProviderConfig::try_from(IdentityProviderConfig {
+            client_id: None,
+            client_secret: None,
+            extra: json!({
+                "idp_entity_id": IDP,
+                "sso_url": "https://adfs.example.com/adfs/ls/",
+                "signing_certificates": ["MIIC"],
+            }),
+        })
+        .unwrap()
+    }
+
+    fn response(now: DateTime<Utc>) -> ReceivedResponse {
+        ReceivedResponse {
+            id: "_response".to_string(),
+            issuer: Some(IDP.to_string()),
+            destination: Some(ACS.to_string()),
+            in_response_to: Some("_request".to_string()),
+            status: STATUS_SUCCESS.to_string(),
+            status_message: None,
+            signature: None,
+            assertion: Some(ReceivedAssertion {
+                id: "_assertion".to_string(),
+                issuer: IDP.to_string(),
+                signature: Some(ReceivedSignature {
+                    algorithm: SamlSignatureAlgorithm::RsaSha256,
+                    signed_info: Vec::new(),
+                    signature: Vec::new(),
+                }),
+                name_id: Some("ferris@example.com".to_string()),
+                name_id_format: Some(NameIdFormat::EmailAddress.uri().to_string()),
+                confirmations: vec![SubjectConfirmation {
+                    method: BEARER.to_string(),
+                    recipient: Some(ACS.to_string()),
+                    in_response_to: Some("_request".to_string()),
+                    not_before: None,
+                    not_on_or_after: Some(now + Duration::minutes(5)),
+                }],
+                not_before: Some(now),
+                not_on_or_after: Some(now + Duration::minutes(5)),
+                audience_restrictions: vec![vec![SP.to_string()]],
+                session_index: Some("_session".to_string()),
+                attributes: vec![
+                    (
+                        "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/givenname"
+                            .to_string(),
+                        vec!["Ferris".to_string()],
+                    ),
+                    (
+                        "http://schemas.microsoft.com/ws/2008/06/identity/claims/groups"
+                            .to_string(),
+                        vec!["admins".to_string(), "devs".to_string()],
+                    ),
+                ],
+            }),
+        }
+    }
+
+    fn expected(now: DateTime<Utc>) -> ExpectedResponse<'static> {
+        ExpectedResponse {
+            idp_entity_id: IDP,
+            sp_entity_id: SP,
+            acs_url: ACS,
+            request_id: "_request",
+            now,
+        }
+    }
+
+    #[test]
+    fn test_validate_response_checks_conditions() {
+        let config = config();
+        let now = Utc::now();
+
+        assert!(validate_response(&config, &response(now), &expected(now)).is_ok());
+        // Within the allowed clock skew
+        assert!(
+            validate_response(
+                &config,
+                &response(now + Duration::seconds(30)),
+                &expected(now)
+            )
+            .is_ok()
+        );
+
+        let mut other_audience = response(now);
+        other_audience
+            .assertion
+            .as_mut()
+            .unwrap()
+            .audience_restrictions = vec![vec!["https://other.example.com".to_string()]];
+        assert!(validate_response(&config, &other_audience, &expected(now)).is_err());
+
+        assert!(
+            validate_response(
+                &config,
+                &response(now),
+                &expected(now + Duration::minutes(7))
+            )
+            .is_err()
+        );
+
+        let mut replayed = response(now);
+        replayed.in_response_to = Some("_earlier_request".to_string());
+        assert!(validate_response(&config, &replayed, &expected(now)).is_err());
+
+        let mut failed = response(now);
+        failed.status = "urn:oasis:names:tc:SAML:2.0:status:Responder".to_string();
+        assert!(matches!(
+            validate_response(&config, &failed, &expected(now)),
+            Err(CoreError::IdpAuthenticationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_signed_assertions_are_required_by_default() {
+        let mut config = config();
+        let now = Utc::now();
+        let mut response = response(now);
+        let signature = response.assertion.as_mut().unwrap().signature.take();
+        response.signature = signature;
+        let assertion = response.assertion.clone().unwrap();
+
+        assert!(signatures_to_verify(&config, &response, &assertion).is_err());
+
+        config.want_assertions_signed = false;
+        assert_eq!(
+            signatures_to_verify(&config, &response, &assertion)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_user_info_from_adfs_claims() {
+        let now = Utc::now();
+        let response = response(now);
+        let user_info =
+            user_info_from_assertion(&config(), response.assertion.as_ref().unwrap()).unwrap();
+
+        assert_eq!(user_info.subject, "ferris@example.com");
+        assert_eq!(user_info.email.as_deref(), Some("ferris@example.com"));
+        assert_eq!(user_info.given_name.as_deref(), Some("Ferris"));
+        assert_eq!(
+            user_info.claims["http://schemas.microsoft.com/ws/2008/06/identity/claims/groups"],
+            json!(["admins", "devs"])
+        );
+        assert_eq!(user_info.claims["session_index"], "_session");
+    }
+
+    #[test]
+    fn test_apply_idp_metadata_keeps_other_settings() {
+        let mut config = json!({
+            "sign_authn_requests": false,
+            "principal_attribute": "urn:oid:1.3.6.1.4.1.5923.1.1.1.6",
+            "metadata_xml": r#"<md:EntityDescriptor xmlns:md="urn:oasis:names:tc:SAML:2.0:metadata" entityID="https://idp.example.org/idp/shibboleth">
+  <md:IDPSSODescriptor protocolSupportEnumeration="urn:oasis:names:tc:SAML:2.0:protocol" WantAuthnRequestsSigned="true">
+    <md:KeyDescriptor><ds:KeyInfo xmlns:ds="http://www.w3.org/2000/09/xmldsig#"><ds:X509Data><ds:X509Certificate>MIIC</ds:X509Certificate></ds:X509Data></ds:KeyInfo></md:KeyDescriptor>
+    <md:SingleSignOnService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect" Location="https://idp.example.org/idp/profile/SAML2/Redirect/SSO"/>
+  </md:IDPSSODescriptor>
+</md:EntityDescriptor>"#,
+        });
+
+        apply_idp_metadata(&mut config).unwrap();
+
+        assert!(config.get("metadata_xml").is_none());
+        assert_eq!(
+            config["idp_entity_id"],
+            "https://idp.example.org/idp/shibboleth"
+        );
+        assert_eq!(
+            config["sso_url"],
+            "https://idp.example.org/idp/profile/SAML2/Redirect/SSO"
+        );
+        assert_eq!(config["signing_certificates"], json!(["MIIC"]));
+        assert_eq!(config["sign_authn_requests"], true);
+        assert_eq!(
+            config["principal_attribute"],
+            "urn:oid:1.3.6.1.4.1.5923.1.1.1.6"
+        );
+    }
+}
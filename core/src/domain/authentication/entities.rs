@@ -1,9 +1,7 @@
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use webauthn_rs::prelude::{
-    DiscoverableAuthentication, PasskeyAuthentication, PasskeyRegistration,
-};
+use webauthn_rs_core::proto::{AuthenticationState, RegistrationState};
 
 use crate::domain::authentication::value_objects::CodeChallengeMethod;
 use crate::domain::common::generate_timestamp;
@@ -24,9 +22,9 @@ pub use ferriskey_domain::authentication::entities::{
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum WebAuthnChallenge {
-    Registration(PasskeyRegistration),
-    Authentication(PasskeyAuthentication),
-    DiscoverableAuthentication(DiscoverableAuthentication),
+    Registration(RegistrationState),
+    Authentication(AuthenticationState),
+    DiscoverableAuthentication(AuthenticationState),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,6 +99,8 @@ impl AuthSession {
 pub struct AuthOutput {
     pub login_url: String,
     pub session: AuthSession,
+    /// The realm has an enabled Kerberos provider, so browsers can be offered SPNEGO.
+    pub negotiate: bool,
 }
 
 pub struct AuthorizeRequestInput {
@@ -38,9 +38,10 @@ pub struct ContextOrganization {
 #[derive(Debug, Clone)]
 pub struct ContextGroup {
     pub id: Uuid,
-    /// Organization the group is scoped to. Lets the mapper prefix paths with the org alias
-    /// (resolved from `MapperContext::organizations`) to disambiguate multi-org tokens.
-    pub organization_id: Uuid,
+    /// Organization the group is scoped to; `None` for realm-level groups. Lets the mapper
+    /// prefix org group paths with the org alias (resolved from `MapperContext::organizations`)
+    /// to disambiguate multi-org tokens.
+    pub organization_id: Option<Uuid>,
     pub name: String,
     /// Full path from the root, e.g. `/engineering/backend`.
     pub path: String,
@@ -70,7 +71,7 @@ pub struct MapperContext {
     pub user_attributes: HashMap<String, Value>,
     /// Organizations the user belongs to, with their attributes pre-loaded.
     pub organizations: Vec<ContextOrganization>,
-    /// Groups the user effectively belongs to (recursive), with full paths.
+    /// Organization and realm groups the user effectively belongs to (recursive), with full paths.
     pub groups: Vec<ContextGroup>,
 }
 
@@ -28,6 +28,8 @@ use super::super::mapper_engine::{
 /// group's org alias (`/acme/finance`), disambiguating multi-org tokens. Only applies to full
 /// paths. Defaults to `false` (Keycloak-compatible).
 ///
+/// Realm-level groups are emitted alongside organization groups and are never prefixed.
+///
 /// ## Config
 ///
 /// ```json
@@ -100,7 +102,7 @@ impl GroupMembershipMapper {
                     && let Some(alias) = context
                         .organizations
                         .iter()
-                        .find(|org| org.id.as_uuid() == group.organization_id)
+                        .find(|org| Some(org.id.as_uuid()) == group.organization_id)
                         .map(|org| org.alias.as_str())
                 {
                     return Value::String(format!("/{}{}", alias, group.path));
@@ -135,7 +137,7 @@ mod tests {
         let name = path.rsplit('/').next().unwrap_or(path).to_string();
         ContextGroup {
             id: Uuid::new_v4(),
-            organization_id: org_id(),
+            organization_id: Some(org_id()),
             name,
             path: path.to_string(),
             direct,
@@ -248,6 +250,26 @@ mod tests {
         assert_eq!(groups_claim(&out), vec!["/acme/novotel/EN/novotel-ld-01"]);
     }
 
+    #[test]
+    fn prefix_org_leaves_realm_groups_unprefixed() {
+        let mut ctx = context();
+        let mut staff = group("/staff", true);
+        staff.organization_id = None;
+        ctx.groups.push(staff);
+
+        let out = GroupMembershipMapper
+            .execute(
+                &json!({ "membership": "direct", "prefix.org": "true" }),
+                &ctx,
+                TokenType::AccessToken,
+            )
+            .unwrap();
+        assert_eq!(
+            groups_claim(&out),
+            vec!["/acme/novotel/EN/novotel-ld-01", "/staff"]
+        );
+    }
+
     #[test]
     fn prefix_org_is_off_by_default() {
         let out = GroupMembershipMapper
@@ -12,7 +12,8 @@ use super::super::mapper_engine::{
 /// The org-scoped roles come from two sources, merged upstream in token assembly:
 /// roles assigned directly to the membership, and roles inherited from the user's groups in
 /// that organization. Realm roles land under `roles`; client roles under
-/// `clients.<client_id>.roles`.
+/// `clients.<client_id>.roles`. Delegated administration roles (`org-owner`, `org-admin`,
+/// `org-member-manager`) are plain realm roles and show up under `roles` like any other.
 ///
 /// This mapper writes into the same `organizations` claim as the membership mapper, so when both
 /// are enabled the objects deep-merge (membership contributes `id`/`name`/`alias`, this one
@@ -4,6 +4,7 @@ pub mod login_resolver;
 pub mod mapper_engine;
 pub mod mappers;
 pub mod ports;
+pub mod saml;
 pub mod scope;
 pub mod services;
 pub mod token_exchange;
@@ -0,0 +1,332 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::authentication::entities::AuthSession;
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+
+/// `client.protocol` of clients that are SAML service providers.
+pub const SAML_PROTOCOL: &str = "saml";
+/// `response_type` of the auth sessions opened for SAML logins.
+pub const SAML_RESPONSE_TYPE: &str = "saml";
+
+pub const DEFAULT_ASSERTION_LIFETIME_SECONDS: i64 = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SamlBinding {
+    HttpRedirect,
+    HttpPost,
+}
+
+impl SamlBinding {
+    pub fn uri(self) -> &'static str {
+        match self {
+            SamlBinding::HttpRedirect => "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect",
+            SamlBinding::HttpPost => "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST",
+        }
+    }
+
+    pub fn from_uri(uri: &str) -> Option<Self> {
+        [SamlBinding::HttpRedirect, SamlBinding::HttpPost]
+            .into_iter()
+            .find(|binding| binding.uri() == uri)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NameIdFormat {
+    /// The username.
+    #[default]
+    Unspecified,
+    EmailAddress,
+    /// The user id, stable across sessions.
+    Persistent,
+    /// A fresh identifier for every assertion.
+    Transient,
+}
+
+impl NameIdFormat {
+    pub const ALL: [NameIdFormat; 4] = [
+        NameIdFormat::Unspecified,
+        NameIdFormat::EmailAddress,
+        NameIdFormat::Persistent,
+        NameIdFormat::Transient,
+    ];
+
+    pub fn uri(self) -> &'static str {
+        match self {
+            NameIdFormat::Unspecified => "urn:oasis:names:tc:SAML:1.1:nameid-format:unspecified",
+            NameIdFormat::EmailAddress => "urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress",
+            NameIdFormat::Persistent => "urn:oasis:names:tc:SAML:2.0:nameid-format:persistent",
+            NameIdFormat::Transient => "urn:oasis:names:tc:SAML:2.0:nameid-format:transient",
+        }
+    }
+
+    pub fn from_uri(uri: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| format.uri() == uri)
+    }
+}
+
+impl fmt::Display for NameIdFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NameIdFormat::Unspecified => write!(f, "unspecified"),
+            NameIdFormat::EmailAddress => write!(f, "email_address"),
+            NameIdFormat::Persistent => write!(f, "persistent"),
+            NameIdFormat::Transient => write!(f, "transient"),
+        }
+    }
+}
+
+impl FromStr for NameIdFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unspecified" => Ok(NameIdFormat::Unspecified),
+            "email_address" => Ok(NameIdFormat::EmailAddress),
+            "persistent" => Ok(NameIdFormat::Persistent),
+            "transient" => Ok(NameIdFormat::Transient),
+            _ => Err(format!("unknown name id format: {s}")),
+        }
+    }
+}
+
+/// A service provider endpoint, as listed in its metadata.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SamlEndpoint {
+    pub binding: SamlBinding,
+    pub location: String,
+}
+
+/// SAML settings of a client whose `protocol` is `saml`. The client's `client_id` is the
+/// service provider's entity ID.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SamlClientSettings {
+    pub client_id: Uuid,
+    pub realm_id: RealmId,
+    /// Assertion consumer services; the first one is used when a request names none.
+    pub assertion_consumer_services: Vec<SamlEndpoint>,
+    pub single_logout_service: Option<SamlEndpoint>,
+    pub name_id_format: NameIdFormat,
+    pub sign_response: bool,
+    pub sign_assertions: bool,
+    pub encrypt_assertions: bool,
+    /// Reject authentication and logout requests that are not signed with
+    /// `signing_certificate`.
+    pub require_signed_requests: bool,
+    /// Certificate the service provider signs its requests with (base64 DER).
+    pub signing_certificate: Option<String>,
+    /// Certificate assertions are encrypted for (base64 DER).
+    pub encryption_certificate: Option<String>,
+    /// Enables IdP-initiated login at `/protocol/saml/clients/{idp_initiated_sso_url_name}`.
+    pub idp_initiated_sso_url_name: Option<String>,
+    pub idp_initiated_relay_state: Option<String>,
+    pub assertion_lifetime_seconds: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SamlClientSettings {
+    pub fn new(client_id: Uuid, realm_id: RealmId) -> Self {
+        let now = Utc::now();
+
+        Self {
+            client_id,
+            realm_id,
+            assertion_consumer_services: Vec::new(),
+            single_logout_service: None,
+            name_id_format: NameIdFormat::default(),
+            sign_response: true,
+            sign_assertions: true,
+            encrypt_assertions: false,
+            require_signed_requests: false,
+            signing_certificate: None,
+            encryption_certificate: None,
+            idp_initiated_sso_url_name: None,
+            idp_initiated_relay_state: None,
+            assertion_lifetime_seconds: DEFAULT_ASSERTION_LIFETIME_SECONDS,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// The assertion consumer service to answer at. Responses are always POSTed, so only
+    /// `HTTP-POST` endpoints qualify, and a URL named by the request must be registered.
+    pub fn assertion_consumer_service(&self, requested: Option<&str>) -> Result<&str, CoreError> {
+        let mut endpoints = self
+            .assertion_consumer_services
+            .iter()
+            .filter(|endpoint| endpoint.binding == SamlBinding::HttpPost);
+
+        let endpoint = match requested {
+            Some(url) => endpoints.find(|endpoint| endpoint.location == url),
+            None => endpoints.next(),
+        };
+
+        endpoint
+            .map(|endpoint| endpoint.location.as_str())
+            .ok_or_else(|| {
+                CoreError::InvalidSamlRequest(
+                    "assertion consumer service is not registered for this client".to_string(),
+                )
+            })
+    }
+
+    pub fn validate(&self) -> Result<(), CoreError> {
+        let invalid = |msg: &str| Err(CoreError::InvalidSamlRequest(msg.to_string()));
+
+        if !self
+            .assertion_consumer_services
+            .iter()
+            .any(|endpoint| endpoint.binding == SamlBinding::HttpPost)
+        {
+            return invalid("at least one HTTP-POST assertion consumer service is required");
+        }
+
+        let endpoints = self
+            .assertion_consumer_services
+            .iter()
+            .chain(self.single_logout_service.as_ref());
+        for endpoint in endpoints {
+            if url::Url::parse(&endpoint.location)
+                .map(|url| !matches!(url.scheme(), "http" | "https"))
+                .unwrap_or(true)
+            {
+                return invalid("endpoint locations must be absolute http(s) URLs");
+            }
+        }
+
+        if !self.sign_response && !self.sign_assertions {
+            return invalid("the response, the assertion or both must be signed");
+        }
+        if self.require_signed_requests && self.signing_certificate.is_none() {
+            return invalid("signed requests require the service provider signing certificate");
+        }
+        if self.encrypt_assertions && self.encryption_certificate.is_none() {
+            return invalid(
+                "encrypted assertions require the service provider encryption certificate",
+            );
+        }
+        if !(30..=3600).contains(&self.assertion_lifetime_seconds) {
+            return invalid("assertion lifetime must be between 30 and 3600 seconds");
+        }
+
+        Ok(())
+    }
+}
+
+/// The parts of an `AuthnRequest` the response has to echo, kept for the auth session
+/// opened for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SamlAuthRequest {
+    pub auth_session_id: Uuid,
+    /// `ID` of the `AuthnRequest`; `None` for IdP-initiated logins.
+    pub request_id: Option<String>,
+    pub acs_url: String,
+    pub relay_state: Option<String>,
+    /// `NameIDPolicy` format asked for by the request.
+    pub name_id_format: Option<NameIdFormat>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Answer to a browser carrying a SAML message.
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum SamlOutcome {
+    /// Send the browser through the login flow of `session`.
+    Login {
+        session: AuthSession,
+        login_url: String,
+        client_id: String,
+        /// `ForceAuthn`: an existing SSO session must not be reused.
+        force_authn: bool,
+    },
+    /// Auto-submit a form to the service provider.
+    Post(SamlPostForm),
+    Redirect(String),
+}
+
+/// HTTP-POST binding: a form posting `parameter` (`SAMLResponse` or `SAMLRequest`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SamlPostForm {
+    pub action: String,
+    pub parameter: &'static str,
+    pub value: String,
+    pub relay_state: Option<String>,
+}
+
+/// A SAML message as received by the SSO endpoint, with either binding.
+#[derive(Debug, Clone)]
+pub struct SamlInboundMessage {
+    pub realm_name: String,
+    /// Root-scoped base URL the IdP's entity ID and endpoints derive from.
+    pub base_url: String,
+    pub binding: SamlBinding,
+    pub saml_request: Option<String>,
+    pub saml_response: Option<String>,
+    pub relay_state: Option<String>,
+    /// HTTP-Redirect binding only: the raw query string, whose parameters are signed as sent.
+    pub raw_query: Option<String>,
+}
+
+/// What the SAML service needs from a completed login.
+#[derive(Debug, Clone)]
+pub struct SamlIdentity {
+    pub auth_session: AuthSession,
+    pub user: crate::domain::user::entities::User,
+    /// The SSO session, used as the assertion's `SessionIndex`.
+    pub session_id: Uuid,
+    pub session_expires_at: DateTime<Utc>,
+    /// ID-token claims produced by the client's protocol mappers.
+    pub claims: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SamlIdentityInput {
+    pub realm_id: RealmId,
+    pub realm_name: String,
+    pub base_url: String,
+    pub code: String,
+}
+
+/// An assertion encrypted for a service provider: the AES key wrapped with RSA-OAEP and
+/// the IV-prefixed AES-256-CBC ciphertext.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SamlEncryptedData {
+    pub encrypted_key: Vec<u8>,
+    pub cipher: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SamlSignatureAlgorithm {
+    RsaSha1,
+    RsaSha256,
+}
+
+impl SamlSignatureAlgorithm {
+    pub fn uri(self) -> &'static str {
+        match self {
+            SamlSignatureAlgorithm::RsaSha1 => "http://www.w3.org/2000/09/xmldsig#rsa-sha1",
+            SamlSignatureAlgorithm::RsaSha256 => {
+                "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256"
+            }
+        }
+    }
+
+    pub fn from_uri(uri: &str) -> Option<Self> {
+        [
+            SamlSignatureAlgorithm::RsaSha1,
+            SamlSignatureAlgorithm::RsaSha256,
+        ]
+        .into_iter()
+        .find(|algorithm| algorithm.uri() == uri)
+    }
+}
@@ -0,0 +1,408 @@
+//! SAML metadata: the identity provider descriptor a realm publishes, and import of the
+//! descriptor a service provider publishes. For brokered logins it is the other way round:
+//! the realm publishes a service provider descriptor and imports the identity provider's.
+
+use roxmltree::Node;
+
+use crate::domain::authentication::saml::entities::{
+    NameIdFormat, SamlBinding, SamlClientSettings, SamlEndpoint,
+};
+use crate::domain::authentication::saml::xml::{self, DSIG_NS, Element, METADATA_NS, invalid};
+use crate::domain::common::entities::app_errors::CoreError;
+
+/// `EntityDescriptor` of a realm acting as identity provider. Both bindings are served at
+/// the same `sso_url`.
+pub fn idp_metadata(entity_id: &str, sso_url: &str, signing_certificate: &str) -> String {
+    let key_descriptor = Element::new("md:KeyDescriptor")
+        .attr("use", "signing")
+        .child(
+            Element::new("ds:KeyInfo")
+                .namespace("ds", DSIG_NS)
+                .child(
+                    Element::new("ds:X509Data")
+                        .child(
+                            Element::new("ds:X509Certificate")
+                                .text(signing_certificate)
+                                .render(),
+                        )
+                        .render(),
+                )
+                .render(),
+        )
+        .render();
+
+    let endpoint = |name: &'static str, binding: SamlBinding| {
+        Element::new(name)
+            .attr("Binding", binding.uri())
+            .attr("Location", sso_url)
+            .render()
+    };
+
+    let mut descriptor = Element::new("md:IDPSSODescriptor")
+        .attr("WantAuthnRequestsSigned", "false")
+        .attr(
+            "protocolSupportEnumeration",
+            "urn:oasis:names:tc:SAML:2.0:protocol",
+        )
+        .child(key_descriptor);
+    for binding in [SamlBinding::HttpRedirect, SamlBinding::HttpPost] {
+        descriptor = descriptor.child(endpoint("md:SingleLogoutService", binding));
+    }
+    for format in NameIdFormat::ALL {
+        descriptor = descriptor.child(Element::new("md:NameIDFormat").text(format.uri()).render());
+    }
+    for binding in [SamlBinding::HttpRedirect, SamlBinding::HttpPost] {
+        descriptor = descriptor.child(endpoint("md:SingleSignOnService", binding));
+    }
+
+    let body = Element::new("md:EntityDescriptor")
+        .namespace("md", METADATA_NS)
+        .attr("entityID", entity_id)
+        .child(descriptor.render())
+        .render();
+
+    format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>{body}")
+}
+
+/// What a service provider's metadata says about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceProviderMetadata {
+    pub assertion_consumer_services: Vec<SamlEndpoint>,
+    pub single_logout_service: Option<SamlEndpoint>,
+    pub name_id_format: Option<NameIdFormat>,
+    pub signing_certificate: Option<String>,
+    pub encryption_certificate: Option<String>,
+    pub authn_requests_signed: bool,
+    pub want_assertions_signed: bool,
+}
+
+impl ServiceProviderMetadata {
+    /// Overwrite the endpoints, certificates and signing requirements of `settings`; the
+    /// options metadata says nothing about are kept.
+    pub fn apply_to(self, settings: &mut SamlClientSettings) {
+        settings.assertion_consumer_services = self.assertion_consumer_services;
+        settings.single_logout_service = self.single_logout_service;
+        if let Some(format) = self.name_id_format {
+            settings.name_id_format = format;
+        }
+        settings.signing_certificate = self.signing_certificate;
+        settings.encryption_certificate = self.encryption_certificate;
+        settings.require_signed_requests = self.authn_requests_signed;
+        if self.want_assertions_signed {
+            settings.sign_assertions = true;
+        }
+        if settings.encryption_certificate.is_none() {
+            settings.encrypt_assertions = false;
+        }
+    }
+}
+
+/// Parse the `SPSSODescriptor` of `entity_id` out of an `EntityDescriptor` or an
+/// `EntitiesDescriptor` aggregate.
+pub fn parse_sp_metadata(
+    metadata: &str,
+    entity_id: &str,
+) -> Result<ServiceProviderMetadata, CoreError> {
+    let doc = xml::parse(metadata)?;
+
+    let entity = doc
+        .root_element()
+        .descendants()
+        .filter(|node| node.has_tag_name((METADATA_NS, "EntityDescriptor")))
+        .find(|node| node.attribute("entityID") == Some(entity_id))
+        .ok_or_else(|| invalid(format!("metadata does not describe entity {entity_id}")))?;
+    let descriptor = xml::child(entity, METADATA_NS, "SPSSODescriptor")
+        .ok_or_else(|| invalid("metadata has no SPSSODescriptor"))?;
+
+    let mut acs: Vec<(bool, u32, SamlEndpoint)> =
+        xml::children(descriptor, METADATA_NS, "AssertionConsumerService")
+            .filter_map(|node| {
+                let endpoint = endpoint(node)?;
+                let is_default = node.attribute("isDefault") == Some("true");
+                let index = node
+                    .attribute("index")
+                    .and_then(|index| index.parse().ok())
+                    .unwrap_or(u32::MAX);
+                Some((is_default, index, endpoint))
+            })
+            .collect();
+    acs.sort_by_key(|(is_default, index, _)| (!is_default, *index));
+
+    let slo: Vec<SamlEndpoint> = xml::children(descriptor, METADATA_NS, "SingleLogoutService")
+        .filter_map(endpoint)
+        .collect();
+    let single_logout_service = slo
+        .iter()
+        .find(|endpoint| endpoint.binding == SamlBinding::HttpRedirect)
+        .or_else(|| slo.first())
+        .cloned();
+
+    let name_id_format = xml::children(descriptor, METADATA_NS, "NameIDFormat")
+        .filter_map(xml::text)
+        .find_map(|uri| NameIdFormat::from_uri(&uri));
+
+    let certificate = |usage: &str| {
+        xml::children(descriptor, METADATA_NS, "KeyDescriptor")
+            .filter(|key| key.attribute("use").is_none_or(|u| u == usage))
+            .find_map(|key| {
+                key.descendants()
+                    .find(|node| node.has_tag_name((DSIG_NS, "X509Certificate")))
+                    .and_then(xml::text)
+                    .map(|text| text.split_whitespace().collect::<String>())
+            })
+    };
+
+    Ok(ServiceProviderMetadata {
+        assertion_consumer_services: acs.into_iter().map(|(_, _, endpoint)| endpoint).collect(),
+        single_logout_service,
+        name_id_format,
+        signing_certificate: certificate("signing"),
+        encryption_certificate: certificate("encryption"),
+        authn_requests_signed: descriptor.attribute("AuthnRequestsSigned") == Some("true"),
+        want_assertions_signed: descriptor.attribute("WantAssertionsSigned") == Some("true"),
+    })
+}
+
+/// The `SPSSODescriptor` a realm publishes toward an upstream identity provider.
+pub struct ServiceProviderDescriptor<'a> {
+    pub entity_id: &'a str,
+    pub acs_url: &'a str,
+    pub signing_certificate: &'a str,
+    pub authn_requests_signed: bool,
+    pub want_assertions_signed: bool,
+    pub name_id_format: Option<NameIdFormat>,
+}
+
+/// `EntityDescriptor` of a realm acting as service provider for a brokered identity
+/// provider. Responses are only accepted over HTTP-POST.
+pub fn sp_metadata(descriptor: &ServiceProviderDescriptor) -> String {
+    let key_descriptor = Element::new("md:KeyDescriptor")
+        .attr("use", "signing")
+        .child(
+            Element::new("ds:KeyInfo")
+                .namespace("ds", DSIG_NS)
+                .child(
+                    Element::new("ds:X509Data")
+                        .child(
+                            Element::new("ds:X509Certificate")
+                                .text(descriptor.signing_certificate)
+                                .render(),
+                        )
+                        .render(),
+                )
+                .render(),
+        )
+        .render();
+
+    let mut sp = Element::new("md:SPSSODescriptor")
+        .attr(
+            "AuthnRequestsSigned",
+            descriptor.authn_requests_signed.to_string(),
+        )
+        .attr(
+            "WantAssertionsSigned",
+            descriptor.want_assertions_signed.to_string(),
+        )
+        .attr(
+            "protocolSupportEnumeration",
+            "urn:oasis:names:tc:SAML:2.0:protocol",
+        )
+        .child(key_descriptor);
+    if let Some(format) = descriptor.name_id_format {
+        sp = sp.child(Element::new("md:NameIDFormat").text(format.uri()).render());
+    }
+    sp = sp.child(
+        Element::new("md:AssertionConsumerService")
+            .attr("Binding", SamlBinding::HttpPost.uri())
+            .attr("Location", descriptor.acs_url)
+            .attr("index", "0")
+            .attr("isDefault", "true")
+            .render(),
+    );
+
+    let body = Element::new("md:EntityDescriptor")
+        .namespace("md", METADATA_NS)
+        .attr("entityID", descriptor.entity_id)
+        .child(sp.render())
+        .render();
+
+    format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>{body}")
+}
+
+/// What an identity provider's metadata says about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentityProviderMetadata {
+    pub entity_id: String,
+    pub single_sign_on_services: Vec<SamlEndpoint>,
+    /// Every signing certificate listed, so responses keep verifying through a key rollover.
+    pub signing_certificates: Vec<String>,
+    pub want_authn_requests_signed: bool,
+}
+
+/// Parse the `IDPSSODescriptor` out of an `EntityDescriptor` or an `EntitiesDescriptor`
+/// aggregate. An aggregate listing several identity providers needs `entity_id` to pick one.
+pub fn parse_idp_metadata(
+    metadata: &str,
+    entity_id: Option<&str>,
+) -> Result<IdentityProviderMetadata, CoreError> {
+    let doc = xml::parse(metadata)?;
+
+    let mut entities = doc
+        .root_element()
+        .descendants()
+        .filter(|node| node.has_tag_name((METADATA_NS, "EntityDescriptor")))
+        .filter(|node| xml::child(*node, METADATA_NS, "IDPSSODescriptor").is_some())
+        .filter(|node| entity_id.is_none_or(|id| node.attribute("entityID") == Some(id)));
+    let entity = entities.next().ok_or_else(|| match entity_id {
+        Some(id) => invalid(format!("metadata does not describe identity provider {id}")),
+        None => invalid("metadata has no IDPSSODescriptor"),
+    })?;
+    if entities.next().is_some() {
+        return Err(invalid(
+            "metadata describes several identity providers; name the entity ID to import",
+        ));
+    }
+
+    let descriptor = xml::child(entity, METADATA_NS, "IDPSSODescriptor")
+        .ok_or_else(|| invalid("metadata has no IDPSSODescriptor"))?;
+
+    let signing_certificates = xml::children(descriptor, METADATA_NS, "KeyDescriptor")
+        .filter(|key| key.attribute("use").is_none_or(|usage| usage == "signing"))
+        .filter_map(|key| {
+            key.descendants()
+                .find(|node| node.has_tag_name((DSIG_NS, "X509Certificate")))
+                .and_then(xml::text)
+                .map(|text| text.split_whitespace().collect::<String>())
+        })
+        .collect();
+
+    Ok(IdentityProviderMetadata {
+        entity_id: entity
+            .attribute("entityID")
+            .ok_or_else(|| invalid("EntityDescriptor has no entityID"))?
+            .to_string(),
+        single_sign_on_services: xml::children(descriptor, METADATA_NS, "SingleSignOnService")
+            .filter_map(endpoint)
+            .collect(),
+        signing_certificates,
+        want_authn_requests_signed: descriptor.attribute("WantAuthnRequestsSigned") == Some("true"),
+    })
+}
+
+fn endpoint(node: Node) -> Option<SamlEndpoint> {
+    Some(SamlEndpoint {
+        binding: SamlBinding::from_uri(node.attribute("Binding")?)?,
+        location: node.attribute("Location")?.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SP_METADATA: &str = r#"<?xml version="1.0"?>
+<md:EntitiesDescriptor xmlns:md="urn:oasis:names:tc:SAML:2.0:metadata">
+  <md:EntityDescriptor entityID="https://other.example.com"/>
+  <md:EntityDescriptor entityID="https://sp.example.com">
+    <md:SPSSODescriptor AuthnRequestsSigned="true" WantAssertionsSigned="true"
+        protocolSupportEnumeration="urn:oasis:names:tc:SAML:2.0:protocol">
+      <md:KeyDescriptor>
+        <ds:KeyInfo xmlns:ds="http://www.w3.org/2000/09/xmldsig#">
+          <ds:X509Data><ds:X509Certificate>
+            MIIB
+            AAAA
+          </ds:X509Certificate></ds:X509Data>
+        </ds:KeyInfo>
+      </md:KeyDescriptor>
+      <md:SingleLogoutService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST"
+          Location="https://sp.example.com/slo/post"/>
+      <md:SingleLogoutService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect"
+          Location="https://sp.example.com/slo"/>
+      <md:NameIDFormat>urn:oasis:names:tc:SAML:2.0:nameid-format:persistent</md:NameIDFormat>
+      <md:AssertionConsumerService index="1"
+          Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST"
+          Location="https://sp.example.com/acs/secondary"/>
+      <md:AssertionConsumerService index="2" isDefault="true"
+          Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST"
+          Location="https://sp.example.com/acs"/>
+    </md:SPSSODescriptor>
+  </md:EntityDescriptor>
+</md:EntitiesDescriptor>"#;
+
+    #[test]
+    fn parses_service_provider_from_aggregate() {
+        let metadata = parse_sp_metadata(SP_METADATA, "https://sp.example.com").unwrap();
+
+        assert_eq!(
+            metadata.assertion_consumer_services[0].location,
+            "https://sp.example.com/acs"
+        );
+        assert_eq!(metadata.assertion_consumer_services.len(), 2);
+        assert_eq!(
+            metadata.single_logout_service,
+            Some(SamlEndpoint {
+                binding: SamlBinding::HttpRedirect,
+                location: "https://sp.example.com/slo".to_string(),
+            })
+        );
+        assert_eq!(metadata.name_id_format, Some(NameIdFormat::Persistent));
+        assert_eq!(metadata.signing_certificate.as_deref(), Some("MIIBAAAA"));
+        assert_eq!(metadata.encryption_certificate.as_deref(), Some("MIIBAAAA"));
+        assert!(metadata.authn_requests_signed);
+    }
+
+    #[test]
+    fn rejects_metadata_of_another_entity() {
+        assert!(parse_sp_metadata(SP_METADATA, "https://unknown.example.com").is_err());
+    }
+
+    #[test]
+    fn idp_metadata_is_well_formed() {
+        let metadata = idp_metadata(
+            "https://idp.example.com/realms/demo",
+            "https://idp.example.com/realms/demo/protocol/saml",
+            "MIIC",
+        );
+        let doc = xml::parse(&metadata).unwrap();
+
+        assert_eq!(
+            doc.root_element().attribute("entityID"),
+            Some("https://idp.example.com/realms/demo")
+        );
+    }
+
+    const IDP_METADATA: &str = r#"<?xml version="1.0"?>
+<EntityDescriptor xmlns="urn:oasis:names:tc:SAML:2.0:metadata" entityID="http://adfs.example.com/adfs/services/trust">
+  <RoleDescriptor xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"/>
+  <SPSSODescriptor protocolSupportEnumeration="urn:oasis:names:tc:SAML:2.0:protocol"/>
+  <IDPSSODescriptor protocolSupportEnumeration="urn:oasis:names:tc:SAML:2.0:protocol">
+    <KeyDescriptor use="encryption">
+      <KeyInfo xmlns="http://www.w3.org/2000/09/xmldsig#"><X509Data><X509Certificate>ENC</X509Certificate></X509Data></KeyInfo>
+    </KeyDescriptor>
+    <KeyDescriptor use="signing">
+      <KeyInfo xmlns="http://www.w3.org/2000/09/xmldsig#"><X509Data><X509Certificate>
+        MIIC AAAA
+      </X509Certificate></X509Data></KeyInfo>
+    </KeyDescriptor>
+    <KeyDescriptor use="signing">
+      <KeyInfo xmlns="http://www.w3.org/2000/09/xmldsig#"><X509Data><X509Certificate>NEXT</X509Certificate></X509Data></KeyInfo>
+    </KeyDescriptor>
+    <SingleSignOnService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect" Location="https://adfs.example.com/adfs/ls/"/>
+    <SingleSignOnService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST" Location="https://adfs.example.com/adfs/ls/"/>
+  </IDPSSODescriptor>
+</EntityDescriptor>"#;
+
+    #[test]
+    fn parses_identity_provider_with_rollover_certificates() {
+        let metadata = parse_idp_metadata(IDP_METADATA, None).unwrap();
+
+        assert_eq!(
+            metadata.entity_id,
+            "http://adfs.example.com/adfs/services/trust"
+        );
+        assert_eq!(metadata.signing_certificates, vec!["MIICAAAA", "NEXT"]);
+        assert_eq!(metadata.single_sign_on_services.len(), 2);
+        assert!(parse_idp_metadata(IDP_METADATA, Some("https://other.example.com")).is_err());
+        assert!(parse_idp_metadata(SP_METADATA, None).is_err());
+    }
+}
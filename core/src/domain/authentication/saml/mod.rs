@@ -0,0 +1,26 @@
+//! SAML 2.0 identity provider: service providers are clients with the `saml` protocol,
+//! logged in through the regular login flow and answered with signed assertions over the
+//! HTTP-Redirect and HTTP-POST bindings.
+
+pub mod entities;
+pub mod metadata;
+pub mod ports;
+pub mod protocol;
+pub mod services;
+pub mod signature;
+pub mod value_objects;
+pub(crate) mod xml;
+
+pub use entities::{
+    NameIdFormat, SAML_PROTOCOL, SAML_RESPONSE_TYPE, SamlAuthRequest, SamlBinding,
+    SamlClientSettings, SamlEncryptedData, SamlEndpoint, SamlIdentity, SamlIdentityInput,
+    SamlInboundMessage, SamlOutcome, SamlPostForm, SamlSignatureAlgorithm,
+};
+pub use ports::{
+    SamlAuthRequestRepository, SamlClientRepository, SamlIdentityIssuer, SamlKeyStore, SamlService,
+};
+pub use services::SamlServiceImpl;
+pub use value_objects::{
+    GetSamlClientSettingsInput, ImportSamlMetadataInput, UpdateSamlClientSettingsInput,
+    UpdateSamlClientSettingsRequest,
+};
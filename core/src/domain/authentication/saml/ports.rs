@@ -0,0 +1,156 @@
+use uuid::Uuid;
+
+use crate::domain::authentication::saml::entities::{
+    SamlAuthRequest, SamlClientSettings, SamlEncryptedData, SamlIdentity, SamlIdentityInput,
+    SamlInboundMessage, SamlOutcome, SamlPostForm, SamlSignatureAlgorithm,
+};
+use crate::domain::authentication::saml::value_objects::{
+    GetSamlClientSettingsInput, ImportSamlMetadataInput, UpdateSamlClientSettingsInput,
+};
+use crate::domain::authentication::value_objects::Identity;
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+use crate::domain::user::entities::User;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait SamlClientRepository: Send + Sync {
+    fn get_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> impl Future<Output = Result<Option<SamlClientSettings>, CoreError>> + Send;
+
+    fn get_by_idp_initiated_url_name(
+        &self,
+        realm_id: RealmId,
+        url_name: String,
+    ) -> impl Future<Output = Result<Option<SamlClientSettings>, CoreError>> + Send;
+
+    fn upsert(
+        &self,
+        settings: &SamlClientSettings,
+    ) -> impl Future<Output = Result<SamlClientSettings, CoreError>> + Send;
+}
+
+/// Pending `AuthnRequest`s, keyed by the auth session opened for them.
+#[cfg_attr(test, mockall::automock)]
+pub trait SamlAuthRequestRepository: Send + Sync {
+    fn create(
+        &self,
+        request: &SamlAuthRequest,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_by_auth_session_id(
+        &self,
+        auth_session_id: Uuid,
+    ) -> impl Future<Output = Result<Option<SamlAuthRequest>, CoreError>> + Send;
+
+    fn delete(&self, auth_session_id: Uuid) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// RSA operations of the SAML bindings. The realm signs with its token signing key, which
+/// is published as a self-signed certificate; service provider keys come as certificates.
+#[cfg_attr(test, mockall::automock)]
+pub trait SamlKeyStore: Send + Sync {
+    /// The realm's signing certificate, base64 DER.
+    fn signing_certificate(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<String, CoreError>> + Send;
+
+    /// RSA-SHA256 signature with the realm's key.
+    fn sign(
+        &self,
+        realm_id: RealmId,
+        data: Vec<u8>,
+    ) -> impl Future<Output = Result<Vec<u8>, CoreError>> + Send;
+
+    fn verify(
+        &self,
+        certificate: String,
+        algorithm: SamlSignatureAlgorithm,
+        data: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    /// Encrypt for the holder of `certificate` (AES-256-CBC, key wrapped with RSA-OAEP).
+    fn encrypt(
+        &self,
+        certificate: String,
+        plaintext: Vec<u8>,
+    ) -> impl Future<Output = Result<SamlEncryptedData, CoreError>> + Send;
+}
+
+/// Session seam for SAML logins, implemented by the authentication service so the SAML
+/// service does not depend on its full surface.
+#[cfg_attr(test, mockall::automock)]
+pub trait SamlIdentityIssuer: Send + Sync {
+    /// Redeem the code of a completed SAML auth session: opens the SSO session and returns
+    /// the claims the client's mappers produce.
+    fn issue_saml_identity(
+        &self,
+        input: SamlIdentityInput,
+    ) -> impl Future<Output = Result<SamlIdentity, CoreError>> + Send;
+
+    fn find_saml_session_user(
+        &self,
+        realm_id: RealmId,
+        session_id: Uuid,
+    ) -> impl Future<Output = Result<Option<User>, CoreError>> + Send;
+
+    fn end_saml_session(
+        &self,
+        realm_id: RealmId,
+        session_id: Uuid,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+pub trait SamlService: Send + Sync {
+    /// The realm's identity provider metadata.
+    fn get_idp_metadata(
+        &self,
+        realm_name: String,
+        base_url: String,
+    ) -> impl Future<Output = Result<String, CoreError>> + Send;
+
+    /// An `AuthnRequest` starts a login; a `LogoutRequest` ends the session it names and
+    /// is answered to the service provider.
+    fn handle_message(
+        &self,
+        input: SamlInboundMessage,
+    ) -> impl Future<Output = Result<SamlOutcome, CoreError>> + Send;
+
+    /// Unsolicited login for the client whose IdP-initiated URL name is `url_name`.
+    fn initiate_idp_sso(
+        &self,
+        realm_name: String,
+        url_name: String,
+        base_url: String,
+    ) -> impl Future<Output = Result<SamlOutcome, CoreError>> + Send;
+
+    /// Turn the code of a completed login into the response posted to the service provider.
+    fn complete_login(
+        &self,
+        realm_name: String,
+        code: String,
+        base_url: String,
+    ) -> impl Future<Output = Result<SamlPostForm, CoreError>> + Send;
+
+    fn get_client_settings(
+        &self,
+        identity: Identity,
+        input: GetSamlClientSettingsInput,
+    ) -> impl Future<Output = Result<SamlClientSettings, CoreError>> + Send;
+
+    fn update_client_settings(
+        &self,
+        identity: Identity,
+        input: UpdateSamlClientSettingsInput,
+    ) -> impl Future<Output = Result<SamlClientSettings, CoreError>> + Send;
+
+    fn import_client_metadata(
+        &self,
+        identity: Identity,
+        input: ImportSamlMetadataInput,
+    ) -> impl Future<Output = Result<SamlClientSettings, CoreError>> + Send;
+}
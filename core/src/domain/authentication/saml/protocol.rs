@@ -0,0 +1,772 @@
+//! SAML 2.0 protocol messages: parsing the requests service providers send, and rendering
+//! the responses and assertions sent back. When a realm brokers logins to an upstream
+//! identity provider the roles swap: it renders `AuthnRequest`s and parses the responses.
+
+use base64::prelude::{BASE64_STANDARD, Engine as _};
+use chrono::{DateTime, Duration, Utc};
+use roxmltree::Node;
+use serde_json::Value;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::authentication::saml::entities::{NameIdFormat, SamlBinding, SamlEncryptedData};
+use crate::domain::authentication::saml::signature::{ReceivedSignature, enveloped_signature};
+use crate::domain::authentication::saml::xml::{
+    self, ASSERTION_NS, DSIG_NS, Element, PROTOCOL_NS, XENC_NS, invalid,
+};
+use crate::domain::common::entities::app_errors::CoreError;
+
+pub const STATUS_SUCCESS: &str = "urn:oasis:names:tc:SAML:2.0:status:Success";
+pub const STATUS_REQUESTER: &str = "urn:oasis:names:tc:SAML:2.0:status:Requester";
+
+pub const BEARER: &str = "urn:oasis:names:tc:SAML:2.0:cm:bearer";
+const PASSWORD_PROTECTED_TRANSPORT: &str =
+    "urn:oasis:names:tc:SAML:2.0:ac:classes:PasswordProtectedTransport";
+const ATTRNAME_BASIC: &str = "urn:oasis:names:tc:SAML:2.0:attrname-format:basic";
+const AES256_CBC: &str = "http://www.w3.org/2001/04/xmlenc#aes256-cbc";
+const RSA_OAEP: &str = "http://www.w3.org/2001/04/xmlenc#rsa-oaep-mgf1p";
+const XENC_ELEMENT: &str = "http://www.w3.org/2001/04/xmlenc#Element";
+
+/// `ID` for a new message: an NCName, so it cannot start with a digit.
+pub fn new_id() -> String {
+    format!("_{}", Uuid::new_v4().simple())
+}
+
+pub fn instant(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthnRequest {
+    pub id: String,
+    pub issuer: String,
+    pub destination: Option<String>,
+    pub acs_url: Option<String>,
+    pub protocol_binding: Option<String>,
+    pub name_id_format: Option<String>,
+    pub force_authn: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogoutRequest {
+    pub id: String,
+    pub issuer: String,
+    pub destination: Option<String>,
+    pub name_id: String,
+    pub session_indexes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestMessage {
+    Authn(AuthnRequest),
+    Logout(LogoutRequest),
+}
+
+impl RequestMessage {
+    pub fn issuer(&self) -> &str {
+        match self {
+            RequestMessage::Authn(request) => &request.issuer,
+            RequestMessage::Logout(request) => &request.issuer,
+        }
+    }
+
+    pub fn destination(&self) -> Option<&str> {
+        match self {
+            RequestMessage::Authn(request) => request.destination.as_deref(),
+            RequestMessage::Logout(request) => request.destination.as_deref(),
+        }
+    }
+}
+
+pub fn parse_request(root: Node) -> Result<RequestMessage, CoreError> {
+    if root.tag_name().namespace() != Some(PROTOCOL_NS) {
+        return Err(invalid("not a SAML 2.0 protocol message"));
+    }
+    if root.attribute("Version") != Some("2.0") {
+        return Err(invalid("unsupported SAML version"));
+    }
+
+    let id = root
+        .attribute("ID")
+        .ok_or_else(|| invalid("message has no ID"))?
+        .to_string();
+    let issuer = xml::child(root, ASSERTION_NS, "Issuer")
+        .and_then(xml::text)
+        .ok_or_else(|| invalid("message has no Issuer"))?;
+    let destination = root.attribute("Destination").map(str::to_string);
+
+    match root.tag_name().name() {
+        "AuthnRequest" => Ok(RequestMessage::Authn(AuthnRequest {
+            id,
+            issuer,
+            destination,
+            acs_url: root
+                .attribute("AssertionConsumerServiceURL")
+                .map(str::to_string),
+            protocol_binding: root.attribute("ProtocolBinding").map(str::to_string),
+            name_id_format: xml::child(root, PROTOCOL_NS, "NameIDPolicy")
+                .and_then(|policy| policy.attribute("Format"))
+                .map(str::to_string),
+            force_authn: matches!(root.attribute("ForceAuthn"), Some("true" | "1")),
+        })),
+        "LogoutRequest" => Ok(RequestMessage::Logout(LogoutRequest {
+            id,
+            issuer,
+            destination,
+            name_id: xml::child(root, ASSERTION_NS, "NameID")
+                .and_then(xml::text)
+                .ok_or_else(|| invalid("LogoutRequest has no NameID"))?,
+            session_indexes: xml::children(root, PROTOCOL_NS, "SessionIndex")
+                .filter_map(xml::text)
+                .collect(),
+        })),
+        other => Err(invalid(format!("unsupported message {other}"))),
+    }
+}
+
+fn issuer(entity_id: &str, declare: bool) -> String {
+    let element = Element::new("saml:Issuer");
+    let element = if declare {
+        element.namespace("saml", ASSERTION_NS)
+    } else {
+        element
+    };
+    element.text(entity_id).render()
+}
+
+fn status(code: &str) -> String {
+    Element::new("samlp:Status")
+        .child(
+            Element::new("samlp:StatusCode")
+                .attr("Value", code)
+                .render(),
+        )
+        .render()
+}
+
+/// Flatten mapper claims into SAML attributes: arrays become multi-valued attributes,
+/// objects are flattened with dotted names and nulls are dropped.
+pub fn claims_to_attributes(claims: &HashMap<String, Value>) -> Vec<(String, Vec<String>)> {
+    fn flatten(name: String, value: &Value, out: &mut Vec<(String, Vec<String>)>) {
+        match value {
+            Value::Null => {}
+            Value::Object(map) => {
+                for (key, value) in map {
+                    flatten(format!("{name}.{key}"), value, out);
+                }
+            }
+            Value::Array(items) => {
+                let values: Vec<String> = items.iter().filter_map(scalar).collect();
+                if !values.is_empty() {
+                    out.push((name, values));
+                }
+            }
+            value => out.extend(scalar(value).map(|value| (name, vec![value]))),
+        }
+    }
+
+    fn scalar(value: &Value) -> Option<String> {
+        match value {
+            Value::String(value) => Some(value.clone()),
+            Value::Bool(_) | Value::Number(_) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+
+    let mut attributes = Vec::new();
+    for (name, value) in claims {
+        flatten(name.clone(), value, &mut attributes);
+    }
+    attributes.sort();
+    attributes
+}
+
+pub struct AssertionParams<'a> {
+    pub id: &'a str,
+    pub issuer: &'a str,
+    /// The service provider's entity ID.
+    pub audience: &'a str,
+    pub recipient: &'a str,
+    pub in_response_to: Option<&'a str>,
+    pub name_id: &'a str,
+    pub name_id_format: NameIdFormat,
+    pub session_index: &'a str,
+    pub session_not_on_or_after: DateTime<Utc>,
+    pub issue_instant: DateTime<Utc>,
+    pub lifetime: Duration,
+    pub attributes: &'a [(String, Vec<String>)],
+}
+
+/// An unsigned assertion.
+pub fn assertion(params: &AssertionParams) -> String {
+    let now = instant(params.issue_instant);
+    let not_on_or_after = instant(params.issue_instant + params.lifetime);
+
+    let subject = Element::new("saml:Subject")
+        .child(
+            Element::new("saml:NameID")
+                .attr("Format", params.name_id_format.uri())
+                .attr("SPNameQualifier", params.audience)
+                .text(params.name_id)
+                .render(),
+        )
+        .child(
+            Element::new("saml:SubjectConfirmation")
+                .attr("Method", BEARER)
+                .child(
+                    Element::new("saml:SubjectConfirmationData")
+                        .opt_attr("InResponseTo", params.in_response_to)
+                        .attr("NotOnOrAfter", not_on_or_after.clone())
+                        .attr("Recipient", params.recipient)
+                        .render(),
+                )
+                .render(),
+        )
+        .render();
+
+    let conditions = Element::new("saml:Conditions")
+        .attr("NotBefore", now.clone())
+        .attr("NotOnOrAfter", not_on_or_after)
+        .child(
+            Element::new("saml:AudienceRestriction")
+                .child(Element::new("saml:Audience").text(params.audience).render())
+                .render(),
+        )
+        .render();
+
+    let authn_statement = Element::new("saml:AuthnStatement")
+        .attr("AuthnInstant", now.clone())
+        .attr("SessionIndex", params.session_index)
+        .attr(
+            "SessionNotOnOrAfter",
+            instant(params.session_not_on_or_after),
+        )
+        .child(
+            Element::new("saml:AuthnContext")
+                .child(
+                    Element::new("saml:AuthnContextClassRef")
+                        .text(PASSWORD_PROTECTED_TRANSPORT)
+                        .render(),
+                )
+                .render(),
+        )
+        .render();
+
+    let mut element = Element::new("saml:Assertion")
+        .namespace("saml", ASSERTION_NS)
+        .attr("ID", params.id)
+        .attr("IssueInstant", now)
+        .attr("Version", "2.0")
+        .child(issuer(params.issuer, false))
+        .child(subject)
+        .child(conditions)
+        .child(authn_statement);
+
+    if !params.attributes.is_empty() {
+        let mut statement = Element::new("saml:AttributeStatement");
+        for (name, values) in params.attributes {
+            let mut attribute = Element::new("saml:Attribute")
+                .attr("Name", name.as_str())
+                .attr("NameFormat", ATTRNAME_BASIC);
+            for value in values {
+                attribute =
+                    attribute.child(Element::new("saml:AttributeValue").text(value).render());
+            }
+            statement = statement.child(attribute.render());
+        }
+        element = element.child(statement.render());
+    }
+
+    element.render()
+}
+
+/// `saml:EncryptedAssertion` carrying an assertion encrypted with
+/// [`SamlKeyStore::encrypt`](super::ports::SamlKeyStore::encrypt).
+pub fn encrypted_assertion(data: &SamlEncryptedData) -> String {
+    let encrypted_key = Element::new("xenc:EncryptedKey")
+        .child(
+            Element::new("xenc:EncryptionMethod")
+                .attr("Algorithm", RSA_OAEP)
+                .child(
+                    Element::new("ds:DigestMethod")
+                        .attr("Algorithm", super::signature::SHA1)
+                        .render(),
+                )
+                .render(),
+        )
+        .child(
+            Element::new("xenc:CipherData")
+                .child(
+                    Element::new("xenc:CipherValue")
+                        .text(&BASE64_STANDARD.encode(&data.encrypted_key))
+                        .render(),
+                )
+                .render(),
+        )
+        .render();
+
+    let encrypted_data = Element::new("xenc:EncryptedData")
+        .namespace("ds", DSIG_NS)
+        .namespace("xenc", XENC_NS)
+        .attr("Type", XENC_ELEMENT)
+        .child(
+            Element::new("xenc:EncryptionMethod")
+                .attr("Algorithm", AES256_CBC)
+                .render(),
+        )
+        .child(Element::new("ds:KeyInfo").child(encrypted_key).render())
+        .child(
+            Element::new("xenc:CipherData")
+                .child(
+                    Element::new("xenc:CipherValue")
+                        .text(&BASE64_STANDARD.encode(&data.cipher))
+                        .render(),
+                )
+                .render(),
+        )
+        .render();
+
+    Element::new("saml:EncryptedAssertion")
+        .namespace("saml", ASSERTION_NS)
+        .child(encrypted_data)
+        .render()
+}
+
+pub struct ResponseParams<'a> {
+    pub id: &'a str,
+    pub issuer: &'a str,
+    pub destination: &'a str,
+    pub in_response_to: Option<&'a str>,
+    pub issue_instant: DateTime<Utc>,
+    pub status: &'a str,
+}
+
+/// An unsigned `samlp:Response`, optionally carrying a rendered assertion.
+pub fn response(params: &ResponseParams, assertion: Option<&str>) -> String {
+    let mut element = status_response("samlp:Response", params);
+    if let Some(assertion) = assertion {
+        element = element.child(assertion);
+    }
+    element.render()
+}
+
+/// An unsigned `samlp:LogoutResponse`.
+pub fn logout_response(params: &ResponseParams) -> String {
+    status_response("samlp:LogoutResponse", params).render()
+}
+
+fn status_response(name: &'static str, params: &ResponseParams) -> Element {
+    Element::new(name)
+        .namespace("samlp", PROTOCOL_NS)
+        .attr("Destination", params.destination)
+        .attr("ID", params.id)
+        .opt_attr("InResponseTo", params.in_response_to)
+        .attr("IssueInstant", instant(params.issue_instant))
+        .attr("Version", "2.0")
+        .child(issuer(params.issuer, true))
+        .child(status(params.status))
+}
+
+pub struct AuthnRequestParams<'a> {
+    pub id: &'a str,
+    /// The service provider's entity ID.
+    pub issuer: &'a str,
+    pub destination: &'a str,
+    pub acs_url: &'a str,
+    pub name_id_format: Option<NameIdFormat>,
+    pub force_authn: bool,
+    pub issue_instant: DateTime<Utc>,
+}
+
+/// An `AuthnRequest` asking for the response to be posted to `acs_url`.
+pub fn authn_request(params: &AuthnRequestParams) -> String {
+    let mut element = Element::new("samlp:AuthnRequest")
+        .namespace("samlp", PROTOCOL_NS)
+        .attr("AssertionConsumerServiceURL", params.acs_url)
+        .attr("Destination", params.destination)
+        .attr("ID", params.id)
+        .attr("IssueInstant", instant(params.issue_instant))
+        .attr("ProtocolBinding", SamlBinding::HttpPost.uri())
+        .attr("Version", "2.0")
+        .opt_attr("ForceAuthn", params.force_authn.then_some("true"))
+        .child(issuer(params.issuer, true));
+
+    let policy = Element::new("samlp:NameIDPolicy").attr("AllowCreate", "true");
+    let policy = match params.name_id_format {
+        Some(format) => policy.attr("Format", format.uri()),
+        None => policy,
+    };
+    element = element.child(policy.render());
+
+    element.render()
+}
+
+/// A `samlp:Response` received from an upstream identity provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceivedResponse {
+    pub id: String,
+    pub issuer: Option<String>,
+    pub destination: Option<String>,
+    pub in_response_to: Option<String>,
+    pub status: String,
+    pub status_message: Option<String>,
+    pub signature: Option<ReceivedSignature>,
+    /// Absent when the identity provider answers with an error status.
+    pub assertion: Option<ReceivedAssertion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceivedAssertion {
+    pub id: String,
+    pub issuer: String,
+    pub signature: Option<ReceivedSignature>,
+    pub name_id: Option<String>,
+    pub name_id_format: Option<String>,
+    pub confirmations: Vec<SubjectConfirmation>,
+    pub not_before: Option<DateTime<Utc>>,
+    pub not_on_or_after: Option<DateTime<Utc>>,
+    /// One entry per `AudienceRestriction`, each satisfied by any of its audiences.
+    pub audience_restrictions: Vec<Vec<String>>,
+    pub session_index: Option<String>,
+    pub attributes: Vec<(String, Vec<String>)>,
+}
+
+/// A `SubjectConfirmation` and its `SubjectConfirmationData`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectConfirmation {
+    pub method: String,
+    pub recipient: Option<String>,
+    pub in_response_to: Option<String>,
+    pub not_before: Option<DateTime<Utc>>,
+    pub not_on_or_after: Option<DateTime<Utc>>,
+}
+
+fn parse_instant(node: Node, attribute: &str) -> Result<Option<DateTime<Utc>>, CoreError> {
+    node.attribute(attribute)
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|at| at.with_timezone(&Utc))
+                .map_err(|_| invalid(format!("{attribute} is not a valid instant")))
+        })
+        .transpose()
+}
+
+/// Parse a `samlp:Response` and the signatures it carries. Only an assertion that is a
+/// direct child of the response is read, and there must be exactly one: whatever is
+/// verified afterwards is what was parsed here.
+pub fn parse_response(root: Node) -> Result<ReceivedResponse, CoreError> {
+    if !root.has_tag_name((PROTOCOL_NS, "Response")) {
+        return Err(invalid("not a SAML 2.0 Response"));
+    }
+    if root.attribute("Version") != Some("2.0") {
+        return Err(invalid("unsupported SAML version"));
+    }
+
+    let status =
+        xml::child(root, PROTOCOL_NS, "Status").ok_or_else(|| invalid("response has no Status"))?;
+    let status_code = xml::child(status, PROTOCOL_NS, "StatusCode")
+        .and_then(|code| code.attribute("Value"))
+        .ok_or_else(|| invalid("response has no StatusCode"))?;
+
+    if xml::child(root, ASSERTION_NS, "EncryptedAssertion").is_some() {
+        return Err(invalid("encrypted assertions are not supported"));
+    }
+    let mut assertions = xml::children(root, ASSERTION_NS, "Assertion");
+    let assertion = assertions.next();
+    if assertions.next().is_some() {
+        return Err(invalid("response must carry a single assertion"));
+    }
+
+    Ok(ReceivedResponse {
+        id: root
+            .attribute("ID")
+            .ok_or_else(|| invalid("message has no ID"))?
+            .to_string(),
+        issuer: xml::child(root, ASSERTION_NS, "Issuer").and_then(xml::text),
+        destination: root.attribute("Destination").map(str::to_string),
+        in_response_to: root.attribute("InResponseTo").map(str::to_string),
+        status: status_code.to_string(),
+        status_message: xml::child(status, PROTOCOL_NS, "StatusMessage").and_then(xml::text),
+        signature: enveloped_signature(root)?,
+        assertion: assertion.map(parse_assertion).transpose()?,
+    })
+}
+
+fn parse_assertion(node: Node) -> Result<ReceivedAssertion, CoreError> {
+    let subject = xml::child(node, ASSERTION_NS, "Subject");
+    let name_id = subject.and_then(|subject| xml::child(subject, ASSERTION_NS, "NameID"));
+
+    let confirmations = subject
+        .map(|subject| {
+            xml::children(subject, ASSERTION_NS, "SubjectConfirmation")
+                .map(|confirmation| {
+                    let data = xml::child(confirmation, ASSERTION_NS, "SubjectConfirmationData");
+                    Ok(SubjectConfirmation {
+                        method: confirmation.attribute("Method").unwrap_or("").to_string(),
+                        recipient: data
+                            .and_then(|data| data.attribute("Recipient"))
+                            .map(str::to_string),
+                        in_response_to: data
+                            .and_then(|data| data.attribute("InResponseTo"))
+                            .map(str::to_string),
+                        not_before: data
+                            .map(|data| parse_instant(data, "NotBefore"))
+                            .transpose()?
+                            .flatten(),
+                        not_on_or_after: data
+                            .map(|data| parse_instant(data, "NotOnOrAfter"))
+                            .transpose()?
+                            .flatten(),
+                    })
+                })
+                .collect::<Result<Vec<_>, CoreError>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    let conditions = xml::child(node, ASSERTION_NS, "Conditions");
+    let audience_restrictions = conditions
+        .map(|conditions| {
+            xml::children(conditions, ASSERTION_NS, "AudienceRestriction")
+                .map(|restriction| {
+                    xml::children(restriction, ASSERTION_NS, "Audience")
+                        .filter_map(xml::text)
+                        .collect()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let attributes = xml::children(node, ASSERTION_NS, "AttributeStatement")
+        .flat_map(|statement| xml::children(statement, ASSERTION_NS, "Attribute"))
+        .filter_map(|attribute| {
+            let values: Vec<String> = xml::children(attribute, ASSERTION_NS, "AttributeValue")
+                .filter_map(xml::text)
+                .collect();
+            Some((attribute.attribute("Name")?.to_string(), values))
+        })
+        .collect();
+
+    Ok(ReceivedAssertion {
+        id: node
+            .attribute("ID")
+            .ok_or_else(|| invalid("assertion has no ID"))?
+            .to_string(),
+        issuer: xml::child(node, ASSERTION_NS, "Issuer")
+            .and_then(xml::text)
+            .ok_or_else(|| invalid("assertion has no Issuer"))?,
+        signature: enveloped_signature(node)?,
+        name_id: name_id.and_then(xml::text),
+        name_id_format: name_id
+            .and_then(|name_id| name_id.attribute("Format"))
+            .map(str::to_string),
+        confirmations,
+        not_before: conditions
+            .map(|conditions| parse_instant(conditions, "NotBefore"))
+            .transpose()?
+            .flatten(),
+        not_on_or_after: conditions
+            .map(|conditions| parse_instant(conditions, "NotOnOrAfter"))
+            .transpose()?
+            .flatten(),
+        audience_restrictions,
+        session_index: xml::child(node, ASSERTION_NS, "AuthnStatement")
+            .and_then(|statement| statement.attribute("SessionIndex"))
+            .map(str::to_string),
+        attributes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::authentication::saml::signature::PendingSignature;
+
+    fn params<'a>(attributes: &'a [(String, Vec<String>)]) -> AssertionParams<'a> {
+        AssertionParams {
+            id: "_assertion",
+            issuer: "https://idp.example.com/realms/demo",
+            audience: "https://sp.example.com",
+            recipient: "https://sp.example.com/acs",
+            in_response_to: Some("_request"),
+            name_id: "ferris",
+            name_id_format: NameIdFormat::Unspecified,
+            session_index: "c9a3",
+            session_not_on_or_after: Utc::now() + Duration::hours(8),
+            issue_instant: Utc::now(),
+            lifetime: Duration::minutes(5),
+            attributes,
+        }
+    }
+
+    #[test]
+    fn parses_authn_request() {
+        let xml = r#"<samlp:AuthnRequest xmlns:samlp="urn:oasis:names:tc:SAML:2.0:protocol"
+            xmlns:saml="urn:oasis:names:tc:SAML:2.0:assertion" ID="_r1" Version="2.0"
+            IssueInstant="2026-01-01T00:00:00Z" ForceAuthn="true"
+            AssertionConsumerServiceURL="https://sp.example.com/acs"
+            ProtocolBinding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST">
+            <saml:Issuer>https://sp.example.com</saml:Issuer>
+            <samlp:NameIDPolicy Format="urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress"/>
+            </samlp:AuthnRequest>"#;
+        let doc = xml::parse(xml).unwrap();
+
+        assert_eq!(
+            parse_request(doc.root_element()).unwrap(),
+            RequestMessage::Authn(AuthnRequest {
+                id: "_r1".to_string(),
+                issuer: "https://sp.example.com".to_string(),
+                destination: None,
+                acs_url: Some("https://sp.example.com/acs".to_string()),
+                protocol_binding: Some(
+                    "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST".to_string()
+                ),
+                name_id_format: Some(NameIdFormat::EmailAddress.uri().to_string()),
+                force_authn: true,
+            })
+        );
+    }
+
+    #[test]
+    fn flattens_claims_into_attributes() {
+        let claims = HashMap::from([
+            ("email".to_string(), json!("ferris@example.com")),
+            ("roles".to_string(), json!(["admin", "dev"])),
+            ("address".to_string(), json!({ "country": "FR" })),
+            ("email_verified".to_string(), json!(true)),
+            ("missing".to_string(), Value::Null),
+        ]);
+
+        assert_eq!(
+            claims_to_attributes(&claims),
+            vec![
+                ("address.country".to_string(), vec!["FR".to_string()]),
+                ("email".to_string(), vec!["ferris@example.com".to_string()]),
+                ("email_verified".to_string(), vec!["true".to_string()]),
+                (
+                    "roles".to_string(),
+                    vec!["admin".to_string(), "dev".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn signed_assertion_in_signed_response_verifies() {
+        let attributes = vec![("email".to_string(), vec!["ferris@example.com".to_string()])];
+        let unsigned_assertion = assertion(&params(&attributes));
+        let signed_assertion = PendingSignature::new(&unsigned_assertion, "_assertion")
+            .apply(&unsigned_assertion, b"a", "MIIC")
+            .unwrap();
+
+        let unsigned = response(
+            &ResponseParams {
+                id: "_response",
+                issuer: "https://idp.example.com/realms/demo",
+                destination: "https://sp.example.com/acs",
+                in_response_to: Some("_request"),
+                issue_instant: Utc::now(),
+                status: STATUS_SUCCESS,
+            },
+            Some(&signed_assertion),
+        );
+        let signed = PendingSignature::new(&unsigned, "_response")
+            .apply(&unsigned, b"r", "MIIC")
+            .unwrap();
+
+        let doc = xml::parse(&signed).unwrap();
+        let root = doc.root_element();
+        assert_eq!(enveloped_signature(root).unwrap().unwrap().signature, b"r");
+
+        let assertion_node = xml::child(root, ASSERTION_NS, "Assertion").unwrap();
+        assert_eq!(
+            enveloped_signature(assertion_node)
+                .unwrap()
+                .unwrap()
+                .signature,
+            b"a"
+        );
+        assert_eq!(
+            xml::child(assertion_node, ASSERTION_NS, "Issuer").and_then(xml::text),
+            Some("https://idp.example.com/realms/demo".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_response_to_an_authn_request() {
+        let request = authn_request(&AuthnRequestParams {
+            id: "_request",
+            issuer: "https://sp.example.com",
+            destination: "https://idp.example.com/sso",
+            acs_url: "https://sp.example.com/acs",
+            name_id_format: Some(NameIdFormat::Persistent),
+            force_authn: false,
+            issue_instant: Utc::now(),
+        });
+        let doc = xml::parse(&request).unwrap();
+        let RequestMessage::Authn(parsed) = parse_request(doc.root_element()).unwrap() else {
+            panic!("expected an AuthnRequest");
+        };
+        assert_eq!(
+            parsed.acs_url.as_deref(),
+            Some("https://sp.example.com/acs")
+        );
+
+        let attributes = vec![(
+            "groups".to_string(),
+            vec!["admin".to_string(), "dev".to_string()],
+        )];
+        let unsigned_assertion = assertion(&params(&attributes));
+        let signed_assertion = PendingSignature::new(&unsigned_assertion, "_assertion")
+            .apply(&unsigned_assertion, b"a", "MIIC")
+            .unwrap();
+        let message = response(
+            &ResponseParams {
+                id: "_response",
+                issuer: "https://idp.example.com/realms/demo",
+                destination: "https://sp.example.com/acs",
+                in_response_to: Some("_request"),
+                issue_instant: Utc::now(),
+                status: STATUS_SUCCESS,
+            },
+            Some(&signed_assertion),
+        );
+
+        let doc = xml::parse(&message).unwrap();
+        let received = parse_response(doc.root_element()).unwrap();
+        assert_eq!(received.in_response_to.as_deref(), Some("_request"));
+        assert_eq!(received.status, STATUS_SUCCESS);
+        assert!(received.signature.is_none());
+
+        let assertion = received.assertion.unwrap();
+        assert_eq!(assertion.signature.unwrap().signature, b"a");
+        assert_eq!(assertion.name_id.as_deref(), Some("ferris"));
+        assert_eq!(
+            assertion.audience_restrictions,
+            vec![vec!["https://sp.example.com".to_string()]]
+        );
+        assert_eq!(assertion.confirmations[0].method, BEARER);
+        assert_eq!(assertion.attributes, attributes);
+    }
+
+    #[test]
+    fn rejects_response_with_two_assertions() {
+        let assertion = assertion(&params(&[]));
+        let message = response(
+            &ResponseParams {
+                id: "_response",
+                issuer: "https://idp.example.com/realms/demo",
+                destination: "https://sp.example.com/acs",
+                in_response_to: Some("_request"),
+                issue_instant: Utc::now(),
+                status: STATUS_SUCCESS,
+            },
+            Some(&[assertion.clone(), assertion].concat()),
+        );
+        let doc = xml::parse(&message).unwrap();
+
+        assert!(parse_response(doc.root_element()).is_err());
+    }
+}
@@ -0,0 +1,735 @@
+use std::sync::Arc;
+
+use base64::prelude::{BASE64_STANDARD, Engine as _};
+use chrono::{Duration, Utc};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::domain::authentication::entities::{AuthSession, AuthSessionParams};
+use crate::domain::authentication::ports::AuthSessionRepository;
+use crate::domain::authentication::saml::entities::{
+    NameIdFormat, SAML_PROTOCOL, SAML_RESPONSE_TYPE, SamlAuthRequest, SamlBinding,
+    SamlClientSettings, SamlIdentityInput, SamlInboundMessage, SamlOutcome, SamlPostForm,
+};
+use crate::domain::authentication::saml::metadata::{idp_metadata, parse_sp_metadata};
+use crate::domain::authentication::saml::ports::{
+    SamlAuthRequestRepository, SamlClientRepository, SamlIdentityIssuer, SamlKeyStore, SamlService,
+};
+use crate::domain::authentication::saml::protocol::{
+    self, AssertionParams, AuthnRequest, LogoutRequest, RequestMessage, ResponseParams,
+    STATUS_SUCCESS,
+};
+use crate::domain::authentication::saml::signature::{self, PendingSignature, ReceivedSignature};
+use crate::domain::authentication::saml::value_objects::{
+    GetSamlClientSettingsInput, ImportSamlMetadataInput, UpdateSamlClientSettingsInput,
+};
+use crate::domain::authentication::saml::xml::{self, invalid};
+use crate::domain::authentication::scope::SCOPE_OPENID;
+use crate::domain::authentication::value_objects::Identity;
+use crate::domain::client::entities::Client;
+use crate::domain::client::ports::{ClientPolicy, ClientRepository};
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use crate::domain::realm::entities::Realm;
+use crate::domain::realm::ports::RealmRepository;
+use crate::domain::user::entities::User;
+use crate::domain::user::ports::{UserRepository, UserRoleRepository};
+
+/// The realm's entity ID.
+fn idp_entity_id(base_url: &str, realm_name: &str) -> String {
+    format!("{}/realms/{}", base_url.trim_end_matches('/'), realm_name)
+}
+
+/// Where both bindings of the SSO and SLO services are served.
+fn idp_sso_url(base_url: &str, realm_name: &str) -> String {
+    format!("{}/protocol/saml", idp_entity_id(base_url, realm_name))
+}
+
+/// Where the login flow sends the browser back with its code.
+fn idp_resume_url(base_url: &str, realm_name: &str) -> String {
+    format!("{}/resume", idp_sso_url(base_url, realm_name))
+}
+
+/// Who a SAML login is answered to.
+struct LoginTarget<'a> {
+    client: &'a Client,
+    request_id: Option<String>,
+    acs_url: String,
+    relay_state: Option<String>,
+    name_id_format: Option<NameIdFormat>,
+    force_authn: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct SamlServiceImpl<R, U, C, UR, SC, SA, AS, K, I>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SC: SamlClientRepository,
+    SA: SamlAuthRequestRepository,
+    AS: AuthSessionRepository,
+    K: SamlKeyStore,
+    I: SamlIdentityIssuer,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) client_repository: Arc<C>,
+    pub(crate) saml_client_repository: Arc<SC>,
+    pub(crate) saml_auth_request_repository: Arc<SA>,
+    pub(crate) auth_session_repository: Arc<AS>,
+    pub(crate) key_store: Arc<K>,
+    pub(crate) identity_issuer: Arc<I>,
+
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, SC, SA, AS, K, I> SamlServiceImpl<R, U, C, UR, SC, SA, AS, K, I>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SC: SamlClientRepository,
+    SA: SamlAuthRequestRepository,
+    AS: AuthSessionRepository,
+    K: SamlKeyStore,
+    I: SamlIdentityIssuer,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        realm_repository: Arc<R>,
+        client_repository: Arc<C>,
+        saml_client_repository: Arc<SC>,
+        saml_auth_request_repository: Arc<SA>,
+        auth_session_repository: Arc<AS>,
+        key_store: Arc<K>,
+        identity_issuer: Arc<I>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            client_repository,
+            saml_client_repository,
+            saml_auth_request_repository,
+            auth_session_repository,
+            key_store,
+            identity_issuer,
+            policy,
+        }
+    }
+
+    async fn realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    /// The enabled SAML client `entity_id` and its settings.
+    async fn service_provider(
+        &self,
+        realm: &Realm,
+        entity_id: &str,
+    ) -> Result<(Client, SamlClientSettings), CoreError> {
+        let client = self
+            .client_repository
+            .get_by_client_id(entity_id.to_string(), realm.id)
+            .await
+            .map_err(|_| invalid(format!("unknown service provider {entity_id}")))?;
+
+        self.saml_client(client).await
+    }
+
+    async fn saml_client(&self, client: Client) -> Result<(Client, SamlClientSettings), CoreError> {
+        if client.protocol != SAML_PROTOCOL || !client.enabled {
+            return Err(CoreError::InvalidClient);
+        }
+
+        let settings = self
+            .saml_client_repository
+            .get_by_client_id(client.id)
+            .await?
+            .ok_or_else(|| invalid("client has no SAML settings"))?;
+
+        Ok((client, settings))
+    }
+
+    /// Check the request signature against the service provider's certificate. Unsigned
+    /// requests pass unless the client requires signatures.
+    async fn verify_request(
+        &self,
+        settings: &SamlClientSettings,
+        received: Option<ReceivedSignature>,
+    ) -> Result<(), CoreError> {
+        let (Some(received), Some(certificate)) = (received, &settings.signing_certificate) else {
+            if settings.require_signed_requests {
+                return Err(invalid("request must be signed"));
+            }
+            return Ok(());
+        };
+
+        let valid = self
+            .key_store
+            .verify(
+                certificate.clone(),
+                received.algorithm,
+                received.signed_info,
+                received.signature,
+            )
+            .await?;
+
+        if !valid {
+            return Err(invalid("request signature is invalid"));
+        }
+
+        Ok(())
+    }
+
+    async fn sign(&self, realm: &Realm, element: String, id: &str) -> Result<String, CoreError> {
+        let pending = PendingSignature::new(&element, id);
+        let signature = self
+            .key_store
+            .sign(realm.id, pending.signed_info().into_bytes())
+            .await?;
+        let certificate = self.key_store.signing_certificate(realm.id).await?;
+
+        pending.apply(&element, &signature, &certificate)
+    }
+
+    /// Open the auth session the login flow runs in, remembering what to answer.
+    async fn open_login(
+        &self,
+        realm: &Realm,
+        base_url: &str,
+        target: LoginTarget<'_>,
+    ) -> Result<SamlOutcome, CoreError> {
+        let resume_url = idp_resume_url(base_url, &realm.name);
+
+        let session = self
+            .auth_session_repository
+            .create(&AuthSession::new(AuthSessionParams {
+                realm_id: realm.id,
+                client_id: target.client.id,
+                redirect_uri: resume_url.clone(),
+                response_type: SAML_RESPONSE_TYPE.to_string(),
+                scope: SCOPE_OPENID.to_string(),
+                state: None,
+                nonce: None,
+                user_id: None,
+                code: None,
+                authenticated: false,
+                webauthn_challenge: None,
+                webauthn_challenge_issued_at: None,
+                compass_flow_id: None,
+                code_challenge: None,
+                code_challenge_method: None,
+            }))
+            .await
+            .map_err(|_| CoreError::SessionCreateError)?;
+
+        self.saml_auth_request_repository
+            .create(&SamlAuthRequest {
+                auth_session_id: session.id,
+                request_id: target.request_id,
+                acs_url: target.acs_url,
+                relay_state: target.relay_state,
+                name_id_format: target.name_id_format,
+                created_at: Utc::now(),
+            })
+            .await?;
+
+        let login_url = format!(
+            "?client_id={}&redirect_uri={}&state=",
+            urlencoding::encode(&target.client.client_id),
+            urlencoding::encode(&resume_url),
+        );
+
+        Ok(SamlOutcome::Login {
+            session,
+            login_url,
+            client_id: target.client.client_id.clone(),
+            force_authn: target.force_authn,
+        })
+    }
+
+    async fn start_login(
+        &self,
+        realm: &Realm,
+        client: &Client,
+        settings: &SamlClientSettings,
+        request: AuthnRequest,
+        input: &SamlInboundMessage,
+    ) -> Result<SamlOutcome, CoreError> {
+        if let Some(binding) = &request.protocol_binding
+            && binding != SamlBinding::HttpPost.uri()
+        {
+            return Err(invalid("only HTTP-POST responses are supported"));
+        }
+
+        let acs_url = settings
+            .assertion_consumer_service(request.acs_url.as_deref())?
+            .to_string();
+
+        let name_id_format = match request.name_id_format.as_deref() {
+            None => None,
+            Some(uri) if uri == NameIdFormat::Unspecified.uri() => None,
+            Some(uri) => Some(
+                NameIdFormat::from_uri(uri)
+                    .ok_or_else(|| invalid(format!("unsupported NameID format {uri}")))?,
+            ),
+        };
+
+        self.open_login(
+            realm,
+            &input.base_url,
+            LoginTarget {
+                client,
+                request_id: Some(request.id),
+                acs_url,
+                relay_state: input.relay_state.clone(),
+                name_id_format,
+                force_authn: request.force_authn,
+            },
+        )
+        .await
+    }
+
+    /// End the sessions a `LogoutRequest` names and answer it at the service provider's
+    /// single logout service, with the binding that endpoint is registered for.
+    async fn logout(
+        &self,
+        realm: &Realm,
+        settings: &SamlClientSettings,
+        request: LogoutRequest,
+        input: &SamlInboundMessage,
+    ) -> Result<SamlOutcome, CoreError> {
+        let slo = settings
+            .single_logout_service
+            .clone()
+            .ok_or_else(|| invalid("client has no single logout service"))?;
+
+        for session_index in &request.session_indexes {
+            let Ok(session_id) = Uuid::parse_str(session_index) else {
+                continue;
+            };
+
+            let Some(user) = self
+                .identity_issuer
+                .find_saml_session_user(realm.id, session_id)
+                .await?
+            else {
+                continue;
+            };
+
+            if !name_id_matches(&user, &request.name_id) {
+                warn!(
+                    realm = %realm.name,
+                    session_id = %session_id,
+                    "SAML logout NameID does not match the session user"
+                );
+                continue;
+            }
+
+            self.identity_issuer
+                .end_saml_session(realm.id, session_id, user.id)
+                .await?;
+        }
+
+        let id = protocol::new_id();
+        let response = protocol::logout_response(&ResponseParams {
+            id: &id,
+            issuer: &idp_entity_id(&input.base_url, &realm.name),
+            destination: &slo.location,
+            in_response_to: Some(&request.id),
+            issue_instant: Utc::now(),
+            status: STATUS_SUCCESS,
+        });
+
+        match slo.binding {
+            SamlBinding::HttpPost => {
+                let response = self.sign(realm, response, &id).await?;
+                Ok(SamlOutcome::Post(SamlPostForm {
+                    action: slo.location,
+                    parameter: "SAMLResponse",
+                    value: BASE64_STANDARD.encode(response),
+                    relay_state: input.relay_state.clone(),
+                }))
+            }
+            SamlBinding::HttpRedirect => {
+                let query = signature::redirect_query(
+                    "SAMLResponse",
+                    &xml::encode_redirect_message(&response)?,
+                    input.relay_state.as_deref(),
+                );
+                let signature = self
+                    .key_store
+                    .sign(realm.id, query.clone().into_bytes())
+                    .await?;
+                let separator = if slo.location.contains('?') { '&' } else { '?' };
+
+                Ok(SamlOutcome::Redirect(format!(
+                    "{}{}{}&Signature={}",
+                    slo.location,
+                    separator,
+                    query,
+                    urlencoding::encode(&BASE64_STANDARD.encode(signature))
+                )))
+            }
+        }
+    }
+
+    async fn update_settings(
+        &self,
+        identity: Identity,
+        realm_name: &str,
+        client_id: Uuid,
+        update: impl FnOnce(&mut SamlClientSettings) -> Result<(), CoreError>,
+    ) -> Result<SamlClientSettings, CoreError> {
+        let realm = self.realm(realm_name).await?;
+        ensure_policy(
+            self.policy.can_update_client(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let client = self
+            .client_repository
+            .get_by_id(realm.id, client_id)
+            .await
+            .map_err(|_| CoreError::NotFound)?;
+        if client.protocol != SAML_PROTOCOL {
+            return Err(invalid("client protocol is not saml"));
+        }
+
+        let mut settings = self
+            .saml_client_repository
+            .get_by_client_id(client.id)
+            .await?
+            .unwrap_or_else(|| SamlClientSettings::new(client.id, realm.id));
+
+        update(&mut settings)?;
+        settings.updated_at = Utc::now();
+        settings.validate()?;
+
+        if let Some(url_name) = settings.idp_initiated_sso_url_name.clone()
+            && let Some(existing) = self
+                .saml_client_repository
+                .get_by_idp_initiated_url_name(realm.id, url_name)
+                .await?
+            && existing.client_id != client.id
+        {
+            return Err(invalid("IdP-initiated URL name is already in use"));
+        }
+
+        self.saml_client_repository.upsert(&settings).await
+    }
+}
+
+/// Whether a `LogoutRequest`'s NameID designates `user`, under any format we issue.
+/// Transient identifiers are not kept, so for those the session index alone has to do.
+fn name_id_matches(user: &User, name_id: &str) -> bool {
+    name_id == user.username
+        || name_id == user.id.to_string()
+        || user.email.as_deref() == Some(name_id)
+        || name_id.starts_with('_')
+}
+
+fn name_id(user: &User, format: NameIdFormat) -> Result<String, CoreError> {
+    match format {
+        NameIdFormat::Unspecified => Ok(user.username.clone()),
+        NameIdFormat::EmailAddress => user
+            .email
+            .clone()
+            .ok_or_else(|| invalid("user has no email address for the requested NameID")),
+        NameIdFormat::Persistent => Ok(user.id.to_string()),
+        NameIdFormat::Transient => Ok(protocol::new_id()),
+    }
+}
+
+impl<R, U, C, UR, SC, SA, AS, K, I> SamlService for SamlServiceImpl<R, U, C, UR, SC, SA, AS, K, I>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SC: SamlClientRepository,
+    SA: SamlAuthRequestRepository,
+    AS: AuthSessionRepository,
+    K: SamlKeyStore,
+    I: SamlIdentityIssuer,
+{
+    async fn get_idp_metadata(
+        &self,
+        realm_name: String,
+        base_url: String,
+    ) -> Result<String, CoreError> {
+        let realm = self.realm(&realm_name).await?;
+        let certificate = self.key_store.signing_certificate(realm.id).await?;
+
+        Ok(idp_metadata(
+            &idp_entity_id(&base_url, &realm.name),
+            &idp_sso_url(&base_url, &realm.name),
+            &certificate,
+        ))
+    }
+
+    async fn handle_message(&self, input: SamlInboundMessage) -> Result<SamlOutcome, CoreError> {
+        let realm = self.realm(&input.realm_name).await?;
+
+        if input.saml_response.is_some() {
+            // This provider never sends requests, so no response is expected.
+            return Err(invalid("unsolicited SAMLResponse"));
+        }
+        let encoded = input
+            .saml_request
+            .as_deref()
+            .ok_or_else(|| invalid("SAMLRequest is required"))?;
+
+        let xml = xml::decode_message(encoded, input.binding == SamlBinding::HttpRedirect)?;
+        let (message, received) = {
+            let doc = xml::parse(&xml)?;
+            let root = doc.root_element();
+            let message = protocol::parse_request(root)?;
+            let received = match input.binding {
+                SamlBinding::HttpPost => signature::enveloped_signature(root)?,
+                SamlBinding::HttpRedirect => signature::redirect_signature(
+                    input.raw_query.as_deref().unwrap_or_default(),
+                    "SAMLRequest",
+                )?,
+            };
+            (message, received)
+        };
+
+        if let Some(destination) = message.destination()
+            && destination != idp_sso_url(&input.base_url, &realm.name)
+        {
+            return Err(invalid("message destination is not this identity provider"));
+        }
+
+        let (client, settings) = self.service_provider(&realm, message.issuer()).await?;
+        self.verify_request(&settings, received).await?;
+
+        match message {
+            RequestMessage::Authn(request) => {
+                self.start_login(&realm, &client, &settings, request, &input)
+                    .await
+            }
+            RequestMessage::Logout(request) => {
+                self.logout(&realm, &settings, request, &input).await
+            }
+        }
+    }
+
+    async fn initiate_idp_sso(
+        &self,
+        realm_name: String,
+        url_name: String,
+        base_url: String,
+    ) -> Result<SamlOutcome, CoreError> {
+        let realm = self.realm(&realm_name).await?;
+
+        let settings = self
+            .saml_client_repository
+            .get_by_idp_initiated_url_name(realm.id, url_name)
+            .await?
+            .ok_or(CoreError::NotFound)?;
+        let client = self
+            .client_repository
+            .get_by_id(realm.id, settings.client_id)
+            .await?;
+        let (client, settings) = self.saml_client(client).await?;
+
+        let acs_url = settings.assertion_consumer_service(None)?.to_string();
+
+        self.open_login(
+            &realm,
+            &base_url,
+            LoginTarget {
+                client: &client,
+                request_id: None,
+                acs_url,
+                relay_state: settings.idp_initiated_relay_state.clone(),
+                name_id_format: None,
+                force_authn: false,
+            },
+        )
+        .await
+    }
+
+    async fn complete_login(
+        &self,
+        realm_name: String,
+        code: String,
+        base_url: String,
+    ) -> Result<SamlPostForm, CoreError> {
+        let realm = self.realm(&realm_name).await?;
+
+        let identity = self
+            .identity_issuer
+            .issue_saml_identity(SamlIdentityInput {
+                realm_id: realm.id,
+                realm_name: realm.name.clone(),
+                base_url: base_url.clone(),
+                code,
+            })
+            .await?;
+
+        let client = self
+            .client_repository
+            .get_by_id(realm.id, identity.auth_session.client_id)
+            .await?;
+        let (client, settings) = self.saml_client(client).await?;
+
+        let request = self
+            .saml_auth_request_repository
+            .get_by_auth_session_id(identity.auth_session.id)
+            .await?
+            .ok_or_else(|| invalid("no pending SAML request for this login"))?;
+        self.saml_auth_request_repository
+            .delete(identity.auth_session.id)
+            .await?;
+
+        let format = request.name_id_format.unwrap_or(settings.name_id_format);
+        let name_id = name_id(&identity.user, format)?;
+        let attributes = protocol::claims_to_attributes(&identity.claims);
+
+        let issuer = idp_entity_id(&base_url, &realm.name);
+        let now = Utc::now();
+        let session_index = identity.session_id.to_string();
+
+        let assertion_id = protocol::new_id();
+        let assertion = protocol::assertion(&AssertionParams {
+            id: &assertion_id,
+            issuer: &issuer,
+            audience: &client.client_id,
+            recipient: &request.acs_url,
+            in_response_to: request.request_id.as_deref(),
+            name_id: &name_id,
+            name_id_format: format,
+            session_index: &session_index,
+            session_not_on_or_after: identity.session_expires_at,
+            issue_instant: now,
+            lifetime: Duration::seconds(settings.assertion_lifetime_seconds),
+            attributes: &attributes,
+        });
+
+        let assertion = if settings.sign_assertions {
+            self.sign(&realm, assertion, &assertion_id).await?
+        } else {
+            assertion
+        };
+
+        let assertion = match (
+            &settings.encryption_certificate,
+            settings.encrypt_assertions,
+        ) {
+            (Some(certificate), true) => {
+                let encrypted = self
+                    .key_store
+                    .encrypt(certificate.clone(), assertion.into_bytes())
+                    .await?;
+                protocol::encrypted_assertion(&encrypted)
+            }
+            _ => assertion,
+        };
+
+        let response_id = protocol::new_id();
+        let response = protocol::response(
+            &ResponseParams {
+                id: &response_id,
+                issuer: &issuer,
+                destination: &request.acs_url,
+                in_response_to: request.request_id.as_deref(),
+                issue_instant: now,
+                status: STATUS_SUCCESS,
+            },
+            Some(&assertion),
+        );
+
+        let response = if settings.sign_response {
+            self.sign(&realm, response, &response_id).await?
+        } else {
+            response
+        };
+
+        Ok(SamlPostForm {
+            action: request.acs_url,
+            parameter: "SAMLResponse",
+            value: BASE64_STANDARD.encode(response),
+            relay_state: request.relay_state,
+        })
+    }
+
+    async fn get_client_settings(
+        &self,
+        identity: Identity,
+        input: GetSamlClientSettingsInput,
+    ) -> Result<SamlClientSettings, CoreError> {
+        let realm = self.realm(&input.realm_name).await?;
+        ensure_policy(
+            self.policy.can_view_client(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let client = self
+            .client_repository
+            .get_by_id(realm.id, input.client_id)
+            .await
+            .map_err(|_| CoreError::NotFound)?;
+
+        self.saml_client_repository
+            .get_by_client_id(client.id)
+            .await?
+            .ok_or(CoreError::NotFound)
+    }
+
+    async fn update_client_settings(
+        &self,
+        identity: Identity,
+        input: UpdateSamlClientSettingsInput,
+    ) -> Result<SamlClientSettings, CoreError> {
+        let payload = input.payload;
+
+        self.update_settings(identity, &input.realm_name, input.client_id, |settings| {
+            settings.assertion_consumer_services = payload.assertion_consumer_services;
+            settings.single_logout_service = payload.single_logout_service;
+            settings.name_id_format = payload.name_id_format;
+            settings.sign_response = payload.sign_response;
+            settings.sign_assertions = payload.sign_assertions;
+            settings.encrypt_assertions = payload.encrypt_assertions;
+            settings.require_signed_requests = payload.require_signed_requests;
+            settings.signing_certificate = payload.signing_certificate;
+            settings.encryption_certificate = payload.encryption_certificate;
+            settings.idp_initiated_sso_url_name = payload.idp_initiated_sso_url_name;
+            settings.idp_initiated_relay_state = payload.idp_initiated_relay_state;
+            settings.assertion_lifetime_seconds = payload.assertion_lifetime_seconds;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn import_client_metadata(
+        &self,
+        identity: Identity,
+        input: ImportSamlMetadataInput,
+    ) -> Result<SamlClientSettings, CoreError> {
+        let realm = self.realm(&input.realm_name).await?;
+        ensure_policy(
+            self.policy.can_update_client(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let client = self
+            .client_repository
+            .get_by_id(realm.id, input.client_id)
+            .await
+            .map_err(|_| CoreError::NotFound)?;
+        let metadata = parse_sp_metadata(&input.metadata, &client.client_id)?;
+
+        self.update_settings(identity, &input.realm_name, input.client_id, |settings| {
+            metadata.apply_to(settings);
+            Ok(())
+        })
+        .await
+    }
+}
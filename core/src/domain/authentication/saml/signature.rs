@@ -0,0 +1,337 @@
+//! XML signatures (enveloped, exclusive c14n, RSA) and HTTP-Redirect query signatures. The
+//! RSA operations themselves belong to [`SamlKeyStore`](super::ports::SamlKeyStore); this
+//! module builds and checks what is signed.
+
+use base64::prelude::{BASE64_STANDARD, Engine as _};
+use roxmltree::Node;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use crate::domain::authentication::saml::entities::SamlSignatureAlgorithm;
+use crate::domain::authentication::saml::xml::{self, DSIG_NS, Element, invalid};
+use crate::domain::common::entities::app_errors::CoreError;
+
+pub const EXC_C14N: &str = "http://www.w3.org/2001/10/xml-exc-c14n#";
+pub const ENVELOPED: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+pub const SHA1: &str = "http://www.w3.org/2000/09/xmldsig#sha1";
+pub const SHA256: &str = "http://www.w3.org/2001/04/xmlenc#sha256";
+
+/// An enveloped signature about to be produced for the element with `ID="{id}"`.
+pub struct PendingSignature {
+    /// Children of `ds:SignedInfo`, as they appear in the document.
+    signed_info: String,
+}
+
+impl PendingSignature {
+    /// Digest the rendered element (which must not carry a signature yet).
+    pub fn new(element: &str, id: &str) -> Self {
+        let digest = BASE64_STANDARD.encode(Sha256::digest(element.as_bytes()));
+
+        let signed_info = [
+            Element::new("ds:CanonicalizationMethod")
+                .attr("Algorithm", EXC_C14N)
+                .render(),
+            Element::new("ds:SignatureMethod")
+                .attr("Algorithm", SamlSignatureAlgorithm::RsaSha256.uri())
+                .render(),
+            Element::new("ds:Reference")
+                .attr("URI", format!("#{id}"))
+                .child(
+                    Element::new("ds:Transforms")
+                        .child(
+                            Element::new("ds:Transform")
+                                .attr("Algorithm", ENVELOPED)
+                                .render(),
+                        )
+                        .child(
+                            Element::new("ds:Transform")
+                                .attr("Algorithm", EXC_C14N)
+                                .render(),
+                        )
+                        .render(),
+                )
+                .child(
+                    Element::new("ds:DigestMethod")
+                        .attr("Algorithm", SHA256)
+                        .render(),
+                )
+                .child(Element::new("ds:DigestValue").text(&digest).render())
+                .render(),
+        ]
+        .concat();
+
+        Self { signed_info }
+    }
+
+    /// The canonical `ds:SignedInfo`, i.e. the bytes to sign. As the apex of its own
+    /// canonicalization it declares the `ds` namespace itself.
+    pub fn signed_info(&self) -> String {
+        Element::new("ds:SignedInfo")
+            .namespace("ds", DSIG_NS)
+            .child(&self.signed_info)
+            .render()
+    }
+
+    /// Insert the `ds:Signature` right after the element's `saml:Issuer`, where the schema
+    /// puts it.
+    pub fn apply(
+        self,
+        element: &str,
+        signature: &[u8],
+        certificate: &str,
+    ) -> Result<String, CoreError> {
+        let signature = Element::new("ds:Signature")
+            .namespace("ds", DSIG_NS)
+            .child(
+                Element::new("ds:SignedInfo")
+                    .child(&self.signed_info)
+                    .render(),
+            )
+            .child(
+                Element::new("ds:SignatureValue")
+                    .text(&BASE64_STANDARD.encode(signature))
+                    .render(),
+            )
+            .child(
+                Element::new("ds:KeyInfo")
+                    .child(
+                        Element::new("ds:X509Data")
+                            .child(
+                                Element::new("ds:X509Certificate")
+                                    .text(certificate)
+                                    .render(),
+                            )
+                            .render(),
+                    )
+                    .render(),
+            )
+            .render();
+
+        const ISSUER_END: &str = "</saml:Issuer>";
+        let at = element
+            .find(ISSUER_END)
+            .map(|at| at + ISSUER_END.len())
+            .ok_or(CoreError::InternalServerError)?;
+
+        Ok([&element[..at], &signature, &element[at..]].concat())
+    }
+}
+
+/// A signature found on an inbound message whose reference digest already checked out;
+/// what remains is the RSA verification of `signed_info`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceivedSignature {
+    pub algorithm: SamlSignatureAlgorithm,
+    pub signed_info: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+/// The enveloped signature of `root`, if it carries one. Only a signature over the root
+/// element itself is accepted, so nothing outside the signed content is ever read.
+pub fn enveloped_signature(root: Node) -> Result<Option<ReceivedSignature>, CoreError> {
+    let Some(signature) = xml::child(root, DSIG_NS, "Signature") else {
+        return Ok(None);
+    };
+    let malformed = || invalid("malformed signature");
+
+    let signed_info = xml::child(signature, DSIG_NS, "SignedInfo").ok_or_else(malformed)?;
+
+    let c14n = xml::child(signed_info, DSIG_NS, "CanonicalizationMethod")
+        .and_then(|node| node.attribute("Algorithm"));
+    if c14n != Some(EXC_C14N) {
+        return Err(invalid("unsupported canonicalization method"));
+    }
+
+    let algorithm = xml::child(signed_info, DSIG_NS, "SignatureMethod")
+        .and_then(|node| node.attribute("Algorithm"))
+        .and_then(SamlSignatureAlgorithm::from_uri)
+        .ok_or_else(|| invalid("unsupported signature method"))?;
+
+    let mut references = xml::children(signed_info, DSIG_NS, "Reference");
+    let reference = references.next().ok_or_else(malformed)?;
+    if references.next().is_some() {
+        return Err(invalid("signature must have a single reference"));
+    }
+
+    let id = root.attribute("ID").ok_or_else(malformed)?;
+    if reference.attribute("URI") != Some(format!("#{id}").as_str()) {
+        return Err(invalid("signature does not reference the message"));
+    }
+
+    if let Some(transforms) = xml::child(reference, DSIG_NS, "Transforms") {
+        for transform in xml::children(transforms, DSIG_NS, "Transform") {
+            if !matches!(transform.attribute("Algorithm"), Some(ENVELOPED | EXC_C14N)) {
+                return Err(invalid("unsupported signature transform"));
+            }
+        }
+    }
+
+    let expected = xml::child(reference, DSIG_NS, "DigestValue")
+        .and_then(xml::text)
+        .and_then(|value| decode_base64(&value))
+        .ok_or_else(malformed)?;
+    let canonical = xml::canonicalize(root, Some(signature.id()));
+    let digest = match xml::child(reference, DSIG_NS, "DigestMethod")
+        .and_then(|node| node.attribute("Algorithm"))
+    {
+        Some(SHA256) => Sha256::digest(canonical.as_bytes()).to_vec(),
+        Some(SHA1) => Sha1::digest(canonical.as_bytes()).to_vec(),
+        _ => return Err(invalid("unsupported digest method")),
+    };
+    if digest != expected {
+        return Err(invalid("signature digest does not match the message"));
+    }
+
+    let signature_value = xml::child(signature, DSIG_NS, "SignatureValue")
+        .and_then(xml::text)
+        .and_then(|value| decode_base64(&value))
+        .ok_or_else(malformed)?;
+
+    Ok(Some(ReceivedSignature {
+        algorithm,
+        signed_info: xml::canonicalize(signed_info, None).into_bytes(),
+        signature: signature_value,
+    }))
+}
+
+/// The HTTP-Redirect signature of a query string, if it carries one. The signed octets are
+/// the message, `RelayState` and `SigAlg` parameters exactly as they were sent.
+pub fn redirect_signature(
+    raw_query: &str,
+    parameter: &str,
+) -> Result<Option<ReceivedSignature>, CoreError> {
+    let raw = |name: &str| {
+        raw_query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+    };
+    let decoded = |value: &str| {
+        urlencoding::decode(&value.replace('+', " "))
+            .map(|value| value.into_owned())
+            .map_err(|_| invalid("malformed query string"))
+    };
+
+    let Some(signature) = raw("Signature") else {
+        return Ok(None);
+    };
+    let sig_alg = raw("SigAlg").ok_or_else(|| invalid("SigAlg is required with Signature"))?;
+    let message = raw(parameter).ok_or_else(|| invalid(format!("{parameter} is required")))?;
+
+    let algorithm = SamlSignatureAlgorithm::from_uri(&decoded(sig_alg)?)
+        .ok_or_else(|| invalid("unsupported signature method"))?;
+    let signature =
+        decode_base64(&decoded(signature)?).ok_or_else(|| invalid("malformed signature"))?;
+
+    let mut signed = format!("{parameter}={message}");
+    if let Some(relay_state) = raw("RelayState") {
+        signed.push_str(&format!("&RelayState={relay_state}"));
+    }
+    signed.push_str(&format!("&SigAlg={sig_alg}"));
+
+    Ok(Some(ReceivedSignature {
+        algorithm,
+        signed_info: signed.into_bytes(),
+        signature,
+    }))
+}
+
+/// The octets to sign for an outbound HTTP-Redirect message (`value` is the deflated,
+/// base64 message), and the query string they are sent in once `signature` is appended.
+pub fn redirect_query(parameter: &str, value: &str, relay_state: Option<&str>) -> String {
+    let mut query = format!("{parameter}={}", urlencoding::encode(value));
+    if let Some(relay_state) = relay_state {
+        query.push_str(&format!("&RelayState={}", urlencoding::encode(relay_state)));
+    }
+    query.push_str(&format!(
+        "&SigAlg={}",
+        urlencoding::encode(SamlSignatureAlgorithm::RsaSha256.uri())
+    ));
+    query
+}
+
+fn decode_base64(value: &str) -> Option<Vec<u8>> {
+    let compact: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    BASE64_STANDARD.decode(compact).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::authentication::saml::xml::{ASSERTION_NS, PROTOCOL_NS};
+
+    fn message(id: &str) -> String {
+        Element::new("samlp:LogoutRequest")
+            .namespace("samlp", PROTOCOL_NS)
+            .attr("ID", id)
+            .attr("Version", "2.0")
+            .child(
+                Element::new("saml:Issuer")
+                    .namespace("saml", ASSERTION_NS)
+                    .text("https://sp.example.com")
+                    .render(),
+            )
+            .child(
+                Element::new("saml:NameID")
+                    .namespace("saml", ASSERTION_NS)
+                    .text("ferris")
+                    .render(),
+            )
+            .render()
+    }
+
+    #[test]
+    fn applied_signature_verifies_as_enveloped() {
+        let unsigned = message("_abc");
+        let pending = PendingSignature::new(&unsigned, "_abc");
+        let signed_info = pending.signed_info();
+        let signed = pending.apply(&unsigned, b"rsa-bytes", "MIIC").unwrap();
+
+        let doc = xml::parse(&signed).unwrap();
+        let received = enveloped_signature(doc.root_element()).unwrap().unwrap();
+
+        assert_eq!(received.algorithm, SamlSignatureAlgorithm::RsaSha256);
+        assert_eq!(received.signed_info, signed_info.into_bytes());
+        assert_eq!(received.signature, b"rsa-bytes");
+    }
+
+    #[test]
+    fn tampered_content_fails_the_digest() {
+        let unsigned = message("_abc");
+        let signed = PendingSignature::new(&unsigned, "_abc")
+            .apply(&unsigned, b"rsa-bytes", "MIIC")
+            .unwrap()
+            .replace(">ferris<", ">admin<");
+
+        let doc = xml::parse(&signed).unwrap();
+        assert!(enveloped_signature(doc.root_element()).is_err());
+    }
+
+    #[test]
+    fn reference_must_point_at_the_root() {
+        let unsigned = message("_abc");
+        let signed = PendingSignature::new(&unsigned, "_other")
+            .apply(&unsigned, b"rsa-bytes", "MIIC")
+            .unwrap();
+
+        let doc = xml::parse(&signed).unwrap();
+        assert!(enveloped_signature(doc.root_element()).is_err());
+    }
+
+    #[test]
+    fn redirect_signature_covers_raw_parameters_in_order() {
+        let query = "Signature=c2ln&SAMLRequest=abc%2B&SigAlg=http%3A%2F%2Fwww.w3.org%2F2001%2F04%2Fxmldsig-more%23rsa-sha256&RelayState=r%20s";
+        let received = redirect_signature(query, "SAMLRequest").unwrap().unwrap();
+
+        assert_eq!(received.signature, b"sig");
+        assert_eq!(
+            String::from_utf8(received.signed_info).unwrap(),
+            "SAMLRequest=abc%2B&RelayState=r%20s&SigAlg=http%3A%2F%2Fwww.w3.org%2F2001%2F04%2Fxmldsig-more%23rsa-sha256"
+        );
+        assert_eq!(
+            redirect_signature("SAMLRequest=abc", "SAMLRequest").unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::authentication::saml::entities::{NameIdFormat, SamlEndpoint};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateSamlClientSettingsRequest {
+    pub assertion_consumer_services: Vec<SamlEndpoint>,
+    pub single_logout_service: Option<SamlEndpoint>,
+    pub name_id_format: NameIdFormat,
+    pub sign_response: bool,
+    pub sign_assertions: bool,
+    pub encrypt_assertions: bool,
+    pub require_signed_requests: bool,
+    pub signing_certificate: Option<String>,
+    pub encryption_certificate: Option<String>,
+    pub idp_initiated_sso_url_name: Option<String>,
+    pub idp_initiated_relay_state: Option<String>,
+    pub assertion_lifetime_seconds: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetSamlClientSettingsInput {
+    pub realm_name: String,
+    pub client_id: Uuid,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateSamlClientSettingsInput {
+    pub realm_name: String,
+    pub client_id: Uuid,
+    pub payload: UpdateSamlClientSettingsRequest,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportSamlMetadataInput {
+    pub realm_name: String,
+    pub client_id: Uuid,
+    /// The service provider's metadata document.
+    pub metadata: String,
+}
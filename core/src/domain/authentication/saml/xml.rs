@@ -0,0 +1,351 @@
+//! XML for the SAML bindings. Outgoing messages are written directly in exclusive canonical
+//! form (sorted attributes, no empty-element tags, a namespace declared on the outermost
+//! element that uses it), so the bytes that are digested are the bytes that are sent.
+//! Incoming messages are parsed with `roxmltree`, which refuses DTDs and so entity expansion.
+
+use std::io::{Read, Write};
+
+use base64::prelude::{BASE64_STANDARD, Engine as _};
+use flate2::{Compression, read::DeflateDecoder, write::DeflateEncoder};
+use roxmltree::{Document, Node, NodeId};
+
+use crate::domain::common::entities::app_errors::CoreError;
+
+pub const PROTOCOL_NS: &str = "urn:oasis:names:tc:SAML:2.0:protocol";
+pub const ASSERTION_NS: &str = "urn:oasis:names:tc:SAML:2.0:assertion";
+pub const METADATA_NS: &str = "urn:oasis:names:tc:SAML:2.0:metadata";
+pub const DSIG_NS: &str = "http://www.w3.org/2000/09/xmldsig#";
+pub const XENC_NS: &str = "http://www.w3.org/2001/04/xmlenc#";
+
+/// Upper bound on a decoded message, so a small deflated payload cannot expand unbounded.
+const MAX_MESSAGE_BYTES: u64 = 256 * 1024;
+
+pub(crate) fn invalid(what: impl Into<String>) -> CoreError {
+    CoreError::InvalidSamlRequest(what.into())
+}
+
+/// An element under construction.
+pub(crate) struct Element {
+    name: &'static str,
+    namespaces: Vec<(&'static str, &'static str)>,
+    attributes: Vec<(&'static str, String)>,
+    content: String,
+}
+
+impl Element {
+    pub(crate) fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            namespaces: Vec::new(),
+            attributes: Vec::new(),
+            content: String::new(),
+        }
+    }
+
+    pub(crate) fn namespace(mut self, prefix: &'static str, uri: &'static str) -> Self {
+        self.namespaces.push((prefix, uri));
+        self
+    }
+
+    pub(crate) fn attr(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.attributes.push((name, value.into()));
+        self
+    }
+
+    pub(crate) fn opt_attr(self, name: &'static str, value: Option<impl Into<String>>) -> Self {
+        match value {
+            Some(value) => self.attr(name, value),
+            None => self,
+        }
+    }
+
+    pub(crate) fn text(mut self, text: &str) -> Self {
+        self.content.push_str(&escape_text(text));
+        self
+    }
+
+    /// Append already rendered markup.
+    pub(crate) fn child(mut self, markup: impl AsRef<str>) -> Self {
+        self.content.push_str(markup.as_ref());
+        self
+    }
+
+    pub(crate) fn render(mut self) -> String {
+        self.namespaces.sort();
+        self.attributes.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut out = format!("<{}", self.name);
+        for (prefix, uri) in &self.namespaces {
+            out.push_str(&format!(" xmlns:{}=\"{}\"", prefix, escape_attr(uri)));
+        }
+        for (name, value) in &self.attributes {
+            out.push_str(&format!(" {}=\"{}\"", name, escape_attr(value)));
+        }
+        out.push('>');
+        out.push_str(&self.content);
+        out.push_str(&format!("</{}>", self.name));
+        out
+    }
+}
+
+pub(crate) fn escape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '\r' => out.push_str("&#xD;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+pub(crate) fn escape_attr(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' => out.push_str("&#x9;"),
+            '\n' => out.push_str("&#xA;"),
+            '\r' => out.push_str("&#xD;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Decode a `SAMLRequest`/`SAMLResponse` parameter: base64, and raw DEFLATE for the
+/// HTTP-Redirect binding.
+pub(crate) fn decode_message(value: &str, deflated: bool) -> Result<String, CoreError> {
+    let compact: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    let bytes = BASE64_STANDARD
+        .decode(compact)
+        .map_err(|_| invalid("message is not base64"))?;
+
+    let bytes = if deflated {
+        let mut inflated = Vec::new();
+        DeflateDecoder::new(bytes.as_slice())
+            .take(MAX_MESSAGE_BYTES + 1)
+            .read_to_end(&mut inflated)
+            .map_err(|_| invalid("message is not DEFLATE compressed"))?;
+        inflated
+    } else {
+        bytes
+    };
+
+    if bytes.len() as u64 > MAX_MESSAGE_BYTES {
+        return Err(invalid("message is too large"));
+    }
+
+    String::from_utf8(bytes).map_err(|_| invalid("message is not UTF-8"))
+}
+
+/// Encode an outbound message for the HTTP-Redirect binding: raw DEFLATE, then base64.
+pub(crate) fn encode_redirect_message(xml: &str) -> Result<String, CoreError> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(xml.as_bytes())
+        .map_err(|_| CoreError::InternalServerError)?;
+    let deflated = encoder
+        .finish()
+        .map_err(|_| CoreError::InternalServerError)?;
+
+    Ok(BASE64_STANDARD.encode(deflated))
+}
+
+pub(crate) fn parse(xml: &str) -> Result<Document<'_>, CoreError> {
+    Document::parse(xml).map_err(|e| invalid(format!("malformed XML: {}", e)))
+}
+
+/// First child element `ns:name`.
+pub(crate) fn child<'a, 'input>(
+    node: Node<'a, 'input>,
+    ns: &str,
+    name: &str,
+) -> Option<Node<'a, 'input>> {
+    children(node, ns, name).next()
+}
+
+pub(crate) fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    ns: &str,
+    name: &str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    let (ns, name) = (ns.to_string(), name.to_string());
+    node.children()
+        .filter(move |c| c.is_element() && c.has_tag_name((ns.as_str(), name.as_str())))
+}
+
+/// Trimmed text content of an element.
+pub(crate) fn text(node: Node) -> Option<String> {
+    let text: String = node
+        .children()
+        .filter(|c| c.is_text())
+        .filter_map(|c| c.text())
+        .collect();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Exclusive XML canonicalization without comments (`xml-exc-c14n#`) of the subtree rooted
+/// at `node`, leaving out the subtree `exclude` (the enveloped signature).
+pub(crate) fn canonicalize(node: Node, exclude: Option<NodeId>) -> String {
+    let mut out = String::new();
+    canonicalize_element(node, exclude, &[], &mut out);
+    out
+}
+
+fn canonicalize_element(
+    node: Node,
+    exclude: Option<NodeId>,
+    rendered: &[(String, String)],
+    out: &mut String,
+) {
+    let input = node.document().input_text();
+    let qname = element_qname(node);
+    let prefix = qname.split_once(':').map(|(p, _)| p).unwrap_or("");
+
+    let mut utilized = vec![(
+        prefix.to_string(),
+        node.tag_name().namespace().unwrap_or("").to_string(),
+    )];
+    let mut attributes = Vec::new();
+    for attr in node.attributes() {
+        let attr_qname = &input[attr.range_qname()];
+        if let (Some((attr_prefix, _)), Some(ns)) = (attr_qname.split_once(':'), attr.namespace())
+            && attr_prefix != "xml"
+        {
+            utilized.push((attr_prefix.to_string(), ns.to_string()));
+        }
+        attributes.push((
+            attr.namespace().unwrap_or(""),
+            attr.name(),
+            attr_qname,
+            attr.value(),
+        ));
+    }
+
+    let mut declarations: Vec<(String, String)> = Vec::new();
+    for (prefix, uri) in utilized {
+        let in_scope = rendered
+            .iter()
+            .rev()
+            .find(|(p, _)| *p == prefix)
+            .map(|(_, u)| u.as_str())
+            .unwrap_or("");
+        if in_scope != uri && !declarations.iter().any(|(p, _)| *p == prefix) {
+            declarations.push((prefix, uri));
+        }
+    }
+    declarations.sort();
+    attributes.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+    out.push('<');
+    out.push_str(qname);
+    for (prefix, uri) in &declarations {
+        if prefix.is_empty() {
+            out.push_str(&format!(" xmlns=\"{}\"", escape_attr(uri)));
+        } else {
+            out.push_str(&format!(" xmlns:{}=\"{}\"", prefix, escape_attr(uri)));
+        }
+    }
+    for (_, _, attr_qname, value) in &attributes {
+        out.push_str(&format!(" {}=\"{}\"", attr_qname, escape_attr(value)));
+    }
+    out.push('>');
+
+    let mut scope = rendered.to_vec();
+    scope.extend(declarations);
+    for child in node.children() {
+        if Some(child.id()) == exclude {
+            continue;
+        }
+        if child.is_element() {
+            canonicalize_element(child, exclude, &scope, out);
+        } else if child.is_text() {
+            out.push_str(&escape_text(child.text().unwrap_or("")));
+        }
+    }
+
+    out.push_str("</");
+    out.push_str(qname);
+    out.push('>');
+}
+
+/// The element's name as written, prefix included.
+fn element_qname<'input>(node: Node<'_, 'input>) -> &'input str {
+    let input = node.document().input_text();
+    let start = node.range().start + 1;
+    let len = input[start..]
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(0);
+    &input[start..start + len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_form_sorts_attributes_and_drops_unused_namespaces() {
+        let doc = parse(
+            r#"<a:Root xmlns:b="urn:b" xmlns:a="urn:a" Z="1" A="x &amp; y"><a:Child/><b:Other
+                b:attr="v">text</b:Other><!-- comment --></a:Root>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            canonicalize(doc.root_element(), None),
+            "<a:Root xmlns:a=\"urn:a\" A=\"x &amp; y\" Z=\"1\"><a:Child></a:Child>\
+             <b:Other xmlns:b=\"urn:b\" b:attr=\"v\">text</b:Other></a:Root>"
+        );
+    }
+
+    #[test]
+    fn canonical_form_leaves_out_the_excluded_subtree() {
+        let doc = parse(r#"<a:Root xmlns:a="urn:a"><a:Keep>1</a:Keep><a:Drop>2</a:Drop></a:Root>"#)
+            .unwrap();
+        let drop = doc
+            .descendants()
+            .find(|n| n.has_tag_name(("urn:a", "Drop")))
+            .unwrap();
+
+        assert_eq!(
+            canonicalize(doc.root_element(), Some(drop.id())),
+            "<a:Root xmlns:a=\"urn:a\"><a:Keep>1</a:Keep></a:Root>"
+        );
+    }
+
+    #[test]
+    fn rendered_elements_are_already_canonical() {
+        let xml = Element::new("a:Root")
+            .namespace("a", "urn:a")
+            .attr("Version", "2.0")
+            .attr("ID", "_1")
+            .child(Element::new("a:Name").text("<Ferris & co>").render())
+            .render();
+
+        let doc = parse(&xml).unwrap();
+        assert_eq!(canonicalize(doc.root_element(), None), xml);
+    }
+
+    #[test]
+    fn decodes_deflated_redirect_messages() {
+        use flate2::{Compression, write::DeflateEncoder};
+        use std::io::Write;
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"<samlp:AuthnRequest/>").unwrap();
+        let encoded = BASE64_STANDARD.encode(encoder.finish().unwrap());
+
+        assert_eq!(
+            decode_message(&encoded, true).unwrap(),
+            "<samlp:AuthnRequest/>"
+        );
+        assert!(decode_message("not base64!", false).is_err());
+    }
+}
@@ -1,7 +1,8 @@
+use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use base64::prelude::{BASE64_URL_SAFE_NO_PAD, Engine as _};
+use base64::prelude::{BASE64_STANDARD, BASE64_URL_SAFE_NO_PAD, Engine as _};
 use chrono::{DateTime, Duration, TimeZone, Utc};
 use ferriskey_security::jwt::ports::KeyStoreRepository;
 use jsonwebtoken::{Header, Validation};
@@ -19,6 +20,11 @@ use ferriskey_organization::{
     Group, GroupId, GroupTokenRepository, OrganizationAttributeRepository, OrganizationId,
     OrganizationMemberRepository, OrganizationRepository,
 };
+use ferriskey_realm_group::{RealmGroupMembershipRepository, effective_realm_groups};
+use ferriskey_risk::{EvaluateLoginRiskInput, LoginContext, LoginRiskService};
+use ferriskey_user_profile::services::realm_profile_config;
+use ferriskey_user_profile::validation::violations_error;
+use ferriskey_user_profile::{ProfileContext, UserProfileSchemaRepository};
 
 use crate::domain::authentication::mapper_engine::{ContextGroup, ContextOrganization};
 use crate::domain::maintenance::ports::{
@@ -26,7 +32,10 @@ use crate::domain::maintenance::ports::{
 };
 use crate::domain::trident::mfa_policy;
 use crate::domain::{
-    abyss::federation::ports::FederationRepository,
+    abyss::federation::{
+        entities::FederationType, kerberos::KerberosConfig, login::FederatedLogin,
+        ports::FederationRepository,
+    },
     authentication::{
         OidcScope,
         entities::{
@@ -37,6 +46,7 @@ use crate::domain::{
         },
         mapper_engine::{MapperContext, MapperEngine, TokenType},
         ports::{AuthService, AuthSessionRepository, LoginActionToken, LoginActionTokenRepository},
+        saml::{SAML_PROTOCOL, SAML_RESPONSE_TYPE, SamlIdentity, SamlIdentityInput},
         value_objects::{
             AuthenticationResult, CodeChallengeMethod, EndSessionInput, EndSessionOutput,
             EvaluateClientScopesInput, EvaluateClientScopesResult, EvaluatedMapper, EvaluatedRoles,
@@ -50,7 +60,11 @@ use crate::domain::{
         ports::{ClientRepository, PostLogoutRedirectUriRepository, RedirectUriRepository},
         redirect_uri_matching::redirect_uri_matches_any,
     },
-    common::{entities::app_errors::CoreError, generate_random_string},
+    common::{
+        entities::app_errors::CoreError,
+        generate_random_string,
+        security_notification::{SecurityNotice, SecurityNotificationKind, SecurityNotifier},
+    },
     credential::{
         entities::{CredentialData, CredentialType},
         ports::CredentialRepository,
@@ -63,14 +77,14 @@ use crate::domain::{
         ports::{AccessTokenRepository, RefreshTokenRepository, RotateOutcome},
     },
     realm::{
-        entities::{RealmId, RealmSetting},
+        entities::{Realm, RealmId, RealmSetting},
         ports::RealmRepository,
     },
     role::entities::Role,
     seawatch::{EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType},
     session::{entities::UserSession, ports::UserSessionRepository},
     user::{
-        entities::{RequiredAction, UserAttribute},
+        entities::{RequiredAction, User, UserAttribute},
         ports::{
             UserAttributeRepository, UserRepository, UserRequiredActionRepository,
             UserRoleRepository,
@@ -85,6 +99,7 @@ use crate::domain::{
 use ferriskey_domain::token_lifetime::TokenLifetimes;
 use ferriskey_security::jwt::entities::DEFAULT_TEMPORARY_TOKEN_LIFETIME;
 
+use crate::infrastructure::abyss::federation::kerberos::SpnegoAcceptorImpl;
 use crate::infrastructure::abyss::federation::ldap::LdapClientImpl;
 
 /// Per-organization role buckets: `org_id -> (realm role names, client roles keyed by client_id)`.
@@ -373,6 +388,12 @@ fn validate_authorization_code_request(
     // RFC 6749 §4.1.3: `redirect_uri` is REQUIRED when the authorization request
     // carried one, and must be identical. Our /auth endpoint always requires it,
     // so an absent value here is a mismatch.
+    // SAML logins are answered with an assertion by the SAML endpoint; their codes must
+    // not buy OIDC tokens.
+    if auth_session.response_type == SAML_RESPONSE_TYPE {
+        return Err(CoreError::InvalidAuthorizationCode);
+    }
+
     if request_redirect_uri != Some(auth_session.redirect_uri.as_str()) {
         warn!(
             client_id = %client.client_id,
@@ -447,7 +468,7 @@ fn build_context_groups(groups: &[Group], direct_ids: &HashSet<Uuid>) -> Vec<Con
             names.reverse();
             ContextGroup {
                 id: group.id.as_uuid(),
-                organization_id: group.organization_id.as_uuid(),
+                organization_id: Some(group.organization_id.as_uuid()),
                 name: group.name.clone(),
                 path: format!("/{}", names.join("/")),
                 direct: direct_ids.contains(&group.id.as_uuid()),
@@ -470,6 +491,19 @@ struct AssembledClaims {
     effective_mappers: Vec<ProtocolMapper>,
 }
 
+/// A user who passed the first factor, handed to `complete_primary_authentication`.
+struct PrimaryAuthentication<'a> {
+    realm: &'a Realm,
+    realm_settings: Option<&'a RealmSetting>,
+    client_id: String,
+    session_code: Uuid,
+    base_url: String,
+    user: User,
+    credentials: Vec<String>,
+    has_temporary_password: bool,
+    context: LoginContext,
+}
+
 #[derive(Clone, Debug)]
 pub struct AuthServiceImpl<
     R,
@@ -500,6 +534,10 @@ pub struct AuthServiceImpl<
     SER,
     USR,
     LAT,
+    RGM,
+    LR,
+    UPS,
+    SN,
 > where
     R: RealmRepository,
     C: ClientRepository,
@@ -529,6 +567,10 @@ pub struct AuthServiceImpl<
     SER: SecurityEventRepository,
     USR: UserSessionRepository,
     LAT: LoginActionTokenRepository,
+    RGM: RealmGroupMembershipRepository,
+    LR: LoginRiskService,
+    UPS: UserProfileSchemaRepository,
+    SN: SecurityNotifier,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) client_repository: Arc<C>,
@@ -558,8 +600,13 @@ pub struct AuthServiceImpl<
     pub(crate) security_event_repository: Arc<SER>,
     pub(crate) user_session_repository: Arc<USR>,
     pub(crate) login_action_token_repository: Arc<LAT>,
+    pub(crate) realm_group_membership_repository: Arc<RGM>,
+    pub(crate) login_risk_service: Arc<LR>,
+    pub(crate) user_profile_schema_repository: Arc<UPS>,
+    pub(crate) security_notifier: Arc<SN>,
     pub(crate) mapper_engine: Arc<MapperEngine>,
     pub(crate) ldap_client: LdapClientImpl,
+    pub(crate) spnego_acceptor: SpnegoAcceptorImpl,
     pub(crate) flow_recorder: FlowRecorder,
 }
 
@@ -592,6 +639,10 @@ impl<
     SER,
     USR,
     LAT,
+    RGM,
+    LR,
+    UPS,
+    SN,
 >
     AuthServiceImpl<
         R,
@@ -622,6 +673,10 @@ impl<
         SER,
         USR,
         LAT,
+        RGM,
+        LR,
+        UPS,
+        SN,
     >
 where
     R: RealmRepository,
@@ -652,6 +707,10 @@ where
     SER: SecurityEventRepository,
     USR: UserSessionRepository,
     LAT: LoginActionTokenRepository,
+    RGM: RealmGroupMembershipRepository,
+    LR: LoginRiskService,
+    UPS: UserProfileSchemaRepository,
+    SN: SecurityNotifier,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -683,6 +742,10 @@ where
         security_event_repository: Arc<SER>,
         user_session_repository: Arc<USR>,
         login_action_token_repository: Arc<LAT>,
+        realm_group_membership_repository: Arc<RGM>,
+        login_risk_service: Arc<LR>,
+        user_profile_schema_repository: Arc<UPS>,
+        security_notifier: Arc<SN>,
         mapper_engine: Arc<MapperEngine>,
         flow_recorder: FlowRecorder,
     ) -> Self {
@@ -715,8 +778,13 @@ where
             security_event_repository,
             user_session_repository,
             login_action_token_repository,
+            realm_group_membership_repository,
+            login_risk_service,
+            user_profile_schema_repository,
+            security_notifier,
             mapper_engine,
             ldap_client: LdapClientImpl,
+            spnego_acceptor: SpnegoAcceptorImpl::new(),
             flow_recorder,
         }
     }
@@ -751,6 +819,10 @@ impl<
     SER,
     USR,
     LAT,
+    RGM,
+    LR,
+    UPS,
+    SN,
 >
     AuthServiceImpl<
         R,
@@ -781,6 +853,10 @@ impl<
         SER,
         USR,
         LAT,
+        RGM,
+        LR,
+        UPS,
+        SN,
     >
 where
     R: RealmRepository,
@@ -811,6 +887,10 @@ where
     SER: SecurityEventRepository,
     USR: UserSessionRepository,
     LAT: LoginActionTokenRepository,
+    RGM: RealmGroupMembershipRepository,
+    LR: LoginRiskService,
+    UPS: UserProfileSchemaRepository,
+    SN: SecurityNotifier,
 {
     fn expires_in_from(exp: i64) -> u32 {
         let now = Utc::now().timestamp();
@@ -828,6 +908,42 @@ where
         lockout_compute_locked_until(new_attempts, threshold, duration_seconds, now)
     }
 
+    /// Counts a failed password attempt and, once it locks the account, tells the user.
+    async fn record_failed_password(
+        &self,
+        user: &User,
+        lockout_threshold: i32,
+        lockout_duration_seconds: i32,
+        now: DateTime<Utc>,
+    ) {
+        let locked_until = Self::compute_locked_until(
+            user.failed_login_attempts + 1,
+            lockout_threshold,
+            lockout_duration_seconds,
+            now,
+        );
+        let _ = self
+            .user_repository
+            .increment_failed_login_attempts(user.id, locked_until)
+            .await;
+
+        if let Some(locked_until) = locked_until {
+            self.security_notifier
+                .notify(
+                    SecurityNotice::new(
+                        user.realm_id,
+                        user.id,
+                        SecurityNotificationKind::AccountLocked,
+                    )
+                    .with_detail(
+                        "locked_until",
+                        locked_until.format("%Y-%m-%d %H:%M UTC").to_string(),
+                    ),
+                )
+                .await;
+        }
+    }
+
     async fn resolve_token_lifetimes(
         &self,
         realm_id: RealmId,
@@ -1050,7 +1166,32 @@ where
             .unwrap_or_default()
             .into_iter()
             .collect();
-        let groups = build_context_groups(&effective_groups, &direct_group_ids);
+        let mut groups = build_context_groups(&effective_groups, &direct_group_ids);
+
+        // Realm-level groups join the same claim; they carry no organization.
+        let realm_group_direct_ids: HashSet<Uuid> = self
+            .realm_group_membership_repository
+            .list_direct_group_ids_for_user(input.user_id)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+        let realm_groups = self
+            .realm_group_membership_repository
+            .list_effective_groups_for_user(input.user_id)
+            .await
+            .unwrap_or_default();
+        groups.extend(
+            effective_realm_groups(realm_groups, &realm_group_direct_ids)
+                .into_iter()
+                .map(|effective| ContextGroup {
+                    id: effective.group.id.as_uuid(),
+                    organization_id: None,
+                    name: effective.group.name,
+                    path: effective.path,
+                    direct: effective.direct,
+                }),
+        );
 
         // Load user organization memberships with their attributes
         let org_memberships = self
@@ -1110,11 +1251,33 @@ where
             .await
             .unwrap_or_default();
 
-        let user_attributes: HashMap<String, serde_json::Value> = raw_user_attributes
+        // Declared integers and booleans are emitted as JSON numbers and booleans.
+        let profile =
+            realm_profile_config(self.user_profile_schema_repository.as_ref(), input.realm_id)
+                .await
+                .unwrap_or_default();
+
+        let mut user_attributes: HashMap<String, serde_json::Value> = raw_user_attributes
             .into_iter()
-            .map(|a: UserAttribute| (a.key, serde_json::Value::String(a.value)))
+            .map(|a: UserAttribute| {
+                let value = profile.claim_value(&a.key, a.value);
+                (a.key, value)
+            })
             .collect();
 
+        // Attributes inherited from realm groups fill in keys the user does not set themselves.
+        let inherited_attributes = self
+            .realm_group_membership_repository
+            .list_inherited_attributes_for_user(input.user_id)
+            .await
+            .unwrap_or_default();
+        for (key, value) in inherited_attributes {
+            if let Entry::Vacant(entry) = user_attributes.entry(key) {
+                let value = profile.claim_value(entry.key(), value);
+                entry.insert(value);
+            }
+        }
+
         // Build mapper context
         let context = MapperContext {
             user_id: input.user_id,
@@ -1358,6 +1521,125 @@ where
         })
     }
 
+    /// Redeem the code of a completed SAML login. Mirrors the authorization code grant:
+    /// the code is single use, bound to its realm and lifetime, and refused while the
+    /// account owes a required action; the SSO session it opens is the assertion's
+    /// `SessionIndex`.
+    pub async fn issue_saml_identity(
+        &self,
+        input: SamlIdentityInput,
+    ) -> Result<SamlIdentity, CoreError> {
+        let auth_session = self
+            .auth_session_repository
+            .get_by_code(input.code)
+            .await
+            .map_err(|_| CoreError::InvalidAuthorizationCode)?
+            .ok_or(CoreError::InvalidAuthorizationCode)?;
+
+        if auth_session.authenticated
+            || auth_session.response_type != SAML_RESPONSE_TYPE
+            || auth_session.realm_id != input.realm_id
+            || Utc::now() >= auth_session.expires_at
+        {
+            return Err(CoreError::InvalidAuthorizationCode);
+        }
+
+        let user_id = auth_session.user_id.ok_or(CoreError::NotFound)?;
+        let user = self.user_repository.get_by_id(user_id).await?;
+
+        let pending_step = self
+            .resolve_pending_auth_step(user_id, input.realm_id)
+            .await?;
+        refuse_token_issuance_when_actions_pending(pending_step.as_ref())?;
+
+        let client = self
+            .client_repository
+            .get_by_id(input.realm_id, auth_session.client_id)
+            .await
+            .map_err(|_| CoreError::InvalidClient)?;
+        let scope = self
+            .resolve_scopes_for_client(client.id, Some(auth_session.scope.clone()))
+            .await?;
+        let lifetimes = self
+            .resolve_token_lifetimes(input.realm_id, client.id)
+            .await?;
+
+        let user_session = self
+            .create_user_session(user.id, input.realm_id, lifetimes.refresh_token)
+            .await?;
+
+        let assembled = self
+            .assemble_token_claims(&GenerateTokenInput {
+                base_url: input.base_url,
+                realm_name: input.realm_name,
+                user_id: user.id,
+                username: user.username.clone(),
+                firstname: user.firstname.clone().unwrap_or_default(),
+                lastname: user.lastname.clone().unwrap_or_default(),
+                email_verified: user.email_verified,
+                client_id: client.client_id.clone(),
+                client_uuid: client.id,
+                email: user.email.clone().unwrap_or_default(),
+                realm_id: input.realm_id,
+                scope: Some(scope),
+                access_token_lifetime: lifetimes.access_token,
+                refresh_token_lifetime: lifetimes.refresh_token,
+                id_token_lifetime: lifetimes.id_token,
+                nonce: None,
+                refresh_jti_override: None,
+                session_id: Some(user_session.id),
+            })
+            .await?;
+
+        self.auth_session_repository
+            .update_authenticated(auth_session.id, true)
+            .await
+            .map_err(|e| {
+                warn!("Failed to mark auth session as authenticated: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(SamlIdentity {
+            auth_session,
+            user,
+            session_id: user_session.id,
+            session_expires_at: user_session.expires_at,
+            claims: assembled.id_mapper_claims.unwrap_or_default(),
+        })
+    }
+
+    /// The user of a live SSO session of `realm_id`.
+    pub async fn find_saml_session_user(
+        &self,
+        realm_id: RealmId,
+        session_id: Uuid,
+    ) -> Result<Option<User>, CoreError> {
+        let session = self
+            .user_session_repository
+            .find_by_id(session_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        match session {
+            Some(session)
+                if session.realm_id == Uuid::from(realm_id) && session.expires_at > Utc::now() =>
+            {
+                Ok(Some(self.user_repository.get_by_id(session.user_id).await?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn end_saml_session(
+        &self,
+        realm_id: RealmId,
+        session_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), CoreError> {
+        self.revoke_session_cascade(session_id, realm_id, user_id)
+            .await
+    }
+
     /// Open the SSO session a login is bound to, and record it in the audit trail.
     ///
     /// The session is given the same lifetime as the refresh token, so that the
@@ -1693,6 +1975,119 @@ where
         Ok(is_valid)
     }
 
+    fn federated_login(&self) -> FederatedLogin<F, U, CR, LdapClientImpl> {
+        FederatedLogin::new(
+            self.federation_repository.clone(),
+            self.user_repository.clone(),
+            self.credential_repository.clone(),
+            Arc::new(self.ldap_client.clone()),
+        )
+    }
+
+    /// SPNEGO sign-in: the realm's enabled Kerberos providers are tried by priority and the
+    /// first one whose keytab accepts the ticket maps the client principal to a user.
+    #[allow(clippy::too_many_arguments)]
+    async fn handle_negotiate_authentication(
+        &self,
+        token: String,
+        realm: &Realm,
+        client_id: String,
+        session_code: Uuid,
+        base_url: String,
+        auth_session: AuthSession,
+        context: LoginContext,
+    ) -> Result<AuthenticateOutput, CoreError> {
+        let token = BASE64_STANDARD
+            .decode(token.trim())
+            .map_err(|_| CoreError::InvalidCredentials)?;
+
+        let mut providers = self
+            .federation_repository
+            .list_by_realm(realm.id.into())
+            .await?;
+        providers.retain(|p| p.enabled && p.provider_type == FederationType::Kerberos);
+        providers.sort_by_key(|p| p.priority);
+
+        let mut accepted = None;
+        for provider in providers {
+            let config = match KerberosConfig::from_provider(&provider) {
+                Ok(config) => config,
+                Err(e) => {
+                    warn!("Skipping Kerberos provider '{}': {}", provider.name, e);
+                    continue;
+                }
+            };
+
+            match self.spnego_acceptor.accept(&config, &token).await {
+                Ok(principal) => {
+                    accepted = Some((provider, config, principal));
+                    break;
+                }
+                Err(e) => info!(
+                    "Kerberos provider '{}' rejected the ticket: {}",
+                    provider.name, e
+                ),
+            }
+        }
+
+        let (provider, config, principal) = accepted.ok_or(CoreError::InvalidCredentials)?;
+        let user = self
+            .federated_login()
+            .kerberos_user(realm.id, &provider, &config, &principal)
+            .await?
+            .ok_or_else(|| {
+                info!("No user for Kerberos principal {}", principal);
+                CoreError::InvalidCredentials
+            })?;
+
+        if !user.enabled {
+            return Err(CoreError::UserDisabled);
+        }
+        if user.is_locked(Utc::now()) {
+            return Err(CoreError::AccountLocked);
+        }
+
+        let client = self
+            .client_repository
+            .get_by_client_id(client_id.clone(), realm.id)
+            .await
+            .map_err(|_| CoreError::InvalidClient)?;
+        self.ensure_client_available(&client, realm.id, &user)
+            .await?;
+
+        let credentials = self
+            .credential_repository
+            .get_credentials_by_user_id(user.id)
+            .await
+            .map_err(|_| CoreError::GetUserCredentialsError)?
+            .iter()
+            .map(|cred| cred.credential_type.to_string())
+            .collect();
+        let realm_settings = self.realm_repository.get_realm_settings(realm.id).await?;
+
+        info!(
+            "User {} signed in with Kerberos as {}",
+            user.username, principal
+        );
+
+        let auth_result = self
+            .complete_primary_authentication(PrimaryAuthentication {
+                realm,
+                realm_settings: realm_settings.as_ref(),
+                client_id,
+                session_code,
+                base_url,
+                user,
+                credentials,
+                has_temporary_password: false,
+                context,
+            })
+            .await?;
+
+        self.determine_next_step(auth_result, session_code, auth_session)
+            .await
+    }
+
     async fn resolve_pending_auth_step(
         &self,
         user_id: Uuid,
@@ -2194,30 +2589,19 @@ where
         let is_valid = match credential {
             Ok(is_valid) => is_valid,
             Err(_) => {
-                let locked_until = Self::compute_locked_until(
-                    user.failed_login_attempts + 1,
+                self.record_failed_password(
+                    &user,
                     lockout_threshold,
                     lockout_duration_seconds,
                     now,
-                );
-                let _ = self
-                    .user_repository
-                    .increment_failed_login_attempts(user.id, locked_until)
-                    .await;
+                )
+                .await;
                 return Err(CoreError::Invalid);
             }
         };
 
         if !is_valid {
-            let locked_until = Self::compute_locked_until(
-                user.failed_login_attempts + 1,
-                lockout_threshold,
-                lockout_duration_seconds,
-                now,
-            );
-            let _ = self
-                .user_repository
-                .increment_failed_login_attempts(user.id, locked_until)
+            self.record_failed_password(&user, lockout_threshold, lockout_duration_seconds, now)
                 .await;
             return Err(CoreError::Invalid);
         }
@@ -2448,32 +2832,23 @@ where
     ) -> Result<AuthenticateOutput, CoreError> {
         let flow_id = auth_session.compass_flow_id.map(FlowId);
         let step_start = Utc::now();
+        let session_code = params.session_code;
 
-        let auth_result = self
-            .using_session_code(
-                params.realm_name,
-                params.client_id,
-                params.session_code,
-                params.username,
-                params.password,
-                params.base_url,
-            )
-            .await
-            .map_err(|e| {
-                warn!("authentication using session code error: {:?}", e);
-                if let Some(ref fid) = flow_id {
-                    let duration = (Utc::now() - step_start).num_milliseconds();
-                    self.flow_recorder.record_step(
-                        fid.clone(),
-                        FlowStepName::CredentialValidation,
-                        StepStatus::Failure,
-                        Some(duration),
-                        Some(format!("{:?}", e)),
-                        None,
-                    );
-                }
-                e
-            })?;
+        let auth_result = self.using_session_code(params).await.map_err(|e| {
+            warn!("authentication using session code error: {:?}", e);
+            if let Some(ref fid) = flow_id {
+                let duration = (Utc::now() - step_start).num_milliseconds();
+                self.flow_recorder.record_step(
+                    fid.clone(),
+                    FlowStepName::CredentialValidation,
+                    StepStatus::Failure,
+                    Some(duration),
+                    Some(format!("{:?}", e)),
+                    None,
+                );
+            }
+            e
+        })?;
 
         if let Some(ref fid) = flow_id {
             let duration = (Utc::now() - step_start).num_milliseconds();
@@ -2487,7 +2862,7 @@ where
             );
         }
 
-        self.determine_next_step(auth_result, params.session_code, auth_session)
+        self.determine_next_step(auth_result, session_code, auth_session)
             .await
     }
 
@@ -2571,13 +2946,19 @@ where
 
     async fn using_session_code(
         &self,
-        realm_name: String,
-        client_id: String,
-        session_code: Uuid,
-        username: String,
-        password: String,
-        base_url: String,
+        params: CredentialsAuthParams,
     ) -> Result<AuthenticationResult, CoreError> {
+        let CredentialsAuthParams {
+            realm_name,
+            client_id,
+            session_code,
+            base_url,
+            username,
+            password,
+            client_ip,
+            user_agent,
+        } = params;
+
         let realm = self
             .realm_repository
             .get_by_name(&realm_name)
@@ -2600,61 +2981,28 @@ where
             .map(|s| s.login_aliases.clone())
             .unwrap_or_default();
 
-        let user = crate::domain::authentication::login_resolver::resolve_user_by_identifier(
+        let user = match crate::domain::authentication::login_resolver::resolve_user_by_identifier(
             self.user_repository.as_ref(),
             &username,
             realm.id,
             &login_aliases,
         )
         .await?
-        .ok_or(CoreError::UserNotFound)?;
+        {
+            Some(user) => user,
+            None => self
+                .federated_login()
+                .import_user(realm.id, &username, &password)
+                .await?
+                .ok_or(CoreError::UserNotFound)?,
+        };
 
         if !user.enabled {
             return Err(CoreError::UserDisabled);
         }
 
-        // Check maintenance mode
-        if client.maintenance_enabled {
-            let user_roles = self
-                .user_role_repository
-                .get_user_roles(user.id)
-                .await
-                .map_err(|_| CoreError::InternalServerError)?;
-            let role_ids: Vec<Uuid> = user_roles.iter().map(|r| r.id).collect();
-
-            let allowed_user_ids = self
-                .maintenance_whitelist_repository
-                .get_whitelisted_user_ids(client.id)
-                .await?;
-            let allowed_role_ids = self
-                .maintenance_whitelist_repository
-                .get_whitelisted_role_ids(client.id)
-                .await?;
-            let realm_allowed_user_ids = self
-                .realm_maintenance_whitelist_repository
-                .get_whitelisted_user_ids(realm.id)
-                .await?;
-            let realm_allowed_role_ids = self
-                .realm_maintenance_whitelist_repository
-                .get_whitelisted_role_ids(realm.id)
-                .await?;
-
-            let is_allowed = allowed_user_ids.contains(&user.id)
-                || role_ids.iter().any(|r| allowed_role_ids.contains(r))
-                || realm_allowed_user_ids.contains(&user.id)
-                || role_ids.iter().any(|r| realm_allowed_role_ids.contains(r));
-
-            if !is_allowed {
-                let reason = client
-                    .maintenance_reason
-                    .unwrap_or_else(|| "This service is currently under maintenance".to_string());
-                warn!(
-                    "User {} denied access to client {} (maintenance mode)",
-                    user.username, client.name
-                );
-                return Err(CoreError::ClientUnderMaintenance(reason));
-            }
-        }
+        self.ensure_client_available(&client, realm.id, &user)
+            .await?;
 
         let realm_settings = self.realm_repository.get_realm_settings(realm.id).await?;
         let lockout_threshold = realm_settings
@@ -2689,26 +3037,23 @@ where
             }
         );
 
+        let directory_provider = match federation_mapping {
+            Some(mapping) => {
+                self.federated_login()
+                    .directory_provider(&user, &mapping)
+                    .await?
+            }
+            None => None,
+        };
+
         let (has_valid_password, credentials, has_temporary_password) =
-            if let Some(mapping) = federation_mapping {
-                // User is federated - authenticate via LDAP
+            if let Some(provider) = directory_provider {
+                // The directory owns the password - authenticate via LDAP
                 info!(
                     "User {} is federated (provider_id: {}), authenticating via LDAP",
-                    user.username, mapping.provider_id
+                    user.username, provider.id
                 );
 
-                let provider = self
-                    .federation_repository
-                    .get_by_id(mapping.provider_id)
-                    .await
-                    .map_err(|_| CoreError::InternalServerError)?
-                    .ok_or(CoreError::InternalServerError)?;
-
-                if !provider.enabled {
-                    error!("Federation provider {} is disabled", provider.name);
-                    return Err(CoreError::InvalidPassword);
-                }
-
                 // Authenticate via LDAP
                 let ldap_auth_result = match self
                     .ldap_client
@@ -2731,9 +3076,9 @@ where
                 // Federated users don't have local credentials
                 (ldap_auth_result, vec!["federated".to_string()], false)
             } else {
-                // User is not federated - use local password hash
+                // Local user, or a federated user whose password now lives here
                 info!(
-                    "User {} is not federated, using local password hash",
+                    "Validating user {} against the local password hash",
                     user.username
                 );
 
@@ -2786,15 +3131,7 @@ This is a server error that should be investigated. Do not forward back this mes
             };
 
         if !has_valid_password {
-            let locked_until = Self::compute_locked_until(
-                user.failed_login_attempts + 1,
-                lockout_threshold,
-                lockout_duration_seconds,
-                now,
-            );
-            let _ = self
-                .user_repository
-                .increment_failed_login_attempts(user.id, locked_until)
+            self.record_failed_password(&user, lockout_threshold, lockout_duration_seconds, now)
                 .await;
             return Err(CoreError::InvalidPassword);
         }
@@ -2804,6 +3141,62 @@ This is a server error that should be investigated. Do not forward back this mes
             .reset_failed_login_attempts(user.id)
             .await;
 
+        self.complete_primary_authentication(PrimaryAuthentication {
+            realm: &realm,
+            realm_settings: realm_settings.as_ref(),
+            client_id,
+            session_code,
+            base_url,
+            user,
+            credentials,
+            has_temporary_password,
+            context: LoginContext {
+                ip: client_ip,
+                user_agent,
+            },
+        })
+        .await
+    }
+
+    /// Everything after the first factor succeeded: the login risk verdict, the step token,
+    /// pending required actions and the MFA decision.
+    async fn complete_primary_authentication(
+        &self,
+        input: PrimaryAuthentication<'_>,
+    ) -> Result<AuthenticationResult, CoreError> {
+        let PrimaryAuthentication {
+            realm,
+            realm_settings,
+            client_id,
+            session_code,
+            base_url,
+            user,
+            credentials,
+            has_temporary_password,
+            context,
+        } = input;
+
+        let has_otp_credentials = credentials.iter().any(|cred| cred == "otp");
+
+        let risk = self
+            .login_risk_service
+            .evaluate_login_risk(EvaluateLoginRiskInput {
+                realm: realm.clone(),
+                user: user.clone(),
+                context,
+                has_mfa_credential: has_otp_credentials,
+            })
+            .await?;
+        if risk.blocks() {
+            info!(
+                "Sign-in of user {} blocked by the login risk policy (score {})",
+                user.username, risk.score
+            );
+            return Err(CoreError::Forbidden(
+                "sign-in blocked by the realm's risk policy".to_string(),
+            ));
+        }
+
         let auth_session = self
             .auth_session_repository
             .get_by_session_code(session_code)
@@ -2814,7 +3207,7 @@ This is a server error that should be investigated. Do not forward back this mes
 
         // A step token must not borrow the (possibly hours-long) access-token
         // lifetime — it only has to survive the next hop of the login flow.
-        let temporary_lifetime = temporary_token_lifetime(realm_settings.as_ref());
+        let temporary_lifetime = temporary_token_lifetime(realm_settings);
 
         let auth_session_id = auth_session.id;
         let mut jwt_claim = JwtClaim::new(
@@ -2845,8 +3238,7 @@ This is a server error that should be investigated. Do not forward back this mes
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
-        // Resolve MFA enforcement: realm-level or role-level require_mfa.
-        let has_otp_credentials = credentials.iter().any(|cred| cred == "otp");
+        // Resolve MFA enforcement: realm-level or role-level require_mfa, or a risky login.
         let user_roles = self
             .user_role_repository
             .get_user_roles(user.id)
@@ -2856,7 +3248,8 @@ This is a server error that should be investigated. Do not forward back this mes
         let mut effective_required_actions = user.required_actions.clone();
 
         let mfa_required_action = (!has_temporary_password
-            && mfa_policy::user_requires_mfa(realm_settings.as_ref(), &user_roles))
+            && (mfa_policy::user_requires_mfa(realm_settings, &user_roles)
+                || risk.requires_step_up()))
         .then(|| {
             mfa_policy::required_action_for_mfa(has_otp_credentials)
                 .filter(|a| !effective_required_actions.contains(a))
@@ -2906,6 +3299,59 @@ This is a server error that should be investigated. Do not forward back this mes
         })
     }
 
+    /// Refuse users outside the maintenance whitelists while the client is under maintenance.
+    async fn ensure_client_available(
+        &self,
+        client: &Client,
+        realm_id: RealmId,
+        user: &User,
+    ) -> Result<(), CoreError> {
+        if client.maintenance_enabled {
+            let user_roles = self
+                .user_role_repository
+                .get_user_roles(user.id)
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+            let role_ids: Vec<Uuid> = user_roles.iter().map(|r| r.id).collect();
+
+            let allowed_user_ids = self
+                .maintenance_whitelist_repository
+                .get_whitelisted_user_ids(client.id)
+                .await?;
+            let allowed_role_ids = self
+                .maintenance_whitelist_repository
+                .get_whitelisted_role_ids(client.id)
+                .await?;
+            let realm_allowed_user_ids = self
+                .realm_maintenance_whitelist_repository
+                .get_whitelisted_user_ids(realm_id)
+                .await?;
+            let realm_allowed_role_ids = self
+                .realm_maintenance_whitelist_repository
+                .get_whitelisted_role_ids(realm_id)
+                .await?;
+
+            let is_allowed = allowed_user_ids.contains(&user.id)
+                || role_ids.iter().any(|r| allowed_role_ids.contains(r))
+                || realm_allowed_user_ids.contains(&user.id)
+                || role_ids.iter().any(|r| realm_allowed_role_ids.contains(r));
+
+            if !is_allowed {
+                let reason = client
+                    .maintenance_reason
+                    .clone()
+                    .unwrap_or_else(|| "This service is currently under maintenance".to_string());
+                warn!(
+                    "User {} denied access to client {} (maintenance mode)",
+                    user.username, client.name
+                );
+                return Err(CoreError::ClientUnderMaintenance(reason));
+            }
+        }
+
+        Ok(())
+    }
+
     async fn handle_token_refresh(
         &self,
         token: String,
@@ -3129,6 +3575,10 @@ impl<
     SER,
     USR,
     LAT,
+    RGM,
+    LR,
+    UPS,
+    SN,
 > AuthService
     for AuthServiceImpl<
         R,
@@ -3159,6 +3609,10 @@ impl<
         SER,
         USR,
         LAT,
+        RGM,
+        LR,
+        UPS,
+        SN,
     >
 where
     R: RealmRepository,
@@ -3189,6 +3643,10 @@ where
     SER: SecurityEventRepository,
     USR: UserSessionRepository,
     LAT: LoginActionTokenRepository,
+    RGM: RealmGroupMembershipRepository,
+    LR: LoginRiskService,
+    UPS: UserProfileSchemaRepository,
+    SN: SecurityNotifier,
 {
     async fn auth(&self, input: AuthInput) -> Result<AuthOutput, CoreError> {
         let realm = self
@@ -3216,7 +3674,7 @@ where
             return Err(CoreError::InvalidRedirectUri);
         }
 
-        if !client.enabled {
+        if !client.enabled || client.protocol == SAML_PROTOCOL {
             return Err(CoreError::InvalidClient);
         }
 
@@ -3274,14 +3732,33 @@ where
             None,
         );
 
-        let login_url = format!(
+        let mut login_url = format!(
             "?client_id={}&redirect_uri={}&state={}",
             client.client_id,
             input.redirect_uri,
             input.state.unwrap_or_default()
         );
+        // Forwarded so the login portal renders in the language the client asked for.
+        if let Some(ui_locales) = input.ui_locales.filter(|l| !l.trim().is_empty()) {
+            login_url.push_str(&format!("&ui_locales={}", urlencoding::encode(&ui_locales)));
+        }
 
-        Ok(AuthOutput { login_url, session })
+        let negotiate = self
+            .federation_repository
+            .list_by_realm(realm.id.into())
+            .await
+            .map(|providers| {
+                providers
+                    .iter()
+                    .any(|p| p.enabled && p.provider_type == FederationType::Kerberos)
+            })
+            .unwrap_or(false);
+
+        Ok(AuthOutput {
+            login_url,
+            session,
+            negotiate,
+        })
     }
 
     async fn get_certs(&self, realm_name: String) -> Result<Vec<JwkKey>, CoreError> {
@@ -3558,6 +4035,21 @@ where
                 self.handle_token_refresh(token, realm.id, auth_session, input.session_code)
                     .await
             }
+            AuthenticationMethod::Negotiate { token } => {
+                self.handle_negotiate_authentication(
+                    token,
+                    &realm,
+                    input.client_id,
+                    input.session_code,
+                    input.base_url,
+                    auth_session,
+                    LoginContext {
+                        ip: input.client_ip,
+                        user_agent: input.user_agent,
+                    },
+                )
+                .await
+            }
             AuthenticationMethod::UserCredentials { username, password } => {
                 let params = CredentialsAuthParams {
                     realm_name: input.realm_name,
@@ -3566,6 +4058,8 @@ where
                     base_url: input.base_url,
                     username,
                     password,
+                    client_ip: input.client_ip,
+                    user_agent: input.user_agent,
                 };
 
                 self.handle_user_credentials_authentication(params, auth_session)
@@ -3591,6 +4085,16 @@ where
             .await?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let profile =
+            realm_profile_config(self.user_profile_schema_repository.as_ref(), realm.id).await?;
+        profile
+            .validate_changes(
+                ProfileContext::Registration,
+                &HashMap::new(),
+                &input.attributes,
+            )
+            .map_err(violations_error)?;
+
         let firstname = input.first_name;
         let lastname = input.last_name;
 
@@ -3626,6 +4130,17 @@ where
             .await
             .map_err(|_| CoreError::CreateCredentialError)?;
 
+        let attributes: HashMap<String, String> = input
+            .attributes
+            .into_iter()
+            .filter(|(_, value)| !value.trim().is_empty())
+            .collect();
+        if !attributes.is_empty() {
+            self.user_attribute_repository
+                .upsert_many(user.id, realm.id, attributes)
+                .await?;
+        }
+
         if email_verification_enabled {
             // Add verify_email required action
             self.user_required_action_repository
@@ -666,6 +666,54 @@ where
         Ok(client.secret_str().map(str::to_string))
     }
 
+    async fn rotate_client_secret(
+        &self,
+        identity: Identity,
+        input: GetClientInput,
+    ) -> Result<String, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        ensure_policy(
+            self.policy.can_update_client(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let client = self
+            .client_repository
+            .get_by_id(realm.id, input.client_id)
+            .await
+            .map_err(|_| CoreError::NotFound)?;
+
+        // Public clients authenticate with PKCE alone and have no secret to rotate.
+        if client.public_client {
+            return Err(CoreError::Invalid);
+        }
+
+        let secret = generate_random_string();
+        self.client_repository
+            .update_secret(realm.id, client.id, secret.clone())
+            .await?;
+
+        self.security_event_repository
+            .store_event(
+                SecurityEvent::new(
+                    realm.id,
+                    SecurityEventType::ClientSecretRotated,
+                    EventStatus::Success,
+                    identity.id(),
+                )
+                .with_target("client".to_string(), client.id, None),
+            )
+            .await?;
+
+        Ok(secret)
+    }
+
     async fn get_client_roles(
         &self,
         identity: Identity,
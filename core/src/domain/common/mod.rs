@@ -6,6 +6,7 @@ pub mod email;
 pub mod entities;
 pub mod policies;
 pub mod ports;
+pub mod security_notification;
 pub mod services;
 
 pub struct AppConfig {
@@ -18,6 +19,24 @@ pub struct FerriskeyConfig {
     /// Public origin the admin console is served from. Seeding needs it to register
     /// the console's callback as an exact redirect URI — see [`console_callback_uri`].
     pub webapp_url: String,
+    /// MaxMind-format (`.mmdb`) GeoIP database the login risk engine resolves client
+    /// addresses with. Without one, only the new-device signal is raised.
+    pub geoip_database: Option<String>,
+    /// FIDO Metadata Service BLOB (the JWT downloaded from `mds3.fidoalliance.org`) that
+    /// WebAuthn attestations are verified against. Without one, AAGUID restrictions apply to
+    /// the reported AAGUID only and FIPS-only policies cannot be enabled.
+    pub fido_metadata_blob: Option<String>,
+    /// Root certificate the BLOB signature is checked against.
+    pub fido_metadata_root_certificate: Option<String>,
+    /// Maildir the outbox writes emails into instead of sending them over SMTP, for local
+    /// development.
+    pub mail_maildir: Option<String>,
+    /// How long sent and failed outbox emails, whose bodies carry tokens, are kept before
+    /// being deleted. Resending is possible until then.
+    pub mail_outbox_retention_hours: u32,
+    /// DNS-over-HTTPS JSON endpoint organization domain verification looks TXT records up
+    /// through.
+    pub dns_over_https_endpoint: String,
 }
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,3 @@
+//! The `SecurityNotifier` port lives in `ferriskey-domain`, next to `EmailPort`, so the lib
+//! crates whose services trigger notifications can depend on it. Re-exported for `core`.
+pub use ferriskey_domain::common::security_notification::*;
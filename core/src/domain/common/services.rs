@@ -16,6 +16,7 @@ use crate::domain::{
     },
     credential::ports::CredentialRepository,
     crypto::HasherRepository,
+    organization::ports::OrganizationMemberRoleRepository,
     realm::ports::RealmRepository,
     role::{
         entities::permission::Permissions, ports::RoleRepository, value_objects::CreateRoleRequest,
@@ -27,7 +28,7 @@ use crate::domain::{
 };
 
 #[derive(Clone, Debug)]
-pub struct CoreServiceImpl<R, K, C, U, RO, UR, H, CR, RU>
+pub struct CoreServiceImpl<R, K, C, U, RO, UR, H, CR, RU, OMR>
 where
     R: RealmRepository,
     K: KeyStoreRepository,
@@ -38,6 +39,7 @@ where
     H: HasherRepository,
     CR: CredentialRepository,
     RU: RedirectUriRepository,
+    OMR: OrganizationMemberRoleRepository,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) keystore_repository: Arc<K>,
@@ -48,9 +50,10 @@ where
     pub(crate) hasher_repository: Arc<H>,
     pub(crate) credential_repository: Arc<CR>,
     pub(crate) redirect_uri_repository: Arc<RU>,
+    pub(crate) organization_member_role_repository: Arc<OMR>,
 }
 
-impl<R, K, C, U, RO, UR, H, CR, RU> CoreServiceImpl<R, K, C, U, RO, UR, H, CR, RU>
+impl<R, K, C, U, RO, UR, H, CR, RU, OMR> CoreServiceImpl<R, K, C, U, RO, UR, H, CR, RU, OMR>
 where
     R: RealmRepository,
     K: KeyStoreRepository,
@@ -61,6 +64,7 @@ where
     H: HasherRepository,
     CR: CredentialRepository,
     RU: RedirectUriRepository,
+    OMR: OrganizationMemberRoleRepository,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -73,6 +77,7 @@ where
         hasher_repository: Arc<H>,
         credential_repository: Arc<CR>,
         redirect_uri_repository: Arc<RU>,
+        organization_member_role_repository: Arc<OMR>,
     ) -> Self {
         CoreServiceImpl {
             realm_repository,
@@ -84,11 +89,13 @@ where
             hasher_repository,
             credential_repository,
             redirect_uri_repository,
+            organization_member_role_repository,
         }
     }
 }
 
-impl<R, K, C, U, RO, UR, H, CR, RU> CoreService for CoreServiceImpl<R, K, C, U, RO, UR, H, CR, RU>
+impl<R, K, C, U, RO, UR, H, CR, RU, OMR> CoreService
+    for CoreServiceImpl<R, K, C, U, RO, UR, H, CR, RU, OMR>
 where
     R: RealmRepository,
     K: KeyStoreRepository,
@@ -99,6 +106,7 @@ where
     H: HasherRepository,
     CR: CredentialRepository,
     RU: RedirectUriRepository,
+    OMR: OrganizationMemberRoleRepository,
 {
     async fn initialize_application(
         &self,
@@ -348,6 +356,13 @@ where
             }
         }
 
+        // Organization admin roles are seeded by migration for existing realms; the master
+        // realm is created after migrations on a fresh install, so seed it here.
+        self.organization_member_role_repository
+            .seed_admin_roles(realm.id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
         let password_credential_exists = self
             .credential_repository
             .get_password_credential(user.id)
@@ -0,0 +1,4 @@
+//! The email outbox lives in the `ferriskey-mail` lib crate (`outbox` module) — entities, ports,
+//! the `EmailOutboxPolicy` impl, the generic `EmailOutboxServiceImpl` and its worker task. The
+//! SeaORM-backed repository stays in `core` under `infrastructure/`.
+pub use ferriskey_mail::outbox::*;
@@ -0,0 +1,5 @@
+//! Localization lives in the `ferriskey-localization` lib crate — locales, message bundles,
+//! the built-in texts, locale resolution, ports, the `LocalizationAccessPolicy` impl and the
+//! generic `LocalizationServiceImpl`. The SeaORM-backed repositories stay in `core` under
+//! `infrastructure/localization`.
+pub use ferriskey_localization::*;
@@ -1,3 +1,4 @@
 pub use ferriskey_domain::maintenance::ports::{
-    MaintenanceService, MaintenanceWhitelistRepository, RealmMaintenanceWhitelistRepository,
+    MaintenanceService, MaintenanceWhitelistRepository, MaintenanceWindowRepository,
+    RealmMaintenanceWhitelistRepository,
 };
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use serde_json::json;
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 use crate::domain::{
@@ -12,9 +12,15 @@ use crate::domain::{
         policies::{FerriskeyPolicy, ensure_policy},
     },
     maintenance::{
-        entities::{MaintenanceWhitelistEntry, RealmMaintenanceWhitelistEntry},
-        ports::{MaintenanceWhitelistRepository, RealmMaintenanceWhitelistRepository},
-        value_objects::ToggleMaintenanceRequest,
+        entities::{
+            MaintenanceAnnouncement, MaintenanceWhitelistEntry, MaintenanceWindow,
+            MaintenanceWindowStatus, RealmMaintenanceWhitelistEntry,
+        },
+        ports::{
+            MaintenanceWhitelistRepository, MaintenanceWindowRepository,
+            RealmMaintenanceWhitelistRepository,
+        },
+        value_objects::{ScheduleMaintenanceWindowRequest, ToggleMaintenanceRequest},
     },
     realm::ports::RealmRepository,
     seawatch::{EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType},
@@ -30,7 +36,7 @@ use ferriskey_domain::maintenance::ports::MaintenanceService;
 use ferriskey_domain::realm::RealmId;
 
 #[derive(Clone, Debug)]
-pub struct MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW>
+pub struct MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW, MWR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -40,6 +46,7 @@ where
     SE: SecurityEventRepository,
     MW: MaintenanceWhitelistRepository,
     RMW: RealmMaintenanceWhitelistRepository,
+    MWR: MaintenanceWindowRepository,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) client_repository: Arc<C>,
@@ -47,10 +54,11 @@ where
     pub(crate) security_event_repository: Arc<SE>,
     pub(crate) maintenance_whitelist_repository: Arc<MW>,
     pub(crate) realm_maintenance_whitelist_repository: Arc<RMW>,
+    pub(crate) maintenance_window_repository: Arc<MWR>,
     pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
 }
 
-impl<R, U, C, UR, W, SE, MW, RMW> MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW>
+impl<R, U, C, UR, W, SE, MW, RMW, MWR> MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW, MWR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -60,6 +68,7 @@ where
     SE: SecurityEventRepository,
     MW: MaintenanceWhitelistRepository,
     RMW: RealmMaintenanceWhitelistRepository,
+    MWR: MaintenanceWindowRepository,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -69,6 +78,7 @@ where
         security_event_repository: Arc<SE>,
         maintenance_whitelist_repository: Arc<MW>,
         realm_maintenance_whitelist_repository: Arc<RMW>,
+        maintenance_window_repository: Arc<MWR>,
         policy: Arc<FerriskeyPolicy<U, C, UR>>,
     ) -> Self {
         Self {
@@ -78,13 +88,173 @@ where
             security_event_repository,
             maintenance_whitelist_repository,
             realm_maintenance_whitelist_repository,
+            maintenance_window_repository,
             policy,
         }
     }
+
+    /// Puts the window's clients under maintenance, unless another instance started it
+    /// first. Clients already under maintenance are left alone and not released later.
+    async fn start_window(&self, window: &MaintenanceWindow) -> Result<bool, CoreError> {
+        let clients = match window.client_id {
+            Some(client_id) => vec![
+                self.client_repository
+                    .get_by_id(window.realm_id, client_id)
+                    .await?,
+            ],
+            None => {
+                self.client_repository
+                    .get_by_realm_id(window.realm_id)
+                    .await?
+            }
+        };
+        let affected: Vec<Uuid> = clients
+            .iter()
+            .filter(|client| !client.maintenance_enabled)
+            .map(|client| client.id)
+            .collect();
+
+        if !self
+            .maintenance_window_repository
+            .start(window.id, affected.clone())
+            .await?
+        {
+            return Ok(false);
+        }
+
+        for client_id in &affected {
+            self.set_window_maintenance(window, *client_id, true)
+                .await?;
+        }
+
+        info!(
+            "Maintenance window {} started for {} client(s)",
+            window.id,
+            affected.len()
+        );
+        self.notify_window(
+            window,
+            MaintenanceWindowStatus::Active,
+            affected,
+            WebhookTrigger::MaintenanceWindowStarted,
+        )
+        .await?;
+
+        Ok(true)
+    }
+
+    /// Releases the clients the window put under maintenance.
+    async fn end_window(
+        &self,
+        window: &MaintenanceWindow,
+        to: MaintenanceWindowStatus,
+    ) -> Result<bool, CoreError> {
+        if !self
+            .maintenance_window_repository
+            .transition(window.id, MaintenanceWindowStatus::Active, to)
+            .await?
+        {
+            return Ok(false);
+        }
+
+        for client_id in &window.affected_client_ids {
+            match self.set_window_maintenance(window, *client_id, false).await {
+                Ok(()) => {}
+                // Deleted while under maintenance: nothing left to release.
+                Err(CoreError::ClientNotFound) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        info!("Maintenance window {} ended", window.id);
+        self.notify_window(
+            window,
+            to,
+            window.affected_client_ids.clone(),
+            WebhookTrigger::MaintenanceWindowEnded,
+        )
+        .await?;
+
+        Ok(true)
+    }
+
+    async fn set_window_maintenance(
+        &self,
+        window: &MaintenanceWindow,
+        client_id: Uuid,
+        enabled: bool,
+    ) -> Result<(), CoreError> {
+        let update = UpdateClientRequest {
+            name: None,
+            client_id: None,
+            enabled: None,
+            direct_access_grants_enabled: None,
+            oauth_device_code_grant_enabled: None,
+            require_pkce: None,
+            access_token_lifetime: None,
+            refresh_token_lifetime: None,
+            id_token_lifetime: None,
+            temporary_token_lifetime: None,
+            maintenance_enabled: Some(enabled),
+            maintenance_reason: Some(enabled.then(|| window.reason.clone())),
+            maintenance_session_strategy: Some(window.session_strategy.clone()),
+        };
+
+        self.client_repository
+            .update_client(window.realm_id, client_id, update)
+            .await
+            .map_err(|_| CoreError::ClientNotFound)?;
+
+        let event_type = if enabled {
+            SecurityEventType::ClientMaintenanceEnabled
+        } else {
+            SecurityEventType::ClientMaintenanceDisabled
+        };
+        self.security_event_repository
+            .store_event(
+                SecurityEvent::new(
+                    window.realm_id,
+                    event_type,
+                    EventStatus::Success,
+                    window.created_by,
+                )
+                .with_target("client".to_string(), client_id, None)
+                .with_details(json!({
+                    "enabled": enabled,
+                    "reason": window.reason,
+                    "session_strategy": window.session_strategy,
+                    "maintenance_window_id": window.id,
+                })),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn notify_window(
+        &self,
+        window: &MaintenanceWindow,
+        status: MaintenanceWindowStatus,
+        affected_client_ids: Vec<Uuid>,
+        trigger: WebhookTrigger,
+    ) -> Result<(), CoreError> {
+        let window = MaintenanceWindow {
+            status,
+            affected_client_ids,
+            ..window.clone()
+        };
+
+        self.webhook_repository
+            .notify(
+                window.realm_id,
+                WebhookPayload::new(trigger, window.id, Some(window)),
+            )
+            .await
+    }
 }
 
-impl<R, U, C, UR, W, SE, MW, RMW> MaintenanceService
-    for MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW>
+impl<R, U, C, UR, W, SE, MW, RMW, MWR> MaintenanceService
+    for MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW, MWR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -94,6 +264,7 @@ where
     SE: SecurityEventRepository,
     MW: MaintenanceWhitelistRepository,
     RMW: RealmMaintenanceWhitelistRepository,
+    MWR: MaintenanceWindowRepository,
 {
     async fn toggle_maintenance(
         &self,
@@ -386,4 +557,224 @@ where
             .get_by_realm_id(realm.id)
             .await
     }
+
+    async fn schedule_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        request: ScheduleMaintenanceWindowRequest,
+    ) -> Result<MaintenanceWindow, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+        ensure_policy(
+            self.policy.can_update_client(&identity, &realm).await,
+            "insufficient permissions to schedule maintenance",
+        )?;
+
+        if let Some(client_id) = request.client_id {
+            self.client_repository
+                .get_by_id(realm.id, client_id)
+                .await
+                .map_err(|_| CoreError::ClientNotFound)?;
+        }
+
+        let window = MaintenanceWindow::new(realm.id, request, identity.id());
+        window
+            .validate(chrono::Utc::now())
+            .map_err(CoreError::InvalidMaintenanceWindow)?;
+
+        let pending = self
+            .maintenance_window_repository
+            .list_pending(realm.id)
+            .await?;
+        if let Some(conflict) = pending.iter().find(|other| window.overlaps(other)) {
+            return Err(CoreError::InvalidMaintenanceWindow(format!(
+                "overlaps maintenance window {} ({} to {})",
+                conflict.id, conflict.starts_at, conflict.ends_at
+            )));
+        }
+
+        self.maintenance_window_repository.create(window).await
+    }
+
+    async fn get_maintenance_windows(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<MaintenanceWindow>, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+        ensure_policy(
+            self.policy.can_view_client(&identity, &realm).await,
+            "insufficient permissions to view maintenance windows",
+        )?;
+
+        self.maintenance_window_repository
+            .list_by_realm(realm.id)
+            .await
+    }
+
+    async fn cancel_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        window_id: Uuid,
+    ) -> Result<MaintenanceWindow, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+        ensure_policy(
+            self.policy.can_update_client(&identity, &realm).await,
+            "insufficient permissions to cancel maintenance",
+        )?;
+
+        let window = self
+            .maintenance_window_repository
+            .get(realm.id, window_id)
+            .await?
+            .ok_or(CoreError::NotFound)?;
+
+        let cancelled = match window.status {
+            MaintenanceWindowStatus::Scheduled => {
+                self.maintenance_window_repository
+                    .transition(
+                        window.id,
+                        MaintenanceWindowStatus::Scheduled,
+                        MaintenanceWindowStatus::Cancelled,
+                    )
+                    .await?
+            }
+            MaintenanceWindowStatus::Active => {
+                self.end_window(&window, MaintenanceWindowStatus::Cancelled)
+                    .await?
+            }
+            MaintenanceWindowStatus::Completed | MaintenanceWindowStatus::Cancelled => false,
+        };
+        if !cancelled {
+            return Err(CoreError::InvalidMaintenanceWindow(
+                "the window is already over".to_string(),
+            ));
+        }
+
+        self.maintenance_window_repository
+            .get(realm.id, window_id)
+            .await?
+            .ok_or(CoreError::NotFound)
+    }
+
+    async fn get_maintenance_announcements(
+        &self,
+        realm_name: String,
+        client_id: Option<String>,
+    ) -> Result<Vec<MaintenanceAnnouncement>, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        // An unknown client_id still sees the realm-wide windows.
+        let only_client = match client_id {
+            Some(client_id) => Some(
+                self.client_repository
+                    .get_by_client_id(client_id, realm.id)
+                    .await
+                    .ok()
+                    .map(|client| client.id),
+            ),
+            None => None,
+        };
+
+        let now = chrono::Utc::now();
+        let mut announcements = Vec::new();
+        for window in self
+            .maintenance_window_repository
+            .list_pending(realm.id)
+            .await?
+        {
+            if !window.is_announced(now) {
+                continue;
+            }
+            if let Some(only_client) = only_client
+                && window.client_id.is_some()
+                && window.client_id != only_client
+            {
+                continue;
+            }
+
+            let client_id = match window.client_id {
+                Some(id) => match self.client_repository.get_by_id(realm.id, id).await {
+                    Ok(client) => Some(client.client_id),
+                    Err(_) => continue,
+                },
+                None => None,
+            };
+
+            announcements.push(MaintenanceAnnouncement {
+                reason: window.reason,
+                client_id,
+                starts_at: window.starts_at,
+                ends_at: window.ends_at,
+                in_progress: window.starts_at <= now,
+            });
+        }
+
+        Ok(announcements)
+    }
+
+    async fn run_due_maintenance_windows(&self) -> Result<usize, CoreError> {
+        let mut changed = 0;
+
+        for window in self
+            .maintenance_window_repository
+            .list_due(chrono::Utc::now())
+            .await?
+        {
+            let result = match window.status {
+                MaintenanceWindowStatus::Scheduled => self.start_window(&window).await,
+                MaintenanceWindowStatus::Active => {
+                    self.end_window(&window, MaintenanceWindowStatus::Completed)
+                        .await
+                }
+                MaintenanceWindowStatus::Completed | MaintenanceWindowStatus::Cancelled => {
+                    Ok(false)
+                }
+            };
+
+            match result {
+                Ok(true) => changed += 1,
+                Ok(false) => {}
+                Err(e) => warn!("Failed to apply maintenance window {}: {:?}", window.id, e),
+            }
+        }
+
+        Ok(changed)
+    }
+}
+
+/// Periodically start and end the maintenance windows that are due. Every replica runs
+/// this loop; the conditional status update decides which one applies each transition.
+pub async fn maintenance_window_scheduler_task<S>(service: S, period: std::time::Duration)
+where
+    S: MaintenanceService,
+{
+    let mut ticker = tokio::time::interval(period);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+        match service.run_due_maintenance_windows().await {
+            Ok(0) => {}
+            Ok(changed) => info!(changed, "Applied due maintenance windows"),
+            Err(error) => warn!(error = ?error, "Failed to apply due maintenance windows"),
+        }
+    }
 }
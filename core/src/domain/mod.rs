@@ -7,19 +7,27 @@ pub mod common;
 pub mod compass;
 pub mod credential;
 pub mod crypto;
+pub mod email_outbox;
 pub mod email_template;
 pub mod email_verification;
 pub mod health;
 pub mod jwt;
+pub mod localization;
 pub mod maintenance;
 pub mod organization;
 pub mod password_policy;
 pub mod portal_layouts;
 pub mod portal_theme;
+pub mod rate_limit;
 pub mod realm;
+pub mod realm_group;
+pub mod risk;
 pub mod role;
 pub mod seawatch;
+pub mod security_notification;
 pub mod session;
 pub mod trident;
 pub mod user;
+pub mod user_profile;
+pub mod webauthn_policy;
 pub mod webhook;
@@ -0,0 +1,709 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use ferriskey_domain::realm::Realm;
+use tracing::info;
+
+use crate::domain::{
+    abyss::identity_provider::IdentityProviderRepository,
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        entities::app_errors::CoreError,
+        generate_random_token,
+        policies::{FerriskeyPolicy, ensure_policy},
+    },
+    organization::ports::{
+        ConfigureOrganizationDomainInput, DiscoverHomeRealmInput, DomainTxtResolver,
+        GetOrganizationDomainInput, HomeRealmDiscovery, Organization, OrganizationDomain,
+        OrganizationDomainRepository, OrganizationDomainService, OrganizationDomainStatus,
+        OrganizationId, OrganizationPolicy, OrganizationRepository, SaveOrganizationDomainParams,
+        VerifyOrganizationDomainInput, email_domain,
+    },
+    realm::ports::RealmRepository,
+    user::ports::{UserRepository, UserRoleRepository},
+};
+
+/// The organization domain as it is matched against email addresses.
+fn normalized_domain(org: &Organization) -> Option<String> {
+    org.domain
+        .as_deref()
+        .map(|domain| domain.trim().trim_end_matches('.').to_lowercase())
+        .filter(|domain| !domain.is_empty())
+}
+
+/// Proof of ownership of an organization's email domain through a DNS TXT record, and
+/// home-realm discovery: routing users of a verified domain to the organization's identity
+/// provider at login.
+#[derive(Clone, Debug)]
+pub struct OrganizationDomainServiceImpl<R, U, C, UR, OR, ODR, IR, DR>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OR: OrganizationRepository,
+    ODR: OrganizationDomainRepository,
+    IR: IdentityProviderRepository,
+    DR: DomainTxtResolver,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) organization_repository: Arc<OR>,
+    pub(crate) organization_domain_repository: Arc<ODR>,
+    pub(crate) identity_provider_repository: Arc<IR>,
+    pub(crate) txt_resolver: Arc<DR>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, OR, ODR, IR, DR> OrganizationDomainServiceImpl<R, U, C, UR, OR, ODR, IR, DR>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OR: OrganizationRepository,
+    ODR: OrganizationDomainRepository,
+    IR: IdentityProviderRepository,
+    DR: DomainTxtResolver,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        organization_repository: Arc<OR>,
+        organization_domain_repository: Arc<ODR>,
+        identity_provider_repository: Arc<IR>,
+        txt_resolver: Arc<DR>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            organization_repository,
+            organization_domain_repository,
+            identity_provider_repository,
+            txt_resolver,
+            policy,
+        }
+    }
+
+    async fn get_org_for_realm_name(
+        &self,
+        realm_name: &str,
+        organization_id: OrganizationId,
+    ) -> Result<(Realm, Organization), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let org = self
+            .organization_repository
+            .get_organization_by_id(organization_id)
+            .await?
+            .ok_or(CoreError::NotFound)?;
+
+        if org.realm_id != realm.id {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok((realm, org))
+    }
+
+    async fn status_of(
+        &self,
+        domain: OrganizationDomain,
+    ) -> Result<OrganizationDomainStatus, CoreError> {
+        let identity_provider_alias = match domain.identity_provider_id {
+            Some(id) => self
+                .identity_provider_repository
+                .get_identity_provider_by_id(id)
+                .await?
+                .map(|provider| provider.alias),
+            None => None,
+        };
+
+        Ok(OrganizationDomainStatus {
+            verified: domain.verified_at.is_some(),
+            verified_at: domain.verified_at,
+            verification_record_name: domain.verification_record_name(),
+            verification_record_value: domain.verification_record_value(),
+            domain: domain.domain,
+            identity_provider_alias,
+        })
+    }
+}
+
+impl<R, U, C, UR, OR, ODR, IR, DR> OrganizationDomainService
+    for OrganizationDomainServiceImpl<R, U, C, UR, OR, ODR, IR, DR>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OR: OrganizationRepository,
+    ODR: OrganizationDomainRepository,
+    IR: IdentityProviderRepository,
+    DR: DomainTxtResolver,
+{
+    async fn get_organization_domain(
+        &self,
+        identity: Identity,
+        input: GetOrganizationDomainInput,
+    ) -> Result<OrganizationDomainStatus, CoreError> {
+        let (realm, org) = self
+            .get_org_for_realm_name(&input.realm_name, input.organization_id)
+            .await?;
+
+        ensure_policy(
+            self.policy.can_view_organization(&identity, &realm).await,
+            "insufficient permissions to view organization domain",
+        )?;
+
+        let domain = self
+            .organization_domain_repository
+            .get_domain(org.id)
+            .await?
+            .ok_or(CoreError::NotFound)?;
+
+        self.status_of(domain).await
+    }
+
+    async fn configure_organization_domain(
+        &self,
+        identity: Identity,
+        input: ConfigureOrganizationDomainInput,
+    ) -> Result<OrganizationDomainStatus, CoreError> {
+        let (realm, org) = self
+            .get_org_for_realm_name(&input.realm_name, input.organization_id)
+            .await?;
+
+        ensure_policy(
+            self.policy.can_update_organization(&identity, &realm).await,
+            "insufficient permissions to configure organization domain",
+        )?;
+
+        let domain = normalized_domain(&org).ok_or(CoreError::Invalid)?;
+
+        let identity_provider_id = match input.identity_provider_alias.as_deref() {
+            Some(alias) => Some(
+                self.identity_provider_repository
+                    .get_identity_provider_by_realm_and_alias(realm.id, alias)
+                    .await?
+                    .ok_or(CoreError::NotFound)?
+                    .id
+                    .as_uuid(),
+            ),
+            None => None,
+        };
+
+        // Keep the proof while the organization domain is unchanged; a new domain needs a
+        // new challenge.
+        let (verification_token, verified_at) = match self
+            .organization_domain_repository
+            .get_domain(org.id)
+            .await?
+        {
+            Some(existing) if existing.domain == domain => {
+                (existing.verification_token, existing.verified_at)
+            }
+            _ => (generate_random_token(), None),
+        };
+
+        let saved = self
+            .organization_domain_repository
+            .save_domain(SaveOrganizationDomainParams {
+                organization_id: org.id,
+                realm_id: realm.id,
+                domain,
+                verification_token,
+                verified_at,
+                identity_provider_id,
+            })
+            .await?;
+
+        self.status_of(saved).await
+    }
+
+    async fn verify_organization_domain(
+        &self,
+        identity: Identity,
+        input: VerifyOrganizationDomainInput,
+    ) -> Result<OrganizationDomainStatus, CoreError> {
+        let (realm, org) = self
+            .get_org_for_realm_name(&input.realm_name, input.organization_id)
+            .await?;
+
+        ensure_policy(
+            self.policy.can_update_organization(&identity, &realm).await,
+            "insufficient permissions to verify organization domain",
+        )?;
+
+        let domain = self
+            .organization_domain_repository
+            .get_domain(org.id)
+            .await?
+            .ok_or(CoreError::NotFound)?;
+
+        if normalized_domain(&org).as_deref() != Some(domain.domain.as_str()) {
+            return Err(CoreError::DomainVerificationFailed(
+                "the organization domain changed, configure it again".to_string(),
+            ));
+        }
+
+        let record_name = domain.verification_record_name();
+        let records = self.txt_resolver.lookup_txt(&record_name).await?;
+        if !domain.is_proven_by(&records) {
+            return Err(CoreError::DomainVerificationFailed(format!(
+                "no TXT record at {record_name} holds the expected value"
+            )));
+        }
+
+        if let Some(other) = self
+            .organization_domain_repository
+            .find_verified_domain(realm.id, &domain.domain)
+            .await?
+            && other.organization_id != org.id
+        {
+            return Err(CoreError::AlreadyExists);
+        }
+
+        let saved = self
+            .organization_domain_repository
+            .save_domain(SaveOrganizationDomainParams {
+                organization_id: org.id,
+                realm_id: realm.id,
+                domain: domain.domain,
+                verification_token: domain.verification_token,
+                verified_at: Some(domain.verified_at.unwrap_or_else(Utc::now)),
+                identity_provider_id: domain.identity_provider_id,
+            })
+            .await?;
+
+        info!(organization_id = %org.id, domain = %saved.domain, "Organization domain verified");
+
+        self.status_of(saved).await
+    }
+
+    async fn discover_home_realm(
+        &self,
+        input: DiscoverHomeRealmInput,
+    ) -> Result<Option<HomeRealmDiscovery>, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let Some(domain) = email_domain(&input.email) else {
+            return Ok(None);
+        };
+
+        let Some(record) = self
+            .organization_domain_repository
+            .find_verified_domain(realm.id, &domain)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let Some(provider_id) = record.identity_provider_id else {
+            return Ok(None);
+        };
+
+        let Some(org) = self
+            .organization_repository
+            .get_organization_by_id(record.organization_id)
+            .await?
+            .filter(|org| org.enabled && normalized_domain(org).as_deref() == Some(&domain))
+        else {
+            return Ok(None);
+        };
+
+        let provider = self
+            .identity_provider_repository
+            .get_identity_provider_by_id(provider_id)
+            .await?
+            .filter(|provider| provider.enabled && provider.realm_id == realm.id);
+
+        Ok(provider.map(|provider| HomeRealmDiscovery {
+            organization_alias: org.alias,
+            identity_provider_alias: provider.alias,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use ferriskey_domain::realm::RealmId;
+    use ferriskey_organization::{
+        MockDomainTxtResolver, MockOrganizationDomainRepository, MockOrganizationRepository,
+        Organization, OrganizationDomain, OrganizationId,
+    };
+
+    use crate::domain::{
+        abyss::identity_provider::{
+            IdentityProvider, IdentityProviderConfig, IdentityProviderId,
+            ports::MockIdentityProviderRepository,
+        },
+        authentication::value_objects::Identity,
+        client::ports::MockClientRepository,
+        common::{entities::app_errors::CoreError, policies::FerriskeyPolicy},
+        realm::{entities::Realm, ports::MockRealmRepository},
+        role::entities::Role,
+        user::{
+            entities::User,
+            ports::{MockUserRepository, MockUserRoleRepository},
+        },
+    };
+
+    use super::*;
+
+    type TestService = OrganizationDomainServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockOrganizationRepository,
+        MockOrganizationDomainRepository,
+        MockIdentityProviderRepository,
+        MockDomainTxtResolver,
+    >;
+
+    fn make_realm(id: RealmId) -> Realm {
+        Realm {
+            id,
+            name: "test-realm".to_string(),
+            display_name: None,
+            settings: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn make_admin(realm: &Realm) -> User {
+        User {
+            id: Uuid::new_v4(),
+            realm_id: realm.id,
+            client_id: None,
+            username: "admin".to_string(),
+            firstname: None,
+            lastname: None,
+            email: Some("admin@test.com".to_string()),
+            email_verified: true,
+            enabled: true,
+            roles: None,
+            realm: Some(realm.clone()),
+            required_actions: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            failed_login_attempts: 0,
+            locked_until: None,
+        }
+    }
+
+    fn make_org(realm_id: RealmId) -> Organization {
+        Organization {
+            id: OrganizationId::new(Uuid::new_v4()),
+            realm_id,
+            name: "Acme".to_string(),
+            alias: "acme".to_string(),
+            domain: Some("Acme.com".to_string()),
+            redirect_url: None,
+            description: None,
+            enabled: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn make_domain(
+        org: &Organization,
+        verified: bool,
+        provider_id: Option<Uuid>,
+    ) -> OrganizationDomain {
+        OrganizationDomain {
+            organization_id: org.id,
+            realm_id: org.realm_id,
+            domain: "acme.com".to_string(),
+            verification_token: "token".to_string(),
+            verified_at: verified.then(Utc::now),
+            identity_provider_id: provider_id,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn make_provider(realm_id: RealmId, id: Uuid) -> IdentityProvider {
+        IdentityProvider {
+            id: IdentityProviderId::new(id),
+            realm_id,
+            alias: "acme-sso".to_string(),
+            provider_id: "oidc".to_string(),
+            enabled: true,
+            display_name: None,
+            first_broker_login_flow_alias: None,
+            post_broker_login_flow_alias: None,
+            store_token: false,
+            add_read_token_role_on_create: false,
+            trust_email: false,
+            link_only: false,
+            config: IdentityProviderConfig {
+                client_id: None,
+                client_secret: None,
+                extra: serde_json::json!({}),
+            },
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn realm_repo_returning(realm_id: RealmId) -> MockRealmRepository {
+        let mut realm_repo = MockRealmRepository::new();
+        realm_repo.expect_get_by_name().returning(move |_| {
+            let realm = make_realm(realm_id);
+            Box::pin(async move { Ok(Some(realm)) })
+        });
+        realm_repo
+    }
+
+    fn build_service(
+        realm_id: RealmId,
+        admin: Option<User>,
+        org_repo: MockOrganizationRepository,
+        domain_repo: MockOrganizationDomainRepository,
+        provider_repo: MockIdentityProviderRepository,
+        resolver: MockDomainTxtResolver,
+    ) -> TestService {
+        let mut user_repo = MockUserRepository::new();
+        let mut user_role_repo = MockUserRoleRepository::new();
+        if let Some(admin) = admin {
+            user_repo.expect_get_by_id().returning(move |_| {
+                let user = admin.clone();
+                Box::pin(async move { Ok(user) })
+            });
+            user_role_repo.expect_get_user_roles().returning(move |_| {
+                let role = Role {
+                    id: Uuid::new_v4(),
+                    name: "admin".to_string(),
+                    description: None,
+                    permissions: vec!["manage_realm".to_string()],
+                    realm_id,
+                    client_id: None,
+                    client: None,
+                    require_mfa: false,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                };
+                Box::pin(async move { Ok(vec![role]) })
+            });
+        }
+
+        let policy = Arc::new(FerriskeyPolicy::new(
+            Arc::new(user_repo),
+            Arc::new(MockClientRepository::new()),
+            Arc::new(user_role_repo),
+        ));
+
+        OrganizationDomainServiceImpl::new(
+            Arc::new(realm_repo_returning(realm_id)),
+            Arc::new(org_repo),
+            Arc::new(domain_repo),
+            Arc::new(provider_repo),
+            Arc::new(resolver),
+            policy,
+        )
+    }
+
+    fn org_repo_returning(org: Organization) -> MockOrganizationRepository {
+        let mut org_repo = MockOrganizationRepository::new();
+        org_repo
+            .expect_get_organization_by_id()
+            .returning(move |_| {
+                let org = org.clone();
+                Box::pin(async move { Ok(Some(org)) })
+            });
+        org_repo
+    }
+
+    #[tokio::test]
+    async fn verify_marks_domain_verified_when_txt_record_matches() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let admin = make_admin(&make_realm(realm_id));
+        let org = make_org(realm_id);
+        let org_id = org.id;
+        let pending = make_domain(&org, false, None);
+        let expected_record = pending.verification_record_value();
+
+        let mut domain_repo = MockOrganizationDomainRepository::new();
+        let stored = pending.clone();
+        domain_repo
+            .expect_get_domain()
+            .return_once(move |_| Box::pin(async move { Ok(Some(stored)) }));
+        domain_repo
+            .expect_find_verified_domain()
+            .return_once(|_, _| Box::pin(async { Ok(None) }));
+        domain_repo
+            .expect_save_domain()
+            .withf(|params| params.verified_at.is_some())
+            .return_once(move |params| {
+                let saved = OrganizationDomain {
+                    verified_at: params.verified_at,
+                    ..pending
+                };
+                Box::pin(async move { Ok(saved) })
+            });
+
+        let mut resolver = MockDomainTxtResolver::new();
+        resolver
+            .expect_lookup_txt()
+            .withf(|name| name == "_ferriskey-challenge.acme.com")
+            .return_once(move |_| Box::pin(async move { Ok(vec![expected_record]) }));
+
+        let service = build_service(
+            realm_id,
+            Some(admin.clone()),
+            org_repo_returning(org),
+            domain_repo,
+            MockIdentityProviderRepository::new(),
+            resolver,
+        );
+
+        let status = service
+            .verify_organization_domain(
+                Identity::User(admin),
+                VerifyOrganizationDomainInput {
+                    realm_name: "test-realm".to_string(),
+                    organization_id: org_id,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(status.verified);
+    }
+
+    #[tokio::test]
+    async fn verify_fails_without_matching_txt_record() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let admin = make_admin(&make_realm(realm_id));
+        let org = make_org(realm_id);
+        let org_id = org.id;
+        let pending = make_domain(&org, false, None);
+
+        let mut domain_repo = MockOrganizationDomainRepository::new();
+        domain_repo
+            .expect_get_domain()
+            .return_once(move |_| Box::pin(async move { Ok(Some(pending)) }));
+
+        let mut resolver = MockDomainTxtResolver::new();
+        resolver
+            .expect_lookup_txt()
+            .return_once(|_| Box::pin(async { Ok(vec!["v=spf1 -all".to_string()]) }));
+
+        let service = build_service(
+            realm_id,
+            Some(admin.clone()),
+            org_repo_returning(org),
+            domain_repo,
+            MockIdentityProviderRepository::new(),
+            resolver,
+        );
+
+        let result = service
+            .verify_organization_domain(
+                Identity::User(admin),
+                VerifyOrganizationDomainInput {
+                    realm_name: "test-realm".to_string(),
+                    organization_id: org_id,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::DomainVerificationFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn discover_routes_verified_domain_to_its_provider() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let org = make_org(realm_id);
+        let provider_id = Uuid::new_v4();
+        let verified = make_domain(&org, true, Some(provider_id));
+
+        let mut domain_repo = MockOrganizationDomainRepository::new();
+        domain_repo
+            .expect_find_verified_domain()
+            .withf(|_, domain| domain == "acme.com")
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(verified)) }));
+
+        let mut provider_repo = MockIdentityProviderRepository::new();
+        provider_repo
+            .expect_get_identity_provider_by_id()
+            .return_once(move |id| {
+                let provider = make_provider(realm_id, id);
+                Box::pin(async move { Ok(Some(provider)) })
+            });
+
+        let service = build_service(
+            realm_id,
+            None,
+            org_repo_returning(org),
+            domain_repo,
+            provider_repo,
+            MockDomainTxtResolver::new(),
+        );
+
+        let discovery = service
+            .discover_home_realm(DiscoverHomeRealmInput {
+                realm_name: "test-realm".to_string(),
+                email: "Jane@ACME.com".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            discovery,
+            Some(HomeRealmDiscovery {
+                organization_alias: "acme".to_string(),
+                identity_provider_alias: "acme-sso".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn discover_ignores_unverified_domains() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+
+        let mut domain_repo = MockOrganizationDomainRepository::new();
+        domain_repo
+            .expect_find_verified_domain()
+            .return_once(|_, _| Box::pin(async { Ok(None) }));
+
+        let service = build_service(
+            realm_id,
+            None,
+            MockOrganizationRepository::new(),
+            domain_repo,
+            MockIdentityProviderRepository::new(),
+            MockDomainTxtResolver::new(),
+        );
+
+        let discovery = service
+            .discover_home_realm(DiscoverHomeRealmInput {
+                realm_name: "test-realm".to_string(),
+                email: "jane@acme.com".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert!(discovery.is_none());
+    }
+}
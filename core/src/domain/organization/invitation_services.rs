@@ -0,0 +1,701 @@
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{Duration, Utc};
+use ferriskey_domain::realm::Realm;
+use sha2::{Digest, Sha256};
+use tokio::time::timeout;
+use tracing::{info, warn};
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        email::EmailPort, entities::app_errors::CoreError, generate_random_token,
+        policies::ensure_policy,
+    },
+    credential::ports::CredentialRepository,
+    crypto::HasherRepository,
+    email_template::{
+        entities::interpolate_variables,
+        ports::{EmailTemplateRepository, TemplateRenderer},
+    },
+    localization::{EmailLocalizer, LocalizedMessages},
+    organization::policies::OrganizationAdminPolicy,
+    organization::ports::{
+        AcceptInvitationInput, AcceptedInvitation, CreateInvitationInput, CreateInvitationParams,
+        GetInvitationInput, GroupMemberRepository, GroupRepository, InvitationPreview,
+        ListInvitationsInput, Organization, OrganizationCapability, OrganizationId,
+        OrganizationInvitation, OrganizationInvitationRepository, OrganizationInvitationService,
+        OrganizationMemberRepository, OrganizationMemberRoleRepository, OrganizationRepository,
+        OrganizationScopedPolicy, RevokeInvitationInput, normalize_invitation_email,
+    },
+    password_policy::{
+        entity::PasswordPolicy, repository::PasswordPolicyRepository,
+        service::violations_to_core_error, validator,
+    },
+    realm::ports::{RealmRepository, SmtpConfigRepository},
+    role::ports::RoleRepository,
+    user::{
+        entities::User,
+        ports::{UserRepository, UserRoleRepository},
+        value_objects::CreateUserRequest,
+    },
+};
+
+/// Invitations stay valid for a week unless the admin asks otherwise.
+const DEFAULT_INVITATION_TTL_HOURS: i64 = 24 * 7;
+const MAX_INVITATION_TTL_HOURS: i64 = 24 * 30;
+
+#[cfg(not(test))]
+const EMAIL_DELIVERY_TIMEOUT: StdDuration = StdDuration::from_secs(10);
+#[cfg(test)]
+const EMAIL_DELIVERY_TIMEOUT: StdDuration = StdDuration::from_millis(10);
+
+fn hash_invitation_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// Emailed invitations to join an organization. The raw token only ever leaves the server in
+/// the invitation email; accepting it creates the invitee's account or links the existing one,
+/// then grants membership and the role and group picked by the inviting admin.
+#[derive(Clone, Debug)]
+pub struct OrganizationInvitationServiceImpl<
+    R,
+    U,
+    C,
+    UR,
+    RO,
+    OR,
+    OMR,
+    OMRR,
+    GR,
+    GMR,
+    OIR,
+    CR,
+    H,
+    PP,
+    SC,
+    ES,
+    ETR,
+    TR,
+    EL,
+> where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RO: RoleRepository,
+    OR: OrganizationRepository,
+    OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
+    GR: GroupRepository,
+    GMR: GroupMemberRepository,
+    OIR: OrganizationInvitationRepository,
+    CR: CredentialRepository,
+    H: HasherRepository,
+    PP: PasswordPolicyRepository,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    ETR: EmailTemplateRepository,
+    TR: TemplateRenderer,
+    EL: EmailLocalizer,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) user_repository: Arc<U>,
+    pub(crate) role_repository: Arc<RO>,
+    pub(crate) organization_repository: Arc<OR>,
+    pub(crate) organization_member_repository: Arc<OMR>,
+    pub(crate) organization_member_role_repository: Arc<OMRR>,
+    pub(crate) group_repository: Arc<GR>,
+    pub(crate) group_member_repository: Arc<GMR>,
+    pub(crate) invitation_repository: Arc<OIR>,
+    pub(crate) credential_repository: Arc<CR>,
+    pub(crate) hasher_repository: Arc<H>,
+    pub(crate) password_policy_repository: Arc<PP>,
+    pub(crate) smtp_config_repository: Arc<SC>,
+    pub(crate) email_port: Arc<ES>,
+    pub(crate) email_template_repository: Arc<ETR>,
+    pub(crate) template_renderer: Arc<TR>,
+    pub(crate) email_localizer: Arc<EL>,
+    pub(crate) policy: Arc<OrganizationAdminPolicy<U, C, UR, OMR, OMRR>>,
+}
+
+impl<R, U, C, UR, RO, OR, OMR, OMRR, GR, GMR, OIR, CR, H, PP, SC, ES, ETR, TR, EL>
+    OrganizationInvitationServiceImpl<
+        R,
+        U,
+        C,
+        UR,
+        RO,
+        OR,
+        OMR,
+        OMRR,
+        GR,
+        GMR,
+        OIR,
+        CR,
+        H,
+        PP,
+        SC,
+        ES,
+        ETR,
+        TR,
+        EL,
+    >
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RO: RoleRepository,
+    OR: OrganizationRepository,
+    OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
+    GR: GroupRepository,
+    GMR: GroupMemberRepository,
+    OIR: OrganizationInvitationRepository,
+    CR: CredentialRepository,
+    H: HasherRepository,
+    PP: PasswordPolicyRepository,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    ETR: EmailTemplateRepository,
+    TR: TemplateRenderer,
+    EL: EmailLocalizer,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        realm_repository: Arc<R>,
+        user_repository: Arc<U>,
+        role_repository: Arc<RO>,
+        organization_repository: Arc<OR>,
+        organization_member_repository: Arc<OMR>,
+        organization_member_role_repository: Arc<OMRR>,
+        group_repository: Arc<GR>,
+        group_member_repository: Arc<GMR>,
+        invitation_repository: Arc<OIR>,
+        credential_repository: Arc<CR>,
+        hasher_repository: Arc<H>,
+        password_policy_repository: Arc<PP>,
+        smtp_config_repository: Arc<SC>,
+        email_port: Arc<ES>,
+        email_template_repository: Arc<ETR>,
+        template_renderer: Arc<TR>,
+        email_localizer: Arc<EL>,
+        policy: Arc<OrganizationAdminPolicy<U, C, UR, OMR, OMRR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            user_repository,
+            role_repository,
+            organization_repository,
+            organization_member_repository,
+            organization_member_role_repository,
+            group_repository,
+            group_member_repository,
+            invitation_repository,
+            credential_repository,
+            hasher_repository,
+            password_policy_repository,
+            smtp_config_repository,
+            email_port,
+            email_template_repository,
+            template_renderer,
+            email_localizer,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    async fn get_org_for_realm(
+        &self,
+        organization_id: OrganizationId,
+        realm: &Realm,
+    ) -> Result<Organization, CoreError> {
+        let org = self
+            .organization_repository
+            .get_organization_by_id(organization_id)
+            .await?
+            .ok_or(CoreError::NotFound)?;
+
+        if org.realm_id != realm.id {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(org)
+    }
+
+    /// The pending invitation behind `token` together with its organization, or
+    /// `InvalidOrganizationInvitation` for anything else (unknown, used, revoked, expired,
+    /// another realm, disabled organization).
+    async fn resolve_pending_invitation(
+        &self,
+        realm: &Realm,
+        token: &str,
+    ) -> Result<(OrganizationInvitation, Organization), CoreError> {
+        let invitation = self
+            .invitation_repository
+            .get_invitation_by_token_hash(&hash_invitation_token(token))
+            .await?
+            .filter(OrganizationInvitation::is_pending)
+            .ok_or(CoreError::InvalidOrganizationInvitation)?;
+
+        let org = self
+            .organization_repository
+            .get_organization_by_id(invitation.organization_id)
+            .await?
+            .filter(|org| org.realm_id == realm.id && org.enabled)
+            .ok_or(CoreError::InvalidOrganizationInvitation)?;
+
+        Ok((invitation, org))
+    }
+
+    async fn render_invitation_template(
+        &self,
+        realm: &Realm,
+        org: &Organization,
+        invitation_link: &str,
+        messages: &LocalizedMessages,
+    ) -> Result<Option<String>, CoreError> {
+        let Some(template_id) = realm
+            .settings
+            .as_ref()
+            .and_then(|s| s.organization_invitation_template_id)
+        else {
+            return Ok(None);
+        };
+
+        let template = self
+            .email_template_repository
+            .get_by_id(realm.id.into(), template_id)
+            .await?
+            .ok_or(CoreError::EmailTemplateNotFound)?;
+
+        let html = self.template_renderer.render_to_html(&template.mjml)?;
+
+        let mut variables = messages.template_variables();
+        variables.extend([
+            ("invitation_link".to_string(), invitation_link.to_string()),
+            ("organization.name".to_string(), org.name.clone()),
+        ]);
+
+        Ok(Some(interpolate_variables(&html, &variables)))
+    }
+
+    async fn create_invited_user(
+        &self,
+        realm: &Realm,
+        email: &str,
+        input: AcceptInvitationInput,
+    ) -> Result<User, CoreError> {
+        let password = input.password.ok_or(CoreError::Invalid)?;
+        let username = input
+            .username
+            .map(|username| username.trim().to_string())
+            .filter(|username| !username.is_empty())
+            .unwrap_or_else(|| email.to_string());
+
+        let policy = self
+            .password_policy_repository
+            .find_by_realm_id(realm.id.into())
+            .await?
+            .unwrap_or_else(|| PasswordPolicy::default(realm.id.into()));
+
+        validator::validate(
+            &password,
+            &policy,
+            Some(username.as_str()),
+            email.split('@').next(),
+        )
+        .map_err(violations_to_core_error)?;
+
+        let hash_result = self
+            .hasher_repository
+            .hash_password(&password)
+            .await
+            .map_err(|e| CoreError::HashPasswordError(e.to_string()))?;
+
+        // The invitation reached this mailbox, so the address counts as verified.
+        let user = self
+            .user_repository
+            .create_user(CreateUserRequest {
+                realm_id: realm.id,
+                client_id: None,
+                username,
+                firstname: input.first_name,
+                lastname: input.last_name,
+                email: Some(email.to_string()),
+                email_verified: true,
+                enabled: true,
+            })
+            .await?;
+
+        self.credential_repository
+            .create_credential(user.id, "password".into(), hash_result, "".into(), false)
+            .await
+            .map_err(|_| CoreError::CreateCredentialError)?;
+
+        Ok(user)
+    }
+}
+
+impl<R, U, C, UR, RO, OR, OMR, OMRR, GR, GMR, OIR, CR, H, PP, SC, ES, ETR, TR, EL>
+    OrganizationInvitationService
+    for OrganizationInvitationServiceImpl<
+        R,
+        U,
+        C,
+        UR,
+        RO,
+        OR,
+        OMR,
+        OMRR,
+        GR,
+        GMR,
+        OIR,
+        CR,
+        H,
+        PP,
+        SC,
+        ES,
+        ETR,
+        TR,
+        EL,
+    >
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RO: RoleRepository,
+    OR: OrganizationRepository,
+    OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
+    GR: GroupRepository,
+    GMR: GroupMemberRepository,
+    OIR: OrganizationInvitationRepository,
+    CR: CredentialRepository,
+    H: HasherRepository,
+    PP: PasswordPolicyRepository,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    ETR: EmailTemplateRepository,
+    TR: TemplateRenderer,
+    EL: EmailLocalizer,
+{
+    async fn create_invitation(
+        &self,
+        identity: Identity,
+        input: CreateInvitationInput,
+    ) -> Result<OrganizationInvitation, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        let org = self
+            .get_org_for_realm(input.organization_id, &realm)
+            .await?;
+
+        ensure_policy(
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageMembers,
+                )
+                .await,
+            "insufficient permissions to invite organization members",
+        )?;
+
+        if !org.enabled {
+            return Err(CoreError::Invalid);
+        }
+
+        let email = normalize_invitation_email(&input.email).map_err(|_| CoreError::Invalid)?;
+
+        let ttl_hours = input
+            .expires_in_hours
+            .unwrap_or(DEFAULT_INVITATION_TTL_HOURS);
+        if !(1..=MAX_INVITATION_TTL_HOURS).contains(&ttl_hours) {
+            return Err(CoreError::Invalid);
+        }
+
+        if let Some(role_id) = input.role_id {
+            let role = self
+                .role_repository
+                .get_by_id(role_id)
+                .await?
+                .filter(|role| role.realm_id == realm.id)
+                .ok_or(CoreError::NotFound)?;
+
+            // The role is granted on acceptance, so inviting with it is a grant.
+            ensure_policy(
+                self.policy
+                    .can_grant_organization_role(&identity, &realm, &org, &role)
+                    .await,
+                "insufficient permissions to grant this role",
+            )?;
+        }
+
+        if let Some(group_id) = input.group_id {
+            self.group_repository
+                .get_group_by_id(group_id)
+                .await?
+                .filter(|group| group.organization_id == org.id)
+                .ok_or(CoreError::NotFound)?;
+        }
+
+        let smtp_config = self
+            .smtp_config_repository
+            .get_by_realm_id(realm.id)
+            .await?
+            .ok_or_else(|| {
+                warn!("SMTP not configured for realm {}", realm.name);
+                CoreError::ServiceUnavailable(
+                    "Email delivery is not configured for this realm".to_string(),
+                )
+            })?;
+
+        // Only the latest invitation of an address works.
+        self.invitation_repository
+            .revoke_pending_invitations(org.id, &email)
+            .await?;
+
+        let token = generate_random_token();
+        let invitation = self
+            .invitation_repository
+            .create_invitation(CreateInvitationParams {
+                organization_id: org.id,
+                email: email.clone(),
+                role_id: input.role_id,
+                group_id: input.group_id,
+                invited_by: match &identity {
+                    Identity::User(user) => Some(user.id),
+                    Identity::Client(_) => None,
+                },
+                token_hash: hash_invitation_token(&token),
+                expires_at: Utc::now() + Duration::hours(ttl_hours),
+            })
+            .await?;
+
+        let invitation_link = format!(
+            "{}/realms/{}/authentication/invitation?token={}",
+            input.base_url.trim_end_matches('/'),
+            realm.name,
+            token
+        );
+
+        // The invitee has no account yet, so the email goes out in the realm default locale.
+        let messages = self.email_localizer.email_messages(realm.id, None).await;
+        let expires_at = invitation
+            .expires_at
+            .format("%Y-%m-%d %H:%M UTC")
+            .to_string();
+        let variables = [
+            ("organization.name", org.name.as_str()),
+            ("realm.name", realm.name.as_str()),
+            ("invitation_link", invitation_link.as_str()),
+            ("expires_at", expires_at.as_str()),
+        ];
+        let subject = messages.email_subject("organization_invitation", &variables);
+        let body = messages.email_body("organization_invitation", &variables);
+
+        let html_body = self
+            .render_invitation_template(&realm, &org, &invitation_link, &messages)
+            .await
+            .inspect_err(|e| warn!("Failed to render organization invitation template: {}", e))
+            .ok()
+            .flatten();
+
+        let delivery = timeout(
+            EMAIL_DELIVERY_TIMEOUT,
+            self.email_port
+                .send_email(&smtp_config, &email, &subject, &body, html_body),
+        )
+        .await;
+
+        match delivery {
+            Ok(result) => {
+                result.inspect_err(|e| warn!("Failed to send organization invitation: {}", e))?
+            }
+            Err(_) => {
+                warn!(invitation_id = %invitation.id, "Organization invitation delivery timed out");
+                return Err(CoreError::ServiceUnavailable(
+                    "Invitation email delivery timed out".to_string(),
+                ));
+            }
+        }
+
+        info!(
+            invitation_id = %invitation.id,
+            organization_id = %org.id,
+            "Organization invitation sent"
+        );
+
+        Ok(invitation)
+    }
+
+    async fn list_invitations(
+        &self,
+        identity: Identity,
+        input: ListInvitationsInput,
+    ) -> Result<Vec<OrganizationInvitation>, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        let org = self
+            .get_org_for_realm(input.organization_id, &realm)
+            .await?;
+
+        ensure_policy(
+            self.policy
+                .can_administer_organization(&identity, &realm, &org, OrganizationCapability::View)
+                .await,
+            "insufficient permissions to view organization invitations",
+        )?;
+
+        self.invitation_repository.list_invitations(org.id).await
+    }
+
+    async fn revoke_invitation(
+        &self,
+        identity: Identity,
+        input: RevokeInvitationInput,
+    ) -> Result<OrganizationInvitation, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        let org = self
+            .get_org_for_realm(input.organization_id, &realm)
+            .await?;
+
+        ensure_policy(
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageMembers,
+                )
+                .await,
+            "insufficient permissions to revoke organization invitations",
+        )?;
+
+        let invitation = self
+            .invitation_repository
+            .get_invitation_by_id(input.invitation_id)
+            .await?
+            .filter(|invitation| invitation.organization_id == org.id)
+            .ok_or(CoreError::NotFound)?;
+
+        if !invitation.is_pending() {
+            return Err(CoreError::InvalidOrganizationInvitation);
+        }
+
+        self.invitation_repository
+            .revoke_invitation(invitation.id)
+            .await?
+            .ok_or(CoreError::InvalidOrganizationInvitation)
+    }
+
+    async fn get_invitation(
+        &self,
+        input: GetInvitationInput,
+    ) -> Result<InvitationPreview, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        let (invitation, org) = self
+            .resolve_pending_invitation(&realm, &input.token)
+            .await?;
+
+        let account_exists = self
+            .user_repository
+            .get_by_email(&invitation.email, realm.id)
+            .await?
+            .is_some();
+
+        Ok(InvitationPreview {
+            organization_name: org.name,
+            organization_alias: org.alias,
+            email: invitation.email,
+            expires_at: invitation.expires_at,
+            account_exists,
+        })
+    }
+
+    async fn accept_invitation(
+        &self,
+        input: AcceptInvitationInput,
+    ) -> Result<AcceptedInvitation, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        let (invitation, org) = self
+            .resolve_pending_invitation(&realm, &input.token)
+            .await?;
+
+        let (user, account_created) = match self
+            .user_repository
+            .get_by_email(&invitation.email, realm.id)
+            .await?
+        {
+            Some(user) if !user.enabled => return Err(CoreError::UserDisabled),
+            Some(user) => (user, false),
+            None => (
+                self.create_invited_user(&realm, &invitation.email, input)
+                    .await?,
+                true,
+            ),
+        };
+
+        // Claims the invitation; a concurrent accept or revoke makes this one lose.
+        self.invitation_repository
+            .mark_invitation_accepted(invitation.id, user.id)
+            .await?
+            .ok_or(CoreError::InvalidOrganizationInvitation)?;
+
+        let member = match self
+            .organization_member_repository
+            .get_member(org.id, user.id)
+            .await?
+        {
+            Some(member) => member,
+            None => {
+                self.organization_member_repository
+                    .add_member(org.id, user.id)
+                    .await?
+            }
+        };
+
+        if let Some(role_id) = invitation.role_id {
+            self.organization_member_role_repository
+                .assign_role(member.id, role_id)
+                .await?;
+        }
+
+        if let Some(group_id) = invitation.group_id
+            && self
+                .group_member_repository
+                .get_member(group_id, user.id)
+                .await?
+                .is_none()
+        {
+            self.group_member_repository
+                .add_member(group_id, user.id)
+                .await?;
+        }
+
+        info!(
+            invitation_id = %invitation.id,
+            organization_id = %org.id,
+            user_id = %user.id,
+            account_created,
+            "Organization invitation accepted"
+        );
+
+        Ok(AcceptedInvitation {
+            organization_id: org.id,
+            user_id: user.id,
+            account_created,
+        })
+    }
+}
@@ -5,14 +5,12 @@ use ferriskey_domain::realm::{Realm, RealmId};
 use crate::domain::{
     authentication::value_objects::Identity,
     client::ports::ClientRepository,
-    common::{
-        entities::app_errors::CoreError,
-        policies::{FerriskeyPolicy, ensure_policy},
-    },
+    common::{entities::app_errors::CoreError, policies::ensure_policy},
+    organization::policies::OrganizationAdminPolicy,
     organization::ports::{
-        AssignMemberRoleInput, ListMemberRolesInput, Organization, OrganizationId,
-        OrganizationMemberRepository, OrganizationMemberRoleRepository,
-        OrganizationMemberRoleService, OrganizationPolicy, OrganizationRepository,
+        AssignMemberRoleInput, ListMemberRolesInput, Organization, OrganizationCapability,
+        OrganizationId, OrganizationMemberRepository, OrganizationMemberRoleRepository,
+        OrganizationMemberRoleService, OrganizationRepository, OrganizationScopedPolicy,
         RevokeMemberRoleInput,
     },
     realm::ports::RealmRepository,
@@ -21,7 +19,9 @@ use crate::domain::{
 };
 
 /// Business logic for roles scoped to an organization membership. Kept as a dedicated service so
-/// the existing organization/group services (and their generics) stay untouched.
+/// the existing organization/group services (and their generics) stay untouched. Granting or
+/// revoking a role is checked against the role itself, which is how delegated organization
+/// administrators are kept from handing out roles above their own.
 #[derive(Clone, Debug)]
 pub struct OrganizationMemberRoleServiceImpl<R, U, C, UR, OR, OMR, OMRR>
 where
@@ -38,7 +38,7 @@ where
     pub(crate) organization_repository: Arc<OR>,
     pub(crate) organization_member_repository: Arc<OMR>,
     pub(crate) organization_member_role_repository: Arc<OMRR>,
-    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    pub(crate) policy: Arc<OrganizationAdminPolicy<U, C, UR, OMR, OMRR>>,
 }
 
 impl<R, U, C, UR, OR, OMR, OMRR> OrganizationMemberRoleServiceImpl<R, U, C, UR, OR, OMR, OMRR>
@@ -57,7 +57,7 @@ where
         organization_repository: Arc<OR>,
         organization_member_repository: Arc<OMR>,
         organization_member_role_repository: Arc<OMRR>,
-        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+        policy: Arc<OrganizationAdminPolicy<U, C, UR, OMR, OMRR>>,
     ) -> Self {
         Self {
             realm_repository,
@@ -118,6 +118,45 @@ where
 
         Ok(member.id)
     }
+
+    async fn ensure_can_grant_role(
+        &self,
+        identity: &Identity,
+        realm: &Realm,
+        org: &Organization,
+        role_id: uuid::Uuid,
+    ) -> Result<(), CoreError> {
+        ensure_policy(
+            self.policy
+                .can_administer_organization(
+                    identity,
+                    realm,
+                    org,
+                    OrganizationCapability::ManageMembers,
+                )
+                .await,
+            "insufficient permissions to manage member roles",
+        )?;
+
+        let role = self
+            .user_role_repository
+            .get_roles_by_ids(vec![role_id])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(CoreError::NotFound)?;
+
+        if role.realm_id != realm.id {
+            return Err(CoreError::NotFound);
+        }
+
+        ensure_policy(
+            self.policy
+                .can_grant_organization_role(identity, realm, org, &role)
+                .await,
+            "insufficient permissions to grant this role",
+        )
+    }
 }
 
 impl<R, U, C, UR, OR, OMR, OMRR> OrganizationMemberRoleService
@@ -140,10 +179,8 @@ where
             .get_org_for_realm_name(input.realm_name, input.organization_id)
             .await?;
 
-        ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
-            "insufficient permissions to manage member roles",
-        )?;
+        self.ensure_can_grant_role(&identity, &realm, &org, input.role_id)
+            .await?;
 
         let member_id = self.resolve_member_id(&org, input.user_id).await?;
         self.organization_member_role_repository
@@ -160,10 +197,8 @@ where
             .get_org_for_realm_name(input.realm_name, input.organization_id)
             .await?;
 
-        ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
-            "insufficient permissions to manage member roles",
-        )?;
+        self.ensure_can_grant_role(&identity, &realm, &org, input.role_id)
+            .await?;
 
         let member_id = self.resolve_member_id(&org, input.user_id).await?;
         self.organization_member_role_repository
@@ -181,7 +216,9 @@ where
             .await?;
 
         ensure_policy(
-            self.policy.can_view_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(&identity, &realm, &org, OrganizationCapability::View)
+                .await,
             "insufficient permissions to view member roles",
         )?;
 
@@ -205,7 +242,8 @@ mod tests {
     use ferriskey_domain::realm::RealmId;
     use ferriskey_organization::{
         MockOrganizationMemberRepository, MockOrganizationMemberRoleRepository,
-        MockOrganizationRepository, Organization, OrganizationId, OrganizationMember,
+        MockOrganizationRepository, Organization, OrganizationAdminRole, OrganizationId,
+        OrganizationMember,
     };
 
     use crate::domain::{
@@ -304,18 +342,25 @@ mod tests {
     ) -> TestService {
         let user_arc = Arc::new(user_repo);
         let user_role_arc = Arc::new(user_role_repo);
-        let policy = Arc::new(FerriskeyPolicy::new(
-            user_arc.clone(),
+        let member_arc = Arc::new(member_repo);
+        let member_role_arc = Arc::new(member_role_repo);
+        let realm_policy = Arc::new(FerriskeyPolicy::new(
+            user_arc,
             Arc::new(MockClientRepository::new()),
             user_role_arc.clone(),
         ));
+        let policy = Arc::new(OrganizationAdminPolicy::new(
+            realm_policy,
+            member_arc.clone(),
+            member_role_arc.clone(),
+        ));
 
         OrganizationMemberRoleServiceImpl::new(
             Arc::new(realm_repo),
             user_role_arc,
             Arc::new(org_repo),
-            Arc::new(member_repo),
-            Arc::new(member_role_repo),
+            member_arc,
+            member_role_arc,
             policy,
         )
     }
@@ -345,6 +390,138 @@ mod tests {
             Box::pin(async move { Ok(vec![role]) })
         });
         user_role_repo
+            .expect_get_roles_by_ids()
+            .returning(move |ids| {
+                let roles = ids
+                    .into_iter()
+                    .map(|id| Role {
+                        id,
+                        name: "billing-viewer".to_string(),
+                        permissions: vec![],
+                        ..make_role(realm_id, "view_users")
+                    })
+                    .collect();
+                Box::pin(async move { Ok(roles) })
+            });
+        user_role_repo
+    }
+
+    fn non_member_repo() -> MockOrganizationMemberRepository {
+        let mut member_repo = MockOrganizationMemberRepository::new();
+        member_repo
+            .expect_get_member()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        member_repo
+    }
+
+    /// An actor without realm permissions holding a role named `org-admin`, a target member,
+    /// and a catalogue in which `granted_role_id` resolves to a permission-less realm role named
+    /// `granted_name`. With `seeded`, roles named like an admin role are the seeded ones.
+    fn delegated_admin_setup(
+        realm_id: RealmId,
+        org_id: OrganizationId,
+        actor_id: Uuid,
+        target_user_id: Uuid,
+        granted_role_id: Uuid,
+        granted_name: &'static str,
+        seeded: bool,
+    ) -> (
+        MockUserRoleRepository,
+        MockOrganizationMemberRepository,
+        MockOrganizationMemberRoleRepository,
+        Uuid,
+    ) {
+        let actor_member_id = Uuid::new_v4();
+        let target_member_id = Uuid::new_v4();
+        let admin_role_id = Uuid::new_v4();
+
+        let mut member_repo = MockOrganizationMemberRepository::new();
+        member_repo
+            .expect_get_member()
+            .returning(move |organization_id, user_id| {
+                let id = if organization_id != org_id {
+                    None
+                } else if user_id == actor_id {
+                    Some(actor_member_id)
+                } else if user_id == target_user_id {
+                    Some(target_member_id)
+                } else {
+                    None
+                };
+                let member = id.map(|id| OrganizationMember {
+                    id,
+                    organization_id,
+                    user_id,
+                    created_at: Utc::now(),
+                });
+                Box::pin(async move { Ok(member) })
+            });
+
+        let mut member_role_repo = MockOrganizationMemberRoleRepository::new();
+        member_role_repo
+            .expect_list_role_ids()
+            .returning(move |member_id| {
+                let ids = if member_id == actor_member_id {
+                    vec![admin_role_id]
+                } else {
+                    vec![]
+                };
+                Box::pin(async move { Ok(ids) })
+            });
+        member_role_repo
+            .expect_find_admin_roles()
+            .returning(move |seeded_realm_id, ids| {
+                let roles = ids
+                    .into_iter()
+                    .filter(|_| seeded && seeded_realm_id == realm_id)
+                    .filter_map(|id| {
+                        if id == admin_role_id {
+                            Some((id, OrganizationAdminRole::Admin))
+                        } else if id == granted_role_id {
+                            OrganizationAdminRole::from_role_name(granted_name)
+                                .map(|admin_role| (id, admin_role))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                Box::pin(async move { Ok(roles) })
+            });
+
+        let mut user_role_repo = MockUserRoleRepository::new();
+        user_role_repo
+            .expect_get_user_roles()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        user_role_repo
+            .expect_get_roles_by_ids()
+            .returning(move |ids| {
+                let roles = ids
+                    .into_iter()
+                    .filter_map(|id| {
+                        let name = if id == admin_role_id {
+                            OrganizationAdminRole::Admin.role_name()
+                        } else if id == granted_role_id {
+                            granted_name
+                        } else {
+                            return None;
+                        };
+                        Some(Role {
+                            id,
+                            name: name.to_string(),
+                            permissions: vec![],
+                            ..make_role(realm_id, "view_users")
+                        })
+                    })
+                    .collect();
+                Box::pin(async move { Ok(roles) })
+            });
+
+        (
+            user_role_repo,
+            member_repo,
+            member_role_repo,
+            target_member_id,
+        )
     }
 
     #[tokio::test]
@@ -467,7 +644,7 @@ mod tests {
             admin_user_repo(admin),
             admin_role_repo(realm_id, "view_users"), // lacks manage rights
             org_repo,
-            MockOrganizationMemberRepository::new(),
+            non_member_repo(),
             MockOrganizationMemberRoleRepository::new(),
         );
 
@@ -553,4 +730,153 @@ mod tests {
 
         assert!(matches!(result, Err(CoreError::Forbidden(_))));
     }
+
+    #[tokio::test]
+    async fn org_admin_assigns_ordinary_role_in_own_organization() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let actor = make_user(&make_realm(realm_id));
+        let org = make_org(realm_id);
+        let org_id = org.id;
+        let target_user_id = Uuid::new_v4();
+        let role_id = Uuid::new_v4();
+        let (user_role_repo, member_repo, mut member_role_repo, target_member_id) =
+            delegated_admin_setup(
+                realm_id,
+                org_id,
+                actor.id,
+                target_user_id,
+                role_id,
+                "billing-viewer",
+                true,
+            );
+        member_role_repo
+            .expect_assign_role()
+            .withf(move |mid, rid| *mid == target_member_id && *rid == role_id)
+            .return_once(|_, _| Box::pin(async { Ok(()) }));
+
+        let mut org_repo = MockOrganizationRepository::new();
+        org_repo
+            .expect_get_organization_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(org)) }));
+
+        let service = build_service(
+            realm_repo_returning(realm_id),
+            MockUserRepository::new(),
+            user_role_repo,
+            org_repo,
+            member_repo,
+            member_role_repo,
+        );
+
+        let result = service
+            .assign_role(
+                Identity::User(actor),
+                AssignMemberRoleInput {
+                    realm_name: "test-realm".to_string(),
+                    organization_id: org_id,
+                    user_id: target_user_id,
+                    role_id,
+                },
+            )
+            .await;
+
+        assert!(
+            result.is_ok(),
+            "org admin must assign roles, got {result:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn org_admin_cannot_grant_owner_role() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let actor = make_user(&make_realm(realm_id));
+        let org = make_org(realm_id);
+        let org_id = org.id;
+        let target_user_id = Uuid::new_v4();
+        let role_id = Uuid::new_v4();
+        let (user_role_repo, member_repo, member_role_repo, _) = delegated_admin_setup(
+            realm_id,
+            org_id,
+            actor.id,
+            target_user_id,
+            role_id,
+            "org-owner",
+            true,
+        );
+
+        let mut org_repo = MockOrganizationRepository::new();
+        org_repo
+            .expect_get_organization_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(org)) }));
+
+        let service = build_service(
+            realm_repo_returning(realm_id),
+            MockUserRepository::new(),
+            user_role_repo,
+            org_repo,
+            member_repo,
+            member_role_repo,
+        );
+
+        let result = service
+            .assign_role(
+                Identity::User(actor),
+                AssignMemberRoleInput {
+                    realm_name: "test-realm".to_string(),
+                    organization_id: org_id,
+                    user_id: target_user_id,
+                    role_id,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn role_named_like_an_admin_role_grants_nothing_unless_seeded() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let actor = make_user(&make_realm(realm_id));
+        let org = make_org(realm_id);
+        let org_id = org.id;
+        let target_user_id = Uuid::new_v4();
+        let role_id = Uuid::new_v4();
+        let (user_role_repo, member_repo, member_role_repo, _) = delegated_admin_setup(
+            realm_id,
+            org_id,
+            actor.id,
+            target_user_id,
+            role_id,
+            "billing-viewer",
+            false,
+        );
+
+        let mut org_repo = MockOrganizationRepository::new();
+        org_repo
+            .expect_get_organization_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(org)) }));
+
+        let service = build_service(
+            realm_repo_returning(realm_id),
+            MockUserRepository::new(),
+            user_role_repo,
+            org_repo,
+            member_repo,
+            member_role_repo,
+        );
+
+        let result = service
+            .assign_role(
+                Identity::User(actor),
+                AssignMemberRoleInput {
+                    realm_name: "test-realm".to_string(),
+                    organization_id: org_id,
+                    user_id: target_user_id,
+                    role_id,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
 }
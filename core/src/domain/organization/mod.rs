@@ -1,4 +1,7 @@
+pub mod domain_services;
 pub mod group_services;
+pub mod invitation_services;
 pub mod member_role_services;
+pub mod policies;
 pub mod ports;
 pub mod services;
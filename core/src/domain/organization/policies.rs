@@ -0,0 +1,3 @@
+//! `OrganizationAdminPolicy` lives in the `ferriskey-organization` lib crate next to the
+//! realm-level organization policy. Re-exported so core services can name it.
+pub use ferriskey_organization::policies::*;
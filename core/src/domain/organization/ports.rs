@@ -1,18 +1,26 @@
 pub use ferriskey_organization::{
-    AddGroupMemberInput, AddOrganizationMemberInput, AssignGroupRoleInput, AssignMemberRoleInput,
-    CreateGroupInput, CreateGroupParams, CreateOrganizationInput, CreateOrganizationParams,
-    DeleteGroupAttributeInput, DeleteGroupInput, DeleteOrganizationAttributeInput,
-    DeleteOrganizationInput, GetGroupInput, GetOrganizationInput, Group, GroupAttribute,
-    GroupAttributeRepository, GroupConfig, GroupId, GroupMember, GroupMemberDetail,
-    GroupMemberPage, GroupMemberRepository, GroupNode, GroupRepository, GroupRoleMapping,
-    GroupRoleRepository, GroupService, GroupTokenRepository, ListGroupAttributesInput,
-    ListGroupMembersInput, ListGroupRolesInput, ListGroupsInput, ListMemberRolesInput,
-    ListOrganizationAttributesInput, ListOrganizationMembersInput, ListOrganizationsInput,
-    ListUserOrganizationsInput, Organization, OrganizationAttribute,
-    OrganizationAttributeRepository, OrganizationId, OrganizationMember,
+    AcceptInvitationInput, AcceptedInvitation, AddGroupMemberInput, AddOrganizationMemberInput,
+    AssignGroupRoleInput, AssignMemberRoleInput, ConfigureOrganizationDomainInput,
+    CreateGroupInput, CreateGroupParams, CreateInvitationInput, CreateInvitationParams,
+    CreateOrganizationInput, CreateOrganizationParams, DeleteGroupAttributeInput, DeleteGroupInput,
+    DeleteOrganizationAttributeInput, DeleteOrganizationInput, DiscoverHomeRealmInput,
+    DomainTxtResolver, GetGroupInput, GetInvitationInput, GetOrganizationDomainInput,
+    GetOrganizationInput, Group, GroupAttribute, GroupAttributeRepository, GroupConfig, GroupId,
+    GroupMember, GroupMemberDetail, GroupMemberPage, GroupMemberRepository, GroupNode,
+    GroupRepository, GroupRoleMapping, GroupRoleRepository, GroupService, GroupTokenRepository,
+    HomeRealmDiscovery, InvitationPreview, InvitationStatus, ListGroupAttributesInput,
+    ListGroupMembersInput, ListGroupRolesInput, ListGroupsInput, ListInvitationsInput,
+    ListMemberRolesInput, ListOrganizationAttributesInput, ListOrganizationMembersInput,
+    ListOrganizationsInput, ListUserOrganizationsInput, Organization, OrganizationAdminRole,
+    OrganizationAttribute, OrganizationAttributeRepository, OrganizationCapability,
+    OrganizationDomain, OrganizationDomainRepository, OrganizationDomainService,
+    OrganizationDomainStatus, OrganizationId, OrganizationInvitation,
+    OrganizationInvitationRepository, OrganizationInvitationService, OrganizationMember,
     OrganizationMemberRepository, OrganizationMemberRoleRepository, OrganizationMemberRoleService,
-    OrganizationPolicy, OrganizationRepository, OrganizationService, OrganizationValidationError,
-    RemoveGroupMemberInput, RemoveOrganizationMemberInput, RevokeGroupRoleInput,
-    RevokeMemberRoleInput, UpdateGroupInput, UpdateGroupParams, UpdateOrganizationInput,
+    OrganizationPolicy, OrganizationRepository, OrganizationScopedPolicy, OrganizationService,
+    OrganizationValidationError, RemoveGroupMemberInput, RemoveOrganizationMemberInput,
+    RevokeGroupRoleInput, RevokeInvitationInput, RevokeMemberRoleInput,
+    SaveOrganizationDomainParams, UpdateGroupInput, UpdateGroupParams, UpdateOrganizationInput,
     UpdateOrganizationParams, UpsertGroupAttributeInput, UpsertOrganizationAttributeInput,
+    VerifyOrganizationDomainInput, email_domain, normalize_invitation_email,
 };
@@ -0,0 +1,5 @@
+//! Per-realm rate limiting lives in the `ferriskey-rate-limit` lib crate — entities, the
+//! in-memory limiter, ports, the `RateLimitAccessPolicy` impl and the generic
+//! `RateLimitServiceImpl`. The SeaORM-backed repositories stay in `core` under
+//! `infrastructure/rate_limit`.
+pub use ferriskey_rate_limit::*;
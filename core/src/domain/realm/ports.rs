@@ -141,6 +141,7 @@ pub struct UpdateRealmSettingInput {
     pub reset_password_template_id: Option<Option<Uuid>>,
     pub magic_link_template_id: Option<Option<Uuid>>,
     pub email_verification_template_id: Option<Option<Uuid>>,
+    pub organization_invitation_template_id: Option<Option<Uuid>>,
     pub email_verification_enabled: Option<bool>,
     pub email_verification_ttl_hours: Option<i64>,
     pub lockout_threshold: Option<i32>,
@@ -164,6 +165,7 @@ pub struct UpsertSmtpConfigInput {
     pub from_email: String,
     pub from_name: String,
     pub encryption: String,
+    pub max_emails_per_minute: Option<u32>,
 }
 
 pub struct GetSmtpConfigInput {
@@ -16,6 +16,7 @@ use crate::domain::{
         generate_random_string,
         policies::{FerriskeyPolicy, ensure_policy},
     },
+    organization::ports::OrganizationMemberRoleRepository,
     realm::{
         entities::{Realm, RealmId, RealmLoginSetting, RealmSetting, SmtpConfig},
         ports::{
@@ -42,7 +43,7 @@ use serde_json::json;
 use tracing::instrument;
 
 #[derive(Clone, Debug)]
-pub struct RealmServiceImpl<R, U, C, UR, RO, W, I, CS, PM, CSM, RU>
+pub struct RealmServiceImpl<R, U, C, UR, RO, W, I, CS, PM, CSM, RU, OMR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -55,6 +56,7 @@ where
     PM: ProtocolMapperRepository,
     CSM: ClientScopeMappingRepository,
     RU: RedirectUriRepository,
+    OMR: OrganizationMemberRoleRepository,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) user_repository: Arc<U>,
@@ -67,6 +69,7 @@ where
     pub(crate) protocol_mapper_repository: Arc<PM>,
     pub(crate) client_scope_mapping_repository: Arc<CSM>,
     pub(crate) redirect_uri_repository: Arc<RU>,
+    pub(crate) organization_member_role_repository: Arc<OMR>,
 
     pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
 
@@ -74,8 +77,8 @@ where
     pub(crate) webapp_url: String,
 }
 
-impl<R, U, C, UR, RO, W, I, CS, PM, CSM, RU>
-    RealmServiceImpl<R, U, C, UR, RO, W, I, CS, PM, CSM, RU>
+impl<R, U, C, UR, RO, W, I, CS, PM, CSM, RU, OMR>
+    RealmServiceImpl<R, U, C, UR, RO, W, I, CS, PM, CSM, RU, OMR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -88,6 +91,7 @@ where
     PM: ProtocolMapperRepository,
     CSM: ClientScopeMappingRepository,
     RU: RedirectUriRepository,
+    OMR: OrganizationMemberRoleRepository,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -102,6 +106,7 @@ where
         protocol_mapper_repository: Arc<PM>,
         client_scope_mapping_repository: Arc<CSM>,
         redirect_uri_repository: Arc<RU>,
+        organization_member_role_repository: Arc<OMR>,
         policy: Arc<FerriskeyPolicy<U, C, UR>>,
         webapp_url: String,
     ) -> Self {
@@ -118,6 +123,7 @@ where
             protocol_mapper_repository,
             client_scope_mapping_repository,
             redirect_uri_repository,
+            organization_member_role_repository,
             policy,
         }
     }
@@ -295,8 +301,8 @@ where
     }
 }
 
-impl<R, U, C, UR, RO, W, I, CS, PM, CSM, RU> RealmService
-    for RealmServiceImpl<R, U, C, UR, RO, W, I, CS, PM, CSM, RU>
+impl<R, U, C, UR, RO, W, I, CS, PM, CSM, RU, OMR> RealmService
+    for RealmServiceImpl<R, U, C, UR, RO, W, I, CS, PM, CSM, RU, OMR>
 where
     R: RealmRepository,
     C: ClientRepository,
@@ -309,6 +315,7 @@ where
     PM: ProtocolMapperRepository,
     CSM: ClientScopeMappingRepository,
     RU: RedirectUriRepository,
+    OMR: OrganizationMemberRoleRepository,
 {
     #[instrument(
         skip(self, identity, input),
@@ -343,6 +350,10 @@ where
             .create_realm_settings(realm.id, "RS256".to_string())
             .await?;
 
+        self.organization_member_role_repository
+            .seed_admin_roles(realm.id)
+            .await?;
+
         let name = format!("{}-realm", realm.name);
 
         let client = self
@@ -820,6 +831,7 @@ where
                 input.reset_password_template_id,
                 input.magic_link_template_id,
                 input.email_verification_template_id,
+                input.organization_invitation_template_id,
                 input.email_verification_enabled,
                 input.email_verification_ttl_hours,
                 input.lockout_threshold,
@@ -993,6 +1005,7 @@ where
                 .encryption
                 .parse()
                 .expect("invariant: SmtpEncryption FromStr is infallible"),
+            max_emails_per_minute: input.max_emails_per_minute,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -1048,6 +1061,7 @@ mod tests {
         webhook::ports::MockWebhookRepository,
     };
     use ferriskey_aegis::entities::{ClientScope, ClientScopeMapping, ProtocolMapper, ScopeType};
+    use ferriskey_organization::MockOrganizationMemberRoleRepository;
 
     struct RealmServiceTestBuilder {
         realm_repo: Arc<MockRealmRepository>,
@@ -1061,6 +1075,7 @@ mod tests {
         protocol_mapper_repo: Arc<MockProtocolMapperRepository>,
         client_scope_mapping_repo: Arc<MockClientScopeMappingRepository>,
         redirect_uri_repo: Arc<MockRedirectUriRepository>,
+        organization_member_role_repo: Arc<MockOrganizationMemberRoleRepository>,
     }
 
     impl RealmServiceTestBuilder {
@@ -1076,6 +1091,8 @@ mod tests {
             let protocol_mapper_repo = Arc::new(MockProtocolMapperRepository::new());
             let client_scope_mapping_repo = Arc::new(MockClientScopeMappingRepository::new());
             let redirect_uri_repo = Arc::new(MockRedirectUriRepository::new());
+            let organization_member_role_repo =
+                Arc::new(MockOrganizationMemberRoleRepository::new());
 
             Self {
                 realm_repo,
@@ -1089,6 +1106,7 @@ mod tests {
                 protocol_mapper_repo,
                 client_scope_mapping_repo,
                 redirect_uri_repo,
+                organization_member_role_repo,
             }
         }
 
@@ -1350,6 +1368,15 @@ mod tests {
             self
         }
 
+        fn with_organization_admin_roles(mut self) -> Self {
+            Arc::get_mut(&mut self.organization_member_role_repo)
+                .unwrap()
+                .expect_seed_admin_roles()
+                .times(1)
+                .return_once(|_| Box::pin(async move { Ok(()) }));
+            self
+        }
+
         fn with_assign_role(mut self) -> Self {
             Arc::get_mut(&mut self.user_role_repo)
                 .unwrap()
@@ -1463,6 +1490,7 @@ mod tests {
             MockProtocolMapperRepository,
             MockClientScopeMappingRepository,
             MockRedirectUriRepository,
+            MockOrganizationMemberRoleRepository,
         > {
             let policy = Arc::new(FerriskeyPolicy::new(
                 self.user_repo.clone(),
@@ -1481,6 +1509,7 @@ mod tests {
                 self.protocol_mapper_repo,
                 self.client_scope_mapping_repo,
                 self.redirect_uri_repo,
+                self.organization_member_role_repo,
                 policy,
                 "https://console.example".to_string(),
             )
@@ -1530,6 +1559,7 @@ mod tests {
             .with_realm_settings(new_realm.id)
             .with_system_client(master_realm.id)
             .with_role_creation(master_realm.id)
+            .with_organization_admin_roles()
             .with_assign_role()
             .with_admin_cli_client(new_realm.id)
             .with_ferriskey_account_client(new_realm.id)
@@ -1588,6 +1618,7 @@ mod tests {
             .with_realm_settings(new_realm.id)
             .with_system_client(master_realm.id)
             .with_role_creation(master_realm.id)
+            .with_organization_admin_roles()
             .with_assign_role()
             .with_admin_cli_client(new_realm.id)
             .with_ferriskey_account_client(new_realm.id)
@@ -0,0 +1,4 @@
+//! Realm-level groups live in the `ferriskey-realm-group` lib crate — entities, ports, the
+//! `RealmGroupPolicy` impl and the generic `RealmGroupServiceImpl`. The SeaORM-backed
+//! repositories stay in `core` under `infrastructure/realm_group`.
+pub use ferriskey_realm_group::*;
@@ -0,0 +1,5 @@
+//! Login risk evaluation lives in the `ferriskey-risk` lib crate — entities, the scoring
+//! functions, ports, the `LoginRiskAccessPolicy` impl and the generic `LoginRiskServiceImpl`.
+//! The SeaORM-backed repositories and the MaxMind GeoIP reader stay in `core` under
+//! `infrastructure/risk`.
+pub use ferriskey_risk::*;
@@ -241,7 +241,7 @@ where
         )?;
 
         self.user_role_repository
-            .get_user_roles(input.user_id)
+            .get_direct_user_roles(input.user_id)
             .await
             .map_err(|_| CoreError::InternalServerError)
     }
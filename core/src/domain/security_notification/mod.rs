@@ -0,0 +1,6 @@
+//! Security notifications live in the `ferriskey-mail` lib crate (`security_notification`
+//! module) — per-realm settings, ports, the `SecurityNotificationPolicy` impl, the generic
+//! `SecurityNotificationServiceImpl` and the `EmailSecurityNotifier`. The `SecurityNotifier`
+//! port itself comes from `ferriskey-domain`. The SeaORM-backed repository stays in `core`
+//! under `infrastructure/`.
+pub use ferriskey_mail::security_notification::*;
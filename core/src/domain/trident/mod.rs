@@ -2,3 +2,4 @@ pub mod entities;
 pub mod mfa_policy;
 pub mod ports;
 pub mod services;
+pub mod webauthn;
@@ -11,18 +11,21 @@ use rand::RngCore;
 use sha1::Sha1;
 use tracing::{debug, error, warn};
 use uuid::Uuid;
-use webauthn_rs::prelude::*;
+use webauthn_rs::prelude::{AuthenticationResult, Credential as WebAuthnCredential, CredentialID};
 
 use crate::{
     domain::{
+        abyss::federation::ports::FederatedPasswordPort,
         authentication::{
             entities::{AuthSession, WebAuthnChallenge},
             ports::AuthSessionRepository,
             value_objects::Identity,
         },
         common::{
-            email::EmailPort, entities::app_errors::CoreError, generate_random_string,
-            generate_random_token,
+            email::EmailPort,
+            entities::app_errors::CoreError,
+            generate_random_string, generate_random_token,
+            security_notification::{SecurityNotice, SecurityNotificationKind, SecurityNotifier},
         },
         credential::{
             entities::{Credential, CredentialData, CredentialType},
@@ -33,6 +36,7 @@ use crate::{
             entities::interpolate_variables,
             ports::{EmailTemplateRepository, TemplateRenderer},
         },
+        localization::{EmailLocalizer, LocalizedMessages},
         password_policy::{
             entity::PasswordPolicy, repository::PasswordPolicyRepository,
             service::violations_to_core_error, validator,
@@ -64,11 +68,13 @@ use crate::{
                 WebAuthnPublicKeyRequestOptionsOutput, WebAuthnRpInfo,
                 WebAuthnValidatePublicKeyInput, WebAuthnValidatePublicKeyOutput,
             },
+            webauthn::RelyingParty,
         },
         user::{
             entities::RequiredAction,
             ports::{UserRepository, UserRequiredActionRepository, UserRoleRepository},
         },
+        webauthn_policy::WebAuthnPolicyProvider,
         webhook::{
             entities::{webhook_payload::WebhookPayload, webhook_trigger::WebhookTrigger},
             ports::WebhookRepository,
@@ -132,7 +138,7 @@ fn generate_totp_code(secret: &[u8], counter: u64, digits: u32) -> Result<u32, C
     Ok(code % 10u32.pow(digits))
 }
 
-fn verify(secret: &TotpSecret, code: &str) -> Result<bool, CoreError> {
+pub(crate) fn verify(secret: &TotpSecret, code: &str) -> Result<bool, CoreError> {
     let Ok(expected_code) = code.parse::<u32>() else {
         error!("failed to parse code: {}", code);
         return Ok(false);
@@ -178,22 +184,35 @@ fn decode_string(code: String, format: RecoveryCodeFormat) -> Result<MfaRecovery
     }
 }
 
-fn build_webauthn_client(rp_info: WebAuthnRpInfo) -> Result<Webauthn, CoreError> {
-    let rp_url = Url::parse(&rp_info.allowed_origin).map_err(|e| {
-        error!("Failed to parse server_host as URL: {e}");
-        CoreError::InternalServerError
-    })?;
+/// The stored WebAuthn credentials the realm policy still accepts. Credentials refused since
+/// they were registered, e.g. a blocked model, cannot be used to sign in.
+fn accepted_webauthn_credentials(
+    relying_party: &RelyingParty,
+    credentials: Vec<Credential>,
+) -> Result<Vec<WebAuthnCredential>, CoreError> {
+    let mut accepted = Vec::with_capacity(credentials.len());
+    let mut rejection = None;
+
+    for credential in credentials {
+        let credential = match credential.credential_data {
+            CredentialData::WebAuthn { credential } => *credential,
+            _ => {
+                error!("A WebAuthn credential doesn't hold WebAuthn credential data");
+                return Err(CoreError::InternalServerError);
+            }
+        };
 
-    WebauthnBuilder::new(&rp_info.rp_id, &rp_url)
-        .map_err(|e| {
-            error!("Failed to build Webauthn client: {e:?}");
-            CoreError::InternalServerError
-        })?
-        .build()
-        .map_err(|e| {
-            error!("Failed to build Webauthn client: {e:?}");
-            CoreError::InternalServerError
-        })
+        match relying_party.check_credential(&credential) {
+            Ok(()) => accepted.push(credential),
+            Err(e) => rejection = Some(e),
+        }
+    }
+
+    // Only refuse outright when the policy is why nothing is left.
+    match rejection {
+        Some(e) if accepted.is_empty() => Err(e),
+        _ => Ok(accepted),
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -217,6 +236,10 @@ pub struct TridentServiceImpl<
     OER,
     URR,
     TRV,
+    FPW,
+    EL,
+    WPP,
+    SN,
 > where
     CR: CredentialRepository,
     RC: RecoveryCodeRepository,
@@ -237,6 +260,10 @@ pub struct TridentServiceImpl<
     OER: OtpEnrollmentRepository,
     URR: UserRoleRepository,
     TRV: TokenRevocationPort,
+    FPW: FederatedPasswordPort,
+    EL: EmailLocalizer,
+    WPP: WebAuthnPolicyProvider,
+    SN: SecurityNotifier,
 {
     pub(crate) credential_repository: Arc<CR>,
     pub(crate) recovery_code_repository: Arc<RC>,
@@ -257,9 +284,37 @@ pub struct TridentServiceImpl<
     pub(crate) otp_enrollment_repository: Arc<OER>,
     pub(crate) user_role_repository: Arc<URR>,
     pub(crate) token_revocation: Arc<TRV>,
+    pub(crate) federated_password: Arc<FPW>,
+    pub(crate) email_localizer: Arc<EL>,
+    pub(crate) webauthn_policy_provider: Arc<WPP>,
+    pub(crate) security_notifier: Arc<SN>,
 }
 
-impl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR, PPR, OER, URR, TRV>
+impl<
+    CR,
+    RC,
+    AS,
+    H,
+    URA,
+    ML,
+    UR,
+    RR,
+    ES,
+    SC,
+    PRT,
+    SE,
+    WH,
+    ETR,
+    TR,
+    PPR,
+    OER,
+    URR,
+    TRV,
+    FPW,
+    EL,
+    WPP,
+    SN,
+>
     TridentServiceImpl<
         CR,
         RC,
@@ -280,6 +335,10 @@ impl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR, PPR, OER, URR
         OER,
         URR,
         TRV,
+        FPW,
+        EL,
+        WPP,
+        SN,
     >
 where
     CR: CredentialRepository,
@@ -301,6 +360,10 @@ where
     OER: OtpEnrollmentRepository,
     URR: UserRoleRepository,
     TRV: TokenRevocationPort,
+    FPW: FederatedPasswordPort,
+    EL: EmailLocalizer,
+    WPP: WebAuthnPolicyProvider,
+    SN: SecurityNotifier,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -323,6 +386,10 @@ where
         otp_enrollment_repository: Arc<OER>,
         user_role_repository: Arc<URR>,
         token_revocation: Arc<TRV>,
+        federated_password: Arc<FPW>,
+        email_localizer: Arc<EL>,
+        webauthn_policy_provider: Arc<WPP>,
+        security_notifier: Arc<SN>,
     ) -> Self {
         Self {
             credential_repository,
@@ -344,6 +411,10 @@ where
             otp_enrollment_repository,
             user_role_repository,
             token_revocation,
+            federated_password,
+            email_localizer,
+            webauthn_policy_provider,
+            security_notifier,
         }
     }
 
@@ -432,6 +503,7 @@ where
         template_id: Uuid,
         user: &crate::domain::user::entities::User,
         extra_vars: &[(&str, &str)],
+        messages: &LocalizedMessages,
     ) -> Result<String, CoreError> {
         let template = self
             .email_template_repository
@@ -441,7 +513,7 @@ where
 
         let html = self.template_renderer.render_to_html(&template.mjml)?;
 
-        let mut variables = std::collections::HashMap::new();
+        let mut variables = messages.template_variables();
         variables.insert(
             "user.first_name".to_string(),
             user.firstname.clone().unwrap_or_default(),
@@ -460,10 +532,62 @@ where
 
         Ok(interpolate_variables(&html, &variables))
     }
+
+    async fn relying_party(
+        &self,
+        rp_info: WebAuthnRpInfo,
+        realm_id: RealmId,
+    ) -> Result<RelyingParty, CoreError> {
+        let policy = self
+            .webauthn_policy_provider
+            .webauthn_policy(realm_id)
+            .await?;
+
+        RelyingParty::new(rp_info, policy, self.webauthn_policy_provider.metadata())
+    }
+
+    /// Every sign-in moves the stored sign count and last use forward.
+    async fn record_webauthn_use(
+        &self,
+        auth_result: &AuthenticationResult,
+    ) -> Result<(), CoreError> {
+        self.credential_repository
+            .update_webauthn_credential(auth_result)
+            .await
+            .map_err(|e| {
+                debug!("{e:?}");
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
 }
 
-impl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR, PPR, OER, URR, TRV>
-    TridentService
+impl<
+    CR,
+    RC,
+    AS,
+    H,
+    URA,
+    ML,
+    UR,
+    RR,
+    ES,
+    SC,
+    PRT,
+    SE,
+    WH,
+    ETR,
+    TR,
+    PPR,
+    OER,
+    URR,
+    TRV,
+    FPW,
+    EL,
+    WPP,
+    SN,
+> TridentService
     for TridentServiceImpl<
         CR,
         RC,
@@ -484,6 +608,10 @@ impl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR, PPR, OER, URR
         OER,
         URR,
         TRV,
+        FPW,
+        EL,
+        WPP,
+        SN,
     >
 where
     CR: CredentialRepository,
@@ -505,6 +633,10 @@ where
     OER: OtpEnrollmentRepository,
     URR: UserRoleRepository,
     TRV: TokenRevocationPort,
+    FPW: FederatedPasswordPort,
+    EL: EmailLocalizer,
+    WPP: WebAuthnPolicyProvider,
+    SN: SecurityNotifier,
 {
     async fn generate_recovery_code(
         &self,
@@ -691,7 +823,7 @@ where
         let session_code =
             Uuid::parse_str(&input.session_code).map_err(|_| CoreError::SessionCreateError)?;
 
-        let webauthn = build_webauthn_client(input.rp_info)?;
+        let relying_party = self.relying_party(input.rp_info, user.realm_id).await?;
 
         let credentials = self
             .credential_repository
@@ -716,21 +848,16 @@ where
             }
         };
 
-        let (ccr, pr) = webauthn
-            .start_passkey_registration(
-                user.id,
-                user.email.as_deref().unwrap_or(""),
-                &user.username,
-                credentials,
-            )
-            .map_err(|e| {
-                error!("Failed to generate webauthn challenge: {e:?}");
-                CoreError::InternalServerError
-            })?;
+        let (ccr, state) = relying_party.start_registration(
+            user.id,
+            user.email.as_deref().unwrap_or(""),
+            &user.username,
+            credentials,
+        )?;
 
         let _ = self
             .auth_session_repository
-            .save_webauthn_challenge(session_code, WebAuthnChallenge::Registration(pr))
+            .save_webauthn_challenge(session_code, WebAuthnChallenge::Registration(state))
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
@@ -769,7 +896,7 @@ where
         let session_code =
             Uuid::parse_str(&input.session_code).map_err(|_| CoreError::SessionCreateError)?;
 
-        let webauthn = build_webauthn_client(input.rp_info)?;
+        let relying_party = self.relying_party(input.rp_info, user.realm_id).await?;
 
         let auth_session = self
             .auth_session_repository
@@ -788,18 +915,15 @@ where
             return Err(CoreError::WebAuthnChallengeFailed);
         }
 
-        let passkey = match auth_session.webauthn_challenge {
-            Some(WebAuthnChallenge::Registration(ref pk)) => webauthn
-                .finish_passkey_registration(&input.credential, pk)
-                .map_err(|e| {
-                    debug!("Failed to complete passkey registration: {e:?}");
-                    CoreError::Invalid
-                }),
+        let (passkey, authenticator) = match auth_session.webauthn_challenge {
+            Some(WebAuthnChallenge::Registration(ref state)) => {
+                relying_party.finish_registration(&input.credential, state)
+            }
             _ => Err(CoreError::Invalid),
         }?;
 
         self.credential_repository
-            .create_webauthn_credential(user.id, passkey)
+            .create_webauthn_credential(user.id, passkey, authenticator)
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
@@ -816,6 +940,17 @@ where
             );
         }
 
+        self.security_notifier
+            .notify(
+                SecurityNotice::new(
+                    user.realm_id,
+                    user.id,
+                    SecurityNotificationKind::MfaFactorAdded,
+                )
+                .with_detail("factor", "security key"),
+            )
+            .await;
+
         Ok(WebAuthnValidatePublicKeyOutput {})
     }
 
@@ -832,7 +967,7 @@ where
         let session_code =
             Uuid::parse_str(&input.session_code).map_err(|_| CoreError::SessionCreateError)?;
 
-        let webauthn = build_webauthn_client(input.rp_info)?;
+        let relying_party = self.relying_party(input.rp_info, user.realm_id).await?;
 
         let creds = self
             .credential_repository
@@ -840,29 +975,13 @@ where
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
-        let creds = creds
-            .into_iter()
-            .map(|v|
-                match v.credential_data {
-                    CredentialData::WebAuthn {credential} => {
-                        Ok(Passkey::from(*credential))
-                    },
-                    _ => {
-                        error!("A Webauthn credential doesn't hold WebAuthn credential data ! Something went wrong during creation...");
-                        Err(CoreError::InternalServerError)
-                    }
-                }
-            )
-            .collect::<Result<Vec<Passkey>, CoreError>>()?;
+        let creds = accepted_webauthn_credentials(&relying_party, creds)?;
 
-        let (rcr, pa) = webauthn.start_passkey_authentication(&creds).map_err(|e| {
-            error!("Failed to generate webauthn challenge: {e:?}");
-            CoreError::InternalServerError
-        })?;
+        let (rcr, state) = relying_party.start_authentication(creds)?;
 
         let _ = self
             .auth_session_repository
-            .save_webauthn_challenge(session_code, WebAuthnChallenge::Authentication(pa))
+            .save_webauthn_challenge(session_code, WebAuthnChallenge::Authentication(state))
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
@@ -888,32 +1007,16 @@ where
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
-        let webauthn = build_webauthn_client(input.rp_info)?;
+        let relying_party = self.relying_party(input.rp_info, user.realm_id).await?;
 
         let auth_result = match auth_session.webauthn_challenge {
-            Some(WebAuthnChallenge::Authentication(ref pa)) => webauthn
-                .finish_passkey_authentication(&input.credential, pa)
-                .map_err(|e| {
-                    error!("Error during webauthn verification: {e:?}");
-                    CoreError::WebAuthnChallengeFailed
-                }),
+            Some(WebAuthnChallenge::Authentication(ref state)) => {
+                relying_party.finish_authentication(&input.credential, state.clone(), None)
+            }
             _ => Err(CoreError::WebAuthnMissingChallenge),
         }?;
 
-        if auth_result.needs_update() {
-            let _ = self
-                .credential_repository
-                .update_webauthn_credential(&auth_result)
-                .await
-                .map_err(|e| {
-                    debug!("{e:?}");
-                    CoreError::InternalServerError
-                })?;
-        }
-
-        if !auth_result.user_verified() {
-            return Err(CoreError::WebAuthnChallengeFailed);
-        }
+        self.record_webauthn_use(&auth_result).await?;
 
         let login_url = self
             .store_auth_code_and_generate_login_url(&auth_session, user.id, &[])
@@ -929,14 +1032,14 @@ where
         let session_code =
             Uuid::parse_str(&input.session_code).map_err(|_| CoreError::SessionCreateError)?;
 
-        let webauthn = build_webauthn_client(input.rp_info)?;
-
         let realm = self
             .realm_repository
             .get_by_name(&input.realm_name)
             .await?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let relying_party = self.relying_party(input.rp_info, realm.id).await?;
+
         if let Some(username) = input.username {
             // Non-discoverable: we know the user, fetch their passkeys
             let user = self
@@ -951,43 +1054,28 @@ where
                 .await
                 .map_err(|_| CoreError::InternalServerError)?;
 
-            let creds = creds
-                .into_iter()
-                .map(|v| match v.credential_data {
-                    CredentialData::WebAuthn { credential } => Ok(Passkey::from(*credential)),
-                    _ => {
-                        error!("A WebAuthn credential doesn't hold WebAuthn credential data");
-                        Err(CoreError::InternalServerError)
-                    }
-                })
-                .collect::<Result<Vec<Passkey>, CoreError>>()?;
+            let creds = accepted_webauthn_credentials(&relying_party, creds)?;
 
             if creds.is_empty() {
                 return Err(CoreError::WebAuthnChallengeFailed);
             }
 
-            let (rcr, pa) = webauthn.start_passkey_authentication(&creds).map_err(|e| {
-                error!("Failed to start passkey authentication: {e:?}");
-                CoreError::InternalServerError
-            })?;
+            let (rcr, state) = relying_party.start_authentication(creds)?;
 
             self.auth_session_repository
-                .save_webauthn_challenge(session_code, WebAuthnChallenge::Authentication(pa))
+                .save_webauthn_challenge(session_code, WebAuthnChallenge::Authentication(state))
                 .await
                 .map_err(|_| CoreError::InternalServerError)?;
 
             Ok(WebAuthnPublicKeyRequestOptionsOutput(rcr))
         } else {
             // Discoverable: no user provided, browser will propose available passkeys
-            let (rcr, da) = webauthn.start_discoverable_authentication().map_err(|e| {
-                error!("Failed to start discoverable authentication: {e:?}");
-                CoreError::InternalServerError
-            })?;
+            let (rcr, state) = relying_party.start_authentication(Vec::new())?;
 
             self.auth_session_repository
                 .save_webauthn_challenge(
                     session_code,
-                    WebAuthnChallenge::DiscoverableAuthentication(da),
+                    WebAuthnChallenge::DiscoverableAuthentication(state),
                 )
                 .await
                 .map_err(|_| CoreError::InternalServerError)?;
@@ -1009,26 +1097,21 @@ where
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
-        let webauthn = build_webauthn_client(input.rp_info)?;
-
         let realm = self
             .realm_repository
             .get_by_name(&input.realm_name)
             .await?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let relying_party = self.relying_party(input.rp_info, realm.id).await?;
+
         let webauthn_challenge = auth_session.webauthn_challenge.take();
         let auth_result = match webauthn_challenge {
-            Some(WebAuthnChallenge::Authentication(ref pa)) => {
+            Some(WebAuthnChallenge::Authentication(state)) => {
                 // Non-discoverable: user was known at challenge time
-                webauthn
-                    .finish_passkey_authentication(&input.credential, pa)
-                    .map_err(|e| {
-                        error!("Error during passkey authentication: {e:?}");
-                        CoreError::WebAuthnChallengeFailed
-                    })?
+                relying_party.finish_authentication(&input.credential, state, None)?
             }
-            Some(WebAuthnChallenge::DiscoverableAuthentication(da)) => {
+            Some(WebAuthnChallenge::DiscoverableAuthentication(state)) => {
                 // Discoverable: resolve credential from the assertion response
                 let user_handle = input
                     .credential
@@ -1047,36 +1130,18 @@ where
                     .map_err(|_| CoreError::InternalServerError)?
                     .ok_or(CoreError::WebAuthnChallengeFailed)?;
 
-                let passkey = match credential.credential_data {
-                    CredentialData::WebAuthn { credential } => Passkey::from(*credential),
+                let credential = match credential.credential_data {
+                    CredentialData::WebAuthn { credential } => *credential,
                     _ => return Err(CoreError::WebAuthnChallengeFailed),
                 };
+                relying_party.check_credential(&credential)?;
 
-                let dk: DiscoverableKey = passkey.into();
-                webauthn
-                    .finish_discoverable_authentication(&input.credential, da, &[dk])
-                    .map_err(|e| {
-                        error!("Error during discoverable authentication: {e:?}");
-                        CoreError::WebAuthnChallengeFailed
-                    })?
+                relying_party.finish_authentication(&input.credential, state, Some(credential))?
             }
             _ => return Err(CoreError::WebAuthnMissingChallenge),
         };
 
-        if auth_result.needs_update() {
-            let _ = self
-                .credential_repository
-                .update_webauthn_credential(&auth_result)
-                .await
-                .map_err(|e| {
-                    debug!("{e:?}");
-                    CoreError::InternalServerError
-                })?;
-        }
-
-        if !auth_result.user_verified() {
-            return Err(CoreError::WebAuthnChallengeFailed);
-        }
+        self.record_webauthn_use(&auth_result).await?;
 
         // Resolve the user from the assertion response
         let user_handle = input
@@ -1249,6 +1314,10 @@ where
         )
         .map_err(violations_to_core_error)?;
 
+        self.federated_password
+            .write_back_password(user.id, &input.value)
+            .await?;
+
         let password_credential = self
             .credential_repository
             .get_password_credential(user.id)
@@ -1281,6 +1350,14 @@ where
             .revoke_all_user_access(user.id, user.realm_id.into())
             .await?;
 
+        self.security_notifier
+            .notify(SecurityNotice::new(
+                user.realm_id,
+                user.id,
+                SecurityNotificationKind::PasswordChanged,
+            ))
+            .await;
+
         Ok(())
     }
 
@@ -1394,6 +1471,17 @@ where
             );
         }
 
+        self.security_notifier
+            .notify(
+                SecurityNotice::new(
+                    user.realm_id,
+                    user.id,
+                    SecurityNotificationKind::MfaFactorAdded,
+                )
+                .with_detail("factor", "authenticator app"),
+            )
+            .await;
+
         Ok(VerifyOtpOutput {
             message: "OTP verified successfully".to_string(),
             user_id: user.id,
@@ -1476,20 +1564,20 @@ where
                     "{}/realms/{}/authentication/magic-link?token_id={}&magic_token={}",
                     input.base_url, realm.name, magic_token_id, magic_token
                 );
-                let body = format!(
-                    "Click the link below to sign in:\n{magic_link_url}\n\nThis link expires in {ttl_minutes} minutes.\n\nIf you did not request this, please ignore this email.",
-                );
+                let messages = self
+                    .email_localizer
+                    .email_messages(realm.id, Some(user.id))
+                    .await;
+                let expiration = messages.minutes(ttl_minutes as i64);
+                let variables = [
+                    ("magic_link", magic_link_url.as_str()),
+                    ("expiration", expiration.as_str()),
+                ];
+                let subject = messages.email_subject("magic_link", &variables);
+                let body = messages.email_body("magic_link", &variables);
 
                 let html_body = self
-                    .render_email_template(
-                        realm.id.into(),
-                        tid,
-                        &user,
-                        &[
-                            ("magic_link", magic_link_url.as_str()),
-                            ("expiration", &format!("{ttl_minutes} minutes")),
-                        ],
-                    )
+                    .render_email_template(realm.id.into(), tid, &user, &variables, &messages)
                     .await
                     .ok();
 
@@ -1498,7 +1586,7 @@ where
                     .send_email(
                         &smtp_config,
                         user.email.as_deref().unwrap_or(""),
-                        "Your magic link",
+                        &subject,
                         &body,
                         html_body,
                     )
@@ -1793,20 +1881,20 @@ where
                     "{}/realms/{}/authentication/reset-password?token_id={}&token={}",
                     input.base_url, realm.name, token_id, raw_token
                 );
-                let body = format!(
-                    "A password reset was requested for your account.\n\nClick the link below to reset your password:\n{reset_link}\n\nThis link expires in {ttl_minutes} minutes.\n\nIf you did not request this, please ignore this email.",
-                );
+                let messages = self
+                    .email_localizer
+                    .email_messages(realm.id, Some(user.id))
+                    .await;
+                let expiration = messages.minutes(ttl_minutes);
+                let variables = [
+                    ("reset_link", reset_link.as_str()),
+                    ("expiration", expiration.as_str()),
+                ];
+                let subject = messages.email_subject("reset_password", &variables);
+                let body = messages.email_body("reset_password", &variables);
 
                 let html_body = self
-                    .render_email_template(
-                        realm.id.into(),
-                        tid,
-                        &user,
-                        &[
-                            ("reset_link", reset_link.as_str()),
-                            ("expiration", &format!("{ttl_minutes} minutes")),
-                        ],
-                    )
+                    .render_email_template(realm.id.into(), tid, &user, &variables, &messages)
                     .await
                     .ok();
 
@@ -1815,7 +1903,7 @@ where
                     .send_email(
                         &smtp_config,
                         user.email.as_deref().unwrap_or(""),
-                        "Reset your password",
+                        &subject,
                         &body,
                         html_body,
                     )
@@ -1995,6 +2083,10 @@ where
         validator::validate(&input.new_password, &policy, username_ref, email_local_ref)
             .map_err(violations_to_core_error)?;
 
+        self.federated_password
+            .write_back_password(prt.user_id, &input.new_password)
+            .await?;
+
         // 4. Delete old password credential
         let _ = self
             .credential_repository
@@ -2066,6 +2158,14 @@ where
             .await
             .inspect_err(|e| warn!("Failed to emit password reset webhook: {}", e));
 
+        self.security_notifier
+            .notify(SecurityNotice::new(
+                realm_id_typed,
+                user_id,
+                SecurityNotificationKind::PasswordChanged,
+            ))
+            .await;
+
         let login_url = if let Some(session_code) = auth_session_code {
             match self
                 .auth_session_repository
@@ -2134,10 +2234,15 @@ where
 mod tests {
     use super::*;
     use crate::domain::{
+        abyss::federation::ports::MockFederatedPasswordPort,
         authentication::{entities::AuthenticationError, ports::MockAuthSessionRepository},
-        common::{email::MockEmailPort, services::tests::create_test_realm_with_name},
+        common::{
+            email::MockEmailPort, security_notification::MockSecurityNotifier,
+            services::tests::create_test_realm_with_name,
+        },
         credential::{entities::CredentialError, ports::MockCredentialRepository},
         email_template::ports::MockEmailTemplateRepository,
+        localization::{Locale, MockEmailLocalizer},
         password_policy::repository::MockPasswordPolicyRepository,
         realm::ports::{MockRealmRepository, MockSmtpConfigRepository},
         seawatch::ports::MockSecurityEventRepository,
@@ -2152,12 +2257,14 @@ mod tests {
         user::ports::{
             MockUserRepository, MockUserRequiredActionRepository, MockUserRoleRepository,
         },
+        webauthn_policy::{MockWebAuthnPolicyProvider, WebAuthnPolicySettings},
         webhook::ports::MockWebhookRepository,
     };
     use chrono::DateTime;
     use ferriskey_domain::realm::RealmSetting;
     use ferriskey_security::crypto::{entities::HashResult, ports::MockHasherRepository};
     use std::sync::Mutex;
+    use webauthn_rs::prelude::RegisterPublicKeyCredential;
 
     #[derive(Debug, Clone)]
     struct NoopTemplateRenderer;
@@ -2195,6 +2302,10 @@ mod tests {
         MockOtpEnrollmentRepository,
         MockUserRoleRepository,
         MockTokenRevocationPort,
+        MockFederatedPasswordPort,
+        MockEmailLocalizer,
+        MockWebAuthnPolicyProvider,
+        MockSecurityNotifier,
     >;
 
     /// `(user_id, secret, expires_at)` as handed to `start_enrollment`.
@@ -2220,6 +2331,10 @@ mod tests {
         otp_enrollment_repo: Arc<MockOtpEnrollmentRepository>,
         user_role_repo: Arc<MockUserRoleRepository>,
         token_revocation: Arc<MockTokenRevocationPort>,
+        federated_password: Arc<MockFederatedPasswordPort>,
+        email_localizer: Arc<MockEmailLocalizer>,
+        webauthn_policy_provider: Arc<MockWebAuthnPolicyProvider>,
+        security_notifier: Arc<MockSecurityNotifier>,
     }
 
     impl TridentTestBuilder {
@@ -2244,9 +2359,23 @@ mod tests {
                 otp_enrollment_repo: Arc::new(MockOtpEnrollmentRepository::new()),
                 user_role_repo: Arc::new(MockUserRoleRepository::new()),
                 token_revocation: Arc::new(MockTokenRevocationPort::new()),
+                federated_password: Arc::new(MockFederatedPasswordPort::new()),
+                email_localizer: Arc::new(english_email_localizer()),
+                webauthn_policy_provider: Arc::new(default_webauthn_policy_provider()),
+                security_notifier: Arc::new(MockSecurityNotifier::new()),
             }
         }
 
+        fn with_security_notice(mut self, kind: SecurityNotificationKind, times: usize) -> Self {
+            Arc::get_mut(&mut self.security_notifier)
+                .unwrap()
+                .expect_notify()
+                .withf(move |notice| notice.kind == kind)
+                .times(times)
+                .returning(|_| Box::pin(async {}));
+            self
+        }
+
         fn with_user_access_revoked(mut self, times: usize) -> Self {
             Arc::get_mut(&mut self.token_revocation)
                 .unwrap()
@@ -2256,6 +2385,15 @@ mod tests {
             self
         }
 
+        fn with_local_password_change(mut self, times: usize) -> Self {
+            Arc::get_mut(&mut self.federated_password)
+                .unwrap()
+                .expect_write_back_password()
+                .times(times)
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+            self
+        }
+
         fn build(self) -> TestTridentService {
             TridentServiceImpl::new(
                 self.credential_repo,
@@ -2277,10 +2415,31 @@ mod tests {
                 self.otp_enrollment_repo,
                 self.user_role_repo,
                 self.token_revocation,
+                self.federated_password,
+                self.email_localizer,
+                self.webauthn_policy_provider,
+                self.security_notifier,
             )
         }
     }
 
+    fn english_email_localizer() -> MockEmailLocalizer {
+        let mut localizer = MockEmailLocalizer::new();
+        localizer
+            .expect_email_messages()
+            .returning(|_, _| Box::pin(async { LocalizedMessages::builtin(Locale::default()) }));
+        localizer
+    }
+
+    fn default_webauthn_policy_provider() -> MockWebAuthnPolicyProvider {
+        let mut provider = MockWebAuthnPolicyProvider::new();
+        provider
+            .expect_webauthn_policy()
+            .returning(|_| Box::pin(async { Ok(WebAuthnPolicySettings::default()) }));
+        provider.expect_metadata().returning(|| None);
+        provider
+    }
+
     fn create_test_realm_setting(realm_id: RealmId, forgot_password_enabled: bool) -> RealmSetting {
         let mut settings = RealmSetting::new(realm_id, Some("RS256".to_string()));
         settings.forgot_password_enabled = forgot_password_enabled;
@@ -2576,6 +2735,7 @@ mod tests {
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                     webauthn_credential_id: None,
+                    authenticator: None,
                 };
                 Box::pin(async move { Ok(cred) })
             });
@@ -2585,7 +2745,11 @@ mod tests {
             .expect_remove_required_action()
             .returning(|_, _| Box::pin(async { Ok(()) }));
 
-        let service = builder.with_user_access_revoked(1).build();
+        let service = builder
+            .with_user_access_revoked(1)
+            .with_local_password_change(1)
+            .with_security_notice(SecurityNotificationKind::PasswordChanged, 1)
+            .build();
 
         let result = service
             .update_password(
@@ -2679,6 +2843,7 @@ mod tests {
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                     webauthn_credential_id: None,
+                    authenticator: None,
                 };
                 Box::pin(async move { Ok(cred) })
             });
@@ -2703,7 +2868,11 @@ mod tests {
             .expect_notify()
             .returning(|_, _: WebhookPayload<()>| Box::pin(async { Ok(()) }));
 
-        let service = builder.with_user_access_revoked(1).build();
+        let service = builder
+            .with_user_access_revoked(1)
+            .with_local_password_change(1)
+            .with_security_notice(SecurityNotificationKind::PasswordChanged, 1)
+            .build();
         // Strong password satisfying CNIL defaults (≥12 chars, all classes, ≥80 bits entropy)
         let result = service
             .complete_password_reset(CompletePasswordResetInput {
@@ -2877,6 +3046,7 @@ mod tests {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             webauthn_credential_id: None,
+            authenticator: None,
         }
     }
 
@@ -2893,6 +3063,7 @@ mod tests {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             webauthn_credential_id: None,
+            authenticator: None,
         }
     }
 
@@ -3152,7 +3323,9 @@ mod tests {
             .expect_remove_required_action()
             .returning(|_, _| Box::pin(async { Ok(()) }));
 
-        let service = builder.build();
+        let service = builder
+            .with_security_notice(SecurityNotificationKind::MfaFactorAdded, 1)
+            .build();
         let first = service
             .verify_otp(
                 Identity::User(user.clone()),
@@ -3298,7 +3471,9 @@ mod tests {
             .expect_remove_required_action()
             .returning(|_, _| Box::pin(async { Ok(()) }));
 
-        let service = builder.build();
+        let service = builder
+            .with_security_notice(SecurityNotificationKind::MfaFactorAdded, 1)
+            .build();
         let result = service
             .verify_otp(
                 Identity::User(user),
@@ -3425,7 +3600,7 @@ mod tests {
             .unwrap()
             .expect_create_webauthn_credential()
             .never()
-            .returning(|_, _| Box::pin(async { Err(CredentialError::CreateCredentialError) }));
+            .returning(|_, _, _| Box::pin(async { Err(CredentialError::CreateCredentialError) }));
 
         let service = builder.build();
         let result = service
@@ -3451,6 +3626,7 @@ mod tests {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             webauthn_credential_id: None,
+            authenticator: None,
         }
     }
 
@@ -3844,7 +4020,11 @@ mod tests {
         );
         expect_no_authorization_code(&mut builder);
 
-        let service = builder.with_user_access_revoked(1).build();
+        let service = builder
+            .with_user_access_revoked(1)
+            .with_local_password_change(1)
+            .with_security_notice(SecurityNotificationKind::PasswordChanged, 1)
+            .build();
         let output = service
             .complete_password_reset(CompletePasswordResetInput {
                 token_id,
@@ -3879,7 +4059,11 @@ mod tests {
         );
         expect_authorization_code(&mut builder, session);
 
-        let service = builder.with_user_access_revoked(1).build();
+        let service = builder
+            .with_user_access_revoked(1)
+            .with_local_password_change(1)
+            .with_security_notice(SecurityNotificationKind::PasswordChanged, 1)
+            .build();
         let output = service
             .complete_password_reset(CompletePasswordResetInput {
                 token_id,
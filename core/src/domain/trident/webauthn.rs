@@ -0,0 +1,409 @@
+//! The relying party trident runs WebAuthn ceremonies with. `webauthn-rs`' passkey API
+//! hardcodes attestation, user verification and resident keys, so the ceremonies are driven
+//! through `webauthn-rs-core` with the settings of the realm's WebAuthn policy.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use tracing::{error, warn};
+use url::Url;
+use uuid::Uuid;
+use webauthn_rs::DEFAULT_AUTHENTICATOR_TIMEOUT;
+use webauthn_rs::prelude::{
+    AttestationCaList, AttestationCaListBuilder, AttestationMetadata, AuthenticationResult,
+    AuthenticationState, COSEAlgorithm, CreationChallengeResponse,
+    Credential as WebAuthnCredential, CredentialID, Passkey, PublicKeyCredential,
+    RegisterPublicKeyCredential, RequestChallengeResponse, WebauthnError,
+};
+use webauthn_rs_core::WebauthnCore;
+use webauthn_rs_core::proto::{
+    AttestationConveyancePreference, AuthenticatorAttachment as ProtoAuthenticatorAttachment,
+    CredProtect, CredentialProtectionPolicy, Mediation, RegistrationState,
+    RequestAuthenticationExtensions, RequestRegistrationExtensions, UserVerificationPolicy,
+};
+
+use crate::domain::{
+    common::entities::app_errors::CoreError,
+    credential::entities::AuthenticatorInfo,
+    trident::ports::WebAuthnRpInfo,
+    webauthn_policy::{
+        AttestationConveyance, AuthenticatorAttachment, AuthenticatorRejection, MetadataBlob,
+        UserVerificationRequirement, WebAuthnPolicySettings,
+    },
+};
+
+/// The AAGUID an authenticator proved at registration. Self and `none` attestations do not
+/// identify the model.
+pub(crate) fn attested_aaguid(credential: &WebAuthnCredential) -> Option<Uuid> {
+    match credential.attestation.metadata {
+        AttestationMetadata::Packed { aaguid } | AttestationMetadata::Tpm { aaguid, .. } => {
+            Some(aaguid)
+        }
+        _ => None,
+    }
+}
+
+pub(crate) struct RelyingParty {
+    core: WebauthnCore,
+    policy: WebAuthnPolicySettings,
+    metadata: Option<Arc<MetadataBlob>>,
+}
+
+impl RelyingParty {
+    /// Accepts the login portal origin from `rp_info` plus the origins of the policy.
+    pub(crate) fn new(
+        rp_info: WebAuthnRpInfo,
+        policy: WebAuthnPolicySettings,
+        metadata: Option<Arc<MetadataBlob>>,
+    ) -> Result<Self, CoreError> {
+        let rp_url = Url::parse(&rp_info.allowed_origin).map_err(|e| {
+            error!("Failed to parse server_host as URL: {e}");
+            CoreError::InternalServerError
+        })?;
+
+        let mut origins = vec![rp_url];
+        for origin in policy.origins() {
+            if !origins.contains(&origin) {
+                origins.push(origin);
+            }
+        }
+
+        let core = WebauthnCore::new_unsafe_experts_only(
+            &rp_info.rp_id,
+            &rp_info.rp_id,
+            origins,
+            DEFAULT_AUTHENTICATOR_TIMEOUT,
+            None,
+            None,
+        );
+
+        Ok(Self {
+            core,
+            policy,
+            metadata,
+        })
+    }
+
+    fn user_verification(&self) -> UserVerificationPolicy {
+        match self.policy.user_verification {
+            UserVerificationRequirement::Required => UserVerificationPolicy::Required,
+            UserVerificationRequirement::Preferred => UserVerificationPolicy::Preferred,
+        }
+    }
+
+    pub(crate) fn requires_user_verification(&self) -> bool {
+        self.policy.user_verification == UserVerificationRequirement::Required
+    }
+
+    /// The trust anchors of the authenticator models the policy accepts. `None` when no
+    /// attestation is requested or no FIDO metadata is loaded, in which case the attestation
+    /// is parsed but not verified. A policy restricting authenticators refuses every
+    /// registration without metadata, since an unverified AAGUID is the client's choice.
+    fn attestation_ca_list(&self) -> Result<Option<AttestationCaList>, CoreError> {
+        let Some(metadata) = self.metadata.as_deref() else {
+            if self.policy.restricts_authenticators() {
+                return Err(CoreError::WebAuthnAuthenticatorRejected(
+                    "restricting authenticators requires FIDO metadata".to_string(),
+                ));
+            }
+            return Ok(None);
+        };
+        if self.policy.attestation == AttestationConveyance::None {
+            return Ok(None);
+        }
+
+        let mut builder = AttestationCaListBuilder::new();
+        for entry in metadata.entries() {
+            if self
+                .policy
+                .check_authenticator(Some(entry.aaguid), Some(entry))
+                .is_err()
+            {
+                continue;
+            }
+            for root in &entry.attestation_root_certificates {
+                if let Err(e) = builder.insert_device_der(
+                    root,
+                    entry.aaguid,
+                    entry.description.clone(),
+                    BTreeMap::new(),
+                ) {
+                    warn!(aaguid = %entry.aaguid, "Skipping unreadable attestation root: {e}");
+                }
+            }
+        }
+
+        let list = builder.build();
+        if list.is_empty() {
+            return Err(CoreError::WebAuthnAuthenticatorRejected(
+                "no authenticator model of the FIDO metadata satisfies the policy".to_string(),
+            ));
+        }
+
+        Ok(Some(list))
+    }
+
+    pub(crate) fn start_registration(
+        &self,
+        user_id: Uuid,
+        user_name: &str,
+        user_display_name: &str,
+        exclude_credentials: Option<Vec<CredentialID>>,
+    ) -> Result<(CreationChallengeResponse, RegistrationState), CoreError> {
+        let attestation = match self.policy.attestation {
+            AttestationConveyance::None => AttestationConveyancePreference::None,
+            AttestationConveyance::Indirect => AttestationConveyancePreference::Indirect,
+            AttestationConveyance::Direct => AttestationConveyancePreference::Direct,
+        };
+        let attachment = self
+            .policy
+            .authenticator_attachment
+            .map(|attachment| match attachment {
+                AuthenticatorAttachment::Platform => ProtoAuthenticatorAttachment::Platform,
+                AuthenticatorAttachment::CrossPlatform => {
+                    ProtoAuthenticatorAttachment::CrossPlatform
+                }
+            });
+        // Same extensions as passkeys. Enforcing credProtect makes many authenticators fail
+        // the ceremony instead of ignoring it.
+        let extensions = RequestRegistrationExtensions {
+            cred_protect: self.requires_user_verification().then_some(CredProtect {
+                credential_protection_policy: CredentialProtectionPolicy::UserVerificationRequired,
+                enforce_credential_protection_policy: Some(false),
+            }),
+            uvm: Some(true),
+            cred_props: Some(true),
+            min_pin_length: None,
+            hmac_create_secret: None,
+        };
+
+        let builder = self
+            .core
+            .new_challenge_register_builder(user_id.as_bytes(), user_name, user_display_name)
+            .map_err(|e| {
+                error!("Failed to generate webauthn challenge: {e:?}");
+                CoreError::InternalServerError
+            })?
+            .attestation(attestation)
+            .credential_algorithms(COSEAlgorithm::secure_algs())
+            .require_resident_key(self.policy.require_resident_key)
+            .authenticator_attachment(attachment)
+            .user_verification_policy(self.user_verification())
+            .reject_synchronised_authenticators(false)
+            .exclude_credentials(exclude_credentials)
+            .hints(None)
+            .extensions(Some(extensions));
+
+        self.core.generate_challenge_register(builder).map_err(|e| {
+            error!("Failed to generate webauthn challenge: {e:?}");
+            CoreError::InternalServerError
+        })
+    }
+
+    /// Verifies the attestation and checks the authenticator against the policy.
+    pub(crate) fn finish_registration(
+        &self,
+        reg: &RegisterPublicKeyCredential,
+        state: &RegistrationState,
+    ) -> Result<(Passkey, AuthenticatorInfo), CoreError> {
+        let ca_list = self.attestation_ca_list()?;
+
+        let credential = self
+            .core
+            .register_credential(reg, state, ca_list.as_ref())
+            .map_err(|e| match e {
+                WebauthnError::AttestationChainNotTrusted(_)
+                | WebauthnError::AttestationNotVerifiable => {
+                    warn!("Refused WebAuthn registration: attestation not trusted: {e:?}");
+                    CoreError::from(AuthenticatorRejection::NotAllowed(None))
+                }
+                e => {
+                    tracing::debug!("Failed to complete passkey registration: {e:?}");
+                    CoreError::Invalid
+                }
+            })?;
+
+        let aaguid = attested_aaguid(&credential);
+        let entry = self
+            .metadata
+            .as_deref()
+            .zip(aaguid)
+            .and_then(|(metadata, aaguid)| metadata.entry(aaguid));
+        self.policy.check_authenticator(aaguid, entry)?;
+
+        let authenticator = AuthenticatorInfo {
+            aaguid,
+            name: entry.map(|entry| entry.description.clone()),
+            sign_count: Some(credential.counter),
+            last_used_at: None,
+        };
+
+        Ok((Passkey::from(credential), authenticator))
+    }
+
+    /// Whether a stored credential is still accepted, e.g. after its model was blocked or the
+    /// FIDO metadata reported it compromised.
+    pub(crate) fn check_credential(
+        &self,
+        credential: &WebAuthnCredential,
+    ) -> Result<(), CoreError> {
+        let aaguid = attested_aaguid(credential);
+        let entry = self
+            .metadata
+            .as_deref()
+            .zip(aaguid)
+            .and_then(|(metadata, aaguid)| metadata.entry(aaguid));
+
+        self.policy
+            .check_authenticator(aaguid, entry)
+            .map_err(CoreError::from)
+    }
+
+    pub(crate) fn start_authentication(
+        &self,
+        credentials: Vec<WebAuthnCredential>,
+    ) -> Result<(RequestChallengeResponse, AuthenticationState), CoreError> {
+        let discoverable = credentials.is_empty();
+        let extensions = discoverable.then_some(RequestAuthenticationExtensions {
+            appid: None,
+            uvm: Some(true),
+            hmac_get_secret: None,
+        });
+
+        let builder = self
+            .core
+            .new_challenge_authenticate_builder(credentials, Some(self.user_verification()))
+            .map_err(|e| {
+                error!("Failed to generate webauthn challenge: {e:?}");
+                CoreError::InternalServerError
+            })?
+            .extensions(extensions)
+            .allow_backup_eligible_upgrade(!discoverable)
+            .hints(None);
+
+        let (mut rcr, state) = self
+            .core
+            .generate_challenge_authenticate(builder)
+            .map_err(|e| {
+                error!("Failed to generate webauthn challenge: {e:?}");
+                CoreError::InternalServerError
+            })?;
+
+        // The browser proposes the passkeys it holds for the site.
+        if discoverable {
+            rcr.mediation = Some(Mediation::Conditional);
+        }
+
+        Ok((rcr, state))
+    }
+
+    /// For discoverable sign-ins, `credential` is the one the assertion names.
+    pub(crate) fn finish_authentication(
+        &self,
+        rsp: &PublicKeyCredential,
+        mut state: AuthenticationState,
+        credential: Option<WebAuthnCredential>,
+    ) -> Result<AuthenticationResult, CoreError> {
+        if let Some(credential) = credential {
+            state.set_allowed_credentials(vec![credential]);
+        }
+
+        let result = self
+            .core
+            .authenticate_credential(rsp, &state)
+            .map_err(|e| {
+                match e {
+                    WebauthnError::CredentialPossibleCompromise => warn!(
+                        "Refused WebAuthn authentication: the sign count did not increase, the authenticator may have been cloned"
+                    ),
+                    e => error!("Error during webauthn verification: {e:?}"),
+                }
+                CoreError::WebAuthnChallengeFailed
+            })?;
+
+        if self.requires_user_verification() && !result.user_verified() {
+            return Err(CoreError::WebAuthnChallengeFailed);
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rp_info() -> WebAuthnRpInfo {
+        WebAuthnRpInfo {
+            rp_id: "localhost".to_string(),
+            allowed_origin: "http://localhost:5555".to_string(),
+        }
+    }
+
+    #[test]
+    fn registration_options_follow_the_policy() {
+        let policy = WebAuthnPolicySettings {
+            attestation: AttestationConveyance::Direct,
+            user_verification: UserVerificationRequirement::Preferred,
+            require_resident_key: true,
+            authenticator_attachment: Some(AuthenticatorAttachment::CrossPlatform),
+            allowed_origins: vec!["http://localhost:3000".to_string()],
+            ..WebAuthnPolicySettings::default()
+        };
+        let relying_party = RelyingParty::new(rp_info(), policy, None).unwrap();
+        assert_eq!(relying_party.core.get_allowed_origins().len(), 2);
+
+        let (ccr, _) = relying_party
+            .start_registration(Uuid::new_v4(), "jane", "Jane", None)
+            .unwrap();
+        let options = ccr.public_key;
+        let selection = options.authenticator_selection.unwrap();
+
+        assert!(matches!(
+            options.attestation,
+            Some(AttestationConveyancePreference::Direct)
+        ));
+        assert!(selection.require_resident_key);
+        assert_eq!(
+            selection.authenticator_attachment,
+            Some(ProtoAuthenticatorAttachment::CrossPlatform)
+        );
+        assert_eq!(
+            selection.user_verification,
+            UserVerificationPolicy::Preferred
+        );
+        assert!(options.extensions.unwrap().cred_protect.is_none());
+    }
+
+    #[test]
+    fn discoverable_sign_in_uses_conditional_mediation() {
+        let relying_party =
+            RelyingParty::new(rp_info(), WebAuthnPolicySettings::default(), None).unwrap();
+
+        let (rcr, _) = relying_party.start_authentication(Vec::new()).unwrap();
+
+        assert!(matches!(rcr.mediation, Some(Mediation::Conditional)));
+        assert_eq!(
+            rcr.public_key.user_verification,
+            UserVerificationPolicy::Required
+        );
+    }
+
+    #[test]
+    fn restricting_policy_refuses_registration_without_metadata() {
+        let policy = WebAuthnPolicySettings {
+            attestation: AttestationConveyance::Direct,
+            allowed_aaguids: vec![Uuid::new_v4()],
+            ..WebAuthnPolicySettings::default()
+        };
+        let relying_party = RelyingParty::new(rp_info(), policy, None).unwrap();
+
+        assert!(matches!(
+            relying_party.attestation_ca_list(),
+            Err(CoreError::WebAuthnAuthenticatorRejected(_))
+        ));
+
+        let relying_party =
+            RelyingParty::new(rp_info(), WebAuthnPolicySettings::default(), None).unwrap();
+        assert!(matches!(relying_party.attestation_ca_list(), Ok(None)));
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use ferriskey_domain::user::commands::BulkDeleteUsersInput;
@@ -10,6 +11,7 @@ use crate::domain::{
     common::{
         entities::app_errors::CoreError,
         policies::{FerriskeyPolicy, Policy, ensure_policy},
+        security_notification::{SecurityNotice, SecurityNotificationKind, SecurityNotifier},
     },
     credential::ports::CredentialRepository,
     crypto::HasherRepository,
@@ -36,6 +38,10 @@ use crate::domain::{
         },
         value_objects::{CreateUserRequest, UpdateUserRequest},
     },
+    user_profile::{
+        Audience, ProfileContext, UserProfileSchemaRepository, services::realm_profile_config,
+        validation::violations_error,
+    },
     webhook::{
         entities::{webhook_payload::WebhookPayload, webhook_trigger::WebhookTrigger},
         ports::WebhookRepository,
@@ -55,7 +61,7 @@ fn normalize_optional_email(email: Option<String>) -> Option<String> {
 }
 
 #[derive(Clone, Debug)]
-pub struct UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, PPR, TRV>
+pub struct UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, PPR, TRV, UPS, SN>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -70,6 +76,8 @@ where
     UAR: UserAttributeRepository,
     PPR: PasswordPolicyRepository,
     TRV: TokenRevocationPort,
+    UPS: UserProfileSchemaRepository,
+    SN: SecurityNotifier,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) user_repository: Arc<U>,
@@ -83,12 +91,14 @@ where
     pub(crate) security_event_repository: Arc<SE>,
     pub(crate) password_policy_repository: Arc<PPR>,
     pub(crate) token_revocation: Arc<TRV>,
+    pub(crate) user_profile_schema_repository: Arc<UPS>,
+    pub(crate) security_notifier: Arc<SN>,
 
     pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
 }
 
-impl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, PPR, TRV>
-    UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, PPR, TRV>
+impl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, PPR, TRV, UPS, SN>
+    UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, PPR, TRV, UPS, SN>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -103,6 +113,8 @@ where
     UAR: UserAttributeRepository,
     PPR: PasswordPolicyRepository,
     TRV: TokenRevocationPort,
+    UPS: UserProfileSchemaRepository,
+    SN: SecurityNotifier,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -118,6 +130,8 @@ where
         security_event_repository: Arc<SE>,
         password_policy_repository: Arc<PPR>,
         token_revocation: Arc<TRV>,
+        user_profile_schema_repository: Arc<UPS>,
+        security_notifier: Arc<SN>,
         policy: Arc<FerriskeyPolicy<U, C, UR>>,
     ) -> Self {
         Self {
@@ -133,10 +147,60 @@ where
             security_event_repository,
             password_policy_repository,
             token_revocation,
+            user_profile_schema_repository,
+            security_notifier,
             policy,
         }
     }
 
+    /// Checks attribute changes an administrator makes against the realm's user profile.
+    async fn validate_admin_changes(
+        &self,
+        realm: &Realm,
+        user_id: Uuid,
+        changes: &HashMap<String, String>,
+    ) -> Result<(), CoreError> {
+        let profile =
+            realm_profile_config(self.user_profile_schema_repository.as_ref(), realm.id).await?;
+        let current: HashMap<String, String> = self
+            .user_attribute_repository
+            .list_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .map(|attribute| (attribute.key, attribute.value))
+            .collect();
+
+        profile
+            .validate_changes(ProfileContext::Admin, &current, changes)
+            .map_err(violations_error)
+    }
+
+    /// Writes validated attribute changes: empty values delete the attribute.
+    async fn apply_attribute_changes(
+        &self,
+        user_id: Uuid,
+        realm: &Realm,
+        changes: HashMap<String, String>,
+    ) -> Result<Vec<UserAttribute>, CoreError> {
+        let (cleared, set): (HashMap<_, _>, HashMap<_, _>) = changes
+            .into_iter()
+            .partition(|(_, value)| value.trim().is_empty());
+
+        for key in cleared.into_keys() {
+            self.user_attribute_repository
+                .delete_by_key(user_id, key)
+                .await?;
+        }
+
+        if set.is_empty() {
+            return Ok(vec![]);
+        }
+
+        self.user_attribute_repository
+            .upsert_many(user_id, realm.id, set)
+            .await
+    }
+
     async fn load_user_in_realm(&self, user_id: Uuid, realm: &Realm) -> Result<User, CoreError> {
         let user = self.user_repository.get_by_id(user_id).await?;
 
@@ -177,8 +241,8 @@ where
     }
 }
 
-impl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, PPR, TRV> UserService
-    for UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, PPR, TRV>
+impl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, PPR, TRV, UPS, SN> UserService
+    for UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, PPR, TRV, UPS, SN>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -193,6 +257,8 @@ where
     UAR: UserAttributeRepository,
     PPR: PasswordPolicyRepository,
     TRV: TokenRevocationPort,
+    UPS: UserProfileSchemaRepository,
+    SN: SecurityNotifier,
 {
     async fn delete_user(
         &self,
@@ -372,6 +438,14 @@ where
                 e
             })?;
 
+        self.security_notifier
+            .notify(SecurityNotice::new(
+                realm.id,
+                input.user_id,
+                SecurityNotificationKind::PasswordChanged,
+            ))
+            .await;
+
         // @TODO: webhook call action
 
         Ok(())
@@ -397,6 +471,11 @@ where
         let existing = self.load_user_in_realm(input.user_id, &realm).await?;
         let is_being_disabled = existing.enabled && !input.enabled;
 
+        if let Some(attributes) = &input.attributes {
+            self.validate_admin_changes(&realm, existing.id, attributes)
+                .await?;
+        }
+
         let user = self
             .user_repository
             .update_user(
@@ -418,6 +497,24 @@ where
                 .await?;
         }
 
+        // Sent to the previous address: whoever changed it may not own that one.
+        if let (Some(previous_email), Some(new_email)) = (&existing.email, &user.email)
+            && !previous_email.eq_ignore_ascii_case(new_email)
+        {
+            self.security_notifier
+                .notify(
+                    SecurityNotice::new(realm_id, user.id, SecurityNotificationKind::EmailChanged)
+                        .with_recipient(previous_email)
+                        .with_detail("new_email", new_email),
+                )
+                .await;
+        }
+
+        if let Some(attributes) = input.attributes {
+            self.apply_attribute_changes(user.id, &realm, attributes)
+                .await?;
+        }
+
         if let Some(required_actions) = input.required_actions {
             self.user_required_action_repository
                 .clear_required_actions(user.id)
@@ -757,9 +854,17 @@ where
             return Err(CoreError::NotFound);
         }
 
-        self.user_attribute_repository
+        let profile =
+            realm_profile_config(self.user_profile_schema_repository.as_ref(), realm.id).await?;
+        let attributes = self
+            .user_attribute_repository
             .list_by_user_id(input.user_id)
-            .await
+            .await?;
+
+        Ok(attributes
+            .into_iter()
+            .filter(|attribute| profile.can_view(Audience::Admin, &attribute.key))
+            .collect())
     }
 
     async fn set_user_attributes(
@@ -788,8 +893,10 @@ where
             return Err(CoreError::NotFound);
         }
 
-        self.user_attribute_repository
-            .upsert_many(input.user_id, realm.id, input.attributes)
+        self.validate_admin_changes(&realm, input.user_id, &input.attributes)
+            .await?;
+
+        self.apply_attribute_changes(input.user_id, &realm, input.attributes)
             .await
     }
 
@@ -815,6 +922,11 @@ where
             return Err(CoreError::NotFound);
         }
 
+        realm_profile_config(self.user_profile_schema_repository.as_ref(), realm.id)
+            .await?
+            .validate_removal(ProfileContext::Admin, &input.key)
+            .map_err(violations_error)?;
+
         self.user_attribute_repository
             .delete_by_key(input.user_id, input.key)
             .await
@@ -852,6 +964,7 @@ mod tests {
     use super::*;
     use crate::domain::{
         client::ports::MockClientRepository,
+        common::security_notification::MockSecurityNotifier,
         common::services::tests::{
             create_test_realm_with_name, create_test_user_identity_with_realm,
             create_test_user_with_params_and_realm,
@@ -867,6 +980,10 @@ mod tests {
             MockUserAttributeRepository, MockUserRepository, MockUserRequiredActionRepository,
             MockUserRoleRepository,
         },
+        user_profile::{
+            AttributeType, AttributeValidator, MockUserProfileSchemaRepository,
+            UserProfileAttribute, UserProfileSchema,
+        },
         webhook::{entities::webhook_payload::WebhookPayload, ports::MockWebhookRepository},
     };
 
@@ -884,6 +1001,8 @@ mod tests {
         MockUserAttributeRepository,
         MockPasswordPolicyRepository,
         MockTokenRevocationPort,
+        MockUserProfileSchemaRepository,
+        MockSecurityNotifier,
     >;
 
     struct UserServiceTestBuilder {
@@ -900,6 +1019,8 @@ mod tests {
         security_event_repo: Arc<MockSecurityEventRepository>,
         password_policy_repo: Arc<MockPasswordPolicyRepository>,
         token_revocation: Arc<MockTokenRevocationPort>,
+        user_profile_schema_repo: Arc<MockUserProfileSchemaRepository>,
+        security_notifier: Arc<MockSecurityNotifier>,
     }
 
     impl UserServiceTestBuilder {
@@ -918,9 +1039,25 @@ mod tests {
                 security_event_repo: Arc::new(MockSecurityEventRepository::new()),
                 password_policy_repo: Arc::new(MockPasswordPolicyRepository::new()),
                 token_revocation: Arc::new(MockTokenRevocationPort::new()),
+                user_profile_schema_repo: Arc::new(MockUserProfileSchemaRepository::new()),
+                security_notifier: Arc::new(MockSecurityNotifier::new()),
             }
         }
 
+        /// Declares `schema` as the realm's user profile and stubs the stored attributes
+        /// the changes are validated against.
+        fn with_user_profile(mut self, schema: UserProfileSchema) -> Self {
+            Arc::get_mut(&mut self.user_profile_schema_repo)
+                .unwrap()
+                .expect_get_by_realm()
+                .return_once(move |_| Box::pin(async move { Ok(Some(schema)) }));
+            Arc::get_mut(&mut self.user_attribute_repo)
+                .unwrap()
+                .expect_list_by_user_id()
+                .return_once(|_| Box::pin(async { Ok(vec![]) }));
+            self
+        }
+
         fn with_user_access_revoked(mut self, user_id: uuid::Uuid, times: usize) -> Self {
             let expectation = Arc::get_mut(&mut self.token_revocation)
                 .unwrap()
@@ -1034,6 +1171,21 @@ mod tests {
             self
         }
 
+        fn with_security_notice(
+            mut self,
+            kind: SecurityNotificationKind,
+            recipient: Option<&str>,
+        ) -> Self {
+            let recipient = recipient.map(str::to_string);
+            Arc::get_mut(&mut self.security_notifier)
+                .unwrap()
+                .expect_notify()
+                .withf(move |notice| notice.kind == kind && notice.recipient == recipient)
+                .times(1)
+                .returning(|_| Box::pin(async {}));
+            self
+        }
+
         fn with_webhook_notify(mut self) -> Self {
             Arc::get_mut(&mut self.webhook_repo)
                 .unwrap()
@@ -1065,6 +1217,8 @@ mod tests {
                 self.security_event_repo,
                 self.password_policy_repo,
                 self.token_revocation,
+                self.user_profile_schema_repo,
+                self.security_notifier,
                 Arc::new(policy),
             )
         }
@@ -1196,6 +1350,7 @@ mod tests {
             email_verified: Some(true),
             enabled: true,
             required_actions: None,
+            attributes: None,
         };
 
         let result = service.update_user(identity, input).await;
@@ -1243,6 +1398,7 @@ mod tests {
             email_verified: Some(true),
             enabled: true,
             required_actions: None,
+            attributes: None,
         };
 
         let result = service.update_user(identity, input).await;
@@ -1253,6 +1409,66 @@ mod tests {
         assert_eq!(updated_user.firstname, Some("Updated".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_update_user_rejects_attributes_violating_the_user_profile() {
+        let realm = create_test_realm_with_name("test-realm");
+        let identity = create_test_user_identity_with_realm(&realm);
+        let admin_role = create_admin_role(&realm);
+
+        let user_id = match &identity {
+            Identity::User(u) => u.id,
+            _ => panic!("Expected user identity"),
+        };
+
+        let user_to_update = create_test_user_with_params_and_realm(
+            &realm,
+            "user_to_update",
+            "user@example.com".to_string(),
+            true,
+        );
+
+        let mut schema = UserProfileSchema::empty_for(realm.id);
+        schema.config.attributes = vec![UserProfileAttribute {
+            name: "phone".to_string(),
+            display_name: None,
+            attribute_type: AttributeType::String,
+            validators: vec![AttributeValidator::Pattern {
+                pattern: r"\+[0-9]{8,15}".to_string(),
+                message: None,
+            }],
+            required_for: vec![],
+            permissions: Default::default(),
+            group: None,
+        }];
+
+        // The user row is never written when the attributes are rejected.
+        let service = UserServiceTestBuilder::new()
+            .with_realm("test-realm".to_string(), realm.clone())
+            .with_user_permissions(user_id, vec![admin_role])
+            .with_target_user(user_to_update.clone())
+            .with_user_profile(schema)
+            .build();
+
+        let input = UpdateUserInput {
+            realm_name: "test-realm".to_string(),
+            user_id: user_to_update.id,
+            firstname: None,
+            lastname: None,
+            email: Some("user@example.com".to_string()),
+            email_verified: Some(true),
+            enabled: true,
+            required_actions: None,
+            attributes: Some(HashMap::from([(
+                "phone".to_string(),
+                "not a phone".to_string(),
+            )])),
+        };
+
+        let result = service.update_user(identity, input).await;
+
+        assert!(matches!(result, Err(CoreError::UserProfileViolation(_))));
+    }
+
     #[tokio::test]
     async fn test_update_user_with_new_unique_email_succeeds() {
         let realm = create_test_realm_with_name("test-realm");
@@ -1291,6 +1507,7 @@ mod tests {
             email_verified: Some(true),
             enabled: true,
             required_actions: None,
+            attributes: None,
         };
 
         let result = service.update_user(identity, input).await;
@@ -1306,6 +1523,52 @@ mod tests {
     // repository call. If the boundary check regressed, the write would fire and
     // mockall would panic on an unexpected call — so the assertion is doubled.
 
+    #[tokio::test]
+    async fn update_user_changing_the_email_notifies_the_previous_address() {
+        let realm = create_test_realm_with_name("test-realm");
+        let identity = create_test_user_identity_with_realm(&realm);
+        let admin_role = create_admin_role(&realm);
+        let admin_id = match &identity {
+            Identity::User(u) => u.id,
+            _ => panic!("Expected user identity"),
+        };
+
+        let existing = create_test_user_with_params_and_realm(
+            &realm,
+            "user_to_update",
+            "old@example.com".to_string(),
+            true,
+        );
+        let mut updated = existing.clone();
+        updated.email = Some("new@example.com".to_string());
+
+        let service = UserServiceTestBuilder::new()
+            .with_realm("test-realm".to_string(), realm.clone())
+            .with_user_permissions(admin_id, vec![admin_role])
+            .with_target_user(existing.clone())
+            .with_update_user_success(existing.id, updated)
+            .with_security_notice(
+                SecurityNotificationKind::EmailChanged,
+                Some("old@example.com"),
+            )
+            .with_webhook_notify()
+            .build();
+
+        let input = UpdateUserInput {
+            realm_name: "test-realm".to_string(),
+            user_id: existing.id,
+            firstname: None,
+            lastname: None,
+            email: Some("new@example.com".to_string()),
+            email_verified: Some(true),
+            enabled: true,
+            required_actions: None,
+            attributes: None,
+        };
+
+        assert!(service.update_user(identity, input).await.is_ok());
+    }
+
     #[tokio::test]
     async fn update_user_refuses_a_target_from_another_realm() {
         let attacker_realm = create_test_realm_with_name("tenant-a");
@@ -1343,6 +1606,7 @@ mod tests {
                     email_verified: Some(true),
                     enabled: true,
                     required_actions: None,
+                    attributes: None,
                 },
             )
             .await;
@@ -1390,6 +1654,7 @@ mod tests {
                     email_verified: Some(true),
                     enabled: false,
                     required_actions: None,
+                    attributes: None,
                 },
             )
             .await;
@@ -1437,6 +1702,7 @@ mod tests {
                     email_verified: Some(true),
                     enabled: true,
                     required_actions: None,
+                    attributes: None,
                 },
             )
             .await;
@@ -1482,6 +1748,7 @@ mod tests {
                     email_verified: Some(true),
                     enabled: false,
                     required_actions: None,
+                    attributes: None,
                 },
             )
             .await;
@@ -1513,7 +1780,8 @@ mod tests {
             .with_realm("test-realm".to_string(), realm.clone())
             .with_user_permissions(admin_id, vec![admin_role])
             .with_target_user(target.clone())
-            .with_user_access_revoked(target_id, 1);
+            .with_user_access_revoked(target_id, 1)
+            .with_security_notice(SecurityNotificationKind::PasswordChanged, None);
 
         Arc::get_mut(&mut builder.password_policy_repo)
             .unwrap()
@@ -1564,6 +1832,7 @@ mod tests {
                     created_at: chrono::Utc::now(),
                     updated_at: chrono::Utc::now(),
                     webauthn_credential_id: None,
+                    authenticator: None,
                 };
                 Box::pin(async move { Ok(cred) })
             });
@@ -0,0 +1,5 @@
+//! Declarative user profiles live in the `ferriskey-user-profile` lib crate — the schema
+//! entities, value validation, ports, the `UserProfileAccessPolicy` impl and the generic
+//! `UserProfileServiceImpl`. The SeaORM-backed repository stays in `core` under
+//! `infrastructure/user_profile`.
+pub use ferriskey_user_profile::*;
@@ -0,0 +1,5 @@
+//! WebAuthn policies live in the `ferriskey-webauthn-policy` lib crate — the per-realm
+//! settings, the FIDO metadata BLOB, ports, the `WebAuthnPolicyAccessPolicy` impl and the
+//! generic `WebAuthnPolicyServiceImpl`. The SeaORM-backed repository stays in `core` under
+//! `infrastructure/webauthn_policy`; the ceremonies themselves are run by trident.
+pub use ferriskey_webauthn_policy::*;
@@ -24,6 +24,10 @@ pub struct Model {
     pub updated_at: DateTime,
     pub temporary: Option<bool>,
     pub webauthn_credential_id: Option<Vec<u8>>,
+    pub aaguid: Option<Uuid>,
+    pub authenticator_name: Option<String>,
+    pub sign_count: Option<i64>,
+    pub last_used_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -39,6 +43,10 @@ pub enum Column {
     UpdatedAt,
     Temporary,
     WebauthnCredentialId,
+    Aaguid,
+    AuthenticatorName,
+    SignCount,
+    LastUsedAt,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -73,6 +81,10 @@ impl ColumnTrait for Column {
             Self::UpdatedAt => ColumnType::DateTime.def(),
             Self::Temporary => ColumnType::Boolean.def().null(),
             Self::WebauthnCredentialId => ColumnType::VarBinary(StringLen::None).def().null(),
+            Self::Aaguid => ColumnType::Uuid.def().null(),
+            Self::AuthenticatorName => ColumnType::String(StringLen::N(255u32)).def().null(),
+            Self::SignCount => ColumnType::BigInteger.def().null(),
+            Self::LastUsedAt => ColumnType::DateTime.def().null(),
         }
     }
 }
@@ -0,0 +1,97 @@
+//! `SeaORM` Entity for per-realm login risk policies.
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Copy, Clone, Default, Debug, DeriveEntity)]
+pub struct Entity;
+
+impl EntityName for Entity {
+    fn table_name(&self) -> &str {
+        "login_risk_policies"
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveModel, DeriveActiveModel, Eq)]
+pub struct Model {
+    pub realm_id: Uuid,
+    pub enabled: bool,
+    pub new_device_score: i16,
+    pub new_country_score: i16,
+    pub impossible_travel_score: i16,
+    pub max_travel_speed_kmh: i32,
+    pub step_up_threshold: Option<i16>,
+    pub notify_threshold: Option<i16>,
+    pub block_threshold: Option<i16>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+pub enum Column {
+    RealmId,
+    Enabled,
+    NewDeviceScore,
+    NewCountryScore,
+    ImpossibleTravelScore,
+    MaxTravelSpeedKmh,
+    StepUpThreshold,
+    NotifyThreshold,
+    BlockThreshold,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
+pub enum PrimaryKey {
+    RealmId,
+}
+
+impl PrimaryKeyTrait for PrimaryKey {
+    type ValueType = Uuid;
+    fn auto_increment() -> bool {
+        false
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {
+    Realms,
+}
+
+impl ColumnTrait for Column {
+    type EntityName = Entity;
+    fn def(&self) -> ColumnDef {
+        match self {
+            Self::RealmId => ColumnType::Uuid.def(),
+            Self::Enabled => ColumnType::Boolean.def(),
+            Self::NewDeviceScore => ColumnType::SmallInteger.def(),
+            Self::NewCountryScore => ColumnType::SmallInteger.def(),
+            Self::ImpossibleTravelScore => ColumnType::SmallInteger.def(),
+            Self::MaxTravelSpeedKmh => ColumnType::Integer.def(),
+            Self::StepUpThreshold => ColumnType::SmallInteger.def().null(),
+            Self::NotifyThreshold => ColumnType::SmallInteger.def().null(),
+            Self::BlockThreshold => ColumnType::SmallInteger.def().null(),
+            Self::CreatedAt => ColumnType::TimestampWithTimeZone.def(),
+            Self::UpdatedAt => ColumnType::TimestampWithTimeZone.def(),
+        }
+    }
+}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        match self {
+            Self::Realms => Entity::belongs_to(super::realms::Entity)
+                .from(Column::RealmId)
+                .to(super::realms::Column::Id)
+                .into(),
+        }
+    }
+}
+
+impl Related<super::realms::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Realms.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,114 @@
+//! `SeaORM` Entity for scheduled maintenance windows.
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Copy, Clone, Default, Debug, DeriveEntity)]
+pub struct Entity;
+
+impl EntityName for Entity {
+    fn table_name(&self) -> &str {
+        "maintenance_windows"
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveModel, DeriveActiveModel, Eq)]
+pub struct Model {
+    pub id: Uuid,
+    pub realm_id: Uuid,
+    pub client_id: Option<Uuid>,
+    pub reason: String,
+    pub session_strategy: String,
+    pub announce_at: DateTimeWithTimeZone,
+    pub starts_at: DateTimeWithTimeZone,
+    pub ends_at: DateTimeWithTimeZone,
+    pub status: String,
+    pub affected_client_ids: Json,
+    pub created_by: Uuid,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+pub enum Column {
+    Id,
+    RealmId,
+    ClientId,
+    Reason,
+    SessionStrategy,
+    AnnounceAt,
+    StartsAt,
+    EndsAt,
+    Status,
+    AffectedClientIds,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
+pub enum PrimaryKey {
+    Id,
+}
+
+impl PrimaryKeyTrait for PrimaryKey {
+    type ValueType = Uuid;
+    fn auto_increment() -> bool {
+        false
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {
+    Clients,
+    Realms,
+}
+
+impl ColumnTrait for Column {
+    type EntityName = Entity;
+    fn def(&self) -> ColumnDef {
+        match self {
+            Self::Id => ColumnType::Uuid.def(),
+            Self::RealmId => ColumnType::Uuid.def(),
+            Self::ClientId => ColumnType::Uuid.def().null(),
+            Self::Reason => ColumnType::Text.def(),
+            Self::SessionStrategy => ColumnType::String(StringLen::N(16u32)).def(),
+            Self::AnnounceAt => ColumnType::TimestampWithTimeZone.def(),
+            Self::StartsAt => ColumnType::TimestampWithTimeZone.def(),
+            Self::EndsAt => ColumnType::TimestampWithTimeZone.def(),
+            Self::Status => ColumnType::String(StringLen::N(16u32)).def(),
+            Self::AffectedClientIds => ColumnType::JsonBinary.def(),
+            Self::CreatedBy => ColumnType::Uuid.def(),
+            Self::CreatedAt => ColumnType::TimestampWithTimeZone.def(),
+            Self::UpdatedAt => ColumnType::TimestampWithTimeZone.def(),
+        }
+    }
+}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        match self {
+            Self::Clients => Entity::belongs_to(super::clients::Entity)
+                .from(Column::ClientId)
+                .to(super::clients::Column::Id)
+                .into(),
+            Self::Realms => Entity::belongs_to(super::realms::Entity)
+                .from(Column::RealmId)
+                .to(super::realms::Column::Id)
+                .into(),
+        }
+    }
+}
+
+impl Related<super::clients::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Clients.def()
+    }
+}
+
+impl Related<super::realms::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Realms.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
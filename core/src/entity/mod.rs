@@ -17,41 +17,66 @@ pub mod compass_flows;
 pub mod credentials;
 pub mod data_migrations;
 pub mod device_auth_sessions;
+pub mod email_outbox;
 pub mod email_templates;
 pub mod email_verification_tokens;
 pub mod identity_provider_links;
+pub mod identity_provider_mappers;
 pub mod identity_providers;
 pub mod jwt_keys;
 pub mod login_action_tokens;
+pub mod login_history;
+pub mod login_risk_policies;
 pub mod magic_links;
+pub mod maintenance_windows;
+pub mod message_bundles;
+pub mod organization_admin_roles;
 pub mod organization_attributes;
+pub mod organization_domains;
 pub mod organization_group_attributes;
 pub mod organization_group_members;
 pub mod organization_group_roles;
 pub mod organization_groups;
+pub mod organization_invitations;
 pub mod organization_member_roles;
 pub mod organization_members;
 pub mod organizations;
 pub mod otp_enrollments;
 pub mod password_policy;
 pub mod password_reset_tokens;
+pub mod pending_broker_logins;
 pub mod portal_layouts;
 pub mod portal_themes;
 pub mod post_logout_redirect_uris;
+pub mod rate_limit_counters;
+pub mod rate_limit_list_entries;
+pub mod rate_limit_policies;
+pub mod realm_group_attributes;
+pub mod realm_group_members;
+pub mod realm_group_roles;
+pub mod realm_groups;
+pub mod realm_localization_settings;
 pub mod realm_maintenance_whitelist;
 pub mod realm_settings;
 pub mod realms;
 pub mod redirect_uris;
 pub mod refresh_tokens;
 pub mod roles;
+pub mod saml_auth_requests;
+pub mod saml_client_settings;
+pub mod saml_signing_certificates;
 pub mod security_events;
+pub mod security_notification_settings;
 pub mod smtp_configs;
 pub mod user_attributes;
 pub mod user_federation_mappings;
 pub mod user_federation_providers;
+pub mod user_federation_sync_runs;
+pub mod user_profile_schemas;
 pub mod user_required_actions;
 pub mod user_role;
 pub mod user_sessions;
 pub mod users;
+pub mod webauthn_policies;
 pub mod webhook_subscribers;
 pub mod webhooks;
@@ -0,0 +1,100 @@
+//! `SeaORM` Entity for organization invitations.
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Copy, Clone, Default, Debug, DeriveEntity)]
+pub struct Entity;
+
+impl EntityName for Entity {
+    fn table_name(&self) -> &str {
+        "organization_invitations"
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveModel, DeriveActiveModel, Eq)]
+pub struct Model {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub email: String,
+    pub role_id: Option<Uuid>,
+    pub group_id: Option<Uuid>,
+    pub invited_by: Option<Uuid>,
+    pub token_hash: String,
+    pub expires_at: DateTimeWithTimeZone,
+    pub accepted_at: Option<DateTimeWithTimeZone>,
+    pub accepted_by: Option<Uuid>,
+    pub revoked_at: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+pub enum Column {
+    Id,
+    OrganizationId,
+    Email,
+    RoleId,
+    GroupId,
+    InvitedBy,
+    TokenHash,
+    ExpiresAt,
+    AcceptedAt,
+    AcceptedBy,
+    RevokedAt,
+    CreatedAt,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
+pub enum PrimaryKey {
+    Id,
+}
+
+impl PrimaryKeyTrait for PrimaryKey {
+    type ValueType = Uuid;
+    fn auto_increment() -> bool {
+        false
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {
+    Organizations,
+}
+
+impl ColumnTrait for Column {
+    type EntityName = Entity;
+    fn def(&self) -> ColumnDef {
+        match self {
+            Self::Id => ColumnType::Uuid.def(),
+            Self::OrganizationId => ColumnType::Uuid.def(),
+            Self::Email => ColumnType::String(StringLen::N(255u32)).def(),
+            Self::RoleId => ColumnType::Uuid.def().null(),
+            Self::GroupId => ColumnType::Uuid.def().null(),
+            Self::InvitedBy => ColumnType::Uuid.def().null(),
+            Self::TokenHash => ColumnType::String(StringLen::N(64u32)).def().unique(),
+            Self::ExpiresAt => ColumnType::TimestampWithTimeZone.def(),
+            Self::AcceptedAt => ColumnType::TimestampWithTimeZone.def().null(),
+            Self::AcceptedBy => ColumnType::Uuid.def().null(),
+            Self::RevokedAt => ColumnType::TimestampWithTimeZone.def().null(),
+            Self::CreatedAt => ColumnType::TimestampWithTimeZone.def(),
+        }
+    }
+}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        match self {
+            Self::Organizations => Entity::belongs_to(super::organizations::Entity)
+                .from(Column::OrganizationId)
+                .to(super::organizations::Column::Id)
+                .into(),
+        }
+    }
+}
+
+impl Related<super::organizations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Organizations.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
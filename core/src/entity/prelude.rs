@@ -18,6 +18,7 @@ pub use super::device_auth_sessions::Entity as DeviceAuthSessions;
 pub use super::email_templates::Entity as EmailTemplates;
 pub use super::email_verification_tokens::Entity as EmailVerificationTokens;
 pub use super::identity_provider_links::Entity as IdentityProviderLinks;
+pub use super::identity_provider_mappers::Entity as IdentityProviderMappers;
 pub use super::identity_providers::Entity as IdentityProviders;
 pub use super::jwt_keys::Entity as JwtKeys;
 pub use super::magic_links::Entity as MagicLinks;
@@ -27,6 +28,7 @@ pub use super::organizations::Entity as Organizations;
 pub use super::otp_enrollments::Entity as OtpEnrollments;
 pub use super::password_policy::Entity as PasswordPolicy;
 pub use super::password_reset_tokens::Entity as PasswordResetTokens;
+pub use super::pending_broker_logins::Entity as PendingBrokerLogins;
 pub use super::portal_layouts::Entity as PortalLayouts;
 pub use super::portal_themes::Entity as PortalThemes;
 pub use super::post_logout_redirect_uris::Entity as PostLogoutRedirectUris;
@@ -36,11 +38,15 @@ pub use super::realms::Entity as Realms;
 pub use super::redirect_uris::Entity as RedirectUris;
 pub use super::refresh_tokens::Entity as RefreshTokens;
 pub use super::roles::Entity as Roles;
+pub use super::saml_auth_requests::Entity as SamlAuthRequests;
+pub use super::saml_client_settings::Entity as SamlClientSettings;
+pub use super::saml_signing_certificates::Entity as SamlSigningCertificates;
 pub use super::security_events::Entity as SecurityEvents;
 pub use super::smtp_configs::Entity as SmtpConfigs;
 pub use super::user_attributes::Entity as UserAttributes;
 pub use super::user_federation_mappings::Entity as UserFederationMappings;
 pub use super::user_federation_providers::Entity as UserFederationProviders;
+pub use super::user_federation_sync_runs::Entity as UserFederationSyncRuns;
 pub use super::user_required_actions::Entity as UserRequiredActions;
 pub use super::user_role::Entity as UserRole;
 pub use super::user_sessions::Entity as UserSessions;
@@ -0,0 +1,64 @@
+//! `SeaORM` Entity for the fixed-window counters shared across replicas.
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Copy, Clone, Default, Debug, DeriveEntity)]
+pub struct Entity;
+
+impl EntityName for Entity {
+    fn table_name(&self) -> &str {
+        "rate_limit_counters"
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveModel, DeriveActiveModel, Eq)]
+pub struct Model {
+    pub key: String,
+    pub window_start: DateTimeWithTimeZone,
+    pub hits: i32,
+    pub expires_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+pub enum Column {
+    Key,
+    WindowStart,
+    Hits,
+    ExpiresAt,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
+pub enum PrimaryKey {
+    Key,
+    WindowStart,
+}
+
+impl PrimaryKeyTrait for PrimaryKey {
+    type ValueType = (String, DateTimeWithTimeZone);
+    fn auto_increment() -> bool {
+        false
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {}
+
+impl ColumnTrait for Column {
+    type EntityName = Entity;
+    fn def(&self) -> ColumnDef {
+        match self {
+            Self::Key => ColumnType::String(StringLen::N(512)).def(),
+            Self::WindowStart => ColumnType::TimestampWithTimeZone.def(),
+            Self::Hits => ColumnType::Integer.def(),
+            Self::ExpiresAt => ColumnType::TimestampWithTimeZone.def(),
+        }
+    }
+}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        panic!("No RelationDef")
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
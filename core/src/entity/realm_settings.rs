@@ -31,6 +31,7 @@ pub struct Model {
     pub reset_password_template_id: Option<Uuid>,
     pub magic_link_template_id: Option<Uuid>,
     pub email_verification_template_id: Option<Uuid>,
+    pub organization_invitation_template_id: Option<Uuid>,
     pub email_verification_enabled: bool,
     pub email_verification_ttl_hours: i32,
     pub portal_theme_id: Option<Uuid>,
@@ -62,6 +63,7 @@ pub enum Column {
     ResetPasswordTemplateId,
     MagicLinkTemplateId,
     EmailVerificationTemplateId,
+    OrganizationInvitationTemplateId,
     EmailVerificationEnabled,
     EmailVerificationTtlHours,
     PortalThemeId,
@@ -116,6 +118,7 @@ impl ColumnTrait for Column {
             Self::ResetPasswordTemplateId => ColumnType::Uuid.def().null(),
             Self::MagicLinkTemplateId => ColumnType::Uuid.def().null(),
             Self::EmailVerificationTemplateId => ColumnType::Uuid.def().null(),
+            Self::OrganizationInvitationTemplateId => ColumnType::Uuid.def().null(),
             Self::EmailVerificationEnabled => ColumnType::Boolean.def(),
             Self::EmailVerificationTtlHours => ColumnType::Integer.def(),
             Self::PortalThemeId => ColumnType::Uuid.def().null(),
@@ -22,6 +22,7 @@ pub struct Model {
     pub from_email: String,
     pub from_name: String,
     pub encryption: String,
+    pub max_emails_per_minute: Option<i32>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -37,6 +38,7 @@ pub enum Column {
     FromEmail,
     FromName,
     Encryption,
+    MaxEmailsPerMinute,
     CreatedAt,
     UpdatedAt,
 }
@@ -71,6 +73,7 @@ impl ColumnTrait for Column {
             Self::FromEmail => ColumnType::String(StringLen::N(255u32)).def(),
             Self::FromName => ColumnType::String(StringLen::N(255u32)).def(),
             Self::Encryption => ColumnType::String(StringLen::N(10u32)).def(),
+            Self::MaxEmailsPerMinute => ColumnType::Integer.def().null(),
             Self::CreatedAt => ColumnType::TimestampWithTimeZone.def(),
             Self::UpdatedAt => ColumnType::TimestampWithTimeZone.def(),
         }
@@ -23,6 +23,8 @@ pub struct Model {
     pub sync_enabled: bool,
     pub sync_mode: String,
     pub sync_interval_minutes: Option<i32>,
+    pub full_sync_cron: Option<String>,
+    pub changed_sync_interval_minutes: Option<i32>,
     pub last_sync_at: Option<DateTimeWithTimeZone>,
     pub last_sync_status: Option<String>,
     pub last_sync_result: Option<Json>,
@@ -42,6 +44,8 @@ pub enum Column {
     SyncEnabled,
     SyncMode,
     SyncIntervalMinutes,
+    FullSyncCron,
+    ChangedSyncIntervalMinutes,
     LastSyncAt,
     LastSyncStatus,
     LastSyncResult,
@@ -65,6 +69,7 @@ impl PrimaryKeyTrait for PrimaryKey {
 pub enum Relation {
     Realms,
     UserFederationMappings,
+    UserFederationSyncRuns,
 }
 
 impl ColumnTrait for Column {
@@ -81,6 +86,8 @@ impl ColumnTrait for Column {
             Self::SyncEnabled => ColumnType::Boolean.def(),
             Self::SyncMode => ColumnType::String(StringLen::N(20u32)).def(),
             Self::SyncIntervalMinutes => ColumnType::Integer.def().null(),
+            Self::FullSyncCron => ColumnType::String(StringLen::N(120u32)).def().null(),
+            Self::ChangedSyncIntervalMinutes => ColumnType::Integer.def().null(),
             Self::LastSyncAt => ColumnType::TimestampWithTimeZone.def().null(),
             Self::LastSyncStatus => ColumnType::String(StringLen::N(50u32)).def().null(),
             Self::LastSyncResult => ColumnType::JsonBinary.def().null(),
@@ -100,6 +107,9 @@ impl RelationTrait for Relation {
             Self::UserFederationMappings => {
                 Entity::has_many(super::user_federation_mappings::Entity).into()
             }
+            Self::UserFederationSyncRuns => {
+                Entity::has_many(super::user_federation_sync_runs::Entity).into()
+            }
         }
     }
 }
@@ -116,4 +126,10 @@ impl Related<super::user_federation_mappings::Entity> for Entity {
     }
 }
 
+impl Related<super::user_federation_sync_runs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserFederationSyncRuns.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,97 @@
+//! `SeaORM` Entity for per-realm WebAuthn policies.
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Copy, Clone, Default, Debug, DeriveEntity)]
+pub struct Entity;
+
+impl EntityName for Entity {
+    fn table_name(&self) -> &str {
+        "webauthn_policies"
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveModel, DeriveActiveModel, Eq)]
+pub struct Model {
+    pub realm_id: Uuid,
+    pub attestation: String,
+    pub user_verification: String,
+    pub require_resident_key: bool,
+    pub authenticator_attachment: Option<String>,
+    pub allowed_origins: Json,
+    pub allowed_aaguids: Json,
+    pub blocked_aaguids: Json,
+    pub fips_certified_only: bool,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+pub enum Column {
+    RealmId,
+    Attestation,
+    UserVerification,
+    RequireResidentKey,
+    AuthenticatorAttachment,
+    AllowedOrigins,
+    AllowedAaguids,
+    BlockedAaguids,
+    FipsCertifiedOnly,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
+pub enum PrimaryKey {
+    RealmId,
+}
+
+impl PrimaryKeyTrait for PrimaryKey {
+    type ValueType = Uuid;
+    fn auto_increment() -> bool {
+        false
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {
+    Realms,
+}
+
+impl ColumnTrait for Column {
+    type EntityName = Entity;
+    fn def(&self) -> ColumnDef {
+        match self {
+            Self::RealmId => ColumnType::Uuid.def(),
+            Self::Attestation => ColumnType::String(StringLen::N(16u32)).def(),
+            Self::UserVerification => ColumnType::String(StringLen::N(16u32)).def(),
+            Self::RequireResidentKey => ColumnType::Boolean.def(),
+            Self::AuthenticatorAttachment => ColumnType::String(StringLen::N(16u32)).def().null(),
+            Self::AllowedOrigins => ColumnType::JsonBinary.def(),
+            Self::AllowedAaguids => ColumnType::JsonBinary.def(),
+            Self::BlockedAaguids => ColumnType::JsonBinary.def(),
+            Self::FipsCertifiedOnly => ColumnType::Boolean.def(),
+            Self::CreatedAt => ColumnType::TimestampWithTimeZone.def(),
+            Self::UpdatedAt => ColumnType::TimestampWithTimeZone.def(),
+        }
+    }
+}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        match self {
+            Self::Realms => Entity::belongs_to(super::realms::Entity)
+                .from(Column::RealmId)
+                .to(super::realms::Column::Id)
+                .into(),
+        }
+    }
+}
+
+impl Related<super::realms::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Realms.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,274 @@
+//! In-process stand-in for an LDAP server, so federation sync can be exercised end to end
+//! without a container. Entries go through the same mapping code as `LdapClientImpl`; search
+//! filters are evaluated locally for the subset of RFC 4515 that provider configs use
+//! (`&`, `|`, `!`, equality, `>=`, presence and `*` substrings).
+
+use std::collections::HashMap;
+
+use ldap3::SearchEntry;
+
+use crate::domain::abyss::federation::entities::{
+    FederatedGroup, FederatedUser, FederationProvider,
+};
+use crate::domain::abyss::federation::group_mapping::GroupMapperSettings;
+use crate::domain::abyss::federation::ports::FederationDirectory;
+use crate::domain::abyss::federation::schedule::compare_change_markers;
+use crate::domain::abyss::federation::value_objects::TestConnectionResult;
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::infrastructure::abyss::federation::ldap::{LdapClientImpl, with_change_marker};
+
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryDirectory {
+    entries: Vec<SearchEntry>,
+}
+
+impl InMemoryDirectory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_entry(mut self, dn: &str, attrs: &[(&str, &[&str])]) -> Self {
+        let attrs: HashMap<String, Vec<String>> = attrs
+            .iter()
+            .map(|(k, vals)| (k.to_string(), vals.iter().map(|v| v.to_string()).collect()))
+            .collect();
+
+        self.entries.push(SearchEntry {
+            dn: dn.to_string(),
+            attrs,
+            bin_attrs: HashMap::new(),
+        });
+        self
+    }
+
+    fn search(&self, base: &str, filter: &str) -> Result<Vec<&SearchEntry>, CoreError> {
+        let filter = Filter::parse(filter)?;
+        let base = normalize(base);
+
+        Ok(self
+            .entries
+            .iter()
+            .filter(|e| {
+                let dn = normalize(&e.dn);
+                dn == base || dn.ends_with(&format!(",{}", base))
+            })
+            .filter(|e| filter.matches(e))
+            .collect())
+    }
+}
+
+impl FederationDirectory for InMemoryDirectory {
+    async fn test_connection(
+        &self,
+        _provider: &FederationProvider,
+    ) -> Result<TestConnectionResult, CoreError> {
+        Ok(TestConnectionResult {
+            success: true,
+            message: "in-memory directory".to_string(),
+            details: None,
+        })
+    }
+
+    async fn search_users(
+        &self,
+        provider: &FederationProvider,
+        changed_since: Option<&str>,
+    ) -> Result<Vec<FederatedUser>, CoreError> {
+        let config = LdapClientImpl::parse_config(provider)?;
+        let filter = config.search.user_filter(provider, changed_since);
+        let tracking = config.search.change_tracking_attribute(provider);
+
+        Ok(self
+            .search(&config.search.base_dn, &filter)?
+            .into_iter()
+            .filter_map(|e| {
+                LdapClientImpl
+                    .map_entry_to_user(e, &config.attributes)
+                    .ok()
+                    .map(|user| with_change_marker(user, e, &tracking))
+            })
+            .collect())
+    }
+
+    async fn search_groups(
+        &self,
+        _provider: &FederationProvider,
+        mapper: &GroupMapperSettings,
+    ) -> Result<Vec<FederatedGroup>, CoreError> {
+        Ok(self
+            .search(&mapper.group_search_base, &mapper.group_filter)?
+            .into_iter()
+            .filter_map(|e| LdapClientImpl::map_entry_to_group(e, mapper))
+            .collect())
+    }
+}
+
+fn normalize(dn: &str) -> String {
+    dn.split(',')
+        .map(|rdn| rdn.trim().to_lowercase())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[derive(Debug)]
+enum Filter {
+    And(Vec<Filter>),
+    Or(Vec<Filter>),
+    Not(Box<Filter>),
+    Present(String),
+    Equal(String, String),
+    GreaterOrEqual(String, String),
+}
+
+impl Filter {
+    fn parse(input: &str) -> Result<Self, CoreError> {
+        let (filter, rest) = Self::parse_one(input.trim())?;
+        if !rest.trim().is_empty() {
+            return Err(invalid(input));
+        }
+        Ok(filter)
+    }
+
+    fn parse_one(input: &str) -> Result<(Self, &str), CoreError> {
+        let body = input.strip_prefix('(').ok_or_else(|| invalid(input))?;
+
+        match body.chars().next() {
+            Some(op @ ('&' | '|')) => {
+                let mut rest = &body[1..];
+                let mut children = Vec::new();
+                while rest.starts_with('(') {
+                    let (child, next) = Self::parse_one(rest)?;
+                    children.push(child);
+                    rest = next;
+                }
+                let rest = rest.strip_prefix(')').ok_or_else(|| invalid(input))?;
+                let filter = if op == '&' {
+                    Filter::And(children)
+                } else {
+                    Filter::Or(children)
+                };
+                Ok((filter, rest))
+            }
+            Some('!') => {
+                let (child, rest) = Self::parse_one(&body[1..])?;
+                let rest = rest.strip_prefix(')').ok_or_else(|| invalid(input))?;
+                Ok((Filter::Not(Box::new(child)), rest))
+            }
+            _ => {
+                let end = body.find(')').ok_or_else(|| invalid(input))?;
+                if let Some((attr, value)) = body[..end].split_once(">=") {
+                    let filter = Filter::GreaterOrEqual(attr.to_lowercase(), value.to_string());
+                    return Ok((filter, &body[end + 1..]));
+                }
+                let (attr, value) = body[..end].split_once('=').ok_or_else(|| invalid(input))?;
+                let filter = if value == "*" {
+                    Filter::Present(attr.to_lowercase())
+                } else {
+                    Filter::Equal(attr.to_lowercase(), value.to_lowercase())
+                };
+                Ok((filter, &body[end + 1..]))
+            }
+        }
+    }
+
+    fn matches(&self, entry: &SearchEntry) -> bool {
+        let values = |attr: &str| {
+            entry
+                .attrs
+                .iter()
+                .find(|(k, _)| k.to_lowercase() == attr)
+                .map(|(_, v)| v.as_slice())
+                .unwrap_or_default()
+        };
+
+        match self {
+            Filter::And(children) => children.iter().all(|c| c.matches(entry)),
+            Filter::Or(children) => children.iter().any(|c| c.matches(entry)),
+            Filter::Not(child) => !child.matches(entry),
+            Filter::Present(attr) => !values(attr).is_empty(),
+            Filter::Equal(attr, pattern) => values(attr)
+                .iter()
+                .any(|v| wildcard_match(pattern, &v.to_lowercase())),
+            Filter::GreaterOrEqual(attr, bound) => values(attr)
+                .iter()
+                .any(|v| compare_change_markers(v, bound).is_ge()),
+        }
+    }
+}
+
+fn wildcard_match(pattern: &str, value: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == value;
+    }
+
+    let mut rest = value;
+    for (i, part) in parts.iter().enumerate() {
+        if i == 0 {
+            match rest.strip_prefix(part) {
+                Some(r) => rest = r,
+                None => return false,
+            }
+        } else if i == parts.len() - 1 {
+            return rest.ends_with(part);
+        } else {
+            match rest.find(part) {
+                Some(pos) => rest = &rest[pos + part.len()..],
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
+fn invalid(filter: &str) -> CoreError {
+    CoreError::Configuration(format!("Unsupported LDAP filter: {}", filter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluates_compound_filters() {
+        let dir = InMemoryDirectory::new()
+            .with_entry(
+                "uid=alice,ou=people,dc=example,dc=org",
+                &[("objectClass", &["inetOrgPerson"]), ("uid", &["alice"])],
+            )
+            .with_entry(
+                "uid=svc-backup,ou=people,dc=example,dc=org",
+                &[
+                    ("objectClass", &["inetOrgPerson"]),
+                    ("uid", &["svc-backup"]),
+                ],
+            )
+            .with_entry(
+                "cn=admins,ou=groups,dc=example,dc=org",
+                &[("objectClass", &["groupOfNames"]), ("cn", &["admins"])],
+            );
+
+        let people = dir
+            .search(
+                "ou=people,dc=example,dc=org",
+                "(&(objectClass=inetOrgPerson)(!(uid=svc-*)))",
+            )
+            .unwrap();
+        assert_eq!(people.len(), 1);
+        assert_eq!(people[0].dn, "uid=alice,ou=people,dc=example,dc=org");
+
+        let everything = dir.search("dc=example,dc=org", "(cn=*)").unwrap();
+        assert_eq!(everything.len(), 1);
+
+        assert!(dir.search("dc=example,dc=org", "(uid=alice").is_err());
+
+        let dir = dir.with_entry(
+            "uid=bob,ou=people,dc=example,dc=org",
+            &[("uid", &["bob"]), ("uSNChanged", &["10250"])],
+        );
+        let changed = dir
+            .search("dc=example,dc=org", "(uSNChanged>=9800)")
+            .unwrap();
+        assert_eq!(changed.len(), 1);
+    }
+}
@@ -0,0 +1,144 @@
+//! Just enough DER to read the GSS-API and Kerberos messages an HTTP acceptor receives.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::domain::common::entities::app_errors::CoreError;
+
+pub(super) const INTEGER: u8 = 0x02;
+pub(super) const BIT_STRING: u8 = 0x03;
+pub(super) const OCTET_STRING: u8 = 0x04;
+pub(super) const OID: u8 = 0x06;
+pub(super) const GENERAL_STRING: u8 = 0x1b;
+pub(super) const GENERALIZED_TIME: u8 = 0x18;
+pub(super) const SEQUENCE: u8 = 0x30;
+
+/// Constructed, context-specific tag `[n]`.
+pub(super) const fn context(n: u8) -> u8 {
+    0xa0 | n
+}
+
+/// Constructed, application tag `[APPLICATION n]`.
+pub(super) const fn application(n: u8) -> u8 {
+    0x60 | n
+}
+
+pub(super) fn malformed(what: &str) -> CoreError {
+    CoreError::FederationAuthenticationFailed(format!("Malformed Kerberos token: {}", what))
+}
+
+pub(super) struct Reader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(input: &'a [u8]) -> Self {
+        Self { input }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    pub(super) fn remaining(&self) -> &'a [u8] {
+        self.input
+    }
+
+    pub(super) fn peek_tag(&self) -> Option<u8> {
+        self.input.first().copied()
+    }
+
+    /// Next element as `(tag, content)`. Only single-byte tags occur in these messages.
+    pub(super) fn read(&mut self) -> Result<(u8, &'a [u8]), CoreError> {
+        let [tag, first, rest @ ..] = self.input else {
+            return Err(malformed("truncated element"));
+        };
+
+        let (len, rest) = if first & 0x80 == 0 {
+            (*first as usize, rest)
+        } else {
+            let count = (first & 0x7f) as usize;
+            if count == 0 || count > 4 || rest.len() < count {
+                return Err(malformed("invalid length"));
+            }
+            let len = rest[..count]
+                .iter()
+                .fold(0usize, |len, b| (len << 8) | *b as usize);
+            (len, &rest[count..])
+        };
+
+        if rest.len() < len {
+            return Err(malformed("truncated element"));
+        }
+
+        let (content, rest) = rest.split_at(len);
+        self.input = rest;
+        Ok((*tag, content))
+    }
+
+    pub(super) fn expect(&mut self, tag: u8) -> Result<&'a [u8], CoreError> {
+        match self.read()? {
+            (t, content) if t == tag => Ok(content),
+            (t, _) => Err(malformed(&format!(
+                "expected tag {:#04x}, found {:#04x}",
+                tag, t
+            ))),
+        }
+    }
+
+    /// The next element if it carries `tag`, for OPTIONAL fields.
+    pub(super) fn optional(&mut self, tag: u8) -> Result<Option<&'a [u8]>, CoreError> {
+        if self.peek_tag() == Some(tag) {
+            self.expect(tag).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// An explicitly tagged field: `[n] inner`.
+    pub(super) fn explicit(&mut self, n: u8, inner: u8) -> Result<&'a [u8], CoreError> {
+        Reader::new(self.expect(context(n))?).expect(inner)
+    }
+
+    pub(super) fn optional_explicit(
+        &mut self,
+        n: u8,
+        inner: u8,
+    ) -> Result<Option<&'a [u8]>, CoreError> {
+        self.optional(context(n))?
+            .map(|field| Reader::new(field).expect(inner))
+            .transpose()
+    }
+
+    /// Skip elements until the next one tagged `[n]`, or the end.
+    pub(super) fn skip_to(&mut self, n: u8) -> Result<(), CoreError> {
+        while let Some(tag) = self.peek_tag() {
+            if tag == context(n) {
+                break;
+            }
+            self.read()?;
+        }
+        Ok(())
+    }
+}
+
+pub(super) fn integer(content: &[u8]) -> Result<i64, CoreError> {
+    if content.is_empty() || content.len() > 8 {
+        return Err(malformed("invalid integer"));
+    }
+
+    let sign = if content[0] & 0x80 != 0 { -1i64 } else { 0 };
+    Ok(content
+        .iter()
+        .fold(sign, |value, b| (value << 8) | *b as i64))
+}
+
+pub(super) fn string(content: &[u8]) -> Result<String, CoreError> {
+    String::from_utf8(content.to_vec()).map_err(|_| malformed("invalid string"))
+}
+
+pub(super) fn time(content: &[u8]) -> Result<DateTime<Utc>, CoreError> {
+    let value = std::str::from_utf8(content).map_err(|_| malformed("invalid time"))?;
+    NaiveDateTime::parse_from_str(value, "%Y%m%d%H%M%SZ")
+        .map(|t| t.and_utc())
+        .map_err(|_| malformed("invalid time"))
+}
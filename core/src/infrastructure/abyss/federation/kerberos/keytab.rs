@@ -0,0 +1,103 @@
+//! MIT keytab files (format `0x0502`), as written by `ktutil` and `ktpass`.
+
+use crate::domain::abyss::federation::kerberos::KerberosPrincipal;
+use crate::domain::common::entities::app_errors::CoreError;
+
+#[derive(Debug, Clone)]
+pub(super) struct KeytabEntry {
+    pub(super) principal: KerberosPrincipal,
+    pub(super) kvno: u32,
+    pub(super) enctype: i64,
+    pub(super) key: Vec<u8>,
+}
+
+fn invalid(what: &str) -> CoreError {
+    CoreError::Configuration(format!("Invalid keytab: {}", what))
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], CoreError> {
+        if self.data.len() < n {
+            return Err(invalid("truncated entry"));
+        }
+        let (head, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, CoreError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, CoreError> {
+        Ok(u16::from_be_bytes(
+            self.take(2)?.try_into().unwrap_or_default(),
+        ))
+    }
+
+    fn u32(&mut self) -> Result<u32, CoreError> {
+        Ok(u32::from_be_bytes(
+            self.take(4)?.try_into().unwrap_or_default(),
+        ))
+    }
+
+    fn counted(&mut self) -> Result<&'a [u8], CoreError> {
+        let len = self.u16()? as usize;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> Result<String, CoreError> {
+        String::from_utf8(self.counted()?.to_vec()).map_err(|_| invalid("non UTF-8 name"))
+    }
+}
+
+pub(super) fn parse(data: &[u8]) -> Result<Vec<KeytabEntry>, CoreError> {
+    let mut cursor = Cursor { data };
+    if cursor.take(2)? != [0x05, 0x02] {
+        return Err(invalid("unsupported version"));
+    }
+
+    let mut entries = Vec::new();
+    while !cursor.data.is_empty() {
+        let size = cursor.u32()? as i32;
+        if size == 0 {
+            break;
+        }
+        // Negative sizes are holes left by deleted entries.
+        let entry = cursor.take(size.unsigned_abs() as usize)?;
+        if size < 0 {
+            continue;
+        }
+
+        let mut entry = Cursor { data: entry };
+        let count = entry.u16()?;
+        let realm = entry.string()?;
+        let components = (0..count)
+            .map(|_| entry.string())
+            .collect::<Result<Vec<_>, _>>()?;
+        let _name_type = entry.u32()?;
+        let _timestamp = entry.u32()?;
+        let vno8 = entry.u8()?;
+        let enctype = entry.u16()?;
+        let key = entry.counted()?.to_vec();
+        // The 32-bit version number, when present, supersedes the 8-bit one.
+        let kvno = match entry.data.len() {
+            4.. => Some(entry.u32()?).filter(|v| *v != 0),
+            _ => None,
+        }
+        .unwrap_or(vno8 as u32);
+
+        entries.push(KeytabEntry {
+            principal: KerberosPrincipal { components, realm },
+            kvno,
+            enctype: enctype as i64,
+            key,
+        });
+    }
+
+    Ok(entries)
+}
@@ -0,0 +1,640 @@
+//! SPNEGO acceptor for browser single sign-on: unwraps the `Negotiate` token, decrypts the
+//! service ticket with the keytab and checks the authenticator, the way a GSS-API acceptor
+//! does for an initial context token without mutual authentication.
+
+mod crypto;
+mod der;
+mod keytab;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::SystemTime;
+
+use chrono::{DateTime, Duration, Utc};
+use tracing::{instrument, warn};
+
+use crate::domain::abyss::federation::kerberos::{KerberosConfig, KerberosPrincipal};
+use crate::domain::common::entities::app_errors::CoreError;
+use crypto::{EncType, USAGE_AP_REQ_AUTHENTICATOR, USAGE_TICKET};
+use der::{
+    BIT_STRING, GENERAL_STRING, GENERALIZED_TIME, INTEGER, OCTET_STRING, OID, Reader, SEQUENCE,
+    application, context, malformed,
+};
+use keytab::KeytabEntry;
+
+/// 1.3.6.1.5.5.2
+const SPNEGO_OID: &[u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x02];
+/// 1.2.840.113554.1.2.2
+const KRB5_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x12, 0x01, 0x02, 0x02];
+/// 1.2.840.48018.1.2.2, sent by Windows clients.
+const MS_KRB5_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x82, 0xf7, 0x12, 0x01, 0x02, 0x02];
+const KRB5_AP_REQ_TOKEN_ID: &[u8] = &[0x01, 0x00];
+
+/// Client principal, `ctime` and `cusec` of an accepted authenticator.
+type ReplayKey = (String, DateTime<Utc>, i64);
+
+/// A parsed keytab with the file metadata it was read at.
+#[derive(Debug)]
+struct CachedKeytab {
+    modified: Option<SystemTime>,
+    len: u64,
+    entries: Arc<Vec<KeytabEntry>>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SpnegoAcceptorImpl {
+    /// Parsed keytabs by path. A keytab is read again when its modification time or size
+    /// changes, so rotated keys are picked up without a restart.
+    keytabs: Arc<RwLock<HashMap<String, CachedKeytab>>>,
+    /// Authenticators already accepted, kept until they fall out of the clock-skew window.
+    ///
+    /// The cache lives in this process: behind a load balancer, an authenticator accepted by
+    /// one instance can be replayed once against each other instance within the clock skew.
+    /// Multi-instance deployments should keep SPNEGO sessions sticky to one instance.
+    replay_cache: Arc<Mutex<HashMap<ReplayKey, DateTime<Utc>>>>,
+}
+
+impl SpnegoAcceptorImpl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validate a decoded `Negotiate` token and return the client principal.
+    #[instrument(skip(self, config, token))]
+    pub async fn accept(
+        &self,
+        config: &KerberosConfig,
+        token: &[u8],
+    ) -> Result<KerberosPrincipal, CoreError> {
+        let entries = self.keytab(&config.keytab_path).await?;
+
+        self.accept_with_keytab(config, &entries, token, Utc::now())
+    }
+
+    /// The parsed keytab at `path`, read from disk only when it changed since the last read.
+    async fn keytab(&self, path: &str) -> Result<Arc<Vec<KeytabEntry>>, CoreError> {
+        let unreadable =
+            |e: std::io::Error| CoreError::Configuration(format!("Cannot read keytab {path}: {e}"));
+
+        let metadata = tokio::fs::metadata(path).await.map_err(unreadable)?;
+        let modified = metadata.modified().ok();
+        let len = metadata.len();
+
+        {
+            let keytabs = self
+                .keytabs
+                .read()
+                .map_err(|_| CoreError::InternalServerError)?;
+            if let Some(cached) = keytabs.get(path)
+                && cached.modified == modified
+                && cached.len == len
+            {
+                return Ok(cached.entries.clone());
+            }
+        }
+
+        let entries = Arc::new(keytab::parse(
+            &tokio::fs::read(path).await.map_err(unreadable)?,
+        )?);
+        self.keytabs
+            .write()
+            .map_err(|_| CoreError::InternalServerError)?
+            .insert(
+                path.to_string(),
+                CachedKeytab {
+                    modified,
+                    len,
+                    entries: entries.clone(),
+                },
+            );
+
+        Ok(entries)
+    }
+
+    fn accept_with_keytab(
+        &self,
+        config: &KerberosConfig,
+        keytab: &[KeytabEntry],
+        token: &[u8],
+        now: DateTime<Utc>,
+    ) -> Result<KerberosPrincipal, CoreError> {
+        let skew = Duration::seconds(config.allowed_clock_skew_seconds.max(0));
+        let ap_req = ApReq::parse(krb5_ap_req(token)?)?;
+
+        let service = config.service_principal();
+        if !ap_req.ticket.server.same_name(&service) {
+            return Err(rejected(&format!(
+                "ticket is for {}, expected {}",
+                ap_req.ticket.server, service
+            )));
+        }
+
+        let etype = EncType::from_etype(ap_req.ticket.enc_part.etype)
+            .ok_or_else(|| rejected("unsupported ticket encryption type"))?;
+        let key = keytab
+            .iter()
+            .find(|entry| {
+                entry.principal.same_name(&service)
+                    && entry.enctype == ap_req.ticket.enc_part.etype
+                    && ap_req
+                        .ticket
+                        .enc_part
+                        .kvno
+                        .is_none_or(|kvno| kvno == entry.kvno)
+            })
+            .ok_or_else(|| rejected("no keytab key for the ticket"))?;
+
+        let ticket = EncTicketPart::parse(&crypto::decrypt(
+            etype,
+            &key.key,
+            USAGE_TICKET,
+            ap_req.ticket.enc_part.cipher,
+        )?)?;
+
+        if ticket.starttime.unwrap_or(ticket.authtime) - skew > now {
+            return Err(rejected("ticket not yet valid"));
+        }
+        if ticket.endtime + skew < now {
+            return Err(rejected("ticket expired"));
+        }
+
+        let session_etype = EncType::from_etype(ticket.session_key_type)
+            .filter(|_| ap_req.authenticator.etype == ticket.session_key_type)
+            .ok_or_else(|| rejected("unsupported session key type"))?;
+        let authenticator = Authenticator::parse(&crypto::decrypt(
+            session_etype,
+            &ticket.session_key,
+            USAGE_AP_REQ_AUTHENTICATOR,
+            ap_req.authenticator.cipher,
+        )?)?;
+
+        if !authenticator.client.same_name(&ticket.client) {
+            return Err(rejected("authenticator does not match the ticket"));
+        }
+        if (authenticator.ctime - now).abs() > skew {
+            return Err(rejected("clock skew too great"));
+        }
+        if !ticket
+            .client
+            .realm
+            .eq_ignore_ascii_case(&config.kerberos_realm)
+        {
+            return Err(rejected(&format!(
+                "principal {} is outside realm {}",
+                ticket.client, config.kerberos_realm
+            )));
+        }
+
+        self.check_replay(&authenticator, now + skew)?;
+
+        Ok(ticket.client)
+    }
+
+    fn check_replay(
+        &self,
+        authenticator: &Authenticator,
+        keep_until: DateTime<Utc>,
+    ) -> Result<(), CoreError> {
+        let mut cache = self
+            .replay_cache
+            .lock()
+            .map_err(|_| CoreError::InternalServerError)?;
+        let now = Utc::now();
+        cache.retain(|_, expires| *expires > now);
+
+        let key = (
+            authenticator.client.to_string(),
+            authenticator.ctime,
+            authenticator.cusec,
+        );
+        if cache.insert(key, keep_until).is_some() {
+            warn!(
+                "Replayed Kerberos authenticator for {}",
+                authenticator.client
+            );
+            return Err(rejected("replayed authenticator"));
+        }
+
+        Ok(())
+    }
+}
+
+fn rejected(reason: &str) -> CoreError {
+    CoreError::FederationAuthenticationFailed(format!("Kerberos ticket rejected: {}", reason))
+}
+
+/// The AP-REQ inside a `Negotiate` token, which is either a SPNEGO `NegTokenInit` carrying a
+/// Kerberos mechanism token or, from some clients, the raw Kerberos GSS-API token.
+fn krb5_ap_req(token: &[u8]) -> Result<&[u8], CoreError> {
+    if token.starts_with(b"NTLMSSP") {
+        return Err(rejected("NTLM is not supported"));
+    }
+
+    let mut outer = Reader::new(Reader::new(token).expect(application(0))?);
+    let mech = outer.expect(OID)?;
+
+    if mech == SPNEGO_OID {
+        let mut neg_token_init =
+            Reader::new(Reader::new(outer.expect(context(0))?).expect(SEQUENCE)?);
+        neg_token_init.skip_to(2)?;
+        let mech_token = neg_token_init
+            .optional_explicit(2, OCTET_STRING)?
+            .ok_or_else(|| malformed("SPNEGO token without a mechanism token"))?;
+        return krb5_ap_req(mech_token);
+    }
+
+    if mech != KRB5_OID && mech != MS_KRB5_OID {
+        return Err(rejected("unsupported GSS-API mechanism"));
+    }
+
+    outer
+        .remaining()
+        .strip_prefix(KRB5_AP_REQ_TOKEN_ID)
+        .ok_or_else(|| malformed("not an AP-REQ"))
+}
+
+fn principal_name(content: &[u8], realm: String) -> Result<KerberosPrincipal, CoreError> {
+    let mut fields = Reader::new(content);
+    fields.explicit(0, INTEGER)?;
+    let mut names = Reader::new(fields.explicit(1, SEQUENCE)?);
+
+    let mut components = Vec::new();
+    while !names.is_empty() {
+        components.push(der::string(names.expect(GENERAL_STRING)?)?);
+    }
+
+    Ok(KerberosPrincipal { components, realm })
+}
+
+struct EncryptedData<'a> {
+    etype: i64,
+    kvno: Option<u32>,
+    cipher: &'a [u8],
+}
+
+impl<'a> EncryptedData<'a> {
+    fn parse(content: &'a [u8]) -> Result<Self, CoreError> {
+        let mut fields = Reader::new(content);
+        Ok(Self {
+            etype: der::integer(fields.explicit(0, INTEGER)?)?,
+            kvno: fields
+                .optional_explicit(1, INTEGER)?
+                .map(der::integer)
+                .transpose()?
+                .map(|kvno| kvno as u32),
+            cipher: fields.explicit(2, OCTET_STRING)?,
+        })
+    }
+}
+
+struct Ticket<'a> {
+    server: KerberosPrincipal,
+    enc_part: EncryptedData<'a>,
+}
+
+struct ApReq<'a> {
+    ticket: Ticket<'a>,
+    authenticator: EncryptedData<'a>,
+}
+
+impl<'a> ApReq<'a> {
+    fn parse(message: &'a [u8]) -> Result<Self, CoreError> {
+        let mut fields = Reader::new(Reader::new(message).expect(application(14))?);
+        let mut fields = Reader::new(fields.expect(SEQUENCE)?);
+
+        fields.explicit(0, INTEGER)?;
+        if der::integer(fields.explicit(1, INTEGER)?)? != 14 {
+            return Err(malformed("not an AP-REQ"));
+        }
+        fields.explicit(2, BIT_STRING)?;
+
+        let ticket = Reader::new(fields.expect(context(3))?).expect(application(1))?;
+        let mut ticket = Reader::new(Reader::new(ticket).expect(SEQUENCE)?);
+        ticket.explicit(0, INTEGER)?;
+        let realm = der::string(ticket.explicit(1, GENERAL_STRING)?)?;
+        let server = principal_name(ticket.explicit(2, SEQUENCE)?, realm)?;
+        let enc_part = EncryptedData::parse(ticket.explicit(3, SEQUENCE)?)?;
+
+        Ok(Self {
+            ticket: Ticket { server, enc_part },
+            authenticator: EncryptedData::parse(fields.explicit(4, SEQUENCE)?)?,
+        })
+    }
+}
+
+struct EncTicketPart {
+    session_key_type: i64,
+    session_key: Vec<u8>,
+    client: KerberosPrincipal,
+    authtime: DateTime<Utc>,
+    starttime: Option<DateTime<Utc>>,
+    endtime: DateTime<Utc>,
+}
+
+impl EncTicketPart {
+    fn parse(plaintext: &[u8]) -> Result<Self, CoreError> {
+        let mut fields = Reader::new(Reader::new(plaintext).expect(application(3))?);
+        let mut fields = Reader::new(fields.expect(SEQUENCE)?);
+
+        fields.explicit(0, BIT_STRING)?;
+        let mut key = Reader::new(fields.explicit(1, SEQUENCE)?);
+        let session_key_type = der::integer(key.explicit(0, INTEGER)?)?;
+        let session_key = key.explicit(1, OCTET_STRING)?.to_vec();
+        let realm = der::string(fields.explicit(2, GENERAL_STRING)?)?;
+        let client = principal_name(fields.explicit(3, SEQUENCE)?, realm)?;
+        fields.expect(context(4))?;
+        let authtime = der::time(fields.explicit(5, GENERALIZED_TIME)?)?;
+        let starttime = fields
+            .optional_explicit(6, GENERALIZED_TIME)?
+            .map(der::time)
+            .transpose()?;
+        let endtime = der::time(fields.explicit(7, GENERALIZED_TIME)?)?;
+
+        Ok(Self {
+            session_key_type,
+            session_key,
+            client,
+            authtime,
+            starttime,
+            endtime,
+        })
+    }
+}
+
+struct Authenticator {
+    client: KerberosPrincipal,
+    cusec: i64,
+    ctime: DateTime<Utc>,
+}
+
+impl Authenticator {
+    fn parse(plaintext: &[u8]) -> Result<Self, CoreError> {
+        let mut fields = Reader::new(Reader::new(plaintext).expect(application(2))?);
+        let mut fields = Reader::new(fields.expect(SEQUENCE)?);
+
+        fields.explicit(0, INTEGER)?;
+        let realm = der::string(fields.explicit(1, GENERAL_STRING)?)?;
+        let client = principal_name(fields.explicit(2, SEQUENCE)?, realm)?;
+        fields.optional(context(3))?;
+        let cusec = der::integer(fields.explicit(4, INTEGER)?)?;
+        let ctime = der::time(fields.explicit(5, GENERALIZED_TIME)?)?;
+
+        Ok(Self {
+            client,
+            cusec,
+            ctime,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+        let mut out = vec![tag];
+        match content.len() {
+            len @ 0..=0x7f => out.push(len as u8),
+            len @ 0x80..=0xff => out.extend([0x81, len as u8]),
+            len => out.extend([0x82, (len >> 8) as u8, len as u8]),
+        }
+        out.extend_from_slice(content);
+        out
+    }
+
+    fn seq(fields: &[Vec<u8>]) -> Vec<u8> {
+        tlv(SEQUENCE, &fields.concat())
+    }
+
+    fn field(n: u8, inner: Vec<u8>) -> Vec<u8> {
+        tlv(context(n), &inner)
+    }
+
+    fn int(value: u8) -> Vec<u8> {
+        tlv(INTEGER, &[value])
+    }
+
+    fn gstr(value: &str) -> Vec<u8> {
+        tlv(GENERAL_STRING, value.as_bytes())
+    }
+
+    fn gtime(value: DateTime<Utc>) -> Vec<u8> {
+        tlv(
+            GENERALIZED_TIME,
+            value.format("%Y%m%d%H%M%SZ").to_string().as_bytes(),
+        )
+    }
+
+    fn name(components: &[&str]) -> Vec<u8> {
+        seq(&[
+            field(0, int(1)),
+            field(
+                1,
+                seq(&components.iter().map(|c| gstr(c)).collect::<Vec<_>>()),
+            ),
+        ])
+    }
+
+    fn encrypted(etype: u8, kvno: u8, cipher: Vec<u8>) -> Vec<u8> {
+        seq(&[
+            field(0, int(etype)),
+            field(1, int(kvno)),
+            field(2, tlv(OCTET_STRING, &cipher)),
+        ])
+    }
+
+    fn config() -> KerberosConfig {
+        KerberosConfig {
+            kerberos_realm: "CORP.EXAMPLE.COM".to_string(),
+            server_principal: "HTTP/sso.example.com".to_string(),
+            keytab_path: String::new(),
+            ldap_provider_id: None,
+            allowed_clock_skew_seconds: 300,
+        }
+    }
+
+    /// A SPNEGO token as a browser would send it, for `jdoe@CORP.EXAMPLE.COM`.
+    fn negotiate_token(service_key: &[u8], now: DateTime<Utc>) -> Vec<u8> {
+        let session_key = [9u8; 32];
+        let enc_ticket_part = tlv(
+            application(3),
+            &seq(&[
+                field(0, tlv(BIT_STRING, &[0, 0, 0, 0, 0])),
+                field(
+                    1,
+                    seq(&[field(0, int(18)), field(1, tlv(OCTET_STRING, &session_key))]),
+                ),
+                field(2, gstr("CORP.EXAMPLE.COM")),
+                field(3, name(&["jdoe"])),
+                field(
+                    4,
+                    seq(&[field(0, int(1)), field(1, tlv(OCTET_STRING, b""))]),
+                ),
+                field(5, gtime(now - Duration::minutes(5))),
+                field(7, gtime(now + Duration::hours(8))),
+            ]),
+        );
+        let authenticator = tlv(
+            application(2),
+            &seq(&[
+                field(0, int(5)),
+                field(1, gstr("CORP.EXAMPLE.COM")),
+                field(2, name(&["jdoe"])),
+                field(4, int(42)),
+                field(5, gtime(now)),
+            ]),
+        );
+
+        let ticket = tlv(
+            application(1),
+            &seq(&[
+                field(0, int(5)),
+                field(1, gstr("CORP.EXAMPLE.COM")),
+                field(2, name(&["HTTP", "sso.example.com"])),
+                field(
+                    3,
+                    encrypted(
+                        18,
+                        3,
+                        crypto::encrypt(
+                            EncType::Aes256CtsHmacSha196,
+                            service_key,
+                            USAGE_TICKET,
+                            [3u8; 16],
+                            &enc_ticket_part,
+                        )
+                        .unwrap(),
+                    ),
+                ),
+            ]),
+        );
+        let ap_req = tlv(
+            application(14),
+            &seq(&[
+                field(0, int(5)),
+                field(1, int(14)),
+                field(2, tlv(BIT_STRING, &[0, 0, 0, 0, 0])),
+                field(3, ticket),
+                field(
+                    4,
+                    encrypted(
+                        18,
+                        0,
+                        crypto::encrypt(
+                            EncType::Aes256CtsHmacSha196,
+                            &session_key,
+                            USAGE_AP_REQ_AUTHENTICATOR,
+                            [4u8; 16],
+                            &authenticator,
+                        )
+                        .unwrap(),
+                    ),
+                ),
+            ]),
+        );
+
+        let mut krb5_token = tlv(OID, KRB5_OID);
+        krb5_token.extend_from_slice(KRB5_AP_REQ_TOKEN_ID);
+        krb5_token.extend(ap_req);
+        let krb5_token = tlv(application(0), &krb5_token);
+
+        let neg_token_init = field(
+            0,
+            seq(&[
+                field(0, seq(&[tlv(OID, MS_KRB5_OID), tlv(OID, KRB5_OID)])),
+                field(2, tlv(OCTET_STRING, &krb5_token)),
+            ]),
+        );
+        tlv(
+            application(0),
+            &[tlv(OID, SPNEGO_OID), neg_token_init].concat(),
+        )
+    }
+
+    #[test]
+    fn accepts_a_spnego_token_once() {
+        let service_key = [5u8; 32];
+        let keytab = vec![KeytabEntry {
+            principal: KerberosPrincipal::parse("HTTP/sso.example.com@CORP.EXAMPLE.COM", ""),
+            kvno: 3,
+            enctype: 18,
+            key: service_key.to_vec(),
+        }];
+        let now = Utc::now();
+        let token = negotiate_token(&service_key, now);
+        let acceptor = SpnegoAcceptorImpl::new();
+
+        let principal = acceptor
+            .accept_with_keytab(&config(), &keytab, &token, now)
+            .unwrap();
+        assert_eq!(principal.to_string(), "jdoe@CORP.EXAMPLE.COM");
+        assert_eq!(principal.username(), Some("jdoe"));
+
+        assert!(
+            acceptor
+                .accept_with_keytab(&config(), &keytab, &token, now)
+                .is_err(),
+            "replayed authenticator must be rejected"
+        );
+
+        let wrong_key = vec![KeytabEntry {
+            key: vec![6u8; 32],
+            ..keytab[0].clone()
+        }];
+        assert!(
+            SpnegoAcceptorImpl::new()
+                .accept_with_keytab(&config(), &wrong_key, &token, now)
+                .is_err()
+        );
+        assert!(
+            SpnegoAcceptorImpl::new()
+                .accept_with_keytab(&config(), &keytab, &token, now + Duration::hours(1))
+                .is_err(),
+            "stale authenticator must be rejected"
+        );
+    }
+
+    fn keytab_file(key: &[u8]) -> Vec<u8> {
+        let counted = |bytes: &[u8]| [&(bytes.len() as u16).to_be_bytes()[..], bytes].concat();
+        let entry = [
+            &2u16.to_be_bytes()[..],
+            &counted(b"CORP.EXAMPLE.COM"),
+            &counted(b"HTTP"),
+            &counted(b"sso.example.com"),
+            &1u32.to_be_bytes(),
+            &0u32.to_be_bytes(),
+            &[3],
+            &18u16.to_be_bytes(),
+            &counted(key),
+        ]
+        .concat();
+        [
+            &[0x05, 0x02][..],
+            &(entry.len() as u32).to_be_bytes(),
+            &entry,
+        ]
+        .concat()
+    }
+
+    #[tokio::test]
+    async fn reads_the_keytab_again_only_when_it_changes() {
+        let path = std::env::temp_dir().join(format!("ferriskey-{}.keytab", uuid::Uuid::new_v4()));
+        let path_str = path.to_str().unwrap();
+        std::fs::write(&path, keytab_file(&[5u8; 32])).unwrap();
+        let acceptor = SpnegoAcceptorImpl::new();
+
+        let first = acceptor.keytab(path_str).await.unwrap();
+        assert_eq!(first[0].key, vec![5u8; 32]);
+        assert!(Arc::ptr_eq(
+            &first,
+            &acceptor.keytab(path_str).await.unwrap()
+        ));
+
+        std::fs::write(&path, keytab_file(&[6u8; 16])).unwrap();
+        let rotated = acceptor.keytab(path_str).await.unwrap();
+        assert_eq!(rotated[0].key, vec![6u8; 16]);
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(acceptor.keytab(path_str).await.is_err());
+    }
+}
@@ -1,28 +1,38 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
-use ldap3::{Ldap, LdapConnAsync, LdapConnSettings, Scope, SearchEntry, SearchResult};
+use ldap3::adapters::{Adapter, EntriesOnly, PagedResults};
+use ldap3::{
+    Ldap, LdapConnAsync, LdapConnSettings, Mod, Scope, SearchEntry, SearchResult, ldap_escape,
+};
 use serde::Deserialize;
 use tokio::time::timeout;
 use tracing::{info, instrument, warn};
 
-use crate::domain::abyss::federation::entities::{FederatedUser, FederationProvider};
+use crate::domain::abyss::federation::entities::{
+    FederatedGroup, FederatedUser, FederationProvider, FederationType,
+};
+use crate::domain::abyss::federation::group_mapping::GroupMapperSettings;
+use crate::domain::abyss::federation::ports::{FederationCredentialDirectory, FederationDirectory};
 use crate::domain::abyss::federation::value_objects::TestConnectionResult;
 use crate::domain::common::entities::app_errors::CoreError;
 
+/// Page size for RFC 2696 paged searches. Active Directory caps pages at 1000 (`MaxPageSize`).
+const DEFAULT_PAGE_SIZE: i32 = 500;
+
 #[derive(Clone, Debug)]
 #[allow(dead_code)]
 pub struct LdapClientImpl;
 
 #[derive(Deserialize, Debug)]
 #[allow(dead_code)]
-struct LdapConfig {
+pub(super) struct LdapConfig {
     connection: LdapConnection,
     bind: LdapBind,
-    search: LdapSearch,
-    attributes: LdapAttributes,
+    pub(super) search: LdapSearch,
+    pub(super) attributes: LdapAttributes,
 }
 
 #[derive(Deserialize, Debug)]
@@ -45,14 +55,64 @@ struct LdapBind {
 
 #[derive(Deserialize, Debug)]
 #[allow(dead_code)]
-struct LdapSearch {
-    base_dn: String,
-    user_search_filter: String,
+pub(super) struct LdapSearch {
+    pub(super) base_dn: String,
+    pub(super) user_search_filter: String,
+    #[serde(default)]
+    pub(super) page_size: Option<i32>,
+    /// Attribute compared against the last sync's marker for changed-since syncs. Defaults to
+    /// `uSNChanged` for Active Directory and `modifyTimestamp` otherwise.
+    #[serde(default)]
+    pub(super) change_tracking_attribute: Option<String>,
+}
+
+impl LdapSearch {
+    pub(super) fn page_size(&self) -> i32 {
+        self.page_size
+            .filter(|size| *size > 0)
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+
+    pub(super) fn change_tracking_attribute(&self, provider: &FederationProvider) -> String {
+        self.change_tracking_attribute
+            .clone()
+            .unwrap_or_else(|| match provider.provider_type {
+                FederationType::ActiveDirectory => "uSNChanged".to_string(),
+                _ => "modifyTimestamp".to_string(),
+            })
+    }
+
+    /// The user filter matching every user, narrowed to entries changed at or after `marker`.
+    pub(super) fn user_filter(
+        &self,
+        provider: &FederationProvider,
+        changed_since: Option<&str>,
+    ) -> String {
+        let filter = self
+            .user_search_filter
+            .replace("{0}", "*")
+            .replace("{username}", "*");
+        let filter = if filter.starts_with('(') {
+            filter
+        } else {
+            format!("({})", filter)
+        };
+
+        match changed_since {
+            Some(marker) => format!(
+                "(&{}({}>={}))",
+                filter,
+                self.change_tracking_attribute(provider),
+                ldap_escape(marker)
+            ),
+            None => filter,
+        }
+    }
 }
 
 #[derive(Deserialize, Debug)]
 #[allow(dead_code)]
-struct LdapAttributes {
+pub(super) struct LdapAttributes {
     username: String,
     email: String,
     first_name: String,
@@ -65,7 +125,7 @@ struct LdapAttributes {
 
 impl LdapClientImpl {
     #[allow(dead_code)]
-    fn parse_config(provider: &FederationProvider) -> Result<LdapConfig, CoreError> {
+    pub(super) fn parse_config(provider: &FederationProvider) -> Result<LdapConfig, CoreError> {
         serde_json::from_value(provider.config.clone())
             .map_err(|e| CoreError::Configuration(format!("Invalid LDAP config: {}", e)))
     }
@@ -281,41 +341,107 @@ impl LdapClientImpl {
         Ok(federated_user)
     }
 
+    /// Run a subtree search with RFC 2696 paged results, so directories holding more entries
+    /// than the server's size limit are read completely.
+    async fn paged_search(
+        ldap: &mut Ldap,
+        base: &str,
+        filter: &str,
+        attrs: Vec<&str>,
+        page_size: i32,
+    ) -> Result<Vec<SearchEntry>, CoreError> {
+        let adapters: Vec<Box<dyn Adapter<_, _>>> = vec![
+            Box::new(EntriesOnly::new()),
+            Box::new(PagedResults::new(page_size)),
+        ];
+
+        let mut stream = ldap
+            .streaming_search_with(adapters, base, Scope::Subtree, filter, attrs)
+            .await
+            .map_err(|e| CoreError::External(format!("LDAP Search failed: {}", e)))?;
+
+        let mut entries = Vec::new();
+        while let Some(entry) = stream
+            .next()
+            .await
+            .map_err(|e| CoreError::External(format!("LDAP Search failed: {}", e)))?
+        {
+            entries.push(SearchEntry::construct(entry));
+        }
+
+        stream
+            .finish()
+            .await
+            .success()
+            .map_err(|e| CoreError::External(format!("LDAP Search failed: {}", e)))?;
+
+        Ok(entries)
+    }
+
     #[instrument(skip(self, provider))]
-    #[allow(dead_code)]
     pub async fn search_users(
         &self,
         provider: &FederationProvider,
-        filter_override: Option<&str>,
+        changed_since: Option<&str>,
     ) -> Result<Vec<FederatedUser>, CoreError> {
         let mut ldap = self.bind(provider).await?;
         let config = Self::parse_config(provider)?;
 
-        let filter = filter_override
-            .unwrap_or(&config.search.user_search_filter)
-            .replace("{0}", "*")
-            .replace("{username}", "*");
+        let filter = config.search.user_filter(provider, changed_since);
+        let tracking = config.search.change_tracking_attribute(provider);
+
+        // memberOf and the change-tracking attributes are operational on OpenLDAP and aren't
+        // returned by "*" alone.
+        let entries = Self::paged_search(
+            &mut ldap,
+            &config.search.base_dn,
+            &filter,
+            vec!["*", "memberOf", tracking.as_str()],
+            config.search.page_size(),
+        )
+        .await;
+        let _ = ldap.unbind().await;
 
-        let SearchResult(rs, _res) = ldap
-            .search(&config.search.base_dn, Scope::Subtree, &filter, vec!["*"])
-            .await
-            .map_err(|e| CoreError::External(format!("LDAP Search failed: {}", e)))?;
+        Ok(entries?
+            .iter()
+            .filter_map(|entry| {
+                self.map_entry_to_user(entry, &config.attributes)
+                    .ok()
+                    .map(|user| with_change_marker(user, entry, &tracking))
+            })
+            .collect())
+    }
 
-        let mut users = Vec::new();
-        for entry in rs {
-            let search_entry = SearchEntry::construct(entry);
-            if let Ok(user) = self.map_entry_to_user(&search_entry, &config.attributes) {
-                users.push(user);
-            }
-        }
+    #[instrument(skip(self, provider, mapper))]
+    pub async fn search_groups(
+        &self,
+        provider: &FederationProvider,
+        mapper: &GroupMapperSettings,
+    ) -> Result<Vec<FederatedGroup>, CoreError> {
+        let mut ldap = self.bind(provider).await?;
+        let config = Self::parse_config(provider)?;
 
+        let entries = Self::paged_search(
+            &mut ldap,
+            &mapper.group_search_base,
+            &mapper.group_filter,
+            vec![
+                mapper.group_name_attribute.as_str(),
+                mapper.membership_attribute.as_str(),
+                mapper.member_of_attribute.as_str(),
+            ],
+            config.search.page_size(),
+        )
+        .await;
         let _ = ldap.unbind().await;
 
-        Ok(users)
+        Ok(entries?
+            .iter()
+            .filter_map(|entry| Self::map_entry_to_group(entry, mapper))
+            .collect())
     }
 
     #[instrument(skip(self, provider))]
-    #[allow(dead_code)]
     pub async fn get_user_by_username(
         &self,
         provider: &FederationProvider,
@@ -346,6 +472,53 @@ impl LdapClientImpl {
         Ok(user)
     }
 
+    /// Replace a user's password with a `modify` bound as the service account. Active
+    /// Directory only accepts `unicodePwd` over an encrypted connection, as the quoted password
+    /// encoded in UTF-16LE.
+    #[instrument(skip(self, provider, new_password))]
+    pub async fn update_password(
+        &self,
+        provider: &FederationProvider,
+        username: &str,
+        new_password: &str,
+    ) -> Result<(), CoreError> {
+        let user = self
+            .get_user_by_username(provider, username)
+            .await?
+            .ok_or_else(|| CoreError::External(format!("User {} not found in LDAP", username)))?;
+
+        let (attribute, value) = match provider.provider_type {
+            FederationType::ActiveDirectory => (
+                "unicodePwd",
+                format!("\"{}\"", new_password)
+                    .encode_utf16()
+                    .flat_map(u16::to_le_bytes)
+                    .collect::<Vec<u8>>(),
+            ),
+            _ => ("userPassword", new_password.as_bytes().to_vec()),
+        };
+
+        let mut ldap = self.bind(provider).await?;
+        let result = ldap
+            .modify(
+                &user.dn,
+                vec![Mod::Replace(
+                    attribute.as_bytes().to_vec(),
+                    HashSet::from([value]),
+                )],
+            )
+            .await
+            .map_err(|e| CoreError::External(format!("LDAP Modify failed: {}", e)))?;
+        let _ = ldap.unbind().await;
+
+        result.success().map_err(|e| {
+            warn!("LDAP password update rejected for {}: {}", username, e);
+            CoreError::External(format!("LDAP password update rejected: {}", e))
+        })?;
+
+        Ok(())
+    }
+
     #[instrument(skip(self, provider))]
     #[allow(dead_code)]
     pub async fn test_connection(
@@ -420,7 +593,7 @@ impl LdapClientImpl {
     }
 
     #[allow(dead_code)]
-    fn map_entry_to_user(
+    pub(super) fn map_entry_to_user(
         &self,
         entry: &SearchEntry,
         attributes: &LdapAttributes,
@@ -463,11 +636,109 @@ impl LdapClientImpl {
 
         Ok(FederatedUser {
             external_id,
+            dn: entry.dn.clone(),
             username,
             email,
             first_name,
             last_name,
             attributes: Some(all_attributes),
+            change_marker: None,
         })
     }
+
+    /// Map a group entry, skipping entries without the configured name attribute.
+    pub(super) fn map_entry_to_group(
+        entry: &SearchEntry,
+        mapper: &GroupMapperSettings,
+    ) -> Option<FederatedGroup> {
+        let name = entry
+            .attrs
+            .get(&mapper.group_name_attribute)
+            .and_then(|vals| vals.first())
+            .cloned()?;
+
+        Some(FederatedGroup {
+            dn: entry.dn.clone(),
+            name,
+            members: entry
+                .attrs
+                .get(&mapper.membership_attribute)
+                .cloned()
+                .unwrap_or_default(),
+            member_of: entry
+                .attrs
+                .get(&mapper.member_of_attribute)
+                .cloned()
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl FederationDirectory for LdapClientImpl {
+    async fn test_connection(
+        &self,
+        provider: &FederationProvider,
+    ) -> Result<TestConnectionResult, CoreError> {
+        LdapClientImpl::test_connection(self, provider).await
+    }
+
+    async fn search_users(
+        &self,
+        provider: &FederationProvider,
+        changed_since: Option<&str>,
+    ) -> Result<Vec<FederatedUser>, CoreError> {
+        LdapClientImpl::search_users(self, provider, changed_since).await
+    }
+
+    async fn search_groups(
+        &self,
+        provider: &FederationProvider,
+        mapper: &GroupMapperSettings,
+    ) -> Result<Vec<FederatedGroup>, CoreError> {
+        LdapClientImpl::search_groups(self, provider, mapper).await
+    }
+}
+
+impl FederationCredentialDirectory for LdapClientImpl {
+    async fn authenticate(
+        &self,
+        provider: &FederationProvider,
+        username: &str,
+        password: &str,
+    ) -> Result<FederatedUser, CoreError> {
+        self.authenticate_user(provider, username, password).await
+    }
+
+    async fn find_user(
+        &self,
+        provider: &FederationProvider,
+        username: &str,
+    ) -> Result<Option<FederatedUser>, CoreError> {
+        self.get_user_by_username(provider, username).await
+    }
+
+    async fn update_password(
+        &self,
+        provider: &FederationProvider,
+        username: &str,
+        new_password: &str,
+    ) -> Result<(), CoreError> {
+        LdapClientImpl::update_password(self, provider, username, new_password).await
+    }
+}
+
+/// Attach the entry's change-tracking value, looked up case-insensitively since servers
+/// return operational attribute names in their own casing.
+pub(super) fn with_change_marker(
+    mut user: FederatedUser,
+    entry: &SearchEntry,
+    attribute: &str,
+) -> FederatedUser {
+    user.change_marker = entry
+        .attrs
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(attribute))
+        .and_then(|(_, values)| values.first())
+        .cloned();
+    user
 }
@@ -1,2 +1,6 @@
+#[cfg(test)]
+pub mod in_memory;
+pub mod kerberos;
 pub mod ldap;
 pub mod repository;
+pub mod sync_repository;
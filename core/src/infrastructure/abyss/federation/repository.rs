@@ -1,13 +1,13 @@
 use chrono::Utc;
 use sea_orm::*;
-use serde::Deserialize;
 use tracing::error;
 use uuid::Uuid;
 
 use crate::domain::abyss::federation::entities::{
-    FederationMapping, FederationProvider, FederationType, SyncMode,
+    FederationMapping, FederationProvider, FederationType,
 };
 use crate::domain::abyss::federation::ports::FederationRepository;
+use crate::domain::abyss::federation::schedule::SyncSettings;
 use crate::domain::abyss::federation::value_objects::{
     CreateProviderRequest, UpdateProviderRequest,
 };
@@ -20,13 +20,6 @@ pub struct FederationRepositoryImpl {
     db: DatabaseConnection,
 }
 
-#[derive(Deserialize)]
-struct SyncSettings {
-    enabled: bool,
-    mode: SyncMode,
-    interval_minutes: Option<i32>,
-}
-
 impl FederationRepositoryImpl {
     #[allow(dead_code)]
     pub fn new(db: DatabaseConnection) -> Self {
@@ -58,6 +51,8 @@ impl TryFrom<user_federation_providers::Model> for FederationProvider {
                 "enabled": model.sync_enabled,
                 "mode": model.sync_mode,
                 "interval_minutes": model.sync_interval_minutes,
+                "full_sync_cron": model.full_sync_cron,
+                "changed_sync_interval_minutes": model.changed_sync_interval_minutes,
                 "last_sync_at": model.last_sync_at,
                 "last_sync_status": model.last_sync_status,
                 "last_sync_result": model.last_sync_result
@@ -89,6 +84,8 @@ impl TryFrom<CreateProviderRequest> for user_federation_providers::ActiveModel {
             sync_enabled: Set(sync_settings.enabled),
             sync_mode: Set(sync_settings.mode.to_string()),
             sync_interval_minutes: Set(sync_settings.interval_minutes),
+            full_sync_cron: Set(sync_settings.full_sync_cron),
+            changed_sync_interval_minutes: Set(sync_settings.changed_sync_interval_minutes),
             last_sync_at: Set(None),
             last_sync_status: Set(None),
             last_sync_result: Set(None),
@@ -195,6 +192,9 @@ impl FederationRepository for FederationRepositoryImpl {
             active_model.sync_enabled = Set(sync_settings.enabled);
             active_model.sync_mode = Set(sync_settings.mode.to_string());
             active_model.sync_interval_minutes = Set(sync_settings.interval_minutes);
+            active_model.full_sync_cron = Set(sync_settings.full_sync_cron);
+            active_model.changed_sync_interval_minutes =
+                Set(sync_settings.changed_sync_interval_minutes);
         }
 
         active_model.updated_at = Set(now.into());
@@ -229,6 +229,20 @@ impl FederationRepository for FederationRepositoryImpl {
         models.into_iter().map(|m| m.try_into()).collect()
     }
 
+    async fn list_sync_enabled(&self) -> Result<Vec<FederationProvider>, CoreError> {
+        let models = user_federation_providers::Entity::find()
+            .filter(user_federation_providers::Column::Enabled.eq(true))
+            .filter(user_federation_providers::Column::SyncEnabled.eq(true))
+            .order_by_asc(user_federation_providers::Column::Priority)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                CoreError::Database(format!("Failed to list sync-enabled providers: {}", e))
+            })?;
+
+        models.into_iter().map(|m| m.try_into()).collect()
+    }
+
     async fn create_mapping(
         &self,
         mapping: FederationMapping,
@@ -0,0 +1,234 @@
+use std::str::FromStr;
+
+use chrono::{Duration, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use uuid::Uuid;
+
+use crate::domain::abyss::federation::entities::{
+    FederationSyncRun, SyncKind, SyncMode, SyncStatus, SyncTrigger,
+};
+use crate::domain::abyss::federation::ports::FederationSyncRepository;
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::{user_federation_providers, user_federation_sync_runs};
+
+#[derive(Clone, Debug)]
+pub struct FederationSyncRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl FederationSyncRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn parse_column<T: FromStr<Err = String>>(value: &str) -> Result<T, CoreError> {
+    value
+        .parse()
+        .map_err(|e: String| CoreError::Database(format!("Invalid sync run row: {}", e)))
+}
+
+impl TryFrom<user_federation_sync_runs::Model> for FederationSyncRun {
+    type Error = CoreError;
+
+    fn try_from(model: user_federation_sync_runs::Model) -> Result<Self, Self::Error> {
+        Ok(FederationSyncRun {
+            id: model.id,
+            provider_id: model.provider_id,
+            kind: parse_column::<SyncKind>(&model.kind)?,
+            trigger: parse_column::<SyncTrigger>(&model.triggered_by)?,
+            mode: parse_column::<SyncMode>(&model.mode)?,
+            status: parse_column::<SyncStatus>(&model.status)?,
+            started_at: model.started_at.into(),
+            completed_at: model.completed_at.map(Into::into),
+            total_processed: model.total_processed as u32,
+            created: model.created as u32,
+            updated: model.updated as u32,
+            disabled: model.disabled as u32,
+            failed: model.failed as u32,
+            groups_added: model.groups_added as u32,
+            groups_removed: model.groups_removed as u32,
+            errors: serde_json::from_value(model.errors).unwrap_or_default(),
+            change_marker: model.change_marker,
+        })
+    }
+}
+
+impl From<&FederationSyncRun> for user_federation_sync_runs::ActiveModel {
+    fn from(run: &FederationSyncRun) -> Self {
+        user_federation_sync_runs::ActiveModel {
+            id: Set(run.id),
+            provider_id: Set(run.provider_id),
+            kind: Set(run.kind.to_string()),
+            triggered_by: Set(run.trigger.to_string()),
+            mode: Set(run.mode.to_string()),
+            status: Set(run.status.to_string()),
+            started_at: Set(run.started_at.into()),
+            completed_at: Set(run.completed_at.map(Into::into)),
+            total_processed: Set(run.total_processed as i32),
+            created: Set(run.created as i32),
+            updated: Set(run.updated as i32),
+            disabled: Set(run.disabled as i32),
+            failed: Set(run.failed as i32),
+            groups_added: Set(run.groups_added as i32),
+            groups_removed: Set(run.groups_removed as i32),
+            errors: Set(serde_json::to_value(&run.errors).unwrap_or_default()),
+            change_marker: Set(run.change_marker.clone()),
+        }
+    }
+}
+
+impl FederationSyncRepository for FederationSyncRepositoryImpl {
+    async fn try_acquire_lock(
+        &self,
+        provider_id: Uuid,
+        holder: &str,
+        ttl: Duration,
+    ) -> Result<bool, CoreError> {
+        // Expiry is computed by the database so replicas with skewed clocks agree on it.
+        let row = self
+            .db
+            .query_one(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                r#"
+                INSERT INTO user_federation_sync_locks (provider_id, holder, expires_at)
+                VALUES ($1, $2, NOW() + make_interval(secs => $3))
+                ON CONFLICT (provider_id) DO UPDATE
+                    SET holder = EXCLUDED.holder, expires_at = EXCLUDED.expires_at
+                    WHERE user_federation_sync_locks.holder = EXCLUDED.holder
+                       OR user_federation_sync_locks.expires_at < NOW()
+                RETURNING provider_id
+                "#,
+                [
+                    provider_id.into(),
+                    holder.into(),
+                    (ttl.num_seconds() as f64).into(),
+                ],
+            ))
+            .await
+            .map_err(|e| CoreError::Database(format!("Failed to acquire sync lock: {}", e)))?;
+
+        Ok(row.is_some())
+    }
+
+    async fn release_lock(&self, provider_id: Uuid, holder: &str) -> Result<(), CoreError> {
+        self.db
+            .execute(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                "DELETE FROM user_federation_sync_locks WHERE provider_id = $1 AND holder = $2",
+                [provider_id.into(), holder.into()],
+            ))
+            .await
+            .map_err(|e| CoreError::Database(format!("Failed to release sync lock: {}", e)))?;
+        Ok(())
+    }
+
+    async fn create_run(&self, run: FederationSyncRun) -> Result<FederationSyncRun, CoreError> {
+        let model = user_federation_sync_runs::ActiveModel::from(&run)
+            .insert(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(format!("Failed to create sync run: {}", e)))?;
+
+        model.try_into()
+    }
+
+    async fn complete_run(&self, run: FederationSyncRun) -> Result<FederationSyncRun, CoreError> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| CoreError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let mut active_model = user_federation_sync_runs::ActiveModel::from(&run);
+        active_model.id = Unchanged(run.id);
+        let model = active_model
+            .update(&txn)
+            .await
+            .map_err(|e| CoreError::Database(format!("Failed to complete sync run: {}", e)))?;
+
+        user_federation_providers::Entity::update_many()
+            .col_expr(
+                user_federation_providers::Column::LastSyncAt,
+                Expr::value(run.completed_at.unwrap_or_else(Utc::now)),
+            )
+            .col_expr(
+                user_federation_providers::Column::LastSyncStatus,
+                Expr::value(run.status.to_string()),
+            )
+            .col_expr(
+                user_federation_providers::Column::LastSyncResult,
+                Expr::value(serde_json::json!({
+                    "run_id": run.id,
+                    "kind": run.kind,
+                    "total_processed": run.total_processed,
+                    "created": run.created,
+                    "updated": run.updated,
+                    "disabled": run.disabled,
+                    "failed": run.failed,
+                })),
+            )
+            .filter(user_federation_providers::Column::Id.eq(run.provider_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| CoreError::Database(format!("Failed to update provider: {}", e)))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| CoreError::Database(format!("Failed to commit sync run: {}", e)))?;
+
+        model.try_into()
+    }
+
+    async fn list_runs(
+        &self,
+        provider_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<FederationSyncRun>, CoreError> {
+        let models = user_federation_sync_runs::Entity::find()
+            .filter(user_federation_sync_runs::Column::ProviderId.eq(provider_id))
+            .order_by_desc(user_federation_sync_runs::Column::StartedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(format!("Failed to list sync runs: {}", e)))?;
+
+        models.into_iter().map(|m| m.try_into()).collect()
+    }
+
+    async fn latest_run(
+        &self,
+        provider_id: Uuid,
+        kind: Option<SyncKind>,
+    ) -> Result<Option<FederationSyncRun>, CoreError> {
+        let mut query = user_federation_sync_runs::Entity::find()
+            .filter(user_federation_sync_runs::Column::ProviderId.eq(provider_id));
+        if let Some(kind) = kind {
+            query = query.filter(user_federation_sync_runs::Column::Kind.eq(kind.to_string()));
+        }
+
+        let model = query
+            .order_by_desc(user_federation_sync_runs::Column::StartedAt)
+            .one(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(format!("Failed to get latest sync run: {}", e)))?;
+
+        model.map(TryInto::try_into).transpose()
+    }
+
+    async fn latest_change_marker(&self, provider_id: Uuid) -> Result<Option<String>, CoreError> {
+        let model = user_federation_sync_runs::Entity::find()
+            .filter(user_federation_sync_runs::Column::ProviderId.eq(provider_id))
+            .filter(user_federation_sync_runs::Column::ChangeMarker.is_not_null())
+            .filter(user_federation_sync_runs::Column::Status.is_in([
+                SyncStatus::Success.to_string(),
+                SyncStatus::PartialSuccess.to_string(),
+            ]))
+            .order_by_desc(user_federation_sync_runs::Column::StartedAt)
+            .one(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(format!("Failed to get change marker: {}", e)))?;
+
+        Ok(model.and_then(|m| m.change_marker))
+    }
+}
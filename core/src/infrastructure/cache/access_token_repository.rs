@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::jwt::{JwtError, entities::AccessToken, ports::AccessTokenRepository};
+use crate::domain::realm::entities::RealmId;
+use crate::infrastructure::cache::RepositoryCaches;
+
+/// Caches access token records looked up during introspection and userinfo calls.
+///
+/// Entries live for a short TTL only; revocations made here drop the affected entries at
+/// once and reach the other replicas through the `access_tokens` trigger.
+#[derive(Debug, Clone)]
+pub struct CachedAccessTokenRepository<A> {
+    inner: A,
+    caches: Arc<RepositoryCaches>,
+}
+
+impl<A> CachedAccessTokenRepository<A> {
+    pub fn new(inner: A, caches: Arc<RepositoryCaches>) -> Self {
+        Self { inner, caches }
+    }
+}
+
+impl<A> AccessTokenRepository for CachedAccessTokenRepository<A>
+where
+    A: AccessTokenRepository,
+{
+    async fn create(
+        &self,
+        token_hash: String,
+        jti: Option<Uuid>,
+        user_id: Uuid,
+        realm_id: RealmId,
+        expires_at: Option<DateTime<Utc>>,
+        claims: serde_json::Value,
+    ) -> Result<AccessToken, JwtError> {
+        self.inner
+            .create(token_hash, jti, user_id, realm_id, expires_at, claims)
+            .await
+    }
+
+    async fn get_by_token_hash(&self, token_hash: String) -> Result<Option<AccessToken>, JwtError> {
+        self.caches
+            .access_tokens
+            .get_or_try_load_optional(token_hash.clone(), self.inner.get_by_token_hash(token_hash))
+            .await
+    }
+
+    async fn revoke_by_token_hash(&self, token_hash: String) -> Result<(), JwtError> {
+        let result = self.inner.revoke_by_token_hash(token_hash.clone()).await;
+        self.caches.access_tokens.invalidate(&token_hash);
+        result
+    }
+
+    async fn revoke_by_session_id(&self, session_id: Uuid) -> Result<u64, JwtError> {
+        let result = self.inner.revoke_by_session_id(session_id).await;
+        self.caches.access_tokens.clear();
+        result
+    }
+
+    async fn revoke_all_for_user(&self, user_id: Uuid) -> Result<u64, JwtError> {
+        let result = self.inner.revoke_all_for_user(user_id).await;
+        self.caches.access_tokens.clear();
+        result
+    }
+}
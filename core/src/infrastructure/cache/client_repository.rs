@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::client::{
+    entities::Client,
+    ports::ClientRepository,
+    value_objects::{CreateClientRequest, UpdateClientRequest},
+};
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+use crate::infrastructure::cache::RepositoryCaches;
+
+/// Caches single-client lookups, which carry the redirect URIs checked on every
+/// authorization request.
+#[derive(Debug, Clone)]
+pub struct CachedClientRepository<C> {
+    inner: C,
+    caches: Arc<RepositoryCaches>,
+}
+
+impl<C> CachedClientRepository<C> {
+    pub fn new(inner: C, caches: Arc<RepositoryCaches>) -> Self {
+        Self { inner, caches }
+    }
+}
+
+impl<C> ClientRepository for CachedClientRepository<C>
+where
+    C: ClientRepository,
+{
+    async fn create_client(&self, data: CreateClientRequest) -> Result<Client, CoreError> {
+        self.inner.create_client(data).await
+    }
+
+    async fn get_by_client_id(
+        &self,
+        client_id: String,
+        realm_id: RealmId,
+    ) -> Result<Client, CoreError> {
+        let key = (realm_id.into(), client_id.clone());
+        self.caches
+            .clients_by_client_id
+            .get_or_try_load(key, self.inner.get_by_client_id(client_id, realm_id))
+            .await
+    }
+
+    async fn get_by_id(&self, realm_id: RealmId, id: Uuid) -> Result<Client, CoreError> {
+        self.caches
+            .clients_by_id
+            .get_or_try_load((realm_id.into(), id), self.inner.get_by_id(realm_id, id))
+            .await
+    }
+
+    async fn get_by_realm_id(&self, realm_id: RealmId) -> Result<Vec<Client>, CoreError> {
+        self.inner.get_by_realm_id(realm_id).await
+    }
+
+    async fn update_client(
+        &self,
+        realm_id: RealmId,
+        client_id: Uuid,
+        data: UpdateClientRequest,
+    ) -> Result<Client, CoreError> {
+        let result = self.inner.update_client(realm_id, client_id, data).await;
+        self.caches.clear_clients();
+        result
+    }
+
+    async fn update_secret(
+        &self,
+        realm_id: RealmId,
+        client_id: Uuid,
+        secret: String,
+    ) -> Result<Client, CoreError> {
+        let result = self.inner.update_secret(realm_id, client_id, secret).await;
+        self.caches.clear_clients();
+        result
+    }
+
+    async fn delete_by_id(&self, realm_id: RealmId, id: Uuid) -> Result<(), CoreError> {
+        let result = self.inner.delete_by_id(realm_id, id).await;
+        self.caches.clear_clients();
+        result
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::aegis::entities::ClientScope;
+use crate::domain::aegis::ports::ClientScopeRepository;
+use crate::domain::aegis::value_objects::{CreateClientScopeRequest, UpdateClientScopeRequest};
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+use crate::infrastructure::cache::RepositoryCaches;
+
+/// Reads go straight to the database; writes drop the per-client scope lists cached by
+/// [`super::scope_mapping_repository::CachedScopeMappingRepository`], which embed the scopes.
+#[derive(Debug, Clone)]
+pub struct CachedClientScopeRepository<S> {
+    inner: S,
+    caches: Arc<RepositoryCaches>,
+}
+
+impl<S> CachedClientScopeRepository<S> {
+    pub fn new(inner: S, caches: Arc<RepositoryCaches>) -> Self {
+        Self { inner, caches }
+    }
+}
+
+impl<S> ClientScopeRepository for CachedClientScopeRepository<S>
+where
+    S: ClientScopeRepository,
+{
+    async fn create(&self, payload: CreateClientScopeRequest) -> Result<ClientScope, CoreError> {
+        self.inner.create(payload).await
+    }
+
+    async fn get_by_id(
+        &self,
+        realm_id: RealmId,
+        id: Uuid,
+    ) -> Result<Option<ClientScope>, CoreError> {
+        self.inner.get_by_id(realm_id, id).await
+    }
+
+    async fn find_by_realm_id(&self, realm_id: RealmId) -> Result<Vec<ClientScope>, CoreError> {
+        self.inner.find_by_realm_id(realm_id).await
+    }
+
+    async fn find_by_name(
+        &self,
+        name: String,
+        realm_id: RealmId,
+    ) -> Result<Option<ClientScope>, CoreError> {
+        self.inner.find_by_name(name, realm_id).await
+    }
+
+    async fn update_by_id(
+        &self,
+        realm_id: RealmId,
+        id: Uuid,
+        payload: UpdateClientScopeRequest,
+    ) -> Result<ClientScope, CoreError> {
+        let result = self.inner.update_by_id(realm_id, id, payload).await;
+        self.caches.client_scopes.clear();
+        result
+    }
+
+    async fn delete_by_id(&self, realm_id: RealmId, id: Uuid) -> Result<(), CoreError> {
+        let result = self.inner.delete_by_id(realm_id, id).await;
+        self.caches.client_scopes.clear();
+        self.caches.protocol_mappers.invalidate(&id);
+        result
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use sea_orm::DatabaseConnection;
+use sqlx::postgres::PgListener;
+use tracing::{info, warn};
+
+use crate::infrastructure::cache::RepositoryCaches;
+
+/// Channel the `ferriskey_cache_notify` trigger publishes changed table names on.
+pub const CACHE_INVALIDATION_CHANNEL: &str = "ferriskey_cache";
+
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Listens for table change notifications and flushes the matching caches.
+///
+/// Notifications sent while the listener is disconnected are lost, so every (re)connection
+/// starts by flushing all caches.
+pub async fn cache_invalidation_listener_task(
+    db: DatabaseConnection,
+    caches: Arc<RepositoryCaches>,
+) {
+    let pool = db.get_postgres_connection_pool().clone();
+
+    loop {
+        let mut listener = match PgListener::connect_with(&pool).await {
+            Ok(listener) => listener,
+            Err(error) => {
+                warn!(error = ?error, "Failed to connect the cache invalidation listener");
+                tokio::time::sleep(RECONNECT_BACKOFF).await;
+                continue;
+            }
+        };
+
+        if let Err(error) = listener.listen(CACHE_INVALIDATION_CHANNEL).await {
+            warn!(error = ?error, "Failed to listen for cache invalidations");
+            tokio::time::sleep(RECONNECT_BACKOFF).await;
+            continue;
+        }
+
+        caches.clear_all();
+        info!(
+            channel = CACHE_INVALIDATION_CHANNEL,
+            "Listening for cache invalidations"
+        );
+
+        loop {
+            match listener.try_recv().await {
+                Ok(Some(notification)) => {
+                    caches.invalidate_table(notification.payload());
+                }
+                Ok(None) => {
+                    warn!("Cache invalidation listener lost its connection, flushing caches");
+                    caches.clear_all();
+                }
+                Err(error) => {
+                    warn!(error = ?error, "Cache invalidation listener failed, reconnecting");
+                    break;
+                }
+            }
+        }
+
+        caches.clear_all();
+        tokio::time::sleep(RECONNECT_BACKOFF).await;
+    }
+}
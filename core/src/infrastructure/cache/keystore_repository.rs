@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::domain::jwt::{JwtError, entities::JwtKeyPair, ports::KeyStoreRepository};
+use crate::domain::realm::entities::RealmId;
+use crate::infrastructure::cache::RepositoryCaches;
+
+/// Caches the signing key pair of each realm, which every issued and verified token needs.
+#[derive(Debug, Clone)]
+pub struct CachedKeyStoreRepository<K> {
+    inner: K,
+    caches: Arc<RepositoryCaches>,
+}
+
+impl<K> CachedKeyStoreRepository<K> {
+    pub fn new(inner: K, caches: Arc<RepositoryCaches>) -> Self {
+        Self { inner, caches }
+    }
+}
+
+impl<K> KeyStoreRepository for CachedKeyStoreRepository<K>
+where
+    K: KeyStoreRepository,
+{
+    async fn get_or_generate_key(&self, realm_id: RealmId) -> Result<JwtKeyPair, JwtError> {
+        self.caches
+            .signing_keys
+            .get_or_try_load(realm_id.into(), self.inner.get_or_generate_key(realm_id))
+            .await
+    }
+}
@@ -0,0 +1,125 @@
+//! In-process read-through caches in front of the repositories on the token hot path.
+//!
+//! Each replica keeps its own bounded, TTL-limited copies. Writes made through a cached
+//! repository invalidate the local entries straight away; every other replica learns about
+//! them through the `LISTEN/NOTIFY` triggers installed by the cache invalidation migration
+//! (see [`invalidation`]), which fire whatever code path touched the table.
+
+pub mod access_token_repository;
+pub mod client_repository;
+pub mod client_scope_repository;
+pub mod invalidation;
+pub mod keystore_repository;
+pub mod protocol_mapper_repository;
+pub mod realm_repository;
+pub mod scope_mapping_repository;
+pub mod store;
+
+use std::time::Duration;
+
+use crate::domain::aegis::entities::{ClientScope, ProtocolMapper};
+use uuid::Uuid;
+
+use crate::domain::client::entities::Client;
+use crate::domain::jwt::entities::{AccessToken, JwtKeyPair};
+use crate::domain::realm::entities::{Realm, RealmSetting};
+use store::TtlCache;
+
+/// Realm-wide configuration changes rarely; the TTL only bounds staleness if a notification
+/// is lost.
+const CONFIGURATION_TTL: Duration = Duration::from_secs(300);
+/// Revocation state must converge quickly even without notifications.
+const ACCESS_TOKEN_TTL: Duration = Duration::from_secs(30);
+
+const REALM_CAPACITY: usize = 1_024;
+const CLIENT_CAPACITY: usize = 10_000;
+const CLIENT_SCOPE_CAPACITY: usize = 10_000;
+const ACCESS_TOKEN_CAPACITY: usize = 100_000;
+
+/// Which client scopes of a client a cached list holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScopeKind {
+    Default,
+    Optional,
+}
+
+/// Every cache, shared by the cached repositories and the invalidation listener.
+#[derive(Debug)]
+pub struct RepositoryCaches {
+    pub realms_by_name: TtlCache<String, Realm>,
+    pub realms_by_id: TtlCache<Uuid, Realm>,
+    pub realm_settings: TtlCache<Uuid, RealmSetting>,
+    pub clients_by_client_id: TtlCache<(Uuid, String), Client>,
+    pub clients_by_id: TtlCache<(Uuid, Uuid), Client>,
+    pub signing_keys: TtlCache<Uuid, JwtKeyPair>,
+    pub client_scopes: TtlCache<(Uuid, ScopeKind), Vec<ClientScope>>,
+    pub protocol_mappers: TtlCache<Uuid, Vec<ProtocolMapper>>,
+    pub access_tokens: TtlCache<String, AccessToken>,
+}
+
+impl Default for RepositoryCaches {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RepositoryCaches {
+    pub fn new() -> Self {
+        Self {
+            realms_by_name: TtlCache::new("realms_by_name", CONFIGURATION_TTL, REALM_CAPACITY),
+            realms_by_id: TtlCache::new("realms_by_id", CONFIGURATION_TTL, REALM_CAPACITY),
+            realm_settings: TtlCache::new("realm_settings", CONFIGURATION_TTL, REALM_CAPACITY),
+            clients_by_client_id: TtlCache::new(
+                "clients_by_client_id",
+                CONFIGURATION_TTL,
+                CLIENT_CAPACITY,
+            ),
+            clients_by_id: TtlCache::new("clients_by_id", CONFIGURATION_TTL, CLIENT_CAPACITY),
+            signing_keys: TtlCache::new("signing_keys", CONFIGURATION_TTL, REALM_CAPACITY),
+            client_scopes: TtlCache::new("client_scopes", CONFIGURATION_TTL, CLIENT_SCOPE_CAPACITY),
+            protocol_mappers: TtlCache::new(
+                "protocol_mappers",
+                CONFIGURATION_TTL,
+                CLIENT_SCOPE_CAPACITY,
+            ),
+            access_tokens: TtlCache::new("access_tokens", ACCESS_TOKEN_TTL, ACCESS_TOKEN_CAPACITY),
+        }
+    }
+
+    pub fn clear_realms(&self) {
+        self.realms_by_name.clear();
+        self.realms_by_id.clear();
+    }
+
+    pub fn clear_clients(&self) {
+        self.clients_by_client_id.clear();
+        self.clients_by_id.clear();
+    }
+
+    /// Flushes the caches fed by `table`. Returns `false` for tables nothing is cached from.
+    pub fn invalidate_table(&self, table: &str) -> bool {
+        match table {
+            "realms" => self.clear_realms(),
+            "realm_settings" => self.realm_settings.clear(),
+            "clients" | "redirect_uris" => self.clear_clients(),
+            "jwt_keys" => self.signing_keys.clear(),
+            "client_scopes" | "client_scope_mappings" => self.client_scopes.clear(),
+            "client_scope_protocol_mappers" => self.protocol_mappers.clear(),
+            "access_tokens" => self.access_tokens.clear(),
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Used after the notification stream was interrupted: anything may have changed.
+    pub fn clear_all(&self) {
+        self.clear_realms();
+        self.realm_settings.clear();
+        self.clear_clients();
+        self.signing_keys.clear();
+        self.client_scopes.clear();
+        self.protocol_mappers.clear();
+        self.access_tokens.clear();
+    }
+}
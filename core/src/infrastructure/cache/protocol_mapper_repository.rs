@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::aegis::entities::ProtocolMapper;
+use crate::domain::aegis::ports::ProtocolMapperRepository;
+use crate::domain::aegis::value_objects::{
+    CreateProtocolMapperRequest, UpdateProtocolMapperRequest,
+};
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::infrastructure::cache::RepositoryCaches;
+
+/// Caches the protocol mappers of each client scope, applied to every issued token.
+#[derive(Debug, Clone)]
+pub struct CachedProtocolMapperRepository<P> {
+    inner: P,
+    caches: Arc<RepositoryCaches>,
+}
+
+impl<P> CachedProtocolMapperRepository<P> {
+    pub fn new(inner: P, caches: Arc<RepositoryCaches>) -> Self {
+        Self { inner, caches }
+    }
+}
+
+impl<P> ProtocolMapperRepository for CachedProtocolMapperRepository<P>
+where
+    P: ProtocolMapperRepository,
+{
+    async fn create(
+        &self,
+        payload: CreateProtocolMapperRequest,
+    ) -> Result<ProtocolMapper, CoreError> {
+        let client_scope_id = payload.client_scope_id;
+        let result = self.inner.create(payload).await;
+        self.caches.protocol_mappers.invalidate(&client_scope_id);
+        result
+    }
+
+    async fn get_by_id(
+        &self,
+        client_scope_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<ProtocolMapper>, CoreError> {
+        self.inner.get_by_id(client_scope_id, id).await
+    }
+
+    async fn get_by_scope_id(&self, scope_id: Uuid) -> Result<Vec<ProtocolMapper>, CoreError> {
+        self.caches
+            .protocol_mappers
+            .get_or_try_load(scope_id, self.inner.get_by_scope_id(scope_id))
+            .await
+    }
+
+    async fn update_by_id(
+        &self,
+        client_scope_id: Uuid,
+        id: Uuid,
+        payload: UpdateProtocolMapperRequest,
+    ) -> Result<ProtocolMapper, CoreError> {
+        let result = self.inner.update_by_id(client_scope_id, id, payload).await;
+        self.caches.protocol_mappers.invalidate(&client_scope_id);
+        result
+    }
+
+    async fn delete_by_id(&self, client_scope_id: Uuid, id: Uuid) -> Result<(), CoreError> {
+        let result = self.inner.delete_by_id(client_scope_id, id).await;
+        self.caches.protocol_mappers.invalidate(&client_scope_id);
+        result
+    }
+}
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::{LoginAliases, Realm, RealmId, RealmSetting};
+use crate::domain::realm::ports::RealmRepository;
+use crate::infrastructure::cache::RepositoryCaches;
+
+/// Caches realm lookups by name and id, and realm settings by realm id.
+#[derive(Debug, Clone)]
+pub struct CachedRealmRepository<R> {
+    inner: R,
+    caches: Arc<RepositoryCaches>,
+}
+
+impl<R> CachedRealmRepository<R> {
+    pub fn new(inner: R, caches: Arc<RepositoryCaches>) -> Self {
+        Self { inner, caches }
+    }
+}
+
+impl<R> RealmRepository for CachedRealmRepository<R>
+where
+    R: RealmRepository,
+{
+    async fn fetch_realm(&self) -> Result<Vec<Realm>, CoreError> {
+        self.inner.fetch_realm().await
+    }
+
+    async fn get_by_name(&self, name: &str) -> Result<Option<Realm>, CoreError> {
+        self.caches
+            .realms_by_name
+            .get_or_try_load_optional(name.to_string(), self.inner.get_by_name(name))
+            .await
+    }
+
+    async fn get_by_id(&self, realm_id: RealmId) -> Result<Option<Realm>, CoreError> {
+        self.caches
+            .realms_by_id
+            .get_or_try_load_optional(realm_id.into(), self.inner.get_by_id(realm_id))
+            .await
+    }
+
+    async fn create_realm(
+        &self,
+        name: String,
+        display_name: Option<String>,
+    ) -> Result<Realm, CoreError> {
+        self.inner.create_realm(name, display_name).await
+    }
+
+    async fn update_realm(
+        &self,
+        realm_name: String,
+        name: String,
+        display_name: Option<String>,
+    ) -> Result<Realm, CoreError> {
+        let result = self
+            .inner
+            .update_realm(realm_name, name, display_name)
+            .await;
+        self.caches.clear_realms();
+        result
+    }
+
+    async fn delete_by_name(&self, name: &str) -> Result<(), CoreError> {
+        let result = self.inner.delete_by_name(name).await;
+        self.caches.clear_all();
+        result
+    }
+
+    async fn create_realm_settings(
+        &self,
+        realm_id: RealmId,
+        algorithm: String,
+    ) -> Result<RealmSetting, CoreError> {
+        let result = self.inner.create_realm_settings(realm_id, algorithm).await;
+        self.caches.realm_settings.invalidate(&realm_id.into());
+        result
+    }
+
+    async fn update_realm_setting(
+        &self,
+        realm_id: RealmId,
+        algorithm: Option<String>,
+        user_registration_enabled: Option<bool>,
+        forgot_password_enabled: Option<bool>,
+        remember_me_enabled: Option<bool>,
+        magic_link_enabled: Option<bool>,
+        magic_link_ttl: Option<u32>,
+        passkey_enabled: Option<bool>,
+        compass_enabled: Option<bool>,
+        access_token_lifetime: Option<i64>,
+        refresh_token_lifetime: Option<i64>,
+        id_token_lifetime: Option<i64>,
+        temporary_token_lifetime: Option<i64>,
+        reset_password_template_id: Option<Option<Uuid>>,
+        magic_link_template_id: Option<Option<Uuid>>,
+        email_verification_template_id: Option<Option<Uuid>>,
+        organization_invitation_template_id: Option<Option<Uuid>>,
+        email_verification_enabled: Option<bool>,
+        email_verification_ttl_hours: Option<i64>,
+        lockout_threshold: Option<i32>,
+        lockout_duration_seconds: Option<i32>,
+        login_aliases: Option<LoginAliases>,
+        seawatch_pii_mode: Option<String>,
+        seawatch_pseudo_key: Option<Option<String>>,
+        require_mfa: Option<bool>,
+    ) -> Result<RealmSetting, CoreError> {
+        let result = self
+            .inner
+            .update_realm_setting(
+                realm_id,
+                algorithm,
+                user_registration_enabled,
+                forgot_password_enabled,
+                remember_me_enabled,
+                magic_link_enabled,
+                magic_link_ttl,
+                passkey_enabled,
+                compass_enabled,
+                access_token_lifetime,
+                refresh_token_lifetime,
+                id_token_lifetime,
+                temporary_token_lifetime,
+                reset_password_template_id,
+                magic_link_template_id,
+                email_verification_template_id,
+                organization_invitation_template_id,
+                email_verification_enabled,
+                email_verification_ttl_hours,
+                lockout_threshold,
+                lockout_duration_seconds,
+                login_aliases,
+                seawatch_pii_mode,
+                seawatch_pseudo_key,
+                require_mfa,
+            )
+            .await;
+        self.caches.realm_settings.invalidate(&realm_id.into());
+        result
+    }
+
+    async fn get_realm_settings(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Option<RealmSetting>, CoreError> {
+        self.caches
+            .realm_settings
+            .get_or_try_load_optional(realm_id.into(), self.inner.get_realm_settings(realm_id))
+            .await
+    }
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::aegis::entities::{ClientScope, ClientScopeMapping};
+use crate::domain::aegis::ports::ClientScopeMappingRepository;
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::infrastructure::cache::{RepositoryCaches, ScopeKind};
+
+/// Caches the default and optional client scopes of each client, resolved on every token
+/// issuance.
+#[derive(Debug, Clone)]
+pub struct CachedScopeMappingRepository<M> {
+    inner: M,
+    caches: Arc<RepositoryCaches>,
+}
+
+impl<M> CachedScopeMappingRepository<M> {
+    pub fn new(inner: M, caches: Arc<RepositoryCaches>) -> Self {
+        Self { inner, caches }
+    }
+
+    fn invalidate_client(&self, client_id: Uuid) {
+        self.caches
+            .client_scopes
+            .invalidate(&(client_id, ScopeKind::Default));
+        self.caches
+            .client_scopes
+            .invalidate(&(client_id, ScopeKind::Optional));
+    }
+}
+
+impl<M> ClientScopeMappingRepository for CachedScopeMappingRepository<M>
+where
+    M: ClientScopeMappingRepository,
+{
+    async fn assign_scope_to_client(
+        &self,
+        client_id: Uuid,
+        scope_id: Uuid,
+        is_default: bool,
+        is_optional: bool,
+    ) -> Result<ClientScopeMapping, CoreError> {
+        let result = self
+            .inner
+            .assign_scope_to_client(client_id, scope_id, is_default, is_optional)
+            .await;
+        self.invalidate_client(client_id);
+        result
+    }
+
+    async fn remove_scope_from_client(
+        &self,
+        client_id: Uuid,
+        scope_id: Uuid,
+    ) -> Result<(), CoreError> {
+        let result = self
+            .inner
+            .remove_scope_from_client(client_id, scope_id)
+            .await;
+        self.invalidate_client(client_id);
+        result
+    }
+
+    async fn get_client_scopes(
+        &self,
+        client_id: Uuid,
+    ) -> Result<Vec<ClientScopeMapping>, CoreError> {
+        self.inner.get_client_scopes(client_id).await
+    }
+
+    async fn get_default_scopes(&self, client_id: Uuid) -> Result<Vec<ClientScope>, CoreError> {
+        self.caches
+            .client_scopes
+            .get_or_try_load(
+                (client_id, ScopeKind::Default),
+                self.inner.get_default_scopes(client_id),
+            )
+            .await
+    }
+
+    async fn get_optional_scopes(&self, client_id: Uuid) -> Result<Vec<ClientScope>, CoreError> {
+        self.caches
+            .client_scopes
+            .get_or_try_load(
+                (client_id, ScopeKind::Optional),
+                self.inner.get_optional_scopes(client_id),
+            )
+            .await
+    }
+}
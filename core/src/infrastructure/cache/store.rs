@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// A bounded map whose entries expire after a fixed TTL.
+///
+/// Every invalidation bumps a generation counter; a value loaded from the database is only
+/// stored when no invalidation happened while it was being loaded, so a slow read can never
+/// put back a row that a concurrent write just invalidated.
+pub struct TtlCache<K, V> {
+    name: &'static str,
+    ttl: Duration,
+    capacity: usize,
+    entries: RwLock<HashMap<K, (Instant, V)>>,
+    generation: AtomicU64,
+}
+
+impl<K, V> std::fmt::Debug for TtlCache<K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TtlCache")
+            .field("name", &self.name)
+            .field("ttl", &self.ttl)
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K, V> TtlCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    pub fn new(name: &'static str, ttl: Duration, capacity: usize) -> Self {
+        Self {
+            name,
+            ttl,
+            capacity,
+            entries: RwLock::new(HashMap::new()),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        let value = self.get_fresh(key, Instant::now());
+        let result = if value.is_some() { "hit" } else { "miss" };
+        metrics::counter!("ferriskey_cache_requests_total", "cache" => self.name, "result" => result)
+            .increment(1);
+        value
+    }
+
+    fn get_fresh(&self, key: &K, now: Instant) -> Option<V> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        let (stored_at, value) = entries.get(key)?;
+
+        (now.duration_since(*stored_at) < self.ttl).then(|| value.clone())
+    }
+
+    /// Returns the cached value, or loads it and caches it when the load succeeds.
+    pub async fn get_or_try_load<E, F>(&self, key: K, load: F) -> Result<V, E>
+    where
+        F: Future<Output = Result<V, E>>,
+    {
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+
+        let generation = self.generation();
+        let value = load.await?;
+        self.insert_if_current(key, value.clone(), generation);
+        Ok(value)
+    }
+
+    /// Same as [`Self::get_or_try_load`] for lookups that may find nothing; misses are not
+    /// cached, so a row created right after a failed lookup is visible immediately.
+    pub async fn get_or_try_load_optional<E, F>(&self, key: K, load: F) -> Result<Option<V>, E>
+    where
+        F: Future<Output = Result<Option<V>, E>>,
+    {
+        if let Some(value) = self.get(&key) {
+            return Ok(Some(value));
+        }
+
+        let generation = self.generation();
+        let value = load.await?;
+        if let Some(value) = &value {
+            self.insert_if_current(key, value.clone(), generation);
+        }
+        Ok(value)
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    pub fn insert_if_current(&self, key: K, value: V, generation: u64) {
+        self.insert_at(key, value, generation, Instant::now());
+    }
+
+    fn insert_at(&self, key: K, value: V, generation: u64, now: Instant) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        if self.generation() != generation {
+            return;
+        }
+
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            entries.retain(|_, (stored_at, _)| now.duration_since(*stored_at) < self.ttl);
+        }
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, (stored_at, _))| *stored_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+                metrics::counter!("ferriskey_cache_evictions_total", "cache" => self.name)
+                    .increment(1);
+            }
+        }
+
+        entries.insert(key, (now, value));
+        metrics::gauge!("ferriskey_cache_entries", "cache" => self.name).set(entries.len() as f64);
+    }
+
+    pub fn invalidate(&self, key: &K) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        entries.remove(key);
+        metrics::gauge!("ferriskey_cache_entries", "cache" => self.name).set(entries.len() as f64);
+    }
+
+    pub fn clear(&self) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        entries.clear();
+        metrics::gauge!("ferriskey_cache_entries", "cache" => self.name).set(0.0);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache(capacity: usize) -> TtlCache<&'static str, u32> {
+        TtlCache::new("test", Duration::from_secs(60), capacity)
+    }
+
+    #[test]
+    fn entries_expire_after_the_ttl() {
+        let cache = cache(4);
+        let stored_at = Instant::now();
+        cache.insert_at("a", 1, cache.generation(), stored_at);
+
+        assert_eq!(
+            cache.get_fresh(&"a", stored_at + Duration::from_secs(59)),
+            Some(1)
+        );
+        assert_eq!(
+            cache.get_fresh(&"a", stored_at + Duration::from_secs(60)),
+            None
+        );
+    }
+
+    #[test]
+    fn the_oldest_entry_is_evicted_when_full() {
+        let cache = cache(2);
+        let now = Instant::now();
+        cache.insert_at("a", 1, cache.generation(), now);
+        cache.insert_at("b", 2, cache.generation(), now + Duration::from_secs(1));
+        cache.insert_at("c", 3, cache.generation(), now + Duration::from_secs(2));
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&"a"), None);
+        assert_eq!(cache.get(&"b"), Some(2));
+        assert_eq!(cache.get(&"c"), Some(3));
+    }
+
+    #[test]
+    fn a_value_loaded_before_an_invalidation_is_not_stored() {
+        let cache = cache(4);
+        let generation = cache.generation();
+        cache.invalidate(&"a");
+        cache.insert_if_current("a", 1, generation);
+
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_and_empty_loads_are_not_cached() {
+        let cache = cache(4);
+
+        let failed: Result<u32, &str> = cache.get_or_try_load("a", async { Err("down") }).await;
+        assert!(failed.is_err());
+        let missing: Result<Option<u32>, &str> = cache
+            .get_or_try_load_optional("b", async { Ok(None) })
+            .await;
+        assert_eq!(missing, Ok(None));
+        assert!(cache.is_empty());
+
+        let loaded: Result<u32, &str> = cache.get_or_try_load("a", async { Ok(7) }).await;
+        assert_eq!(loaded, Ok(7));
+        let cached: Result<u32, &str> = cache.get_or_try_load("a", async { Ok(8) }).await;
+        assert_eq!(cached, Ok(7));
+    }
+}
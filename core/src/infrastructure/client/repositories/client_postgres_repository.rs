@@ -199,6 +199,32 @@ impl ClientRepository for PostgresClientRepository {
         Ok(client.into())
     }
 
+    async fn update_secret(
+        &self,
+        realm_id: RealmId,
+        client_id: Uuid,
+        secret: String,
+    ) -> Result<Client, CoreError> {
+        let client = ClientEntity::find()
+            .filter(crate::entity::clients::Column::Id.eq(client_id))
+            .filter(crate::entity::clients::Column::RealmId.eq(Uuid::from(realm_id)))
+            .one(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::NotFound)?;
+
+        let mut client: ActiveModel = client.into();
+        client.secret = Set(Some(secret));
+        client.updated_at = Set(Utc::now().naive_utc());
+
+        let client = client
+            .update(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        Ok(client.into())
+    }
+
     async fn delete_by_id(&self, realm_id: RealmId, id: Uuid) -> Result<(), CoreError> {
         let result = ClientEntity::delete_many()
             .filter(crate::entity::clients::Column::Id.eq(id))
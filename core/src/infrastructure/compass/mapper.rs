@@ -54,15 +54,8 @@ impl From<CompassFlow> for compass_flows::ActiveModel {
 
 impl From<compass_flow_steps::Model> for CompassFlowStep {
     fn from(model: compass_flow_steps::Model) -> Self {
-        let step_name = match model.step_name.as_str() {
-            "credential_validation" => FlowStepName::CredentialValidation,
-            "mfa_challenge" => FlowStepName::MfaChallenge,
-            "token_exchange" => FlowStepName::TokenExchange,
-            "idp_redirect" => FlowStepName::IdpRedirect,
-            "idp_callback" => FlowStepName::IdpCallback,
-            "finalize" => FlowStepName::Finalize,
-            _ => FlowStepName::Authorize,
-        };
+        let step_name =
+            FlowStepName::from_name(&model.step_name).unwrap_or(FlowStepName::Authorize);
 
         let status = match model.status.as_str() {
             "failure" => StepStatus::Failure,
@@ -0,0 +1,39 @@
+use sea_orm::{QueryResult, TryGetable, Value};
+use uuid::Uuid;
+
+use ferriskey_compass::value_objects::FlowAnalyticsFilter;
+use ferriskey_domain::realm::RealmId;
+
+use crate::domain::common::entities::app_errors::CoreError;
+
+/// Restricts `compass_flows f` to the analysed realm and range; binds `$1` to `$5`.
+pub(super) const FLOW_ANALYTICS_FILTER: &str = r#"
+    f.realm_id = $1
+    AND f.started_at >= $2
+    AND f.started_at < $3
+    AND ($4::text IS NULL OR f.client_id = $4)
+    AND ($5::text IS NULL OR f.grant_type = $5)
+"#;
+
+pub(super) fn analytics_values(realm_id: RealmId, filter: FlowAnalyticsFilter) -> Vec<Value> {
+    let realm_uuid: Uuid = realm_id.into();
+
+    vec![
+        realm_uuid.into(),
+        filter.from_timestamp.naive_utc().into(),
+        filter.to_timestamp.naive_utc().into(),
+        filter.client_id.into(),
+        filter.grant_type.into(),
+    ]
+}
+
+pub(super) fn read_column<T: TryGetable>(row: &QueryResult, column: &str) -> Result<T, CoreError> {
+    T::try_get_by(row, column).map_err(|e| {
+        tracing::error!(
+            "Failed to read compass analytics column {}: {:?}",
+            column,
+            e
+        );
+        CoreError::InternalServerError
+    })
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use sea_orm::{
     ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseBackend, DatabaseConnection,
     EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Statement,
@@ -8,10 +8,14 @@ use uuid::Uuid;
 use ferriskey_compass::{
     entities::{CompassFlow, FlowStatus},
     ports::CompassFlowRepository,
-    value_objects::{DailyActivityStats, DailyActivityStatsFilter, FlowFilter, FlowStats},
+    value_objects::{
+        DailyActivityStats, DailyActivityStatsFilter, FlowAnalyticsFilter, FlowFilter, FlowStats,
+        FunnelEntry, HourlyFlowBucket,
+    },
 };
 use ferriskey_domain::realm::RealmId;
 
+use super::analytics_query::{FLOW_ANALYTICS_FILTER, analytics_values, read_column};
 use crate::domain::common::entities::app_errors::CoreError;
 use crate::entity::{compass_flow_steps, compass_flows};
 
@@ -392,4 +396,114 @@ impl CompassFlowRepository for PostgresCompassFlowRepository {
             })
             .collect()
     }
+
+    async fn get_funnel_entries(
+        &self,
+        realm_id: RealmId,
+        filter: FlowAnalyticsFilter,
+    ) -> Result<Vec<FunnelEntry>, CoreError> {
+        let rows = self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                format!(
+                    r#"
+                    SELECT
+                        f.client_id,
+                        f.grant_type,
+                        COUNT(*)::bigint AS started,
+                        COUNT(*) FILTER (WHERE f.status = 'success')::bigint AS succeeded
+                    FROM compass_flows f
+                    WHERE {FLOW_ANALYTICS_FILTER}
+                    GROUP BY f.client_id, f.grant_type
+                    "#
+                ),
+                analytics_values(realm_id, filter),
+            ))
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get compass funnel entries: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(FunnelEntry {
+                    client_id: read_column(row, "client_id")?,
+                    grant_type: read_column(row, "grant_type")?,
+                    started: read_column(row, "started")?,
+                    succeeded: read_column(row, "succeeded")?,
+                })
+            })
+            .collect()
+    }
+
+    async fn get_hourly_buckets(
+        &self,
+        realm_id: RealmId,
+        filter: FlowAnalyticsFilter,
+    ) -> Result<Vec<HourlyFlowBucket>, CoreError> {
+        let rows = self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                format!(
+                    r#"
+                    WITH hours AS (
+                        SELECT generate_series(
+                            date_trunc('hour', $2::timestamp),
+                            $3::timestamp - interval '1 microsecond',
+                            interval '1 hour'
+                        ) AS hour
+                    ),
+                    flow_stats AS (
+                        SELECT
+                            date_trunc('hour', f.started_at) AS hour,
+                            COUNT(*)::bigint AS total_flows,
+                            COUNT(*) FILTER (WHERE f.status = 'success')::bigint AS succeeded,
+                            COUNT(*) FILTER (WHERE f.status = 'failure')::bigint AS failed,
+                            percentile_cont(0.5) WITHIN GROUP (ORDER BY f.duration_ms)::float8 AS p50,
+                            percentile_cont(0.9) WITHIN GROUP (ORDER BY f.duration_ms)::float8 AS p90,
+                            percentile_cont(0.99) WITHIN GROUP (ORDER BY f.duration_ms)::float8 AS p99
+                        FROM compass_flows f
+                        WHERE {FLOW_ANALYTICS_FILTER}
+                        GROUP BY date_trunc('hour', f.started_at)
+                    )
+                    SELECT
+                        hours.hour,
+                        COALESCE(flow_stats.total_flows, 0)::bigint AS total_flows,
+                        COALESCE(flow_stats.succeeded, 0)::bigint AS succeeded,
+                        COALESCE(flow_stats.failed, 0)::bigint AS failed,
+                        flow_stats.p50,
+                        flow_stats.p90,
+                        flow_stats.p99
+                    FROM hours
+                    LEFT JOIN flow_stats ON flow_stats.hour = hours.hour
+                    ORDER BY hours.hour ASC
+                    "#
+                ),
+                analytics_values(realm_id, filter),
+            ))
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get compass hourly buckets: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        rows.iter()
+            .map(|row| {
+                let hour: NaiveDateTime = read_column(row, "hour")?;
+
+                Ok(HourlyFlowBucket {
+                    hour: Utc.from_utc_datetime(&hour),
+                    total_flows: read_column(row, "total_flows")?,
+                    succeeded: read_column(row, "succeeded")?,
+                    failed: read_column(row, "failed")?,
+                    p50_duration_ms: read_column(row, "p50")?,
+                    p90_duration_ms: read_column(row, "p90")?,
+                    p99_duration_ms: read_column(row, "p99")?,
+                })
+            })
+            .collect()
+    }
 }
@@ -1,8 +1,17 @@
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseBackend, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QueryResult, Statement,
+};
 use uuid::Uuid;
 
-use ferriskey_compass::{entities::CompassFlowStep, ports::CompassFlowStepRepository};
+use ferriskey_compass::{
+    entities::{CompassFlowStep, FlowStepName},
+    ports::CompassFlowStepRepository,
+    value_objects::{FailureReason, FlowAnalyticsFilter, StepLatency, StepReach},
+};
+use ferriskey_domain::realm::RealmId;
 
+use super::analytics_query::{FLOW_ANALYTICS_FILTER, analytics_values, read_column};
 use crate::domain::common::entities::app_errors::CoreError;
 use crate::entity::compass_flow_steps;
 
@@ -17,6 +26,13 @@ impl PostgresCompassFlowStepRepository {
     }
 }
 
+/// Rows of steps the enum no longer knows are left out of the aggregates.
+fn read_step_name(row: &QueryResult) -> Result<Option<FlowStepName>, CoreError> {
+    let step_name: String = read_column(row, "step_name")?;
+
+    Ok(FlowStepName::from_name(&step_name))
+}
+
 impl CompassFlowStepRepository for PostgresCompassFlowStepRepository {
     async fn create_step(&self, step: CompassFlowStep) -> Result<(), CoreError> {
         let active_model: compass_flow_steps::ActiveModel = step.into();
@@ -47,4 +63,158 @@ impl CompassFlowStepRepository for PostgresCompassFlowStepRepository {
 
         Ok(steps)
     }
+
+    async fn get_step_reach(
+        &self,
+        realm_id: RealmId,
+        filter: FlowAnalyticsFilter,
+    ) -> Result<Vec<StepReach>, CoreError> {
+        let rows = self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                format!(
+                    r#"
+                    SELECT
+                        f.client_id,
+                        f.grant_type,
+                        s.step_name,
+                        COUNT(DISTINCT s.flow_id)::bigint AS reached,
+                        COUNT(DISTINCT s.flow_id) FILTER (WHERE s.status = 'success')::bigint AS succeeded,
+                        COUNT(DISTINCT s.flow_id) FILTER (WHERE s.status = 'failure')::bigint AS failed,
+                        COUNT(DISTINCT s.flow_id) FILTER (WHERE s.status = 'skipped')::bigint AS skipped
+                    FROM compass_flow_steps s
+                    JOIN compass_flows f ON f.id = s.flow_id
+                    WHERE {FLOW_ANALYTICS_FILTER}
+                    GROUP BY f.client_id, f.grant_type, s.step_name
+                    "#
+                ),
+                analytics_values(realm_id, filter),
+            ))
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get compass step reach: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        let mut reaches = Vec::with_capacity(rows.len());
+        for row in &rows {
+            let Some(step_name) = read_step_name(row)? else {
+                continue;
+            };
+
+            reaches.push(StepReach {
+                client_id: read_column(row, "client_id")?,
+                grant_type: read_column(row, "grant_type")?,
+                step_name,
+                reached: read_column(row, "reached")?,
+                succeeded: read_column(row, "succeeded")?,
+                failed: read_column(row, "failed")?,
+                skipped: read_column(row, "skipped")?,
+            });
+        }
+
+        Ok(reaches)
+    }
+
+    async fn get_step_latencies(
+        &self,
+        realm_id: RealmId,
+        filter: FlowAnalyticsFilter,
+    ) -> Result<Vec<StepLatency>, CoreError> {
+        let rows = self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                format!(
+                    r#"
+                    SELECT
+                        s.step_name,
+                        COUNT(*)::bigint AS samples,
+                        percentile_cont(0.5) WITHIN GROUP (ORDER BY s.duration_ms)::float8 AS p50,
+                        percentile_cont(0.9) WITHIN GROUP (ORDER BY s.duration_ms)::float8 AS p90,
+                        percentile_cont(0.99) WITHIN GROUP (ORDER BY s.duration_ms)::float8 AS p99,
+                        MAX(s.duration_ms)::bigint AS max_ms
+                    FROM compass_flow_steps s
+                    JOIN compass_flows f ON f.id = s.flow_id
+                    WHERE {FLOW_ANALYTICS_FILTER}
+                        AND s.duration_ms IS NOT NULL
+                    GROUP BY s.step_name
+                    "#
+                ),
+                analytics_values(realm_id, filter),
+            ))
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get compass step latencies: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        let mut latencies = Vec::with_capacity(rows.len());
+        for row in &rows {
+            let Some(step_name) = read_step_name(row)? else {
+                continue;
+            };
+
+            latencies.push(StepLatency {
+                step_name,
+                samples: read_column(row, "samples")?,
+                p50_ms: read_column(row, "p50")?,
+                p90_ms: read_column(row, "p90")?,
+                p99_ms: read_column(row, "p99")?,
+                max_ms: read_column(row, "max_ms")?,
+            });
+        }
+        latencies.sort_by_key(|latency| latency.step_name.funnel_position());
+
+        Ok(latencies)
+    }
+
+    async fn get_failure_reasons(
+        &self,
+        realm_id: RealmId,
+        filter: FlowAnalyticsFilter,
+    ) -> Result<Vec<FailureReason>, CoreError> {
+        let rows = self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                format!(
+                    r#"
+                    SELECT
+                        s.step_name,
+                        COALESCE(s.error_code, 'unknown') AS error_code,
+                        COUNT(*)::bigint AS occurrences
+                    FROM compass_flow_steps s
+                    JOIN compass_flows f ON f.id = s.flow_id
+                    WHERE {FLOW_ANALYTICS_FILTER}
+                        AND s.status = 'failure'
+                    GROUP BY s.step_name, COALESCE(s.error_code, 'unknown')
+                    ORDER BY occurrences DESC, error_code ASC
+                    LIMIT 100
+                    "#
+                ),
+                analytics_values(realm_id, filter),
+            ))
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get compass failure reasons: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        let mut reasons = Vec::with_capacity(rows.len());
+        for row in &rows {
+            let Some(step_name) = read_step_name(row)? else {
+                continue;
+            };
+
+            reasons.push(FailureReason {
+                step_name,
+                error_code: read_column(row, "error_code")?,
+                occurrences: read_column(row, "occurrences")?,
+            });
+        }
+
+        Ok(reasons)
+    }
 }
@@ -1,3 +1,4 @@
+mod analytics_query;
 pub mod compass_flow_postgres_repository;
 pub mod compass_flow_step_postgres_repository;
 
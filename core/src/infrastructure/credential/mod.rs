@@ -1,4 +1,4 @@
-use crate::domain::credential::entities::{Credential, CredentialError};
+use crate::domain::credential::entities::{AuthenticatorInfo, Credential, CredentialError};
 use crate::domain::credential::ports::CredentialRepository;
 use crate::domain::crypto::HashResult;
 use crate::infrastructure::repositories::credential_repository::PostgresCredentialRepository;
@@ -93,10 +93,11 @@ impl CredentialRepository for CredentialRepoAny {
         &self,
         user_id: Uuid,
         webauthn_credential: Passkey,
+        authenticator: AuthenticatorInfo,
     ) -> Result<Credential, CredentialError> {
         match self {
             CredentialRepoAny::Postgres(repo) => {
-                repo.create_webauthn_credential(user_id, webauthn_credential)
+                repo.create_webauthn_credential(user_id, webauthn_credential, authenticator)
                     .await
             }
         }
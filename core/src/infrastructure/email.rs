@@ -1,24 +1,36 @@
+use std::sync::Arc;
+
 use ferriskey_mail::{
-    adapters::smtp::SmtpEmailSender,
-    entities::{EmailAddress, EmailFrom, EmailMessage, EmailSubject, SmtpEncryption},
-    ports::EmailSender,
+    adapters::{maildir::MaildirEmailSender, smtp::SmtpTransport},
+    entities::{EmailAddress, EmailMessage, EmailSubject},
+    error::EmailError,
 };
 
 use crate::domain::{
     common::{email::EmailPort, entities::app_errors::CoreError},
+    email_outbox::{
+        entities::OutboxEmail,
+        ports::{EmailOutboxRepository, EmailTransport},
+    },
     realm::entities::SmtpConfig,
 };
 
-#[derive(Debug, Clone, Default)]
-pub struct SmtpEmailPort;
+/// Queues emails in the outbox instead of sending them inline; the outbox worker delivers
+/// them and retries failed attempts. `send_email` succeeding means the email is persisted.
+#[derive(Debug, Clone)]
+pub struct OutboxEmailPort<EO: EmailOutboxRepository> {
+    email_outbox_repository: Arc<EO>,
+}
 
-impl SmtpEmailPort {
-    pub fn new() -> Self {
-        Self
+impl<EO: EmailOutboxRepository> OutboxEmailPort<EO> {
+    pub fn new(email_outbox_repository: Arc<EO>) -> Self {
+        Self {
+            email_outbox_repository,
+        }
     }
 }
 
-impl EmailPort for SmtpEmailPort {
+impl<EO: EmailOutboxRepository> EmailPort for OutboxEmailPort<EO> {
     async fn send_email(
         &self,
         config: &SmtpConfig,
@@ -27,38 +39,46 @@ impl EmailPort for SmtpEmailPort {
         body: &str,
         html_body: Option<String>,
     ) -> Result<(), CoreError> {
-        let encryption = match &config.encryption {
-            crate::domain::realm::entities::SmtpEncryption::Tls => SmtpEncryption::Tls,
-            crate::domain::realm::entities::SmtpEncryption::StartTls => SmtpEncryption::StartTls,
-            crate::domain::realm::entities::SmtpEncryption::None => SmtpEncryption::None,
-        };
-
-        let sender = SmtpEmailSender::with_config(
-            &config.host,
-            config.port,
-            &config.username,
-            &config.password,
-            &encryption,
-        )
-        .map_err(|e| CoreError::External(format!("Failed to create SMTP sender: {e}")))?;
-
-        let from_email = EmailAddress::new(config.from_email.clone())
-            .map_err(|e| CoreError::External(format!("Invalid from email: {e}")))?;
-        let from = EmailFrom::new(config.from_name.clone(), from_email)
-            .map_err(|e| CoreError::External(format!("Invalid from: {e}")))?;
-        let to = EmailAddress::new(to_email.to_string())
+        // Rejected here rather than by the worker, so callers still see malformed emails fail.
+        EmailAddress::new(to_email.to_string())
             .map_err(|e| CoreError::External(format!("Invalid to email: {e}")))?;
-        let email_subject = EmailSubject::new(subject.to_string())
+        EmailSubject::new(subject.to_string())
             .map_err(|e| CoreError::External(format!("Invalid subject: {e}")))?;
 
-        let message = EmailMessage::new(from, vec![to], email_subject, body.to_string(), html_body)
-            .map_err(|e| CoreError::External(format!("Failed to build email: {e}")))?;
+        let email = OutboxEmail::new(
+            config.realm_id,
+            to_email.to_string(),
+            subject.to_string(),
+            body.to_string(),
+            html_body,
+        );
 
-        sender
-            .send(message)
-            .await
-            .map_err(|e| CoreError::External(format!("Failed to send email: {e}")))?;
+        self.email_outbox_repository.enqueue(&email).await
+    }
+}
+
+/// Where the outbox worker delivers emails: the SMTP server of each realm, or a local
+/// maildir when `--mail-maildir` is set.
+#[derive(Debug, Clone)]
+pub enum ConfiguredEmailTransport {
+    Smtp(SmtpTransport),
+    Maildir(MaildirEmailSender),
+}
+
+impl ConfiguredEmailTransport {
+    pub fn new(maildir: Option<&str>) -> Self {
+        match maildir {
+            Some(path) => Self::Maildir(MaildirEmailSender::new(path)),
+            None => Self::Smtp(SmtpTransport),
+        }
+    }
+}
 
-        Ok(())
+impl EmailTransport for ConfiguredEmailTransport {
+    async fn deliver(&self, config: &SmtpConfig, message: EmailMessage) -> Result<(), EmailError> {
+        match self {
+            Self::Smtp(transport) => transport.deliver(config, message).await,
+            Self::Maildir(sender) => sender.deliver(config, message).await,
+        }
     }
 }
@@ -0,0 +1,232 @@
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::email_outbox::{
+    entities::{OutboxEmail, OutboxEmailStatus},
+    ports::EmailOutboxRepository,
+};
+use crate::entity::email_outbox::{ActiveModel, Column, Entity as OutboxEntity, Model};
+
+#[derive(Clone, Debug)]
+pub struct PostgresEmailOutboxRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresEmailOutboxRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+const QUEUED: [&str; 2] = ["pending", "sending"];
+const FINISHED: [&str; 2] = ["sent", "failed"];
+
+fn model_to_domain(model: Model) -> Result<OutboxEmail, CoreError> {
+    let status = model.status.parse().map_err(|_| {
+        error!("Failed to decode outbox email {}: status", model.id);
+        CoreError::InternalServerError
+    })?;
+
+    Ok(OutboxEmail {
+        id: model.id,
+        realm_id: model.realm_id,
+        recipient: model.recipient,
+        subject: model.subject,
+        body: model.body,
+        html_body: model.html_body,
+        status,
+        attempts: model.attempts.max(0) as u32,
+        next_attempt_at: model.next_attempt_at.with_timezone(&Utc),
+        last_error: model.last_error,
+        sent_at: model.sent_at.map(|sent_at| sent_at.with_timezone(&Utc)),
+        created_at: model.created_at.with_timezone(&Utc),
+        updated_at: model.updated_at.with_timezone(&Utc),
+    })
+}
+
+fn database_error(action: &str) -> impl FnOnce(sea_orm::DbErr) -> CoreError + '_ {
+    move |e| {
+        error!("Failed to {} outbox email: {}", action, e);
+        CoreError::InternalServerError
+    }
+}
+
+impl EmailOutboxRepository for PostgresEmailOutboxRepository {
+    async fn enqueue(&self, email: &OutboxEmail) -> Result<(), CoreError> {
+        ActiveModel {
+            id: Set(email.id),
+            realm_id: Set(email.realm_id),
+            recipient: Set(email.recipient.clone()),
+            subject: Set(email.subject.clone()),
+            body: Set(email.body.clone()),
+            html_body: Set(email.html_body.clone()),
+            status: Set(email.status.to_string()),
+            attempts: Set(email.attempts as i32),
+            next_attempt_at: Set(email.next_attempt_at.fixed_offset()),
+            last_error: Set(email.last_error.clone()),
+            sent_at: Set(email.sent_at.map(|sent_at| sent_at.fixed_offset())),
+            created_at: Set(email.created_at.fixed_offset()),
+            updated_at: Set(email.updated_at.fixed_offset()),
+        }
+        .insert(&self.db)
+        .await
+        .map_err(database_error("enqueue"))?;
+
+        Ok(())
+    }
+
+    async fn get(&self, realm_id: Uuid, email_id: Uuid) -> Result<Option<OutboxEmail>, CoreError> {
+        OutboxEntity::find_by_id(email_id)
+            .filter(Column::RealmId.eq(realm_id))
+            .one(&self.db)
+            .await
+            .map_err(database_error("get"))?
+            .map(model_to_domain)
+            .transpose()
+    }
+
+    async fn list_by_realm(
+        &self,
+        realm_id: Uuid,
+        status: Option<OutboxEmailStatus>,
+        limit: u64,
+    ) -> Result<Vec<OutboxEmail>, CoreError> {
+        let mut query = OutboxEntity::find().filter(Column::RealmId.eq(realm_id));
+        if let Some(status) = status {
+            query = query.filter(Column::Status.eq(status.to_string()));
+        }
+
+        query
+            .order_by_desc(Column::CreatedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(database_error("list"))?
+            .into_iter()
+            .map(model_to_domain)
+            .collect()
+    }
+
+    async fn list_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<OutboxEmail>, CoreError> {
+        OutboxEntity::find()
+            .filter(Column::Status.is_in(QUEUED))
+            .filter(Column::NextAttemptAt.lte(now.fixed_offset()))
+            .order_by_asc(Column::NextAttemptAt)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(database_error("list due"))?
+            .into_iter()
+            .map(model_to_domain)
+            .collect()
+    }
+
+    async fn claim(
+        &self,
+        email: &OutboxEmail,
+        claimed_until: DateTime<Utc>,
+    ) -> Result<bool, CoreError> {
+        let result = OutboxEntity::update_many()
+            .col_expr(
+                Column::Status,
+                Expr::value(OutboxEmailStatus::Sending.to_string()),
+            )
+            .col_expr(
+                Column::NextAttemptAt,
+                Expr::value(claimed_until.fixed_offset()),
+            )
+            .col_expr(Column::UpdatedAt, Expr::value(Utc::now().fixed_offset()))
+            .filter(Column::Id.eq(email.id))
+            .filter(Column::Status.eq(email.status.to_string()))
+            .filter(Column::Attempts.eq(email.attempts as i32))
+            .filter(Column::NextAttemptAt.eq(email.next_attempt_at.fixed_offset()))
+            .exec(&self.db)
+            .await
+            .map_err(database_error("claim"))?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn postpone(
+        &self,
+        email: &OutboxEmail,
+        next_attempt_at: DateTime<Utc>,
+    ) -> Result<bool, CoreError> {
+        let result = OutboxEntity::update_many()
+            .col_expr(
+                Column::NextAttemptAt,
+                Expr::value(next_attempt_at.fixed_offset()),
+            )
+            .col_expr(Column::UpdatedAt, Expr::value(Utc::now().fixed_offset()))
+            .filter(Column::Id.eq(email.id))
+            .filter(Column::Status.eq(email.status.to_string()))
+            .filter(Column::Attempts.eq(email.attempts as i32))
+            .filter(Column::NextAttemptAt.eq(email.next_attempt_at.fixed_offset()))
+            .exec(&self.db)
+            .await
+            .map_err(database_error("postpone"))?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn save_attempt(&self, email: &OutboxEmail) -> Result<(), CoreError> {
+        OutboxEntity::update_many()
+            .col_expr(Column::Status, Expr::value(email.status.to_string()))
+            .col_expr(Column::Attempts, Expr::value(email.attempts as i32))
+            .col_expr(
+                Column::NextAttemptAt,
+                Expr::value(email.next_attempt_at.fixed_offset()),
+            )
+            .col_expr(Column::LastError, Expr::value(email.last_error.clone()))
+            .col_expr(
+                Column::SentAt,
+                Expr::value(email.sent_at.map(|sent_at| sent_at.fixed_offset())),
+            )
+            .col_expr(
+                Column::UpdatedAt,
+                Expr::value(email.updated_at.fixed_offset()),
+            )
+            .filter(Column::Id.eq(email.id))
+            .exec(&self.db)
+            .await
+            .map_err(database_error("update"))?;
+
+        Ok(())
+    }
+
+    async fn delete_finished_before(&self, before: DateTime<Utc>) -> Result<u64, CoreError> {
+        let result = OutboxEntity::delete_many()
+            .filter(Column::Status.is_in(FINISHED))
+            .filter(Column::UpdatedAt.lt(before.fixed_offset()))
+            .exec(&self.db)
+            .await
+            .map_err(database_error("purge"))?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn count_sent_since(
+        &self,
+        realm_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<u64, CoreError> {
+        OutboxEntity::find()
+            .filter(Column::RealmId.eq(realm_id))
+            .filter(Column::Status.eq(OutboxEmailStatus::Sent.to_string()))
+            .filter(Column::SentAt.gte(since.fixed_offset()))
+            .count(&self.db)
+            .await
+            .map_err(database_error("count"))
+    }
+}
@@ -0,0 +1 @@
+pub mod email_outbox_repository;
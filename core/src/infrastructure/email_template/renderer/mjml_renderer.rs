@@ -315,4 +315,21 @@ mod tests {
         let html = renderer.render_to_html(&mjml).unwrap();
         assert!(html.contains("Hello {{user.first_name}}"));
     }
+
+    #[test]
+    fn test_security_notification_default_templates_render() {
+        use crate::domain::{
+            email_template::entities::EmailType,
+            security_notification::entities::SecurityNotificationKind,
+        };
+
+        let renderer = MjmlTemplateRenderer::new();
+        for kind in SecurityNotificationKind::ALL {
+            let mjml = EmailType::from(kind).default_mjml().unwrap();
+            let html = renderer.render_to_html(&mjml).unwrap();
+
+            assert!(html.contains("{{notification.subject}}"), "{kind}");
+            assert!(html.contains("{{notification.body}}"), "{kind}");
+        }
+    }
 }
@@ -3,5 +3,7 @@ pub mod repositories;
 
 pub use repositories::PostgresBrokerAuthSessionRepository;
 pub use repositories::PostgresIdentityProviderLinkRepository;
+pub use repositories::PostgresIdentityProviderMapperRepository;
 pub use repositories::PostgresIdentityProviderRepository;
+pub use repositories::PostgresPendingBrokerLoginRepository;
 pub use repositories::ReqwestOAuthClient;
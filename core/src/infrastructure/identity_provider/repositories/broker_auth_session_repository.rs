@@ -1,6 +1,7 @@
 use chrono::Utc;
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    sea_query::Expr,
 };
 use tracing::instrument;
 use uuid::Uuid;
@@ -93,6 +94,27 @@ impl BrokerAuthSessionRepository for PostgresBrokerAuthSessionRepository {
         Ok(session)
     }
 
+    #[instrument(skip(self), fields(broker_state = %broker_state))]
+    async fn consume_broker_state(
+        &self,
+        broker_state: &str,
+    ) -> Result<Option<BrokerAuthSession>, CoreError> {
+        let consumed = BrokerAuthSessionEntity::update_many()
+            .col_expr(
+                Column::BrokerState,
+                Expr::value(format!("consumed:{}", generate_uuid_v7())),
+            )
+            .filter(Column::BrokerState.eq(broker_state))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to consume broker auth session state: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(consumed.into_iter().next().map(BrokerAuthSession::from))
+    }
+
     #[instrument(skip(self), fields(session_id = %id))]
     async fn delete(&self, id: Uuid) -> Result<(), CoreError> {
         BrokerAuthSessionEntity::delete_many()
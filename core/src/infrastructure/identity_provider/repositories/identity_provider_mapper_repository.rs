@@ -0,0 +1,192 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, SqlErr,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::abyss::identity_provider::IdentityProviderId;
+use crate::domain::abyss::identity_provider::mappers::{
+    CreateIdentityProviderMapperRequest, IdentityProviderMapper, IdentityProviderMapperRepository,
+    MapperSyncMode, UpdateIdentityProviderMapperRequest,
+};
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::common::generate_uuid_v7;
+use crate::entity::identity_provider_mappers::{
+    ActiveModel, Column, Entity as IdentityProviderMapperEntity, Model,
+};
+
+/// PostgreSQL implementation of the IdentityProviderMapperRepository trait
+#[derive(Debug, Clone)]
+pub struct PostgresIdentityProviderMapperRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresIdentityProviderMapperRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn sync_mode_to_db(sync_mode: MapperSyncMode) -> String {
+    match sync_mode {
+        MapperSyncMode::Import => "import",
+        MapperSyncMode::Force => "force",
+    }
+    .to_string()
+}
+
+fn write_error(action: &str, error: DbErr) -> CoreError {
+    match error.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => CoreError::AlreadyExists,
+        _ => {
+            tracing::error!("Failed to {} identity provider mapper: {}", action, error);
+            CoreError::InternalServerError
+        }
+    }
+}
+
+impl IdentityProviderMapperRepository for PostgresIdentityProviderMapperRepository {
+    #[instrument(skip(self, request), fields(idp_id = %request.identity_provider_id))]
+    async fn create_mapper(
+        &self,
+        request: CreateIdentityProviderMapperRequest,
+    ) -> Result<IdentityProviderMapper, CoreError> {
+        let now = Utc::now().fixed_offset();
+        let config = serde_json::to_value(&request.config).map_err(|e| {
+            tracing::error!("Failed to serialize identity provider mapper config: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        let payload = ActiveModel {
+            id: Set(generate_uuid_v7()),
+            identity_provider_id: Set(request.identity_provider_id.into()),
+            realm_id: Set(request.realm_id.into()),
+            name: Set(request.name),
+            sync_mode: Set(sync_mode_to_db(request.sync_mode)),
+            config: Set(config),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        let mapper = payload
+            .insert(&self.db)
+            .await
+            .map_err(|e| write_error("create", e))?;
+
+        IdentityProviderMapper::try_from(mapper)
+    }
+
+    #[instrument(skip(self), fields(idp_id = %identity_provider_id))]
+    async fn list_mappers_by_provider(
+        &self,
+        identity_provider_id: IdentityProviderId,
+    ) -> Result<Vec<IdentityProviderMapper>, CoreError> {
+        IdentityProviderMapperEntity::find()
+            .filter(Column::IdentityProviderId.eq::<Uuid>(identity_provider_id.into()))
+            .order_by_asc(Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to list identity provider mappers: {}", e);
+                CoreError::InternalServerError
+            })?
+            .into_iter()
+            .map(IdentityProviderMapper::try_from)
+            .collect()
+    }
+
+    #[instrument(skip(self), fields(mapper_id = %id))]
+    async fn get_mapper_by_id(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<IdentityProviderMapper>, CoreError> {
+        IdentityProviderMapperEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get identity provider mapper: {}", e);
+                CoreError::InternalServerError
+            })?
+            .map(IdentityProviderMapper::try_from)
+            .transpose()
+    }
+
+    #[instrument(skip(self, request), fields(mapper_id = %request.id))]
+    async fn update_mapper(
+        &self,
+        request: UpdateIdentityProviderMapperRequest,
+    ) -> Result<IdentityProviderMapper, CoreError> {
+        let existing = IdentityProviderMapperEntity::find_by_id(request.id)
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to find identity provider mapper for update: {}", e);
+                CoreError::InternalServerError
+            })?
+            .ok_or(CoreError::NotFound)?;
+
+        let config = serde_json::to_value(&request.config).map_err(|e| {
+            tracing::error!("Failed to serialize identity provider mapper config: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        let mut mapper: ActiveModel = existing.into();
+        mapper.name = Set(request.name);
+        mapper.sync_mode = Set(sync_mode_to_db(request.sync_mode));
+        mapper.config = Set(config);
+        mapper.updated_at = Set(Utc::now().fixed_offset());
+
+        let mapper = mapper
+            .update(&self.db)
+            .await
+            .map_err(|e| write_error("update", e))?;
+
+        IdentityProviderMapper::try_from(mapper)
+    }
+
+    #[instrument(skip(self), fields(mapper_id = %id))]
+    async fn delete_mapper(&self, id: Uuid) -> Result<(), CoreError> {
+        IdentityProviderMapperEntity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to delete identity provider mapper: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
+
+/// Convert from SeaORM model to domain entity
+impl TryFrom<Model> for IdentityProviderMapper {
+    type Error = CoreError;
+
+    fn try_from(model: Model) -> Result<Self, Self::Error> {
+        let sync_mode = match model.sync_mode.as_str() {
+            "force" => MapperSyncMode::Force,
+            _ => MapperSyncMode::Import,
+        };
+        let config = serde_json::from_value(model.config).map_err(|e| {
+            tracing::error!(
+                "Invalid config stored for identity provider mapper {}: {}",
+                model.id,
+                e
+            );
+            CoreError::InternalServerError
+        })?;
+
+        Ok(Self {
+            id: model.id,
+            realm_id: model.realm_id.into(),
+            identity_provider_id: model.identity_provider_id.into(),
+            name: model.name,
+            sync_mode,
+            config,
+            created_at: model.created_at.with_timezone(&Utc),
+            updated_at: model.updated_at.with_timezone(&Utc),
+        })
+    }
+}
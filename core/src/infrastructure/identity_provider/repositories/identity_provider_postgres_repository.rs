@@ -1,6 +1,6 @@
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait,
-    PaginatorTrait, QueryFilter, QueryOrder,
+    PaginatorTrait, QueryFilter, QueryOrder, sea_query::Expr,
 };
 use tracing::instrument;
 use uuid::Uuid;
@@ -210,6 +210,26 @@ impl IdentityProviderRepository for PostgresIdentityProviderRepository {
         Ok(())
     }
 
+    #[instrument(skip(self))]
+    async fn list_identity_providers_with_discovery(
+        &self,
+    ) -> Result<Vec<IdentityProvider>, CoreError> {
+        let identity_providers = IdentityProviderEntity::find()
+            .filter(Expr::cust("config ->> 'discovery_url' IS NOT NULL"))
+            .order_by_asc(Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to list identity providers with discovery: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(identity_providers
+            .into_iter()
+            .map(IdentityProvider::from)
+            .collect())
+    }
+
     #[instrument(skip(self), fields(realm_id = ?realm_id, alias = %alias))]
     async fn exists_identity_provider_by_realm_and_alias(
         &self,
@@ -2,8 +2,9 @@ use reqwest::Client;
 use tracing::instrument;
 
 use crate::domain::abyss::identity_provider::broker::{
-    BrokeredUserInfo, OAuthClient, OAuthTokenResponse,
+    BrokeredUserInfo, OAuthClient, OAuthTokenResponse, TokenEndpointAuth,
 };
+use crate::domain::abyss::identity_provider::discovery::OidcDiscoveryDocument;
 use crate::domain::common::entities::app_errors::CoreError;
 
 /// HTTP client implementation for OAuth operations with external IdPs
@@ -37,22 +38,19 @@ impl Default for ReqwestOAuthClient {
 }
 
 impl OAuthClient for ReqwestOAuthClient {
-    #[instrument(skip(self, client_secret, code_verifier), fields(token_url = %token_url))]
+    #[instrument(skip(self, auth, code_verifier), fields(token_url = %token_url))]
     async fn exchange_code(
         &self,
         token_url: &str,
         code: &str,
         redirect_uri: &str,
-        client_id: &str,
-        client_secret: &str,
+        auth: &TokenEndpointAuth,
         code_verifier: Option<&str>,
     ) -> Result<OAuthTokenResponse, CoreError> {
         let mut params = vec![
             ("grant_type", "authorization_code"),
             ("code", code),
             ("redirect_uri", redirect_uri),
-            ("client_id", client_id),
-            ("client_secret", client_secret),
         ];
 
         // Add PKCE code verifier if present
@@ -60,19 +58,44 @@ impl OAuthClient for ReqwestOAuthClient {
             params.push(("code_verifier", verifier));
         }
 
-        let response = self
+        let mut request = self
             .client
             .post(token_url)
             // GitHub (and some other IdPs) default to form-urlencoded token
             // responses unless the client explicitly asks for JSON.
-            .header(reqwest::header::ACCEPT, "application/json")
-            .form(&params)
-            .send()
-            .await
-            .map_err(|e| {
-                tracing::error!("Token exchange request failed: {}", e);
-                CoreError::IdpTokenExchangeFailed(format!("Request failed: {}", e))
-            })?;
+            .header(reqwest::header::ACCEPT, "application/json");
+
+        match auth {
+            TokenEndpointAuth::ClientSecretPost {
+                client_id,
+                client_secret,
+            } => {
+                params.push(("client_id", client_id));
+                params.push(("client_secret", client_secret));
+            }
+            TokenEndpointAuth::ClientSecretBasic {
+                client_id,
+                client_secret,
+            } => {
+                request = request.basic_auth(client_id, Some(client_secret));
+            }
+            TokenEndpointAuth::PrivateKeyJwt {
+                client_id,
+                client_assertion,
+            } => {
+                params.push(("client_id", client_id));
+                params.push((
+                    "client_assertion_type",
+                    "urn:ietf:params:oauth:client-assertion-type:jwt-bearer",
+                ));
+                params.push(("client_assertion", client_assertion));
+            }
+        }
+
+        let response = request.form(&params).send().await.map_err(|e| {
+            tracing::error!("Token exchange request failed: {}", e);
+            CoreError::IdpTokenExchangeFailed(format!("Request failed: {}", e))
+        })?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -110,6 +133,37 @@ impl OAuthClient for ReqwestOAuthClient {
         })
     }
 
+    #[instrument(skip(self), fields(discovery_url = %discovery_url))]
+    async fn fetch_discovery_document(
+        &self,
+        discovery_url: &str,
+    ) -> Result<OidcDiscoveryDocument, CoreError> {
+        let response = self.client.get(discovery_url).send().await.map_err(|e| {
+            tracing::error!("Discovery request failed: {}", e);
+            CoreError::InvalidProviderConfiguration(format!(
+                "discovery document could not be fetched: {}",
+                e
+            ))
+        })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            tracing::error!("Discovery request failed with status {}", status);
+            return Err(CoreError::InvalidProviderConfiguration(format!(
+                "discovery document request returned HTTP {}",
+                status
+            )));
+        }
+
+        response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse discovery document: {}", e);
+            CoreError::InvalidProviderConfiguration(format!(
+                "discovery document is not valid: {}",
+                e
+            ))
+        })
+    }
+
     #[instrument(skip(self, access_token), fields(userinfo_url = %userinfo_url))]
     async fn fetch_userinfo(
         &self,
@@ -174,6 +228,7 @@ impl OAuthClient for ReqwestOAuthClient {
                 .as_str()
                 .or_else(|| json["avatar_url"].as_str())
                 .map(|s| s.to_string()),
+            claims: json,
         };
 
         Ok(user_info)
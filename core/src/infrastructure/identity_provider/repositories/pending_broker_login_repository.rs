@@ -0,0 +1,220 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    sea_query::Expr,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::abyss::identity_provider::broker::PendingBrokerLogin;
+use crate::domain::abyss::identity_provider::broker::first_login::{
+    CreatePendingBrokerLoginRequest, UpdatePendingBrokerLoginRequest,
+};
+use crate::domain::abyss::identity_provider::broker::ports::PendingBrokerLoginRepository;
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::common::generate_uuid_v7;
+use crate::entity::pending_broker_logins::{
+    ActiveModel, Column, Entity as PendingBrokerLoginEntity, Model,
+};
+
+/// How long the user has to finish a brokered first login.
+const PENDING_BROKER_LOGIN_TTL_MINUTES: i64 = 15;
+
+/// PostgreSQL implementation of the PendingBrokerLoginRepository trait
+#[derive(Debug, Clone)]
+pub struct PostgresPendingBrokerLoginRepository {
+    db: DatabaseConnection,
+}
+
+impl PostgresPendingBrokerLoginRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> Result<PendingBrokerLogin, CoreError> {
+        PendingBrokerLoginEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get pending broker login: {}", e);
+                CoreError::InternalServerError
+            })?
+            .ok_or(CoreError::BrokerSessionNotFound)?
+            .try_into()
+    }
+}
+
+impl PendingBrokerLoginRepository for PostgresPendingBrokerLoginRepository {
+    #[instrument(skip(self, request), fields(realm_id = ?request.realm_id, stage = %request.stage))]
+    async fn create(
+        &self,
+        request: CreatePendingBrokerLoginRequest,
+    ) -> Result<PendingBrokerLogin, CoreError> {
+        let now = Utc::now();
+        let brokered_user = serde_json::to_value(&request.brokered_user).map_err(|e| {
+            tracing::error!("Failed to serialize brokered user: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        let payload = ActiveModel {
+            id: Set(generate_uuid_v7()),
+            realm_id: Set(request.realm_id.into()),
+            identity_provider_id: Set(request.identity_provider_id.into()),
+            broker_session_id: Set(request.broker_session_id),
+            token: Set(request.token),
+            stage: Set(request.stage.to_string()),
+            brokered_user: Set(brokered_user),
+            idp_access_token: Set(request.idp_access_token),
+            user_id: Set(request.user_id),
+            is_new_user: Set(request.is_new_user),
+            confirmation_code_hash: Set(None),
+            confirmation_code_expires_at: Set(None),
+            failed_attempts: Set(0),
+            compass_flow_id: Set(request.compass_flow_id),
+            created_at: Set(now.fixed_offset()),
+            expires_at: Set(
+                (now + chrono::Duration::minutes(PENDING_BROKER_LOGIN_TTL_MINUTES)).fixed_offset(),
+            ),
+        };
+
+        let login = payload.insert(&self.db).await.map_err(|e| {
+            tracing::error!("Failed to create pending broker login: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        login.try_into()
+    }
+
+    #[instrument(skip(self, token))]
+    async fn get_by_token(&self, token: &str) -> Result<Option<PendingBrokerLogin>, CoreError> {
+        PendingBrokerLoginEntity::find()
+            .filter(Column::Token.eq(token))
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get pending broker login by token: {}", e);
+                CoreError::InternalServerError
+            })?
+            .map(PendingBrokerLogin::try_from)
+            .transpose()
+    }
+
+    #[instrument(skip(self, request), fields(login_id = %id, stage = %request.stage))]
+    async fn update(
+        &self,
+        id: Uuid,
+        request: UpdatePendingBrokerLoginRequest,
+    ) -> Result<PendingBrokerLogin, CoreError> {
+        let brokered_user = serde_json::to_value(&request.brokered_user).map_err(|e| {
+            tracing::error!("Failed to serialize brokered user: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        PendingBrokerLoginEntity::update_many()
+            .col_expr(Column::Stage, Expr::value(request.stage.to_string()))
+            .col_expr(Column::BrokeredUser, Expr::value(brokered_user))
+            .col_expr(Column::UserId, Expr::value(request.user_id))
+            .col_expr(Column::IsNewUser, Expr::value(request.is_new_user))
+            .col_expr(Column::FailedAttempts, Expr::value(0))
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to update pending broker login: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        self.get_by_id(id).await
+    }
+
+    #[instrument(skip(self, code_hash), fields(login_id = %id))]
+    async fn set_confirmation_code(
+        &self,
+        id: Uuid,
+        code_hash: String,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), CoreError> {
+        PendingBrokerLoginEntity::update_many()
+            .col_expr(Column::ConfirmationCodeHash, Expr::value(code_hash))
+            .col_expr(
+                Column::ConfirmationCodeExpiresAt,
+                Expr::value(expires_at.fixed_offset()),
+            )
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to store broker link confirmation code: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(login_id = %id))]
+    async fn record_failed_attempt(&self, id: Uuid) -> Result<i32, CoreError> {
+        let updated = PendingBrokerLoginEntity::update_many()
+            .col_expr(
+                Column::FailedAttempts,
+                Expr::col(Column::FailedAttempts).add(1),
+            )
+            .filter(Column::Id.eq(id))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to record failed broker login attempt: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        updated
+            .first()
+            .map(|login| login.failed_attempts)
+            .ok_or(CoreError::BrokerSessionNotFound)
+    }
+
+    #[instrument(skip(self), fields(login_id = %id))]
+    async fn delete(&self, id: Uuid) -> Result<(), CoreError> {
+        PendingBrokerLoginEntity::delete_many()
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to delete pending broker login: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
+
+impl TryFrom<Model> for PendingBrokerLogin {
+    type Error = CoreError;
+
+    fn try_from(model: Model) -> Result<Self, Self::Error> {
+        let brokered_user = serde_json::from_value(model.brokered_user).map_err(|e| {
+            tracing::error!("Failed to deserialize brokered user: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(Self {
+            id: model.id,
+            realm_id: model.realm_id.into(),
+            identity_provider_id: model.identity_provider_id.into(),
+            broker_session_id: model.broker_session_id,
+            token: model.token,
+            stage: model.stage.parse()?,
+            brokered_user,
+            idp_access_token: model.idp_access_token,
+            user_id: model.user_id,
+            is_new_user: model.is_new_user,
+            confirmation_code_hash: model.confirmation_code_hash,
+            confirmation_code_expires_at: model
+                .confirmation_code_expires_at
+                .map(|expires_at| expires_at.with_timezone(&Utc)),
+            failed_attempts: model.failed_attempts,
+            compass_flow_id: model.compass_flow_id,
+            created_at: model.created_at.with_timezone(&Utc),
+            expires_at: model.expires_at.with_timezone(&Utc),
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_domain::realm::RealmId;
+use ferriskey_localization::{Locale, MessageBundle, MessageBundleRepository};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::message_bundles::{
+    ActiveModel as BundleActiveModel, Column as BundleColumn, Entity as BundleEntity,
+    Model as BundleModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresMessageBundleRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresMessageBundleRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn model_to_domain(model: BundleModel) -> Result<MessageBundle, CoreError> {
+    let locale = Locale::parse(&model.locale).map_err(|e| {
+        error!("Failed to decode message bundle locale: {}", e);
+        CoreError::InternalServerError
+    })?;
+    let messages = serde_json::from_value(model.messages).map_err(|e| {
+        error!("Failed to decode message bundle: {}", e);
+        CoreError::InternalServerError
+    })?;
+
+    Ok(MessageBundle {
+        realm_id: RealmId::new(model.realm_id),
+        locale,
+        messages,
+        created_at: model.created_at.with_timezone(&Utc),
+        updated_at: model.updated_at.with_timezone(&Utc),
+    })
+}
+
+impl MessageBundleRepository for PostgresMessageBundleRepository {
+    async fn list_by_realm(&self, realm_id: RealmId) -> Result<Vec<MessageBundle>, CoreError> {
+        let realm_id: Uuid = realm_id.into();
+        let models = BundleEntity::find()
+            .filter(BundleColumn::RealmId.eq(realm_id))
+            .order_by_asc(BundleColumn::Locale)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to list message bundles: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        models.into_iter().map(model_to_domain).collect()
+    }
+
+    async fn get(
+        &self,
+        realm_id: RealmId,
+        locale: Locale,
+    ) -> Result<Option<MessageBundle>, CoreError> {
+        let model = BundleEntity::find_by_id::<(Uuid, String)>((realm_id.into(), locale.into()))
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to get message bundle: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        model.map(model_to_domain).transpose()
+    }
+
+    async fn upsert(&self, bundle: MessageBundle) -> Result<MessageBundle, CoreError> {
+        let messages = serde_json::to_value(&bundle.messages).map_err(|e| {
+            error!("Failed to encode message bundle: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        let model = BundleEntity::insert(BundleActiveModel {
+            realm_id: Set(bundle.realm_id.into()),
+            locale: Set(bundle.locale.into()),
+            messages: Set(messages),
+            created_at: Set(bundle.created_at.fixed_offset()),
+            updated_at: Set(bundle.updated_at.fixed_offset()),
+        })
+        .on_conflict(
+            OnConflict::columns([BundleColumn::RealmId, BundleColumn::Locale])
+                .update_columns([BundleColumn::Messages, BundleColumn::UpdatedAt])
+                .to_owned(),
+        )
+        .exec_with_returning(&self.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to save message bundle: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        model_to_domain(model)
+    }
+
+    async fn delete(&self, realm_id: RealmId, locale: Locale) -> Result<bool, CoreError> {
+        let result = BundleEntity::delete_by_id::<(Uuid, String)>((realm_id.into(), locale.into()))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to delete message bundle: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
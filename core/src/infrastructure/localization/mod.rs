@@ -0,0 +1,2 @@
+pub mod bundle_repository;
+pub mod settings_repository;
@@ -0,0 +1,97 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{DatabaseConnection, EntityTrait};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_domain::realm::RealmId;
+use ferriskey_localization::{Locale, LocalizationSettings, LocalizationSettingsRepository};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::realm_localization_settings::{
+    ActiveModel as SettingsActiveModel, Column as SettingsColumn, Entity as SettingsEntity,
+    Model as SettingsModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresLocalizationSettingsRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresLocalizationSettingsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn model_to_domain(model: SettingsModel) -> Result<LocalizationSettings, CoreError> {
+    let supported_locales = serde_json::from_value(model.supported_locales).map_err(|e| {
+        error!("Failed to decode supported locales: {}", e);
+        CoreError::InternalServerError
+    })?;
+    let default_locale = Locale::parse(&model.default_locale).map_err(|e| {
+        error!("Failed to decode default locale: {}", e);
+        CoreError::InternalServerError
+    })?;
+
+    Ok(LocalizationSettings {
+        realm_id: RealmId::new(model.realm_id),
+        supported_locales,
+        default_locale,
+        created_at: model.created_at.with_timezone(&Utc),
+        updated_at: model.updated_at.with_timezone(&Utc),
+    })
+}
+
+impl LocalizationSettingsRepository for PostgresLocalizationSettingsRepository {
+    async fn get_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Option<LocalizationSettings>, CoreError> {
+        let model = SettingsEntity::find_by_id::<Uuid>(realm_id.into())
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to get localization settings: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        model.map(model_to_domain).transpose()
+    }
+
+    async fn upsert(
+        &self,
+        settings: LocalizationSettings,
+    ) -> Result<LocalizationSettings, CoreError> {
+        let supported_locales = serde_json::to_value(&settings.supported_locales).map_err(|e| {
+            error!("Failed to encode supported locales: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        let model = SettingsEntity::insert(SettingsActiveModel {
+            realm_id: Set(settings.realm_id.into()),
+            supported_locales: Set(supported_locales),
+            default_locale: Set(settings.default_locale.to_string()),
+            created_at: Set(settings.created_at.fixed_offset()),
+            updated_at: Set(settings.updated_at.fixed_offset()),
+        })
+        .on_conflict(
+            OnConflict::column(SettingsColumn::RealmId)
+                .update_columns([
+                    SettingsColumn::SupportedLocales,
+                    SettingsColumn::DefaultLocale,
+                    SettingsColumn::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(&self.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to save localization settings: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        model_to_domain(model)
+    }
+}
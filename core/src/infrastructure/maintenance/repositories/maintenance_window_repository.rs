@@ -0,0 +1,198 @@
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Condition, Expr};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::maintenance_windows::{ActiveModel, Column, Entity as WindowEntity, Model};
+use ferriskey_domain::maintenance::{
+    entities::{MaintenanceWindow, MaintenanceWindowStatus},
+    ports::MaintenanceWindowRepository,
+};
+use ferriskey_domain::realm::RealmId;
+
+#[derive(Clone, Debug)]
+pub struct PostgresMaintenanceWindowRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresMaintenanceWindowRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+const PENDING: [&str; 2] = ["scheduled", "active"];
+
+fn model_to_domain(model: Model) -> Result<MaintenanceWindow, CoreError> {
+    let decode_error = |field: &str| {
+        error!(
+            "Failed to decode maintenance window {}: {}",
+            model.id, field
+        );
+        CoreError::InternalServerError
+    };
+
+    Ok(MaintenanceWindow {
+        id: model.id,
+        realm_id: RealmId::new(model.realm_id),
+        client_id: model.client_id,
+        reason: model.reason.clone(),
+        session_strategy: model
+            .session_strategy
+            .parse()
+            .map_err(|_| decode_error("session_strategy"))?,
+        announce_at: model.announce_at.with_timezone(&Utc),
+        starts_at: model.starts_at.with_timezone(&Utc),
+        ends_at: model.ends_at.with_timezone(&Utc),
+        status: model.status.parse().map_err(|_| decode_error("status"))?,
+        affected_client_ids: serde_json::from_value(model.affected_client_ids.clone())
+            .map_err(|_| decode_error("affected_client_ids"))?,
+        created_by: model.created_by,
+        created_at: model.created_at.with_timezone(&Utc),
+        updated_at: model.updated_at.with_timezone(&Utc),
+    })
+}
+
+fn database_error(action: &str) -> impl FnOnce(sea_orm::DbErr) -> CoreError + '_ {
+    move |e| {
+        error!("Failed to {} maintenance window: {}", action, e);
+        CoreError::InternalServerError
+    }
+}
+
+impl MaintenanceWindowRepository for PostgresMaintenanceWindowRepository {
+    async fn create(&self, window: MaintenanceWindow) -> Result<MaintenanceWindow, CoreError> {
+        let model = ActiveModel {
+            id: Set(window.id),
+            realm_id: Set(window.realm_id.into()),
+            client_id: Set(window.client_id),
+            reason: Set(window.reason.clone()),
+            session_strategy: Set(window.session_strategy.to_string()),
+            announce_at: Set(window.announce_at.fixed_offset()),
+            starts_at: Set(window.starts_at.fixed_offset()),
+            ends_at: Set(window.ends_at.fixed_offset()),
+            status: Set(window.status.to_string()),
+            affected_client_ids: Set(serde_json::json!(window.affected_client_ids)),
+            created_by: Set(window.created_by),
+            created_at: Set(window.created_at.fixed_offset()),
+            updated_at: Set(window.updated_at.fixed_offset()),
+        }
+        .insert(&self.db)
+        .await
+        .map_err(database_error("create"))?;
+
+        model_to_domain(model)
+    }
+
+    async fn get(
+        &self,
+        realm_id: RealmId,
+        window_id: Uuid,
+    ) -> Result<Option<MaintenanceWindow>, CoreError> {
+        WindowEntity::find_by_id(window_id)
+            .filter(Column::RealmId.eq::<Uuid>(realm_id.into()))
+            .one(&self.db)
+            .await
+            .map_err(database_error("get"))?
+            .map(model_to_domain)
+            .transpose()
+    }
+
+    async fn list_by_realm(&self, realm_id: RealmId) -> Result<Vec<MaintenanceWindow>, CoreError> {
+        WindowEntity::find()
+            .filter(Column::RealmId.eq::<Uuid>(realm_id.into()))
+            .order_by_desc(Column::StartsAt)
+            .all(&self.db)
+            .await
+            .map_err(database_error("list"))?
+            .into_iter()
+            .map(model_to_domain)
+            .collect()
+    }
+
+    async fn list_pending(&self, realm_id: RealmId) -> Result<Vec<MaintenanceWindow>, CoreError> {
+        WindowEntity::find()
+            .filter(Column::RealmId.eq::<Uuid>(realm_id.into()))
+            .filter(Column::Status.is_in(PENDING))
+            .order_by_asc(Column::StartsAt)
+            .all(&self.db)
+            .await
+            .map_err(database_error("list"))?
+            .into_iter()
+            .map(model_to_domain)
+            .collect()
+    }
+
+    async fn list_due(&self, now: DateTime<Utc>) -> Result<Vec<MaintenanceWindow>, CoreError> {
+        let now = now.fixed_offset();
+
+        WindowEntity::find()
+            .filter(
+                Condition::any()
+                    .add(
+                        Condition::all()
+                            .add(Column::Status.eq("scheduled"))
+                            .add(Column::StartsAt.lte(now)),
+                    )
+                    .add(
+                        Condition::all()
+                            .add(Column::Status.eq("active"))
+                            .add(Column::EndsAt.lte(now)),
+                    ),
+            )
+            .order_by_asc(Column::StartsAt)
+            .all(&self.db)
+            .await
+            .map_err(database_error("list due"))?
+            .into_iter()
+            .map(model_to_domain)
+            .collect()
+    }
+
+    async fn start(
+        &self,
+        window_id: Uuid,
+        affected_client_ids: Vec<Uuid>,
+    ) -> Result<bool, CoreError> {
+        let result = WindowEntity::update_many()
+            .col_expr(
+                Column::Status,
+                Expr::value(MaintenanceWindowStatus::Active.to_string()),
+            )
+            .col_expr(
+                Column::AffectedClientIds,
+                Expr::value(serde_json::json!(affected_client_ids)),
+            )
+            .col_expr(Column::UpdatedAt, Expr::value(Utc::now().fixed_offset()))
+            .filter(Column::Id.eq(window_id))
+            .filter(Column::Status.eq(MaintenanceWindowStatus::Scheduled.to_string()))
+            .exec(&self.db)
+            .await
+            .map_err(database_error("start"))?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn transition(
+        &self,
+        window_id: Uuid,
+        from: MaintenanceWindowStatus,
+        to: MaintenanceWindowStatus,
+    ) -> Result<bool, CoreError> {
+        let result = WindowEntity::update_many()
+            .col_expr(Column::Status, Expr::value(to.to_string()))
+            .col_expr(Column::UpdatedAt, Expr::value(Utc::now().fixed_offset()))
+            .filter(Column::Id.eq(window_id))
+            .filter(Column::Status.eq(from.to_string()))
+            .exec(&self.db)
+            .await
+            .map_err(database_error("update"))?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
@@ -1,21 +1,31 @@
 pub mod abyss;
 pub mod aegis;
+pub mod cache;
 pub mod client;
 pub mod common;
 pub mod compass;
 pub mod db;
 pub mod email;
+pub mod email_outbox;
 pub mod email_template;
 pub mod health;
 pub mod identity_provider;
+pub mod localization;
 pub mod maintenance;
 pub mod migrate;
 pub mod organization;
+pub mod rate_limit;
 pub mod realm;
+pub mod realm_group;
 pub mod recovery_code;
 pub mod repositories;
+pub mod risk;
 pub mod role;
+pub mod saml;
 pub mod seawatch;
+pub mod security_notification;
 pub mod trident;
 pub mod user;
+pub mod user_profile;
+pub mod webauthn_policy;
 pub mod webhook;
@@ -0,0 +1,143 @@
+use reqwest::Client;
+use serde::Deserialize;
+use tracing::{error, instrument};
+
+use ferriskey_organization::DomainTxtResolver;
+
+use crate::domain::common::entities::app_errors::CoreError;
+
+const TXT_RECORD_TYPE: u16 = 16;
+const NXDOMAIN: u16 = 3;
+
+/// Resolves TXT records through a DNS-over-HTTPS JSON API (RFC 8484 JSON flavour, as served
+/// by Cloudflare and Google), at the endpoint set by `DNS_OVER_HTTPS_ENDPOINT`.
+#[derive(Debug, Clone)]
+pub struct DohTxtResolver {
+    client: Client,
+    endpoint: String,
+}
+
+impl DohTxtResolver {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        let client = Client::builder()
+            .user_agent(concat!("FerrisKey/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            client,
+            endpoint: endpoint.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DohResponse {
+    #[serde(rename = "Status")]
+    status: u16,
+    #[serde(rename = "Answer", default)]
+    answer: Vec<DohAnswer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DohAnswer {
+    #[serde(rename = "type")]
+    record_type: u16,
+    data: String,
+}
+
+/// TXT data comes back as one or more quoted character-strings; long records are split
+/// into 255-byte chunks that must be concatenated.
+fn unquote_txt(data: &str) -> String {
+    let data = data.trim();
+    if !data.starts_with('"') {
+        return data.to_string();
+    }
+
+    data.split('"')
+        .enumerate()
+        .filter(|(index, _)| index % 2 == 1)
+        .map(|(_, chunk)| chunk)
+        .collect()
+}
+
+fn txt_records(response: DohResponse) -> Result<Vec<String>, CoreError> {
+    match response.status {
+        0 => Ok(response
+            .answer
+            .into_iter()
+            .filter(|answer| answer.record_type == TXT_RECORD_TYPE)
+            .map(|answer| unquote_txt(&answer.data))
+            .collect()),
+        NXDOMAIN => Ok(Vec::new()),
+        status => Err(CoreError::ServiceUnavailable(format!(
+            "DNS lookup failed with status {status}"
+        ))),
+    }
+}
+
+impl DomainTxtResolver for DohTxtResolver {
+    #[instrument(skip(self))]
+    async fn lookup_txt(&self, name: &str) -> Result<Vec<String>, CoreError> {
+        let response = self
+            .client
+            .get(&self.endpoint)
+            .query(&[("name", name), ("type", "TXT")])
+            .header(reqwest::header::ACCEPT, "application/dns-json")
+            .send()
+            .await
+            .map_err(|e| {
+                error!("DNS-over-HTTPS request failed: {}", e);
+                CoreError::ServiceUnavailable("DNS lookup failed".to_string())
+            })?;
+
+        if !response.status().is_success() {
+            error!("DNS-over-HTTPS endpoint returned {}", response.status());
+            return Err(CoreError::ServiceUnavailable(
+                "DNS lookup failed".to_string(),
+            ));
+        }
+
+        let body: DohResponse = response.json().await.map_err(|e| {
+            error!("Failed to parse DNS-over-HTTPS response: {}", e);
+            CoreError::ServiceUnavailable("DNS lookup failed".to_string())
+        })?;
+
+        txt_records(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn txt_records_joins_split_strings_and_skips_other_types() {
+        let response: DohResponse = serde_json::from_str(
+            r#"{
+                "Status": 0,
+                "Answer": [
+                    {"name": "_ferriskey-challenge.acme.com", "type": 5, "data": "alias.acme.com."},
+                    {"name": "_ferriskey-challenge.acme.com", "type": 16, "data": "\"ferriskey-domain-\" \"verification=abc\""},
+                    {"name": "_ferriskey-challenge.acme.com", "type": 16, "data": "\"v=spf1 -all\""}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            txt_records(response).unwrap(),
+            vec![
+                "ferriskey-domain-verification=abc".to_string(),
+                "v=spf1 -all".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn txt_records_treats_nxdomain_as_empty() {
+        let response: DohResponse = serde_json::from_str(r#"{"Status": 3}"#).unwrap();
+
+        assert!(txt_records(response).unwrap().is_empty());
+    }
+}
@@ -1,9 +1,12 @@
+pub mod dns_resolver;
 pub mod group_attribute_repository;
 pub mod group_member_repository;
 pub mod group_repository;
 pub mod group_role_repository;
 pub mod group_token_repository;
 pub mod organization_attribute_repository;
+pub mod organization_domain_repository;
+pub mod organization_invitation_repository;
 pub mod organization_member_repository;
 pub mod organization_member_role_repository;
 pub mod organization_repository;
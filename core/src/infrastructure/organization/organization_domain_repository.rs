@@ -0,0 +1,114 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_domain::realm::RealmId;
+use ferriskey_organization::{
+    OrganizationDomain, OrganizationDomainRepository, OrganizationId, SaveOrganizationDomainParams,
+};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::organization_domains::{
+    ActiveModel as DomainActiveModel, Column as DomainColumn, Entity as DomainEntity,
+    Model as DomainModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresOrganizationDomainRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresOrganizationDomainRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn model_to_domain(model: DomainModel) -> OrganizationDomain {
+    OrganizationDomain {
+        organization_id: OrganizationId::new(model.organization_id),
+        realm_id: RealmId::new(model.realm_id),
+        domain: model.domain,
+        verification_token: model.verification_token,
+        verified_at: model.verified_at.map(|at| at.with_timezone(&Utc)),
+        identity_provider_id: model.identity_provider_id,
+        created_at: model.created_at.with_timezone(&Utc),
+        updated_at: model.updated_at.with_timezone(&Utc),
+    }
+}
+
+impl OrganizationDomainRepository for PostgresOrganizationDomainRepository {
+    async fn get_domain(
+        &self,
+        organization_id: OrganizationId,
+    ) -> Result<Option<OrganizationDomain>, CoreError> {
+        let model = DomainEntity::find_by_id(organization_id.as_uuid())
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to get organization domain: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(model.map(model_to_domain))
+    }
+
+    async fn save_domain(
+        &self,
+        params: SaveOrganizationDomainParams,
+    ) -> Result<OrganizationDomain, CoreError> {
+        let now = Utc::now().fixed_offset();
+
+        let model = DomainEntity::insert(DomainActiveModel {
+            organization_id: Set(params.organization_id.as_uuid()),
+            realm_id: Set(params.realm_id.into()),
+            domain: Set(params.domain),
+            verification_token: Set(params.verification_token),
+            verified_at: Set(params.verified_at.map(|at| at.fixed_offset())),
+            identity_provider_id: Set(params.identity_provider_id),
+            created_at: Set(now),
+            updated_at: Set(now),
+        })
+        .on_conflict(
+            OnConflict::column(DomainColumn::OrganizationId)
+                .update_columns([
+                    DomainColumn::Domain,
+                    DomainColumn::VerificationToken,
+                    DomainColumn::VerifiedAt,
+                    DomainColumn::IdentityProviderId,
+                    DomainColumn::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(&self.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to save organization domain: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(model_to_domain(model))
+    }
+
+    async fn find_verified_domain(
+        &self,
+        realm_id: RealmId,
+        domain: &str,
+    ) -> Result<Option<OrganizationDomain>, CoreError> {
+        let model = DomainEntity::find()
+            .filter(DomainColumn::RealmId.eq::<Uuid>(realm_id.into()))
+            .filter(DomainColumn::Domain.eq(domain))
+            .filter(DomainColumn::VerifiedAt.is_not_null())
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to find verified organization domain: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(model.map(model_to_domain))
+    }
+}
@@ -0,0 +1,200 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_organization::{
+    CreateInvitationParams, GroupId, InvitationStatus, OrganizationId, OrganizationInvitation,
+    OrganizationInvitationRepository,
+};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::common::generate_timestamp;
+use crate::entity::organization_invitations::{
+    ActiveModel as InvitationActiveModel, Column as InvitationColumn, Entity as InvitationEntity,
+    Model as InvitationModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresOrganizationInvitationRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresOrganizationInvitationRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn model_to_domain(model: InvitationModel) -> OrganizationInvitation {
+    let accepted_at = model.accepted_at.map(|at| at.with_timezone(&Utc));
+    let revoked_at = model.revoked_at.map(|at| at.with_timezone(&Utc));
+    let expires_at = model.expires_at.with_timezone(&Utc);
+
+    OrganizationInvitation {
+        id: model.id,
+        organization_id: OrganizationId::new(model.organization_id),
+        email: model.email,
+        role_id: model.role_id,
+        group_id: model.group_id.map(GroupId::new),
+        invited_by: model.invited_by,
+        status: InvitationStatus::of(accepted_at, revoked_at, expires_at, Utc::now()),
+        expires_at,
+        accepted_at,
+        accepted_by: model.accepted_by,
+        revoked_at,
+        created_at: model.created_at.with_timezone(&Utc),
+    }
+}
+
+impl OrganizationInvitationRepository for PostgresOrganizationInvitationRepository {
+    async fn create_invitation(
+        &self,
+        params: CreateInvitationParams,
+    ) -> Result<OrganizationInvitation, CoreError> {
+        let (_, timestamp) = generate_timestamp();
+
+        let model = InvitationEntity::insert(InvitationActiveModel {
+            id: Set(Uuid::new_v7(timestamp)),
+            organization_id: Set(params.organization_id.as_uuid()),
+            email: Set(params.email),
+            role_id: Set(params.role_id),
+            group_id: Set(params.group_id.map(|id| id.as_uuid())),
+            invited_by: Set(params.invited_by),
+            token_hash: Set(params.token_hash),
+            expires_at: Set(params.expires_at.fixed_offset()),
+            accepted_at: Set(None),
+            accepted_by: Set(None),
+            revoked_at: Set(None),
+            created_at: Set(Utc::now().fixed_offset()),
+        })
+        .exec_with_returning(&self.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to create organization invitation: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(model_to_domain(model))
+    }
+
+    async fn get_invitation_by_id(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<OrganizationInvitation>, CoreError> {
+        let model = InvitationEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to get organization invitation: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(model.map(model_to_domain))
+    }
+
+    async fn get_invitation_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<OrganizationInvitation>, CoreError> {
+        let model = InvitationEntity::find()
+            .filter(InvitationColumn::TokenHash.eq(token_hash))
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to get organization invitation by token: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(model.map(model_to_domain))
+    }
+
+    async fn list_invitations(
+        &self,
+        organization_id: OrganizationId,
+    ) -> Result<Vec<OrganizationInvitation>, CoreError> {
+        let models = InvitationEntity::find()
+            .filter(InvitationColumn::OrganizationId.eq(organization_id.as_uuid()))
+            .order_by_desc(InvitationColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to list organization invitations: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn revoke_pending_invitations(
+        &self,
+        organization_id: OrganizationId,
+        email: &str,
+    ) -> Result<u64, CoreError> {
+        let result = InvitationEntity::update_many()
+            .col_expr(
+                InvitationColumn::RevokedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(InvitationColumn::OrganizationId.eq(organization_id.as_uuid()))
+            .filter(InvitationColumn::Email.eq(email))
+            .filter(InvitationColumn::AcceptedAt.is_null())
+            .filter(InvitationColumn::RevokedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke pending organization invitations: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn revoke_invitation(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<OrganizationInvitation>, CoreError> {
+        let models = InvitationEntity::update_many()
+            .col_expr(
+                InvitationColumn::RevokedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(InvitationColumn::Id.eq(id))
+            .filter(InvitationColumn::AcceptedAt.is_null())
+            .filter(InvitationColumn::RevokedAt.is_null())
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke organization invitation: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(models.into_iter().next().map(model_to_domain))
+    }
+
+    async fn mark_invitation_accepted(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<OrganizationInvitation>, CoreError> {
+        let now = Utc::now().fixed_offset();
+
+        let models = InvitationEntity::update_many()
+            .col_expr(InvitationColumn::AcceptedAt, Expr::value(now))
+            .col_expr(InvitationColumn::AcceptedBy, Expr::value(user_id))
+            .filter(InvitationColumn::Id.eq(id))
+            .filter(InvitationColumn::AcceptedAt.is_null())
+            .filter(InvitationColumn::RevokedAt.is_null())
+            .filter(InvitationColumn::ExpiresAt.gt(now))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to accept organization invitation: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(models.into_iter().next().map(model_to_domain))
+    }
+}
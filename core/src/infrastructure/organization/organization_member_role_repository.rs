@@ -1,17 +1,37 @@
 use chrono::Utc;
 use sea_orm::ActiveValue::Set;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, EntityTrait, QueryFilter,
+    QuerySelect, Statement,
+};
 use tracing::error;
 use uuid::Uuid;
 
-use ferriskey_organization::OrganizationMemberRoleRepository;
+use ferriskey_domain::realm::RealmId;
+use ferriskey_organization::{OrganizationAdminRole, OrganizationMemberRoleRepository};
 
 use crate::domain::common::entities::app_errors::CoreError;
 use crate::domain::common::generate_timestamp;
+use crate::entity::organization_admin_roles::{
+    Column as AdminRoleColumn, Entity as AdminRoleEntity,
+};
 use crate::entity::organization_member_roles::{
     ActiveModel as RoleActiveModel, Column as RoleColumn, Entity as RoleEntity,
 };
 
+/// Creates an admin role unless the realm already holds a role under its name, and records
+/// only a role it actually created as seeded.
+const SEED_ADMIN_ROLE_SQL: &str = r#"
+WITH seeded AS (
+    INSERT INTO roles (id, name, description, permissions, realm_id, client_id, created_at, updated_at)
+    VALUES ($1, $2, $3, 0, $4, NULL, NOW(), NOW())
+    ON CONFLICT (name, realm_id) DO NOTHING
+    RETURNING id, realm_id, name
+)
+INSERT INTO organization_admin_roles (role_id, realm_id, admin_role)
+SELECT id, realm_id, name FROM seeded
+"#;
+
 #[derive(Debug, Clone)]
 pub struct PostgresOrganizationMemberRoleRepository {
     pub db: DatabaseConnection,
@@ -82,4 +102,59 @@ impl OrganizationMemberRoleRepository for PostgresOrganizationMemberRoleReposito
 
         Ok(ids)
     }
+
+    async fn seed_admin_roles(&self, realm_id: RealmId) -> Result<(), CoreError> {
+        let realm_id: Uuid = realm_id.into();
+
+        for admin_role in OrganizationAdminRole::ALL {
+            let (_, timestamp) = generate_timestamp();
+
+            self.db
+                .execute(Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    SEED_ADMIN_ROLE_SQL,
+                    [
+                        Uuid::new_v7(timestamp).into(),
+                        admin_role.role_name().into(),
+                        admin_role.description().into(),
+                        realm_id.into(),
+                    ],
+                ))
+                .await
+                .map_err(|e| {
+                    error!("Failed to seed organization admin role: {}", e);
+                    CoreError::InternalServerError
+                })?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_admin_roles(
+        &self,
+        realm_id: RealmId,
+        role_ids: Vec<Uuid>,
+    ) -> Result<Vec<(Uuid, OrganizationAdminRole)>, CoreError> {
+        if role_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = AdminRoleEntity::find()
+            .filter(AdminRoleColumn::RealmId.eq(Uuid::from(realm_id)))
+            .filter(AdminRoleColumn::RoleId.is_in(role_ids))
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to find organization admin roles: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                OrganizationAdminRole::from_role_name(&row.admin_role)
+                    .map(|admin_role| (row.role_id, admin_role))
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseBackend, DatabaseConnection, EntityTrait, QueryFilter,
+    Statement,
+};
+use tracing::error;
+
+use ferriskey_rate_limit::RateLimitCounterRepository;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::rate_limit_counters::{Column as CounterColumn, Entity as CounterEntity};
+
+#[derive(Debug, Clone)]
+pub struct PostgresRateLimitCounterRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresRateLimitCounterRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl RateLimitCounterRepository for PostgresRateLimitCounterRepository {
+    async fn increment(
+        &self,
+        key: String,
+        window_start: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<u32, CoreError> {
+        let row = self
+            .db
+            .query_one(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                "INSERT INTO rate_limit_counters (key, window_start, hits, expires_at) \
+                 VALUES ($1, $2, 1, $3) \
+                 ON CONFLICT (key, window_start) \
+                 DO UPDATE SET hits = rate_limit_counters.hits + 1 \
+                 RETURNING hits",
+                [
+                    key.into(),
+                    window_start.fixed_offset().into(),
+                    expires_at.fixed_offset().into(),
+                ],
+            ))
+            .await
+            .map_err(|e| {
+                error!("Failed to increment rate limit counter: {}", e);
+                CoreError::InternalServerError
+            })?
+            .ok_or(CoreError::InternalServerError)?;
+
+        let hits: i32 = row.try_get("", "hits").map_err(|e| {
+            error!("Failed to read rate limit counter: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(hits.max(0) as u32)
+    }
+
+    async fn purge_expired(&self, now: DateTime<Utc>) -> Result<u64, CoreError> {
+        let result = CounterEntity::delete_many()
+            .filter(CounterColumn::ExpiresAt.lt(now.fixed_offset()))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to purge expired rate limit counters: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(result.rows_affected)
+    }
+}
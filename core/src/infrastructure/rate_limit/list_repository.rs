@@ -0,0 +1,104 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, SqlErr};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_rate_limit::{RateLimitListEntry, RateLimitListRepository};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+use crate::entity::rate_limit_list_entries::{
+    ActiveModel as EntryActiveModel, Column as EntryColumn, Entity as EntryEntity,
+    Model as EntryModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresRateLimitListRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresRateLimitListRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn model_to_domain(model: EntryModel) -> Result<RateLimitListEntry, CoreError> {
+    let kind = model.kind.parse().map_err(|_| {
+        error!(entry_id = %model.id, "Invalid rate limit list kind in database");
+        CoreError::InternalServerError
+    })?;
+
+    Ok(RateLimitListEntry {
+        id: model.id,
+        realm_id: RealmId::new(model.realm_id),
+        kind,
+        cidr: model.cidr,
+        description: model.description,
+        created_at: model.created_at.with_timezone(&Utc),
+    })
+}
+
+impl RateLimitListRepository for PostgresRateLimitListRepository {
+    async fn list_by_realm(&self, realm_id: RealmId) -> Result<Vec<RateLimitListEntry>, CoreError> {
+        let models = EntryEntity::find()
+            .filter(EntryColumn::RealmId.eq::<Uuid>(realm_id.into()))
+            .order_by_asc(EntryColumn::Kind)
+            .order_by_asc(EntryColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to list rate limit list entries: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        models.into_iter().map(model_to_domain).collect()
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<RateLimitListEntry>, CoreError> {
+        let model = EntryEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to get rate limit list entry: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        model.map(model_to_domain).transpose()
+    }
+
+    async fn create(&self, entry: RateLimitListEntry) -> Result<RateLimitListEntry, CoreError> {
+        let model = EntryEntity::insert(EntryActiveModel {
+            id: Set(entry.id),
+            realm_id: Set(entry.realm_id.into()),
+            kind: Set(entry.kind.as_str().to_string()),
+            cidr: Set(entry.cidr),
+            description: Set(entry.description),
+            created_at: Set(entry.created_at.fixed_offset()),
+        })
+        .exec_with_returning(&self.db)
+        .await
+        .map_err(|e| match e.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => CoreError::AlreadyExists,
+            _ => {
+                error!("Failed to create rate limit list entry: {}", e);
+                CoreError::InternalServerError
+            }
+        })?;
+
+        model_to_domain(model)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), CoreError> {
+        EntryEntity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to delete rate limit list entry: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
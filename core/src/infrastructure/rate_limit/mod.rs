@@ -0,0 +1,3 @@
+pub mod counter_repository;
+pub mod list_repository;
+pub mod policy_repository;
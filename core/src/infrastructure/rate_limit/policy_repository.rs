@@ -0,0 +1,137 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder, SqlErr,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_rate_limit::{RateLimitPolicy, RateLimitPolicyRepository};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+use crate::entity::rate_limit_policies::{
+    ActiveModel as PolicyActiveModel, Column as PolicyColumn, Entity as PolicyEntity,
+    Model as PolicyModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresRateLimitPolicyRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresRateLimitPolicyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn write_error(action: &str, error: DbErr) -> CoreError {
+    match error.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => CoreError::AlreadyExists,
+        _ => {
+            error!("Failed to {} rate limit policy: {}", action, error);
+            CoreError::InternalServerError
+        }
+    }
+}
+
+fn model_to_domain(model: PolicyModel) -> Result<RateLimitPolicy, CoreError> {
+    let invalid = |field: &str| {
+        error!(policy_id = %model.id, "Invalid rate limit policy {} in database", field);
+        CoreError::InternalServerError
+    };
+
+    Ok(RateLimitPolicy {
+        id: model.id,
+        realm_id: RealmId::new(model.realm_id),
+        endpoint: model.endpoint.parse().map_err(|_| invalid("endpoint"))?,
+        key_kind: model.key_kind.parse().map_err(|_| invalid("key_kind"))?,
+        limit: u32::try_from(model.limit).map_err(|_| invalid("limit"))?,
+        window_seconds: u32::try_from(model.window_seconds).map_err(|_| invalid("window"))?,
+        block_seconds: u32::try_from(model.block_seconds).map_err(|_| invalid("block"))?,
+        shared: model.shared,
+        enabled: model.enabled,
+        created_at: model.created_at.with_timezone(&Utc),
+        updated_at: model.updated_at.with_timezone(&Utc),
+    })
+}
+
+fn domain_to_active_model(policy: RateLimitPolicy) -> PolicyActiveModel {
+    // Bounds are enforced by `RateLimitRule::validate`, well below `i32::MAX`.
+    PolicyActiveModel {
+        id: Set(policy.id),
+        realm_id: Set(policy.realm_id.into()),
+        endpoint: Set(policy.endpoint.as_str().to_string()),
+        key_kind: Set(policy.key_kind.as_str().to_string()),
+        limit: Set(policy.limit as i32),
+        window_seconds: Set(policy.window_seconds as i32),
+        block_seconds: Set(policy.block_seconds as i32),
+        shared: Set(policy.shared),
+        enabled: Set(policy.enabled),
+        created_at: Set(policy.created_at.fixed_offset()),
+        updated_at: Set(policy.updated_at.fixed_offset()),
+    }
+}
+
+impl RateLimitPolicyRepository for PostgresRateLimitPolicyRepository {
+    async fn list_by_realm(&self, realm_id: RealmId) -> Result<Vec<RateLimitPolicy>, CoreError> {
+        let models = PolicyEntity::find()
+            .filter(PolicyColumn::RealmId.eq::<Uuid>(realm_id.into()))
+            .order_by_asc(PolicyColumn::Endpoint)
+            .order_by_asc(PolicyColumn::KeyKind)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to list rate limit policies: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        models.into_iter().map(model_to_domain).collect()
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<RateLimitPolicy>, CoreError> {
+        let model = PolicyEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to get rate limit policy: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        model.map(model_to_domain).transpose()
+    }
+
+    async fn create(&self, policy: RateLimitPolicy) -> Result<RateLimitPolicy, CoreError> {
+        let model = PolicyEntity::insert(domain_to_active_model(policy))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| write_error("create", e))?;
+
+        model_to_domain(model)
+    }
+
+    async fn update(&self, policy: RateLimitPolicy) -> Result<RateLimitPolicy, CoreError> {
+        let model = PolicyEntity::update(domain_to_active_model(policy))
+            .exec(&self.db)
+            .await
+            .map_err(|e| match e {
+                DbErr::RecordNotUpdated => CoreError::NotFound,
+                e => write_error("update", e),
+            })?;
+
+        model_to_domain(model)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), CoreError> {
+        PolicyEntity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to delete rate limit policy: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
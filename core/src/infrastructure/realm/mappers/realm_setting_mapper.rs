@@ -24,6 +24,7 @@ impl From<Model> for RealmSetting {
             reset_password_template_id: value.reset_password_template_id,
             magic_link_template_id: value.magic_link_template_id,
             email_verification_template_id: value.email_verification_template_id,
+            organization_invitation_template_id: value.organization_invitation_template_id,
             email_verification_enabled: value.email_verification_enabled,
             email_verification_ttl_hours: value.email_verification_ttl_hours as i64,
             login_aliases: value
@@ -68,6 +69,7 @@ mod tests {
             reset_password_template_id: None,
             magic_link_template_id: None,
             email_verification_template_id: None,
+            organization_invitation_template_id: None,
             email_verification_enabled: false,
             email_verification_ttl_hours: 24,
             portal_theme_id: None,
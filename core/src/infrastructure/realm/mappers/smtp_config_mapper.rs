@@ -20,6 +20,9 @@ impl From<Model> for SmtpConfig {
                 .encryption
                 .parse()
                 .expect("invariant: SmtpEncryption FromStr is infallible"),
+            max_emails_per_minute: value
+                .max_emails_per_minute
+                .and_then(|limit| limit.try_into().ok()),
             created_at,
             updated_at,
         }
@@ -198,6 +198,7 @@ impl RealmRepository for PostgresRealmRepository {
         reset_password_template_id: Option<Option<Uuid>>,
         magic_link_template_id: Option<Option<Uuid>>,
         email_verification_template_id: Option<Option<Uuid>>,
+        organization_invitation_template_id: Option<Option<Uuid>>,
         email_verification_enabled: Option<bool>,
         email_verification_ttl_hours: Option<i64>,
         lockout_threshold: Option<i32>,
@@ -281,6 +282,10 @@ impl RealmRepository for PostgresRealmRepository {
             realm_setting.email_verification_template_id = Set(value);
         }
 
+        if let Some(value) = organization_invitation_template_id {
+            realm_setting.organization_invitation_template_id = Set(value);
+        }
+
         if let Some(value) = email_verification_enabled {
             realm_setting.email_verification_enabled = Set(value);
         }
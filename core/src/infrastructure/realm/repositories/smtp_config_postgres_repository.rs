@@ -55,6 +55,7 @@ impl SmtpConfigRepository for PostgresSmtpConfigRepository {
             active.from_email = Set(config.from_email.clone());
             active.from_name = Set(config.from_name.clone());
             active.encryption = Set(config.encryption.as_str().to_string());
+            active.max_emails_per_minute = Set(config.max_emails_per_minute.map(|l| l as i32));
             active.updated_at = Set(now);
 
             active
@@ -73,6 +74,7 @@ impl SmtpConfigRepository for PostgresSmtpConfigRepository {
                 from_email: Set(config.from_email.clone()),
                 from_name: Set(config.from_name.clone()),
                 encryption: Set(config.encryption.as_str().to_string()),
+                max_emails_per_minute: Set(config.max_emails_per_minute.map(|l| l as i32)),
                 created_at: Set(now),
                 updated_at: Set(now),
             };
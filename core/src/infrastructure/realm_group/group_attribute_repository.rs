@@ -0,0 +1,123 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_realm_group::{RealmGroupAttribute, RealmGroupAttributeRepository, RealmGroupId};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::common::generate_timestamp;
+use crate::entity::realm_group_attributes::{
+    ActiveModel as AttributeActiveModel, Column as AttributeColumn, Entity as AttributeEntity,
+    Model as AttributeModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresRealmGroupAttributeRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresRealmGroupAttributeRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn model_to_domain(model: AttributeModel) -> RealmGroupAttribute {
+    RealmGroupAttribute {
+        id: model.id,
+        group_id: RealmGroupId::new(model.group_id),
+        key: model.key,
+        value: model.value,
+        created_at: model.created_at.with_timezone(&Utc),
+    }
+}
+
+impl RealmGroupAttributeRepository for PostgresRealmGroupAttributeRepository {
+    async fn list_attributes(
+        &self,
+        group_id: RealmGroupId,
+    ) -> Result<Vec<RealmGroupAttribute>, CoreError> {
+        let models = AttributeEntity::find()
+            .filter(AttributeColumn::GroupId.eq(group_id.as_uuid()))
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to list realm group attributes: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn upsert_attribute(
+        &self,
+        group_id: RealmGroupId,
+        key: String,
+        value: String,
+    ) -> Result<RealmGroupAttribute, CoreError> {
+        let existing = AttributeEntity::find()
+            .filter(AttributeColumn::GroupId.eq(group_id.as_uuid()))
+            .filter(AttributeColumn::Key.eq(key.as_str()))
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to find realm group attribute: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        let model = if let Some(existing) = existing {
+            let active_model = AttributeActiveModel {
+                id: Set(existing.id),
+                group_id: Set(existing.group_id),
+                key: Set(existing.key),
+                value: Set(value),
+                created_at: Set(existing.created_at),
+            };
+
+            AttributeEntity::update(active_model)
+                .filter(AttributeColumn::Id.eq(existing.id))
+                .exec(&self.db)
+                .await
+                .map_err(|e| {
+                    error!("Failed to update realm group attribute: {}", e);
+                    CoreError::InternalServerError
+                })?
+        } else {
+            let (_, timestamp) = generate_timestamp();
+            let id = Uuid::new_v7(timestamp);
+            let now = Utc::now().fixed_offset();
+
+            AttributeEntity::insert(AttributeActiveModel {
+                id: Set(id),
+                group_id: Set(group_id.as_uuid()),
+                key: Set(key),
+                value: Set(value),
+                created_at: Set(now),
+            })
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to insert realm group attribute: {}", e);
+                CoreError::InternalServerError
+            })?
+        };
+
+        Ok(model_to_domain(model))
+    }
+
+    async fn delete_attribute(&self, group_id: RealmGroupId, key: &str) -> Result<(), CoreError> {
+        AttributeEntity::delete_many()
+            .filter(AttributeColumn::GroupId.eq(group_id.as_uuid()))
+            .filter(AttributeColumn::Key.eq(key))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to delete realm group attribute: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
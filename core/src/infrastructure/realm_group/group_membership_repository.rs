@@ -0,0 +1,169 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_realm_group::{RealmGroup, RealmGroupId, RealmGroupMembershipRepository};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+
+/// The user's direct realm groups plus every ancestor, resolved with a recursive CTE.
+const EFFECTIVE_GROUPS_SQL: &str = r#"
+WITH RECURSIVE user_groups AS (
+    SELECT g.id, g.realm_id, g.parent_group_id, g.name, g.description, g.created_at, g.updated_at
+    FROM realm_groups g
+    JOIN realm_group_members m ON m.group_id = g.id
+    WHERE m.user_id = $1
+  UNION
+    SELECT p.id, p.realm_id, p.parent_group_id, p.name, p.description, p.created_at, p.updated_at
+    FROM realm_groups p
+    JOIN user_groups ug ON ug.parent_group_id = p.id
+)
+SELECT DISTINCT id, realm_id, parent_group_id, name, description, created_at, updated_at
+FROM user_groups
+"#;
+
+/// Ids of the realm groups the user is a direct member of (no ancestor expansion).
+const DIRECT_GROUP_IDS_SQL: &str = r#"
+SELECT group_id
+FROM realm_group_members
+WHERE user_id = $1
+"#;
+
+/// Distinct role ids mapped to the user's effective (recursive) realm groups.
+const INHERITED_ROLE_IDS_SQL: &str = r#"
+WITH RECURSIVE user_groups AS (
+    SELECT g.id, g.parent_group_id
+    FROM realm_groups g
+    JOIN realm_group_members m ON m.group_id = g.id
+    WHERE m.user_id = $1
+  UNION
+    SELECT p.id, p.parent_group_id
+    FROM realm_groups p
+    JOIN user_groups ug ON ug.parent_group_id = p.id
+)
+SELECT DISTINCT gr.role_id
+FROM realm_group_roles gr
+JOIN user_groups ug ON ug.id = gr.group_id
+"#;
+
+/// One value per key across the user's effective groups. `depth` counts hops from a direct
+/// membership, so the closest group wins; ties fall back to the group name for stability.
+const INHERITED_ATTRIBUTES_SQL: &str = r#"
+WITH RECURSIVE user_groups AS (
+    SELECT g.id, g.parent_group_id, g.name, 0 AS depth
+    FROM realm_groups g
+    JOIN realm_group_members m ON m.group_id = g.id
+    WHERE m.user_id = $1
+  UNION
+    SELECT p.id, p.parent_group_id, p.name, ug.depth + 1
+    FROM realm_groups p
+    JOIN user_groups ug ON ug.parent_group_id = p.id
+)
+SELECT DISTINCT ON (a.key) a.key, a.value
+FROM realm_group_attributes a
+JOIN user_groups ug ON ug.id = a.group_id
+ORDER BY a.key, ug.depth, ug.name
+"#;
+
+#[derive(Debug, Clone)]
+pub struct PostgresRealmGroupMembershipRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresRealmGroupMembershipRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    async fn query_user(
+        &self,
+        sql: &str,
+        user_id: Uuid,
+        context: &str,
+    ) -> Result<Vec<sea_orm::QueryResult>, CoreError> {
+        self.db
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                sql,
+                [user_id.into()],
+            ))
+            .await
+            .map_err(|e| {
+                error!("Failed to resolve {} for user: {}", context, e);
+                CoreError::InternalServerError
+            })
+    }
+}
+
+impl RealmGroupMembershipRepository for PostgresRealmGroupMembershipRepository {
+    async fn list_effective_groups_for_user(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<RealmGroup>, CoreError> {
+        let rows = self
+            .query_user(EFFECTIVE_GROUPS_SQL, user_id, "effective realm groups")
+            .await?;
+
+        let mut groups = Vec::with_capacity(rows.len());
+        for row in rows {
+            let created_at: DateTime<FixedOffset> =
+                row.try_get("", "created_at").map_err(map_row_err)?;
+            let updated_at: DateTime<FixedOffset> =
+                row.try_get("", "updated_at").map_err(map_row_err)?;
+            let parent: Option<Uuid> = row.try_get("", "parent_group_id").map_err(map_row_err)?;
+
+            groups.push(RealmGroup {
+                id: RealmGroupId::new(row.try_get("", "id").map_err(map_row_err)?),
+                realm_id: RealmId::new(row.try_get("", "realm_id").map_err(map_row_err)?),
+                parent_group_id: parent.map(RealmGroupId::new),
+                name: row.try_get("", "name").map_err(map_row_err)?,
+                description: row.try_get("", "description").map_err(map_row_err)?,
+                created_at: created_at.with_timezone(&Utc),
+                updated_at: updated_at.with_timezone(&Utc),
+            });
+        }
+
+        Ok(groups)
+    }
+
+    async fn list_direct_group_ids_for_user(&self, user_id: Uuid) -> Result<Vec<Uuid>, CoreError> {
+        self.query_user(DIRECT_GROUP_IDS_SQL, user_id, "direct realm groups")
+            .await?
+            .into_iter()
+            .map(|row| row.try_get::<Uuid>("", "group_id").map_err(map_row_err))
+            .collect()
+    }
+
+    async fn list_inherited_role_ids_for_user(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<Uuid>, CoreError> {
+        self.query_user(INHERITED_ROLE_IDS_SQL, user_id, "realm group roles")
+            .await?
+            .into_iter()
+            .map(|row| row.try_get::<Uuid>("", "role_id").map_err(map_row_err))
+            .collect()
+    }
+
+    async fn list_inherited_attributes_for_user(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<(String, String)>, CoreError> {
+        self.query_user(INHERITED_ATTRIBUTES_SQL, user_id, "realm group attributes")
+            .await?
+            .into_iter()
+            .map(|row| {
+                let key: String = row.try_get("", "key").map_err(map_row_err)?;
+                let value: String = row.try_get("", "value").map_err(map_row_err)?;
+                Ok((key, value))
+            })
+            .collect()
+    }
+}
+
+fn map_row_err(e: sea_orm::DbErr) -> CoreError {
+    error!("Failed to read realm group row: {}", e);
+    CoreError::InternalServerError
+}
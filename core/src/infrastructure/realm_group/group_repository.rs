@@ -0,0 +1,141 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_realm_group::{
+    CreateRealmGroupParams, RealmGroup, RealmGroupId, RealmGroupRepository, UpdateRealmGroupParams,
+};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::common::generate_timestamp;
+use crate::domain::realm::entities::RealmId;
+use crate::entity::realm_groups::{
+    ActiveModel as GroupActiveModel, Column as GroupColumn, Entity as GroupEntity,
+    Model as GroupModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresRealmGroupRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresRealmGroupRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn model_to_domain(model: GroupModel) -> RealmGroup {
+    RealmGroup {
+        id: RealmGroupId::new(model.id),
+        realm_id: RealmId::new(model.realm_id),
+        parent_group_id: model.parent_group_id.map(RealmGroupId::new),
+        name: model.name,
+        description: model.description,
+        created_at: model.created_at.with_timezone(&Utc),
+        updated_at: model.updated_at.with_timezone(&Utc),
+    }
+}
+
+impl RealmGroupRepository for PostgresRealmGroupRepository {
+    async fn create_group(&self, params: CreateRealmGroupParams) -> Result<RealmGroup, CoreError> {
+        let (_, timestamp) = generate_timestamp();
+        let id = Uuid::new_v7(timestamp);
+        let now = Utc::now().fixed_offset();
+
+        let model = GroupEntity::insert(GroupActiveModel {
+            id: Set(id),
+            realm_id: Set(params.realm_id.into()),
+            parent_group_id: Set(params.parent_group_id.map(|p| p.as_uuid())),
+            name: Set(params.name),
+            description: Set(params.description),
+            created_at: Set(now),
+            updated_at: Set(now),
+        })
+        .exec_with_returning(&self.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to create realm group: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(model_to_domain(model))
+    }
+
+    async fn get_group_by_id(&self, id: RealmGroupId) -> Result<Option<RealmGroup>, CoreError> {
+        let model = GroupEntity::find_by_id(id.as_uuid())
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to get realm group: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(model.map(model_to_domain))
+    }
+
+    async fn list_groups_by_realm(&self, realm_id: RealmId) -> Result<Vec<RealmGroup>, CoreError> {
+        let models = GroupEntity::find()
+            .filter(GroupColumn::RealmId.eq::<Uuid>(realm_id.into()))
+            .order_by_asc(GroupColumn::Name)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to list realm groups: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn update_group(
+        &self,
+        id: RealmGroupId,
+        params: UpdateRealmGroupParams,
+    ) -> Result<RealmGroup, CoreError> {
+        let existing = GroupEntity::find_by_id(id.as_uuid())
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to load realm group for update: {}", e);
+                CoreError::InternalServerError
+            })?
+            .ok_or(CoreError::NotFound)?;
+
+        let mut active: GroupActiveModel = existing.into();
+        if let Some(name) = params.name {
+            active.name = Set(name);
+        }
+        if let Some(description) = params.description {
+            active.description = Set(Some(description));
+        }
+        if let Some(parent) = params.parent_group_id {
+            active.parent_group_id = Set(parent.map(|p| p.as_uuid()));
+        }
+        active.updated_at = Set(Utc::now().fixed_offset());
+
+        let model = GroupEntity::update(active)
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to update realm group: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(model_to_domain(model))
+    }
+
+    async fn delete_group(&self, id: RealmGroupId) -> Result<(), CoreError> {
+        GroupEntity::delete_by_id(id.as_uuid())
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to delete realm group: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_realm_group::{RealmGroupId, RealmGroupRoleMapping, RealmGroupRoleRepository};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::common::generate_timestamp;
+use crate::entity::realm_group_roles::{
+    ActiveModel as RoleActiveModel, Column as RoleColumn, Entity as RoleEntity, Model as RoleModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresRealmGroupRoleRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresRealmGroupRoleRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn model_to_domain(model: RoleModel) -> RealmGroupRoleMapping {
+    RealmGroupRoleMapping {
+        id: model.id,
+        group_id: RealmGroupId::new(model.group_id),
+        role_id: model.role_id,
+        created_at: model.created_at.with_timezone(&Utc),
+    }
+}
+
+impl RealmGroupRoleRepository for PostgresRealmGroupRoleRepository {
+    async fn assign_role(
+        &self,
+        group_id: RealmGroupId,
+        role_id: Uuid,
+    ) -> Result<RealmGroupRoleMapping, CoreError> {
+        let (_, timestamp) = generate_timestamp();
+        let id = Uuid::new_v7(timestamp);
+        let now = Utc::now().fixed_offset();
+
+        let model = RoleEntity::insert(RoleActiveModel {
+            id: Set(id),
+            group_id: Set(group_id.as_uuid()),
+            role_id: Set(role_id),
+            created_at: Set(now),
+        })
+        .exec_with_returning(&self.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to assign realm group role: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(model_to_domain(model))
+    }
+
+    async fn revoke_role(&self, group_id: RealmGroupId, role_id: Uuid) -> Result<(), CoreError> {
+        RoleEntity::delete_many()
+            .filter(RoleColumn::GroupId.eq(group_id.as_uuid()))
+            .filter(RoleColumn::RoleId.eq(role_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke realm group role: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+
+    async fn list_role_ids(&self, group_id: RealmGroupId) -> Result<Vec<Uuid>, CoreError> {
+        let ids = RoleEntity::find()
+            .filter(RoleColumn::GroupId.eq(group_id.as_uuid()))
+            .select_only()
+            .column(RoleColumn::RoleId)
+            .into_tuple::<Uuid>()
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to list realm group role ids: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(ids)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod group_attribute_repository;
+pub mod group_member_repository;
+pub mod group_membership_repository;
+pub mod group_repository;
+pub mod group_role_repository;
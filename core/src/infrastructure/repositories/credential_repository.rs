@@ -14,7 +14,7 @@ use webauthn_rs::prelude::{AuthenticationResult, CredentialID, Passkey};
 use crate::domain::{
     common::{generate_timestamp, generate_uuid_v7},
     credential::{
-        entities::{Credential, CredentialData, CredentialError},
+        entities::{AuthenticatorInfo, Credential, CredentialData, CredentialError},
         ports::CredentialRepository,
     },
     crypto::HashResult,
@@ -33,6 +33,16 @@ impl From<crate::entity::credentials::Model> for Credential {
             });
 
         let webauthn_credential_id = model.webauthn_credential_id.map(CredentialID::from);
+        let authenticator = webauthn_credential_id.is_some().then(|| AuthenticatorInfo {
+            aaguid: model.aaguid,
+            name: model.authenticator_name,
+            sign_count: model
+                .sign_count
+                .map(|count| count.clamp(0, u32::MAX.into()) as u32),
+            last_used_at: model
+                .last_used_at
+                .map(|last_used_at| Utc.from_utc_datetime(&last_used_at)),
+        });
 
         Self {
             id: model.id,
@@ -46,6 +56,7 @@ impl From<crate::entity::credentials::Model> for Credential {
             created_at,
             updated_at,
             webauthn_credential_id,
+            authenticator,
         }
     }
 }
@@ -91,6 +102,10 @@ impl CredentialRepository for PostgresCredentialRepository {
             updated_at: Set(now.naive_utc()),
             temporary: Set(Some(temporary)), // Assuming credentials are not temporary by default
             webauthn_credential_id: Set(None),
+            aaguid: Set(None),
+            authenticator_name: Set(None),
+            sign_count: Set(None),
+            last_used_at: Set(None),
         };
 
         let t = payload.insert(&self.db).await.map_err(|e| {
@@ -195,6 +210,10 @@ impl CredentialRepository for PostgresCredentialRepository {
             updated_at: Set(now.naive_utc()),
             temporary: Set(Some(false)), // Assuming custom credentials are not temporary
             webauthn_credential_id: Set(None),
+            aaguid: Set(None),
+            authenticator_name: Set(None),
+            sign_count: Set(None),
+            last_used_at: Set(None),
         };
 
         let model = payload
@@ -238,6 +257,10 @@ impl CredentialRepository for PostgresCredentialRepository {
                 updated_at: Set(now.naive_utc()),
                 temporary: Set(Some(false)),
                 webauthn_credential_id: Set(None),
+                aaguid: Set(None),
+                authenticator_name: Set(None),
+                sign_count: Set(None),
+                last_used_at: Set(None),
             });
 
         let _ = CredentialEntity::insert_many(models)
@@ -252,6 +275,7 @@ impl CredentialRepository for PostgresCredentialRepository {
         &self,
         user_id: uuid::Uuid,
         webauthn_credential: Passkey,
+        authenticator: AuthenticatorInfo,
     ) -> Result<Credential, CredentialError> {
         let (now, _) = generate_timestamp();
 
@@ -273,6 +297,10 @@ impl CredentialRepository for PostgresCredentialRepository {
             updated_at: Set(now.naive_utc()),
             temporary: Set(Some(false)),
             webauthn_credential_id: Set(Some(credential_id)),
+            aaguid: Set(authenticator.aaguid),
+            authenticator_name: Set(authenticator.name),
+            sign_count: Set(authenticator.sign_count.map(i64::from)),
+            last_used_at: Set(None),
         };
 
         let model = payload
@@ -334,12 +362,13 @@ impl CredentialRepository for PostgresCredentialRepository {
 
         let credential: Credential = credential_model.clone().into();
 
-        let update_res;
         let updated_data = match credential.credential_data {
             CredentialData::WebAuthn { credential } => {
                 let mut passkey = Passkey::from(*credential);
 
-                update_res = passkey
+                // `None` when the result is for another credential, which the lookup above
+                // rules out.
+                passkey
                     .update_credential(auth_result)
                     .ok_or(CredentialError::UpdateCredentialError)?;
 
@@ -353,13 +382,16 @@ impl CredentialRepository for PostgresCredentialRepository {
         let updated_data = serde_json::to_value(updated_data)
             .map_err(|_| CredentialError::UpdateCredentialError)?;
 
+        let (now, _) = generate_timestamp();
         let mut active_model = credential_model.into_active_model();
         active_model.credential_data = Set(updated_data);
+        active_model.sign_count = Set(Some(auth_result.counter().into()));
+        active_model.last_used_at = Set(Some(now.naive_utc()));
         active_model
             .update(&self.db)
             .await
             .map_err(|_| CredentialError::UpdateCredentialError)?;
 
-        Ok(update_res)
+        Ok(auth_result.needs_update())
     }
 }
@@ -0,0 +1,60 @@
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use maxminddb::{Reader, geoip2};
+use tracing::{info, warn};
+
+use ferriskey_risk::{GeoIpLookup, GeoLocation};
+
+/// GeoIP lookups against a MaxMind-format (`.mmdb`) database. Without a database every
+/// lookup misses, which turns off the country and travel signals.
+#[derive(Clone, Default)]
+pub struct MaxMindGeoIpLookup {
+    reader: Option<Arc<Reader<Vec<u8>>>>,
+}
+
+impl MaxMindGeoIpLookup {
+    pub fn new(path: Option<&str>) -> Self {
+        let Some(path) = path else {
+            return Self::default();
+        };
+
+        match Reader::open_readfile(path) {
+            Ok(reader) => {
+                info!("Loaded GeoIP database from {}", path);
+                Self {
+                    reader: Some(Arc::new(reader)),
+                }
+            }
+            Err(e) => {
+                warn!("Failed to open GeoIP database {}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+}
+
+impl fmt::Debug for MaxMindGeoIpLookup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MaxMindGeoIpLookup")
+            .field("loaded", &self.reader.is_some())
+            .finish()
+    }
+}
+
+impl GeoIpLookup for MaxMindGeoIpLookup {
+    fn lookup(&self, ip: IpAddr) -> Option<GeoLocation> {
+        let city = self.reader.as_ref()?.lookup::<geoip2::City>(ip).ok()?;
+        let location = city.location.as_ref();
+
+        Some(GeoLocation {
+            country: city
+                .country
+                .and_then(|country| country.iso_code)
+                .map(str::to_string),
+            latitude: location.and_then(|location| location.latitude),
+            longitude: location.and_then(|location| location.longitude),
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_domain::realm::RealmId;
+use ferriskey_risk::{LoginHistoryRepository, LoginRecord};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::login_history::{
+    ActiveModel as HistoryActiveModel, Column as HistoryColumn, Entity as HistoryEntity,
+    Model as HistoryModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresLoginHistoryRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresLoginHistoryRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn model_to_domain(model: HistoryModel) -> LoginRecord {
+    LoginRecord {
+        id: model.id,
+        realm_id: RealmId::new(model.realm_id),
+        user_id: model.user_id,
+        ip_address: model.ip_address,
+        user_agent: model.user_agent,
+        device_fingerprint: model.device_fingerprint,
+        country: model.country,
+        latitude: model.latitude,
+        longitude: model.longitude,
+        risk_score: model.risk_score.clamp(0, u8::MAX.into()) as u8,
+        // Signals are informational; an entry written by another version should not make
+        // the whole history unreadable.
+        signals: serde_json::from_value(model.signals).unwrap_or_default(),
+        created_at: model.created_at.with_timezone(&Utc),
+    }
+}
+
+impl LoginHistoryRepository for PostgresLoginHistoryRepository {
+    async fn list_recent(&self, user_id: Uuid, limit: u64) -> Result<Vec<LoginRecord>, CoreError> {
+        let models = HistoryEntity::find()
+            .filter(HistoryColumn::UserId.eq(user_id))
+            .order_by_desc(HistoryColumn::CreatedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to list login history: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn record(&self, record: LoginRecord) -> Result<(), CoreError> {
+        let signals = serde_json::to_value(&record.signals).map_err(|e| {
+            error!("Failed to serialize login risk signals: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        HistoryEntity::insert(HistoryActiveModel {
+            id: Set(record.id),
+            realm_id: Set(record.realm_id.into()),
+            user_id: Set(record.user_id),
+            ip_address: Set(record.ip_address),
+            user_agent: Set(record.user_agent),
+            device_fingerprint: Set(record.device_fingerprint),
+            country: Set(record.country),
+            latitude: Set(record.latitude),
+            longitude: Set(record.longitude),
+            risk_score: Set(record.risk_score.into()),
+            signals: Set(signals),
+            created_at: Set(record.created_at.fixed_offset()),
+        })
+        .exec_without_returning(&self.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to record login history: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(())
+    }
+
+    async fn purge_older_than(&self, cutoff: DateTime<Utc>) -> Result<u64, CoreError> {
+        let result = HistoryEntity::delete_many()
+            .filter(HistoryColumn::CreatedAt.lt(cutoff.fixed_offset()))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to purge login history: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod geoip;
+pub mod history_repository;
+pub mod policy_repository;
@@ -0,0 +1,105 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{DatabaseConnection, EntityTrait};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_domain::realm::RealmId;
+use ferriskey_risk::{LoginRiskPolicy, LoginRiskPolicyRepository, LoginRiskSettings};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::login_risk_policies::{
+    ActiveModel as PolicyActiveModel, Column as PolicyColumn, Entity as PolicyEntity,
+    Model as PolicyModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresLoginRiskPolicyRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresLoginRiskPolicyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+// Scores and thresholds are bounded by CHECK constraints, so the narrowing never clamps
+// a stored value.
+fn score(value: i16) -> u8 {
+    value.clamp(0, u8::MAX.into()) as u8
+}
+
+fn model_to_domain(model: PolicyModel) -> LoginRiskPolicy {
+    LoginRiskPolicy {
+        realm_id: RealmId::new(model.realm_id),
+        settings: LoginRiskSettings {
+            enabled: model.enabled,
+            new_device_score: score(model.new_device_score),
+            new_country_score: score(model.new_country_score),
+            impossible_travel_score: score(model.impossible_travel_score),
+            max_travel_speed_kmh: model.max_travel_speed_kmh.max(0) as u32,
+            step_up_threshold: model.step_up_threshold.map(score),
+            notify_threshold: model.notify_threshold.map(score),
+            block_threshold: model.block_threshold.map(score),
+        },
+        created_at: model.created_at.with_timezone(&Utc),
+        updated_at: model.updated_at.with_timezone(&Utc),
+    }
+}
+
+impl LoginRiskPolicyRepository for PostgresLoginRiskPolicyRepository {
+    async fn get_by_realm(&self, realm_id: RealmId) -> Result<Option<LoginRiskPolicy>, CoreError> {
+        let model = PolicyEntity::find_by_id::<Uuid>(realm_id.into())
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to get login risk policy: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(model.map(model_to_domain))
+    }
+
+    async fn upsert(&self, policy: LoginRiskPolicy) -> Result<LoginRiskPolicy, CoreError> {
+        let settings = policy.settings;
+
+        let model = PolicyEntity::insert(PolicyActiveModel {
+            realm_id: Set(policy.realm_id.into()),
+            enabled: Set(settings.enabled),
+            new_device_score: Set(settings.new_device_score.into()),
+            new_country_score: Set(settings.new_country_score.into()),
+            impossible_travel_score: Set(settings.impossible_travel_score.into()),
+            max_travel_speed_kmh: Set(settings.max_travel_speed_kmh.min(i32::MAX as u32) as i32),
+            step_up_threshold: Set(settings.step_up_threshold.map(i16::from)),
+            notify_threshold: Set(settings.notify_threshold.map(i16::from)),
+            block_threshold: Set(settings.block_threshold.map(i16::from)),
+            created_at: Set(policy.created_at.fixed_offset()),
+            updated_at: Set(policy.updated_at.fixed_offset()),
+        })
+        .on_conflict(
+            OnConflict::column(PolicyColumn::RealmId)
+                .update_columns([
+                    PolicyColumn::Enabled,
+                    PolicyColumn::NewDeviceScore,
+                    PolicyColumn::NewCountryScore,
+                    PolicyColumn::ImpossibleTravelScore,
+                    PolicyColumn::MaxTravelSpeedKmh,
+                    PolicyColumn::StepUpThreshold,
+                    PolicyColumn::NotifyThreshold,
+                    PolicyColumn::BlockThreshold,
+                    PolicyColumn::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(&self.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to save login risk policy: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(model_to_domain(model))
+    }
+}
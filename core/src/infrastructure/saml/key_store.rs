@@ -0,0 +1,290 @@
+//! RSA operations of the SAML bindings, with OpenSSL. The realm signs with its token signing
+//! key, published in metadata as a self-signed certificate that is issued on first use and
+//! reissued when the key changes.
+
+use base64::prelude::{BASE64_STANDARD, Engine as _};
+use chrono::Utc;
+use ferriskey_security::jwt::ports::KeyStoreRepository;
+use openssl::asn1::{Asn1Integer, Asn1Time};
+use openssl::bn::BigNum;
+use openssl::hash::MessageDigest;
+use openssl::pkey::{PKey, Private};
+use openssl::rand::rand_bytes;
+use openssl::rsa::Padding;
+use openssl::sign::{Signer, Verifier};
+use openssl::symm::{Cipher, encrypt};
+use openssl::x509::{X509, X509NameBuilder};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::*;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::authentication::saml::entities::{SamlEncryptedData, SamlSignatureAlgorithm};
+use crate::domain::authentication::saml::ports::SamlKeyStore;
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+use crate::entity::{jwt_keys, saml_signing_certificates};
+use crate::infrastructure::repositories::keystore_repository::PostgresKeyStoreRepository;
+
+const CERTIFICATE_VALIDITY_DAYS: u32 = 3650;
+
+fn crypto_error(e: impl std::fmt::Display) -> CoreError {
+    error!("SAML key operation failed: {}", e);
+    CoreError::InternalServerError
+}
+
+#[derive(Clone, Debug)]
+pub struct OpenSslSamlKeyStore {
+    db: DatabaseConnection,
+    keystore: PostgresKeyStoreRepository,
+}
+
+impl OpenSslSamlKeyStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self {
+            keystore: PostgresKeyStoreRepository::new(db.clone()),
+            db,
+        }
+    }
+
+    /// The realm's signing key, generated like for tokens when the realm has none yet.
+    async fn private_key(&self, realm_id: RealmId) -> Result<(Uuid, PKey<Private>), CoreError> {
+        let key_pair = self
+            .keystore
+            .get_or_generate_key(realm_id)
+            .await
+            .map_err(|_| CoreError::RealmKeyNotFound)?;
+
+        let key = jwt_keys::Entity::find_by_id(key_pair.id)
+            .one(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?
+            .ok_or(CoreError::RealmKeyNotFound)?;
+
+        let pkey = PKey::private_key_from_pem(key.private_key.as_bytes()).map_err(crypto_error)?;
+
+        Ok((key.id, pkey))
+    }
+}
+
+fn self_signed_certificate(realm_id: RealmId, key: &PKey<Private>) -> Result<X509, CoreError> {
+    let mut name = X509NameBuilder::new().map_err(crypto_error)?;
+    name.append_entry_by_text("CN", &format!("ferriskey-{}", Uuid::from(realm_id)))
+        .map_err(crypto_error)?;
+    let name = name.build();
+
+    let mut serial = [0u8; 16];
+    rand_bytes(&mut serial).map_err(crypto_error)?;
+    serial[0] &= 0x7f;
+    let serial = BigNum::from_slice(&serial)
+        .and_then(|bn| Asn1Integer::from_bn(&bn))
+        .map_err(crypto_error)?;
+
+    let mut builder = X509::builder().map_err(crypto_error)?;
+    builder.set_version(2).map_err(crypto_error)?;
+    builder.set_serial_number(&serial).map_err(crypto_error)?;
+    builder.set_subject_name(&name).map_err(crypto_error)?;
+    builder.set_issuer_name(&name).map_err(crypto_error)?;
+    builder.set_pubkey(key).map_err(crypto_error)?;
+    let not_before = Asn1Time::days_from_now(0).map_err(crypto_error)?;
+    let not_after = Asn1Time::days_from_now(CERTIFICATE_VALIDITY_DAYS).map_err(crypto_error)?;
+    builder.set_not_before(&not_before).map_err(crypto_error)?;
+    builder.set_not_after(&not_after).map_err(crypto_error)?;
+    builder
+        .sign(key, MessageDigest::sha256())
+        .map_err(crypto_error)?;
+
+    Ok(builder.build())
+}
+
+fn certificate_from_base64(certificate: &str) -> Result<X509, CoreError> {
+    let compact: String = certificate.split_whitespace().collect();
+    let der = BASE64_STANDARD
+        .decode(compact)
+        .map_err(|_| CoreError::InvalidSamlRequest("certificate is not base64".to_string()))?;
+
+    X509::from_der(&der)
+        .map_err(|_| CoreError::InvalidSamlRequest("certificate is not X.509 DER".to_string()))
+}
+
+fn digest(algorithm: SamlSignatureAlgorithm) -> MessageDigest {
+    match algorithm {
+        SamlSignatureAlgorithm::RsaSha1 => MessageDigest::sha1(),
+        SamlSignatureAlgorithm::RsaSha256 => MessageDigest::sha256(),
+    }
+}
+
+fn sign_with(key: &PKey<Private>, data: &[u8]) -> Result<Vec<u8>, CoreError> {
+    let mut signer = Signer::new(MessageDigest::sha256(), key).map_err(crypto_error)?;
+    signer.update(data).map_err(crypto_error)?;
+    signer.sign_to_vec().map_err(crypto_error)
+}
+
+fn verify_with(
+    certificate: &str,
+    algorithm: SamlSignatureAlgorithm,
+    data: &[u8],
+    signature: &[u8],
+) -> Result<bool, CoreError> {
+    let key = certificate_from_base64(certificate)?
+        .public_key()
+        .map_err(crypto_error)?;
+
+    let mut verifier = Verifier::new(digest(algorithm), &key).map_err(crypto_error)?;
+    verifier.update(data).map_err(crypto_error)?;
+
+    // A malformed signature is a failed verification, not a server error.
+    Ok(verifier.verify(signature).unwrap_or(false))
+}
+
+fn encrypt_for(certificate: &str, plaintext: &[u8]) -> Result<SamlEncryptedData, CoreError> {
+    let rsa = certificate_from_base64(certificate)?
+        .public_key()
+        .and_then(|key| key.rsa())
+        .map_err(|_| {
+            CoreError::InvalidSamlRequest("encryption certificate must hold an RSA key".to_string())
+        })?;
+
+    let mut key = [0u8; 32];
+    let mut iv = [0u8; 16];
+    rand_bytes(&mut key).map_err(crypto_error)?;
+    rand_bytes(&mut iv).map_err(crypto_error)?;
+
+    let mut cipher = iv.to_vec();
+    cipher
+        .extend(encrypt(Cipher::aes_256_cbc(), &key, Some(&iv), plaintext).map_err(crypto_error)?);
+
+    let mut encrypted_key = vec![0u8; rsa.size() as usize];
+    let len = rsa
+        .public_encrypt(&key, &mut encrypted_key, Padding::PKCS1_OAEP)
+        .map_err(crypto_error)?;
+    encrypted_key.truncate(len);
+
+    Ok(SamlEncryptedData {
+        encrypted_key,
+        cipher,
+    })
+}
+
+impl SamlKeyStore for OpenSslSamlKeyStore {
+    async fn signing_certificate(&self, realm_id: RealmId) -> Result<String, CoreError> {
+        let (key_id, key) = self.private_key(realm_id).await?;
+
+        let stored = saml_signing_certificates::Entity::find_by_id(Uuid::from(realm_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+        if let Some(stored) = stored
+            && stored.key_id == key_id
+        {
+            return Ok(stored.certificate);
+        }
+
+        let certificate = self_signed_certificate(realm_id, &key)?
+            .to_der()
+            .map(|der| BASE64_STANDARD.encode(der))
+            .map_err(crypto_error)?;
+
+        saml_signing_certificates::Entity::insert(saml_signing_certificates::ActiveModel {
+            realm_id: Set(realm_id.into()),
+            key_id: Set(key_id),
+            certificate: Set(certificate.clone()),
+            created_at: Set(Utc::now().fixed_offset()),
+        })
+        .on_conflict(
+            OnConflict::column(saml_signing_certificates::Column::RealmId)
+                .update_columns([
+                    saml_signing_certificates::Column::KeyId,
+                    saml_signing_certificates::Column::Certificate,
+                    saml_signing_certificates::Column::CreatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec(&self.db)
+        .await
+        .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        Ok(certificate)
+    }
+
+    async fn sign(&self, realm_id: RealmId, data: Vec<u8>) -> Result<Vec<u8>, CoreError> {
+        let (_, key) = self.private_key(realm_id).await?;
+        sign_with(&key, &data)
+    }
+
+    async fn verify(
+        &self,
+        certificate: String,
+        algorithm: SamlSignatureAlgorithm,
+        data: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<bool, CoreError> {
+        verify_with(&certificate, algorithm, &data, &signature)
+    }
+
+    async fn encrypt(
+        &self,
+        certificate: String,
+        plaintext: Vec<u8>,
+    ) -> Result<SamlEncryptedData, CoreError> {
+        encrypt_for(&certificate, &plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use openssl::rsa::Rsa;
+    use openssl::symm::decrypt;
+
+    use super::*;
+
+    fn key_and_certificate() -> (PKey<Private>, String) {
+        let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+        let certificate = self_signed_certificate(RealmId::new(Uuid::new_v4()), &key).unwrap();
+
+        (key, BASE64_STANDARD.encode(certificate.to_der().unwrap()))
+    }
+
+    #[test]
+    fn signatures_verify_against_the_self_signed_certificate() {
+        let (key, certificate) = key_and_certificate();
+        let signature = sign_with(&key, b"signed info").unwrap();
+
+        assert!(
+            verify_with(
+                &certificate,
+                SamlSignatureAlgorithm::RsaSha256,
+                b"signed info",
+                &signature
+            )
+            .unwrap()
+        );
+        assert!(
+            !verify_with(
+                &certificate,
+                SamlSignatureAlgorithm::RsaSha256,
+                b"tampered",
+                &signature
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn encrypted_assertions_decrypt_with_the_private_key() {
+        let (key, certificate) = key_and_certificate();
+        let encrypted = encrypt_for(&certificate, b"<saml:Assertion/>").unwrap();
+
+        let rsa = key.rsa().unwrap();
+        let mut aes_key = vec![0u8; rsa.size() as usize];
+        let len = rsa
+            .private_decrypt(&encrypted.encrypted_key, &mut aes_key, Padding::PKCS1_OAEP)
+            .unwrap();
+        let (iv, cipher) = encrypted.cipher.split_at(16);
+
+        assert_eq!(
+            decrypt(Cipher::aes_256_cbc(), &aes_key[..len], Some(iv), cipher).unwrap(),
+            b"<saml:Assertion/>"
+        );
+    }
+}
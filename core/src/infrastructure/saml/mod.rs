@@ -0,0 +1,2 @@
+pub mod key_store;
+pub mod repository;
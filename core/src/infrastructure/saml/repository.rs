@@ -0,0 +1,219 @@
+use std::str::FromStr;
+
+use chrono::Utc;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::*;
+use uuid::Uuid;
+
+use crate::domain::authentication::saml::entities::{
+    NameIdFormat, SamlAuthRequest, SamlClientSettings,
+};
+use crate::domain::authentication::saml::ports::{SamlAuthRequestRepository, SamlClientRepository};
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+use crate::entity::{saml_auth_requests, saml_client_settings};
+
+fn database_error(e: DbErr) -> CoreError {
+    CoreError::Database(e.to_string())
+}
+
+fn parse_name_id_format(value: &str) -> Result<NameIdFormat, CoreError> {
+    NameIdFormat::from_str(value)
+        .map_err(|e| CoreError::Database(format!("Invalid SAML settings row: {}", e)))
+}
+
+impl TryFrom<saml_client_settings::Model> for SamlClientSettings {
+    type Error = CoreError;
+
+    fn try_from(model: saml_client_settings::Model) -> Result<Self, Self::Error> {
+        let invalid_json =
+            |e: serde_json::Error| CoreError::Database(format!("Invalid SAML settings row: {}", e));
+
+        Ok(SamlClientSettings {
+            client_id: model.client_id,
+            realm_id: model.realm_id.into(),
+            assertion_consumer_services: serde_json::from_value(model.assertion_consumer_services)
+                .map_err(invalid_json)?,
+            single_logout_service: model
+                .single_logout_service
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(invalid_json)?,
+            name_id_format: parse_name_id_format(&model.name_id_format)?,
+            sign_response: model.sign_response,
+            sign_assertions: model.sign_assertions,
+            encrypt_assertions: model.encrypt_assertions,
+            require_signed_requests: model.require_signed_requests,
+            signing_certificate: model.signing_certificate,
+            encryption_certificate: model.encryption_certificate,
+            idp_initiated_sso_url_name: model.idp_initiated_sso_url_name,
+            idp_initiated_relay_state: model.idp_initiated_relay_state,
+            assertion_lifetime_seconds: i64::from(model.assertion_lifetime_seconds),
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+        })
+    }
+}
+
+impl TryFrom<saml_auth_requests::Model> for SamlAuthRequest {
+    type Error = CoreError;
+
+    fn try_from(model: saml_auth_requests::Model) -> Result<Self, Self::Error> {
+        Ok(SamlAuthRequest {
+            auth_session_id: model.auth_session_id,
+            request_id: model.request_id,
+            acs_url: model.acs_url,
+            relay_state: model.relay_state,
+            name_id_format: model
+                .name_id_format
+                .as_deref()
+                .map(parse_name_id_format)
+                .transpose()?,
+            created_at: model.created_at.into(),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SamlClientRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl SamlClientRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl SamlClientRepository for SamlClientRepositoryImpl {
+    async fn get_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> Result<Option<SamlClientSettings>, CoreError> {
+        saml_client_settings::Entity::find_by_id(client_id)
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(SamlClientSettings::try_from)
+            .transpose()
+    }
+
+    async fn get_by_idp_initiated_url_name(
+        &self,
+        realm_id: RealmId,
+        url_name: String,
+    ) -> Result<Option<SamlClientSettings>, CoreError> {
+        saml_client_settings::Entity::find()
+            .filter(saml_client_settings::Column::RealmId.eq(Uuid::from(realm_id)))
+            .filter(saml_client_settings::Column::IdpInitiatedSsoUrlName.eq(url_name))
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(SamlClientSettings::try_from)
+            .transpose()
+    }
+
+    async fn upsert(&self, settings: &SamlClientSettings) -> Result<SamlClientSettings, CoreError> {
+        let to_json =
+            |value| serde_json::to_value(value).map_err(|e| CoreError::Database(e.to_string()));
+
+        let model = saml_client_settings::ActiveModel {
+            client_id: Set(settings.client_id),
+            realm_id: Set(settings.realm_id.into()),
+            assertion_consumer_services: Set(to_json(&settings.assertion_consumer_services)?),
+            single_logout_service: Set(settings
+                .single_logout_service
+                .as_ref()
+                .map(serde_json::to_value)
+                .transpose()
+                .map_err(|e| CoreError::Database(e.to_string()))?),
+            name_id_format: Set(settings.name_id_format.to_string()),
+            sign_response: Set(settings.sign_response),
+            sign_assertions: Set(settings.sign_assertions),
+            encrypt_assertions: Set(settings.encrypt_assertions),
+            require_signed_requests: Set(settings.require_signed_requests),
+            signing_certificate: Set(settings.signing_certificate.clone()),
+            encryption_certificate: Set(settings.encryption_certificate.clone()),
+            idp_initiated_sso_url_name: Set(settings.idp_initiated_sso_url_name.clone()),
+            idp_initiated_relay_state: Set(settings.idp_initiated_relay_state.clone()),
+            assertion_lifetime_seconds: Set(settings.assertion_lifetime_seconds as i32),
+            created_at: Set(settings.created_at.fixed_offset()),
+            updated_at: Set(Utc::now().fixed_offset()),
+        };
+
+        saml_client_settings::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(saml_client_settings::Column::ClientId)
+                    .update_columns([
+                        saml_client_settings::Column::AssertionConsumerServices,
+                        saml_client_settings::Column::SingleLogoutService,
+                        saml_client_settings::Column::NameIdFormat,
+                        saml_client_settings::Column::SignResponse,
+                        saml_client_settings::Column::SignAssertions,
+                        saml_client_settings::Column::EncryptAssertions,
+                        saml_client_settings::Column::RequireSignedRequests,
+                        saml_client_settings::Column::SigningCertificate,
+                        saml_client_settings::Column::EncryptionCertificate,
+                        saml_client_settings::Column::IdpInitiatedSsoUrlName,
+                        saml_client_settings::Column::IdpInitiatedRelayState,
+                        saml_client_settings::Column::AssertionLifetimeSeconds,
+                        saml_client_settings::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(database_error)?
+            .try_into()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SamlAuthRequestRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl SamlAuthRequestRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl SamlAuthRequestRepository for SamlAuthRequestRepositoryImpl {
+    async fn create(&self, request: &SamlAuthRequest) -> Result<(), CoreError> {
+        saml_auth_requests::ActiveModel {
+            auth_session_id: Set(request.auth_session_id),
+            request_id: Set(request.request_id.clone()),
+            acs_url: Set(request.acs_url.clone()),
+            relay_state: Set(request.relay_state.clone()),
+            name_id_format: Set(request.name_id_format.map(|format| format.to_string())),
+            created_at: Set(request.created_at.fixed_offset()),
+        }
+        .insert(&self.db)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn get_by_auth_session_id(
+        &self,
+        auth_session_id: Uuid,
+    ) -> Result<Option<SamlAuthRequest>, CoreError> {
+        saml_auth_requests::Entity::find_by_id(auth_session_id)
+            .one(&self.db)
+            .await
+            .map_err(database_error)?
+            .map(SamlAuthRequest::try_from)
+            .transpose()
+    }
+
+    async fn delete(&self, auth_session_id: Uuid) -> Result<(), CoreError> {
+        saml_auth_requests::Entity::delete_by_id(auth_session_id)
+            .exec(&self.db)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+}
@@ -81,6 +81,10 @@ fn parse_event_type(raw: &str) -> SecurityEventType {
         "session_created" => SecurityEventType::SessionCreated,
         "session_revoked" => SecurityEventType::SessionRevoked,
         "identity_provider_link_removed" => SecurityEventType::IdentityProviderLinkRemoved,
+        "rate_limit_exceeded" => SecurityEventType::RateLimitExceeded,
+        "deny_list_blocked" => SecurityEventType::DenyListBlocked,
+        "login_risk_evaluated" => SecurityEventType::LoginRiskEvaluated,
+        "login_risk_blocked" => SecurityEventType::LoginRiskBlocked,
         _ => SecurityEventType::LoginSuccess,
     }
 }
@@ -141,6 +145,10 @@ mod tests {
         SecurityEventType::SessionCreated,
         SecurityEventType::SessionRevoked,
         SecurityEventType::IdentityProviderLinkRemoved,
+        SecurityEventType::RateLimitExceeded,
+        SecurityEventType::DenyListBlocked,
+        SecurityEventType::LoginRiskEvaluated,
+        SecurityEventType::LoginRiskBlocked,
     ];
 
     /// The write path persists `event_type` via `Display` and the read path
@@ -196,7 +204,11 @@ mod tests {
                 | SecurityEventType::ClientMaintenanceDisabled
                 | SecurityEventType::SessionCreated
                 | SecurityEventType::SessionRevoked
-                | SecurityEventType::IdentityProviderLinkRemoved => true,
+                | SecurityEventType::IdentityProviderLinkRemoved
+                | SecurityEventType::RateLimitExceeded
+                | SecurityEventType::DenyListBlocked
+                | SecurityEventType::LoginRiskEvaluated
+                | SecurityEventType::LoginRiskBlocked => true,
             };
 
             assert!(listed && ALL_EVENT_TYPES.contains(event_type));
@@ -0,0 +1 @@
+pub mod security_notification_repository;
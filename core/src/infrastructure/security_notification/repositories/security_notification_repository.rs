@@ -0,0 +1,100 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::security_notification::{
+    entities::{SecurityNotificationKind, SecurityNotificationSetting},
+    ports::SecurityNotificationRepository,
+};
+use crate::entity::security_notification_settings::{
+    ActiveModel, Column, Entity as SettingEntity, Model,
+};
+
+#[derive(Clone, Debug)]
+pub struct PostgresSecurityNotificationRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresSecurityNotificationRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn model_to_domain(model: Model) -> Result<SecurityNotificationSetting, CoreError> {
+    let kind = model.kind.parse().map_err(|e| {
+        error!("Failed to decode security notification setting: {}", e);
+        CoreError::InternalServerError
+    })?;
+
+    Ok(SecurityNotificationSetting {
+        realm_id: model.realm_id,
+        kind,
+        enabled: model.enabled,
+        template_id: model.template_id,
+        updated_at: model.updated_at.with_timezone(&Utc),
+    })
+}
+
+fn database_error(action: &str) -> impl FnOnce(sea_orm::DbErr) -> CoreError + '_ {
+    move |e| {
+        error!("Failed to {} security notification setting: {}", action, e);
+        CoreError::InternalServerError
+    }
+}
+
+impl SecurityNotificationRepository for PostgresSecurityNotificationRepository {
+    async fn list_by_realm(
+        &self,
+        realm_id: Uuid,
+    ) -> Result<Vec<SecurityNotificationSetting>, CoreError> {
+        SettingEntity::find()
+            .filter(Column::RealmId.eq(realm_id))
+            .all(&self.db)
+            .await
+            .map_err(database_error("list"))?
+            .into_iter()
+            .map(model_to_domain)
+            .collect()
+    }
+
+    async fn get(
+        &self,
+        realm_id: Uuid,
+        kind: SecurityNotificationKind,
+    ) -> Result<Option<SecurityNotificationSetting>, CoreError> {
+        SettingEntity::find_by_id((realm_id, kind.to_string()))
+            .one(&self.db)
+            .await
+            .map_err(database_error("get"))?
+            .map(model_to_domain)
+            .transpose()
+    }
+
+    async fn upsert(
+        &self,
+        setting: &SecurityNotificationSetting,
+    ) -> Result<SecurityNotificationSetting, CoreError> {
+        let model = SettingEntity::insert(ActiveModel {
+            realm_id: Set(setting.realm_id),
+            kind: Set(setting.kind.to_string()),
+            enabled: Set(setting.enabled),
+            template_id: Set(setting.template_id),
+            updated_at: Set(setting.updated_at.fixed_offset()),
+        })
+        .on_conflict(
+            OnConflict::columns([Column::RealmId, Column::Kind])
+                .update_columns([Column::Enabled, Column::TemplateId, Column::UpdatedAt])
+                .to_owned(),
+        )
+        .exec_with_returning(&self.db)
+        .await
+        .map_err(database_error("save"))?;
+
+        model_to_domain(model)
+    }
+}
@@ -1,6 +1,7 @@
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
-    JoinType, QueryFilter, QuerySelect, RelationTrait, prelude::Expr, sea_query::IntoCondition,
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait,
+    DatabaseConnection, DbBackend, EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait,
+    Statement, prelude::Expr, sea_query::IntoCondition,
 };
 use tracing::{error, instrument};
 use uuid::Uuid;
@@ -9,6 +10,28 @@ use crate::domain::{
     common::entities::app_errors::CoreError, role::entities::Role, user::ports::UserRoleRepository,
 };
 
+/// Roles assigned to the user directly plus those mapped to any of the user's realm groups or
+/// their ancestors, resolved with a recursive CTE.
+const EFFECTIVE_ROLE_IDS_SQL: &str = r#"
+WITH RECURSIVE user_groups AS (
+    SELECT g.id, g.parent_group_id
+    FROM realm_groups g
+    JOIN realm_group_members m ON m.group_id = g.id
+    WHERE m.user_id = $1
+  UNION
+    SELECT p.id, p.parent_group_id
+    FROM realm_groups p
+    JOIN user_groups ug ON ug.parent_group_id = p.id
+)
+SELECT ur.role_id
+FROM user_role ur
+WHERE ur.user_id = $1
+UNION
+SELECT gr.role_id
+FROM realm_group_roles gr
+JOIN user_groups ug ON ug.id = gr.group_id
+"#;
+
 #[derive(Debug, Clone)]
 pub struct PostgresUserRoleRepository {
     pub db: DatabaseConnection,
@@ -56,6 +79,31 @@ impl UserRoleRepository for PostgresUserRoleRepository {
 
     #[instrument]
     async fn get_user_roles(&self, user_id: Uuid) -> Result<Vec<Role>, CoreError> {
+        let role_ids = self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                EFFECTIVE_ROLE_IDS_SQL,
+                [user_id.into()],
+            ))
+            .await
+            .map_err(|e| {
+                error!("error resolving effective user roles: {:?}", e);
+                CoreError::InternalServerError
+            })?
+            .into_iter()
+            .map(|row| row.try_get::<Uuid>("", "role_id"))
+            .collect::<Result<Vec<Uuid>, _>>()
+            .map_err(|e| {
+                error!("error reading effective user role row: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        self.get_roles_by_ids(role_ids).await
+    }
+
+    #[instrument]
+    async fn get_direct_user_roles(&self, user_id: Uuid) -> Result<Vec<Role>, CoreError> {
         let roles = crate::entity::roles::Entity::find()
             .join(
                 JoinType::InnerJoin,
@@ -0,0 +1 @@
+pub mod schema_repository;
@@ -0,0 +1,84 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{DatabaseConnection, EntityTrait};
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_domain::realm::RealmId;
+use ferriskey_user_profile::{UserProfileSchema, UserProfileSchemaRepository};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::user_profile_schemas::{
+    ActiveModel as SchemaActiveModel, Column as SchemaColumn, Entity as SchemaEntity,
+    Model as SchemaModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresUserProfileSchemaRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresUserProfileSchemaRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn model_to_domain(model: SchemaModel) -> Result<UserProfileSchema, CoreError> {
+    let config = serde_json::from_value(model.schema).map_err(|e| {
+        error!("Failed to decode user profile schema: {}", e);
+        CoreError::InternalServerError
+    })?;
+
+    Ok(UserProfileSchema {
+        realm_id: RealmId::new(model.realm_id),
+        config,
+        created_at: model.created_at.with_timezone(&Utc),
+        updated_at: model.updated_at.with_timezone(&Utc),
+    })
+}
+
+impl UserProfileSchemaRepository for PostgresUserProfileSchemaRepository {
+    async fn get_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Option<UserProfileSchema>, CoreError> {
+        let model = SchemaEntity::find_by_id::<Uuid>(realm_id.into())
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to get user profile schema: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        model.map(model_to_domain).transpose()
+    }
+
+    async fn upsert(&self, schema: UserProfileSchema) -> Result<UserProfileSchema, CoreError> {
+        let config = serde_json::to_value(&schema.config).map_err(|e| {
+            error!("Failed to encode user profile schema: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        let model = SchemaEntity::insert(SchemaActiveModel {
+            realm_id: Set(schema.realm_id.into()),
+            schema: Set(config),
+            created_at: Set(schema.created_at.fixed_offset()),
+            updated_at: Set(schema.updated_at.fixed_offset()),
+        })
+        .on_conflict(
+            OnConflict::column(SchemaColumn::RealmId)
+                .update_columns([SchemaColumn::Schema, SchemaColumn::UpdatedAt])
+                .to_owned(),
+        )
+        .exec_with_returning(&self.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to save user profile schema: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        model_to_domain(model)
+    }
+}
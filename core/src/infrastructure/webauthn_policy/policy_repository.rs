@@ -0,0 +1,134 @@
+use chrono::Utc;
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{DatabaseConnection, EntityTrait};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tracing::error;
+use uuid::Uuid;
+
+use ferriskey_domain::realm::RealmId;
+use ferriskey_webauthn_policy::{WebAuthnPolicy, WebAuthnPolicyRepository, WebAuthnPolicySettings};
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::webauthn_policies::{
+    ActiveModel as PolicyActiveModel, Column as PolicyColumn, Entity as PolicyEntity,
+    Model as PolicyModel,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresWebAuthnPolicyRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresWebAuthnPolicyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+// The enums are stored as their snake_case serde names, the same strings the CHECK
+// constraints list.
+fn to_column<T: Serialize>(value: &T) -> Result<String, CoreError> {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => Ok(value),
+        _ => {
+            error!("Failed to encode WebAuthn policy value");
+            Err(CoreError::InternalServerError)
+        }
+    }
+}
+
+fn from_column<T: DeserializeOwned>(value: serde_json::Value) -> Result<T, CoreError> {
+    serde_json::from_value(value).map_err(|e| {
+        error!("Failed to decode WebAuthn policy value: {}", e);
+        CoreError::InternalServerError
+    })
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<serde_json::Value, CoreError> {
+    serde_json::to_value(value).map_err(|e| {
+        error!("Failed to encode WebAuthn policy value: {}", e);
+        CoreError::InternalServerError
+    })
+}
+
+fn model_to_domain(model: PolicyModel) -> Result<WebAuthnPolicy, CoreError> {
+    Ok(WebAuthnPolicy {
+        realm_id: RealmId::new(model.realm_id),
+        settings: WebAuthnPolicySettings {
+            attestation: from_column(model.attestation.into())?,
+            user_verification: from_column(model.user_verification.into())?,
+            require_resident_key: model.require_resident_key,
+            authenticator_attachment: model
+                .authenticator_attachment
+                .map(|attachment| from_column(attachment.into()))
+                .transpose()?,
+            allowed_origins: from_column(model.allowed_origins)?,
+            allowed_aaguids: from_column(model.allowed_aaguids)?,
+            blocked_aaguids: from_column(model.blocked_aaguids)?,
+            fips_certified_only: model.fips_certified_only,
+        },
+        created_at: model.created_at.with_timezone(&Utc),
+        updated_at: model.updated_at.with_timezone(&Utc),
+    })
+}
+
+impl WebAuthnPolicyRepository for PostgresWebAuthnPolicyRepository {
+    async fn get_by_realm(&self, realm_id: RealmId) -> Result<Option<WebAuthnPolicy>, CoreError> {
+        let model = PolicyEntity::find_by_id::<Uuid>(realm_id.into())
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to get WebAuthn policy: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        model.map(model_to_domain).transpose()
+    }
+
+    async fn upsert(&self, policy: WebAuthnPolicy) -> Result<WebAuthnPolicy, CoreError> {
+        let settings = policy.settings;
+
+        let model = PolicyEntity::insert(PolicyActiveModel {
+            realm_id: Set(policy.realm_id.into()),
+            attestation: Set(to_column(&settings.attestation)?),
+            user_verification: Set(to_column(&settings.user_verification)?),
+            require_resident_key: Set(settings.require_resident_key),
+            authenticator_attachment: Set(settings
+                .authenticator_attachment
+                .as_ref()
+                .map(to_column)
+                .transpose()?),
+            allowed_origins: Set(to_json(&settings.allowed_origins)?),
+            allowed_aaguids: Set(to_json(&settings.allowed_aaguids)?),
+            blocked_aaguids: Set(to_json(&settings.blocked_aaguids)?),
+            fips_certified_only: Set(settings.fips_certified_only),
+            created_at: Set(policy.created_at.fixed_offset()),
+            updated_at: Set(policy.updated_at.fixed_offset()),
+        })
+        .on_conflict(
+            OnConflict::column(PolicyColumn::RealmId)
+                .update_columns([
+                    PolicyColumn::Attestation,
+                    PolicyColumn::UserVerification,
+                    PolicyColumn::RequireResidentKey,
+                    PolicyColumn::AuthenticatorAttachment,
+                    PolicyColumn::AllowedOrigins,
+                    PolicyColumn::AllowedAaguids,
+                    PolicyColumn::BlockedAaguids,
+                    PolicyColumn::FipsCertifiedOnly,
+                    PolicyColumn::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(&self.db)
+        .await
+        .map_err(|e| {
+            error!("Failed to save WebAuthn policy: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        model_to_domain(model)
+    }
+}
@@ -106,6 +106,34 @@ export namespace Schemas {
     url?: (string | null) | undefined;
   };
   export type AuthenticationAttemptResponse = { login_url: string };
+  export type PendingBrokerLoginStage = "review_profile" | "confirm_link" | "otp";
+  export type BrokeredProfile = Partial<{
+    email: string | null;
+    first_name: string | null;
+    last_name: string | null;
+    username: string | null;
+  }>;
+  export type ProfileField = "username" | "email" | "first_name" | "last_name";
+  export type LinkConfirmationMethod = "password" | "email_code";
+  export type ExistingAccountHint = { email?: (string | null) | undefined; username: string };
+  export type PendingBrokerLoginView = {
+    confirmation_methods: Array<LinkConfirmationMethod>;
+    existing_account?: (ExistingAccountHint | null) | undefined;
+    expires_at: string;
+    identity_provider_alias: string;
+    identity_provider_display_name?: (string | null) | undefined;
+    missing_fields: Array<ProfileField>;
+    profile: BrokeredProfile;
+    required_fields: Array<ProfileField>;
+    stage: PendingBrokerLoginStage;
+    username_taken: boolean;
+  };
+  export type BrokerLoginStepStatus = "completed" | "pending";
+  export type BrokerLoginStepResponse = {
+    login?: (PendingBrokerLoginView | null) | undefined;
+    redirect_url?: (string | null) | undefined;
+    status: BrokerLoginStepStatus;
+  };
   export type BulkDeleteUserResponse = { count: number; realm_name: string };
   export type BulkDeleteUserValidator = Partial<{ ids: Array<string> }>;
   export type BurnRecoveryCodeRequest = { recovery_code: string; recovery_code_format: string };
@@ -210,6 +238,7 @@ export namespace Schemas {
     token_type: string;
   };
   export type CompletePasswordResetResponse = JwtToken & Partial<{ login_url: string | null }>;
+  export type ConfirmBrokerLinkRequest = Partial<{ code: string | null; password: string | null }>;
   export type CreateClientScopeValidator = Partial<{
     description: string | null;
     is_default: boolean;
@@ -531,6 +560,14 @@ export namespace Schemas {
   export type GetEmailTemplatesResponse = { data: Array<EmailTemplate> };
   export type GetFlowResponse = { data: CompassFlow };
   export type GetFlowsResponse = { data: Array<CompassFlow> };
+  export type MaintenanceAnnouncement = {
+    client_id?: (string | null) | undefined;
+    ends_at: string;
+    in_progress: boolean;
+    reason: string;
+    starts_at: string;
+  };
+  export type GetMaintenanceAnnouncementsResponse = { data: Array<MaintenanceAnnouncement> };
   export type GetOpenIdConfigurationResponse = {
     authorization_endpoint: string;
     end_session_endpoint: string;
@@ -729,6 +766,9 @@ export namespace Schemas {
   }>;
   export type OrganizationMember = { created_at: string; id: string; organization_id: OrganizationId; user_id: string };
   export type OtpVerifyRequest = { code: string; label: string };
+  export type Locale = string;
+  export type LocalizedMessages = { locale: Locale; messages: Record<string, string> };
+  export type PortalMessages = LocalizedMessages & { supported_locales: Array<Locale> };
   export type PortalPageType =
     | "login"
     | "register"
@@ -850,6 +890,12 @@ export namespace Schemas {
   export type ResetPasswordRequest = { new_password: string; token: string; token_id: string };
   export type ResetPasswordResponse = { message: string; realm_name: string; user_id: string };
   export type ResetPasswordValidator = Partial<{ credential_type: string; temporary: boolean; value: string }>;
+  export type ReviewBrokeredProfileRequest = Partial<{
+    email: string | null;
+    first_name: string | null;
+    last_name: string | null;
+    username: string | null;
+  }>;
   export type RevokeTokenRequestValidator = Partial<{
     client_id: string;
     token: string;
@@ -1089,6 +1135,7 @@ export namespace Schemas {
   export type UserResponse = { data: User };
   export type UsersResponse = { data: Array<User> };
   export type ValidatePublicKeyResponse = Record<string, unknown>;
+  export type VerifyBrokerLoginOtpRequest = { code: string };
   export type VerifyEmailRequest = { token: string };
   export type VerifyEmailResult = { user_id: string; verified: boolean };
   export type VerifyOtpResponse = { message: string };
@@ -1262,6 +1309,77 @@ export namespace Endpoints {
       500: Schemas.ApiErrorResponse;
     };
   };
+  export type get_Get_pending_broker_login = {
+    method: "GET";
+    path: "/realms/{realm_name}/broker/login-actions/first-login";
+    requestFormat: "json";
+    parameters: {
+      path: { realm_name: string };
+    };
+    responses: {
+      200: Schemas.PendingBrokerLoginView;
+      400: Schemas.ApiErrorResponse;
+      401: Schemas.ApiErrorResponse;
+    };
+  };
+  export type post_Review_brokered_profile = {
+    method: "POST";
+    path: "/realms/{realm_name}/broker/login-actions/first-login/profile";
+    requestFormat: "json";
+    parameters: {
+      path: { realm_name: string };
+
+      body: Schemas.ReviewBrokeredProfileRequest;
+    };
+    responses: {
+      200: Schemas.BrokerLoginStepResponse;
+      400: Schemas.ApiErrorResponse;
+      401: Schemas.ApiErrorResponse;
+    };
+  };
+  export type post_Send_link_confirmation_code = {
+    method: "POST";
+    path: "/realms/{realm_name}/broker/login-actions/first-login/send-code";
+    requestFormat: "json";
+    parameters: {
+      path: { realm_name: string };
+    };
+    responses: {
+      204: unknown;
+      400: Schemas.ApiErrorResponse;
+      401: Schemas.ApiErrorResponse;
+    };
+  };
+  export type post_Confirm_broker_link = {
+    method: "POST";
+    path: "/realms/{realm_name}/broker/login-actions/first-login/confirm";
+    requestFormat: "json";
+    parameters: {
+      path: { realm_name: string };
+
+      body: Schemas.ConfirmBrokerLinkRequest;
+    };
+    responses: {
+      200: Schemas.BrokerLoginStepResponse;
+      400: Schemas.ApiErrorResponse;
+      401: Schemas.ApiErrorResponse;
+    };
+  };
+  export type post_Verify_broker_login_otp = {
+    method: "POST";
+    path: "/realms/{realm_name}/broker/login-actions/first-login/otp";
+    requestFormat: "json";
+    parameters: {
+      path: { realm_name: string };
+
+      body: Schemas.VerifyBrokerLoginOtpRequest;
+    };
+    responses: {
+      200: Schemas.BrokerLoginStepResponse;
+      400: Schemas.ApiErrorResponse;
+      401: Schemas.ApiErrorResponse;
+    };
+  };
   export type get_Get_client_scopes = {
     method: "GET";
     path: "/realms/{realm_name}/client-scopes";
@@ -2392,6 +2510,33 @@ export namespace Endpoints {
       500: Schemas.ApiErrorResponse;
     };
   };
+  export type get_Get_portal_messages = {
+    method: "GET";
+    path: "/realms/{realm_name}/localization/messages";
+    requestFormat: "json";
+    parameters: {
+      query: Partial<{ ui_locales: string | null }>;
+      path: { realm_name: string };
+    };
+    responses: {
+      200: Schemas.PortalMessages;
+      401: Schemas.ApiErrorResponse;
+      500: Schemas.ApiErrorResponse;
+    };
+  };
+  export type get_Get_maintenance_announcements = {
+    method: "GET";
+    path: "/realms/{realm_name}/maintenance/announcements";
+    requestFormat: "json";
+    parameters: {
+      query: Partial<{ client_id: string }>;
+      path: { realm_name: string };
+    };
+    responses: {
+      200: Schemas.GetMaintenanceAnnouncementsResponse;
+      401: Schemas.ApiErrorResponse;
+    };
+  };
   export type get_List_organizations = {
     method: "GET";
     path: "/realms/{realm_name}/organizations";
@@ -3824,6 +3969,7 @@ export type EndpointByMethod = {
     "/realms/{name}/login-settings": Endpoints.get_Get_login_realm_settings_handler;
     "/realms/{realm_name}/.well-known/openid-configuration": Endpoints.get_Get_openid_configuration;
     "/realms/{realm_name}/broker/{alias}/login": Endpoints.get_Broker_login;
+    "/realms/{realm_name}/broker/login-actions/first-login": Endpoints.get_Get_pending_broker_login;
     "/realms/{realm_name}/client-scopes": Endpoints.get_Get_client_scopes;
     "/realms/{realm_name}/client-scopes/{scope_id}": Endpoints.get_Get_client_scope;
     "/realms/{realm_name}/clients": Endpoints.get_Get_clients;
@@ -3848,8 +3994,10 @@ export type EndpointByMethod = {
     "/realms/{realm_name}/federation/providers/{id}": Endpoints.get_Get_provider;
     "/realms/{realm_name}/identity-providers": Endpoints.get_List_identity_providers;
     "/realms/{realm_name}/identity-providers/{alias}": Endpoints.get_Get_identity_provider;
+    "/realms/{realm_name}/localization/messages": Endpoints.get_Get_portal_messages;
     "/realms/{realm_name}/login-actions/setup-otp": Endpoints.get_Setup_otp;
     "/realms/{realm_name}/login-actions/verify-magic-link": Endpoints.get_Verify_magic_link;
+    "/realms/{realm_name}/maintenance/announcements": Endpoints.get_Get_maintenance_announcements;
     "/realms/{realm_name}/organizations": Endpoints.get_List_organizations;
     "/realms/{realm_name}/organizations/{organization_id}": Endpoints.get_Get_organization;
     "/realms/{realm_name}/organizations/{organization_id}/attributes": Endpoints.get_List_attributes;
@@ -3895,6 +4043,10 @@ export type EndpointByMethod = {
   post: {
     "/realms": Endpoints.post_Create_realm;
     "/realms/{realm_name}/broker/{alias}/endpoint": Endpoints.post_Broker_callback;
+    "/realms/{realm_name}/broker/login-actions/first-login/confirm": Endpoints.post_Confirm_broker_link;
+    "/realms/{realm_name}/broker/login-actions/first-login/otp": Endpoints.post_Verify_broker_login_otp;
+    "/realms/{realm_name}/broker/login-actions/first-login/profile": Endpoints.post_Review_brokered_profile;
+    "/realms/{realm_name}/broker/login-actions/first-login/send-code": Endpoints.post_Send_link_confirmation_code;
     "/realms/{realm_name}/client-scopes": Endpoints.post_Create_client_scope;
     "/realms/{realm_name}/client-scopes/{scope_id}/protocol-mappers": Endpoints.post_Create_protocol_mapper;
     "/realms/{realm_name}/clients": Endpoints.post_Create_client;
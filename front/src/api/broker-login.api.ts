@@ -0,0 +1,86 @@
+import { useMutation, useQuery } from '@tanstack/react-query'
+import { BaseQuery } from '.'
+import type { Schemas } from './api.client'
+
+// Every call below is authorised by the pending brokered login cookie the
+// broker callback sets before redirecting to the broker-login page.
+
+export const usePendingBrokerLogin = ({ realm }: BaseQuery) => {
+  return useQuery({
+    queryKey: ['pending-broker-login', realm],
+    retry: false,
+    staleTime: 0,
+    queryFn: async () => {
+      return window.tanstackApi.client.get(
+        '/realms/{realm_name}/broker/login-actions/first-login',
+        {
+          path: { realm_name: realm ?? 'master' },
+        } as never
+      ) as Promise<Schemas.PendingBrokerLoginView>
+    },
+  })
+}
+
+export const useReviewBrokeredProfile = () => {
+  return useMutation({
+    mutationFn: async ({
+      realm,
+      data,
+    }: BaseQuery & { data: Schemas.ReviewBrokeredProfileRequest }) => {
+      return window.tanstackApi.client.post(
+        '/realms/{realm_name}/broker/login-actions/first-login/profile',
+        {
+          path: { realm_name: realm ?? 'master' },
+          body: data,
+        } as never
+      ) as Promise<Schemas.BrokerLoginStepResponse>
+    },
+  })
+}
+
+export const useSendLinkConfirmationCode = () => {
+  return useMutation({
+    mutationFn: async ({ realm }: BaseQuery) => {
+      return window.tanstackApi.client.post(
+        '/realms/{realm_name}/broker/login-actions/first-login/send-code',
+        {
+          path: { realm_name: realm ?? 'master' },
+        } as never
+      )
+    },
+  })
+}
+
+export const useConfirmBrokerLink = () => {
+  return useMutation({
+    mutationFn: async ({
+      realm,
+      data,
+    }: BaseQuery & { data: Schemas.ConfirmBrokerLinkRequest }) => {
+      return window.tanstackApi.client.post(
+        '/realms/{realm_name}/broker/login-actions/first-login/confirm',
+        {
+          path: { realm_name: realm ?? 'master' },
+          body: data,
+        } as never
+      ) as Promise<Schemas.BrokerLoginStepResponse>
+    },
+  })
+}
+
+export const useVerifyBrokerLoginOtp = () => {
+  return useMutation({
+    mutationFn: async ({
+      realm,
+      data,
+    }: BaseQuery & { data: Schemas.VerifyBrokerLoginOtpRequest }) => {
+      return window.tanstackApi.client.post(
+        '/realms/{realm_name}/broker/login-actions/first-login/otp',
+        {
+          path: { realm_name: realm ?? 'master' },
+          body: data,
+        } as never
+      ) as Promise<Schemas.BrokerLoginStepResponse>
+    },
+  })
+}
@@ -0,0 +1,17 @@
+import { useQuery } from '@tanstack/react-query'
+import { BaseQuery } from '.'
+
+export function useGetPortalMessages({
+  realm = 'master',
+  uiLocales,
+}: BaseQuery & { uiLocales?: string | null }) {
+  return useQuery({
+    ...window.tanstackApi.get('/realms/{realm_name}/localization/messages', {
+      path: { realm_name: realm },
+      query: { ui_locales: uiLocales ?? undefined },
+    }).queryOptions,
+    enabled: !!realm,
+    retry: false,
+    staleTime: 5 * 60 * 1000,
+  })
+}
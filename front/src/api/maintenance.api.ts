@@ -118,3 +118,18 @@ export function useRemoveRealmWhitelistEntry() {
     },
   })
 }
+
+// -- Maintenance announcements --
+
+export function useGetMaintenanceAnnouncements({
+  realm = 'master',
+  clientId,
+}: BaseQuery & { clientId?: string }) {
+  return useQuery({
+    ...window.tanstackApi.get('/realms/{realm_name}/maintenance/announcements', {
+      path: { realm_name: realm },
+      query: { client_id: clientId },
+    }).queryOptions,
+    enabled: !!realm,
+  })
+}
@@ -0,0 +1,174 @@
+import {
+  useConfirmBrokerLink,
+  usePendingBrokerLogin,
+  useReviewBrokeredProfile,
+  useSendLinkConfirmationCode,
+  useVerifyBrokerLoginOtp,
+} from '@/api/broker-login.api'
+import type { Schemas } from '@/api/api.client'
+import { RouterParams } from '@/routes/router'
+import { zodResolver } from '@hookform/resolvers/zod'
+import { useEffect, useState } from 'react'
+import { useForm } from 'react-hook-form'
+import { useNavigate, useParams } from 'react-router'
+import { toast } from 'sonner'
+import {
+  brokerLinkSchema,
+  BrokerLinkSchema,
+  brokerOtpSchema,
+  BrokerOtpSchema,
+  brokerProfileSchema,
+  BrokerProfileSchema,
+} from '../schemas/broker-login.schema'
+import PageBrokerLogin from '../ui/page-broker-login'
+
+type ApiError = { status?: number; data?: { message?: string }; message?: string }
+
+// Empty inputs keep the value the identity provider sent.
+const orNull = (value: string) => (value.length > 0 ? value : null)
+
+// First login through an identity provider. The broker callback parks the
+// login behind a short-lived cookie and sends the browser here; each step
+// answers with the next stage or, once the login completes, the URL that
+// resumes the original authorization request.
+export default function PageBrokerLoginFeature() {
+  const { realm_name } = useParams<RouterParams>()
+  const realm = realm_name ?? 'master'
+  const navigate = useNavigate()
+
+  const { data: pending, error: pendingError, isLoading } = usePendingBrokerLogin({ realm })
+  const [login, setLogin] = useState<Schemas.PendingBrokerLoginView | null>(null)
+  const [expired, setExpired] = useState(false)
+  const [codeSent, setCodeSent] = useState(false)
+
+  const { mutateAsync: reviewProfile, isPending: isReviewing } = useReviewBrokeredProfile()
+  const { mutateAsync: sendCode, isPending: isSendingCode } = useSendLinkConfirmationCode()
+  const { mutateAsync: confirmLink, isPending: isConfirming } = useConfirmBrokerLink()
+  const { mutateAsync: verifyOtp, isPending: isVerifying } = useVerifyBrokerLoginOtp()
+
+  const current = login ?? pending ?? null
+
+  const profileForm = useForm<BrokerProfileSchema>({
+    resolver: zodResolver(brokerProfileSchema),
+    defaultValues: { username: '', email: '', first_name: '', last_name: '' },
+  })
+  const linkForm = useForm<BrokerLinkSchema>({
+    resolver: zodResolver(brokerLinkSchema),
+    defaultValues: { password: '', code: '' },
+  })
+  const otpForm = useForm<BrokerOtpSchema>({
+    resolver: zodResolver(brokerOtpSchema),
+    defaultValues: { code: '' },
+  })
+
+  useEffect(() => {
+    if (!current) return
+    profileForm.reset({
+      username: current.profile.username ?? '',
+      email: current.profile.email ?? '',
+      first_name: current.profile.first_name ?? '',
+      last_name: current.profile.last_name ?? '',
+    })
+  }, [current, profileForm])
+
+  useEffect(() => {
+    if ((pendingError as ApiError | null)?.status === 401) setExpired(true)
+  }, [pendingError])
+
+  const handleError = (err: unknown) => {
+    const error = err as ApiError
+    // 401: the pending login cookie is gone or expired, the user has to
+    // start over from the login page.
+    if (error.status === 401) {
+      setExpired(true)
+      return
+    }
+    toast.error(error.data?.message ?? error.message ?? 'Something went wrong, please try again.')
+  }
+
+  const handleStep = (response: Schemas.BrokerLoginStepResponse) => {
+    if (response.status === 'completed' && response.redirect_url) {
+      window.location.href = response.redirect_url
+      return
+    }
+    if (response.login) {
+      setLogin(response.login)
+      linkForm.reset({ password: '', code: '' })
+      otpForm.reset({ code: '' })
+    }
+  }
+
+  const onReviewProfile = async (values: BrokerProfileSchema) => {
+    try {
+      handleStep(
+        await reviewProfile({
+          realm,
+          data: {
+            username: orNull(values.username),
+            email: orNull(values.email),
+            first_name: orNull(values.first_name),
+            last_name: orNull(values.last_name),
+          },
+        })
+      )
+    } catch (err) {
+      handleError(err)
+    }
+  }
+
+  const onSendCode = async () => {
+    try {
+      await sendCode({ realm })
+      setCodeSent(true)
+      toast.success('A confirmation code has been sent to your email.')
+    } catch (err) {
+      handleError(err)
+    }
+  }
+
+  const onConfirmLink = async (values: BrokerLinkSchema) => {
+    try {
+      handleStep(
+        await confirmLink({
+          realm,
+          data: { password: orNull(values.password), code: orNull(values.code) },
+        })
+      )
+    } catch (err) {
+      handleError(err)
+    }
+  }
+
+  const onVerifyOtp = async (values: BrokerOtpSchema) => {
+    try {
+      handleStep(await verifyOtp({ realm, data: { code: values.code } }))
+    } catch (err) {
+      handleError(err)
+    }
+  }
+
+  const backToLogin = () => {
+    navigate(`/realms/${realm}/authentication/login`)
+  }
+
+  return (
+    <PageBrokerLogin
+      login={current}
+      isLoading={isLoading}
+      expired={expired || (!isLoading && !current)}
+      codeSent={codeSent}
+      profileForm={profileForm}
+      linkForm={linkForm}
+      otpForm={otpForm}
+      onReviewProfile={onReviewProfile}
+      onSendCode={onSendCode}
+      onConfirmLink={onConfirmLink}
+      onVerifyOtp={onVerifyOtp}
+      backToLogin={backToLogin}
+      isReviewing={isReviewing}
+      isSendingCode={isSendingCode}
+      isConfirming={isConfirming}
+      isVerifying={isVerifying}
+    />
+  )
+}
@@ -8,11 +8,13 @@ import {
   type ForgotPasswordSchema,
 } from '../schemas/forgot-password.schema'
 import PageForgotPassword from '../ui/page-forgot-password'
+import { usePortalMessages } from '../hooks/use-portal-messages'
 import { Form } from '@/components/ui/form'
 
 export default function PageForgotPasswordFeature() {
   const { realm_name } = useParams()
   const [submitted, setSubmitted] = useState(false)
+  const { t } = usePortalMessages()
 
   const { mutate: forgotPassword, isPending } = useForgotPassword()
 
@@ -38,6 +40,7 @@ export default function PageForgotPasswordFeature() {
         onSubmit={onSubmit}
         submitted={submitted}
         isPending={isPending}
+        t={t}
       />
     </Form>
   )
@@ -1,3 +1,4 @@
+import { useGetMaintenanceAnnouncements } from '@/api/maintenance.api'
 import { useGetLoginSettings } from '@/api/realm.api'
 import FloatingActionBar from '@/components/ui/floating-action-bar'
 import { useEffect } from 'react'
@@ -5,6 +6,7 @@ import { useLoginForm } from '../hooks/use-login-form'
 import { useMagicLinkAuth } from '../hooks/use-magic-link-auth'
 import { useOAuthParams } from '../hooks/use-oauth-params'
 import { usePasskeyAuth } from '../hooks/use-passkey-auth'
+import { usePortalMessages } from '../hooks/use-portal-messages'
 import { useSessionRefresh } from '../hooks/use-session-refresh'
 import PageLogin, { LoginErrorPage } from '../ui/page-login'
 import { useAuth } from '@/hooks/use-auth'
@@ -33,6 +35,11 @@ export default function PageLoginFeature() {
   }, [isAuthenticated, navigate, realm_name, isAuthInitiated, getOAuthParams])
 
   const { data: loginSettings } = useGetLoginSettings({ realm: realm_name })
+  const { t } = usePortalMessages()
+  const { data: announcements } = useGetMaintenanceAnnouncements({
+    realm: realm_name,
+    clientId: getAuthParamsFromUrl().clientId,
+  })
 
   const { form, onSubmit, errorMessage, isSessionError, resetAuthenticate } = useLoginForm({
     realm_name,
@@ -75,7 +82,9 @@ export default function PageLoginFeature() {
   }, [isRedirecting, getOAuthParams])
 
   if (isRedirecting) {
-    return <PageLogin form={form} onSubmit={onSubmit} isLoading loginSettings={loginSettings} />
+    return (
+      <PageLogin form={form} onSubmit={onSubmit} t={t} isLoading loginSettings={loginSettings} />
+    )
   }
 
   // Fatal configuration error (e.g. "Invalid redirect URI", "Client not found").
@@ -92,9 +101,11 @@ export default function PageLoginFeature() {
       <PageLogin
         form={form}
         onSubmit={onSubmit}
+        t={t}
         isError={undefined}
         loginSettings={loginSettings}
         errorMessage={errorMessage}
+        announcements={announcements?.data}
         onPasskeyLogin={loginSettings?.passkey_enabled ? onPasskeyLogin : undefined}
         isPasskeyLoading={isPasskeyLoading}
         onMagicLinkLogin={loginSettings?.magic_link_enabled ? onMagicLinkLogin : undefined}
@@ -1,5 +1,6 @@
 import { z } from 'zod'
 import PageRegister from '../ui/page-register'
+import { usePortalMessages } from '../hooks/use-portal-messages'
 import { useForm } from 'react-hook-form'
 import { zodResolver } from '@hookform/resolvers/zod'
 import { useNavigate, useParams } from 'react-router'
@@ -47,6 +48,7 @@ export default function PageRegisterFeature() {
 
   const { data: policy, isLoading: isPolicyLoading } = usePublicPasswordPolicy(realm_name)
   const resolvedPolicy = policy ?? DEFAULT_PASSWORD_POLICY
+  const { t } = usePortalMessages()
 
   const registerSchema = useMemo(() => buildRegisterSchema(resolvedPolicy), [resolvedPolicy])
 
@@ -122,6 +124,7 @@ export default function PageRegisterFeature() {
       onSubmit={onSubmit}
       backToLogin={backToLogin}
       policy={resolvedPolicy}
+      t={t}
     />
   )
 }
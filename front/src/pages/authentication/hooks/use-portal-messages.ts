@@ -0,0 +1,33 @@
+import { useCallback, useEffect, useMemo } from 'react'
+import { useLocation, useParams } from 'react-router'
+import { useGetPortalMessages } from '@/api/localization.api'
+
+export type PortalTranslate = (key: string, fallback: string) => string
+
+/**
+ * Texts of the login portal in the visitor's language, from the realm's message bundles.
+ * The authorize endpoint forwards `ui_locales` on the login URL and the pages pass it on;
+ * without it the backend falls back to the locale cookie, `Accept-Language` and the realm
+ * default. `fallback` is rendered until the texts load or when a key has no text.
+ */
+export function usePortalMessages() {
+  const { realm_name } = useParams()
+  const location = useLocation()
+  const uiLocales = useMemo(
+    () => new URLSearchParams(location.search).get('ui_locales'),
+    [location.search]
+  )
+
+  const { data } = useGetPortalMessages({ realm: realm_name ?? 'master', uiLocales })
+
+  useEffect(() => {
+    if (data?.locale) document.documentElement.lang = data.locale
+  }, [data?.locale])
+
+  const t = useCallback<PortalTranslate>(
+    (key, fallback) => data?.messages[key] ?? fallback,
+    [data]
+  )
+
+  return { t, locale: data?.locale }
+}
@@ -11,6 +11,7 @@ import PageMagicLinkRequestFeature from './feature/page-magic-link-request-featu
 import PageEmailVerifiedFeature from './feature/page-email-verified-feature'
 import PageCheckYourEmail from './feature/page-check-your-email'
 import PageDeviceVerifyFeature from './feature/page-device-verify-feature'
+import PageBrokerLoginFeature from './feature/page-broker-login-feature'
 import VerifyEmailRoute from './feature/verify-email-route'
 import { PortalLayoutWrapper } from './components/portal-layout-wrapper'
 import type { Schemas } from '@/api/api.client'
@@ -118,6 +119,10 @@ export default function PageAuthentication() {
       {/* Routes without a portal page type render bare. */}
       <Route path='/callback' element={<PageCallbackFeature />} />
       <Route path='/required-action' element={<PageRequiredActionFeature />} />
+      {/* First login through an identity provider: the broker callback parks
+          the login behind a cookie and redirects here for link confirmation,
+          profile review or the OTP step. */}
+      <Route path='/broker-login' element={<PageBrokerLoginFeature />} />
       {/* RFC 8628 §3.3 device verification page. Wrapped in <Portal> so realm
           admins can customise the code-entry screen via the theme builder
           (`device_verify` page type); `PageDeviceVerifyFeature` is the React
@@ -0,0 +1,28 @@
+import { z } from 'zod'
+
+export const brokerProfileSchema = z.object({
+  username: z.string().trim(),
+  email: z.union([
+    z.literal(''),
+    z.string().trim().email({ message: 'Please enter a valid email address' }),
+  ]),
+  first_name: z.string().trim(),
+  last_name: z.string().trim(),
+})
+
+export type BrokerProfileSchema = z.infer<typeof brokerProfileSchema>
+
+export const brokerLinkSchema = z.object({
+  password: z.string(),
+  code: z.string().trim(),
+})
+
+export type BrokerLinkSchema = z.infer<typeof brokerLinkSchema>
+
+export const brokerOtpSchema = z.object({
+  code: z.string().min(6, {
+    message: 'Code must be at least 6 characters long',
+  }),
+})
+
+export type BrokerOtpSchema = z.infer<typeof brokerOtpSchema>
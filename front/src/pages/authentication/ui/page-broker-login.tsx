@@ -0,0 +1,375 @@
+import type { Schemas } from '@/api/api.client'
+import { Button } from '@/components/ui/button'
+import { Card, CardContent } from '@/components/ui/card'
+import { Form, FormControl, FormField, FormItem } from '@/components/ui/form'
+import { InputOTP, InputOTPGroup, InputOTPSlot } from '@/components/ui/input-otp'
+import { InputText } from '@/components/ui/input-text'
+import { BasicSpinner } from '@/components/ui/spinner'
+import { REGEXP_ONLY_DIGITS } from 'input-otp'
+import { Link2, ShieldCheck, UserPen } from 'lucide-react'
+import type { ReactNode } from 'react'
+import { UseFormReturn } from 'react-hook-form'
+import type {
+  BrokerLinkSchema,
+  BrokerOtpSchema,
+  BrokerProfileSchema,
+} from '../schemas/broker-login.schema'
+import './page-login.css'
+
+const PROFILE_FIELD_LABELS: Record<Schemas.ProfileField, string> = {
+  username: 'Username',
+  email: 'Email',
+  first_name: 'First name',
+  last_name: 'Last name',
+}
+
+export interface PageBrokerLoginProps {
+  login: Schemas.PendingBrokerLoginView | null
+  isLoading: boolean
+  expired: boolean
+  codeSent: boolean
+  profileForm: UseFormReturn<BrokerProfileSchema>
+  linkForm: UseFormReturn<BrokerLinkSchema>
+  otpForm: UseFormReturn<BrokerOtpSchema>
+  onReviewProfile: (values: BrokerProfileSchema) => void
+  onSendCode: () => void
+  onConfirmLink: (values: BrokerLinkSchema) => void
+  onVerifyOtp: (values: BrokerOtpSchema) => void
+  backToLogin: () => void
+  isReviewing?: boolean
+  isSendingCode?: boolean
+  isConfirming?: boolean
+  isVerifying?: boolean
+}
+
+export default function PageBrokerLogin(props: PageBrokerLoginProps) {
+  const { login, isLoading, expired, backToLogin } = props
+
+  if (isLoading) {
+    return (
+      <BrokerLoginShell>
+        <div className='flex justify-center p-10'>
+          <BasicSpinner />
+        </div>
+      </BrokerLoginShell>
+    )
+  }
+
+  if (expired || !login) {
+    return (
+      <BrokerLoginShell>
+        <BrokerLoginHeader
+          title='Sign-in expired'
+          description='This sign-in is no longer pending. Start again from the login page.'
+        />
+        <div className='px-6 pb-6 md:px-10 md:pb-10'>
+          <Button type='button' className='w-full rounded-lg py-5 text-sm' onClick={backToLogin}>
+            Back to login
+          </Button>
+        </div>
+      </BrokerLoginShell>
+    )
+  }
+
+  const provider = login.identity_provider_display_name ?? login.identity_provider_alias
+
+  return (
+    <BrokerLoginShell>
+      {login.stage === 'review_profile' && (
+        <ReviewProfileStep {...props} provider={provider} login={login} />
+      )}
+      {login.stage === 'confirm_link' && (
+        <ConfirmLinkStep {...props} provider={provider} login={login} />
+      )}
+      {login.stage === 'otp' && <OtpStep {...props} />}
+    </BrokerLoginShell>
+  )
+}
+
+type StepProps = PageBrokerLoginProps & {
+  provider: string
+  login: Schemas.PendingBrokerLoginView
+}
+
+function ReviewProfileStep({
+  login,
+  provider,
+  profileForm: form,
+  onReviewProfile,
+  backToLogin,
+  isReviewing,
+}: StepProps) {
+  const fields: Schemas.ProfileField[] = ['username', 'email', 'first_name', 'last_name']
+
+  return (
+    <Form {...form}>
+      <form onSubmit={form.handleSubmit(onReviewProfile)}>
+        <BrokerLoginHeader
+          icon={<UserPen className='h-6 w-6 text-primary' />}
+          title='Review your profile'
+          description={`Check the details ${provider} shared before your account is created.`}
+        />
+        <div className='flex flex-col gap-6 px-6 pb-6 md:px-10 md:pb-10'>
+          {login.missing_fields.length > 0 && (
+            <p className='text-sm text-muted-foreground'>
+              {provider} did not share every required detail, please fill in the missing fields.
+            </p>
+          )}
+          <div className='grid gap-4'>
+            {fields.map((name) => (
+              <FormField
+                key={name}
+                control={form.control}
+                name={name}
+                render={({ field, fieldState }) => (
+                  <div className='flex flex-col gap-1'>
+                    <InputText
+                      {...field}
+                      label={PROFILE_FIELD_LABELS[name]}
+                      type={name === 'email' ? 'email' : 'text'}
+                      required={login.required_fields.includes(name)}
+                      className='w-full'
+                    />
+                    {fieldState.error && (
+                      <p className='text-sm text-destructive'>{fieldState.error.message}</p>
+                    )}
+                    {name === 'username' && login.username_taken && (
+                      <p className='text-sm text-destructive'>
+                        This username is already taken, please choose another one.
+                      </p>
+                    )}
+                  </div>
+                )}
+              />
+            ))}
+          </div>
+          <div className='flex flex-col gap-2'>
+            <Button type='submit' className='w-full rounded-lg py-5 text-sm' disabled={isReviewing}>
+              {isReviewing ? <Pending label='Saving...' /> : 'Continue'}
+            </Button>
+            <Button
+              type='button'
+              variant='outline'
+              className='w-full rounded-lg py-5 text-sm'
+              onClick={backToLogin}
+            >
+              Cancel
+            </Button>
+          </div>
+        </div>
+      </form>
+    </Form>
+  )
+}
+
+function ConfirmLinkStep({
+  login,
+  provider,
+  linkForm: form,
+  codeSent,
+  onSendCode,
+  onConfirmLink,
+  backToLogin,
+  isSendingCode,
+  isConfirming,
+}: StepProps) {
+  const byPassword = login.confirmation_methods.includes('password')
+  const byEmailCode = login.confirmation_methods.includes('email_code')
+  const account = login.existing_account
+
+  return (
+    <Form {...form}>
+      <form onSubmit={form.handleSubmit(onConfirmLink)}>
+        <BrokerLoginHeader
+          icon={<Link2 className='h-6 w-6 text-primary' />}
+          title='Link your account'
+          description={`An account already matches this ${provider} login. Confirm it is yours to link them.`}
+        />
+        <div className='flex flex-col gap-6 px-6 pb-6 md:px-10 md:pb-10'>
+          {account && (
+            <div className='rounded-lg border border-border bg-muted/30 p-3 text-sm'>
+              <span className='font-medium text-foreground'>{account.username}</span>
+              {account.email && (
+                <span className='text-muted-foreground'> · {account.email}</span>
+              )}
+            </div>
+          )}
+          {byPassword && (
+            <FormField
+              control={form.control}
+              name='password'
+              render={({ field }) => (
+                <InputText
+                  {...field}
+                  label='Password'
+                  type='password'
+                  autoComplete='current-password'
+                  className='w-full'
+                />
+              )}
+            />
+          )}
+          {byEmailCode && (
+            <div className='flex flex-col gap-2'>
+              {byPassword && (
+                <p className='text-xs text-muted-foreground'>
+                  Or confirm with a code sent by email.
+                </p>
+              )}
+              {codeSent && (
+                <FormField
+                  control={form.control}
+                  name='code'
+                  render={({ field }) => (
+                    <InputText {...field} label='Confirmation code' className='w-full' />
+                  )}
+                />
+              )}
+              <Button
+                type='button'
+                variant='outline'
+                className='w-full rounded-lg py-5 text-sm'
+                onClick={onSendCode}
+                disabled={isSendingCode}
+              >
+                {isSendingCode ? (
+                  <Pending label='Sending...' />
+                ) : codeSent ? (
+                  'Resend code'
+                ) : (
+                  'Email me a code'
+                )}
+              </Button>
+            </div>
+          )}
+          <div className='flex flex-col gap-2'>
+            <Button
+              type='submit'
+              className='w-full rounded-lg py-5 text-sm'
+              disabled={isConfirming}
+            >
+              {isConfirming ? <Pending label='Linking...' /> : 'Link account'}
+            </Button>
+            <Button
+              type='button'
+              variant='outline'
+              className='w-full rounded-lg py-5 text-sm'
+              onClick={backToLogin}
+            >
+              Cancel
+            </Button>
+          </div>
+        </div>
+      </form>
+    </Form>
+  )
+}
+
+function OtpStep({ otpForm: form, onVerifyOtp, backToLogin, isVerifying }: PageBrokerLoginProps) {
+  return (
+    <Form {...form}>
+      <form onSubmit={form.handleSubmit(onVerifyOtp)}>
+        <BrokerLoginHeader
+          icon={<ShieldCheck className='h-6 w-6 text-primary' />}
+          title='Verification code'
+          description='Enter the 6-digit code from your authenticator app.'
+        />
+        <div className='flex flex-col items-center gap-6 px-6 pb-6 md:px-10 md:pb-10'>
+          <FormField
+            control={form.control}
+            name='code'
+            render={({ field }) => (
+              <FormItem>
+                <FormControl>
+                  <InputOTP {...field} maxLength={6} pattern={REGEXP_ONLY_DIGITS} autoFocus>
+                    <InputOTPGroup>
+                      {[0, 1, 2, 3, 4, 5].map((index) => (
+                        <InputOTPSlot
+                          key={index}
+                          className='h-10 w-9 sm:h-11 sm:w-11'
+                          index={index}
+                        />
+                      ))}
+                    </InputOTPGroup>
+                  </InputOTP>
+                </FormControl>
+              </FormItem>
+            )}
+          />
+          <div className='flex w-full flex-col gap-2'>
+            <Button
+              type='submit'
+              className='w-full rounded-lg py-5 text-sm'
+              disabled={!form.formState.isValid || isVerifying}
+            >
+              {isVerifying ? <Pending label='Signing in...' /> : 'Sign in'}
+            </Button>
+            <Button
+              type='button'
+              variant='outline'
+              className='w-full rounded-lg py-5 text-sm'
+              onClick={backToLogin}
+            >
+              Cancel
+            </Button>
+          </div>
+        </div>
+      </form>
+    </Form>
+  )
+}
+
+function Pending({ label }: { label: string }) {
+  return (
+    <div className='flex items-center gap-2'>
+      <BasicSpinner />
+      <span>{label}</span>
+    </div>
+  )
+}
+
+function BrokerLoginHeader({
+  icon,
+  title,
+  description,
+}: {
+  icon?: ReactNode
+  title: string
+  description: string
+}) {
+  return (
+    <div className='flex flex-col gap-4 p-6 md:p-10'>
+      <div className='flex items-center gap-3'>
+        <img src='/logo_ferriskey.png' alt='FerrisKey' className='h-7 w-7 object-contain' />
+        <p className='text-xs font-semibold uppercase tracking-[0.35em] text-muted-foreground'>
+          FerrisKey
+        </p>
+      </div>
+      {icon && (
+        <div className='flex h-12 w-12 items-center justify-center rounded-full bg-primary/10'>
+          {icon}
+        </div>
+      )}
+      <div className='space-y-2'>
+        <h1 className='login-title text-3xl font-semibold tracking-tight text-foreground'>
+          {title}
+        </h1>
+        <p className='text-sm text-muted-foreground'>{description}</p>
+      </div>
+    </div>
+  )
+}
+
+function BrokerLoginShell({ children }: { children: ReactNode }) {
+  return (
+    <div className='login-shell relative flex min-h-svh items-center justify-center px-6 py-10'>
+      <div className='relative z-10 w-full max-w-sm md:max-w-md lg:max-w-lg'>
+        <div className='flex flex-col gap-6'>
+          <Card className='login-card overflow-hidden border p-0 shadow-sm'>
+            <CardContent className='grid gap-0 p-0'>{children}</CardContent>
+          </Card>
+        </div>
+      </div>
+    </div>
+  )
+}
@@ -7,15 +7,23 @@ import { Link, useParams } from 'react-router'
 import { type ForgotPasswordSchema } from '../schemas/forgot-password.schema'
 import { CheckCircle } from 'lucide-react'
 import './page-login.css'
+import type { PortalTranslate } from '../hooks/use-portal-messages'
 
 export interface PageForgotPasswordProps {
   form: UseFormReturn<ForgotPasswordSchema>
   onSubmit: (data: ForgotPasswordSchema) => void
   submitted: boolean
   isPending: boolean
+  t: PortalTranslate
 }
 
-export default function PageForgotPassword({ form, onSubmit, submitted, isPending }: PageForgotPasswordProps) {
+export default function PageForgotPassword({
+  form,
+  onSubmit,
+  submitted,
+  isPending,
+  t,
+}: PageForgotPasswordProps) {
   const { realm_name } = useParams()
 
   return (
@@ -38,7 +46,7 @@ export default function PageForgotPassword({ form, onSubmit, submitted, isPendin
                       </p>
                     </div>
                     <h1 className='login-title text-3xl font-semibold tracking-tight text-foreground'>
-                      Forgot your password?
+                      {t('reset_password.title', 'Forgot your password?')}
                     </h1>
                   </div>
 
@@ -74,7 +82,7 @@ export default function PageForgotPassword({ form, onSubmit, submitted, isPendin
                           />
                         </div>
                         <Button type='submit' className='w-full rounded-lg py-5 text-sm' disabled={isPending}>
-                          {isPending ? 'Sending...' : 'Send reset link'}
+                          {isPending ? 'Sending...' : t('reset_password.submit', 'Send reset link')}
                         </Button>
                       </div>
                     </form>
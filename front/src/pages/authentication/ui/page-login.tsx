@@ -9,21 +9,25 @@ import { InputText } from '@/components/ui/input-text'
 import { Link, useParams } from 'react-router'
 import { Schemas } from '@/api/api.client'
 import RealmLoginSetting = Schemas.RealmLoginSetting
+import MaintenanceAnnouncement = Schemas.MaintenanceAnnouncement
 import { LoginProviders } from './login-providers'
 import './page-login.css'
 import LoaderSpinner from '@/components/ui/loader-spinner'
 import { Separator } from '@/components/ui/separator'
 import { ArrowLeft, KeyRound, Mail, ShieldAlert, Wrench } from 'lucide-react'
+import type { PortalTranslate } from '../hooks/use-portal-messages'
 
 export type MagicLinkStep = 'idle' | 'form' | 'sent'
 
 export interface PageLoginProps {
   form: UseFormReturn<AuthenticateSchema>
   onSubmit: (data: AuthenticateSchema) => void
+  t: PortalTranslate
   isError?: boolean
   isLoading?: boolean
   loginSettings?: RealmLoginSetting
   errorMessage?: string | null
+  announcements?: MaintenanceAnnouncement[]
   onPasskeyLogin?: () => void
   isPasskeyLoading?: boolean
   onMagicLinkLogin?: () => void
@@ -37,10 +41,12 @@ export interface PageLoginProps {
 export default function PageLogin({
   form,
   onSubmit,
+  t,
   isError,
   isLoading,
   loginSettings,
   errorMessage,
+  announcements,
   onPasskeyLogin,
   isPasskeyLoading,
   onMagicLinkLogin,
@@ -61,7 +67,7 @@ export default function PageLogin({
   const aliases = loginSettings.login_aliases ?? ['username']
   const identifierLabel =
     aliases.length > 1
-      ? 'Username or email'
+      ? t('login.username', 'Username or email')
       : aliases[0] === 'email'
         ? 'Email'
         : 'Username'
@@ -103,6 +109,9 @@ export default function PageLogin({
                               : (realm_name?.toUpperCase() ?? 'Login')}
                           </h1>
                         </div>
+                        {announcements && announcements.length > 0 && (
+                          <MaintenanceBanner announcements={announcements} />
+                        )}
                         {errorMessage && (
                           errorMessage.includes('under maintenance') ? (
                             <div className='rounded-md border border-amber-500/30 bg-amber-500/10 px-4 py-3 text-sm text-amber-700 dark:text-amber-400 flex items-start gap-2'>
@@ -138,7 +147,7 @@ export default function PageLogin({
                             render={({ field }) => (
                               <InputText
                                 {...field}
-                                label='Password'
+                                label={t('login.password', 'Password')}
                                 name='password'
                                 type='password'
                                 className='w-full'
@@ -152,13 +161,13 @@ export default function PageLogin({
                                 to={'../forgot-password'}
                                 className='ml-auto text-xs font-medium text-muted-foreground underline-offset-4 transition hover:text-foreground hover:underline'
                               >
-                                Forgot your password?
+                                {t('login.forgot_password', 'Forgot your password?')}
                               </Link>
                             </div>
                           )}
                         </div>
                         <Button type='submit' className='w-full rounded-lg py-5 text-sm'>
-                          Login
+                          {t('login.submit', 'Login')}
                         </Button>
                         {(onPasskeyLogin || onMagicLinkLogin) && (
                           <>
@@ -200,7 +209,7 @@ export default function PageLogin({
                           <LoginProviders providers={providers} />
                           {loginSettings.user_registration_enabled && (
                             <div className='text-center text-xs text-muted-foreground md:text-sm'>
-                              Don&apos;t have an account?{' '}
+                              {t('login.no_account', "Don't have an account?")}{' '}
                               <Link to={'../register'} className='font-semibold text-foreground underline underline-offset-4'>
                                 Sign up
                               </Link>
@@ -322,6 +331,37 @@ function MagicLinkSentView({ onBack }: { onBack?: () => void }) {
   )
 }
 
+function formatMaintenanceDate(value: string) {
+  return new Intl.DateTimeFormat(undefined, {
+    dateStyle: 'medium',
+    timeStyle: 'short',
+  }).format(new Date(value))
+}
+
+function MaintenanceBanner({ announcements }: { announcements: MaintenanceAnnouncement[] }) {
+  return (
+    <div className='flex flex-col gap-2'>
+      {announcements.map((announcement) => (
+        <div
+          key={`${announcement.client_id ?? 'realm'}-${announcement.starts_at}`}
+          role='status'
+          className='rounded-md border border-amber-500/30 bg-amber-500/10 px-4 py-3 text-sm text-amber-700 dark:text-amber-400 flex items-start gap-2'
+        >
+          <Wrench className='h-4 w-4 mt-0.5 shrink-0' />
+          <div className='flex flex-col gap-0.5'>
+            <span className='font-medium'>
+              {announcement.in_progress
+                ? `Maintenance in progress until ${formatMaintenanceDate(announcement.ends_at)}`
+                : `Scheduled maintenance from ${formatMaintenanceDate(announcement.starts_at)} to ${formatMaintenanceDate(announcement.ends_at)}`}
+            </span>
+            {announcement.reason && <span>{announcement.reason}</span>}
+          </div>
+        </div>
+      ))}
+    </div>
+  )
+}
+
 function ErrorMessage() {
   return (
     <div className='flex min-h-svh flex-col items-center justify-center'>
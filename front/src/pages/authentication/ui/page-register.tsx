@@ -8,15 +8,23 @@ import { useParams } from 'react-router'
 import './page-login.css'
 import type { PublicPasswordPolicy } from '@/api/password-policy.api'
 import PasswordRequirements from '../components/password-requirements'
+import type { PortalTranslate } from '../hooks/use-portal-messages'
 
 export interface PageRegisterProps {
   form: UseFormReturn<RegisterSchema>
   onSubmit: (data: RegisterSchema) => void
   backToLogin?: () => void
   policy: PublicPasswordPolicy
+  t: PortalTranslate
 }
 
-export default function PageRegister({ form, onSubmit, backToLogin, policy }: PageRegisterProps) {
+export default function PageRegister({
+  form,
+  onSubmit,
+  backToLogin,
+  policy,
+  t,
+}: PageRegisterProps) {
   const { realm_name } = useParams()
   const password = form.watch('password')
   const isPasswordValid = !form.formState.errors.password && password.length > 0
@@ -46,7 +54,7 @@ export default function PageRegister({ form, onSubmit, backToLogin, policy }: Pa
                         </div>
                         <div className='space-y-1'>
                           <h1 className='login-title text-3xl font-semibold tracking-tight text-foreground md:text-4xl'>
-                            {realm_name ?? 'Create account'}
+                            {realm_name ?? t('register.title', 'Create account')}
                           </h1>
                         </div>
                       </div>
@@ -92,7 +100,7 @@ export default function PageRegister({ form, onSubmit, backToLogin, policy }: Pa
                               <div className='flex flex-col gap-1'>
                                 <InputText
                                   {...field}
-                                  label='Password'
+                                  label={t('login.password', 'Password')}
                                   type='password'
                                   className='w-full'
                                 />
@@ -162,7 +170,7 @@ export default function PageRegister({ form, onSubmit, backToLogin, policy }: Pa
 
                       <div className='flex flex-col gap-2'>
                         <Button className='w-full' disabled={!isFormSubmittable}>
-                          Create Account
+                          {t('register.submit', 'Create Account')}
                         </Button>
 
                         <Button type='button' variant='outline' onClick={backToLogin} className='w-full'>
@@ -0,0 +1,394 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::identity_provider::IdentityProviderId;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::RealmId;
+
+use super::value_objects::{BrokerCallbackOutput, BrokeredUserInfo};
+
+/// Wrong passwords or codes tolerated before a pending login is thrown away.
+pub const MAX_CONFIRMATION_ATTEMPTS: i32 = 5;
+
+/// What happens the first time an upstream identity signs in, selected by the provider's
+/// `first_broker_login_flow_alias`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FirstBrokerLoginFlow {
+    /// Create the account straight away unless a profile field is missing or taken.
+    #[default]
+    Default,
+    /// Always let the user review the profile before the account is created.
+    ReviewProfile,
+}
+
+impl FirstBrokerLoginFlow {
+    pub const DEFAULT_ALIAS: &'static str = "first-broker-login";
+    pub const REVIEW_PROFILE_ALIAS: &'static str = "review-profile";
+
+    pub fn from_alias(alias: Option<&str>) -> Result<Self, CoreError> {
+        match alias {
+            None | Some(Self::DEFAULT_ALIAS) => Ok(Self::Default),
+            Some(Self::REVIEW_PROFILE_ALIAS) => Ok(Self::ReviewProfile),
+            Some(other) => Err(CoreError::InvalidProviderConfiguration(format!(
+                "unknown first broker login flow '{other}'"
+            ))),
+        }
+    }
+}
+
+/// Checks run after every brokered login, selected by `post_broker_login_flow_alias`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PostBrokerLoginFlow {
+    #[default]
+    None,
+    /// The account's one-time password must be entered before the login completes.
+    RequireOtp,
+}
+
+impl PostBrokerLoginFlow {
+    pub const REQUIRE_OTP_ALIAS: &'static str = "require-otp";
+
+    pub fn from_alias(alias: Option<&str>) -> Result<Self, CoreError> {
+        match alias {
+            None => Ok(Self::None),
+            Some(Self::REQUIRE_OTP_ALIAS) => Ok(Self::RequireOtp),
+            Some(other) => Err(CoreError::InvalidProviderConfiguration(format!(
+                "unknown post broker login flow '{other}'"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileField {
+    Username,
+    Email,
+    FirstName,
+    LastName,
+}
+
+/// The step a pending brokered login waits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PendingBrokerLoginStage {
+    /// Required profile fields are missing or the username is taken.
+    ReviewProfile,
+    /// The email belongs to an existing account, whose owner has to prove it is theirs.
+    ConfirmLink,
+    /// The post-login flow asks for the account's one-time password.
+    Otp,
+}
+
+impl PendingBrokerLoginStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ReviewProfile => "review_profile",
+            Self::ConfirmLink => "confirm_link",
+            Self::Otp => "otp",
+        }
+    }
+}
+
+impl Display for PendingBrokerLoginStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PendingBrokerLoginStage {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "review_profile" => Ok(Self::ReviewProfile),
+            "confirm_link" => Ok(Self::ConfirmLink),
+            "otp" => Ok(Self::Otp),
+            _ => Err(CoreError::InternalServerError),
+        }
+    }
+}
+
+/// How the owner of an existing account confirms it may be linked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkConfirmationMethod {
+    Password,
+    EmailCode,
+}
+
+/// A brokered login that stopped for user interaction; the browser holds `token`.
+#[derive(Debug, Clone)]
+pub struct PendingBrokerLogin {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub identity_provider_id: IdentityProviderId,
+    pub broker_session_id: Uuid,
+    pub token: String,
+    pub stage: PendingBrokerLoginStage,
+    pub brokered_user: BrokeredUserInfo,
+    pub idp_access_token: Option<String>,
+    /// The account to link (confirm_link) or that signed in (otp)
+    pub user_id: Option<Uuid>,
+    pub is_new_user: bool,
+    pub confirmation_code_hash: Option<String>,
+    pub confirmation_code_expires_at: Option<DateTime<Utc>>,
+    pub failed_attempts: i32,
+    pub compass_flow_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PendingBrokerLogin {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now > self.expires_at
+    }
+}
+
+pub struct CreatePendingBrokerLoginRequest {
+    pub realm_id: RealmId,
+    pub identity_provider_id: IdentityProviderId,
+    pub broker_session_id: Uuid,
+    pub token: String,
+    pub stage: PendingBrokerLoginStage,
+    pub brokered_user: BrokeredUserInfo,
+    pub idp_access_token: Option<String>,
+    pub user_id: Option<Uuid>,
+    pub is_new_user: bool,
+    pub compass_flow_id: Option<Uuid>,
+}
+
+/// Moves a pending login to its next stage.
+pub struct UpdatePendingBrokerLoginRequest {
+    pub stage: PendingBrokerLoginStage,
+    pub brokered_user: BrokeredUserInfo,
+    pub user_id: Option<Uuid>,
+    pub is_new_user: bool,
+}
+
+/// Profile values entered on the review page; absent values keep what the provider sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct BrokeredProfile {
+    pub username: Option<String>,
+    pub email: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+}
+
+impl BrokeredProfile {
+    pub fn value(&self, field: ProfileField) -> Option<&str> {
+        match field {
+            ProfileField::Username => self.username.as_deref(),
+            ProfileField::Email => self.email.as_deref(),
+            ProfileField::FirstName => self.first_name.as_deref(),
+            ProfileField::LastName => self.last_name.as_deref(),
+        }
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    }
+
+    /// Required fields without a value.
+    pub fn missing_fields(&self, required: &[ProfileField]) -> Vec<ProfileField> {
+        required
+            .iter()
+            .copied()
+            .filter(|field| self.value(*field).is_none())
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if let Some(email) = self.value(ProfileField::Email) {
+            let valid = email
+                .split_once('@')
+                .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.'));
+            if !valid {
+                return Err(CoreError::InvalidRequest);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&BrokeredUserInfo> for BrokeredProfile {
+    fn from(user_info: &BrokeredUserInfo) -> Self {
+        Self {
+            username: user_info.preferred_username.clone(),
+            email: user_info.email.clone(),
+            first_name: user_info.given_name.clone(),
+            last_name: user_info.family_name.clone(),
+        }
+    }
+}
+
+impl BrokeredUserInfo {
+    /// Replaces the profile claims with reviewed values. A changed email is no longer the
+    /// one the provider vouched for.
+    pub fn apply_review(&mut self, profile: BrokeredProfile) {
+        let trimmed = |value: Option<String>| {
+            value
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        if let Some(username) = trimmed(profile.username) {
+            self.preferred_username = Some(username);
+        }
+        if let Some(email) = trimmed(profile.email)
+            && self.email.as_deref() != Some(email.as_str())
+        {
+            self.email = Some(email);
+            self.email_verified = Some(false);
+        }
+        if let Some(first_name) = trimmed(profile.first_name) {
+            self.given_name = Some(first_name);
+        }
+        if let Some(last_name) = trimmed(profile.last_name) {
+            self.family_name = Some(last_name);
+        }
+    }
+}
+
+/// The existing account a login would be linked to, with its email partly hidden.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ExistingAccountHint {
+    pub username: String,
+    pub email: Option<String>,
+}
+
+pub fn mask_email(email: &str) -> String {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            let visible: String = local.chars().take(1).collect();
+            format!("{visible}***@{domain}")
+        }
+        None => "***".to_string(),
+    }
+}
+
+/// What the login page shows for a pending brokered login.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PendingBrokerLoginView {
+    pub stage: PendingBrokerLoginStage,
+    pub identity_provider_alias: String,
+    pub identity_provider_display_name: Option<String>,
+    pub profile: BrokeredProfile,
+    pub required_fields: Vec<ProfileField>,
+    pub missing_fields: Vec<ProfileField>,
+    pub username_taken: bool,
+    pub existing_account: Option<ExistingAccountHint>,
+    pub confirmation_methods: Vec<LinkConfirmationMethod>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Result of a brokered login step: done, or waiting on the user.
+#[derive(Debug, Clone)]
+pub enum BrokerLoginOutcome {
+    Completed(BrokerCallbackOutput),
+    Pending {
+        token: String,
+        login: Box<PendingBrokerLoginView>,
+    },
+}
+
+pub struct PendingBrokerLoginInput {
+    pub realm_name: String,
+    pub token: String,
+}
+
+pub struct ReviewBrokeredProfileInput {
+    pub realm_name: String,
+    pub token: String,
+    pub profile: BrokeredProfile,
+}
+
+pub struct ConfirmBrokerLinkInput {
+    pub realm_name: String,
+    pub token: String,
+    pub password: Option<String>,
+    pub code: Option<String>,
+}
+
+pub struct VerifyBrokerLoginOtpInput {
+    pub realm_name: String,
+    pub token: String,
+    pub code: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flow_aliases() {
+        assert_eq!(
+            FirstBrokerLoginFlow::from_alias(None).unwrap(),
+            FirstBrokerLoginFlow::Default
+        );
+        assert_eq!(
+            FirstBrokerLoginFlow::from_alias(Some("review-profile")).unwrap(),
+            FirstBrokerLoginFlow::ReviewProfile
+        );
+        assert!(FirstBrokerLoginFlow::from_alias(Some("browser")).is_err());
+
+        assert_eq!(
+            PostBrokerLoginFlow::from_alias(Some("require-otp")).unwrap(),
+            PostBrokerLoginFlow::RequireOtp
+        );
+        assert!(PostBrokerLoginFlow::from_alias(Some("")).is_err());
+    }
+
+    #[test]
+    fn test_missing_fields_ignore_blank_values() {
+        let profile = BrokeredProfile {
+            username: Some("ada".to_string()),
+            email: Some("  ".to_string()),
+            first_name: None,
+            last_name: Some("Lovelace".to_string()),
+        };
+
+        assert_eq!(
+            profile.missing_fields(&[
+                ProfileField::Username,
+                ProfileField::Email,
+                ProfileField::FirstName
+            ]),
+            vec![ProfileField::Email, ProfileField::FirstName]
+        );
+    }
+
+    #[test]
+    fn test_reviewed_email_is_no_longer_verified() {
+        let mut user_info = BrokeredUserInfo {
+            subject: "42".to_string(),
+            email: Some("ada@example.com".to_string()),
+            email_verified: Some(true),
+            ..Default::default()
+        };
+
+        user_info.apply_review(BrokeredProfile {
+            email: Some("ada@example.com".to_string()),
+            first_name: Some("Ada".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(user_info.email_verified, Some(true));
+        assert_eq!(user_info.given_name.as_deref(), Some("Ada"));
+
+        user_info.apply_review(BrokeredProfile {
+            email: Some("someone@example.com".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(user_info.email_verified, Some(false));
+    }
+
+    #[test]
+    fn test_mask_email() {
+        assert_eq!(mask_email("ada@example.com"), "a***@example.com");
+        assert_eq!(mask_email("invalid"), "***");
+    }
+}
@@ -1,13 +1,22 @@
 pub mod entities;
+pub mod first_login;
 pub mod ports;
 pub mod value_objects;
 
 pub use entities::{BrokerAuthSession, BrokerAuthSessionParams, IdentityProviderLink};
+pub use first_login::{
+    BrokerLoginOutcome, BrokeredProfile, ConfirmBrokerLinkInput, FirstBrokerLoginFlow,
+    LinkConfirmationMethod, PendingBrokerLogin, PendingBrokerLoginInput, PendingBrokerLoginStage,
+    PendingBrokerLoginView, PostBrokerLoginFlow, ProfileField, ReviewBrokeredProfileInput,
+    VerifyBrokerLoginOtpInput,
+};
 pub use ports::{
     BrokerAuthSessionRepository, BrokerService, IdentityProviderLinkRepository, OAuthClient,
+    PendingBrokerLoginRepository,
 };
 pub use value_objects::{
     BrokerCallbackInput, BrokerCallbackOutput, BrokerLoginInput, BrokerLoginOutput,
-    BrokeredUserInfo, CreateBrokerAuthSessionRequest, CreateIdentityProviderLinkRequest,
-    OAuthProviderConfig, OAuthTokenResponse,
+    BrokerSamlMetadataInput, BrokerSamlResponseInput, BrokeredUserInfo, ClientAuthMethod,
+    CreateBrokerAuthSessionRequest, CreateIdentityProviderLinkRequest, OAuthProviderConfig,
+    OAuthTokenResponse, TokenEndpointAuth,
 };
@@ -1,16 +1,24 @@
 use std::future::Future;
 
+use chrono::{DateTime, Utc};
+
 use uuid::Uuid;
 
 use crate::identity_provider::IdentityProviderId;
 use ferriskey_domain::common::app_errors::CoreError;
 
 use super::entities::{BrokerAuthSession, IdentityProviderLink};
+use super::first_login::{
+    BrokerLoginOutcome, ConfirmBrokerLinkInput, CreatePendingBrokerLoginRequest,
+    PendingBrokerLogin, PendingBrokerLoginInput, PendingBrokerLoginView,
+    ReviewBrokeredProfileInput, UpdatePendingBrokerLoginRequest, VerifyBrokerLoginOtpInput,
+};
 use super::value_objects::{
-    BrokerCallbackInput, BrokerCallbackOutput, BrokerLoginInput, BrokerLoginOutput,
-    BrokeredUserInfo, CreateBrokerAuthSessionRequest, CreateIdentityProviderLinkRequest,
-    OAuthProviderConfig, OAuthTokenResponse,
+    BrokerCallbackInput, BrokerLoginInput, BrokerLoginOutput, BrokerSamlMetadataInput,
+    BrokerSamlResponseInput, BrokeredUserInfo, CreateBrokerAuthSessionRequest,
+    CreateIdentityProviderLinkRequest, OAuthProviderConfig, OAuthTokenResponse, TokenEndpointAuth,
 };
+use crate::identity_provider::discovery::OidcDiscoveryDocument;
 
 /// Repository trait for BrokerAuthSession persistence
 pub trait BrokerAuthSessionRepository: Send + Sync {
@@ -32,6 +40,13 @@ pub trait BrokerAuthSessionRepository: Send + Sync {
         id: Uuid,
     ) -> impl Future<Output = Result<Option<BrokerAuthSession>, CoreError>> + Send;
 
+    /// Retrieves a broker auth session by its broker state and replaces that state, so the
+    /// same state is never accepted twice (SAML responses are replayable otherwise)
+    fn consume_broker_state(
+        &self,
+        broker_state: &str,
+    ) -> impl Future<Output = Result<Option<BrokerAuthSession>, CoreError>> + Send;
+
     /// Deletes a broker auth session by ID
     fn delete(&self, id: Uuid) -> impl Future<Output = Result<(), CoreError>> + Send;
 
@@ -84,6 +99,41 @@ pub trait IdentityProviderLinkRepository: Send + Sync {
     ) -> impl Future<Output = Result<u64, CoreError>> + Send;
 }
 
+/// Repository trait for brokered logins waiting on the user
+pub trait PendingBrokerLoginRepository: Send + Sync {
+    fn create(
+        &self,
+        request: CreatePendingBrokerLoginRequest,
+    ) -> impl Future<Output = Result<PendingBrokerLogin, CoreError>> + Send;
+
+    fn get_by_token(
+        &self,
+        token: &str,
+    ) -> impl Future<Output = Result<Option<PendingBrokerLogin>, CoreError>> + Send;
+
+    fn update(
+        &self,
+        id: Uuid,
+        request: UpdatePendingBrokerLoginRequest,
+    ) -> impl Future<Output = Result<PendingBrokerLogin, CoreError>> + Send;
+
+    /// Stores the hash of a freshly emailed confirmation code
+    fn set_confirmation_code(
+        &self,
+        id: Uuid,
+        code_hash: String,
+        expires_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Counts a wrong password or code and returns the new total
+    fn record_failed_attempt(
+        &self,
+        id: Uuid,
+    ) -> impl Future<Output = Result<i32, CoreError>> + Send;
+
+    fn delete(&self, id: Uuid) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
 /// Trait for OAuth HTTP client operations (external IdP communication)
 pub trait OAuthClient: Send + Sync {
     /// Exchange an authorization code for tokens at the IdP
@@ -92,8 +142,7 @@ pub trait OAuthClient: Send + Sync {
         token_url: &str,
         code: &str,
         redirect_uri: &str,
-        client_id: &str,
-        client_secret: &str,
+        auth: &TokenEndpointAuth,
         code_verifier: Option<&str>,
     ) -> impl Future<Output = Result<OAuthTokenResponse, CoreError>> + Send;
 
@@ -108,6 +157,12 @@ pub trait OAuthClient: Send + Sync {
         &self,
         jwks_url: &str,
     ) -> impl Future<Output = Result<serde_json::Value, CoreError>> + Send;
+
+    /// Fetch an OpenID Connect discovery document
+    fn fetch_discovery_document(
+        &self,
+        discovery_url: &str,
+    ) -> impl Future<Output = Result<OidcDiscoveryDocument, CoreError>> + Send;
 }
 
 /// Service trait for broker authentication business logic
@@ -118,11 +173,55 @@ pub trait BrokerService: Send + Sync {
         input: BrokerLoginInput,
     ) -> impl Future<Output = Result<BrokerLoginOutput, CoreError>> + Send;
 
-    /// Handles the callback from the IdP
+    /// Handles the callback from the IdP; the login may stop for the first-login or
+    /// post-login flow
     fn handle_callback(
         &self,
         input: BrokerCallbackInput,
-    ) -> impl Future<Output = Result<BrokerCallbackOutput, CoreError>> + Send;
+    ) -> impl Future<Output = Result<BrokerLoginOutcome, CoreError>> + Send;
+
+    /// Handles a response posted by a SAML identity provider; the login may stop for the
+    /// first-login or post-login flow
+    fn handle_saml_response(
+        &self,
+        input: BrokerSamlResponseInput,
+    ) -> impl Future<Output = Result<BrokerLoginOutcome, CoreError>> + Send;
+
+    /// The service provider metadata a SAML identity provider is configured from
+    fn get_saml_metadata(
+        &self,
+        input: BrokerSamlMetadataInput,
+    ) -> impl Future<Output = Result<String, CoreError>> + Send;
+
+    /// Describes the step a pending brokered login waits on
+    fn get_pending_login(
+        &self,
+        input: PendingBrokerLoginInput,
+    ) -> impl Future<Output = Result<PendingBrokerLoginView, CoreError>> + Send;
+
+    /// Submits the reviewed profile of a first login
+    fn review_brokered_profile(
+        &self,
+        input: ReviewBrokeredProfileInput,
+    ) -> impl Future<Output = Result<BrokerLoginOutcome, CoreError>> + Send;
+
+    /// Emails a code to the existing account a first login would be linked to
+    fn send_link_confirmation_code(
+        &self,
+        input: PendingBrokerLoginInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Links the existing account once its password or emailed code is confirmed
+    fn confirm_link(
+        &self,
+        input: ConfirmBrokerLinkInput,
+    ) -> impl Future<Output = Result<BrokerLoginOutcome, CoreError>> + Send;
+
+    /// Completes a login held by the post-login flow's one-time password check
+    fn verify_login_otp(
+        &self,
+        input: VerifyBrokerLoginOtpInput,
+    ) -> impl Future<Output = Result<BrokerLoginOutcome, CoreError>> + Send;
 
     /// Extracts user info from OAuth tokens
     fn extract_user_info(
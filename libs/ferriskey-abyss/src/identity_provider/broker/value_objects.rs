@@ -1,6 +1,8 @@
 use crate::identity_provider::IdentityProviderConfig;
+use crate::identity_provider::broker::first_login::ProfileField;
 use ferriskey_domain::common::app_errors::CoreError;
 use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value as JsonValue;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -10,9 +12,14 @@ pub struct OAuthProviderConfig {
     /// OAuth client ID
     pub client_id: String,
 
-    /// OAuth client secret
+    /// OAuth client secret; unused with `private_key_jwt`
+    #[serde(default)]
     pub client_secret: String,
 
+    /// How the broker authenticates to the token endpoint
+    #[serde(default)]
+    pub client_auth_method: ClientAuthMethod,
+
     /// Authorization endpoint URL
     pub authorization_url: String,
 
@@ -37,6 +44,55 @@ pub struct OAuthProviderConfig {
 
     /// Expected issuer for ID token validation
     pub issuer: Option<String>,
+
+    /// OpenID Connect discovery document the endpoints above are refreshed from
+    #[serde(default)]
+    pub discovery_url: Option<String>,
+
+    /// Profile fields a new account needs; missing ones are asked for on first login
+    #[serde(default)]
+    pub required_profile_fields: Vec<ProfileField>,
+}
+
+/// Client authentication toward the token endpoint of the upstream identity provider.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientAuthMethod {
+    /// `client_id` and `client_secret` in the request body.
+    #[default]
+    ClientSecretPost,
+    /// `client_id` and `client_secret` in an HTTP Basic `Authorization` header.
+    ClientSecretBasic,
+    /// A JWT assertion signed with the realm's signing key, which the upstream provider
+    /// verifies against the realm's JWKS.
+    PrivateKeyJwt,
+}
+
+impl ClientAuthMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ClientSecretPost => "client_secret_post",
+            Self::ClientSecretBasic => "client_secret_basic",
+            Self::PrivateKeyJwt => "private_key_jwt",
+        }
+    }
+}
+
+/// Credentials presented to the token endpoint of the upstream identity provider.
+#[derive(Debug, Clone)]
+pub enum TokenEndpointAuth {
+    ClientSecretPost {
+        client_id: String,
+        client_secret: String,
+    },
+    ClientSecretBasic {
+        client_id: String,
+        client_secret: String,
+    },
+    PrivateKeyJwt {
+        client_id: String,
+        client_assertion: String,
+    },
 }
 
 fn deserialize_scopes<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
@@ -86,18 +142,29 @@ impl TryFrom<IdentityProviderConfig> for OAuthProviderConfig {
         let client_id = config.client_id.ok_or_else(|| {
             CoreError::InvalidProviderConfiguration("Missing client_id".to_string())
         })?;
-        let client_secret = config.client_secret.ok_or_else(|| {
-            CoreError::InvalidProviderConfiguration("Missing client_secret".to_string())
-        })?;
+        let uses_private_key_jwt = extra.get("client_auth_method")
+            == Some(&serde_json::Value::String(
+                ClientAuthMethod::PrivateKeyJwt.as_str().to_string(),
+            ));
 
         extra.insert(
             "client_id".to_string(),
             serde_json::Value::String(client_id),
         );
-        extra.insert(
-            "client_secret".to_string(),
-            serde_json::Value::String(client_secret.expose().clone()),
-        );
+        match config.client_secret {
+            Some(client_secret) => {
+                extra.insert(
+                    "client_secret".to_string(),
+                    serde_json::Value::String(client_secret.expose().clone()),
+                );
+            }
+            None if uses_private_key_jwt => {}
+            None => {
+                return Err(CoreError::InvalidProviderConfiguration(
+                    "Missing client_secret".to_string(),
+                ));
+            }
+        }
 
         OAuthProviderConfig::try_from(serde_json::Value::Object(extra))
     }
@@ -176,6 +243,30 @@ pub struct BrokerCallbackInput {
     pub base_url: String,
 }
 
+/// Input for a response posted by a SAML identity provider
+#[derive(Debug, Clone)]
+pub struct BrokerSamlResponseInput {
+    /// Realm name
+    pub realm_name: String,
+
+    /// Identity provider alias
+    pub alias: String,
+
+    /// Base64 `SAMLResponse` form field
+    pub saml_response: String,
+
+    /// Base URL of the API server (e.g., "https://auth.example.com")
+    pub base_url: String,
+}
+
+/// Input for the service provider metadata of a SAML identity provider
+#[derive(Debug, Clone)]
+pub struct BrokerSamlMetadataInput {
+    pub realm_name: String,
+    pub alias: String,
+    pub base_url: String,
+}
+
 /// Output from broker callback handling
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct BrokerCallbackOutput {
@@ -221,6 +312,10 @@ pub struct BrokeredUserInfo {
 
     /// Profile picture URL
     pub picture: Option<String>,
+
+    /// Every claim the provider returned, for identity provider mappers
+    #[serde(default)]
+    pub claims: JsonValue,
 }
 
 impl BrokeredUserInfo {
@@ -311,6 +406,26 @@ mod tests {
         assert_eq!(config.use_pkce, Some(true));
     }
 
+    #[test]
+    fn test_private_key_jwt_config_needs_no_client_secret() {
+        let config = |client_auth_method: &str| IdentityProviderConfig {
+            client_id: Some("ferriskey".to_string()),
+            client_secret: None,
+            extra: json!({
+                "authorization_url": "https://idp.example.com/authorize",
+                "token_url": "https://idp.example.com/token",
+                "scopes": "openid",
+                "client_auth_method": client_auth_method,
+            }),
+        };
+
+        let parsed = OAuthProviderConfig::try_from(config("private_key_jwt")).unwrap();
+        assert_eq!(parsed.client_auth_method, ClientAuthMethod::PrivateKeyJwt);
+        assert!(parsed.client_secret.is_empty());
+
+        assert!(OAuthProviderConfig::try_from(config("client_secret_basic")).is_err());
+    }
+
     #[test]
     fn test_brokered_user_info_get_username() {
         let mut info = BrokeredUserInfo {
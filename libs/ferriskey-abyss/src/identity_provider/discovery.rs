@@ -0,0 +1,264 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use utoipa::ToSchema;
+
+use ferriskey_domain::common::app_errors::CoreError;
+
+use crate::identity_provider::broker::value_objects::ClientAuthMethod;
+
+const WELL_KNOWN_PATH: &str = "/.well-known/openid-configuration";
+
+/// The subset of an OpenID Connect discovery document the broker relies on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct OidcDiscoveryDocument {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    #[serde(default)]
+    pub userinfo_endpoint: Option<String>,
+    pub jwks_uri: String,
+    #[serde(default)]
+    pub end_session_endpoint: Option<String>,
+    #[serde(default)]
+    pub scopes_supported: Vec<String>,
+    /// Absent means `client_secret_basic` only, as the specification prescribes.
+    #[serde(default)]
+    pub token_endpoint_auth_methods_supported: Option<Vec<String>>,
+    #[serde(default)]
+    pub id_token_signing_alg_values_supported: Vec<String>,
+}
+
+/// Discovery URL for what an administrator pasted: either the document itself or the issuer.
+pub fn discovery_document_url(input: &str) -> String {
+    let trimmed = input.trim().trim_end_matches('/');
+
+    if trimmed.ends_with(WELL_KNOWN_PATH) {
+        trimmed.to_string()
+    } else {
+        format!("{trimmed}{WELL_KNOWN_PATH}")
+    }
+}
+
+impl OidcDiscoveryDocument {
+    /// The issuer must be the URL the document was published under, minus the well-known path.
+    pub fn validate_issuer(&self, discovery_url: &str) -> Result<(), CoreError> {
+        let expected = discovery_url
+            .strip_suffix(WELL_KNOWN_PATH)
+            .unwrap_or(discovery_url);
+
+        if self.issuer.trim_end_matches('/') != expected.trim_end_matches('/') {
+            return Err(CoreError::InvalidProviderConfiguration(format!(
+                "discovery document issuer '{}' does not match '{}'",
+                self.issuer, expected
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn supports_auth_method(&self, method: ClientAuthMethod) -> bool {
+        match &self.token_endpoint_auth_methods_supported {
+            Some(methods) => methods.iter().any(|m| m == method.as_str()),
+            None => method == ClientAuthMethod::ClientSecretBasic,
+        }
+    }
+
+    /// Overwrite the endpoints of a provider configuration with the discovered ones; client
+    /// credentials, scopes and every other setting are kept.
+    pub fn apply_to(
+        &self,
+        config: &mut JsonValue,
+        discovery_url: &str,
+        refreshed_at: DateTime<Utc>,
+    ) -> Result<(), CoreError> {
+        if config.is_null() {
+            *config = JsonValue::Object(Default::default());
+        }
+        let settings = config.as_object_mut().ok_or_else(|| {
+            CoreError::InvalidProviderConfiguration(
+                "Provider configuration must be a JSON object".to_string(),
+            )
+        })?;
+
+        let mut set = |key: &str, value: Option<&String>| match value {
+            Some(value) => {
+                settings.insert(key.to_string(), JsonValue::String(value.clone()));
+            }
+            None => {
+                settings.remove(key);
+            }
+        };
+        set("discovery_url", Some(&discovery_url.to_string()));
+        set("issuer", Some(&self.issuer));
+        set("authorization_url", Some(&self.authorization_endpoint));
+        set("token_url", Some(&self.token_endpoint));
+        set("userinfo_url", self.userinfo_endpoint.as_ref());
+        set("jwks_url", Some(&self.jwks_uri));
+        set("metadata_refreshed_at", Some(&refreshed_at.to_rfc3339()));
+
+        Ok(())
+    }
+}
+
+/// Endpoints and capabilities read from a discovery document, before any provider exists.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct DiscoveredIdentityProviderConfiguration {
+    pub discovery_url: String,
+    pub issuer: String,
+    pub authorization_url: String,
+    pub token_url: String,
+    pub userinfo_url: Option<String>,
+    pub jwks_url: String,
+    pub scopes_supported: Vec<String>,
+    pub client_auth_methods_supported: Vec<ClientAuthMethod>,
+}
+
+impl DiscoveredIdentityProviderConfiguration {
+    pub fn new(discovery_url: String, document: OidcDiscoveryDocument) -> Self {
+        let client_auth_methods_supported = [
+            ClientAuthMethod::ClientSecretPost,
+            ClientAuthMethod::ClientSecretBasic,
+            ClientAuthMethod::PrivateKeyJwt,
+        ]
+        .into_iter()
+        .filter(|method| document.supports_auth_method(*method))
+        .collect();
+
+        Self {
+            discovery_url,
+            issuer: document.issuer,
+            authorization_url: document.authorization_endpoint,
+            token_url: document.token_endpoint,
+            userinfo_url: document.userinfo_endpoint,
+            jwks_url: document.jwks_uri,
+            scopes_supported: document.scopes_supported,
+            client_auth_methods_supported,
+        }
+    }
+}
+
+/// Outcome of one check run against an identity provider's configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ConfigurationCheck {
+    pub name: String,
+    pub passed: bool,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct IdentityProviderValidationReport {
+    pub valid: bool,
+    pub checks: Vec<ConfigurationCheck>,
+}
+
+impl IdentityProviderValidationReport {
+    pub fn new(checks: Vec<ConfigurationCheck>) -> Self {
+        Self {
+            valid: checks.iter().all(|check| check.passed),
+            checks,
+        }
+    }
+}
+
+impl ConfigurationCheck {
+    pub fn from_result(name: &str, result: Result<String, CoreError>) -> Self {
+        let (passed, message) = match result {
+            Ok(message) => (true, message),
+            Err(error) => (false, error.to_string()),
+        };
+
+        Self {
+            name: name.to_string(),
+            passed,
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn document() -> OidcDiscoveryDocument {
+        serde_json::from_value(json!({
+            "issuer": "https://login.example.com/tenant",
+            "authorization_endpoint": "https://login.example.com/tenant/authorize",
+            "token_endpoint": "https://login.example.com/tenant/token",
+            "jwks_uri": "https://login.example.com/tenant/keys",
+            "token_endpoint_auth_methods_supported": ["client_secret_post", "private_key_jwt"],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_discovery_document_url() {
+        assert_eq!(
+            discovery_document_url("https://login.example.com/tenant/"),
+            "https://login.example.com/tenant/.well-known/openid-configuration"
+        );
+        assert_eq!(
+            discovery_document_url(
+                "https://login.example.com/tenant/.well-known/openid-configuration"
+            ),
+            "https://login.example.com/tenant/.well-known/openid-configuration"
+        );
+    }
+
+    #[test]
+    fn test_validate_issuer() {
+        let document = document();
+
+        assert!(
+            document
+                .validate_issuer(
+                    "https://login.example.com/tenant/.well-known/openid-configuration"
+                )
+                .is_ok()
+        );
+        assert!(
+            document
+                .validate_issuer("https://evil.example.com/tenant/.well-known/openid-configuration")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_apply_to_overwrites_endpoints_and_keeps_credentials() {
+        let mut config = json!({
+            "client_id": "ferriskey",
+            "scopes": ["openid", "email"],
+            "userinfo_url": "https://stale.example.com/userinfo",
+            "token_url": "https://stale.example.com/token",
+        });
+
+        document()
+            .apply_to(
+                &mut config,
+                "https://login.example.com/tenant/.well-known/openid-configuration",
+                Utc::now(),
+            )
+            .unwrap();
+
+        assert_eq!(config["client_id"], "ferriskey");
+        assert_eq!(config["scopes"], json!(["openid", "email"]));
+        assert_eq!(
+            config["token_url"],
+            "https://login.example.com/tenant/token"
+        );
+        assert_eq!(config["issuer"], "https://login.example.com/tenant");
+        assert!(config.get("userinfo_url").is_none());
+    }
+
+    #[test]
+    fn test_supported_auth_methods_default_to_basic() {
+        let mut document = document();
+        assert!(document.supports_auth_method(ClientAuthMethod::PrivateKeyJwt));
+        assert!(!document.supports_auth_method(ClientAuthMethod::ClientSecretBasic));
+
+        document.token_endpoint_auth_methods_supported = None;
+        assert!(document.supports_auth_method(ClientAuthMethod::ClientSecretBasic));
+        assert!(!document.supports_auth_method(ClientAuthMethod::ClientSecretPost));
+    }
+}
@@ -105,6 +105,28 @@ pub struct IdentityProviderConfig {
     pub extra: JsonValue,
 }
 
+impl IdentityProviderConfig {
+    /// The configuration as it is persisted, with the client secret in clear.
+    pub fn into_stored(self) -> JsonValue {
+        let mut stored = match self.extra {
+            JsonValue::Object(map) => map,
+            _ => serde_json::Map::new(),
+        };
+
+        if let Some(client_id) = self.client_id {
+            stored.insert("client_id".to_string(), JsonValue::String(client_id));
+        }
+        if let Some(client_secret) = self.client_secret {
+            stored.insert(
+                "client_secret".to_string(),
+                JsonValue::String(client_secret.expose().clone()),
+            );
+        }
+
+        JsonValue::Object(stored)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Ord, PartialOrd, ToSchema)]
 pub struct IdentityProviderPresentation {
     pub id: String,
@@ -234,6 +256,13 @@ pub struct DeleteIdentityProviderInput {
     pub alias: String,
 }
 
+/// Input for reading the discovery document of a prospective identity provider
+pub struct DiscoverIdentityProviderInput {
+    pub realm_name: String,
+    /// The discovery document URL, or the issuer it is published under
+    pub discovery_url: String,
+}
+
 pub struct ListIdentityProviderLinksInput {
     pub realm_name: String,
     pub user_id: Uuid,
@@ -0,0 +1,408 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::RealmId;
+
+use crate::identity_provider::IdentityProviderId;
+
+/// User fields a claim-to-attribute mapper writes directly; any other attribute name is stored
+/// as a user attribute.
+pub const PROFILE_ATTRIBUTES: &[&str] = &["firstname", "lastname", "email"];
+
+/// When a mapper runs during a brokered login.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MapperSyncMode {
+    /// Only when the login creates the account.
+    #[default]
+    Import,
+    /// On every login. Role and organization mappers also revoke what the claims no longer
+    /// grant.
+    Force,
+}
+
+/// What a mapper does with the claims of the upstream identity provider.
+///
+/// Claims are addressed by name; nested claims use a dotted path (`realm_access.roles`), with
+/// `\.` for a dot inside a name. A claim whose full name contains dots is matched as is first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IdentityProviderMapperConfig {
+    /// Copy a claim into `firstname`, `lastname`, `email` or a user attribute.
+    ClaimToAttribute { claim: String, attribute: String },
+    /// Grant a realm or client role while `claim` holds `claim_value`.
+    ClaimToRole {
+        claim: String,
+        claim_value: String,
+        role_id: Uuid,
+    },
+    /// Make the user a member of an organization, and of one of its groups when `group_id`
+    /// is set, while `claim` holds `claim_value`.
+    ClaimToOrganization {
+        claim: String,
+        claim_value: String,
+        organization_id: Uuid,
+        #[serde(default)]
+        group_id: Option<Uuid>,
+    },
+    /// Grant a role to every user of the provider.
+    HardcodedRole { role_id: Uuid },
+    /// Username of the accounts the provider creates, e.g.
+    /// `${ALIAS}.${CLAIM.preferred_username | lowercase}`. Supported transforms are
+    /// `lowercase`, `uppercase` and `localpart`.
+    UsernameTemplate { template: String },
+}
+
+/// A mapper attached to an identity provider.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct IdentityProviderMapper {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub identity_provider_id: IdentityProviderId,
+    pub name: String,
+    pub sync_mode: MapperSyncMode,
+    pub config: IdentityProviderMapperConfig,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl IdentityProviderMapper {
+    /// Whether the mapper runs for a login that did (`is_new_user`) or did not create the
+    /// account.
+    pub fn applies(&self, is_new_user: bool) -> bool {
+        is_new_user || self.sync_mode == MapperSyncMode::Force
+    }
+}
+
+impl IdentityProviderMapperConfig {
+    pub fn validate(&self, sync_mode: MapperSyncMode) -> Result<(), CoreError> {
+        let invalid = |msg: &str| Err(CoreError::InvalidProviderConfiguration(msg.to_string()));
+
+        match self {
+            Self::ClaimToAttribute { claim, attribute } => {
+                if claim.trim().is_empty() {
+                    return invalid("claim is required");
+                }
+                if attribute.trim().is_empty() {
+                    return invalid("attribute is required");
+                }
+            }
+            Self::ClaimToRole {
+                claim, claim_value, ..
+            }
+            | Self::ClaimToOrganization {
+                claim, claim_value, ..
+            } => {
+                if claim.trim().is_empty() {
+                    return invalid("claim is required");
+                }
+                if claim_value.is_empty() {
+                    return invalid("claim_value is required");
+                }
+            }
+            Self::HardcodedRole { .. } => {}
+            Self::UsernameTemplate { template } => {
+                if sync_mode == MapperSyncMode::Force {
+                    return invalid("a username template only applies when the account is created");
+                }
+                let segments = parse_template(template)?;
+                if segments.is_empty() {
+                    return invalid("template is required");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Input for listing the mappers of an identity provider
+pub struct ListIdentityProviderMappersInput {
+    pub realm_name: String,
+    pub alias: String,
+}
+
+/// Input for creating an identity provider mapper
+pub struct CreateIdentityProviderMapperInput {
+    pub realm_name: String,
+    pub alias: String,
+    pub name: String,
+    pub sync_mode: MapperSyncMode,
+    pub config: IdentityProviderMapperConfig,
+}
+
+/// Input for replacing an identity provider mapper
+pub struct UpdateIdentityProviderMapperInput {
+    pub realm_name: String,
+    pub alias: String,
+    pub mapper_id: Uuid,
+    pub name: String,
+    pub sync_mode: MapperSyncMode,
+    pub config: IdentityProviderMapperConfig,
+}
+
+/// Input for deleting an identity provider mapper
+pub struct DeleteIdentityProviderMapperInput {
+    pub realm_name: String,
+    pub alias: String,
+    pub mapper_id: Uuid,
+}
+
+/// The values of a claim as strings: a scalar gives one value, an array one per scalar
+/// element. Objects and missing claims give none.
+pub fn claim_values(claims: &JsonValue, path: &str) -> Vec<String> {
+    let claim = match claims.get(path) {
+        Some(value) => Some(value),
+        None => split_path(path)
+            .iter()
+            .try_fold(claims, |value, segment| value.get(segment.as_str())),
+    };
+
+    match claim {
+        Some(JsonValue::Array(items)) => items.iter().filter_map(scalar).collect(),
+        Some(value) => scalar(value).into_iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Whether the claim at `path` is, or contains, `expected`.
+pub fn claim_has_value(claims: &JsonValue, path: &str, expected: &str) -> bool {
+    claim_values(claims, path)
+        .iter()
+        .any(|value| value == expected)
+}
+
+fn scalar(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::String(s) => Some(s.clone()),
+        JsonValue::Number(n) => Some(n.to_string()),
+        JsonValue::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn split_path(path: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut chars = path.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'.') => {
+                current.push('.');
+                chars.next();
+            }
+            '.' => segments.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    segments.push(current);
+
+    segments
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplateSegment {
+    Literal(String),
+    Alias(Vec<Transform>),
+    Claim(String, Vec<Transform>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Transform {
+    Lowercase,
+    Uppercase,
+    /// The part of an email address before the `@`.
+    Localpart,
+}
+
+impl Transform {
+    fn apply(self, value: String) -> String {
+        match self {
+            Self::Lowercase => value.to_lowercase(),
+            Self::Uppercase => value.to_uppercase(),
+            Self::Localpart => match value.split_once('@') {
+                Some((local, _)) => local.to_string(),
+                None => value,
+            },
+        }
+    }
+}
+
+fn parse_template(template: &str) -> Result<Vec<TemplateSegment>, CoreError> {
+    let invalid = |msg: String| CoreError::InvalidProviderConfiguration(msg);
+
+    let mut segments = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("${") {
+        if start > 0 {
+            segments.push(TemplateSegment::Literal(rest[..start].to_string()));
+        }
+        let after = &rest[start + 2..];
+        let end = after
+            .find('}')
+            .ok_or_else(|| invalid("unterminated ${ in username template".to_string()))?;
+
+        let mut parts = after[..end].split('|').map(str::trim);
+        let name = parts.next().unwrap_or_default();
+        let transforms = parts
+            .map(|transform| match transform {
+                "lowercase" => Ok(Transform::Lowercase),
+                "uppercase" => Ok(Transform::Uppercase),
+                "localpart" => Ok(Transform::Localpart),
+                other => Err(invalid(format!("unknown template transform '{other}'"))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let segment = if name == "ALIAS" {
+            TemplateSegment::Alias(transforms)
+        } else if let Some(claim) = name.strip_prefix("CLAIM.").filter(|c| !c.is_empty()) {
+            TemplateSegment::Claim(claim.to_string(), transforms)
+        } else {
+            return Err(invalid(format!(
+                "unknown template variable '{name}', expected ALIAS or CLAIM.<name>"
+            )));
+        };
+        segments.push(segment);
+
+        rest = &after[end + 1..];
+    }
+
+    if !rest.is_empty() {
+        segments.push(TemplateSegment::Literal(rest.to_string()));
+    }
+
+    Ok(segments)
+}
+
+/// Render a username template; a claim that is missing renders as nothing, a multi-valued
+/// claim as its first value.
+pub fn render_username_template(
+    template: &str,
+    alias: &str,
+    claims: &JsonValue,
+) -> Result<String, CoreError> {
+    let transformed = |value: String, transforms: &[Transform]| {
+        transforms
+            .iter()
+            .fold(value, |value, transform| transform.apply(value))
+    };
+
+    Ok(parse_template(template)?
+        .into_iter()
+        .map(|segment| match segment {
+            TemplateSegment::Literal(text) => text,
+            TemplateSegment::Alias(transforms) => transformed(alias.to_string(), &transforms),
+            TemplateSegment::Claim(claim, transforms) => transformed(
+                claim_values(claims, &claim)
+                    .into_iter()
+                    .next()
+                    .unwrap_or_default(),
+                &transforms,
+            ),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_claim_values_reads_scalars_arrays_and_nested_claims() {
+        let claims = json!({
+            "groups": ["a1b2", "c3d4"],
+            "realm_access": { "roles": ["admin"] },
+            "https://example.com/tier": "gold",
+            "tenant.id": 42,
+        });
+
+        assert_eq!(claim_values(&claims, "groups"), vec!["a1b2", "c3d4"]);
+        assert_eq!(claim_values(&claims, "realm_access.roles"), vec!["admin"]);
+        assert_eq!(
+            claim_values(&claims, "https://example.com/tier"),
+            vec!["gold"]
+        );
+        assert_eq!(claim_values(&claims, "tenant\\.id"), vec!["42"]);
+        assert!(claim_values(&claims, "realm_access").is_empty());
+        assert!(claim_values(&claims, "missing.claim").is_empty());
+    }
+
+    #[test]
+    fn test_claim_has_value_matches_array_members() {
+        let claims = json!({ "groups": ["a1b2", "c3d4"], "email_verified": true });
+
+        assert!(claim_has_value(&claims, "groups", "c3d4"));
+        assert!(!claim_has_value(&claims, "groups", "c3"));
+        assert!(claim_has_value(&claims, "email_verified", "true"));
+    }
+
+    #[test]
+    fn test_render_username_template() {
+        let claims = json!({ "email": "Jane.Doe@Example.com", "sub": "1234" });
+
+        let username = render_username_template(
+            "${ALIAS | uppercase}.${CLAIM.email | localpart | lowercase}-${CLAIM.missing}",
+            "azure",
+            &claims,
+        )
+        .unwrap();
+
+        assert_eq!(username, "AZURE.jane.doe-");
+    }
+
+    #[test]
+    fn test_username_template_validation() {
+        let template = |template: &str| IdentityProviderMapperConfig::UsernameTemplate {
+            template: template.to_string(),
+        };
+
+        assert!(
+            template("${ALIAS}_${CLAIM.sub}")
+                .validate(MapperSyncMode::Import)
+                .is_ok()
+        );
+        assert!(
+            template("${ALIAS}_${CLAIM.sub}")
+                .validate(MapperSyncMode::Force)
+                .is_err()
+        );
+        assert!(
+            template("${USER}")
+                .validate(MapperSyncMode::Import)
+                .is_err()
+        );
+        assert!(
+            template("${CLAIM.sub | reverse}")
+                .validate(MapperSyncMode::Import)
+                .is_err()
+        );
+        assert!(
+            template("${ALIAS")
+                .validate(MapperSyncMode::Import)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_mapper_config_serialization_is_tagged() {
+        let config: IdentityProviderMapperConfig = serde_json::from_value(json!({
+            "type": "claim_to_role",
+            "claim": "groups",
+            "claim_value": "a1b2",
+            "role_id": "0191d5a2-8c59-7bd4-9d2b-3f8ad0c1b001",
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            config,
+            IdentityProviderMapperConfig::ClaimToRole { ref claim, .. } if claim == "groups"
+        ));
+    }
+}
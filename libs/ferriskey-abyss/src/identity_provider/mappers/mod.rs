@@ -0,0 +1,12 @@
+pub mod entities;
+pub mod ports;
+pub mod value_objects;
+
+pub use entities::{
+    CreateIdentityProviderMapperInput, DeleteIdentityProviderMapperInput, IdentityProviderMapper,
+    IdentityProviderMapperConfig, ListIdentityProviderMappersInput, MapperSyncMode,
+    PROFILE_ATTRIBUTES, UpdateIdentityProviderMapperInput, claim_has_value, claim_values,
+    render_username_template,
+};
+pub use ports::{IdentityProviderMapperRepository, IdentityProviderMapperService};
+pub use value_objects::{CreateIdentityProviderMapperRequest, UpdateIdentityProviderMapperRequest};
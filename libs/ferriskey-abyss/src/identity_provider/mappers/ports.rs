@@ -0,0 +1,75 @@
+use std::future::Future;
+
+use uuid::Uuid;
+
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::common::app_errors::CoreError;
+
+use crate::identity_provider::IdentityProviderId;
+
+use super::entities::{
+    CreateIdentityProviderMapperInput, DeleteIdentityProviderMapperInput, IdentityProviderMapper,
+    ListIdentityProviderMappersInput, UpdateIdentityProviderMapperInput,
+};
+use super::value_objects::{
+    CreateIdentityProviderMapperRequest, UpdateIdentityProviderMapperRequest,
+};
+
+/// Repository trait for identity provider mapper persistence
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait IdentityProviderMapperRepository: Send + Sync {
+    fn create_mapper(
+        &self,
+        request: CreateIdentityProviderMapperRequest,
+    ) -> impl Future<Output = Result<IdentityProviderMapper, CoreError>> + Send;
+
+    /// Lists the mappers of an identity provider, oldest first
+    fn list_mappers_by_provider(
+        &self,
+        identity_provider_id: IdentityProviderId,
+    ) -> impl Future<Output = Result<Vec<IdentityProviderMapper>, CoreError>> + Send;
+
+    fn get_mapper_by_id(
+        &self,
+        id: Uuid,
+    ) -> impl Future<Output = Result<Option<IdentityProviderMapper>, CoreError>> + Send;
+
+    fn update_mapper(
+        &self,
+        request: UpdateIdentityProviderMapperRequest,
+    ) -> impl Future<Output = Result<IdentityProviderMapper, CoreError>> + Send;
+
+    fn delete_mapper(&self, id: Uuid) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Service trait for managing the mappers of an identity provider
+///
+/// Access follows the identity provider policy: viewing the provider lists its mappers,
+/// updating it manages them.
+pub trait IdentityProviderMapperService: Send + Sync {
+    fn list_identity_provider_mappers(
+        &self,
+        identity: Identity,
+        input: ListIdentityProviderMappersInput,
+    ) -> impl Future<Output = Result<Vec<IdentityProviderMapper>, CoreError>> + Send;
+
+    /// Creates a mapper; the roles, organization and group it references must belong to the
+    /// provider's realm
+    fn create_identity_provider_mapper(
+        &self,
+        identity: Identity,
+        input: CreateIdentityProviderMapperInput,
+    ) -> impl Future<Output = Result<IdentityProviderMapper, CoreError>> + Send;
+
+    fn update_identity_provider_mapper(
+        &self,
+        identity: Identity,
+        input: UpdateIdentityProviderMapperInput,
+    ) -> impl Future<Output = Result<IdentityProviderMapper, CoreError>> + Send;
+
+    fn delete_identity_provider_mapper(
+        &self,
+        identity: Identity,
+        input: DeleteIdentityProviderMapperInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
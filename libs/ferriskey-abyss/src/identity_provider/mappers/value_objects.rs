@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use ferriskey_domain::realm::RealmId;
+
+use crate::identity_provider::IdentityProviderId;
+
+use super::entities::{IdentityProviderMapperConfig, MapperSyncMode};
+
+/// Request DTO for creating an identity provider mapper
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateIdentityProviderMapperRequest {
+    pub realm_id: RealmId,
+    pub identity_provider_id: IdentityProviderId,
+    pub name: String,
+    pub sync_mode: MapperSyncMode,
+    pub config: IdentityProviderMapperConfig,
+}
+
+/// Request DTO for replacing an identity provider mapper
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateIdentityProviderMapperRequest {
+    pub id: Uuid,
+    pub name: String,
+    pub sync_mode: MapperSyncMode,
+    pub config: IdentityProviderMapperConfig,
+}
@@ -1,14 +1,20 @@
 pub mod broker;
+pub mod discovery;
 pub mod entities;
+pub mod mappers;
 pub mod ports;
 pub mod value_objects;
 
+pub use discovery::{
+    ConfigurationCheck, DiscoveredIdentityProviderConfiguration, IdentityProviderValidationReport,
+    OidcDiscoveryDocument,
+};
 pub use entities::{
     CreateIdentityProviderInput, DeleteIdentityProviderInput, DeleteIdentityProviderLinkInput,
-    GetIdentityProviderInput, IdentityProvider, IdentityProviderConfig,
-    IdentityProviderCreationConfig, IdentityProviderId, IdentityProviderLinkView,
-    IdentityProviderPresentation, ListIdentityProviderLinksInput, ListIdentityProvidersInput,
-    UpdateIdentityProviderInput,
+    DiscoverIdentityProviderInput, GetIdentityProviderInput, IdentityProvider,
+    IdentityProviderConfig, IdentityProviderCreationConfig, IdentityProviderId,
+    IdentityProviderLinkView, IdentityProviderPresentation, ListIdentityProviderLinksInput,
+    ListIdentityProvidersInput, UpdateIdentityProviderInput,
 };
 pub use ports::{IdentityProviderPolicy, IdentityProviderRepository, IdentityProviderService};
 pub use value_objects::{CreateIdentityProviderRequest, UpdateIdentityProviderRequest};
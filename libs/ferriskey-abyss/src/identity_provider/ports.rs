@@ -6,10 +6,12 @@ use ferriskey_domain::auth::Identity;
 use ferriskey_domain::common::app_errors::CoreError;
 use ferriskey_domain::realm::{Realm, RealmId};
 
+use super::discovery::{DiscoveredIdentityProviderConfiguration, IdentityProviderValidationReport};
 use super::entities::{
     CreateIdentityProviderInput, DeleteIdentityProviderInput, DeleteIdentityProviderLinkInput,
-    GetIdentityProviderInput, IdentityProvider, IdentityProviderLinkView,
-    ListIdentityProviderLinksInput, ListIdentityProvidersInput, UpdateIdentityProviderInput,
+    DiscoverIdentityProviderInput, GetIdentityProviderInput, IdentityProvider,
+    IdentityProviderLinkView, ListIdentityProviderLinksInput, ListIdentityProvidersInput,
+    UpdateIdentityProviderInput,
 };
 use super::value_objects::{CreateIdentityProviderRequest, UpdateIdentityProviderRequest};
 
@@ -58,6 +60,11 @@ pub trait IdentityProviderRepository: Send + Sync {
         id: Uuid,
     ) -> impl Future<Output = Result<(), CoreError>> + Send;
 
+    /// Lists the identity providers of every realm configured from a discovery document
+    fn list_identity_providers_with_discovery(
+        &self,
+    ) -> impl Future<Output = Result<Vec<IdentityProvider>, CoreError>> + Send;
+
     /// Checks if an alias already exists in a realm
     fn exists_identity_provider_by_realm_and_alias(
         &self,
@@ -117,6 +124,24 @@ pub trait IdentityProviderService: Send + Sync {
         identity: Identity,
         input: DeleteIdentityProviderLinkInput,
     ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Reads an OpenID Connect discovery document so its endpoints can prefill a new provider
+    fn discover_identity_provider_configuration(
+        &self,
+        identity: Identity,
+        input: DiscoverIdentityProviderInput,
+    ) -> impl Future<Output = Result<DiscoveredIdentityProviderConfiguration, CoreError>> + Send;
+
+    /// Checks that a provider's configuration is complete and its endpoints answer
+    fn validate_identity_provider(
+        &self,
+        identity: Identity,
+        input: GetIdentityProviderInput,
+    ) -> impl Future<Output = Result<IdentityProviderValidationReport, CoreError>> + Send;
+
+    /// Re-reads the discovery document of every provider that has one; returns how many
+    /// providers were updated
+    fn refresh_discovery_metadata(&self) -> impl Future<Output = Result<u32, CoreError>> + Send;
 }
 
 /// Policy trait for Identity Provider authorization
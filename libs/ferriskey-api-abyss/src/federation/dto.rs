@@ -1,10 +1,10 @@
 use chrono::{DateTime, Utc};
 use ferriskey_core::domain::abyss::federation::{
-    entities::FederationProvider,
-    value_objects::{SyncResult, TestConnectionResult},
+    entities::{FederationProvider, FederationSyncRun, SyncKind, SyncStatus, SyncTrigger},
+    value_objects::{SyncError, SyncResult, TestConnectionResult},
 };
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateProviderRequest {
@@ -17,6 +17,12 @@ pub struct CreateProviderRequest {
     pub sync_mode: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sync_interval_minutes: Option<i32>,
+    /// Five-field cron expression (UTC) for full syncs; takes precedence over the interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_sync_cron: Option<String>,
+    /// Period of the changed-since sync (`modifyTimestamp` / `uSNChanged`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changed_sync_interval_minutes: Option<i32>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -37,6 +43,12 @@ pub struct UpdateProviderRequest {
     pub sync_mode: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sync_interval_minutes: Option<i32>,
+    /// Five-field cron expression (UTC) for full syncs; takes precedence over the interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_sync_cron: Option<String>,
+    /// Period of the changed-since sync (`modifyTimestamp` / `uSNChanged`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changed_sync_interval_minutes: Option<i32>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -51,6 +63,8 @@ pub struct ProviderResponse {
     pub sync_enabled: bool,
     pub sync_mode: String,
     pub sync_interval_minutes: Option<i32>,
+    pub full_sync_cron: Option<String>,
+    pub changed_sync_interval_minutes: Option<i32>,
     pub last_sync_at: Option<String>,
     pub last_sync_status: Option<String>,
     pub created_at: String,
@@ -99,6 +113,14 @@ impl From<FederationProvider> for ProviderResponse {
             .get("interval_minutes")
             .and_then(|v| v.as_i64())
             .map(|v| v as i32);
+        let full_sync_cron = sync
+            .get("full_sync_cron")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+        let changed_sync_interval_minutes = sync
+            .get("changed_sync_interval_minutes")
+            .and_then(|v| v.as_i64())
+            .map(|v| v as i32);
         let last_sync_at = sync
             .get("last_sync_at")
             .and_then(|v| v.as_str())
@@ -119,6 +141,8 @@ impl From<FederationProvider> for ProviderResponse {
             sync_enabled,
             sync_mode,
             sync_interval_minutes,
+            full_sync_cron,
+            changed_sync_interval_minutes,
             last_sync_at,
             last_sync_status,
             created_at: provider.created_at.to_rfc3339(),
@@ -157,6 +181,8 @@ pub struct SyncUsersResponse {
     pub updated: u32,
     pub disabled: u32,
     pub failed: u32,
+    pub groups_added: u32,
+    pub groups_removed: u32,
     pub started_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
 }
@@ -179,8 +205,80 @@ impl From<SyncResult> for SyncUsersResponse {
             updated: result.updated,
             disabled: result.disabled,
             failed: result.failed,
+            groups_added: result.groups_added,
+            groups_removed: result.groups_removed,
             started_at,
             completed_at,
         }
     }
 }
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListSyncRunsQuery {
+    /// Maximum number of runs to return (1-100, default 20).
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SyncRunErrorResponse {
+    pub username: Option<String>,
+    pub external_id: String,
+    pub error: String,
+}
+
+impl From<SyncError> for SyncRunErrorResponse {
+    fn from(error: SyncError) -> Self {
+        Self {
+            username: error.username,
+            external_id: error.external_id,
+            error: error.error,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SyncRunResponse {
+    pub id: String,
+    pub kind: SyncKind,
+    pub trigger: SyncTrigger,
+    pub mode: String,
+    pub status: SyncStatus,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub total_processed: u32,
+    pub created: u32,
+    pub updated: u32,
+    pub disabled: u32,
+    pub failed: u32,
+    pub groups_added: u32,
+    pub groups_removed: u32,
+    pub errors: Vec<SyncRunErrorResponse>,
+}
+
+impl From<FederationSyncRun> for SyncRunResponse {
+    fn from(run: FederationSyncRun) -> Self {
+        Self {
+            id: run.id.to_string(),
+            kind: run.kind,
+            trigger: run.trigger,
+            mode: run.mode.to_string(),
+            status: run.status,
+            started_at: run.started_at,
+            completed_at: run.completed_at,
+            total_processed: run.total_processed,
+            created: run.created,
+            updated: run.updated,
+            disabled: run.disabled,
+            failed: run.failed,
+            groups_added: run.groups_added,
+            groups_removed: run.groups_removed,
+            errors: run.errors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ListSyncRunsResponse {
+    pub data: Vec<SyncRunResponse>,
+}
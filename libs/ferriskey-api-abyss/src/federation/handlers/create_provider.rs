@@ -59,7 +59,9 @@ pub async fn create_provider(
     let sync_settings = serde_json::json!({
         "enabled": payload.sync_enabled,
         "mode": payload.sync_mode,
-        "interval_minutes": payload.sync_interval_minutes
+        "interval_minutes": payload.sync_interval_minutes,
+        "full_sync_cron": payload.full_sync_cron,
+        "changed_sync_interval_minutes": payload.changed_sync_interval_minutes
     });
 
     let core_request = CoreCreateProviderRequest {
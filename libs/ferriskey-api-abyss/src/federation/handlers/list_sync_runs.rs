@@ -0,0 +1,54 @@
+use axum::Extension;
+use axum::extract::{Path, Query, State};
+use ferriskey_core::domain::abyss::federation::ports::FederationService;
+use uuid::Uuid;
+
+use crate::federation::dto::{ListSyncRunsQuery, ListSyncRunsResponse};
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::authentication::value_objects::Identity;
+
+const DEFAULT_SYNC_RUNS_LIMIT: u64 = 20;
+
+#[utoipa::path(
+    get,
+    path = "/federation/providers/{id}/sync-runs",
+    summary = "List sync runs of a federation provider",
+    description = "Returns the most recent manual and scheduled sync runs of the provider, newest first, with their counts and errors.",
+    responses(
+        (status = 200, description = "Sync run history", body = ListSyncRunsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Realm or Provider not found", body = ApiErrorResponse),
+    ),
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("id" = String, Path, description = "Provider ID"),
+        ListSyncRunsQuery,
+    ),
+    tag = "federation"
+)]
+pub async fn list_sync_runs(
+    Path((realm_name, id)): Path<(String, Uuid)>,
+    Query(query): Query<ListSyncRunsQuery>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<ListSyncRunsResponse>, ApiError> {
+    let runs = state
+        .service
+        .list_federation_sync_runs(
+            identity,
+            realm_name,
+            id,
+            query.limit.unwrap_or(DEFAULT_SYNC_RUNS_LIMIT),
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(ListSyncRunsResponse {
+        data: runs.into_iter().map(Into::into).collect(),
+    }))
+}
@@ -2,6 +2,7 @@ pub mod create_provider;
 pub mod delete_provider;
 pub mod get_provider;
 pub mod list_providers;
+pub mod list_sync_runs;
 pub mod sync_users;
 pub mod test_connection;
 pub mod update_provider;
@@ -10,5 +11,6 @@ pub use create_provider::create_provider;
 pub use delete_provider::delete_provider;
 pub use get_provider::get_provider;
 pub use list_providers::list_providers;
+pub use list_sync_runs::list_sync_runs;
 pub use test_connection::test_connection;
 pub use update_provider::update_provider;
@@ -62,6 +62,8 @@ pub async fn update_provider(
     let sync_settings = if payload.sync_enabled.is_some()
         || payload.sync_mode.is_some()
         || payload.sync_interval_minutes.is_some()
+        || payload.full_sync_cron.is_some()
+        || payload.changed_sync_interval_minutes.is_some()
     {
         let enabled = payload.sync_enabled.unwrap_or(false);
         let mode_str = payload.sync_mode.clone().unwrap_or("LinkOnly".to_string()); // Clone because we consume payload
@@ -74,7 +76,9 @@ pub async fn update_provider(
         Some(serde_json::json!({
             "enabled": enabled,
             "mode": mode_str,
-            "interval_minutes": interval
+            "interval_minutes": interval,
+            "full_sync_cron": payload.full_sync_cron,
+            "changed_sync_interval_minutes": payload.changed_sync_interval_minutes
         }))
     } else {
         None
@@ -4,8 +4,8 @@ use axum::{
 };
 
 use crate::federation::handlers::{
-    create_provider, delete_provider, get_provider, list_providers, sync_users::sync_users,
-    test_connection, update_provider,
+    create_provider, delete_provider, get_provider, list_providers, list_sync_runs,
+    sync_users::sync_users, test_connection, update_provider,
 };
 use ferriskey_api_core::app_state::AppState;
 
@@ -32,4 +32,8 @@ pub fn federation_routes(state: AppState) -> Router<AppState> {
             &format!("{}/{{id}}/sync-users", root_path),
             post(sync_users),
         )
+        .route(
+            &format!("{}/{{id}}/sync-runs", root_path),
+            get(list_sync_runs),
+        )
 }
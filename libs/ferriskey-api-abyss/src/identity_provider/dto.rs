@@ -123,3 +123,11 @@ pub struct ListIdentityProvidersQuery {
     #[serde(default)]
     pub brief_representation: Option<bool>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct DiscoverIdentityProviderValidator {
+    /// The discovery document URL, or the issuer it is published under
+    #[validate(url(message = "discovery_url must be a valid URL"))]
+    #[serde(default)]
+    pub discovery_url: String,
+}
@@ -0,0 +1,53 @@
+use crate::identity_provider::dto::DiscoverIdentityProviderValidator;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::abyss::identity_provider::{
+    discovery::DiscoveredIdentityProviderConfiguration, entities::DiscoverIdentityProviderInput,
+    ports::IdentityProviderService,
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+
+#[utoipa::path(
+    post,
+    path = "/identity-providers/discovery",
+    summary = "Read an OpenID Connect discovery document",
+    description = "Fetches the `.well-known/openid-configuration` document of an upstream provider and returns its endpoints and supported client authentication methods. Nothing is stored; pass the returned `discovery_url` in a provider's `config` to keep its endpoints in sync with the document.",
+    responses(
+        (status = 200, body = DiscoveredIdentityProviderConfiguration, description = "Discovered provider configuration"),
+        (status = 400, description = "The document is unreachable, invalid, or its issuer does not match the URL"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Realm not found"),
+    ),
+    params(
+        ("realm_name" = String, Path, description = "The name of the realm"),
+    ),
+    tag = "identity_provider",
+    request_body = DiscoverIdentityProviderValidator,
+)]
+pub async fn discover_identity_provider(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<DiscoverIdentityProviderValidator>,
+) -> Result<Response<DiscoveredIdentityProviderConfiguration>, ApiError> {
+    let configuration = state
+        .service
+        .discover_identity_provider_configuration(
+            identity,
+            DiscoverIdentityProviderInput {
+                realm_name,
+                discovery_url: payload.discovery_url,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(configuration))
+}
@@ -1,5 +1,7 @@
 pub mod create_identity_provider;
 pub mod delete_identity_provider;
+pub mod discover_identity_provider;
 pub mod get_identity_provider;
 pub mod list_identity_providers;
 pub mod update_identity_provider;
+pub mod validate_identity_provider;
@@ -0,0 +1,41 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::{api_error::ApiError, response::Response};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::abyss::identity_provider::{
+    discovery::IdentityProviderValidationReport, entities::GetIdentityProviderInput,
+    ports::IdentityProviderService,
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+
+#[utoipa::path(
+    post,
+    path = "/identity-providers/{alias}/validate",
+    summary = "Validate an identity provider configuration",
+    description = "Checks a provider's stored configuration against the upstream provider: the configuration is complete, the discovery document is reachable and its issuer matches, the token endpoint accepts the configured client authentication method, and the key set publishes signing keys. Failed checks are reported, not returned as errors.",
+    responses(
+        (status = 200, body = IdentityProviderValidationReport, description = "Result of every check"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Identity provider or realm not found"),
+    ),
+    params(
+        ("realm_name" = String, Path, description = "The name of the realm"),
+        ("alias" = String, Path, description = "The unique alias of the identity provider"),
+    ),
+    tag = "identity_provider",
+)]
+pub async fn validate_identity_provider(
+    Path((realm_name, alias)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<IdentityProviderValidationReport>, ApiError> {
+    let report = state
+        .service
+        .validate_identity_provider(identity, GetIdentityProviderInput { realm_name, alias })
+        .await?;
+
+    Ok(Response::OK(report))
+}
@@ -6,8 +6,10 @@ use axum::{
 use crate::identity_provider::handlers::{
     create_identity_provider::create_identity_provider,
     delete_identity_provider::delete_identity_provider,
+    discover_identity_provider::discover_identity_provider,
     get_identity_provider::get_identity_provider, list_identity_providers::list_identity_providers,
     update_identity_provider::update_identity_provider,
+    validate_identity_provider::validate_identity_provider,
 };
 use ferriskey_api_core::app_state::AppState;
 
@@ -23,6 +25,10 @@ pub fn identity_provider_routes(state: AppState) -> Router<AppState> {
     Router::new()
         .route(&root_path, post(create_identity_provider))
         .route(&root_path, get(list_identity_providers))
+        .route(
+            &format!("{}/discovery", root_path),
+            post(discover_identity_provider),
+        )
         .route(&format!("{}/{{id}}", root_path), get(get_identity_provider))
         .route(
             &format!("{}/{{id}}", root_path),
@@ -32,4 +38,8 @@ pub fn identity_provider_routes(state: AppState) -> Router<AppState> {
             &format!("{}/{{id}}", root_path),
             delete(delete_identity_provider),
         )
+        .route(
+            &format!("{}/{{id}}/validate", root_path),
+            post(validate_identity_provider),
+        )
 }
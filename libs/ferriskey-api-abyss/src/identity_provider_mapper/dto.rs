@@ -0,0 +1,33 @@
+use ferriskey_core::domain::abyss::identity_provider::mappers::{
+    IdentityProviderMapper, IdentityProviderMapperConfig, MapperSyncMode,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct IdentityProviderMapperValidator {
+    #[validate(length(min = 1, max = 255, message = "name is required"))]
+    #[serde(default)]
+    pub name: String,
+
+    #[serde(default)]
+    pub sync_mode: MapperSyncMode,
+
+    pub config: IdentityProviderMapperConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct IdentityProviderMapperResponse {
+    pub data: IdentityProviderMapper,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct IdentityProviderMappersResponse {
+    pub data: Vec<IdentityProviderMapper>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DeleteIdentityProviderMapperResponse {
+    pub count: u32,
+}
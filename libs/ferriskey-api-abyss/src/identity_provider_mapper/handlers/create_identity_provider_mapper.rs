@@ -0,0 +1,60 @@
+use crate::identity_provider_mapper::dto::{
+    IdentityProviderMapperResponse, IdentityProviderMapperValidator,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::abyss::identity_provider::mappers::{
+    CreateIdentityProviderMapperInput, IdentityProviderMapperService,
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+
+#[utoipa::path(
+    post,
+    path = "/identity-providers/{alias}/mappers",
+    summary = "Create an identity provider mapper",
+    description = "Adds a mapper that turns the claims of the identity provider into user attributes, roles, organization or group memberships, or the username of new accounts. In `import` sync mode the mapper only runs when the login creates the account; in `force` mode it runs on every login and revokes roles and memberships the claims no longer grant.",
+    responses(
+        (status = 201, body = IdentityProviderMapperResponse, description = "Identity provider mapper created successfully"),
+        (status = 400, description = "Invalid mapper configuration, a role, organization or group outside the realm, or a name already used by another mapper of the identity provider"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Identity provider or realm not found"),
+    ),
+    params(
+        ("realm_name" = String, Path, description = "The name of the realm"),
+        ("alias" = String, Path, description = "The unique alias of the identity provider"),
+    ),
+    tag = "identity_provider",
+    request_body = IdentityProviderMapperValidator,
+)]
+pub async fn create_identity_provider_mapper(
+    Path((realm_name, alias)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<IdentityProviderMapperValidator>,
+) -> Result<Response<IdentityProviderMapperResponse>, ApiError> {
+    let mapper = state
+        .service
+        .create_identity_provider_mapper(
+            identity,
+            CreateIdentityProviderMapperInput {
+                realm_name,
+                alias,
+                name: payload.name,
+                sync_mode: payload.sync_mode,
+                config: payload.config,
+            },
+        )
+        .await?;
+
+    Ok(Response::Created(IdentityProviderMapperResponse {
+        data: mapper,
+    }))
+}
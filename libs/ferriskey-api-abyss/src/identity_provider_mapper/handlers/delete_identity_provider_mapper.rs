@@ -0,0 +1,52 @@
+use crate::identity_provider_mapper::dto::DeleteIdentityProviderMapperResponse;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::{api_error::ApiError, response::Response};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::abyss::identity_provider::mappers::{
+    DeleteIdentityProviderMapperInput, IdentityProviderMapperService,
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    path = "/identity-providers/{alias}/mappers/{mapper_id}",
+    summary = "Delete an identity provider mapper",
+    description = "Removes a mapper from the identity provider. Attributes, roles and memberships it already granted are kept.",
+    responses(
+        (status = 200, body = DeleteIdentityProviderMapperResponse, description = "Identity provider mapper deleted successfully"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Mapper, identity provider or realm not found"),
+    ),
+    params(
+        ("realm_name" = String, Path, description = "The name of the realm"),
+        ("alias" = String, Path, description = "The unique alias of the identity provider"),
+        ("mapper_id" = Uuid, Path, description = "The ID of the mapper to delete"),
+    ),
+    tag = "identity_provider",
+)]
+pub async fn delete_identity_provider_mapper(
+    Path((realm_name, alias, mapper_id)): Path<(String, String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DeleteIdentityProviderMapperResponse>, ApiError> {
+    state
+        .service
+        .delete_identity_provider_mapper(
+            identity,
+            DeleteIdentityProviderMapperInput {
+                realm_name,
+                alias,
+                mapper_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(DeleteIdentityProviderMapperResponse {
+        count: 1,
+    }))
+}
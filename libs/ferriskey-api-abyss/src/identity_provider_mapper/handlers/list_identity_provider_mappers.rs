@@ -0,0 +1,46 @@
+use crate::identity_provider_mapper::dto::IdentityProviderMappersResponse;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::{api_error::ApiError, response::Response};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::abyss::identity_provider::mappers::{
+    IdentityProviderMapperService, ListIdentityProviderMappersInput,
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+
+#[utoipa::path(
+    get,
+    path = "/identity-providers/{alias}/mappers",
+    summary = "List the mappers of an identity provider",
+    description = "Retrieves the mappers applied to users who log in through the identity provider, oldest first.",
+    responses(
+        (status = 200, body = IdentityProviderMappersResponse, description = "List of identity provider mappers"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Identity provider or realm not found"),
+    ),
+    params(
+        ("realm_name" = String, Path, description = "The name of the realm"),
+        ("alias" = String, Path, description = "The unique alias of the identity provider"),
+    ),
+    tag = "identity_provider",
+)]
+pub async fn list_identity_provider_mappers(
+    Path((realm_name, alias)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<IdentityProviderMappersResponse>, ApiError> {
+    let mappers = state
+        .service
+        .list_identity_provider_mappers(
+            identity,
+            ListIdentityProviderMappersInput { realm_name, alias },
+        )
+        .await?;
+
+    Ok(Response::OK(IdentityProviderMappersResponse {
+        data: mappers,
+    }))
+}
@@ -0,0 +1,4 @@
+pub mod create_identity_provider_mapper;
+pub mod delete_identity_provider_mapper;
+pub mod list_identity_provider_mappers;
+pub mod update_identity_provider_mapper;
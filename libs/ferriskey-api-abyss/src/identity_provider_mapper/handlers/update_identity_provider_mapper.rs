@@ -0,0 +1,63 @@
+use crate::identity_provider_mapper::dto::{
+    IdentityProviderMapperResponse, IdentityProviderMapperValidator,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::abyss::identity_provider::mappers::{
+    IdentityProviderMapperService, UpdateIdentityProviderMapperInput,
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use uuid::Uuid;
+
+#[utoipa::path(
+    put,
+    path = "/identity-providers/{alias}/mappers/{mapper_id}",
+    summary = "Update an identity provider mapper",
+    description = "Replaces the name, sync mode and configuration of a mapper. The change applies from the next login through the identity provider.",
+    responses(
+        (status = 200, body = IdentityProviderMapperResponse, description = "Identity provider mapper updated successfully"),
+        (status = 400, description = "Invalid mapper configuration, a role, organization or group outside the realm, or a name already used by another mapper of the identity provider"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Mapper, identity provider or realm not found"),
+    ),
+    params(
+        ("realm_name" = String, Path, description = "The name of the realm"),
+        ("alias" = String, Path, description = "The unique alias of the identity provider"),
+        ("mapper_id" = Uuid, Path, description = "The ID of the mapper to update"),
+    ),
+    tag = "identity_provider",
+    request_body = IdentityProviderMapperValidator,
+)]
+pub async fn update_identity_provider_mapper(
+    Path((realm_name, alias, mapper_id)): Path<(String, String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<IdentityProviderMapperValidator>,
+) -> Result<Response<IdentityProviderMapperResponse>, ApiError> {
+    let mapper = state
+        .service
+        .update_identity_provider_mapper(
+            identity,
+            UpdateIdentityProviderMapperInput {
+                realm_name,
+                alias,
+                mapper_id,
+                name: payload.name,
+                sync_mode: payload.sync_mode,
+                config: payload.config,
+            },
+        )
+        .await?;
+
+    Ok(Response::Updated(IdentityProviderMapperResponse {
+        data: mapper,
+    }))
+}
@@ -0,0 +1,34 @@
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+
+use crate::identity_provider_mapper::handlers::{
+    create_identity_provider_mapper::create_identity_provider_mapper,
+    delete_identity_provider_mapper::delete_identity_provider_mapper,
+    list_identity_provider_mappers::list_identity_provider_mappers,
+    update_identity_provider_mapper::update_identity_provider_mapper,
+};
+use ferriskey_api_core::app_state::AppState;
+
+pub mod dto;
+pub mod handlers;
+
+pub fn identity_provider_mapper_routes(state: AppState) -> Router<AppState> {
+    let root_path = format!(
+        "{}/realms/{{realm_name}}/identity-providers/{{alias}}/mappers",
+        state.args.server.root_path
+    );
+
+    Router::new()
+        .route(&root_path, get(list_identity_provider_mappers))
+        .route(&root_path, post(create_identity_provider_mapper))
+        .route(
+            &format!("{}/{{mapper_id}}", root_path),
+            put(update_identity_provider_mapper),
+        )
+        .route(
+            &format!("{}/{{mapper_id}}", root_path),
+            delete(delete_identity_provider_mapper),
+        )
+}
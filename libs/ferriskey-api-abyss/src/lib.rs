@@ -3,6 +3,7 @@ use utoipa::OpenApi;
 pub mod federation;
 pub mod identity_provider;
 pub mod identity_provider_link;
+pub mod identity_provider_mapper;
 pub mod routes;
 
 #[derive(OpenApi)]
@@ -15,24 +16,38 @@ pub mod routes;
         federation::handlers::delete_provider::delete_provider,
         federation::handlers::test_connection::test_connection,
         federation::handlers::sync_users::sync_users,
+        federation::handlers::list_sync_runs::list_sync_runs,
 
         identity_provider::handlers::create_identity_provider::create_identity_provider,
         identity_provider::handlers::list_identity_providers::list_identity_providers,
         identity_provider::handlers::get_identity_provider::get_identity_provider,
         identity_provider::handlers::update_identity_provider::update_identity_provider,
         identity_provider::handlers::delete_identity_provider::delete_identity_provider,
+        identity_provider::handlers::discover_identity_provider::discover_identity_provider,
+        identity_provider::handlers::validate_identity_provider::validate_identity_provider,
 
         identity_provider_link::handlers::list_identity_provider_links::list_identity_provider_links,
         identity_provider_link::handlers::delete_identity_provider_link::delete_identity_provider_link,
+
+        identity_provider_mapper::handlers::list_identity_provider_mappers::list_identity_provider_mappers,
+        identity_provider_mapper::handlers::create_identity_provider_mapper::create_identity_provider_mapper,
+        identity_provider_mapper::handlers::update_identity_provider_mapper::update_identity_provider_mapper,
+        identity_provider_mapper::handlers::delete_identity_provider_mapper::delete_identity_provider_mapper,
     ),
     components(
         schemas(
             federation::dto::CreateProviderRequest,
             federation::dto::UpdateProviderRequest,
             federation::dto::ProviderResponse,
+            federation::dto::ListSyncRunsResponse,
+            identity_provider::dto::DiscoverIdentityProviderValidator,
             identity_provider_link::dto::IdentityProviderLinkResponse,
             identity_provider_link::dto::IdentityProviderLinksResponse,
             identity_provider_link::dto::DeleteIdentityProviderLinkResponse,
+            identity_provider_mapper::dto::IdentityProviderMapperValidator,
+            identity_provider_mapper::dto::IdentityProviderMapperResponse,
+            identity_provider_mapper::dto::IdentityProviderMappersResponse,
+            identity_provider_mapper::dto::DeleteIdentityProviderMapperResponse,
         )
     ),
     tags(
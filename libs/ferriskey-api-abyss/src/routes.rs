@@ -1,6 +1,7 @@
 use crate::{
     federation::federation_routes, identity_provider::identity_provider_routes,
     identity_provider_link::identity_provider_link_routes,
+    identity_provider_mapper::identity_provider_mapper_routes,
 };
 use axum::{Router, middleware};
 use ferriskey_api_core::app_state::AppState;
@@ -11,5 +12,6 @@ pub fn abyss_routes(state: AppState) -> Router<AppState> {
         .merge(federation_routes(state.clone()))
         .merge(identity_provider_routes(state.clone()))
         .merge(identity_provider_link_routes(state.clone()))
+        .merge(identity_provider_mapper_routes(state.clone()))
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
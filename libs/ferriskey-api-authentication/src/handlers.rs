@@ -9,6 +9,7 @@ pub mod openid_configuration;
 pub mod registration;
 pub mod resend_verification_email;
 pub mod revoke;
+pub mod saml;
 pub mod token;
 pub mod userinfo;
 pub mod verify_email;
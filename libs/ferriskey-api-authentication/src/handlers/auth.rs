@@ -1,5 +1,7 @@
+use std::net::IpAddr;
+
 use axum::extract::Path;
-use axum::http::header::LOCATION;
+use axum::http::header::{AUTHORIZATION, CONTENT_TYPE, LOCATION, WWW_AUTHENTICATE};
 use axum::{
     extract::{Query, State},
     http::{HeaderMap, HeaderValue, StatusCode, header::SET_COOKIE},
@@ -17,8 +19,10 @@ use ferriskey_core::domain::common::entities::app_errors::CoreError;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
 use validator::Validate;
 
+use ferriskey_api_core::client_ip::{ClientIp, user_agent};
 use ferriskey_api_core::url::FullUrl;
 pub use ferriskey_api_core::url::root_scoped_base_url;
 use ferriskey_api_core::{api_entities::api_error::ApiError, app_state::AppState};
@@ -35,6 +39,32 @@ fn webapp_login_url(webapp_url: &str, realm_name: &str, login_url: &str) -> Stri
     )
 }
 
+/// The `Negotiate` token a browser sent after our SPNEGO challenge, if any.
+fn negotiate_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Negotiate ")
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+}
+
+/// Body of the SPNEGO challenge. Browsers without a Kerberos ticket render it instead of
+/// retrying, so it forwards them to the login form.
+fn negotiate_fallback_page(url: &str) -> String {
+    let url = url
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+
+    format!(
+        "<!DOCTYPE html><html><head><meta http-equiv=\"refresh\" content=\"0;url={url}\"></head>\
+         <body><a href=\"{url}\">Continue to sign in</a></body></html>"
+    )
+}
+
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema, IntoParams)]
 #[into_params(parameter_in = Query)]
 pub struct AuthRequest {
@@ -57,6 +87,9 @@ pub struct AuthRequest {
     pub code_challenge: Option<String>,
     #[serde(default)]
     pub code_challenge_method: Option<CodeChallengeMethod>,
+    /// Preferred languages of the login pages, space-separated, most preferred first.
+    #[serde(default)]
+    pub ui_locales: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema, PartialEq, Eq)]
@@ -77,7 +110,7 @@ pub struct AuthResponse {
     responses(
         (status = 302, description = "Redirects to the login page with session cookie set", body = AuthResponse),
         (status = 400, description = "Bad Request"),
-        (status = 401, description = "Unauthorized"),
+        (status = 401, description = "Unauthorized, or a `WWW-Authenticate: Negotiate` challenge when the realm has a Kerberos provider"),
         (status = 500, description = "Internal Server Error")
     )
 )]
@@ -85,7 +118,9 @@ pub async fn auth_handler(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     FullUrl(_, base_url): FullUrl,
+    ClientIp(client_ip): ClientIp,
     cookie: CookieManager,
+    request_headers: HeaderMap,
     Query(params): Query<AuthRequest>,
 ) -> Result<axum::response::Response, ApiError> {
     let result = match state
@@ -100,6 +135,7 @@ pub async fn auth_handler(
             nonce: params.nonce.clone(),
             code_challenge: params.code_challenge.clone(),
             code_challenge_method: params.code_challenge_method.clone(),
+            ui_locales: params.ui_locales.clone(),
         })
         .await
     {
@@ -129,23 +165,75 @@ pub async fn auth_handler(
         Err(e) => return Err(ApiError::from(e)),
     };
 
-    if let Some(identity_cookie) = cookie.get(IDENTITY_COOKIE)
+    browser_login(
+        &state,
+        BrowserLogin {
+            realm_name: &realm_name,
+            client_id: &params.client_id,
+            base_url: &base_url,
+            session_id: result.session.id,
+            login_url: &result.login_url,
+            negotiate: result.negotiate,
+            allow_sso: true,
+            client_ip,
+        },
+        &cookie,
+        &request_headers,
+    )
+    .await
+}
+
+/// A login session waiting for the browser to authenticate.
+pub(crate) struct BrowserLogin<'a> {
+    pub realm_name: &'a str,
+    pub client_id: &'a str,
+    pub base_url: &'a str,
+    pub session_id: Uuid,
+    pub login_url: &'a str,
+    pub negotiate: bool,
+    /// Whether an existing identity cookie may complete the session without a prompt.
+    pub allow_sso: bool,
+    /// Scored by the login risk engine when a Kerberos ticket signs the user in.
+    pub client_ip: Option<IpAddr>,
+}
+
+/// Complete `login` from the identity cookie or a Kerberos ticket when possible, otherwise
+/// send the browser to the login page with the session cookie set.
+pub(crate) async fn browser_login(
+    state: &AppState,
+    login: BrowserLogin<'_>,
+    cookie: &CookieManager,
+    request_headers: &HeaderMap,
+) -> Result<axum::response::Response, ApiError> {
+    let BrowserLogin {
+        realm_name,
+        client_id,
+        base_url,
+        session_id,
+        login_url,
+        negotiate,
+        allow_sso,
+        client_ip,
+    } = login;
+
+    if allow_sso
+        && let Some(identity_cookie) = cookie.get(IDENTITY_COOKIE)
         && !identity_cookie.value().trim().is_empty()
     {
         warn!(
             realm = %realm_name,
-            client_id = %params.client_id,
-            session_code = %result.session.id,
+            client_id = %client_id,
+            session_code = %session_id,
             "Attempting automatic SSO with identity cookie"
         );
 
         let auth_result = state
             .service
             .authenticate(AuthenticateInput::with_existing_token(
-                realm_name.clone(),
-                params.client_id.clone(),
-                result.session.id,
-                root_scoped_base_url(&base_url, &state.args.server.root_path),
+                realm_name.to_string(),
+                client_id.to_string(),
+                session_id,
+                root_scoped_base_url(base_url, &state.args.server.root_path),
                 identity_cookie.value().to_string(),
             ))
             .await;
@@ -163,8 +251,8 @@ pub async fn auth_handler(
             Err(e) => {
                 warn!(
                     realm = %realm_name,
-                    client_id = %params.client_id,
-                    session_code = %result.session.id,
+                    client_id = %client_id,
+                    session_code = %session_id,
                     error = ?e,
                     "Automatic SSO with identity cookie failed, redirecting to login page"
                 );
@@ -172,9 +260,49 @@ pub async fn auth_handler(
         }
     }
 
-    let full_url = webapp_login_url(&state.args.webapp_url, &realm_name, &result.login_url);
+    let negotiate_token = negotiate_token(request_headers);
+
+    // A failed ticket falls through to the login form; challenging again would loop.
+    if negotiate && let Some(token) = negotiate_token {
+        let auth_result = state
+            .service
+            .authenticate(
+                AuthenticateInput::with_negotiate_token(
+                    realm_name.to_string(),
+                    client_id.to_string(),
+                    session_id,
+                    root_scoped_base_url(base_url, &state.args.server.root_path),
+                    token.to_string(),
+                )
+                .with_client_context(client_ip, user_agent(request_headers)),
+            )
+            .await;
 
-    let mut session_cookie = Cookie::build((AUTH_SESSION_COOKIE, result.session.id.to_string()))
+        match auth_result {
+            Ok(auth_result)
+                if auth_result.status == AuthenticationStepStatus::Success
+                    && auth_result.redirect_url.is_some() =>
+            {
+                if let Some(redirect_url) = auth_result.redirect_url {
+                    return Ok((StatusCode::FOUND, [(LOCATION, redirect_url)]).into_response());
+                }
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!(
+                    realm = %realm_name,
+                    client_id = %client_id,
+                    session_code = %session_id,
+                    error = ?e,
+                    "Kerberos SSO failed, redirecting to login page"
+                );
+            }
+        }
+    }
+
+    let full_url = webapp_login_url(&state.args.webapp_url, realm_name, login_url);
+
+    let mut session_cookie = Cookie::build((AUTH_SESSION_COOKIE, session_id.to_string()))
         .path("/")
         .http_only(true)
         .same_site(SameSite::Lax);
@@ -206,17 +334,32 @@ pub async fn auth_handler(
         headers.append(SET_COOKIE, clear_identity_cookie_value);
     }
 
+    let challenge = negotiate && negotiate_token.is_none();
+
     let mut response_builder = axum::response::Response::builder();
-    response_builder = response_builder
-        .status(StatusCode::FOUND)
-        .header(LOCATION, &full_url);
+    response_builder = if challenge {
+        response_builder
+            .status(StatusCode::UNAUTHORIZED)
+            .header(WWW_AUTHENTICATE, "Negotiate")
+            .header(CONTENT_TYPE, "text/html; charset=utf-8")
+    } else {
+        response_builder
+            .status(StatusCode::FOUND)
+            .header(LOCATION, &full_url)
+    };
 
     for value in headers.get_all(SET_COOKIE).iter() {
         response_builder = response_builder.header(SET_COOKIE, value);
     }
 
+    let body = if challenge {
+        axum::body::Body::from(negotiate_fallback_page(&full_url))
+    } else {
+        axum::body::Body::empty()
+    };
+
     let axum_response = response_builder
-        .body(axum::body::Body::empty())
+        .body(body)
         .map_err(|_| ApiError::InternalServerError("Failed to build response".into()))?;
 
     Ok(axum_response.into_response())
@@ -224,7 +367,9 @@ pub async fn auth_handler(
 
 #[cfg(test)]
 mod tests {
-    use super::webapp_login_url;
+    use axum::http::{HeaderMap, HeaderValue, header::AUTHORIZATION};
+
+    use super::{negotiate_fallback_page, negotiate_token, webapp_login_url};
 
     #[test]
     fn joins_webapp_login_url_without_double_slashes() {
@@ -253,4 +398,23 @@ mod tests {
             "https://login.example.com/realms/demo/authentication/login?client_id=test-client"
         );
     }
+
+    #[test]
+    fn reads_negotiate_token_from_authorization_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(negotiate_token(&headers), None);
+
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Basic dXNlcg=="));
+        assert_eq!(negotiate_token(&headers), None);
+
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Negotiate YIIB"));
+        assert_eq!(negotiate_token(&headers), Some("YIIB"));
+    }
+
+    #[test]
+    fn escapes_login_url_in_negotiate_fallback_page() {
+        let page = negotiate_fallback_page("https://login.example.com/?a=1&b=\"2\"");
+
+        assert!(page.contains("url=https://login.example.com/?a=1&amp;b=&quot;2&quot;\""));
+    }
 }
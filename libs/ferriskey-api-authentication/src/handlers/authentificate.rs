@@ -6,6 +6,7 @@ use axum_cookie::CookieManager;
 use axum_extra::extract::cookie::{Cookie, SameSite};
 use ferriskey_api_core::api_entities::api_error::{ApiError, ValidateJson};
 use ferriskey_api_core::app_state::AppState;
+use ferriskey_api_core::client_ip::{ClientIp, user_agent};
 use ferriskey_api_core::decoded_token::OptionalToken;
 use ferriskey_api_core::url::FullUrl;
 
@@ -13,6 +14,7 @@ use ferriskey_core::domain::authentication::entities::AuthenticateInput;
 use ferriskey_core::domain::authentication::entities::AuthenticationStepStatus;
 use ferriskey_core::domain::authentication::ports::AuthService;
 use ferriskey_core::domain::email_verification::ports::EmailVerificationService;
+use ferriskey_core::domain::rate_limit::{RateLimitCheck, RateLimitEndpoint, RateLimitService};
 use ferriskey_core::domain::user::entities::RequiredAction;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
@@ -54,18 +56,35 @@ pub struct AuthenticateRequest {
         (status = 400, description = "Invalid input", body = ApiError),
         (status = 401, description = "Missing session cookie", body = ApiError),
         (status = 401, description = "Invalid realm", body = ApiError),
+        (status = 429, description = "Too many requests, see the Retry-After header", body = ApiError),
         (status = 500, description = "Internal server error", body = ApiError)
     )
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn authenticate(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     FullUrl(_, base_url): FullUrl,
     OptionalToken(optional_token): OptionalToken,
+    ClientIp(client_ip): ClientIp,
     Query(query): Query<AuthenticateQueryParams>,
     cookie: CookieManager,
+    headers: HeaderMap,
     ValidateJson(payload): ValidateJson<AuthenticateRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
+    state
+        .service
+        .check_rate_limit(
+            RateLimitCheck::new(
+                realm_name.clone(),
+                RateLimitEndpoint::Authenticate,
+                client_ip,
+            )
+            .with_username(payload.username.clone())
+            .with_client_id(Some(query.client_id.clone())),
+        )
+        .await?;
+
     let session_code = match cookie.get("FERRISKEY_SESSION") {
         Some(cookie) => cookie,
         None => return Err(ApiError::Unauthorized("Missing session cookie".into())),
@@ -103,6 +122,7 @@ pub async fn authenticate(
             username,
             password,
         )
+        .with_client_context(client_ip, user_agent(&headers))
     };
     let result = state.service.authenticate(authenticate_params).await?;
 
@@ -10,12 +10,14 @@ use ferriskey_core::domain::authentication::device_flow::DeviceVerificationPrevi
 use ferriskey_core::domain::authentication::entities::AuthorizeRequestInput;
 use ferriskey_core::domain::authentication::ports::AuthService;
 use ferriskey_core::domain::jwt::entities::JwtClaim;
+use ferriskey_core::domain::rate_limit::{RateLimitCheck, RateLimitEndpoint, RateLimitService};
 use serde::{Deserialize, Serialize};
 use tracing::{instrument, warn};
 use utoipa::ToSchema;
 
 use ferriskey_api_core::api_entities::api_error::{ApiError, ApiErrorResponse};
 use ferriskey_api_core::app_state::AppState;
+use ferriskey_api_core::client_ip::ClientIp;
 
 const IDENTITY_COOKIE: &str = "FERRISKEY_IDENTITY";
 
@@ -65,15 +67,27 @@ pub struct DevicePreviewQuery {
         (status = 200, description = "Pending session details", body = DeviceVerificationPreview),
         (status = 401, description = "Authentication required", body = ApiErrorResponse),
         (status = 403, description = "The session belongs to another realm", body = ApiErrorResponse),
+        (status = 429, description = "Too many requests, see the Retry-After header", body = ApiErrorResponse),
     )
 )]
-#[instrument(skip(state, cookie), fields(realm_name = %realm_name))]
+#[instrument(skip(state, cookie, client_ip), fields(realm_name = %realm_name))]
 pub async fn device_preview(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
     cookie: CookieManager,
     Query(query): Query<DevicePreviewQuery>,
 ) -> Result<Response, ApiError> {
+    // Shares the verify budget: both look up user codes, so either could enumerate them.
+    state
+        .service
+        .check_rate_limit(RateLimitCheck::new(
+            realm_name.clone(),
+            RateLimitEndpoint::DeviceVerify,
+            client_ip,
+        ))
+        .await?;
+
     let token = cookie
         .get(IDENTITY_COOKIE)
         .map(|c| c.value().to_string())
@@ -157,15 +171,26 @@ pub async fn device_verification_page(
         (status = 400, description = "Unknown or expired user code", body = ApiErrorResponse),
         (status = 401, description = "Not logged in — redirect hint provided"),
         (status = 403, description = "Service accounts cannot approve devices", body = ApiErrorResponse),
+        (status = 429, description = "Too many requests, see the Retry-After header", body = ApiErrorResponse),
     )
 )]
-#[instrument(skip(state, cookie, payload), fields(realm_name = %realm_name, action = ?payload.action))]
+#[instrument(skip(state, cookie, payload, client_ip), fields(realm_name = %realm_name, action = ?payload.action))]
 pub async fn device_verify(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
     cookie: CookieManager,
     Json(payload): Json<DeviceVerifyRequest>,
 ) -> Result<Response, ApiError> {
+    state
+        .service
+        .check_rate_limit(RateLimitCheck::new(
+            realm_name.clone(),
+            RateLimitEndpoint::DeviceVerify,
+            client_ip,
+        ))
+        .await?;
+
     // Require a non-empty identity cookie; otherwise hint the front to log in
     // and come back to this verification page.
     let token = cookie
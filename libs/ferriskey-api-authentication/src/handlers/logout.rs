@@ -28,7 +28,7 @@ const IDENTITY_COOKIE: &str = "FERRISKEY_IDENTITY";
         (status = 500, description = "Internal Server Error", body = ApiErrorResponse),
     )
 )]
-fn clear_session_cookies_headers(base_url: &str) -> Result<HeaderMap, ApiError> {
+pub(crate) fn clear_session_cookies_headers(base_url: &str) -> Result<HeaderMap, ApiError> {
     let is_secure = base_url.starts_with("https://");
     let mut clear_session_cookie = Cookie::build((AUTH_SESSION_COOKIE, ""))
         .path("/")
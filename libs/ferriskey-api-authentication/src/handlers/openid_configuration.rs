@@ -5,6 +5,7 @@ use axum::{
     extract::{Path, State},
 };
 use ferriskey_api_core::{api_entities::response::Response, app_state::AppState};
+use ferriskey_core::domain::localization::LocalizationService;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -23,6 +24,7 @@ pub struct GetOpenIdConfigurationResponse {
     pub subject_types_supported: Vec<String>,
     pub id_token_signing_alg_values_supported: Vec<String>,
     pub token_endpoint_auth_methods_supported: Vec<String>,
+    pub ui_locales_supported: Vec<String>,
 }
 
 #[utoipa::path(
@@ -64,6 +66,14 @@ pub async fn get_openid_configuration(
         realm_name
     );
 
+    // Discovery stays available when the locales cannot be read; clients then assume none.
+    let ui_locales_supported = state
+        .service
+        .get_supported_locales(realm_name)
+        .await
+        .map(|locales| locales.into_iter().map(String::from).collect())
+        .unwrap_or_default();
+
     Ok(Response::OK(GetOpenIdConfigurationResponse {
         issuer: issuer.clone(),
         authorization_endpoint: format!("{issuer}/protocol/openid-connect/auth"),
@@ -95,5 +105,6 @@ pub async fn get_openid_configuration(
             "client_secret_basic".to_string(),
             "client_secret_post".to_string(),
         ],
+        ui_locales_supported,
     }))
 }
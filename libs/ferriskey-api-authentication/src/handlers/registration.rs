@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use axum::extract::{Path, State};
 use axum_cookie::CookieManager;
 use ferriskey_core::domain::{
@@ -34,6 +36,10 @@ pub struct RegistrationRequest {
 
     pub first_name: Option<String>,
     pub last_name: Option<String>,
+
+    /// Values for the attributes of the realm's user profile, keyed by attribute name.
+    #[serde(default)]
+    pub attributes: HashMap<String, String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -82,6 +88,7 @@ fn registration_url_context(
         (status = 400, description = "Email already exists", body = ApiErrorResponse),
         (status = 401, description = "Realm not found", body = ApiErrorResponse),
         (status = 403, description = "User registration is disabled for this realm", body = ApiErrorResponse),
+        (status = 422, description = "Attributes violate the realm's user profile", body = ApiErrorResponse),
         (status = 500, description = "Internal Server Error", body = ApiErrorResponse),
     ),
     params(
@@ -127,6 +134,7 @@ pub async fn registration_handler(
                 realm_name: realm_name.clone(),
                 username: req.username,
                 session_code,
+                attributes: req.attributes,
             },
         )
         .await?;
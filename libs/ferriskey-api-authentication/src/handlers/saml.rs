@@ -0,0 +1,380 @@
+use axum::{
+    Form,
+    extract::{Path, Query, RawQuery, State},
+    http::{
+        HeaderMap, StatusCode,
+        header::{CACHE_CONTROL, CONTENT_TYPE, LOCATION},
+    },
+    response::{IntoResponse, Response},
+};
+use axum_cookie::CookieManager;
+use ferriskey_core::domain::authentication::saml::{
+    entities::{SamlBinding, SamlInboundMessage, SamlOutcome, SamlPostForm},
+    ports::SamlService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::handlers::auth::{BrowserLogin, browser_login, root_scoped_base_url};
+use crate::handlers::logout::clear_session_cookies_headers;
+use ferriskey_api_core::api_entities::api_error::{ApiError, ApiErrorResponse};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_api_core::url::FullUrl;
+
+/// A SAML protocol message, as query parameters (HTTP-Redirect) or form fields (HTTP-POST).
+#[derive(Debug, Serialize, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SamlMessageParams {
+    #[serde(rename = "SAMLRequest", default)]
+    pub saml_request: Option<String>,
+    #[serde(rename = "SAMLResponse", default)]
+    pub saml_response: Option<String>,
+    #[serde(rename = "RelayState", default)]
+    pub relay_state: Option<String>,
+    /// HTTP-Redirect binding only; verified against the raw query string.
+    #[serde(rename = "SigAlg", default)]
+    pub sig_alg: Option<String>,
+    #[serde(rename = "Signature", default)]
+    pub signature: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SamlResumeParams {
+    pub code: String,
+}
+
+/// HTTP-POST binding: a page that posts `form` to the service provider as soon as it loads.
+fn post_form_page(form: &SamlPostForm) -> String {
+    let escape = |value: &str| {
+        value
+            .replace('&', "&amp;")
+            .replace('"', "&quot;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    };
+
+    let relay_state = form
+        .relay_state
+        .as_deref()
+        .map(|relay_state| {
+            format!(
+                "<input type=\"hidden\" name=\"RelayState\" value=\"{}\"/>",
+                escape(relay_state)
+            )
+        })
+        .unwrap_or_default();
+
+    format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"></head>\
+         <body onload=\"document.forms[0].submit()\">\
+         <form method=\"post\" action=\"{action}\">\
+         <input type=\"hidden\" name=\"{parameter}\" value=\"{value}\"/>{relay_state}\
+         <noscript><button type=\"submit\">Continue</button></noscript>\
+         </form></body></html>",
+        action = escape(&form.action),
+        parameter = form.parameter,
+        value = escape(&form.value),
+    )
+}
+
+fn post_form_response(form: &SamlPostForm, headers: HeaderMap) -> Response {
+    (
+        StatusCode::OK,
+        headers,
+        [
+            (CONTENT_TYPE, "text/html; charset=utf-8"),
+            (CACHE_CONTROL, "no-store"),
+        ],
+        post_form_page(form),
+    )
+        .into_response()
+}
+
+async fn respond(
+    state: &AppState,
+    realm_name: &str,
+    base_url: &str,
+    outcome: SamlOutcome,
+    cookie: &CookieManager,
+    request_headers: &HeaderMap,
+) -> Result<Response, ApiError> {
+    match outcome {
+        SamlOutcome::Login {
+            session,
+            login_url,
+            client_id,
+            force_authn,
+        } => {
+            browser_login(
+                state,
+                BrowserLogin {
+                    realm_name,
+                    client_id: &client_id,
+                    base_url,
+                    session_id: session.id,
+                    login_url: &login_url,
+                    negotiate: false,
+                    allow_sso: !force_authn,
+                    client_ip: None,
+                },
+                cookie,
+                request_headers,
+            )
+            .await
+        }
+        // Only logout answers the service provider without a login: the browser session
+        // ended with it.
+        SamlOutcome::Post(form) => Ok(post_form_response(
+            &form,
+            clear_session_cookies_headers(base_url)?,
+        )),
+        SamlOutcome::Redirect(url) => {
+            let mut headers = clear_session_cookies_headers(base_url)?;
+            headers.insert(
+                LOCATION,
+                url.parse()
+                    .map_err(|_| ApiError::InternalServerError("Invalid redirect URL".into()))?,
+            );
+            Ok((StatusCode::FOUND, headers).into_response())
+        }
+    }
+}
+
+impl SamlMessageParams {
+    fn into_message(
+        self,
+        state: &AppState,
+        realm_name: &str,
+        base_url: &str,
+        binding: SamlBinding,
+        raw_query: Option<String>,
+    ) -> SamlInboundMessage {
+        SamlInboundMessage {
+            realm_name: realm_name.to_string(),
+            base_url: root_scoped_base_url(base_url, &state.args.server.root_path),
+            binding,
+            saml_request: self.saml_request,
+            saml_response: self.saml_response,
+            relay_state: self.relay_state,
+            raw_query,
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/protocol/saml/descriptor",
+    tag = "auth",
+    summary = "Get SAML identity provider metadata",
+    description = "Returns the realm's SAML 2.0 IdP EntityDescriptor: entity ID, signing certificate, supported NameID formats and the single sign-on and logout endpoints.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "SAML metadata document", content_type = "application/samlmetadata+xml", body = String),
+        (status = 404, description = "Realm not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal Server Error", body = ApiErrorResponse),
+    )
+)]
+pub async fn get_saml_descriptor(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+) -> Result<Response, ApiError> {
+    let metadata = state
+        .service
+        .get_idp_metadata(
+            realm_name,
+            root_scoped_base_url(&base_url, &state.args.server.root_path),
+        )
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        [(CONTENT_TYPE, "application/samlmetadata+xml")],
+        metadata,
+    )
+        .into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/protocol/saml",
+    tag = "auth",
+    summary = "SAML single sign-on and logout (HTTP-Redirect binding)",
+    description = "Receives an AuthnRequest, which starts a login, or a LogoutRequest, which ends the SSO session it names and is answered to the service provider.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        SamlMessageParams
+    ),
+    responses(
+        (status = 200, description = "Auto-submitting form posting the LogoutResponse"),
+        (status = 302, description = "Redirect to the login page, the completed login, or the service provider"),
+        (status = 400, description = "Invalid SAML message", body = ApiErrorResponse),
+        (status = 401, description = "Unknown service provider", body = ApiErrorResponse),
+    )
+)]
+pub async fn saml_redirect_binding(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+    RawQuery(raw_query): RawQuery,
+    cookie: CookieManager,
+    request_headers: HeaderMap,
+    Query(params): Query<SamlMessageParams>,
+) -> Result<Response, ApiError> {
+    let message = params.into_message(
+        &state,
+        &realm_name,
+        &base_url,
+        SamlBinding::HttpRedirect,
+        raw_query,
+    );
+    let outcome = state.service.handle_message(message).await?;
+
+    respond(
+        &state,
+        &realm_name,
+        &base_url,
+        outcome,
+        &cookie,
+        &request_headers,
+    )
+    .await
+}
+
+#[utoipa::path(
+    post,
+    path = "/protocol/saml",
+    tag = "auth",
+    summary = "SAML single sign-on and logout (HTTP-POST binding)",
+    description = "Receives an AuthnRequest, which starts a login, or a LogoutRequest, which ends the SSO session it names and is answered to the service provider.",
+    request_body(content = SamlMessageParams, content_type = "application/x-www-form-urlencoded"),
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Auto-submitting form posting the LogoutResponse"),
+        (status = 302, description = "Redirect to the login page, the completed login, or the service provider"),
+        (status = 400, description = "Invalid SAML message", body = ApiErrorResponse),
+        (status = 401, description = "Unknown service provider", body = ApiErrorResponse),
+    )
+)]
+pub async fn saml_post_binding(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+    cookie: CookieManager,
+    request_headers: HeaderMap,
+    Form(params): Form<SamlMessageParams>,
+) -> Result<Response, ApiError> {
+    let message = params.into_message(&state, &realm_name, &base_url, SamlBinding::HttpPost, None);
+    let outcome = state.service.handle_message(message).await?;
+
+    respond(
+        &state,
+        &realm_name,
+        &base_url,
+        outcome,
+        &cookie,
+        &request_headers,
+    )
+    .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/protocol/saml/resume",
+    tag = "auth",
+    summary = "Complete a SAML login",
+    description = "Target of a completed SAML login: redeems the authorization code and posts the signed SAML Response to the service provider's assertion consumer service.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        SamlResumeParams
+    ),
+    responses(
+        (status = 200, description = "Auto-submitting form posting the SAMLResponse"),
+        (status = 400, description = "Invalid or expired code", body = ApiErrorResponse),
+    )
+)]
+pub async fn resume_saml_login(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+    Query(params): Query<SamlResumeParams>,
+) -> Result<Response, ApiError> {
+    let form = state
+        .service
+        .complete_login(
+            realm_name,
+            params.code,
+            root_scoped_base_url(&base_url, &state.args.server.root_path),
+        )
+        .await?;
+
+    Ok(post_form_response(&form, HeaderMap::new()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/protocol/saml/clients/{url_name}",
+    tag = "auth",
+    summary = "IdP-initiated SAML login",
+    description = "Starts an unsolicited login for the SAML client whose IdP-initiated SSO URL name is `url_name`; the response is posted to its default assertion consumer service.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("url_name" = String, Path, description = "IdP-initiated SSO URL name of the client"),
+    ),
+    responses(
+        (status = 302, description = "Redirect to the login page or the completed login"),
+        (status = 404, description = "No client uses this URL name", body = ApiErrorResponse),
+    )
+)]
+pub async fn idp_initiated_saml_login(
+    Path((realm_name, url_name)): Path<(String, String)>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+    cookie: CookieManager,
+    request_headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let outcome = state
+        .service
+        .initiate_idp_sso(
+            realm_name.clone(),
+            url_name,
+            root_scoped_base_url(&base_url, &state.args.server.root_path),
+        )
+        .await?;
+
+    respond(
+        &state,
+        &realm_name,
+        &base_url,
+        outcome,
+        &cookie,
+        &request_headers,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use ferriskey_core::domain::authentication::saml::entities::SamlPostForm;
+
+    use super::post_form_page;
+
+    #[test]
+    fn escapes_values_in_post_form_page() {
+        let page = post_form_page(&SamlPostForm {
+            action: "https://sp.example.com/acs?a=1&b=2".to_string(),
+            parameter: "SAMLResponse",
+            value: "PHNhbWxwOlJlc3BvbnNlLz4=".to_string(),
+            relay_state: Some("\"><script>".to_string()),
+        });
+
+        assert!(page.contains("action=\"https://sp.example.com/acs?a=1&amp;b=2\""));
+        assert!(page.contains("name=\"SAMLResponse\" value=\"PHNhbWxwOlJlc3BvbnNlLz4=\""));
+        assert!(page.contains("value=\"&quot;&gt;&lt;script&gt;\""));
+    }
+}
@@ -11,9 +11,11 @@ use axum_extra::extract::cookie::{Cookie, SameSite};
 use ferriskey_api_core::api_entities::api_error::ApiError;
 use ferriskey_api_core::api_entities::api_error::ApiErrorResponse;
 use ferriskey_api_core::app_state::AppState;
+use ferriskey_api_core::client_ip::ClientIp;
 use ferriskey_api_core::url::FullUrl;
 use ferriskey_core::domain::authentication::entities::{GrantType, JwtToken};
 use ferriskey_core::domain::authentication::{entities::ExchangeTokenInput, ports::AuthService};
+use ferriskey_core::domain::rate_limit::{RateLimitCheck, RateLimitEndpoint, RateLimitService};
 use tracing::{instrument, warn};
 
 const IDENTITY_COOKIE: &str = "FERRISKEY_IDENTITY";
@@ -32,11 +34,12 @@ const IDENTITY_COOKIE: &str = "FERRISKEY_IDENTITY";
         (status = 200, body = JwtToken),
         (status = 401, description = "Realm not found", body = ApiErrorResponse),
         (status = 404, description = "Client not found", body = ApiErrorResponse),
+        (status = 429, description = "Too many requests, see the Retry-After header", body = ApiErrorResponse),
         (status = 500, description = "Internal Server Error", body = ApiErrorResponse),
     )
 )]
 #[instrument(
-    skip(state, payload, headers),
+    skip(state, payload, headers, client_ip),
     fields(
         realm_name = %realm_name,
         grant_type = ?payload.grant_type,
@@ -50,6 +53,7 @@ pub async fn exchange_token(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     FullUrl(_, base_url): FullUrl,
+    ClientIp(client_ip): ClientIp,
     headers: HeaderMap,
     Form(payload): Form<TokenRequestValidator>,
 ) -> Result<impl IntoResponse, ApiError> {
@@ -61,6 +65,15 @@ pub async fn exchange_token(
         ),
     };
 
+    state
+        .service
+        .check_rate_limit(
+            RateLimitCheck::new(realm_name.clone(), RateLimitEndpoint::Token, client_ip)
+                .with_username(payload.username.clone())
+                .with_client_id(Some(client_id.clone())),
+        )
+        .await?;
+
     let grant_type = payload.grant_type.clone();
     let has_client_secret = client_secret.is_some();
     let has_username = payload.username.is_some();
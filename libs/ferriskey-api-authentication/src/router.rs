@@ -22,6 +22,11 @@ use super::handlers::{
         __path_resend_verification_email_handler, resend_verification_email_handler,
     },
     revoke::{__path_revoke_token, revoke_token},
+    saml::{
+        __path_get_saml_descriptor, __path_idp_initiated_saml_login, __path_resume_saml_login,
+        __path_saml_post_binding, __path_saml_redirect_binding, get_saml_descriptor,
+        idp_initiated_saml_login, resume_saml_login, saml_post_binding, saml_redirect_binding,
+    },
     token::{__path_exchange_token, exchange_token},
     userinfo::{__path_get_userinfo, get_userinfo},
     verify_email::{__path_verify_email_handler, verify_email_handler},
@@ -51,6 +56,11 @@ use ferriskey_api_core::{app_state::AppState, auth::auth};
         verify_email_handler,
         resend_verification_email_handler,
         get_userinfo,
+        get_saml_descriptor,
+        saml_redirect_binding,
+        saml_post_binding,
+        resume_saml_login,
+        idp_initiated_saml_login,
     ),
     // `CodeChallengeMethod` is only reached through the `AuthRequest` query-params
     // struct (`IntoParams`), which utoipa does not walk for component schemas — so the
@@ -143,4 +153,20 @@ pub fn authentication_routes(state: AppState, root_path: &str) -> Router<AppStat
             &format!("{root_path}/realms/{{realm_name}}/.well-known/openid-configuration"),
             get(get_openid_configuration),
         )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/protocol/saml/descriptor"),
+            get(get_saml_descriptor),
+        )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/protocol/saml"),
+            get(saml_redirect_binding).post(saml_post_binding),
+        )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/protocol/saml/resume"),
+            get(resume_saml_login),
+        )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/protocol/saml/clients/{{url_name}}"),
+            get(idp_initiated_saml_login),
+        )
 }
@@ -1,11 +1,14 @@
 use axum::{
     extract::{Path, Query, State},
-    http::{StatusCode, header::LOCATION},
-    response::IntoResponse,
+    http::{
+        HeaderValue, StatusCode,
+        header::{LOCATION, SET_COOKIE},
+    },
+    response::{IntoResponse, Response},
 };
 
 use ferriskey_core::domain::abyss::identity_provider::broker::{
-    BrokerCallbackInput, BrokerService,
+    BrokerCallbackInput, BrokerLoginOutcome, BrokerService,
 };
 use ferriskey_core::domain::common::entities::app_errors::CoreError;
 
@@ -16,14 +19,16 @@ use ferriskey_api_core::{
 };
 
 use super::super::validators::BrokerCallbackQuery;
+use super::first_login::broker_login_cookie;
 
 /// Handles the callback from the external identity provider
 ///
 /// This endpoint validates the state parameter, exchanges the authorization code
 /// for tokens, finds or creates the user, and redirects back to the client
-/// with an authorization code.
+/// with an authorization code. When the first-login or post-login flow needs the
+/// user, it redirects to the login page instead, with the pending login in a cookie.
 #[utoipa::path(
-    post,
+    get,
     path = "/broker/{alias}/endpoint",
     tag = "broker",
     summary = "Handle SSO callback from identity provider",
@@ -34,7 +39,7 @@ use super::super::validators::BrokerCallbackQuery;
         BrokerCallbackQuery
     ),
     responses(
-        (status = 302, description = "Redirect to client with authorization code"),
+        (status = 302, description = "Redirect to client with authorization code, or to the login page for a pending step"),
         (status = 400, description = "Bad request - invalid state or expired session", body = ApiErrorResponse),
         (status = 401, description = "Authentication failed at identity provider", body = ApiErrorResponse),
         (status = 502, description = "Error communicating with identity provider", body = ApiErrorResponse),
@@ -45,9 +50,10 @@ pub async fn broker_callback(
     State(state): State<AppState>,
     FullUrl(_, base_url): FullUrl,
     Query(params): Query<BrokerCallbackQuery>,
-) -> Result<impl IntoResponse, ApiError> {
+) -> Result<Response, ApiError> {
     let root_scoped_base_url = format!("{base_url}{}", state.args.server.root_path);
-    let result = match state
+
+    let result = state
         .service
         .handle_callback(BrokerCallbackInput {
             realm_name: realm_name.clone(),
@@ -56,27 +62,62 @@ pub async fn broker_callback(
             state: params.state,
             error: params.error,
             error_description: params.error_description,
-            base_url: root_scoped_base_url.clone(),
+            base_url: root_scoped_base_url,
         })
-        .await
-    {
+        .await;
+
+    login_outcome_response(&state, &realm_name, &base_url, result)
+}
+
+/// Where the browser goes once the upstream provider answered: back to the client, to the
+/// login page for a pending step, or to the login page with the reason the login stopped.
+pub(crate) fn login_outcome_response(
+    state: &AppState,
+    realm_name: &str,
+    base_url: &str,
+    result: Result<BrokerLoginOutcome, CoreError>,
+) -> Result<Response, ApiError> {
+    let frontend_origin = state
+        .args
+        .server
+        .allowed_origins
+        .first()
+        .map(|s| s.trim_end_matches('/').to_string())
+        .unwrap_or_else(|| base_url.to_string());
+
+    let result = match result {
         Ok(result) => result,
         Err(CoreError::UserDisabled) => {
-            let frontend_origin = state
-                .args
-                .server
-                .allowed_origins
-                .first()
-                .map(|s| s.trim_end_matches('/').to_string())
-                .unwrap_or_else(|| base_url.clone());
-
             let login_url = format!(
                 "{frontend_origin}/realms/{realm_name}/authentication/login?login_error=User+account+is+disabled"
             );
             return Ok((StatusCode::FOUND, [(LOCATION, login_url)]).into_response());
         }
+        Err(CoreError::BrokerLoginOtpNotConfigured) => {
+            let login_url = format!(
+                "{frontend_origin}/realms/{realm_name}/authentication/login?login_error=An+OTP+must+be+configured+before+signing+in+with+this+provider"
+            );
+            return Ok((StatusCode::FOUND, [(LOCATION, login_url)]).into_response());
+        }
         Err(e) => return Err(e.into()),
     };
 
-    Ok((StatusCode::FOUND, [(LOCATION, result.redirect_url)]).into_response())
+    match result {
+        BrokerLoginOutcome::Completed(output) => {
+            Ok((StatusCode::FOUND, [(LOCATION, output.redirect_url)]).into_response())
+        }
+        BrokerLoginOutcome::Pending { token, .. } => {
+            let cookie = broker_login_cookie(state, realm_name, base_url, &token)?;
+            let login_url =
+                format!("{frontend_origin}/realms/{realm_name}/authentication/broker-login");
+            let login_url = HeaderValue::from_str(&login_url)
+                .map_err(|_| ApiError::InternalServerError("Invalid redirect URL".into()))?;
+
+            Ok((
+                StatusCode::FOUND,
+                [(LOCATION, login_url), (SET_COOKIE, cookie)],
+            )
+                .into_response())
+        }
+    }
 }
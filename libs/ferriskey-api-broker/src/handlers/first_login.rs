@@ -0,0 +1,288 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header::SET_COOKIE},
+    response::{IntoResponse, Response},
+};
+use axum_cookie::CookieManager;
+use axum_extra::extract::cookie::{Cookie, SameSite};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use ferriskey_core::domain::abyss::identity_provider::broker::{
+    BrokerLoginOutcome, BrokerService, BrokeredProfile, ConfirmBrokerLinkInput,
+    PendingBrokerLoginInput, PendingBrokerLoginView, ReviewBrokeredProfileInput,
+    VerifyBrokerLoginOtpInput,
+};
+
+use ferriskey_api_core::url::FullUrl;
+use ferriskey_api_core::{
+    api_entities::api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    app_state::AppState,
+};
+
+use super::super::validators::{
+    ConfirmBrokerLinkRequest, ReviewBrokeredProfileRequest, VerifyBrokerLoginOtpRequest,
+};
+
+/// Holds the token of a brokered login waiting on the user.
+pub const BROKER_LOGIN_COOKIE: &str = "FERRISKEY_BROKER_LOGIN";
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BrokerLoginStepStatus {
+    Completed,
+    Pending,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BrokerLoginStepResponse {
+    pub status: BrokerLoginStepStatus,
+    /// Where to send the browser once the login completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<String>,
+    /// The next step of a login still waiting on the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login: Option<PendingBrokerLoginView>,
+}
+
+pub(crate) fn broker_login_cookie(
+    state: &AppState,
+    realm_name: &str,
+    base_url: &str,
+    token: &str,
+) -> Result<HeaderValue, ApiError> {
+    let mut cookie = Cookie::build((BROKER_LOGIN_COOKIE, token.to_string()))
+        .path(format!(
+            "{}/realms/{realm_name}/broker",
+            state.args.server.root_path
+        ))
+        .http_only(true)
+        .same_site(SameSite::Lax);
+
+    if token.is_empty() {
+        cookie = cookie.removal();
+    }
+    if base_url.starts_with("https") {
+        cookie = cookie.secure(true);
+    }
+
+    HeaderValue::from_str(&cookie.to_string())
+        .map_err(|_| ApiError::InternalServerError("Invalid cookie header".into()))
+}
+
+fn pending_token(cookie: &CookieManager) -> Result<String, ApiError> {
+    cookie
+        .get(BROKER_LOGIN_COOKIE)
+        .map(|cookie| cookie.value().to_string())
+        .ok_or_else(|| ApiError::Unauthorized("Missing broker login cookie".into()))
+}
+
+fn step_response(
+    state: &AppState,
+    realm_name: &str,
+    base_url: &str,
+    outcome: BrokerLoginOutcome,
+) -> Result<Response, ApiError> {
+    let (token, response) = match outcome {
+        BrokerLoginOutcome::Completed(output) => (
+            String::new(),
+            BrokerLoginStepResponse {
+                status: BrokerLoginStepStatus::Completed,
+                redirect_url: Some(output.redirect_url),
+                login: None,
+            },
+        ),
+        BrokerLoginOutcome::Pending { token, login } => (
+            token,
+            BrokerLoginStepResponse {
+                status: BrokerLoginStepStatus::Pending,
+                redirect_url: None,
+                login: Some(*login),
+            },
+        ),
+    };
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        SET_COOKIE,
+        broker_login_cookie(state, realm_name, base_url, &token)?,
+    );
+
+    Ok((StatusCode::OK, headers, Json(response)).into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/broker/login-actions/first-login",
+    tag = "broker",
+    summary = "Get the pending step of a brokered login",
+    description = "Describes what a brokered login waits on: a profile review, the confirmation of an existing account, or a one-time password.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Pending brokered login", body = PendingBrokerLoginView),
+        (status = 400, description = "Unknown or expired brokered login", body = ApiErrorResponse),
+        (status = 401, description = "Missing broker login cookie", body = ApiErrorResponse),
+    )
+)]
+pub async fn get_pending_broker_login(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    cookie: CookieManager,
+) -> Result<Json<PendingBrokerLoginView>, ApiError> {
+    let token = pending_token(&cookie)?;
+
+    let login = state
+        .service
+        .get_pending_login(PendingBrokerLoginInput { realm_name, token })
+        .await?;
+
+    Ok(Json(login))
+}
+
+#[utoipa::path(
+    post,
+    path = "/broker/login-actions/first-login/profile",
+    tag = "broker",
+    summary = "Submit the reviewed profile of a brokered first login",
+    request_body = ReviewBrokeredProfileRequest,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Login completed or waiting on the next step", body = BrokerLoginStepResponse),
+        (status = 400, description = "Unknown or expired brokered login", body = ApiErrorResponse),
+        (status = 401, description = "Missing broker login cookie", body = ApiErrorResponse),
+    )
+)]
+pub async fn review_brokered_profile(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+    cookie: CookieManager,
+    ValidateJson(payload): ValidateJson<ReviewBrokeredProfileRequest>,
+) -> Result<Response, ApiError> {
+    let token = pending_token(&cookie)?;
+
+    let outcome = state
+        .service
+        .review_brokered_profile(ReviewBrokeredProfileInput {
+            realm_name: realm_name.clone(),
+            token,
+            profile: BrokeredProfile {
+                username: payload.username,
+                email: payload.email,
+                first_name: payload.first_name,
+                last_name: payload.last_name,
+            },
+        })
+        .await?;
+
+    step_response(&state, &realm_name, &base_url, outcome)
+}
+
+#[utoipa::path(
+    post,
+    path = "/broker/login-actions/first-login/send-code",
+    tag = "broker",
+    summary = "Email a code confirming the existing account",
+    description = "Sends a one-time code to the email of the account a brokered login would be linked to.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 204, description = "Code sent"),
+        (status = 400, description = "Unknown or expired brokered login, or no email can be sent", body = ApiErrorResponse),
+        (status = 401, description = "Missing broker login cookie", body = ApiErrorResponse),
+    )
+)]
+pub async fn send_link_confirmation_code(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    cookie: CookieManager,
+) -> Result<StatusCode, ApiError> {
+    let token = pending_token(&cookie)?;
+
+    state
+        .service
+        .send_link_confirmation_code(PendingBrokerLoginInput { realm_name, token })
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/broker/login-actions/first-login/confirm",
+    tag = "broker",
+    summary = "Confirm the link to an existing account",
+    description = "Links the brokered identity to the existing account once its password or the emailed code is confirmed.",
+    request_body = ConfirmBrokerLinkRequest,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Login completed or waiting on the next step", body = BrokerLoginStepResponse),
+        (status = 400, description = "Unknown or expired brokered login", body = ApiErrorResponse),
+        (status = 401, description = "Invalid password or confirmation code", body = ApiErrorResponse),
+    )
+)]
+pub async fn confirm_broker_link(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+    cookie: CookieManager,
+    ValidateJson(payload): ValidateJson<ConfirmBrokerLinkRequest>,
+) -> Result<Response, ApiError> {
+    let token = pending_token(&cookie)?;
+
+    let outcome = state
+        .service
+        .confirm_link(ConfirmBrokerLinkInput {
+            realm_name: realm_name.clone(),
+            token,
+            password: payload.password,
+            code: payload.code,
+        })
+        .await?;
+
+    step_response(&state, &realm_name, &base_url, outcome)
+}
+
+#[utoipa::path(
+    post,
+    path = "/broker/login-actions/first-login/otp",
+    tag = "broker",
+    summary = "Verify the one-time password of a brokered login",
+    request_body = VerifyBrokerLoginOtpRequest,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Login completed", body = BrokerLoginStepResponse),
+        (status = 400, description = "Unknown or expired brokered login", body = ApiErrorResponse),
+        (status = 401, description = "Invalid one-time password", body = ApiErrorResponse),
+    )
+)]
+pub async fn verify_broker_login_otp(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+    cookie: CookieManager,
+    ValidateJson(payload): ValidateJson<VerifyBrokerLoginOtpRequest>,
+) -> Result<Response, ApiError> {
+    let token = pending_token(&cookie)?;
+
+    let outcome = state
+        .service
+        .verify_login_otp(VerifyBrokerLoginOtpInput {
+            realm_name: realm_name.clone(),
+            token,
+            code: payload.code,
+        })
+        .await?;
+
+    step_response(&state, &realm_name, &base_url, outcome)
+}
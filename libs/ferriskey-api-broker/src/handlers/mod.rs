@@ -1,5 +1,12 @@
 pub mod callback;
+pub mod first_login;
 pub mod login;
+pub mod saml;
 
 pub use callback::broker_callback;
+pub use first_login::{
+    confirm_broker_link, get_pending_broker_login, review_brokered_profile,
+    send_link_confirmation_code, verify_broker_login_otp,
+};
 pub use login::broker_login;
+pub use saml::{broker_saml_metadata, broker_saml_response};
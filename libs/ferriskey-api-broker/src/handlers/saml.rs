@@ -0,0 +1,101 @@
+use axum::{
+    Form,
+    extract::{Path, State},
+    http::{StatusCode, header::CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+
+use ferriskey_core::domain::abyss::identity_provider::broker::{
+    BrokerSamlMetadataInput, BrokerSamlResponseInput, BrokerService,
+};
+
+use ferriskey_api_core::url::FullUrl;
+use ferriskey_api_core::{
+    api_entities::api_error::{ApiError, ApiErrorResponse},
+    app_state::AppState,
+};
+
+use super::super::validators::BrokerSamlResponseForm;
+use super::callback::login_outcome_response;
+
+/// Assertion consumer service for SAML identity providers
+///
+/// Receives the SAML Response posted by the identity provider (HTTP-POST binding),
+/// validates it against the AuthnRequest it answers, and continues the login exactly
+/// like the OAuth callback does.
+#[utoipa::path(
+    post,
+    path = "/broker/{alias}/endpoint",
+    tag = "broker",
+    summary = "Handle SAML response from identity provider",
+    description = "Validates the SAML Response posted by the IdP, finds or creates the user, and redirects to the client",
+    request_body(content = BrokerSamlResponseForm, content_type = "application/x-www-form-urlencoded"),
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("alias" = String, Path, description = "Identity provider alias"),
+    ),
+    responses(
+        (status = 302, description = "Redirect to client with authorization code, or to the login page for a pending step"),
+        (status = 400, description = "Invalid SAML message, unknown or expired login", body = ApiErrorResponse),
+        (status = 401, description = "Authentication failed at identity provider", body = ApiErrorResponse),
+    )
+)]
+pub async fn broker_saml_response(
+    Path((realm_name, alias)): Path<(String, String)>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+    Form(form): Form<BrokerSamlResponseForm>,
+) -> Result<Response, ApiError> {
+    let root_scoped_base_url = format!("{base_url}{}", state.args.server.root_path);
+
+    let result = state
+        .service
+        .handle_saml_response(BrokerSamlResponseInput {
+            realm_name: realm_name.clone(),
+            alias,
+            saml_response: form.saml_response,
+            base_url: root_scoped_base_url,
+        })
+        .await;
+
+    login_outcome_response(&state, &realm_name, &base_url, result)
+}
+
+/// Service provider metadata to register FerrisKey at a SAML identity provider
+#[utoipa::path(
+    get,
+    path = "/broker/{alias}/endpoint/descriptor",
+    tag = "broker",
+    summary = "Get SAML service provider metadata for an identity provider",
+    description = "Returns the SP EntityDescriptor: entity ID, assertion consumer service and the realm's signing certificate.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("alias" = String, Path, description = "Identity provider alias"),
+    ),
+    responses(
+        (status = 200, description = "SAML metadata document", content_type = "application/samlmetadata+xml", body = String),
+        (status = 400, description = "Identity provider is not a SAML provider", body = ApiErrorResponse),
+        (status = 404, description = "Realm or identity provider not found", body = ApiErrorResponse),
+    )
+)]
+pub async fn broker_saml_metadata(
+    Path((realm_name, alias)): Path<(String, String)>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+) -> Result<Response, ApiError> {
+    let metadata = state
+        .service
+        .get_saml_metadata(BrokerSamlMetadataInput {
+            realm_name,
+            alias,
+            base_url: format!("{base_url}{}", state.args.server.root_path),
+        })
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        [(CONTENT_TYPE, "application/samlmetadata+xml")],
+        metadata,
+    )
+        .into_response())
+}
@@ -1,17 +1,42 @@
-use axum::{Router, routing::get};
+use axum::{
+    Router,
+    routing::{get, post},
+};
 use utoipa::OpenApi;
 
 use ferriskey_api_core::app_state::AppState;
 
 use super::handlers::callback::__path_broker_callback;
+use super::handlers::first_login::{
+    __path_confirm_broker_link, __path_get_pending_broker_login, __path_review_brokered_profile,
+    __path_send_link_confirmation_code, __path_verify_broker_login_otp,
+};
 use super::handlers::login::__path_broker_login;
-use super::handlers::{broker_callback, broker_login};
+use super::handlers::saml::{__path_broker_saml_metadata, __path_broker_saml_response};
+use super::handlers::{
+    broker_callback, broker_login, broker_saml_metadata, broker_saml_response, confirm_broker_link,
+    get_pending_broker_login, review_brokered_profile, send_link_confirmation_code,
+    verify_broker_login_otp,
+};
 
 #[derive(OpenApi)]
-#[openapi(paths(broker_login, broker_callback))]
+#[openapi(paths(
+    broker_login,
+    broker_callback,
+    broker_saml_response,
+    broker_saml_metadata,
+    get_pending_broker_login,
+    review_brokered_profile,
+    send_link_confirmation_code,
+    confirm_broker_link,
+    verify_broker_login_otp
+))]
 pub struct BrokerApiDoc;
 
 pub fn broker_routes(state: AppState, root_path: &str) -> Router<AppState> {
+    let first_login_path =
+        format!("{root_path}/realms/{{realm_name}}/broker/login-actions/first-login");
+
     Router::new()
         .route(
             &format!("{root_path}/realms/{{realm_name}}/broker/{{alias}}/login"),
@@ -19,7 +44,28 @@ pub fn broker_routes(state: AppState, root_path: &str) -> Router<AppState> {
         )
         .route(
             &format!("{root_path}/realms/{{realm_name}}/broker/{{alias}}/endpoint"),
-            get(broker_callback),
+            get(broker_callback).post(broker_saml_response),
+        )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/broker/{{alias}}/endpoint/descriptor"),
+            get(broker_saml_metadata),
+        )
+        .route(&first_login_path, get(get_pending_broker_login))
+        .route(
+            &format!("{first_login_path}/profile"),
+            post(review_brokered_profile),
+        )
+        .route(
+            &format!("{first_login_path}/send-code"),
+            post(send_link_confirmation_code),
+        )
+        .route(
+            &format!("{first_login_path}/confirm"),
+            post(confirm_broker_link),
+        )
+        .route(
+            &format!("{first_login_path}/otp"),
+            post(verify_broker_login_otp),
         )
         .with_state(state)
 }
@@ -67,3 +67,51 @@ pub struct BrokerCallbackQuery {
     #[serde(default)]
     pub error_description: Option<String>,
 }
+
+/// Form posted by a SAML identity provider to the broker's assertion consumer service
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BrokerSamlResponseForm {
+    /// Base64-encoded SAML Response
+    #[serde(rename = "SAMLResponse")]
+    pub saml_response: String,
+
+    /// Opaque value echoed by the identity provider; the login is found via InResponseTo
+    #[serde(rename = "RelayState", default)]
+    pub relay_state: Option<String>,
+}
+
+/// Profile values submitted on the first-login review page
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ReviewBrokeredProfileRequest {
+    #[validate(length(min = 1, max = 255, message = "username must not be empty"))]
+    #[serde(default)]
+    pub username: Option<String>,
+
+    #[validate(email(message = "email must be a valid email address"))]
+    #[serde(default)]
+    pub email: Option<String>,
+
+    #[serde(default)]
+    pub first_name: Option<String>,
+
+    #[serde(default)]
+    pub last_name: Option<String>,
+}
+
+/// Proof that the existing account belongs to the user: its password or the emailed code
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ConfirmBrokerLinkRequest {
+    #[validate(length(min = 1, message = "password must not be empty"))]
+    #[serde(default)]
+    pub password: Option<String>,
+
+    #[validate(length(min = 1, message = "code must not be empty"))]
+    #[serde(default)]
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct VerifyBrokerLoginOtpRequest {
+    #[validate(length(equal = 6, message = "code must be 6 digits"))]
+    pub code: String,
+}
@@ -14,7 +14,11 @@ pub mod get_client_secret;
 pub mod get_clients;
 pub mod get_post_logout_redirect_uris;
 pub mod get_redirect_uris;
+pub mod get_saml_settings;
 pub mod get_web_origins;
+pub mod import_saml_metadata;
+pub mod rotate_client_secret;
 pub mod update_client;
 pub mod update_post_logout_redirect_uri;
 pub mod update_redirect_uri;
+pub mod update_saml_settings;
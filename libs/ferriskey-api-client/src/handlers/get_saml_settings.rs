@@ -0,0 +1,51 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::authentication::{
+    saml::{GetSamlClientSettingsInput, SamlClientSettings, SamlService},
+    value_objects::Identity,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    get,
+    path = "/{client_id}/saml",
+    summary = "Get the SAML settings of a client",
+    description = "Returns the service provider settings of a `saml` client: assertion consumer and logout endpoints, NameID format, signing and encryption options, certificates and IdP-initiated SSO.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("client_id" = Uuid, Path, description = "Client ID"),
+    ),
+    tag = "client",
+    responses(
+        (status = 200, body = SamlClientSettings),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Client not found, or no SAML settings saved yet", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_saml_settings(
+    Path((realm_name, client_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<SamlClientSettings>, ApiError> {
+    state
+        .service
+        .get_client_settings(
+            identity,
+            GetSamlClientSettingsInput {
+                realm_name,
+                client_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(Response::OK)
+}
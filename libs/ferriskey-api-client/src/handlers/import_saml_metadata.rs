@@ -0,0 +1,57 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::authentication::{
+    saml::{ImportSamlMetadataInput, SamlClientSettings, SamlService},
+    value_objects::Identity,
+};
+use uuid::Uuid;
+
+use crate::validators::ImportSamlMetadataValidator;
+
+#[utoipa::path(
+    post,
+    path = "/{client_id}/saml/metadata",
+    summary = "Import service provider metadata into a SAML client",
+    description = "Reads the SPSSODescriptor of the client's entity ID (its `client_id`) and overwrites the assertion consumer services, logout endpoint, certificates and request signing requirement. Options the metadata does not describe are kept.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("client_id" = Uuid, Path, description = "Client ID"),
+    ),
+    tag = "client",
+    request_body = ImportSamlMetadataValidator,
+    responses(
+        (status = 200, description = "Metadata imported", body = SamlClientSettings),
+        (status = 400, description = "Invalid metadata, or the client does not use the SAML protocol", body = ApiErrorResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Client not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn import_saml_metadata(
+    Path((realm_name, client_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<ImportSamlMetadataValidator>,
+) -> Result<Response<SamlClientSettings>, ApiError> {
+    state
+        .service
+        .import_client_metadata(
+            identity,
+            ImportSamlMetadataInput {
+                realm_name,
+                client_id,
+                metadata: payload.metadata,
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(Response::Updated)
+}
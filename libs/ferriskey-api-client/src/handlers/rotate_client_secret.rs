@@ -0,0 +1,50 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::{api_error::ApiError, response::Response};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client::entities::GetClientInput;
+use ferriskey_core::domain::client::ports::ClientService;
+use uuid::Uuid;
+
+use super::get_client_secret::ClientSecretResponse;
+
+#[utoipa::path(
+    post,
+    path = "/{client_id}/client-secret",
+    tag = "client",
+    summary = "Rotate a confidential client's secret",
+    description = "Replaces the secret of a confidential client with a new random one and returns it. The previous secret stops working immediately. Requires ManageClients (or ManageRealm) and records a `client_secret_rotated` security event.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("client_id" = Uuid, Path, description = "Client ID"),
+    ),
+    responses(
+        (status = 200, body = ClientSecretResponse),
+        (status = 400, description = "Public clients have no secret"),
+        (status = 403, description = "Insufficient permissions"),
+        (status = 404, description = "Client not found"),
+    )
+)]
+pub async fn rotate_client_secret(
+    Path((realm_name, client_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<ClientSecretResponse>, ApiError> {
+    let client_secret = state
+        .service
+        .rotate_client_secret(
+            identity,
+            GetClientInput {
+                client_id,
+                realm_name,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(ClientSecretResponse {
+        client_secret: Some(client_secret),
+    }))
+}
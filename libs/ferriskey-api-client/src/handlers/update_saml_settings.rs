@@ -0,0 +1,58 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::authentication::{
+    saml::{
+        SamlClientSettings, SamlService, UpdateSamlClientSettingsInput,
+        UpdateSamlClientSettingsRequest,
+    },
+    value_objects::Identity,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    put,
+    path = "/{client_id}/saml",
+    summary = "Replace the SAML settings of a client",
+    description = "Replaces the service provider settings of a `saml` client. At least one assertion consumer service is required, responses or assertions must be signed, encryption needs an encryption certificate and signed requests need a signing certificate. IdP-initiated SSO URL names are unique within the realm.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("client_id" = Uuid, Path, description = "Client ID"),
+    ),
+    tag = "client",
+    request_body = UpdateSamlClientSettingsRequest,
+    responses(
+        (status = 200, description = "SAML settings updated", body = SamlClientSettings),
+        (status = 400, description = "Invalid settings, IdP-initiated SSO URL name already in use, or the client does not use the SAML protocol", body = ApiErrorResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Client not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_saml_settings(
+    Path((realm_name, client_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(payload): Json<UpdateSamlClientSettingsRequest>,
+) -> Result<Response<SamlClientSettings>, ApiError> {
+    state
+        .service
+        .update_client_settings(
+            identity,
+            UpdateSamlClientSettingsInput {
+                realm_name,
+                client_id,
+                payload,
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(Response::Updated)
+}
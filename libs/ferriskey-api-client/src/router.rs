@@ -27,12 +27,16 @@ use super::handlers::{
         __path_get_post_logout_redirect_uris, get_post_logout_redirect_uris,
     },
     get_redirect_uris::{__path_get_redirect_uris, get_redirect_uris},
+    get_saml_settings::{__path_get_saml_settings, get_saml_settings},
     get_web_origins::{__path_get_web_origins, get_web_origins},
+    import_saml_metadata::{__path_import_saml_metadata, import_saml_metadata},
+    rotate_client_secret::{__path_rotate_client_secret, rotate_client_secret},
     update_client::{__path_update_client, update_client},
     update_post_logout_redirect_uri::{
         __path_update_post_logout_redirect_uri, update_post_logout_redirect_uri,
     },
     update_redirect_uri::{__path_update_redirect_uri, update_redirect_uri},
+    update_saml_settings::{__path_update_saml_settings, update_saml_settings},
 };
 use ferriskey_api_core::app_state::AppState;
 use ferriskey_api_core::auth::auth;
@@ -42,6 +46,7 @@ use ferriskey_api_core::auth::auth;
     paths(
         get_client,
         get_client_secret,
+        rotate_client_secret,
         get_clients,
         create_client,
         delete_client,
@@ -59,7 +64,10 @@ use ferriskey_api_core::auth::auth;
         evaluate_scopes,
         create_web_origin,
         get_web_origins,
-        delete_web_origin
+        delete_web_origin,
+        get_saml_settings,
+        update_saml_settings,
+        import_saml_metadata
     ),
 
     tags(
@@ -89,7 +97,7 @@ pub fn client_routes(state: AppState) -> Router<AppState> {
                 "{}/realms/{{realm_name}}/clients/{{client_id}}/client-secret",
                 state.args.server.root_path
             ),
-            get(get_client_secret),
+            get(get_client_secret).post(rotate_client_secret),
         )
         .route(
             &format!(
@@ -210,5 +218,19 @@ pub fn client_routes(state: AppState) -> Router<AppState> {
             ),
             delete(delete_web_origin),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/clients/{{client_id}}/saml",
+                state.args.server.root_path
+            ),
+            get(get_saml_settings).put(update_saml_settings),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/clients/{{client_id}}/saml/metadata",
+                state.args.server.root_path
+            ),
+            post(import_saml_metadata),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
@@ -92,3 +92,12 @@ pub struct CreateWebOriginValidator {
     #[serde(default)]
     pub value: String,
 }
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ImportSamlMetadataValidator {
+    /// `EntityDescriptor` (or an `EntitiesDescriptor` aggregate) describing the client's
+    /// entity ID.
+    #[validate(length(min = 1, message = "metadata is required"))]
+    #[serde(default)]
+    pub metadata: String,
+}
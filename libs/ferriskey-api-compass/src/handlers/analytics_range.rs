@@ -0,0 +1,43 @@
+use chrono::{DateTime, Duration, Utc};
+use ferriskey_api_core::api_entities::api_error::ApiError;
+
+/// Longest range the analytics endpoints aggregate over; hourly buckets stay under ~2200.
+pub const MAX_ANALYTICS_RANGE_DAYS: i64 = 92;
+
+fn parse_timestamp(value: &str, field: &str) -> Result<DateTime<Utc>, ApiError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| ApiError::BadRequest(format!("{field} must be an RFC 3339 timestamp").into()))
+}
+
+/// Resolves an optional `from`/`to` pair. `to` defaults to now and `from` to 24 hours
+/// before `to`.
+pub fn resolve_range(
+    from: Option<&str>,
+    to: Option<&str>,
+    from_field: &str,
+    to_field: &str,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), ApiError> {
+    let to_timestamp = match to {
+        Some(to) => parse_timestamp(to, to_field)?,
+        None => Utc::now(),
+    };
+    let from_timestamp = match from {
+        Some(from) => parse_timestamp(from, from_field)?,
+        None => to_timestamp - Duration::hours(24),
+    };
+
+    if from_timestamp >= to_timestamp {
+        return Err(ApiError::BadRequest(
+            format!("{from_field} must be earlier than {to_field}").into(),
+        ));
+    }
+
+    if to_timestamp - from_timestamp > Duration::days(MAX_ANALYTICS_RANGE_DAYS) {
+        return Err(ApiError::BadRequest(
+            format!("analytics range cannot exceed {MAX_ANALYTICS_RANGE_DAYS} days").into(),
+        ));
+    }
+
+    Ok((from_timestamp, to_timestamp))
+}
@@ -0,0 +1,109 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    compass::{
+        ports::CompassService,
+        value_objects::{FlowAnalyticsComparison, FlowAnalyticsFilter},
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use ferriskey_api_core::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+use super::analytics_range::resolve_range;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CompareFlowAnalyticsResponse {
+    data: FlowAnalyticsComparison,
+}
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CompareFlowAnalyticsQuery {
+    /// Start of the current range as an RFC 3339 timestamp. Defaults to 24 hours before `to`.
+    pub from: Option<String>,
+    /// End of the current range as an RFC 3339 timestamp. Defaults to now.
+    pub to: Option<String>,
+    /// Start of the baseline range. Defaults to a range as long as the current one, ending where it starts.
+    pub baseline_from: Option<String>,
+    /// End of the baseline range. Defaults to the start of the current range.
+    pub baseline_to: Option<String>,
+    pub client_id: Option<String>,
+    pub grant_type: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    summary = "Compare Login Funnel Analytics",
+    description = "Computes the flow analytics of a baseline and a current range, with the completion rate and latency percentile changes between them.",
+    path = "/compass/v1/analytics/compare",
+    tag = "compass",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        CompareFlowAnalyticsQuery,
+    ),
+    responses(
+        (status = 200, description = "Flow analytics compared successfully", body = CompareFlowAnalyticsResponse),
+        (status = 400, description = "Invalid time range", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn compare_flow_analytics(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<CompareFlowAnalyticsQuery>,
+) -> Result<Response<CompareFlowAnalyticsResponse>, ApiError> {
+    let (from_timestamp, to_timestamp) =
+        resolve_range(query.from.as_deref(), query.to.as_deref(), "from", "to")?;
+
+    let baseline_to = query
+        .baseline_to
+        .unwrap_or_else(|| from_timestamp.to_rfc3339());
+    let baseline_from = query
+        .baseline_from
+        .unwrap_or_else(|| (from_timestamp - (to_timestamp - from_timestamp)).to_rfc3339());
+    let (baseline_from, baseline_to) = resolve_range(
+        Some(&baseline_from),
+        Some(&baseline_to),
+        "baseline_from",
+        "baseline_to",
+    )?;
+
+    let comparison = state
+        .service
+        .compare_flow_analytics(
+            identity,
+            realm_name,
+            FlowAnalyticsFilter {
+                from_timestamp: baseline_from,
+                to_timestamp: baseline_to,
+                client_id: query.client_id.clone(),
+                grant_type: query.grant_type.clone(),
+            },
+            FlowAnalyticsFilter {
+                from_timestamp,
+                to_timestamp,
+                client_id: query.client_id,
+                grant_type: query.grant_type,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(CompareFlowAnalyticsResponse {
+        data: comparison,
+    }))
+}
@@ -0,0 +1,82 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+    http::{StatusCode, header::CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    compass::{ports::CompassService, value_objects::FlowAnalyticsFilter},
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use ferriskey_api_core::{
+    api_entities::api_error::{ApiError, ApiErrorResponse},
+    app_state::AppState,
+};
+
+use super::analytics_range::resolve_range;
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportFlowMetricsQuery {
+    /// Start of the range as an RFC 3339 timestamp. Defaults to 24 hours before `to`.
+    pub from: Option<String>,
+    /// End of the range as an RFC 3339 timestamp. Defaults to now.
+    pub to: Option<String>,
+    pub client_id: Option<String>,
+    pub grant_type: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    summary = "Export Login Funnel Metrics",
+    description = "Funnel, latency percentile and failure aggregates in the Prometheus text exposition format, for scraping.",
+    path = "/compass/v1/analytics/metrics",
+    tag = "compass",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ExportFlowMetricsQuery,
+    ),
+    responses(
+        (status = 200, description = "Prometheus metrics", content_type = "text/plain", body = String),
+        (status = 400, description = "Invalid time range", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn export_flow_metrics(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<ExportFlowMetricsQuery>,
+) -> Result<Response, ApiError> {
+    let (from_timestamp, to_timestamp) =
+        resolve_range(query.from.as_deref(), query.to.as_deref(), "from", "to")?;
+
+    let metrics = state
+        .service
+        .export_flow_metrics(
+            identity,
+            realm_name,
+            FlowAnalyticsFilter {
+                from_timestamp,
+                to_timestamp,
+                client_id: query.client_id,
+                grant_type: query.grant_type,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok((
+        StatusCode::OK,
+        [(CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        metrics,
+    )
+        .into_response())
+}
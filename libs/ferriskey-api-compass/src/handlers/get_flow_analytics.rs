@@ -0,0 +1,84 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    compass::{
+        ports::CompassService,
+        value_objects::{FlowAnalytics, FlowAnalyticsFilter},
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use ferriskey_api_core::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+use super::analytics_range::resolve_range;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GetFlowAnalyticsResponse {
+    data: FlowAnalytics,
+}
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetFlowAnalyticsQuery {
+    /// Start of the range, inclusive, as an RFC 3339 timestamp. Defaults to 24 hours before `to`.
+    pub from: Option<String>,
+    /// End of the range, exclusive, as an RFC 3339 timestamp. Defaults to now.
+    pub to: Option<String>,
+    pub client_id: Option<String>,
+    pub grant_type: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    summary = "Get Login Funnel Analytics",
+    description = "Funnel conversion per client and grant type, step latency percentiles, failure reasons and hourly buckets for a time range.",
+    path = "/compass/v1/analytics",
+    tag = "compass",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        GetFlowAnalyticsQuery,
+    ),
+    responses(
+        (status = 200, description = "Flow analytics retrieved successfully", body = GetFlowAnalyticsResponse),
+        (status = 400, description = "Invalid time range", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn get_flow_analytics(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<GetFlowAnalyticsQuery>,
+) -> Result<Response<GetFlowAnalyticsResponse>, ApiError> {
+    let (from_timestamp, to_timestamp) =
+        resolve_range(query.from.as_deref(), query.to.as_deref(), "from", "to")?;
+
+    let analytics = state
+        .service
+        .get_flow_analytics(
+            identity,
+            realm_name,
+            FlowAnalyticsFilter {
+                from_timestamp,
+                to_timestamp,
+                client_id: query.client_id,
+                grant_type: query.grant_type,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(GetFlowAnalyticsResponse { data: analytics }))
+}
@@ -1,4 +1,8 @@
+mod analytics_range;
+pub mod compare_flow_analytics;
+pub mod export_flow_metrics;
 pub mod get_daily_activity_stats;
 pub mod get_flow;
+pub mod get_flow_analytics;
 pub mod get_flows;
 pub mod get_stats;
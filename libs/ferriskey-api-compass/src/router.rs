@@ -2,8 +2,11 @@ use axum::{Router, middleware, routing::get};
 use utoipa::OpenApi;
 
 use crate::handlers::{
+    compare_flow_analytics::{__path_compare_flow_analytics, compare_flow_analytics},
+    export_flow_metrics::{__path_export_flow_metrics, export_flow_metrics},
     get_daily_activity_stats::{__path_get_daily_activity_stats, get_daily_activity_stats},
     get_flow::{__path_get_flow, get_flow},
+    get_flow_analytics::{__path_get_flow_analytics, get_flow_analytics},
     get_flows::{__path_get_flows, get_flows},
     get_stats::{__path_get_stats, get_stats},
 };
@@ -11,7 +14,15 @@ use ferriskey_api_core::app_state::AppState;
 use ferriskey_api_core::auth::auth;
 
 #[derive(OpenApi)]
-#[openapi(paths(get_flows, get_flow, get_stats, get_daily_activity_stats))]
+#[openapi(paths(
+    get_flows,
+    get_flow,
+    get_stats,
+    get_daily_activity_stats,
+    get_flow_analytics,
+    compare_flow_analytics,
+    export_flow_metrics
+))]
 pub struct CompassApiDoc;
 
 pub fn compass_routes(state: AppState) -> Router<AppState> {
@@ -44,5 +55,26 @@ pub fn compass_routes(state: AppState) -> Router<AppState> {
             ),
             get(get_daily_activity_stats),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/compass/v1/analytics",
+                state.args.server.root_path
+            ),
+            get(get_flow_analytics),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/compass/v1/analytics/compare",
+                state.args.server.root_path
+            ),
+            get(compare_flow_analytics),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/compass/v1/analytics/metrics",
+                state.args.server.root_path
+            ),
+            get(export_flow_metrics),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
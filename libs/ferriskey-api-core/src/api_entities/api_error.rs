@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use axum::{
     Json,
     extract::{Form, FromRequest, Request, rejection::FormRejection},
-    http::StatusCode,
+    http::{StatusCode, header::RETRY_AFTER},
     response::IntoResponse,
 };
 use ferriskey_core::domain::jwt::JwtError;
@@ -34,6 +34,11 @@ pub enum ApiError {
     Forbidden(Cow<'static, str>),
     BadRequest(Cow<'static, str>),
     ServiceUnavailable(Cow<'static, str>),
+    /// Answered with `429` and a `Retry-After` header.
+    TooManyRequests {
+        message: Cow<'static, str>,
+        retry_after_seconds: u64,
+    },
     /// RFC 6749 §5.2 OAuth2 error response
     OAuthError {
         error: Cow<'static, str>,
@@ -255,6 +260,19 @@ impl IntoResponse for ApiError {
                 }),
             )
                 .into_response(),
+            ApiError::TooManyRequests {
+                message,
+                retry_after_seconds,
+            } => (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(RETRY_AFTER, retry_after_seconds.to_string())],
+                Json(ApiErrorResponse {
+                    code: "E_TOO_MANY_REQUESTS".to_string(),
+                    status: 429,
+                    message: message.into(),
+                }),
+            )
+                .into_response(),
             ApiError::OAuthError {
                 error,
                 error_description,
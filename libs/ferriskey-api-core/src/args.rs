@@ -1,9 +1,10 @@
 #![allow(deprecated)]
 
-use std::{fmt::Display, path::PathBuf};
+use std::{fmt::Display, net::IpAddr, path::PathBuf};
 
 use clap::{Parser, Subcommand, ValueEnum};
 use ferriskey_core::domain::common::{DatabaseConfig, FerriskeyConfig};
+use ipnet::IpNet;
 use url::Url;
 
 #[derive(Debug, Clone, ValueEnum, Default)]
@@ -70,6 +71,45 @@ pub struct Args {
         long_help = "The url to the webapp to use"
     )]
     pub webapp_url: String,
+    #[arg(
+        long,
+        env,
+        long_help = "Path to a MaxMind-format (.mmdb) GeoIP database used by the login risk engine"
+    )]
+    pub geoip_database: Option<String>,
+    #[arg(
+        long,
+        env,
+        long_help = "Path to a FIDO Metadata Service BLOB used to verify WebAuthn attestations"
+    )]
+    pub fido_metadata_blob: Option<String>,
+    #[arg(
+        long,
+        env,
+        long_help = "Path to the root certificate (PEM or DER) the FIDO metadata BLOB is signed under"
+    )]
+    pub fido_metadata_root_certificate: Option<String>,
+    #[arg(
+        long,
+        env,
+        long_help = "Maildir directory emails are written to instead of being sent over SMTP, for local development"
+    )]
+    pub mail_maildir: Option<String>,
+    #[arg(
+        long,
+        env,
+        default_value_t = 72,
+        value_parser = clap::value_parser!(u32).range(1..),
+        long_help = "Hours sent and failed outbox emails, bodies included, are kept before being deleted"
+    )]
+    pub mail_outbox_retention_hours: u32,
+    #[arg(
+        long,
+        env,
+        default_value = "https://cloudflare-dns.com/dns-query",
+        long_help = "DNS-over-HTTPS endpoint serving the JSON API (as Cloudflare, Google and most resolvers do) that organization domains are verified through. Point it at an internal resolver when outbound DNS is restricted or split-horizon records must be seen"
+    )]
+    pub dns_over_https_endpoint: String,
     #[command(flatten)]
     pub observability: ObservabilityArgs,
     #[command(subcommand)]
@@ -85,6 +125,12 @@ impl Default for Args {
             log: LogArgs::default(),
             server: ServerArgs::default(),
             webapp_url: "http://localhost:5555".to_string(),
+            geoip_database: None,
+            fido_metadata_blob: None,
+            fido_metadata_root_certificate: None,
+            mail_maildir: None,
+            mail_outbox_retention_hours: 72,
+            dns_over_https_endpoint: "https://cloudflare-dns.com/dns-query".to_string(),
             observability: ObservabilityArgs::default(),
             command: None,
         }
@@ -283,6 +329,16 @@ pub struct ServerArgs {
         value_parser = parse_root_path,
     )]
     pub root_path: String,
+    #[arg(
+        long = "trusted-proxies",
+        env = "TRUSTED_PROXIES",
+        name = "TRUSTED_PROXIES",
+        num_args = 0..,
+        value_delimiter = ',',
+        value_parser = parse_trusted_proxy,
+        long_help = "Reverse proxies allowed to report the client address, as comma-separated CIDR ranges or addresses. When the TCP peer is one of them, the client address is the right-most X-Forwarded-For entry that is not: entries further left were written by the client and cannot be trusted. Rate limits and allow/deny lists are keyed by this address. Empty by default, which ignores the header."
+    )]
+    pub trusted_proxies: Vec<IpNet>,
     #[command(flatten)]
     pub tls: Option<ServerTlsArgs>,
 }
@@ -294,6 +350,7 @@ impl Default for ServerArgs {
             host: "0.0.0.0".into(),
             port: 3333,
             root_path: String::new(),
+            trusted_proxies: vec![],
             tls: None,
         }
     }
@@ -361,6 +418,16 @@ impl Default for ObservabilityArgs {
     }
 }
 
+/// A CIDR range, or a bare address as a single-host range.
+fn parse_trusted_proxy(value: &str) -> Result<IpNet, String> {
+    let value = value.trim();
+    value
+        .parse::<IpNet>()
+        .map(|network| network.trunc())
+        .or_else(|_| value.parse::<IpAddr>().map(IpNet::from))
+        .map_err(|_| format!("invalid CIDR range or address: {value}"))
+}
+
 fn parse_root_path(value: &str) -> Result<String, String> {
     let value = value.trim_end_matches('/');
     if value.is_empty() || value.starts_with('/') {
@@ -382,6 +449,12 @@ impl From<Args> for FerriskeyConfig {
                 schema: value.db.schema,
             },
             webapp_url: value.webapp_url,
+            geoip_database: value.geoip_database,
+            fido_metadata_blob: value.fido_metadata_blob,
+            fido_metadata_root_certificate: value.fido_metadata_root_certificate,
+            mail_maildir: value.mail_maildir,
+            mail_outbox_retention_hours: value.mail_outbox_retention_hours,
+            dns_over_https_endpoint: value.dns_over_https_endpoint,
         }
     }
 }
@@ -390,6 +463,27 @@ impl From<Args> for FerriskeyConfig {
 mod test {
     use super::*;
 
+    mod parse_trusted_proxy {
+        use super::*;
+
+        #[test]
+        fn cidr() {
+            let network = parse_trusted_proxy("10.1.2.3/8").unwrap();
+            assert_eq!(network, "10.0.0.0/8".parse::<IpNet>().unwrap());
+        }
+
+        #[test]
+        fn address() {
+            let network = parse_trusted_proxy(" 2001:db8::1 ").unwrap();
+            assert_eq!(network, "2001:db8::1/128".parse::<IpNet>().unwrap());
+        }
+
+        #[test]
+        fn invalid() {
+            assert!(parse_trusted_proxy("proxy.internal").is_err());
+        }
+    }
+
     mod parse_root_path {
         use super::*;
 
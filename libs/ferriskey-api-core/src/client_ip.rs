@@ -0,0 +1,134 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::extract::{ConnectInfo, FromRequestParts};
+use axum::http::{HeaderMap, header::USER_AGENT, request::Parts};
+use ipnet::IpNet;
+
+use crate::app_state::AppState;
+
+/// Address of the client that sent the request, as used by rate limits and allow/deny lists.
+///
+/// The TCP peer by default. When the peer is one of the `TRUSTED_PROXIES`, `X-Forwarded-For`
+/// is read from the right, skipping the trusted proxies that appended to it: the first other
+/// entry is the client. Entries left of it were written by the client and are ignored. `None`
+/// when there is no peer (e.g. in tests without connect info).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub Option<IpAddr>);
+
+impl FromRequestParts<AppState> for ClientIp {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+
+        Ok(ClientIp(client_ip(
+            &parts.headers,
+            peer,
+            &state.args.server.trusted_proxies,
+        )))
+    }
+}
+
+/// The `User-Agent` the client sent, when there is one and it is valid text. Like the client
+/// address, it only describes the request and is not trusted beyond risk scoring.
+pub fn user_agent(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn client_ip(
+    headers: &HeaderMap,
+    peer: Option<IpAddr>,
+    trusted_proxies: &[IpNet],
+) -> Option<IpAddr> {
+    let trusted = |ip: &IpAddr| trusted_proxies.iter().any(|network| network.contains(ip));
+
+    let mut client = peer?;
+    if !trusted(&client) {
+        return Some(client);
+    }
+
+    // Every header line counts, in order, as one list.
+    let hops = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .collect::<Vec<_>>();
+
+    for hop in hops.into_iter().rev() {
+        // A malformed entry cannot be attributed to anyone: keep the last hop vouched for.
+        let Ok(ip) = hop.trim().parse::<IpAddr>() else {
+            break;
+        };
+        client = ip;
+        if !trusted(&client) {
+            break;
+        }
+    }
+
+    Some(client)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(forwarded_for: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", forwarded_for.parse().unwrap());
+        headers
+    }
+
+    fn ip(value: &str) -> Option<IpAddr> {
+        Some(value.parse().unwrap())
+    }
+
+    #[test]
+    fn forwarded_for_is_ignored_unless_the_peer_is_trusted() {
+        let peer = ip("10.0.0.2");
+        let headers = headers("203.0.113.7, 10.0.0.1");
+
+        assert_eq!(client_ip(&headers, peer, &[]), peer);
+        assert_eq!(
+            client_ip(&headers, peer, &["192.168.0.0/16".parse().unwrap()]),
+            peer
+        );
+        assert_eq!(
+            client_ip(&headers, peer, &["10.0.0.0/8".parse().unwrap()]),
+            ip("203.0.113.7")
+        );
+    }
+
+    #[test]
+    fn forwarded_for_is_read_from_the_right() {
+        let trusted = ["10.0.0.0/8".parse().unwrap()];
+        let peer = ip("10.0.0.2");
+
+        // The client prepended a spoofed entry: the proxy appended the real address after it.
+        assert_eq!(
+            client_ip(&headers("1.2.3.4, 203.0.113.7, 10.0.0.1"), peer, &trusted),
+            ip("203.0.113.7")
+        );
+        // Only trusted proxies: the left-most one is as far as the chain goes.
+        assert_eq!(
+            client_ip(&headers("10.0.0.3, 10.0.0.1"), peer, &trusted),
+            ip("10.0.0.3")
+        );
+        assert_eq!(
+            client_ip(&headers("203.0.113.7, garbage, 10.0.0.1"), peer, &trusted),
+            ip("10.0.0.1")
+        );
+        assert_eq!(client_ip(&HeaderMap::new(), peer, &trusted), peer);
+    }
+}
@@ -2,6 +2,7 @@ use ferriskey_core::domain::{
     authentication::device_flow::error::DeviceFlowError, common::entities::app_errors::CoreError,
     credential::entities::CredentialError, password_policy::error::PasswordPolicyViolation,
     portal_theme::validation::MissingBlocks, user::entities::RequiredAction,
+    user_profile::UserProfileViolation,
 };
 use serde_json::{from_str, to_string};
 
@@ -180,6 +181,7 @@ impl From<CoreError> for ApiError {
             CoreError::Database(msg) => Self::InternalServerError(format!("Database error: {}", msg).into()),
             CoreError::Configuration(msg) => Self::InternalServerError(format!("Configuration error: {}", msg).into()),
             CoreError::FederationAuthenticationFailed(msg) => Self::Unauthorized(format!("Federation authentication error: {}", msg).into()),
+            CoreError::InvalidSamlRequest(msg) => Self::BadRequest(format!("Invalid SAML message: {}", msg).into()),
 
             // Broker (SSO) errors
             CoreError::BrokerSessionNotFound => {
@@ -206,6 +208,12 @@ impl From<CoreError> for ApiError {
             CoreError::LinkOnlyUserNotFound => {
                 Self::Forbidden("No existing account found for linking".into())
             }
+            CoreError::BrokerLinkConfirmationFailed => {
+                Self::Unauthorized("Invalid password or confirmation code".into())
+            }
+            CoreError::BrokerLoginOtpNotConfigured => {
+                Self::Forbidden("An OTP must be configured on this account before signing in with this provider".into())
+            }
             CoreError::LinkNotFound => {
                 Self::NotFound("Identity provider link not found".into())
             }
@@ -232,10 +240,19 @@ impl From<CoreError> for ApiError {
             CoreError::AccountLocked => Self::Unauthorized(
                 "Account is temporarily locked due to too many failed login attempts".into(),
             ),
+            CoreError::RateLimited {
+                retry_after_seconds,
+            } => Self::TooManyRequests {
+                message: "Too many requests".into(),
+                retry_after_seconds,
+            },
             CoreError::ClientUnderMaintenance(reason) => Self::ServiceUnavailable(reason.into()),
             CoreError::EmailTemplateNotFound => {
                 Self::NotFound("Email template not found".into())
             }
+            CoreError::EmailTemplateTypeMismatch(email_type) => {
+                Self::BadRequest(format!("Email template is not a {email_type} template").into())
+            }
             CoreError::NoActiveEmailTemplate(email_type) => {
                 Self::NotFound(format!("No active email template for type: {email_type}").into())
             }
@@ -299,6 +316,45 @@ impl From<CoreError> for ApiError {
                     }]),
                 }
             }
+            CoreError::UserProfileViolation(details) => {
+                match from_str::<Vec<UserProfileViolation>>(&details) {
+                    Ok(violations) => Self::validation_errors(
+                        violations
+                            .into_iter()
+                            .map(|v| ValidationError {
+                                field: v.attribute.into(),
+                                message: v.message.into(),
+                            })
+                            .collect(),
+                    ),
+                    Err(_) => Self::validation_errors(vec![ValidationError {
+                        field: "attributes".into(),
+                        message: details.into(),
+                    }]),
+                }
+            }
+            CoreError::InvalidLocalization(details) => {
+                Self::BadRequest(format!("Invalid localization: {details}").into())
+            }
+            CoreError::InvalidWebAuthnPolicy(details) => {
+                Self::BadRequest(format!("Invalid WebAuthn policy: {details}").into())
+            }
+            CoreError::WebAuthnAuthenticatorRejected(reason) => Self::Forbidden(
+                format!("This authenticator is not allowed in this realm: {reason}").into(),
+            ),
+            CoreError::InvalidMaintenanceWindow(details) => {
+                Self::BadRequest(format!("Invalid maintenance window: {details}").into())
+            }
+            CoreError::OutboxEmailNotFound => Self::NotFound("Outbox email not found".into()),
+            CoreError::OutboxEmailStillQueued => Self::BadRequest(
+                "The email is still queued for delivery and cannot be resent".into(),
+            ),
+            CoreError::InvalidOrganizationInvitation => Self::BadRequest(
+                "Invitation is invalid, expired, revoked or already accepted".into(),
+            ),
+            CoreError::DomainVerificationFailed(reason) => {
+                Self::BadRequest(format!("Domain verification failed: {reason}").into())
+            }
             // PKCE errors (RFC 7636) → OAuth2 invalid_request / invalid_grant
             CoreError::PkceRequired => Self::OAuthError {
                 error: "invalid_request".into(),
@@ -413,6 +469,8 @@ impl From<CredentialError> for ApiError {
 
 #[cfg(test)]
 mod tests {
+    use axum::response::IntoResponse;
+
     use super::*;
 
     #[test]
@@ -424,4 +482,15 @@ mod tests {
             ApiError::BadRequest("Username already exists in this realm".into())
         );
     }
+
+    #[test]
+    fn maps_rate_limited_to_too_many_requests_with_retry_after() {
+        let response = ApiError::from(CoreError::RateLimited {
+            retry_after_seconds: 42,
+        })
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[axum::http::header::RETRY_AFTER], "42");
+    }
 }
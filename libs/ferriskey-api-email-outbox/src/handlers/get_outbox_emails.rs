@@ -0,0 +1,64 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_api_core::api_entities::api_error::{ApiError, ApiErrorResponse};
+use ferriskey_api_core::api_entities::response::Response;
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::email_outbox::entities::{OutboxEmail, OutboxEmailStatus};
+use ferriskey_core::domain::email_outbox::ports::{EmailOutboxService, GetOutboxEmailsInput};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetOutboxEmailsQuery {
+    /// Only emails in this delivery status.
+    pub status: Option<OutboxEmailStatus>,
+    /// Maximum number of emails to return (1-500, default 50).
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetOutboxEmailsResponse {
+    pub data: Vec<OutboxEmail>,
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "email-outbox",
+    summary = "List recent emails",
+    description = "Returns the most recent emails of the realm with their delivery status, attempts and last error, newest first. Bodies are not returned: they carry sign-in and password reset links.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        GetOutboxEmailsQuery,
+    ),
+    responses(
+        (status = 200, description = "Recent emails", body = GetOutboxEmailsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_outbox_emails(
+    Path(realm_name): Path<String>,
+    Query(query): Query<GetOutboxEmailsQuery>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetOutboxEmailsResponse>, ApiError> {
+    let emails = state
+        .service
+        .get_outbox_emails(
+            identity,
+            GetOutboxEmailsInput {
+                realm_name,
+                status: query.status,
+                limit: query.limit,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(GetOutboxEmailsResponse { data: emails }))
+}
@@ -0,0 +1,2 @@
+pub mod get_outbox_emails;
+pub mod resend_outbox_email;
@@ -0,0 +1,48 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::api_error::{ApiError, ApiErrorResponse};
+use ferriskey_api_core::api_entities::response::Response;
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::email_outbox::entities::OutboxEmail;
+use ferriskey_core::domain::email_outbox::ports::{EmailOutboxService, ResendOutboxEmailInput};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/{email_id}/resend",
+    tag = "email-outbox",
+    summary = "Resend an email",
+    description = "Queues a sent or failed email again with a fresh attempt budget. Emails still pending or being sent cannot be resent.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("email_id" = Uuid, Path, description = "Outbox email ID"),
+    ),
+    responses(
+        (status = 200, description = "Email queued again", body = OutboxEmail),
+        (status = 400, description = "Email still queued", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Email not found", body = ApiErrorResponse),
+    ),
+)]
+pub async fn resend_outbox_email(
+    Path((realm_name, email_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<OutboxEmail>, ApiError> {
+    state
+        .service
+        .resend_outbox_email(
+            identity,
+            ResendOutboxEmailInput {
+                realm_name,
+                email_id,
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
@@ -0,0 +1,35 @@
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+use utoipa::OpenApi;
+
+use ferriskey_api_core::{app_state::AppState, auth::auth};
+
+use super::handlers::{
+    get_outbox_emails::{__path_get_outbox_emails, get_outbox_emails},
+    resend_outbox_email::{__path_resend_outbox_email, resend_outbox_email},
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(get_outbox_emails, resend_outbox_email))]
+pub struct EmailOutboxApiDoc;
+
+pub fn email_outbox_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/emails",
+                state.args.server.root_path
+            ),
+            get(get_outbox_emails),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/emails/{{email_id}}/resend",
+                state.args.server.root_path
+            ),
+            post(resend_outbox_email),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
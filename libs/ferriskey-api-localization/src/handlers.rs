@@ -0,0 +1,3 @@
+pub mod bundles;
+pub mod messages;
+pub mod settings;
@@ -0,0 +1,141 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::localization::{
+    Locale, LocalizationService, MessageBundle, PutMessageBundleInput,
+};
+
+use crate::validators::PutMessageBundleValidator;
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/localization/bundles",
+    tag = "localization",
+    summary = "List the realm's message bundles",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Message bundles", body = Vec<MessageBundle>),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_message_bundles(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<Vec<MessageBundle>>, ApiError> {
+    state
+        .service
+        .list_message_bundles(identity, realm_name)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    get,
+    path = "/localization/bundles/{locale}",
+    tag = "localization",
+    summary = "Get the realm's message bundle for a locale",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("locale" = String, Path, description = "Locale, e.g. `fr` or `pt-BR`"),
+    ),
+    responses(
+        (status = 200, description = "Message bundle", body = MessageBundle),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_message_bundle(
+    Path((realm_name, locale)): Path<(String, Locale)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<MessageBundle>, ApiError> {
+    state
+        .service
+        .get_message_bundle(identity, realm_name, locale)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    put,
+    path = "/localization/bundles/{locale}",
+    tag = "localization",
+    summary = "Replace the realm's message bundle for a locale",
+    description = "Messages are keyed like `login.title` or `email.magic_link.subject` and may use `{{variable}}` placeholders. The locale must be supported by the realm.",
+    request_body = PutMessageBundleValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("locale" = String, Path, description = "Locale, e.g. `fr` or `pt-BR`"),
+    ),
+    responses(
+        (status = 200, description = "Message bundle saved", body = MessageBundle),
+        (status = 400, description = "Unsupported locale or invalid message", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn put_message_bundle(
+    Path((realm_name, locale)): Path<(String, Locale)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<PutMessageBundleValidator>,
+) -> Result<Response<MessageBundle>, ApiError> {
+    state
+        .service
+        .put_message_bundle(
+            identity,
+            PutMessageBundleInput {
+                realm_name,
+                locale,
+                messages: payload.messages,
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/localization/bundles/{locale}",
+    tag = "localization",
+    summary = "Delete the realm's message bundle for a locale",
+    description = "The locale falls back to the built-in texts.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("locale" = String, Path, description = "Locale, e.g. `fr` or `pt-BR`"),
+    ),
+    responses(
+        (status = 204, description = "Message bundle deleted"),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn delete_message_bundle(
+    Path((realm_name, locale)): Path<(String, Locale)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .service
+        .delete_message_bundle(identity, realm_name, locale)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiError::from)
+}
@@ -0,0 +1,68 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, header::ACCEPT_LANGUAGE},
+};
+use axum_cookie::CookieManager;
+use ferriskey_core::domain::localization::{
+    GetPortalMessagesInput, LOCALE_COOKIE, LocaleHints, LocalizationService, PortalMessages,
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PortalMessagesQuery {
+    /// Space-separated locales, most preferred first, as forwarded from the authorization
+    /// request.
+    #[serde(default)]
+    pub ui_locales: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/localization/messages",
+    tag = "localization",
+    summary = "Get the login portal texts in the visitor's language",
+    description = "The locale comes from `ui_locales`, then the `FERRISKEY_LOCALE` cookie, then the `Accept-Language` header, then the realm default. Public, like the portal that renders the texts.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        PortalMessagesQuery,
+    ),
+    responses(
+        (status = 200, description = "Portal texts", body = PortalMessages),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_portal_messages(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Query(query): Query<PortalMessagesQuery>,
+    cookie: CookieManager,
+    headers: HeaderMap,
+) -> Result<Response<PortalMessages>, ApiError> {
+    let hints = LocaleHints {
+        ui_locales: query.ui_locales,
+        cookie: cookie
+            .get(LOCALE_COOKIE)
+            .map(|cookie| cookie.value().to_string()),
+        user_locale: None,
+        accept_language: headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string),
+    };
+
+    state
+        .service
+        .get_portal_messages(GetPortalMessagesInput { realm_name, hints })
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
@@ -0,0 +1,82 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::localization::{
+    LocalizationService, LocalizationSettings, UpdateLocalizationSettingsInput,
+};
+
+use crate::validators::UpdateLocalizationSettingsValidator;
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/localization",
+    tag = "localization",
+    summary = "Get the realm's localization settings",
+    description = "Realms that never configured localization support English only.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Localization settings", body = LocalizationSettings),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_localization_settings(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<LocalizationSettings>, ApiError> {
+    state
+        .service
+        .get_localization_settings(identity, realm_name)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    put,
+    path = "/localization",
+    tag = "localization",
+    summary = "Set the realm's supported and default locales",
+    description = "Bundles of locales dropped from the list are kept and apply again once the locale is re-added.",
+    request_body = UpdateLocalizationSettingsValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Localization settings updated", body = LocalizationSettings),
+        (status = 400, description = "Default locale not supported", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 422, description = "Invalid locale", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_localization_settings(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateLocalizationSettingsValidator>,
+) -> Result<Response<LocalizationSettings>, ApiError> {
+    state
+        .service
+        .update_localization_settings(
+            identity,
+            UpdateLocalizationSettingsInput {
+                realm_name,
+                supported_locales: payload.supported_locales,
+                default_locale: payload.default_locale,
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
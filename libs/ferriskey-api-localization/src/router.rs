@@ -0,0 +1,69 @@
+use axum::{Router, middleware, routing::get};
+use utoipa::OpenApi;
+
+use ferriskey_api_core::{app_state::AppState, auth::auth};
+
+use super::handlers::{
+    bundles::{
+        __path_delete_message_bundle, __path_get_message_bundle, __path_list_message_bundles,
+        __path_put_message_bundle, delete_message_bundle, get_message_bundle, list_message_bundles,
+        put_message_bundle,
+    },
+    messages::{__path_get_portal_messages, get_portal_messages},
+    settings::{
+        __path_get_localization_settings, __path_update_localization_settings,
+        get_localization_settings, update_localization_settings,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_localization_settings,
+    update_localization_settings,
+    list_message_bundles,
+    get_message_bundle,
+    put_message_bundle,
+    delete_message_bundle,
+    get_portal_messages
+))]
+pub struct LocalizationApiDoc;
+
+pub fn localization_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/localization",
+                state.args.server.root_path
+            ),
+            get(get_localization_settings).put(update_localization_settings),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/localization/bundles",
+                state.args.server.root_path
+            ),
+            get(list_message_bundles),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/localization/bundles/{{locale}}",
+                state.args.server.root_path
+            ),
+            get(get_message_bundle)
+                .put(put_message_bundle)
+                .delete(delete_message_bundle),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+        .merge(public_localization_routes(&state))
+}
+
+/// Routes the login portal calls before anyone is signed in.
+fn public_localization_routes(state: &AppState) -> Router<AppState> {
+    Router::new().route(
+        &format!(
+            "{}/realms/{{realm_name}}/localization/messages",
+            state.args.server.root_path
+        ),
+        get(get_portal_messages),
+    )
+}
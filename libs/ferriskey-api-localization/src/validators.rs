@@ -0,0 +1,21 @@
+use std::collections::BTreeMap;
+
+use ferriskey_core::domain::localization::Locale;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateLocalizationSettingsValidator {
+    #[validate(length(min = 1, message = "at least one locale must be supported"))]
+    pub supported_locales: Vec<Locale>,
+    /// Must be one of `supported_locales`.
+    pub default_locale: Locale,
+}
+
+/// Keys the bundle leaves out fall back to the built-in texts.
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct PutMessageBundleValidator {
+    #[serde(default)]
+    pub messages: BTreeMap<String, String>,
+}
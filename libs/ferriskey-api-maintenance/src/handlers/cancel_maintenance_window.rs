@@ -0,0 +1,42 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::api_error::{ApiError, ApiErrorResponse};
+use ferriskey_api_core::api_entities::response::Response;
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::maintenance::entities::MaintenanceWindow;
+use ferriskey_core::domain::maintenance::ports::MaintenanceService;
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    path = "/windows/{window_id}",
+    tag = "maintenance",
+    summary = "Cancel a maintenance window",
+    description = "Cancels a scheduled window. An active window ends now: the clients it put under maintenance are released.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("window_id" = Uuid, Path, description = "Maintenance window ID"),
+    ),
+    responses(
+        (status = 200, description = "Maintenance window cancelled", body = MaintenanceWindow),
+        (status = 400, description = "The window is already over", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Maintenance window not found", body = ApiErrorResponse),
+    ),
+)]
+pub async fn cancel_maintenance_window(
+    Path((realm_name, window_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<MaintenanceWindow>, ApiError> {
+    state
+        .service
+        .cancel_maintenance_window(identity, realm_name, window_id)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
@@ -0,0 +1,52 @@
+use axum::extract::{Path, Query, State};
+use ferriskey_api_core::api_entities::api_error::{ApiError, ApiErrorResponse};
+use ferriskey_api_core::api_entities::response::Response;
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::maintenance::entities::MaintenanceAnnouncement;
+use ferriskey_core::domain::maintenance::ports::MaintenanceService;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct MaintenanceAnnouncementsQuery {
+    /// The OAuth `client_id` of the login request; other clients' windows are left out.
+    #[serde(default)]
+    pub client_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetMaintenanceAnnouncementsResponse {
+    pub data: Vec<MaintenanceAnnouncement>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/announcements",
+    tag = "maintenance",
+    summary = "Get announced maintenance windows",
+    description = "Upcoming and in-progress maintenance windows past their announcement time, for the login page banner. Public, like the login page.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        MaintenanceAnnouncementsQuery,
+    ),
+    responses(
+        (status = 200, description = "Announced maintenance windows", body = GetMaintenanceAnnouncementsResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_maintenance_announcements(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Query(query): Query<MaintenanceAnnouncementsQuery>,
+) -> Result<Response<GetMaintenanceAnnouncementsResponse>, ApiError> {
+    let announcements = state
+        .service
+        .get_maintenance_announcements(realm_name, query.client_id)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(GetMaintenanceAnnouncementsResponse {
+        data: announcements,
+    }))
+}
@@ -7,3 +7,8 @@ pub mod remove_client_whitelist_entry;
 pub mod add_realm_whitelist_entry;
 pub mod get_realm_whitelist;
 pub mod remove_realm_whitelist_entry;
+
+pub mod cancel_maintenance_window;
+pub mod get_maintenance_announcements;
+pub mod get_maintenance_windows;
+pub mod schedule_maintenance_window;
@@ -0,0 +1,55 @@
+use crate::validators::ScheduleMaintenanceWindowValidator;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::api_error::{ApiError, ApiErrorResponse, ValidateJson};
+use ferriskey_api_core::api_entities::response::Response;
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::maintenance::entities::MaintenanceWindow;
+use ferriskey_core::domain::maintenance::ports::MaintenanceService;
+use ferriskey_core::domain::maintenance::value_objects::ScheduleMaintenanceWindowRequest;
+
+#[utoipa::path(
+    post,
+    path = "/windows",
+    tag = "maintenance",
+    summary = "Schedule a maintenance window",
+    description = "Plans maintenance of one client, or of every client of the realm when `client_id` is omitted. Maintenance is switched on at `starts_at` and off at `ends_at`, and the window is announced on the login page from `announce_at`. Windows of the same scope must not overlap.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    request_body = ScheduleMaintenanceWindowValidator,
+    responses(
+        (status = 201, description = "Maintenance window scheduled", body = MaintenanceWindow),
+        (status = 400, description = "Invalid or overlapping window", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Client not found", body = ApiErrorResponse),
+    ),
+)]
+pub async fn schedule_maintenance_window(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<ScheduleMaintenanceWindowValidator>,
+) -> Result<Response<MaintenanceWindow>, ApiError> {
+    state
+        .service
+        .schedule_maintenance_window(
+            identity,
+            realm_name,
+            ScheduleMaintenanceWindowRequest {
+                client_id: payload.client_id,
+                reason: payload.reason,
+                session_strategy: payload.session_strategy,
+                announce_at: payload.announce_at,
+                starts_at: payload.starts_at,
+                ends_at: payload.ends_at,
+            },
+        )
+        .await
+        .map(Response::Created)
+        .map_err(ApiError::from)
+}
@@ -4,7 +4,16 @@ use super::handlers::add_client_whitelist_entry::{
 use super::handlers::add_realm_whitelist_entry::{
     __path_add_realm_whitelist_entry, add_realm_whitelist_entry,
 };
+use super::handlers::cancel_maintenance_window::{
+    __path_cancel_maintenance_window, cancel_maintenance_window,
+};
 use super::handlers::get_client_whitelist::{__path_get_client_whitelist, get_client_whitelist};
+use super::handlers::get_maintenance_announcements::{
+    __path_get_maintenance_announcements, get_maintenance_announcements,
+};
+use super::handlers::get_maintenance_windows::{
+    __path_get_maintenance_windows, get_maintenance_windows,
+};
 use super::handlers::get_realm_whitelist::{__path_get_realm_whitelist, get_realm_whitelist};
 use super::handlers::remove_client_whitelist_entry::{
     __path_remove_client_whitelist_entry, remove_client_whitelist_entry,
@@ -12,6 +21,9 @@ use super::handlers::remove_client_whitelist_entry::{
 use super::handlers::remove_realm_whitelist_entry::{
     __path_remove_realm_whitelist_entry, remove_realm_whitelist_entry,
 };
+use super::handlers::schedule_maintenance_window::{
+    __path_schedule_maintenance_window, schedule_maintenance_window,
+};
 use super::handlers::toggle_maintenance::{__path_toggle_maintenance, toggle_maintenance};
 use ferriskey_api_core::app_state::AppState;
 use ferriskey_api_core::auth::auth;
@@ -34,6 +46,15 @@ use utoipa::OpenApi;
 ))]
 pub struct MaintenanceApiDoc;
 
+#[derive(OpenApi)]
+#[openapi(paths(
+    schedule_maintenance_window,
+    get_maintenance_windows,
+    cancel_maintenance_window,
+    get_maintenance_announcements,
+))]
+pub struct MaintenanceWindowApiDoc;
+
 pub fn maintenance_routes(state: AppState) -> Router<AppState> {
     Router::new()
         // Client maintenance toggle
@@ -74,5 +95,32 @@ pub fn maintenance_routes(state: AppState) -> Router<AppState> {
             ),
             delete(remove_realm_whitelist_entry),
         )
+        // Scheduled maintenance windows
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/maintenance/windows",
+                state.args.server.root_path
+            ),
+            get(get_maintenance_windows).post(schedule_maintenance_window),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/maintenance/windows/{{window_id}}",
+                state.args.server.root_path
+            ),
+            delete(cancel_maintenance_window),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
+        .merge(public_maintenance_routes(&state))
+}
+
+/// Routes the login portal calls before anyone is signed in.
+fn public_maintenance_routes(state: &AppState) -> Router<AppState> {
+    Router::new().route(
+        &format!(
+            "{}/realms/{{realm_name}}/maintenance/announcements",
+            state.args.server.root_path
+        ),
+        get(get_maintenance_announcements),
+    )
 }
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use ferriskey_core::domain::client::entities::MaintenanceSessionStrategy;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -23,3 +24,24 @@ pub struct AddWhitelistEntryValidator {
     #[serde(default)]
     pub role_id: Option<Uuid>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ScheduleMaintenanceWindowValidator {
+    /// The client to put under maintenance; omit it for every client of the realm.
+    #[serde(default)]
+    pub client_id: Option<Uuid>,
+
+    #[validate(length(min = 1, max = 500, message = "reason must be 1 to 500 characters"))]
+    pub reason: String,
+
+    #[serde(default)]
+    pub session_strategy: Option<MaintenanceSessionStrategy>,
+
+    /// When the window starts being announced on the login page. Defaults to now.
+    #[serde(default)]
+    pub announce_at: Option<DateTime<Utc>>,
+
+    pub starts_at: DateTime<Utc>,
+
+    pub ends_at: DateTime<Utc>,
+}
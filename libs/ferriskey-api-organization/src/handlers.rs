@@ -2,8 +2,10 @@ pub mod add_member;
 pub mod create_organization;
 pub mod delete_attribute;
 pub mod delete_organization;
+pub mod domain;
 pub mod get_organization;
 pub mod groups;
+pub mod invitations;
 pub mod list_attributes;
 pub mod list_members;
 pub mod list_organizations;
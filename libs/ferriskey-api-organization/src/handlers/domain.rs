@@ -0,0 +1,159 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::organization::ports::{
+    ConfigureOrganizationDomainInput, DiscoverHomeRealmInput, GetOrganizationDomainInput,
+    HomeRealmDiscovery, OrganizationDomainService, OrganizationDomainStatus, OrganizationId,
+    VerifyOrganizationDomainInput,
+};
+use uuid::Uuid;
+
+use crate::validators::{ConfigureDomainValidator, DiscoverQuery};
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/{organization_id}/domain",
+    tag = "organization",
+    summary = "Get the verification state of the organization domain",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("organization_id" = Uuid, Path, description = "Organization ID"),
+    ),
+    responses(
+        (status = 200, description = "Domain verification state", body = OrganizationDomainStatus),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Organization or domain not found", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_organization_domain(
+    Path((realm_name, organization_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<OrganizationDomainStatus>, ApiError> {
+    state
+        .service
+        .get_organization_domain(
+            identity,
+            GetOrganizationDomainInput {
+                realm_name,
+                organization_id: OrganizationId::new(organization_id),
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    put,
+    path = "/{organization_id}/domain",
+    tag = "organization",
+    summary = "Start verification of the organization domain and link its identity provider",
+    request_body = ConfigureDomainValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("organization_id" = Uuid, Path, description = "Organization ID"),
+    ),
+    responses(
+        (status = 200, description = "TXT record to publish", body = OrganizationDomainStatus),
+        (status = 400, description = "The organization has no domain", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Organization or identity provider not found", body = ApiErrorResponse),
+    ),
+)]
+pub async fn configure_organization_domain(
+    Path((realm_name, organization_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<ConfigureDomainValidator>,
+) -> Result<Response<OrganizationDomainStatus>, ApiError> {
+    state
+        .service
+        .configure_organization_domain(
+            identity,
+            ConfigureOrganizationDomainInput {
+                realm_name,
+                organization_id: OrganizationId::new(organization_id),
+                identity_provider_alias: payload.identity_provider_alias,
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    post,
+    path = "/{organization_id}/domain/verify",
+    tag = "organization",
+    summary = "Check the DNS TXT record proving control of the organization domain",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("organization_id" = Uuid, Path, description = "Organization ID"),
+    ),
+    responses(
+        (status = 200, description = "Domain verified", body = OrganizationDomainStatus),
+        (status = 400, description = "The TXT record is missing or wrong, or another organization verified the domain", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Organization or domain not found", body = ApiErrorResponse),
+        (status = 503, description = "DNS lookup failed", body = ApiErrorResponse),
+    ),
+)]
+pub async fn verify_organization_domain(
+    Path((realm_name, organization_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<OrganizationDomainStatus>, ApiError> {
+    state
+        .service
+        .verify_organization_domain(
+            identity,
+            VerifyOrganizationDomainInput {
+                realm_name,
+                organization_id: OrganizationId::new(organization_id),
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    get,
+    path = "/discover",
+    tag = "organization",
+    summary = "Find the identity provider a user signs in with from their email domain",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("email" = String, Query, description = "Email typed on the login page"),
+    ),
+    responses(
+        (status = 200, description = "Provider to redirect to, or null when the domain is not routed", body = Option<HomeRealmDiscovery>),
+        (status = 400, description = "Unknown realm", body = ApiErrorResponse),
+    ),
+)]
+pub async fn discover_home_realm(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Query(query): Query<DiscoverQuery>,
+) -> Result<Response<Option<HomeRealmDiscovery>>, ApiError> {
+    state
+        .service
+        .discover_home_realm(DiscoverHomeRealmInput {
+            realm_name,
+            email: query.email,
+        })
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
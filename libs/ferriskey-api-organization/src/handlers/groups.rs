@@ -233,8 +233,8 @@ pub async fn delete_group(
         ("realm_name" = String, Path, description = "Realm name"),
         ("organization_id" = Uuid, Path, description = "Organization ID"),
         ("group_id" = Uuid, Path, description = "Group ID"),
+        ListGroupMembersQuery,
     ),
-    params(ListGroupMembersQuery),
     responses(
         (status = 200, description = "Members page", body = GroupMemberPage),
         (status = 404, description = "Not found", body = ApiErrorResponse),
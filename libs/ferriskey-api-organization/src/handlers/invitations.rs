@@ -0,0 +1,202 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::organization::ports::{
+    AcceptInvitationInput, AcceptedInvitation, CreateInvitationInput, GetInvitationInput, GroupId,
+    InvitationPreview, ListInvitationsInput, OrganizationId, OrganizationInvitation,
+    OrganizationInvitationService, RevokeInvitationInput,
+};
+use uuid::Uuid;
+
+use crate::validators::{
+    AcceptInvitationValidator, CreateInvitationValidator, InvitationTokenQuery,
+};
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    post,
+    path = "/{organization_id}/invitations",
+    tag = "organization",
+    summary = "Invite someone to an organization by email",
+    request_body = CreateInvitationValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("organization_id" = Uuid, Path, description = "Organization ID"),
+    ),
+    responses(
+        (status = 201, description = "Invitation created and emailed", body = OrganizationInvitation),
+        (status = 400, description = "Invalid request data", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Organization, role or group not found", body = ApiErrorResponse),
+        (status = 503, description = "Email delivery is unavailable", body = ApiErrorResponse),
+    ),
+)]
+pub async fn create_invitation(
+    Path((realm_name, organization_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<CreateInvitationValidator>,
+) -> Result<Response<OrganizationInvitation>, ApiError> {
+    state
+        .service
+        .create_invitation(
+            identity,
+            CreateInvitationInput {
+                realm_name,
+                organization_id: OrganizationId::new(organization_id),
+                email: payload.email,
+                role_id: payload.role_id,
+                group_id: payload.group_id.map(GroupId::new),
+                expires_in_hours: payload.expires_in_hours,
+                base_url: state.args.webapp_url.trim_end_matches('/').to_string(),
+            },
+        )
+        .await
+        .map(Response::Created)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    get,
+    path = "/{organization_id}/invitations",
+    tag = "organization",
+    summary = "List the invitations of an organization",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("organization_id" = Uuid, Path, description = "Organization ID"),
+    ),
+    responses(
+        (status = 200, description = "Invitations, newest first", body = Vec<OrganizationInvitation>),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Organization not found", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_invitations(
+    Path((realm_name, organization_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<Vec<OrganizationInvitation>>, ApiError> {
+    state
+        .service
+        .list_invitations(
+            identity,
+            ListInvitationsInput {
+                realm_name,
+                organization_id: OrganizationId::new(organization_id),
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{organization_id}/invitations/{invitation_id}",
+    tag = "organization",
+    summary = "Revoke a pending invitation",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("organization_id" = Uuid, Path, description = "Organization ID"),
+        ("invitation_id" = Uuid, Path, description = "Invitation ID"),
+    ),
+    responses(
+        (status = 200, description = "Invitation revoked", body = OrganizationInvitation),
+        (status = 400, description = "Invitation is no longer pending", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Organization or invitation not found", body = ApiErrorResponse),
+    ),
+)]
+pub async fn revoke_invitation(
+    Path((realm_name, organization_id, invitation_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<OrganizationInvitation>, ApiError> {
+    state
+        .service
+        .revoke_invitation(
+            identity,
+            RevokeInvitationInput {
+                realm_name,
+                organization_id: OrganizationId::new(organization_id),
+                invitation_id,
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    get,
+    path = "/invitations",
+    tag = "organization",
+    summary = "Preview an invitation from its token",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("token" = String, Query, description = "Invitation token from the email link"),
+    ),
+    responses(
+        (status = 200, description = "Invitation details", body = InvitationPreview),
+        (status = 400, description = "Invitation is invalid, expired, revoked or already accepted", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_invitation(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Query(query): Query<InvitationTokenQuery>,
+) -> Result<Response<InvitationPreview>, ApiError> {
+    state
+        .service
+        .get_invitation(GetInvitationInput {
+            realm_name,
+            token: query.token,
+        })
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    post,
+    path = "/invitations/accept",
+    tag = "organization",
+    summary = "Accept an invitation, creating the account when none exists",
+    request_body = AcceptInvitationValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Invitation accepted", body = AcceptedInvitation),
+        (status = 400, description = "Invitation is invalid or the account data is rejected", body = ApiErrorResponse),
+        (status = 403, description = "The invited account is disabled", body = ApiErrorResponse),
+    ),
+)]
+pub async fn accept_invitation(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    ValidateJson(payload): ValidateJson<AcceptInvitationValidator>,
+) -> Result<Response<AcceptedInvitation>, ApiError> {
+    state
+        .service
+        .accept_invitation(AcceptInvitationInput {
+            realm_name,
+            token: payload.token,
+            username: payload.username,
+            password: payload.password,
+            first_name: payload.first_name,
+            last_name: payload.last_name,
+        })
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
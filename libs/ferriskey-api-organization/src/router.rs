@@ -8,6 +8,12 @@ use super::handlers::{
     create_organization::{__path_create_organization, create_organization},
     delete_attribute::{__path_delete_attribute, delete_attribute},
     delete_organization::{__path_delete_organization, delete_organization},
+    domain::{
+        __path_configure_organization_domain, __path_discover_home_realm,
+        __path_get_organization_domain, __path_verify_organization_domain,
+        configure_organization_domain, discover_home_realm, get_organization_domain,
+        verify_organization_domain,
+    },
     get_organization::{__path_get_organization, get_organization},
     groups::{
         __path_add_group_member, __path_assign_group_role, __path_create_group,
@@ -19,6 +25,11 @@ use super::handlers::{
         list_group_members, list_group_roles, list_groups, remove_group_member, revoke_group_role,
         update_group, upsert_group_attribute,
     },
+    invitations::{
+        __path_accept_invitation, __path_create_invitation, __path_get_invitation,
+        __path_list_invitations, __path_revoke_invitation, accept_invitation, create_invitation,
+        get_invitation, list_invitations, revoke_invitation,
+    },
     list_attributes::{__path_list_attributes, list_attributes},
     list_members::{__path_list_members, list_members},
     list_organizations::{__path_list_organizations, list_organizations},
@@ -61,6 +72,15 @@ use super::handlers::{
     list_group_attributes,
     upsert_group_attribute,
     delete_group_attribute,
+    list_invitations,
+    create_invitation,
+    revoke_invitation,
+    get_invitation,
+    accept_invitation,
+    get_organization_domain,
+    configure_organization_domain,
+    verify_organization_domain,
+    discover_home_realm,
 ))]
 pub struct OrganizationApiDoc;
 
@@ -182,5 +202,61 @@ pub fn organization_routes(state: AppState) -> Router<AppState> {
             ),
             axum::routing::put(upsert_group_attribute).delete(delete_group_attribute),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/organizations/{{organization_id}}/invitations",
+                state.args.server.root_path
+            ),
+            get(list_invitations).post(create_invitation),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/organizations/{{organization_id}}/invitations/{{invitation_id}}",
+                state.args.server.root_path
+            ),
+            axum::routing::delete(revoke_invitation),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/organizations/{{organization_id}}/domain",
+                state.args.server.root_path
+            ),
+            get(get_organization_domain).put(configure_organization_domain),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/organizations/{{organization_id}}/domain/verify",
+                state.args.server.root_path
+            ),
+            axum::routing::post(verify_organization_domain),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
+        .merge(public_organization_routes(&state))
+}
+
+/// Routes used before the user is signed in: the invitation token, or the email typed on the
+/// login page, is all the caller has.
+fn public_organization_routes(state: &AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/organizations/invitations",
+                state.args.server.root_path
+            ),
+            get(get_invitation),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/organizations/invitations/accept",
+                state.args.server.root_path
+            ),
+            axum::routing::post(accept_invitation),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/organizations/discover",
+                state.args.server.root_path
+            ),
+            get(discover_home_realm),
+        )
 }
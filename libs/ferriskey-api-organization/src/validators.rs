@@ -75,3 +75,50 @@ pub struct AssignGroupRoleValidator {
 pub struct AssignMemberRoleValidator {
     pub role_id: Uuid,
 }
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateInvitationValidator {
+    #[validate(email(message = "email must be a valid address"))]
+    pub email: String,
+    /// Role granted to the member on acceptance.
+    pub role_id: Option<Uuid>,
+    /// Group of the organization the member joins on acceptance.
+    pub group_id: Option<Uuid>,
+    /// Validity of the link, seven days when omitted.
+    #[validate(range(
+        min = 1,
+        max = 720,
+        message = "expires_in_hours must be between 1 and 720"
+    ))]
+    pub expires_in_hours: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AcceptInvitationValidator {
+    #[validate(length(min = 1, message = "token is required"))]
+    pub token: String,
+    /// Only used when no account exists for the invited email; defaults to the email.
+    pub username: Option<String>,
+    /// Required when no account exists for the invited email.
+    pub password: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ConfigureDomainValidator {
+    /// Alias of the realm identity provider the domain's users are sent to; omit to only
+    /// verify the domain.
+    #[validate(length(min = 1, message = "identity_provider_alias must not be empty"))]
+    pub identity_provider_alias: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct InvitationTokenQuery {
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DiscoverQuery {
+    pub email: String,
+}
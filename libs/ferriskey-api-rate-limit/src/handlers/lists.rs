@@ -0,0 +1,112 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::rate_limit::{
+    CreateRateLimitListEntryInput, RateLimitListEntry, RateLimitService,
+};
+use uuid::Uuid;
+
+use crate::validators::CreateRateLimitListEntryValidator;
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/rate-limits/lists",
+    tag = "rate-limit",
+    summary = "List the realm's allow and deny list entries",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Allow and deny list entries", body = Vec<RateLimitListEntry>),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_rate_limit_list(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<Vec<RateLimitListEntry>>, ApiError> {
+    state
+        .service
+        .get_rate_limit_list(identity, realm_name)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    post,
+    path = "/rate-limits/lists",
+    tag = "rate-limit",
+    summary = "Add an address range to the allow or deny list",
+    description = "Allow-listed clients bypass every rate limit; deny-listed clients are rejected with 403 on the rate-limited endpoints.",
+    request_body = CreateRateLimitListEntryValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 201, description = "Entry created", body = RateLimitListEntry),
+        (status = 400, description = "Invalid or already listed range", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn create_rate_limit_list_entry(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<CreateRateLimitListEntryValidator>,
+) -> Result<Response<RateLimitListEntry>, ApiError> {
+    state
+        .service
+        .create_rate_limit_list_entry(
+            identity,
+            CreateRateLimitListEntryInput {
+                realm_name,
+                kind: payload.kind,
+                cidr: payload.cidr,
+                description: payload.description,
+            },
+        )
+        .await
+        .map(Response::Created)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/rate-limits/lists/{entry_id}",
+    tag = "rate-limit",
+    summary = "Remove an allow or deny list entry",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("entry_id" = Uuid, Path, description = "Entry ID"),
+    ),
+    responses(
+        (status = 204, description = "Entry deleted"),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Entry not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn delete_rate_limit_list_entry(
+    Path((realm_name, entry_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .service
+        .delete_rate_limit_list_entry(identity, realm_name, entry_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiError::from)
+}
@@ -0,0 +1,163 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::rate_limit::{
+    CreateRateLimitPolicyInput, RateLimitOverview, RateLimitPolicy, RateLimitRule,
+    RateLimitService, UpdateRateLimitPolicyInput,
+};
+use uuid::Uuid;
+
+use crate::validators::{CreateRateLimitPolicyValidator, UpdateRateLimitPolicyValidator};
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/rate-limits",
+    tag = "rate-limit",
+    summary = "Get the realm's rate limit policies and the rules in effect",
+    description = "`policies` are the realm's own overrides; `effective` is what is enforced, built-in defaults included.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Rate limits", body = RateLimitOverview),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_rate_limits(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RateLimitOverview>, ApiError> {
+    state
+        .service
+        .get_rate_limits(identity, realm_name)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    post,
+    path = "/rate-limits/policies",
+    tag = "rate-limit",
+    summary = "Override the rate limit of an endpoint for one key kind",
+    request_body = CreateRateLimitPolicyValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 201, description = "Policy created", body = RateLimitPolicy),
+        (status = 400, description = "A policy already exists for this endpoint and key kind", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 422, description = "Invalid limit, window or block duration"),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn create_rate_limit_policy(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<CreateRateLimitPolicyValidator>,
+) -> Result<Response<RateLimitPolicy>, ApiError> {
+    state
+        .service
+        .create_rate_limit_policy(
+            identity,
+            CreateRateLimitPolicyInput {
+                realm_name,
+                rule: RateLimitRule {
+                    endpoint: payload.endpoint,
+                    key_kind: payload.key_kind,
+                    limit: payload.limit,
+                    window_seconds: payload.window_seconds,
+                    block_seconds: payload.block_seconds,
+                    shared: payload.shared,
+                    enabled: payload.enabled,
+                },
+            },
+        )
+        .await
+        .map(Response::Created)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    put,
+    path = "/rate-limits/policies/{policy_id}",
+    tag = "rate-limit",
+    summary = "Update a rate limit policy",
+    request_body = UpdateRateLimitPolicyValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("policy_id" = Uuid, Path, description = "Policy ID"),
+    ),
+    responses(
+        (status = 200, description = "Policy updated", body = RateLimitPolicy),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Policy not found", body = ApiErrorResponse),
+        (status = 422, description = "Invalid limit, window or block duration"),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_rate_limit_policy(
+    Path((realm_name, policy_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateRateLimitPolicyValidator>,
+) -> Result<Response<RateLimitPolicy>, ApiError> {
+    state
+        .service
+        .update_rate_limit_policy(
+            identity,
+            UpdateRateLimitPolicyInput {
+                realm_name,
+                policy_id,
+                limit: payload.limit,
+                window_seconds: payload.window_seconds,
+                block_seconds: payload.block_seconds,
+                shared: payload.shared,
+                enabled: payload.enabled,
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/rate-limits/policies/{policy_id}",
+    tag = "rate-limit",
+    summary = "Delete a rate limit policy, restoring the built-in default",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("policy_id" = Uuid, Path, description = "Policy ID"),
+    ),
+    responses(
+        (status = 204, description = "Policy deleted"),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Policy not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn delete_rate_limit_policy(
+    Path((realm_name, policy_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .service
+        .delete_rate_limit_policy(identity, realm_name, policy_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiError::from)
+}
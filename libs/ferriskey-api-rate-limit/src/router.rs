@@ -0,0 +1,72 @@
+use axum::{
+    Router, middleware,
+    routing::{delete, get, post, put},
+};
+use utoipa::OpenApi;
+
+use ferriskey_api_core::{app_state::AppState, auth::auth};
+
+use super::handlers::{
+    lists::{
+        __path_create_rate_limit_list_entry, __path_delete_rate_limit_list_entry,
+        __path_get_rate_limit_list, create_rate_limit_list_entry, delete_rate_limit_list_entry,
+        get_rate_limit_list,
+    },
+    policies::{
+        __path_create_rate_limit_policy, __path_delete_rate_limit_policy, __path_get_rate_limits,
+        __path_update_rate_limit_policy, create_rate_limit_policy, delete_rate_limit_policy,
+        get_rate_limits, update_rate_limit_policy,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_rate_limits,
+    create_rate_limit_policy,
+    update_rate_limit_policy,
+    delete_rate_limit_policy,
+    get_rate_limit_list,
+    create_rate_limit_list_entry,
+    delete_rate_limit_list_entry,
+))]
+pub struct RateLimitApiDoc;
+
+pub fn rate_limit_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/rate-limits",
+                state.args.server.root_path
+            ),
+            get(get_rate_limits),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/rate-limits/policies",
+                state.args.server.root_path
+            ),
+            post(create_rate_limit_policy),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/rate-limits/policies/{{policy_id}}",
+                state.args.server.root_path
+            ),
+            put(update_rate_limit_policy).delete(delete_rate_limit_policy),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/rate-limits/lists",
+                state.args.server.root_path
+            ),
+            get(get_rate_limit_list).post(create_rate_limit_list_entry),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/rate-limits/lists/{{entry_id}}",
+                state.args.server.root_path
+            ),
+            delete(delete_rate_limit_list_entry),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
@@ -0,0 +1,51 @@
+use ferriskey_core::domain::rate_limit::{
+    MAX_BLOCK_SECONDS, MAX_WINDOW_SECONDS, RateLimitEndpoint, RateLimitKeyKind, RateLimitListKind,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateRateLimitPolicyValidator {
+    pub endpoint: RateLimitEndpoint,
+    pub key_kind: RateLimitKeyKind,
+    /// Requests allowed per window.
+    #[validate(range(min = 1, message = "limit must be at least 1"))]
+    pub limit: u32,
+    #[validate(range(min = 1, max = MAX_WINDOW_SECONDS, message = "window_seconds must be between 1 and 86400"))]
+    pub window_seconds: u32,
+    /// How long a key stays blocked once the limit is reached; 0 only waits for the refill.
+    #[serde(default)]
+    #[validate(range(max = MAX_BLOCK_SECONDS, message = "block_seconds must not exceed 86400"))]
+    pub block_seconds: u32,
+    /// Share the counter across replicas through the database.
+    #[serde(default)]
+    pub shared: bool,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateRateLimitPolicyValidator {
+    #[validate(range(min = 1, message = "limit must be at least 1"))]
+    pub limit: Option<u32>,
+    #[validate(range(min = 1, max = MAX_WINDOW_SECONDS, message = "window_seconds must be between 1 and 86400"))]
+    pub window_seconds: Option<u32>,
+    #[validate(range(max = MAX_BLOCK_SECONDS, message = "block_seconds must not exceed 86400"))]
+    pub block_seconds: Option<u32>,
+    pub shared: Option<bool>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateRateLimitListEntryValidator {
+    pub kind: RateLimitListKind,
+    /// An IP address or a CIDR range, e.g. `203.0.113.0/24`.
+    #[validate(length(min = 1, max = 64, message = "cidr is required"))]
+    pub cidr: String,
+    pub description: Option<String>,
+}
@@ -0,0 +1,518 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::realm_group::{
+    CreateRealmGroupInput, DeleteRealmGroupAttributeInput, ListRealmGroupMembersInput, RealmGroup,
+    RealmGroupAttribute, RealmGroupId, RealmGroupMember, RealmGroupMemberInput,
+    RealmGroupMemberPage, RealmGroupNode, RealmGroupRoleInput, RealmGroupService,
+    UpdateRealmGroupInput, UpsertRealmGroupAttributeInput,
+};
+use ferriskey_core::domain::role::entities::Role;
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListRealmGroupMembersQuery {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    /// Case-insensitive filter on username/email.
+    pub search: Option<String>,
+}
+
+use crate::validators::{
+    AddRealmGroupMemberValidator, AssignRealmGroupRoleValidator, CreateRealmGroupValidator,
+    UpdateRealmGroupValidator, UpsertRealmGroupAttributeValidator,
+};
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/groups",
+    tag = "group",
+    summary = "List the realm's groups as a tree",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Group tree", body = Vec<RealmGroupNode>),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Realm not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_groups(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<Vec<RealmGroupNode>>, ApiError> {
+    state
+        .service
+        .list_groups(identity, realm_name)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups",
+    tag = "group",
+    summary = "Create a group",
+    request_body = CreateRealmGroupValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 201, description = "Group created", body = RealmGroup),
+        (status = 400, description = "Invalid group name", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Realm or parent group not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn create_group(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<CreateRealmGroupValidator>,
+) -> Result<Response<RealmGroup>, ApiError> {
+    state
+        .service
+        .create_group(
+            identity,
+            CreateRealmGroupInput {
+                realm_name,
+                parent_group_id: payload.parent_group_id.map(RealmGroupId::new),
+                name: payload.name,
+                description: payload.description,
+            },
+        )
+        .await
+        .map(Response::Created)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/{group_id}",
+    tag = "group",
+    summary = "Get a group",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 200, description = "Group", body = RealmGroup),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_group(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RealmGroup>, ApiError> {
+    state
+        .service
+        .get_group(identity, realm_name, RealmGroupId::new(group_id))
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    put,
+    path = "/groups/{group_id}",
+    tag = "group",
+    summary = "Update a group",
+    request_body = UpdateRealmGroupValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 200, description = "Group updated", body = RealmGroup),
+        (status = 400, description = "Invalid name or parent (cycle)", body = ApiErrorResponse),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_group(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateRealmGroupValidator>,
+) -> Result<Response<RealmGroup>, ApiError> {
+    state
+        .service
+        .update_group(
+            identity,
+            UpdateRealmGroupInput {
+                realm_name,
+                group_id: RealmGroupId::new(group_id),
+                name: payload.name,
+                description: payload.description,
+                parent_group_id: payload
+                    .parent_group_id
+                    .map(|id| Some(RealmGroupId::new(id))),
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/groups/{group_id}",
+    tag = "group",
+    summary = "Delete a group (and its sub-groups)",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 204, description = "Group deleted"),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn delete_group(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .service
+        .delete_group(identity, realm_name, RealmGroupId::new(group_id))
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/{group_id}/members",
+    tag = "group",
+    summary = "List group members",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+        ListRealmGroupMembersQuery,
+    ),
+    responses(
+        (status = 200, description = "Members page", body = RealmGroupMemberPage),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_group_members(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<ListRealmGroupMembersQuery>,
+) -> Result<Response<RealmGroupMemberPage>, ApiError> {
+    state
+        .service
+        .list_members(
+            identity,
+            ListRealmGroupMembersInput {
+                realm_name,
+                group_id: RealmGroupId::new(group_id),
+                limit: query.limit,
+                offset: query.offset,
+                search: query.search,
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups/{group_id}/members",
+    tag = "group",
+    summary = "Add a user to a group",
+    request_body = AddRealmGroupMemberValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 201, description = "Member added", body = RealmGroupMember),
+        (status = 404, description = "Group or user not found", body = ApiErrorResponse),
+        (status = 409, description = "Already a member", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn add_group_member(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<AddRealmGroupMemberValidator>,
+) -> Result<Response<RealmGroupMember>, ApiError> {
+    state
+        .service
+        .add_member(
+            identity,
+            RealmGroupMemberInput {
+                realm_name,
+                group_id: RealmGroupId::new(group_id),
+                user_id: payload.user_id,
+            },
+        )
+        .await
+        .map(Response::Created)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/groups/{group_id}/members/{user_id}",
+    tag = "group",
+    summary = "Remove a user from a group",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    responses(
+        (status = 204, description = "Member removed"),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn remove_group_member(
+    Path((realm_name, group_id, user_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .service
+        .remove_member(
+            identity,
+            RealmGroupMemberInput {
+                realm_name,
+                group_id: RealmGroupId::new(group_id),
+                user_id,
+            },
+        )
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/{group_id}/roles",
+    tag = "group",
+    summary = "List roles mapped to a group",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 200, description = "Roles", body = Vec<Role>),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_group_roles(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<Vec<Role>>, ApiError> {
+    state
+        .service
+        .list_roles(identity, realm_name, RealmGroupId::new(group_id))
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    post,
+    path = "/groups/{group_id}/roles",
+    tag = "group",
+    summary = "Map a realm or client role to a group",
+    request_body = AssignRealmGroupRoleValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 204, description = "Role assigned"),
+        (status = 404, description = "Group or role not found", body = ApiErrorResponse),
+        (status = 409, description = "Role already mapped", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn assign_group_role(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<AssignRealmGroupRoleValidator>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .service
+        .assign_role(
+            identity,
+            RealmGroupRoleInput {
+                realm_name,
+                group_id: RealmGroupId::new(group_id),
+                role_id: payload.role_id,
+            },
+        )
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/groups/{group_id}/roles/{role_id}",
+    tag = "group",
+    summary = "Revoke a role from a group",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+        ("role_id" = Uuid, Path, description = "Role ID"),
+    ),
+    responses(
+        (status = 204, description = "Role revoked"),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn revoke_group_role(
+    Path((realm_name, group_id, role_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .service
+        .revoke_role(
+            identity,
+            RealmGroupRoleInput {
+                realm_name,
+                group_id: RealmGroupId::new(group_id),
+                role_id,
+            },
+        )
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    get,
+    path = "/groups/{group_id}/attributes",
+    tag = "group",
+    summary = "List group attributes",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 200, description = "Attributes", body = Vec<RealmGroupAttribute>),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_group_attributes(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<Vec<RealmGroupAttribute>>, ApiError> {
+    state
+        .service
+        .list_attributes(identity, realm_name, RealmGroupId::new(group_id))
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    put,
+    path = "/groups/{group_id}/attributes/{key}",
+    tag = "group",
+    summary = "Create or update a group attribute",
+    request_body = UpsertRealmGroupAttributeValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+        ("key" = String, Path, description = "Attribute key"),
+    ),
+    responses(
+        (status = 200, description = "Attribute upserted", body = RealmGroupAttribute),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn upsert_group_attribute(
+    Path((realm_name, group_id, key)): Path<(String, Uuid, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpsertRealmGroupAttributeValidator>,
+) -> Result<Response<RealmGroupAttribute>, ApiError> {
+    state
+        .service
+        .upsert_attribute(
+            identity,
+            UpsertRealmGroupAttributeInput {
+                realm_name,
+                group_id: RealmGroupId::new(group_id),
+                key,
+                value: payload.value,
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/groups/{group_id}/attributes/{key}",
+    tag = "group",
+    summary = "Delete a group attribute",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+        ("key" = String, Path, description = "Attribute key"),
+    ),
+    responses(
+        (status = 204, description = "Attribute deleted"),
+        (status = 404, description = "Not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn delete_group_attribute(
+    Path((realm_name, group_id, key)): Path<(String, Uuid, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .service
+        .delete_attribute(
+            identity,
+            DeleteRealmGroupAttributeInput {
+                realm_name,
+                group_id: RealmGroupId::new(group_id),
+                key,
+            },
+        )
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiError::from)
+}
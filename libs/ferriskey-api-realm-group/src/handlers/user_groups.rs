@@ -0,0 +1,43 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::realm_group::{RealmGroupMembership, RealmGroupService};
+use uuid::Uuid;
+
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/users/{user_id}/groups",
+    tag = "group",
+    summary = "Get a user's effective group membership",
+    description = "Direct and inherited groups with their paths, the roles inherited through them and the attributes they contribute.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    responses(
+        (status = 200, description = "Effective membership", body = RealmGroupMembership),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "User not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_user_groups(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RealmGroupMembership>, ApiError> {
+    state
+        .service
+        .get_user_membership(identity, realm_name, user_id)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
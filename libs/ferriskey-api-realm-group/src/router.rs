@@ -0,0 +1,109 @@
+use axum::{
+    Router, middleware,
+    routing::{delete, get, put},
+};
+use utoipa::OpenApi;
+
+use ferriskey_api_core::{app_state::AppState, auth::auth};
+
+use super::handlers::{
+    groups::{
+        __path_add_group_member, __path_assign_group_role, __path_create_group,
+        __path_delete_group, __path_delete_group_attribute, __path_get_group,
+        __path_list_group_attributes, __path_list_group_members, __path_list_group_roles,
+        __path_list_groups, __path_remove_group_member, __path_revoke_group_role,
+        __path_update_group, __path_upsert_group_attribute, add_group_member, assign_group_role,
+        create_group, delete_group, delete_group_attribute, get_group, list_group_attributes,
+        list_group_members, list_group_roles, list_groups, remove_group_member, revoke_group_role,
+        update_group, upsert_group_attribute,
+    },
+    user_groups::{__path_get_user_groups, get_user_groups},
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    list_groups,
+    create_group,
+    get_group,
+    update_group,
+    delete_group,
+    list_group_members,
+    add_group_member,
+    remove_group_member,
+    list_group_roles,
+    assign_group_role,
+    revoke_group_role,
+    list_group_attributes,
+    upsert_group_attribute,
+    delete_group_attribute,
+    get_user_groups,
+))]
+pub struct RealmGroupApiDoc;
+
+pub fn realm_group_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups",
+                state.args.server.root_path
+            ),
+            get(list_groups).post(create_group),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}",
+                state.args.server.root_path
+            ),
+            get(get_group).put(update_group).delete(delete_group),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/members",
+                state.args.server.root_path
+            ),
+            get(list_group_members).post(add_group_member),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/members/{{user_id}}",
+                state.args.server.root_path
+            ),
+            delete(remove_group_member),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/roles",
+                state.args.server.root_path
+            ),
+            get(list_group_roles).post(assign_group_role),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/roles/{{role_id}}",
+                state.args.server.root_path
+            ),
+            delete(revoke_group_role),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/attributes",
+                state.args.server.root_path
+            ),
+            get(list_group_attributes),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/attributes/{{key}}",
+                state.args.server.root_path
+            ),
+            put(upsert_group_attribute).delete(delete_group_attribute),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/groups",
+                state.args.server.root_path
+            ),
+            get(get_user_groups),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
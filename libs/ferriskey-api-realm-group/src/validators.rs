@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateRealmGroupValidator {
+    #[validate(length(min = 1, message = "name is required"))]
+    pub name: String,
+    pub description: Option<String>,
+    /// Optional parent group; omit for a top-level group.
+    pub parent_group_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateRealmGroupValidator {
+    #[validate(length(min = 1, message = "name must not be empty when provided"))]
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// When present, move the group under this parent.
+    pub parent_group_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AddRealmGroupMemberValidator {
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AssignRealmGroupRoleValidator {
+    pub role_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpsertRealmGroupAttributeValidator {
+    #[validate(length(min = 1, message = "value is required"))]
+    pub value: String,
+}
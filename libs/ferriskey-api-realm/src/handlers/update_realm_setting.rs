@@ -63,6 +63,7 @@ pub async fn update_realm_setting(
                 reset_password_template_id: payload.reset_password_template_id,
                 magic_link_template_id: payload.magic_link_template_id,
                 email_verification_template_id: payload.email_verification_template_id,
+                organization_invitation_template_id: payload.organization_invitation_template_id,
                 email_verification_enabled: payload.email_verification_enabled,
                 email_verification_ttl_hours: payload.email_verification_ttl_hours,
                 lockout_threshold: payload.lockout_threshold,
@@ -53,6 +53,7 @@ pub async fn upsert_smtp_config(
                 from_email: payload.from_email,
                 from_name: payload.from_name,
                 encryption: payload.encryption,
+                max_emails_per_minute: payload.max_emails_per_minute,
             },
         )
         .await
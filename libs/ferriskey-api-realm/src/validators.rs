@@ -56,6 +56,9 @@ pub struct UpdateRealmSettingValidator {
     #[serde(default, deserialize_with = "deserialize_optional_field")]
     #[schema(value_type = Option<Uuid>)]
     pub email_verification_template_id: Option<Option<Uuid>>,
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    #[schema(value_type = Option<Uuid>)]
+    pub organization_invitation_template_id: Option<Option<Uuid>>,
     pub email_verification_enabled: Option<bool>,
     #[validate(range(
         min = 1,
@@ -96,6 +99,9 @@ pub struct UpsertSmtpConfigValidator {
     pub from_name: String,
     #[validate(custom(function = "validate_encryption"))]
     pub encryption: String,
+    /// Emails delivered per minute for the realm; unlimited when omitted.
+    #[validate(range(min = 1, message = "max_emails_per_minute must be at least 1"))]
+    pub max_emails_per_minute: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
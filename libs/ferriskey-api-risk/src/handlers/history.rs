@@ -0,0 +1,48 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::risk::{GetLoginHistoryInput, LoginRecord, LoginRiskService};
+use uuid::Uuid;
+
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/users/{user_id}/login-history",
+    tag = "login-risk",
+    summary = "List a user's recent logins",
+    description = "The logins the risk engine compares new ones with, most recent first, with the score and signals each one raised. Blocked attempts are not part of the history; they are recorded as security events.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    responses(
+        (status = 200, description = "Login history", body = Vec<LoginRecord>),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_login_history(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<Vec<LoginRecord>>, ApiError> {
+    state
+        .service
+        .get_login_history(
+            identity,
+            GetLoginHistoryInput {
+                realm_name,
+                user_id,
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
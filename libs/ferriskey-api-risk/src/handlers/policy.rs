@@ -0,0 +1,78 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::risk::{LoginRiskPolicy, LoginRiskService, UpdateLoginRiskPolicyInput};
+
+use crate::validators::UpdateLoginRiskPolicyValidator;
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/login-risk",
+    tag = "login-risk",
+    summary = "Get the realm's login risk policy",
+    description = "Realms that never configured one get the defaults, with evaluation disabled.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Login risk policy", body = LoginRiskPolicy),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_login_risk_policy(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<LoginRiskPolicy>, ApiError> {
+    state
+        .service
+        .get_login_risk_policy(identity, realm_name)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    put,
+    path = "/login-risk",
+    tag = "login-risk",
+    summary = "Replace the realm's login risk policy",
+    description = "Signal scores add up to at most 100. Each action applies once the score reaches its threshold; a user without a second factor is blocked instead of stepped up.",
+    request_body = UpdateLoginRiskPolicyValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Policy updated", body = LoginRiskPolicy),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 422, description = "Invalid score, threshold or travel speed"),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_login_risk_policy(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateLoginRiskPolicyValidator>,
+) -> Result<Response<LoginRiskPolicy>, ApiError> {
+    state
+        .service
+        .update_login_risk_policy(
+            identity,
+            UpdateLoginRiskPolicyInput {
+                realm_name,
+                settings: payload.into(),
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
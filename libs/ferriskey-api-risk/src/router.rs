@@ -0,0 +1,35 @@
+use axum::{Router, middleware, routing::get};
+use utoipa::OpenApi;
+
+use ferriskey_api_core::{app_state::AppState, auth::auth};
+
+use super::handlers::{
+    history::{__path_get_login_history, get_login_history},
+    policy::{
+        __path_get_login_risk_policy, __path_update_login_risk_policy, get_login_risk_policy,
+        update_login_risk_policy,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(get_login_risk_policy, update_login_risk_policy, get_login_history))]
+pub struct LoginRiskApiDoc;
+
+pub fn login_risk_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/login-risk",
+                state.args.server.root_path
+            ),
+            get(get_login_risk_policy).put(update_login_risk_policy),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/login-history",
+                state.args.server.root_path
+            ),
+            get(get_login_history),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
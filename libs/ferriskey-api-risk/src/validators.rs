@@ -0,0 +1,49 @@
+use ferriskey_core::domain::risk::{LoginRiskSettings, MAX_RISK_SCORE, MAX_TRAVEL_SPEED_KMH};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateLoginRiskPolicyValidator {
+    pub enabled: bool,
+    #[validate(range(max = MAX_RISK_SCORE, message = "new_device_score must not exceed 100"))]
+    pub new_device_score: u8,
+    #[validate(range(max = MAX_RISK_SCORE, message = "new_country_score must not exceed 100"))]
+    pub new_country_score: u8,
+    #[validate(range(
+        max = MAX_RISK_SCORE,
+        message = "impossible_travel_score must not exceed 100"
+    ))]
+    pub impossible_travel_score: u8,
+    /// Travel between two consecutive logins faster than this is impossible.
+    #[validate(range(
+        min = 1,
+        max = MAX_TRAVEL_SPEED_KMH,
+        message = "max_travel_speed_kmh must be between 1 and 20000"
+    ))]
+    pub max_travel_speed_kmh: u32,
+    /// Score from which the user must pass MFA; `null` disables the step-up.
+    #[validate(range(min = 1, max = MAX_RISK_SCORE, message = "step_up_threshold must be between 1 and 100"))]
+    pub step_up_threshold: Option<u8>,
+    /// Score from which the user is emailed about the login; `null` disables notifications.
+    #[validate(range(min = 1, max = MAX_RISK_SCORE, message = "notify_threshold must be between 1 and 100"))]
+    pub notify_threshold: Option<u8>,
+    /// Score from which the login is refused; `null` never blocks.
+    #[validate(range(min = 1, max = MAX_RISK_SCORE, message = "block_threshold must be between 1 and 100"))]
+    pub block_threshold: Option<u8>,
+}
+
+impl From<UpdateLoginRiskPolicyValidator> for LoginRiskSettings {
+    fn from(payload: UpdateLoginRiskPolicyValidator) -> Self {
+        LoginRiskSettings {
+            enabled: payload.enabled,
+            new_device_score: payload.new_device_score,
+            new_country_score: payload.new_country_score,
+            impossible_travel_score: payload.impossible_travel_score,
+            max_travel_speed_kmh: payload.max_travel_speed_kmh,
+            step_up_threshold: payload.step_up_threshold,
+            notify_threshold: payload.notify_threshold,
+            block_threshold: payload.block_threshold,
+        }
+    }
+}
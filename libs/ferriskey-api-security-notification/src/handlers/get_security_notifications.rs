@@ -0,0 +1,50 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::api_error::{ApiError, ApiErrorResponse};
+use ferriskey_api_core::api_entities::response::Response;
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::security_notification::entities::SecurityNotificationSetting;
+use ferriskey_core::domain::security_notification::ports::{
+    GetSecurityNotificationsInput, SecurityNotificationService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetSecurityNotificationsResponse {
+    pub data: Vec<SecurityNotificationSetting>,
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "security-notification",
+    summary = "List security notifications",
+    description = "Returns every security notification the realm can send to its users, whether it is enabled and which email template it uses. Notifications never configured are listed with their defaults: enabled, with the built-in template.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Security notifications", body = GetSecurityNotificationsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_security_notifications(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetSecurityNotificationsResponse>, ApiError> {
+    let settings = state
+        .service
+        .get_security_notifications(identity, GetSecurityNotificationsInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(GetSecurityNotificationsResponse {
+        data: settings,
+    }))
+}
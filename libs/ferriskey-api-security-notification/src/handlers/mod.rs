@@ -0,0 +1,2 @@
+pub mod get_security_notifications;
+pub mod update_security_notification;
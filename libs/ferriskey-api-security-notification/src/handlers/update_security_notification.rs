@@ -0,0 +1,57 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_api_core::api_entities::api_error::{ApiError, ApiErrorResponse, ValidateJson};
+use ferriskey_api_core::api_entities::response::Response;
+use ferriskey_api_core::app_state::AppState;
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::security_notification::entities::{
+    SecurityNotificationKind, SecurityNotificationSetting,
+};
+use ferriskey_core::domain::security_notification::ports::{
+    SecurityNotificationService, UpdateSecurityNotificationInput,
+};
+
+use crate::validators::UpdateSecurityNotificationValidator;
+
+#[utoipa::path(
+    put,
+    path = "/{kind}",
+    tag = "security-notification",
+    summary = "Update a security notification",
+    description = "Turns one kind of security notification on or off for the realm and picks its email template. The template must have the same type as the notification.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("kind" = SecurityNotificationKind, Path, description = "Kind of notification"),
+    ),
+    request_body = UpdateSecurityNotificationValidator,
+    responses(
+        (status = 200, description = "Security notification updated", body = SecurityNotificationSetting),
+        (status = 400, description = "Template of another type", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Email template not found", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_security_notification(
+    Path((realm_name, kind)): Path<(String, SecurityNotificationKind)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateSecurityNotificationValidator>,
+) -> Result<Response<SecurityNotificationSetting>, ApiError> {
+    state
+        .service
+        .update_security_notification(
+            identity,
+            UpdateSecurityNotificationInput {
+                realm_name,
+                kind,
+                enabled: payload.enabled,
+                template_id: payload.template_id,
+            },
+        )
+        .await
+        .map(Response::Updated)
+        .map_err(ApiError::from)
+}
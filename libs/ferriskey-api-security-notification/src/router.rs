@@ -0,0 +1,37 @@
+use axum::{
+    Router, middleware,
+    routing::{get, put},
+};
+use utoipa::OpenApi;
+
+use ferriskey_api_core::{app_state::AppState, auth::auth};
+
+use super::handlers::{
+    get_security_notifications::{__path_get_security_notifications, get_security_notifications},
+    update_security_notification::{
+        __path_update_security_notification, update_security_notification,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(get_security_notifications, update_security_notification))]
+pub struct SecurityNotificationApiDoc;
+
+pub fn security_notification_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/security-notifications",
+                state.args.server.root_path
+            ),
+            get(get_security_notifications),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/security-notifications/{{kind}}",
+                state.args.server.root_path
+            ),
+            put(update_security_notification),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateSecurityNotificationValidator {
+    pub enabled: bool,
+    /// Email template of the same type as the notification; omit it for the built-in one.
+    pub template_id: Option<Uuid>,
+}
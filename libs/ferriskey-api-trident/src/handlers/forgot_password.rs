@@ -1,5 +1,6 @@
 use axum::extract::{Path, State};
 use axum_cookie::CookieManager;
+use ferriskey_core::domain::rate_limit::{RateLimitCheck, RateLimitEndpoint, RateLimitService};
 use ferriskey_core::domain::trident::ports::{RequestPasswordResetInput, TridentService};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -11,6 +12,7 @@ use ferriskey_api_core::{
         response::Response,
     },
     app_state::AppState,
+    client_ip::ClientIp,
 };
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -35,15 +37,29 @@ pub struct ForgotPasswordRequest {
     responses(
         (status = 200, description = "Request processed (email sent if user exists)", body = ForgotPasswordResponse),
         (status = 400, description = "Bad Request", body = ApiErrorResponse),
+        (status = 429, description = "Too many requests, see the Retry-After header", body = ApiErrorResponse),
         (status = 500, description = "Internal Server Error", body = ApiErrorResponse),
     )
 )]
 pub async fn forgot_password(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
     cookie: CookieManager,
     ValidateJson(payload): ValidateJson<ForgotPasswordRequest>,
 ) -> Result<Response<ForgotPasswordResponse>, ApiError> {
+    state
+        .service
+        .check_rate_limit(
+            RateLimitCheck::new(
+                realm_name.clone(),
+                RateLimitEndpoint::ForgotPassword,
+                client_ip,
+            )
+            .with_username(Some(payload.email.clone())),
+        )
+        .await?;
+
     let base_url = state.args.webapp_url.trim_end_matches('/').to_string();
     let session_code = cookie
         .get("FERRISKEY_SESSION")
@@ -4,6 +4,7 @@ use axum::{
     response::IntoResponse,
 };
 use axum_cookie::CookieManager;
+use ferriskey_core::domain::rate_limit::{RateLimitCheck, RateLimitEndpoint, RateLimitService};
 use ferriskey_core::domain::trident::ports::{
     MagicLinkInput, TridentService, VerifyMagicLinkInput,
 };
@@ -20,6 +21,7 @@ use ferriskey_api_core::{
     },
     app_state::AppState,
     authentication::{AuthenticateResponse, AuthenticationStatus},
+    client_ip::ClientIp,
 };
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
@@ -52,15 +54,25 @@ pub struct VerifyMagicLinkQuery {
     responses(
         (status = 200, body = SendMagicLinkResponse, description = "Magic link sent successfully"),
         (status = 400, description = "Bad Request"),
+        (status = 429, description = "Too many requests, see the Retry-After header"),
         (status = 500, description = "Internal Server Error")
     )
 )]
 pub async fn send_magic_link(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
     cookie: CookieManager,
     ValidateJson(payload): ValidateJson<SendMagicLinkRequest>,
 ) -> Result<Response<SendMagicLinkResponse>, ApiError> {
+    state
+        .service
+        .check_rate_limit(
+            RateLimitCheck::new(realm_name.clone(), RateLimitEndpoint::MagicLink, client_ip)
+                .with_username(Some(payload.email.clone())),
+        )
+        .await?;
+
     debug!(
         "Generating magic link for email: {} in realm: {}",
         payload.email, realm_name
@@ -0,0 +1,35 @@
+use axum::extract::{Path, State};
+use ferriskey_core::domain::user_profile::{UserProfileConfig, UserProfileService};
+
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/user-profile/registration",
+    tag = "user-profile",
+    summary = "Get the attributes of the registration form",
+    description = "The attributes users fill in themselves, with their validators and groups. Public, like the registration form that renders them.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Registration profile", body = UserProfileConfig),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_registration_profile(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+) -> Result<Response<UserProfileConfig>, ApiError> {
+    state
+        .service
+        .get_registration_profile(realm_name)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
@@ -0,0 +1,80 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::user_profile::{
+    UpdateUserProfileSchemaInput, UserProfileSchema, UserProfileService,
+};
+
+use crate::validators::UpdateUserProfileSchemaValidator;
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/user-profile",
+    tag = "user-profile",
+    summary = "Get the realm's user profile schema",
+    description = "Realms that never declared one get an empty schema: no declared attributes, and undeclared ones editable by administrators only.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "User profile schema", body = UserProfileSchema),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_user_profile_schema(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<UserProfileSchema>, ApiError> {
+    state
+        .service
+        .get_user_profile_schema(identity, realm_name)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    put,
+    path = "/user-profile",
+    tag = "user-profile",
+    summary = "Replace the realm's user profile schema",
+    description = "Attribute values already stored are kept; the new rules apply from their next change.",
+    request_body = UpdateUserProfileSchemaValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Schema updated", body = UserProfileSchema),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 422, description = "Invalid attribute, validator or group", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_user_profile_schema(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateUserProfileSchemaValidator>,
+) -> Result<Response<UserProfileSchema>, ApiError> {
+    state
+        .service
+        .update_user_profile_schema(
+            identity,
+            UpdateUserProfileSchemaInput {
+                realm_name,
+                config: payload.into(),
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
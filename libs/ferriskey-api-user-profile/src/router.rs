@@ -0,0 +1,44 @@
+use axum::{Router, middleware, routing::get};
+use utoipa::OpenApi;
+
+use ferriskey_api_core::{app_state::AppState, auth::auth};
+
+use super::handlers::{
+    registration::{__path_get_registration_profile, get_registration_profile},
+    schema::{
+        __path_get_user_profile_schema, __path_update_user_profile_schema, get_user_profile_schema,
+        update_user_profile_schema,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_user_profile_schema,
+    update_user_profile_schema,
+    get_registration_profile
+))]
+pub struct UserProfileApiDoc;
+
+pub fn user_profile_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/user-profile",
+                state.args.server.root_path
+            ),
+            get(get_user_profile_schema).put(update_user_profile_schema),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+        .merge(public_user_profile_routes(&state))
+}
+
+/// Routes the registration page calls before anyone is signed in.
+fn public_user_profile_routes(state: &AppState) -> Router<AppState> {
+    Router::new().route(
+        &format!(
+            "{}/realms/{{realm_name}}/user-profile/registration",
+            state.args.server.root_path
+        ),
+        get(get_registration_profile),
+    )
+}
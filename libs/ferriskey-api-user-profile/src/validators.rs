@@ -0,0 +1,28 @@
+use ferriskey_core::domain::user_profile::{
+    AttributeGroup, UnmanagedAttributePolicy, UserProfileAttribute, UserProfileConfig,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// The schema is checked as a whole by the service, which reports every problem at once.
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateUserProfileSchemaValidator {
+    #[serde(default)]
+    pub attributes: Vec<UserProfileAttribute>,
+    #[serde(default)]
+    pub groups: Vec<AttributeGroup>,
+    /// What happens to attributes the schema does not declare.
+    #[serde(default)]
+    pub unmanaged_attributes: UnmanagedAttributePolicy,
+}
+
+impl From<UpdateUserProfileSchemaValidator> for UserProfileConfig {
+    fn from(payload: UpdateUserProfileSchemaValidator) -> Self {
+        UserProfileConfig {
+            attributes: payload.attributes,
+            groups: payload.groups,
+            unmanaged_attributes: payload.unmanaged_attributes,
+        }
+    }
+}
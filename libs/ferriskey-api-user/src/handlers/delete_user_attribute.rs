@@ -36,6 +36,7 @@ pub struct DeleteUserAttributeResponse {}
         (status = 401, description = "Unauthorized", body = ApiErrorResponse),
         (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
         (status = 404, description = "User or attribute not found", body = ApiErrorResponse),
+        (status = 422, description = "The attribute is required or read-only in the realm's user profile", body = ApiErrorResponse),
         (status = 500, description = "Internal server error", body = ApiErrorResponse),
     )
 )]
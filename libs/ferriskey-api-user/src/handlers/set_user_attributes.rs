@@ -53,7 +53,7 @@ impl validator::Validate for SetUserAttributesValidator {
     path = "/{user_id}/attributes",
     tag = "user",
     summary = "Set custom attributes for a user",
-    description = "Upserts a set of key-value attributes on the user. Existing keys are updated, new keys are created.",
+    description = "Upserts a set of key-value attributes on the user. Existing keys are updated, new keys are created and empty values delete the key. Values are checked against the realm's user profile.",
     params(
         ("realm_name" = String, Path, description = "Realm name"),
         ("user_id" = Uuid, Path, description = "User ID"),
@@ -69,6 +69,7 @@ impl validator::Validate for SetUserAttributesValidator {
         (status = 401, description = "Unauthorized", body = ApiErrorResponse),
         (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
         (status = 404, description = "User not found", body = ApiErrorResponse),
+        (status = 422, description = "Attributes violate the realm's user profile", body = ApiErrorResponse),
         (status = 500, description = "Internal server error", body = ApiErrorResponse),
     )
 )]
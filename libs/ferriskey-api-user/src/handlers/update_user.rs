@@ -40,6 +40,7 @@ pub struct UpdateUserResponse {
         (status = 401, description = "Realm not found", body = ApiErrorResponse),
         (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
         (status = 404, description = "User not found", body = ApiErrorResponse),
+        (status = 422, description = "Attributes violate the realm's user profile", body = ApiErrorResponse),
         (status = 500, description = "Internal server error", body = ApiErrorResponse),
     )
 )]
@@ -62,6 +63,7 @@ pub async fn update_user(
                 email_verified: payload.email_verified,
                 enabled: payload.enabled.unwrap_or(true),
                 required_actions: payload.required_actions,
+                attributes: payload.attributes,
             },
         )
         .await?;
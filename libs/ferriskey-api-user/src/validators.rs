@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -60,4 +62,9 @@ pub struct UpdateUserValidator {
 
     #[serde(default)]
     pub required_actions: Option<Vec<String>>,
+
+    /// Attribute changes checked against the realm's user profile; an empty value clears
+    /// the attribute.
+    #[serde(default)]
+    pub attributes: Option<HashMap<String, String>>,
 }
@@ -0,0 +1,40 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::webauthn_policy::{MetadataSummary, WebAuthnPolicyService};
+
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/webauthn-policy/fido-metadata",
+    tag = "webauthn-policy",
+    summary = "Describe the FIDO metadata attestations are verified against",
+    description = "The BLOB is read from the file given by `FIDO_METADATA_BLOB` at startup and is shared by every realm.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "FIDO metadata summary", body = MetadataSummary),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_fido_metadata(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<MetadataSummary>, ApiError> {
+    state
+        .service
+        .get_fido_metadata(identity, realm_name)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
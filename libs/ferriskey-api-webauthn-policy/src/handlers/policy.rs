@@ -0,0 +1,81 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::webauthn_policy::{
+    UpdateWebAuthnPolicyInput, WebAuthnPolicy, WebAuthnPolicyService,
+};
+
+use crate::validators::UpdateWebAuthnPolicyValidator;
+use ferriskey_api_core::api_entities::{
+    api_error::{ApiError, ApiErrorResponse, ValidateJson},
+    response::Response,
+};
+use ferriskey_api_core::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/webauthn-policy",
+    tag = "webauthn-policy",
+    summary = "Get the realm's WebAuthn policy",
+    description = "Realms that never configured one get the passkey defaults: no attestation, user verification required and any authenticator.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "WebAuthn policy", body = WebAuthnPolicy),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_webauthn_policy(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<WebAuthnPolicy>, ApiError> {
+    state
+        .service
+        .get_webauthn_policy(identity, realm_name)
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
+
+#[utoipa::path(
+    put,
+    path = "/webauthn-policy",
+    tag = "webauthn-policy",
+    summary = "Replace the realm's WebAuthn policy",
+    description = "Allowing, blocking or requiring FIPS certified authenticators needs direct or indirect attestation, and FIPS-only policies need FIDO metadata. Credentials registered before a change are re-checked at every sign-in.",
+    request_body = UpdateWebAuthnPolicyValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Policy updated", body = WebAuthnPolicy),
+        (status = 400, description = "Invalid origin or inconsistent policy", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 422, description = "Too many origins or AAGUIDs"),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_webauthn_policy(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateWebAuthnPolicyValidator>,
+) -> Result<Response<WebAuthnPolicy>, ApiError> {
+    state
+        .service
+        .update_webauthn_policy(
+            identity,
+            UpdateWebAuthnPolicyInput {
+                realm_name,
+                settings: payload.into(),
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
@@ -0,0 +1,35 @@
+use axum::{Router, middleware, routing::get};
+use utoipa::OpenApi;
+
+use ferriskey_api_core::{app_state::AppState, auth::auth};
+
+use super::handlers::{
+    metadata::{__path_get_fido_metadata, get_fido_metadata},
+    policy::{
+        __path_get_webauthn_policy, __path_update_webauthn_policy, get_webauthn_policy,
+        update_webauthn_policy,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(get_webauthn_policy, update_webauthn_policy, get_fido_metadata))]
+pub struct WebAuthnPolicyApiDoc;
+
+pub fn webauthn_policy_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/webauthn-policy",
+                state.args.server.root_path
+            ),
+            get(get_webauthn_policy).put(update_webauthn_policy),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/webauthn-policy/fido-metadata",
+                state.args.server.root_path
+            ),
+            get(get_fido_metadata),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
@@ -0,0 +1,45 @@
+use ferriskey_core::domain::webauthn_policy::{
+    AttestationConveyance, AuthenticatorAttachment, UserVerificationRequirement,
+    WebAuthnPolicySettings,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateWebAuthnPolicyValidator {
+    pub attestation: AttestationConveyance,
+    pub user_verification: UserVerificationRequirement,
+    pub require_resident_key: bool,
+    /// `null` lets the user choose.
+    pub authenticator_attachment: Option<AuthenticatorAttachment>,
+    /// Origins accepted besides the one of the login portal, e.g. `https://login.acme.com`.
+    #[validate(length(max = 20, message = "at most 20 additional origins are allowed"))]
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
+    /// When not empty, only these authenticator models are accepted.
+    #[validate(length(max = 500, message = "at most 500 AAGUIDs can be allowed"))]
+    #[serde(default)]
+    pub allowed_aaguids: Vec<Uuid>,
+    #[validate(length(max = 500, message = "at most 500 AAGUIDs can be blocked"))]
+    #[serde(default)]
+    pub blocked_aaguids: Vec<Uuid>,
+    #[serde(default)]
+    pub fips_certified_only: bool,
+}
+
+impl From<UpdateWebAuthnPolicyValidator> for WebAuthnPolicySettings {
+    fn from(payload: UpdateWebAuthnPolicyValidator) -> Self {
+        WebAuthnPolicySettings {
+            attestation: payload.attestation,
+            user_verification: payload.user_verification,
+            require_resident_key: payload.require_resident_key,
+            authenticator_attachment: payload.authenticator_attachment,
+            allowed_origins: payload.allowed_origins,
+            allowed_aaguids: payload.allowed_aaguids,
+            blocked_aaguids: payload.blocked_aaguids,
+            fips_certified_only: payload.fips_certified_only,
+        }
+    }
+}
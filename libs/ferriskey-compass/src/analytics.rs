@@ -0,0 +1,377 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::value_objects::{
+    FlowAnalytics, FunnelEntry, FunnelStage, LoginFunnel, StepLatency, StepLatencyDelta, StepReach,
+};
+
+fn ratio(part: i64, whole: i64) -> f64 {
+    if whole <= 0 {
+        0.0
+    } else {
+        part as f64 / whole as f64
+    }
+}
+
+/// Assembles one funnel per client and grant type. Stages keep only the steps that were
+/// recorded for that pair, in login order, so a password grant does not show broker steps.
+pub fn build_funnels(entries: Vec<FunnelEntry>, reaches: Vec<StepReach>) -> Vec<LoginFunnel> {
+    let mut stages_by_funnel: BTreeMap<(Option<String>, String), Vec<StepReach>> = BTreeMap::new();
+    for reach in reaches {
+        stages_by_funnel
+            .entry((reach.client_id.clone(), reach.grant_type.clone()))
+            .or_default()
+            .push(reach);
+    }
+
+    let mut funnels: Vec<LoginFunnel> = entries
+        .into_iter()
+        .map(|entry| {
+            let mut reaches = stages_by_funnel
+                .remove(&(entry.client_id.clone(), entry.grant_type.clone()))
+                .unwrap_or_default();
+            reaches.sort_by_key(|reach| reach.step_name.funnel_position());
+
+            let mut previous = entry.started;
+            let stages = reaches
+                .into_iter()
+                .map(|reach| {
+                    let stage = FunnelStage {
+                        conversion_rate: ratio(reach.reached, entry.started),
+                        drop_off_rate: ratio((previous - reach.reached).max(0), previous),
+                        step_name: reach.step_name,
+                        reached: reach.reached,
+                        succeeded: reach.succeeded,
+                        failed: reach.failed,
+                        skipped: reach.skipped,
+                    };
+                    previous = reach.reached;
+                    stage
+                })
+                .collect();
+
+            LoginFunnel {
+                completion_rate: ratio(entry.succeeded, entry.started),
+                client_id: entry.client_id,
+                grant_type: entry.grant_type,
+                started: entry.started,
+                succeeded: entry.succeeded,
+                stages,
+            }
+        })
+        .collect();
+
+    funnels.sort_by_key(|funnel| std::cmp::Reverse(funnel.started));
+    funnels
+}
+
+/// Completion rate across every funnel of the range, `None` when no flow started.
+pub fn overall_completion_rate(analytics: &FlowAnalytics) -> Option<f64> {
+    let (started, succeeded) = analytics
+        .funnels
+        .iter()
+        .fold((0, 0), |(started, succeeded), funnel| {
+            (started + funnel.started, succeeded + funnel.succeeded)
+        });
+
+    (started > 0).then(|| ratio(succeeded, started))
+}
+
+/// Latency change of every step seen in either range, current minus baseline.
+pub fn step_latency_deltas(
+    baseline: &FlowAnalytics,
+    current: &FlowAnalytics,
+) -> Vec<StepLatencyDelta> {
+    let mut steps: Vec<_> = baseline
+        .step_latencies
+        .iter()
+        .chain(current.step_latencies.iter())
+        .map(|latency| latency.step_name.clone())
+        .collect();
+    steps.sort_by_key(|step| step.funnel_position());
+    steps.dedup();
+
+    steps
+        .into_iter()
+        .map(|step_name| {
+            let before = baseline
+                .step_latencies
+                .iter()
+                .find(|latency| latency.step_name == step_name);
+            let after = current
+                .step_latencies
+                .iter()
+                .find(|latency| latency.step_name == step_name);
+
+            let delta = |pick: fn(&StepLatency) -> f64| match (before, after) {
+                (Some(before), Some(after)) => Some(pick(after) - pick(before)),
+                _ => None,
+            };
+
+            StepLatencyDelta {
+                p50_ms: delta(|latency| latency.p50_ms),
+                p90_ms: delta(|latency| latency.p90_ms),
+                p99_ms: delta(|latency| latency.p99_ms),
+                step_name,
+            }
+        })
+        .collect()
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn write_header(out: &mut String, name: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} gauge");
+}
+
+/// Renders the aggregates in the Prometheus text exposition format. Everything is a gauge
+/// over the analysed range; hourly buckets are left out since Prometheus keeps its own
+/// history between scrapes.
+pub fn render_prometheus(realm_name: &str, analytics: &FlowAnalytics) -> String {
+    let realm = escape_label(realm_name);
+    let mut out = String::new();
+
+    let funnel_labels = |funnel: &LoginFunnel| {
+        format!(
+            "realm=\"{realm}\",client_id=\"{}\",grant_type=\"{}\"",
+            escape_label(funnel.client_id.as_deref().unwrap_or("")),
+            escape_label(&funnel.grant_type)
+        )
+    };
+
+    write_header(
+        &mut out,
+        "ferriskey_compass_flows_started",
+        "Login flows started in the analysed range.",
+    );
+    for funnel in &analytics.funnels {
+        let _ = writeln!(
+            out,
+            "ferriskey_compass_flows_started{{{}}} {}",
+            funnel_labels(funnel),
+            funnel.started
+        );
+    }
+
+    write_header(
+        &mut out,
+        "ferriskey_compass_flows_succeeded",
+        "Login flows that completed successfully in the analysed range.",
+    );
+    for funnel in &analytics.funnels {
+        let _ = writeln!(
+            out,
+            "ferriskey_compass_flows_succeeded{{{}}} {}",
+            funnel_labels(funnel),
+            funnel.succeeded
+        );
+    }
+
+    write_header(
+        &mut out,
+        "ferriskey_compass_funnel_step_reached",
+        "Login flows that reached a funnel step.",
+    );
+    for funnel in &analytics.funnels {
+        for stage in &funnel.stages {
+            let _ = writeln!(
+                out,
+                "ferriskey_compass_funnel_step_reached{{{},step=\"{}\"}} {}",
+                funnel_labels(funnel),
+                stage.step_name,
+                stage.reached
+            );
+        }
+    }
+
+    write_header(
+        &mut out,
+        "ferriskey_compass_funnel_step_conversion_ratio",
+        "Share of the started login flows that reached a funnel step.",
+    );
+    for funnel in &analytics.funnels {
+        for stage in &funnel.stages {
+            let _ = writeln!(
+                out,
+                "ferriskey_compass_funnel_step_conversion_ratio{{{},step=\"{}\"}} {}",
+                funnel_labels(funnel),
+                stage.step_name,
+                stage.conversion_rate
+            );
+        }
+    }
+
+    write_header(
+        &mut out,
+        "ferriskey_compass_step_latency_milliseconds",
+        "Step latency percentiles in the analysed range.",
+    );
+    for latency in &analytics.step_latencies {
+        for (quantile, value) in [
+            ("0.5", latency.p50_ms),
+            ("0.9", latency.p90_ms),
+            ("0.99", latency.p99_ms),
+        ] {
+            let _ = writeln!(
+                out,
+                "ferriskey_compass_step_latency_milliseconds{{realm=\"{realm}\",step=\"{}\",quantile=\"{quantile}\"}} {value}",
+                latency.step_name
+            );
+        }
+    }
+
+    write_header(
+        &mut out,
+        "ferriskey_compass_step_failures",
+        "Failed steps by error code in the analysed range.",
+    );
+    for failure in &analytics.failure_reasons {
+        let _ = writeln!(
+            out,
+            "ferriskey_compass_step_failures{{realm=\"{realm}\",step=\"{}\",error_code=\"{}\"}} {}",
+            failure.step_name,
+            escape_label(&failure.error_code),
+            failure.occurrences
+        );
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::entities::FlowStepName;
+    use crate::value_objects::FailureReason;
+
+    fn reach(step_name: FlowStepName, reached: i64) -> StepReach {
+        StepReach {
+            client_id: Some("web".to_string()),
+            grant_type: "authorization_code".to_string(),
+            step_name,
+            reached,
+            succeeded: reached,
+            failed: 0,
+            skipped: 0,
+        }
+    }
+
+    fn analytics(step_latencies: Vec<StepLatency>) -> FlowAnalytics {
+        FlowAnalytics {
+            from: Utc::now(),
+            to: Utc::now(),
+            funnels: Vec::new(),
+            step_latencies,
+            failure_reasons: Vec::new(),
+            hourly: Vec::new(),
+        }
+    }
+
+    fn latency(step_name: FlowStepName, p50_ms: f64) -> StepLatency {
+        StepLatency {
+            step_name,
+            samples: 10,
+            p50_ms,
+            p90_ms: p50_ms * 2.0,
+            p99_ms: p50_ms * 4.0,
+            max_ms: (p50_ms * 5.0) as i64,
+        }
+    }
+
+    #[test]
+    fn funnel_stages_follow_login_order_and_measure_drop_off() {
+        let funnels = build_funnels(
+            vec![FunnelEntry {
+                client_id: Some("web".to_string()),
+                grant_type: "authorization_code".to_string(),
+                started: 100,
+                succeeded: 40,
+            }],
+            vec![
+                reach(FlowStepName::TokenExchange, 40),
+                reach(FlowStepName::Authorize, 100),
+                reach(FlowStepName::MfaChallenge, 50),
+                reach(FlowStepName::CredentialValidation, 80),
+            ],
+        );
+
+        assert_eq!(funnels.len(), 1);
+        let funnel = &funnels[0];
+        assert_eq!(funnel.completion_rate, 0.4);
+
+        let steps: Vec<_> = funnel.stages.iter().map(|s| s.step_name.clone()).collect();
+        assert_eq!(
+            steps,
+            vec![
+                FlowStepName::Authorize,
+                FlowStepName::CredentialValidation,
+                FlowStepName::MfaChallenge,
+                FlowStepName::TokenExchange,
+            ]
+        );
+        assert_eq!(funnel.stages[1].conversion_rate, 0.8);
+        assert_eq!(funnel.stages[1].drop_off_rate, 0.2);
+        assert_eq!(funnel.stages[2].drop_off_rate, 0.375);
+    }
+
+    #[test]
+    fn funnel_without_steps_has_no_stages() {
+        let funnels = build_funnels(
+            vec![FunnelEntry {
+                client_id: None,
+                grant_type: "client_credentials".to_string(),
+                started: 0,
+                succeeded: 0,
+            }],
+            Vec::new(),
+        );
+
+        assert!(funnels[0].stages.is_empty());
+        assert_eq!(funnels[0].completion_rate, 0.0);
+    }
+
+    #[test]
+    fn latency_deltas_only_compare_steps_present_in_both_ranges() {
+        let baseline = analytics(vec![latency(FlowStepName::CredentialValidation, 100.0)]);
+        let current = analytics(vec![
+            latency(FlowStepName::CredentialValidation, 150.0),
+            latency(FlowStepName::MfaChallenge, 900.0),
+        ]);
+
+        let deltas = step_latency_deltas(&baseline, &current);
+
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].step_name, FlowStepName::CredentialValidation);
+        assert_eq!(deltas[0].p50_ms, Some(50.0));
+        assert_eq!(deltas[0].p99_ms, Some(200.0));
+        assert_eq!(deltas[1].p50_ms, None);
+    }
+
+    #[test]
+    fn prometheus_export_escapes_label_values() {
+        let mut analytics = analytics(vec![latency(FlowStepName::Authorize, 12.5)]);
+        analytics.failure_reasons.push(FailureReason {
+            step_name: FlowStepName::CredentialValidation,
+            error_code: "bad \"password\"".to_string(),
+            occurrences: 3,
+        });
+
+        let output = render_prometheus("main", &analytics);
+
+        assert!(output.contains("# TYPE ferriskey_compass_step_latency_milliseconds gauge"));
+        assert!(output.contains(
+            "ferriskey_compass_step_latency_milliseconds{realm=\"main\",step=\"authorize\",quantile=\"0.99\"} 50"
+        ));
+        assert!(output.contains(
+            "ferriskey_compass_step_failures{realm=\"main\",step=\"credential_validation\",error_code=\"bad \\\"password\\\"\"} 3"
+        ));
+    }
+}
@@ -108,7 +108,7 @@ impl Display for StepStatus {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 pub enum FlowStepName {
     #[serde(rename = "authorize")]
     Authorize,
@@ -146,6 +146,35 @@ impl Display for FlowStepName {
     }
 }
 
+impl FlowStepName {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "authorize" => Some(FlowStepName::Authorize),
+            "credential_validation" => Some(FlowStepName::CredentialValidation),
+            "mfa_challenge" => Some(FlowStepName::MfaChallenge),
+            "token_exchange" => Some(FlowStepName::TokenExchange),
+            "idp_redirect" => Some(FlowStepName::IdpRedirect),
+            "idp_callback" => Some(FlowStepName::IdpCallback),
+            "finalize" => Some(FlowStepName::Finalize),
+            _ => None,
+        }
+    }
+
+    /// Where the step happens in a login, used to order funnel stages. Brokered logins
+    /// go through the identity provider where local ones validate credentials.
+    pub fn funnel_position(&self) -> u8 {
+        match self {
+            FlowStepName::Authorize => 0,
+            FlowStepName::IdpRedirect => 1,
+            FlowStepName::IdpCallback => 2,
+            FlowStepName::CredentialValidation => 3,
+            FlowStepName::MfaChallenge => 4,
+            FlowStepName::TokenExchange => 5,
+            FlowStepName::Finalize => 6,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
 pub struct CompassFlow {
     pub id: FlowId,
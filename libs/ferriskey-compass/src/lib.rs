@@ -1,3 +1,4 @@
+pub mod analytics;
 pub mod entities;
 pub mod ports;
 pub mod recorder;
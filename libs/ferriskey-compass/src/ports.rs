@@ -11,7 +11,9 @@ use uuid::Uuid;
 use crate::{
     entities::{CompassFlow, CompassFlowStep, FlowStatus},
     value_objects::{
-        DailyActivityStats, DailyActivityStatsFilter, FetchFlowsInput, FlowFilter, FlowStats,
+        DailyActivityStats, DailyActivityStatsFilter, FailureReason, FetchFlowsInput,
+        FlowAnalytics, FlowAnalyticsComparison, FlowAnalyticsFilter, FlowFilter, FlowStats,
+        FunnelEntry, HourlyFlowBucket, StepLatency, StepReach,
     },
 };
 
@@ -41,6 +43,29 @@ pub trait CompassService: Send + Sync {
         realm_name: String,
         filter: DailyActivityStatsFilter,
     ) -> impl Future<Output = Result<Vec<DailyActivityStats>, CoreError>> + Send;
+
+    fn get_flow_analytics(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        filter: FlowAnalyticsFilter,
+    ) -> impl Future<Output = Result<FlowAnalytics, CoreError>> + Send;
+
+    fn compare_flow_analytics(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        baseline: FlowAnalyticsFilter,
+        current: FlowAnalyticsFilter,
+    ) -> impl Future<Output = Result<FlowAnalyticsComparison, CoreError>> + Send;
+
+    /// The analytics of the range in the Prometheus text exposition format.
+    fn export_flow_metrics(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        filter: FlowAnalyticsFilter,
+    ) -> impl Future<Output = Result<String, CoreError>> + Send;
 }
 
 pub trait CompassPolicy: Send + Sync {
@@ -96,6 +121,19 @@ pub trait CompassFlowRepository: Send + Sync {
         realm_id: RealmId,
         filter: DailyActivityStatsFilter,
     ) -> impl Future<Output = Result<Vec<DailyActivityStats>, CoreError>> + Send;
+
+    fn get_funnel_entries(
+        &self,
+        realm_id: RealmId,
+        filter: FlowAnalyticsFilter,
+    ) -> impl Future<Output = Result<Vec<FunnelEntry>, CoreError>> + Send;
+
+    /// One bucket per hour of the range, including the hours without flows.
+    fn get_hourly_buckets(
+        &self,
+        realm_id: RealmId,
+        filter: FlowAnalyticsFilter,
+    ) -> impl Future<Output = Result<Vec<HourlyFlowBucket>, CoreError>> + Send;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -109,4 +147,23 @@ pub trait CompassFlowStepRepository: Send + Sync {
         &self,
         flow_id: Uuid,
     ) -> impl Future<Output = Result<Vec<CompassFlowStep>, CoreError>> + Send;
+
+    fn get_step_reach(
+        &self,
+        realm_id: RealmId,
+        filter: FlowAnalyticsFilter,
+    ) -> impl Future<Output = Result<Vec<StepReach>, CoreError>> + Send;
+
+    fn get_step_latencies(
+        &self,
+        realm_id: RealmId,
+        filter: FlowAnalyticsFilter,
+    ) -> impl Future<Output = Result<Vec<StepLatency>, CoreError>> + Send;
+
+    /// Failed steps grouped by step and error code, most frequent first.
+    fn get_failure_reasons(
+        &self,
+        realm_id: RealmId,
+        filter: FlowAnalyticsFilter,
+    ) -> impl Future<Output = Result<Vec<FailureReason>, CoreError>> + Send;
 }
@@ -4,14 +4,19 @@ use ferriskey_domain::auth::Identity;
 use ferriskey_domain::client::ports::ClientRepository;
 use ferriskey_domain::common::app_errors::CoreError;
 use ferriskey_domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use ferriskey_domain::realm::Realm;
 use ferriskey_domain::realm::ports::RealmRepository;
 use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
 use uuid::Uuid;
 
 use crate::{
+    analytics::{build_funnels, overall_completion_rate, render_prometheus, step_latency_deltas},
     entities::CompassFlow,
     ports::{CompassFlowRepository, CompassFlowStepRepository, CompassPolicy, CompassService},
-    value_objects::{DailyActivityStats, DailyActivityStatsFilter, FetchFlowsInput, FlowStats},
+    value_objects::{
+        DailyActivityStats, DailyActivityStatsFilter, FetchFlowsInput, FlowAnalytics,
+        FlowAnalyticsComparison, FlowAnalyticsFilter, FlowStats,
+    },
 };
 
 #[derive(Clone, Debug)]
@@ -52,6 +57,64 @@ where
             policy,
         }
     }
+
+    async fn authorized_realm(
+        &self,
+        identity: &Identity,
+        realm_name: &str,
+    ) -> Result<Realm, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        ensure_policy(
+            self.policy.can_view_flows(identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        Ok(realm)
+    }
+
+    async fn analytics_for(
+        &self,
+        realm: &Realm,
+        filter: FlowAnalyticsFilter,
+    ) -> Result<FlowAnalytics, CoreError> {
+        let (from, to) = (filter.from_timestamp, filter.to_timestamp);
+
+        let entries = self
+            .flow_repository
+            .get_funnel_entries(realm.id, filter.clone())
+            .await?;
+        let reaches = self
+            .step_repository
+            .get_step_reach(realm.id, filter.clone())
+            .await?;
+        let step_latencies = self
+            .step_repository
+            .get_step_latencies(realm.id, filter.clone())
+            .await?;
+        let failure_reasons = self
+            .step_repository
+            .get_failure_reasons(realm.id, filter.clone())
+            .await?;
+        let hourly = self
+            .flow_repository
+            .get_hourly_buckets(realm.id, filter)
+            .await?;
+
+        Ok(FlowAnalytics {
+            from,
+            to,
+            funnels: build_funnels(entries, reaches),
+            step_latencies,
+            failure_reasons,
+            hourly,
+        })
+    }
 }
 
 impl<R, U, C, UR, FR, FS> CompassService for CompassServiceImpl<R, U, C, UR, FR, FS>
@@ -172,4 +235,53 @@ where
 
         Ok(stats)
     }
+
+    async fn get_flow_analytics(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        filter: FlowAnalyticsFilter,
+    ) -> Result<FlowAnalytics, CoreError> {
+        let realm = self.authorized_realm(&identity, &realm_name).await?;
+
+        self.analytics_for(&realm, filter).await
+    }
+
+    async fn compare_flow_analytics(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        baseline: FlowAnalyticsFilter,
+        current: FlowAnalyticsFilter,
+    ) -> Result<FlowAnalyticsComparison, CoreError> {
+        let realm = self.authorized_realm(&identity, &realm_name).await?;
+
+        let baseline = self.analytics_for(&realm, baseline).await?;
+        let current = self.analytics_for(&realm, current).await?;
+
+        let completion_rate_delta = overall_completion_rate(&baseline)
+            .zip(overall_completion_rate(&current))
+            .map(|(before, after)| after - before);
+        let step_latency_deltas = step_latency_deltas(&baseline, &current);
+
+        Ok(FlowAnalyticsComparison {
+            baseline,
+            current,
+            completion_rate_delta,
+            step_latency_deltas,
+        })
+    }
+
+    async fn export_flow_metrics(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        filter: FlowAnalyticsFilter,
+    ) -> Result<String, CoreError> {
+        let realm = self.authorized_realm(&identity, &realm_name).await?;
+
+        let analytics = self.analytics_for(&realm, filter).await?;
+
+        Ok(render_prometheus(&realm.name, &analytics))
+    }
 }
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::entities::FlowStepName;
+
 #[derive(Debug, Clone)]
 pub struct FlowFilter {
     pub client_id: Option<String>,
@@ -65,3 +67,115 @@ pub struct DailyActivityStats {
     pub unique_login_users: i64,
     pub avg_login_duration_ms: Option<f64>,
 }
+
+/// Time range and dimensions the funnel and latency analytics are computed over.
+#[derive(Debug, Clone)]
+pub struct FlowAnalyticsFilter {
+    pub from_timestamp: DateTime<Utc>,
+    pub to_timestamp: DateTime<Utc>,
+    pub client_id: Option<String>,
+    pub grant_type: Option<String>,
+}
+
+/// Flows started for one client and grant type, the top of their funnel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunnelEntry {
+    pub client_id: Option<String>,
+    pub grant_type: String,
+    pub started: i64,
+    pub succeeded: i64,
+}
+
+/// Distinct flows of one client and grant type that recorded a step, split by how it ended.
+/// A flow that retried a step can count as both failed and succeeded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepReach {
+    pub client_id: Option<String>,
+    pub grant_type: String,
+    pub step_name: FlowStepName,
+    pub reached: i64,
+    pub succeeded: i64,
+    pub failed: i64,
+    pub skipped: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FunnelStage {
+    pub step_name: FlowStepName,
+    pub reached: i64,
+    pub succeeded: i64,
+    pub failed: i64,
+    pub skipped: i64,
+    /// Share of the started flows that reached this stage.
+    pub conversion_rate: f64,
+    /// Share of the flows that reached the previous stage and never reached this one.
+    pub drop_off_rate: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct LoginFunnel {
+    pub client_id: Option<String>,
+    pub grant_type: String,
+    pub started: i64,
+    pub succeeded: i64,
+    pub completion_rate: f64,
+    pub stages: Vec<FunnelStage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct StepLatency {
+    pub step_name: FlowStepName,
+    pub samples: i64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FailureReason {
+    pub step_name: FlowStepName,
+    /// The recorded error code, or `unknown` when the step failed without one.
+    pub error_code: String,
+    pub occurrences: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct HourlyFlowBucket {
+    pub hour: DateTime<Utc>,
+    pub total_flows: i64,
+    pub succeeded: i64,
+    pub failed: i64,
+    pub p50_duration_ms: Option<f64>,
+    pub p90_duration_ms: Option<f64>,
+    pub p99_duration_ms: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FlowAnalytics {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub funnels: Vec<LoginFunnel>,
+    pub step_latencies: Vec<StepLatency>,
+    pub failure_reasons: Vec<FailureReason>,
+    pub hourly: Vec<HourlyFlowBucket>,
+}
+
+/// Change of a step's latency percentiles from the baseline range to the current one.
+/// A percentile is `None` when the step has no samples in either range.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct StepLatencyDelta {
+    pub step_name: FlowStepName,
+    pub p50_ms: Option<f64>,
+    pub p90_ms: Option<f64>,
+    pub p99_ms: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FlowAnalyticsComparison {
+    pub baseline: FlowAnalytics,
+    pub current: FlowAnalytics,
+    /// Change of the overall completion rate, `None` when either range has no flows.
+    pub completion_rate_delta: Option<f64>,
+    pub step_latency_deltas: Vec<StepLatencyDelta>,
+}
@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::net::IpAddr;
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -205,6 +206,8 @@ pub struct AuthInput {
     pub nonce: Option<String>,
     pub code_challenge: Option<String>,
     pub code_challenge_method: Option<CodeChallengeMethod>,
+    /// Space-separated locales the client wants the login pages in (OIDC `ui_locales`).
+    pub ui_locales: Option<String>,
 }
 
 pub struct ExchangeTokenInput {
@@ -236,6 +239,9 @@ pub struct AuthenticateInput {
     pub session_code: Uuid,
     pub base_url: String,
     pub auth_method: AuthenticationMethod,
+    /// Where the sign-in comes from, for login risk scoring.
+    pub client_ip: Option<IpAddr>,
+    pub user_agent: Option<String>,
 }
 
 impl AuthenticateInput {
@@ -253,6 +259,8 @@ impl AuthenticateInput {
             session_code,
             base_url,
             auth_method: AuthenticationMethod::UserCredentials { username, password },
+            client_ip: None,
+            user_agent: None,
         }
     }
 
@@ -269,9 +277,39 @@ impl AuthenticateInput {
             session_code,
             base_url,
             auth_method: AuthenticationMethod::ExistingToken { token },
+            client_ip: None,
+            user_agent: None,
         }
     }
 
+    pub fn with_negotiate_token(
+        realm_name: String,
+        client_id: String,
+        session_code: Uuid,
+        base_url: String,
+        token: String,
+    ) -> Self {
+        Self {
+            realm_name,
+            client_id,
+            session_code,
+            base_url,
+            auth_method: AuthenticationMethod::Negotiate { token },
+            client_ip: None,
+            user_agent: None,
+        }
+    }
+
+    pub fn with_client_context(
+        mut self,
+        client_ip: Option<IpAddr>,
+        user_agent: Option<String>,
+    ) -> Self {
+        self.client_ip = client_ip;
+        self.user_agent = user_agent;
+        self
+    }
+
     pub fn is_token_refresh(&self) -> bool {
         matches!(self.auth_method, AuthenticationMethod::ExistingToken { .. })
     }
@@ -356,6 +394,8 @@ pub struct CredentialsAuthParams {
     pub base_url: String,
     pub username: String,
     pub password: String,
+    pub client_ip: Option<IpAddr>,
+    pub user_agent: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -368,8 +408,17 @@ pub enum AuthenticationStepStatus {
 
 #[derive(Debug, Clone)]
 pub enum AuthenticationMethod {
-    UserCredentials { username: String, password: String },
-    ExistingToken { token: String },
+    UserCredentials {
+        username: String,
+        password: String,
+    },
+    ExistingToken {
+        token: String,
+    },
+    /// A base64 SPNEGO token from an `Authorization: Negotiate` header.
+    Negotiate {
+        token: String,
+    },
 }
 
 #[cfg(test)]
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -92,6 +94,9 @@ pub struct RegisterUserInput {
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub session_code: Option<Uuid>,
+    /// Values for the attributes the realm's user profile lets users fill in.
+    #[serde(default)]
+    pub attributes: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
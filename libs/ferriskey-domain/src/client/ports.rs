@@ -96,6 +96,13 @@ pub trait ClientService: Send + Sync {
         input: GetClientInput,
     ) -> impl Future<Output = Result<Option<String>, CoreError>> + Send;
 
+    /// Replaces a confidential client's secret with a new random one and returns it.
+    fn rotate_client_secret(
+        &self,
+        identity: Identity,
+        input: GetClientInput,
+    ) -> impl Future<Output = Result<String, CoreError>> + Send;
+
     fn get_redirect_uris(
         &self,
         identity: Identity,
@@ -177,6 +184,13 @@ pub trait ClientRepository: Send + Sync {
         data: UpdateClientRequest,
     ) -> impl Future<Output = Result<Client, CoreError>> + Send;
 
+    fn update_secret(
+        &self,
+        realm_id: RealmId,
+        client_id: Uuid,
+        secret: String,
+    ) -> impl Future<Output = Result<Client, CoreError>> + Send;
+
     fn delete_by_id(
         &self,
         realm_id: RealmId,
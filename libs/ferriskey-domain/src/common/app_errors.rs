@@ -222,6 +222,10 @@ pub enum CoreError {
     #[error("Federation authentication error: {0}")]
     FederationAuthenticationFailed(String),
 
+    // SAML errors
+    #[error("Invalid SAML message: {0}")]
+    InvalidSamlRequest(String),
+
     // Broker (SSO) errors
     #[error("Broker session not found")]
     BrokerSessionNotFound,
@@ -247,6 +251,12 @@ pub enum CoreError {
     #[error("Link only mode - user not found")]
     LinkOnlyUserNotFound,
 
+    #[error("Existing account could not be confirmed")]
+    BrokerLinkConfirmationFailed,
+
+    #[error("The post broker login flow requires an OTP the account does not have")]
+    BrokerLoginOtpNotConfigured,
+
     #[error("Identity provider link not found")]
     LinkNotFound,
 
@@ -293,12 +303,18 @@ pub enum CoreError {
     #[error("Account is temporarily locked due to too many failed login attempts")]
     AccountLocked,
 
+    #[error("Too many requests, retry in {retry_after_seconds} seconds")]
+    RateLimited { retry_after_seconds: u64 },
+
     #[error("Client is under maintenance: {0}")]
     ClientUnderMaintenance(String),
 
     #[error("Email template not found")]
     EmailTemplateNotFound,
 
+    #[error("Email template is not a {0} template")]
+    EmailTemplateTypeMismatch(String),
+
     #[error("No active email template for type: {0}")]
     NoActiveEmailTemplate(String),
 
@@ -331,6 +347,33 @@ pub enum CoreError {
 
     #[error("Password policy violated: {0}")]
     PasswordPolicyViolation(String),
+
+    #[error("Invitation is invalid, expired, revoked or already accepted")]
+    InvalidOrganizationInvitation,
+
+    #[error("Domain verification failed: {0}")]
+    DomainVerificationFailed(String),
+
+    #[error("User profile violated: {0}")]
+    UserProfileViolation(String),
+
+    #[error("Invalid localization: {0}")]
+    InvalidLocalization(String),
+
+    #[error("Invalid WebAuthn policy: {0}")]
+    InvalidWebAuthnPolicy(String),
+
+    #[error("Authenticator not allowed: {0}")]
+    WebAuthnAuthenticatorRejected(String),
+
+    #[error("Invalid maintenance window: {0}")]
+    InvalidMaintenanceWindow(String),
+
+    #[error("Outbox email not found")]
+    OutboxEmailNotFound,
+
+    #[error("Outbox email is still queued for delivery")]
+    OutboxEmailStillQueued,
 }
 
 impl From<AuthenticationError> for CoreError {
@@ -1,3 +1,4 @@
 pub mod app_errors;
 pub mod email;
 pub mod policies;
+pub mod security_notification;
@@ -0,0 +1,134 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::realm::RealmId;
+
+/// Emails telling a user that something security-sensitive happened to their account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityNotificationKind {
+    PasswordChanged,
+    /// A sign-in from a device or location the risk engine has not seen for this user.
+    NewSignIn,
+    AccountLocked,
+    MfaFactorAdded,
+    MfaFactorRemoved,
+    /// Sent to the previous address, the one an attacker cannot read.
+    EmailChanged,
+    IdentityProviderLinked,
+}
+
+impl SecurityNotificationKind {
+    pub const ALL: [SecurityNotificationKind; 7] = [
+        SecurityNotificationKind::PasswordChanged,
+        SecurityNotificationKind::NewSignIn,
+        SecurityNotificationKind::AccountLocked,
+        SecurityNotificationKind::MfaFactorAdded,
+        SecurityNotificationKind::MfaFactorRemoved,
+        SecurityNotificationKind::EmailChanged,
+        SecurityNotificationKind::IdentityProviderLinked,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecurityNotificationKind::PasswordChanged => "password_changed",
+            SecurityNotificationKind::NewSignIn => "new_sign_in",
+            SecurityNotificationKind::AccountLocked => "account_locked",
+            SecurityNotificationKind::MfaFactorAdded => "mfa_factor_added",
+            SecurityNotificationKind::MfaFactorRemoved => "mfa_factor_removed",
+            SecurityNotificationKind::EmailChanged => "email_changed",
+            SecurityNotificationKind::IdentityProviderLinked => "identity_provider_linked",
+        }
+    }
+}
+
+impl Display for SecurityNotificationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SecurityNotificationKind {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == value)
+            .ok_or_else(|| format!("unknown security notification: {value}"))
+    }
+}
+
+/// One notification to send: who it is about and the details the email shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityNotice {
+    pub realm_id: RealmId,
+    pub user_id: Uuid,
+    pub kind: SecurityNotificationKind,
+    /// Overrides the current address of the user.
+    pub recipient: Option<String>,
+    /// Template variables, e.g. `("ip_address", "203.0.113.7")`.
+    pub details: Vec<(String, String)>,
+}
+
+impl SecurityNotice {
+    pub fn new(realm_id: RealmId, user_id: Uuid, kind: SecurityNotificationKind) -> Self {
+        Self {
+            realm_id,
+            user_id,
+            kind,
+            recipient: None,
+            details: Vec::new(),
+        }
+    }
+
+    pub fn with_recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.recipient = Some(recipient.into());
+        self
+    }
+
+    pub fn with_detail(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.details.push((name.to_string(), value.into()));
+        self
+    }
+}
+
+/// Sends security notifications on behalf of the services that change accounts.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait SecurityNotifier: Send + Sync {
+    /// Never fails: a notification that cannot be sent is logged, and the action that
+    /// triggered it goes through regardless.
+    fn notify(&self, notice: SecurityNotice) -> impl Future<Output = ()> + Send;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_round_trips_through_its_name() {
+        for kind in SecurityNotificationKind::ALL {
+            assert_eq!(
+                kind.to_string().parse::<SecurityNotificationKind>(),
+                Ok(kind)
+            );
+        }
+        assert!(
+            "password_reset"
+                .parse::<SecurityNotificationKind>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn kind_serializes_as_its_name() {
+        assert_eq!(
+            serde_json::to_string(&SecurityNotificationKind::NewSignIn).unwrap(),
+            "\"new_sign_in\""
+        );
+    }
+}
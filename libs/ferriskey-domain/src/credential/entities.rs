@@ -19,6 +19,22 @@ pub struct Credential {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub webauthn_credential_id: Option<CredentialID>,
+    pub authenticator: Option<AuthenticatorInfo>,
+}
+
+/// What is known about the authenticator behind a WebAuthn credential.
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema,
+)]
+pub struct AuthenticatorInfo {
+    /// The model of the authenticator, known when it was registered with attestation.
+    pub aaguid: Option<Uuid>,
+    /// The model name, from the FIDO metadata.
+    pub name: Option<String>,
+    /// The last signature counter the authenticator reported. Authenticators that keep no
+    /// counter always report 0.
+    pub sign_count: Option<u32>,
+    pub last_used_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd)]
@@ -71,6 +87,7 @@ pub struct CredentialOverview {
     pub credential_type: String,
     pub user_label: Option<String>,
     pub credential_data: CredentialDataOverview,
+    pub authenticator: Option<AuthenticatorInfo>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -83,6 +100,7 @@ impl From<Credential> for CredentialOverview {
             credential_type: credential.credential_type.to_string(),
             user_label: credential.user_label,
             credential_data: credential.credential_data.into(),
+            authenticator: credential.authenticator,
             created_at: credential.created_at,
             updated_at: credential.updated_at,
         }
@@ -103,6 +121,7 @@ impl Credential {
             created_at: config.created_at,
             updated_at: config.updated_at,
             webauthn_credential_id: config.webauthn_credential_id,
+            authenticator: config.authenticator,
         }
     }
 }
@@ -195,6 +214,7 @@ pub struct CredentialConfig {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub webauthn_credential_id: Option<CredentialID>,
+    pub authenticator: Option<AuthenticatorInfo>,
 }
 
 #[derive(Debug, Clone, Error)]
@@ -234,5 +254,6 @@ pub struct GetCredentialsInput {
 
 pub struct DeleteCredentialInput {
     pub realm_name: String,
+    pub user_id: Uuid,
     pub credential_id: Uuid,
 }
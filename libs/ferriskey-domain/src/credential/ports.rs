@@ -4,7 +4,8 @@ use webauthn_rs::prelude::{AuthenticationResult, Passkey};
 use crate::auth::Identity;
 use crate::common::app_errors::CoreError;
 use crate::credential::entities::{
-    Credential, CredentialError, CredentialOverview, DeleteCredentialInput, GetCredentialsInput,
+    AuthenticatorInfo, Credential, CredentialError, CredentialOverview, DeleteCredentialInput,
+    GetCredentialsInput,
 };
 use crate::crypto::HashResult;
 
@@ -69,6 +70,7 @@ pub trait CredentialRepository: Send + Sync {
         &self,
         user_id: Uuid,
         webauthn_credential: Passkey,
+        authenticator: AuthenticatorInfo,
     ) -> impl Future<Output = Result<Credential, CredentialError>> + Send;
 
     fn get_webauthn_public_key_credentials(
@@ -82,7 +84,8 @@ pub trait CredentialRepository: Send + Sync {
         user_id: Uuid,
     ) -> impl Future<Output = Result<Option<Credential>, CredentialError>> + Send;
 
-    /// Sometimes webauthn credential needs an internal counter updated after auth attempt
+    /// Records a successful authentication: the sign count and last use, and the internal
+    /// counter of the credential when it needs an update.
     fn update_webauthn_credential(
         &self,
         auth_result: &AuthenticationResult,
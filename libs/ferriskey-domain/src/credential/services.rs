@@ -4,54 +4,65 @@ use crate::auth::Identity;
 use crate::client::ports::ClientRepository;
 use crate::common::app_errors::CoreError;
 use crate::common::policies::{FerriskeyPolicy, ensure_policy};
-use crate::credential::entities::{CredentialOverview, DeleteCredentialInput, GetCredentialsInput};
+use crate::common::security_notification::{
+    SecurityNotice, SecurityNotificationKind, SecurityNotifier,
+};
+use crate::credential::entities::{
+    CredentialOverview, CredentialType, DeleteCredentialInput, GetCredentialsInput,
+};
 use crate::credential::ports::{CredentialRepository, CredentialService};
 use crate::realm::ports::RealmRepository;
 use crate::user::ports::{UserPolicy, UserRepository, UserRoleRepository};
 
 #[derive(Clone, Debug)]
-pub struct CredentialServiceImpl<R, U, C, UR, CR>
+pub struct CredentialServiceImpl<R, U, C, UR, CR, SN>
 where
     R: RealmRepository,
     U: UserRepository,
     C: ClientRepository,
     UR: UserRoleRepository,
     CR: CredentialRepository,
+    SN: SecurityNotifier,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) credential_repository: Arc<CR>,
+    pub(crate) security_notifier: Arc<SN>,
 
     pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
 }
 
-impl<R, U, C, UR, CR> CredentialServiceImpl<R, U, C, UR, CR>
+impl<R, U, C, UR, CR, SN> CredentialServiceImpl<R, U, C, UR, CR, SN>
 where
     R: RealmRepository,
     U: UserRepository,
     C: ClientRepository,
     UR: UserRoleRepository,
     CR: CredentialRepository,
+    SN: SecurityNotifier,
 {
     pub fn new(
         realm_repository: Arc<R>,
         credential_repository: Arc<CR>,
+        security_notifier: Arc<SN>,
         policy: Arc<FerriskeyPolicy<U, C, UR>>,
     ) -> Self {
         Self {
             realm_repository,
             credential_repository,
+            security_notifier,
             policy,
         }
     }
 }
 
-impl<R, U, C, UR, CR> CredentialService for CredentialServiceImpl<R, U, C, UR, CR>
+impl<R, U, C, UR, CR, SN> CredentialService for CredentialServiceImpl<R, U, C, UR, CR, SN>
 where
     R: RealmRepository,
     U: UserRepository,
     C: ClientRepository,
     UR: UserRoleRepository,
     CR: CredentialRepository,
+    SN: SecurityNotifier,
 {
     async fn get_credentials(
         &self,
@@ -99,11 +110,37 @@ where
             "insufficient permissions",
         )?;
 
+        let credential = self
+            .credential_repository
+            .get_credentials_by_user_id(input.user_id)
+            .await
+            .map_err(|_| CoreError::GetUserCredentialsError)?
+            .into_iter()
+            .find(|credential| credential.id == input.credential_id);
+
         self.credential_repository
             .delete_by_id(input.credential_id)
             .await
             .map_err(|_| CoreError::DeleteCredentialError)?;
 
+        let factor = match credential.map(|credential| credential.credential_type) {
+            Some(CredentialType::Otp) => Some("authenticator app"),
+            Some(CredentialType::WebAuthnPublicKeyCredential) => Some("security key"),
+            _ => None,
+        };
+        if let Some(factor) = factor {
+            self.security_notifier
+                .notify(
+                    SecurityNotice::new(
+                        realm.id,
+                        input.user_id,
+                        SecurityNotificationKind::MfaFactorRemoved,
+                    )
+                    .with_detail("factor", factor),
+                )
+                .await;
+        }
+
         // @TODO: implement webhook notifier
 
         Ok(())
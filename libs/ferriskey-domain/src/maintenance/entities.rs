@@ -1,9 +1,15 @@
+use std::fmt;
+use std::str::FromStr;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::{generate_timestamp, realm::RealmId};
+use crate::{
+    client::entities::MaintenanceSessionStrategy, generate_timestamp,
+    maintenance::value_objects::ScheduleMaintenanceWindowRequest, realm::RealmId,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub struct MaintenanceWhitelistEntry {
@@ -70,3 +76,198 @@ impl RealmMaintenanceWhitelistEntry {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceWindowStatus {
+    Scheduled,
+    Active,
+    Completed,
+    Cancelled,
+}
+
+impl fmt::Display for MaintenanceWindowStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaintenanceWindowStatus::Scheduled => write!(f, "scheduled"),
+            MaintenanceWindowStatus::Active => write!(f, "active"),
+            MaintenanceWindowStatus::Completed => write!(f, "completed"),
+            MaintenanceWindowStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl FromStr for MaintenanceWindowStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "scheduled" => Ok(MaintenanceWindowStatus::Scheduled),
+            "active" => Ok(MaintenanceWindowStatus::Active),
+            "completed" => Ok(MaintenanceWindowStatus::Completed),
+            "cancelled" => Ok(MaintenanceWindowStatus::Cancelled),
+            _ => Err(format!("unknown maintenance window status: {s}")),
+        }
+    }
+}
+
+/// A planned maintenance of one client, or of every client of the realm. The scheduler
+/// switches client maintenance on at `starts_at` and off at `ends_at`; from `announce_at`
+/// the window is listed on the public announcements route.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct MaintenanceWindow {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    /// `None` for a realm-wide window.
+    pub client_id: Option<Uuid>,
+    pub reason: String,
+    pub session_strategy: MaintenanceSessionStrategy,
+    pub announce_at: DateTime<Utc>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub status: MaintenanceWindowStatus,
+    /// The clients this window put under maintenance when it started. Only those are
+    /// released at the end, so a client already under manual maintenance stays so.
+    pub affected_client_ids: Vec<Uuid>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MaintenanceWindow {
+    pub fn new(
+        realm_id: RealmId,
+        request: ScheduleMaintenanceWindowRequest,
+        created_by: Uuid,
+    ) -> Self {
+        let (now, timestamp) = generate_timestamp();
+        Self {
+            id: Uuid::new_v7(timestamp),
+            realm_id,
+            client_id: request.client_id,
+            reason: request.reason,
+            session_strategy: request.session_strategy.unwrap_or_default(),
+            announce_at: request.announce_at.unwrap_or(now).min(request.starts_at),
+            starts_at: request.starts_at,
+            ends_at: request.ends_at,
+            status: MaintenanceWindowStatus::Scheduled,
+            affected_client_ids: Vec::new(),
+            created_by,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Scheduled or in progress.
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self.status,
+            MaintenanceWindowStatus::Scheduled | MaintenanceWindowStatus::Active
+        )
+    }
+
+    pub fn is_announced(&self, now: DateTime<Utc>) -> bool {
+        self.is_pending() && self.announce_at <= now && now < self.ends_at
+    }
+
+    /// Whether both windows are pending, cover a common client and share some time. A
+    /// realm-wide window covers every client of its realm.
+    pub fn overlaps(&self, other: &MaintenanceWindow) -> bool {
+        let same_scope = self.client_id.is_none()
+            || other.client_id.is_none()
+            || self.client_id == other.client_id;
+
+        self.is_pending()
+            && other.is_pending()
+            && self.realm_id == other.realm_id
+            && same_scope
+            && self.starts_at < other.ends_at
+            && other.starts_at < self.ends_at
+    }
+
+    /// Rejects windows that end before they start or are already over at `now`.
+    pub fn validate(&self, now: DateTime<Utc>) -> Result<(), String> {
+        if self.reason.trim().is_empty() {
+            return Err("reason must not be empty".to_string());
+        }
+        if self.ends_at <= self.starts_at {
+            return Err("ends_at must be after starts_at".to_string());
+        }
+        if self.ends_at <= now {
+            return Err("ends_at must be in the future".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// What the login portal shows about a pending window.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct MaintenanceAnnouncement {
+    pub reason: String,
+    /// The OAuth `client_id` under maintenance, `None` when the whole realm is.
+    pub client_id: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub in_progress: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    fn window(client_id: Option<Uuid>, starts_in_hours: i64, hours: i64) -> MaintenanceWindow {
+        let starts_at = Utc::now() + Duration::hours(starts_in_hours);
+        MaintenanceWindow::new(
+            RealmId::new(Uuid::nil()),
+            ScheduleMaintenanceWindowRequest {
+                client_id,
+                reason: "Database upgrade".to_string(),
+                session_strategy: None,
+                announce_at: None,
+                starts_at,
+                ends_at: starts_at + Duration::hours(hours),
+            },
+            Uuid::nil(),
+        )
+    }
+
+    #[test]
+    fn overlaps_only_when_scope_and_time_intersect() {
+        let client = Some(Uuid::new_v4());
+        let other_client = Some(Uuid::new_v4());
+
+        assert!(window(client, 1, 2).overlaps(&window(client, 2, 2)));
+        assert!(!window(client, 1, 2).overlaps(&window(other_client, 2, 2)));
+        assert!(window(None, 1, 2).overlaps(&window(other_client, 2, 2)));
+        // Back-to-back windows share no instant.
+        assert!(!window(None, 1, 2).overlaps(&window(None, 3, 2)));
+
+        let mut cancelled = window(client, 2, 2);
+        cancelled.status = MaintenanceWindowStatus::Cancelled;
+        assert!(!window(client, 1, 2).overlaps(&cancelled));
+    }
+
+    #[test]
+    fn announces_from_announce_at_until_the_end() {
+        let mut upcoming = window(None, 24, 2);
+        let now = Utc::now();
+        assert!(upcoming.is_announced(now));
+
+        upcoming.announce_at = now + Duration::hours(12);
+        assert!(!upcoming.is_announced(now));
+        assert!(upcoming.is_announced(now + Duration::hours(25)));
+        assert!(!upcoming.is_announced(now + Duration::hours(26)));
+    }
+
+    #[test]
+    fn validates_the_time_range() {
+        let now = Utc::now();
+        assert!(window(None, 1, 2).validate(now).is_ok());
+        assert!(window(None, 1, 0).validate(now).is_err());
+        assert!(window(None, -3, 2).validate(now).is_err());
+        // A window that already started is accepted and activated on the next tick.
+        assert!(window(None, -1, 2).validate(now).is_ok());
+    }
+}
@@ -1,9 +1,15 @@
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::auth::Identity;
 use crate::common::app_errors::CoreError;
-use crate::maintenance::entities::{MaintenanceWhitelistEntry, RealmMaintenanceWhitelistEntry};
-use crate::maintenance::value_objects::ToggleMaintenanceRequest;
+use crate::maintenance::entities::{
+    MaintenanceAnnouncement, MaintenanceWhitelistEntry, MaintenanceWindow, MaintenanceWindowStatus,
+    RealmMaintenanceWhitelistEntry,
+};
+use crate::maintenance::value_objects::{
+    ScheduleMaintenanceWindowRequest, ToggleMaintenanceRequest,
+};
 use crate::realm::RealmId;
 
 pub trait MaintenanceWhitelistRepository: Send + Sync {
@@ -68,6 +74,54 @@ pub trait RealmMaintenanceWhitelistRepository: Send + Sync {
     ) -> impl Future<Output = Result<Vec<Uuid>, CoreError>> + Send;
 }
 
+pub trait MaintenanceWindowRepository: Send + Sync {
+    fn create(
+        &self,
+        window: MaintenanceWindow,
+    ) -> impl Future<Output = Result<MaintenanceWindow, CoreError>> + Send;
+
+    fn get(
+        &self,
+        realm_id: RealmId,
+        window_id: Uuid,
+    ) -> impl Future<Output = Result<Option<MaintenanceWindow>, CoreError>> + Send;
+
+    /// Every window of the realm, latest start first.
+    fn list_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Vec<MaintenanceWindow>, CoreError>> + Send;
+
+    /// Scheduled and active windows of the realm, earliest start first.
+    fn list_pending(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Vec<MaintenanceWindow>, CoreError>> + Send;
+
+    /// Scheduled windows whose start has passed and active windows whose end has, across
+    /// all realms.
+    fn list_due(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<MaintenanceWindow>, CoreError>> + Send;
+
+    /// Marks a scheduled window active with the clients it put under maintenance. Returns
+    /// `false` when the window was no longer scheduled, e.g. another instance started it.
+    fn start(
+        &self,
+        window_id: Uuid,
+        affected_client_ids: Vec<Uuid>,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    /// Moves a window from `from` to `to`. Returns `false` when it was no longer in `from`.
+    fn transition(
+        &self,
+        window_id: Uuid,
+        from: MaintenanceWindowStatus,
+        to: MaintenanceWindowStatus,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
 pub trait MaintenanceService: Send + Sync {
     fn toggle_maintenance(
         &self,
@@ -142,4 +196,37 @@ pub trait MaintenanceService: Send + Sync {
         identity: Identity,
         realm_name: String,
     ) -> impl Future<Output = Result<Vec<RealmMaintenanceWhitelistEntry>, CoreError>> + Send;
+
+    /// Plans a window; it must not overlap another pending window of the same scope.
+    fn schedule_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        request: ScheduleMaintenanceWindowRequest,
+    ) -> impl Future<Output = Result<MaintenanceWindow, CoreError>> + Send;
+
+    fn get_maintenance_windows(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<Vec<MaintenanceWindow>, CoreError>> + Send;
+
+    /// Cancels a scheduled window, or ends an active one now.
+    fn cancel_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        window_id: Uuid,
+    ) -> impl Future<Output = Result<MaintenanceWindow, CoreError>> + Send;
+
+    /// Announced windows, for the login portal. With `client_id` (the OAuth one), only the
+    /// realm-wide windows and those of that client.
+    fn get_maintenance_announcements(
+        &self,
+        realm_name: String,
+        client_id: Option<String>,
+    ) -> impl Future<Output = Result<Vec<MaintenanceAnnouncement>, CoreError>> + Send;
+
+    /// Starts and ends the windows that are due. Returns how many changed state.
+    fn run_due_maintenance_windows(&self) -> impl Future<Output = Result<usize, CoreError>> + Send;
 }
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -19,3 +20,15 @@ pub struct AddMaintenanceWhitelistUserRequest {
 pub struct AddMaintenanceWhitelistRoleRequest {
     pub role_id: Uuid,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleMaintenanceWindowRequest {
+    /// `None` puts every client of the realm under maintenance.
+    pub client_id: Option<Uuid>,
+    pub reason: String,
+    pub session_strategy: Option<MaintenanceSessionStrategy>,
+    /// Defaults to now: the window is announced as soon as it is scheduled.
+    pub announce_at: Option<DateTime<Utc>>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
@@ -54,6 +54,8 @@ pub struct SmtpConfig {
     pub from_email: String,
     pub from_name: String,
     pub encryption: SmtpEncryption,
+    /// Emails the outbox delivers per minute for the realm, unlimited when unset.
+    pub max_emails_per_minute: Option<u32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -205,6 +207,7 @@ pub struct RealmSetting {
     pub reset_password_template_id: Option<Uuid>,
     pub magic_link_template_id: Option<Uuid>,
     pub email_verification_template_id: Option<Uuid>,
+    pub organization_invitation_template_id: Option<Uuid>,
     pub email_verification_enabled: bool,
     pub email_verification_ttl_hours: i64,
     pub login_aliases: LoginAliases,
@@ -239,6 +242,7 @@ impl RealmSetting {
             reset_password_template_id: None,
             magic_link_template_id: None,
             email_verification_template_id: None,
+            organization_invitation_template_id: None,
             email_verification_enabled: false,
             email_verification_ttl_hours: 24,
             login_aliases: LoginAliases::default(),
@@ -80,6 +80,7 @@ pub trait RealmRepository: Send + Sync {
         reset_password_template_id: Option<Option<Uuid>>,
         magic_link_template_id: Option<Option<Uuid>>,
         email_verification_template_id: Option<Option<Uuid>>,
+        organization_invitation_template_id: Option<Option<Uuid>>,
         email_verification_enabled: Option<bool>,
         email_verification_ttl_hours: Option<i64>,
         lockout_threshold: Option<i32>,
@@ -11,6 +11,9 @@ pub struct UpdateUserInput {
     pub email_verified: Option<bool>,
     pub enabled: bool,
     pub required_actions: Option<Vec<String>>,
+    /// Attribute changes checked against the realm's user profile; an empty value clears
+    /// the attribute.
+    pub attributes: Option<HashMap<String, String>>,
 }
 
 pub struct UnassignRoleInput {
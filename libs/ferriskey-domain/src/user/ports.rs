@@ -228,10 +228,16 @@ pub trait UserRoleRepository: Send + Sync {
         user_id: Uuid,
         role_id: Uuid,
     ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Effective roles: assigned directly or inherited through the user's realm groups.
     fn get_user_roles(
         &self,
         user_id: Uuid,
     ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+    /// Roles assigned to the user directly, without realm group inheritance.
+    fn get_direct_user_roles(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
     /// Resolve a set of role ids to full `Role`s (with client populated). Used to turn
     /// group-inherited role ids into roles during token issuance.
     fn get_roles_by_ids(
@@ -0,0 +1,433 @@
+//! Texts shipped with FerrisKey. Realm bundles override them key by key; the English set is
+//! the last fallback for every locale.
+
+const EN: &[(&str, &str)] = &[
+    ("duration.minute", "1 minute"),
+    ("duration.minutes", "{{count}} minutes"),
+    ("duration.hour", "1 hour"),
+    ("duration.hours", "{{count}} hours"),
+    ("login.title", "Sign in to your account"),
+    ("login.username", "Username or email"),
+    ("login.password", "Password"),
+    ("login.submit", "Sign in"),
+    ("login.forgot_password", "Forgot password?"),
+    ("login.no_account", "Don't have an account?"),
+    ("register.title", "Create an account"),
+    ("register.submit", "Register"),
+    ("reset_password.title", "Reset your password"),
+    ("reset_password.submit", "Send reset link"),
+    ("email.reset_password.subject", "Reset your password"),
+    (
+        "email.reset_password.body",
+        "A password reset was requested for your account.\n\nClick the link below to reset your password:\n{{reset_link}}\n\nThis link expires in {{expiration}}.\n\nIf you did not request this, please ignore this email.",
+    ),
+    ("email.magic_link.subject", "Your magic link"),
+    (
+        "email.magic_link.body",
+        "Click the link below to sign in:\n{{magic_link}}\n\nThis link expires in {{expiration}}.\n\nIf you did not request this, please ignore this email.",
+    ),
+    (
+        "email.email_verification.subject",
+        "Verify your email address",
+    ),
+    (
+        "email.email_verification.body",
+        "Click the link below to verify your email address:\n{{verification_link}}\n\nThis link expires in {{expiration}}.\n\nIf you did not register for this account, please ignore this email.",
+    ),
+    (
+        "email.organization_invitation.subject",
+        "You are invited to join {{organization.name}}",
+    ),
+    (
+        "email.organization_invitation.body",
+        "You have been invited to join {{organization.name}} on {{realm.name}}.\n\nAccept the invitation:\n{{invitation_link}}\n\nThis link expires on {{expires_at}}.\n\nIf you were not expecting this invitation, you can ignore this email.",
+    ),
+    (
+        "email.password_changed.subject",
+        "Your {{realm.name}} password was changed",
+    ),
+    (
+        "email.password_changed.body",
+        "The password of your {{realm.name}} account was changed on {{occurred_at}}.\n\nIf you made this change, you can ignore this email. Otherwise, reset your password right away and contact your administrator.",
+    ),
+    (
+        "email.new_sign_in.subject",
+        "New sign-in to your {{realm.name}} account",
+    ),
+    (
+        "email.new_sign_in.body",
+        "Your {{realm.name}} account was signed in to from a new device or location.\n\nTime: {{occurred_at}}\nIP address: {{ip_address}}\nLocation: {{location}}\nDevice: {{device}}\n\nIf this was you, you can ignore this email. Otherwise, change your password right away.",
+    ),
+    (
+        "email.account_locked.subject",
+        "Your {{realm.name}} account is locked",
+    ),
+    (
+        "email.account_locked.body",
+        "Your {{realm.name}} account was locked on {{occurred_at}} after too many failed sign-in attempts.\n\nYou can sign in again after {{locked_until}}.\n\nIf these attempts were not yours, someone may be guessing your password: change it once you can sign in again.",
+    ),
+    (
+        "email.mfa_factor_added.subject",
+        "A sign-in factor was added to your {{realm.name}} account",
+    ),
+    (
+        "email.mfa_factor_added.body",
+        "A new {{factor}} was added to your {{realm.name}} account on {{occurred_at}}.\n\nIf you did not add it, contact your administrator right away.",
+    ),
+    (
+        "email.mfa_factor_removed.subject",
+        "A sign-in factor was removed from your {{realm.name}} account",
+    ),
+    (
+        "email.mfa_factor_removed.body",
+        "A {{factor}} was removed from your {{realm.name}} account on {{occurred_at}}.\n\nIf you did not remove it, contact your administrator right away.",
+    ),
+    (
+        "email.email_changed.subject",
+        "The email address of your {{realm.name}} account was changed",
+    ),
+    (
+        "email.email_changed.body",
+        "The email address of your {{realm.name}} account was changed to {{new_email}} on {{occurred_at}}. This address no longer receives emails for the account.\n\nIf you did not make this change, contact your administrator right away.",
+    ),
+    (
+        "email.identity_provider_linked.subject",
+        "Your {{realm.name}} account was linked to {{identity_provider}}",
+    ),
+    (
+        "email.identity_provider_linked.body",
+        "Your {{realm.name}} account was linked to {{identity_provider}} on {{occurred_at}}. You can now sign in with it.\n\nIf you did not link it, contact your administrator right away.",
+    ),
+];
+
+const FR: &[(&str, &str)] = &[
+    ("duration.minute", "1 minute"),
+    ("duration.minutes", "{{count}} minutes"),
+    ("duration.hour", "1 heure"),
+    ("duration.hours", "{{count}} heures"),
+    ("login.title", "Connectez-vous à votre compte"),
+    ("login.username", "Nom d'utilisateur ou e-mail"),
+    ("login.password", "Mot de passe"),
+    ("login.submit", "Se connecter"),
+    ("login.forgot_password", "Mot de passe oublié ?"),
+    ("login.no_account", "Vous n'avez pas de compte ?"),
+    ("register.title", "Créer un compte"),
+    ("register.submit", "S'inscrire"),
+    ("reset_password.title", "Réinitialiser votre mot de passe"),
+    ("reset_password.submit", "Envoyer le lien"),
+    (
+        "email.reset_password.subject",
+        "Réinitialisez votre mot de passe",
+    ),
+    (
+        "email.reset_password.body",
+        "Une réinitialisation du mot de passe a été demandée pour votre compte.\n\nCliquez sur le lien ci-dessous pour réinitialiser votre mot de passe :\n{{reset_link}}\n\nCe lien expire dans {{expiration}}.\n\nSi vous n'êtes pas à l'origine de cette demande, ignorez cet e-mail.",
+    ),
+    ("email.magic_link.subject", "Votre lien de connexion"),
+    (
+        "email.magic_link.body",
+        "Cliquez sur le lien ci-dessous pour vous connecter :\n{{magic_link}}\n\nCe lien expire dans {{expiration}}.\n\nSi vous n'êtes pas à l'origine de cette demande, ignorez cet e-mail.",
+    ),
+    (
+        "email.email_verification.subject",
+        "Vérifiez votre adresse e-mail",
+    ),
+    (
+        "email.email_verification.body",
+        "Cliquez sur le lien ci-dessous pour vérifier votre adresse e-mail :\n{{verification_link}}\n\nCe lien expire dans {{expiration}}.\n\nSi vous n'avez pas créé ce compte, ignorez cet e-mail.",
+    ),
+    (
+        "email.organization_invitation.subject",
+        "Vous êtes invité à rejoindre {{organization.name}}",
+    ),
+    (
+        "email.organization_invitation.body",
+        "Vous avez été invité à rejoindre {{organization.name}} sur {{realm.name}}.\n\nAccepter l'invitation :\n{{invitation_link}}\n\nCe lien expire le {{expires_at}}.\n\nSi vous n'attendiez pas cette invitation, vous pouvez ignorer cet e-mail.",
+    ),
+    (
+        "email.password_changed.subject",
+        "Votre mot de passe {{realm.name}} a été modifié",
+    ),
+    (
+        "email.password_changed.body",
+        "Le mot de passe de votre compte {{realm.name}} a été modifié le {{occurred_at}}.\n\nSi vous êtes à l'origine de ce changement, ignorez cet e-mail. Sinon, réinitialisez immédiatement votre mot de passe et contactez votre administrateur.",
+    ),
+    (
+        "email.new_sign_in.subject",
+        "Nouvelle connexion à votre compte {{realm.name}}",
+    ),
+    (
+        "email.new_sign_in.body",
+        "Une connexion à votre compte {{realm.name}} a eu lieu depuis un nouvel appareil ou un nouveau lieu.\n\nDate : {{occurred_at}}\nAdresse IP : {{ip_address}}\nLieu : {{location}}\nAppareil : {{device}}\n\nSi c'était vous, ignorez cet e-mail. Sinon, changez immédiatement votre mot de passe.",
+    ),
+    (
+        "email.account_locked.subject",
+        "Votre compte {{realm.name}} est verrouillé",
+    ),
+    (
+        "email.account_locked.body",
+        "Votre compte {{realm.name}} a été verrouillé le {{occurred_at}} après trop de tentatives de connexion échouées.\n\nVous pourrez vous reconnecter après {{locked_until}}.\n\nSi ces tentatives ne venaient pas de vous, quelqu'un essaie peut-être de deviner votre mot de passe : changez-le dès que vous pourrez vous reconnecter.",
+    ),
+    (
+        "email.mfa_factor_added.subject",
+        "Un facteur de connexion a été ajouté à votre compte {{realm.name}}",
+    ),
+    (
+        "email.mfa_factor_added.body",
+        "Un nouveau facteur ({{factor}}) a été ajouté à votre compte {{realm.name}} le {{occurred_at}}.\n\nSi vous ne l'avez pas ajouté, contactez immédiatement votre administrateur.",
+    ),
+    (
+        "email.mfa_factor_removed.subject",
+        "Un facteur de connexion a été retiré de votre compte {{realm.name}}",
+    ),
+    (
+        "email.mfa_factor_removed.body",
+        "Un facteur ({{factor}}) a été retiré de votre compte {{realm.name}} le {{occurred_at}}.\n\nSi vous ne l'avez pas retiré, contactez immédiatement votre administrateur.",
+    ),
+    (
+        "email.email_changed.subject",
+        "L'adresse e-mail de votre compte {{realm.name}} a été modifiée",
+    ),
+    (
+        "email.email_changed.body",
+        "L'adresse e-mail de votre compte {{realm.name}} a été remplacée par {{new_email}} le {{occurred_at}}. Cette adresse ne reçoit plus les e-mails du compte.\n\nSi vous n'êtes pas à l'origine de ce changement, contactez immédiatement votre administrateur.",
+    ),
+    (
+        "email.identity_provider_linked.subject",
+        "Votre compte {{realm.name}} a été lié à {{identity_provider}}",
+    ),
+    (
+        "email.identity_provider_linked.body",
+        "Votre compte {{realm.name}} a été lié à {{identity_provider}} le {{occurred_at}}. Vous pouvez désormais vous connecter avec ce fournisseur.\n\nSi vous ne l'avez pas lié, contactez immédiatement votre administrateur.",
+    ),
+];
+
+const DE: &[(&str, &str)] = &[
+    ("duration.minute", "1 Minute"),
+    ("duration.minutes", "{{count}} Minuten"),
+    ("duration.hour", "1 Stunde"),
+    ("duration.hours", "{{count}} Stunden"),
+    ("login.title", "Melden Sie sich bei Ihrem Konto an"),
+    ("login.username", "Benutzername oder E-Mail"),
+    ("login.password", "Passwort"),
+    ("login.submit", "Anmelden"),
+    ("login.forgot_password", "Passwort vergessen?"),
+    ("login.no_account", "Noch kein Konto?"),
+    ("register.title", "Konto erstellen"),
+    ("register.submit", "Registrieren"),
+    ("reset_password.title", "Passwort zurücksetzen"),
+    ("reset_password.submit", "Link senden"),
+    (
+        "email.reset_password.subject",
+        "Setzen Sie Ihr Passwort zurück",
+    ),
+    (
+        "email.reset_password.body",
+        "Für Ihr Konto wurde das Zurücksetzen des Passworts angefordert.\n\nKlicken Sie auf den folgenden Link, um Ihr Passwort zurückzusetzen:\n{{reset_link}}\n\nDieser Link läuft in {{expiration}} ab.\n\nWenn Sie dies nicht angefordert haben, ignorieren Sie diese E-Mail.",
+    ),
+    ("email.magic_link.subject", "Ihr Anmeldelink"),
+    (
+        "email.magic_link.body",
+        "Klicken Sie auf den folgenden Link, um sich anzumelden:\n{{magic_link}}\n\nDieser Link läuft in {{expiration}} ab.\n\nWenn Sie dies nicht angefordert haben, ignorieren Sie diese E-Mail.",
+    ),
+    (
+        "email.email_verification.subject",
+        "Bestätigen Sie Ihre E-Mail-Adresse",
+    ),
+    (
+        "email.email_verification.body",
+        "Klicken Sie auf den folgenden Link, um Ihre E-Mail-Adresse zu bestätigen:\n{{verification_link}}\n\nDieser Link läuft in {{expiration}} ab.\n\nWenn Sie sich nicht für dieses Konto registriert haben, ignorieren Sie diese E-Mail.",
+    ),
+    (
+        "email.organization_invitation.subject",
+        "Sie wurden eingeladen, {{organization.name}} beizutreten",
+    ),
+    (
+        "email.organization_invitation.body",
+        "Sie wurden eingeladen, {{organization.name}} auf {{realm.name}} beizutreten.\n\nEinladung annehmen:\n{{invitation_link}}\n\nDieser Link läuft am {{expires_at}} ab.\n\nWenn Sie diese Einladung nicht erwartet haben, können Sie diese E-Mail ignorieren.",
+    ),
+    (
+        "email.password_changed.subject",
+        "Ihr {{realm.name}}-Passwort wurde geändert",
+    ),
+    (
+        "email.password_changed.body",
+        "Das Passwort Ihres {{realm.name}}-Kontos wurde am {{occurred_at}} geändert.\n\nWenn Sie diese Änderung vorgenommen haben, ignorieren Sie diese E-Mail. Andernfalls setzen Sie Ihr Passwort sofort zurück und wenden Sie sich an Ihren Administrator.",
+    ),
+    (
+        "email.new_sign_in.subject",
+        "Neue Anmeldung bei Ihrem {{realm.name}}-Konto",
+    ),
+    (
+        "email.new_sign_in.body",
+        "Bei Ihrem {{realm.name}}-Konto wurde sich von einem neuen Gerät oder Ort aus angemeldet.\n\nZeit: {{occurred_at}}\nIP-Adresse: {{ip_address}}\nOrt: {{location}}\nGerät: {{device}}\n\nWenn Sie das waren, ignorieren Sie diese E-Mail. Andernfalls ändern Sie sofort Ihr Passwort.",
+    ),
+    (
+        "email.account_locked.subject",
+        "Ihr {{realm.name}}-Konto ist gesperrt",
+    ),
+    (
+        "email.account_locked.body",
+        "Ihr {{realm.name}}-Konto wurde am {{occurred_at}} nach zu vielen fehlgeschlagenen Anmeldeversuchen gesperrt.\n\nSie können sich nach {{locked_until}} wieder anmelden.\n\nWenn diese Versuche nicht von Ihnen stammen, versucht möglicherweise jemand, Ihr Passwort zu erraten: Ändern Sie es, sobald Sie sich wieder anmelden können.",
+    ),
+    (
+        "email.mfa_factor_added.subject",
+        "Ihrem {{realm.name}}-Konto wurde ein Anmeldefaktor hinzugefügt",
+    ),
+    (
+        "email.mfa_factor_added.body",
+        "Ihrem {{realm.name}}-Konto wurde am {{occurred_at}} ein neuer Faktor ({{factor}}) hinzugefügt.\n\nWenn Sie ihn nicht hinzugefügt haben, wenden Sie sich sofort an Ihren Administrator.",
+    ),
+    (
+        "email.mfa_factor_removed.subject",
+        "Von Ihrem {{realm.name}}-Konto wurde ein Anmeldefaktor entfernt",
+    ),
+    (
+        "email.mfa_factor_removed.body",
+        "Von Ihrem {{realm.name}}-Konto wurde am {{occurred_at}} ein Faktor ({{factor}}) entfernt.\n\nWenn Sie ihn nicht entfernt haben, wenden Sie sich sofort an Ihren Administrator.",
+    ),
+    (
+        "email.email_changed.subject",
+        "Die E-Mail-Adresse Ihres {{realm.name}}-Kontos wurde geändert",
+    ),
+    (
+        "email.email_changed.body",
+        "Die E-Mail-Adresse Ihres {{realm.name}}-Kontos wurde am {{occurred_at}} in {{new_email}} geändert. Diese Adresse erhält keine E-Mails mehr für das Konto.\n\nWenn Sie diese Änderung nicht vorgenommen haben, wenden Sie sich sofort an Ihren Administrator.",
+    ),
+    (
+        "email.identity_provider_linked.subject",
+        "Ihr {{realm.name}}-Konto wurde mit {{identity_provider}} verknüpft",
+    ),
+    (
+        "email.identity_provider_linked.body",
+        "Ihr {{realm.name}}-Konto wurde am {{occurred_at}} mit {{identity_provider}} verknüpft. Sie können sich jetzt darüber anmelden.\n\nWenn Sie es nicht verknüpft haben, wenden Sie sich sofort an Ihren Administrator.",
+    ),
+];
+
+const ES: &[(&str, &str)] = &[
+    ("duration.minute", "1 minuto"),
+    ("duration.minutes", "{{count}} minutos"),
+    ("duration.hour", "1 hora"),
+    ("duration.hours", "{{count}} horas"),
+    ("login.title", "Inicia sesión en tu cuenta"),
+    ("login.username", "Usuario o correo electrónico"),
+    ("login.password", "Contraseña"),
+    ("login.submit", "Iniciar sesión"),
+    ("login.forgot_password", "¿Olvidaste tu contraseña?"),
+    ("login.no_account", "¿No tienes una cuenta?"),
+    ("register.title", "Crear una cuenta"),
+    ("register.submit", "Registrarse"),
+    ("reset_password.title", "Restablece tu contraseña"),
+    ("reset_password.submit", "Enviar enlace"),
+    ("email.reset_password.subject", "Restablece tu contraseña"),
+    (
+        "email.reset_password.body",
+        "Se solicitó restablecer la contraseña de tu cuenta.\n\nHaz clic en el siguiente enlace para restablecer tu contraseña:\n{{reset_link}}\n\nEste enlace caduca en {{expiration}}.\n\nSi no lo solicitaste, ignora este correo.",
+    ),
+    ("email.magic_link.subject", "Tu enlace de acceso"),
+    (
+        "email.magic_link.body",
+        "Haz clic en el siguiente enlace para iniciar sesión:\n{{magic_link}}\n\nEste enlace caduca en {{expiration}}.\n\nSi no lo solicitaste, ignora este correo.",
+    ),
+    (
+        "email.email_verification.subject",
+        "Verifica tu dirección de correo electrónico",
+    ),
+    (
+        "email.email_verification.body",
+        "Haz clic en el siguiente enlace para verificar tu dirección de correo electrónico:\n{{verification_link}}\n\nEste enlace caduca en {{expiration}}.\n\nSi no te registraste para esta cuenta, ignora este correo.",
+    ),
+    (
+        "email.organization_invitation.subject",
+        "Te han invitado a unirte a {{organization.name}}",
+    ),
+    (
+        "email.organization_invitation.body",
+        "Te han invitado a unirte a {{organization.name}} en {{realm.name}}.\n\nAcepta la invitación:\n{{invitation_link}}\n\nEste enlace caduca el {{expires_at}}.\n\nSi no esperabas esta invitación, puedes ignorar este correo.",
+    ),
+    (
+        "email.password_changed.subject",
+        "Se cambió tu contraseña de {{realm.name}}",
+    ),
+    (
+        "email.password_changed.body",
+        "La contraseña de tu cuenta de {{realm.name}} se cambió el {{occurred_at}}.\n\nSi hiciste este cambio, ignora este correo. Si no, restablece tu contraseña de inmediato y contacta con tu administrador.",
+    ),
+    (
+        "email.new_sign_in.subject",
+        "Nuevo inicio de sesión en tu cuenta de {{realm.name}}",
+    ),
+    (
+        "email.new_sign_in.body",
+        "Se inició sesión en tu cuenta de {{realm.name}} desde un dispositivo o una ubicación nuevos.\n\nFecha: {{occurred_at}}\nDirección IP: {{ip_address}}\nUbicación: {{location}}\nDispositivo: {{device}}\n\nSi fuiste tú, ignora este correo. Si no, cambia tu contraseña de inmediato.",
+    ),
+    (
+        "email.account_locked.subject",
+        "Tu cuenta de {{realm.name}} está bloqueada",
+    ),
+    (
+        "email.account_locked.body",
+        "Tu cuenta de {{realm.name}} se bloqueó el {{occurred_at}} tras demasiados intentos de inicio de sesión fallidos.\n\nPodrás volver a iniciar sesión después de {{locked_until}}.\n\nSi esos intentos no fueron tuyos, alguien podría estar intentando adivinar tu contraseña: cámbiala en cuanto puedas iniciar sesión.",
+    ),
+    (
+        "email.mfa_factor_added.subject",
+        "Se añadió un factor de inicio de sesión a tu cuenta de {{realm.name}}",
+    ),
+    (
+        "email.mfa_factor_added.body",
+        "Se añadió un nuevo factor ({{factor}}) a tu cuenta de {{realm.name}} el {{occurred_at}}.\n\nSi no lo añadiste tú, contacta con tu administrador de inmediato.",
+    ),
+    (
+        "email.mfa_factor_removed.subject",
+        "Se eliminó un factor de inicio de sesión de tu cuenta de {{realm.name}}",
+    ),
+    (
+        "email.mfa_factor_removed.body",
+        "Se eliminó un factor ({{factor}}) de tu cuenta de {{realm.name}} el {{occurred_at}}.\n\nSi no lo eliminaste tú, contacta con tu administrador de inmediato.",
+    ),
+    (
+        "email.email_changed.subject",
+        "Se cambió la dirección de correo de tu cuenta de {{realm.name}}",
+    ),
+    (
+        "email.email_changed.body",
+        "La dirección de correo de tu cuenta de {{realm.name}} se cambió a {{new_email}} el {{occurred_at}}. Esta dirección ya no recibe correos de la cuenta.\n\nSi no hiciste este cambio, contacta con tu administrador de inmediato.",
+    ),
+    (
+        "email.identity_provider_linked.subject",
+        "Tu cuenta de {{realm.name}} se vinculó a {{identity_provider}}",
+    ),
+    (
+        "email.identity_provider_linked.body",
+        "Tu cuenta de {{realm.name}} se vinculó a {{identity_provider}} el {{occurred_at}}. Ahora puedes iniciar sesión con este proveedor.\n\nSi no la vinculaste tú, contacta con tu administrador de inmediato.",
+    ),
+];
+
+/// The built-in texts of a language, if FerrisKey ships it.
+pub fn builtin_messages(language: &str) -> Option<&'static [(&'static str, &'static str)]> {
+    match language {
+        "en" => Some(EN),
+        "fr" => Some(FR),
+        "de" => Some(DE),
+        "es" => Some(ES),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_language_translates_every_english_key() {
+        for language in ["fr", "de", "es"] {
+            let messages = builtin_messages(language).unwrap();
+            for (key, _) in EN {
+                assert!(
+                    messages.iter().any(|(k, _)| k == key),
+                    "{language} is missing {key}"
+                );
+            }
+        }
+    }
+}
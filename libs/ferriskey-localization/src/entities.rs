@@ -0,0 +1,418 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::RealmId;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// The user attribute holding a user's preferred locale.
+pub const USER_LOCALE_ATTRIBUTE: &str = "locale";
+/// The cookie the login portal stores the visitor's language choice in.
+pub const LOCALE_COOKIE: &str = "FERRISKEY_LOCALE";
+/// The locale of realms that never configured localization, and of the built-in texts every
+/// other locale falls back to.
+pub const FALLBACK_LOCALE: &str = "en";
+
+pub const MAX_MESSAGE_KEY_LENGTH: usize = 200;
+pub const MAX_MESSAGE_LENGTH: usize = 10_000;
+pub const MAX_MESSAGES_PER_BUNDLE: usize = 2_000;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum LocalizationValidationError {
+    #[error("'{0}' is not a valid locale")]
+    InvalidLocale(String),
+    #[error("at least one locale must be supported")]
+    NoSupportedLocale,
+    #[error("the default locale {0} is not one of the supported locales")]
+    DefaultNotSupported(Locale),
+    #[error("locale {0} is not supported by the realm")]
+    UnsupportedLocale(Locale),
+    #[error("message key '{0}' is invalid")]
+    InvalidMessageKey(String),
+    #[error("message '{0}' is longer than 10000 characters")]
+    MessageTooLong(String),
+    #[error("a bundle holds at most 2000 messages")]
+    TooManyMessages,
+}
+
+impl From<LocalizationValidationError> for CoreError {
+    fn from(error: LocalizationValidationError) -> Self {
+        CoreError::InvalidLocalization(error.to_string())
+    }
+}
+
+/// A BCP 47 language tag such as `fr` or `pt-BR`, normalized to lowercase language and
+/// uppercase region.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, example = "fr")]
+pub struct Locale(String);
+
+impl Locale {
+    pub fn parse(tag: &str) -> Result<Self, LocalizationValidationError> {
+        let invalid = || LocalizationValidationError::InvalidLocale(tag.to_string());
+
+        let mut subtags = tag.trim().split(['-', '_']);
+        let language = subtags.next().ok_or_else(invalid)?;
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return Err(invalid());
+        }
+
+        let mut normalized = language.to_ascii_lowercase();
+        for (position, subtag) in subtags.enumerate() {
+            if position >= 2
+                || !(2..=8).contains(&subtag.len())
+                || !subtag.chars().all(|c| c.is_ascii_alphanumeric())
+            {
+                return Err(invalid());
+            }
+            normalized.push('-');
+            match subtag.len() {
+                // Region, e.g. `BR` or `419`.
+                2 | 3 => normalized.push_str(&subtag.to_ascii_uppercase()),
+                // Script, e.g. `Hant`.
+                4 => {
+                    let (first, rest) = subtag.split_at(1);
+                    normalized.push_str(&first.to_ascii_uppercase());
+                    normalized.push_str(&rest.to_ascii_lowercase());
+                }
+                _ => normalized.push_str(&subtag.to_ascii_lowercase()),
+            }
+        }
+
+        Ok(Self(normalized))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The primary language subtag: `pt` for `pt-BR`.
+    pub fn language(&self) -> &str {
+        self.0.split('-').next().unwrap_or(&self.0)
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self(FALLBACK_LOCALE.to_string())
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for Locale {
+    type Error = LocalizationValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Locale::parse(&value)
+    }
+}
+
+impl From<Locale> for String {
+    fn from(locale: Locale) -> Self {
+        locale.0
+    }
+}
+
+/// The languages a realm serves its login portal and emails in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LocalizationSettings {
+    pub realm_id: RealmId,
+    pub supported_locales: Vec<Locale>,
+    /// Used when nothing about the visitor or the user matches a supported locale.
+    pub default_locale: Locale,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LocalizationSettings {
+    pub fn new(
+        realm_id: RealmId,
+        supported_locales: Vec<Locale>,
+        default_locale: Locale,
+    ) -> Result<Self, LocalizationValidationError> {
+        let supported_locales = validate_locales(supported_locales, &default_locale)?;
+
+        let now = Utc::now();
+        Ok(Self {
+            realm_id,
+            supported_locales,
+            default_locale,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn default_for(realm_id: RealmId) -> Self {
+        let now = Utc::now();
+        Self {
+            realm_id,
+            supported_locales: vec![Locale::default()],
+            default_locale: Locale::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        supported_locales: Vec<Locale>,
+        default_locale: Locale,
+    ) -> Result<(), LocalizationValidationError> {
+        self.supported_locales = validate_locales(supported_locales, &default_locale)?;
+        self.default_locale = default_locale;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    pub fn supports(&self, locale: &Locale) -> bool {
+        self.supported_locales.contains(locale)
+    }
+}
+
+/// Drops duplicates, keeping the first occurrence, and checks the default is supported.
+fn validate_locales(
+    locales: Vec<Locale>,
+    default_locale: &Locale,
+) -> Result<Vec<Locale>, LocalizationValidationError> {
+    let mut unique: Vec<Locale> = Vec::with_capacity(locales.len());
+    for locale in locales {
+        if !unique.contains(&locale) {
+            unique.push(locale);
+        }
+    }
+
+    if unique.is_empty() {
+        return Err(LocalizationValidationError::NoSupportedLocale);
+    }
+    if !unique.contains(default_locale) {
+        return Err(LocalizationValidationError::DefaultNotSupported(
+            default_locale.clone(),
+        ));
+    }
+
+    Ok(unique)
+}
+
+/// A realm's texts for one locale, keyed like `login.title` or `email.magic_link.subject`.
+/// Keys the bundle does not define fall back to the built-in texts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct MessageBundle {
+    pub realm_id: RealmId,
+    pub locale: Locale,
+    pub messages: BTreeMap<String, String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MessageBundle {
+    pub fn new(
+        realm_id: RealmId,
+        locale: Locale,
+        messages: BTreeMap<String, String>,
+    ) -> Result<Self, LocalizationValidationError> {
+        validate_messages(&messages)?;
+
+        let now = Utc::now();
+        Ok(Self {
+            realm_id,
+            locale,
+            messages,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn replace_messages(
+        &mut self,
+        messages: BTreeMap<String, String>,
+    ) -> Result<(), LocalizationValidationError> {
+        validate_messages(&messages)?;
+        self.messages = messages;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= MAX_MESSAGE_KEY_LENGTH
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+}
+
+fn validate_messages(
+    messages: &BTreeMap<String, String>,
+) -> Result<(), LocalizationValidationError> {
+    if messages.len() > MAX_MESSAGES_PER_BUNDLE {
+        return Err(LocalizationValidationError::TooManyMessages);
+    }
+    for (key, message) in messages {
+        if !is_valid_key(key) {
+            return Err(LocalizationValidationError::InvalidMessageKey(key.clone()));
+        }
+        if message.chars().count() > MAX_MESSAGE_LENGTH {
+            return Err(LocalizationValidationError::MessageTooLong(key.clone()));
+        }
+    }
+    Ok(())
+}
+
+/// What is known about the reader's language, most explicit first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocaleHints {
+    /// The OIDC `ui_locales` parameter: space-separated tags in order of preference.
+    pub ui_locales: Option<String>,
+    /// The language picked in the login portal, from [`LOCALE_COOKIE`].
+    pub cookie: Option<String>,
+    /// The user's [`USER_LOCALE_ATTRIBUTE`].
+    pub user_locale: Option<String>,
+    /// The raw `Accept-Language` header.
+    pub accept_language: Option<String>,
+}
+
+/// The texts of one locale, with the built-in and default-locale texts filled in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LocalizedMessages {
+    pub locale: Locale,
+    pub messages: BTreeMap<String, String>,
+}
+
+impl LocalizedMessages {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    /// The message `key` with each `{{name}}` replaced by its value. Unknown keys come out
+    /// as the key itself so a missing translation is visible rather than blank.
+    pub fn format(&self, key: &str, variables: &[(&str, &str)]) -> String {
+        let mut text = self.get(key).unwrap_or(key).to_string();
+        for (name, value) in variables {
+            text = text.replace(&format!("{{{{{name}}}}}"), value);
+        }
+        text
+    }
+
+    pub fn email_subject(&self, email_type: &str, variables: &[(&str, &str)]) -> String {
+        self.format(&format!("email.{email_type}.subject"), variables)
+    }
+
+    /// The plain-text body sent alongside the HTML template.
+    pub fn email_body(&self, email_type: &str, variables: &[(&str, &str)]) -> String {
+        self.format(&format!("email.{email_type}.body"), variables)
+    }
+
+    pub fn minutes(&self, count: i64) -> String {
+        self.count("duration.minute", "duration.minutes", count)
+    }
+
+    pub fn hours(&self, count: i64) -> String {
+        self.count("duration.hour", "duration.hours", count)
+    }
+
+    fn count(&self, singular: &str, plural: &str, count: i64) -> String {
+        let key = if count == 1 { singular } else { plural };
+        self.format(key, &[("count", &count.to_string())])
+    }
+
+    /// Every message as a `msg.<key>` email template variable, so one template can be
+    /// written once and filled in per locale.
+    pub fn template_variables(&self) -> HashMap<String, String> {
+        self.messages
+            .iter()
+            .map(|(key, message)| (format!("msg.{key}"), message.clone()))
+            .collect()
+    }
+}
+
+/// The texts the login portal renders, and the languages it can offer to switch to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PortalMessages {
+    pub supported_locales: Vec<Locale>,
+    #[serde(flatten)]
+    pub localized: LocalizedMessages,
+}
+
+pub struct UpdateLocalizationSettingsInput {
+    pub realm_name: String,
+    pub supported_locales: Vec<Locale>,
+    pub default_locale: Locale,
+}
+
+pub struct PutMessageBundleInput {
+    pub realm_name: String,
+    pub locale: Locale,
+    pub messages: BTreeMap<String, String>,
+}
+
+pub struct GetPortalMessagesInput {
+    pub realm_name: String,
+    pub hints: LocaleHints,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locales_are_normalized() {
+        assert_eq!(Locale::parse("FR").unwrap().as_str(), "fr");
+        assert_eq!(Locale::parse("pt_br").unwrap().as_str(), "pt-BR");
+        assert_eq!(Locale::parse("zh-hant-tw").unwrap().as_str(), "zh-Hant-TW");
+        assert_eq!(Locale::parse("es-419").unwrap().language(), "es");
+    }
+
+    #[test]
+    fn malformed_locales_are_rejected() {
+        for tag in ["", "f", "french", "fr-", "fr-C@", "en-US-x-private-more"] {
+            assert!(Locale::parse(tag).is_err(), "{tag} should be rejected");
+        }
+    }
+
+    #[test]
+    fn settings_require_the_default_locale_to_be_supported() {
+        let realm_id = RealmId::default();
+        let fr = Locale::parse("fr").unwrap();
+        let de = Locale::parse("de").unwrap();
+
+        assert_eq!(
+            LocalizationSettings::new(realm_id, vec![fr.clone()], de.clone()),
+            Err(LocalizationValidationError::DefaultNotSupported(de))
+        );
+
+        let settings =
+            LocalizationSettings::new(realm_id, vec![fr.clone(), fr.clone()], fr).unwrap();
+        assert_eq!(settings.supported_locales.len(), 1);
+    }
+
+    #[test]
+    fn messages_are_formatted_with_their_variables() {
+        let messages = LocalizedMessages {
+            locale: Locale::default(),
+            messages: BTreeMap::from([
+                ("greeting".to_string(), "Hello {{name}}".to_string()),
+                ("duration.hour".to_string(), "1 hour".to_string()),
+                ("duration.hours".to_string(), "{{count}} hours".to_string()),
+            ]),
+        };
+
+        assert_eq!(messages.format("greeting", &[("name", "Ada")]), "Hello Ada");
+        assert_eq!(messages.format("missing.key", &[]), "missing.key");
+        assert_eq!(messages.hours(1), "1 hour");
+        assert_eq!(messages.hours(24), "24 hours");
+        assert_eq!(
+            messages.template_variables().get("msg.greeting").unwrap(),
+            "Hello {{name}}"
+        );
+    }
+}
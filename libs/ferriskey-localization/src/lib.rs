@@ -0,0 +1,10 @@
+pub mod builtin;
+pub mod entities;
+pub mod ports;
+pub mod resolution;
+
+pub use entities::*;
+pub use ports::*;
+
+pub mod policies;
+pub mod services;
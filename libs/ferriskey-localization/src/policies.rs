@@ -0,0 +1,52 @@
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::client::ports::ClientRepository;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::common::policies::{FerriskeyPolicy, Policy};
+use ferriskey_domain::realm::Realm;
+use ferriskey_domain::role::permission::Permissions;
+use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
+
+use crate::ports::LocalizationAccessPolicy;
+
+impl<U, C, UR> LocalizationAccessPolicy for FerriskeyPolicy<U, C, UR>
+where
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+{
+    async fn can_view_localization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> Result<bool, CoreError> {
+        let user = self.get_user_from_identity(identity).await?;
+
+        let permissions = self
+            .get_permission_for_target_realm(&user, target_realm)
+            .await?;
+
+        let has_permission = Permissions::has_one_of_permissions(
+            &permissions,
+            &[Permissions::ManageRealm, Permissions::ViewRealm],
+        );
+
+        Ok(has_permission)
+    }
+
+    async fn can_manage_localization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> Result<bool, CoreError> {
+        let user = self.get_user_from_identity(identity).await?;
+
+        let permissions = self
+            .get_permission_for_target_realm(&user, target_realm)
+            .await?;
+
+        let has_permission =
+            Permissions::has_one_of_permissions(&permissions, &[Permissions::ManageRealm]);
+
+        Ok(has_permission)
+    }
+}
@@ -0,0 +1,129 @@
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::{Realm, RealmId};
+use uuid::Uuid;
+
+use crate::entities::{
+    GetPortalMessagesInput, Locale, LocalizationSettings, LocalizedMessages, MessageBundle,
+    PortalMessages, PutMessageBundleInput, UpdateLocalizationSettingsInput,
+};
+
+/// Persistence for the localization settings of each realm.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait LocalizationSettingsRepository: Send + Sync {
+    fn get_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<LocalizationSettings>, CoreError>> + Send;
+
+    fn upsert(
+        &self,
+        settings: LocalizationSettings,
+    ) -> impl Future<Output = Result<LocalizationSettings, CoreError>> + Send;
+}
+
+/// Persistence for the message bundles of each realm, one per locale.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait MessageBundleRepository: Send + Sync {
+    fn list_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Vec<MessageBundle>, CoreError>> + Send;
+
+    fn get(
+        &self,
+        realm_id: RealmId,
+        locale: Locale,
+    ) -> impl Future<Output = Result<Option<MessageBundle>, CoreError>> + Send;
+
+    fn upsert(
+        &self,
+        bundle: MessageBundle,
+    ) -> impl Future<Output = Result<MessageBundle, CoreError>> + Send;
+
+    /// Returns whether a bundle existed.
+    fn delete(
+        &self,
+        realm_id: RealmId,
+        locale: Locale,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+/// The texts emails are written with, for the services that send them.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait EmailLocalizer: Send + Sync {
+    /// The texts in the language of `user_id`, or of the realm default for recipients who
+    /// are not users yet. Never fails: emails fall back to the built-in English texts.
+    fn email_messages(
+        &self,
+        realm_id: RealmId,
+        user_id: Option<Uuid>,
+    ) -> impl Future<Output = LocalizedMessages> + Send;
+}
+
+pub trait LocalizationAccessPolicy: Send + Sync {
+    fn can_view_localization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_manage_localization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+pub trait LocalizationService: Send + Sync {
+    fn get_localization_settings(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<LocalizationSettings, CoreError>> + Send;
+
+    fn update_localization_settings(
+        &self,
+        identity: Identity,
+        input: UpdateLocalizationSettingsInput,
+    ) -> impl Future<Output = Result<LocalizationSettings, CoreError>> + Send;
+
+    fn list_message_bundles(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<Vec<MessageBundle>, CoreError>> + Send;
+
+    fn get_message_bundle(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        locale: Locale,
+    ) -> impl Future<Output = Result<MessageBundle, CoreError>> + Send;
+
+    /// Replaces every message of the bundle. The locale must be supported by the realm.
+    fn put_message_bundle(
+        &self,
+        identity: Identity,
+        input: PutMessageBundleInput,
+    ) -> impl Future<Output = Result<MessageBundle, CoreError>> + Send;
+
+    fn delete_message_bundle(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        locale: Locale,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// The texts of the login portal in the visitor's language. Public, like the portal.
+    fn get_portal_messages(
+        &self,
+        input: GetPortalMessagesInput,
+    ) -> impl Future<Output = Result<PortalMessages, CoreError>> + Send;
+
+    /// The locales a realm serves, as advertised in `ui_locales_supported`.
+    fn get_supported_locales(
+        &self,
+        realm_name: String,
+    ) -> impl Future<Output = Result<Vec<Locale>, CoreError>> + Send;
+}
@@ -0,0 +1,211 @@
+use std::collections::BTreeMap;
+
+use crate::builtin::builtin_messages;
+use crate::entities::{
+    FALLBACK_LOCALE, Locale, LocaleHints, LocalizationSettings, LocalizedMessages, MessageBundle,
+};
+
+/// The tags of an `Accept-Language` header, most preferred first. Malformed entries,
+/// wildcards and `q=0` entries are skipped.
+pub fn parse_accept_language(header: &str) -> Vec<Locale> {
+    let mut weighted: Vec<(Locale, f32)> = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let locale = Locale::parse(parts.next()?.trim()).ok()?;
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map(|q| q.trim().parse::<f32>().unwrap_or(0.0))
+                .unwrap_or(1.0);
+            (quality > 0.0).then_some((locale, quality))
+        })
+        .collect();
+
+    // Stable, so equally weighted tags keep their header order.
+    weighted.sort_by(|a, b| b.1.total_cmp(&a.1));
+    weighted.into_iter().map(|(locale, _)| locale).collect()
+}
+
+impl LocalizationSettings {
+    /// The supported locale matching `candidate`, exactly or else by language: a visitor
+    /// asking for `fr-CA` gets `fr`, one asking for `fr` gets `fr-FR` if that is all there is.
+    pub fn match_locale(&self, candidate: &Locale) -> Option<Locale> {
+        self.supported_locales
+            .iter()
+            .find(|supported| *supported == candidate)
+            .or_else(|| {
+                self.supported_locales
+                    .iter()
+                    .find(|supported| supported.language() == candidate.language())
+            })
+            .cloned()
+    }
+
+    /// The locale to render in. An explicit request beats a remembered choice, which beats
+    /// the user's profile, which beats the browser; the realm default covers the rest.
+    pub fn resolve(&self, hints: &LocaleHints) -> Locale {
+        let explicit = hints
+            .ui_locales
+            .iter()
+            .flat_map(|ui_locales| ui_locales.split_whitespace())
+            .chain(hints.cookie.as_deref())
+            .chain(hints.user_locale.as_deref())
+            .filter_map(|tag| Locale::parse(tag).ok());
+        let browser = hints
+            .accept_language
+            .as_deref()
+            .map(parse_accept_language)
+            .unwrap_or_default();
+
+        explicit
+            .chain(browser)
+            .find_map(|candidate| self.match_locale(&candidate))
+            .unwrap_or_else(|| self.default_locale.clone())
+    }
+}
+
+/// Builds the texts of `locale`. Each layer overrides the previous one key by key: built-in
+/// English, the realm's default-locale bundle, the built-in texts of the language, then the
+/// realm's bundle for the locale itself.
+pub fn localize(
+    settings: &LocalizationSettings,
+    locale: Locale,
+    bundles: &[MessageBundle],
+) -> LocalizedMessages {
+    let mut messages = BTreeMap::new();
+    let apply_builtin = |language: &str, messages: &mut BTreeMap<String, String>| {
+        for (key, text) in builtin_messages(language).unwrap_or_default() {
+            messages.insert(key.to_string(), text.to_string());
+        }
+    };
+    let bundle_for = |locale: &Locale| bundles.iter().find(|bundle| &bundle.locale == locale);
+
+    apply_builtin(FALLBACK_LOCALE, &mut messages);
+    if settings.default_locale != locale
+        && let Some(bundle) = bundle_for(&settings.default_locale)
+    {
+        messages.extend(bundle.messages.clone());
+    }
+    if locale.language() != FALLBACK_LOCALE {
+        apply_builtin(locale.language(), &mut messages);
+    }
+    if let Some(bundle) = bundle_for(&locale) {
+        messages.extend(bundle.messages.clone());
+    }
+
+    LocalizedMessages { locale, messages }
+}
+
+impl LocalizedMessages {
+    /// The built-in texts of `locale`, for readers of realms whose texts cannot be loaded.
+    pub fn builtin(locale: Locale) -> Self {
+        let settings = LocalizationSettings {
+            supported_locales: vec![locale.clone()],
+            default_locale: locale.clone(),
+            ..LocalizationSettings::default_for(Default::default())
+        };
+        localize(&settings, locale, &[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ferriskey_domain::realm::RealmId;
+
+    fn locale(tag: &str) -> Locale {
+        Locale::parse(tag).unwrap()
+    }
+
+    fn settings(supported: &[&str], default: &str) -> LocalizationSettings {
+        LocalizationSettings::new(
+            RealmId::default(),
+            supported.iter().map(|tag| locale(tag)).collect(),
+            locale(default),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn accept_language_is_sorted_by_quality() {
+        assert_eq!(
+            parse_accept_language("de;q=0.5, fr-CH, *;q=0.1, en;q=0, es;q=0.8"),
+            vec![locale("fr-CH"), locale("es"), locale("de")]
+        );
+    }
+
+    #[test]
+    fn explicit_hints_beat_the_browser() {
+        let settings = settings(&["en", "fr", "de", "es"], "en");
+        let hints = LocaleHints {
+            ui_locales: Some("it de".to_string()),
+            cookie: Some("es".to_string()),
+            user_locale: None,
+            accept_language: Some("fr".to_string()),
+        };
+
+        assert_eq!(settings.resolve(&hints), locale("de"));
+        assert_eq!(
+            settings.resolve(&LocaleHints {
+                ui_locales: None,
+                ..hints.clone()
+            }),
+            locale("es")
+        );
+        assert_eq!(
+            settings.resolve(&LocaleHints {
+                accept_language: Some("fr-CA,fr;q=0.9".to_string()),
+                ..LocaleHints::default()
+            }),
+            locale("fr")
+        );
+    }
+
+    #[test]
+    fn unsupported_requests_fall_back_to_the_default_locale() {
+        let settings = settings(&["fr", "de"], "fr");
+        let hints = LocaleHints {
+            user_locale: Some("ja".to_string()),
+            accept_language: Some("it, en;q=0.8".to_string()),
+            ..LocaleHints::default()
+        };
+
+        assert_eq!(settings.resolve(&hints), locale("fr"));
+    }
+
+    #[test]
+    fn realm_bundles_override_builtin_texts() {
+        let settings = settings(&["en", "fr"], "en");
+        let realm_id = settings.realm_id;
+        let bundles = vec![
+            MessageBundle::new(
+                realm_id,
+                locale("en"),
+                BTreeMap::from([
+                    ("login.title".to_string(), "Welcome to Acme".to_string()),
+                    ("acme.footer".to_string(), "Acme Corp".to_string()),
+                ]),
+            )
+            .unwrap(),
+            MessageBundle::new(
+                realm_id,
+                locale("fr"),
+                BTreeMap::from([("login.title".to_string(), "Bienvenue chez Acme".to_string())]),
+            )
+            .unwrap(),
+        ];
+
+        let french = localize(&settings, locale("fr"), &bundles);
+        assert_eq!(french.get("login.title"), Some("Bienvenue chez Acme"));
+        assert_eq!(french.get("login.submit"), Some("Se connecter"));
+        // Keys only the default-locale bundle defines are still rendered.
+        assert_eq!(french.get("acme.footer"), Some("Acme Corp"));
+
+        let english = localize(&settings, locale("en"), &bundles);
+        assert_eq!(english.get("login.title"), Some("Welcome to Acme"));
+        assert_eq!(
+            english.email_subject("magic_link", &[]),
+            "Your magic link".to_string()
+        );
+    }
+}
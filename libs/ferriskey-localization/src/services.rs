@@ -0,0 +1,414 @@
+use std::sync::Arc;
+
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::client::ports::ClientRepository;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use ferriskey_domain::realm::ports::RealmRepository;
+use ferriskey_domain::realm::{Realm, RealmId};
+use ferriskey_domain::user::ports::{UserAttributeRepository, UserRepository, UserRoleRepository};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::entities::USER_LOCALE_ATTRIBUTE;
+use crate::resolution::localize;
+use crate::{
+    EmailLocalizer, GetPortalMessagesInput, Locale, LocaleHints, LocalizationAccessPolicy,
+    LocalizationService, LocalizationSettings, LocalizationSettingsRepository,
+    LocalizationValidationError, LocalizedMessages, MessageBundle, MessageBundleRepository,
+    PortalMessages, PutMessageBundleInput, UpdateLocalizationSettingsInput,
+};
+
+#[derive(Clone, Debug)]
+pub struct LocalizationServiceImpl<R, U, C, UR, LS, MB, UAR>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    LS: LocalizationSettingsRepository,
+    MB: MessageBundleRepository,
+    UAR: UserAttributeRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) settings_repository: Arc<LS>,
+    pub(crate) bundle_repository: Arc<MB>,
+    pub(crate) user_attribute_repository: Arc<UAR>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, LS, MB, UAR> LocalizationServiceImpl<R, U, C, UR, LS, MB, UAR>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    LS: LocalizationSettingsRepository,
+    MB: MessageBundleRepository,
+    UAR: UserAttributeRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        settings_repository: Arc<LS>,
+        bundle_repository: Arc<MB>,
+        user_attribute_repository: Arc<UAR>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            settings_repository,
+            bundle_repository,
+            user_attribute_repository,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    async fn settings(&self, realm_id: RealmId) -> Result<LocalizationSettings, CoreError> {
+        Ok(self
+            .settings_repository
+            .get_by_realm(realm_id)
+            .await?
+            .unwrap_or_else(|| LocalizationSettings::default_for(realm_id)))
+    }
+
+    async fn messages(
+        &self,
+        realm_id: RealmId,
+        hints: &LocaleHints,
+    ) -> Result<(LocalizationSettings, LocalizedMessages), CoreError> {
+        let settings = self.settings(realm_id).await?;
+        let locale = settings.resolve(hints);
+        let bundles = self.bundle_repository.list_by_realm(realm_id).await?;
+        let messages = localize(&settings, locale, &bundles);
+
+        Ok((settings, messages))
+    }
+
+    async fn user_locale(&self, user_id: Uuid) -> Result<Option<String>, CoreError> {
+        Ok(self
+            .user_attribute_repository
+            .list_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .find(|attribute| attribute.key == USER_LOCALE_ATTRIBUTE)
+            .map(|attribute| attribute.value))
+    }
+
+    async fn ensure_can_view(&self, identity: &Identity, realm: &Realm) -> Result<(), CoreError> {
+        ensure_policy(
+            self.policy.can_view_localization(identity, realm).await,
+            "insufficient permissions to view localization",
+        )
+    }
+
+    async fn ensure_can_manage(&self, identity: &Identity, realm: &Realm) -> Result<(), CoreError> {
+        ensure_policy(
+            self.policy.can_manage_localization(identity, realm).await,
+            "insufficient permissions to manage localization",
+        )
+    }
+}
+
+impl<R, U, C, UR, LS, MB, UAR> LocalizationService
+    for LocalizationServiceImpl<R, U, C, UR, LS, MB, UAR>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    LS: LocalizationSettingsRepository,
+    MB: MessageBundleRepository,
+    UAR: UserAttributeRepository,
+{
+    async fn get_localization_settings(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<LocalizationSettings, CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+        self.ensure_can_view(&identity, &realm).await?;
+
+        self.settings(realm.id).await
+    }
+
+    async fn update_localization_settings(
+        &self,
+        identity: Identity,
+        input: UpdateLocalizationSettingsInput,
+    ) -> Result<LocalizationSettings, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        self.ensure_can_manage(&identity, &realm).await?;
+
+        // Bundles of locales no longer supported are kept, so re-adding a locale restores them.
+        let settings = match self.settings_repository.get_by_realm(realm.id).await? {
+            Some(mut settings) => {
+                settings.update(input.supported_locales, input.default_locale)?;
+                settings
+            }
+            None => {
+                LocalizationSettings::new(realm.id, input.supported_locales, input.default_locale)?
+            }
+        };
+
+        self.settings_repository.upsert(settings).await
+    }
+
+    async fn list_message_bundles(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<MessageBundle>, CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+        self.ensure_can_view(&identity, &realm).await?;
+
+        self.bundle_repository.list_by_realm(realm.id).await
+    }
+
+    async fn get_message_bundle(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        locale: Locale,
+    ) -> Result<MessageBundle, CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+        self.ensure_can_view(&identity, &realm).await?;
+
+        self.bundle_repository
+            .get(realm.id, locale)
+            .await?
+            .ok_or(CoreError::NotFound)
+    }
+
+    async fn put_message_bundle(
+        &self,
+        identity: Identity,
+        input: PutMessageBundleInput,
+    ) -> Result<MessageBundle, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        self.ensure_can_manage(&identity, &realm).await?;
+
+        let settings = self.settings(realm.id).await?;
+        if !settings.supports(&input.locale) {
+            return Err(LocalizationValidationError::UnsupportedLocale(input.locale).into());
+        }
+
+        let bundle = match self
+            .bundle_repository
+            .get(realm.id, input.locale.clone())
+            .await?
+        {
+            Some(mut bundle) => {
+                bundle.replace_messages(input.messages)?;
+                bundle
+            }
+            None => MessageBundle::new(realm.id, input.locale, input.messages)?,
+        };
+
+        self.bundle_repository.upsert(bundle).await
+    }
+
+    async fn delete_message_bundle(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        locale: Locale,
+    ) -> Result<(), CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+        self.ensure_can_manage(&identity, &realm).await?;
+
+        if self.bundle_repository.delete(realm.id, locale).await? {
+            Ok(())
+        } else {
+            Err(CoreError::NotFound)
+        }
+    }
+
+    async fn get_portal_messages(
+        &self,
+        input: GetPortalMessagesInput,
+    ) -> Result<PortalMessages, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        let (settings, localized) = self.messages(realm.id, &input.hints).await?;
+
+        Ok(PortalMessages {
+            supported_locales: settings.supported_locales,
+            localized,
+        })
+    }
+
+    async fn get_supported_locales(&self, realm_name: String) -> Result<Vec<Locale>, CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+
+        Ok(self.settings(realm.id).await?.supported_locales)
+    }
+}
+
+impl<R, U, C, UR, LS, MB, UAR> EmailLocalizer for LocalizationServiceImpl<R, U, C, UR, LS, MB, UAR>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    LS: LocalizationSettingsRepository,
+    MB: MessageBundleRepository,
+    UAR: UserAttributeRepository,
+{
+    async fn email_messages(&self, realm_id: RealmId, user_id: Option<Uuid>) -> LocalizedMessages {
+        let user_locale = match user_id {
+            Some(user_id) => self
+                .user_locale(user_id)
+                .await
+                .inspect_err(|e| warn!("Failed to read the locale of user {user_id}: {e}"))
+                .ok()
+                .flatten(),
+            None => None,
+        };
+        let hints = LocaleHints {
+            user_locale,
+            ..LocaleHints::default()
+        };
+
+        match self.messages(realm_id, &hints).await {
+            Ok((_, messages)) => messages,
+            Err(e) => {
+                warn!("Failed to load the email texts of realm {realm_id:?}: {e}");
+                LocalizedMessages::builtin(Locale::default())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    use chrono::Utc;
+    use ferriskey_domain::client::ports::MockClientRepository;
+    use ferriskey_domain::realm::ports::MockRealmRepository;
+    use ferriskey_domain::user::entities::UserAttribute;
+    use ferriskey_domain::user::ports::{
+        MockUserAttributeRepository, MockUserRepository, MockUserRoleRepository,
+    };
+
+    use crate::ports::{MockLocalizationSettingsRepository, MockMessageBundleRepository};
+
+    type TestService = LocalizationServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockLocalizationSettingsRepository,
+        MockMessageBundleRepository,
+        MockUserAttributeRepository,
+    >;
+
+    fn locale(tag: &str) -> Locale {
+        Locale::parse(tag).unwrap()
+    }
+
+    fn service(
+        settings: Option<LocalizationSettings>,
+        bundles: Vec<MessageBundle>,
+        attributes: MockUserAttributeRepository,
+    ) -> TestService {
+        let mut settings_repository = MockLocalizationSettingsRepository::new();
+        settings_repository
+            .expect_get_by_realm()
+            .returning(move |_| {
+                let settings = settings.clone();
+                Box::pin(async move { Ok(settings) })
+            });
+
+        let mut bundle_repository = MockMessageBundleRepository::new();
+        bundle_repository
+            .expect_list_by_realm()
+            .returning(move |_| {
+                let bundles = bundles.clone();
+                Box::pin(async move { Ok(bundles) })
+            });
+
+        LocalizationServiceImpl::new(
+            Arc::new(MockRealmRepository::new()),
+            Arc::new(settings_repository),
+            Arc::new(bundle_repository),
+            Arc::new(attributes),
+            Arc::new(FerriskeyPolicy::new(
+                Arc::new(MockUserRepository::new()),
+                Arc::new(MockClientRepository::new()),
+                Arc::new(MockUserRoleRepository::new()),
+            )),
+        )
+    }
+
+    #[tokio::test]
+    async fn emails_are_written_in_the_language_of_the_user() {
+        let realm_id = RealmId::default();
+        let user_id = Uuid::new_v4();
+        let settings =
+            LocalizationSettings::new(realm_id, vec![locale("en"), locale("de")], locale("en"))
+                .unwrap();
+        let bundle = MessageBundle::new(
+            realm_id,
+            locale("de"),
+            BTreeMap::from([(
+                "email.magic_link.subject".to_string(),
+                "Ihr Acme-Anmeldelink".to_string(),
+            )]),
+        )
+        .unwrap();
+
+        let mut attributes = MockUserAttributeRepository::new();
+        attributes
+            .expect_list_by_user_id()
+            .returning(move |user_id| {
+                Box::pin(async move {
+                    Ok(vec![UserAttribute {
+                        id: Uuid::new_v4(),
+                        user_id,
+                        realm_id,
+                        key: USER_LOCALE_ATTRIBUTE.to_string(),
+                        value: "de-AT".to_string(),
+                        created_at: Utc::now(),
+                        updated_at: Utc::now(),
+                    }])
+                })
+            });
+
+        let messages = service(Some(settings), vec![bundle], attributes)
+            .email_messages(realm_id, Some(user_id))
+            .await;
+
+        assert_eq!(messages.locale, locale("de"));
+        assert_eq!(
+            messages.email_subject("magic_link", &[]),
+            "Ihr Acme-Anmeldelink"
+        );
+        assert_eq!(
+            messages.email_subject("reset_password", &[]),
+            "Setzen Sie Ihr Passwort zurück"
+        );
+    }
+
+    #[tokio::test]
+    async fn realms_without_settings_send_english_emails() {
+        let messages = service(None, vec![], MockUserAttributeRepository::new())
+            .email_messages(RealmId::default(), None)
+            .await;
+
+        assert_eq!(messages.locale, Locale::default());
+        assert_eq!(
+            messages.email_subject("email_verification", &[]),
+            "Verify your email address"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use uuid::Uuid;
+
+use crate::{
+    adapters::smtp::build_message, entities::EmailMessage, error::EmailError,
+    outbox::ports::EmailTransport, ports::EmailSender,
+};
+use ferriskey_domain::realm::SmtpConfig;
+
+/// Writes emails into a maildir instead of sending them, for local development: any mail
+/// client reading maildirs (mutt, `mblaze`, …) shows them as received.
+#[derive(Debug, Clone)]
+pub struct MaildirEmailSender {
+    root: PathBuf,
+}
+
+impl MaildirEmailSender {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl EmailSender for MaildirEmailSender {
+    async fn send(&self, message: EmailMessage) -> Result<(), EmailError> {
+        let formatted = build_message(message)?.formatted();
+        let io_error = |e: std::io::Error| EmailError::Transport(format!("maildir: {e}"));
+
+        for dir in ["tmp", "new", "cur"] {
+            tokio::fs::create_dir_all(self.root.join(dir))
+                .await
+                .map_err(io_error)?;
+        }
+
+        // Maildir delivery: write under tmp/, then move into new/ in one rename so readers
+        // never see a partial message.
+        let name = format!(
+            "{}.{}.ferriskey",
+            chrono::Utc::now().timestamp(),
+            Uuid::new_v4().simple()
+        );
+        let tmp = self.root.join("tmp").join(&name);
+        tokio::fs::write(&tmp, formatted).await.map_err(io_error)?;
+        tokio::fs::rename(&tmp, self.root.join("new").join(&name))
+            .await
+            .map_err(io_error)?;
+
+        Ok(())
+    }
+}
+
+/// Every outbox email lands in the maildir, whatever the SMTP settings of its realm.
+impl EmailTransport for MaildirEmailSender {
+    async fn deliver(&self, _config: &SmtpConfig, message: EmailMessage) -> Result<(), EmailError> {
+        self.send(message).await
+    }
+}
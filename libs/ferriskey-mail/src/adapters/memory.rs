@@ -0,0 +1,54 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    entities::EmailMessage, error::EmailError, outbox::ports::EmailTransport, ports::EmailSender,
+};
+use ferriskey_domain::realm::SmtpConfig;
+
+/// Keeps sent emails in memory, for tests asserting on what would have been sent. Clones
+/// share the same captured messages.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryEmailSender {
+    messages: Arc<Mutex<Vec<EmailMessage>>>,
+}
+
+impl InMemoryEmailSender {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The messages captured so far, oldest first.
+    pub fn messages(&self) -> Vec<EmailMessage> {
+        self.messages
+            .lock()
+            .expect("captured messages lock poisoned")
+            .clone()
+    }
+
+    pub fn clear(&self) {
+        self.messages
+            .lock()
+            .expect("captured messages lock poisoned")
+            .clear();
+    }
+}
+
+impl EmailSender for InMemoryEmailSender {
+    async fn send(&self, message: EmailMessage) -> Result<(), EmailError> {
+        if message.to.is_empty() {
+            return Err(EmailError::MissingRecipients);
+        }
+
+        self.messages
+            .lock()
+            .expect("captured messages lock poisoned")
+            .push(message);
+        Ok(())
+    }
+}
+
+impl EmailTransport for InMemoryEmailSender {
+    async fn deliver(&self, _config: &SmtpConfig, message: EmailMessage) -> Result<(), EmailError> {
+        self.send(message).await
+    }
+}
@@ -1 +1,3 @@
+pub mod maildir;
+pub mod memory;
 pub mod smtp;
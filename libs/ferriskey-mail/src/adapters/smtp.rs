@@ -7,8 +7,10 @@ use lettre::{
 use crate::{
     entities::{EmailMessage, SmtpEncryption},
     error::EmailError,
+    outbox::ports::EmailTransport,
     ports::EmailSender,
 };
+use ferriskey_domain::realm::{SmtpConfig, SmtpEncryption as RealmSmtpEncryption};
 
 pub struct SmtpEmailSender {
     mailer: AsyncSmtpTransport<Tokio1Executor>,
@@ -58,49 +60,7 @@ impl SmtpEmailSender {
 
 impl EmailSender for SmtpEmailSender {
     async fn send(&self, message: EmailMessage) -> Result<(), EmailError> {
-        let EmailMessage {
-            from,
-            to,
-            subject,
-            body,
-            html_body,
-        } = message;
-
-        if to.is_empty() {
-            return Err(EmailError::MissingRecipients);
-        }
-
-        let from_mailbox: Mailbox = from
-            .as_mailbox()
-            .parse()
-            .map_err(|e| EmailError::MessageBuild(format!("invalid from mailbox: {e}")))?;
-
-        let mut builder = Message::builder()
-            .from(from_mailbox)
-            .subject(subject.as_str());
-
-        for recipient in to {
-            let recipient_mailbox: Mailbox = recipient
-                .as_str()
-                .parse()
-                .map_err(|e| EmailError::MessageBuild(format!("invalid recipient mailbox: {e}")))?;
-            builder = builder.to(recipient_mailbox);
-        }
-
-        let email = match html_body {
-            Some(html) => builder
-                .multipart(
-                    MultiPart::alternative()
-                        .singlepart(SinglePart::plain(body))
-                        .singlepart(SinglePart::html(html)),
-                )
-                .map_err(|e| {
-                    EmailError::MessageBuild(format!("failed to build email message: {e}"))
-                })?,
-            None => builder.body(body).map_err(|e| {
-                EmailError::MessageBuild(format!("failed to build email message: {e}"))
-            })?,
-        };
+        let email = build_message(message)?;
 
         self.mailer
             .send(email)
@@ -110,3 +70,69 @@ impl EmailSender for SmtpEmailSender {
         Ok(())
     }
 }
+
+/// Builds the MIME message of `message`: plain text, or plain text and HTML alternatives.
+pub(crate) fn build_message(message: EmailMessage) -> Result<Message, EmailError> {
+    let EmailMessage {
+        from,
+        to,
+        subject,
+        body,
+        html_body,
+    } = message;
+
+    if to.is_empty() {
+        return Err(EmailError::MissingRecipients);
+    }
+
+    let from_mailbox: Mailbox = from
+        .as_mailbox()
+        .parse()
+        .map_err(|e| EmailError::MessageBuild(format!("invalid from mailbox: {e}")))?;
+
+    let mut builder = Message::builder()
+        .from(from_mailbox)
+        .subject(subject.as_str());
+
+    for recipient in to {
+        let recipient_mailbox: Mailbox = recipient
+            .as_str()
+            .parse()
+            .map_err(|e| EmailError::MessageBuild(format!("invalid recipient mailbox: {e}")))?;
+        builder = builder.to(recipient_mailbox);
+    }
+
+    match html_body {
+        Some(html) => builder.multipart(
+            MultiPart::alternative()
+                .singlepart(SinglePart::plain(body))
+                .singlepart(SinglePart::html(html)),
+        ),
+        None => builder.body(body),
+    }
+    .map_err(|e| EmailError::MessageBuild(format!("failed to build email message: {e}")))
+}
+
+/// Sends outbox emails over SMTP with the configuration of their realm.
+#[derive(Debug, Clone, Default)]
+pub struct SmtpTransport;
+
+impl EmailTransport for SmtpTransport {
+    async fn deliver(&self, config: &SmtpConfig, message: EmailMessage) -> Result<(), EmailError> {
+        let encryption = match config.encryption {
+            RealmSmtpEncryption::Tls => SmtpEncryption::Tls,
+            RealmSmtpEncryption::StartTls => SmtpEncryption::StartTls,
+            RealmSmtpEncryption::None => SmtpEncryption::None,
+        };
+
+        SmtpEmailSender::with_config(
+            &config.host,
+            config.port,
+            &config.username,
+            &config.password,
+            &encryption,
+        )?
+        .send(message)
+        .await
+    }
+}
@@ -7,6 +7,7 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 
 use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::common::security_notification::SecurityNotificationKind;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
@@ -14,6 +15,14 @@ pub enum EmailType {
     ResetPassword,
     MagicLink,
     EmailVerification,
+    OrganizationInvitation,
+    PasswordChanged,
+    NewSignIn,
+    AccountLocked,
+    MfaFactorAdded,
+    MfaFactorRemoved,
+    EmailChanged,
+    IdentityProviderLinked,
 }
 
 impl Display for EmailType {
@@ -22,6 +31,14 @@ impl Display for EmailType {
             EmailType::ResetPassword => write!(f, "reset_password"),
             EmailType::MagicLink => write!(f, "magic_link"),
             EmailType::EmailVerification => write!(f, "email_verification"),
+            EmailType::OrganizationInvitation => write!(f, "organization_invitation"),
+            EmailType::PasswordChanged => write!(f, "password_changed"),
+            EmailType::NewSignIn => write!(f, "new_sign_in"),
+            EmailType::AccountLocked => write!(f, "account_locked"),
+            EmailType::MfaFactorAdded => write!(f, "mfa_factor_added"),
+            EmailType::MfaFactorRemoved => write!(f, "mfa_factor_removed"),
+            EmailType::EmailChanged => write!(f, "email_changed"),
+            EmailType::IdentityProviderLinked => write!(f, "identity_provider_linked"),
         }
     }
 }
@@ -34,6 +51,14 @@ impl TryFrom<String> for EmailType {
             "reset_password" => Ok(EmailType::ResetPassword),
             "magic_link" => Ok(EmailType::MagicLink),
             "email_verification" => Ok(EmailType::EmailVerification),
+            "organization_invitation" => Ok(EmailType::OrganizationInvitation),
+            "password_changed" => Ok(EmailType::PasswordChanged),
+            "new_sign_in" => Ok(EmailType::NewSignIn),
+            "account_locked" => Ok(EmailType::AccountLocked),
+            "mfa_factor_added" => Ok(EmailType::MfaFactorAdded),
+            "mfa_factor_removed" => Ok(EmailType::MfaFactorRemoved),
+            "email_changed" => Ok(EmailType::EmailChanged),
+            "identity_provider_linked" => Ok(EmailType::IdentityProviderLinked),
             _ => Err(CoreError::InvalidEmailTemplateStructure(format!(
                 "unknown email type: {value}"
             ))),
@@ -41,6 +66,20 @@ impl TryFrom<String> for EmailType {
     }
 }
 
+impl From<SecurityNotificationKind> for EmailType {
+    fn from(kind: SecurityNotificationKind) -> Self {
+        match kind {
+            SecurityNotificationKind::PasswordChanged => EmailType::PasswordChanged,
+            SecurityNotificationKind::NewSignIn => EmailType::NewSignIn,
+            SecurityNotificationKind::AccountLocked => EmailType::AccountLocked,
+            SecurityNotificationKind::MfaFactorAdded => EmailType::MfaFactorAdded,
+            SecurityNotificationKind::MfaFactorRemoved => EmailType::MfaFactorRemoved,
+            SecurityNotificationKind::EmailChanged => EmailType::EmailChanged,
+            SecurityNotificationKind::IdentityProviderLinked => EmailType::IdentityProviderLinked,
+        }
+    }
+}
+
 impl EmailType {
     pub fn available_variables(&self) -> Vec<TemplateVariable> {
         let mut vars = vec![
@@ -56,11 +95,27 @@ impl EmailType {
                 name: "user.email".to_string(),
                 description: "User's email address".to_string(),
             },
-            TemplateVariable {
+        ];
+
+        if self.is_security_notification() {
+            vars.extend(notification_variables(&[
+                (
+                    "notification.subject",
+                    "Localized subject of the notification",
+                ),
+                (
+                    "notification.body",
+                    "Localized plain-text message, details included",
+                ),
+                ("realm.name", "Name of the realm"),
+                ("occurred_at", "When the change happened (UTC)"),
+            ]));
+        } else {
+            vars.push(TemplateVariable {
                 name: "expiration".to_string(),
                 description: "Expiration time".to_string(),
-            },
-        ];
+            });
+        }
 
         match self {
             EmailType::ResetPassword => {
@@ -81,10 +136,91 @@ impl EmailType {
                     description: "Email verification link".to_string(),
                 });
             }
+            EmailType::OrganizationInvitation => {
+                vars.push(TemplateVariable {
+                    name: "invitation_link".to_string(),
+                    description: "Invitation acceptance link".to_string(),
+                });
+                vars.push(TemplateVariable {
+                    name: "organization.name".to_string(),
+                    description: "Name of the inviting organization".to_string(),
+                });
+            }
+            EmailType::PasswordChanged => {}
+            EmailType::AccountLocked => {
+                vars.extend(notification_variables(&[(
+                    "locked_until",
+                    "When sign-in attempts are accepted again (UTC)",
+                )]));
+            }
+            EmailType::NewSignIn => {
+                vars.extend(notification_variables(&[
+                    ("ip_address", "IP address of the sign-in"),
+                    ("device", "Browser or client that signed in"),
+                    ("location", "Country resolved from the IP address"),
+                ]));
+            }
+            EmailType::MfaFactorAdded | EmailType::MfaFactorRemoved => {
+                vars.extend(notification_variables(&[(
+                    "factor",
+                    "Kind of factor, e.g. authenticator app or security key",
+                )]));
+            }
+            EmailType::EmailChanged => {
+                vars.extend(notification_variables(&[(
+                    "new_email",
+                    "Address the account now uses",
+                )]));
+            }
+            EmailType::IdentityProviderLinked => {
+                vars.extend(notification_variables(&[(
+                    "identity_provider",
+                    "Identity provider the account was linked to",
+                )]));
+            }
         }
 
         vars
     }
+
+    /// Whether this type is one of the security notifications, sent after the fact rather
+    /// than carrying a link the user has to follow.
+    pub fn is_security_notification(&self) -> bool {
+        !matches!(
+            self,
+            EmailType::ResetPassword
+                | EmailType::MagicLink
+                | EmailType::EmailVerification
+                | EmailType::OrganizationInvitation
+        )
+    }
+
+    /// The MJML sent when the realm has not picked a template for this type. Only security
+    /// notifications have one: the other emails are not sent without a template.
+    pub fn default_mjml(&self) -> Option<String> {
+        if !self.is_security_notification() {
+            return None;
+        }
+
+        let accent = match self {
+            EmailType::AccountLocked | EmailType::MfaFactorRemoved => "#b42318",
+            _ => "#1f2937",
+        };
+
+        Some(format!(
+            r##"<mjml><mj-head><mj-style>.notification div {{ white-space: pre-line; }}</mj-style></mj-head><mj-body background-color="#f4f4f5"><mj-section background-color="#ffffff" border-top="4px solid {accent}"><mj-column><mj-text font-size="20px" font-weight="bold" color="{accent}">{{{{notification.subject}}}}</mj-text><mj-text css-class="notification" font-size="14px" line-height="22px" color="#374151">{{{{notification.body}}}}</mj-text></mj-column></mj-section><mj-section><mj-column><mj-text font-size="12px" color="#6b7280">{{{{realm.name}}}}</mj-text></mj-column></mj-section></mj-body></mjml>"##
+        ))
+    }
+}
+
+fn notification_variables(variables: &[(&str, &str)]) -> Vec<TemplateVariable> {
+    variables
+        .iter()
+        .map(|(name, description)| TemplateVariable {
+            name: name.to_string(),
+            description: description.to_string(),
+        })
+        .collect()
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
@@ -190,5 +326,27 @@ mod tests {
 
         let vars = EmailType::EmailVerification.available_variables();
         assert!(vars.iter().any(|v| v.name == "verification_link"));
+
+        let vars = EmailType::OrganizationInvitation.available_variables();
+        assert!(vars.iter().any(|v| v.name == "invitation_link"));
+        assert!(vars.iter().any(|v| v.name == "organization.name"));
+
+        let vars = EmailType::NewSignIn.available_variables();
+        assert!(vars.iter().any(|v| v.name == "ip_address"));
+        assert!(vars.iter().any(|v| v.name == "notification.body"));
+        assert!(!vars.iter().any(|v| v.name == "expiration"));
+    }
+
+    #[test]
+    fn test_security_notifications_have_a_default_template() {
+        for kind in SecurityNotificationKind::ALL {
+            let email_type = EmailType::from(kind);
+            assert_eq!(email_type.to_string(), kind.to_string());
+            assert_eq!(EmailType::try_from(kind.to_string()).unwrap(), email_type);
+
+            let mjml = email_type.default_mjml().unwrap();
+            assert!(mjml.contains("{{notification.body}}"));
+        }
+        assert!(EmailType::MagicLink.default_mjml().is_none());
     }
 }
@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration as StdDuration;
 
@@ -15,6 +14,7 @@ use ferriskey_domain::common::email::EmailPort;
 use ferriskey_domain::realm::ports::{RealmRepository, SmtpConfigRepository};
 use ferriskey_domain::user::entities::{RequiredAction, RequiredActionError};
 use ferriskey_domain::user::ports::{UserRepository, UserRequiredActionRepository};
+use ferriskey_localization::{EmailLocalizer, LocalizedMessages};
 use ferriskey_seawatch::{EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType};
 use ferriskey_webhook::entities::webhook_payload::WebhookPayload;
 use ferriskey_webhook::entities::webhook_trigger::WebhookTrigger;
@@ -34,7 +34,7 @@ const EMAIL_DELIVERY_TIMEOUT: StdDuration = StdDuration::from_secs(10);
 const EMAIL_DELIVERY_TIMEOUT: StdDuration = StdDuration::from_millis(10);
 
 #[derive(Debug)]
-pub struct EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER>
+pub struct EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, EL>
 where
     EVRT: EmailVerificationTokenRepository,
     UR: UserRepository,
@@ -46,6 +46,7 @@ where
     TR: TemplateRenderer,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    EL: EmailLocalizer,
 {
     pub(crate) email_verification_token_repository: Arc<EVRT>,
     pub(crate) user_repository: Arc<UR>,
@@ -57,10 +58,11 @@ where
     pub(crate) template_renderer: Arc<TR>,
     pub(crate) webhook_repository: Arc<WR>,
     pub(crate) security_event_repository: Arc<SER>,
+    pub(crate) email_localizer: Arc<EL>,
 }
 
-impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER> Clone
-    for EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER>
+impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, EL> Clone
+    for EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, EL>
 where
     EVRT: EmailVerificationTokenRepository,
     UR: UserRepository,
@@ -72,6 +74,7 @@ where
     TR: TemplateRenderer,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    EL: EmailLocalizer,
 {
     fn clone(&self) -> Self {
         Self {
@@ -85,12 +88,13 @@ where
             template_renderer: self.template_renderer.clone(),
             webhook_repository: self.webhook_repository.clone(),
             security_event_repository: self.security_event_repository.clone(),
+            email_localizer: self.email_localizer.clone(),
         }
     }
 }
 
-impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER>
-    EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER>
+impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, EL>
+    EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, EL>
 where
     EVRT: EmailVerificationTokenRepository,
     UR: UserRepository,
@@ -102,6 +106,7 @@ where
     TR: TemplateRenderer,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    EL: EmailLocalizer,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -115,6 +120,7 @@ where
         template_renderer: Arc<TR>,
         webhook_repository: Arc<WR>,
         security_event_repository: Arc<SER>,
+        email_localizer: Arc<EL>,
     ) -> Self {
         Self {
             email_verification_token_repository,
@@ -127,6 +133,7 @@ where
             template_renderer,
             webhook_repository,
             security_event_repository,
+            email_localizer,
         }
     }
 
@@ -136,6 +143,7 @@ where
         template_id: Uuid,
         user: &ferriskey_domain::user::entities::User,
         extra_vars: &[(&str, &str)],
+        messages: &LocalizedMessages,
     ) -> Result<String, CoreError> {
         let template = self
             .email_template_repository
@@ -145,7 +153,7 @@ where
 
         let html = self.template_renderer.render_to_html(&template.mjml)?;
 
-        let mut variables = HashMap::new();
+        let mut variables = messages.template_variables();
         variables.insert(
             "user.first_name".to_string(),
             user.firstname.clone().unwrap_or_default(),
@@ -166,8 +174,8 @@ where
     }
 }
 
-impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER> EmailVerificationService
-    for EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER>
+impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, EL> EmailVerificationService
+    for EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, EL>
 where
     EVRT: EmailVerificationTokenRepository,
     UR: UserRepository,
@@ -179,6 +187,7 @@ where
     TR: TemplateRenderer,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    EL: EmailLocalizer,
 {
     async fn send_verification_email(
         &self,
@@ -237,39 +246,27 @@ where
             base_url, realm_name, raw_token
         );
 
-        let expiration_label = if ttl_hours == 1 {
-            "1 hour".to_string()
-        } else {
-            format!("{} hours", ttl_hours)
-        };
-
-        let body = format!(
-            "Click the link below to verify your email address:\n{}\n\nThis link expires in {}.\n\nIf you did not register for this account, please ignore this email.",
-            verification_link, expiration_label
-        );
+        let messages = self
+            .email_localizer
+            .email_messages(realm.id, Some(user.id))
+            .await;
+        let expiration_label = messages.hours(ttl_hours);
+        let variables = [
+            ("verification_link", verification_link.as_str()),
+            ("expiration", expiration_label.as_str()),
+        ];
+        let subject = messages.email_subject("email_verification", &variables);
+        let body = messages.email_body("email_verification", &variables);
 
         let html_body = self
-            .render_email_template(
-                realm.id.into(),
-                template_id,
-                &user,
-                &[
-                    ("verification_link", verification_link.as_str()),
-                    ("expiration", expiration_label.as_str()),
-                ],
-            )
+            .render_email_template(realm.id.into(), template_id, &user, &variables, &messages)
             .await
             .ok();
 
         let delivery = timeout(
             EMAIL_DELIVERY_TIMEOUT,
-            self.email_port.send_email(
-                &smtp_config,
-                user_email,
-                "Verify your email address",
-                &body,
-                html_body,
-            ),
+            self.email_port
+                .send_email(&smtp_config, user_email, &subject, &body, html_body),
         )
         .await;
 
@@ -439,6 +436,7 @@ mod tests {
     use ferriskey_domain::realm::{Realm, RealmId, RealmSetting, SmtpConfig, SmtpEncryption};
     use ferriskey_domain::user::entities::{User, UserConfig};
     use ferriskey_domain::user::ports::{MockUserRepository, MockUserRequiredActionRepository};
+    use ferriskey_localization::{Locale, MockEmailLocalizer};
     use ferriskey_seawatch::ports::MockSecurityEventRepository;
     use ferriskey_webhook::ports::MockWebhookRepository;
     use mockall::predicate::*;
@@ -496,6 +494,7 @@ mod tests {
             from_email: "noreply@example.com".to_string(),
             from_name: "Test".to_string(),
             encryption: SmtpEncryption::Tls,
+            max_emails_per_minute: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -548,7 +547,13 @@ mod tests {
         TestRenderer,
         MockWebhookRepository,
         MockSecurityEventRepository,
+        MockEmailLocalizer,
     > {
+        let mut email_localizer = MockEmailLocalizer::new();
+        email_localizer
+            .expect_email_messages()
+            .returning(|_, _| Box::pin(async { LocalizedMessages::builtin(Locale::default()) }));
+
         EmailVerificationServiceImpl::new(
             Arc::new(evrt),
             Arc::new(user_repo),
@@ -560,6 +565,7 @@ mod tests {
             Arc::new(TestRenderer),
             Arc::new(webhook_repo),
             Arc::new(security_event_repo),
+            Arc::new(email_localizer),
         )
     }
 
@@ -21,6 +21,13 @@ pub enum EmailError {
     Transport(String),
 }
 
+impl EmailError {
+    /// Whether retrying cannot help: only transport failures are transient.
+    pub fn is_permanent(&self) -> bool {
+        !matches!(self, EmailError::Transport(_))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::EmailError;
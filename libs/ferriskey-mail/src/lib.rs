@@ -3,4 +3,6 @@ pub mod email_template;
 pub mod email_verification;
 pub mod entities;
 pub mod error;
+pub mod outbox;
 pub mod ports;
+pub mod security_notification;
@@ -0,0 +1,221 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Utc};
+use ferriskey_domain::generate_timestamp;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Delivery attempts before an email is given up and marked failed.
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 8;
+
+/// Delay before the first retry; doubled after each failed attempt.
+const FIRST_RETRY_DELAY: Duration = Duration::seconds(30);
+const MAX_RETRY_DELAY: Duration = Duration::hours(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxEmailStatus {
+    /// Waiting for its first or next delivery attempt.
+    Pending,
+    /// Claimed by a worker. A worker that dies mid-send leaves it claimable again once
+    /// `next_attempt_at` is reached.
+    Sending,
+    Sent,
+    /// Gave up after [`MAX_DELIVERY_ATTEMPTS`]; only a resend queues it again.
+    Failed,
+}
+
+impl Display for OutboxEmailStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutboxEmailStatus::Pending => write!(f, "pending"),
+            OutboxEmailStatus::Sending => write!(f, "sending"),
+            OutboxEmailStatus::Sent => write!(f, "sent"),
+            OutboxEmailStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl FromStr for OutboxEmailStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(OutboxEmailStatus::Pending),
+            "sending" => Ok(OutboxEmailStatus::Sending),
+            "sent" => Ok(OutboxEmailStatus::Sent),
+            "failed" => Ok(OutboxEmailStatus::Failed),
+            other => Err(format!("unknown outbox email status: {other}")),
+        }
+    }
+}
+
+/// An email persisted before delivery. The sender address comes from the realm SMTP
+/// configuration at delivery time, so a fixed configuration applies to queued emails too.
+///
+/// Bodies are never serialized: they carry password reset and magic link tokens, which
+/// the admin listing must not hand out.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct OutboxEmail {
+    pub id: Uuid,
+    pub realm_id: Uuid,
+    pub recipient: String,
+    pub subject: String,
+    #[serde(default, skip_serializing)]
+    pub body: String,
+    #[serde(default, skip_serializing)]
+    pub html_body: Option<String>,
+    pub status: OutboxEmailStatus,
+    pub attempts: u32,
+    pub next_attempt_at: DateTime<Utc>,
+    /// Error of the last failed attempt.
+    pub last_error: Option<String>,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OutboxEmail {
+    pub fn new(
+        realm_id: Uuid,
+        recipient: String,
+        subject: String,
+        body: String,
+        html_body: Option<String>,
+    ) -> Self {
+        let (now, timestamp) = generate_timestamp();
+        Self {
+            id: Uuid::new_v7(timestamp),
+            realm_id,
+            recipient,
+            subject,
+            body,
+            html_body,
+            status: OutboxEmailStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+            sent_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Delay after the `attempts`-th failed attempt: 30 seconds, doubled each time, at most
+    /// an hour.
+    pub fn retry_delay(attempts: u32) -> Duration {
+        let factor = 1i32 << attempts.saturating_sub(1).min(16);
+        (FIRST_RETRY_DELAY * factor).min(MAX_RETRY_DELAY)
+    }
+
+    pub fn record_sent(&mut self, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.status = OutboxEmailStatus::Sent;
+        self.last_error = None;
+        self.sent_at = Some(now);
+        self.updated_at = now;
+    }
+
+    /// Schedules the next attempt with backoff, or marks the email failed once
+    /// [`MAX_DELIVERY_ATTEMPTS`] is reached.
+    pub fn record_failure(&mut self, error: String, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.last_error = Some(error);
+        self.updated_at = now;
+
+        if self.attempts >= MAX_DELIVERY_ATTEMPTS {
+            self.status = OutboxEmailStatus::Failed;
+        } else {
+            self.status = OutboxEmailStatus::Pending;
+            self.next_attempt_at = now + Self::retry_delay(self.attempts);
+        }
+    }
+
+    /// Marks the email failed without further attempts, for messages no retry can deliver.
+    pub fn abandon(&mut self, error: String, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.status = OutboxEmailStatus::Failed;
+        self.last_error = Some(error);
+        self.updated_at = now;
+    }
+
+    /// Only delivered or abandoned emails can be resent; the others are still queued.
+    pub fn can_resend(&self) -> bool {
+        matches!(
+            self.status,
+            OutboxEmailStatus::Sent | OutboxEmailStatus::Failed
+        )
+    }
+
+    /// Queues the email again with a fresh attempt budget.
+    pub fn resend(&mut self, now: DateTime<Utc>) {
+        self.status = OutboxEmailStatus::Pending;
+        self.attempts = 0;
+        self.next_attempt_at = now;
+        self.last_error = None;
+        self.sent_at = None;
+        self.updated_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn email() -> OutboxEmail {
+        OutboxEmail::new(
+            Uuid::nil(),
+            "alice@example.com".to_string(),
+            "Reset your password".to_string(),
+            "Body".to_string(),
+            None,
+        )
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_an_hour() {
+        assert_eq!(OutboxEmail::retry_delay(1), Duration::seconds(30));
+        assert_eq!(OutboxEmail::retry_delay(2), Duration::seconds(60));
+        assert_eq!(OutboxEmail::retry_delay(4), Duration::seconds(240));
+        assert_eq!(OutboxEmail::retry_delay(7), Duration::minutes(32));
+        assert_eq!(OutboxEmail::retry_delay(8), Duration::hours(1));
+        assert_eq!(OutboxEmail::retry_delay(40), Duration::hours(1));
+    }
+
+    #[test]
+    fn failures_are_retried_until_the_attempt_budget_is_spent() {
+        let now = Utc::now();
+        let mut email = email();
+
+        email.record_failure("connection refused".to_string(), now);
+        assert_eq!(email.status, OutboxEmailStatus::Pending);
+        assert_eq!(email.next_attempt_at, now + Duration::seconds(30));
+        assert!(!email.can_resend());
+
+        for _ in 1..MAX_DELIVERY_ATTEMPTS {
+            email.record_failure("connection refused".to_string(), now);
+        }
+        assert_eq!(email.status, OutboxEmailStatus::Failed);
+        assert_eq!(email.attempts, MAX_DELIVERY_ATTEMPTS);
+        assert!(email.can_resend());
+
+        email.resend(now);
+        assert_eq!(email.status, OutboxEmailStatus::Pending);
+        assert_eq!(email.attempts, 0);
+        assert_eq!(email.last_error, None);
+    }
+
+    #[test]
+    fn status_round_trips_through_its_string_form() {
+        for status in [
+            OutboxEmailStatus::Pending,
+            OutboxEmailStatus::Sending,
+            OutboxEmailStatus::Sent,
+            OutboxEmailStatus::Failed,
+        ] {
+            assert_eq!(status.to_string().parse::<OutboxEmailStatus>(), Ok(status));
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::entities::EmailMessage;
+use crate::error::EmailError;
+use crate::outbox::entities::{OutboxEmail, OutboxEmailStatus};
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::{Realm, SmtpConfig};
+
+pub trait EmailOutboxService: Send + Sync {
+    fn get_outbox_emails(
+        &self,
+        identity: Identity,
+        input: GetOutboxEmailsInput,
+    ) -> impl Future<Output = Result<Vec<OutboxEmail>, CoreError>> + Send;
+
+    /// Queues a sent or failed email again.
+    fn resend_outbox_email(
+        &self,
+        identity: Identity,
+        input: ResendOutboxEmailInput,
+    ) -> impl Future<Output = Result<OutboxEmail, CoreError>> + Send;
+
+    /// Attempts the emails that are due, within the send rate of each realm, and returns how
+    /// many were attempted. Called by the outbox worker.
+    fn deliver_due_emails(&self) -> impl Future<Output = Result<usize, CoreError>> + Send;
+
+    /// Deletes sent and failed emails last updated before `before`, bodies included, and
+    /// returns how many were deleted. Called by the outbox purge task.
+    fn purge_finished_emails(
+        &self,
+        before: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+}
+
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait EmailOutboxRepository: Send + Sync {
+    fn enqueue(&self, email: &OutboxEmail) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get(
+        &self,
+        realm_id: Uuid,
+        email_id: Uuid,
+    ) -> impl Future<Output = Result<Option<OutboxEmail>, CoreError>> + Send;
+
+    /// Most recent first.
+    fn list_by_realm(
+        &self,
+        realm_id: Uuid,
+        status: Option<OutboxEmailStatus>,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<OutboxEmail>, CoreError>> + Send;
+
+    /// Pending emails whose `next_attempt_at` is reached, and sending ones whose claim
+    /// expired, oldest first.
+    fn list_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<OutboxEmail>, CoreError>> + Send;
+
+    /// Marks `email` as sending until `claimed_until`, provided no other worker claimed it
+    /// since it was listed. Returns whether this call got the claim.
+    fn claim(
+        &self,
+        email: &OutboxEmail,
+        claimed_until: DateTime<Utc>,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    /// Moves the next attempt of `email` to `next_attempt_at` without counting an attempt,
+    /// provided no worker claimed it since it was listed. Returns whether it was moved.
+    fn postpone(
+        &self,
+        email: &OutboxEmail,
+        next_attempt_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    /// Stores the status, attempts and schedule of `email`.
+    fn save_attempt(
+        &self,
+        email: &OutboxEmail,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Deletes sent and failed emails last updated before `before`.
+    fn delete_finished_before(
+        &self,
+        before: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+
+    fn count_sent_since(
+        &self,
+        realm_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+}
+
+/// Hands an email to the outside world with the SMTP configuration of its realm.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait EmailTransport: Send + Sync {
+    fn deliver(
+        &self,
+        config: &SmtpConfig,
+        message: EmailMessage,
+    ) -> impl Future<Output = Result<(), EmailError>> + Send;
+}
+
+pub trait EmailOutboxPolicy: Send + Sync {
+    fn can_view_outbox(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_manage_outbox(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+pub struct GetOutboxEmailsInput {
+    pub realm_name: String,
+    pub status: Option<OutboxEmailStatus>,
+    pub limit: Option<u64>,
+}
+
+pub struct ResendOutboxEmailInput {
+    pub realm_name: String,
+    pub email_id: Uuid,
+}
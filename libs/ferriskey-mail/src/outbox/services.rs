@@ -0,0 +1,551 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::entities::{EmailAddress, EmailFrom, EmailMessage, EmailSubject};
+use crate::error::EmailError;
+use crate::outbox::entities::OutboxEmail;
+use crate::outbox::ports::{
+    EmailOutboxPolicy, EmailOutboxRepository, EmailOutboxService, EmailTransport,
+    GetOutboxEmailsInput, ResendOutboxEmailInput,
+};
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::client::ports::ClientRepository;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use ferriskey_domain::realm::ports::{RealmRepository, SmtpConfigRepository};
+use ferriskey_domain::realm::{RealmId, SmtpConfig};
+use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
+
+/// Emails attempted per worker tick.
+const DELIVERY_BATCH_SIZE: u64 = 100;
+/// How long a worker owns the emails it claimed before another may retry them.
+const CLAIM_DURATION: chrono::Duration = chrono::Duration::minutes(5);
+/// Window of the per-realm send rate.
+const RATE_WINDOW: chrono::Duration = chrono::Duration::minutes(1);
+const DEFAULT_LIST_LIMIT: u64 = 50;
+const MAX_LIST_LIMIT: u64 = 500;
+
+#[derive(Clone, Debug)]
+pub struct EmailOutboxServiceImpl<R, U, C, UR, SC, EO, ET>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SC: SmtpConfigRepository,
+    EO: EmailOutboxRepository,
+    ET: EmailTransport,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) smtp_config_repository: Arc<SC>,
+    pub(crate) email_outbox_repository: Arc<EO>,
+    pub(crate) email_transport: Arc<ET>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+/// What the worker may still send for one realm during a tick.
+struct RealmBudget {
+    config: Option<SmtpConfig>,
+    /// Emails left under the per-minute limit, `None` when unlimited.
+    remaining: Option<u64>,
+}
+
+impl<R, U, C, UR, SC, EO, ET> EmailOutboxServiceImpl<R, U, C, UR, SC, EO, ET>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SC: SmtpConfigRepository,
+    EO: EmailOutboxRepository,
+    ET: EmailTransport,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        smtp_config_repository: Arc<SC>,
+        email_outbox_repository: Arc<EO>,
+        email_transport: Arc<ET>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            smtp_config_repository,
+            email_outbox_repository,
+            email_transport,
+            policy,
+        }
+    }
+
+    async fn realm_budget(&self, realm_id: Uuid) -> Result<RealmBudget, CoreError> {
+        let config = self
+            .smtp_config_repository
+            .get_by_realm_id(RealmId::new(realm_id))
+            .await?;
+
+        let remaining = match config.as_ref().and_then(|c| c.max_emails_per_minute) {
+            Some(limit) => {
+                let since = Utc::now() - RATE_WINDOW;
+                let sent = self
+                    .email_outbox_repository
+                    .count_sent_since(realm_id, since)
+                    .await?;
+                Some(u64::from(limit).saturating_sub(sent))
+            }
+            None => None,
+        };
+
+        Ok(RealmBudget { config, remaining })
+    }
+
+    async fn deliver(
+        &self,
+        config: Option<&SmtpConfig>,
+        email: &OutboxEmail,
+    ) -> Result<(), EmailError> {
+        let config = config.ok_or_else(|| {
+            EmailError::Transport("SMTP is not configured for the realm".to_string())
+        })?;
+
+        let from = EmailFrom::new(
+            config.from_name.clone(),
+            EmailAddress::new(config.from_email.clone())?,
+        )?;
+        let message = EmailMessage::new(
+            from,
+            vec![EmailAddress::new(email.recipient.clone())?],
+            EmailSubject::new(email.subject.clone())?,
+            email.body.clone(),
+            email.html_body.clone(),
+        )?;
+
+        self.email_transport.deliver(config, message).await
+    }
+}
+
+impl<R, U, C, UR, SC, EO, ET> EmailOutboxService for EmailOutboxServiceImpl<R, U, C, UR, SC, EO, ET>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SC: SmtpConfigRepository,
+    EO: EmailOutboxRepository,
+    ET: EmailTransport,
+{
+    async fn get_outbox_emails(
+        &self,
+        identity: Identity,
+        input: GetOutboxEmailsInput,
+    ) -> Result<Vec<OutboxEmail>, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&input.realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        ensure_policy(
+            self.policy.can_view_outbox(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let limit = input
+            .limit
+            .unwrap_or(DEFAULT_LIST_LIMIT)
+            .clamp(1, MAX_LIST_LIMIT);
+
+        self.email_outbox_repository
+            .list_by_realm(realm.id.into(), input.status, limit)
+            .await
+    }
+
+    async fn resend_outbox_email(
+        &self,
+        identity: Identity,
+        input: ResendOutboxEmailInput,
+    ) -> Result<OutboxEmail, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&input.realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        ensure_policy(
+            self.policy.can_manage_outbox(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let mut email = self
+            .email_outbox_repository
+            .get(realm.id.into(), input.email_id)
+            .await?
+            .ok_or(CoreError::OutboxEmailNotFound)?;
+
+        if !email.can_resend() {
+            return Err(CoreError::OutboxEmailStillQueued);
+        }
+
+        email.resend(Utc::now());
+        self.email_outbox_repository.save_attempt(&email).await?;
+
+        Ok(email)
+    }
+
+    async fn deliver_due_emails(&self) -> Result<usize, CoreError> {
+        let now = Utc::now();
+        let due = self
+            .email_outbox_repository
+            .list_due(now, DELIVERY_BATCH_SIZE)
+            .await?;
+
+        let mut budgets: HashMap<Uuid, RealmBudget> = HashMap::new();
+        let mut attempted = 0;
+
+        for mut email in due {
+            let budget = match budgets.entry(email.realm_id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(self.realm_budget(email.realm_id).await?),
+            };
+
+            // Over the realm's rate: move the email past the rate window, otherwise a realm
+            // with a full batch of queued emails would fill every batch and starve the others.
+            if budget.remaining == Some(0) {
+                self.email_outbox_repository
+                    .postpone(&email, now + RATE_WINDOW)
+                    .await?;
+                continue;
+            }
+
+            if !self
+                .email_outbox_repository
+                .claim(&email, Utc::now() + CLAIM_DURATION)
+                .await?
+            {
+                continue;
+            }
+
+            if let Some(remaining) = budget.remaining.as_mut() {
+                *remaining -= 1;
+            }
+            attempted += 1;
+
+            let result = self.deliver(budget.config.as_ref(), &email).await;
+            let now = Utc::now();
+            match result {
+                Ok(()) => email.record_sent(now),
+                Err(error) if error.is_permanent() => {
+                    warn!(email_id = %email.id, error = %error, "Outbox email cannot be delivered");
+                    email.abandon(error.to_string(), now);
+                }
+                Err(error) => {
+                    warn!(email_id = %email.id, attempts = email.attempts + 1, error = %error, "Outbox email delivery failed");
+                    email.record_failure(error.to_string(), now);
+                }
+            }
+
+            self.email_outbox_repository.save_attempt(&email).await?;
+        }
+
+        Ok(attempted)
+    }
+
+    async fn purge_finished_emails(&self, before: DateTime<Utc>) -> Result<u64, CoreError> {
+        self.email_outbox_repository
+            .delete_finished_before(before)
+            .await
+    }
+}
+
+/// Delivers due outbox emails every `period`.
+pub async fn email_outbox_worker_task<S>(service: S, period: Duration)
+where
+    S: EmailOutboxService,
+{
+    let mut ticker = tokio::time::interval(period);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+        match service.deliver_due_emails().await {
+            Ok(0) => {}
+            Ok(attempted) => info!(attempted, "Delivered due outbox emails"),
+            Err(error) => warn!(error = ?error, "Failed to deliver outbox emails"),
+        }
+    }
+}
+
+/// Deletes, every `period`, the sent and failed emails older than `retention`: their bodies
+/// carry password reset and magic link tokens, which must not stay readable in the
+/// database. A resend is only possible until then.
+pub async fn email_outbox_purge_task<S>(service: S, retention: chrono::Duration, period: Duration)
+where
+    S: EmailOutboxService,
+{
+    let mut ticker = tokio::time::interval(period);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+        match service.purge_finished_emails(Utc::now() - retention).await {
+            Ok(0) => {}
+            Ok(deleted) => info!(deleted, "Purged finished outbox emails"),
+            Err(error) => warn!(error = ?error, "Failed to purge outbox emails"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::adapters::memory::InMemoryEmailSender;
+    use crate::outbox::entities::OutboxEmailStatus;
+    use crate::outbox::ports::{MockEmailOutboxRepository, MockEmailTransport};
+    use ferriskey_domain::client::ports::MockClientRepository;
+    use ferriskey_domain::realm::SmtpEncryption;
+    use ferriskey_domain::realm::ports::{MockRealmRepository, MockSmtpConfigRepository};
+    use ferriskey_domain::user::ports::{MockUserRepository, MockUserRoleRepository};
+
+    type TestService<ET> = EmailOutboxServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockSmtpConfigRepository,
+        MockEmailOutboxRepository,
+        ET,
+    >;
+
+    fn smtp_config(realm_id: Uuid, max_emails_per_minute: Option<u32>) -> SmtpConfig {
+        SmtpConfig {
+            id: Uuid::new_v4(),
+            realm_id,
+            host: "smtp.example.com".to_string(),
+            port: 587,
+            username: "smtp_user".to_string(),
+            password: "smtp_pass".to_string(),
+            from_email: "noreply@example.com".to_string(),
+            from_name: "Acme".to_string(),
+            encryption: SmtpEncryption::StartTls,
+            max_emails_per_minute,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn outbox_email(realm_id: Uuid, recipient: &str) -> OutboxEmail {
+        OutboxEmail::new(
+            realm_id,
+            recipient.to_string(),
+            "Reset your password".to_string(),
+            "Follow the link".to_string(),
+            None,
+        )
+    }
+
+    type Postponed = Arc<Mutex<Vec<(Uuid, DateTime<Utc>)>>>;
+
+    /// An outbox repository serving `due` and recording every saved attempt.
+    fn outbox_repository(
+        due: Vec<OutboxEmail>,
+        sent_last_minute: u64,
+        saved: Arc<Mutex<Vec<OutboxEmail>>>,
+    ) -> MockEmailOutboxRepository {
+        outbox_repository_with_postponed(due, sent_last_minute, saved, Arc::default())
+    }
+
+    /// Like [`outbox_repository`], also recording every postponed email with its new
+    /// attempt time.
+    fn outbox_repository_with_postponed(
+        due: Vec<OutboxEmail>,
+        sent_last_minute: u64,
+        saved: Arc<Mutex<Vec<OutboxEmail>>>,
+        postponed: Postponed,
+    ) -> MockEmailOutboxRepository {
+        let mut repository = MockEmailOutboxRepository::new();
+        repository
+            .expect_list_due()
+            .returning(move |_, _| Box::pin(std::future::ready(Ok(due.clone()))));
+        repository
+            .expect_count_sent_since()
+            .returning(move |_, _| Box::pin(std::future::ready(Ok(sent_last_minute))));
+        repository
+            .expect_claim()
+            .returning(|_, _| Box::pin(std::future::ready(Ok(true))));
+        repository.expect_postpone().returning(move |email, until| {
+            postponed.lock().unwrap().push((email.id, until));
+            Box::pin(std::future::ready(Ok(true)))
+        });
+        repository.expect_save_attempt().returning(move |email| {
+            saved.lock().unwrap().push(email.clone());
+            Box::pin(std::future::ready(Ok(())))
+        });
+        repository
+    }
+
+    fn service<ET: EmailTransport>(
+        config: SmtpConfig,
+        outbox: MockEmailOutboxRepository,
+        transport: ET,
+    ) -> TestService<ET> {
+        realms_service(vec![config], outbox, transport)
+    }
+
+    /// A service where each realm of `configs` has its own SMTP configuration.
+    fn realms_service<ET: EmailTransport>(
+        configs: Vec<SmtpConfig>,
+        outbox: MockEmailOutboxRepository,
+        transport: ET,
+    ) -> TestService<ET> {
+        let mut smtp_config_repository = MockSmtpConfigRepository::new();
+        smtp_config_repository
+            .expect_get_by_realm_id()
+            .returning(move |realm_id| {
+                let config = configs
+                    .iter()
+                    .find(|config| RealmId::new(config.realm_id) == realm_id)
+                    .cloned();
+                Box::pin(std::future::ready(Ok(config)))
+            });
+
+        EmailOutboxServiceImpl::new(
+            Arc::new(MockRealmRepository::new()),
+            Arc::new(smtp_config_repository),
+            Arc::new(outbox),
+            Arc::new(transport),
+            Arc::new(FerriskeyPolicy::new(
+                Arc::new(MockUserRepository::new()),
+                Arc::new(MockClientRepository::new()),
+                Arc::new(MockUserRoleRepository::new()),
+            )),
+        )
+    }
+
+    #[tokio::test]
+    async fn delivers_due_emails_from_the_realm_sender() {
+        let realm_id = Uuid::new_v4();
+        let saved = Arc::new(Mutex::new(Vec::new()));
+        let sink = InMemoryEmailSender::new();
+        let service = service(
+            smtp_config(realm_id, None),
+            outbox_repository(
+                vec![outbox_email(realm_id, "alice@example.com")],
+                0,
+                saved.clone(),
+            ),
+            sink.clone(),
+        );
+
+        assert_eq!(service.deliver_due_emails().await.unwrap(), 1);
+
+        let messages = sink.messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].from.as_mailbox(), "Acme <noreply@example.com>");
+        assert_eq!(messages[0].to[0].as_str(), "alice@example.com");
+
+        let saved = saved.lock().unwrap();
+        assert_eq!(saved[0].status, OutboxEmailStatus::Sent);
+        assert_eq!(saved[0].attempts, 1);
+        assert!(saved[0].sent_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn holds_emails_back_once_the_realm_rate_is_reached() {
+        let realm_id = Uuid::new_v4();
+        let saved = Arc::new(Mutex::new(Vec::new()));
+        let sink = InMemoryEmailSender::new();
+        let due = (0..3)
+            .map(|_| outbox_email(realm_id, "alice@example.com"))
+            .collect();
+        let service = service(
+            smtp_config(realm_id, Some(2)),
+            outbox_repository(due, 1, saved.clone()),
+            sink.clone(),
+        );
+
+        assert_eq!(service.deliver_due_emails().await.unwrap(), 1);
+        assert_eq!(sink.messages().len(), 1);
+        assert_eq!(saved.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn postpones_emails_of_a_realm_over_its_rate_so_other_realms_are_served() {
+        let busy_realm = Uuid::new_v4();
+        let quiet_realm = Uuid::new_v4();
+        let saved = Arc::new(Mutex::new(Vec::new()));
+        let postponed = Arc::new(Mutex::new(Vec::new()));
+        let sink = InMemoryEmailSender::new();
+        let busy: Vec<OutboxEmail> = (0..5)
+            .map(|_| outbox_email(busy_realm, "alice@example.com"))
+            .collect();
+        let mut due = busy.clone();
+        due.push(outbox_email(quiet_realm, "bob@example.com"));
+        let service = realms_service(
+            vec![
+                smtp_config(busy_realm, Some(1)),
+                smtp_config(quiet_realm, None),
+            ],
+            outbox_repository_with_postponed(due, 1, saved.clone(), postponed.clone()),
+            sink.clone(),
+        );
+
+        let before = Utc::now();
+        assert_eq!(service.deliver_due_emails().await.unwrap(), 1);
+
+        let messages = sink.messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].to[0].as_str(), "bob@example.com");
+
+        let postponed = postponed.lock().unwrap();
+        assert_eq!(
+            postponed.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            busy.iter().map(|email| email.id).collect::<Vec<_>>()
+        );
+        assert!(
+            postponed
+                .iter()
+                .all(|(_, until)| *until >= before + RATE_WINDOW)
+        );
+    }
+
+    #[tokio::test]
+    async fn retries_transport_failures_and_abandons_malformed_emails() {
+        let realm_id = Uuid::new_v4();
+        let saved = Arc::new(Mutex::new(Vec::new()));
+        let mut transport = MockEmailTransport::new();
+        transport.expect_deliver().returning(|_, _| {
+            Box::pin(std::future::ready(Err(EmailError::Transport(
+                "connection refused".to_string(),
+            ))))
+        });
+        let service = service(
+            smtp_config(realm_id, None),
+            outbox_repository(
+                vec![
+                    outbox_email(realm_id, "alice@example.com"),
+                    outbox_email(realm_id, "not-an-address"),
+                ],
+                0,
+                saved.clone(),
+            ),
+            transport,
+        );
+
+        assert_eq!(service.deliver_due_emails().await.unwrap(), 2);
+
+        let saved = saved.lock().unwrap();
+        assert_eq!(saved[0].status, OutboxEmailStatus::Pending);
+        assert_eq!(
+            saved[0].last_error.as_deref(),
+            Some("Failed to send email: connection refused")
+        );
+        assert!(saved[0].next_attempt_at > Utc::now());
+        assert_eq!(saved[1].status, OutboxEmailStatus::Failed);
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+pub use ferriskey_domain::common::security_notification::{
+    SecurityNotice, SecurityNotificationKind, SecurityNotifier,
+};
+
+/// How a realm sends one kind of security notification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SecurityNotificationSetting {
+    pub realm_id: Uuid,
+    pub kind: SecurityNotificationKind,
+    pub enabled: bool,
+    /// Email template of the matching type; the built-in MJML is used without one.
+    pub template_id: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SecurityNotificationSetting {
+    /// Notifications are on until a realm turns them off: users should hear about changes
+    /// to their account without an administrator opting in first.
+    pub fn default_for(realm_id: Uuid, kind: SecurityNotificationKind) -> Self {
+        Self {
+            realm_id,
+            kind,
+            enabled: true,
+            template_id: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::future::Future;
+
+use uuid::Uuid;
+
+use crate::security_notification::entities::{
+    SecurityNotificationKind, SecurityNotificationSetting,
+};
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::Realm;
+
+pub trait SecurityNotificationService: Send + Sync {
+    /// Every kind of notification, with the defaults filled in for those never configured.
+    fn get_security_notifications(
+        &self,
+        identity: Identity,
+        input: GetSecurityNotificationsInput,
+    ) -> impl Future<Output = Result<Vec<SecurityNotificationSetting>, CoreError>> + Send;
+
+    fn update_security_notification(
+        &self,
+        identity: Identity,
+        input: UpdateSecurityNotificationInput,
+    ) -> impl Future<Output = Result<SecurityNotificationSetting, CoreError>> + Send;
+}
+
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait SecurityNotificationRepository: Send + Sync {
+    fn list_by_realm(
+        &self,
+        realm_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<SecurityNotificationSetting>, CoreError>> + Send;
+
+    fn get(
+        &self,
+        realm_id: Uuid,
+        kind: SecurityNotificationKind,
+    ) -> impl Future<Output = Result<Option<SecurityNotificationSetting>, CoreError>> + Send;
+
+    fn upsert(
+        &self,
+        setting: &SecurityNotificationSetting,
+    ) -> impl Future<Output = Result<SecurityNotificationSetting, CoreError>> + Send;
+}
+
+pub trait SecurityNotificationPolicy: Send + Sync {
+    fn can_view_security_notifications(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_manage_security_notifications(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+pub struct GetSecurityNotificationsInput {
+    pub realm_name: String,
+}
+
+pub struct UpdateSecurityNotificationInput {
+    pub realm_name: String,
+    pub kind: SecurityNotificationKind,
+    pub enabled: bool,
+    pub template_id: Option<Uuid>,
+}
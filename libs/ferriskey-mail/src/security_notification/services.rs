@@ -0,0 +1,631 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::time::timeout;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::email_template::entities::{EmailType, interpolate_variables};
+use crate::email_template::ports::{EmailTemplateRepository, TemplateRenderer};
+use crate::security_notification::entities::{
+    SecurityNotice, SecurityNotificationKind, SecurityNotificationSetting, SecurityNotifier,
+};
+use crate::security_notification::ports::{
+    GetSecurityNotificationsInput, SecurityNotificationPolicy, SecurityNotificationRepository,
+    SecurityNotificationService, UpdateSecurityNotificationInput,
+};
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::client::ports::ClientRepository;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::common::email::EmailPort;
+use ferriskey_domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use ferriskey_domain::realm::Realm;
+use ferriskey_domain::realm::ports::{RealmRepository, SmtpConfigRepository};
+use ferriskey_domain::user::entities::User;
+use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
+use ferriskey_localization::{EmailLocalizer, LocalizedMessages};
+use ferriskey_seawatch::{EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType};
+
+/// Notifications are sent from inside the request that changed the account, so a slow
+/// database only delays that request this long.
+#[cfg(not(test))]
+const NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(5);
+#[cfg(test)]
+const NOTIFICATION_TIMEOUT: Duration = Duration::from_millis(100);
+
+#[derive(Clone, Debug)]
+pub struct SecurityNotificationServiceImpl<R, U, C, UR, SN, ET>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SN: SecurityNotificationRepository,
+    ET: EmailTemplateRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) security_notification_repository: Arc<SN>,
+    pub(crate) email_template_repository: Arc<ET>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, SN, ET> SecurityNotificationServiceImpl<R, U, C, UR, SN, ET>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SN: SecurityNotificationRepository,
+    ET: EmailTemplateRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        security_notification_repository: Arc<SN>,
+        email_template_repository: Arc<ET>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            security_notification_repository,
+            email_template_repository,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+}
+
+impl<R, U, C, UR, SN, ET> SecurityNotificationService
+    for SecurityNotificationServiceImpl<R, U, C, UR, SN, ET>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SN: SecurityNotificationRepository,
+    ET: EmailTemplateRepository,
+{
+    async fn get_security_notifications(
+        &self,
+        identity: Identity,
+        input: GetSecurityNotificationsInput,
+    ) -> Result<Vec<SecurityNotificationSetting>, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_view_security_notifications(&identity, &realm)
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let stored = self
+            .security_notification_repository
+            .list_by_realm(realm.id.into())
+            .await?;
+
+        Ok(SecurityNotificationKind::ALL
+            .into_iter()
+            .map(|kind| {
+                stored
+                    .iter()
+                    .find(|setting| setting.kind == kind)
+                    .cloned()
+                    .unwrap_or_else(|| {
+                        SecurityNotificationSetting::default_for(realm.id.into(), kind)
+                    })
+            })
+            .collect())
+    }
+
+    async fn update_security_notification(
+        &self,
+        identity: Identity,
+        input: UpdateSecurityNotificationInput,
+    ) -> Result<SecurityNotificationSetting, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_security_notifications(&identity, &realm)
+                .await,
+            "insufficient permissions",
+        )?;
+
+        if let Some(template_id) = input.template_id {
+            let template = self
+                .email_template_repository
+                .get_by_id(realm.id.into(), template_id)
+                .await?
+                .ok_or(CoreError::EmailTemplateNotFound)?;
+
+            if template.email_type != EmailType::from(input.kind) {
+                return Err(CoreError::EmailTemplateTypeMismatch(input.kind.to_string()));
+            }
+        }
+
+        self.security_notification_repository
+            .upsert(&SecurityNotificationSetting {
+                realm_id: realm.id.into(),
+                kind: input.kind,
+                enabled: input.enabled,
+                template_id: input.template_id,
+                updated_at: Utc::now(),
+            })
+            .await
+    }
+}
+
+/// Sends security notifications by email, in the language of the user, through the same
+/// outbox as every other email.
+#[derive(Clone, Debug)]
+pub struct EmailSecurityNotifier<R, U, SC, SN, ET, TR, E, SE, EL>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    SC: SmtpConfigRepository,
+    SN: SecurityNotificationRepository,
+    ET: EmailTemplateRepository,
+    TR: TemplateRenderer,
+    E: EmailPort,
+    SE: SecurityEventRepository,
+    EL: EmailLocalizer,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) user_repository: Arc<U>,
+    pub(crate) smtp_config_repository: Arc<SC>,
+    pub(crate) security_notification_repository: Arc<SN>,
+    pub(crate) email_template_repository: Arc<ET>,
+    pub(crate) template_renderer: Arc<TR>,
+    pub(crate) email_port: Arc<E>,
+    pub(crate) security_event_repository: Arc<SE>,
+    pub(crate) email_localizer: Arc<EL>,
+}
+
+impl<R, U, SC, SN, ET, TR, E, SE, EL> EmailSecurityNotifier<R, U, SC, SN, ET, TR, E, SE, EL>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    SC: SmtpConfigRepository,
+    SN: SecurityNotificationRepository,
+    ET: EmailTemplateRepository,
+    TR: TemplateRenderer,
+    E: EmailPort,
+    SE: SecurityEventRepository,
+    EL: EmailLocalizer,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        realm_repository: Arc<R>,
+        user_repository: Arc<U>,
+        smtp_config_repository: Arc<SC>,
+        security_notification_repository: Arc<SN>,
+        email_template_repository: Arc<ET>,
+        template_renderer: Arc<TR>,
+        email_port: Arc<E>,
+        security_event_repository: Arc<SE>,
+        email_localizer: Arc<EL>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            user_repository,
+            smtp_config_repository,
+            security_notification_repository,
+            email_template_repository,
+            template_renderer,
+            email_port,
+            security_event_repository,
+            email_localizer,
+        }
+    }
+
+    async fn send(&self, notice: SecurityNotice) -> Result<(), CoreError> {
+        let realm_id: Uuid = notice.realm_id.into();
+        let setting = self
+            .security_notification_repository
+            .get(realm_id, notice.kind)
+            .await?
+            .unwrap_or_else(|| SecurityNotificationSetting::default_for(realm_id, notice.kind));
+        if !setting.enabled {
+            return Ok(());
+        }
+
+        let user = self.user_repository.get_by_id(notice.user_id).await?;
+        let Some(recipient) = notice.recipient.clone().or_else(|| user.email.clone()) else {
+            debug!(user_id = %user.id, kind = %notice.kind, "No address to notify");
+            return Ok(());
+        };
+        let Some(smtp_config) = self
+            .smtp_config_repository
+            .get_by_realm_id(notice.realm_id)
+            .await?
+        else {
+            debug!(kind = %notice.kind, "SMTP not configured, security notification skipped");
+            return Ok(());
+        };
+        let realm = self
+            .realm_repository
+            .get_by_id(notice.realm_id)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let messages = self
+            .email_localizer
+            .email_messages(notice.realm_id, Some(user.id))
+            .await;
+        let occurred_at = Utc::now().format("%Y-%m-%d %H:%M UTC").to_string();
+        let mut variables = vec![
+            ("realm.name".to_string(), realm.name.clone()),
+            ("occurred_at".to_string(), occurred_at),
+        ];
+        variables.extend(notice.details.iter().cloned());
+
+        let pairs: Vec<(&str, &str)> = variables
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        let email_type = notice.kind.as_str();
+        let subject = messages.email_subject(email_type, &pairs);
+        let body = messages.email_body(email_type, &pairs);
+
+        let html_body = self
+            .render_html(&setting, &user, &messages, &variables, &subject, &body)
+            .await;
+
+        let result = self
+            .email_port
+            .send_email(&smtp_config, &recipient, &subject, &body, html_body)
+            .await;
+
+        let event = match &result {
+            Ok(()) => SecurityEvent::new(
+                notice.realm_id,
+                SecurityEventType::EmailSent,
+                EventStatus::Success,
+                user.id,
+            )
+            .with_details(serde_json::json!({
+                "email_type": email_type,
+                "template_id": setting.template_id.map(|id| id.to_string()),
+                "user_id": user.id.to_string(),
+            })),
+            Err(e) => SecurityEvent::new(
+                notice.realm_id,
+                SecurityEventType::EmailNotSent,
+                EventStatus::Failure,
+                user.id,
+            )
+            .with_details(serde_json::json!({
+                "reason": format!("Failed to send {email_type} notification: {e}"),
+                "email_type": email_type,
+                "error_code": "SMTP_SEND_FAILED",
+                "user_id": user.id.to_string(),
+            })),
+        };
+        let _ = self
+            .security_event_repository
+            .store_event(event)
+            .await
+            .inspect_err(|e| warn!("Failed to log security notification event: {}", e));
+
+        result
+    }
+
+    /// The HTML part: the realm template when one is set, the built-in MJML otherwise.
+    /// Rendering problems only cost the HTML part, the plain-text body still goes out.
+    async fn render_html(
+        &self,
+        setting: &SecurityNotificationSetting,
+        user: &User,
+        messages: &LocalizedMessages,
+        variables: &[(String, String)],
+        subject: &str,
+        body: &str,
+    ) -> Option<String> {
+        let email_type = EmailType::from(setting.kind);
+        let template = match setting.template_id {
+            Some(template_id) => self
+                .email_template_repository
+                .get_by_id(setting.realm_id, template_id)
+                .await
+                .inspect_err(|e| warn!("Failed to load security notification template: {}", e))
+                .ok()
+                .flatten()
+                .filter(|template| template.email_type == email_type)
+                .map(|template| template.mjml),
+            None => None,
+        };
+        let mjml = template.or_else(|| email_type.default_mjml())?;
+
+        let html = self
+            .template_renderer
+            .render_to_html(&mjml)
+            .inspect_err(|e| warn!("Failed to render {} notification: {}", email_type, e))
+            .ok()?;
+
+        let mut all_variables: HashMap<String, String> = messages.template_variables();
+        all_variables.extend([
+            (
+                "user.first_name".to_string(),
+                user.firstname.clone().unwrap_or_default(),
+            ),
+            (
+                "user.last_name".to_string(),
+                user.lastname.clone().unwrap_or_default(),
+            ),
+            (
+                "user.email".to_string(),
+                user.email.clone().unwrap_or_default(),
+            ),
+            ("notification.subject".to_string(), subject.to_string()),
+            ("notification.body".to_string(), body.to_string()),
+        ]);
+        all_variables.extend(variables.iter().cloned());
+
+        Some(interpolate_variables(&html, &all_variables))
+    }
+}
+
+impl<R, U, SC, SN, ET, TR, E, SE, EL> SecurityNotifier
+    for EmailSecurityNotifier<R, U, SC, SN, ET, TR, E, SE, EL>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    SC: SmtpConfigRepository,
+    SN: SecurityNotificationRepository,
+    ET: EmailTemplateRepository,
+    TR: TemplateRenderer,
+    E: EmailPort,
+    SE: SecurityEventRepository,
+    EL: EmailLocalizer,
+{
+    async fn notify(&self, notice: SecurityNotice) {
+        let user_id = notice.user_id;
+        let kind = notice.kind;
+
+        match timeout(NOTIFICATION_TIMEOUT, self.send(notice)).await {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => {
+                warn!(user_id = %user_id, kind = %kind, error = ?error, "Failed to send security notification")
+            }
+            Err(_) => warn!(user_id = %user_id, kind = %kind, "Security notification timed out"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::email_template::ports::MockEmailTemplateRepository;
+    use crate::security_notification::ports::MockSecurityNotificationRepository;
+    use ferriskey_domain::common::email::MockEmailPort;
+    use ferriskey_domain::realm::ports::{MockRealmRepository, MockSmtpConfigRepository};
+    use ferriskey_domain::realm::{RealmId, RealmSetting, SmtpConfig, SmtpEncryption};
+    use ferriskey_domain::user::entities::UserConfig;
+    use ferriskey_domain::user::ports::MockUserRepository;
+    use ferriskey_localization::{Locale, MockEmailLocalizer};
+    use ferriskey_seawatch::ports::MockSecurityEventRepository;
+
+    /// Treats the MJML as HTML, so tests can read what the template interpolated.
+    struct PassthroughRenderer;
+
+    impl TemplateRenderer for PassthroughRenderer {
+        fn render_to_intermediate(
+            &self,
+            _structure: &serde_json::Value,
+        ) -> Result<String, CoreError> {
+            Ok(String::new())
+        }
+
+        fn render_to_html(&self, intermediate: &str) -> Result<String, CoreError> {
+            Ok(intermediate.to_string())
+        }
+    }
+
+    type TestNotifier = EmailSecurityNotifier<
+        MockRealmRepository,
+        MockUserRepository,
+        MockSmtpConfigRepository,
+        MockSecurityNotificationRepository,
+        MockEmailTemplateRepository,
+        PassthroughRenderer,
+        MockEmailPort,
+        MockSecurityEventRepository,
+        MockEmailLocalizer,
+    >;
+
+    /// One email handed to the email port: recipient, subject, body and HTML part.
+    type SentEmail = (String, String, String, Option<String>);
+
+    fn test_realm() -> Realm {
+        let id = RealmId::new(Uuid::new_v4());
+        Realm {
+            id,
+            name: "acme".to_string(),
+            display_name: None,
+            settings: Some(RealmSetting::new(id, Some("RS256".to_string()))),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn test_user(realm: &Realm) -> User {
+        User::new(UserConfig {
+            realm_id: realm.id,
+            client_id: None,
+            username: "alice".to_string(),
+            firstname: Some("Alice".to_string()),
+            lastname: None,
+            email: Some("alice@example.com".to_string()),
+            email_verified: true,
+            enabled: true,
+        })
+    }
+
+    fn smtp_config(realm: &Realm) -> SmtpConfig {
+        SmtpConfig {
+            id: Uuid::new_v4(),
+            realm_id: realm.id.into(),
+            host: "smtp.example.com".to_string(),
+            port: 587,
+            username: "smtp_user".to_string(),
+            password: "smtp_pass".to_string(),
+            from_email: "noreply@example.com".to_string(),
+            from_name: "Acme".to_string(),
+            encryption: SmtpEncryption::StartTls,
+            max_emails_per_minute: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// A notifier for `realm` and `user`, where `setting` is what the realm stored and every
+    /// email is recorded into `sent`.
+    fn build_notifier(
+        realm: &Realm,
+        user: &User,
+        setting: Option<SecurityNotificationSetting>,
+        sent: Arc<Mutex<Vec<SentEmail>>>,
+    ) -> TestNotifier {
+        let mut realm_repository = MockRealmRepository::new();
+        let stored_realm = realm.clone();
+        realm_repository
+            .expect_get_by_id()
+            .returning(move |_| Box::pin(std::future::ready(Ok(Some(stored_realm.clone())))));
+
+        let mut user_repository = MockUserRepository::new();
+        let stored_user = user.clone();
+        user_repository
+            .expect_get_by_id()
+            .returning(move |_| Box::pin(std::future::ready(Ok(stored_user.clone()))));
+
+        let mut smtp_config_repository = MockSmtpConfigRepository::new();
+        let config = smtp_config(realm);
+        smtp_config_repository
+            .expect_get_by_realm_id()
+            .returning(move |_| Box::pin(std::future::ready(Ok(Some(config.clone())))));
+
+        let mut security_notification_repository = MockSecurityNotificationRepository::new();
+        security_notification_repository
+            .expect_get()
+            .returning(move |_, _| Box::pin(std::future::ready(Ok(setting.clone()))));
+
+        let mut email_port = MockEmailPort::new();
+        email_port
+            .expect_send_email()
+            .returning(move |_, to, subject, body, html| {
+                sent.lock().unwrap().push((
+                    to.to_string(),
+                    subject.to_string(),
+                    body.to_string(),
+                    html,
+                ));
+                Box::pin(std::future::ready(Ok(())))
+            });
+
+        let mut security_event_repository = MockSecurityEventRepository::new();
+        security_event_repository
+            .expect_store_event()
+            .returning(|_| Box::pin(std::future::ready(Ok(()))));
+
+        let mut email_localizer = MockEmailLocalizer::new();
+        email_localizer
+            .expect_email_messages()
+            .returning(|_, _| Box::pin(async { LocalizedMessages::builtin(Locale::default()) }));
+
+        EmailSecurityNotifier::new(
+            Arc::new(realm_repository),
+            Arc::new(user_repository),
+            Arc::new(smtp_config_repository),
+            Arc::new(security_notification_repository),
+            Arc::new(MockEmailTemplateRepository::new()),
+            Arc::new(PassthroughRenderer),
+            Arc::new(email_port),
+            Arc::new(security_event_repository),
+            Arc::new(email_localizer),
+        )
+    }
+
+    #[tokio::test]
+    async fn sends_the_default_template_when_the_realm_configured_nothing() {
+        let realm = test_realm();
+        let user = test_user(&realm);
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let notifier = build_notifier(&realm, &user, None, sent.clone());
+
+        notifier
+            .notify(
+                SecurityNotice::new(realm.id, user.id, SecurityNotificationKind::NewSignIn)
+                    .with_detail("ip_address", "203.0.113.7")
+                    .with_detail("device", "Firefox")
+                    .with_detail("location", "FR"),
+            )
+            .await;
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        let (to, subject, body, html) = &sent[0];
+        assert_eq!(to, "alice@example.com");
+        assert_eq!(subject, "New sign-in to your acme account");
+        assert!(body.contains("IP address: 203.0.113.7"));
+        let html = html.as_deref().unwrap();
+        assert!(html.contains("IP address: 203.0.113.7"));
+        assert!(!html.contains("{{"));
+    }
+
+    #[tokio::test]
+    async fn disabled_notifications_are_not_sent() {
+        let realm = test_realm();
+        let user = test_user(&realm);
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let setting = SecurityNotificationSetting {
+            enabled: false,
+            ..SecurityNotificationSetting::default_for(
+                realm.id.into(),
+                SecurityNotificationKind::PasswordChanged,
+            )
+        };
+        let notifier = build_notifier(&realm, &user, Some(setting), sent.clone());
+
+        notifier
+            .notify(SecurityNotice::new(
+                realm.id,
+                user.id,
+                SecurityNotificationKind::PasswordChanged,
+            ))
+            .await;
+
+        assert!(sent.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn email_change_is_sent_to_the_previous_address() {
+        let realm = test_realm();
+        let user = test_user(&realm);
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let notifier = build_notifier(&realm, &user, None, sent.clone());
+
+        notifier
+            .notify(
+                SecurityNotice::new(realm.id, user.id, SecurityNotificationKind::EmailChanged)
+                    .with_recipient("old@example.com")
+                    .with_detail("new_email", "alice@example.com"),
+            )
+            .await;
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].0, "old@example.com");
+        assert!(sent[0].2.contains("changed to alice@example.com"));
+    }
+}
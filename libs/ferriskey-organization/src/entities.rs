@@ -65,6 +65,8 @@ pub enum OrganizationValidationError {
     EmptyAttributeValue,
     #[error("organization members must belong to the same realm")]
     CrossRealmMembership,
+    #[error("invitation email must be a valid email address")]
+    InvalidInvitationEmail,
 }
 
 /// Organization domain entity
@@ -592,6 +594,342 @@ pub struct DeleteGroupAttributeInput {
     pub key: String,
 }
 
+// ============================================================================
+// Invitations
+// ============================================================================
+
+/// Where an invitation stands. `Expired` is never stored: a pending invitation past its
+/// `expires_at` reads as expired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitationStatus {
+    Pending,
+    Accepted,
+    Revoked,
+    Expired,
+}
+
+impl InvitationStatus {
+    pub fn of(
+        accepted_at: Option<DateTime<Utc>>,
+        revoked_at: Option<DateTime<Utc>>,
+        expires_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        if accepted_at.is_some() {
+            Self::Accepted
+        } else if revoked_at.is_some() {
+            Self::Revoked
+        } else if expires_at <= now {
+            Self::Expired
+        } else {
+            Self::Pending
+        }
+    }
+}
+
+/// An emailed invitation to join an organization, optionally with a member role and a
+/// group granted on acceptance. Only the hash of the invitation token is stored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationInvitation {
+    pub id: Uuid,
+    pub organization_id: OrganizationId,
+    pub email: String,
+    pub role_id: Option<Uuid>,
+    pub group_id: Option<GroupId>,
+    pub invited_by: Option<Uuid>,
+    pub status: InvitationStatus,
+    pub expires_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub accepted_by: Option<Uuid>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OrganizationInvitation {
+    pub fn is_pending(&self) -> bool {
+        self.status == InvitationStatus::Pending
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateInvitationParams {
+    pub organization_id: OrganizationId,
+    pub email: String,
+    pub role_id: Option<Uuid>,
+    pub group_id: Option<GroupId>,
+    pub invited_by: Option<Uuid>,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// What the invitee sees before accepting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct InvitationPreview {
+    pub organization_name: String,
+    pub organization_alias: String,
+    pub email: String,
+    pub expires_at: DateTime<Utc>,
+    /// Whether accepting links an existing account instead of creating one.
+    pub account_exists: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AcceptedInvitation {
+    pub organization_id: OrganizationId,
+    pub user_id: Uuid,
+    /// `true` when accepting created the account.
+    pub account_created: bool,
+}
+
+/// Normalizes an invitee address: trimmed, lowercased, one `@` with a non-empty local part
+/// and a dotted domain.
+pub fn normalize_invitation_email(email: &str) -> Result<String, OrganizationValidationError> {
+    let email = email.trim().to_lowercase();
+    let Some((local, domain)) = email.split_once('@') else {
+        return Err(OrganizationValidationError::InvalidInvitationEmail);
+    };
+
+    let valid = !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !email.chars().any(char::is_whitespace)
+        && email.chars().count() <= 255;
+
+    if valid {
+        Ok(email)
+    } else {
+        Err(OrganizationValidationError::InvalidInvitationEmail)
+    }
+}
+
+// --- Invitation input structs ---
+
+pub struct CreateInvitationInput {
+    pub realm_name: String,
+    pub organization_id: OrganizationId,
+    pub email: String,
+    pub role_id: Option<Uuid>,
+    pub group_id: Option<GroupId>,
+    /// Defaults to seven days.
+    pub expires_in_hours: Option<i64>,
+    /// Origin of the login pages the invitation link points to.
+    pub base_url: String,
+}
+
+pub struct ListInvitationsInput {
+    pub realm_name: String,
+    pub organization_id: OrganizationId,
+}
+
+pub struct RevokeInvitationInput {
+    pub realm_name: String,
+    pub organization_id: OrganizationId,
+    pub invitation_id: Uuid,
+}
+
+pub struct GetInvitationInput {
+    pub realm_name: String,
+    pub token: String,
+}
+
+/// Accepting links the invitee's existing account; `username` and `password` are only
+/// needed when no account uses the invited email.
+pub struct AcceptInvitationInput {
+    pub realm_name: String,
+    pub token: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+}
+
+// ============================================================================
+// Domain verification and home-realm discovery
+// ============================================================================
+
+/// DNS label under the organization domain that holds the verification TXT record.
+pub const DOMAIN_VERIFICATION_LABEL: &str = "_ferriskey-challenge";
+
+/// Prefix of the verification TXT record value.
+pub const DOMAIN_VERIFICATION_PREFIX: &str = "ferriskey-domain-verification=";
+
+/// The organization's email domain, the proof it controls it and the identity provider its
+/// users are routed to at login. Routing only applies once `verified_at` is set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationDomain {
+    pub organization_id: OrganizationId,
+    pub realm_id: RealmId,
+    pub domain: String,
+    pub verification_token: String,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub identity_provider_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OrganizationDomain {
+    /// Name of the TXT record the organization publishes.
+    pub fn verification_record_name(&self) -> String {
+        format!("{DOMAIN_VERIFICATION_LABEL}.{}", self.domain)
+    }
+
+    /// Value the TXT record must hold.
+    pub fn verification_record_value(&self) -> String {
+        format!("{DOMAIN_VERIFICATION_PREFIX}{}", self.verification_token)
+    }
+
+    /// Whether one of the published TXT strings proves control of the domain.
+    pub fn is_proven_by(&self, txt_records: &[String]) -> bool {
+        let expected = self.verification_record_value();
+        txt_records.iter().any(|record| record.trim() == expected)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveOrganizationDomainParams {
+    pub organization_id: OrganizationId,
+    pub realm_id: RealmId,
+    pub domain: String,
+    pub verification_token: String,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub identity_provider_id: Option<Uuid>,
+}
+
+/// Admin view of the organization domain: the record to publish and the linked provider.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationDomainStatus {
+    pub domain: String,
+    pub verified: bool,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub verification_record_name: String,
+    pub verification_record_value: String,
+    pub identity_provider_alias: Option<String>,
+}
+
+/// Where the login page sends a user whose email domain belongs to an organization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct HomeRealmDiscovery {
+    pub organization_alias: String,
+    pub identity_provider_alias: String,
+}
+
+/// The part after `@`, lowercased, or `None` for anything that is not an address.
+pub fn email_domain(email: &str) -> Option<String> {
+    let (_, domain) = email.trim().rsplit_once('@')?;
+    let domain = domain.trim_end_matches('.').to_lowercase();
+
+    (!domain.is_empty()).then_some(domain)
+}
+
+pub struct GetOrganizationDomainInput {
+    pub realm_name: String,
+    pub organization_id: OrganizationId,
+}
+
+/// Starts (or restarts, when the organization domain changed) verification of the
+/// organization domain and links the identity provider its users sign in with.
+pub struct ConfigureOrganizationDomainInput {
+    pub realm_name: String,
+    pub organization_id: OrganizationId,
+    pub identity_provider_alias: Option<String>,
+}
+
+pub struct VerifyOrganizationDomainInput {
+    pub realm_name: String,
+    pub organization_id: OrganizationId,
+}
+
+pub struct DiscoverHomeRealmInput {
+    pub realm_name: String,
+    pub email: String,
+}
+
+// ============================================================================
+// Delegated administration
+// ============================================================================
+
+/// Organization-scoped administrative roles. Each one is backed by an ordinary realm role
+/// with a reserved name and is granted through a member role assignment, so holding it only
+/// confers authority over the organization that membership belongs to.
+///
+/// Variants are declared from least to most privileged: the derived ordering is the rank.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationAdminRole {
+    MemberManager,
+    Admin,
+    Owner,
+}
+
+/// What an organization-scoped administrator may do inside their own organization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrganizationCapability {
+    /// Read the organization, its members, groups, attributes and invitations.
+    View,
+    /// Update the organization profile and its attributes.
+    Update,
+    /// Add and remove members, send and revoke invitations, manage group membership.
+    ManageMembers,
+    /// Create, update and delete groups along with their roles and attributes.
+    ManageGroups,
+}
+
+impl OrganizationAdminRole {
+    pub const ALL: [Self; 3] = [Self::Owner, Self::Admin, Self::MemberManager];
+
+    /// Name of the realm role backing this admin role.
+    pub fn role_name(&self) -> &'static str {
+        match self {
+            Self::Owner => "org-owner",
+            Self::Admin => "org-admin",
+            Self::MemberManager => "org-member-manager",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Owner => "Full control over an organization, including its administrators",
+            Self::Admin => "Manage an organization, its members, groups and invitations",
+            Self::MemberManager => "Manage the members and invitations of an organization",
+        }
+    }
+
+    pub fn from_role_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|role| role.role_name() == name)
+    }
+
+    pub fn allows(&self, capability: OrganizationCapability) -> bool {
+        match capability {
+            OrganizationCapability::View | OrganizationCapability::ManageMembers => true,
+            OrganizationCapability::Update | OrganizationCapability::ManageGroups => {
+                *self >= Self::Admin
+            }
+        }
+    }
+
+    /// Whether this role may grant or revoke a member role backed by `target` (`None` for an
+    /// ordinary role). Member managers never assign roles; admins and owners may hand out
+    /// admin roles up to their own rank.
+    pub fn can_grant(&self, target: Option<OrganizationAdminRole>) -> bool {
+        match (self, target) {
+            (Self::MemberManager, _) => false,
+            (_, None) => true,
+            (role, Some(target)) => target <= *role,
+        }
+    }
+
+    /// Whether this role may act on a member holding `target`: a delegated admin never
+    /// removes someone who outranks them.
+    pub fn outranks_or_equals(&self, target: Option<OrganizationAdminRole>) -> bool {
+        target.is_none_or(|target| target <= *self)
+    }
+}
+
 fn validate_required_name(name: String) -> Result<String, OrganizationValidationError> {
     let trimmed = name.trim();
     if trimmed.is_empty() {
@@ -953,4 +1291,122 @@ mod tests {
             Err(OrganizationValidationError::CrossRealmMembership)
         );
     }
+
+    // --- Invitations ---
+
+    #[test]
+    fn invitation_status_prefers_terminal_states_over_expiry() {
+        let now = chrono::Utc::now();
+        let past = now - chrono::Duration::hours(1);
+        let future = now + chrono::Duration::hours(1);
+
+        assert_eq!(
+            InvitationStatus::of(None, None, future, now),
+            InvitationStatus::Pending
+        );
+        assert_eq!(
+            InvitationStatus::of(None, None, past, now),
+            InvitationStatus::Expired
+        );
+        assert_eq!(
+            InvitationStatus::of(Some(past), None, past, now),
+            InvitationStatus::Accepted
+        );
+        assert_eq!(
+            InvitationStatus::of(None, Some(past), past, now),
+            InvitationStatus::Revoked
+        );
+    }
+
+    #[test]
+    fn normalize_invitation_email_lowercases_and_rejects_garbage() {
+        assert_eq!(
+            normalize_invitation_email("  Jane.Doe@Acme.COM ").unwrap(),
+            "jane.doe@acme.com"
+        );
+
+        for invalid in [
+            "",
+            "jane",
+            "@acme.com",
+            "jane@acme",
+            "jane@@acme.com",
+            "ja ne@acme.com",
+        ] {
+            assert_eq!(
+                normalize_invitation_email(invalid),
+                Err(OrganizationValidationError::InvalidInvitationEmail),
+                "{invalid}"
+            );
+        }
+    }
+
+    // --- Domain verification ---
+
+    #[test]
+    fn organization_domain_is_proven_by_matching_txt_record() {
+        let now = chrono::Utc::now();
+        let domain = OrganizationDomain {
+            organization_id: OrganizationId::new(Uuid::new_v4()),
+            realm_id: realm_id(),
+            domain: "acme.com".to_string(),
+            verification_token: "abc123".to_string(),
+            verified_at: None,
+            identity_provider_id: None,
+            created_at: now,
+            updated_at: now,
+        };
+
+        assert_eq!(
+            domain.verification_record_name(),
+            "_ferriskey-challenge.acme.com"
+        );
+        assert!(domain.is_proven_by(&[
+            "v=spf1 -all".to_string(),
+            "ferriskey-domain-verification=abc123".to_string(),
+        ]));
+        assert!(!domain.is_proven_by(&["ferriskey-domain-verification=other".to_string()]));
+    }
+
+    #[test]
+    fn email_domain_extracts_lowercased_domain() {
+        assert_eq!(email_domain("Jane@Acme.com").as_deref(), Some("acme.com"));
+        assert_eq!(email_domain("jane@acme.com.").as_deref(), Some("acme.com"));
+        assert_eq!(email_domain("jane"), None);
+        assert_eq!(email_domain("jane@"), None);
+    }
+
+    // --- OrganizationAdminRole ---
+
+    #[test]
+    fn organization_admin_role_roundtrips_role_name() {
+        for role in OrganizationAdminRole::ALL {
+            assert_eq!(
+                OrganizationAdminRole::from_role_name(role.role_name()),
+                Some(role)
+            );
+        }
+        assert_eq!(OrganizationAdminRole::from_role_name("admin"), None);
+    }
+
+    #[test]
+    fn organization_admin_role_capabilities() {
+        let manager = OrganizationAdminRole::MemberManager;
+
+        assert!(manager.allows(OrganizationCapability::ManageMembers));
+        assert!(!manager.allows(OrganizationCapability::Update));
+        assert!(!manager.allows(OrganizationCapability::ManageGroups));
+        assert!(OrganizationAdminRole::Admin.allows(OrganizationCapability::ManageGroups));
+    }
+
+    #[test]
+    fn organization_admin_role_grants_up_to_own_rank() {
+        let admin = OrganizationAdminRole::Admin;
+
+        assert!(admin.can_grant(None));
+        assert!(admin.can_grant(Some(OrganizationAdminRole::Admin)));
+        assert!(!admin.can_grant(Some(OrganizationAdminRole::Owner)));
+        assert!(OrganizationAdminRole::Owner.can_grant(Some(OrganizationAdminRole::Owner)));
+        assert!(!OrganizationAdminRole::MemberManager.can_grant(None));
+    }
 }
@@ -4,25 +4,28 @@ use std::sync::Arc;
 use ferriskey_domain::auth::Identity;
 use ferriskey_domain::client::ports::ClientRepository;
 use ferriskey_domain::common::app_errors::CoreError;
-use ferriskey_domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use ferriskey_domain::common::policies::ensure_policy;
 use ferriskey_domain::realm::Realm;
 use ferriskey_domain::realm::ports::RealmRepository;
 use ferriskey_domain::role::entities::Role;
 use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
+use uuid::Uuid;
 
+use crate::policies::OrganizationAdminPolicy;
 use crate::{
     AddGroupMemberInput, AssignGroupRoleInput, CreateGroupInput, CreateGroupParams,
     DeleteGroupAttributeInput, DeleteGroupInput, GetGroupInput, Group, GroupAttribute,
     GroupAttributeRepository, GroupConfig, GroupId, GroupMember, GroupMemberPage,
     GroupMemberRepository, GroupNode, GroupRepository, GroupRoleRepository, GroupService,
     ListGroupAttributesInput, ListGroupMembersInput, ListGroupRolesInput, ListGroupsInput,
-    Organization, OrganizationId, OrganizationPolicy, OrganizationRepository,
+    Organization, OrganizationCapability, OrganizationId, OrganizationMemberRepository,
+    OrganizationMemberRoleRepository, OrganizationRepository, OrganizationScopedPolicy,
     RemoveGroupMemberInput, RevokeGroupRoleInput, UpdateGroupInput, UpdateGroupParams,
     UpsertGroupAttributeInput, validate_membership_realms,
 };
 
 #[derive(Clone, Debug)]
-pub struct GroupServiceImpl<R, U, C, UR, OR, GR, GMR, GRR, GAR>
+pub struct GroupServiceImpl<R, U, C, UR, OR, GR, GMR, GRR, GAR, OMR, OMRR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -33,6 +36,8 @@ where
     GMR: GroupMemberRepository,
     GRR: GroupRoleRepository,
     GAR: GroupAttributeRepository,
+    OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) user_repository: Arc<U>,
@@ -42,10 +47,11 @@ where
     pub(crate) group_member_repository: Arc<GMR>,
     pub(crate) group_role_repository: Arc<GRR>,
     pub(crate) group_attribute_repository: Arc<GAR>,
-    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    pub(crate) policy: Arc<OrganizationAdminPolicy<U, C, UR, OMR, OMRR>>,
 }
 
-impl<R, U, C, UR, OR, GR, GMR, GRR, GAR> GroupServiceImpl<R, U, C, UR, OR, GR, GMR, GRR, GAR>
+impl<R, U, C, UR, OR, GR, GMR, GRR, GAR, OMR, OMRR>
+    GroupServiceImpl<R, U, C, UR, OR, GR, GMR, GRR, GAR, OMR, OMRR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -56,6 +62,8 @@ where
     GMR: GroupMemberRepository,
     GRR: GroupRoleRepository,
     GAR: GroupAttributeRepository,
+    OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -67,7 +75,7 @@ where
         group_member_repository: Arc<GMR>,
         group_role_repository: Arc<GRR>,
         group_attribute_repository: Arc<GAR>,
-        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+        policy: Arc<OrganizationAdminPolicy<U, C, UR, OMR, OMRR>>,
     ) -> Self {
         Self {
             realm_repository,
@@ -107,6 +115,36 @@ where
         Ok((realm, org))
     }
 
+    /// Group roles are inherited by every member, so attaching one is a grant like any other.
+    async fn ensure_can_grant_role(
+        &self,
+        identity: &Identity,
+        realm: &Realm,
+        org: &Organization,
+        role_id: Uuid,
+    ) -> Result<Role, CoreError> {
+        let role = self
+            .user_role_repository
+            .get_roles_by_ids(vec![role_id])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(CoreError::NotFound)?;
+
+        if role.realm_id != realm.id {
+            return Err(CoreError::NotFound);
+        }
+
+        ensure_policy(
+            self.policy
+                .can_grant_organization_role(identity, realm, org, &role)
+                .await,
+            "insufficient permissions to grant this role",
+        )?;
+
+        Ok(role)
+    }
+
     /// Load a group and assert it belongs to `organization_id`.
     async fn get_group_in_org(
         &self,
@@ -192,8 +230,8 @@ fn build_nodes(
         .unwrap_or_default()
 }
 
-impl<R, U, C, UR, OR, GR, GMR, GRR, GAR> GroupService
-    for GroupServiceImpl<R, U, C, UR, OR, GR, GMR, GRR, GAR>
+impl<R, U, C, UR, OR, GR, GMR, GRR, GAR, OMR, OMRR> GroupService
+    for GroupServiceImpl<R, U, C, UR, OR, GR, GMR, GRR, GAR, OMR, OMRR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -204,6 +242,8 @@ where
     GMR: GroupMemberRepository,
     GRR: GroupRoleRepository,
     GAR: GroupAttributeRepository,
+    OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
 {
     async fn create_group(
         &self,
@@ -214,7 +254,14 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageGroups,
+                )
+                .await,
             "insufficient permissions to manage groups",
         )?;
 
@@ -250,7 +297,9 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_view_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(&identity, &realm, &org, OrganizationCapability::View)
+                .await,
             "insufficient permissions to view groups",
         )?;
 
@@ -266,7 +315,9 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_view_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(&identity, &realm, &org, OrganizationCapability::View)
+                .await,
             "insufficient permissions to view groups",
         )?;
 
@@ -287,7 +338,14 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageGroups,
+                )
+                .await,
             "insufficient permissions to manage groups",
         )?;
 
@@ -319,7 +377,14 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageGroups,
+                )
+                .await,
             "insufficient permissions to manage groups",
         )?;
 
@@ -336,7 +401,14 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageMembers,
+                )
+                .await,
             "insufficient permissions to manage group members",
         )?;
 
@@ -368,7 +440,14 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageMembers,
+                )
+                .await,
             "insufficient permissions to manage group members",
         )?;
 
@@ -387,7 +466,9 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_view_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(&identity, &realm, &org, OrganizationCapability::View)
+                .await,
             "insufficient permissions to view group members",
         )?;
 
@@ -423,11 +504,28 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageGroups,
+                )
+                .await,
             "insufficient permissions to manage group roles",
         )?;
 
         self.get_group_in_org(org.id, input.group_id).await?;
+        let role = self
+            .ensure_can_grant_role(&identity, &realm, &org, input.role_id)
+            .await?;
+
+        // Admin roles are only honoured on a direct membership; inherited through a group they
+        // would show up in tokens without granting anything.
+        if self.policy.admin_role_of(&role).await?.is_some() {
+            return Err(CoreError::Invalid);
+        }
+
         self.group_role_repository
             .assign_role(input.group_id, input.role_id)
             .await?;
@@ -444,11 +542,20 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageGroups,
+                )
+                .await,
             "insufficient permissions to manage group roles",
         )?;
 
         self.get_group_in_org(org.id, input.group_id).await?;
+        self.ensure_can_grant_role(&identity, &realm, &org, input.role_id)
+            .await?;
         self.group_role_repository
             .revoke_role(input.group_id, input.role_id)
             .await
@@ -463,7 +570,9 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_view_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(&identity, &realm, &org, OrganizationCapability::View)
+                .await,
             "insufficient permissions to view group roles",
         )?;
 
@@ -485,7 +594,9 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_view_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(&identity, &realm, &org, OrganizationCapability::View)
+                .await,
             "insufficient permissions to view group attributes",
         )?;
 
@@ -504,7 +615,14 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageGroups,
+                )
+                .await,
             "insufficient permissions to manage group attributes",
         )?;
 
@@ -528,7 +646,14 @@ where
             .get_org(input.realm_name, input.organization_id)
             .await?;
         ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageGroups,
+                )
+                .await,
             "insufficient permissions to manage group attributes",
         )?;
 
@@ -547,13 +672,16 @@ mod tests {
     use uuid::Uuid;
 
     use ferriskey_domain::client::ports::MockClientRepository;
+    use ferriskey_domain::common::policies::FerriskeyPolicy;
     use ferriskey_domain::realm::{RealmId, ports::MockRealmRepository};
     use ferriskey_domain::user::entities::User;
     use ferriskey_domain::user::ports::{MockUserRepository, MockUserRoleRepository};
 
     use crate::{
         MockGroupAttributeRepository, MockGroupMemberRepository, MockGroupRepository,
-        MockGroupRoleRepository, MockOrganizationRepository,
+        MockGroupRoleRepository, MockOrganizationMemberRepository,
+        MockOrganizationMemberRoleRepository, MockOrganizationRepository, OrganizationAdminRole,
+        OrganizationMember,
     };
 
     use super::*;
@@ -645,6 +773,8 @@ mod tests {
         MockGroupMemberRepository,
         MockGroupRoleRepository,
         MockGroupAttributeRepository,
+        MockOrganizationMemberRepository,
+        MockOrganizationMemberRoleRepository,
     >;
 
     fn build_service(
@@ -654,14 +784,42 @@ mod tests {
         org_repo: MockOrganizationRepository,
         group_repo: MockGroupRepository,
         group_member_repo: MockGroupMemberRepository,
+    ) -> TestService {
+        build_service_with_org_admins(
+            realm_repo,
+            user_repo,
+            user_role_repo,
+            org_repo,
+            group_repo,
+            group_member_repo,
+            MockOrganizationMemberRepository::new(),
+            MockOrganizationMemberRoleRepository::new(),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_service_with_org_admins(
+        realm_repo: MockRealmRepository,
+        user_repo: MockUserRepository,
+        user_role_repo: MockUserRoleRepository,
+        org_repo: MockOrganizationRepository,
+        group_repo: MockGroupRepository,
+        group_member_repo: MockGroupMemberRepository,
+        org_member_repo: MockOrganizationMemberRepository,
+        org_member_role_repo: MockOrganizationMemberRoleRepository,
     ) -> TestService {
         let user_arc = Arc::new(user_repo);
         let user_role_arc = Arc::new(user_role_repo);
-        let policy = Arc::new(FerriskeyPolicy::new(
+        let realm_policy = Arc::new(FerriskeyPolicy::new(
             user_arc.clone(),
             Arc::new(MockClientRepository::new()),
             user_role_arc.clone(),
         ));
+        let policy = Arc::new(OrganizationAdminPolicy::new(
+            realm_policy,
+            Arc::new(org_member_repo),
+            Arc::new(org_member_role_repo),
+        ));
 
         GroupServiceImpl::new(
             Arc::new(realm_repo),
@@ -915,4 +1073,216 @@ mod tests {
             "the organization's own realm admin must be allowed, got {result:?}"
         );
     }
+
+    /// Policy repositories for an actor with no realm permissions holding `admin_role` in
+    /// the organization. Any other role id resolves to a role carrying `manage_realm`.
+    fn delegated_admin(
+        realm_id: RealmId,
+        org_id: OrganizationId,
+        user_id: Uuid,
+        admin_role: OrganizationAdminRole,
+    ) -> (
+        MockUserRoleRepository,
+        MockOrganizationMemberRepository,
+        MockOrganizationMemberRoleRepository,
+    ) {
+        let member = OrganizationMember {
+            id: Uuid::new_v4(),
+            organization_id: org_id,
+            user_id,
+            created_at: Utc::now(),
+        };
+        let member_id = member.id;
+        let admin_role_id = Uuid::new_v4();
+
+        let mut member_repo = MockOrganizationMemberRepository::new();
+        member_repo
+            .expect_get_member()
+            .returning(move |organization_id, candidate| {
+                let found =
+                    (organization_id == org_id && candidate == user_id).then(|| member.clone());
+                Box::pin(async move { Ok(found) })
+            });
+
+        let mut member_role_repo = MockOrganizationMemberRoleRepository::new();
+        member_role_repo
+            .expect_list_role_ids()
+            .returning(move |id| {
+                let ids = if id == member_id {
+                    vec![admin_role_id]
+                } else {
+                    vec![]
+                };
+                Box::pin(async move { Ok(ids) })
+            });
+        member_role_repo
+            .expect_find_admin_roles()
+            .returning(move |_, ids| {
+                let roles = ids
+                    .into_iter()
+                    .filter(|id| *id == admin_role_id)
+                    .map(|id| (id, admin_role))
+                    .collect();
+                Box::pin(async move { Ok(roles) })
+            });
+
+        let mut user_role_repo = MockUserRoleRepository::new();
+        user_role_repo
+            .expect_get_user_roles()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        user_role_repo
+            .expect_get_roles_by_ids()
+            .returning(move |ids| {
+                let roles = ids
+                    .into_iter()
+                    .map(|id| {
+                        if id == admin_role_id {
+                            Role {
+                                id,
+                                name: admin_role.role_name().to_string(),
+                                permissions: vec![],
+                                ..make_role_with_permission(realm_id, "view_users")
+                            }
+                        } else {
+                            Role {
+                                id,
+                                ..make_role_with_permission(realm_id, "manage_realm")
+                            }
+                        }
+                    })
+                    .collect();
+                Box::pin(async move { Ok(roles) })
+            });
+
+        (user_role_repo, member_repo, member_role_repo)
+    }
+
+    fn realm_repo_for(realm_id: RealmId) -> MockRealmRepository {
+        let mut realm_repo = MockRealmRepository::new();
+        realm_repo.expect_get_by_name().returning(move |_| {
+            let r = make_realm(realm_id, "test-realm");
+            Box::pin(async move { Ok(Some(r)) })
+        });
+        realm_repo
+    }
+
+    fn create_group_input(organization_id: OrganizationId) -> CreateGroupInput {
+        CreateGroupInput {
+            realm_name: "test-realm".to_string(),
+            organization_id,
+            parent_group_id: None,
+            name: "Engineering".to_string(),
+            description: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_group_succeeds_for_organization_admin() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let user = make_user(&make_realm(realm_id, "test-realm"));
+        let org = make_org(realm_id);
+        let org_id = org.id;
+        let (user_role_repo, member_repo, member_role_repo) =
+            delegated_admin(realm_id, org_id, user.id, OrganizationAdminRole::Admin);
+
+        let mut group_repo = MockGroupRepository::new();
+        group_repo.expect_create_group().returning(move |_| {
+            let g = make_group(org_id);
+            Box::pin(async move { Ok(g) })
+        });
+
+        let service = build_service_with_org_admins(
+            realm_repo_for(realm_id),
+            MockUserRepository::new(),
+            user_role_repo,
+            org_repo_returning(org),
+            group_repo,
+            MockGroupMemberRepository::new(),
+            member_repo,
+            member_role_repo,
+        );
+
+        let result = service
+            .create_group(Identity::User(user), create_group_input(org_id))
+            .await;
+
+        assert!(
+            result.is_ok(),
+            "org admin must manage groups, got {result:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn create_group_denies_member_manager() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let user = make_user(&make_realm(realm_id, "test-realm"));
+        let org = make_org(realm_id);
+        let org_id = org.id;
+        let (user_role_repo, member_repo, member_role_repo) = delegated_admin(
+            realm_id,
+            org_id,
+            user.id,
+            OrganizationAdminRole::MemberManager,
+        );
+
+        let service = build_service_with_org_admins(
+            realm_repo_for(realm_id),
+            MockUserRepository::new(),
+            user_role_repo,
+            org_repo_returning(org),
+            MockGroupRepository::new(),
+            MockGroupMemberRepository::new(),
+            member_repo,
+            member_role_repo,
+        );
+
+        let result = service
+            .create_group(Identity::User(user), create_group_input(org_id))
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn assign_role_denies_org_admin_granting_realm_permissions() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let user = make_user(&make_realm(realm_id, "test-realm"));
+        let org = make_org(realm_id);
+        let org_id = org.id;
+        let group = make_group(org_id);
+        let group_id = group.id;
+        let (user_role_repo, member_repo, member_role_repo) =
+            delegated_admin(realm_id, org_id, user.id, OrganizationAdminRole::Owner);
+
+        let mut group_repo = MockGroupRepository::new();
+        group_repo.expect_get_group_by_id().returning(move |_| {
+            let g = group.clone();
+            Box::pin(async move { Ok(Some(g)) })
+        });
+
+        let service = build_service_with_org_admins(
+            realm_repo_for(realm_id),
+            MockUserRepository::new(),
+            user_role_repo,
+            org_repo_returning(org),
+            group_repo,
+            MockGroupMemberRepository::new(),
+            member_repo,
+            member_role_repo,
+        );
+
+        let result = service
+            .assign_role(
+                Identity::User(user),
+                AssignGroupRoleInput {
+                    realm_name: "test-realm".to_string(),
+                    organization_id: org_id,
+                    group_id,
+                    role_id: Uuid::new_v4(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
 }
@@ -1,12 +1,19 @@
+use std::sync::Arc;
+
 use ferriskey_domain::auth::Identity;
 use ferriskey_domain::client::ports::ClientRepository;
 use ferriskey_domain::common::app_errors::CoreError;
 use ferriskey_domain::common::policies::{FerriskeyPolicy, Policy};
 use ferriskey_domain::realm::Realm;
+use ferriskey_domain::role::entities::Role;
 use ferriskey_domain::role::permission::Permissions;
 use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
+use uuid::Uuid;
 
-use crate::OrganizationPolicy;
+use crate::{
+    Organization, OrganizationAdminRole, OrganizationCapability, OrganizationMemberRepository,
+    OrganizationMemberRoleRepository, OrganizationPolicy, OrganizationScopedPolicy,
+};
 
 impl<U, C, UR> OrganizationPolicy for FerriskeyPolicy<U, C, UR>
 where
@@ -98,3 +105,266 @@ where
         ))
     }
 }
+
+/// Organization policy with delegated administration: realm-level checks come from the
+/// wrapped [`FerriskeyPolicy`], organization-scoped ones additionally honour the
+/// [`OrganizationAdminRole`]s assigned to the caller's membership of the target organization.
+#[derive(Clone, Debug)]
+pub struct OrganizationAdminPolicy<U, C, UR, OMR, OMRR>
+where
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
+{
+    realm_policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    organization_member_repository: Arc<OMR>,
+    organization_member_role_repository: Arc<OMRR>,
+}
+
+impl<U, C, UR, OMR, OMRR> OrganizationAdminPolicy<U, C, UR, OMR, OMRR>
+where
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
+{
+    pub fn new(
+        realm_policy: Arc<FerriskeyPolicy<U, C, UR>>,
+        organization_member_repository: Arc<OMR>,
+        organization_member_role_repository: Arc<OMRR>,
+    ) -> Self {
+        Self {
+            realm_policy,
+            organization_member_repository,
+            organization_member_role_repository,
+        }
+    }
+
+    async fn member_admin_role(
+        &self,
+        organization: &Organization,
+        user_id: Uuid,
+    ) -> Result<Option<OrganizationAdminRole>, CoreError> {
+        let Some(member) = self
+            .organization_member_repository
+            .get_member(organization.id, user_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let role_ids = self
+            .organization_member_role_repository
+            .list_role_ids(member.id)
+            .await?;
+        if role_ids.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(self
+            .organization_member_role_repository
+            .find_admin_roles(organization.realm_id, role_ids)
+            .await?
+            .into_iter()
+            .map(|(_, admin_role)| admin_role)
+            .max())
+    }
+
+    /// The organization admin role `role` backs, when it is one of the seeded roles.
+    pub async fn admin_role_of(
+        &self,
+        role: &Role,
+    ) -> Result<Option<OrganizationAdminRole>, CoreError> {
+        if role.client_id.is_some() {
+            return Ok(None);
+        }
+
+        Ok(self
+            .organization_member_role_repository
+            .find_admin_roles(role.realm_id, vec![role.id])
+            .await?
+            .into_iter()
+            .map(|(_, admin_role)| admin_role)
+            .next())
+    }
+
+    async fn realm_allows(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+        capability: OrganizationCapability,
+    ) -> Result<bool, CoreError> {
+        match capability {
+            OrganizationCapability::View => {
+                self.realm_policy
+                    .can_view_organization(identity, target_realm)
+                    .await
+            }
+            OrganizationCapability::Update => {
+                self.realm_policy
+                    .can_update_organization(identity, target_realm)
+                    .await
+            }
+            OrganizationCapability::ManageMembers | OrganizationCapability::ManageGroups => {
+                self.realm_policy
+                    .can_manage_members(identity, target_realm)
+                    .await
+            }
+        }
+    }
+}
+
+impl<U, C, UR, OMR, OMRR> OrganizationPolicy for OrganizationAdminPolicy<U, C, UR, OMR, OMRR>
+where
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
+{
+    async fn can_create_organization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> Result<bool, CoreError> {
+        self.realm_policy
+            .can_create_organization(identity, target_realm)
+            .await
+    }
+
+    async fn can_view_organization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> Result<bool, CoreError> {
+        self.realm_policy
+            .can_view_organization(identity, target_realm)
+            .await
+    }
+
+    async fn can_update_organization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> Result<bool, CoreError> {
+        self.realm_policy
+            .can_update_organization(identity, target_realm)
+            .await
+    }
+
+    async fn can_delete_organization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> Result<bool, CoreError> {
+        self.realm_policy
+            .can_delete_organization(identity, target_realm)
+            .await
+    }
+
+    async fn can_manage_members(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> Result<bool, CoreError> {
+        self.realm_policy
+            .can_manage_members(identity, target_realm)
+            .await
+    }
+}
+
+impl<U, C, UR, OMR, OMRR> OrganizationScopedPolicy for OrganizationAdminPolicy<U, C, UR, OMR, OMRR>
+where
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
+{
+    async fn organization_admin_role(
+        &self,
+        identity: &Identity,
+        organization: &Organization,
+    ) -> Result<Option<OrganizationAdminRole>, CoreError> {
+        let user = self.realm_policy.get_user_from_identity(identity).await?;
+        if user.realm_id != organization.realm_id {
+            return Ok(None);
+        }
+
+        self.member_admin_role(organization, user.id).await
+    }
+
+    async fn can_administer_organization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+        organization: &Organization,
+        capability: OrganizationCapability,
+    ) -> Result<bool, CoreError> {
+        if self
+            .realm_allows(identity, target_realm, capability)
+            .await?
+        {
+            return Ok(true);
+        }
+
+        Ok(self
+            .organization_admin_role(identity, organization)
+            .await?
+            .is_some_and(|role| role.allows(capability)))
+    }
+
+    /// Delegated administrators only hand out roles that carry no realm permissions, so an
+    /// organization admin can never mint realm-level authority.
+    async fn can_grant_organization_role(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+        organization: &Organization,
+        role: &Role,
+    ) -> Result<bool, CoreError> {
+        if self
+            .realm_policy
+            .can_manage_members(identity, target_realm)
+            .await?
+        {
+            return Ok(true);
+        }
+
+        let Some(actor) = self.organization_admin_role(identity, organization).await? else {
+            return Ok(false);
+        };
+        let target = self.admin_role_of(role).await?;
+
+        Ok(role.permissions.is_empty() && actor.can_grant(target))
+    }
+
+    async fn can_remove_organization_member(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+        organization: &Organization,
+        user_id: Uuid,
+    ) -> Result<bool, CoreError> {
+        if self
+            .realm_policy
+            .can_manage_members(identity, target_realm)
+            .await?
+        {
+            return Ok(true);
+        }
+
+        let Some(actor) = self.organization_admin_role(identity, organization).await? else {
+            return Ok(false);
+        };
+        if !actor.allows(OrganizationCapability::ManageMembers) {
+            return Ok(false);
+        }
+
+        let target = self.member_admin_role(organization, user_id).await?;
+        Ok(actor.outranks_or_equals(target))
+    }
+}
@@ -6,18 +6,23 @@ use ferriskey_domain::realm::{Realm, RealmId};
 use ferriskey_domain::role::entities::Role;
 
 use crate::entities::{
-    AddGroupMemberInput, AddOrganizationMemberInput, AssignGroupRoleInput, AssignMemberRoleInput,
-    CreateGroupInput, CreateGroupParams, CreateOrganizationInput, CreateOrganizationParams,
-    DeleteGroupAttributeInput, DeleteGroupInput, DeleteOrganizationAttributeInput,
-    DeleteOrganizationInput, GetGroupInput, GetOrganizationInput, Group, GroupAttribute, GroupId,
-    GroupMember, GroupMemberDetail, GroupMemberPage, GroupNode, GroupRoleMapping,
-    ListGroupAttributesInput, ListGroupMembersInput, ListGroupRolesInput, ListGroupsInput,
+    AcceptInvitationInput, AcceptedInvitation, AddGroupMemberInput, AddOrganizationMemberInput,
+    AssignGroupRoleInput, AssignMemberRoleInput, ConfigureOrganizationDomainInput,
+    CreateGroupInput, CreateGroupParams, CreateInvitationInput, CreateInvitationParams,
+    CreateOrganizationInput, CreateOrganizationParams, DeleteGroupAttributeInput, DeleteGroupInput,
+    DeleteOrganizationAttributeInput, DeleteOrganizationInput, DiscoverHomeRealmInput,
+    GetGroupInput, GetInvitationInput, GetOrganizationDomainInput, GetOrganizationInput, Group,
+    GroupAttribute, GroupId, GroupMember, GroupMemberDetail, GroupMemberPage, GroupNode,
+    GroupRoleMapping, HomeRealmDiscovery, InvitationPreview, ListGroupAttributesInput,
+    ListGroupMembersInput, ListGroupRolesInput, ListGroupsInput, ListInvitationsInput,
     ListMemberRolesInput, ListOrganizationAttributesInput, ListOrganizationMembersInput,
-    ListOrganizationsInput, ListUserOrganizationsInput, Organization, OrganizationAttribute,
-    OrganizationId, OrganizationMember, RemoveGroupMemberInput, RemoveOrganizationMemberInput,
-    RevokeGroupRoleInput, RevokeMemberRoleInput, UpdateGroupInput, UpdateGroupParams,
+    ListOrganizationsInput, ListUserOrganizationsInput, Organization, OrganizationAdminRole,
+    OrganizationAttribute, OrganizationCapability, OrganizationDomain, OrganizationDomainStatus,
+    OrganizationId, OrganizationInvitation, OrganizationMember, RemoveGroupMemberInput,
+    RemoveOrganizationMemberInput, RevokeGroupRoleInput, RevokeInvitationInput,
+    RevokeMemberRoleInput, SaveOrganizationDomainParams, UpdateGroupInput, UpdateGroupParams,
     UpdateOrganizationInput, UpdateOrganizationParams, UpsertGroupAttributeInput,
-    UpsertOrganizationAttributeInput,
+    UpsertOrganizationAttributeInput, VerifyOrganizationDomainInput,
 };
 
 /// Repository trait for Organization persistence
@@ -136,6 +141,21 @@ pub trait OrganizationMemberRoleRepository: Send + Sync {
         &self,
         organization_member_id: Uuid,
     ) -> impl Future<Output = Result<Vec<Uuid>, CoreError>> + Send;
+
+    /// Create the organization admin roles missing in `realm_id` and record them as seeded.
+    /// A realm role already holding one of their names is left alone and never honoured.
+    fn seed_admin_roles(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// The seeded organization admin roles of `realm_id` among `role_ids`. Other roles never
+    /// count, whatever their name.
+    fn find_admin_roles(
+        &self,
+        realm_id: RealmId,
+        role_ids: Vec<Uuid>,
+    ) -> impl Future<Output = Result<Vec<(Uuid, OrganizationAdminRole)>, CoreError>> + Send;
 }
 
 /// Service trait for Organization business logic
@@ -478,6 +498,145 @@ pub trait GroupService: Send + Sync {
     ) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
 
+// ============================================================================
+// Invitations and home-realm discovery
+// ============================================================================
+
+/// Persistence for organization invitations.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait OrganizationInvitationRepository: Send + Sync {
+    fn create_invitation(
+        &self,
+        params: CreateInvitationParams,
+    ) -> impl Future<Output = Result<OrganizationInvitation, CoreError>> + Send;
+
+    fn get_invitation_by_id(
+        &self,
+        id: Uuid,
+    ) -> impl Future<Output = Result<Option<OrganizationInvitation>, CoreError>> + Send;
+
+    fn get_invitation_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> impl Future<Output = Result<Option<OrganizationInvitation>, CoreError>> + Send;
+
+    fn list_invitations(
+        &self,
+        organization_id: OrganizationId,
+    ) -> impl Future<Output = Result<Vec<OrganizationInvitation>, CoreError>> + Send;
+
+    /// Revokes every pending invitation of `email` to the organization, so that only the
+    /// latest link works.
+    fn revoke_pending_invitations(
+        &self,
+        organization_id: OrganizationId,
+        email: &str,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+
+    /// Revokes the invitation if it is still pending; `None` otherwise.
+    fn revoke_invitation(
+        &self,
+        id: Uuid,
+    ) -> impl Future<Output = Result<Option<OrganizationInvitation>, CoreError>> + Send;
+
+    /// Marks the invitation accepted by `user_id` if it is still pending and unexpired;
+    /// `None` when another request accepted, revoked or outlived it first.
+    fn mark_invitation_accepted(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Option<OrganizationInvitation>, CoreError>> + Send;
+}
+
+/// Persistence for organization domains (one per organization).
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait OrganizationDomainRepository: Send + Sync {
+    fn get_domain(
+        &self,
+        organization_id: OrganizationId,
+    ) -> impl Future<Output = Result<Option<OrganizationDomain>, CoreError>> + Send;
+
+    fn save_domain(
+        &self,
+        params: SaveOrganizationDomainParams,
+    ) -> impl Future<Output = Result<OrganizationDomain, CoreError>> + Send;
+
+    /// The verified domain record of the realm for `domain`, if any organization proved it.
+    fn find_verified_domain(
+        &self,
+        realm_id: RealmId,
+        domain: &str,
+    ) -> impl Future<Output = Result<Option<OrganizationDomain>, CoreError>> + Send;
+}
+
+/// Looks up DNS TXT records, for domain ownership checks.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait DomainTxtResolver: Send + Sync {
+    /// The TXT strings published at `name`; empty when the name does not exist.
+    fn lookup_txt(&self, name: &str)
+    -> impl Future<Output = Result<Vec<String>, CoreError>> + Send;
+}
+
+/// Service trait for organization invitations.
+pub trait OrganizationInvitationService: Send + Sync {
+    fn create_invitation(
+        &self,
+        identity: Identity,
+        input: CreateInvitationInput,
+    ) -> impl Future<Output = Result<OrganizationInvitation, CoreError>> + Send;
+
+    fn list_invitations(
+        &self,
+        identity: Identity,
+        input: ListInvitationsInput,
+    ) -> impl Future<Output = Result<Vec<OrganizationInvitation>, CoreError>> + Send;
+
+    fn revoke_invitation(
+        &self,
+        identity: Identity,
+        input: RevokeInvitationInput,
+    ) -> impl Future<Output = Result<OrganizationInvitation, CoreError>> + Send;
+
+    /// Unauthenticated: the invitation token is the credential.
+    fn get_invitation(
+        &self,
+        input: GetInvitationInput,
+    ) -> impl Future<Output = Result<InvitationPreview, CoreError>> + Send;
+
+    /// Unauthenticated: the invitation token is the credential.
+    fn accept_invitation(
+        &self,
+        input: AcceptInvitationInput,
+    ) -> impl Future<Output = Result<AcceptedInvitation, CoreError>> + Send;
+}
+
+/// Service trait for organization domain verification and home-realm discovery.
+pub trait OrganizationDomainService: Send + Sync {
+    fn get_organization_domain(
+        &self,
+        identity: Identity,
+        input: GetOrganizationDomainInput,
+    ) -> impl Future<Output = Result<OrganizationDomainStatus, CoreError>> + Send;
+
+    fn configure_organization_domain(
+        &self,
+        identity: Identity,
+        input: ConfigureOrganizationDomainInput,
+    ) -> impl Future<Output = Result<OrganizationDomainStatus, CoreError>> + Send;
+
+    fn verify_organization_domain(
+        &self,
+        identity: Identity,
+        input: VerifyOrganizationDomainInput,
+    ) -> impl Future<Output = Result<OrganizationDomainStatus, CoreError>> + Send;
+
+    /// Unauthenticated: used by the login page before the user is known.
+    fn discover_home_realm(
+        &self,
+        input: DiscoverHomeRealmInput,
+    ) -> impl Future<Output = Result<Option<HomeRealmDiscovery>, CoreError>> + Send;
+}
+
 /// Policy trait for Organization authorization
 pub trait OrganizationPolicy: Send + Sync {
     fn can_create_organization(
@@ -510,3 +669,40 @@ pub trait OrganizationPolicy: Send + Sync {
         target_realm: &Realm,
     ) -> impl Future<Output = Result<bool, CoreError>> + Send;
 }
+
+/// Authorization scoped to a single organization. Realm administrators pass every check
+/// through the realm-level [`OrganizationPolicy`]; members holding an
+/// [`OrganizationAdminRole`] pass the checks their role allows, for their own organization only.
+pub trait OrganizationScopedPolicy: OrganizationPolicy {
+    /// Highest admin role the identity holds in `organization`, if any.
+    fn organization_admin_role(
+        &self,
+        identity: &Identity,
+        organization: &Organization,
+    ) -> impl Future<Output = Result<Option<OrganizationAdminRole>, CoreError>> + Send;
+
+    fn can_administer_organization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+        organization: &Organization,
+        capability: OrganizationCapability,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    /// Granting and revoking a member role, including through an invitation.
+    fn can_grant_organization_role(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+        organization: &Organization,
+        role: &Role,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_remove_organization_member(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+        organization: &Organization,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
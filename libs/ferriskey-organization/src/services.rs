@@ -3,23 +3,25 @@ use std::sync::Arc;
 use ferriskey_domain::auth::Identity;
 use ferriskey_domain::client::ports::ClientRepository;
 use ferriskey_domain::common::app_errors::CoreError;
-use ferriskey_domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use ferriskey_domain::common::policies::ensure_policy;
 use ferriskey_domain::realm::ports::RealmRepository;
 use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
 
+use crate::policies::OrganizationAdminPolicy;
 use crate::{
     AddOrganizationMemberInput, CreateOrganizationInput, DeleteOrganizationAttributeInput,
     DeleteOrganizationInput, GetOrganizationInput, ListOrganizationAttributesInput,
     ListOrganizationMembersInput, ListOrganizationsInput, ListUserOrganizationsInput, Organization,
-    OrganizationAttribute, OrganizationAttributeRepository, OrganizationConfig, OrganizationId,
-    OrganizationMember, OrganizationMemberRepository, OrganizationPolicy, OrganizationRepository,
-    OrganizationService, OrganizationValidationError, RemoveOrganizationMemberInput,
-    UpdateOrganizationInput, UpdateOrganizationParams, UpsertOrganizationAttributeInput,
-    validate_membership_realms,
+    OrganizationAttribute, OrganizationAttributeRepository, OrganizationCapability,
+    OrganizationConfig, OrganizationId, OrganizationMember, OrganizationMemberRepository,
+    OrganizationMemberRoleRepository, OrganizationPolicy, OrganizationRepository,
+    OrganizationScopedPolicy, OrganizationService, OrganizationValidationError,
+    RemoveOrganizationMemberInput, UpdateOrganizationInput, UpdateOrganizationParams,
+    UpsertOrganizationAttributeInput, validate_membership_realms,
 };
 
 #[derive(Clone, Debug)]
-pub struct OrganizationServiceImpl<R, U, C, UR, OR, OAR, OMR>
+pub struct OrganizationServiceImpl<R, U, C, UR, OR, OAR, OMR, OMRR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -28,16 +30,17 @@ where
     OR: OrganizationRepository,
     OAR: OrganizationAttributeRepository,
     OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) user_repository: Arc<U>,
     pub(crate) organization_repository: Arc<OR>,
     pub(crate) organization_attribute_repository: Arc<OAR>,
     pub(crate) organization_member_repository: Arc<OMR>,
-    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    pub(crate) policy: Arc<OrganizationAdminPolicy<U, C, UR, OMR, OMRR>>,
 }
 
-impl<R, U, C, UR, OR, OAR, OMR> OrganizationServiceImpl<R, U, C, UR, OR, OAR, OMR>
+impl<R, U, C, UR, OR, OAR, OMR, OMRR> OrganizationServiceImpl<R, U, C, UR, OR, OAR, OMR, OMRR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -46,6 +49,7 @@ where
     OR: OrganizationRepository,
     OAR: OrganizationAttributeRepository,
     OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
 {
     pub fn new(
         realm_repository: Arc<R>,
@@ -53,7 +57,7 @@ where
         organization_repository: Arc<OR>,
         organization_attribute_repository: Arc<OAR>,
         organization_member_repository: Arc<OMR>,
-        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+        policy: Arc<OrganizationAdminPolicy<U, C, UR, OMR, OMRR>>,
     ) -> Self {
         Self {
             realm_repository,
@@ -95,8 +99,8 @@ where
     }
 }
 
-impl<R, U, C, UR, OR, OAR, OMR> OrganizationService
-    for OrganizationServiceImpl<R, U, C, UR, OR, OAR, OMR>
+impl<R, U, C, UR, OR, OAR, OMR, OMRR> OrganizationService
+    for OrganizationServiceImpl<R, U, C, UR, OR, OAR, OMR, OMRR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -105,6 +109,7 @@ where
     OR: OrganizationRepository,
     OAR: OrganizationAttributeRepository,
     OMR: OrganizationMemberRepository,
+    OMRR: OrganizationMemberRoleRepository,
 {
     async fn create_organization(
         &self,
@@ -162,7 +167,9 @@ where
             .await?;
 
         ensure_policy(
-            self.policy.can_view_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(&identity, &realm, &org, OrganizationCapability::View)
+                .await,
             "insufficient permissions to view organization",
         )?;
 
@@ -197,10 +204,25 @@ where
             .await?;
 
         ensure_policy(
-            self.policy.can_update_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::Update,
+                )
+                .await,
             "insufficient permissions to update organization",
         )?;
 
+        // Enabling or disabling an organization stays with realm administrators.
+        if input.enabled.is_some_and(|enabled| enabled != org.enabled) {
+            ensure_policy(
+                self.policy.can_update_organization(&identity, &realm).await,
+                "insufficient permissions to change organization status",
+            )?;
+        }
+
         if let Some(ref new_alias) = input.alias
             && *new_alias != org.alias
             && self
@@ -256,7 +278,9 @@ where
             .await?;
 
         ensure_policy(
-            self.policy.can_view_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(&identity, &realm, &org, OrganizationCapability::View)
+                .await,
             "insufficient permissions to list organization attributes",
         )?;
 
@@ -276,7 +300,14 @@ where
             .await?;
 
         ensure_policy(
-            self.policy.can_update_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::Update,
+                )
+                .await,
             "insufficient permissions to upsert organization attribute",
         )?;
 
@@ -306,7 +337,14 @@ where
             .await?;
 
         ensure_policy(
-            self.policy.can_update_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::Update,
+                )
+                .await,
             "insufficient permissions to delete organization attribute",
         )?;
 
@@ -326,7 +364,14 @@ where
             .await?;
 
         ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(
+                    &identity,
+                    &realm,
+                    &org,
+                    OrganizationCapability::ManageMembers,
+                )
+                .await,
             "insufficient permissions to add organization member",
         )?;
 
@@ -370,7 +415,9 @@ where
             .await?;
 
         ensure_policy(
-            self.policy.can_manage_members(&identity, &realm).await,
+            self.policy
+                .can_remove_organization_member(&identity, &realm, &org, input.user_id)
+                .await,
             "insufficient permissions to remove organization member",
         )?;
 
@@ -396,7 +443,9 @@ where
             .await?;
 
         ensure_policy(
-            self.policy.can_view_organization(&identity, &realm).await,
+            self.policy
+                .can_administer_organization(&identity, &realm, &org, OrganizationCapability::View)
+                .await,
             "insufficient permissions to list organization members",
         )?;
 
@@ -452,7 +501,8 @@ mod tests {
 
     use crate::{
         MockOrganizationAttributeRepository, MockOrganizationMemberRepository,
-        MockOrganizationRepository, Organization, OrganizationId, OrganizationMember,
+        MockOrganizationMemberRoleRepository, MockOrganizationRepository, Organization,
+        OrganizationAdminRole, OrganizationId, OrganizationMember,
     };
 
     use super::*;
@@ -528,6 +578,98 @@ mod tests {
         }
     }
 
+    fn non_member_repo() -> MockOrganizationMemberRepository {
+        let mut member_repo = MockOrganizationMemberRepository::new();
+        member_repo
+            .expect_get_member()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        member_repo
+    }
+
+    /// Repositories for an actor without realm permissions whose membership of
+    /// `organization` carries the given admin roles, keyed by user id.
+    fn delegated_admin_repos(
+        realm_id: RealmId,
+        org_id: OrganizationId,
+        admins: Vec<(Uuid, OrganizationAdminRole)>,
+    ) -> (
+        MockUserRoleRepository,
+        MockOrganizationMemberRepository,
+        MockOrganizationMemberRoleRepository,
+    ) {
+        let members: Vec<(OrganizationMember, OrganizationAdminRole)> = admins
+            .into_iter()
+            .map(|(user_id, role)| (make_member(org_id, user_id), role))
+            .collect();
+        let role_ids: Vec<(Uuid, Uuid, OrganizationAdminRole)> = members
+            .iter()
+            .map(|(member, role)| (member.id, Uuid::new_v4(), *role))
+            .collect();
+
+        let mut member_repo = MockOrganizationMemberRepository::new();
+        let lookup = members.clone();
+        member_repo
+            .expect_get_member()
+            .returning(move |organization_id, user_id| {
+                let found = lookup
+                    .iter()
+                    .find(|(m, _)| m.organization_id == organization_id && m.user_id == user_id)
+                    .map(|(m, _)| m.clone());
+                Box::pin(async move { Ok(found) })
+            });
+
+        let mut member_role_repo = MockOrganizationMemberRoleRepository::new();
+        let assignments = role_ids.clone();
+        member_role_repo
+            .expect_list_role_ids()
+            .returning(move |member_id| {
+                let ids = assignments
+                    .iter()
+                    .filter(|(m, _, _)| *m == member_id)
+                    .map(|(_, role_id, _)| *role_id)
+                    .collect();
+                Box::pin(async move { Ok(ids) })
+            });
+        member_role_repo
+            .expect_find_admin_roles()
+            .returning(move |seeded_realm_id, ids| {
+                let roles = role_ids
+                    .iter()
+                    .filter(|(_, role_id, _)| seeded_realm_id == realm_id && ids.contains(role_id))
+                    .map(|(_, role_id, admin_role)| (*role_id, *admin_role))
+                    .collect();
+                Box::pin(async move { Ok(roles) })
+            });
+
+        let mut user_role_repo = MockUserRoleRepository::new();
+        user_role_repo
+            .expect_get_user_roles()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        (user_role_repo, member_repo, member_role_repo)
+    }
+
+    fn realm_and_org_repos(
+        realm_id: RealmId,
+        org: Organization,
+    ) -> (MockRealmRepository, MockOrganizationRepository) {
+        let mut realm_repo = MockRealmRepository::new();
+        realm_repo.expect_get_by_name().returning(move |_| {
+            let r = make_realm(realm_id, "test-realm");
+            Box::pin(async move { Ok(Some(r)) })
+        });
+
+        let mut org_repo = MockOrganizationRepository::new();
+        org_repo
+            .expect_get_organization_by_id()
+            .returning(move |_| {
+                let o = org.clone();
+                Box::pin(async move { Ok(Some(o)) })
+            });
+
+        (realm_repo, org_repo)
+    }
+
     type TestService = OrganizationServiceImpl<
         MockRealmRepository,
         MockUserRepository,
@@ -536,6 +678,7 @@ mod tests {
         MockOrganizationRepository,
         MockOrganizationAttributeRepository,
         MockOrganizationMemberRepository,
+        MockOrganizationMemberRoleRepository,
     >;
 
     fn build_service(
@@ -545,12 +688,39 @@ mod tests {
         org_repo: MockOrganizationRepository,
         attr_repo: MockOrganizationAttributeRepository,
         member_repo: MockOrganizationMemberRepository,
+    ) -> TestService {
+        build_service_with_member_roles(
+            realm_repo,
+            user_repo,
+            user_role_repo,
+            org_repo,
+            attr_repo,
+            member_repo,
+            MockOrganizationMemberRoleRepository::new(),
+        )
+    }
+
+    fn build_service_with_member_roles(
+        realm_repo: MockRealmRepository,
+        user_repo: MockUserRepository,
+        user_role_repo: MockUserRoleRepository,
+        org_repo: MockOrganizationRepository,
+        attr_repo: MockOrganizationAttributeRepository,
+        member_repo: MockOrganizationMemberRepository,
+        member_role_repo: MockOrganizationMemberRoleRepository,
     ) -> TestService {
         let user_arc = Arc::new(user_repo);
-        let policy = Arc::new(FerriskeyPolicy::new(
+        let user_role_arc = Arc::new(user_role_repo);
+        let member_arc = Arc::new(member_repo);
+        let realm_policy = Arc::new(FerriskeyPolicy::new(
             user_arc.clone(),
             Arc::new(MockClientRepository::new()),
-            Arc::new(user_role_repo),
+            user_role_arc.clone(),
+        ));
+        let policy = Arc::new(OrganizationAdminPolicy::new(
+            realm_policy,
+            member_arc.clone(),
+            Arc::new(member_role_repo),
         ));
 
         OrganizationServiceImpl::new(
@@ -558,7 +728,7 @@ mod tests {
             user_arc,
             Arc::new(org_repo),
             Arc::new(attr_repo),
-            Arc::new(member_repo),
+            member_arc,
             policy,
         )
     }
@@ -654,7 +824,7 @@ mod tests {
             user_role_repo,
             org_repo,
             MockOrganizationAttributeRepository::new(),
-            MockOrganizationMemberRepository::new(),
+            non_member_repo(),
         );
 
         let result = service
@@ -1768,4 +1938,227 @@ mod tests {
             "a viewer must be able to list organizations, got {result:?}"
         );
     }
+
+    // ─── delegated organization administration ──────────────────────────────────
+
+    #[tokio::test]
+    async fn org_admin_can_upsert_attribute_of_own_organization() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let realm = make_realm(realm_id, "test-realm");
+        let user = make_user(&realm);
+        let identity = Identity::User(user.clone());
+        let org = make_organization(realm_id);
+        let org_id = org.id;
+
+        let (realm_repo, org_repo) = realm_and_org_repos(realm_id, org);
+        let (user_role_repo, member_repo, member_role_repo) = delegated_admin_repos(
+            realm_id,
+            org_id,
+            vec![(user.id, OrganizationAdminRole::Admin)],
+        );
+
+        let mut attr_repo = MockOrganizationAttributeRepository::new();
+        attr_repo
+            .expect_upsert_attribute()
+            .return_once(move |organization_id, key, value| {
+                let attribute = OrganizationAttribute::new(organization_id, key, value).unwrap();
+                Box::pin(async move { Ok(attribute) })
+            });
+
+        let service = build_service_with_member_roles(
+            realm_repo,
+            MockUserRepository::new(),
+            user_role_repo,
+            org_repo,
+            attr_repo,
+            member_repo,
+            member_role_repo,
+        );
+
+        let result = service
+            .upsert_attribute(
+                identity,
+                UpsertOrganizationAttributeInput {
+                    realm_name: "test-realm".to_string(),
+                    organization_id: org_id,
+                    key: "cost-center".to_string(),
+                    value: "42".to_string(),
+                },
+            )
+            .await;
+
+        assert!(
+            result.is_ok(),
+            "org admin must manage attributes, got {result:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn org_admin_cannot_manage_another_organization() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let realm = make_realm(realm_id, "test-realm");
+        let user = make_user(&realm);
+        let identity = Identity::User(user.clone());
+        let own_org = make_organization(realm_id);
+        let other_org = make_organization(realm_id);
+        let other_org_id = other_org.id;
+
+        let (realm_repo, org_repo) = realm_and_org_repos(realm_id, other_org);
+        let (user_role_repo, member_repo, member_role_repo) = delegated_admin_repos(
+            realm_id,
+            own_org.id,
+            vec![(user.id, OrganizationAdminRole::Owner)],
+        );
+
+        let service = build_service_with_member_roles(
+            realm_repo,
+            MockUserRepository::new(),
+            user_role_repo,
+            org_repo,
+            MockOrganizationAttributeRepository::new(),
+            member_repo,
+            member_role_repo,
+        );
+
+        let result = service
+            .get_organization(
+                identity,
+                GetOrganizationInput {
+                    realm_name: "test-realm".to_string(),
+                    organization_id: other_org_id,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn member_manager_cannot_update_organization() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let realm = make_realm(realm_id, "test-realm");
+        let user = make_user(&realm);
+        let identity = Identity::User(user.clone());
+        let org = make_organization(realm_id);
+        let org_id = org.id;
+
+        let (realm_repo, org_repo) = realm_and_org_repos(realm_id, org);
+        let (user_role_repo, member_repo, member_role_repo) = delegated_admin_repos(
+            realm_id,
+            org_id,
+            vec![(user.id, OrganizationAdminRole::MemberManager)],
+        );
+
+        let service = build_service_with_member_roles(
+            realm_repo,
+            MockUserRepository::new(),
+            user_role_repo,
+            org_repo,
+            MockOrganizationAttributeRepository::new(),
+            member_repo,
+            member_role_repo,
+        );
+
+        let result = service
+            .delete_attribute(
+                identity,
+                DeleteOrganizationAttributeInput {
+                    realm_name: "test-realm".to_string(),
+                    organization_id: org_id,
+                    key: "cost-center".to_string(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn org_admin_cannot_change_organization_status() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let realm = make_realm(realm_id, "test-realm");
+        let user = make_user(&realm);
+        let identity = Identity::User(user.clone());
+        let org = make_organization(realm_id);
+        let org_id = org.id;
+
+        let (realm_repo, org_repo) = realm_and_org_repos(realm_id, org);
+        let (user_role_repo, member_repo, member_role_repo) = delegated_admin_repos(
+            realm_id,
+            org_id,
+            vec![(user.id, OrganizationAdminRole::Owner)],
+        );
+
+        let service = build_service_with_member_roles(
+            realm_repo,
+            MockUserRepository::new(),
+            user_role_repo,
+            org_repo,
+            MockOrganizationAttributeRepository::new(),
+            member_repo,
+            member_role_repo,
+        );
+
+        let result = service
+            .update_organization(
+                identity,
+                UpdateOrganizationInput {
+                    realm_name: "test-realm".to_string(),
+                    organization_id: org_id,
+                    name: None,
+                    alias: None,
+                    domain: None,
+                    redirect_url: None,
+                    description: None,
+                    enabled: Some(false),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn member_manager_cannot_remove_organization_owner() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let realm = make_realm(realm_id, "test-realm");
+        let user = make_user(&realm);
+        let identity = Identity::User(user.clone());
+        let owner_id = Uuid::new_v4();
+        let org = make_organization(realm_id);
+        let org_id = org.id;
+
+        let (realm_repo, org_repo) = realm_and_org_repos(realm_id, org);
+        let (user_role_repo, member_repo, member_role_repo) = delegated_admin_repos(
+            realm_id,
+            org_id,
+            vec![
+                (user.id, OrganizationAdminRole::MemberManager),
+                (owner_id, OrganizationAdminRole::Owner),
+            ],
+        );
+
+        let service = build_service_with_member_roles(
+            realm_repo,
+            MockUserRepository::new(),
+            user_role_repo,
+            org_repo,
+            MockOrganizationAttributeRepository::new(),
+            member_repo,
+            member_role_repo,
+        );
+
+        let result = service
+            .remove_member(
+                identity,
+                RemoveOrganizationMemberInput {
+                    realm_name: "test-realm".to_string(),
+                    organization_id: org_id,
+                    user_id: owner_id,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
 }
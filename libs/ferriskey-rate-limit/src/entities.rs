@@ -0,0 +1,503 @@
+use std::fmt::Display;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use ferriskey_domain::{generate_timestamp, realm::RealmId};
+use ipnet::IpNet;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Upper bound for `block_seconds`, so a mistyped policy cannot lock a key out for days.
+pub const MAX_BLOCK_SECONDS: u32 = 86_400;
+/// Upper bound for `window_seconds`.
+pub const MAX_WINDOW_SECONDS: u32 = 86_400;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum RateLimitValidationError {
+    #[error("limit must be at least 1")]
+    InvalidLimit,
+    #[error("window must be between 1 second and 1 day")]
+    InvalidWindow,
+    #[error("block duration must be at most 1 day")]
+    BlockTooLong,
+    #[error("invalid IP address or CIDR range")]
+    InvalidCidr,
+    #[error("unknown rate limited endpoint")]
+    UnknownEndpoint,
+    #[error("unknown rate limit key")]
+    UnknownKeyKind,
+    #[error("unknown list kind")]
+    UnknownListKind,
+}
+
+/// The unauthenticated endpoints a realm can throttle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitEndpoint {
+    /// `POST /protocol/openid-connect/token`
+    Token,
+    /// `POST /login-actions/authenticate`
+    Authenticate,
+    /// `POST /login-actions/forgot-password`
+    ForgotPassword,
+    /// `POST /login-actions/send-magic-link`
+    MagicLink,
+    /// `POST /device/verify`
+    DeviceVerify,
+}
+
+impl RateLimitEndpoint {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateLimitEndpoint::Token => "token",
+            RateLimitEndpoint::Authenticate => "authenticate",
+            RateLimitEndpoint::ForgotPassword => "forgot_password",
+            RateLimitEndpoint::MagicLink => "magic_link",
+            RateLimitEndpoint::DeviceVerify => "device_verify",
+        }
+    }
+}
+
+impl Display for RateLimitEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RateLimitEndpoint {
+    type Err = RateLimitValidationError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "token" => Ok(RateLimitEndpoint::Token),
+            "authenticate" => Ok(RateLimitEndpoint::Authenticate),
+            "forgot_password" => Ok(RateLimitEndpoint::ForgotPassword),
+            "magic_link" => Ok(RateLimitEndpoint::MagicLink),
+            "device_verify" => Ok(RateLimitEndpoint::DeviceVerify),
+            _ => Err(RateLimitValidationError::UnknownEndpoint),
+        }
+    }
+}
+
+/// What a rate limit bucket is keyed by, on top of the endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitKeyKind {
+    Ip,
+    /// The submitted login name, or the email address on forgot-password and magic link.
+    Username,
+    ClientId,
+}
+
+impl RateLimitKeyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateLimitKeyKind::Ip => "ip",
+            RateLimitKeyKind::Username => "username",
+            RateLimitKeyKind::ClientId => "client_id",
+        }
+    }
+}
+
+impl Display for RateLimitKeyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RateLimitKeyKind {
+    type Err = RateLimitValidationError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ip" => Ok(RateLimitKeyKind::Ip),
+            "username" => Ok(RateLimitKeyKind::Username),
+            "client_id" => Ok(RateLimitKeyKind::ClientId),
+            _ => Err(RateLimitValidationError::UnknownKeyKind),
+        }
+    }
+}
+
+/// The budget of one endpoint for one key kind: `limit` requests per `window_seconds`,
+/// refilled continuously. Once exhausted the key is blocked for `block_seconds` (when
+/// non-zero) before the budget refills.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RateLimitRule {
+    pub endpoint: RateLimitEndpoint,
+    pub key_kind: RateLimitKeyKind,
+    pub limit: u32,
+    pub window_seconds: u32,
+    pub block_seconds: u32,
+    /// Count hits in Postgres so every replica shares the budget, instead of in memory.
+    pub shared: bool,
+    pub enabled: bool,
+}
+
+impl RateLimitRule {
+    const fn default_for(
+        endpoint: RateLimitEndpoint,
+        key_kind: RateLimitKeyKind,
+        limit: u32,
+        window_seconds: u32,
+        block_seconds: u32,
+    ) -> Self {
+        Self {
+            endpoint,
+            key_kind,
+            limit,
+            window_seconds,
+            block_seconds,
+            shared: false,
+            enabled: true,
+        }
+    }
+
+    /// Tokens regained per second.
+    pub fn refill_rate(&self) -> f64 {
+        f64::from(self.limit) / f64::from(self.window_seconds.max(1))
+    }
+
+    pub fn validate(&self) -> Result<(), RateLimitValidationError> {
+        if self.limit == 0 {
+            return Err(RateLimitValidationError::InvalidLimit);
+        }
+        if self.window_seconds == 0 || self.window_seconds > MAX_WINDOW_SECONDS {
+            return Err(RateLimitValidationError::InvalidWindow);
+        }
+        if self.block_seconds > MAX_BLOCK_SECONDS {
+            return Err(RateLimitValidationError::BlockTooLong);
+        }
+
+        Ok(())
+    }
+}
+
+/// Limits applied to every realm until it configures its own policy for the same endpoint
+/// and key kind. Per-username limits are tight and block: they are what stops password
+/// spraying against a single account.
+pub fn default_rate_limit_rules() -> Vec<RateLimitRule> {
+    use RateLimitEndpoint::*;
+    use RateLimitKeyKind::*;
+
+    vec![
+        RateLimitRule::default_for(Token, Ip, 300, 60, 0),
+        RateLimitRule::default_for(Token, ClientId, 1_200, 60, 0),
+        RateLimitRule::default_for(Token, Username, 20, 60, 300),
+        RateLimitRule::default_for(Authenticate, Ip, 60, 60, 0),
+        RateLimitRule::default_for(Authenticate, Username, 10, 60, 300),
+        RateLimitRule::default_for(ForgotPassword, Ip, 10, 300, 0),
+        RateLimitRule::default_for(ForgotPassword, Username, 3, 300, 0),
+        RateLimitRule::default_for(MagicLink, Ip, 10, 300, 0),
+        RateLimitRule::default_for(MagicLink, Username, 3, 300, 0),
+        RateLimitRule::default_for(DeviceVerify, Ip, 20, 60, 300),
+    ]
+}
+
+/// The rules in force for a realm: its configured policies, plus every default whose
+/// endpoint and key kind it has not configured. Disabled policies are kept so that
+/// disabling one also turns the matching default off.
+pub fn effective_rate_limit_rules(policies: &[RateLimitPolicy]) -> Vec<RateLimitRule> {
+    let mut rules: Vec<RateLimitRule> = policies.iter().map(RateLimitPolicy::rule).collect();
+
+    for default in default_rate_limit_rules() {
+        let configured = rules
+            .iter()
+            .any(|rule| rule.endpoint == default.endpoint && rule.key_kind == default.key_kind);
+        if !configured {
+            rules.push(default);
+        }
+    }
+
+    rules
+}
+
+/// A rate limit rule configured by a realm administrator.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RateLimitPolicy {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub endpoint: RateLimitEndpoint,
+    pub key_kind: RateLimitKeyKind,
+    pub limit: u32,
+    pub window_seconds: u32,
+    pub block_seconds: u32,
+    pub shared: bool,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RateLimitPolicy {
+    pub fn new(realm_id: RealmId, rule: RateLimitRule) -> Result<Self, RateLimitValidationError> {
+        rule.validate()?;
+        let (now, timestamp) = generate_timestamp();
+
+        Ok(Self {
+            id: Uuid::new_v7(timestamp),
+            realm_id,
+            endpoint: rule.endpoint,
+            key_kind: rule.key_kind,
+            limit: rule.limit,
+            window_seconds: rule.window_seconds,
+            block_seconds: rule.block_seconds,
+            shared: rule.shared,
+            enabled: rule.enabled,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn rule(&self) -> RateLimitRule {
+        RateLimitRule {
+            endpoint: self.endpoint,
+            key_kind: self.key_kind,
+            limit: self.limit,
+            window_seconds: self.window_seconds,
+            block_seconds: self.block_seconds,
+            shared: self.shared,
+            enabled: self.enabled,
+        }
+    }
+}
+
+/// The configured policies of a realm and the rules actually enforced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RateLimitOverview {
+    pub policies: Vec<RateLimitPolicy>,
+    pub effective: Vec<RateLimitRule>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitListKind {
+    /// Addresses that bypass every rate limit (monitoring, trusted back offices).
+    Allow,
+    /// Addresses rejected on every rate limited endpoint.
+    Deny,
+}
+
+impl RateLimitListKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateLimitListKind::Allow => "allow",
+            RateLimitListKind::Deny => "deny",
+        }
+    }
+}
+
+impl Display for RateLimitListKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RateLimitListKind {
+    type Err = RateLimitValidationError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "allow" => Ok(RateLimitListKind::Allow),
+            "deny" => Ok(RateLimitListKind::Deny),
+            _ => Err(RateLimitValidationError::UnknownListKind),
+        }
+    }
+}
+
+/// An allow or deny list entry: a single address or a CIDR range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RateLimitListEntry {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub kind: RateLimitListKind,
+    /// Normalized CIDR, e.g. `203.0.113.0/24` or `2001:db8::1/128`.
+    pub cidr: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RateLimitListEntry {
+    pub fn new(
+        realm_id: RealmId,
+        kind: RateLimitListKind,
+        cidr: &str,
+        description: Option<String>,
+    ) -> Result<Self, RateLimitValidationError> {
+        let network = parse_cidr(cidr)?;
+        let (now, timestamp) = generate_timestamp();
+
+        Ok(Self {
+            id: Uuid::new_v7(timestamp),
+            realm_id,
+            kind,
+            cidr: network.to_string(),
+            description: description.and_then(|value| {
+                let trimmed = value.trim();
+                (!trimmed.is_empty()).then(|| trimmed.to_string())
+            }),
+            created_at: now,
+        })
+    }
+
+    pub fn network(&self) -> Option<IpNet> {
+        parse_cidr(&self.cidr).ok()
+    }
+}
+
+/// Parses `value` as a CIDR range, accepting a bare address as a single-host range. Host
+/// bits are cleared, so `10.0.0.7/8` is stored as `10.0.0.0/8`.
+pub fn parse_cidr(value: &str) -> Result<IpNet, RateLimitValidationError> {
+    let value = value.trim();
+
+    if let Ok(network) = value.parse::<IpNet>() {
+        return Ok(network.trunc());
+    }
+
+    value
+        .parse::<IpAddr>()
+        .map(IpNet::from)
+        .map_err(|_| RateLimitValidationError::InvalidCidr)
+}
+
+/// What a rate limit check is made of. Missing keys skip the rules keyed by them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitCheck {
+    pub realm_name: String,
+    pub endpoint: RateLimitEndpoint,
+    pub ip: Option<IpAddr>,
+    pub username: Option<String>,
+    pub client_id: Option<String>,
+}
+
+impl RateLimitCheck {
+    pub fn new(realm_name: String, endpoint: RateLimitEndpoint, ip: Option<IpAddr>) -> Self {
+        Self {
+            realm_name,
+            endpoint,
+            ip,
+            username: None,
+            client_id: None,
+        }
+    }
+
+    pub fn with_username(mut self, username: Option<String>) -> Self {
+        self.username = username;
+        self
+    }
+
+    pub fn with_client_id(mut self, client_id: Option<String>) -> Self {
+        self.client_id = client_id;
+        self
+    }
+
+    /// The bucket value for `kind`. Usernames are case-folded so `Alice` and `alice` share a
+    /// budget, as they resolve to the same account.
+    pub fn key_value(&self, kind: RateLimitKeyKind) -> Option<String> {
+        let value = match kind {
+            RateLimitKeyKind::Ip => self.ip.map(|ip| ip.to_string()),
+            RateLimitKeyKind::Username => self.username.as_ref().map(|u| u.trim().to_lowercase()),
+            RateLimitKeyKind::ClientId => self.client_id.clone(),
+        }?;
+
+        (!value.is_empty()).then_some(value)
+    }
+}
+
+// --- Input structs ---
+
+pub struct CreateRateLimitPolicyInput {
+    pub realm_name: String,
+    pub rule: RateLimitRule,
+}
+
+pub struct UpdateRateLimitPolicyInput {
+    pub realm_name: String,
+    pub policy_id: Uuid,
+    pub limit: Option<u32>,
+    pub window_seconds: Option<u32>,
+    pub block_seconds: Option<u32>,
+    pub shared: Option<bool>,
+    pub enabled: Option<bool>,
+}
+
+pub struct CreateRateLimitListEntryInput {
+    pub realm_name: String,
+    pub kind: RateLimitListKind,
+    pub cidr: String,
+    pub description: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bare_addresses_and_ranges_are_normalized() {
+        assert_eq!(
+            parse_cidr("203.0.113.7").unwrap().to_string(),
+            "203.0.113.7/32"
+        );
+        assert_eq!(
+            parse_cidr(" 10.1.2.3/8 ").unwrap().to_string(),
+            "10.0.0.0/8"
+        );
+        assert_eq!(
+            parse_cidr("2001:db8::1").unwrap().to_string(),
+            "2001:db8::1/128"
+        );
+        assert_eq!(
+            parse_cidr("10.0.0.0/33"),
+            Err(RateLimitValidationError::InvalidCidr)
+        );
+        assert_eq!(
+            parse_cidr("example.com"),
+            Err(RateLimitValidationError::InvalidCidr)
+        );
+    }
+
+    #[test]
+    fn configured_policies_replace_the_matching_defaults_only() {
+        let realm_id = RealmId::new(Uuid::new_v4());
+        let disabled = RateLimitPolicy::new(
+            realm_id,
+            RateLimitRule {
+                endpoint: RateLimitEndpoint::Token,
+                key_kind: RateLimitKeyKind::Ip,
+                limit: 5,
+                window_seconds: 60,
+                block_seconds: 0,
+                shared: false,
+                enabled: false,
+            },
+        )
+        .unwrap();
+
+        let rules = effective_rate_limit_rules(&[disabled]);
+
+        let token_ip: Vec<_> = rules
+            .iter()
+            .filter(|r| {
+                r.endpoint == RateLimitEndpoint::Token && r.key_kind == RateLimitKeyKind::Ip
+            })
+            .collect();
+        assert_eq!(token_ip.len(), 1);
+        assert!(!token_ip[0].enabled);
+        assert_eq!(rules.len(), default_rate_limit_rules().len());
+    }
+
+    #[test]
+    fn usernames_share_a_bucket_regardless_of_case() {
+        let check = RateLimitCheck::new("master".into(), RateLimitEndpoint::Authenticate, None)
+            .with_username(Some(" Alice ".into()))
+            .with_client_id(Some(String::new()));
+
+        assert_eq!(
+            check.key_value(RateLimitKeyKind::Username).as_deref(),
+            Some("alice")
+        );
+        assert_eq!(check.key_value(RateLimitKeyKind::ClientId), None);
+        assert_eq!(check.key_value(RateLimitKeyKind::Ip), None);
+    }
+}
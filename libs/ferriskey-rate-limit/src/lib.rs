@@ -0,0 +1,9 @@
+pub mod entities;
+pub mod limiter;
+pub mod ports;
+
+pub use entities::*;
+pub use ports::*;
+
+pub mod policies;
+pub mod services;
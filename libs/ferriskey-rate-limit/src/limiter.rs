@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::entities::RateLimitRule;
+
+/// Above this many tracked keys, idle buckets are dropped before a new one is added.
+pub const DEFAULT_MAX_TRACKED_KEYS: usize = 100_000;
+
+/// Outcome of spending one request from a bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Acquire {
+    Allowed,
+    Limited {
+        retry_after: Duration,
+        /// `true` for the first rejection since the key was last allowed through, so callers
+        /// can record one event per episode instead of one per rejected request.
+        first: bool,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Bucket {
+    tokens: f64,
+    capacity: f64,
+    refill_rate: f64,
+    updated_at: Instant,
+    /// Last time the key was seen; refills alone do not count as use.
+    touched_at: Instant,
+    blocked_until: Option<Instant>,
+    limited: bool,
+}
+
+impl Bucket {
+    fn full(rule: &RateLimitRule, now: Instant) -> Self {
+        Self {
+            tokens: f64::from(rule.limit),
+            capacity: f64::from(rule.limit),
+            refill_rate: rule.refill_rate(),
+            updated_at: now,
+            touched_at: now,
+            blocked_until: None,
+            limited: false,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_rate).min(self.capacity);
+        self.updated_at = now;
+    }
+
+    fn blocked_for(&self, now: Instant) -> Option<Duration> {
+        self.blocked_until
+            .filter(|until| *until > now)
+            .map(|until| until - now)
+    }
+
+    /// A bucket that is full and not blocked behaves exactly like a missing one.
+    fn is_idle(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.blocked_for(now).is_none() && self.tokens >= self.capacity
+    }
+}
+
+/// In-memory token buckets, one per key. Bounded: when the map grows past `max_keys`, idle
+/// buckets are evicted first.
+#[derive(Debug)]
+pub struct LocalRateLimiter {
+    buckets: Mutex<HashMap<String, Bucket>>,
+    max_keys: usize,
+}
+
+impl Default for LocalRateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_TRACKED_KEYS)
+    }
+}
+
+impl LocalRateLimiter {
+    pub fn new(max_keys: usize) -> Self {
+        Self {
+            buckets: Mutex::new(HashMap::new()),
+            max_keys,
+        }
+    }
+
+    /// Spends one request from the bucket of `key`, created full on first use.
+    pub fn acquire(&self, key: &str, rule: &RateLimitRule, now: Instant) -> Acquire {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if !buckets.contains_key(key) {
+            Self::make_room(&mut buckets, self.max_keys, now);
+        }
+        let bucket = buckets
+            .entry(key.to_string())
+            .or_insert_with(|| Bucket::full(rule, now));
+        bucket.touched_at = now;
+
+        if let Some(retry_after) = bucket.blocked_for(now) {
+            return Acquire::Limited {
+                retry_after,
+                first: false,
+            };
+        }
+
+        bucket.refill(now);
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            bucket.limited = false;
+            return Acquire::Allowed;
+        }
+
+        let retry_after = if rule.block_seconds > 0 {
+            let block = Duration::from_secs(u64::from(rule.block_seconds));
+            bucket.blocked_until = Some(now + block);
+            block
+        } else {
+            Duration::from_secs_f64((1.0 - bucket.tokens) / bucket.refill_rate.max(f64::EPSILON))
+        };
+        let first = !bucket.limited;
+        bucket.limited = true;
+
+        Acquire::Limited { retry_after, first }
+    }
+
+    /// Remaining block of `key`, if any.
+    pub fn blocked_for(&self, key: &str, now: Instant) -> Option<Duration> {
+        let buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        buckets.get(key).and_then(|bucket| bucket.blocked_for(now))
+    }
+
+    /// Blocks `key` until `until`, for limits decided outside this process (shared
+    /// counters). Returns `true` if the key was not blocked already.
+    pub fn block(&self, key: &str, rule: &RateLimitRule, until: Instant, now: Instant) -> bool {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if !buckets.contains_key(key) {
+            Self::make_room(&mut buckets, self.max_keys, now);
+        }
+        let bucket = buckets
+            .entry(key.to_string())
+            .or_insert_with(|| Bucket::full(rule, now));
+        bucket.touched_at = now;
+
+        let newly_blocked = bucket.blocked_for(now).is_none();
+        bucket.blocked_until = Some(until);
+        newly_blocked
+    }
+
+    pub fn len(&self) -> usize {
+        self.buckets.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn make_room(buckets: &mut HashMap<String, Bucket>, max_keys: usize, now: Instant) {
+        if buckets.len() < max_keys {
+            return;
+        }
+
+        buckets.retain(|_, bucket| !bucket.is_idle(now));
+
+        // Still full of active keys: drop the ones touched least recently.
+        if buckets.len() >= max_keys {
+            let mut by_age: Vec<(String, Instant)> = buckets
+                .iter()
+                .map(|(key, bucket)| (key.clone(), bucket.touched_at))
+                .collect();
+            by_age.sort_by_key(|(_, touched_at)| *touched_at);
+            let excess = buckets.len() + 1 - max_keys;
+            for (key, _) in by_age.into_iter().take(excess) {
+                buckets.remove(&key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{RateLimitEndpoint, RateLimitKeyKind};
+
+    fn rule(limit: u32, window_seconds: u32, block_seconds: u32) -> RateLimitRule {
+        RateLimitRule {
+            endpoint: RateLimitEndpoint::Token,
+            key_kind: RateLimitKeyKind::Ip,
+            limit,
+            window_seconds,
+            block_seconds,
+            shared: false,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn the_bucket_refills_over_the_window() {
+        let limiter = LocalRateLimiter::default();
+        let rule = rule(2, 10, 0);
+        let start = Instant::now();
+
+        assert_eq!(limiter.acquire("k", &rule, start), Acquire::Allowed);
+        assert_eq!(limiter.acquire("k", &rule, start), Acquire::Allowed);
+        let Acquire::Limited { retry_after, first } = limiter.acquire("k", &rule, start) else {
+            panic!("third request should be limited");
+        };
+        assert!(first);
+        assert_eq!(retry_after.as_secs(), 5);
+
+        assert!(matches!(
+            limiter.acquire("k", &rule, start),
+            Acquire::Limited { first: false, .. }
+        ));
+        assert_eq!(
+            limiter.acquire("k", &rule, start + Duration::from_secs(5)),
+            Acquire::Allowed
+        );
+    }
+
+    #[test]
+    fn an_exhausted_bucket_blocks_the_key_for_the_block_duration() {
+        let limiter = LocalRateLimiter::default();
+        let rule = rule(1, 60, 300);
+        let start = Instant::now();
+
+        assert_eq!(limiter.acquire("k", &rule, start), Acquire::Allowed);
+        assert_eq!(
+            limiter.acquire("k", &rule, start),
+            Acquire::Limited {
+                retry_after: Duration::from_secs(300),
+                first: true
+            }
+        );
+
+        // The budget has refilled long before the block ends.
+        let later = start + Duration::from_secs(120);
+        assert_eq!(
+            limiter.acquire("k", &rule, later),
+            Acquire::Limited {
+                retry_after: Duration::from_secs(180),
+                first: false
+            }
+        );
+        assert_eq!(
+            limiter.acquire("k", &rule, start + Duration::from_secs(301)),
+            Acquire::Allowed
+        );
+    }
+
+    #[test]
+    fn idle_buckets_are_evicted_before_active_ones() {
+        let limiter = LocalRateLimiter::new(2);
+        let rule = rule(1, 60, 0);
+        let start = Instant::now();
+
+        limiter.acquire("spent", &rule, start);
+        let soon = start + Duration::from_millis(500);
+        limiter.block("blocked", &rule, start + Duration::from_secs(60), soon);
+        // Both are active: the least recently touched one goes.
+        limiter.acquire("new", &rule, start + Duration::from_secs(1));
+        assert_eq!(limiter.len(), 2);
+        assert!(limiter.blocked_for("blocked", soon).is_some());
+
+        // A minute later "new" has refilled and the block is over: both are idle.
+        let later = start + Duration::from_secs(62);
+        limiter.block("fresh", &rule, later + Duration::from_secs(60), later);
+        assert!(limiter.blocked_for("fresh", later).is_some());
+        assert_eq!(limiter.len(), 1);
+    }
+}
@@ -0,0 +1,152 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::{Realm, RealmId};
+
+use crate::entities::{
+    CreateRateLimitListEntryInput, CreateRateLimitPolicyInput, RateLimitCheck, RateLimitListEntry,
+    RateLimitOverview, RateLimitPolicy, UpdateRateLimitPolicyInput,
+};
+
+/// Persistence for the rate limit policies configured by realm administrators.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait RateLimitPolicyRepository: Send + Sync {
+    fn list_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Vec<RateLimitPolicy>, CoreError>> + Send;
+
+    fn get_by_id(
+        &self,
+        id: Uuid,
+    ) -> impl Future<Output = Result<Option<RateLimitPolicy>, CoreError>> + Send;
+
+    /// Fails with `CoreError::AlreadyExists` when the realm already has a policy for the same
+    /// endpoint and key kind.
+    fn create(
+        &self,
+        policy: RateLimitPolicy,
+    ) -> impl Future<Output = Result<RateLimitPolicy, CoreError>> + Send;
+
+    fn update(
+        &self,
+        policy: RateLimitPolicy,
+    ) -> impl Future<Output = Result<RateLimitPolicy, CoreError>> + Send;
+
+    fn delete(&self, id: Uuid) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Persistence for allow and deny list entries.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait RateLimitListRepository: Send + Sync {
+    fn list_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Vec<RateLimitListEntry>, CoreError>> + Send;
+
+    fn get_by_id(
+        &self,
+        id: Uuid,
+    ) -> impl Future<Output = Result<Option<RateLimitListEntry>, CoreError>> + Send;
+
+    /// Fails with `CoreError::AlreadyExists` when the same range is already listed.
+    fn create(
+        &self,
+        entry: RateLimitListEntry,
+    ) -> impl Future<Output = Result<RateLimitListEntry, CoreError>> + Send;
+
+    fn delete(&self, id: Uuid) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Fixed-window hit counters shared by every replica, for policies flagged `shared`.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait RateLimitCounterRepository: Send + Sync {
+    /// Records a hit on `key` in the window starting at `window_start` and returns the number
+    /// of hits in that window, this one included.
+    fn increment(
+        &self,
+        key: String,
+        window_start: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u32, CoreError>> + Send;
+
+    /// Deletes the windows that ended before `now`; returns how many were deleted.
+    fn purge_expired(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+}
+
+pub trait RateLimitAccessPolicy: Send + Sync {
+    fn can_view_rate_limits(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_manage_rate_limits(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+pub trait RateLimitService: Send + Sync {
+    /// Spends one request of every rule matching `check`. Fails with
+    /// `CoreError::RateLimited` when a budget is exhausted and `CoreError::Forbidden` when the
+    /// client address is deny-listed.
+    fn check_rate_limit(
+        &self,
+        check: RateLimitCheck,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_rate_limits(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<RateLimitOverview, CoreError>> + Send;
+
+    fn create_rate_limit_policy(
+        &self,
+        identity: Identity,
+        input: CreateRateLimitPolicyInput,
+    ) -> impl Future<Output = Result<RateLimitPolicy, CoreError>> + Send;
+
+    fn update_rate_limit_policy(
+        &self,
+        identity: Identity,
+        input: UpdateRateLimitPolicyInput,
+    ) -> impl Future<Output = Result<RateLimitPolicy, CoreError>> + Send;
+
+    fn delete_rate_limit_policy(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        policy_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_rate_limit_list(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<Vec<RateLimitListEntry>, CoreError>> + Send;
+
+    fn create_rate_limit_list_entry(
+        &self,
+        identity: Identity,
+        input: CreateRateLimitListEntryInput,
+    ) -> impl Future<Output = Result<RateLimitListEntry, CoreError>> + Send;
+
+    fn delete_rate_limit_list_entry(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        entry_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn purge_expired_rate_limit_counters(
+        &self,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+}
@@ -0,0 +1,865 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::client::ports::ClientRepository;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use ferriskey_domain::realm::ports::RealmRepository;
+use ferriskey_domain::realm::{Realm, RealmId};
+use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
+use ferriskey_seawatch::{EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType};
+use ipnet::IpNet;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::limiter::{Acquire, LocalRateLimiter};
+use crate::{
+    CreateRateLimitListEntryInput, CreateRateLimitPolicyInput, RateLimitAccessPolicy,
+    RateLimitCheck, RateLimitCounterRepository, RateLimitEndpoint, RateLimitKeyKind,
+    RateLimitListEntry, RateLimitListKind, RateLimitListRepository, RateLimitOverview,
+    RateLimitPolicy, RateLimitPolicyRepository, RateLimitRule, RateLimitService,
+    UpdateRateLimitPolicyInput, effective_rate_limit_rules,
+};
+
+/// How long a realm's policies and lists are reused before being reloaded. Changes made on
+/// this replica apply immediately; other replicas pick them up within this delay.
+const REALM_LIMITS_TTL: Duration = Duration::from_secs(30);
+
+/// Deny-listed addresses are rejected on every request but recorded at most once a minute.
+fn deny_list_event_rule() -> RateLimitRule {
+    RateLimitRule {
+        endpoint: RateLimitEndpoint::Token,
+        key_kind: RateLimitKeyKind::Ip,
+        limit: 1,
+        window_seconds: 60,
+        block_seconds: 0,
+        shared: false,
+        enabled: true,
+    }
+}
+
+/// The configuration of one realm, as enforced.
+#[derive(Debug)]
+struct RealmRateLimits {
+    rules: Vec<RateLimitRule>,
+    allow: Vec<IpNet>,
+    deny: Vec<IpNet>,
+}
+
+impl RealmRateLimits {
+    fn new(policies: &[RateLimitPolicy], entries: &[RateLimitListEntry]) -> Self {
+        let networks = |kind: RateLimitListKind| {
+            entries
+                .iter()
+                .filter(|entry| entry.kind == kind)
+                .filter_map(RateLimitListEntry::network)
+                .collect()
+        };
+
+        Self {
+            rules: effective_rate_limit_rules(policies),
+            allow: networks(RateLimitListKind::Allow),
+            deny: networks(RateLimitListKind::Deny),
+        }
+    }
+
+    fn allows(&self, ip: IpAddr) -> bool {
+        self.allow.iter().any(|network| network.contains(&ip))
+    }
+
+    fn denies(&self, ip: IpAddr) -> bool {
+        self.deny.iter().any(|network| network.contains(&ip))
+    }
+}
+
+/// Per-process state shared by every clone of the service: the token buckets and the
+/// cached realm configurations.
+#[derive(Debug, Default)]
+pub struct RateLimitState {
+    limiter: LocalRateLimiter,
+    realm_limits: Mutex<HashMap<Uuid, (Instant, Arc<RealmRateLimits>)>>,
+}
+
+impl RateLimitState {
+    fn cached_limits(&self, realm_id: Uuid, now: Instant) -> Option<Arc<RealmRateLimits>> {
+        let limits = self.realm_limits.lock().unwrap_or_else(|e| e.into_inner());
+        limits
+            .get(&realm_id)
+            .filter(|(loaded_at, _)| now.duration_since(*loaded_at) < REALM_LIMITS_TTL)
+            .map(|(_, limits)| limits.clone())
+    }
+
+    fn store_limits(&self, realm_id: Uuid, limits: Arc<RealmRateLimits>, now: Instant) {
+        let mut cached = self.realm_limits.lock().unwrap_or_else(|e| e.into_inner());
+        cached.insert(realm_id, (now, limits));
+    }
+
+    fn forget_limits(&self, realm_id: Uuid) {
+        let mut cached = self.realm_limits.lock().unwrap_or_else(|e| e.into_inner());
+        cached.remove(&realm_id);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RateLimitServiceImpl<R, U, C, UR, RP, RL, RC, SE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RP: RateLimitPolicyRepository,
+    RL: RateLimitListRepository,
+    RC: RateLimitCounterRepository,
+    SE: SecurityEventRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) policy_repository: Arc<RP>,
+    pub(crate) list_repository: Arc<RL>,
+    pub(crate) counter_repository: Arc<RC>,
+    pub(crate) security_event_repository: Arc<SE>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    pub(crate) state: Arc<RateLimitState>,
+}
+
+impl<R, U, C, UR, RP, RL, RC, SE> RateLimitServiceImpl<R, U, C, UR, RP, RL, RC, SE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RP: RateLimitPolicyRepository,
+    RL: RateLimitListRepository,
+    RC: RateLimitCounterRepository,
+    SE: SecurityEventRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        policy_repository: Arc<RP>,
+        list_repository: Arc<RL>,
+        counter_repository: Arc<RC>,
+        security_event_repository: Arc<SE>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            policy_repository,
+            list_repository,
+            counter_repository,
+            security_event_repository,
+            policy,
+            state: Arc::new(RateLimitState::default()),
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    async fn realm_for_view(
+        &self,
+        identity: &Identity,
+        realm_name: &str,
+    ) -> Result<Realm, CoreError> {
+        let realm = self.get_realm(realm_name).await?;
+        ensure_policy(
+            self.policy.can_view_rate_limits(identity, &realm).await,
+            "insufficient permissions to view rate limits",
+        )?;
+        Ok(realm)
+    }
+
+    async fn realm_for_manage(
+        &self,
+        identity: &Identity,
+        realm_name: &str,
+    ) -> Result<Realm, CoreError> {
+        let realm = self.get_realm(realm_name).await?;
+        ensure_policy(
+            self.policy.can_manage_rate_limits(identity, &realm).await,
+            "insufficient permissions to manage rate limits",
+        )?;
+        Ok(realm)
+    }
+
+    async fn get_policy_in_realm(
+        &self,
+        realm: &Realm,
+        policy_id: Uuid,
+    ) -> Result<RateLimitPolicy, CoreError> {
+        self.policy_repository
+            .get_by_id(policy_id)
+            .await?
+            .filter(|policy| policy.realm_id == realm.id)
+            .ok_or(CoreError::NotFound)
+    }
+
+    async fn realm_limits(&self, realm_id: RealmId) -> Result<Arc<RealmRateLimits>, CoreError> {
+        let now = Instant::now();
+        if let Some(limits) = self.state.cached_limits(realm_id.into(), now) {
+            return Ok(limits);
+        }
+
+        let policies = self.policy_repository.list_by_realm(realm_id).await?;
+        let entries = self.list_repository.list_by_realm(realm_id).await?;
+        let limits = Arc::new(RealmRateLimits::new(&policies, &entries));
+        self.state
+            .store_limits(realm_id.into(), limits.clone(), now);
+
+        Ok(limits)
+    }
+
+    /// Counts the hit in the shared fixed window of `rule`. Once over the limit the key is
+    /// blocked locally until the window (or the block) ends, so a flood stops reaching the
+    /// database. Falls back to the local bucket when the counters are unreachable.
+    async fn acquire_shared(&self, key: &str, rule: &RateLimitRule) -> Acquire {
+        let now = Instant::now();
+        if let Some(retry_after) = self.state.limiter.blocked_for(key, now) {
+            return Acquire::Limited {
+                retry_after,
+                first: false,
+            };
+        }
+
+        let wall_clock = Utc::now();
+        let window = i64::from(rule.window_seconds.max(1));
+        let window_start = DateTime::from_timestamp(
+            wall_clock.timestamp() - wall_clock.timestamp().rem_euclid(window),
+            0,
+        )
+        .unwrap_or(wall_clock);
+        let window_end = window_start + chrono::Duration::seconds(window);
+
+        let hits = match self
+            .counter_repository
+            .increment(key.to_string(), window_start, window_end)
+            .await
+        {
+            Ok(hits) => hits,
+            Err(error) => {
+                warn!(error = ?error, "Shared rate limit counters unavailable, using local limits");
+                return self.state.limiter.acquire(key, rule, now);
+            }
+        };
+
+        if hits <= rule.limit {
+            return Acquire::Allowed;
+        }
+
+        let retry_after = if rule.block_seconds > 0 {
+            Duration::from_secs(u64::from(rule.block_seconds))
+        } else {
+            (window_end - wall_clock)
+                .to_std()
+                .unwrap_or_default()
+                .max(Duration::from_secs(1))
+        };
+        let first = self.state.limiter.block(key, rule, now + retry_after, now);
+
+        Acquire::Limited { retry_after, first }
+    }
+
+    async fn record_event(
+        &self,
+        realm: &Realm,
+        event_type: SecurityEventType,
+        ip: Option<IpAddr>,
+        details: serde_json::Value,
+    ) {
+        let event = SecurityEvent::anonymous(realm.id, event_type, EventStatus::Failure)
+            .with_context(ip.map(|ip| ip.to_string()), None, None)
+            .with_details(details);
+
+        if let Err(error) = self.security_event_repository.store_event(event).await {
+            warn!(error = ?error, "Failed to store rate limit security event");
+        }
+    }
+}
+
+impl<R, U, C, UR, RP, RL, RC, SE> RateLimitService
+    for RateLimitServiceImpl<R, U, C, UR, RP, RL, RC, SE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RP: RateLimitPolicyRepository,
+    RL: RateLimitListRepository,
+    RC: RateLimitCounterRepository,
+    SE: SecurityEventRepository,
+{
+    async fn check_rate_limit(&self, check: RateLimitCheck) -> Result<(), CoreError> {
+        // Unknown realms and configuration errors are left to the handler: the limiter must
+        // never be the reason authentication is unavailable.
+        let realm = match self.realm_repository.get_by_name(&check.realm_name).await {
+            Ok(Some(realm)) => realm,
+            Ok(None) => return Ok(()),
+            Err(error) => {
+                warn!(error = ?error, "Failed to load realm for rate limiting");
+                return Ok(());
+            }
+        };
+        let limits = match self.realm_limits(realm.id).await {
+            Ok(limits) => limits,
+            Err(error) => {
+                warn!(error = ?error, realm = %realm.name, "Failed to load rate limits");
+                return Ok(());
+            }
+        };
+
+        if let Some(ip) = check.ip {
+            if limits.allows(ip) {
+                return Ok(());
+            }
+
+            if limits.denies(ip) {
+                let key = format!("{}:deny:{ip}", Uuid::from(realm.id));
+                let outcome =
+                    self.state
+                        .limiter
+                        .acquire(&key, &deny_list_event_rule(), Instant::now());
+                if outcome == Acquire::Allowed {
+                    self.record_event(
+                        &realm,
+                        SecurityEventType::DenyListBlocked,
+                        Some(ip),
+                        serde_json::json!({ "endpoint": check.endpoint }),
+                    )
+                    .await;
+                }
+
+                return Err(CoreError::Forbidden(
+                    "requests from this address are not allowed".to_string(),
+                ));
+            }
+        }
+
+        let rules = limits
+            .rules
+            .iter()
+            .filter(|rule| rule.enabled && rule.endpoint == check.endpoint);
+
+        for rule in rules {
+            let Some(value) = check.key_value(rule.key_kind) else {
+                continue;
+            };
+            let key = format!(
+                "{}:{}:{}:{value}",
+                Uuid::from(realm.id),
+                rule.endpoint,
+                rule.key_kind.as_str()
+            );
+
+            let outcome = if rule.shared {
+                self.acquire_shared(&key, rule).await
+            } else {
+                self.state.limiter.acquire(&key, rule, Instant::now())
+            };
+
+            if let Acquire::Limited { retry_after, first } = outcome {
+                let retry_after_seconds = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+
+                if first {
+                    self.record_event(
+                        &realm,
+                        SecurityEventType::RateLimitExceeded,
+                        check.ip,
+                        serde_json::json!({
+                            "endpoint": rule.endpoint,
+                            "key_kind": rule.key_kind,
+                            "key": value,
+                            "limit": rule.limit,
+                            "window_seconds": rule.window_seconds,
+                            "block_seconds": rule.block_seconds,
+                            "retry_after_seconds": retry_after_seconds,
+                        }),
+                    )
+                    .await;
+                }
+
+                return Err(CoreError::RateLimited {
+                    retry_after_seconds,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_rate_limits(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<RateLimitOverview, CoreError> {
+        let realm = self.realm_for_view(&identity, &realm_name).await?;
+        let policies = self.policy_repository.list_by_realm(realm.id).await?;
+        let effective = effective_rate_limit_rules(&policies);
+
+        Ok(RateLimitOverview {
+            policies,
+            effective,
+        })
+    }
+
+    async fn create_rate_limit_policy(
+        &self,
+        identity: Identity,
+        input: CreateRateLimitPolicyInput,
+    ) -> Result<RateLimitPolicy, CoreError> {
+        let realm = self.realm_for_manage(&identity, &input.realm_name).await?;
+        let policy = RateLimitPolicy::new(realm.id, input.rule).map_err(|_| CoreError::Invalid)?;
+
+        let policy = self.policy_repository.create(policy).await?;
+        self.state.forget_limits(realm.id.into());
+
+        Ok(policy)
+    }
+
+    async fn update_rate_limit_policy(
+        &self,
+        identity: Identity,
+        input: UpdateRateLimitPolicyInput,
+    ) -> Result<RateLimitPolicy, CoreError> {
+        let realm = self.realm_for_manage(&identity, &input.realm_name).await?;
+        let mut policy = self.get_policy_in_realm(&realm, input.policy_id).await?;
+
+        if let Some(limit) = input.limit {
+            policy.limit = limit;
+        }
+        if let Some(window_seconds) = input.window_seconds {
+            policy.window_seconds = window_seconds;
+        }
+        if let Some(block_seconds) = input.block_seconds {
+            policy.block_seconds = block_seconds;
+        }
+        if let Some(shared) = input.shared {
+            policy.shared = shared;
+        }
+        if let Some(enabled) = input.enabled {
+            policy.enabled = enabled;
+        }
+        policy.rule().validate().map_err(|_| CoreError::Invalid)?;
+        policy.updated_at = Utc::now();
+
+        let policy = self.policy_repository.update(policy).await?;
+        self.state.forget_limits(realm.id.into());
+
+        Ok(policy)
+    }
+
+    async fn delete_rate_limit_policy(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        policy_id: Uuid,
+    ) -> Result<(), CoreError> {
+        let realm = self.realm_for_manage(&identity, &realm_name).await?;
+        let policy = self.get_policy_in_realm(&realm, policy_id).await?;
+
+        self.policy_repository.delete(policy.id).await?;
+        self.state.forget_limits(realm.id.into());
+
+        Ok(())
+    }
+
+    async fn get_rate_limit_list(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<RateLimitListEntry>, CoreError> {
+        let realm = self.realm_for_view(&identity, &realm_name).await?;
+        self.list_repository.list_by_realm(realm.id).await
+    }
+
+    async fn create_rate_limit_list_entry(
+        &self,
+        identity: Identity,
+        input: CreateRateLimitListEntryInput,
+    ) -> Result<RateLimitListEntry, CoreError> {
+        let realm = self.realm_for_manage(&identity, &input.realm_name).await?;
+        let entry = RateLimitListEntry::new(realm.id, input.kind, &input.cidr, input.description)
+            .map_err(|_| CoreError::Invalid)?;
+
+        let entry = self.list_repository.create(entry).await?;
+        self.state.forget_limits(realm.id.into());
+
+        Ok(entry)
+    }
+
+    async fn delete_rate_limit_list_entry(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        entry_id: Uuid,
+    ) -> Result<(), CoreError> {
+        let realm = self.realm_for_manage(&identity, &realm_name).await?;
+        let entry = self
+            .list_repository
+            .get_by_id(entry_id)
+            .await?
+            .filter(|entry| entry.realm_id == realm.id)
+            .ok_or(CoreError::NotFound)?;
+
+        self.list_repository.delete(entry.id).await?;
+        self.state.forget_limits(realm.id.into());
+
+        Ok(())
+    }
+
+    async fn purge_expired_rate_limit_counters(&self) -> Result<u64, CoreError> {
+        self.counter_repository.purge_expired(Utc::now()).await
+    }
+}
+
+pub async fn purge_expired_rate_limit_counters_task<S>(service: S, period: Duration)
+where
+    S: RateLimitService,
+{
+    let mut ticker = tokio::time::interval(period);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+        match service.purge_expired_rate_limit_counters().await {
+            Ok(0) => {}
+            Ok(removed) => info!(removed, "Purged expired rate limit counters"),
+            Err(error) => warn!(error = ?error, "Failed to purge expired rate limit counters"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ferriskey_domain::client::ports::MockClientRepository;
+    use ferriskey_domain::realm::ports::MockRealmRepository;
+    use ferriskey_domain::role::entities::Role;
+    use ferriskey_domain::user::entities::User;
+    use ferriskey_domain::user::ports::{MockUserRepository, MockUserRoleRepository};
+    use ferriskey_seawatch::ports::MockSecurityEventRepository;
+
+    use crate::{
+        MockRateLimitCounterRepository, MockRateLimitListRepository, MockRateLimitPolicyRepository,
+    };
+
+    const REALM: &str = "acme";
+
+    type TestService = RateLimitServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockRateLimitPolicyRepository,
+        MockRateLimitListRepository,
+        MockRateLimitCounterRepository,
+        MockSecurityEventRepository,
+    >;
+
+    fn make_realm() -> Realm {
+        Realm {
+            id: RealmId::new(Uuid::new_v4()),
+            name: REALM.to_string(),
+            display_name: None,
+            settings: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn make_user(realm: &Realm) -> User {
+        User {
+            id: Uuid::new_v4(),
+            realm_id: realm.id,
+            client_id: None,
+            username: "admin".to_string(),
+            firstname: None,
+            lastname: None,
+            email: None,
+            email_verified: true,
+            enabled: true,
+            roles: None,
+            realm: Some(realm.clone()),
+            required_actions: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            failed_login_attempts: 0,
+            locked_until: None,
+        }
+    }
+
+    fn realm_repo(realm: &Realm) -> MockRealmRepository {
+        let realm = realm.clone();
+        let mut realm_repo = MockRealmRepository::new();
+        realm_repo.expect_get_by_name().returning(move |_| {
+            let realm = realm.clone();
+            Box::pin(async move { Ok(Some(realm)) })
+        });
+        realm_repo
+    }
+
+    fn user_roles(realm: &Realm, permission: &'static str) -> MockUserRoleRepository {
+        let realm_id = realm.id;
+        let mut user_role_repo = MockUserRoleRepository::new();
+        user_role_repo.expect_get_user_roles().returning(move |_| {
+            let role = Role {
+                id: Uuid::new_v4(),
+                name: "admin".to_string(),
+                description: None,
+                permissions: vec![permission.to_string()],
+                realm_id,
+                client_id: None,
+                client: None,
+                require_mfa: false,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            };
+            Box::pin(async move { Ok(vec![role]) })
+        });
+        user_role_repo
+    }
+
+    fn policies(policies: Vec<RateLimitPolicy>) -> MockRateLimitPolicyRepository {
+        let mut repo = MockRateLimitPolicyRepository::new();
+        repo.expect_list_by_realm().returning(move |_| {
+            let policies = policies.clone();
+            Box::pin(async move { Ok(policies) })
+        });
+        repo
+    }
+
+    fn list(entries: Vec<RateLimitListEntry>) -> MockRateLimitListRepository {
+        let mut repo = MockRateLimitListRepository::new();
+        repo.expect_list_by_realm().returning(move |_| {
+            let entries = entries.clone();
+            Box::pin(async move { Ok(entries) })
+        });
+        repo
+    }
+
+    fn events(expected: usize) -> MockSecurityEventRepository {
+        let mut repo = MockSecurityEventRepository::new();
+        repo.expect_store_event()
+            .times(expected)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        repo
+    }
+
+    fn build_service(
+        realm_repo: MockRealmRepository,
+        user_role_repo: MockUserRoleRepository,
+        policy_repo: MockRateLimitPolicyRepository,
+        list_repo: MockRateLimitListRepository,
+        counter_repo: MockRateLimitCounterRepository,
+        event_repo: MockSecurityEventRepository,
+    ) -> TestService {
+        let policy = Arc::new(FerriskeyPolicy::new(
+            Arc::new(MockUserRepository::new()),
+            Arc::new(MockClientRepository::new()),
+            Arc::new(user_role_repo),
+        ));
+
+        RateLimitServiceImpl::new(
+            Arc::new(realm_repo),
+            Arc::new(policy_repo),
+            Arc::new(list_repo),
+            Arc::new(counter_repo),
+            Arc::new(event_repo),
+            policy,
+        )
+    }
+
+    fn username_policy(realm: &Realm, shared: bool) -> RateLimitPolicy {
+        RateLimitPolicy::new(
+            realm.id,
+            RateLimitRule {
+                endpoint: RateLimitEndpoint::Authenticate,
+                key_kind: RateLimitKeyKind::Username,
+                limit: 2,
+                window_seconds: 60,
+                block_seconds: 120,
+                shared,
+                enabled: true,
+            },
+        )
+        .unwrap()
+    }
+
+    fn login(ip: &str, username: &str) -> RateLimitCheck {
+        RateLimitCheck::new(
+            REALM.to_string(),
+            RateLimitEndpoint::Authenticate,
+            Some(ip.parse().unwrap()),
+        )
+        .with_username(Some(username.to_string()))
+    }
+
+    #[tokio::test]
+    async fn an_exhausted_budget_is_rejected_and_recorded_once() {
+        let realm = make_realm();
+        let service = build_service(
+            realm_repo(&realm),
+            MockUserRoleRepository::new(),
+            policies(vec![username_policy(&realm, false)]),
+            list(vec![]),
+            MockRateLimitCounterRepository::new(),
+            events(1),
+        );
+
+        // Spread over addresses: only the per-username budget applies to all of them.
+        for ip in ["198.51.100.1", "198.51.100.2"] {
+            service.check_rate_limit(login(ip, "alice")).await.unwrap();
+        }
+        for ip in ["198.51.100.3", "198.51.100.4"] {
+            let error = service
+                .check_rate_limit(login(ip, "Alice"))
+                .await
+                .unwrap_err();
+            assert!(matches!(
+                error,
+                CoreError::RateLimited {
+                    retry_after_seconds: 120
+                }
+            ));
+        }
+
+        service
+            .check_rate_limit(login("198.51.100.5", "bob"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn allow_listed_addresses_bypass_limits_and_denied_ones_are_rejected() {
+        let realm = make_realm();
+        let allow = RateLimitListEntry::new(realm.id, RateLimitListKind::Allow, "10.0.0.0/8", None)
+            .unwrap();
+        let deny = RateLimitListEntry::new(realm.id, RateLimitListKind::Deny, "203.0.113.9", None)
+            .unwrap();
+        let service = build_service(
+            realm_repo(&realm),
+            MockUserRoleRepository::new(),
+            policies(vec![username_policy(&realm, false)]),
+            list(vec![allow, deny]),
+            MockRateLimitCounterRepository::new(),
+            events(1),
+        );
+
+        for _ in 0..5 {
+            service
+                .check_rate_limit(login("10.20.30.40", "alice"))
+                .await
+                .unwrap();
+        }
+        for _ in 0..3 {
+            let error = service
+                .check_rate_limit(login("203.0.113.9", "carol"))
+                .await
+                .unwrap_err();
+            assert!(matches!(error, CoreError::Forbidden(_)));
+        }
+    }
+
+    #[tokio::test]
+    async fn shared_policies_count_hits_in_postgres() {
+        let realm = make_realm();
+        let mut counters = MockRateLimitCounterRepository::new();
+        let mut hits = 0;
+        counters
+            .expect_increment()
+            .times(3)
+            .returning(move |key, window_start, expires_at| {
+                assert!(key.ends_with(":authenticate:username:alice"));
+                assert_eq!((expires_at - window_start).num_seconds(), 60);
+                hits += 1;
+                Box::pin(async move { Ok(hits) })
+            });
+
+        let service = build_service(
+            realm_repo(&realm),
+            MockUserRoleRepository::new(),
+            policies(vec![username_policy(&realm, true)]),
+            list(vec![]),
+            counters,
+            events(1),
+        );
+
+        service
+            .check_rate_limit(login("198.51.100.1", "alice"))
+            .await
+            .unwrap();
+        service
+            .check_rate_limit(login("198.51.100.1", "alice"))
+            .await
+            .unwrap();
+        // Over the limit: blocked locally, the fourth attempt does not reach the counters.
+        for _ in 0..2 {
+            assert!(matches!(
+                service
+                    .check_rate_limit(login("198.51.100.1", "alice"))
+                    .await,
+                Err(CoreError::RateLimited { .. })
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn unavailable_configuration_fails_open() {
+        let realm = make_realm();
+        let mut policy_repo = MockRateLimitPolicyRepository::new();
+        policy_repo
+            .expect_list_by_realm()
+            .returning(|_| Box::pin(async { Err(CoreError::InternalServerError) }));
+
+        let service = build_service(
+            realm_repo(&realm),
+            MockUserRoleRepository::new(),
+            policy_repo,
+            MockRateLimitListRepository::new(),
+            MockRateLimitCounterRepository::new(),
+            events(0),
+        );
+
+        for _ in 0..20 {
+            service
+                .check_rate_limit(login("198.51.100.1", "alice"))
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn managing_policies_requires_manage_realm() {
+        let realm = make_realm();
+        let mut policy_repo = MockRateLimitPolicyRepository::new();
+        policy_repo.expect_create().never();
+
+        let service = build_service(
+            realm_repo(&realm),
+            user_roles(&realm, "view_realm"),
+            policy_repo,
+            MockRateLimitListRepository::new(),
+            MockRateLimitCounterRepository::new(),
+            events(0),
+        );
+
+        let result = service
+            .create_rate_limit_policy(
+                Identity::User(make_user(&realm)),
+                CreateRateLimitPolicyInput {
+                    realm_name: REALM.to_string(),
+                    rule: username_policy(&realm, false).rule(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+}
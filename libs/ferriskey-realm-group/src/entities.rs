@@ -0,0 +1,398 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use ferriskey_domain::{generate_timestamp, realm::RealmId, role::entities::Role};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Unique identifier for a realm group.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord, Hash, ToSchema,
+)]
+pub struct RealmGroupId(Uuid);
+
+impl RealmGroupId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Display for RealmGroupId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Uuid> for RealmGroupId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl From<RealmGroupId> for Uuid {
+    fn from(id: RealmGroupId) -> Self {
+        id.0
+    }
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum RealmGroupValidationError {
+    #[error("group name is required")]
+    EmptyName,
+    #[error("group name must be at most 255 characters")]
+    NameTooLong,
+    #[error("group name cannot contain '/'")]
+    InvalidName,
+    #[error("group attribute key is required")]
+    EmptyAttributeKey,
+    #[error("group attribute key must be at most 255 characters")]
+    AttributeKeyTooLong,
+    #[error("group attribute value is required")]
+    EmptyAttributeValue,
+}
+
+/// A group defined at the realm level, independent of any organization. Groups form a tree via
+/// `parent_group_id` (`None` = top-level group). A member of a group is effectively a member of
+/// all its ancestors and inherits every role mapped along the way.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RealmGroup {
+    pub id: RealmGroupId,
+    pub realm_id: RealmId,
+    pub parent_group_id: Option<RealmGroupId>,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct RealmGroupConfig {
+    pub realm_id: RealmId,
+    pub parent_group_id: Option<RealmGroupId>,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl RealmGroup {
+    pub fn new(config: RealmGroupConfig) -> Result<Self, RealmGroupValidationError> {
+        let (now, timestamp) = generate_timestamp();
+
+        Ok(Self {
+            id: RealmGroupId::new(Uuid::new_v7(timestamp)),
+            realm_id: config.realm_id,
+            parent_group_id: config.parent_group_id,
+            name: validate_group_name(config.name)?,
+            description: normalize_optional_text(config.description),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct CreateRealmGroupParams {
+    pub realm_id: RealmId,
+    pub parent_group_id: Option<RealmGroupId>,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct UpdateRealmGroupParams {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// When `Some`, moves the group under a new parent (`Some(None)` promotes to top level).
+    pub parent_group_id: Option<Option<RealmGroupId>>,
+}
+
+/// A user's direct membership in a realm group.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RealmGroupMember {
+    pub id: Uuid,
+    pub group_id: RealmGroupId,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A group member enriched with the user's identity fields, for the admin members list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RealmGroupMemberDetail {
+    pub id: Uuid,
+    pub group_id: RealmGroupId,
+    pub user_id: Uuid,
+    pub username: String,
+    pub email: Option<String>,
+    pub firstname: Option<String>,
+    pub lastname: Option<String>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A page of group members plus the total count (for server-side pagination).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RealmGroupMemberPage {
+    pub data: Vec<RealmGroupMemberDetail>,
+    pub total: i64,
+    pub limit: u32,
+    pub offset: u32,
+}
+
+/// A realm or client role mapped to a group. Members (recursively) inherit the role.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RealmGroupRoleMapping {
+    pub id: Uuid,
+    pub group_id: RealmGroupId,
+    pub role_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A key/value attribute on a group, inherited by members as a user attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RealmGroupAttribute {
+    pub id: Uuid,
+    pub group_id: RealmGroupId,
+    pub key: String,
+    pub value: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RealmGroupAttribute {
+    pub fn new(
+        group_id: RealmGroupId,
+        key: String,
+        value: String,
+    ) -> Result<Self, RealmGroupValidationError> {
+        let (now, timestamp) = generate_timestamp();
+        Ok(Self {
+            id: Uuid::new_v7(timestamp),
+            group_id,
+            key: validate_attribute_key(key)?,
+            value: validate_attribute_value(value)?,
+            created_at: now,
+        })
+    }
+}
+
+/// A node in the group tree, used for hierarchical responses.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RealmGroupNode {
+    #[serde(flatten)]
+    pub group: RealmGroup,
+    /// Breaks the self-referential schema so utoipa's OpenAPI generation doesn't recurse forever.
+    #[schema(no_recursion)]
+    pub children: Vec<RealmGroupNode>,
+}
+
+/// A group the user effectively belongs to, with its full path from the root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct EffectiveRealmGroup {
+    #[serde(flatten)]
+    pub group: RealmGroup,
+    /// Full path from the root, e.g. `/engineering/backend`.
+    pub path: String,
+    /// `true` for a direct membership, `false` when present only as an ancestor of one.
+    pub direct: bool,
+}
+
+/// What a user gets from realm groups: every group in their effective membership, the roles
+/// inherited through them and the attributes those groups contribute.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RealmGroupMembership {
+    pub user_id: Uuid,
+    pub groups: Vec<EffectiveRealmGroup>,
+    pub inherited_roles: Vec<Role>,
+    pub attributes: BTreeMap<String, String>,
+}
+
+/// Attach full `/parent/child` paths to a flat list of the user's effective groups (which
+/// already includes every ancestor). `direct_ids` marks the direct memberships.
+pub fn effective_realm_groups(
+    groups: Vec<RealmGroup>,
+    direct_ids: &HashSet<Uuid>,
+) -> Vec<EffectiveRealmGroup> {
+    let by_id: HashMap<RealmGroupId, &RealmGroup> = groups.iter().map(|g| (g.id, g)).collect();
+
+    let mut effective: Vec<EffectiveRealmGroup> = groups
+        .iter()
+        .map(|group| {
+            let mut names = vec![group.name.as_str()];
+            let mut cursor = group.parent_group_id;
+            while let Some(parent_id) = cursor {
+                match by_id.get(&parent_id) {
+                    Some(parent) => {
+                        names.push(parent.name.as_str());
+                        cursor = parent.parent_group_id;
+                    }
+                    None => break,
+                }
+            }
+            names.reverse();
+
+            EffectiveRealmGroup {
+                group: group.clone(),
+                path: format!("/{}", names.join("/")),
+                direct: direct_ids.contains(&group.id.as_uuid()),
+            }
+        })
+        .collect();
+
+    effective.sort_by(|a, b| a.path.cmp(&b.path));
+    effective
+}
+
+// --- Input structs ---
+
+pub struct CreateRealmGroupInput {
+    pub realm_name: String,
+    pub parent_group_id: Option<RealmGroupId>,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+pub struct UpdateRealmGroupInput {
+    pub realm_name: String,
+    pub group_id: RealmGroupId,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub parent_group_id: Option<Option<RealmGroupId>>,
+}
+
+pub struct RealmGroupMemberInput {
+    pub realm_name: String,
+    pub group_id: RealmGroupId,
+    pub user_id: Uuid,
+}
+
+pub struct ListRealmGroupMembersInput {
+    pub realm_name: String,
+    pub group_id: RealmGroupId,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    /// Case-insensitive filter on username/email.
+    pub search: Option<String>,
+}
+
+pub struct RealmGroupRoleInput {
+    pub realm_name: String,
+    pub group_id: RealmGroupId,
+    pub role_id: Uuid,
+}
+
+pub struct UpsertRealmGroupAttributeInput {
+    pub realm_name: String,
+    pub group_id: RealmGroupId,
+    pub key: String,
+    pub value: String,
+}
+
+pub struct DeleteRealmGroupAttributeInput {
+    pub realm_name: String,
+    pub group_id: RealmGroupId,
+    pub key: String,
+}
+
+// ============================================================================
+// Validation helpers
+// ============================================================================
+
+/// Names are joined with `/` into paths, so the separator itself is not allowed.
+pub(crate) fn validate_group_name(name: String) -> Result<String, RealmGroupValidationError> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err(RealmGroupValidationError::EmptyName);
+    }
+    if trimmed.chars().count() > 255 {
+        return Err(RealmGroupValidationError::NameTooLong);
+    }
+    if trimmed.contains('/') {
+        return Err(RealmGroupValidationError::InvalidName);
+    }
+
+    Ok(trimmed.to_string())
+}
+
+fn normalize_optional_text(value: Option<String>) -> Option<String> {
+    value.and_then(|value| {
+        let trimmed = value.trim();
+        (!trimmed.is_empty()).then(|| trimmed.to_string())
+    })
+}
+
+fn validate_attribute_key(key: String) -> Result<String, RealmGroupValidationError> {
+    let trimmed = key.trim();
+    if trimmed.is_empty() {
+        return Err(RealmGroupValidationError::EmptyAttributeKey);
+    }
+    if trimmed.chars().count() > 255 {
+        return Err(RealmGroupValidationError::AttributeKeyTooLong);
+    }
+
+    Ok(trimmed.to_string())
+}
+
+fn validate_attribute_value(value: String) -> Result<String, RealmGroupValidationError> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Err(RealmGroupValidationError::EmptyAttributeValue);
+    }
+
+    Ok(trimmed.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(name: &str, parent: Option<RealmGroupId>) -> RealmGroup {
+        RealmGroup::new(RealmGroupConfig {
+            realm_id: RealmId::new(Uuid::new_v4()),
+            parent_group_id: parent,
+            name: name.to_string(),
+            description: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn group_name_is_trimmed_and_rejects_path_separator() {
+        assert_eq!(group("  staff ", None).name, "staff");
+
+        let err = RealmGroup::new(RealmGroupConfig {
+            realm_id: RealmId::new(Uuid::new_v4()),
+            parent_group_id: None,
+            name: "a/b".to_string(),
+            description: None,
+        })
+        .unwrap_err();
+        assert_eq!(err, RealmGroupValidationError::InvalidName);
+    }
+
+    #[test]
+    fn effective_groups_carry_full_paths_and_direct_flag() {
+        let root = group("staff", None);
+        let child = group("engineering", Some(root.id));
+        let leaf = group("backend", Some(child.id));
+        let direct = HashSet::from([leaf.id.as_uuid()]);
+
+        let effective = effective_realm_groups(vec![leaf.clone(), root, child], &direct);
+
+        let paths: Vec<(&str, bool)> = effective
+            .iter()
+            .map(|g| (g.path.as_str(), g.direct))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("/staff", false),
+                ("/staff/engineering", false),
+                ("/staff/engineering/backend", true),
+            ]
+        );
+    }
+}
@@ -0,0 +1,8 @@
+pub mod entities;
+pub mod ports;
+
+pub use entities::*;
+pub use ports::*;
+
+pub mod policies;
+pub mod services;
@@ -0,0 +1,273 @@
+use uuid::Uuid;
+
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::{Realm, RealmId};
+use ferriskey_domain::role::entities::Role;
+
+use crate::entities::{
+    CreateRealmGroupInput, CreateRealmGroupParams, DeleteRealmGroupAttributeInput,
+    ListRealmGroupMembersInput, RealmGroup, RealmGroupAttribute, RealmGroupId, RealmGroupMember,
+    RealmGroupMemberDetail, RealmGroupMemberInput, RealmGroupMemberPage, RealmGroupMembership,
+    RealmGroupNode, RealmGroupRoleInput, RealmGroupRoleMapping, UpdateRealmGroupInput,
+    UpdateRealmGroupParams, UpsertRealmGroupAttributeInput,
+};
+
+/// Persistence for realm groups (the tree nodes themselves).
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait RealmGroupRepository: Send + Sync {
+    fn create_group(
+        &self,
+        params: CreateRealmGroupParams,
+    ) -> impl Future<Output = Result<RealmGroup, CoreError>> + Send;
+
+    fn get_group_by_id(
+        &self,
+        id: RealmGroupId,
+    ) -> impl Future<Output = Result<Option<RealmGroup>, CoreError>> + Send;
+
+    /// Flat list of all groups in a realm (tree built in the service layer).
+    fn list_groups_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Vec<RealmGroup>, CoreError>> + Send;
+
+    fn update_group(
+        &self,
+        id: RealmGroupId,
+        params: UpdateRealmGroupParams,
+    ) -> impl Future<Output = Result<RealmGroup, CoreError>> + Send;
+
+    fn delete_group(&self, id: RealmGroupId) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Persistence for direct realm group memberships.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait RealmGroupMemberRepository: Send + Sync {
+    fn add_member(
+        &self,
+        group_id: RealmGroupId,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<RealmGroupMember, CoreError>> + Send;
+
+    fn remove_member(
+        &self,
+        group_id: RealmGroupId,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_member(
+        &self,
+        group_id: RealmGroupId,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Option<RealmGroupMember>, CoreError>> + Send;
+
+    /// Members of a group (enriched with user identity), paginated + optionally filtered.
+    fn list_members(
+        &self,
+        group_id: RealmGroupId,
+        limit: u32,
+        offset: u32,
+        search: Option<String>,
+    ) -> impl Future<Output = Result<Vec<RealmGroupMemberDetail>, CoreError>> + Send;
+
+    /// Total number of members matching `search` (for pagination totals).
+    fn count_members(
+        &self,
+        group_id: RealmGroupId,
+        search: Option<String>,
+    ) -> impl Future<Output = Result<i64, CoreError>> + Send;
+}
+
+/// Persistence for group→role mappings (realm and client roles alike).
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait RealmGroupRoleRepository: Send + Sync {
+    fn assign_role(
+        &self,
+        group_id: RealmGroupId,
+        role_id: Uuid,
+    ) -> impl Future<Output = Result<RealmGroupRoleMapping, CoreError>> + Send;
+
+    fn revoke_role(
+        &self,
+        group_id: RealmGroupId,
+        role_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn list_role_ids(
+        &self,
+        group_id: RealmGroupId,
+    ) -> impl Future<Output = Result<Vec<Uuid>, CoreError>> + Send;
+}
+
+/// Persistence for group attributes.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait RealmGroupAttributeRepository: Send + Sync {
+    fn list_attributes(
+        &self,
+        group_id: RealmGroupId,
+    ) -> impl Future<Output = Result<Vec<RealmGroupAttribute>, CoreError>> + Send;
+
+    fn upsert_attribute(
+        &self,
+        group_id: RealmGroupId,
+        key: String,
+        value: String,
+    ) -> impl Future<Output = Result<RealmGroupAttribute, CoreError>> + Send;
+
+    fn delete_attribute(
+        &self,
+        group_id: RealmGroupId,
+        key: &str,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Read model resolving a user's *effective* (recursive) realm group membership, powering token
+/// issuance and the effective-membership view. Implemented with recursive CTEs.
+///
+/// Inherited roles are deliberately absent: `UserRoleRepository::get_user_roles` already folds
+/// them in, so permission checks and tokens see them without knowing about groups.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait RealmGroupMembershipRepository: Send + Sync {
+    /// The groups a user belongs to plus all their ancestors (deduplicated).
+    fn list_effective_groups_for_user(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<RealmGroup>, CoreError>> + Send;
+
+    /// Ids of the groups the user is a *direct* member of (no ancestor expansion).
+    fn list_direct_group_ids_for_user(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<Uuid>, CoreError>> + Send;
+
+    /// Distinct role ids mapped to the user's effective groups.
+    fn list_inherited_role_ids_for_user(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<Uuid>, CoreError>> + Send;
+
+    /// One value per attribute key across the user's effective groups. When several groups set
+    /// the same key, the group closest to the user's direct membership wins.
+    fn list_inherited_attributes_for_user(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<(String, String)>, CoreError>> + Send;
+}
+
+pub trait RealmGroupPolicy: Send + Sync {
+    fn can_view_realm_groups(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_manage_realm_groups(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+/// Service trait for realm group business logic.
+pub trait RealmGroupService: Send + Sync {
+    fn create_group(
+        &self,
+        identity: Identity,
+        input: CreateRealmGroupInput,
+    ) -> impl Future<Output = Result<RealmGroup, CoreError>> + Send;
+
+    fn get_group(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> impl Future<Output = Result<RealmGroup, CoreError>> + Send;
+
+    /// All groups in the realm, as a tree.
+    fn list_groups(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<Vec<RealmGroupNode>, CoreError>> + Send;
+
+    fn update_group(
+        &self,
+        identity: Identity,
+        input: UpdateRealmGroupInput,
+    ) -> impl Future<Output = Result<RealmGroup, CoreError>> + Send;
+
+    /// Deletes the group and its whole subtree.
+    fn delete_group(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn add_member(
+        &self,
+        identity: Identity,
+        input: RealmGroupMemberInput,
+    ) -> impl Future<Output = Result<RealmGroupMember, CoreError>> + Send;
+
+    fn remove_member(
+        &self,
+        identity: Identity,
+        input: RealmGroupMemberInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn list_members(
+        &self,
+        identity: Identity,
+        input: ListRealmGroupMembersInput,
+    ) -> impl Future<Output = Result<RealmGroupMemberPage, CoreError>> + Send;
+
+    fn assign_role(
+        &self,
+        identity: Identity,
+        input: RealmGroupRoleInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn revoke_role(
+        &self,
+        identity: Identity,
+        input: RealmGroupRoleInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Roles mapped directly to the group (resolved to full `Role`s).
+    fn list_roles(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+
+    fn list_attributes(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> impl Future<Output = Result<Vec<RealmGroupAttribute>, CoreError>> + Send;
+
+    fn upsert_attribute(
+        &self,
+        identity: Identity,
+        input: UpsertRealmGroupAttributeInput,
+    ) -> impl Future<Output = Result<RealmGroupAttribute, CoreError>> + Send;
+
+    fn delete_attribute(
+        &self,
+        identity: Identity,
+        input: DeleteRealmGroupAttributeInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// The effective-membership view of a user: groups (direct and inherited), roles inherited
+    /// through them and the attributes they contribute.
+    fn get_user_membership(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<RealmGroupMembership, CoreError>> + Send;
+}
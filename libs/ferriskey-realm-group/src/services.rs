@@ -0,0 +1,944 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::client::ports::ClientRepository;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use ferriskey_domain::realm::Realm;
+use ferriskey_domain::realm::ports::RealmRepository;
+use ferriskey_domain::role::entities::Role;
+use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
+use uuid::Uuid;
+
+use crate::{
+    CreateRealmGroupInput, CreateRealmGroupParams, DeleteRealmGroupAttributeInput,
+    ListRealmGroupMembersInput, RealmGroup, RealmGroupAttribute, RealmGroupAttributeRepository,
+    RealmGroupConfig, RealmGroupId, RealmGroupMember, RealmGroupMemberInput, RealmGroupMemberPage,
+    RealmGroupMemberRepository, RealmGroupMembership, RealmGroupMembershipRepository,
+    RealmGroupNode, RealmGroupPolicy, RealmGroupRepository, RealmGroupRoleInput,
+    RealmGroupRoleRepository, RealmGroupService, UpdateRealmGroupInput, UpdateRealmGroupParams,
+    UpsertRealmGroupAttributeInput, effective_realm_groups, entities::validate_group_name,
+};
+
+#[derive(Clone, Debug)]
+pub struct RealmGroupServiceImpl<R, U, C, UR, RG, RGM, RGR, RGA, RGE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RG: RealmGroupRepository,
+    RGM: RealmGroupMemberRepository,
+    RGR: RealmGroupRoleRepository,
+    RGA: RealmGroupAttributeRepository,
+    RGE: RealmGroupMembershipRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) user_repository: Arc<U>,
+    pub(crate) user_role_repository: Arc<UR>,
+    pub(crate) group_repository: Arc<RG>,
+    pub(crate) group_member_repository: Arc<RGM>,
+    pub(crate) group_role_repository: Arc<RGR>,
+    pub(crate) group_attribute_repository: Arc<RGA>,
+    pub(crate) membership_repository: Arc<RGE>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, RG, RGM, RGR, RGA, RGE> RealmGroupServiceImpl<R, U, C, UR, RG, RGM, RGR, RGA, RGE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RG: RealmGroupRepository,
+    RGM: RealmGroupMemberRepository,
+    RGR: RealmGroupRoleRepository,
+    RGA: RealmGroupAttributeRepository,
+    RGE: RealmGroupMembershipRepository,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        realm_repository: Arc<R>,
+        user_repository: Arc<U>,
+        user_role_repository: Arc<UR>,
+        group_repository: Arc<RG>,
+        group_member_repository: Arc<RGM>,
+        group_role_repository: Arc<RGR>,
+        group_attribute_repository: Arc<RGA>,
+        membership_repository: Arc<RGE>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            user_repository,
+            user_role_repository,
+            group_repository,
+            group_member_repository,
+            group_role_repository,
+            group_attribute_repository,
+            membership_repository,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    async fn realm_for_view(
+        &self,
+        identity: &Identity,
+        realm_name: &str,
+    ) -> Result<Realm, CoreError> {
+        let realm = self.get_realm(realm_name).await?;
+        ensure_policy(
+            self.policy.can_view_realm_groups(identity, &realm).await,
+            "insufficient permissions to view groups",
+        )?;
+        Ok(realm)
+    }
+
+    async fn realm_for_manage(
+        &self,
+        identity: &Identity,
+        realm_name: &str,
+    ) -> Result<Realm, CoreError> {
+        let realm = self.get_realm(realm_name).await?;
+        ensure_policy(
+            self.policy.can_manage_realm_groups(identity, &realm).await,
+            "insufficient permissions to manage groups",
+        )?;
+        Ok(realm)
+    }
+
+    /// Load a group and assert it belongs to `realm`.
+    async fn get_group_in_realm(
+        &self,
+        realm: &Realm,
+        group_id: RealmGroupId,
+    ) -> Result<RealmGroup, CoreError> {
+        let group = self
+            .group_repository
+            .get_group_by_id(group_id)
+            .await?
+            .ok_or(CoreError::NotFound)?;
+
+        if group.realm_id != realm.id {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(group)
+    }
+
+    /// Load a role and assert it belongs to `realm`; client roles qualify as long as their
+    /// client lives in the same realm.
+    async fn get_role_in_realm(&self, realm: &Realm, role_id: Uuid) -> Result<Role, CoreError> {
+        let role = self
+            .user_role_repository
+            .get_roles_by_ids(vec![role_id])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(CoreError::NotFound)?;
+
+        if role.realm_id != realm.id {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(role)
+    }
+
+    /// Reject a parent assignment that would create a cycle (parent is the group itself or one
+    /// of its descendants) or that points outside the realm.
+    async fn validate_parent(
+        &self,
+        realm: &Realm,
+        group_id: RealmGroupId,
+        parent_group_id: RealmGroupId,
+    ) -> Result<(), CoreError> {
+        if parent_group_id == group_id {
+            return Err(CoreError::Invalid);
+        }
+
+        let flat = self.group_repository.list_groups_by_realm(realm.id).await?;
+        let parent_map: HashMap<RealmGroupId, Option<RealmGroupId>> =
+            flat.iter().map(|g| (g.id, g.parent_group_id)).collect();
+
+        if !parent_map.contains_key(&parent_group_id) {
+            return Err(CoreError::NotFound);
+        }
+
+        let mut cursor = Some(parent_group_id);
+        while let Some(current) = cursor {
+            if current == group_id {
+                return Err(CoreError::Invalid);
+            }
+            cursor = parent_map.get(&current).copied().flatten();
+        }
+
+        Ok(())
+    }
+}
+
+fn build_tree(flat: Vec<RealmGroup>) -> Vec<RealmGroupNode> {
+    let mut children: HashMap<Option<RealmGroupId>, Vec<RealmGroup>> = HashMap::new();
+    for group in flat {
+        children
+            .entry(group.parent_group_id)
+            .or_default()
+            .push(group);
+    }
+    build_nodes(None, &children)
+}
+
+fn build_nodes(
+    parent: Option<RealmGroupId>,
+    children: &HashMap<Option<RealmGroupId>, Vec<RealmGroup>>,
+) -> Vec<RealmGroupNode> {
+    children
+        .get(&parent)
+        .map(|groups| {
+            groups
+                .iter()
+                .map(|group| RealmGroupNode {
+                    group: group.clone(),
+                    children: build_nodes(Some(group.id), children),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl<R, U, C, UR, RG, RGM, RGR, RGA, RGE> RealmGroupService
+    for RealmGroupServiceImpl<R, U, C, UR, RG, RGM, RGR, RGA, RGE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RG: RealmGroupRepository,
+    RGM: RealmGroupMemberRepository,
+    RGR: RealmGroupRoleRepository,
+    RGA: RealmGroupAttributeRepository,
+    RGE: RealmGroupMembershipRepository,
+{
+    async fn create_group(
+        &self,
+        identity: Identity,
+        input: CreateRealmGroupInput,
+    ) -> Result<RealmGroup, CoreError> {
+        let realm = self.realm_for_manage(&identity, &input.realm_name).await?;
+
+        if let Some(parent_id) = input.parent_group_id {
+            self.get_group_in_realm(&realm, parent_id).await?;
+        }
+
+        let group = RealmGroup::new(RealmGroupConfig {
+            realm_id: realm.id,
+            parent_group_id: input.parent_group_id,
+            name: input.name,
+            description: input.description,
+        })
+        .map_err(|_| CoreError::Invalid)?;
+
+        self.group_repository
+            .create_group(CreateRealmGroupParams {
+                realm_id: group.realm_id,
+                parent_group_id: group.parent_group_id,
+                name: group.name,
+                description: group.description,
+            })
+            .await
+    }
+
+    async fn get_group(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> Result<RealmGroup, CoreError> {
+        let realm = self.realm_for_view(&identity, &realm_name).await?;
+        self.get_group_in_realm(&realm, group_id).await
+    }
+
+    async fn list_groups(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<RealmGroupNode>, CoreError> {
+        let realm = self.realm_for_view(&identity, &realm_name).await?;
+        let flat = self.group_repository.list_groups_by_realm(realm.id).await?;
+
+        Ok(build_tree(flat))
+    }
+
+    async fn update_group(
+        &self,
+        identity: Identity,
+        input: UpdateRealmGroupInput,
+    ) -> Result<RealmGroup, CoreError> {
+        let realm = self.realm_for_manage(&identity, &input.realm_name).await?;
+        self.get_group_in_realm(&realm, input.group_id).await?;
+
+        if let Some(Some(parent_id)) = input.parent_group_id {
+            self.validate_parent(&realm, input.group_id, parent_id)
+                .await?;
+        }
+
+        // Renames go through the same validation as creation.
+        let name = input
+            .name
+            .map(validate_group_name)
+            .transpose()
+            .map_err(|_| CoreError::Invalid)?;
+
+        self.group_repository
+            .update_group(
+                input.group_id,
+                UpdateRealmGroupParams {
+                    name,
+                    description: input.description,
+                    parent_group_id: input.parent_group_id,
+                },
+            )
+            .await
+    }
+
+    async fn delete_group(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> Result<(), CoreError> {
+        let realm = self.realm_for_manage(&identity, &realm_name).await?;
+        self.get_group_in_realm(&realm, group_id).await?;
+
+        self.group_repository.delete_group(group_id).await
+    }
+
+    async fn add_member(
+        &self,
+        identity: Identity,
+        input: RealmGroupMemberInput,
+    ) -> Result<RealmGroupMember, CoreError> {
+        let realm = self.realm_for_manage(&identity, &input.realm_name).await?;
+        self.get_group_in_realm(&realm, input.group_id).await?;
+
+        let user = self.user_repository.get_by_id(input.user_id).await?;
+        if user.realm_id != realm.id {
+            return Err(CoreError::NotFound);
+        }
+
+        if self
+            .group_member_repository
+            .get_member(input.group_id, input.user_id)
+            .await?
+            .is_some()
+        {
+            return Err(CoreError::AlreadyExists);
+        }
+
+        self.group_member_repository
+            .add_member(input.group_id, input.user_id)
+            .await
+    }
+
+    async fn remove_member(
+        &self,
+        identity: Identity,
+        input: RealmGroupMemberInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.realm_for_manage(&identity, &input.realm_name).await?;
+        self.get_group_in_realm(&realm, input.group_id).await?;
+
+        self.group_member_repository
+            .remove_member(input.group_id, input.user_id)
+            .await
+    }
+
+    async fn list_members(
+        &self,
+        identity: Identity,
+        input: ListRealmGroupMembersInput,
+    ) -> Result<RealmGroupMemberPage, CoreError> {
+        let realm = self.realm_for_view(&identity, &input.realm_name).await?;
+        self.get_group_in_realm(&realm, input.group_id).await?;
+
+        // Clamp pagination to sane bounds (default page of 50, hard max of 200).
+        let limit = input.limit.unwrap_or(50).clamp(1, 200);
+        let offset = input.offset.unwrap_or(0);
+
+        let data = self
+            .group_member_repository
+            .list_members(input.group_id, limit, offset, input.search.clone())
+            .await?;
+        let total = self
+            .group_member_repository
+            .count_members(input.group_id, input.search)
+            .await?;
+
+        Ok(RealmGroupMemberPage {
+            data,
+            total,
+            limit,
+            offset,
+        })
+    }
+
+    async fn assign_role(
+        &self,
+        identity: Identity,
+        input: RealmGroupRoleInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.realm_for_manage(&identity, &input.realm_name).await?;
+        self.get_group_in_realm(&realm, input.group_id).await?;
+        self.get_role_in_realm(&realm, input.role_id).await?;
+
+        if self
+            .group_role_repository
+            .list_role_ids(input.group_id)
+            .await?
+            .contains(&input.role_id)
+        {
+            return Err(CoreError::AlreadyExists);
+        }
+
+        self.group_role_repository
+            .assign_role(input.group_id, input.role_id)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revoke_role(
+        &self,
+        identity: Identity,
+        input: RealmGroupRoleInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.realm_for_manage(&identity, &input.realm_name).await?;
+        self.get_group_in_realm(&realm, input.group_id).await?;
+
+        self.group_role_repository
+            .revoke_role(input.group_id, input.role_id)
+            .await
+    }
+
+    async fn list_roles(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> Result<Vec<Role>, CoreError> {
+        let realm = self.realm_for_view(&identity, &realm_name).await?;
+        self.get_group_in_realm(&realm, group_id).await?;
+
+        let role_ids = self.group_role_repository.list_role_ids(group_id).await?;
+        self.user_role_repository.get_roles_by_ids(role_ids).await
+    }
+
+    async fn list_attributes(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        group_id: RealmGroupId,
+    ) -> Result<Vec<RealmGroupAttribute>, CoreError> {
+        let realm = self.realm_for_view(&identity, &realm_name).await?;
+        self.get_group_in_realm(&realm, group_id).await?;
+
+        self.group_attribute_repository
+            .list_attributes(group_id)
+            .await
+    }
+
+    async fn upsert_attribute(
+        &self,
+        identity: Identity,
+        input: UpsertRealmGroupAttributeInput,
+    ) -> Result<RealmGroupAttribute, CoreError> {
+        let realm = self.realm_for_manage(&identity, &input.realm_name).await?;
+        self.get_group_in_realm(&realm, input.group_id).await?;
+
+        let attribute = RealmGroupAttribute::new(input.group_id, input.key, input.value)
+            .map_err(|_| CoreError::Invalid)?;
+
+        self.group_attribute_repository
+            .upsert_attribute(input.group_id, attribute.key, attribute.value)
+            .await
+    }
+
+    async fn delete_attribute(
+        &self,
+        identity: Identity,
+        input: DeleteRealmGroupAttributeInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.realm_for_manage(&identity, &input.realm_name).await?;
+        self.get_group_in_realm(&realm, input.group_id).await?;
+
+        self.group_attribute_repository
+            .delete_attribute(input.group_id, &input.key)
+            .await
+    }
+
+    async fn get_user_membership(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        user_id: Uuid,
+    ) -> Result<RealmGroupMembership, CoreError> {
+        let realm = self.realm_for_view(&identity, &realm_name).await?;
+
+        let user = self.user_repository.get_by_id(user_id).await?;
+        if user.realm_id != realm.id {
+            return Err(CoreError::NotFound);
+        }
+
+        let groups = self
+            .membership_repository
+            .list_effective_groups_for_user(user_id)
+            .await?;
+        let direct_ids: HashSet<Uuid> = self
+            .membership_repository
+            .list_direct_group_ids_for_user(user_id)
+            .await?
+            .into_iter()
+            .collect();
+
+        let role_ids = self
+            .membership_repository
+            .list_inherited_role_ids_for_user(user_id)
+            .await?;
+        let mut inherited_roles = self.user_role_repository.get_roles_by_ids(role_ids).await?;
+        inherited_roles.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let attributes: BTreeMap<String, String> = self
+            .membership_repository
+            .list_inherited_attributes_for_user(user_id)
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(RealmGroupMembership {
+            user_id,
+            groups: effective_realm_groups(groups, &direct_ids),
+            inherited_roles,
+            attributes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use ferriskey_domain::client::ports::MockClientRepository;
+    use ferriskey_domain::common::policies::FerriskeyPolicy;
+    use ferriskey_domain::realm::{RealmId, ports::MockRealmRepository};
+    use ferriskey_domain::user::entities::User;
+    use ferriskey_domain::user::ports::{MockUserRepository, MockUserRoleRepository};
+
+    use crate::{
+        MockRealmGroupAttributeRepository, MockRealmGroupMemberRepository,
+        MockRealmGroupMembershipRepository, MockRealmGroupRepository, MockRealmGroupRoleRepository,
+    };
+
+    use super::*;
+
+    const REALM: &str = "acme";
+
+    type TestService = RealmGroupServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockRealmGroupRepository,
+        MockRealmGroupMemberRepository,
+        MockRealmGroupRoleRepository,
+        MockRealmGroupAttributeRepository,
+        MockRealmGroupMembershipRepository,
+    >;
+
+    fn make_realm(id: RealmId) -> Realm {
+        Realm {
+            id,
+            name: REALM.to_string(),
+            display_name: None,
+            settings: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn make_user(realm: &Realm, username: &str) -> User {
+        User {
+            id: Uuid::new_v4(),
+            realm_id: realm.id,
+            client_id: None,
+            username: username.to_string(),
+            firstname: None,
+            lastname: None,
+            email: None,
+            email_verified: true,
+            enabled: true,
+            roles: None,
+            realm: Some(realm.clone()),
+            required_actions: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            failed_login_attempts: 0,
+            locked_until: None,
+        }
+    }
+
+    fn make_role(realm_id: RealmId, name: &str, permissions: &[&str]) -> Role {
+        Role {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            description: None,
+            permissions: permissions.iter().map(|p| p.to_string()).collect(),
+            realm_id,
+            client_id: None,
+            client: None,
+            require_mfa: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn make_group(realm_id: RealmId, name: &str, parent: Option<RealmGroupId>) -> RealmGroup {
+        RealmGroup {
+            id: RealmGroupId::new(Uuid::new_v4()),
+            realm_id,
+            parent_group_id: parent,
+            name: name.to_string(),
+            description: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// An admin of `realm` holding `permission`, plus the realm lookup.
+    fn actor(
+        realm: &Realm,
+        permission: &'static str,
+    ) -> (Identity, MockRealmRepository, MockUserRoleRepository) {
+        let identity = Identity::User(make_user(realm, "admin"));
+
+        let realm_clone = realm.clone();
+        let mut realm_repo = MockRealmRepository::new();
+        realm_repo.expect_get_by_name().returning(move |_| {
+            let r = realm_clone.clone();
+            Box::pin(async move { Ok(Some(r)) })
+        });
+
+        let realm_id = realm.id;
+        let mut user_role_repo = MockUserRoleRepository::new();
+        user_role_repo.expect_get_user_roles().returning(move |_| {
+            let role = make_role(realm_id, "admin", &[permission]);
+            Box::pin(async move { Ok(vec![role]) })
+        });
+
+        (identity, realm_repo, user_role_repo)
+    }
+
+    fn groups_returning(groups: Vec<RealmGroup>) -> MockRealmGroupRepository {
+        let mut group_repo = MockRealmGroupRepository::new();
+        let by_id = groups.clone();
+        group_repo.expect_get_group_by_id().returning(move |id| {
+            let g = by_id.iter().find(|g| g.id == id).cloned();
+            Box::pin(async move { Ok(g) })
+        });
+        group_repo
+            .expect_list_groups_by_realm()
+            .returning(move |_| {
+                let all = groups.clone();
+                Box::pin(async move { Ok(all) })
+            });
+        group_repo
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_service(
+        realm_repo: MockRealmRepository,
+        user_repo: MockUserRepository,
+        user_role_repo: MockUserRoleRepository,
+        group_repo: MockRealmGroupRepository,
+        group_member_repo: MockRealmGroupMemberRepository,
+        group_role_repo: MockRealmGroupRoleRepository,
+        membership_repo: MockRealmGroupMembershipRepository,
+    ) -> TestService {
+        let user_arc = Arc::new(user_repo);
+        let user_role_arc = Arc::new(user_role_repo);
+        let policy = Arc::new(FerriskeyPolicy::new(
+            user_arc.clone(),
+            Arc::new(MockClientRepository::new()),
+            user_role_arc.clone(),
+        ));
+
+        RealmGroupServiceImpl::new(
+            Arc::new(realm_repo),
+            user_arc,
+            user_role_arc,
+            Arc::new(group_repo),
+            Arc::new(group_member_repo),
+            Arc::new(group_role_repo),
+            Arc::new(MockRealmGroupAttributeRepository::new()),
+            Arc::new(membership_repo),
+            policy,
+        )
+    }
+
+    #[tokio::test]
+    async fn list_groups_builds_the_tree() {
+        let realm = make_realm(RealmId::new(Uuid::new_v4()));
+        let (identity, realm_repo, user_role_repo) = actor(&realm, "query_groups");
+        let root = make_group(realm.id, "staff", None);
+        let child = make_group(realm.id, "engineering", Some(root.id));
+
+        let service = build_service(
+            realm_repo,
+            MockUserRepository::new(),
+            user_role_repo,
+            groups_returning(vec![child.clone(), root.clone()]),
+            MockRealmGroupMemberRepository::new(),
+            MockRealmGroupRoleRepository::new(),
+            MockRealmGroupMembershipRepository::new(),
+        );
+
+        let tree = service
+            .list_groups(identity, REALM.to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].group.id, root.id);
+        assert_eq!(tree[0].children[0].group.id, child.id);
+    }
+
+    #[tokio::test]
+    async fn create_group_requires_manage_permission() {
+        let realm = make_realm(RealmId::new(Uuid::new_v4()));
+        let (identity, realm_repo, user_role_repo) = actor(&realm, "query_groups");
+
+        let mut group_repo = MockRealmGroupRepository::new();
+        group_repo.expect_create_group().never();
+
+        let service = build_service(
+            realm_repo,
+            MockUserRepository::new(),
+            user_role_repo,
+            group_repo,
+            MockRealmGroupMemberRepository::new(),
+            MockRealmGroupRoleRepository::new(),
+            MockRealmGroupMembershipRepository::new(),
+        );
+
+        let result = service
+            .create_group(
+                identity,
+                CreateRealmGroupInput {
+                    realm_name: REALM.to_string(),
+                    parent_group_id: None,
+                    name: "staff".to_string(),
+                    description: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn update_group_rejects_moving_under_a_descendant() {
+        let realm = make_realm(RealmId::new(Uuid::new_v4()));
+        let (identity, realm_repo, user_role_repo) = actor(&realm, "manage_users");
+        let root = make_group(realm.id, "staff", None);
+        let child = make_group(realm.id, "engineering", Some(root.id));
+
+        let mut group_repo = groups_returning(vec![root.clone(), child.clone()]);
+        group_repo.expect_update_group().never();
+
+        let service = build_service(
+            realm_repo,
+            MockUserRepository::new(),
+            user_role_repo,
+            group_repo,
+            MockRealmGroupMemberRepository::new(),
+            MockRealmGroupRoleRepository::new(),
+            MockRealmGroupMembershipRepository::new(),
+        );
+
+        let result = service
+            .update_group(
+                identity,
+                UpdateRealmGroupInput {
+                    realm_name: REALM.to_string(),
+                    group_id: root.id,
+                    name: None,
+                    description: None,
+                    parent_group_id: Some(Some(child.id)),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Invalid)));
+    }
+
+    #[tokio::test]
+    async fn add_member_rejects_user_from_another_realm() {
+        let realm = make_realm(RealmId::new(Uuid::new_v4()));
+        let other_realm = make_realm(RealmId::new(Uuid::new_v4()));
+        let (identity, realm_repo, user_role_repo) = actor(&realm, "manage_users");
+        let group = make_group(realm.id, "staff", None);
+        let outsider = make_user(&other_realm, "outsider");
+        let outsider_id = outsider.id;
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_get_by_id().returning(move |_| {
+            let u = outsider.clone();
+            Box::pin(async move { Ok(u) })
+        });
+        let mut member_repo = MockRealmGroupMemberRepository::new();
+        member_repo.expect_add_member().never();
+
+        let service = build_service(
+            realm_repo,
+            user_repo,
+            user_role_repo,
+            groups_returning(vec![group.clone()]),
+            member_repo,
+            MockRealmGroupRoleRepository::new(),
+            MockRealmGroupMembershipRepository::new(),
+        );
+
+        let result = service
+            .add_member(
+                identity,
+                RealmGroupMemberInput {
+                    realm_name: REALM.to_string(),
+                    group_id: group.id,
+                    user_id: outsider_id,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn assign_role_rejects_role_from_another_realm() {
+        let realm = make_realm(RealmId::new(Uuid::new_v4()));
+        let (identity, realm_repo, mut user_role_repo) = actor(&realm, "manage_users");
+        let group = make_group(realm.id, "staff", None);
+        let foreign_role = make_role(RealmId::new(Uuid::new_v4()), "foreign", &["manage_realm"]);
+        let foreign_role_id = foreign_role.id;
+
+        user_role_repo
+            .expect_get_roles_by_ids()
+            .returning(move |_| {
+                let r = foreign_role.clone();
+                Box::pin(async move { Ok(vec![r]) })
+            });
+        let mut group_role_repo = MockRealmGroupRoleRepository::new();
+        group_role_repo.expect_assign_role().never();
+
+        let service = build_service(
+            realm_repo,
+            MockUserRepository::new(),
+            user_role_repo,
+            groups_returning(vec![group.clone()]),
+            MockRealmGroupMemberRepository::new(),
+            group_role_repo,
+            MockRealmGroupMembershipRepository::new(),
+        );
+
+        let result = service
+            .assign_role(
+                identity,
+                RealmGroupRoleInput {
+                    realm_name: REALM.to_string(),
+                    group_id: group.id,
+                    role_id: foreign_role_id,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn user_membership_reports_paths_roles_and_attributes() {
+        let realm = make_realm(RealmId::new(Uuid::new_v4()));
+        let (identity, realm_repo, mut user_role_repo) = actor(&realm, "view_users");
+        let member = make_user(&realm, "alice");
+        let member_id = member.id;
+        let root = make_group(realm.id, "staff", None);
+        let child = make_group(realm.id, "engineering", Some(root.id));
+        let child_id = child.id.as_uuid();
+        let inherited = make_role(realm.id, "developer", &[]);
+        let inherited_id = inherited.id;
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_get_by_id().returning(move |_| {
+            let u = member.clone();
+            Box::pin(async move { Ok(u) })
+        });
+        user_role_repo
+            .expect_get_roles_by_ids()
+            .withf(move |ids| ids == &vec![inherited_id])
+            .returning(move |_| {
+                let r = inherited.clone();
+                Box::pin(async move { Ok(vec![r]) })
+            });
+
+        let mut membership_repo = MockRealmGroupMembershipRepository::new();
+        membership_repo
+            .expect_list_effective_groups_for_user()
+            .returning(move |_| {
+                let groups = vec![child.clone(), root.clone()];
+                Box::pin(async move { Ok(groups) })
+            });
+        membership_repo
+            .expect_list_direct_group_ids_for_user()
+            .returning(move |_| Box::pin(async move { Ok(vec![child_id]) }));
+        membership_repo
+            .expect_list_inherited_role_ids_for_user()
+            .returning(move |_| Box::pin(async move { Ok(vec![inherited_id]) }));
+        membership_repo
+            .expect_list_inherited_attributes_for_user()
+            .returning(|_| {
+                Box::pin(async { Ok(vec![("department".to_string(), "rnd".to_string())]) })
+            });
+
+        let service = build_service(
+            realm_repo,
+            user_repo,
+            user_role_repo,
+            MockRealmGroupRepository::new(),
+            MockRealmGroupMemberRepository::new(),
+            MockRealmGroupRoleRepository::new(),
+            membership_repo,
+        );
+
+        let membership = service
+            .get_user_membership(identity, REALM.to_string(), member_id)
+            .await
+            .unwrap();
+
+        let paths: Vec<(&str, bool)> = membership
+            .groups
+            .iter()
+            .map(|g| (g.path.as_str(), g.direct))
+            .collect();
+        assert_eq!(paths, vec![("/staff", false), ("/staff/engineering", true)]);
+        assert_eq!(membership.inherited_roles[0].name, "developer");
+        assert_eq!(membership.attributes["department"], "rnd");
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::{BTreeMap, HashMap};
+
+use ferriskey_security::jwt::entities::JwtClaim;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use uuid::Uuid;
+
+/// Roles wrapper used by `realm_access`, `resource_access.<client>` and organization clients.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct RoleList {
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+/// One entry of the `organizations` claim, merged from the membership and organization role
+/// mappers. Only the fields of the enabled mappers are present.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct OrganizationClaim {
+    #[serde(default)]
+    pub id: Option<Uuid>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub alias: Option<String>,
+    #[serde(default)]
+    pub domain: Option<String>,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub clients: HashMap<String, RoleList>,
+}
+
+/// A validated access token. Typed accessors read the claims under the names the default
+/// FerrisKey mappers emit; renamed claims stay reachable through [`Self::claim`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticatedToken {
+    pub claims: JwtClaim,
+}
+
+impl AuthenticatedToken {
+    pub fn new(claims: JwtClaim) -> Self {
+        Self { claims }
+    }
+
+    pub fn subject(&self) -> Uuid {
+        self.claims.sub
+    }
+
+    /// The client the token was issued to.
+    pub fn authorized_party(&self) -> &str {
+        &self.claims.azp
+    }
+
+    /// A mapper claim deserialized into `T`, `None` when absent or of another shape.
+    pub fn claim<T: DeserializeOwned>(&self, name: &str) -> Option<T> {
+        self.claims
+            .additional_claims
+            .get(name)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    pub fn scopes(&self) -> impl Iterator<Item = &str> {
+        self.claims
+            .scope
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+    }
+
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes().any(|granted| granted == scope)
+    }
+
+    pub fn realm_roles(&self) -> Vec<String> {
+        self.claim::<RoleList>("realm_access")
+            .map(|access| access.roles)
+            .unwrap_or_default()
+    }
+
+    pub fn has_realm_role(&self, role: &str) -> bool {
+        self.realm_roles().iter().any(|granted| granted == role)
+    }
+
+    /// Client roles from `resource_access`, keyed by client id.
+    pub fn client_roles(&self) -> HashMap<String, Vec<String>> {
+        self.claim::<HashMap<String, RoleList>>("resource_access")
+            .map(|access| {
+                access
+                    .into_iter()
+                    .map(|(client_id, roles)| (client_id, roles.roles))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn has_client_role(&self, client_id: &str, role: &str) -> bool {
+        self.client_roles()
+            .get(client_id)
+            .is_some_and(|roles| roles.iter().any(|granted| granted == role))
+    }
+
+    /// Organizations keyed by alias.
+    pub fn organizations(&self) -> BTreeMap<String, OrganizationClaim> {
+        self.claim("organizations").unwrap_or_default()
+    }
+
+    pub fn is_member_of(&self, organization_alias: &str) -> bool {
+        self.organizations().contains_key(organization_alias)
+    }
+
+    /// Group paths, prefixed with the organization alias for organization groups.
+    pub fn groups(&self) -> Vec<String> {
+        self.claim("groups").unwrap_or_default()
+    }
+
+    pub fn in_group(&self, group_path: &str) -> bool {
+        self.groups().iter().any(|group| group == group_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ferriskey_security::jwt::entities::ClaimsTyp;
+    use serde_json::json;
+
+    use super::*;
+
+    fn token(additional_claims: serde_json::Value) -> AuthenticatedToken {
+        AuthenticatedToken::new(JwtClaim {
+            sub: Uuid::new_v4(),
+            iat: 0,
+            jti: Uuid::new_v4(),
+            iss: "https://auth.example.com/realms/main".to_string(),
+            typ: ClaimsTyp::Bearer,
+            azp: "web".to_string(),
+            aud: vec!["api".to_string()],
+            scope: Some("openid profile orders:read".to_string()),
+            exp: None,
+            preferred_username: None,
+            email: None,
+            client_id: None,
+            sid: None,
+            additional_claims: serde_json::from_value(additional_claims).unwrap(),
+        })
+    }
+
+    #[test]
+    fn reads_roles_from_the_default_mapper_claims() {
+        let token = token(json!({
+            "realm_access": { "roles": ["admin", "user"] },
+            "resource_access": { "billing": { "roles": ["viewer"] } },
+        }));
+
+        assert!(token.has_realm_role("admin"));
+        assert!(!token.has_realm_role("owner"));
+        assert!(token.has_client_role("billing", "viewer"));
+        assert!(!token.has_client_role("orders", "viewer"));
+        assert!(token.has_scope("orders:read"));
+    }
+
+    #[test]
+    fn organizations_merge_membership_and_role_fields() {
+        let id = Uuid::new_v4();
+        let token = token(json!({
+            "organizations": {
+                "acme": {
+                    "id": id,
+                    "name": "Acme Corp",
+                    "alias": "acme",
+                    "roles": ["org-admin"],
+                    "clients": { "billing": { "roles": ["viewer"] } }
+                }
+            },
+            "groups": ["acme/support", "/staff"],
+        }));
+
+        let organizations = token.organizations();
+        let acme = &organizations["acme"];
+        assert_eq!(acme.id, Some(id));
+        assert_eq!(acme.roles, vec!["org-admin".to_string()]);
+        assert_eq!(acme.clients["billing"].roles, vec!["viewer".to_string()]);
+        assert!(token.is_member_of("acme"));
+        assert!(token.in_group("acme/support"));
+    }
+
+    #[test]
+    fn missing_or_malformed_claims_read_as_empty() {
+        let token = token(json!({ "realm_access": "admin" }));
+
+        assert!(token.realm_roles().is_empty());
+        assert!(token.organizations().is_empty());
+        assert!(token.groups().is_empty());
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+/// Where the realm lives and what a token must carry to be accepted.
+#[derive(Debug, Clone)]
+pub struct ResourceServerConfig {
+    /// The realm issuer, `{base_url}/realms/{realm_name}`. Tokens must carry it as `iss`
+    /// and the JWKS and introspection endpoints are derived from it.
+    pub issuer: String,
+    /// Accepted `aud` values; a token is valid when it carries at least one of them. With
+    /// none, every token is rejected unless `allow_any_audience` is set.
+    pub audiences: Vec<String>,
+    /// Accept tokens issued for any client of the realm. Off by default.
+    pub allow_any_audience: bool,
+    /// Clock skew tolerated on `exp` and `nbf`.
+    pub leeway: Duration,
+    /// Minimum time between two JWKS fetches, so unknown `kid`s cannot flood the realm.
+    pub jwks_refetch_interval: Duration,
+    pub introspection: Option<IntrospectionConfig>,
+}
+
+/// Credentials of the confidential client calling `token/introspect`. Its service
+/// account needs the `introspect` role.
+#[derive(Debug, Clone)]
+pub struct IntrospectionConfig {
+    pub client_id: String,
+    pub client_secret: String,
+    /// How long an introspection answer is reused. It is never kept past the token expiry.
+    pub cache_ttl: Duration,
+}
+
+impl ResourceServerConfig {
+    pub fn new(issuer: impl Into<String>) -> Self {
+        Self {
+            issuer: issuer.into().trim_end_matches('/').to_string(),
+            audiences: Vec::new(),
+            allow_any_audience: false,
+            leeway: Duration::from_secs(30),
+            jwks_refetch_interval: Duration::from_secs(30),
+            introspection: None,
+        }
+    }
+
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.audiences.push(audience.into());
+        self
+    }
+
+    /// Skip the `aud` check, so a token the realm issued for any client is accepted. Only
+    /// for services that authorize every route through roles or scopes.
+    pub fn allow_any_audience(mut self) -> Self {
+        self.allow_any_audience = true;
+        self
+    }
+
+    pub fn with_leeway(mut self, leeway: Duration) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    pub fn with_jwks_refetch_interval(mut self, interval: Duration) -> Self {
+        self.jwks_refetch_interval = interval;
+        self
+    }
+
+    pub fn with_introspection(
+        mut self,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        cache_ttl: Duration,
+    ) -> Self {
+        self.introspection = Some(IntrospectionConfig {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            cache_ttl,
+        });
+        self
+    }
+
+    pub fn jwks_url(&self) -> String {
+        format!("{}/protocol/openid-connect/certs", self.issuer)
+    }
+
+    pub fn introspection_url(&self) -> String {
+        format!("{}/protocol/openid-connect/token/introspect", self.issuer)
+    }
+}
@@ -0,0 +1,86 @@
+use axum::{
+    Json,
+    http::{HeaderValue, StatusCode, header::WWW_AUTHENTICATE},
+    response::{IntoResponse, Response},
+};
+use serde_json::json;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ResourceServerError {
+    #[error("Missing bearer token")]
+    MissingToken,
+
+    #[error("Invalid token: {0}")]
+    InvalidToken(String),
+
+    #[error("Token has been revoked")]
+    RevokedToken,
+
+    #[error("Insufficient permissions: {0}")]
+    Forbidden(String),
+
+    #[error("Signing keys unavailable: {0}")]
+    KeysUnavailable(String),
+
+    #[error("Token introspection failed: {0}")]
+    IntrospectionFailed(String),
+}
+
+impl ResourceServerError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ResourceServerError::MissingToken
+            | ResourceServerError::InvalidToken(_)
+            | ResourceServerError::RevokedToken => StatusCode::UNAUTHORIZED,
+            ResourceServerError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ResourceServerError::KeysUnavailable(_)
+            | ResourceServerError::IntrospectionFailed(_) => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self.status() {
+            StatusCode::UNAUTHORIZED => "E_UNAUTHORIZED",
+            StatusCode::FORBIDDEN => "E_FORBIDDEN",
+            _ => "E_SERVICE_UNAVAILABLE",
+        }
+    }
+
+    /// The RFC 6750 `WWW-Authenticate` challenge for the error, if it has one.
+    fn challenge(&self) -> Option<&'static str> {
+        match self {
+            ResourceServerError::MissingToken => Some("Bearer"),
+            ResourceServerError::InvalidToken(_) | ResourceServerError::RevokedToken => {
+                Some("Bearer error=\"invalid_token\"")
+            }
+            ResourceServerError::Forbidden(_) => Some("Bearer error=\"insufficient_scope\""),
+            _ => None,
+        }
+    }
+}
+
+impl IntoResponse for ResourceServerError {
+    fn into_response(self) -> Response {
+        let status = self.status();
+        let challenge = self.challenge();
+
+        let mut response = (
+            status,
+            Json(json!({
+                "code": self.code(),
+                "status": status.as_u16(),
+                "message": self.to_string(),
+            })),
+        )
+            .into_response();
+
+        if let Some(challenge) = challenge {
+            response
+                .headers_mut()
+                .insert(WWW_AUTHENTICATE, HeaderValue::from_static(challenge));
+        }
+
+        response
+    }
+}
@@ -0,0 +1,29 @@
+use axum::extract::{FromRequestParts, OptionalFromRequestParts};
+use axum::http::request::Parts;
+
+use crate::claims::AuthenticatedToken;
+use crate::error::ResourceServerError;
+
+/// Reads the token authenticated by [`crate::ResourceServerLayer`].
+impl<S: Send + Sync> FromRequestParts<S> for AuthenticatedToken {
+    type Rejection = ResourceServerError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<AuthenticatedToken>()
+            .cloned()
+            .ok_or(ResourceServerError::MissingToken)
+    }
+}
+
+impl<S: Send + Sync> OptionalFromRequestParts<S> for AuthenticatedToken {
+    type Rejection = ResourceServerError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _state: &S,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        Ok(parts.extensions.get::<AuthenticatedToken>().cloned())
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use axum::http::Request;
+use axum::response::{IntoResponse, Response};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::claims::AuthenticatedToken;
+use crate::error::ResourceServerError;
+use crate::layer::BoxFuture;
+
+/// What a validated token must grant to reach a route.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Requirement {
+    Scope(String),
+    RealmRole(String),
+    ClientRole { client_id: String, role: String },
+    Organization(String),
+    Group(String),
+    AnyOf(Vec<Requirement>),
+    AllOf(Vec<Requirement>),
+}
+
+impl Requirement {
+    pub fn scope(scope: impl Into<String>) -> Self {
+        Requirement::Scope(scope.into())
+    }
+
+    pub fn realm_role(role: impl Into<String>) -> Self {
+        Requirement::RealmRole(role.into())
+    }
+
+    pub fn client_role(client_id: impl Into<String>, role: impl Into<String>) -> Self {
+        Requirement::ClientRole {
+            client_id: client_id.into(),
+            role: role.into(),
+        }
+    }
+
+    pub fn organization(alias: impl Into<String>) -> Self {
+        Requirement::Organization(alias.into())
+    }
+
+    pub fn group(path: impl Into<String>) -> Self {
+        Requirement::Group(path.into())
+    }
+
+    pub fn is_met_by(&self, token: &AuthenticatedToken) -> bool {
+        match self {
+            Requirement::Scope(scope) => token.has_scope(scope),
+            Requirement::RealmRole(role) => token.has_realm_role(role),
+            Requirement::ClientRole { client_id, role } => token.has_client_role(client_id, role),
+            Requirement::Organization(alias) => token.is_member_of(alias),
+            Requirement::Group(path) => token.in_group(path),
+            Requirement::AnyOf(requirements) => requirements.iter().any(|r| r.is_met_by(token)),
+            Requirement::AllOf(requirements) => requirements.iter().all(|r| r.is_met_by(token)),
+        }
+    }
+
+    /// A layer rejecting requests whose token does not meet the requirement with `403`.
+    /// It must run inside a [`crate::ResourceServerLayer`].
+    pub fn layer(self) -> RequireLayer {
+        RequireLayer {
+            requirement: Arc::new(self),
+        }
+    }
+}
+
+impl AuthenticatedToken {
+    /// Guard for handlers: `token.require(&Requirement::realm_role("admin"))?`.
+    pub fn require(&self, requirement: &Requirement) -> Result<(), ResourceServerError> {
+        if requirement.is_met_by(self) {
+            Ok(())
+        } else {
+            Err(ResourceServerError::Forbidden(
+                "the token does not grant access to this resource".to_string(),
+            ))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RequireLayer {
+    requirement: Arc<Requirement>,
+}
+
+impl<S> Layer<S> for RequireLayer {
+    type Service = Require<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Require {
+            inner,
+            requirement: self.requirement.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Require<S> {
+    inner: S,
+    requirement: Arc<Requirement>,
+}
+
+impl<S, B> Service<Request<B>> for Require<S>
+where
+    S: Service<Request<B>, Response = Response>,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = BoxFuture<Result<Response, S::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let checked = match request.extensions().get::<AuthenticatedToken>() {
+            Some(token) => token.require(&self.requirement),
+            None => Err(ResourceServerError::MissingToken),
+        };
+
+        match checked {
+            Ok(()) => Box::pin(self.inner.call(request)),
+            Err(e) => Box::pin(async move { Ok(e.into_response()) }),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use ferriskey_domain::authentication::entities::TokenIntrospectionResponse;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::config::IntrospectionConfig;
+use crate::error::ResourceServerError;
+
+struct CachedAnswer {
+    active: bool,
+    valid_until: Instant,
+}
+
+/// Asks the realm whether a token is still active, for routes that must notice a revocation
+/// before the token expires. Answers are cached by `jti`.
+pub(crate) struct Introspector {
+    http: reqwest::Client,
+    url: String,
+    config: IntrospectionConfig,
+    answers: RwLock<HashMap<Uuid, CachedAnswer>>,
+}
+
+impl Introspector {
+    pub(crate) fn new(http: reqwest::Client, url: String, config: IntrospectionConfig) -> Self {
+        Self {
+            http,
+            url,
+            config,
+            answers: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) async fn is_active(
+        &self,
+        token: &str,
+        jti: Uuid,
+        exp: Option<i64>,
+    ) -> Result<bool, ResourceServerError> {
+        let now = Instant::now();
+        if let Some(answer) = self.answers.read().await.get(&jti)
+            && answer.valid_until > now
+        {
+            return Ok(answer.active);
+        }
+
+        let response: TokenIntrospectionResponse = self
+            .http
+            .post(&self.url)
+            .basic_auth(&self.config.client_id, Some(&self.config.client_secret))
+            .form(&[("token", token), ("token_type_hint", "access_token")])
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| ResourceServerError::IntrospectionFailed(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| ResourceServerError::IntrospectionFailed(e.to_string()))?;
+
+        let ttl = match exp.map(remaining_lifetime) {
+            Some(remaining) => remaining.min(self.config.cache_ttl),
+            None => self.config.cache_ttl,
+        };
+
+        let mut answers = self.answers.write().await;
+        answers.retain(|_, answer| answer.valid_until > now);
+        answers.insert(
+            jti,
+            CachedAnswer {
+                active: response.active,
+                valid_until: now + ttl,
+            },
+        );
+
+        Ok(response.active)
+    }
+}
+
+fn remaining_lifetime(exp: i64) -> Duration {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or_default();
+
+    Duration::from_secs(exp.saturating_sub(now).max(0) as u64)
+}
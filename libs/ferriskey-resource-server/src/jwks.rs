@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use ferriskey_security::jwt::entities::JwkKey;
+use jsonwebtoken::{Algorithm, DecodingKey};
+use serde::Deserialize;
+use tokio::sync::{Mutex, RwLock};
+
+use crate::error::ResourceServerError;
+
+#[derive(Debug, Deserialize)]
+struct JwksDocument {
+    keys: Vec<JwkKey>,
+}
+
+#[derive(Clone)]
+pub(crate) struct SigningKey {
+    pub(crate) algorithm: Algorithm,
+    pub(crate) decoding_key: DecodingKey,
+}
+
+#[derive(Default)]
+struct KeySet {
+    keys: HashMap<String, SigningKey>,
+    fetched_at: Option<Instant>,
+}
+
+/// The realm signing keys, fetched lazily and refetched when a token names an unknown `kid`
+/// (the realm rotated its keys). Refetches are spaced by `refetch_interval`.
+pub(crate) struct JwksCache {
+    http: reqwest::Client,
+    url: String,
+    refetch_interval: Duration,
+    key_set: RwLock<KeySet>,
+    fetch_lock: Mutex<()>,
+}
+
+impl JwksCache {
+    pub(crate) fn new(http: reqwest::Client, url: String, refetch_interval: Duration) -> Self {
+        Self {
+            http,
+            url,
+            refetch_interval,
+            key_set: RwLock::new(KeySet::default()),
+            fetch_lock: Mutex::new(()),
+        }
+    }
+
+    pub(crate) async fn key(&self, kid: &str) -> Result<SigningKey, ResourceServerError> {
+        if let Some(key) = self.key_set.read().await.keys.get(kid) {
+            return Ok(key.clone());
+        }
+
+        // Concurrent misses wait for a single fetch instead of each hitting the realm.
+        let _fetching = self.fetch_lock.lock().await;
+        {
+            let key_set = self.key_set.read().await;
+            if let Some(key) = key_set.keys.get(kid) {
+                return Ok(key.clone());
+            }
+            if key_set
+                .fetched_at
+                .is_some_and(|fetched_at| fetched_at.elapsed() < self.refetch_interval)
+            {
+                return Err(unknown_key(kid));
+            }
+        }
+
+        self.refresh().await?;
+
+        self.key_set
+            .read()
+            .await
+            .keys
+            .get(kid)
+            .cloned()
+            .ok_or_else(|| unknown_key(kid))
+    }
+
+    async fn refresh(&self) -> Result<(), ResourceServerError> {
+        let document: JwksDocument = self
+            .http
+            .get(&self.url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| ResourceServerError::KeysUnavailable(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| ResourceServerError::KeysUnavailable(e.to_string()))?;
+
+        let keys = document
+            .keys
+            .into_iter()
+            .filter_map(|jwk| {
+                let algorithm = Algorithm::from_str(&jwk.alg).ok()?;
+                match DecodingKey::from_rsa_components(&jwk.n, &jwk.e) {
+                    Ok(decoding_key) => Some((
+                        jwk.kid,
+                        SigningKey {
+                            algorithm,
+                            decoding_key,
+                        },
+                    )),
+                    Err(e) => {
+                        tracing::warn!(kid = %jwk.kid, "skipping unusable signing key: {}", e);
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        *self.key_set.write().await = KeySet {
+            keys,
+            fetched_at: Some(Instant::now()),
+        };
+
+        Ok(())
+    }
+}
+
+fn unknown_key(kid: &str) -> ResourceServerError {
+    ResourceServerError::InvalidToken(format!("unknown signing key {kid}"))
+}
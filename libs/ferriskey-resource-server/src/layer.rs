@@ -0,0 +1,115 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use axum::http::{Request, header::AUTHORIZATION};
+use axum::response::{IntoResponse, Response};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::error::ResourceServerError;
+use crate::validator::TokenValidator;
+
+pub(crate) type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// Authenticates requests with their bearer token and stores the resulting
+/// [`crate::AuthenticatedToken`] in the request extensions. Requests without a valid token
+/// are answered with `401` before reaching the inner service.
+#[derive(Clone)]
+pub struct ResourceServerLayer {
+    validator: Arc<TokenValidator>,
+    introspect: bool,
+}
+
+impl ResourceServerLayer {
+    pub fn new(validator: Arc<TokenValidator>) -> Self {
+        Self {
+            validator,
+            introspect: false,
+        }
+    }
+
+    /// Also checks with the realm that the token was not revoked, for revocation-sensitive
+    /// routes. The validator needs an introspection client.
+    pub fn with_introspection(mut self) -> Self {
+        self.introspect = true;
+        self
+    }
+}
+
+impl<S> Layer<S> for ResourceServerLayer {
+    type Service = ResourceServer<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ResourceServer {
+            inner,
+            validator: self.validator.clone(),
+            introspect: self.introspect,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ResourceServer<S> {
+    inner: S,
+    validator: Arc<TokenValidator>,
+    introspect: bool,
+}
+
+fn bearer_token<B>(request: &Request<B>) -> Result<String, ResourceServerError> {
+    request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            value
+                .strip_prefix("Bearer ")
+                .or_else(|| value.strip_prefix("bearer "))
+        })
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+        .ok_or(ResourceServerError::MissingToken)
+}
+
+impl<S, B> Service<Request<B>> for ResourceServer<S>
+where
+    S: Service<Request<B>, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    B: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = BoxFuture<Result<Response, S::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<B>) -> Self::Future {
+        // The clone is not ready yet; keep the service `poll_ready` was called on.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let validator = self.validator.clone();
+        let introspect = self.introspect;
+
+        Box::pin(async move {
+            let authenticated = match bearer_token(&request) {
+                Ok(token) if introspect => validator.validate_active(&token).await,
+                Ok(token) => validator.validate(&token).await,
+                Err(e) => Err(e),
+            };
+
+            match authenticated {
+                Ok(token) => {
+                    request.extensions_mut().insert(token);
+                    inner.call(request).await
+                }
+                Err(e) => {
+                    tracing::debug!("rejected request: {}", e);
+                    Ok(e.into_response())
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,45 @@
+//! Access token validation for services protected by FerrisKey.
+//!
+//! Build one [`TokenValidator`] per realm, put a [`ResourceServerLayer`] in front of the
+//! protected routes and read the [`AuthenticatedToken`] in handlers:
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use axum::{Router, routing::get};
+//! use ferriskey_resource_server::{
+//!     AuthenticatedToken, Requirement, ResourceServerConfig, ResourceServerLayer,
+//!     TokenValidator,
+//! };
+//!
+//! async fn orders(token: AuthenticatedToken) -> String {
+//!     token.subject().to_string()
+//! }
+//!
+//! let validator = Arc::new(TokenValidator::new(
+//!     ResourceServerConfig::new("https://auth.example.com/realms/main").with_audience("orders-api"),
+//! ));
+//!
+//! let app: Router = Router::new()
+//!     .route("/orders", get(orders))
+//!     .layer(Requirement::scope("orders:read").layer())
+//!     .layer(ResourceServerLayer::new(validator));
+//! ```
+
+mod claims;
+mod config;
+mod error;
+mod extract;
+mod guard;
+mod introspection;
+mod jwks;
+mod layer;
+mod validator;
+
+pub use claims::{AuthenticatedToken, OrganizationClaim, RoleList};
+pub use config::{IntrospectionConfig, ResourceServerConfig};
+pub use error::ResourceServerError;
+pub use ferriskey_security::jwt::entities::JwtClaim;
+pub use guard::{Require, RequireLayer, Requirement};
+pub use layer::{ResourceServer, ResourceServerLayer};
+pub use validator::TokenValidator;
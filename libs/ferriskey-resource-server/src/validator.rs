@@ -0,0 +1,390 @@
+use ferriskey_security::jwt::entities::{ClaimsTyp, JwtClaim};
+use jsonwebtoken::{Validation, decode, decode_header, errors::ErrorKind};
+
+use crate::claims::AuthenticatedToken;
+use crate::config::ResourceServerConfig;
+use crate::error::ResourceServerError;
+use crate::introspection::Introspector;
+use crate::jwks::JwksCache;
+
+/// Validates FerrisKey access tokens of one realm. Share it behind an `Arc` so every route
+/// uses the same key and introspection caches.
+pub struct TokenValidator {
+    config: ResourceServerConfig,
+    jwks: JwksCache,
+    introspector: Option<Introspector>,
+}
+
+impl TokenValidator {
+    pub fn new(config: ResourceServerConfig) -> Self {
+        Self::with_http_client(config, reqwest::Client::new())
+    }
+
+    pub fn with_http_client(config: ResourceServerConfig, http: reqwest::Client) -> Self {
+        let jwks = JwksCache::new(
+            http.clone(),
+            config.jwks_url(),
+            config.jwks_refetch_interval,
+        );
+        let introspector = config.introspection.clone().map(|introspection| {
+            Introspector::new(http, config.introspection_url(), introspection)
+        });
+
+        Self {
+            config,
+            jwks,
+            introspector,
+        }
+    }
+
+    pub fn config(&self) -> &ResourceServerConfig {
+        &self.config
+    }
+
+    /// Checks the signature, `iss`, `aud`, `exp` and `nbf` locally.
+    pub async fn validate(&self, token: &str) -> Result<AuthenticatedToken, ResourceServerError> {
+        let header =
+            decode_header(token).map_err(|e| ResourceServerError::InvalidToken(e.to_string()))?;
+        let kid = header
+            .kid
+            .ok_or_else(|| ResourceServerError::InvalidToken("missing kid".to_string()))?;
+
+        let key = self.jwks.key(&kid).await?;
+        if header.alg != key.algorithm {
+            return Err(ResourceServerError::InvalidToken(
+                "algorithm does not match the signing key".to_string(),
+            ));
+        }
+
+        let mut validation = Validation::new(key.algorithm);
+        validation.set_issuer(&[&self.config.issuer]);
+        if self.config.audiences.is_empty() {
+            if !self.config.allow_any_audience {
+                return Err(ResourceServerError::InvalidToken(
+                    "no audience configured".to_string(),
+                ));
+            }
+            validation.validate_aud = false;
+        } else {
+            validation.set_audience(&self.config.audiences);
+        }
+        validation.validate_nbf = true;
+        validation.leeway = self.config.leeway.as_secs();
+
+        let claims = decode::<JwtClaim>(token, &key.decoding_key, &validation)
+            .map_err(|e| match e.kind() {
+                ErrorKind::ExpiredSignature => {
+                    ResourceServerError::InvalidToken("token expired".to_string())
+                }
+                _ => ResourceServerError::InvalidToken(e.to_string()),
+            })?
+            .claims;
+
+        if claims.typ != ClaimsTyp::Bearer {
+            return Err(ResourceServerError::InvalidToken(
+                "not an access token".to_string(),
+            ));
+        }
+
+        Ok(AuthenticatedToken::new(claims))
+    }
+
+    /// [`Self::validate`], then asks the realm whether the token was revoked. Requires an
+    /// introspection client in the configuration.
+    pub async fn validate_active(
+        &self,
+        token: &str,
+    ) -> Result<AuthenticatedToken, ResourceServerError> {
+        let introspector = self.introspector.as_ref().ok_or_else(|| {
+            ResourceServerError::IntrospectionFailed("no introspection client configured".into())
+        })?;
+
+        let authenticated = self.validate(token).await?;
+        let active = introspector
+            .is_active(token, authenticated.claims.jti, authenticated.claims.exp)
+            .await?;
+
+        if !active {
+            return Err(ResourceServerError::RevokedToken);
+        }
+
+        Ok(authenticated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex, OnceLock};
+    use std::time::Duration;
+
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode, header::AUTHORIZATION};
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use ferriskey_security::jwt::entities::{JwkKey, JwtKeyPair};
+    use jsonwebtoken::{Algorithm, Header, encode};
+    use serde_json::json;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{Requirement, ResourceServerLayer};
+
+    const AUDIENCE: &str = "orders-api";
+
+    fn key_pair(slot: usize) -> &'static JwtKeyPair {
+        static KEYS: OnceLock<Vec<JwtKeyPair>> = OnceLock::new();
+        &KEYS.get_or_init(|| {
+            (0..2)
+                .map(|_| {
+                    let (private_pem, public_pem) = JwtKeyPair::generate().unwrap();
+                    JwtKeyPair::from_pem(&private_pem, &public_pem, Uuid::new_v4(), Uuid::new_v4())
+                        .unwrap()
+                })
+                .collect()
+        })[slot]
+    }
+
+    #[derive(Clone, Default)]
+    struct Realm {
+        keys: Arc<Mutex<Vec<JwkKey>>>,
+        jwks_fetches: Arc<AtomicUsize>,
+        introspections: Arc<AtomicUsize>,
+        active: Arc<Mutex<bool>>,
+    }
+
+    async fn serve(realm: Realm) -> String {
+        let certs = realm.clone();
+        let introspect = realm.clone();
+        let app = Router::new()
+            .route(
+                "/realms/main/protocol/openid-connect/certs",
+                get(move || async move {
+                    certs.jwks_fetches.fetch_add(1, Ordering::SeqCst);
+                    Json(json!({ "keys": certs.keys.lock().unwrap().clone() }))
+                }),
+            )
+            .route(
+                "/realms/main/protocol/openid-connect/token/introspect",
+                post(move || async move {
+                    introspect.introspections.fetch_add(1, Ordering::SeqCst);
+                    Json(json!({ "active": *introspect.active.lock().unwrap() }))
+                }),
+            );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        format!("http://{address}/realms/main")
+    }
+
+    fn sign(slot: usize, issuer: &str, extra: serde_json::Value) -> String {
+        let key = key_pair(slot);
+        let now = chrono::Utc::now().timestamp();
+        let mut claims = json!({
+            "sub": Uuid::new_v4(),
+            "iat": now,
+            "jti": Uuid::new_v4(),
+            "iss": issuer,
+            "typ": "Bearer",
+            "azp": "web",
+            "aud": [AUDIENCE],
+            "scope": "openid orders:read",
+            "exp": now + 300,
+            "realm_access": { "roles": ["user"] },
+        });
+        for (name, value) in extra.as_object().unwrap() {
+            claims[name] = value.clone();
+        }
+
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(key.id.to_string());
+        encode(&header, &claims, &key.encoding_key).unwrap()
+    }
+
+    fn validator(issuer: &str) -> TokenValidator {
+        TokenValidator::new(
+            ResourceServerConfig::new(issuer)
+                .with_audience(AUDIENCE)
+                .with_leeway(Duration::ZERO)
+                .with_jwks_refetch_interval(Duration::ZERO)
+                .with_introspection("orders-api", "secret", Duration::from_secs(60)),
+        )
+    }
+
+    #[tokio::test]
+    async fn validates_issuer_audience_and_lifetime() {
+        let realm = Realm::default();
+        realm
+            .keys
+            .lock()
+            .unwrap()
+            .push(key_pair(0).to_jwk_key().unwrap());
+        let issuer = serve(realm).await;
+        let validator = validator(&issuer);
+        let now = chrono::Utc::now().timestamp();
+
+        let token = validator
+            .validate(&sign(0, &issuer, json!({})))
+            .await
+            .unwrap();
+        assert!(token.has_realm_role("user"));
+
+        for extra in [
+            json!({ "aud": ["billing-api"] }),
+            json!({ "iss": "https://other.example.com/realms/main" }),
+            json!({ "exp": now - 10 }),
+            json!({ "nbf": now + 600 }),
+            json!({ "typ": "Refresh" }),
+        ] {
+            let result = validator.validate(&sign(0, &issuer, extra.clone())).await;
+            assert!(
+                matches!(result, Err(ResourceServerError::InvalidToken(_))),
+                "{extra} should be rejected"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn audience_is_required_unless_any_is_allowed() {
+        let realm = Realm::default();
+        realm
+            .keys
+            .lock()
+            .unwrap()
+            .push(key_pair(0).to_jwk_key().unwrap());
+        let issuer = serve(realm).await;
+        let token = sign(0, &issuer, json!({ "aud": ["billing-api"] }));
+
+        let validator = TokenValidator::new(ResourceServerConfig::new(&issuer));
+        assert!(matches!(
+            validator.validate(&token).await,
+            Err(ResourceServerError::InvalidToken(_))
+        ));
+
+        let validator =
+            TokenValidator::new(ResourceServerConfig::new(&issuer).allow_any_audience());
+        assert!(validator.validate(&token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn refetches_keys_when_the_kid_is_unknown() {
+        let realm = Realm::default();
+        realm
+            .keys
+            .lock()
+            .unwrap()
+            .push(key_pair(0).to_jwk_key().unwrap());
+        let issuer = serve(realm.clone()).await;
+        let validator = validator(&issuer);
+
+        validator
+            .validate(&sign(0, &issuer, json!({})))
+            .await
+            .unwrap();
+        validator
+            .validate(&sign(0, &issuer, json!({})))
+            .await
+            .unwrap();
+        assert_eq!(realm.jwks_fetches.load(Ordering::SeqCst), 1);
+
+        realm
+            .keys
+            .lock()
+            .unwrap()
+            .push(key_pair(1).to_jwk_key().unwrap());
+        validator
+            .validate(&sign(1, &issuer, json!({})))
+            .await
+            .unwrap();
+        assert_eq!(realm.jwks_fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn caches_introspection_answers_per_token() {
+        let realm = Realm::default();
+        realm
+            .keys
+            .lock()
+            .unwrap()
+            .push(key_pair(0).to_jwk_key().unwrap());
+        let issuer = serve(realm.clone()).await;
+        let validator = validator(&issuer);
+
+        let revoked = sign(0, &issuer, json!({}));
+        for _ in 0..2 {
+            let result = validator.validate_active(&revoked).await;
+            assert!(matches!(result, Err(ResourceServerError::RevokedToken)));
+        }
+        assert_eq!(realm.introspections.load(Ordering::SeqCst), 1);
+
+        *realm.active.lock().unwrap() = true;
+        let active = sign(0, &issuer, json!({}));
+        assert!(validator.validate_active(&active).await.is_ok());
+        assert_eq!(realm.introspections.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn layer_rejects_missing_tokens_and_unmet_requirements() {
+        let realm = Realm::default();
+        realm
+            .keys
+            .lock()
+            .unwrap()
+            .push(key_pair(0).to_jwk_key().unwrap());
+        let issuer = serve(realm).await;
+        let validator = Arc::new(validator(&issuer));
+
+        let app = |requirement: Requirement| {
+            Router::new()
+                .route(
+                    "/orders",
+                    get(|token: AuthenticatedToken| async move { token.subject().to_string() }),
+                )
+                .layer(requirement.layer())
+                .layer(ResourceServerLayer::new(validator.clone()))
+        };
+        let request = |token: Option<&str>| {
+            let mut request = Request::builder().uri("/orders");
+            if let Some(token) = token {
+                request = request.header(AUTHORIZATION, format!("Bearer {token}"));
+            }
+            request.body(Body::empty()).unwrap()
+        };
+
+        let token = sign(0, &issuer, json!({}));
+        let statuses: HashMap<&str, StatusCode> = HashMap::from([
+            (
+                "missing",
+                app(Requirement::scope("orders:read"))
+                    .oneshot(request(None))
+                    .await
+                    .unwrap()
+                    .status(),
+            ),
+            (
+                "granted",
+                app(Requirement::scope("orders:read"))
+                    .oneshot(request(Some(&token)))
+                    .await
+                    .unwrap()
+                    .status(),
+            ),
+            (
+                "forbidden",
+                app(Requirement::realm_role("admin"))
+                    .oneshot(request(Some(&token)))
+                    .await
+                    .unwrap()
+                    .status(),
+            ),
+        ]);
+
+        assert_eq!(statuses["missing"], StatusCode::UNAUTHORIZED);
+        assert_eq!(statuses["granted"], StatusCode::OK);
+        assert_eq!(statuses["forbidden"], StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,271 @@
+use std::net::IpAddr;
+
+use chrono::{DateTime, Utc};
+use ferriskey_domain::generate_uuid_v7;
+use ferriskey_domain::realm::{Realm, RealmId};
+use ferriskey_domain::user::entities::User;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Scores are summed and capped at this value.
+pub const MAX_RISK_SCORE: u8 = 100;
+/// How many previous logins a new one is compared with.
+pub const LOGIN_HISTORY_DEPTH: u64 = 50;
+/// Login history older than this is purged.
+pub const LOGIN_HISTORY_RETENTION_DAYS: i64 = 90;
+/// Upper bound for `max_travel_speed_kmh`; anything faster is not a meaningful limit.
+pub const MAX_TRAVEL_SPEED_KMH: u32 = 20_000;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum LoginRiskValidationError {
+    #[error("signal scores must be between 0 and 100")]
+    ScoreOutOfRange,
+    #[error("thresholds must be between 1 and 100")]
+    ThresholdOutOfRange,
+    #[error("maximum travel speed must be between 1 and 20000 km/h")]
+    InvalidTravelSpeed,
+}
+
+/// How a realm scores logins and what it does about the score. Each action applies once the
+/// score reaches its threshold; a `null` threshold disables the action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LoginRiskSettings {
+    pub enabled: bool,
+    pub new_device_score: u8,
+    pub new_country_score: u8,
+    pub impossible_travel_score: u8,
+    /// Travel between two consecutive logins faster than this is impossible.
+    pub max_travel_speed_kmh: u32,
+    pub step_up_threshold: Option<u8>,
+    pub notify_threshold: Option<u8>,
+    pub block_threshold: Option<u8>,
+}
+
+impl Default for LoginRiskSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            new_device_score: 30,
+            new_country_score: 40,
+            impossible_travel_score: 60,
+            max_travel_speed_kmh: 1_000,
+            step_up_threshold: Some(30),
+            notify_threshold: Some(40),
+            block_threshold: None,
+        }
+    }
+}
+
+impl LoginRiskSettings {
+    pub fn validate(&self) -> Result<(), LoginRiskValidationError> {
+        let scores = [
+            self.new_device_score,
+            self.new_country_score,
+            self.impossible_travel_score,
+        ];
+        if scores.iter().any(|score| *score > MAX_RISK_SCORE) {
+            return Err(LoginRiskValidationError::ScoreOutOfRange);
+        }
+
+        let thresholds = [
+            self.step_up_threshold,
+            self.notify_threshold,
+            self.block_threshold,
+        ];
+        if thresholds
+            .iter()
+            .flatten()
+            .any(|threshold| *threshold == 0 || *threshold > MAX_RISK_SCORE)
+        {
+            return Err(LoginRiskValidationError::ThresholdOutOfRange);
+        }
+
+        if self.max_travel_speed_kmh == 0 || self.max_travel_speed_kmh > MAX_TRAVEL_SPEED_KMH {
+            return Err(LoginRiskValidationError::InvalidTravelSpeed);
+        }
+
+        Ok(())
+    }
+}
+
+/// The risk settings of one realm. Realms that never configured one use the defaults, with
+/// evaluation disabled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LoginRiskPolicy {
+    pub realm_id: RealmId,
+    #[serde(flatten)]
+    pub settings: LoginRiskSettings,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LoginRiskPolicy {
+    pub fn new(
+        realm_id: RealmId,
+        settings: LoginRiskSettings,
+    ) -> Result<Self, LoginRiskValidationError> {
+        settings.validate()?;
+        let now = Utc::now();
+
+        Ok(Self {
+            realm_id,
+            settings,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn default_for(realm_id: RealmId) -> Self {
+        let now = Utc::now();
+
+        Self {
+            realm_id,
+            settings: LoginRiskSettings::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// Something unusual about a login, compared with the user's history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RiskSignal {
+    /// The device fingerprint was never seen for this user.
+    NewDevice,
+    /// The login comes from a country never seen for this user.
+    NewCountry { country: String },
+    /// The user would have travelled from the previous login's location faster than the
+    /// realm's maximum travel speed.
+    ImpossibleTravel {
+        from_country: Option<String>,
+        distance_km: u32,
+        speed_kmh: u32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskAction {
+    /// Challenge the user for their second factor.
+    StepUpMfa,
+    /// Email the user about the login.
+    Notify,
+    /// Refuse the login.
+    Block,
+}
+
+/// The outcome of evaluating one login. No actions lets the login through untouched.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, ToSchema)]
+pub struct LoginRiskAssessment {
+    pub score: u8,
+    pub signals: Vec<RiskSignal>,
+    pub actions: Vec<RiskAction>,
+}
+
+impl LoginRiskAssessment {
+    pub fn allow() -> Self {
+        Self::default()
+    }
+
+    pub fn blocks(&self) -> bool {
+        self.actions.contains(&RiskAction::Block)
+    }
+
+    pub fn requires_step_up(&self) -> bool {
+        self.actions.contains(&RiskAction::StepUpMfa)
+    }
+
+    pub fn notifies(&self) -> bool {
+        self.actions.contains(&RiskAction::Notify)
+    }
+}
+
+/// Where a client address is, according to the GeoIP database.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GeoLocation {
+    /// ISO 3166-1 alpha-2 code.
+    pub country: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
+impl GeoLocation {
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+}
+
+/// The client side of a login, as seen by the HTTP layer.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LoginContext {
+    pub ip: Option<IpAddr>,
+    pub user_agent: Option<String>,
+}
+
+/// One past login of a user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct LoginRecord {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub user_id: Uuid,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub device_fingerprint: Option<String>,
+    pub country: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub risk_score: u8,
+    pub signals: Vec<RiskSignal>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LoginRecord {
+    pub fn new(
+        realm_id: RealmId,
+        user_id: Uuid,
+        context: &LoginContext,
+        device_fingerprint: Option<String>,
+        location: GeoLocation,
+        assessment: &LoginRiskAssessment,
+    ) -> Self {
+        Self {
+            id: generate_uuid_v7(),
+            realm_id,
+            user_id,
+            ip_address: context.ip.map(|ip| ip.to_string()),
+            user_agent: context.user_agent.clone(),
+            device_fingerprint,
+            country: location.country,
+            latitude: location.latitude,
+            longitude: location.longitude,
+            risk_score: assessment.score,
+            signals: assessment.signals.clone(),
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+}
+
+pub struct EvaluateLoginRiskInput {
+    pub realm: Realm,
+    pub user: User,
+    pub context: LoginContext,
+    /// Whether the user can answer an MFA challenge.
+    pub has_mfa_credential: bool,
+}
+
+pub struct UpdateLoginRiskPolicyInput {
+    pub realm_name: String,
+    pub settings: LoginRiskSettings,
+}
+
+pub struct GetLoginHistoryInput {
+    pub realm_name: String,
+    pub user_id: Uuid,
+}
@@ -0,0 +1,9 @@
+pub mod entities;
+pub mod ports;
+pub mod scoring;
+
+pub use entities::*;
+pub use ports::*;
+
+pub mod policies;
+pub mod services;
@@ -0,0 +1,102 @@
+use std::net::IpAddr;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::{Realm, RealmId};
+
+use crate::entities::{
+    EvaluateLoginRiskInput, GeoLocation, GetLoginHistoryInput, LoginRecord, LoginRiskAssessment,
+    LoginRiskPolicy, UpdateLoginRiskPolicyInput,
+};
+
+/// Persistence for the login risk policy of each realm.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait LoginRiskPolicyRepository: Send + Sync {
+    fn get_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<LoginRiskPolicy>, CoreError>> + Send;
+
+    fn upsert(
+        &self,
+        policy: LoginRiskPolicy,
+    ) -> impl Future<Output = Result<LoginRiskPolicy, CoreError>> + Send;
+}
+
+/// Persistence for the logins a risk evaluation compares against.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait LoginHistoryRepository: Send + Sync {
+    /// The latest `limit` logins of the user, most recent first.
+    fn list_recent(
+        &self,
+        user_id: Uuid,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<LoginRecord>, CoreError>> + Send;
+
+    fn record(&self, record: LoginRecord) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Deletes the logins recorded before `cutoff`; returns how many were deleted.
+    fn purge_older_than(
+        &self,
+        cutoff: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+}
+
+/// Resolves client addresses to a location. Returns `None` when no database is configured
+/// or the address is unknown to it.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait GeoIpLookup: Send + Sync {
+    fn lookup(&self, ip: IpAddr) -> Option<GeoLocation>;
+}
+
+pub trait LoginRiskAccessPolicy: Send + Sync {
+    fn can_view_login_risk(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_manage_login_risk(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_view_login_history(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+pub trait LoginRiskService: Send + Sync {
+    /// Scores a login that passed its first factor, records it unless blocked, and carries
+    /// out the notification. The caller enforces the step-up and the block.
+    fn evaluate_login_risk(
+        &self,
+        input: EvaluateLoginRiskInput,
+    ) -> impl Future<Output = Result<LoginRiskAssessment, CoreError>> + Send;
+
+    fn get_login_risk_policy(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<LoginRiskPolicy, CoreError>> + Send;
+
+    fn update_login_risk_policy(
+        &self,
+        identity: Identity,
+        input: UpdateLoginRiskPolicyInput,
+    ) -> impl Future<Output = Result<LoginRiskPolicy, CoreError>> + Send;
+
+    fn get_login_history(
+        &self,
+        identity: Identity,
+        input: GetLoginHistoryInput,
+    ) -> impl Future<Output = Result<Vec<LoginRecord>, CoreError>> + Send;
+
+    fn purge_login_history(&self) -> impl Future<Output = Result<u64, CoreError>> + Send;
+}
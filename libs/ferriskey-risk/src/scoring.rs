@@ -0,0 +1,309 @@
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+
+use crate::entities::{
+    GeoLocation, LoginRecord, LoginRiskSettings, MAX_RISK_SCORE, RiskAction, RiskSignal,
+};
+
+/// GeoIP locations are city-level at best; shorter hops are never reported as travel.
+pub const MIN_TRAVEL_DISTANCE_KM: f64 = 500.0;
+
+const EARTH_RADIUS_KM: f64 = 6_371.0;
+
+/// A stable identifier for the browser and platform behind a user agent. Version numbers
+/// are folded away so that browser updates do not look like a new device.
+pub fn device_fingerprint(user_agent: &str) -> Option<String> {
+    let mut normalized = String::with_capacity(user_agent.len());
+    for c in user_agent.trim().chars() {
+        if c.is_ascii_digit() {
+            if !normalized.ends_with('#') {
+                normalized.push('#');
+            }
+        } else if (c == '.' || c == '_') && normalized.ends_with('#') {
+            continue;
+        } else {
+            normalized.extend(c.to_lowercase());
+        }
+    }
+
+    if normalized.is_empty() {
+        return None;
+    }
+
+    let digest = Sha256::digest(normalized.as_bytes());
+    Some(hex::encode(&digest[..16]))
+}
+
+/// Great-circle distance between two points, in kilometres.
+pub fn haversine_km(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lon1) = (from.0.to_radians(), from.1.to_radians());
+    let (lat2, lon2) = (to.0.to_radians(), to.1.to_radians());
+
+    let a = ((lat2 - lat1) / 2.0).sin().powi(2)
+        + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Scores a login against `history`, most recent first. A user without history raises no
+/// signal: there is nothing to compare with.
+pub fn score_login(
+    settings: &LoginRiskSettings,
+    history: &[LoginRecord],
+    fingerprint: Option<&str>,
+    location: &GeoLocation,
+    now: DateTime<Utc>,
+) -> (u8, Vec<RiskSignal>) {
+    let mut score: u8 = 0;
+    let mut signals = Vec::new();
+
+    if history.is_empty() {
+        return (score, signals);
+    }
+
+    if let Some(fingerprint) = fingerprint {
+        let mut known = history
+            .iter()
+            .filter_map(|record| record.device_fingerprint.as_deref())
+            .peekable();
+        if known.peek().is_some() && !known.any(|known| known == fingerprint) {
+            score = score.saturating_add(settings.new_device_score);
+            signals.push(RiskSignal::NewDevice);
+        }
+    }
+
+    if let Some(country) = location.country.as_deref() {
+        let mut known = history
+            .iter()
+            .filter_map(|record| record.country.as_deref())
+            .peekable();
+        if known.peek().is_some() && !known.any(|known| known.eq_ignore_ascii_case(country)) {
+            score = score.saturating_add(settings.new_country_score);
+            signals.push(RiskSignal::NewCountry {
+                country: country.to_string(),
+            });
+        }
+    }
+
+    let previous = history
+        .iter()
+        .find_map(|record| Some((record, record.coordinates()?)));
+    if let (Some((previous, from)), Some(to)) = (previous, location.coordinates()) {
+        let distance_km = haversine_km(from, to);
+        // Anything under a minute apart is treated as a minute, so two logins in the same
+        // second do not divide by zero.
+        let hours = (now - previous.created_at).num_seconds().max(60) as f64 / 3_600.0;
+        let speed_kmh = distance_km / hours;
+
+        if distance_km >= MIN_TRAVEL_DISTANCE_KM
+            && speed_kmh > f64::from(settings.max_travel_speed_kmh)
+        {
+            score = score.saturating_add(settings.impossible_travel_score);
+            signals.push(RiskSignal::ImpossibleTravel {
+                from_country: previous.country.clone(),
+                distance_km: distance_km.round() as u32,
+                speed_kmh: speed_kmh.round().min(f64::from(u32::MAX)) as u32,
+            });
+        }
+    }
+
+    (score.min(MAX_RISK_SCORE), signals)
+}
+
+/// The actions a score calls for. A user who cannot answer an MFA challenge is blocked
+/// instead of stepped up, and a blocked user is always told about it.
+pub fn decide_actions(
+    settings: &LoginRiskSettings,
+    score: u8,
+    has_mfa_credential: bool,
+) -> Vec<RiskAction> {
+    let reached = |threshold: Option<u8>| score > 0 && threshold.is_some_and(|t| score >= t);
+
+    let step_up = reached(settings.step_up_threshold);
+    let block = reached(settings.block_threshold) || (step_up && !has_mfa_credential);
+
+    if block {
+        return vec![RiskAction::Block, RiskAction::Notify];
+    }
+
+    let mut actions = Vec::new();
+    if step_up {
+        actions.push(RiskAction::StepUpMfa);
+    }
+    if reached(settings.notify_threshold) {
+        actions.push(RiskAction::Notify);
+    }
+
+    actions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::Duration;
+    use ferriskey_domain::realm::RealmId;
+    use uuid::Uuid;
+
+    const PARIS: (f64, f64) = (48.8566, 2.3522);
+    const NEW_YORK: (f64, f64) = (40.7128, -74.0060);
+    const CHROME_120: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.6099.109 Safari/537.36";
+    const CHROME_121: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/121.0.6167.85 Safari/537.36";
+    const FIREFOX: &str = "Mozilla/5.0 (X11; Linux x86_64; rv:121.0) Gecko/20100101 Firefox/121.0";
+
+    fn settings() -> LoginRiskSettings {
+        LoginRiskSettings {
+            enabled: true,
+            ..LoginRiskSettings::default()
+        }
+    }
+
+    fn record(
+        user_agent: &str,
+        country: &str,
+        at: (f64, f64),
+        created_at: DateTime<Utc>,
+    ) -> LoginRecord {
+        LoginRecord {
+            id: Uuid::new_v4(),
+            realm_id: RealmId::new(Uuid::new_v4()),
+            user_id: Uuid::new_v4(),
+            ip_address: None,
+            user_agent: Some(user_agent.to_string()),
+            device_fingerprint: device_fingerprint(user_agent),
+            country: Some(country.to_string()),
+            latitude: Some(at.0),
+            longitude: Some(at.1),
+            risk_score: 0,
+            signals: vec![],
+            created_at,
+        }
+    }
+
+    fn location(country: &str, at: (f64, f64)) -> GeoLocation {
+        GeoLocation {
+            country: Some(country.to_string()),
+            latitude: Some(at.0),
+            longitude: Some(at.1),
+        }
+    }
+
+    #[test]
+    fn fingerprint_ignores_version_numbers() {
+        assert_eq!(
+            device_fingerprint(CHROME_120),
+            device_fingerprint(CHROME_121)
+        );
+        assert_ne!(device_fingerprint(CHROME_120), device_fingerprint(FIREFOX));
+        assert_eq!(device_fingerprint("   "), None);
+    }
+
+    #[test]
+    fn haversine_matches_known_distance() {
+        let distance = haversine_km(PARIS, NEW_YORK);
+        assert!((distance - 5_837.0).abs() < 10.0, "got {distance}");
+    }
+
+    #[test]
+    fn first_login_raises_no_signal() {
+        let (score, signals) = score_login(
+            &settings(),
+            &[],
+            device_fingerprint(FIREFOX).as_deref(),
+            &location("US", NEW_YORK),
+            Utc::now(),
+        );
+
+        assert_eq!(score, 0);
+        assert!(signals.is_empty());
+    }
+
+    #[test]
+    fn known_device_and_country_raise_no_signal() {
+        let now = Utc::now();
+        let history = vec![record(CHROME_120, "FR", PARIS, now - Duration::days(1))];
+
+        let (score, signals) = score_login(
+            &settings(),
+            &history,
+            device_fingerprint(CHROME_121).as_deref(),
+            &location("FR", PARIS),
+            now,
+        );
+
+        assert_eq!(score, 0);
+        assert!(signals.is_empty());
+    }
+
+    #[test]
+    fn new_device_country_and_impossible_travel_add_up() {
+        let now = Utc::now();
+        let history = vec![record(CHROME_120, "FR", PARIS, now - Duration::hours(1))];
+
+        let (score, signals) = score_login(
+            &settings(),
+            &history,
+            device_fingerprint(FIREFOX).as_deref(),
+            &location("US", NEW_YORK),
+            now,
+        );
+
+        assert_eq!(score, MAX_RISK_SCORE);
+        assert_eq!(signals.len(), 3);
+        assert!(matches!(
+            signals[2],
+            RiskSignal::ImpossibleTravel { speed_kmh, .. } if speed_kmh > 5_000
+        ));
+    }
+
+    #[test]
+    fn plausible_travel_is_not_reported() {
+        let now = Utc::now();
+        let history = vec![record(CHROME_120, "FR", PARIS, now - Duration::hours(12))];
+
+        let (_, signals) = score_login(
+            &settings(),
+            &history,
+            device_fingerprint(CHROME_120).as_deref(),
+            &location("US", NEW_YORK),
+            now,
+        );
+
+        assert_eq!(
+            signals,
+            vec![RiskSignal::NewCountry {
+                country: "US".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn thresholds_select_actions() {
+        let settings = LoginRiskSettings {
+            block_threshold: Some(90),
+            ..settings()
+        };
+
+        assert!(decide_actions(&settings, 0, true).is_empty());
+        assert_eq!(
+            decide_actions(&settings, 30, true),
+            vec![RiskAction::StepUpMfa]
+        );
+        assert_eq!(
+            decide_actions(&settings, 60, true),
+            vec![RiskAction::StepUpMfa, RiskAction::Notify]
+        );
+        assert_eq!(
+            decide_actions(&settings, 100, true),
+            vec![RiskAction::Block, RiskAction::Notify]
+        );
+    }
+
+    #[test]
+    fn step_up_without_second_factor_blocks() {
+        assert_eq!(
+            decide_actions(&settings(), 30, false),
+            vec![RiskAction::Block, RiskAction::Notify]
+        );
+    }
+}
@@ -0,0 +1,730 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::client::ports::ClientRepository;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::common::email::EmailPort;
+use ferriskey_domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use ferriskey_domain::common::security_notification::{
+    SecurityNotice, SecurityNotificationKind, SecurityNotifier,
+};
+use ferriskey_domain::realm::Realm;
+use ferriskey_domain::realm::ports::{RealmRepository, SmtpConfigRepository};
+use ferriskey_domain::user::entities::User;
+use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
+use ferriskey_seawatch::{EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType};
+use tokio::time::timeout;
+use tracing::{info, warn};
+
+use crate::scoring::{decide_actions, device_fingerprint, score_login};
+use crate::{
+    EvaluateLoginRiskInput, GeoIpLookup, GeoLocation, GetLoginHistoryInput, LOGIN_HISTORY_DEPTH,
+    LOGIN_HISTORY_RETENTION_DAYS, LoginContext, LoginHistoryRepository, LoginRecord,
+    LoginRiskAccessPolicy, LoginRiskAssessment, LoginRiskPolicy, LoginRiskPolicyRepository,
+    LoginRiskService, RiskSignal, UpdateLoginRiskPolicyInput,
+};
+
+/// A slow mail server delays the login it reports on, so give up quickly.
+#[cfg(not(test))]
+const NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(5);
+#[cfg(test)]
+const NOTIFICATION_TIMEOUT: Duration = Duration::from_millis(10);
+
+#[derive(Clone, Debug)]
+pub struct LoginRiskServiceImpl<R, U, C, UR, RP, LH, G, SC, E, SE, SN>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RP: LoginRiskPolicyRepository,
+    LH: LoginHistoryRepository,
+    G: GeoIpLookup,
+    SC: SmtpConfigRepository,
+    E: EmailPort,
+    SE: SecurityEventRepository,
+    SN: SecurityNotifier,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) policy_repository: Arc<RP>,
+    pub(crate) history_repository: Arc<LH>,
+    pub(crate) geoip: Arc<G>,
+    pub(crate) smtp_config_repository: Arc<SC>,
+    pub(crate) email_port: Arc<E>,
+    pub(crate) security_event_repository: Arc<SE>,
+    pub(crate) security_notifier: Arc<SN>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, RP, LH, G, SC, E, SE, SN>
+    LoginRiskServiceImpl<R, U, C, UR, RP, LH, G, SC, E, SE, SN>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RP: LoginRiskPolicyRepository,
+    LH: LoginHistoryRepository,
+    G: GeoIpLookup,
+    SC: SmtpConfigRepository,
+    E: EmailPort,
+    SE: SecurityEventRepository,
+    SN: SecurityNotifier,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        realm_repository: Arc<R>,
+        policy_repository: Arc<RP>,
+        history_repository: Arc<LH>,
+        geoip: Arc<G>,
+        smtp_config_repository: Arc<SC>,
+        email_port: Arc<E>,
+        security_event_repository: Arc<SE>,
+        security_notifier: Arc<SN>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            policy_repository,
+            history_repository,
+            geoip,
+            smtp_config_repository,
+            email_port,
+            security_event_repository,
+            security_notifier,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    async fn realm_policy(&self, realm: &Realm) -> Result<LoginRiskPolicy, CoreError> {
+        Ok(self
+            .policy_repository
+            .get_by_realm(realm.id)
+            .await?
+            .unwrap_or_else(|| LoginRiskPolicy::default_for(realm.id)))
+    }
+
+    async fn record_event(
+        &self,
+        realm: &Realm,
+        user: &User,
+        context: &LoginContext,
+        assessment: &LoginRiskAssessment,
+    ) {
+        let (event_type, status) = if assessment.blocks() {
+            (SecurityEventType::LoginRiskBlocked, EventStatus::Failure)
+        } else {
+            (SecurityEventType::LoginRiskEvaluated, EventStatus::Success)
+        };
+
+        let event = SecurityEvent::new(realm.id, event_type, status, user.id)
+            .with_target("user".to_string(), user.id, None)
+            .with_context(
+                context.ip.map(|ip| ip.to_string()),
+                context.user_agent.clone(),
+                None,
+            )
+            .with_details(serde_json::json!({
+                "score": assessment.score,
+                "signals": assessment.signals,
+                "actions": assessment.actions,
+            }));
+
+        if let Err(error) = self.security_event_repository.store_event(event).await {
+            warn!(error = ?error, "Failed to store login risk security event");
+        }
+    }
+
+    /// Tells the user about a sign-in from a new device or location through the realm
+    /// security notifications, which the realm can turn off or restyle.
+    async fn notify_new_sign_in(
+        &self,
+        realm: &Realm,
+        user: &User,
+        context: &LoginContext,
+        location: &GeoLocation,
+    ) {
+        let unknown = || "unknown".to_string();
+        let notice = SecurityNotice::new(realm.id, user.id, SecurityNotificationKind::NewSignIn)
+            .with_detail(
+                "ip_address",
+                context.ip.map(|ip| ip.to_string()).unwrap_or_else(unknown),
+            )
+            .with_detail("device", context.user_agent.clone().unwrap_or_else(unknown))
+            .with_detail("location", location.country.clone().unwrap_or_else(unknown));
+
+        self.security_notifier.notify(notice).await;
+    }
+
+    /// Emails the user about a blocked login. Delivery problems are logged, never surfaced.
+    async fn notify_user(
+        &self,
+        realm: &Realm,
+        user: &User,
+        context: &LoginContext,
+        location: &GeoLocation,
+        assessment: &LoginRiskAssessment,
+    ) {
+        let Some(email) = user.email.as_deref() else {
+            return;
+        };
+        let smtp_config = match self.smtp_config_repository.get_by_realm_id(realm.id).await {
+            Ok(Some(config)) => config,
+            Ok(None) => {
+                warn!(realm = %realm.name, "SMTP not configured, login risk notification skipped");
+                return;
+            }
+            Err(error) => {
+                warn!(error = ?error, "Failed to load SMTP configuration");
+                return;
+            }
+        };
+
+        let subject = format!("Sign-in attempt blocked on your {} account", realm.name);
+        let body = notification_body(realm, user, context, location, assessment);
+
+        let delivery = timeout(
+            NOTIFICATION_TIMEOUT,
+            self.email_port
+                .send_email(&smtp_config, email, &subject, &body, None),
+        )
+        .await;
+
+        match delivery {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => warn!(error = ?error, "Failed to send login risk notification"),
+            Err(_) => warn!(user_id = %user.id, "Login risk notification delivery timed out"),
+        }
+    }
+}
+
+fn notification_body(
+    realm: &Realm,
+    user: &User,
+    context: &LoginContext,
+    location: &GeoLocation,
+    assessment: &LoginRiskAssessment,
+) -> String {
+    let reasons: Vec<String> = assessment
+        .signals
+        .iter()
+        .map(|signal| match signal {
+            RiskSignal::NewDevice => "a device you have not used before".to_string(),
+            RiskSignal::NewCountry { country } => format!("a new country ({country})"),
+            RiskSignal::ImpossibleTravel { distance_km, .. } => {
+                format!("a location {distance_km} km away from your previous sign-in")
+            }
+        })
+        .collect();
+
+    format!(
+        "We blocked a sign-in to the {realm} account of {username} from {reasons}.\n\nTime: {time}\nIP address: {ip}\nCountry: {country}\nDevice: {device}\n\nIf this was you, you can ignore this email. Otherwise, change your password right away.",
+        realm = realm.name,
+        username = user.username,
+        reasons = reasons.join(" and "),
+        time = Utc::now().format("%Y-%m-%d %H:%M UTC"),
+        ip = context
+            .ip
+            .map(|ip| ip.to_string())
+            .unwrap_or_else(|| "unknown".to_string()),
+        country = location.country.as_deref().unwrap_or("unknown"),
+        device = context.user_agent.as_deref().unwrap_or("unknown"),
+    )
+}
+
+impl<R, U, C, UR, RP, LH, G, SC, E, SE, SN> LoginRiskService
+    for LoginRiskServiceImpl<R, U, C, UR, RP, LH, G, SC, E, SE, SN>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RP: LoginRiskPolicyRepository,
+    LH: LoginHistoryRepository,
+    G: GeoIpLookup,
+    SC: SmtpConfigRepository,
+    E: EmailPort,
+    SE: SecurityEventRepository,
+    SN: SecurityNotifier,
+{
+    async fn evaluate_login_risk(
+        &self,
+        input: EvaluateLoginRiskInput,
+    ) -> Result<LoginRiskAssessment, CoreError> {
+        let EvaluateLoginRiskInput {
+            realm,
+            user,
+            context,
+            has_mfa_credential,
+        } = input;
+
+        // Like the rate limiter, the risk engine must never be the reason logins fail.
+        let policy = match self.realm_policy(&realm).await {
+            Ok(policy) if policy.settings.enabled => policy,
+            Ok(_) => return Ok(LoginRiskAssessment::allow()),
+            Err(error) => {
+                warn!(error = ?error, realm = %realm.name, "Failed to load login risk policy");
+                return Ok(LoginRiskAssessment::allow());
+            }
+        };
+        let history = match self
+            .history_repository
+            .list_recent(user.id, LOGIN_HISTORY_DEPTH)
+            .await
+        {
+            Ok(history) => history,
+            Err(error) => {
+                warn!(error = ?error, "Failed to load login history");
+                return Ok(LoginRiskAssessment::allow());
+            }
+        };
+
+        let fingerprint = context.user_agent.as_deref().and_then(device_fingerprint);
+        let location = context
+            .ip
+            .and_then(|ip| self.geoip.lookup(ip))
+            .unwrap_or_default();
+
+        let (score, signals) = score_login(
+            &policy.settings,
+            &history,
+            fingerprint.as_deref(),
+            &location,
+            Utc::now(),
+        );
+        let actions = decide_actions(&policy.settings, score, has_mfa_credential);
+        let assessment = LoginRiskAssessment {
+            score,
+            signals,
+            actions,
+        };
+
+        // A blocked login must not teach the engine that its device and location are normal.
+        if !assessment.blocks() {
+            let record = LoginRecord::new(
+                realm.id,
+                user.id,
+                &context,
+                fingerprint,
+                location.clone(),
+                &assessment,
+            );
+            if let Err(error) = self.history_repository.record(record).await {
+                warn!(error = ?error, "Failed to record login history");
+            }
+        }
+
+        if assessment.score > 0 {
+            self.record_event(&realm, &user, &context, &assessment)
+                .await;
+            info!(
+                user_id = %user.id,
+                score = assessment.score,
+                actions = ?assessment.actions,
+                "Login risk evaluated"
+            );
+        }
+
+        // Blocked logins get the risk engine's own email: the security notification is about
+        // sign-ins that went through.
+        if assessment.blocks() {
+            self.notify_user(&realm, &user, &context, &location, &assessment)
+                .await;
+        } else if assessment.notifies() {
+            self.notify_new_sign_in(&realm, &user, &context, &location)
+                .await;
+        }
+
+        Ok(assessment)
+    }
+
+    async fn get_login_risk_policy(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<LoginRiskPolicy, CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+        ensure_policy(
+            self.policy.can_view_login_risk(&identity, &realm).await,
+            "insufficient permissions to view the login risk policy",
+        )?;
+
+        self.realm_policy(&realm).await
+    }
+
+    async fn update_login_risk_policy(
+        &self,
+        identity: Identity,
+        input: UpdateLoginRiskPolicyInput,
+    ) -> Result<LoginRiskPolicy, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        ensure_policy(
+            self.policy.can_manage_login_risk(&identity, &realm).await,
+            "insufficient permissions to manage the login risk policy",
+        )?;
+
+        let policy = match self.policy_repository.get_by_realm(realm.id).await? {
+            Some(mut policy) => {
+                input.settings.validate().map_err(|_| CoreError::Invalid)?;
+                policy.settings = input.settings;
+                policy.updated_at = Utc::now();
+                policy
+            }
+            None => {
+                LoginRiskPolicy::new(realm.id, input.settings).map_err(|_| CoreError::Invalid)?
+            }
+        };
+
+        self.policy_repository.upsert(policy).await
+    }
+
+    async fn get_login_history(
+        &self,
+        identity: Identity,
+        input: GetLoginHistoryInput,
+    ) -> Result<Vec<LoginRecord>, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        ensure_policy(
+            self.policy.can_view_login_history(&identity, &realm).await,
+            "insufficient permissions to view login history",
+        )?;
+
+        let history = self
+            .history_repository
+            .list_recent(input.user_id, LOGIN_HISTORY_DEPTH)
+            .await?;
+
+        Ok(history
+            .into_iter()
+            .filter(|record| record.realm_id == realm.id)
+            .collect())
+    }
+
+    async fn purge_login_history(&self) -> Result<u64, CoreError> {
+        let cutoff = Utc::now() - chrono::Duration::days(LOGIN_HISTORY_RETENTION_DAYS);
+        self.history_repository.purge_older_than(cutoff).await
+    }
+}
+
+pub async fn purge_login_history_task<S>(service: S, period: Duration)
+where
+    S: LoginRiskService,
+{
+    let mut ticker = tokio::time::interval(period);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+        match service.purge_login_history().await {
+            Ok(0) => {}
+            Ok(removed) => info!(removed, "Purged expired login history"),
+            Err(error) => warn!(error = ?error, "Failed to purge expired login history"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use chrono::Duration as ChronoDuration;
+    use ferriskey_domain::client::ports::MockClientRepository;
+    use ferriskey_domain::common::email::MockEmailPort;
+    use ferriskey_domain::common::security_notification::MockSecurityNotifier;
+    use ferriskey_domain::realm::RealmId;
+    use ferriskey_domain::realm::ports::{MockRealmRepository, MockSmtpConfigRepository};
+    use ferriskey_domain::user::ports::{MockUserRepository, MockUserRoleRepository};
+    use ferriskey_seawatch::ports::MockSecurityEventRepository;
+    use uuid::Uuid;
+
+    use crate::{
+        LoginRiskSettings, MockGeoIpLookup, MockLoginHistoryRepository,
+        MockLoginRiskPolicyRepository, RiskAction,
+    };
+
+    const FIREFOX: &str = "Mozilla/5.0 (X11; Linux x86_64; rv:121.0) Gecko/20100101 Firefox/121.0";
+    const CHROME: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+    type TestService = LoginRiskServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockLoginRiskPolicyRepository,
+        MockLoginHistoryRepository,
+        MockGeoIpLookup,
+        MockSmtpConfigRepository,
+        MockEmailPort,
+        MockSecurityEventRepository,
+        MockSecurityNotifier,
+    >;
+
+    fn make_realm() -> Realm {
+        Realm {
+            id: RealmId::new(Uuid::new_v4()),
+            name: "acme".to_string(),
+            display_name: None,
+            settings: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn make_user(realm: &Realm) -> User {
+        User {
+            id: Uuid::new_v4(),
+            realm_id: realm.id,
+            client_id: None,
+            username: "alice".to_string(),
+            firstname: None,
+            lastname: None,
+            email: None,
+            email_verified: true,
+            enabled: true,
+            roles: None,
+            realm: Some(realm.clone()),
+            required_actions: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            failed_login_attempts: 0,
+            locked_until: None,
+        }
+    }
+
+    fn policy_repo(realm: &Realm, settings: LoginRiskSettings) -> MockLoginRiskPolicyRepository {
+        let policy = LoginRiskPolicy::new(realm.id, settings).expect("valid settings");
+        let mut repo = MockLoginRiskPolicyRepository::new();
+        repo.expect_get_by_realm().returning(move |_| {
+            let policy = policy.clone();
+            Box::pin(async move { Ok(Some(policy)) })
+        });
+        repo
+    }
+
+    fn known_chrome_login(realm: &Realm, user: &User) -> LoginRecord {
+        let mut record = LoginRecord::new(
+            realm.id,
+            user.id,
+            &LoginContext {
+                ip: None,
+                user_agent: Some(CHROME.to_string()),
+            },
+            device_fingerprint(CHROME),
+            GeoLocation::default(),
+            &LoginRiskAssessment::allow(),
+        );
+        record.created_at = Utc::now() - ChronoDuration::days(1);
+        record
+    }
+
+    fn service(
+        policy_repository: MockLoginRiskPolicyRepository,
+        history_repository: MockLoginHistoryRepository,
+        security_event_repository: MockSecurityEventRepository,
+    ) -> TestService {
+        service_with_notifier(
+            policy_repository,
+            history_repository,
+            security_event_repository,
+            MockSecurityNotifier::new(),
+        )
+    }
+
+    fn service_with_notifier(
+        policy_repository: MockLoginRiskPolicyRepository,
+        history_repository: MockLoginHistoryRepository,
+        security_event_repository: MockSecurityEventRepository,
+        security_notifier: MockSecurityNotifier,
+    ) -> TestService {
+        let mut geoip = MockGeoIpLookup::new();
+        geoip.expect_lookup().returning(|_| None);
+
+        LoginRiskServiceImpl::new(
+            Arc::new(MockRealmRepository::new()),
+            Arc::new(policy_repository),
+            Arc::new(history_repository),
+            Arc::new(geoip),
+            Arc::new(MockSmtpConfigRepository::new()),
+            Arc::new(MockEmailPort::new()),
+            Arc::new(security_event_repository),
+            Arc::new(security_notifier),
+            Arc::new(FerriskeyPolicy::new(
+                Arc::new(MockUserRepository::new()),
+                Arc::new(MockClientRepository::new()),
+                Arc::new(MockUserRoleRepository::new()),
+            )),
+        )
+    }
+
+    fn input(realm: &Realm, user: &User, has_mfa_credential: bool) -> EvaluateLoginRiskInput {
+        EvaluateLoginRiskInput {
+            realm: realm.clone(),
+            user: user.clone(),
+            context: LoginContext {
+                ip: Some(IpAddr::V4(Ipv4Addr::new(203, 0, 113, 7))),
+                user_agent: Some(FIREFOX.to_string()),
+            },
+            has_mfa_credential,
+        }
+    }
+
+    #[tokio::test]
+    async fn disabled_policy_allows_without_touching_history() {
+        let realm = make_realm();
+        let user = make_user(&realm);
+
+        let service = service(
+            policy_repo(&realm, LoginRiskSettings::default()),
+            MockLoginHistoryRepository::new(),
+            MockSecurityEventRepository::new(),
+        );
+
+        let assessment = service
+            .evaluate_login_risk(input(&realm, &user, true))
+            .await
+            .expect("evaluation");
+
+        assert_eq!(assessment, LoginRiskAssessment::allow());
+    }
+
+    #[tokio::test]
+    async fn new_device_steps_up_and_is_recorded() {
+        let realm = make_realm();
+        let user = make_user(&realm);
+        let history = vec![known_chrome_login(&realm, &user)];
+
+        let mut history_repo = MockLoginHistoryRepository::new();
+        history_repo.expect_list_recent().returning(move |_, _| {
+            let history = history.clone();
+            Box::pin(async move { Ok(history) })
+        });
+        history_repo
+            .expect_record()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let mut events = MockSecurityEventRepository::new();
+        events
+            .expect_store_event()
+            .withf(|event| event.event_type == SecurityEventType::LoginRiskEvaluated)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let settings = LoginRiskSettings {
+            enabled: true,
+            ..LoginRiskSettings::default()
+        };
+        let service = service(policy_repo(&realm, settings), history_repo, events);
+
+        let assessment = service
+            .evaluate_login_risk(input(&realm, &user, true))
+            .await
+            .expect("evaluation");
+
+        assert_eq!(assessment.signals, vec![RiskSignal::NewDevice]);
+        assert_eq!(assessment.actions, vec![RiskAction::StepUpMfa]);
+    }
+
+    #[tokio::test]
+    async fn new_device_past_the_notify_threshold_sends_a_security_notification() {
+        let realm = make_realm();
+        let user = make_user(&realm);
+        let history = vec![known_chrome_login(&realm, &user)];
+
+        let mut history_repo = MockLoginHistoryRepository::new();
+        history_repo.expect_list_recent().returning(move |_, _| {
+            let history = history.clone();
+            Box::pin(async move { Ok(history) })
+        });
+        history_repo
+            .expect_record()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let mut events = MockSecurityEventRepository::new();
+        events
+            .expect_store_event()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let user_id = user.id;
+        let mut notifier = MockSecurityNotifier::new();
+        notifier
+            .expect_notify()
+            .withf(move |notice| {
+                notice.kind == SecurityNotificationKind::NewSignIn
+                    && notice.user_id == user_id
+                    && notice
+                        .details
+                        .contains(&("ip_address".to_string(), "203.0.113.7".to_string()))
+            })
+            .times(1)
+            .returning(|_| Box::pin(async {}));
+
+        let settings = LoginRiskSettings {
+            enabled: true,
+            notify_threshold: Some(30),
+            ..LoginRiskSettings::default()
+        };
+        let service = service_with_notifier(
+            policy_repo(&realm, settings),
+            history_repo,
+            events,
+            notifier,
+        );
+
+        let assessment = service
+            .evaluate_login_risk(input(&realm, &user, true))
+            .await
+            .expect("evaluation");
+
+        assert!(assessment.notifies());
+        assert!(!assessment.blocks());
+    }
+
+    #[tokio::test]
+    async fn blocked_login_is_not_added_to_history() {
+        let realm = make_realm();
+        let user = make_user(&realm);
+        let history = vec![known_chrome_login(&realm, &user)];
+
+        let mut history_repo = MockLoginHistoryRepository::new();
+        history_repo.expect_list_recent().returning(move |_, _| {
+            let history = history.clone();
+            Box::pin(async move { Ok(history) })
+        });
+        history_repo.expect_record().never();
+
+        let mut events = MockSecurityEventRepository::new();
+        events
+            .expect_store_event()
+            .withf(|event| event.event_type == SecurityEventType::LoginRiskBlocked)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let settings = LoginRiskSettings {
+            enabled: true,
+            ..LoginRiskSettings::default()
+        };
+        let service = service(policy_repo(&realm, settings), history_repo, events);
+
+        // No second factor to step up to: the new device is blocked. The user has no email,
+        // so nothing is sent.
+        let assessment = service
+            .evaluate_login_risk(input(&realm, &user, false))
+            .await
+            .expect("evaluation");
+
+        assert!(assessment.blocks());
+    }
+}
@@ -0,0 +1,287 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use ferriskey_client::apis::configuration::Configuration;
+use ferriskey_client::apis::{Error as GeneratedError, ResponseContent};
+
+use crate::auth::FerrisKeyAuth;
+use crate::error::FerrisKeyError;
+
+/// An authenticated handle on the admin API of one realm. Cheap to clone; clones share the
+/// token cache.
+#[derive(Clone)]
+pub struct FerrisKeyAdmin {
+    http: reqwest::Client,
+    base_url: Arc<str>,
+    realm: Arc<str>,
+    auth: Arc<FerrisKeyAuth>,
+}
+
+impl FerrisKeyAdmin {
+    /// `base_url` includes the server root path, e.g. `https://auth.example.com/api`.
+    pub fn new(base_url: &str, realm: &str, auth: FerrisKeyAuth) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            base_url: base_url.trim_end_matches('/').into(),
+            realm: realm.into(),
+            auth: Arc::new(auth),
+        }
+    }
+
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// The same credentials, managing another realm (a `master` admin provisioning tenants).
+    pub fn in_realm(&self, realm: &str) -> Self {
+        Self {
+            realm: realm.into(),
+            ..self.clone()
+        }
+    }
+
+    pub fn realm(&self) -> &str {
+        &self.realm
+    }
+
+    pub fn auth(&self) -> &FerrisKeyAuth {
+        &self.auth
+    }
+
+    /// A configuration for the generated `ferriskey_client::apis` functions, carrying a
+    /// valid bearer token. Prefer [`Self::call`], which also retries on `401`.
+    pub async fn configuration(&self) -> Result<Configuration, FerrisKeyError> {
+        Ok(Configuration {
+            base_path: self.base_url.to_string(),
+            user_agent: Some(concat!("ferriskey-sdk/", env!("CARGO_PKG_VERSION")).to_string()),
+            client: self.http.clone(),
+            bearer_access_token: Some(self.auth.access_token().await?),
+            ..Configuration::default()
+        })
+    }
+
+    /// Runs a generated API function, retrying once with a fresh token when it answers `401`.
+    ///
+    /// ```no_run
+    /// # async fn example(admin: ferriskey_sdk::FerrisKeyAdmin) -> Result<(), ferriskey_sdk::FerrisKeyError> {
+    /// use ferriskey_client::apis::realm_api;
+    ///
+    /// let realm = admin
+    ///     .call(|config| async move { realm_api::get_realm(&config, "tenant-a").await })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn call<T, E, F, Fut>(&self, f: F) -> Result<T, FerrisKeyError>
+    where
+        F: Fn(Configuration) -> Fut,
+        Fut: Future<Output = Result<T, ferriskey_client::apis::Error<E>>>,
+    {
+        match f(self.configuration().await?)
+            .await
+            .map_err(FerrisKeyError::from)
+        {
+            Err(e) if e.is_unauthorized() => {
+                self.auth.invalidate().await;
+                Ok(f(self.configuration().await?).await?)
+            }
+            result => result,
+        }
+    }
+
+    /// [`Self::call`] for a function of the realm this handle manages, which is passed as
+    /// the second argument.
+    pub(crate) async fn realm_call<'a, T, E, F, Fut>(&'a self, f: F) -> Result<T, FerrisKeyError>
+    where
+        F: Fn(Configuration, &'a str) -> Fut,
+        Fut: Future<Output = Result<T, ferriskey_client::apis::Error<E>>>,
+    {
+        let realm = self.realm();
+        self.call(|config| f(config, realm)).await
+    }
+
+    /// `path` is relative to the realm, e.g. `/compass/v1/analytics/metrics`.
+    pub(crate) fn realm_path(&self, path: &str) -> String {
+        format!(
+            "/realms/{}{path}",
+            ferriskey_client::apis::urlencode(&*self.realm)
+        )
+    }
+
+    /// `GET` on a route answering `text/plain` or XML, which the generated functions cannot
+    /// decode into a `String`.
+    pub(crate) async fn get_text(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<String, FerrisKeyError> {
+        self.call(|config| async move {
+            let mut request = config
+                .client
+                .get(format!("{}{path}", config.base_path))
+                .query(query);
+            if let Some(token) = &config.bearer_access_token {
+                request = request.bearer_auth(token);
+            }
+
+            let response = request.send().await?;
+            let status = response.status();
+            let content = response.text().await?;
+            if status.is_success() {
+                Ok(content)
+            } else {
+                Err(GeneratedError::<()>::ResponseError(ResponseContent {
+                    status,
+                    content,
+                    entity: None,
+                }))
+            }
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use axum::extract::{Form, State};
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Server {
+        grants: Arc<std::sync::Mutex<Vec<String>>>,
+        issued: Arc<AtomicUsize>,
+    }
+
+    async fn token(
+        State(server): State<Server>,
+        Form(form): Form<Vec<(String, String)>>,
+    ) -> (StatusCode, Json<Value>) {
+        let field = |name: &str| {
+            form.iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+                .unwrap_or_default()
+        };
+        if field("client_secret") != "secret" {
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(json!({ "error": "invalid_client", "error_description": "bad secret" })),
+            );
+        }
+
+        server.grants.lock().unwrap().push(field("grant_type"));
+        let issued = server.issued.fetch_add(1, Ordering::SeqCst) + 1;
+        (
+            StatusCode::OK,
+            Json(json!({
+                "access_token": format!("token-{issued}"),
+                "token_type": "Bearer",
+                "expires_in": 300,
+                "refresh_token": "refresh",
+                "refresh_expires_in": 1800,
+            })),
+        )
+    }
+
+    /// Accepts only the latest token, as if earlier ones had been revoked.
+    async fn organizations(
+        State(server): State<Server>,
+        headers: HeaderMap,
+    ) -> (StatusCode, Json<Value>) {
+        let expected = format!("Bearer token-{}", server.issued.load(Ordering::SeqCst));
+        if headers.get("authorization").and_then(|v| v.to_str().ok()) != Some(&expected) {
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(
+                    json!({ "code": "E_UNAUTHORIZED", "status": 401, "message": "Token revoked" }),
+                ),
+            );
+        }
+        (StatusCode::OK, Json(json!({ "data": [] })))
+    }
+
+    async fn serve(server: Server) -> String {
+        let app = Router::new()
+            .route(
+                "/realms/master/protocol/openid-connect/token",
+                post(token),
+            )
+            .route("/realms/acme/organizations", get(organizations))
+            .route(
+                "/realms/acme/email-templates",
+                get(|| async {
+                    (
+                        StatusCode::FORBIDDEN,
+                        Json(json!({ "code": "E_FORBIDDEN", "status": 403, "message": "Insufficient permissions" })),
+                    )
+                }),
+            )
+            .with_state(server);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        format!("http://{address}")
+    }
+
+    fn admin(base_url: &str, secret: &str) -> FerrisKeyAdmin {
+        let auth = FerrisKeyAuth::client_credentials(base_url, "master", "provisioner", secret);
+        FerrisKeyAdmin::new(base_url, "master", auth).in_realm("acme")
+    }
+
+    #[tokio::test]
+    async fn caches_the_token_and_refreshes_it_after_a_401() {
+        let server = Server::default();
+        let base_url = serve(server.clone()).await;
+        let admin = admin(&base_url, "secret");
+
+        admin.organizations().list().await.unwrap();
+        admin.organizations().list().await.unwrap();
+        assert_eq!(server.issued.load(Ordering::SeqCst), 1);
+
+        // The realm revokes every token issued so far.
+        server.issued.fetch_add(1, Ordering::SeqCst);
+        admin.organizations().list().await.unwrap();
+
+        assert_eq!(
+            *server.grants.lock().unwrap(),
+            vec!["client_credentials", "client_credentials"]
+        );
+    }
+
+    #[tokio::test]
+    async fn maps_error_bodies_to_typed_errors() {
+        let base_url = serve(Server::default()).await;
+
+        let forbidden = admin(&base_url, "secret")
+            .email_templates()
+            .list()
+            .await
+            .unwrap_err();
+        assert!(forbidden.is_forbidden());
+        assert!(matches!(
+            forbidden,
+            FerrisKeyError::Api { ref code, .. } if code == "E_FORBIDDEN"
+        ));
+
+        let rejected = admin(&base_url, "wrong")
+            .organizations()
+            .list()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            rejected,
+            FerrisKeyError::OAuth { ref error, .. } if error == "invalid_client"
+        ));
+    }
+}
@@ -0,0 +1,236 @@
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::error::FerrisKeyError;
+
+/// How the SDK proves who it is to the token endpoint.
+#[derive(Debug, Clone)]
+pub enum Grant {
+    /// A confidential client acting through its service account.
+    ClientCredentials {
+        client_id: String,
+        client_secret: String,
+    },
+    /// A user of the realm, through a client with direct access grants enabled.
+    Password {
+        client_id: String,
+        client_secret: Option<String>,
+        username: String,
+        password: String,
+    },
+}
+
+impl Grant {
+    fn client(&self) -> (&str, Option<&str>) {
+        match self {
+            Grant::ClientCredentials {
+                client_id,
+                client_secret,
+            } => (client_id, Some(client_secret)),
+            Grant::Password {
+                client_id,
+                client_secret,
+                ..
+            } => (client_id, client_secret.as_deref()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+    refresh_token: Option<String>,
+    refresh_expires_in: Option<u64>,
+}
+
+struct CachedToken {
+    access_token: String,
+    expires_at: Instant,
+    refresh_token: Option<(String, Instant)>,
+}
+
+/// Acquires access tokens from a realm and caches them until shortly before they expire.
+/// An expired token is refreshed with its refresh token when it still holds, and acquired
+/// again from the grant otherwise.
+pub struct FerrisKeyAuth {
+    http: reqwest::Client,
+    token_url: String,
+    grant: Grant,
+    scope: Option<String>,
+    refresh_skew: Duration,
+    token: Mutex<Option<CachedToken>>,
+}
+
+impl FerrisKeyAuth {
+    pub fn new(base_url: &str, realm: &str, grant: Grant) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            token_url: format!(
+                "{}/realms/{}/protocol/openid-connect/token",
+                base_url.trim_end_matches('/'),
+                ferriskey_client::apis::urlencode(realm)
+            ),
+            grant,
+            scope: None,
+            refresh_skew: Duration::from_secs(30),
+            token: Mutex::new(None),
+        }
+    }
+
+    pub fn client_credentials(
+        base_url: &str,
+        realm: &str,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            base_url,
+            realm,
+            Grant::ClientCredentials {
+                client_id: client_id.into(),
+                client_secret: client_secret.into(),
+            },
+        )
+    }
+
+    pub fn password(
+        base_url: &str,
+        realm: &str,
+        client_id: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            base_url,
+            realm,
+            Grant::Password {
+                client_id: client_id.into(),
+                client_secret: None,
+                username: username.into(),
+                password: password.into(),
+            },
+        )
+    }
+
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Space-delimited scopes requested with every token.
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    /// How long before its expiry a cached token stops being handed out. Defaults to 30s.
+    pub fn with_refresh_skew(mut self, skew: Duration) -> Self {
+        self.refresh_skew = skew;
+        self
+    }
+
+    /// A valid access token, from the cache when possible.
+    pub async fn access_token(&self) -> Result<String, FerrisKeyError> {
+        let mut cached = self.token.lock().await;
+        let now = Instant::now();
+
+        if let Some(token) = cached.as_ref()
+            && token.expires_at > now + self.refresh_skew
+        {
+            return Ok(token.access_token.clone());
+        }
+
+        let refresh_token = cached
+            .as_ref()
+            .and_then(|token| token.refresh_token.as_ref())
+            .filter(|(_, expires_at)| *expires_at > now + self.refresh_skew)
+            .map(|(refresh_token, _)| refresh_token.clone());
+
+        let response = match refresh_token {
+            // A refresh token revoked by the realm (logout, session expiry) answers
+            // `invalid_grant`: fall back to the grant itself.
+            Some(refresh_token) => match self.refresh(&refresh_token).await {
+                Ok(response) => response,
+                Err(FerrisKeyError::OAuth { .. }) => self.acquire().await?,
+                Err(e) => return Err(e),
+            },
+            None => self.acquire().await?,
+        };
+
+        let token = CachedToken {
+            access_token: response.access_token,
+            expires_at: now + Duration::from_secs(response.expires_in),
+            refresh_token: response.refresh_token.map(|refresh_token| {
+                let lifetime = response.refresh_expires_in.unwrap_or(response.expires_in);
+                (refresh_token, now + Duration::from_secs(lifetime))
+            }),
+        };
+        let access_token = token.access_token.clone();
+        *cached = Some(token);
+
+        Ok(access_token)
+    }
+
+    /// Drops the cached token, e.g. after the API rejected it.
+    pub async fn invalidate(&self) {
+        *self.token.lock().await = None;
+    }
+
+    async fn acquire(&self) -> Result<TokenResponse, FerrisKeyError> {
+        let mut form = match &self.grant {
+            Grant::ClientCredentials { .. } => vec![("grant_type", "client_credentials")],
+            Grant::Password {
+                username, password, ..
+            } => vec![
+                ("grant_type", "password"),
+                ("username", username.as_str()),
+                ("password", password.as_str()),
+            ],
+        };
+        if let Some(scope) = &self.scope {
+            form.push(("scope", scope));
+        }
+
+        self.request_token(form).await
+    }
+
+    async fn refresh(&self, refresh_token: &str) -> Result<TokenResponse, FerrisKeyError> {
+        self.request_token(vec![
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])
+        .await
+    }
+
+    async fn request_token(
+        &self,
+        mut form: Vec<(&str, &str)>,
+    ) -> Result<TokenResponse, FerrisKeyError> {
+        let (client_id, client_secret) = self.grant.client();
+        form.push(("client_id", client_id));
+        if let Some(client_secret) = client_secret {
+            form.push(("client_secret", client_secret));
+        }
+
+        let response = self.http.post(&self.token_url).form(&form).send().await?;
+        if !response.status().is_success() {
+            return Err(match FerrisKeyError::from_response(response).await {
+                FerrisKeyError::Api {
+                    status,
+                    code,
+                    message,
+                } => FerrisKeyError::OAuth {
+                    status,
+                    error: code,
+                    description: Some(message),
+                },
+                e => e,
+            });
+        }
+
+        Ok(response.json().await?)
+    }
+}
@@ -0,0 +1,44 @@
+use ferriskey_client::apis::client_api;
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// The client secret routes of `client_api`.
+pub struct Clients<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> Clients<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    /// The plaintext secret; `None` for public clients. Recorded as a security event.
+    pub async fn reveal_secret(&self, client_id: Uuid) -> Result<Option<String>, FerrisKeyError> {
+        let client_id = &client_id.to_string();
+        let secret = self
+            .admin
+            .realm_call(|config, realm| async move {
+                client_api::get_client_secret(&config, realm, client_id).await
+            })
+            .await?;
+        Ok(secret.client_secret.flatten())
+    }
+
+    /// Replaces the secret with a new random one, which is returned. The previous secret
+    /// stops working immediately.
+    pub async fn rotate_secret(&self, client_id: Uuid) -> Result<String, FerrisKeyError> {
+        let client_id = &client_id.to_string();
+        let secret = self
+            .admin
+            .realm_call(|config, realm| async move {
+                client_api::rotate_client_secret(&config, realm, client_id).await
+            })
+            .await?;
+        secret
+            .client_secret
+            .flatten()
+            .ok_or_else(|| FerrisKeyError::Decode("rotation returned no secret".to_string()))
+    }
+}
@@ -0,0 +1,205 @@
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use ferriskey_client::apis::compass_api;
+use ferriskey_client::models::{
+    CompassFlow, DailyActivityStats, FlowAnalytics, FlowAnalyticsComparison, FlowStats,
+};
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+use crate::pagination::{Page, collect_pages};
+
+/// Filters of the flow list. `limit`/`offset` are set by the pagination helpers.
+#[derive(Debug, Clone, Default)]
+pub struct FlowQuery {
+    pub client_id: Option<String>,
+    pub user_id: Option<Uuid>,
+    pub grant_type: Option<String>,
+    pub status: Option<String>,
+}
+
+/// An analytics window; the server defaults to the last 24 hours.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsRange {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub client_id: Option<String>,
+    pub grant_type: Option<String>,
+}
+
+/// A window of days, inclusive; the server defaults to the last 30 days.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityRange {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub client_id: Option<String>,
+    pub user_id: Option<Uuid>,
+    pub grant_type: Option<String>,
+}
+
+fn timestamp(value: Option<DateTime<Utc>>) -> Option<String> {
+    value.map(|value| value.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+impl AnalyticsRange {
+    fn query(&self) -> Vec<(&'static str, String)> {
+        [
+            ("from", timestamp(self.from)),
+            ("to", timestamp(self.to)),
+            ("client_id", self.client_id.clone()),
+            ("grant_type", self.grant_type.clone()),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name, value)))
+        .collect()
+    }
+}
+
+/// `/realms/{realm}/compass/v1`, the login flow analytics.
+pub struct Compass<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> Compass<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    pub async fn flows(
+        &self,
+        filter: &FlowQuery,
+        page: Page,
+    ) -> Result<Vec<CompassFlow>, FerrisKeyError> {
+        let user_id = &filter.user_id.map(|id| id.to_string());
+        let flows = self
+            .admin
+            .realm_call(|config, realm| async move {
+                compass_api::get_flows(
+                    &config,
+                    realm,
+                    filter.client_id.as_deref(),
+                    user_id.as_deref(),
+                    filter.grant_type.as_deref(),
+                    filter.status.as_deref(),
+                    Some(page.limit as i32),
+                    Some(page.offset as i32),
+                )
+                .await
+            })
+            .await?;
+        Ok(flows.data)
+    }
+
+    /// Every flow matching `filter`, fetched `page_size` at a time.
+    pub async fn all_flows(
+        &self,
+        filter: &FlowQuery,
+        page_size: u32,
+    ) -> Result<Vec<CompassFlow>, FerrisKeyError> {
+        collect_pages(page_size, |page| self.flows(filter, page)).await
+    }
+
+    pub async fn flow(&self, flow_id: Uuid) -> Result<CompassFlow, FerrisKeyError> {
+        let flow_id = &flow_id.to_string();
+        let flow = self
+            .admin
+            .realm_call(|config, realm| async move {
+                compass_api::get_flow(&config, realm, flow_id).await
+            })
+            .await?;
+        Ok(*flow.data)
+    }
+
+    pub async fn stats(&self) -> Result<FlowStats, FerrisKeyError> {
+        let stats = self
+            .admin
+            .realm_call(|config, realm| async move { compass_api::get_stats(&config, realm).await })
+            .await?;
+        Ok(*stats.data)
+    }
+
+    pub async fn daily_activity(
+        &self,
+        range: &ActivityRange,
+    ) -> Result<Vec<DailyActivityStats>, FerrisKeyError> {
+        let from = &range.from.map(|day| day.to_string());
+        let to = &range.to.map(|day| day.to_string());
+        let user_id = &range.user_id.map(|id| id.to_string());
+        let activity = self
+            .admin
+            .realm_call(|config, realm| async move {
+                compass_api::get_daily_activity_stats(
+                    &config,
+                    realm,
+                    from.as_deref(),
+                    to.as_deref(),
+                    range.client_id.as_deref(),
+                    user_id.as_deref(),
+                    range.grant_type.as_deref(),
+                )
+                .await
+            })
+            .await?;
+        Ok(activity.data)
+    }
+
+    pub async fn analytics(&self, range: &AnalyticsRange) -> Result<FlowAnalytics, FerrisKeyError> {
+        let from = &timestamp(range.from);
+        let to = &timestamp(range.to);
+        let analytics = self
+            .admin
+            .realm_call(|config, realm| async move {
+                compass_api::get_flow_analytics(
+                    &config,
+                    realm,
+                    from.as_deref(),
+                    to.as_deref(),
+                    range.client_id.as_deref(),
+                    range.grant_type.as_deref(),
+                )
+                .await
+            })
+            .await?;
+        Ok(*analytics.data)
+    }
+
+    /// `range` against the window right before it, or against `baseline` when given. The
+    /// client and grant filters of `range` apply to both windows.
+    pub async fn compare(
+        &self,
+        range: &AnalyticsRange,
+        baseline: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    ) -> Result<FlowAnalyticsComparison, FerrisKeyError> {
+        let from = &timestamp(range.from);
+        let to = &timestamp(range.to);
+        let baseline_from = &timestamp(baseline.map(|(from, _)| from));
+        let baseline_to = &timestamp(baseline.map(|(_, to)| to));
+        let comparison = self
+            .admin
+            .realm_call(|config, realm| async move {
+                compass_api::compare_flow_analytics(
+                    &config,
+                    realm,
+                    from.as_deref(),
+                    to.as_deref(),
+                    baseline_from.as_deref(),
+                    baseline_to.as_deref(),
+                    range.client_id.as_deref(),
+                    range.grant_type.as_deref(),
+                )
+                .await
+            })
+            .await?;
+        Ok(*comparison.data)
+    }
+
+    /// The analytics in the Prometheus text exposition format.
+    pub async fn metrics(&self, range: &AnalyticsRange) -> Result<String, FerrisKeyError> {
+        self.admin
+            .get_text(
+                &self.admin.realm_path("/compass/v1/analytics/metrics"),
+                &range.query(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,105 @@
+use ferriskey_client::apis::{email_template_api, email_template_variables_api};
+use ferriskey_client::models::{
+    CreateEmailTemplateValidator, EmailTemplate, EmailType, TemplateVariable,
+    UpdateEmailTemplateValidator,
+};
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// `/realms/{realm}/email-templates`. `structure` is the editor tree the MJML is compiled
+/// from.
+pub struct EmailTemplates<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> EmailTemplates<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    pub async fn list(&self) -> Result<Vec<EmailTemplate>, FerrisKeyError> {
+        let templates = self
+            .admin
+            .realm_call(|config, realm| async move {
+                email_template_api::fetch_templates(&config, realm).await
+            })
+            .await?;
+        Ok(templates.data)
+    }
+
+    pub async fn get(&self, template_id: Uuid) -> Result<EmailTemplate, FerrisKeyError> {
+        let template_id = &template_id.to_string();
+        let template = self
+            .admin
+            .realm_call(|config, realm| async move {
+                email_template_api::get_template(&config, realm, template_id).await
+            })
+            .await?;
+        Ok(*template.data)
+    }
+
+    pub async fn create(
+        &self,
+        name: &str,
+        email_type: EmailType,
+        structure: &serde_json::Value,
+    ) -> Result<EmailTemplate, FerrisKeyError> {
+        let template = self
+            .admin
+            .realm_call(|config, realm| async move {
+                let body = CreateEmailTemplateValidator::new(
+                    email_type.to_string(),
+                    name.to_string(),
+                    Some(structure.clone()),
+                );
+                email_template_api::create_template(&config, realm, body).await
+            })
+            .await?;
+        Ok(*template.data)
+    }
+
+    pub async fn update(
+        &self,
+        template_id: Uuid,
+        name: &str,
+        structure: &serde_json::Value,
+    ) -> Result<EmailTemplate, FerrisKeyError> {
+        let template_id = &template_id.to_string();
+        let template = self
+            .admin
+            .realm_call(|config, realm| async move {
+                let body =
+                    UpdateEmailTemplateValidator::new(name.to_string(), Some(structure.clone()));
+                email_template_api::update_template(&config, realm, template_id, body).await
+            })
+            .await?;
+        Ok(*template.data)
+    }
+
+    pub async fn delete(&self, template_id: Uuid) -> Result<(), FerrisKeyError> {
+        let template_id = &template_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                email_template_api::delete_template(&config, realm, template_id).await
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// The variables a template of `email_type` can interpolate. Not realm-scoped.
+    pub async fn variables(
+        &self,
+        email_type: EmailType,
+    ) -> Result<Vec<TemplateVariable>, FerrisKeyError> {
+        let email_type = &email_type.to_string();
+        let variables = self
+            .admin
+            .call(|config| async move {
+                email_template_variables_api::get_variables(&config, email_type).await
+            })
+            .await?;
+        Ok(variables.data)
+    }
+}
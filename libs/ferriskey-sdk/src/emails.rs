@@ -0,0 +1,44 @@
+use ferriskey_client::apis::email_outbox_api;
+use ferriskey_client::models::{OutboxEmail, OutboxEmailStatus};
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// `/realms/{realm}/emails`, the email outbox: delivery status of recent emails, without
+/// their bodies.
+pub struct Emails<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> Emails<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    /// Newest first, optionally only those in `status`.
+    pub async fn list(
+        &self,
+        status: Option<OutboxEmailStatus>,
+        limit: Option<u64>,
+    ) -> Result<Vec<OutboxEmail>, FerrisKeyError> {
+        let limit = limit.map(|limit| limit as i64);
+        let emails = self
+            .admin
+            .realm_call(|config, realm| async move {
+                email_outbox_api::get_outbox_emails(&config, realm, status, limit).await
+            })
+            .await?;
+        Ok(emails.data)
+    }
+
+    /// Queues a sent or failed email again.
+    pub async fn resend(&self, email_id: Uuid) -> Result<OutboxEmail, FerrisKeyError> {
+        let email_id = &email_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                email_outbox_api::resend_outbox_email(&config, realm, email_id).await
+            })
+            .await
+    }
+}
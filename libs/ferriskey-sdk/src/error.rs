@@ -0,0 +1,129 @@
+use ferriskey_client::apis::{Error as GeneratedError, ResponseContent};
+use serde::Deserialize;
+use thiserror::Error;
+
+/// The `ApiErrorResponse` body every FerrisKey admin route answers errors with.
+#[derive(Debug, Deserialize)]
+struct ApiErrorBody {
+    code: String,
+    message: String,
+}
+
+/// The RFC 6749 §5.2 body of the token endpoint.
+#[derive(Debug, Deserialize)]
+struct OAuthErrorBody {
+    error: String,
+    error_description: Option<String>,
+}
+
+#[derive(Debug, Error)]
+pub enum FerrisKeyError {
+    /// FerrisKey rejected the request; `code` is the `E_*` code of the error body.
+    #[error("{code} ({status}): {message}")]
+    Api {
+        status: u16,
+        code: String,
+        message: String,
+    },
+
+    /// The token endpoint refused to issue or refresh a token.
+    #[error("token request rejected ({status}): {error}")]
+    OAuth {
+        status: u16,
+        error: String,
+        description: Option<String>,
+    },
+
+    #[error("request failed: {0}")]
+    Transport(String),
+
+    #[error("unexpected response body: {0}")]
+    Decode(String),
+}
+
+impl FerrisKeyError {
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            FerrisKeyError::Api { status, .. } | FerrisKeyError::OAuth { status, .. } => {
+                Some(*status)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        self.status() == Some(401)
+    }
+
+    pub fn is_forbidden(&self) -> bool {
+        self.status() == Some(403)
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404)
+    }
+
+    /// Maps an error body to the variant it describes, falling back to the raw text.
+    pub(crate) fn from_body(status: u16, body: &str) -> Self {
+        if let Ok(api) = serde_json::from_str::<ApiErrorBody>(body) {
+            return FerrisKeyError::Api {
+                status,
+                code: api.code,
+                message: api.message,
+            };
+        }
+
+        if let Ok(oauth) = serde_json::from_str::<OAuthErrorBody>(body) {
+            return FerrisKeyError::OAuth {
+                status,
+                error: oauth.error,
+                description: oauth.error_description,
+            };
+        }
+
+        FerrisKeyError::Api {
+            status,
+            code: format!("HTTP_{status}"),
+            message: body.trim().to_string(),
+        }
+    }
+
+    pub(crate) async fn from_response(response: reqwest::Response) -> Self {
+        let status = response.status().as_u16();
+        match response.text().await {
+            Ok(body) => Self::from_body(status, &body),
+            Err(e) => e.into(),
+        }
+    }
+}
+
+impl From<reqwest::Error> for FerrisKeyError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_decode() {
+            FerrisKeyError::Decode(e.to_string())
+        } else {
+            FerrisKeyError::Transport(e.to_string())
+        }
+    }
+}
+
+impl From<serde_json::Error> for FerrisKeyError {
+    fn from(e: serde_json::Error) -> Self {
+        FerrisKeyError::Decode(e.to_string())
+    }
+}
+
+/// Errors of the generated `ferriskey_client::apis` functions carry the raw error body; it is
+/// mapped the same way as for the calls made by this crate.
+impl<T> From<GeneratedError<T>> for FerrisKeyError {
+    fn from(e: GeneratedError<T>) -> Self {
+        match e {
+            GeneratedError::ResponseError(ResponseContent {
+                status, content, ..
+            }) => FerrisKeyError::from_body(status.as_u16(), &content),
+            GeneratedError::Reqwest(e) => e.into(),
+            GeneratedError::Serde(e) => e.into(),
+            GeneratedError::Io(e) => FerrisKeyError::Transport(e.to_string()),
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use ferriskey_client::apis::group_api;
+use ferriskey_client::models::{
+    AddRealmGroupMemberValidator, AssignRealmGroupRoleValidator, CreateRealmGroupValidator,
+    RealmGroup, RealmGroupAttribute, RealmGroupMember, RealmGroupMemberDetail,
+    RealmGroupMemberPage, RealmGroupNode, Role, UpdateRealmGroupValidator,
+    UpsertRealmGroupAttributeValidator,
+};
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+use crate::pagination::{Page, collect_pages};
+
+/// `/realms/{realm}/groups`, the realm-wide group hierarchy. Members inherit the roles and
+/// attributes of their groups and of the parents of those groups.
+pub struct Groups<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> Groups<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    /// The groups as a tree, roots first.
+    pub async fn list(&self) -> Result<Vec<RealmGroupNode>, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move { group_api::list_groups(&config, realm).await })
+            .await
+    }
+
+    pub async fn get(&self, group_id: Uuid) -> Result<RealmGroup, FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::get_group(&config, realm, group_id).await
+            })
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        group: &CreateRealmGroupValidator,
+    ) -> Result<RealmGroup, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::create_group(&config, realm, group.clone()).await
+            })
+            .await
+    }
+
+    pub async fn update(
+        &self,
+        group_id: Uuid,
+        changes: &UpdateRealmGroupValidator,
+    ) -> Result<RealmGroup, FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::update_group(&config, realm, group_id, changes.clone()).await
+            })
+            .await
+    }
+
+    /// Deletes the group with its subgroups.
+    pub async fn delete(&self, group_id: Uuid) -> Result<(), FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::delete_group(&config, realm, group_id).await
+            })
+            .await
+    }
+
+    pub async fn attributes(
+        &self,
+        group_id: Uuid,
+    ) -> Result<Vec<RealmGroupAttribute>, FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::list_group_attributes(&config, realm, group_id).await
+            })
+            .await
+    }
+
+    pub async fn set_attribute(
+        &self,
+        group_id: Uuid,
+        key: &str,
+        value: &str,
+    ) -> Result<RealmGroupAttribute, FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::upsert_group_attribute(
+                    &config,
+                    realm,
+                    group_id,
+                    key,
+                    UpsertRealmGroupAttributeValidator::new(value.to_string()),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn delete_attribute(&self, group_id: Uuid, key: &str) -> Result<(), FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::delete_group_attribute(&config, realm, group_id, key).await
+            })
+            .await
+    }
+
+    /// Direct members of the group, optionally filtered by username or email.
+    pub async fn members(
+        &self,
+        group_id: Uuid,
+        search: Option<&str>,
+        page: Page,
+    ) -> Result<RealmGroupMemberPage, FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::list_group_members(
+                    &config,
+                    realm,
+                    group_id,
+                    Some(page.limit as i32),
+                    Some(page.offset as i32),
+                    search,
+                )
+                .await
+            })
+            .await
+    }
+
+    /// Every direct member of the group, fetched `page_size` at a time.
+    pub async fn all_members(
+        &self,
+        group_id: Uuid,
+        page_size: u32,
+    ) -> Result<Vec<RealmGroupMemberDetail>, FerrisKeyError> {
+        collect_pages(page_size, |page| async move {
+            Ok(self.members(group_id, None, page).await?.data)
+        })
+        .await
+    }
+
+    pub async fn add_member(
+        &self,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<RealmGroupMember, FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::add_group_member(
+                    &config,
+                    realm,
+                    group_id,
+                    AddRealmGroupMemberValidator::new(user_id),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn remove_member(&self, group_id: Uuid, user_id: Uuid) -> Result<(), FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        let user_id = &user_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::remove_group_member(&config, realm, group_id, user_id).await
+            })
+            .await
+    }
+
+    pub async fn roles(&self, group_id: Uuid) -> Result<Vec<Role>, FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::list_group_roles(&config, realm, group_id).await
+            })
+            .await
+    }
+
+    pub async fn assign_role(&self, group_id: Uuid, role_id: Uuid) -> Result<(), FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::assign_group_role(
+                    &config,
+                    realm,
+                    group_id,
+                    AssignRealmGroupRoleValidator::new(role_id),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn revoke_role(&self, group_id: Uuid, role_id: Uuid) -> Result<(), FerrisKeyError> {
+        let group_id = &group_id.to_string();
+        let role_id = &role_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::revoke_group_role(&config, realm, group_id, role_id).await
+            })
+            .await
+    }
+}
@@ -0,0 +1,157 @@
+use ferriskey_client::apis::identity_provider_api;
+use ferriskey_client::models::{
+    CreateIdentityProviderValidator, IdentityProviderMapper, IdentityProviderMapperResponse,
+    IdentityProviderMapperValidator, IdentityProviderResponse, IdentityProviderValidationReport,
+    UpdateIdentityProviderResponse, UpdateIdentityProviderValidator,
+};
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// `/realms/{realm}/identity-providers`, the external OIDC, OAuth 2.0 and SAML providers
+/// users can log in with, and the mappers importing their claims.
+pub struct IdentityProviders<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> IdentityProviders<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    pub async fn list(&self) -> Result<Vec<IdentityProviderResponse>, FerrisKeyError> {
+        let providers = self
+            .admin
+            .realm_call(|config, realm| async move {
+                identity_provider_api::list_identity_providers(&config, realm, None).await
+            })
+            .await?;
+        Ok(providers.data)
+    }
+
+    pub async fn get(&self, alias: &str) -> Result<IdentityProviderResponse, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                identity_provider_api::get_identity_provider(&config, realm, alias).await
+            })
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        provider: &CreateIdentityProviderValidator,
+    ) -> Result<IdentityProviderResponse, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                identity_provider_api::create_identity_provider(&config, realm, provider.clone())
+                    .await
+            })
+            .await
+    }
+
+    pub async fn update(
+        &self,
+        alias: &str,
+        changes: &UpdateIdentityProviderValidator,
+    ) -> Result<UpdateIdentityProviderResponse, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                identity_provider_api::update_identity_provider(
+                    &config,
+                    realm,
+                    alias,
+                    changes.clone(),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn delete(&self, alias: &str) -> Result<(), FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                identity_provider_api::delete_identity_provider(&config, realm, alias).await
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Checks the stored configuration against the upstream provider. Failed checks are in
+    /// the report, not returned as errors.
+    pub async fn validate(
+        &self,
+        alias: &str,
+    ) -> Result<IdentityProviderValidationReport, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                identity_provider_api::validate_identity_provider(&config, realm, alias).await
+            })
+            .await
+    }
+
+    pub async fn mappers(
+        &self,
+        alias: &str,
+    ) -> Result<Vec<IdentityProviderMapper>, FerrisKeyError> {
+        let mappers = self
+            .admin
+            .realm_call(|config, realm| async move {
+                identity_provider_api::list_identity_provider_mappers(&config, realm, alias).await
+            })
+            .await?;
+        Ok(mappers.data)
+    }
+
+    pub async fn create_mapper(
+        &self,
+        alias: &str,
+        mapper: &IdentityProviderMapperValidator,
+    ) -> Result<IdentityProviderMapperResponse, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                identity_provider_api::create_identity_provider_mapper(
+                    &config,
+                    realm,
+                    alias,
+                    mapper.clone(),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn update_mapper(
+        &self,
+        alias: &str,
+        mapper_id: Uuid,
+        mapper: &IdentityProviderMapperValidator,
+    ) -> Result<IdentityProviderMapperResponse, FerrisKeyError> {
+        let mapper_id = &mapper_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                identity_provider_api::update_identity_provider_mapper(
+                    &config,
+                    realm,
+                    alias,
+                    mapper_id,
+                    mapper.clone(),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn delete_mapper(&self, alias: &str, mapper_id: Uuid) -> Result<(), FerrisKeyError> {
+        let mapper_id = &mapper_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                identity_provider_api::delete_identity_provider_mapper(
+                    &config, realm, alias, mapper_id,
+                )
+                .await
+            })
+            .await?;
+        Ok(())
+    }
+}
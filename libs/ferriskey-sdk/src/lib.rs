@@ -0,0 +1,128 @@
+//! A high-level client for the FerrisKey admin API.
+//!
+//! [`FerrisKeyAuth`] acquires and caches the access token; [`FerrisKeyAdmin`] sends the
+//! requests of one realm with it, retries once on `401` with a fresh token, and maps error
+//! bodies to [`FerrisKeyError`]. The wrappers are built on the generated `ferriskey-client`
+//! functions and return its models; every other generated function is reachable through
+//! [`FerrisKeyAdmin::call`].
+//!
+//! ```no_run
+//! use ferriskey_sdk::generated::models::CreateOrganizationValidator;
+//! use ferriskey_sdk::{FerrisKeyAdmin, FerrisKeyAuth};
+//!
+//! # async fn provision() -> Result<(), ferriskey_sdk::FerrisKeyError> {
+//! let auth = FerrisKeyAuth::client_credentials(
+//!     "https://auth.example.com",
+//!     "master",
+//!     "provisioner",
+//!     "secret",
+//! );
+//! let admin = FerrisKeyAdmin::new("https://auth.example.com", "master", auth).in_realm("acme");
+//!
+//! let organization = admin
+//!     .organizations()
+//!     .create(&CreateOrganizationValidator::new(
+//!         "acme".to_string(),
+//!         "Acme".to_string(),
+//!     ))
+//!     .await?;
+//! admin
+//!     .organizations()
+//!     .set_attribute(organization.id, "plan", "enterprise")
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod admin;
+pub mod auth;
+pub mod clients;
+pub mod compass;
+pub mod email_templates;
+pub mod emails;
+pub mod error;
+pub mod groups;
+pub mod identity_providers;
+pub mod localization;
+pub mod maintenance;
+pub mod organizations;
+pub mod pagination;
+pub mod portal_themes;
+pub mod rate_limits;
+pub mod risk;
+pub mod saml;
+pub mod user_profile;
+pub mod users;
+pub mod webauthn_policy;
+
+pub use admin::FerrisKeyAdmin;
+pub use auth::{FerrisKeyAuth, Grant};
+pub use error::FerrisKeyError;
+pub use ferriskey_client as generated;
+pub use pagination::{Page, collect_pages};
+
+impl FerrisKeyAdmin {
+    pub fn clients(&self) -> clients::Clients<'_> {
+        clients::Clients::new(self)
+    }
+
+    pub fn users(&self) -> users::Users<'_> {
+        users::Users::new(self)
+    }
+
+    pub fn organizations(&self) -> organizations::Organizations<'_> {
+        organizations::Organizations::new(self)
+    }
+
+    pub fn compass(&self) -> compass::Compass<'_> {
+        compass::Compass::new(self)
+    }
+
+    pub fn maintenance(&self) -> maintenance::Maintenance<'_> {
+        maintenance::Maintenance::new(self)
+    }
+
+    pub fn portal_themes(&self) -> portal_themes::PortalThemes<'_> {
+        portal_themes::PortalThemes::new(self)
+    }
+
+    pub fn email_templates(&self) -> email_templates::EmailTemplates<'_> {
+        email_templates::EmailTemplates::new(self)
+    }
+
+    pub fn emails(&self) -> emails::Emails<'_> {
+        emails::Emails::new(self)
+    }
+
+    pub fn groups(&self) -> groups::Groups<'_> {
+        groups::Groups::new(self)
+    }
+
+    pub fn rate_limits(&self) -> rate_limits::RateLimits<'_> {
+        rate_limits::RateLimits::new(self)
+    }
+
+    pub fn risk(&self) -> risk::Risk<'_> {
+        risk::Risk::new(self)
+    }
+
+    pub fn user_profile(&self) -> user_profile::UserProfile<'_> {
+        user_profile::UserProfile::new(self)
+    }
+
+    pub fn localization(&self) -> localization::Localization<'_> {
+        localization::Localization::new(self)
+    }
+
+    pub fn webauthn_policy(&self) -> webauthn_policy::WebAuthn<'_> {
+        webauthn_policy::WebAuthn::new(self)
+    }
+
+    pub fn identity_providers(&self) -> identity_providers::IdentityProviders<'_> {
+        identity_providers::IdentityProviders::new(self)
+    }
+
+    pub fn saml(&self) -> saml::Saml<'_> {
+        saml::Saml::new(self)
+    }
+}
@@ -0,0 +1,91 @@
+use ferriskey_client::apis::localization_api;
+use ferriskey_client::models::{
+    LocalizationSettings, MessageBundle, PortalMessages, PutMessageBundleValidator,
+    UpdateLocalizationSettingsValidator,
+};
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// `/realms/{realm}/localization`: the locales of the realm and the message bundles
+/// overriding the built-in texts of the login portal.
+pub struct Localization<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> Localization<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    pub async fn settings(&self) -> Result<LocalizationSettings, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                localization_api::get_localization_settings(&config, realm).await
+            })
+            .await
+    }
+
+    /// Sets the supported and default locales.
+    pub async fn update_settings(
+        &self,
+        settings: &UpdateLocalizationSettingsValidator,
+    ) -> Result<LocalizationSettings, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                localization_api::update_localization_settings(&config, realm, settings.clone())
+                    .await
+            })
+            .await
+    }
+
+    pub async fn bundles(&self) -> Result<Vec<MessageBundle>, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                localization_api::list_message_bundles(&config, realm).await
+            })
+            .await
+    }
+
+    pub async fn bundle(&self, locale: &str) -> Result<MessageBundle, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                localization_api::get_message_bundle(&config, realm, locale).await
+            })
+            .await
+    }
+
+    /// Replaces the bundle of `locale`.
+    pub async fn put_bundle(
+        &self,
+        locale: &str,
+        bundle: &PutMessageBundleValidator,
+    ) -> Result<MessageBundle, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                localization_api::put_message_bundle(&config, realm, locale, bundle.clone()).await
+            })
+            .await
+    }
+
+    pub async fn delete_bundle(&self, locale: &str) -> Result<(), FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                localization_api::delete_message_bundle(&config, realm, locale).await
+            })
+            .await
+    }
+
+    /// The login portal texts in the first of `ui_locales` the realm supports, or in its
+    /// default locale. Public, like the portal that renders them.
+    pub async fn portal_messages(
+        &self,
+        ui_locales: Option<&str>,
+    ) -> Result<PortalMessages, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                localization_api::get_portal_messages(&config, realm, ui_locales).await
+            })
+            .await
+    }
+}
@@ -0,0 +1,194 @@
+use ferriskey_client::apis::maintenance_api;
+use ferriskey_client::models::{
+    AddWhitelistEntryValidator, MaintenanceAnnouncement, MaintenanceWhitelistEntry,
+    MaintenanceWindow, RealmMaintenanceWhitelistEntry, ScheduleMaintenanceWindowValidator,
+    ToggleMaintenanceValidator,
+};
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// Who a maintenance whitelist entry lets through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhitelistSubject {
+    User(Uuid),
+    Role(Uuid),
+}
+
+impl From<WhitelistSubject> for AddWhitelistEntryValidator {
+    fn from(subject: WhitelistSubject) -> Self {
+        match subject {
+            WhitelistSubject::User(user_id) => Self {
+                user_id: Some(Some(user_id)),
+                role_id: None,
+            },
+            WhitelistSubject::Role(role_id) => Self {
+                user_id: None,
+                role_id: Some(Some(role_id)),
+            },
+        }
+    }
+}
+
+/// Client and realm maintenance mode, with the whitelists that bypass it.
+pub struct Maintenance<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> Maintenance<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    /// Puts a client in or out of maintenance; returns the server acknowledgement.
+    pub async fn set_client_maintenance(
+        &self,
+        client_id: Uuid,
+        request: &ToggleMaintenanceValidator,
+    ) -> Result<String, FerrisKeyError> {
+        let client_id = &client_id.to_string();
+        let response = self
+            .admin
+            .realm_call(|config, realm| async move {
+                maintenance_api::toggle_maintenance(&config, realm, client_id, request.clone())
+                    .await
+            })
+            .await?;
+        Ok(response.message)
+    }
+
+    pub async fn client_whitelist(
+        &self,
+        client_id: Uuid,
+    ) -> Result<Vec<MaintenanceWhitelistEntry>, FerrisKeyError> {
+        let client_id = &client_id.to_string();
+        let entries = self
+            .admin
+            .realm_call(|config, realm| async move {
+                maintenance_api::get_client_whitelist(&config, realm, client_id).await
+            })
+            .await?;
+        Ok(entries.data)
+    }
+
+    pub async fn add_client_whitelist_entry(
+        &self,
+        client_id: Uuid,
+        subject: WhitelistSubject,
+    ) -> Result<MaintenanceWhitelistEntry, FerrisKeyError> {
+        let client_id = &client_id.to_string();
+        let entry = self
+            .admin
+            .realm_call(|config, realm| async move {
+                maintenance_api::add_client_whitelist_entry(
+                    &config,
+                    realm,
+                    client_id,
+                    subject.into(),
+                )
+                .await
+            })
+            .await?;
+        Ok(*entry.data)
+    }
+
+    pub async fn remove_client_whitelist_entry(
+        &self,
+        client_id: Uuid,
+        entry_id: Uuid,
+    ) -> Result<(), FerrisKeyError> {
+        let client_id = &client_id.to_string();
+        let entry_id = &entry_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                maintenance_api::remove_client_whitelist_entry(&config, realm, client_id, entry_id)
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn realm_whitelist(
+        &self,
+    ) -> Result<Vec<RealmMaintenanceWhitelistEntry>, FerrisKeyError> {
+        let entries = self
+            .admin
+            .realm_call(|config, realm| async move {
+                maintenance_api::get_realm_whitelist(&config, realm).await
+            })
+            .await?;
+        Ok(entries.data)
+    }
+
+    pub async fn add_realm_whitelist_entry(
+        &self,
+        subject: WhitelistSubject,
+    ) -> Result<RealmMaintenanceWhitelistEntry, FerrisKeyError> {
+        let entry = self
+            .admin
+            .realm_call(|config, realm| async move {
+                maintenance_api::add_realm_whitelist_entry(&config, realm, subject.into()).await
+            })
+            .await?;
+        Ok(*entry.data)
+    }
+
+    pub async fn remove_realm_whitelist_entry(&self, entry_id: Uuid) -> Result<(), FerrisKeyError> {
+        let entry_id = &entry_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                maintenance_api::remove_realm_whitelist_entry(&config, realm, entry_id).await
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn schedule_window(
+        &self,
+        request: &ScheduleMaintenanceWindowValidator,
+    ) -> Result<MaintenanceWindow, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                maintenance_api::schedule_maintenance_window(&config, realm, request.clone()).await
+            })
+            .await
+    }
+
+    pub async fn windows(&self) -> Result<Vec<MaintenanceWindow>, FerrisKeyError> {
+        let windows = self
+            .admin
+            .realm_call(|config, realm| async move {
+                maintenance_api::get_maintenance_windows(&config, realm).await
+            })
+            .await?;
+        Ok(windows.data)
+    }
+
+    /// Cancels a scheduled window, or ends an active one early.
+    pub async fn cancel_window(
+        &self,
+        window_id: Uuid,
+    ) -> Result<MaintenanceWindow, FerrisKeyError> {
+        let window_id = &window_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                maintenance_api::cancel_maintenance_window(&config, realm, window_id).await
+            })
+            .await
+    }
+
+    /// The announcements the login portal shows, optionally only those for one OAuth client.
+    pub async fn announcements(
+        &self,
+        client_id: Option<&str>,
+    ) -> Result<Vec<MaintenanceAnnouncement>, FerrisKeyError> {
+        let announcements = self
+            .admin
+            .realm_call(|config, realm| async move {
+                maintenance_api::get_maintenance_announcements(&config, realm, client_id).await
+            })
+            .await?;
+        Ok(announcements.data)
+    }
+}
@@ -0,0 +1,578 @@
+use ferriskey_client::apis::organization_api;
+use ferriskey_client::models::{
+    AddGroupMemberValidator, AddMemberValidator, AssignGroupRoleValidator,
+    AssignMemberRoleValidator, ConfigureDomainValidator, CreateGroupValidator,
+    CreateInvitationValidator, CreateOrganizationValidator, Group, GroupAttribute, GroupMember,
+    GroupMemberDetail, GroupMemberPage, GroupNode, Organization, OrganizationAttribute,
+    OrganizationDomainStatus, OrganizationInvitation, OrganizationMember, Role,
+    UpdateGroupValidator, UpdateOrganizationValidator, UpsertAttributeValidator,
+};
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+use crate::pagination::{Page, collect_pages};
+
+/// `/realms/{realm}/organizations`.
+pub struct Organizations<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> Organizations<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Organization>, FerrisKeyError> {
+        let organizations = self
+            .admin
+            .realm_call(|config, realm| async move {
+                organization_api::list_organizations(&config, realm).await
+            })
+            .await?;
+        Ok(organizations.data)
+    }
+
+    pub async fn get(&self, organization_id: Uuid) -> Result<Organization, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::get_organization(&config, realm, organization_id).await
+            })
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        organization: &CreateOrganizationValidator,
+    ) -> Result<Organization, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::create_organization(&config, realm, organization.clone()).await
+            })
+            .await
+    }
+
+    /// Fields left to `None` keep their current value.
+    pub async fn update(
+        &self,
+        organization_id: Uuid,
+        changes: &UpdateOrganizationValidator,
+    ) -> Result<Organization, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::update_organization(
+                    &config,
+                    realm,
+                    organization_id,
+                    changes.clone(),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn delete(&self, organization_id: Uuid) -> Result<(), FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::delete_organization(&config, realm, organization_id).await
+            })
+            .await
+    }
+
+    pub async fn attributes(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<OrganizationAttribute>, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        let attributes = self
+            .admin
+            .realm_call(|config, realm| async move {
+                organization_api::list_attributes(&config, realm, organization_id).await
+            })
+            .await?;
+        Ok(attributes.data)
+    }
+
+    pub async fn set_attribute(
+        &self,
+        organization_id: Uuid,
+        key: &str,
+        value: &str,
+    ) -> Result<OrganizationAttribute, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::upsert_attribute(
+                    &config,
+                    realm,
+                    organization_id,
+                    key,
+                    UpsertAttributeValidator::new(value.to_string()),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn delete_attribute(
+        &self,
+        organization_id: Uuid,
+        key: &str,
+    ) -> Result<(), FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::delete_attribute(&config, realm, organization_id, key).await
+            })
+            .await
+    }
+
+    pub async fn members(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<OrganizationMember>, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::list_members(&config, realm, organization_id).await
+            })
+            .await
+    }
+
+    pub async fn add_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<OrganizationMember, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::add_member(
+                    &config,
+                    realm,
+                    organization_id,
+                    AddMemberValidator::new(user_id),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn remove_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        let user_id = &user_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::remove_member(&config, realm, organization_id, user_id).await
+            })
+            .await
+    }
+
+    pub async fn member_roles(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<Role>, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        let user_id = &user_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::list_member_roles(&config, realm, organization_id, user_id).await
+            })
+            .await
+    }
+
+    pub async fn assign_member_role(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        role_id: Uuid,
+    ) -> Result<(), FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        let user_id = &user_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::assign_member_role(
+                    &config,
+                    realm,
+                    organization_id,
+                    user_id,
+                    AssignMemberRoleValidator::new(role_id),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn revoke_member_role(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        role_id: Uuid,
+    ) -> Result<(), FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        let user_id = &user_id.to_string();
+        let role_id = &role_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::revoke_member_role(
+                    &config,
+                    realm,
+                    organization_id,
+                    user_id,
+                    role_id,
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn invitations(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<OrganizationInvitation>, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::list_invitations(&config, realm, organization_id).await
+            })
+            .await
+    }
+
+    /// Creates the invitation and emails its link to `invitation.email`.
+    pub async fn invite(
+        &self,
+        organization_id: Uuid,
+        invitation: &CreateInvitationValidator,
+    ) -> Result<OrganizationInvitation, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::create_invitation(
+                    &config,
+                    realm,
+                    organization_id,
+                    invitation.clone(),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn revoke_invitation(
+        &self,
+        organization_id: Uuid,
+        invitation_id: Uuid,
+    ) -> Result<OrganizationInvitation, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        let invitation_id = &invitation_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::revoke_invitation(&config, realm, organization_id, invitation_id)
+                    .await
+            })
+            .await
+    }
+
+    /// The verified domain of the organization and the DNS TXT record proving it.
+    pub async fn domain(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<OrganizationDomainStatus, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::get_organization_domain(&config, realm, organization_id).await
+            })
+            .await
+    }
+
+    /// Starts the verification of the organization domain and links the identity provider
+    /// its users are sent to; returns the TXT record to publish.
+    pub async fn configure_domain(
+        &self,
+        organization_id: Uuid,
+        domain: &ConfigureDomainValidator,
+    ) -> Result<OrganizationDomainStatus, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::configure_organization_domain(
+                    &config,
+                    realm,
+                    organization_id,
+                    domain.clone(),
+                )
+                .await
+            })
+            .await
+    }
+
+    /// Checks the DNS TXT record proving control of the domain.
+    pub async fn verify_domain(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<OrganizationDomainStatus, FerrisKeyError> {
+        let organization_id = &organization_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::verify_organization_domain(&config, realm, organization_id).await
+            })
+            .await
+    }
+
+    pub fn groups(&self, organization_id: Uuid) -> OrganizationGroups<'a> {
+        OrganizationGroups {
+            admin: self.admin,
+            organization_id: organization_id.to_string(),
+        }
+    }
+}
+
+/// `/realms/{realm}/organizations/{organization_id}/groups`.
+pub struct OrganizationGroups<'a> {
+    admin: &'a FerrisKeyAdmin,
+    organization_id: String,
+}
+
+impl OrganizationGroups<'_> {
+    /// The groups as a tree, roots first.
+    pub async fn list(&self) -> Result<Vec<GroupNode>, FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::list_groups(&config, realm, organization_id).await
+            })
+            .await
+    }
+
+    pub async fn get(&self, group_id: Uuid) -> Result<Group, FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::get_group(&config, realm, organization_id, group_id).await
+            })
+            .await
+    }
+
+    pub async fn create(&self, group: &CreateGroupValidator) -> Result<Group, FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::create_group(&config, realm, organization_id, group.clone()).await
+            })
+            .await
+    }
+
+    pub async fn update(
+        &self,
+        group_id: Uuid,
+        changes: &UpdateGroupValidator,
+    ) -> Result<Group, FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::update_group(
+                    &config,
+                    realm,
+                    organization_id,
+                    group_id,
+                    changes.clone(),
+                )
+                .await
+            })
+            .await
+    }
+
+    /// Deletes the group with its subgroups.
+    pub async fn delete(&self, group_id: Uuid) -> Result<(), FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::delete_group(&config, realm, organization_id, group_id).await
+            })
+            .await
+    }
+
+    pub async fn attributes(&self, group_id: Uuid) -> Result<Vec<GroupAttribute>, FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::list_group_attributes(&config, realm, organization_id, group_id)
+                    .await
+            })
+            .await
+    }
+
+    pub async fn set_attribute(
+        &self,
+        group_id: Uuid,
+        key: &str,
+        value: &str,
+    ) -> Result<GroupAttribute, FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::upsert_group_attribute(
+                    &config,
+                    realm,
+                    organization_id,
+                    group_id,
+                    key,
+                    UpsertAttributeValidator::new(value.to_string()),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn delete_attribute(&self, group_id: Uuid, key: &str) -> Result<(), FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::delete_group_attribute(
+                    &config,
+                    realm,
+                    organization_id,
+                    group_id,
+                    key,
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn members(
+        &self,
+        group_id: Uuid,
+        search: Option<&str>,
+        page: Page,
+    ) -> Result<GroupMemberPage, FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::list_group_members(
+                    &config,
+                    realm,
+                    organization_id,
+                    group_id,
+                    Some(page.limit as i32),
+                    Some(page.offset as i32),
+                    search,
+                )
+                .await
+            })
+            .await
+    }
+
+    /// Every member of the group, fetched `page_size` at a time.
+    pub async fn all_members(
+        &self,
+        group_id: Uuid,
+        page_size: u32,
+    ) -> Result<Vec<GroupMemberDetail>, FerrisKeyError> {
+        collect_pages(page_size, |page| async move {
+            Ok(self.members(group_id, None, page).await?.data)
+        })
+        .await
+    }
+
+    pub async fn add_member(
+        &self,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<GroupMember, FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::add_group_member(
+                    &config,
+                    realm,
+                    organization_id,
+                    group_id,
+                    AddGroupMemberValidator::new(user_id),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn remove_member(&self, group_id: Uuid, user_id: Uuid) -> Result<(), FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        let user_id = &user_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::remove_group_member(
+                    &config,
+                    realm,
+                    organization_id,
+                    group_id,
+                    user_id,
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn roles(&self, group_id: Uuid) -> Result<Vec<Role>, FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::list_group_roles(&config, realm, organization_id, group_id).await
+            })
+            .await
+    }
+
+    /// Members of the group, and of its subgroups, inherit the role.
+    pub async fn assign_role(&self, group_id: Uuid, role_id: Uuid) -> Result<(), FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::assign_group_role(
+                    &config,
+                    realm,
+                    organization_id,
+                    group_id,
+                    AssignGroupRoleValidator::new(role_id),
+                )
+                .await
+            })
+            .await
+    }
+
+    pub async fn revoke_role(&self, group_id: Uuid, role_id: Uuid) -> Result<(), FerrisKeyError> {
+        let organization_id = &self.organization_id;
+        let group_id = &group_id.to_string();
+        let role_id = &role_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                organization_api::revoke_group_role(
+                    &config,
+                    realm,
+                    organization_id,
+                    group_id,
+                    role_id,
+                )
+                .await
+            })
+            .await
+    }
+}
@@ -0,0 +1,78 @@
+use std::future::Future;
+
+use crate::error::FerrisKeyError;
+
+/// One `limit`/`offset` window of a paginated route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Page {
+    pub limit: u32,
+    pub offset: u32,
+}
+
+impl Page {
+    pub fn first(limit: u32) -> Self {
+        Self { limit, offset: 0 }
+    }
+
+    pub fn next(self) -> Self {
+        Self {
+            limit: self.limit,
+            offset: self.offset + self.limit,
+        }
+    }
+}
+
+/// Fetches pages of `page_size` items until one comes back short, and concatenates them.
+pub async fn collect_pages<T, F, Fut>(
+    page_size: u32,
+    mut fetch: F,
+) -> Result<Vec<T>, FerrisKeyError>
+where
+    F: FnMut(Page) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, FerrisKeyError>>,
+{
+    let page_size = page_size.max(1);
+    let mut page = Page::first(page_size);
+    let mut items = Vec::new();
+
+    loop {
+        let batch = fetch(page).await?;
+        let complete = batch.len() < page_size as usize;
+        items.extend(batch);
+
+        if complete {
+            return Ok(items);
+        }
+        page = page.next();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn stops_at_the_first_short_page() {
+        let source: Vec<u32> = (0..7).collect();
+        let mut calls = Vec::new();
+
+        let items = collect_pages(3, |page| {
+            calls.push(page);
+            let batch = source
+                .iter()
+                .skip(page.offset as usize)
+                .take(page.limit as usize)
+                .copied()
+                .collect();
+            async move { Ok(batch) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(items, source);
+        assert_eq!(
+            calls.iter().map(|page| page.offset).collect::<Vec<_>>(),
+            vec![0, 3, 6]
+        );
+    }
+}
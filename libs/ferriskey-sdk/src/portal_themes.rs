@@ -0,0 +1,130 @@
+use ferriskey_client::apis::{portal_theme_api, portal_theme_public_api};
+use ferriskey_client::models::{
+    ActiveThemeResponse, CreateThemeValidator, PortalPageType, PortalTheme,
+    UpdateThemeMetadataValidator, UpdateThemePageValidator,
+};
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// `/realms/{realm}/portal/themes`.
+pub struct PortalThemes<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> PortalThemes<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    pub async fn list(&self) -> Result<Vec<PortalTheme>, FerrisKeyError> {
+        let themes = self
+            .admin
+            .realm_call(|config, realm| async move {
+                portal_theme_api::list_themes(&config, realm).await
+            })
+            .await?;
+        Ok(themes.data)
+    }
+
+    pub async fn get(&self, theme_id: Uuid) -> Result<PortalTheme, FerrisKeyError> {
+        let theme_id = &theme_id.to_string();
+        let theme = self
+            .admin
+            .realm_call(|config, realm| async move {
+                portal_theme_api::get_theme_by_id(&config, realm, theme_id).await
+            })
+            .await?;
+        Ok(*theme.data)
+    }
+
+    pub async fn create(
+        &self,
+        theme: &CreateThemeValidator,
+    ) -> Result<PortalTheme, FerrisKeyError> {
+        let theme = self
+            .admin
+            .realm_call(|config, realm| async move {
+                portal_theme_api::create_theme(&config, realm, theme.clone()).await
+            })
+            .await?;
+        Ok(*theme.data)
+    }
+
+    /// Name, layout and design tokens; the pages are edited one at a time with
+    /// [`Self::update_page`].
+    pub async fn update(
+        &self,
+        theme_id: Uuid,
+        theme: &UpdateThemeMetadataValidator,
+    ) -> Result<PortalTheme, FerrisKeyError> {
+        let theme_id = &theme_id.to_string();
+        let theme = self
+            .admin
+            .realm_call(|config, realm| async move {
+                portal_theme_api::update_theme_metadata(&config, realm, theme_id, theme.clone())
+                    .await
+            })
+            .await?;
+        Ok(*theme.data)
+    }
+
+    pub async fn update_page(
+        &self,
+        theme_id: Uuid,
+        page_type: PortalPageType,
+        tree: &serde_json::Value,
+    ) -> Result<PortalTheme, FerrisKeyError> {
+        let theme_id = &theme_id.to_string();
+        let theme = self
+            .admin
+            .realm_call(|config, realm| async move {
+                portal_theme_api::update_theme_page(
+                    &config,
+                    realm,
+                    theme_id,
+                    page_type,
+                    UpdateThemePageValidator::new(Some(tree.clone())),
+                )
+                .await
+            })
+            .await?;
+        Ok(*theme.data)
+    }
+
+    pub async fn delete(&self, theme_id: Uuid) -> Result<(), FerrisKeyError> {
+        let theme_id = &theme_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                portal_theme_api::delete_theme(&config, realm, theme_id).await
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Makes the theme the one the login portal renders; the previously active theme is
+    /// deactivated.
+    pub async fn activate(&self, theme_id: Uuid) -> Result<(), FerrisKeyError> {
+        let theme_id = &theme_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                portal_theme_api::activate_theme(&config, realm, theme_id).await
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// What the login portal renders for one page: the active theme, or the defaults when
+    /// no theme is active.
+    pub async fn active(
+        &self,
+        page_type: PortalPageType,
+    ) -> Result<ActiveThemeResponse, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                portal_theme_public_api::get_active_theme(&config, realm, page_type).await
+            })
+            .await
+    }
+}
@@ -0,0 +1,93 @@
+use ferriskey_client::apis::rate_limit_api;
+use ferriskey_client::models::{
+    CreateRateLimitListEntryValidator, CreateRateLimitPolicyValidator, RateLimitListEntry,
+    RateLimitOverview, RateLimitPolicy, UpdateRateLimitPolicyValidator,
+};
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// `/realms/{realm}/rate-limits`: the request budgets of the realm and the addresses always
+/// or never let through.
+pub struct RateLimits<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> RateLimits<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    /// The policies of the realm and the rules in effect for each endpoint.
+    pub async fn overview(&self) -> Result<RateLimitOverview, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                rate_limit_api::get_rate_limits(&config, realm).await
+            })
+            .await
+    }
+
+    pub async fn create_policy(
+        &self,
+        policy: &CreateRateLimitPolicyValidator,
+    ) -> Result<RateLimitPolicy, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                rate_limit_api::create_rate_limit_policy(&config, realm, policy.clone()).await
+            })
+            .await
+    }
+
+    pub async fn update_policy(
+        &self,
+        policy_id: Uuid,
+        changes: &UpdateRateLimitPolicyValidator,
+    ) -> Result<RateLimitPolicy, FerrisKeyError> {
+        let policy_id = &policy_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                rate_limit_api::update_rate_limit_policy(&config, realm, policy_id, changes.clone())
+                    .await
+            })
+            .await
+    }
+
+    pub async fn delete_policy(&self, policy_id: Uuid) -> Result<(), FerrisKeyError> {
+        let policy_id = &policy_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                rate_limit_api::delete_rate_limit_policy(&config, realm, policy_id).await
+            })
+            .await
+    }
+
+    /// The allow and deny list entries.
+    pub async fn list_entries(&self) -> Result<Vec<RateLimitListEntry>, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                rate_limit_api::get_rate_limit_list(&config, realm).await
+            })
+            .await
+    }
+
+    pub async fn add_list_entry(
+        &self,
+        entry: &CreateRateLimitListEntryValidator,
+    ) -> Result<RateLimitListEntry, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                rate_limit_api::create_rate_limit_list_entry(&config, realm, entry.clone()).await
+            })
+            .await
+    }
+
+    pub async fn remove_list_entry(&self, entry_id: Uuid) -> Result<(), FerrisKeyError> {
+        let entry_id = &entry_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                rate_limit_api::delete_rate_limit_list_entry(&config, realm, entry_id).await
+            })
+            .await
+    }
+}
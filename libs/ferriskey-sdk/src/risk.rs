@@ -0,0 +1,47 @@
+use ferriskey_client::apis::login_risk_api;
+use ferriskey_client::models::{LoginRecord, LoginRiskPolicy, UpdateLoginRiskPolicyValidator};
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// Login risk scoring: the policy deciding when a login is stepped up, refused or reported
+/// to the user, and the logins it scored.
+pub struct Risk<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> Risk<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    pub async fn policy(&self) -> Result<LoginRiskPolicy, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                login_risk_api::get_login_risk_policy(&config, realm).await
+            })
+            .await
+    }
+
+    pub async fn update_policy(
+        &self,
+        policy: &UpdateLoginRiskPolicyValidator,
+    ) -> Result<LoginRiskPolicy, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                login_risk_api::update_login_risk_policy(&config, realm, policy.clone()).await
+            })
+            .await
+    }
+
+    /// Recent logins of the user, with their score and the signals behind it.
+    pub async fn login_history(&self, user_id: Uuid) -> Result<Vec<LoginRecord>, FerrisKeyError> {
+        let user_id = &user_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                login_risk_api::get_login_history(&config, realm, user_id).await
+            })
+            .await
+    }
+}
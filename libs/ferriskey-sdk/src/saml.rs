@@ -0,0 +1,81 @@
+use ferriskey_client::apis::client_api;
+use ferriskey_client::models::{
+    ImportSamlMetadataValidator, SamlClientSettings, UpdateSamlClientSettingsRequest,
+};
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// SAML 2.0 on both sides: the realm as identity provider of `saml` clients, and as service
+/// provider of SAML identity providers.
+pub struct Saml<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> Saml<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    /// The IdP EntityDescriptor of the realm, the XML service providers are configured with.
+    pub async fn idp_descriptor(&self) -> Result<String, FerrisKeyError> {
+        self.admin
+            .get_text(&self.admin.realm_path("/protocol/saml/descriptor"), &[])
+            .await
+    }
+
+    /// The SP EntityDescriptor the realm presents to the SAML identity provider `alias`.
+    pub async fn broker_descriptor(&self, alias: &str) -> Result<String, FerrisKeyError> {
+        let alias = ferriskey_client::apis::urlencode(alias);
+        self.admin
+            .get_text(
+                &self
+                    .admin
+                    .realm_path(&format!("/broker/{alias}/endpoint/descriptor")),
+                &[],
+            )
+            .await
+    }
+
+    /// The service provider settings of a `saml` client.
+    pub async fn client_settings(
+        &self,
+        client_id: Uuid,
+    ) -> Result<SamlClientSettings, FerrisKeyError> {
+        let client_id = &client_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                client_api::get_saml_settings(&config, realm, client_id).await
+            })
+            .await
+    }
+
+    pub async fn update_client_settings(
+        &self,
+        client_id: Uuid,
+        settings: &UpdateSamlClientSettingsRequest,
+    ) -> Result<SamlClientSettings, FerrisKeyError> {
+        let client_id = &client_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                client_api::update_saml_settings(&config, realm, client_id, settings.clone()).await
+            })
+            .await
+    }
+
+    /// Overwrites the endpoints and certificates of the client with those of the
+    /// SPSSODescriptor in the metadata; options it does not describe are kept.
+    pub async fn import_client_metadata(
+        &self,
+        client_id: Uuid,
+        metadata: &ImportSamlMetadataValidator,
+    ) -> Result<SamlClientSettings, FerrisKeyError> {
+        let client_id = &client_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                client_api::import_saml_metadata(&config, realm, client_id, metadata.clone()).await
+            })
+            .await
+    }
+}
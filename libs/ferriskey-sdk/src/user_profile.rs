@@ -0,0 +1,48 @@
+use ferriskey_client::apis::user_profile_api;
+use ferriskey_client::models::{
+    UpdateUserProfileSchemaValidator, UserProfileConfig, UserProfileSchema,
+};
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// `/realms/{realm}/user-profile`, the attributes users carry and how they are validated.
+pub struct UserProfile<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> UserProfile<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    pub async fn schema(&self) -> Result<UserProfileSchema, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                user_profile_api::get_user_profile_schema(&config, realm).await
+            })
+            .await
+    }
+
+    /// Replaces the whole schema. Stored values are kept; the new rules apply from their next
+    /// change.
+    pub async fn update_schema(
+        &self,
+        schema: &UpdateUserProfileSchemaValidator,
+    ) -> Result<UserProfileSchema, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                user_profile_api::update_user_profile_schema(&config, realm, schema.clone()).await
+            })
+            .await
+    }
+
+    /// The attributes of the registration form.
+    pub async fn registration(&self) -> Result<UserProfileConfig, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                user_profile_api::get_registration_profile(&config, realm).await
+            })
+            .await
+    }
+}
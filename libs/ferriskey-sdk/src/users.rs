@@ -0,0 +1,37 @@
+use ferriskey_client::apis::{group_api, user_api};
+use ferriskey_client::models::RealmGroupMembership;
+use uuid::Uuid;
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// Lockouts and realm groups of users; their CRUD is in `user_api`.
+pub struct Users<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> Users<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    /// Lifts a brute-force lockout.
+    pub async fn unlock(&self, user_id: Uuid) -> Result<(), FerrisKeyError> {
+        let user_id = &user_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                user_api::unlock_user(&config, realm, user_id).await
+            })
+            .await
+    }
+
+    /// The realm groups of the user, direct and inherited from parent groups.
+    pub async fn groups(&self, user_id: Uuid) -> Result<RealmGroupMembership, FerrisKeyError> {
+        let user_id = &user_id.to_string();
+        self.admin
+            .realm_call(|config, realm| async move {
+                group_api::get_user_groups(&config, realm, user_id).await
+            })
+            .await
+    }
+}
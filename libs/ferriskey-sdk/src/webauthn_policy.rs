@@ -0,0 +1,45 @@
+use ferriskey_client::apis::webauthn_policy_api;
+use ferriskey_client::models::{MetadataSummary, UpdateWebAuthnPolicyValidator, WebAuthnPolicy};
+
+use crate::admin::FerrisKeyAdmin;
+use crate::error::FerrisKeyError;
+
+/// `/realms/{realm}/webauthn-policy`: the authenticators the realm accepts for passkeys
+/// and security keys.
+pub struct WebAuthn<'a> {
+    admin: &'a FerrisKeyAdmin,
+}
+
+impl<'a> WebAuthn<'a> {
+    pub(crate) fn new(admin: &'a FerrisKeyAdmin) -> Self {
+        Self { admin }
+    }
+
+    pub async fn get(&self) -> Result<WebAuthnPolicy, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                webauthn_policy_api::get_webauthn_policy(&config, realm).await
+            })
+            .await
+    }
+
+    pub async fn update(
+        &self,
+        policy: &UpdateWebAuthnPolicyValidator,
+    ) -> Result<WebAuthnPolicy, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                webauthn_policy_api::update_webauthn_policy(&config, realm, policy.clone()).await
+            })
+            .await
+    }
+
+    /// The FIDO metadata attestations are verified against.
+    pub async fn fido_metadata(&self) -> Result<MetadataSummary, FerrisKeyError> {
+        self.admin
+            .realm_call(|config, realm| async move {
+                webauthn_policy_api::get_fido_metadata(&config, realm).await
+            })
+            .await
+    }
+}
@@ -81,6 +81,18 @@ pub enum SecurityEventType {
 
     #[serde(rename = "identity_provider_link_removed")]
     IdentityProviderLinkRemoved,
+
+    #[serde(rename = "rate_limit_exceeded")]
+    RateLimitExceeded,
+
+    #[serde(rename = "deny_list_blocked")]
+    DenyListBlocked,
+
+    #[serde(rename = "login_risk_evaluated")]
+    LoginRiskEvaluated,
+
+    #[serde(rename = "login_risk_blocked")]
+    LoginRiskBlocked,
 }
 
 impl Display for SecurityEventType {
@@ -116,6 +128,10 @@ impl Display for SecurityEventType {
             SecurityEventType::IdentityProviderLinkRemoved => {
                 write!(f, "identity_provider_link_removed")
             }
+            SecurityEventType::RateLimitExceeded => write!(f, "rate_limit_exceeded"),
+            SecurityEventType::DenyListBlocked => write!(f, "deny_list_blocked"),
+            SecurityEventType::LoginRiskEvaluated => write!(f, "login_risk_evaluated"),
+            SecurityEventType::LoginRiskBlocked => write!(f, "login_risk_blocked"),
         }
     }
 }
@@ -219,11 +235,23 @@ impl SecurityEvent {
         event_type: SecurityEventType,
         status: EventStatus,
         actor_id: Uuid,
+    ) -> Self {
+        Self {
+            actor_id: Some(actor_id),
+            ..Self::anonymous(realm_id, event_type, status)
+        }
+    }
+
+    /// An event no known actor triggered, such as a request rejected before any login.
+    pub fn anonymous(
+        realm_id: RealmId,
+        event_type: SecurityEventType,
+        status: EventStatus,
     ) -> Self {
         Self {
             id: SecurityEventId::new(),
             realm_id,
-            actor_id: Some(actor_id),
+            actor_id: None,
             actor_type: None,
             event_type,
             status,
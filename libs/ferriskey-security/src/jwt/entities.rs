@@ -309,6 +309,7 @@ impl RefreshToken {
     }
 }
 
+#[derive(Clone)]
 pub struct AccessToken {
     pub id: Uuid,
     pub token_hash: String,
@@ -0,0 +1,230 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use ferriskey_domain::realm::RealmId;
+
+/// Attribute names that belong to the fixed user columns and cannot be declared in a schema.
+pub const RESERVED_ATTRIBUTE_NAMES: [&str; 6] = [
+    "username",
+    "email",
+    "email_verified",
+    "firstname",
+    "lastname",
+    "password",
+];
+
+/// How an attribute value is parsed. Values are stored as text either way; the type drives
+/// validation and the JSON type of the claim a protocol mapper emits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeType {
+    #[default]
+    String,
+    Integer,
+    Boolean,
+    /// An ISO 8601 calendar date, `YYYY-MM-DD`.
+    Date,
+}
+
+/// Who reads or writes an attribute: the user themselves or a realm administrator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Audience {
+    User,
+    Admin,
+}
+
+/// Where a profile is being written from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileContext {
+    Registration,
+    Admin,
+    SelfService,
+}
+
+impl ProfileContext {
+    pub fn audience(self) -> Audience {
+        match self {
+            ProfileContext::Registration | ProfileContext::SelfService => Audience::User,
+            ProfileContext::Admin => Audience::Admin,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AttributeValidator {
+    /// The whole value must match the regular expression.
+    Pattern {
+        pattern: String,
+        /// Shown instead of the default message when the value does not match.
+        #[serde(default)]
+        message: Option<String>,
+    },
+    /// Bounds on the number of characters.
+    Length {
+        #[serde(default)]
+        min: Option<usize>,
+        #[serde(default)]
+        max: Option<usize>,
+    },
+    /// The value must be one of `values`.
+    Options { values: Vec<String> },
+    /// Bounds on a `date` attribute.
+    Date {
+        #[serde(default)]
+        min: Option<NaiveDate>,
+        #[serde(default)]
+        max: Option<NaiveDate>,
+        #[serde(default)]
+        not_in_future: bool,
+    },
+}
+
+/// Audiences allowed to read and to write an attribute. Write access implies read access.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AttributePermissions {
+    #[serde(default)]
+    pub view: Vec<Audience>,
+    #[serde(default)]
+    pub edit: Vec<Audience>,
+}
+
+impl Default for AttributePermissions {
+    fn default() -> Self {
+        Self {
+            view: vec![Audience::User, Audience::Admin],
+            edit: vec![Audience::Admin],
+        }
+    }
+}
+
+impl AttributePermissions {
+    pub fn can_view(&self, audience: Audience) -> bool {
+        self.view.contains(&audience) || self.can_edit(audience)
+    }
+
+    pub fn can_edit(&self, audience: Audience) -> bool {
+        self.edit.contains(&audience)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct UserProfileAttribute {
+    pub name: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default, rename = "type")]
+    pub attribute_type: AttributeType,
+    #[serde(default)]
+    pub validators: Vec<AttributeValidator>,
+    /// Contexts in which the attribute must have a value.
+    #[serde(default)]
+    pub required_for: Vec<ProfileContext>,
+    #[serde(default)]
+    pub permissions: AttributePermissions,
+    /// Name of the [`AttributeGroup`] the attribute is displayed in.
+    #[serde(default)]
+    pub group: Option<String>,
+}
+
+impl UserProfileAttribute {
+    pub fn is_required_for(&self, context: ProfileContext) -> bool {
+        self.required_for.contains(&context)
+    }
+}
+
+/// A heading under which forms display related attributes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AttributeGroup {
+    pub name: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// What happens to attributes the schema does not declare.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UnmanagedAttributePolicy {
+    /// Anyone who can edit the profile can set them.
+    Enabled,
+    /// Only administrators can set them, as before schemas existed.
+    #[default]
+    AdminEdit,
+    /// Nobody can set them.
+    Disabled,
+}
+
+/// The editable part of a realm's user profile schema.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct UserProfileConfig {
+    #[serde(default)]
+    pub attributes: Vec<UserProfileAttribute>,
+    #[serde(default)]
+    pub groups: Vec<AttributeGroup>,
+    #[serde(default)]
+    pub unmanaged_attributes: UnmanagedAttributePolicy,
+}
+
+/// The attributes users of a realm have beyond the fixed user columns, and the rules their
+/// values follow. Realms without one behave as if it declared nothing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct UserProfileSchema {
+    pub realm_id: RealmId,
+    #[serde(flatten)]
+    pub config: UserProfileConfig,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UserProfileSchema {
+    pub fn new(
+        realm_id: RealmId,
+        config: UserProfileConfig,
+    ) -> Result<Self, Vec<UserProfileViolation>> {
+        config.validate()?;
+
+        let now = Utc::now();
+        Ok(Self {
+            realm_id,
+            config,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn empty_for(realm_id: RealmId) -> Self {
+        let now = Utc::now();
+        Self {
+            realm_id,
+            config: UserProfileConfig::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// One reason a profile or a schema was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct UserProfileViolation {
+    pub attribute: String,
+    pub message: String,
+}
+
+impl UserProfileViolation {
+    pub fn new(attribute: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            attribute: attribute.into(),
+            message: message.into(),
+        }
+    }
+}
+
+pub struct UpdateUserProfileSchemaInput {
+    pub realm_name: String,
+    pub config: UserProfileConfig,
+}
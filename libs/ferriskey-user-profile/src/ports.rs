@@ -0,0 +1,53 @@
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::{Realm, RealmId};
+
+use crate::entities::{UpdateUserProfileSchemaInput, UserProfileConfig, UserProfileSchema};
+
+/// Persistence for the user profile schema of each realm.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait UserProfileSchemaRepository: Send + Sync {
+    fn get_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<UserProfileSchema>, CoreError>> + Send;
+
+    fn upsert(
+        &self,
+        schema: UserProfileSchema,
+    ) -> impl Future<Output = Result<UserProfileSchema, CoreError>> + Send;
+}
+
+pub trait UserProfileAccessPolicy: Send + Sync {
+    fn can_view_user_profile_schema(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_manage_user_profile_schema(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+pub trait UserProfileService: Send + Sync {
+    fn get_user_profile_schema(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<UserProfileSchema, CoreError>> + Send;
+
+    fn update_user_profile_schema(
+        &self,
+        identity: Identity,
+        input: UpdateUserProfileSchemaInput,
+    ) -> impl Future<Output = Result<UserProfileSchema, CoreError>> + Send;
+
+    /// The attributes a self-registering user fills in. Public, like the registration form.
+    fn get_registration_profile(
+        &self,
+        realm_name: String,
+    ) -> impl Future<Output = Result<UserProfileConfig, CoreError>> + Send;
+}
@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::client::ports::ClientRepository;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use ferriskey_domain::realm::ports::RealmRepository;
+use ferriskey_domain::realm::{Realm, RealmId};
+use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
+
+use crate::validation::violations_error;
+use crate::{
+    UpdateUserProfileSchemaInput, UserProfileAccessPolicy, UserProfileConfig, UserProfileSchema,
+    UserProfileSchemaRepository, UserProfileService,
+};
+
+/// The profile configuration of a realm, empty when it never declared a schema. Shared by
+/// the services that write user attributes.
+pub async fn realm_profile_config<PS>(
+    repository: &PS,
+    realm_id: RealmId,
+) -> Result<UserProfileConfig, CoreError>
+where
+    PS: UserProfileSchemaRepository,
+{
+    Ok(repository
+        .get_by_realm(realm_id)
+        .await?
+        .map(|schema| schema.config)
+        .unwrap_or_default())
+}
+
+#[derive(Clone, Debug)]
+pub struct UserProfileServiceImpl<R, U, C, UR, PS>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    PS: UserProfileSchemaRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) schema_repository: Arc<PS>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, PS> UserProfileServiceImpl<R, U, C, UR, PS>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    PS: UserProfileSchemaRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        schema_repository: Arc<PS>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            schema_repository,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+}
+
+impl<R, U, C, UR, PS> UserProfileService for UserProfileServiceImpl<R, U, C, UR, PS>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    PS: UserProfileSchemaRepository,
+{
+    async fn get_user_profile_schema(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<UserProfileSchema, CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+        ensure_policy(
+            self.policy
+                .can_view_user_profile_schema(&identity, &realm)
+                .await,
+            "insufficient permissions to view the user profile schema",
+        )?;
+
+        Ok(self
+            .schema_repository
+            .get_by_realm(realm.id)
+            .await?
+            .unwrap_or_else(|| UserProfileSchema::empty_for(realm.id)))
+    }
+
+    async fn update_user_profile_schema(
+        &self,
+        identity: Identity,
+        input: UpdateUserProfileSchemaInput,
+    ) -> Result<UserProfileSchema, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        ensure_policy(
+            self.policy
+                .can_manage_user_profile_schema(&identity, &realm)
+                .await,
+            "insufficient permissions to manage the user profile schema",
+        )?;
+
+        // Stored values are not revalidated: a tightened rule applies from the next write.
+        let schema = match self.schema_repository.get_by_realm(realm.id).await? {
+            Some(mut schema) => {
+                input.config.validate().map_err(violations_error)?;
+                schema.config = input.config;
+                schema.updated_at = Utc::now();
+                schema
+            }
+            None => UserProfileSchema::new(realm.id, input.config).map_err(violations_error)?,
+        };
+
+        self.schema_repository.upsert(schema).await
+    }
+
+    async fn get_registration_profile(
+        &self,
+        realm_name: String,
+    ) -> Result<UserProfileConfig, CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+
+        Ok(
+            realm_profile_config(self.schema_repository.as_ref(), realm.id)
+                .await?
+                .registration_view(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ferriskey_domain::client::ports::MockClientRepository;
+    use ferriskey_domain::realm::ports::MockRealmRepository;
+    use ferriskey_domain::user::ports::{MockUserRepository, MockUserRoleRepository};
+    use uuid::Uuid;
+
+    use crate::entities::{
+        AttributePermissions, AttributeType, Audience, ProfileContext, UserProfileAttribute,
+    };
+    use crate::ports::MockUserProfileSchemaRepository;
+
+    type TestService = UserProfileServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockUserProfileSchemaRepository,
+    >;
+
+    fn realm() -> Realm {
+        Realm {
+            id: RealmId::new(Uuid::new_v4()),
+            name: "acme".to_string(),
+            display_name: None,
+            settings: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn attribute(name: &str, edit: Vec<Audience>) -> UserProfileAttribute {
+        UserProfileAttribute {
+            name: name.to_string(),
+            display_name: None,
+            attribute_type: AttributeType::String,
+            validators: vec![],
+            required_for: vec![],
+            permissions: AttributePermissions {
+                view: vec![Audience::Admin],
+                edit,
+            },
+            group: None,
+        }
+    }
+
+    fn service(realm: Realm, schemas: MockUserProfileSchemaRepository) -> TestService {
+        let mut realms = MockRealmRepository::new();
+        realms.expect_get_by_name().returning(move |_| {
+            let realm = realm.clone();
+            Box::pin(async move { Ok(Some(realm)) })
+        });
+
+        UserProfileServiceImpl::new(
+            Arc::new(realms),
+            Arc::new(schemas),
+            Arc::new(FerriskeyPolicy::new(
+                Arc::new(MockUserRepository::new()),
+                Arc::new(MockClientRepository::new()),
+                Arc::new(MockUserRoleRepository::new()),
+            )),
+        )
+    }
+
+    #[tokio::test]
+    async fn registration_profile_only_lists_user_editable_attributes() {
+        let realm = realm();
+        let mut schema = UserProfileSchema::empty_for(realm.id);
+        schema.config.attributes = vec![
+            UserProfileAttribute {
+                required_for: vec![ProfileContext::Registration],
+                ..attribute("company_name", vec![Audience::User, Audience::Admin])
+            },
+            attribute("employee_number", vec![Audience::Admin]),
+        ];
+
+        let mut schemas = MockUserProfileSchemaRepository::new();
+        schemas.expect_get_by_realm().returning(move |_| {
+            let schema = schema.clone();
+            Box::pin(async move { Ok(Some(schema)) })
+        });
+
+        let profile = service(realm, schemas)
+            .get_registration_profile("acme".to_string())
+            .await
+            .unwrap();
+
+        let names: Vec<&str> = profile.attributes.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["company_name"]);
+    }
+
+    #[tokio::test]
+    async fn realms_without_schema_get_an_empty_registration_profile() {
+        let mut schemas = MockUserProfileSchemaRepository::new();
+        schemas
+            .expect_get_by_realm()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        let profile = service(realm(), schemas)
+            .get_registration_profile("acme".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(profile, UserProfileConfig::default());
+    }
+}
@@ -0,0 +1,577 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{NaiveDate, Utc};
+use regex::Regex;
+use serde_json::Value;
+
+use ferriskey_domain::common::app_errors::CoreError;
+
+use crate::entities::{
+    AttributeType, AttributeValidator, Audience, ProfileContext, RESERVED_ATTRIBUTE_NAMES,
+    UnmanagedAttributePolicy, UserProfileAttribute, UserProfileConfig, UserProfileViolation,
+};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Carries the violations through `CoreError` as JSON, so the HTTP layer can report each
+/// one against its attribute.
+pub fn violations_error(violations: Vec<UserProfileViolation>) -> CoreError {
+    CoreError::UserProfileViolation(
+        serde_json::to_string(&violations).unwrap_or_else(|_| "invalid user profile".to_string()),
+    )
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+fn anchored(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{pattern})$"))
+}
+
+fn check_validator(
+    attribute: &UserProfileAttribute,
+    validator: &AttributeValidator,
+) -> Option<String> {
+    let kind = attribute.attribute_type;
+    match validator {
+        AttributeValidator::Pattern { pattern, .. } => {
+            if kind != AttributeType::String {
+                return Some("pattern validators only apply to string attributes".to_string());
+            }
+            anchored(pattern)
+                .err()
+                .map(|e| format!("invalid pattern: {e}"))
+        }
+        AttributeValidator::Length { min, max } => {
+            if kind != AttributeType::String {
+                return Some("length validators only apply to string attributes".to_string());
+            }
+            match (min, max) {
+                (None, None) => Some("length validator needs a minimum or a maximum".to_string()),
+                (Some(min), Some(max)) if min > max => {
+                    Some("minimum length is greater than the maximum".to_string())
+                }
+                _ => None,
+            }
+        }
+        AttributeValidator::Options { values } => {
+            if values.is_empty() {
+                return Some("options validator needs at least one value".to_string());
+            }
+            values
+                .iter()
+                .find_map(|value| type_error(kind, value))
+                .map(|e| format!("every option {e}"))
+        }
+        AttributeValidator::Date { min, max, .. } => {
+            if kind != AttributeType::Date {
+                return Some("date validators only apply to date attributes".to_string());
+            }
+            match (min, max) {
+                (Some(min), Some(max)) if min > max => {
+                    Some("minimum date is after the maximum".to_string())
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+fn type_error(kind: AttributeType, value: &str) -> Option<&'static str> {
+    match kind {
+        AttributeType::String => None,
+        AttributeType::Integer => value
+            .parse::<i64>()
+            .is_err()
+            .then_some("must be a whole number"),
+        AttributeType::Boolean => {
+            (!matches!(value, "true" | "false")).then_some("must be true or false")
+        }
+        AttributeType::Date => NaiveDate::parse_from_str(value, DATE_FORMAT)
+            .is_err()
+            .then_some("must be a date formatted as YYYY-MM-DD"),
+    }
+}
+
+fn check_value(attribute: &UserProfileAttribute, value: &str, today: NaiveDate) -> Vec<String> {
+    if let Some(message) = type_error(attribute.attribute_type, value) {
+        return vec![message.to_string()];
+    }
+
+    attribute
+        .validators
+        .iter()
+        .filter_map(|validator| match validator {
+            AttributeValidator::Pattern { pattern, message } => {
+                let matches = anchored(pattern).is_ok_and(|re| re.is_match(value));
+                (!matches).then(|| {
+                    message
+                        .clone()
+                        .unwrap_or_else(|| "has an invalid format".to_string())
+                })
+            }
+            AttributeValidator::Length { min, max } => {
+                let length = value.chars().count();
+                if let Some(min) = min
+                    && length < *min
+                {
+                    Some(format!("must be at least {min} characters"))
+                } else if let Some(max) = max
+                    && length > *max
+                {
+                    Some(format!("must be at most {max} characters"))
+                } else {
+                    None
+                }
+            }
+            AttributeValidator::Options { values } => (!values.iter().any(|v| v == value))
+                .then(|| format!("must be one of: {}", values.join(", "))),
+            AttributeValidator::Date {
+                min,
+                max,
+                not_in_future,
+            } => {
+                let date = NaiveDate::parse_from_str(value, DATE_FORMAT).ok()?;
+                if let Some(min) = min
+                    && date < *min
+                {
+                    Some(format!("must not be before {}", min.format(DATE_FORMAT)))
+                } else if let Some(max) = max
+                    && date > *max
+                {
+                    Some(format!("must not be after {}", max.format(DATE_FORMAT)))
+                } else if *not_in_future && date > today {
+                    Some("must not be in the future".to_string())
+                } else {
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+impl UserProfileConfig {
+    pub fn attribute(&self, name: &str) -> Option<&UserProfileAttribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+    }
+
+    /// Checks the schema itself: names, validators, groups, and that every required
+    /// attribute can be filled in by the audience that has to provide it.
+    pub fn validate(&self) -> Result<(), Vec<UserProfileViolation>> {
+        let mut violations = Vec::new();
+
+        let mut group_names = HashSet::new();
+        for group in &self.groups {
+            if !is_valid_name(&group.name) {
+                violations.push(UserProfileViolation::new(
+                    &group.name,
+                    "group names may only contain letters, digits, '_', '-' and '.'",
+                ));
+            } else if !group_names.insert(group.name.as_str()) {
+                violations.push(UserProfileViolation::new(
+                    &group.name,
+                    "group is declared more than once",
+                ));
+            }
+        }
+
+        let mut names = HashSet::new();
+        for attribute in &self.attributes {
+            let name = attribute.name.as_str();
+            if !is_valid_name(name) {
+                violations.push(UserProfileViolation::new(
+                    name,
+                    "attribute names may only contain letters, digits, '_', '-' and '.'",
+                ));
+                continue;
+            }
+            if RESERVED_ATTRIBUTE_NAMES.contains(&name.to_ascii_lowercase().as_str()) {
+                violations.push(UserProfileViolation::new(
+                    name,
+                    "is a built-in user field and cannot be declared",
+                ));
+            }
+            if !names.insert(name) {
+                violations.push(UserProfileViolation::new(
+                    name,
+                    "attribute is declared more than once",
+                ));
+            }
+            if let Some(group) = &attribute.group
+                && !group_names.contains(group.as_str())
+            {
+                violations.push(UserProfileViolation::new(
+                    name,
+                    format!("references unknown group '{group}'"),
+                ));
+            }
+            for validator in &attribute.validators {
+                if let Some(message) = check_validator(attribute, validator) {
+                    violations.push(UserProfileViolation::new(name, message));
+                }
+            }
+            for context in &attribute.required_for {
+                if !attribute.permissions.can_edit(context.audience()) {
+                    violations.push(UserProfileViolation::new(
+                        name,
+                        "is required where its audience is not allowed to edit it",
+                    ));
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    fn can_write(&self, audience: Audience, name: &str) -> bool {
+        match self.attribute(name) {
+            Some(attribute) => attribute.permissions.can_edit(audience),
+            None => match self.unmanaged_attributes {
+                UnmanagedAttributePolicy::Enabled => true,
+                UnmanagedAttributePolicy::AdminEdit => audience == Audience::Admin,
+                UnmanagedAttributePolicy::Disabled => false,
+            },
+        }
+    }
+
+    /// Whether `audience` may read the attribute. Undeclared attributes stay readable by
+    /// administrators and, when users may write them, by users.
+    pub fn can_view(&self, audience: Audience, name: &str) -> bool {
+        match self.attribute(name) {
+            Some(attribute) => attribute.permissions.can_view(audience),
+            None => audience == Audience::Admin || self.can_write(audience, name),
+        }
+    }
+
+    /// Validates `changes` written from `context` on top of the `current` attributes. An
+    /// empty value clears the attribute.
+    pub fn validate_changes(
+        &self,
+        context: ProfileContext,
+        current: &HashMap<String, String>,
+        changes: &HashMap<String, String>,
+    ) -> Result<(), Vec<UserProfileViolation>> {
+        let audience = context.audience();
+        let today = Utc::now().date_naive();
+        let mut violations = Vec::new();
+
+        let mut keys: Vec<&String> = changes.keys().collect();
+        keys.sort();
+        for key in keys {
+            let value = changes[key].trim();
+            if !self.can_write(audience, key) {
+                let message = if self.attribute(key).is_some() {
+                    "cannot be changed here"
+                } else {
+                    "is not part of the user profile"
+                };
+                violations.push(UserProfileViolation::new(key, message));
+                continue;
+            }
+            if let Some(attribute) = self.attribute(key)
+                && !value.is_empty()
+            {
+                violations.extend(
+                    check_value(attribute, value, today)
+                        .into_iter()
+                        .map(|message| UserProfileViolation::new(key, message)),
+                );
+            }
+        }
+
+        for attribute in &self.attributes {
+            if !attribute.is_required_for(context) {
+                continue;
+            }
+            let value = changes
+                .get(&attribute.name)
+                .or_else(|| current.get(&attribute.name))
+                .map(|value| value.trim())
+                .unwrap_or_default();
+            if value.is_empty() {
+                violations.push(UserProfileViolation::new(&attribute.name, "is required"));
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Validates deleting the attribute `name` from `context`.
+    pub fn validate_removal(
+        &self,
+        context: ProfileContext,
+        name: &str,
+    ) -> Result<(), Vec<UserProfileViolation>> {
+        let audience = context.audience();
+        match self.attribute(name) {
+            Some(attribute) if !attribute.permissions.can_edit(audience) => {
+                Err(vec![UserProfileViolation::new(
+                    name,
+                    "cannot be changed here",
+                )])
+            }
+            Some(attribute) if attribute.is_required_for(context) => {
+                Err(vec![UserProfileViolation::new(name, "is required")])
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The claim value for an attribute: declared integers and booleans become JSON numbers
+    /// and booleans, everything else stays a string.
+    pub fn claim_value(&self, name: &str, value: String) -> Value {
+        let typed = match self.attribute(name).map(|a| a.attribute_type) {
+            Some(AttributeType::Integer) => value.parse::<i64>().ok().map(Value::from),
+            Some(AttributeType::Boolean) => value.parse::<bool>().ok().map(Value::Bool),
+            _ => None,
+        };
+
+        typed.unwrap_or(Value::String(value))
+    }
+
+    /// The part of the schema a registration form needs: the attributes users may fill in
+    /// and the groups they belong to.
+    pub fn registration_view(&self) -> UserProfileConfig {
+        let attributes: Vec<UserProfileAttribute> = self
+            .attributes
+            .iter()
+            .filter(|attribute| attribute.permissions.can_edit(Audience::User))
+            .cloned()
+            .collect();
+        let groups = self
+            .groups
+            .iter()
+            .filter(|group| {
+                attributes
+                    .iter()
+                    .any(|attribute| attribute.group.as_deref() == Some(group.name.as_str()))
+            })
+            .cloned()
+            .collect();
+
+        UserProfileConfig {
+            attributes,
+            groups,
+            unmanaged_attributes: self.unmanaged_attributes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::entities::{AttributeGroup, AttributePermissions};
+
+    fn user_editable(name: &str, attribute_type: AttributeType) -> UserProfileAttribute {
+        UserProfileAttribute {
+            name: name.to_string(),
+            display_name: None,
+            attribute_type,
+            validators: vec![],
+            required_for: vec![ProfileContext::Registration],
+            permissions: AttributePermissions {
+                view: vec![Audience::User, Audience::Admin],
+                edit: vec![Audience::User, Audience::Admin],
+            },
+            group: Some("company".to_string()),
+        }
+    }
+
+    fn registration_schema() -> UserProfileConfig {
+        UserProfileConfig {
+            attributes: vec![
+                UserProfileAttribute {
+                    validators: vec![AttributeValidator::Length {
+                        min: Some(2),
+                        max: Some(100),
+                    }],
+                    ..user_editable("company_name", AttributeType::String)
+                },
+                UserProfileAttribute {
+                    validators: vec![AttributeValidator::Pattern {
+                        pattern: r"\+[0-9]{6,15}".to_string(),
+                        message: Some("must be an international phone number".to_string()),
+                    }],
+                    ..user_editable("phone", AttributeType::String)
+                },
+                UserProfileAttribute {
+                    validators: vec![AttributeValidator::Date {
+                        min: None,
+                        max: None,
+                        not_in_future: true,
+                    }],
+                    ..user_editable("terms_accepted_at", AttributeType::Date)
+                },
+                UserProfileAttribute {
+                    name: "employee_number".to_string(),
+                    display_name: None,
+                    attribute_type: AttributeType::Integer,
+                    validators: vec![],
+                    required_for: vec![ProfileContext::Admin],
+                    permissions: AttributePermissions::default(),
+                    group: None,
+                },
+            ],
+            groups: vec![AttributeGroup {
+                name: "company".to_string(),
+                display_name: Some("Company".to_string()),
+                description: None,
+            }],
+            unmanaged_attributes: UnmanagedAttributePolicy::AdminEdit,
+        }
+    }
+
+    fn attributes(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn violated(result: Result<(), Vec<UserProfileViolation>>) -> Vec<String> {
+        result
+            .unwrap_err()
+            .into_iter()
+            .map(|violation| violation.attribute)
+            .collect()
+    }
+
+    #[test]
+    fn accepts_a_complete_registration() {
+        let schema = registration_schema();
+        assert_eq!(schema.validate(), Ok(()));
+
+        let changes = attributes(&[
+            ("company_name", "Ferris Inc"),
+            ("phone", "+33612345678"),
+            ("terms_accepted_at", "2024-05-01"),
+        ]);
+        assert_eq!(
+            schema.validate_changes(ProfileContext::Registration, &HashMap::new(), &changes),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn rejects_missing_and_malformed_registration_values() {
+        let changes = attributes(&[("phone", "0612"), ("terms_accepted_at", "2999-01-01")]);
+
+        let violations = registration_schema()
+            .validate_changes(ProfileContext::Registration, &HashMap::new(), &changes)
+            .unwrap_err();
+
+        assert_eq!(
+            violations,
+            vec![
+                UserProfileViolation::new("phone", "must be an international phone number"),
+                UserProfileViolation::new("terms_accepted_at", "must not be in the future"),
+                UserProfileViolation::new("company_name", "is required"),
+            ]
+        );
+    }
+
+    #[test]
+    fn users_cannot_write_admin_or_unknown_attributes() {
+        let changes = attributes(&[
+            ("company_name", "Ferris Inc"),
+            ("phone", "+33612345678"),
+            ("terms_accepted_at", "2024-05-01"),
+            ("employee_number", "42"),
+            ("nickname", "crab"),
+        ]);
+
+        assert_eq!(
+            violated(registration_schema().validate_changes(
+                ProfileContext::Registration,
+                &HashMap::new(),
+                &changes
+            )),
+            vec!["employee_number", "nickname"]
+        );
+    }
+
+    #[test]
+    fn admin_changes_are_checked_against_stored_values() {
+        let schema = registration_schema();
+        let current = attributes(&[("employee_number", "42")]);
+
+        assert_eq!(
+            schema.validate_changes(
+                ProfileContext::Admin,
+                &current,
+                &attributes(&[("nickname", "crab")])
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            violated(schema.validate_changes(
+                ProfileContext::Admin,
+                &HashMap::new(),
+                &attributes(&[("employee_number", "forty-two")])
+            )),
+            vec!["employee_number"]
+        );
+        assert!(
+            schema
+                .validate_removal(ProfileContext::Admin, "employee_number")
+                .is_err()
+        );
+        assert!(
+            schema
+                .validate_removal(ProfileContext::Admin, "phone")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_inconsistent_schemas() {
+        let mut schema = registration_schema();
+        schema.attributes.push(UserProfileAttribute {
+            validators: vec![AttributeValidator::Pattern {
+                pattern: "(".to_string(),
+                message: None,
+            }],
+            group: Some("missing".to_string()),
+            ..user_editable("email", AttributeType::Integer)
+        });
+        schema.attributes[3].required_for = vec![ProfileContext::Registration];
+
+        assert_eq!(
+            violated(schema.validate()),
+            vec!["employee_number", "email", "email", "email"]
+        );
+    }
+
+    #[test]
+    fn claim_values_follow_the_declared_type() {
+        let schema = registration_schema();
+
+        assert_eq!(
+            schema.claim_value("employee_number", "42".to_string()),
+            Value::from(42)
+        );
+        assert_eq!(
+            schema.claim_value("employee_number", "n/a".to_string()),
+            Value::from("n/a")
+        );
+        assert_eq!(
+            schema.claim_value("department", "42".to_string()),
+            Value::from("42")
+        );
+    }
+}
@@ -0,0 +1,340 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::RealmId;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use url::Url;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::metadata::{AuthenticatorStatus, MetadataEntry};
+
+pub const MAX_ALLOWED_ORIGINS: usize = 20;
+pub const MAX_LISTED_AAGUIDS: usize = 500;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum WebAuthnPolicyValidationError {
+    #[error("'{0}' is not a valid origin")]
+    InvalidOrigin(String),
+    #[error("at most 20 additional origins are allowed")]
+    TooManyOrigins,
+    #[error("at most 500 AAGUIDs can be listed")]
+    TooManyAaguids,
+    #[error("AAGUID {0} is both allowed and blocked")]
+    AaguidAllowedAndBlocked(Uuid),
+    #[error("restricting authenticators requires direct or indirect attestation")]
+    AttestationRequired,
+    #[error("restricting authenticators requires FIDO metadata")]
+    MetadataRequired,
+}
+
+impl From<WebAuthnPolicyValidationError> for CoreError {
+    fn from(error: WebAuthnPolicyValidationError) -> Self {
+        CoreError::InvalidWebAuthnPolicy(error.to_string())
+    }
+}
+
+/// Whether authenticators are asked to prove their make and model at registration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AttestationConveyance {
+    /// No attestation: the AAGUID of the authenticator is unknown.
+    #[default]
+    None,
+    /// Attestation, possibly anonymized by the client.
+    Indirect,
+    /// The attestation statement produced by the authenticator.
+    Direct,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserVerificationRequirement {
+    /// A PIN or biometric check is required at registration and at every sign-in.
+    #[default]
+    Required,
+    /// Verification is requested. Credentials registered with it must keep using it.
+    Preferred,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthenticatorAttachment {
+    /// Built into the device, such as Windows Hello or Touch ID.
+    Platform,
+    /// Roaming, such as a USB or NFC security key.
+    CrossPlatform,
+}
+
+/// How a realm registers and accepts WebAuthn credentials. The defaults are those of
+/// passkeys: no attestation, user verification required, any authenticator.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct WebAuthnPolicySettings {
+    pub attestation: AttestationConveyance,
+    pub user_verification: UserVerificationRequirement,
+    /// Whether the credential must be discoverable, so users can sign in without a username.
+    pub require_resident_key: bool,
+    /// `null` lets the user choose.
+    pub authenticator_attachment: Option<AuthenticatorAttachment>,
+    /// Origins accepted besides the one of the login portal, e.g. a custom login domain.
+    pub allowed_origins: Vec<String>,
+    /// When not empty, only these authenticator models are accepted.
+    pub allowed_aaguids: Vec<Uuid>,
+    pub blocked_aaguids: Vec<Uuid>,
+    /// Only accept authenticators whose FIPS 140 validation the FIDO metadata records.
+    pub fips_certified_only: bool,
+}
+
+impl WebAuthnPolicySettings {
+    pub fn validate(&self) -> Result<(), WebAuthnPolicyValidationError> {
+        if self.allowed_origins.len() > MAX_ALLOWED_ORIGINS {
+            return Err(WebAuthnPolicyValidationError::TooManyOrigins);
+        }
+        for origin in &self.allowed_origins {
+            parse_origin(origin)?;
+        }
+
+        if self.allowed_aaguids.len() > MAX_LISTED_AAGUIDS
+            || self.blocked_aaguids.len() > MAX_LISTED_AAGUIDS
+        {
+            return Err(WebAuthnPolicyValidationError::TooManyAaguids);
+        }
+        if let Some(aaguid) = self
+            .allowed_aaguids
+            .iter()
+            .find(|aaguid| self.blocked_aaguids.contains(aaguid))
+        {
+            return Err(WebAuthnPolicyValidationError::AaguidAllowedAndBlocked(
+                *aaguid,
+            ));
+        }
+
+        // Without attestation every authenticator reports the same all-zero AAGUID.
+        if self.restricts_authenticators() && self.attestation == AttestationConveyance::None {
+            return Err(WebAuthnPolicyValidationError::AttestationRequired);
+        }
+
+        Ok(())
+    }
+
+    pub fn restricts_authenticators(&self) -> bool {
+        !self.allowed_aaguids.is_empty()
+            || !self.blocked_aaguids.is_empty()
+            || self.fips_certified_only
+    }
+
+    /// The additional origins, parsed. Invalid entries were rejected when the policy was saved.
+    pub fn origins(&self) -> Vec<Url> {
+        self.allowed_origins
+            .iter()
+            .filter_map(|origin| parse_origin(origin).ok())
+            .collect()
+    }
+
+    /// Checks an authenticator against the policy. `entry` is its FIDO metadata, when the
+    /// attestation was verified against it.
+    pub fn check_authenticator(
+        &self,
+        aaguid: Option<Uuid>,
+        entry: Option<&MetadataEntry>,
+    ) -> Result<(), AuthenticatorRejection> {
+        if let Some(aaguid) = aaguid
+            && self.blocked_aaguids.contains(&aaguid)
+        {
+            return Err(AuthenticatorRejection::Blocked(aaguid));
+        }
+
+        if !self.allowed_aaguids.is_empty()
+            && !aaguid.is_some_and(|aaguid| self.allowed_aaguids.contains(&aaguid))
+        {
+            return Err(AuthenticatorRejection::NotAllowed(aaguid));
+        }
+
+        if let Some(status) = entry.and_then(MetadataEntry::status)
+            && status.is_compromised()
+        {
+            return Err(AuthenticatorRejection::Compromised(status));
+        }
+
+        if self.fips_certified_only && !entry.is_some_and(MetadataEntry::is_fips_certified) {
+            return Err(AuthenticatorRejection::NotFipsCertified(aaguid));
+        }
+
+        Ok(())
+    }
+}
+
+/// Origins are a scheme, a host and an optional port. Plain HTTP is only accepted on localhost.
+fn parse_origin(origin: &str) -> Result<Url, WebAuthnPolicyValidationError> {
+    let invalid = || WebAuthnPolicyValidationError::InvalidOrigin(origin.to_string());
+
+    let url = Url::parse(origin).map_err(|_| invalid())?;
+    let secure = match url.scheme() {
+        "https" => true,
+        "http" => url.host_str() == Some("localhost"),
+        _ => false,
+    };
+    if !secure
+        || url.host_str().is_none()
+        || url.path() != "/"
+        || url.query().is_some()
+        || url.fragment().is_some()
+        || !url.username().is_empty()
+    {
+        return Err(invalid());
+    }
+
+    Ok(url)
+}
+
+/// Why an authenticator was refused.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AuthenticatorRejection {
+    #[error("authenticator model {0} is blocked")]
+    Blocked(Uuid),
+    #[error("authenticator model {} is not in the allowed list", display_aaguid(.0))]
+    NotAllowed(Option<Uuid>),
+    #[error("the FIDO metadata reports this authenticator model as {0:?}")]
+    Compromised(AuthenticatorStatus),
+    #[error("authenticator model {} is not FIPS certified", display_aaguid(.0))]
+    NotFipsCertified(Option<Uuid>),
+}
+
+fn display_aaguid(aaguid: &Option<Uuid>) -> String {
+    aaguid.map_or_else(|| "unknown".to_string(), |aaguid| aaguid.to_string())
+}
+
+impl From<AuthenticatorRejection> for CoreError {
+    fn from(rejection: AuthenticatorRejection) -> Self {
+        CoreError::WebAuthnAuthenticatorRejected(rejection.to_string())
+    }
+}
+
+/// The WebAuthn settings of one realm. Realms that never configured one use the defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct WebAuthnPolicy {
+    pub realm_id: RealmId,
+    #[serde(flatten)]
+    pub settings: WebAuthnPolicySettings,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WebAuthnPolicy {
+    pub fn new(
+        realm_id: RealmId,
+        settings: WebAuthnPolicySettings,
+    ) -> Result<Self, WebAuthnPolicyValidationError> {
+        settings.validate()?;
+        let now = Utc::now();
+
+        Ok(Self {
+            realm_id,
+            settings,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn default_for(realm_id: RealmId) -> Self {
+        let now = Utc::now();
+
+        Self {
+            realm_id,
+            settings: WebAuthnPolicySettings::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// What FerrisKey knows about the FIDO metadata it was started with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct MetadataSummary {
+    pub loaded: bool,
+    /// Serial number of the BLOB.
+    pub serial: Option<u64>,
+    /// Date by which FIDO publishes a newer BLOB.
+    pub next_update: Option<NaiveDate>,
+    pub authenticator_count: usize,
+    /// Whether the BLOB signature was checked against the configured root certificate.
+    pub signature_verified: bool,
+}
+
+pub struct UpdateWebAuthnPolicyInput {
+    pub realm_name: String,
+    pub settings: WebAuthnPolicySettings,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restrictions_require_attestation() {
+        let settings = WebAuthnPolicySettings {
+            fips_certified_only: true,
+            ..WebAuthnPolicySettings::default()
+        };
+        assert_eq!(
+            settings.validate(),
+            Err(WebAuthnPolicyValidationError::AttestationRequired)
+        );
+
+        let settings = WebAuthnPolicySettings {
+            attestation: AttestationConveyance::Direct,
+            ..settings
+        };
+        assert_eq!(settings.validate(), Ok(()));
+    }
+
+    #[test]
+    fn origins_must_be_bare_and_secure() {
+        for origin in [
+            "http://login.acme.test",
+            "https://login.acme.test/path",
+            "https://login.acme.test?x=1",
+            "ftp://login.acme.test",
+            "login.acme.test",
+        ] {
+            let settings = WebAuthnPolicySettings {
+                allowed_origins: vec![origin.to_string()],
+                ..WebAuthnPolicySettings::default()
+            };
+            assert!(settings.validate().is_err(), "{origin} was accepted");
+        }
+
+        let settings = WebAuthnPolicySettings {
+            allowed_origins: vec![
+                "https://login.acme.test".to_string(),
+                "https://acme.test:8443".to_string(),
+                "http://localhost:5555".to_string(),
+            ],
+            ..WebAuthnPolicySettings::default()
+        };
+        assert_eq!(settings.validate(), Ok(()));
+        assert_eq!(settings.origins().len(), 3);
+    }
+
+    #[test]
+    fn blocked_and_unlisted_authenticators_are_rejected() {
+        let allowed = Uuid::new_v4();
+        let blocked = Uuid::new_v4();
+        let settings = WebAuthnPolicySettings {
+            attestation: AttestationConveyance::Direct,
+            allowed_aaguids: vec![allowed],
+            blocked_aaguids: vec![blocked],
+            ..WebAuthnPolicySettings::default()
+        };
+
+        assert_eq!(settings.check_authenticator(Some(allowed), None), Ok(()));
+        assert_eq!(
+            settings.check_authenticator(Some(blocked), None),
+            Err(AuthenticatorRejection::Blocked(blocked))
+        );
+        assert_eq!(
+            settings.check_authenticator(None, None),
+            Err(AuthenticatorRejection::NotAllowed(None))
+        );
+    }
+}
@@ -0,0 +1,10 @@
+pub mod entities;
+pub mod metadata;
+pub mod ports;
+
+pub use entities::*;
+pub use metadata::*;
+pub use ports::*;
+
+pub mod policies;
+pub mod services;
@@ -0,0 +1,538 @@
+//! The FIDO Metadata Service (MDS3) BLOB: a JWT signed by the FIDO Alliance listing, per
+//! authenticator model, the roots its attestations chain to, its certifications and its
+//! security status. FerrisKey reads it from a local file, which operators refresh from
+//! <https://mds3.fidoalliance.org/> on their own schedule.
+
+use std::collections::BTreeMap;
+use std::fs;
+
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use chrono::{NaiveDate, Utc};
+use openssl::bn::BigNum;
+use openssl::ecdsa::EcdsaSig;
+use openssl::error::ErrorStack;
+use openssl::hash::MessageDigest;
+use openssl::sign::Verifier;
+use openssl::stack::Stack;
+use openssl::x509::store::X509StoreBuilder;
+use openssl::x509::{X509, X509StoreContext};
+use serde::Deserialize;
+use thiserror::Error;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::entities::MetadataSummary;
+
+#[derive(Debug, Error)]
+pub enum MetadataError {
+    #[error("failed to read {path}: {source}")]
+    Read {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("the BLOB is not a JWT")]
+    MalformedJwt,
+    #[error("invalid BLOB header: {0}")]
+    InvalidHeader(String),
+    #[error("invalid BLOB payload: {0}")]
+    InvalidPayload(String),
+    #[error("unsupported BLOB signature algorithm {0}")]
+    UnsupportedAlgorithm(String),
+    #[error("invalid certificate: {0}")]
+    InvalidCertificate(String),
+    #[error("the BLOB certificate chain does not lead to the root certificate")]
+    UntrustedChain,
+    #[error("the BLOB signature is invalid")]
+    InvalidSignature,
+}
+
+impl From<ErrorStack> for MetadataError {
+    fn from(error: ErrorStack) -> Self {
+        MetadataError::InvalidCertificate(error.to_string())
+    }
+}
+
+/// The security status FIDO reports for an authenticator model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum AuthenticatorStatus {
+    #[serde(rename = "NOT_FIDO_CERTIFIED")]
+    NotFidoCertified,
+    #[serde(rename = "FIDO_CERTIFIED")]
+    FidoCertified,
+    #[serde(rename = "USER_VERIFICATION_BYPASS")]
+    UserVerificationBypass,
+    #[serde(rename = "ATTESTATION_KEY_COMPROMISE")]
+    AttestationKeyCompromise,
+    #[serde(rename = "USER_KEY_REMOTE_COMPROMISE")]
+    UserKeyRemoteCompromise,
+    #[serde(rename = "USER_KEY_PHYSICAL_COMPROMISE")]
+    UserKeyPhysicalCompromise,
+    #[serde(rename = "UPDATE_AVAILABLE")]
+    UpdateAvailable,
+    #[serde(rename = "REVOKED")]
+    Revoked,
+    #[serde(rename = "SELF_ASSERTION_SUBMITTED")]
+    SelfAssertionSubmitted,
+    #[serde(rename = "FIDO_CERTIFIED_L1")]
+    FidoCertifiedL1,
+    #[serde(rename = "FIDO_CERTIFIED_L1plus")]
+    FidoCertifiedL1Plus,
+    #[serde(rename = "FIDO_CERTIFIED_L2")]
+    FidoCertifiedL2,
+    #[serde(rename = "FIDO_CERTIFIED_L2plus")]
+    FidoCertifiedL2Plus,
+    #[serde(rename = "FIDO_CERTIFIED_L3")]
+    FidoCertifiedL3,
+    #[serde(rename = "FIDO_CERTIFIED_L3plus")]
+    FidoCertifiedL3Plus,
+    #[serde(other)]
+    Other,
+}
+
+impl AuthenticatorStatus {
+    /// Statuses under which credentials of the model can no longer be trusted.
+    pub fn is_compromised(&self) -> bool {
+        matches!(
+            self,
+            AuthenticatorStatus::UserVerificationBypass
+                | AuthenticatorStatus::AttestationKeyCompromise
+                | AuthenticatorStatus::UserKeyRemoteCompromise
+                | AuthenticatorStatus::UserKeyPhysicalCompromise
+                | AuthenticatorStatus::Revoked
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusReport {
+    pub status: AuthenticatorStatus,
+    pub effective_date: Option<NaiveDate>,
+}
+
+/// One FIDO2 authenticator model of the BLOB.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataEntry {
+    pub aaguid: Uuid,
+    pub description: String,
+    /// DER certificates its attestations must chain to.
+    pub attestation_root_certificates: Vec<Vec<u8>>,
+    pub status_reports: Vec<StatusReport>,
+    /// The `certifications` the authenticator reports in `authenticatorGetInfo`, such as
+    /// `FIPS-CMVP-2` or `FIDO`.
+    pub certifications: Vec<String>,
+}
+
+impl MetadataEntry {
+    /// The most recent status report.
+    pub fn status(&self) -> Option<AuthenticatorStatus> {
+        self.status_reports
+            .iter()
+            .max_by_key(|report| report.effective_date)
+            .map(|report| report.status)
+    }
+
+    /// Whether the model holds a FIPS 140 (CMVP) validation.
+    pub fn is_fips_certified(&self) -> bool {
+        self.certifications
+            .iter()
+            .any(|certification| certification.starts_with("FIPS-CMVP"))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MetadataBlob {
+    pub serial: u64,
+    pub next_update: NaiveDate,
+    /// Whether the signature was checked against a root certificate. Unverified BLOBs are
+    /// trusted as the local file they were read from.
+    pub signature_verified: bool,
+    entries: BTreeMap<Uuid, MetadataEntry>,
+}
+
+#[derive(Deserialize)]
+struct BlobHeader {
+    alg: String,
+    #[serde(default)]
+    x5c: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BlobPayload {
+    no: u64,
+    next_update: NaiveDate,
+    #[serde(default)]
+    entries: Vec<BlobEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BlobEntry {
+    aaguid: Option<Uuid>,
+    metadata_statement: Option<BlobStatement>,
+    #[serde(default)]
+    status_reports: Vec<StatusReport>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BlobStatement {
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    attestation_root_certificates: Vec<String>,
+    authenticator_get_info: Option<BlobGetInfo>,
+}
+
+#[derive(Deserialize)]
+struct BlobGetInfo {
+    #[serde(default)]
+    certifications: BTreeMap<String, serde_json::Value>,
+}
+
+impl MetadataBlob {
+    /// Reads the BLOB at `blob_path`, checking its signature when a root certificate (PEM or
+    /// DER, normally the FIDO Alliance's GlobalSign Root CA - R3) is given.
+    pub fn load(
+        blob_path: &str,
+        root_certificate_path: Option<&str>,
+    ) -> Result<Self, MetadataError> {
+        let read = |path: &str| {
+            fs::read(path).map_err(|source| MetadataError::Read {
+                path: path.to_string(),
+                source,
+            })
+        };
+
+        let root = root_certificate_path
+            .map(|path| {
+                let bytes = read(path)?;
+                X509::from_pem(&bytes)
+                    .or_else(|_| X509::from_der(&bytes))
+                    .map_err(MetadataError::from)
+            })
+            .transpose()?;
+        let jwt = String::from_utf8(read(blob_path)?).map_err(|_| MetadataError::MalformedJwt)?;
+
+        let blob = Self::from_jwt(jwt.trim(), root.as_ref())?;
+        if !blob.signature_verified {
+            warn!("FIDO metadata {blob_path} was loaded without checking its signature");
+        }
+        if blob.next_update < Utc::now().date_naive() {
+            warn!(
+                "FIDO metadata {blob_path} is out of date: a newer BLOB was due on {}",
+                blob.next_update
+            );
+        }
+        info!(
+            "Loaded FIDO metadata #{} with {} authenticators",
+            blob.serial,
+            blob.entries.len()
+        );
+
+        Ok(blob)
+    }
+
+    pub fn from_jwt(jwt: &str, root: Option<&X509>) -> Result<Self, MetadataError> {
+        let mut parts = jwt.split('.');
+        let (Some(header), Some(payload), Some(signature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(MetadataError::MalformedJwt);
+        };
+        let decode = |part: &str| {
+            URL_SAFE_NO_PAD
+                .decode(part)
+                .map_err(|_| MetadataError::MalformedJwt)
+        };
+
+        let signature_verified = match root {
+            Some(root) => {
+                let parsed: BlobHeader = serde_json::from_slice(&decode(header)?)
+                    .map_err(|e| MetadataError::InvalidHeader(e.to_string()))?;
+                let signing_input = format!("{header}.{payload}");
+                verify_signature(&parsed, signing_input.as_bytes(), &decode(signature)?, root)?;
+                true
+            }
+            None => false,
+        };
+
+        let payload: BlobPayload = serde_json::from_slice(&decode(payload)?)
+            .map_err(|e| MetadataError::InvalidPayload(e.to_string()))?;
+
+        let entries = payload
+            .entries
+            .into_iter()
+            .filter_map(|entry| {
+                // FIDO U2F and UAF models are identified by key identifiers instead of an
+                // AAGUID and never register as WebAuthn credentials with one.
+                let aaguid = entry.aaguid.filter(|aaguid| !aaguid.is_nil())?;
+                let statement = entry.metadata_statement?;
+                let attestation_root_certificates = statement
+                    .attestation_root_certificates
+                    .iter()
+                    .filter_map(|certificate| STANDARD.decode(certificate).ok())
+                    .collect();
+                let certifications = statement
+                    .authenticator_get_info
+                    .map(|info| info.certifications.into_keys().collect())
+                    .unwrap_or_default();
+
+                Some((
+                    aaguid,
+                    MetadataEntry {
+                        aaguid,
+                        description: statement.description,
+                        attestation_root_certificates,
+                        status_reports: entry.status_reports,
+                        certifications,
+                    },
+                ))
+            })
+            .collect();
+
+        Ok(Self {
+            serial: payload.no,
+            next_update: payload.next_update,
+            signature_verified,
+            entries,
+        })
+    }
+
+    pub fn entry(&self, aaguid: Uuid) -> Option<&MetadataEntry> {
+        self.entries.get(&aaguid)
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &MetadataEntry> {
+        self.entries.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl MetadataSummary {
+    pub fn of(blob: Option<&MetadataBlob>) -> Self {
+        Self {
+            loaded: blob.is_some(),
+            serial: blob.map(|blob| blob.serial),
+            next_update: blob.map(|blob| blob.next_update),
+            authenticator_count: blob.map_or(0, MetadataBlob::len),
+            signature_verified: blob.is_some_and(|blob| blob.signature_verified),
+        }
+    }
+}
+
+/// Checks that the `x5c` chain of the header leads to `root` and that its leaf signed the
+/// BLOB. Revocation lists are not consulted.
+fn verify_signature(
+    header: &BlobHeader,
+    signing_input: &[u8],
+    signature: &[u8],
+    root: &X509,
+) -> Result<(), MetadataError> {
+    let mut chain = header
+        .x5c
+        .iter()
+        .map(|certificate| {
+            let der = STANDARD
+                .decode(certificate)
+                .map_err(|e| MetadataError::InvalidHeader(e.to_string()))?;
+            X509::from_der(&der).map_err(MetadataError::from)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if chain.is_empty() {
+        return Err(MetadataError::InvalidHeader(
+            "x5c certificate chain is missing".to_string(),
+        ));
+    }
+    let leaf = chain.remove(0);
+
+    let mut intermediates = Stack::new()?;
+    for certificate in chain {
+        intermediates.push(certificate)?;
+    }
+    let mut store = X509StoreBuilder::new()?;
+    store.add_cert(root.clone())?;
+    let store = store.build();
+
+    let mut context = X509StoreContext::new()?;
+    if !context.init(&store, &leaf, &intermediates, |context| {
+        context.verify_cert()
+    })? {
+        return Err(MetadataError::UntrustedChain);
+    }
+
+    let signature = match header.alg.as_str() {
+        "RS256" => signature.to_vec(),
+        // JWS carries ECDSA signatures as the raw `r || s` pair, OpenSSL expects DER.
+        "ES256" if signature.len() == 64 => {
+            let (r, s) = signature.split_at(32);
+            EcdsaSig::from_private_components(BigNum::from_slice(r)?, BigNum::from_slice(s)?)?
+                .to_der()?
+        }
+        other => return Err(MetadataError::UnsupportedAlgorithm(other.to_string())),
+    };
+
+    let key = leaf.public_key()?;
+    let mut verifier = Verifier::new(MessageDigest::sha256(), &key)?;
+    verifier.update(signing_input)?;
+    if verifier.verify(&signature)? {
+        Ok(())
+    } else {
+        Err(MetadataError::InvalidSignature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use openssl::asn1::Asn1Time;
+    use openssl::ec::{EcGroup, EcKey};
+    use openssl::nid::Nid;
+    use openssl::pkey::{PKey, Private};
+    use openssl::sign::Signer;
+    use openssl::x509::extension::BasicConstraints;
+    use openssl::x509::{X509Builder, X509NameBuilder};
+
+    const YUBIKEY_FIPS: &str = "62e54e98-c209-4df3-b692-de71bb6a8528";
+    const REVOKED_KEY: &str = "d94a29d9-9e5d-4e0c-8d4a-52a0d31f1ac8";
+
+    fn key() -> PKey<Private> {
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap()
+    }
+
+    fn certificate(
+        name: &str,
+        key: &PKey<Private>,
+        issuer: Option<(&X509, &PKey<Private>)>,
+    ) -> X509 {
+        let mut subject = X509NameBuilder::new().unwrap();
+        subject.append_entry_by_text("CN", name).unwrap();
+        let subject = subject.build();
+
+        let mut builder = X509Builder::new().unwrap();
+        builder.set_version(2).unwrap();
+        builder.set_subject_name(&subject).unwrap();
+        builder
+            .set_issuer_name(issuer.map_or(&subject, |(issuer, _)| issuer.subject_name()))
+            .unwrap();
+        builder.set_pubkey(key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(30).unwrap())
+            .unwrap();
+        if issuer.is_none() {
+            builder
+                .append_extension(BasicConstraints::new().critical().ca().build().unwrap())
+                .unwrap();
+        }
+        let signing_key = issuer.map_or(key, |(_, key)| key);
+        builder.sign(signing_key, MessageDigest::sha256()).unwrap();
+        builder.build()
+    }
+
+    fn payload() -> String {
+        serde_json::json!({
+            "no": 42,
+            "nextUpdate": "2099-01-01",
+            "entries": [
+                {
+                    "aaguid": YUBIKEY_FIPS,
+                    "metadataStatement": {
+                        "description": "YubiKey 5 FIPS Series",
+                        "attestationRootCertificates": [STANDARD.encode(b"not a real certificate")],
+                        "authenticatorGetInfo": { "certifications": { "FIPS-CMVP-2": 2, "FIDO": 1 } }
+                    },
+                    "statusReports": [{ "status": "FIDO_CERTIFIED_L2", "effectiveDate": "2023-01-01" }]
+                },
+                {
+                    "aaguid": REVOKED_KEY,
+                    "metadataStatement": { "description": "Leaky Key" },
+                    "statusReports": [
+                        { "status": "FIDO_CERTIFIED", "effectiveDate": "2020-01-01" },
+                        { "status": "ATTESTATION_KEY_COMPROMISE", "effectiveDate": "2022-06-01" }
+                    ]
+                },
+                { "attestationCertificateKeyIdentifiers": ["923881fe2f214ee465484371aeb72e97f5a58e0a"] }
+            ]
+        })
+        .to_string()
+    }
+
+    fn signed_blob(signer: &PKey<Private>, leaf: &X509) -> String {
+        let header = serde_json::json!({
+            "alg": "ES256",
+            "typ": "JWT",
+            "x5c": [STANDARD.encode(leaf.to_der().unwrap())],
+        });
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header.to_string()),
+            URL_SAFE_NO_PAD.encode(payload())
+        );
+
+        let mut der_signer = Signer::new(MessageDigest::sha256(), signer).unwrap();
+        der_signer.update(signing_input.as_bytes()).unwrap();
+        let signature = EcdsaSig::from_der(&der_signer.sign_to_vec().unwrap()).unwrap();
+        let mut raw = signature.r().to_vec_padded(32).unwrap();
+        raw.extend(signature.s().to_vec_padded(32).unwrap());
+
+        format!("{signing_input}.{}", URL_SAFE_NO_PAD.encode(raw))
+    }
+
+    #[test]
+    fn signed_blob_is_verified_and_read() {
+        let root_key = key();
+        let root = certificate("FIDO Test Root", &root_key, None);
+        let leaf_key = key();
+        let leaf = certificate("mds.test", &leaf_key, Some((&root, &root_key)));
+
+        let blob = MetadataBlob::from_jwt(&signed_blob(&leaf_key, &leaf), Some(&root)).unwrap();
+
+        assert!(blob.signature_verified);
+        assert_eq!(blob.serial, 42);
+        assert_eq!(blob.len(), 2);
+
+        let fips = blob.entry(YUBIKEY_FIPS.parse().unwrap()).unwrap();
+        assert!(fips.is_fips_certified());
+        assert_eq!(fips.status(), Some(AuthenticatorStatus::FidoCertifiedL2));
+
+        let revoked = blob.entry(REVOKED_KEY.parse().unwrap()).unwrap();
+        assert!(!revoked.is_fips_certified());
+        assert!(revoked.status().unwrap().is_compromised());
+    }
+
+    #[test]
+    fn blob_signed_outside_the_root_is_rejected() {
+        let root_key = key();
+        let root = certificate("FIDO Test Root", &root_key, None);
+        let other_key = key();
+        let other_root = certificate("Someone Else", &other_key, None);
+        let leaf_key = key();
+        let leaf = certificate("mds.test", &leaf_key, Some((&other_root, &other_key)));
+
+        assert!(matches!(
+            MetadataBlob::from_jwt(&signed_blob(&leaf_key, &leaf), Some(&root)),
+            Err(MetadataError::UntrustedChain)
+        ));
+
+        // A chain to the right root does not vouch for a payload signed by another key.
+        let leaf = certificate("mds.test", &leaf_key, Some((&root, &root_key)));
+        assert!(matches!(
+            MetadataBlob::from_jwt(&signed_blob(&key(), &leaf), Some(&root)),
+            Err(MetadataError::InvalidSignature)
+        ));
+    }
+}
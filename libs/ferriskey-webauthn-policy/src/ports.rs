@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::realm::{Realm, RealmId};
+
+use crate::entities::{
+    MetadataSummary, UpdateWebAuthnPolicyInput, WebAuthnPolicy, WebAuthnPolicySettings,
+};
+use crate::metadata::MetadataBlob;
+
+/// Persistence for the WebAuthn policy of each realm.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait WebAuthnPolicyRepository: Send + Sync {
+    fn get_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<WebAuthnPolicy>, CoreError>> + Send;
+
+    fn upsert(
+        &self,
+        policy: WebAuthnPolicy,
+    ) -> impl Future<Output = Result<WebAuthnPolicy, CoreError>> + Send;
+}
+
+/// What the WebAuthn ceremonies of a realm are held to, for the services running them.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait WebAuthnPolicyProvider: Send + Sync {
+    fn webauthn_policy(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<WebAuthnPolicySettings, CoreError>> + Send;
+
+    /// The FIDO metadata FerrisKey was started with, if any.
+    fn metadata(&self) -> Option<Arc<MetadataBlob>>;
+}
+
+pub trait WebAuthnPolicyAccessPolicy: Send + Sync {
+    fn can_view_webauthn_policy(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_manage_webauthn_policy(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+pub trait WebAuthnPolicyService: Send + Sync {
+    fn get_webauthn_policy(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<WebAuthnPolicy, CoreError>> + Send;
+
+    fn update_webauthn_policy(
+        &self,
+        identity: Identity,
+        input: UpdateWebAuthnPolicyInput,
+    ) -> impl Future<Output = Result<WebAuthnPolicy, CoreError>> + Send;
+
+    /// The FIDO metadata attestations are verified against. Server-wide, but exposed per
+    /// realm to whoever may view its policy.
+    fn get_fido_metadata(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<MetadataSummary, CoreError>> + Send;
+}
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use ferriskey_domain::auth::Identity;
+use ferriskey_domain::client::ports::ClientRepository;
+use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::common::policies::{FerriskeyPolicy, ensure_policy};
+use ferriskey_domain::realm::ports::RealmRepository;
+use ferriskey_domain::realm::{Realm, RealmId};
+use ferriskey_domain::user::ports::{UserRepository, UserRoleRepository};
+
+use crate::{
+    MetadataBlob, MetadataSummary, UpdateWebAuthnPolicyInput, WebAuthnPolicy,
+    WebAuthnPolicyAccessPolicy, WebAuthnPolicyProvider, WebAuthnPolicyRepository,
+    WebAuthnPolicyService, WebAuthnPolicySettings, WebAuthnPolicyValidationError,
+};
+
+#[derive(Clone, Debug)]
+pub struct WebAuthnPolicyServiceImpl<R, U, C, UR, WP>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    WP: WebAuthnPolicyRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) policy_repository: Arc<WP>,
+    pub(crate) metadata: Option<Arc<MetadataBlob>>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, WP> WebAuthnPolicyServiceImpl<R, U, C, UR, WP>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    WP: WebAuthnPolicyRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        policy_repository: Arc<WP>,
+        metadata: Option<Arc<MetadataBlob>>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            policy_repository,
+            metadata,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    async fn ensure_can_view(&self, identity: &Identity, realm: &Realm) -> Result<(), CoreError> {
+        ensure_policy(
+            self.policy.can_view_webauthn_policy(identity, realm).await,
+            "insufficient permissions to view the WebAuthn policy",
+        )
+    }
+}
+
+impl<R, U, C, UR, WP> WebAuthnPolicyService for WebAuthnPolicyServiceImpl<R, U, C, UR, WP>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    WP: WebAuthnPolicyRepository,
+{
+    async fn get_webauthn_policy(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<WebAuthnPolicy, CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+        self.ensure_can_view(&identity, &realm).await?;
+
+        Ok(self
+            .policy_repository
+            .get_by_realm(realm.id)
+            .await?
+            .unwrap_or_else(|| WebAuthnPolicy::default_for(realm.id)))
+    }
+
+    async fn update_webauthn_policy(
+        &self,
+        identity: Identity,
+        input: UpdateWebAuthnPolicyInput,
+    ) -> Result<WebAuthnPolicy, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+        ensure_policy(
+            self.policy
+                .can_manage_webauthn_policy(&identity, &realm)
+                .await,
+            "insufficient permissions to manage the WebAuthn policy",
+        )?;
+
+        // Without metadata the attestation cannot be verified, so the AAGUID is whatever the
+        // client claims and FIPS validations are unknown.
+        if input.settings.restricts_authenticators() && self.metadata.is_none() {
+            return Err(WebAuthnPolicyValidationError::MetadataRequired.into());
+        }
+
+        let policy = match self.policy_repository.get_by_realm(realm.id).await? {
+            Some(mut policy) => {
+                input.settings.validate()?;
+                policy.settings = input.settings;
+                policy.updated_at = chrono::Utc::now();
+                policy
+            }
+            None => WebAuthnPolicy::new(realm.id, input.settings)?,
+        };
+
+        self.policy_repository.upsert(policy).await
+    }
+
+    async fn get_fido_metadata(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<MetadataSummary, CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+        self.ensure_can_view(&identity, &realm).await?;
+
+        Ok(MetadataSummary::of(self.metadata.as_deref()))
+    }
+}
+
+impl<R, U, C, UR, WP> WebAuthnPolicyProvider for WebAuthnPolicyServiceImpl<R, U, C, UR, WP>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    WP: WebAuthnPolicyRepository,
+{
+    async fn webauthn_policy(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<WebAuthnPolicySettings, CoreError> {
+        Ok(self
+            .policy_repository
+            .get_by_realm(realm_id)
+            .await?
+            .map(|policy| policy.settings)
+            .unwrap_or_default())
+    }
+
+    fn metadata(&self) -> Option<Arc<MetadataBlob>> {
+        self.metadata.clone()
+    }
+}
@@ -76,6 +76,10 @@ pub enum WebhookTrigger {
     ClientMaintenanceEnabled,
     #[serde(rename = "client.maintenance.disabled")]
     ClientMaintenanceDisabled,
+    #[serde(rename = "maintenance.window.started")]
+    MaintenanceWindowStarted,
+    #[serde(rename = "maintenance.window.ended")]
+    MaintenanceWindowEnded,
 }
 
 impl Display for WebhookTrigger {
@@ -120,6 +124,8 @@ impl Display for WebhookTrigger {
             WebhookTrigger::ClientMaintenanceDisabled => {
                 write!(f, "client.maintenance.disabled")
             }
+            WebhookTrigger::MaintenanceWindowStarted => write!(f, "maintenance.window.started"),
+            WebhookTrigger::MaintenanceWindowEnded => write!(f, "maintenance.window.ended"),
         }
     }
 }
@@ -164,6 +170,8 @@ impl TryFrom<String> for WebhookTrigger {
             "webhook.deleted" => Ok(WebhookTrigger::WebhookDeleted),
             "client.maintenance.enabled" => Ok(WebhookTrigger::ClientMaintenanceEnabled),
             "client.maintenance.disabled" => Ok(WebhookTrigger::ClientMaintenanceDisabled),
+            "maintenance.window.started" => Ok(WebhookTrigger::MaintenanceWindowStarted),
+            "maintenance.window.ended" => Ok(WebhookTrigger::MaintenanceWindowEnded),
             _ => Err("Invalid webhook trigger".to_string()),
         }
     }